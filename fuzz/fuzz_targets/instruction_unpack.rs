@@ -0,0 +1,11 @@
+#![no_main]
+
+use {libfuzzer_sys::fuzz_target, rndr::instruction::RNDRInstruction};
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(instruction) = RNDRInstruction::unpack(data) {
+        // Anything that unpacks must pack to bytes that unpack to the same instruction
+        let packed = instruction.pack();
+        assert_eq!(RNDRInstruction::unpack(&packed).unwrap(), instruction);
+    }
+});
@@ -0,0 +1,91 @@
+#![no_main]
+
+use {
+    arbitrary::Arbitrary,
+    libfuzzer_sys::fuzz_target,
+    rndr::{pda::find_escrow_address, processor::process_instruction},
+    solana_program::{account_info::AccountInfo, pubkey::Pubkey, system_program, sysvar},
+    spl_associated_token_account::get_associated_token_address,
+};
+
+/// Account key, either one the processor validates against or an arbitrary one
+#[derive(Arbitrary, Debug)]
+enum FuzzKey {
+    Known(u8),
+    Arbitrary([u8; 32]),
+}
+
+#[derive(Arbitrary, Debug)]
+struct FuzzAccount {
+    key: FuzzKey,
+    owner: FuzzKey,
+    is_signer: bool,
+    is_writable: bool,
+    lamports: u64,
+    data: Vec<u8>,
+}
+
+#[derive(Arbitrary, Debug)]
+struct FuzzInput {
+    instruction_data: Vec<u8>,
+    accounts: Vec<FuzzAccount>,
+}
+
+fn known_keys() -> Vec<Pubkey> {
+    let program_id = rndr::id();
+    let token_mint = Pubkey::new_from_array([1; 32]);
+    let (escrow, _bump_seed) = find_escrow_address(&program_id, &token_mint);
+    vec![
+        program_id,
+        system_program::id(),
+        spl_token::id(),
+        spl_associated_token_account::id(),
+        sysvar::rent::id(),
+        token_mint,
+        escrow,
+        get_associated_token_address(&escrow, &token_mint),
+    ]
+}
+
+fn resolve(key: &FuzzKey, known_keys: &[Pubkey]) -> Pubkey {
+    match key {
+        FuzzKey::Known(index) => known_keys[*index as usize % known_keys.len()],
+        FuzzKey::Arbitrary(bytes) => Pubkey::new_from_array(*bytes),
+    }
+}
+
+fuzz_target!(|input: FuzzInput| {
+    let known_keys = known_keys();
+    let mut accounts: Vec<_> = input
+        .accounts
+        .into_iter()
+        .map(|account| {
+            (
+                resolve(&account.key, &known_keys),
+                resolve(&account.owner, &known_keys),
+                account.is_signer,
+                account.is_writable,
+                account.lamports,
+                account.data,
+            )
+        })
+        .collect();
+    let account_infos: Vec<_> = accounts
+        .iter_mut()
+        .map(|(key, owner, is_signer, is_writable, lamports, data)| {
+            AccountInfo::new(
+                key,
+                *is_signer,
+                *is_writable,
+                lamports,
+                data,
+                owner,
+                false,
+                0,
+            )
+        })
+        .collect();
+
+    // Invalid accounts and instruction data must be rejected with an error, never a panic
+    let _ = process_instruction(&rndr::id(), &account_infos, &input.instruction_data);
+});
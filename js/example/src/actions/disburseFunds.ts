@@ -4,7 +4,7 @@ import { createDisburseFundsInstruction, RNDR_TOKEN_MINT } from 'rndr';
 import { AUTHORITY_PUBKEY, OWNER_KEYPAIR, OWNER_PUBKEY } from '../config';
 import { sendTransaction } from '../util';
 
-export const disburseFunds = async (amount: number | bigint): Promise<string> => {
+export const disburseFunds = async (amount: number | bigint, jobId: number | bigint = 0): Promise<string> => {
     const [destinationTokenPubkey] = await PublicKey.findProgramAddress(
         [AUTHORITY_PUBKEY.toBuffer(), TOKEN_PROGRAM_ID.toBuffer(), RNDR_TOKEN_MINT.toBuffer()],
         ASSOCIATED_TOKEN_PROGRAM_ID
@@ -13,7 +13,8 @@ export const disburseFunds = async (amount: number | bigint): Promise<string> =>
         amount,
         OWNER_PUBKEY,
         destinationTokenPubkey,
-        AUTHORITY_PUBKEY
+        AUTHORITY_PUBKEY,
+        jobId
     );
     return await sendTransaction([disburseFunds], [OWNER_KEYPAIR]);
 };
@@ -3,3 +3,11 @@ import { PublicKey } from '@solana/web3.js';
 export const RNDR_PROGRAM_ID = new PublicKey('7g4aX3DYhk6xHiGuoAbEnVTp9HMgLqyENoK53AVm267E');
 
 export const RNDR_TOKEN_MINT = new PublicKey('EZPrZ4VS4XonzrcrsJaYVbXHtnAKw89wq5VN7beK4EJF');
+
+export const BPF_LOADER_UPGRADEABLE_PROGRAM_ID = new PublicKey('BPFLoaderUpgradeab1e11111111111111111111111');
+
+export const BUBBLEGUM_PROGRAM_ID = new PublicKey('BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY');
+
+export const ACCOUNT_COMPRESSION_PROGRAM_ID = new PublicKey('cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK');
+
+export const NOOP_PROGRAM_ID = new PublicKey('noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV');
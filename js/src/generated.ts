@@ -0,0 +1,2973 @@
+// Generated by rndr-codegen from the instruction and state definitions of the program, do not edit. Regenerate with
+// `cargo run --features codegen --bin rndr-codegen > js/src/generated.ts` from the repository root.
+import { PublicKey } from '@solana/web3.js';
+import { toBufferLE } from 'bigint-buffer';
+
+class Writer {
+    private readonly buffers: Buffer[] = [];
+
+    u8(value: number): void {
+        this.buffers.push(Buffer.from([value]));
+    }
+
+    u16(value: number): void {
+        const buffer = Buffer.alloc(2);
+        buffer.writeUInt16LE(value);
+        this.buffers.push(buffer);
+    }
+
+    u32(value: number): void {
+        const buffer = Buffer.alloc(4);
+        buffer.writeUInt32LE(value);
+        this.buffers.push(buffer);
+    }
+
+    u64(value: bigint): void {
+        this.buffers.push(toBufferLE(value, 8));
+    }
+
+    i64(value: bigint): void {
+        this.buffers.push(toBufferLE(BigInt.asUintN(64, value), 8));
+    }
+
+    bool(value: boolean): void {
+        this.u8(value ? 1 : 0);
+    }
+
+    publicKey(value: PublicKey): void {
+        this.buffers.push(value.toBuffer());
+    }
+
+    bytes(value: Uint8Array, length?: number): void {
+        if (length !== undefined && value.length !== length) throw new Error(`Expected ${length} bytes`);
+        this.buffers.push(Buffer.from(value));
+    }
+
+    string(value: string): void {
+        const buffer = Buffer.from(value, 'utf8');
+        this.u8(buffer.length);
+        this.buffers.push(buffer);
+    }
+
+    toBuffer(): Buffer {
+        return Buffer.concat(this.buffers);
+    }
+}
+
+
+export enum RNDRInstruction {
+    InitEscrow = 0,
+    SetEscrowOwner = 1,
+    FundJob = 2,
+    DisburseFunds = 3,
+    AggregateEscrowShard = 4,
+    FundIsolatedJob = 5,
+    DisburseIsolatedJobFunds = 6,
+    DisburseBatch = 7,
+    FundJobs = 8,
+    CommitResult = 9,
+    RevealResult = 10,
+    ClaimPayment = 11,
+    SetChallengeWindow = 12,
+    ChallengeResult = 13,
+    AddVerifier = 14,
+    RemoveVerifier = 15,
+    FlagJob = 16,
+    SelectVerifiers = 17,
+    SetProofVerifier = 18,
+    SetArbitrator = 19,
+    OpenDispute = 20,
+    SubmitEvidence = 21,
+    ResolveDispute = 22,
+    InitConfig = 23,
+    SetConfig = 24,
+    VerifyUpgradeAuthority = 25,
+    SetApprover = 26,
+    ProposeDisbursement = 27,
+    ApproveDisbursement = 28,
+    ExecuteDisbursement = 29,
+    QueueAction = 30,
+    ExecuteQueuedAction = 31,
+    CancelQueuedAction = 32,
+    SetRecoveryCouncil = 33,
+    ApproveRecovery = 34,
+    ExecuteRecovery = 35,
+    ScheduleOwnerRotation = 36,
+    FundRewardPool = 37,
+    ClaimEpochReward = 38,
+    PublishMerkleRoot = 39,
+    ClaimMerkleReward = 40,
+    SetEmissionSchedule = 41,
+    EmitRewards = 42,
+    Stake = 43,
+    Unstake = 44,
+    SetAutoCompound = 45,
+    CompoundRewards = 46,
+    LiquidStake = 47,
+    LiquidUnstake = 48,
+    DepositStakeRewards = 49,
+    SetCrankBounty = 50,
+    SetTreasuryBurnCap = 51,
+    BurnTreasury = 52,
+    InitStats = 53,
+    FundJobWithVoucher = 54,
+    RegisterSessionKey = 55,
+    ApproveDelegate = 56,
+    RevokeDelegate = 57,
+    CreateSubscription = 58,
+    ProcessSubscription = 59,
+    CancelJob = 60,
+    TransferJobAuthority = 61,
+    AcceptJobAuthority = 62,
+    CreateJobBundle = 63,
+    FundJobBundle = 64,
+    CancelJobBundle = 65,
+    AggregateJobBundle = 66,
+    InitJobTree = 67,
+    AppendCompressedJob = 68,
+    ReplaceCompressedJob = 69,
+    SettleCompressedJob = 70,
+    InitJobIndex = 71,
+    UpdateJobMetadata = 72,
+    SetEscrowTokenAccount = 73,
+    MigrateEscrowMint = 74,
+    ConvertEscrowMintVault = 75,
+    RedenominateJobs = 76,
+    ConvertIsolatedJobMint = 77,
+    InitEscrowWithSeed = 78,
+    RaiseJobPriority = 79,
+    InitJobQueue = 80,
+    ClaimJob = 81,
+    SetJobExchangeRate = 82,
+    EstimateJobCost = 83,
+    SetPriceFeed = 84,
+    FundJobAtOraclePrice = 85,
+    FreezeJob = 86,
+    UnfreezeJob = 87,
+    SyncIncidentMode = 88,
+    RegisterColdWallet = 89,
+    ScheduleTreasurySweep = 90,
+    SweepToColdWallet = 91,
+    CancelTreasurySweep = 92,
+    GetVersion = 93,
+    GetEscrowSummary = 94,
+    GetJobSummary = 95,
+    GetEscrowedAmount = 96,
+    ReconcileEscrow = 97,
+}
+
+/** Metadata of a job, pointing to its full off-chain manifest, with the tags nodes discover it by */
+export interface JobMetadata {
+    /** URI of the job's manifest, such as an `ar://` or `ipfs://` URI, or empty if there is none */
+    uri: string;
+    /** Hash of the content of the manifest, so it can be verified against the URI's content */
+    hash: Uint8Array;
+    /** Bitflags of the capabilities the job requires, see `Job::tags` */
+    tags: bigint;
+}
+
+/** Change to a single field of a config */
+export type ConfigChange =
+    | { kind: 'Governance'; value: PublicKey }
+    | { kind: 'FeeBps'; value: number }
+    | { kind: 'MinStake'; value: bigint }
+    | { kind: 'DisputeWindow'; value: bigint }
+    | { kind: 'TimelockDuration'; value: bigint }
+    | { kind: 'RateLimitWindow'; value: bigint }
+    | { kind: 'RateLimitMax'; value: number }
+    | { kind: 'Guardian'; value: PublicKey }
+    | { kind: 'InstantOwnerChange'; value: boolean }
+    | { kind: 'TimelockedFields'; value: number }
+    | { kind: 'RewardTier'; value: [number, RewardTier] }
+    | { kind: 'CancelGracePeriod'; value: bigint }
+    | { kind: 'CancelFeeBps'; value: number }
+    | { kind: 'PrioritySurchargeBps'; value: number }
+    | { kind: 'PriorityClaimDelay'; value: bigint }
+    | { kind: 'RoundingPolicy'; value: RoundingPolicy }
+    | { kind: 'MinDisbursement'; value: bigint }
+    | { kind: 'ObhPrice'; value: [number, bigint] }
+    | { kind: 'MaxPriceAge'; value: bigint }
+    | { kind: 'MaxPriceConfidenceBps'; value: number }
+    | { kind: 'Compliance'; value: PublicKey }
+    | { kind: 'SweepDelay'; value: bigint }
+    | { kind: 'IncidentMode'; value: IncidentMode };
+
+/**
+ * Reward tier of nodes with at least a minimum stake, whose recorded work is weighted by a
+ * multiplier
+ */
+export interface RewardTier {
+    /** Minimum stake of a node in the tier, in base units of the token */
+    minStake: bigint;
+    /** Reward multiplier of nodes in the tier, in basis points, where zero disables the tier */
+    multiplierBps: number;
+}
+
+/**
+ * Enum representing where the remainder of an amount split between recipients goes, which is
+ * left over when the portion of each recipient is rounded down
+ */
+export enum RoundingPolicy {
+    /** The remainder is added to the portion of the first recipient, the value will be 0 */
+    FirstRecipient = 0,
+    /** The remainder is paid to the treasury of the escrow the amount is split from */
+    Treasury = 1,
+    /** The remainder is burned */
+    Burn = 2,
+}
+
+/**
+ * Enum representing which direction funds can flow through escrows during an incident, so the
+ * governance authority of the config can stop one direction without pausing the other
+ */
+export enum IncidentMode {
+    /** Jobs can be funded and their funds disbursed or refunded, the value will be 0 */
+    Normal = 0,
+    /** Jobs can be funded, but their funds can't be disbursed or refunded */
+    FundOnly = 1,
+    /**
+     * Funds of existing jobs can be disbursed or refunded, but no jobs can be funded, so
+     * existing obligations settle without new money coming in
+     */
+    DisburseOnly = 2,
+}
+
+/** Administrative action that waits for the timelock duration of the config before it's applied */
+export type AdminAction = { kind: 'SetEscrowOwner'; value: PublicKey } | { kind: 'SetConfig'; value: ConfigChange };
+
+/** Compressed job, stored as a leaf of a `JobTree` instead of in a `Job` account */
+export interface CompressedJob {
+    /** Identifier of the job, which is the index of its leaf */
+    id: bigint;
+    /** Amount of tokens in escrow for the job */
+    amount: bigint;
+    /** Status of the job, which is Open until it's assigned to a node */
+    status: JobStatus;
+    /** Node the job is assigned to, or the default pubkey if the job is open */
+    node: PublicKey;
+}
+
+/** Enum representing the lifecycle of a job's result */
+export enum JobStatus {
+    /** No node has committed to a result yet, the value will be 0 */
+    Open = 0,
+    /** A node has committed to the hash of a result */
+    Committed = 1,
+    /** The node has revealed the result matching its commitment */
+    Revealed = 2,
+    /** The node has claimed payment for the result */
+    Paid = 3,
+    /** The job's authority challenged the result within the escrow's challenge window */
+    Challenged = 4,
+    /**
+     * A registered verifier flagged the job or a dispute was opened over it, freezing disbursement
+     * of its funds
+     */
+    Disputed = 5,
+    /**
+     * The escrow's arbitrator resolved the dispute over the job, splitting its funds between the
+     * job's authority and node
+     */
+    Resolved = 6,
+    /** The job's authority cancelled the job before a result was revealed, refunding its funds */
+    Cancelled = 7,
+}
+
+const writeJobMetadata = (writer: Writer, value: JobMetadata): void => {
+    writer.string(value.uri);
+    writer.bytes(value.hash, 32);
+    writer.u64(value.tags);
+};
+
+const writeConfigChange = (writer: Writer, value: ConfigChange): void => {
+    switch (value.kind) {
+        case 'Governance':
+            writer.u8(0);
+            writer.publicKey(value.value);
+            break;
+        case 'FeeBps':
+            writer.u8(1);
+            writer.u16(value.value);
+            break;
+        case 'MinStake':
+            writer.u8(2);
+            writer.u64(value.value);
+            break;
+        case 'DisputeWindow':
+            writer.u8(3);
+            writer.u64(value.value);
+            break;
+        case 'TimelockDuration':
+            writer.u8(4);
+            writer.u64(value.value);
+            break;
+        case 'RateLimitWindow':
+            writer.u8(5);
+            writer.u64(value.value);
+            break;
+        case 'RateLimitMax':
+            writer.u8(6);
+            writer.u32(value.value);
+            break;
+        case 'Guardian':
+            writer.u8(7);
+            writer.publicKey(value.value);
+            break;
+        case 'InstantOwnerChange':
+            writer.u8(8);
+            writer.bool(value.value);
+            break;
+        case 'TimelockedFields':
+            writer.u8(9);
+            writer.u32(value.value);
+            break;
+        case 'RewardTier':
+            writer.u8(10);
+            writer.u8(value.value[0]);
+            writeRewardTier(writer, value.value[1]);
+            break;
+        case 'CancelGracePeriod':
+            writer.u8(11);
+            writer.u64(value.value);
+            break;
+        case 'CancelFeeBps':
+            writer.u8(12);
+            writer.u16(value.value);
+            break;
+        case 'PrioritySurchargeBps':
+            writer.u8(13);
+            writer.u16(value.value);
+            break;
+        case 'PriorityClaimDelay':
+            writer.u8(14);
+            writer.u64(value.value);
+            break;
+        case 'RoundingPolicy':
+            writer.u8(15);
+            writer.u8(value.value);
+            break;
+        case 'MinDisbursement':
+            writer.u8(16);
+            writer.u64(value.value);
+            break;
+        case 'ObhPrice':
+            writer.u8(17);
+            writer.u8(value.value[0]);
+            writer.u64(value.value[1]);
+            break;
+        case 'MaxPriceAge':
+            writer.u8(18);
+            writer.u64(value.value);
+            break;
+        case 'MaxPriceConfidenceBps':
+            writer.u8(19);
+            writer.u16(value.value);
+            break;
+        case 'Compliance':
+            writer.u8(20);
+            writer.publicKey(value.value);
+            break;
+        case 'SweepDelay':
+            writer.u8(21);
+            writer.u64(value.value);
+            break;
+        case 'IncidentMode':
+            writer.u8(22);
+            writer.u8(value.value);
+            break;
+    }
+};
+
+const writeRewardTier = (writer: Writer, value: RewardTier): void => {
+    writer.u64(value.minStake);
+    writer.u16(value.multiplierBps);
+};
+
+const writeAdminAction = (writer: Writer, value: AdminAction): void => {
+    switch (value.kind) {
+        case 'SetEscrowOwner':
+            writer.u8(0);
+            writer.publicKey(value.value);
+            break;
+        case 'SetConfig':
+            writer.u8(1);
+            writeConfigChange(writer, value.value);
+            break;
+    }
+};
+
+const writeCompressedJob = (writer: Writer, value: CompressedJob): void => {
+    writer.u64(value.id);
+    writer.u64(value.amount);
+    writer.u8(value.status);
+    writer.publicKey(value.node);
+};
+
+/**
+ * Initialize an Escrow.
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[]` RNDR SPL Token mint
+ *   1. `[writable,signer]` Funder SOL account
+ *   2. `[writable]` Escrow PDA account
+ *   3. `[writable]` Escrow ATA account
+ *   4. `[]` System program id
+ *   5. `[]` Token program id
+ *   6. `[]` Associated Token Account program id
+ */
+export interface InitEscrowData {
+    /** Owner authority that can disburse funds */
+    owner: PublicKey;
+}
+
+export const encodeInitEscrowData = (data: InitEscrowData): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.InitEscrow);
+    writer.publicKey(data.owner);
+    return writer.toBuffer();
+};
+
+/**
+ * Set the new owner of an Escrow immediately. Only allowed when instant owner changes are
+ * enabled in the Config, otherwise owners are rotated with `ScheduleOwnerRotation`.
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[writable]` Escrow PDA account
+ *   1. `[writable,signer]` Current owner authority, pays to reallocate legacy escrow accounts
+ *   2. `[]` Config PDA account
+ *   3. `[]` System program id
+ */
+export interface SetEscrowOwnerData {
+    /** New Escrow owner authority */
+    newOwner: PublicKey;
+}
+
+export const encodeSetEscrowOwnerData = (data: SetEscrowOwnerData): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.SetEscrowOwner);
+    writer.publicKey(data.newOwner);
+    return writer.toBuffer();
+};
+
+/**
+ * Transfer funds into an Escrow and credit a Job.
+ *
+ * The Job's amount is credited to its escrow shard rather than the Escrow, so the Escrow PDA
+ * account is only read. The Escrow ATA account still receives the tokens and is write-locked
+ * by every funding transaction of the Escrow.
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[writable,signer]` Funder SOL account
+ *   1. `[writable]` Source RNDR token account
+ *      $authority can transfer $amount
+ *   2. `[signer]` Source token account authority ($authority)
+ *   3. `[]` Escrow PDA account
+ *   4. `[writable]` Escrow ATA account
+ *   5. `[writable]` Job PDA account
+ *   6. `[writable]` Escrow shard PDA account for the job
+ *   7. `[]` System program id
+ *   8. `[]` Token program id
+ *
+ * Optionally, to top up an existing Job with a session key of its authority instead, in
+ * which case the session key is account 2 and must be able to transfer $amount:
+ *
+ *   9. `[]` SessionKey PDA account of the Escrow, Job authority and session key
+ *
+ * Metadata can only be set by the Job authority itself, not a session key, while the Job is
+ * open. The funder pays to reallocate legacy job accounts to store it.
+ *
+ * The JobQueue PDA account of the Escrow can be appended, writable, after all the other
+ * accounts including any JobIndex accounts, and before the Stats PDA account, to push the
+ * Job to the queue if it's created.
+ *
+ * To mint a compressed NFT receipt of the Job to the funder with Bubblegum, named after the
+ * Job's identifier and with the URI and hash of its manifest, the receipt authority PDA
+ * account, the writable Bubblegum tree config PDA and merkle tree accounts, and the SPL noop,
+ * SPL account compression and Bubblegum program ids can be appended in that order after all
+ * the other accounts including the JobQueue, and before the Stats PDA account. The receipt
+ * authority must be the delegate of the merkle tree.
+ */
+export interface FundJobData {
+    /** Amount of RNDR tokens to escrow */
+    amount: bigint;
+    /** Identifier of the job, unique for the authority */
+    jobId: bigint;
+    /** Metadata and tags to set on the job, if any, packed after the other fields */
+    metadata?: JobMetadata;
+}
+
+export const encodeFundJobData = (data: FundJobData): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.FundJob);
+    writer.u64(data.amount);
+    writer.u64(data.jobId);
+    if (data.metadata !== undefined) {
+        writeJobMetadata(writer, data.metadata);
+    }
+    return writer.toBuffer();
+};
+
+/**
+ * Transfer funds from an Escrow and debit a Job
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[writable]` Escrow PDA account
+ *   1. `[signer]` Escrow owner authority
+ *   2. `[writable]` Escrow ATA account
+ *   3. `[writable]` Job PDA account
+ *   4. `[writable]` Destination RNDR token account
+ *   5. `[]` Token program id
+ *
+ * Optionally, to disburse as a delegate of the owner within its allowance instead, in which
+ * case the delegate signs as account 1:
+ *
+ *   6. `[writable]` DelegateAllowance PDA account of the Escrow and delegate
+ *
+ * The EscrowShard PDA account the Job is funded through can be appended, writable, after all
+ * the other accounts and before the Stats PDA account, so the tokens funded through it are
+ * moved into the Escrow before it's debited.
+ */
+export interface DisburseFundsData {
+    /** Amount of RNDR tokens to disburse */
+    amount: bigint;
+}
+
+export const encodeDisburseFundsData = (data: DisburseFundsData): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.DisburseFunds);
+    writer.u64(data.amount);
+    return writer.toBuffer();
+};
+
+/**
+ * Move the amount funded through an Escrow shard into the Escrow.
+ * Anyone can call this instruction.
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[writable]` Escrow PDA account
+ *   1. `[writable]` Escrow shard PDA account
+ *
+ * Optionally, to be paid the bounty of the Escrow's CrankVault:
+ *
+ *   2. `[writable]` CrankVault PDA account of the Escrow
+ *   3. `[writable]` Bounty recipient SOL account
+ */
+export const encodeAggregateEscrowShardData = (): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.AggregateEscrowShard);
+    return writer.toBuffer();
+};
+
+/**
+ * Transfer funds into a Job's own token account and credit the Job.
+ * The Job is created isolated from the Escrow's pooled token account if it doesn't exist.
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[]` RNDR SPL Token mint
+ *   1. `[writable,signer]` Funder SOL account
+ *   2. `[writable]` Source RNDR token account
+ *      $authority can transfer $amount
+ *   3. `[signer]` Source token account authority ($authority)
+ *   4. `[]` Escrow PDA account
+ *   5. `[writable]` Job PDA account
+ *   6. `[writable]` Job ATA account
+ *   7. `[]` System program id
+ *   8. `[]` Token program id
+ *   9. `[]` Associated Token Account program id
+ */
+export interface FundIsolatedJobData {
+    /** Amount of RNDR tokens to escrow */
+    amount: bigint;
+    /** Identifier of the job, unique for the authority */
+    jobId: bigint;
+}
+
+export const encodeFundIsolatedJobData = (data: FundIsolatedJobData): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.FundIsolatedJob);
+    writer.u64(data.amount);
+    writer.u64(data.jobId);
+    return writer.toBuffer();
+};
+
+/**
+ * Transfer funds from a Job's own token account and debit the Job
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[]` Escrow PDA account
+ *   1. `[signer]` Escrow owner authority
+ *   2. `[writable]` Job PDA account
+ *   3. `[writable]` Job ATA account
+ *   4. `[writable]` Destination RNDR token account
+ *   5. `[]` Token program id
+ */
+export interface DisburseIsolatedJobFundsData {
+    /** Amount of RNDR tokens to disburse */
+    amount: bigint;
+}
+
+export const encodeDisburseIsolatedJobFundsData = (data: DisburseIsolatedJobFundsData): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.DisburseIsolatedJobFunds);
+    writer.u64(data.amount);
+    return writer.toBuffer();
+};
+
+/**
+ * Transfer funds from an Escrow to multiple destinations and debit a Job
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[writable]` Escrow PDA account
+ *   1. `[signer]` Escrow owner authority
+ *   2. `[writable]` Escrow ATA account
+ *   3. `[writable]` Job PDA account
+ *   4. `[]` Token program id
+ *   5. ..5+N `[writable]` Destination RNDR token accounts, one for each amount
+ *
+ * The EscrowShard PDA account the Job is funded through can be appended, writable, after all
+ * the other accounts and before the Stats PDA account, so the tokens funded through it are
+ * moved into the Escrow before it's debited.
+ */
+export interface DisburseBatchData {
+    /** Amounts of RNDR tokens to disburse, at most `MAX_DISBURSE_BATCH_LEN` */
+    amounts: bigint[];
+}
+
+export const encodeDisburseBatchData = (data: DisburseBatchData): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.DisburseBatch);
+    writer.u8(data.amounts.length);
+    for (const item of data.amounts) {
+        writer.u64(item);
+    }
+    return writer.toBuffer();
+};
+
+/**
+ * Transfer funds into an Escrow and credit multiple Jobs, creating them if needed
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[writable,signer]` Funder SOL account
+ *   1. `[writable]` Source RNDR token account
+ *      $authority can transfer the total amount
+ *   2. `[signer]` Source token account authority ($authority)
+ *   3. `[]` Escrow PDA account
+ *   4. `[writable]` Escrow ATA account
+ *   5. `[]` System program id
+ *   6. `[]` Token program id
+ *   7. ..7+2N `[writable]` Job PDA account and Escrow shard PDA account for each job
+ */
+export interface FundJobsData {
+    /** Job identifiers and amounts of RNDR tokens to escrow, at most `MAX_FUND_JOBS_LEN` */
+    jobs: [bigint, bigint][];
+}
+
+export const encodeFundJobsData = (data: FundJobsData): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.FundJobs);
+    writer.u8(data.jobs.length);
+    for (const item of data.jobs) {
+        writer.u64(item[0]);
+        writer.u64(item[1]);
+    }
+    return writer.toBuffer();
+};
+
+/**
+ * Commit to the result of a Job, assigning the Job to the node if it's open and the config's
+ * priority claim delay for the Job's priority has passed since it was funded.
+ * The node may replace its commitment until it reveals the result.
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[writable,signer]` Node SOL account, pays to reallocate legacy Jobs
+ *   1. `[writable]` Job PDA account
+ *   2. `[]` System program id
+ *   3. `[]` Config PDA account
+ */
+export interface CommitResultData {
+    /** Commitment to the result, see `Job::result_commitment` */
+    commitment: Uint8Array;
+}
+
+export const encodeCommitResultData = (data: CommitResultData): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.CommitResult);
+    writer.bytes(data.commitment, 32);
+    return writer.toBuffer();
+};
+
+/**
+ * Reveal the result a node committed to for a Job, starting its Escrow's challenge window.
+ * If the Escrow has a proof verifier, the result is only revealed if the proof verifier
+ * succeeds when invoked with the instruction created by `verify_proof`.
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[signer]` Node authority
+ *   1. `[writable]` Job PDA account
+ *   2. `[]` Escrow PDA account
+ *   3. `[]` Proof verifier program id, only if the Escrow has a proof verifier
+ *   4. ..4+N `[]` Accounts passed through to the proof verifier
+ */
+export interface RevealResultData {
+    /** Hash of the result */
+    resultHash: Uint8Array;
+    /** Salt the commitment was made with */
+    salt: Uint8Array;
+    /** Proof of the result for the Escrow's proof verifier, the rest of the instruction data */
+    proof: Uint8Array;
+}
+
+export const encodeRevealResultData = (data: RevealResultData): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.RevealResult);
+    writer.bytes(data.resultHash, 32);
+    writer.bytes(data.salt, 32);
+    writer.bytes(data.proof);
+    return writer.toBuffer();
+};
+
+/**
+ * Transfer a Job's funds from an Escrow to the node that revealed its result, once the
+ * Escrow's challenge window has passed without the result being challenged
+ *
+ * The node is paid the Job's full amount. The Job's amount is credited in the ledger of the
+ * node's Node account, if it has one, at its value in RNDR if the Job is funded in another
+ * token and has an exchange rate recorded. Only the work recorded toward the reward pool is
+ * weighted by the reward multiplier of the tier the node's stake reaches in the Config,
+ * counting only tokens staked before the current epoch.
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[signer]` Node authority
+ *   1. `[writable]` Escrow PDA account
+ *   2. `[writable]` Escrow ATA account
+ *   3. `[writable]` Job PDA account
+ *   4. `[writable]` Destination RNDR token account
+ *   5. `[]` Token program id
+ *   6. `[]` Config PDA account
+ *   7. `[writable]` Node PDA account of the Escrow and node authority
+ *
+ * Optionally, to record the Job's weighted value as work of the node toward the reward pool of
+ * the current epoch, which requires the node authority to be writable to pay for the
+ * EpochWork:
+ *
+ *   8. `[writable]` RewardPool PDA account of the Escrow for the current epoch
+ *   9. `[writable]` EpochWork PDA account
+ *   10. `[]` System program id
+ *
+ * The EscrowShard PDA account the Job is funded through can be appended, writable, after all
+ * the other accounts and before the Stats PDA account, so the tokens funded through it are
+ * moved into the Escrow before it's debited.
+ */
+export const encodeClaimPaymentData = (): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.ClaimPayment);
+    return writer.toBuffer();
+};
+
+/**
+ * Set the challenge window of an Escrow
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[writable]` Escrow PDA account
+ *   1. `[writable,signer]` Owner authority, pays to reallocate legacy Escrows
+ *   2. `[]` System program id
+ */
+export interface SetChallengeWindowData {
+    /** Seconds after a Job's result is revealed during which it can be challenged */
+    challengeWindow: bigint;
+}
+
+export const encodeSetChallengeWindowData = (data: SetChallengeWindowData): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.SetChallengeWindow);
+    writer.u64(data.challengeWindow);
+    return writer.toBuffer();
+};
+
+/**
+ * Challenge the result revealed for a Job within its Escrow's challenge window, so the node
+ * can't claim payment for it
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[signer]` Job authority
+ *   1. `[]` Escrow PDA account
+ *   2. `[writable]` Job PDA account
+ *
+ * Optionally, to challenge with a session key of the Job authority as account 0 instead:
+ *
+ *   3. `[]` SessionKey PDA account of the Escrow, Job authority and session key
+ */
+export const encodeChallengeResultData = (): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.ChallengeResult);
+    return writer.toBuffer();
+};
+
+/**
+ * Register a verifier for an Escrow, creating its verifier registry if it doesn't exist yet
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[]` Escrow PDA account
+ *   1. `[writable,signer]` Owner authority, pays to create the verifier registry
+ *   2. `[writable]` Verifier registry PDA account
+ *   3. `[]` System program id
+ */
+export interface AddVerifierData {
+    /** Verifier authority that can flag jobs */
+    verifier: PublicKey;
+}
+
+export const encodeAddVerifierData = (data: AddVerifierData): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.AddVerifier);
+    writer.publicKey(data.verifier);
+    return writer.toBuffer();
+};
+
+/**
+ * Deregister a verifier of an Escrow
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[]` Escrow PDA account
+ *   1. `[signer]` Owner authority
+ *   2. `[writable]` Verifier registry PDA account
+ */
+export interface RemoveVerifierData {
+    /** Verifier authority to deregister */
+    verifier: PublicKey;
+}
+
+export const encodeRemoveVerifierData = (data: RemoveVerifierData): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.RemoveVerifier);
+    writer.publicKey(data.verifier);
+    return writer.toBuffer();
+};
+
+/**
+ * Flag a Job whose result a registered verifier finds to be incorrect, freezing disbursement
+ * of its funds until the dispute is resolved
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[signer]` Verifier authority
+ *   1. `[]` Escrow PDA account
+ *   2. `[]` Verifier registry PDA account
+ *   3. `[writable]` Job PDA account
+ */
+export interface FlagJobData {
+    /** Hash of the evidence that the result is incorrect */
+    evidenceHash: Uint8Array;
+}
+
+export const encodeFlagJobData = (data: FlagJobData): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.FlagJob);
+    writer.bytes(data.evidenceHash, 32);
+    return writer.toBuffer();
+};
+
+/**
+ * Sample registered verifiers of an Escrow to spot-check the result of a Job, using the
+ * most recent slot hash as entropy. Verifiers can only be selected once for each Job.
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[writable,signer]` Funder SOL account, pays to create the verifier selection
+ *   1. `[]` Escrow PDA account
+ *   2. `[]` Verifier registry PDA account
+ *   3. `[]` Job PDA account
+ *   4. `[writable]` Verifier selection PDA account
+ *   5. `[]` SlotHashes sysvar
+ *   6. `[]` System program id
+ */
+export interface SelectVerifiersData {
+    /** Number of verifiers to select, at most `MAX_SELECTED_VERIFIERS` */
+    count: number;
+}
+
+export const encodeSelectVerifiersData = (data: SelectVerifiersData): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.SelectVerifiers);
+    writer.u8(data.count);
+    return writer.toBuffer();
+};
+
+/**
+ * Set the proof verifier program of an Escrow, or the default pubkey to reveal results
+ * without a proof
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[writable]` Escrow PDA account
+ *   1. `[writable,signer]` Owner authority, pays to reallocate legacy Escrows
+ *   2. `[]` System program id
+ */
+export interface SetProofVerifierData {
+    /** Program that must verify the proof of a Job's result for it to be revealed */
+    proofVerifier: PublicKey;
+}
+
+export const encodeSetProofVerifierData = (data: SetProofVerifierData): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.SetProofVerifier);
+    writer.publicKey(data.proofVerifier);
+    return writer.toBuffer();
+};
+
+/**
+ * Set the arbitrator of an Escrow that resolves disputes over its Jobs
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[writable]` Escrow PDA account
+ *   1. `[writable,signer]` Owner authority, pays to reallocate legacy Escrows
+ *   2. `[]` System program id
+ */
+export interface SetArbitratorData {
+    /** Arbitrator authority that resolves disputes */
+    arbitrator: PublicKey;
+}
+
+export const encodeSetArbitratorData = (data: SetArbitratorData): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.SetArbitrator);
+    writer.publicKey(data.arbitrator);
+    return writer.toBuffer();
+};
+
+/**
+ * Open a dispute over the result of a Job a node has committed to, freezing disbursement of
+ * its funds until the Escrow's arbitrator resolves the dispute. Once the result is revealed,
+ * a dispute can only be opened within the Config's dispute window.
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[writable,signer]` Job authority or node, pays to create the dispute
+ *   1. `[]` Escrow PDA account
+ *   2. `[]` Config PDA account
+ *   3. `[writable]` Job PDA account
+ *   4. `[writable]` Dispute PDA account
+ *   5. `[]` System program id
+ */
+export const encodeOpenDisputeData = (): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.OpenDispute);
+    return writer.toBuffer();
+};
+
+/**
+ * Submit the hash of evidence for a dispute, replacing any evidence previously submitted by
+ * the same party
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[signer]` Job authority or node
+ *   1. `[]` Job PDA account
+ *   2. `[writable]` Dispute PDA account
+ */
+export interface SubmitEvidenceData {
+    /** Hash of the evidence */
+    evidenceHash: Uint8Array;
+}
+
+export const encodeSubmitEvidenceData = (data: SubmitEvidenceData): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.SubmitEvidence);
+    writer.bytes(data.evidenceHash, 32);
+    return writer.toBuffer();
+};
+
+/**
+ * Resolve a dispute, transferring the Job's funds from the Escrow to the node and the Job's
+ * authority according to the arbitrator's ruling
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[signer]` Arbitrator authority
+ *   1. `[writable]` Escrow PDA account
+ *   2. `[writable]` Escrow ATA account
+ *   3. `[writable]` Job PDA account
+ *   4. `[writable]` Dispute PDA account
+ *   5. `[writable]` Node ATA account
+ *   6. `[writable]` Job authority ATA account
+ *   7. `[]` Token program id
+ *
+ * The EscrowShard PDA account the Job is funded through can be appended, writable, after all
+ * the other accounts and before the Stats PDA account, so the tokens funded through it are
+ * moved into the Escrow before it's debited.
+ */
+export interface ResolveDisputeData {
+    /** Amount of the Job's tokens awarded to the node, the rest are returned to the authority */
+    nodeAmount: bigint;
+}
+
+export const encodeResolveDisputeData = (data: ResolveDisputeData): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.ResolveDispute);
+    writer.u64(data.nodeAmount);
+    return writer.toBuffer();
+};
+
+/**
+ * Create the Config with the default parameters, which must be done by the upgrade authority
+ * of the program when it's deployed
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[writable,signer]` Funder SOL account, pays to create the Config
+ *   1. `[writable]` Config PDA account
+ *   2. `[]` Program data account of the RNDR program
+ *   3. `[signer]` Upgrade authority of the RNDR program
+ *   4. `[]` System program id
+ */
+export interface InitConfigData {
+    /** Governance authority that can change the Config */
+    governance: PublicKey;
+}
+
+export const encodeInitConfigData = (data: InitConfigData): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.InitConfig);
+    writer.publicKey(data.governance);
+    return writer.toBuffer();
+};
+
+/**
+ * Change a single field of the Config, which must be within the field's bounds. Changes to
+ * fields that are timelocked in the Config must be queued with `QueueAction` instead.
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[writable]` Config PDA account
+ *   1. `[signer]` Governance authority of the Config
+ *
+ * Optionally, required to set fields appended after a legacy Config was created:
+ *
+ *   2. `[writable,signer]` Payer SOL account, pays to reallocate a legacy Config
+ *   3. `[]` System program id
+ */
+export interface SetConfigData {
+    /** Field of the Config to change and its new value */
+    change: ConfigChange;
+}
+
+export const encodeSetConfigData = (data: SetConfigData): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.SetConfig);
+    writeConfigChange(writer, data.change);
+    return writer.toBuffer();
+};
+
+/**
+ * Verify that the upgrade authority of the program is the governance authority of the
+ * Config, or that the program is immutable, failing otherwise. Monitoring tools can simulate
+ * this and other programs can invoke it to confirm who can upgrade the program.
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[]` Config PDA account
+ *   1. `[]` Program data account of the RNDR program
+ */
+export const encodeVerifyUpgradeAuthorityData = (): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.VerifyUpgradeAuthority);
+    return writer.toBuffer();
+};
+
+/**
+ * Set the approver of the Escrow, after which the owner's disbursements must be proposed and
+ * approved instead of being made directly, or the default pubkey to disburse directly again.
+ * Once an Escrow has an approver, the approver must also sign to change or clear it.
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[writable]` Escrow PDA account
+ *   1. `[writable,signer]` Escrow owner authority, pays to reallocate legacy escrow accounts
+ *   2. `[]` System program id
+ *
+ * Optionally, required if the Escrow has an approver:
+ *
+ *   3. `[signer]` Current approver authority of the Escrow
+ */
+export interface SetApproverData {
+    /** Approver authority, which must differ from the owner */
+    approver: PublicKey;
+}
+
+export const encodeSetApproverData = (data: SetApproverData): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.SetApprover);
+    writer.publicKey(data.approver);
+    return writer.toBuffer();
+};
+
+/**
+ * Propose a disbursement of a Job's funds, which the approver of the Escrow must approve
+ * before it can be executed
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[writable,signer]` Escrow owner authority, pays to create the Proposal
+ *   1. `[]` Escrow PDA account
+ *   2. `[]` Job PDA account
+ *   3. `[]` Destination SPL Token account
+ *   4. `[writable]` Proposal PDA account
+ *   5. `[]` System program id
+ */
+export interface ProposeDisbursementData {
+    /** Identifier of the proposal, unique for the escrow */
+    proposalId: bigint;
+    /** Amount of tokens to disburse */
+    amount: bigint;
+}
+
+export const encodeProposeDisbursementData = (data: ProposeDisbursementData): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.ProposeDisbursement);
+    writer.u64(data.proposalId);
+    writer.u64(data.amount);
+    return writer.toBuffer();
+};
+
+/**
+ * Approve a proposed disbursement
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[signer]` Approver authority of the Escrow
+ *   1. `[]` Escrow PDA account
+ *   2. `[writable]` Proposal PDA account
+ */
+export const encodeApproveDisbursementData = (): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.ApproveDisbursement);
+    return writer.toBuffer();
+};
+
+/**
+ * Execute an approved disbursement, transferring the funds from the Escrow, or from the Job's
+ * own token account if the Job is isolated. Anyone can execute an approved disbursement.
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[writable]` Escrow PDA account
+ *   1. `[writable]` Escrow ATA account, or Job ATA account if the Job is isolated
+ *   2. `[writable]` Job PDA account
+ *   3. `[writable]` Proposal PDA account
+ *   4. `[writable]` Destination SPL Token account
+ *   5. `[]` Token program id
+ *
+ * The EscrowShard PDA account the Job is funded through, unless it's isolated, can be
+ * appended, writable, after all the other accounts and before the Stats PDA account, so the
+ * tokens funded through it are moved into the Escrow before it's debited.
+ */
+export const encodeExecuteDisbursementData = (): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.ExecuteDisbursement);
+    return writer.toBuffer();
+};
+
+/**
+ * Queue an administrative action, which can be executed once the timelock duration of the
+ * Config has passed. Setting the owner of an Escrow is queued by its owner, and changing a
+ * field of the Config is queued by its governance authority.
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[writable,signer]` Escrow owner or Config governance authority, pays to create the
+ *      QueuedAction
+ *   1. `[]` Config PDA account
+ *   2. `[writable]` Target Escrow PDA account, reallocated if it's a legacy escrow account, or
+ *      the Config PDA account again
+ *   3. `[writable]` QueuedAction PDA account
+ *   4. `[]` System program id
+ */
+export interface QueueActionData {
+    /** Identifier of the queued action, unique for the target */
+    actionId: bigint;
+    /** Action to apply once the timelock has passed */
+    action: AdminAction;
+}
+
+export const encodeQueueActionData = (data: QueueActionData): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.QueueAction);
+    writer.u64(data.actionId);
+    writeAdminAction(writer, data.action);
+    return writer.toBuffer();
+};
+
+/**
+ * Execute a queued action once its timelock has passed, if its authority still owns the
+ * target. Anyone can execute a queued action.
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[writable]` QueuedAction PDA account
+ *   1. `[writable]` Target Escrow or Config PDA account
+ *
+ * Optionally, required to set fields appended after a legacy Config was created:
+ *
+ *   2. `[writable,signer]` Payer SOL account, pays to reallocate a legacy Config
+ *   3. `[]` System program id
+ */
+export const encodeExecuteQueuedActionData = (): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.ExecuteQueuedAction);
+    return writer.toBuffer();
+};
+
+/**
+ * Cancel a queued action before its timelock has passed
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[signer]` Guardian authority of the Config
+ *   1. `[]` Config PDA account
+ *   2. `[writable]` QueuedAction PDA account
+ */
+export const encodeCancelQueuedActionData = (): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.CancelQueuedAction);
+    return writer.toBuffer();
+};
+
+/**
+ * Create or replace the recovery council of an Escrow, clearing any votes and pending
+ * recovery
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[writable]` Escrow PDA account
+ *   1. `[writable,signer]` Escrow owner authority, pays to create the Recovery and to
+ *      reallocate legacy escrow accounts
+ *   2. `[writable]` Recovery PDA account
+ *   3. `[]` System program id
+ */
+export interface SetRecoveryCouncilData {
+    /**
+     * Number of guardians that must vote for the same new owner, between one and the number
+     * of guardians
+     */
+    threshold: number;
+    /** Distinct guardians on the council, at most `MAX_RECOVERY_GUARDIANS` */
+    guardians: PublicKey[];
+}
+
+export const encodeSetRecoveryCouncilData = (data: SetRecoveryCouncilData): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.SetRecoveryCouncil);
+    writer.u8(data.threshold);
+    writer.u8(data.guardians.length);
+    for (const item of data.guardians) {
+        writer.publicKey(item);
+    }
+    return writer.toBuffer();
+};
+
+/**
+ * Vote as a guardian of the recovery council for a new owner of the Escrow. Once the
+ * threshold of guardians vote for the same new owner, it can be set after `RECOVERY_DELAY`.
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[signer]` Guardian authority on the recovery council
+ *   1. `[]` Escrow PDA account
+ *   2. `[writable]` Recovery PDA account
+ */
+export interface ApproveRecoveryData {
+    /** New owner authority of the Escrow */
+    newOwner: PublicKey;
+}
+
+export const encodeApproveRecoveryData = (data: ApproveRecoveryData): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.ApproveRecovery);
+    writer.publicKey(data.newOwner);
+    return writer.toBuffer();
+};
+
+/**
+ * Set the owner of the Escrow to the new owner of a pending recovery once its delay has
+ * passed, if the threshold of guardians still vote for it. Anyone can execute a recovery.
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[writable]` Escrow PDA account
+ *   1. `[writable]` Recovery PDA account
+ */
+export const encodeExecuteRecoveryData = (): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.ExecuteRecovery);
+    return writer.toBuffer();
+};
+
+/**
+ * Announce a new owner of an Escrow, which takes effect once the timelock duration of the
+ * Config has passed. The rotation is a queued action, so it's applied with
+ * `ExecuteQueuedAction` and can be cancelled by the guardian of the Config until then.
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[writable,signer]` Escrow owner authority, pays to create the QueuedAction and to
+ *      reallocate legacy escrow accounts
+ *   1. `[]` Config PDA account
+ *   2. `[writable]` Escrow PDA account
+ *   3. `[writable]` QueuedAction PDA account
+ *   4. `[]` System program id
+ */
+export interface ScheduleOwnerRotationData {
+    /** Identifier of the queued action, unique for the Escrow */
+    rotationId: bigint;
+    /** New Escrow owner authority */
+    newOwner: PublicKey;
+}
+
+export const encodeScheduleOwnerRotationData = (data: ScheduleOwnerRotationData): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.ScheduleOwnerRotation);
+    writer.u64(data.rotationId);
+    writer.publicKey(data.newOwner);
+    return writer.toBuffer();
+};
+
+/**
+ * Transfer rewards into the reward pool of an Escrow for an epoch that hasn't ended, such as
+ * protocol fees or burn proceeds. The RewardPool is created if it doesn't exist.
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[]` RNDR SPL Token mint
+ *   1. `[writable,signer]` Funder SOL account
+ *   2. `[writable]` Source RNDR token account
+ *      $authority can transfer $amount
+ *   3. `[signer]` Source token account authority ($authority)
+ *   4. `[]` Escrow PDA account
+ *   5. `[writable]` RewardPool PDA account
+ *   6. `[writable]` RewardPool ATA account
+ *   7. `[]` System program id
+ *   8. `[]` Token program id
+ *   9. `[]` Associated Token Account program id
+ */
+export interface FundRewardPoolData {
+    /** Epoch the rewards are paid for */
+    epoch: bigint;
+    /** Amount of tokens to transfer */
+    amount: bigint;
+}
+
+export const encodeFundRewardPoolData = (data: FundRewardPoolData): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.FundRewardPool);
+    writer.u64(data.epoch);
+    writer.u64(data.amount);
+    return writer.toBuffer();
+};
+
+/**
+ * Transfer the share of a reward pool's rewards proportional to the work a node recorded
+ * during its epoch to the node, once the epoch has ended
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[signer]` Node authority
+ *   1. `[writable]` RewardPool PDA account
+ *   2. `[writable]` RewardPool ATA account
+ *   3. `[writable]` EpochWork PDA account
+ *   4. `[writable]` Destination RNDR token account
+ *   5. `[]` Token program id
+ */
+export const encodeClaimEpochRewardData = (): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.ClaimEpochReward);
+    return writer.toBuffer();
+};
+
+/**
+ * Publish the Merkle root of the rewards of every node for an epoch, computed off chain, and
+ * transfer the sum of the rewards into the MerkleDistribution. The root of an epoch can only
+ * be published once.
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[]` RNDR SPL Token mint
+ *   1. `[writable,signer]` Escrow owner authority, pays to create the MerkleDistribution
+ *   2. `[writable]` Source RNDR token account
+ *      $authority can transfer $amount
+ *   3. `[signer]` Source token account authority ($authority)
+ *   4. `[]` Escrow PDA account
+ *   5. `[writable]` MerkleDistribution PDA account
+ *   6. `[writable]` MerkleDistribution ATA account
+ *   7. `[]` System program id
+ *   8. `[]` Token program id
+ *   9. `[]` Associated Token Account program id
+ */
+export interface PublishMerkleRootData {
+    /** Epoch the rewards are paid for */
+    epoch: bigint;
+    /** Merkle root of the `(index, node, amount)` leaves of every node's reward */
+    root: Uint8Array;
+    /** Number of leaves in the tree */
+    nodeCount: number;
+    /** Sum of the amounts of every leaf, transferred from the source token account */
+    amount: bigint;
+}
+
+export const encodePublishMerkleRootData = (data: PublishMerkleRootData): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.PublishMerkleRoot);
+    writer.u64(data.epoch);
+    writer.bytes(data.root, 32);
+    writer.u32(data.nodeCount);
+    writer.u64(data.amount);
+    return writer.toBuffer();
+};
+
+/**
+ * Transfer a node's reward from a MerkleDistribution with a proof of its leaf, recording the
+ * claim in the ClaimBitmap covering the leaf's index so it can't be claimed again. The
+ * ClaimBitmap is created if it doesn't exist.
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[writable,signer]` Node authority, pays to create the ClaimBitmap
+ *   1. `[writable]` MerkleDistribution PDA account
+ *   2. `[writable]` MerkleDistribution ATA account
+ *   3. `[writable]` ClaimBitmap PDA account of the leaf's chunk
+ *   4. `[writable]` Destination RNDR token account
+ *   5. `[]` System program id
+ *   6. `[]` Token program id
+ */
+export interface ClaimMerkleRewardData {
+    /** Index of the node's leaf */
+    index: number;
+    /** Amount of tokens in the node's leaf */
+    amount: bigint;
+    /** Sibling hashes from the leaf up to the root, at most `MAX_MERKLE_PROOF_LEN` */
+    proof: Uint8Array[];
+}
+
+export const encodeClaimMerkleRewardData = (data: ClaimMerkleRewardData): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.ClaimMerkleReward);
+    writer.u32(data.index);
+    writer.u64(data.amount);
+    writer.u8(data.proof.length);
+    for (const item of data.proof) {
+        writer.bytes(item, 32);
+    }
+    return writer.toBuffer();
+};
+
+/**
+ * Set the emission schedule of an Escrow, creating the EmissionSchedule if it doesn't exist.
+ * Changing an existing schedule keeps the epochs it has already emitted for. The rewards are
+ * transferred by anyone into the EmissionSchedule ATA account, and emitted with
+ * `EmitRewards`.
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[writable,signer]` Escrow owner authority, pays to create the EmissionSchedule
+ *   1. `[]` Escrow PDA account
+ *   2. `[writable]` EmissionSchedule PDA account
+ *   3. `[]` System program id
+ */
+export interface SetEmissionScheduleData {
+    /** First epoch that emits rewards */
+    startEpoch: bigint;
+    /** Amount of tokens emitted in the first epoch */
+    initialAmount: bigint;
+    /**
+     * Basis points the emission decays by from each epoch to the next, at most
+     * `MAX_DECAY_BPS`
+     */
+    decayBps: number;
+}
+
+export const encodeSetEmissionScheduleData = (data: SetEmissionScheduleData): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.SetEmissionSchedule);
+    writer.u64(data.startEpoch);
+    writer.u64(data.initialAmount);
+    writer.u16(data.decayBps);
+    return writer.toBuffer();
+};
+
+/**
+ * Transfer the emission of the current epoch from an EmissionSchedule into the reward pool
+ * of the epoch, creating the RewardPool if it doesn't exist. Each epoch emits once, and
+ * epochs that ended before their emission was transferred don't emit. Anyone can emit rewards.
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[]` RNDR SPL Token mint
+ *   1. `[writable,signer]` Funder SOL account
+ *   2. `[]` Escrow PDA account
+ *   3. `[writable]` EmissionSchedule PDA account
+ *   4. `[writable]` EmissionSchedule ATA account
+ *   5. `[writable]` RewardPool PDA account for the current epoch
+ *   6. `[writable]` RewardPool ATA account
+ *   7. `[]` System program id
+ *   8. `[]` Token program id
+ *   9. `[]` Associated Token Account program id
+ *
+ * Optionally, to be paid the bounty of the Escrow's CrankVault:
+ *
+ *   10. `[writable]` CrankVault PDA account of the Escrow
+ *   11. `[writable]` Bounty recipient SOL account
+ */
+export const encodeEmitRewardsData = (): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.EmitRewards);
+    return writer.toBuffer();
+};
+
+/**
+ * Stake tokens for a node, creating the Node if it doesn't exist
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[]` RNDR SPL Token mint
+ *   1. `[writable,signer]` Node authority, pays to create the Node
+ *   2. `[writable]` Source RNDR token account
+ *   3. `[signer]` Source RNDR token account authority
+ *   4. `[]` Escrow PDA account
+ *   5. `[writable]` Node PDA account
+ *   6. `[writable]` Node ATA account
+ *   7. `[]` System program id
+ *   8. `[]` Token program id
+ *   9. `[]` Associated Token Account program id
+ */
+export interface StakeData {
+    /** Amount of tokens to stake */
+    amount: bigint;
+}
+
+export const encodeStakeData = (data: StakeData): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.Stake);
+    writer.u64(data.amount);
+    return writer.toBuffer();
+};
+
+/**
+ * Withdraw staked tokens of a node
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[signer]` Node authority
+ *   1. `[writable]` Node PDA account
+ *   2. `[writable]` Node ATA account
+ *   3. `[writable]` Destination RNDR token account
+ *   4. `[]` Token program id
+ */
+export interface UnstakeData {
+    /** Amount of tokens to withdraw */
+    amount: bigint;
+}
+
+export const encodeUnstakeData = (data: UnstakeData): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.Unstake);
+    writer.u64(data.amount);
+    return writer.toBuffer();
+};
+
+/**
+ * Set whether anyone can compound the epoch rewards of a node into its stake
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[signer]` Node authority
+ *   1. `[writable]` Node PDA account
+ */
+export interface SetAutoCompoundData {
+    /** Whether to compound the node's epoch rewards */
+    autoCompound: boolean;
+}
+
+export const encodeSetAutoCompoundData = (data: SetAutoCompoundData): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.SetAutoCompound);
+    writer.bool(data.autoCompound);
+    return writer.toBuffer();
+};
+
+/**
+ * Claim the reward of a node for an ended epoch into its stake, for a node that has
+ * auto-compounding enabled. Anyone can compound rewards.
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[]` RNDR SPL Token mint
+ *   1. `[writable]` RewardPool PDA account
+ *   2. `[writable]` RewardPool ATA account
+ *   3. `[writable]` EpochWork PDA account of the node
+ *   4. `[writable]` Node PDA account
+ *   5. `[writable]` Node ATA account
+ *   6. `[]` Token program id
+ *
+ * Optionally, to be paid the bounty of the Escrow's CrankVault:
+ *
+ *   7. `[writable]` CrankVault PDA account of the Escrow
+ *   8. `[writable]` Bounty recipient SOL account
+ */
+export const encodeCompoundRewardsData = (): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.CompoundRewards);
+    return writer.toBuffer();
+};
+
+/**
+ * Stake tokens in the liquid stake pool of an Escrow for receipt tokens, creating the
+ * StakePool, its receipt mint and the receipt owner's receipt ATA if they don't exist.
+ * Receipts are minted at the current exchange rate of the pool.
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[]` RNDR SPL Token mint
+ *   1. `[writable,signer]` Funder SOL account
+ *   2. `[writable]` Source RNDR token account
+ *   3. `[signer]` Source RNDR token account authority
+ *   4. `[]` Escrow PDA account
+ *   5. `[writable]` StakePool PDA account
+ *   6. `[writable]` StakePool ATA account
+ *   7. `[writable]` Stake receipt mint PDA account
+ *   8. `[]` Receipt owner
+ *   9. `[writable]` Receipt owner's ATA account of the stake receipt mint
+ *   10. `[]` System program id
+ *   11. `[]` Token program id
+ *   12. `[]` Associated Token Account program id
+ */
+export interface LiquidStakeData {
+    /** Amount of tokens to stake */
+    amount: bigint;
+}
+
+export const encodeLiquidStakeData = (data: LiquidStakeData): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.LiquidStake);
+    writer.u64(data.amount);
+    return writer.toBuffer();
+};
+
+/**
+ * Burn receipt tokens of a liquid stake pool to withdraw their share of its stake
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[writable]` StakePool PDA account
+ *   1. `[writable]` StakePool ATA account
+ *   2. `[writable]` Stake receipt mint PDA account
+ *   3. `[writable]` Source receipt token account
+ *   4. `[signer]` Source receipt token account authority
+ *   5. `[writable]` Destination RNDR token account
+ *   6. `[]` Token program id
+ */
+export interface LiquidUnstakeData {
+    /** Amount of receipt tokens to burn */
+    receiptAmount: bigint;
+}
+
+export const encodeLiquidUnstakeData = (data: LiquidUnstakeData): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.LiquidUnstake);
+    writer.u64(data.receiptAmount);
+    return writer.toBuffer();
+};
+
+/**
+ * Deposit rewards into a liquid stake pool without minting receipts, raising the amount of
+ * stake each receipt token can be exchanged for. Anyone can deposit rewards.
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[writable]` Source RNDR token account
+ *   1. `[signer]` Source RNDR token account authority
+ *   2. `[writable]` StakePool PDA account
+ *   3. `[writable]` StakePool ATA account
+ *   4. `[]` Token program id
+ */
+export interface DepositStakeRewardsData {
+    /** Amount of tokens to deposit */
+    amount: bigint;
+}
+
+export const encodeDepositStakeRewardsData = (data: DepositStakeRewardsData): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.DepositStakeRewards);
+    writer.u64(data.amount);
+    return writer.toBuffer();
+};
+
+/**
+ * Set the bounty the CrankVault of an Escrow pays for each successfully executed crank,
+ * creating the CrankVault if it doesn't exist. The cranks that pay bounties are
+ * `AggregateEscrowShard` when it moves a nonzero amount, `EmitRewards`, `CompoundRewards` and
+ * `ProcessSubscription`.
+ * Anyone can fund the CrankVault by transferring lamports to it.
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[writable,signer]` Escrow owner authority, pays to create the CrankVault
+ *   1. `[]` Escrow PDA account
+ *   2. `[writable]` CrankVault PDA account
+ *   3. `[]` System program id
+ */
+export interface SetCrankBountyData {
+    /** Lamports paid for each successfully executed crank */
+    bounty: bigint;
+}
+
+export const encodeSetCrankBountyData = (data: SetCrankBountyData): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.SetCrankBounty);
+    writer.u64(data.bounty);
+    return writer.toBuffer();
+};
+
+/**
+ * Set the maximum amount of tokens the Treasury of an Escrow can burn in each epoch, creating
+ * the Treasury and the Treasury ATA if they don't exist. Anyone can fund the Treasury by
+ * transferring tokens into the Treasury ATA.
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[]` RNDR SPL Token mint
+ *   1. `[writable,signer]` Escrow owner authority, pays to create the Treasury
+ *   2. `[]` Escrow PDA account
+ *   3. `[writable]` Treasury PDA account
+ *   4. `[writable]` Treasury ATA account
+ *   5. `[]` System program id
+ *   6. `[]` Token program id
+ *   7. `[]` Associated Token Account program id
+ */
+export interface SetTreasuryBurnCapData {
+    /** Maximum amount of tokens burned in each epoch, where zero is uncapped */
+    epochBurnCap: bigint;
+}
+
+export const encodeSetTreasuryBurnCapData = (data: SetTreasuryBurnCapData): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.SetTreasuryBurnCap);
+    writer.u64(data.epochBurnCap);
+    return writer.toBuffer();
+};
+
+/**
+ * Burn tokens held by the Treasury of an Escrow, up to the amount its epoch burn cap leaves
+ * for the current epoch, and record them in the Treasury's burn totals
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[writable]` RNDR SPL Token mint
+ *   1. `[signer]` Escrow owner authority
+ *   2. `[]` Escrow PDA account
+ *   3. `[writable]` Treasury PDA account
+ *   4. `[writable]` Treasury ATA account
+ *   5. `[]` Token program id
+ */
+export interface BurnTreasuryData {
+    /** Amount of tokens to burn */
+    amount: bigint;
+}
+
+export const encodeBurnTreasuryData = (data: BurnTreasuryData): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.BurnTreasury);
+    writer.u64(data.amount);
+    return writer.toBuffer();
+};
+
+/**
+ * Create the Stats of an Escrow, which record the tokens escrowed, burned, emitted as rewards
+ * and disbursed in total and during the latest epoch. Anyone can create the Stats.
+ *
+ * The Stats PDA account can then be appended, writable, after all the other accounts of
+ * `FundJob`, `FundIsolatedJob`, `FundJobs`, `DisburseFunds`, `DisburseIsolatedJobFunds`,
+ * `DisburseBatch`, `ClaimPayment`, `ExecuteDisbursement`, `EmitRewards` and `BurnTreasury`
+ * to record their flows.
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[writable,signer]` Funder SOL account
+ *   1. `[]` Escrow PDA account
+ *   2. `[writable]` Stats PDA account
+ *   3. `[]` System program id
+ */
+export const encodeInitStatsData = (): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.InitStats);
+    return writer.toBuffer();
+};
+
+/**
+ * Transfer funds into an Escrow and credit a Job, authorized by a voucher the Job authority
+ * signed offchain instead of a transaction signature, so that a relayer can pay the fees.
+ *
+ * The instruction immediately before this one must be an ed25519 program instruction that
+ * verifies the authority's signature of the packed voucher, with its data in the same
+ * instruction. The voucher's nonce must be the next nonce of the authority's VoucherNonce,
+ * which is then incremented so the voucher can't be used again.
+ *
+ * An authority that signs this instruction, such as a PDA of another program signing with its
+ * seeds through `invoke_signed`, authorizes the voucher without the ed25519 program
+ * instruction, since a PDA has no private key to sign a voucher with.
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[writable,signer]` Relayer SOL account, pays to create the Job, the escrow shard and
+ *      the VoucherNonce
+ *   1. `[writable]` Source RNDR token account, with the Escrow PDA approved as its delegate
+ *      for at least $amount
+ *   2. `[]` Source token account owner and Job authority that signed the voucher
+ *   3. `[]` Escrow PDA account
+ *   4. `[writable]` Escrow ATA account
+ *   5. `[writable]` Job PDA account
+ *   6. `[writable]` Escrow shard PDA account for the job
+ *   7. `[writable]` VoucherNonce PDA account of the Escrow and authority
+ *   8. `[]` Instructions sysvar
+ *   9. `[]` System program id
+ *   10. `[]` Token program id
+ */
+export interface FundJobWithVoucherData {
+    /** Amount of tokens to fund */
+    amount: bigint;
+    /** Identifier of the Job, unique for the authority */
+    jobId: bigint;
+    /** Nonce of the voucher */
+    nonce: bigint;
+    /** Unix timestamp after which the voucher can't be used */
+    expiresAt: bigint;
+}
+
+export const encodeFundJobWithVoucherData = (data: FundJobWithVoucherData): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.FundJobWithVoucher);
+    writer.u64(data.amount);
+    writer.u64(data.jobId);
+    writer.u64(data.nonce);
+    writer.i64(data.expiresAt);
+    return writer.toBuffer();
+};
+
+/**
+ * Register a session key that can sign the instructions in its mask in place of a Job
+ * authority until its expiry slot, for the authority's Jobs in an Escrow. Registering the
+ * same session key again replaces its expiry slot and mask, so registering it with an empty
+ * mask revokes it.
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[writable,signer]` Job authority, pays to create the SessionKey
+ *   1. `[]` Escrow PDA account
+ *   2. `[writable]` SessionKey PDA account of the Escrow, Job authority and session key
+ *   3. `[]` System program id
+ */
+export interface RegisterSessionKeyData {
+    /** Session key accepted as a signer in place of the authority */
+    sessionKey: PublicKey;
+    /** Last slot the session key can be used in */
+    expirySlot: bigint;
+    /** Instructions the session key can sign, see `SESSION_KEY_INSTRUCTIONS` */
+    instructionMask: bigint;
+}
+
+export const encodeRegisterSessionKeyData = (data: RegisterSessionKeyData): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.RegisterSessionKey);
+    writer.publicKey(data.sessionKey);
+    writer.u64(data.expirySlot);
+    writer.u64(data.instructionMask);
+    return writer.toBuffer();
+};
+
+/**
+ * Approve a delegate that can disburse funds from an Escrow in place of its owner, up to a
+ * cumulative limit and until an expiry slot. Approving the same delegate again replaces its
+ * remaining allowance and expiry slot.
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[]` Escrow PDA account
+ *   1. `[writable,signer]` Owner authority, pays to create the DelegateAllowance
+ *   2. `[writable]` DelegateAllowance PDA account of the Escrow and delegate
+ *   3. `[]` System program id
+ */
+export interface ApproveDelegateData {
+    /** Delegate authority that can disburse in place of the owner */
+    delegate: PublicKey;
+    /** Cumulative amount of tokens the delegate can disburse */
+    limit: bigint;
+    /** Last slot the delegate can disburse in */
+    expirySlot: bigint;
+}
+
+export const encodeApproveDelegateData = (data: ApproveDelegateData): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.ApproveDelegate);
+    writer.publicKey(data.delegate);
+    writer.u64(data.limit);
+    writer.u64(data.expirySlot);
+    return writer.toBuffer();
+};
+
+/**
+ * Revoke a delegate of an Escrow's owner, closing its DelegateAllowance
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[]` Escrow PDA account
+ *   1. `[writable,signer]` Owner authority, receives the DelegateAllowance's lamports
+ *   2. `[writable]` DelegateAllowance PDA account of the Escrow and delegate
+ */
+export const encodeRevokeDelegateData = (): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.RevokeDelegate);
+    return writer.toBuffer();
+};
+
+/**
+ * Create a Subscription that funds a Job of the authority with an amount of tokens each
+ * period, starting from the current slot. The authority approves the Subscription as the
+ * SPL Token delegate of the source token account separately, so the delegated amount caps
+ * the total the Subscription can transfer and revoking the delegation stops it. Creating the
+ * Subscription of the same Job again replaces its source token account, amount and period.
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[writable,signer]` Job authority, pays to create the Subscription
+ *   1. `[]` Escrow PDA account
+ *   2. `[]` Source SPL Token account
+ *   3. `[writable]` Subscription PDA account of the Escrow, authority and job identifier
+ *   4. `[]` System program id
+ */
+export interface CreateSubscriptionData {
+    /** Identifier of the job to fund */
+    jobId: bigint;
+    /** Amount of tokens to fund each period */
+    amount: bigint;
+    /** Length of a period in slots */
+    period: bigint;
+}
+
+export const encodeCreateSubscriptionData = (data: CreateSubscriptionData): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.CreateSubscription);
+    writer.u64(data.jobId);
+    writer.u64(data.amount);
+    writer.u64(data.period);
+    return writer.toBuffer();
+};
+
+/**
+ * Fund the Job of a Subscription for its next due period, transferring the tokens from its
+ * source token account as its delegate. Anyone can crank a due Subscription, and each
+ * period a crank missed can still be funded by cranking again.
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[writable,signer]` Funder, pays to create the Job or EscrowShard if they don't exist
+ *   1. `[writable]` Subscription PDA account
+ *   2. `[writable]` Source SPL Token account of the Subscription
+ *   3. `[]` Escrow PDA account
+ *   4. `[writable]` Escrow ATA account
+ *   5. `[writable]` Job PDA account of the Subscription
+ *   6. `[writable]` Escrow shard PDA account of the Job
+ *   7. `[]` System program id
+ *   8. `[]` Token program id
+ *
+ * Optionally, to be paid the bounty of the Escrow's CrankVault:
+ *
+ *   9. `[writable]` CrankVault PDA account of the Escrow
+ *   10. `[writable]` Bounty recipient SOL account
+ */
+export const encodeProcessSubscriptionData = (): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.ProcessSubscription);
+    return writer.toBuffer();
+};
+
+/**
+ * Cancel a Job whose result hasn't been revealed, refunding its tokens to the authority. A
+ * Job that a node has committed to is charged the Config's cancellation fee once the grace
+ * period after funding has passed, which is paid to the node as compensation for the
+ * capacity it reserved. The remainder of rounding down the refund and the fee goes where the
+ * Config's rounding policy sends it, and a refund or fee below the Config's minimum
+ * disbursement is dust swept to the Treasury.
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[writable,signer]` Job authority, pays to reallocate a legacy Job
+ *   1. `[writable]` Escrow PDA account
+ *   2. `[writable]` Escrow ATA account
+ *   3. `[writable]` Job PDA account
+ *   4. `[writable]` Destination SPL Token account for the refund
+ *   5. `[]` Config PDA account
+ *   6. `[]` System program id
+ *   7. `[]` Token program id
+ *
+ * Optionally, required if a cancellation fee is charged:
+ *
+ *   8. `[writable]` SPL Token account of the Job's node for the fee
+ *
+ * Optionally, required if dust is swept to the Treasury, or a remainder is left and the
+ * rounding policy pays it to the Treasury or burns it:
+ *
+ *   9. `[]` Treasury PDA account of the Escrow
+ *   10. `[writable]` Treasury ATA account
+ *   11. `[writable]` RNDR token mint
+ *
+ * The EscrowShard PDA account the Job is funded through can be appended, writable, after all
+ * the other accounts and before the Stats PDA account, so the tokens funded through it are
+ * moved into the Escrow before it's debited.
+ */
+export const encodeCancelJobData = (): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.CancelJob);
+    return writer.toBuffer();
+};
+
+/**
+ * Start transferring a Job to a new authority, which takes over managing it once it accepts
+ * the transfer with `AcceptJobAuthority`. Starting another transfer replaces the pending
+ * one, and the default pubkey cancels it. The Job keeps its address, which is derived from
+ * the authority that initialized it.
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[writable,signer]` Job authority, pays to reallocate a legacy Job
+ *   1. `[]` Escrow PDA account
+ *   2. `[writable]` Job PDA account
+ *   3. `[]` System program id
+ */
+export interface TransferJobAuthorityData {
+    /** New authority of the job */
+    newAuthority: PublicKey;
+}
+
+export const encodeTransferJobAuthorityData = (data: TransferJobAuthorityData): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.TransferJobAuthority);
+    writer.publicKey(data.newAuthority);
+    return writer.toBuffer();
+};
+
+/**
+ * Accept the pending transfer of a Job, becoming its authority.
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[signer]` Pending authority of the Job
+ *   1. `[]` Escrow PDA account
+ *   2. `[writable]` Job PDA account
+ */
+export const encodeAcceptJobAuthorityData = (): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.AcceptJobAuthority);
+    return writer.toBuffer();
+};
+
+/**
+ * Create a JobBundle grouping the consecutive Jobs of the authority from a first job
+ * identifier, which share the bundle's metadata and are funded, cancelled and tracked to
+ * completion through it.
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[writable,signer]` Job authority, pays to create the JobBundle
+ *   1. `[]` Escrow PDA account
+ *   2. `[writable]` JobBundle PDA account of the Escrow, authority and bundle identifier
+ *   3. `[]` System program id
+ */
+export interface CreateJobBundleData {
+    /** Identifier of the bundle, unique for the authority */
+    bundleId: bigint;
+    /** Identifier of the first job of the bundle */
+    firstJobId: bigint;
+    /** Number of jobs of the bundle, at most `MAX_BUNDLE_JOBS` */
+    jobCount: number;
+    /** Hash of the metadata the jobs share */
+    metadataHash: Uint8Array;
+}
+
+export const encodeCreateJobBundleData = (data: CreateJobBundleData): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.CreateJobBundle);
+    writer.u64(data.bundleId);
+    writer.u64(data.firstJobId);
+    writer.u8(data.jobCount);
+    writer.bytes(data.metadataHash, 32);
+    return writer.toBuffer();
+};
+
+/**
+ * Transfer funds into an Escrow from a single source and credit consecutive Jobs of a
+ * JobBundle with the same amount each, creating them if needed
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[writable,signer]` Funder SOL account
+ *   1. `[writable]` Source RNDR token account
+ *      $authority can transfer the total amount
+ *   2. `[signer]` Source token account authority ($authority)
+ *   3. `[writable]` JobBundle PDA account
+ *   4. `[]` Escrow PDA account
+ *   5. `[writable]` Escrow ATA account
+ *   6. `[]` System program id
+ *   7. `[]` Token program id
+ *   8. ..8+2N `[writable]` Job PDA account and Escrow shard PDA account for each job
+ */
+export interface FundJobBundleData {
+    /** Identifier of the first job to fund, which must be part of the bundle */
+    firstJobId: bigint;
+    /** Number of consecutive jobs to fund, at most `MAX_FUND_JOBS_LEN` */
+    count: number;
+    /** Amount of RNDR tokens to fund each job with */
+    amountPerJob: bigint;
+}
+
+export const encodeFundJobBundleData = (data: FundJobBundleData): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.FundJobBundle);
+    writer.u64(data.firstJobId);
+    writer.u8(data.count);
+    writer.u64(data.amountPerJob);
+    return writer.toBuffer();
+};
+
+/**
+ * Cancel a JobBundle so its Jobs can't be funded through it anymore, cancelling the Jobs
+ * provided that no node has committed to and refunding their tokens to the authority. Jobs
+ * that a node has committed to are cancelled individually with `CancelJob`, which pays the
+ * node any cancellation fee.
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[writable,signer]` Job authority, pays to reallocate legacy Jobs
+ *   1. `[writable]` Escrow PDA account
+ *   2. `[writable]` Escrow ATA account
+ *   3. `[writable]` JobBundle PDA account
+ *   4. `[writable]` Destination SPL Token account for the refund
+ *   5. `[]` System program id
+ *   6. `[]` Token program id
+ *   7. ..7+N `[writable]` Job PDA accounts of the bundle to cancel
+ *
+ * The EscrowShard PDA accounts the Jobs are funded through can be appended, writable, after
+ * all the other accounts and before the Stats PDA account, so the tokens funded through them
+ * are moved into the Escrow before it's debited.
+ */
+export const encodeCancelJobBundleData = (): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.CancelJobBundle);
+    return writer.toBuffer();
+};
+
+/**
+ * Record the outcomes of Jobs of a JobBundle that have been paid, resolved or cancelled,
+ * completing the bundle once every Job has an outcome. Anyone can aggregate a JobBundle.
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[writable]` JobBundle PDA account
+ *   1. `[]` Escrow PDA account
+ *   2. ..2+N `[]` Job PDA accounts of the bundle
+ */
+export const encodeAggregateJobBundleData = (): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.AggregateJobBundle);
+    return writer.toBuffer();
+};
+
+/**
+ * Create the JobTree of an authority in an Escrow, which holds compressed Jobs as the leaves
+ * of an spl-account-compression concurrent merkle tree instead of in their own accounts, and
+ * initialize the merkle tree with the JobTree as its authority
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[writable,signer]` Job authority, pays to create the JobTree
+ *   1. `[]` Escrow PDA account
+ *   2. `[writable]` JobTree PDA account of the Escrow and authority
+ *   3. `[writable]` Merkle tree account, created with the account compression program as its
+ *      owner and the size of a tree of $max_depth and $max_buffer_size
+ *   4. `[]` Noop program id
+ *   5. `[]` Account compression program id
+ *   6. `[]` System program id
+ */
+export interface InitJobTreeData {
+    /** Depth of the tree, at most `MAX_JOB_TREE_DEPTH` */
+    maxDepth: number;
+    /** Number of recent roots of the merkle tree that proofs of leaves can be of */
+    maxBufferSize: number;
+}
+
+export const encodeInitJobTreeData = (data: InitJobTreeData): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.InitJobTree);
+    writer.u8(data.maxDepth);
+    writer.u32(data.maxBufferSize);
+    return writer.toBuffer();
+};
+
+/**
+ * Transfer funds into an Escrow and append an open compressed Job with the amount to the
+ * JobTree of the authority, identified by the index of its leaf. The leaf's preimage is logged
+ * through the noop program so clients can build proofs of its leaf.
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[signer]` Job authority
+ *   1. `[writable]` Source RNDR token account
+ *      $authority can transfer $amount
+ *   2. `[signer]` Source token account authority ($authority)
+ *   3. `[writable]` JobTree PDA account
+ *   4. `[writable]` Escrow PDA account
+ *   5. `[writable]` Escrow ATA account
+ *   6. `[]` Token program id
+ *   7. `[writable]` Merkle tree account of the JobTree
+ *   8. `[]` Noop program id
+ *   9. `[]` Account compression program id
+ */
+export interface AppendCompressedJobData {
+    /** Amount of RNDR tokens to fund the job with */
+    amount: bigint;
+}
+
+export const encodeAppendCompressedJobData = (data: AppendCompressedJobData): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.AppendCompressedJob);
+    writer.u64(data.amount);
+    return writer.toBuffer();
+};
+
+/**
+ * Replace the leaf of an unsettled compressed Job with a proof of its current leaf, assigning
+ * the job to a node, or back to open with the default pubkey. The Job authority can only
+ * assign an open Job, while a committed Job can only be reassigned or reopened with the
+ * signature of the node it's committed to.
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[signer]` Job authority
+ *   1. `[]` Escrow PDA account
+ *   2. `[]` JobTree PDA account
+ *   3. `[writable]` Merkle tree account of the JobTree
+ *   4. `[]` Noop program id
+ *   5. `[]` Account compression program id
+ *
+ * Optionally, if the Job is committed:
+ *
+ *   6. `[signer]` Node the Job is committed to
+ *
+ * Followed by the proof of the Job's current leaf:
+ *
+ *   7. ..7+N `[]` Sibling hashes from the leaf up, one for each level of the tree below its
+ *      canopy
+ */
+export interface ReplaceCompressedJobData {
+    /** Current state of the compressed job */
+    job: CompressedJob;
+    /** Node to assign the job to */
+    node: PublicKey;
+    /** Recent root of the merkle tree that the proof is of */
+    root: Uint8Array;
+}
+
+export const encodeReplaceCompressedJobData = (data: ReplaceCompressedJobData): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.ReplaceCompressedJob);
+    writeCompressedJob(writer, data.job);
+    writer.publicKey(data.node);
+    writer.bytes(data.root, 32);
+    return writer.toBuffer();
+};
+
+/**
+ * Settle a compressed Job with a proof of its current leaf, paying its tokens to the node it's
+ * assigned to, or refunding them if it's still open, and replacing its leaf with a paid or
+ * cancelled job of no tokens
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[signer]` Job authority
+ *   1. `[writable]` Escrow PDA account
+ *   2. `[writable]` Escrow ATA account
+ *   3. `[writable]` JobTree PDA account
+ *   4. `[writable]` Destination SPL Token account, owned by the node if the job is assigned
+ *   5. `[]` Token program id
+ *   6. `[writable]` Merkle tree account of the JobTree
+ *   7. `[]` Noop program id
+ *   8. `[]` Account compression program id
+ *   9. ..9+N `[]` Sibling hashes from the leaf up, one for each level of the tree below its
+ *      canopy
+ */
+export interface SettleCompressedJobData {
+    /** Current state of the compressed job */
+    job: CompressedJob;
+    /** Recent root of the merkle tree that the proof is of */
+    root: Uint8Array;
+}
+
+export const encodeSettleCompressedJobData = (data: SettleCompressedJobData): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.SettleCompressedJob);
+    writeCompressedJob(writer, data.job);
+    writer.bytes(data.root, 32);
+    return writer.toBuffer();
+};
+
+/**
+ * Create the JobIndex of an authority in an Escrow, which records the identifiers of the
+ * authority's jobs in JobIndexPage accounts as they're created.
+ *
+ * The JobIndex PDA account and the JobIndexPage PDA account of its next page can then be
+ * appended, both writable, after all the other accounts of `FundJob`, `FundIsolatedJob`,
+ * `FundJobs`, `FundJobWithVoucher`, `ProcessSubscription` and `FundJobBundle`, and before the
+ * Stats PDA account, to record the jobs they create. The funder pays to create the page.
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[writable,signer]` Job authority, pays to create the JobIndex
+ *   1. `[]` Escrow PDA account
+ *   2. `[writable]` JobIndex PDA account of the Escrow and authority
+ *   3. `[]` System program id
+ */
+export const encodeInitJobIndexData = (): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.InitJobIndex);
+    return writer.toBuffer();
+};
+
+/**
+ * Set the metadata of an open Job, which is the URI and content hash of its off-chain
+ * manifest, cleared with an empty URI, and the tags of the capabilities it requires
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[writable,signer]` Job authority, pays to reallocate legacy job accounts
+ *   1. `[]` Escrow PDA account
+ *   2. `[writable]` Job PDA account
+ *   3. `[]` System program id
+ */
+export interface UpdateJobMetadataData {
+    /** Metadata to set on the job */
+    metadata: JobMetadata;
+}
+
+export const encodeUpdateJobMetadataData = (data: UpdateJobMetadataData): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.UpdateJobMetadata);
+    writeJobMetadata(writer, data.metadata);
+    return writer.toBuffer();
+};
+
+/**
+ * Move the pooled tokens of an Escrow to a new token account, which holds them from then on,
+ * such as when moving them to or from its associated token account or after the token
+ * account was compromised. The new token account must be owned by the Escrow PDA, without a
+ * delegate or close authority. Isolated jobs keep their own token accounts.
+ *
+ * Instructions built for the Escrow afterwards must pass the new token account in place of
+ * the Escrow ATA account, see `with_escrow_token_account`.
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[writable]` Escrow PDA account
+ *   1. `[writable,signer]` Owner authority, pays to reallocate legacy Escrows
+ *   2. `[writable]` Current token account of the Escrow
+ *   3. `[writable]` New token account of the Escrow
+ *   4. `[]` System program id
+ *   5. `[]` Token program id
+ */
+export const encodeSetEscrowTokenAccountData = (): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.SetEscrowTokenAccount);
+    return writer.toBuffer();
+};
+
+/**
+ * Begin the migration of an Escrow to a new token mint of the token program, such as from
+ * RNDR to RENDER, creating the MintMigration and the associated token account of the Escrow
+ * for the new mint. Amounts are converted at the rate of the numerator over the denominator,
+ * rounded down. The governance authority of the Config approves the migration and the token
+ * account of the old mint that the Escrow's old tokens will be sent to, and the migration
+ * can't begin during an incident. The migration continues with `ConvertEscrowMintVault` once
+ * the timelock of the Config has passed, then `RedenominateJobs` and
+ * `ConvertIsolatedJobMint`, which can each be resumed.
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[]` Escrow PDA account
+ *   1. `[writable,signer]` Owner authority, pays to create the MintMigration
+ *   2. `[]` Config PDA account
+ *   3. `[signer]` Governance authority of the Config
+ *   4. `[writable]` MintMigration PDA account for the Escrow's number of migrations
+ *   5. `[]` New SPL Token mint
+ *   6. `[writable]` Escrow ATA account of the new mint
+ *   7. `[]` Destination token account of the old mint
+ *   8. `[]` System program id
+ *   9. `[]` Token program id
+ *   10. `[]` Associated Token Account program id
+ *
+ * Optionally, required if the Escrow has an approver:
+ *
+ *   11. `[signer]` Approver authority of the Escrow
+ */
+export interface MigrateEscrowMintData {
+    /** Numerator of the conversion rate */
+    rateNumerator: bigint;
+    /** Denominator of the conversion rate, which can't be zero */
+    rateDenominator: bigint;
+}
+
+export const encodeMigrateEscrowMintData = (data: MigrateEscrowMintData): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.MigrateEscrowMint);
+    writer.u64(data.rateNumerator);
+    writer.u64(data.rateDenominator);
+    return writer.toBuffer();
+};
+
+/**
+ * Convert the pooled tokens of an Escrow being migrated to a new token mint, aggregating its
+ * escrow shards first. The owner deposits the converted amount of new tokens into the Escrow
+ * ATA account of the new mint, and every old token is sent in exchange to the destination
+ * token account approved when the migration began, to convert them off-chain. The Escrow then
+ * uses the new mint, its tokens are held in the new ATA account and its jobs must be migrated
+ * before they can be funded or paid. The timelock of the migration must have passed, and
+ * the incident mode of the Escrow must allow disbursing.
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[writable]` Escrow PDA account
+ *   1. `[writable,signer]` Owner authority, pays to reallocate legacy Escrows
+ *      $owner can transfer the converted amount from the source token account
+ *   2. `[writable]` MintMigration PDA account
+ *   3. `[writable]` Current token account of the Escrow
+ *   4. `[writable]` Escrow ATA account of the new mint
+ *   5. `[writable]` Source token account of the new mint
+ *   6. `[writable]` Destination token account of the old mint approved for the MintMigration
+ *   7. `[]` System program id
+ *   8. `[]` Token program id
+ *   9. ..9+N `[writable]` Every EscrowShard PDA account of the Escrow, in index order
+ */
+export const encodeConvertEscrowMintVaultData = (): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.ConvertEscrowMintVault);
+    return writer.toBuffer();
+};
+
+/**
+ * Re-denominate the amounts of jobs of an Escrow whose pooled tokens were converted by a
+ * mint migration, which jobs must be migrated by in order. Jobs that were already
+ * re-denominated by the migration are skipped, so batches can be retried. Anyone can
+ * re-denominate jobs.
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[]` Escrow PDA account
+ *   1. `[writable]` MintMigration PDA account
+ *   2. `[writable,signer]` Payer SOL account, pays to reallocate legacy jobs
+ *   3. `[]` System program id
+ *   4. ..4+N `[writable]` Job PDA accounts whose tokens are held by the Escrow
+ */
+export const encodeRedenominateJobsData = (): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.RedenominateJobs);
+    return writer.toBuffer();
+};
+
+/**
+ * Convert the tokens of an isolated job of an Escrow whose pooled tokens were converted by a
+ * mint migration, like `ConvertEscrowMintVault` does for the Escrow, creating the job's ATA
+ * account of the new mint. The job's old ATA account is left empty. Frozen jobs can't be
+ * converted, and the incident mode of the Escrow must allow disbursing.
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[]` Escrow PDA account
+ *   1. `[writable,signer]` Owner authority, pays to create the new job ATA account and to
+ *      reallocate legacy jobs
+ *      $owner can transfer the converted amount from the source token account
+ *   2. `[writable]` MintMigration PDA account
+ *   3. `[writable]` Job PDA account
+ *   4. `[writable]` Job ATA account of the old mint
+ *   5. `[writable]` Job ATA account of the new mint
+ *   6. `[]` New SPL Token mint
+ *   7. `[writable]` Source token account of the new mint
+ *   8. `[writable]` Destination token account of the old mint approved for the MintMigration
+ *   9. `[]` System program id
+ *   10. `[]` Token program id
+ *   11. `[]` Associated Token Account program id
+ */
+export const encodeConvertIsolatedJobMintData = (): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.ConvertIsolatedJobMint);
+    return writer.toBuffer();
+};
+
+/**
+ * Initialize an Escrow in a namespace, like `InitEscrow` does for the default escrow of the
+ * token mint, whose address is derived from the escrow id so several escrows of the same
+ * token mint can coexist. An escrow id of zero initializes the default escrow.
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[]` RNDR SPL Token mint
+ *   1. `[writable,signer]` Funder SOL account
+ *   2. `[writable]` Escrow PDA account
+ *   3. `[writable]` Escrow ATA account
+ *   4. `[]` System program id
+ *   5. `[]` Token program id
+ *   6. `[]` Associated Token Account program id
+ */
+export interface InitEscrowWithSeedData {
+    /** Owner authority that can disburse funds */
+    owner: PublicKey;
+    /** Namespace of the escrow */
+    escrowId: bigint;
+}
+
+export const encodeInitEscrowWithSeedData = (data: InitEscrowWithSeedData): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.InitEscrowWithSeed);
+    writer.publicKey(data.owner);
+    writer.u64(data.escrowId);
+    return writer.toBuffer();
+};
+
+/**
+ * Raise the priority of an open Job, paying the config's priority surcharge on the Job's
+ * amount for each level raised to the Treasury of its Escrow. Nodes can commit to a Job of a
+ * lower priority only once the config's priority claim delay has passed for each level below
+ * `MAX_JOB_PRIORITY`, so Jobs of a higher priority are claimed first.
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[writable,signer]` Job authority ($authority), pays to reallocate legacy Jobs
+ *   1. `[]` Escrow PDA account
+ *   2. `[writable]` Job PDA account
+ *   3. `[writable]` Source token account
+ *      $authority can transfer the surcharge
+ *   4. `[]` Treasury PDA account
+ *   5. `[writable]` Treasury ATA account
+ *   6. `[]` Config PDA account
+ *   7. `[]` System program id
+ *   8. `[]` Token program id
+ */
+export interface RaiseJobPriorityData {
+    /** Priority of the Job, at most `MAX_JOB_PRIORITY` */
+    priority: number;
+}
+
+export const encodeRaiseJobPriorityData = (data: RaiseJobPriorityData): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.RaiseJobPriority);
+    writer.u8(data.priority);
+    return writer.toBuffer();
+};
+
+/**
+ * Create the JobQueue of an Escrow, which Jobs created by `FundJob` are pushed to when it's
+ * appended and which nodes claim them from with `ClaimJob`. Anyone can create the JobQueue.
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[writable,signer]` Funder SOL account, pays to create the JobQueue
+ *   1. `[]` Escrow PDA account
+ *   2. `[writable]` JobQueue PDA account of the Escrow
+ *   3. `[]` System program id
+ */
+export const encodeInitJobQueueData = (): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.InitJobQueue);
+    return writer.toBuffer();
+};
+
+/**
+ * Pop the oldest Job of a JobQueue and commit to its result, assigning the Job to the node
+ * like `CommitResult` does. A Job that was cancelled or committed to since it was queued is
+ * popped without being claimed, and a Job whose priority claim delay hasn't passed yet is
+ * moved to the back of the queue, so neither blocks the Jobs behind it.
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[writable,signer]` Node SOL account, pays to reallocate legacy Jobs
+ *   1. `[writable]` JobQueue PDA account
+ *   2. `[writable]` Job PDA account of the oldest Job of the JobQueue
+ *   3. `[]` Config PDA account
+ *   4. `[]` System program id
+ */
+export interface ClaimJobData {
+    /** Commitment to the result, see `Job::result_commitment` */
+    commitment: Uint8Array;
+}
+
+export const encodeClaimJobData = (data: ClaimJobData): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.ClaimJob);
+    writer.bytes(data.commitment, 32);
+    return writer.toBuffer();
+};
+
+/**
+ * Record the exchange rate a Job funded in a token other than RNDR is settled at, in RNDR
+ * base units per base unit of the Escrow's token, see `ExchangeRate`. The rate is derived from
+ * the live price of the Escrow's price feed, read as the price of one whole token in RNDR, see
+ * `ExchangeRate::try_from_price`, and the instruction fails if the price is older or less
+ * certain than the oracle price guards of the Config allow. The rate and the publish slot of
+ * the price can only be recorded once and before the Job is settled, so all of the Job's
+ * amounts are converted at the same rate. Disbursing, claiming or cancelling the Job then logs
+ * the value in RNDR of the tokens settled, the work credited for its payment is its value in
+ * RNDR, and the Config's minimum disbursement is converted to the token when its cancellation
+ * is split.
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[writable,signer]` Owner authority, pays to reallocate legacy Jobs
+ *   1. `[]` Escrow PDA account
+ *   2. `[writable]` Job PDA account
+ *   3. `[]` Price feed account of the Escrow
+ *   4. `[]` Token mint of the Escrow
+ *   5. `[]` Config PDA account
+ *   6. `[]` System program id
+ */
+export const encodeSetJobExchangeRateData = (): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.SetJobExchangeRate);
+    return writer.toBuffer();
+};
+
+/**
+ * Estimate the funding amount of a Job from the OctaneBench-hour price of a price tier in the
+ * Config, see `Config::job_cost`, so clients derive it the same way the program does. The
+ * amount is set as the return data of the transaction, as a little-endian u64 in RNDR base
+ * units, and the instruction fails if the price tier is disabled.
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[]` Config PDA account
+ */
+export interface EstimateJobCostData {
+    /** Index of the price tier, below `MAX_PRICE_TIERS` */
+    tier: number;
+    /** Work of the Job, in thousandths of an OctaneBench-hour */
+    milliObh: bigint;
+}
+
+export const encodeEstimateJobCostData = (data: EstimateJobCostData): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.EstimateJobCost);
+    writer.u8(data.tier);
+    writer.u64(data.milliObh);
+    return writer.toBuffer();
+};
+
+/**
+ * Set the oracle price account of an Escrow's token that Jobs are funded at with
+ * `FundJobAtOraclePrice`, or the default pubkey so Jobs can't be funded at an oracle price
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[writable]` Escrow PDA account
+ *   1. `[writable,signer]` Owner authority, pays to reallocate legacy Escrows
+ *   2. `[]` System program id
+ */
+export interface SetPriceFeedData {
+    /** Price account of the Escrow's token, see `OraclePrice` */
+    priceFeed: PublicKey;
+}
+
+export const encodeSetPriceFeedData = (data: SetPriceFeedData): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.SetPriceFeed);
+    writer.publicKey(data.priceFeed);
+    return writer.toBuffer();
+};
+
+/**
+ * Transfer funds into an Escrow and credit a Job like `FundJob` does, with the amount of
+ * tokens converted from a value in the quote currency of the Escrow's price feed at its live
+ * price, see `OraclePrice::try_token_amount`. The instruction fails if the amount exceeds the
+ * maximum the funder signed for, so a price swing between signing and execution can't charge
+ * the funder more than they expected, and if the price is older or less certain than the oracle
+ * price guards of the Config allow. Unless the Job already has an exchange rate, the rate of
+ * the price and its publish slot are recorded on it as with `SetJobExchangeRate`, so its
+ * amounts are settled at the price it was funded at.
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[]` Price feed account of the Escrow
+ *   1. `[]` Token mint of the Escrow
+ *   2. `[]` Config PDA account
+ *   3. `[writable,signer]` Funder SOL account
+ *   4. `[writable]` Source RNDR token account
+ *      $authority can transfer the converted amount
+ *   5. `[signer]` Source token account authority ($authority)
+ *   6. `[]` Escrow PDA account
+ *   7. `[writable]` Escrow ATA account
+ *   8. `[writable]` Job PDA account
+ *   9. `[writable]` Escrow shard PDA account for the job
+ *   10. `[]` System program id
+ *   11. `[]` Token program id
+ *
+ * Optionally, to top up an existing Job with a session key of its authority instead, as
+ * with `FundJob`:
+ *
+ *   12. `[]` SessionKey PDA account of the Escrow, Job authority and session key
+ */
+export interface FundJobAtOraclePriceData {
+    /**
+     * Value of the Job in the quote currency of the price feed, with
+     * `PRICED_VALUE_DECIMALS` decimals
+     */
+    value: bigint;
+    /** Maximum amount of RNDR tokens to escrow for the value */
+    maxRndrAmount: bigint;
+    /** Identifier of the job, unique for the authority */
+    jobId: bigint;
+}
+
+export const encodeFundJobAtOraclePriceData = (data: FundJobAtOraclePriceData): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.FundJobAtOraclePrice);
+    writer.u64(data.value);
+    writer.u64(data.maxRndrAmount);
+    writer.u64(data.jobId);
+    return writer.toBuffer();
+};
+
+/**
+ * Freeze a Job under a legal hold, so its funds can't be disbursed or refunded until it's
+ * unfrozen with `UnfreezeJob`. Only the Job is held, so the rest of the Escrow keeps working.
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[writable,signer]` Compliance authority of the Config, pays to reallocate legacy Jobs
+ *   1. `[]` Config PDA account
+ *   2. `[]` Escrow PDA account
+ *   3. `[writable]` Job PDA account
+ *   4. `[]` System program id
+ */
+export const encodeFreezeJobData = (): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.FreezeJob);
+    return writer.toBuffer();
+};
+
+/**
+ * Unfreeze a Job frozen with `FreezeJob`, releasing its legal hold
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[signer]` Compliance authority of the Config
+ *   1. `[]` Config PDA account
+ *   2. `[]` Escrow PDA account
+ *   3. `[writable]` Job PDA account
+ */
+export const encodeUnfreezeJobData = (): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.UnfreezeJob);
+    return writer.toBuffer();
+};
+
+/**
+ * Copy the incident mode of the Config, set by its governance authority with `SetConfig`, to
+ * an Escrow, so Jobs can't be funded, or their funds can't be disbursed or refunded, while the
+ * other direction keeps working, see `IncidentMode`
+ *
+ * Funding and disbursing instructions only unpack the Escrow, so they enforce the incident
+ * mode it was last synced to. Anyone can sync an Escrow, so the governance authority should
+ * sync every Escrow in the transaction that changes the incident mode, or right after a queued
+ * change is applied.
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[writable]` Escrow PDA account
+ *   1. `[]` Config PDA account
+ *   2. `[writable,signer]` Payer account, pays to reallocate legacy Escrows
+ *   3. `[]` System program id
+ */
+export const encodeSyncIncidentModeData = (): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.SyncIncidentMode);
+    return writer.toBuffer();
+};
+
+/**
+ * Register the cold wallet the Treasury of an Escrow can be swept to with
+ * `ScheduleTreasurySweep`, cancelling any sweep scheduled to the previous cold wallet
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[writable,signer]` Escrow owner authority, pays to reallocate legacy Treasuries
+ *   1. `[]` Escrow PDA account
+ *   2. `[writable]` Treasury PDA account
+ *   3. `[]` Treasury ATA account
+ *   4. `[]` Cold wallet token account of the Treasury's token mint
+ *   5. `[]` System program id
+ */
+export const encodeRegisterColdWalletData = (): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.RegisterColdWallet);
+    return writer.toBuffer();
+};
+
+/**
+ * Schedule a sweep of tokens held by the Treasury of an Escrow to its registered cold wallet,
+ * which can be executed with `SweepToColdWallet` once the sweep delay of the Config has
+ * passed, and cancelled by the guardian of the Config with `CancelTreasurySweep` until then
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[signer]` Escrow owner authority
+ *   1. `[]` Config PDA account
+ *   2. `[]` Escrow PDA account
+ *   3. `[writable]` Treasury PDA account
+ *   4. `[]` Treasury ATA account
+ */
+export interface ScheduleTreasurySweepData {
+    /** Amount of tokens to sweep */
+    amount: bigint;
+}
+
+export const encodeScheduleTreasurySweepData = (data: ScheduleTreasurySweepData): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.ScheduleTreasurySweep);
+    writer.u64(data.amount);
+    return writer.toBuffer();
+};
+
+/**
+ * Execute the scheduled sweep of the Treasury of an Escrow to its cold wallet once the sweep
+ * delay has passed, which anyone can crank
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[]` Escrow PDA account
+ *   1. `[writable]` Treasury PDA account
+ *   2. `[writable]` Treasury ATA account
+ *   3. `[writable]` Cold wallet token account of the Treasury
+ *   4. `[]` Token program id
+ */
+export const encodeSweepToColdWalletData = (): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.SweepToColdWallet);
+    return writer.toBuffer();
+};
+
+/**
+ * Cancel the scheduled sweep of the Treasury of an Escrow before it can be executed
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[signer]` Guardian authority of the Config
+ *   1. `[]` Config PDA account
+ *   2. `[]` Escrow PDA account
+ *   3. `[writable]` Treasury PDA account
+ *   4. `[]` Treasury ATA account
+ */
+export const encodeCancelTreasurySweepData = (): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.CancelTreasurySweep);
+    return writer.toBuffer();
+};
+
+/**
+ * Report the version of the deployed program, see `version::VERSION`. The crate version and
+ * layout version are set as the return data of the transaction, packed as a
+ * `ProgramVersion`.
+ *
+ * No accounts are expected by this instruction.
+ */
+export const encodeGetVersionData = (): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.GetVersion);
+    return writer.toBuffer();
+};
+
+/**
+ * Report a summary of an Escrow, see `summary::EscrowSummary`, so CPI callers and simulating
+ * clients can read it without decoding the account. The summary is set as the return data
+ * of the transaction.
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[]` Escrow PDA account
+ */
+export const encodeGetEscrowSummaryData = (): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.GetEscrowSummary);
+    return writer.toBuffer();
+};
+
+/**
+ * Report a summary of a Job, see `summary::JobSummary`, so CPI callers and simulating clients
+ * can read it without decoding the account. The summary is set as the return data of the
+ * transaction.
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[]` Escrow PDA account
+ *   1. `[]` Job PDA account of the Escrow
+ */
+export const encodeGetJobSummaryData = (): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.GetJobSummary);
+    return writer.toBuffer();
+};
+
+/**
+ * Report the amount of tokens in escrow for a Job, so other programs can read it as
+ * collateral data by CPI. The amount is set as the return data of the transaction, as a
+ * little-endian u64 in base units of the Escrow's token mint, a layout that won't change.
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[]` Escrow PDA account
+ *   1. `[]` Job PDA account of the Escrow
+ */
+export const encodeGetEscrowedAmountData = (): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.GetEscrowedAmount);
+    return writer.toBuffer();
+};
+
+/**
+ * Reconcile the balance of an Escrow's token account with the amount of tokens the Escrow
+ * tracks, which is its amount and the amounts pending in its escrow shards. Anyone can
+ * reconcile an Escrow. When the balance falls short, an `EscrowDiscrepancyFound` event is
+ * logged and the Escrow is flagged as needing attention, and the flag is cleared by a later
+ * reconciliation that finds the balance covers the tracked amount.
+ *
+ * Accounts expected by this instruction:
+ *
+ *   0. `[writable]` Escrow PDA account
+ *   1. `[]` Token account of the Escrow
+ *   2. `[writable,signer]` Payer, pays to reallocate legacy Escrows
+ *   3. `[]` System program id
+ *   4. ..4+N `[]` Every EscrowShard PDA account of the Escrow, in index order
+ */
+export const encodeReconcileEscrowData = (): Buffer => {
+    const writer = new Writer();
+    writer.u8(RNDRInstruction.ReconcileEscrow);
+    return writer.toBuffer();
+};
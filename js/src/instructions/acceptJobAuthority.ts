@@ -0,0 +1,45 @@
+import { PublicKey, TransactionInstruction } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import { findEscrowAddress } from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+}
+
+const DataLayout = struct<Data>([u8('instruction')]);
+
+export const createAcceptJobAuthorityInstruction = async (
+    newAuthority: PublicKey,
+    job: PublicKey
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    return acceptJobAuthority(newAuthority, escrow, job);
+};
+
+export const acceptJobAuthority = (
+    newAuthority: PublicKey,
+    escrow: PublicKey,
+    job: PublicKey
+): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.AcceptJobAuthority,
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: newAuthority, isSigner: true, isWritable: false },
+        { pubkey: escrow, isSigner: false, isWritable: false },
+        { pubkey: job, isSigner: false, isWritable: true },
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
@@ -0,0 +1,50 @@
+import { PublicKey, SystemProgram, TransactionInstruction } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import { findEscrowAddress, findVerifierRegistryAddress, publicKey } from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+    verifier: PublicKey;
+}
+
+const DataLayout = struct<Data>([u8('instruction'), publicKey('verifier')]);
+
+export const createAddVerifierInstruction = async (
+    verifier: PublicKey,
+    owner: PublicKey
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [verifierRegistry] = await findVerifierRegistryAddress(escrow);
+    return addVerifier(verifier, escrow, owner, verifierRegistry);
+};
+
+export const addVerifier = (
+    verifier: PublicKey,
+    escrow: PublicKey,
+    owner: PublicKey,
+    verifierRegistry: PublicKey
+): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.AddVerifier,
+            verifier,
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: escrow, isSigner: false, isWritable: false },
+        { pubkey: owner, isSigner: true, isWritable: true },
+        { pubkey: verifierRegistry, isSigner: false, isWritable: true },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
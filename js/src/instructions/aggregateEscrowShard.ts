@@ -0,0 +1,41 @@
+import { PublicKey, TransactionInstruction } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import { findEscrowAddress, findEscrowShardAddress } from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+}
+
+const DataLayout = struct<Data>([u8('instruction')]);
+
+export const createAggregateEscrowShardInstruction = async (index: number): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [escrowShard] = await findEscrowShardAddress(escrow, index);
+    return aggregateEscrowShardInstruction(escrow, escrowShard);
+};
+
+export const aggregateEscrowShardInstruction = (
+    escrow: PublicKey,
+    escrowShard: PublicKey
+): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.AggregateEscrowShard,
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: escrow, isSigner: false, isWritable: true },
+        { pubkey: escrowShard, isSigner: false, isWritable: true },
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
@@ -0,0 +1,36 @@
+import { PublicKey, TransactionInstruction } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID } from '../constants';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+}
+
+const DataLayout = struct<Data>([u8('instruction')]);
+
+export const aggregateJobBundle = (
+    jobBundle: PublicKey,
+    escrow: PublicKey,
+    jobs: PublicKey[]
+): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.AggregateJobBundle,
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: jobBundle, isSigner: false, isWritable: true },
+        { pubkey: escrow, isSigner: false, isWritable: false },
+        ...jobs.map((job) => ({ pubkey: job, isSigner: false, isWritable: false })),
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
@@ -0,0 +1,75 @@
+import { TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { PublicKey, TransactionInstruction } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { ACCOUNT_COMPRESSION_PROGRAM_ID, NOOP_PROGRAM_ID, RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import { findEscrowAddress, findEscrowAssociatedTokenAddress, findJobTreeAddress, u64 } from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+    amount: bigint;
+}
+
+const DataLayout = struct<Data>([u8('instruction'), u64('amount')]);
+
+/** Fund a compressed job appended to the job tree of an authority, identified by the tree's leaf count */
+export const createAppendCompressedJobInstruction = async (
+    amount: number | bigint,
+    authority: PublicKey,
+    sourceToken: PublicKey,
+    sourceAuthority: PublicKey,
+    merkleTree: PublicKey
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [escrowAssociatedToken] = await findEscrowAssociatedTokenAddress(escrow, RNDR_TOKEN_MINT);
+    const [jobTree] = await findJobTreeAddress(escrow, authority);
+    return appendCompressedJob(
+        amount,
+        authority,
+        sourceToken,
+        sourceAuthority,
+        jobTree,
+        escrow,
+        escrowAssociatedToken,
+        merkleTree
+    );
+};
+
+export const appendCompressedJob = (
+    amount: number | bigint,
+    authority: PublicKey,
+    sourceToken: PublicKey,
+    sourceAuthority: PublicKey,
+    jobTree: PublicKey,
+    escrow: PublicKey,
+    escrowAssociatedToken: PublicKey,
+    merkleTree: PublicKey
+): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.AppendCompressedJob,
+            amount: BigInt(amount),
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: authority, isSigner: true, isWritable: false },
+        { pubkey: sourceToken, isSigner: false, isWritable: true },
+        { pubkey: sourceAuthority, isSigner: true, isWritable: false },
+        { pubkey: jobTree, isSigner: false, isWritable: true },
+        { pubkey: escrow, isSigner: false, isWritable: true },
+        { pubkey: escrowAssociatedToken, isSigner: false, isWritable: true },
+        { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+        { pubkey: merkleTree, isSigner: false, isWritable: true },
+        { pubkey: NOOP_PROGRAM_ID, isSigner: false, isWritable: false },
+        { pubkey: ACCOUNT_COMPRESSION_PROGRAM_ID, isSigner: false, isWritable: false },
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
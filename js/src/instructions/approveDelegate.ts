@@ -0,0 +1,58 @@
+import { PublicKey, SystemProgram, TransactionInstruction } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import { findDelegateAllowanceAddress, findEscrowAddress, publicKey, u64 } from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+    delegate: PublicKey;
+    limit: bigint;
+    expirySlot: bigint;
+}
+
+const DataLayout = struct<Data>([u8('instruction'), publicKey('delegate'), u64('limit'), u64('expirySlot')]);
+
+export const createApproveDelegateInstruction = async (
+    delegate: PublicKey,
+    limit: number | bigint,
+    expirySlot: number | bigint,
+    owner: PublicKey
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [delegateAllowance] = await findDelegateAllowanceAddress(escrow, delegate);
+    return approveDelegate(delegate, limit, expirySlot, escrow, owner, delegateAllowance);
+};
+
+export const approveDelegate = (
+    delegate: PublicKey,
+    limit: number | bigint,
+    expirySlot: number | bigint,
+    escrow: PublicKey,
+    owner: PublicKey,
+    delegateAllowance: PublicKey
+): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.ApproveDelegate,
+            delegate,
+            limit: BigInt(limit),
+            expirySlot: BigInt(expirySlot),
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: escrow, isSigner: false, isWritable: false },
+        { pubkey: owner, isSigner: true, isWritable: true },
+        { pubkey: delegateAllowance, isSigner: false, isWritable: true },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
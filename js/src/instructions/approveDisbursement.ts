@@ -0,0 +1,46 @@
+import { PublicKey, TransactionInstruction } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import { findEscrowAddress, findProposalAddress } from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+}
+
+const DataLayout = struct<Data>([u8('instruction')]);
+
+export const createApproveDisbursementInstruction = async (
+    approver: PublicKey,
+    proposalId: number | bigint
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [proposal] = await findProposalAddress(escrow, proposalId);
+    return approveDisbursement(approver, escrow, proposal);
+};
+
+export const approveDisbursement = (
+    approver: PublicKey,
+    escrow: PublicKey,
+    proposal: PublicKey
+): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.ApproveDisbursement,
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: approver, isSigner: true, isWritable: false },
+        { pubkey: escrow, isSigner: false, isWritable: false },
+        { pubkey: proposal, isSigner: false, isWritable: true },
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
@@ -0,0 +1,49 @@
+import { PublicKey, TransactionInstruction } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import { findEscrowAddress, findRecoveryAddress, publicKey } from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+    newOwner: PublicKey;
+}
+
+const DataLayout = struct<Data>([u8('instruction'), publicKey('newOwner')]);
+
+export const createApproveRecoveryInstruction = async (
+    newOwner: PublicKey,
+    guardian: PublicKey
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [recovery] = await findRecoveryAddress(escrow);
+    return approveRecovery(newOwner, guardian, escrow, recovery);
+};
+
+export const approveRecovery = (
+    newOwner: PublicKey,
+    guardian: PublicKey,
+    escrow: PublicKey,
+    recovery: PublicKey
+): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.ApproveRecovery,
+            newOwner,
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: guardian, isSigner: true, isWritable: false },
+        { pubkey: escrow, isSigner: false, isWritable: false },
+        { pubkey: recovery, isSigner: false, isWritable: true },
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
@@ -0,0 +1,56 @@
+import { TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { PublicKey, TransactionInstruction } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import { findEscrowAddress, findTreasuryAddress, findTreasuryAssociatedTokenAddress, u64 } from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+    amount: bigint;
+}
+
+const DataLayout = struct<Data>([u8('instruction'), u64('amount')]);
+
+export const createBurnTreasuryInstruction = async (
+    amount: number | bigint,
+    owner: PublicKey
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [treasury] = await findTreasuryAddress(escrow);
+    const [treasuryAssociatedToken] = await findTreasuryAssociatedTokenAddress(treasury, RNDR_TOKEN_MINT);
+    return burnTreasury(amount, RNDR_TOKEN_MINT, owner, escrow, treasury, treasuryAssociatedToken);
+};
+
+export const burnTreasury = (
+    amount: number | bigint,
+    tokenMint: PublicKey,
+    owner: PublicKey,
+    escrow: PublicKey,
+    treasury: PublicKey,
+    treasuryAssociatedToken: PublicKey
+): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.BurnTreasury,
+            amount: BigInt(amount),
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: tokenMint, isSigner: false, isWritable: true },
+        { pubkey: owner, isSigner: true, isWritable: false },
+        { pubkey: escrow, isSigner: false, isWritable: false },
+        { pubkey: treasury, isSigner: false, isWritable: true },
+        { pubkey: treasuryAssociatedToken, isSigner: false, isWritable: true },
+        { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
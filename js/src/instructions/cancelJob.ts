@@ -0,0 +1,103 @@
+import { TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { AccountMeta, PublicKey, SystemProgram, TransactionInstruction } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import {
+    findConfigAddress,
+    findEscrowAddress,
+    findEscrowAssociatedTokenAddress,
+    findEscrowShardAddress,
+    findEscrowShardIndex,
+    findJobAddress,
+    findTreasuryAddress,
+    findTreasuryAssociatedTokenAddress,
+} from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+}
+
+const DataLayout = struct<Data>([u8('instruction')]);
+
+export const createCancelJobInstruction = async (
+    authority: PublicKey,
+    jobId: number | bigint,
+    destinationToken: PublicKey,
+    nodeToken?: PublicKey
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [escrowAssociatedToken] = await findEscrowAssociatedTokenAddress(escrow, RNDR_TOKEN_MINT);
+    const [job] = await findJobAddress(escrow, authority, jobId);
+    const [config] = await findConfigAddress();
+    const [escrowShard] = await findEscrowShardAddress(escrow, findEscrowShardIndex(job));
+    if (!nodeToken) {
+        return cancelJob(
+            authority,
+            escrow,
+            escrowAssociatedToken,
+            job,
+            destinationToken,
+            config,
+            undefined,
+            escrowShard
+        );
+    }
+    const [treasury] = await findTreasuryAddress(escrow);
+    const [treasuryAssociatedToken] = await findTreasuryAssociatedTokenAddress(treasury, RNDR_TOKEN_MINT);
+    const fee = { nodeToken, treasury, treasuryAssociatedToken, tokenMint: RNDR_TOKEN_MINT };
+    return cancelJob(authority, escrow, escrowAssociatedToken, job, destinationToken, config, fee, escrowShard);
+};
+
+/** Accounts of a committed Job's cancellation fee and of the remainder of its split */
+export interface CancellationFeeAccounts {
+    nodeToken: PublicKey;
+    treasury: PublicKey;
+    treasuryAssociatedToken: PublicKey;
+    tokenMint: PublicKey;
+}
+
+export const cancelJob = (
+    authority: PublicKey,
+    escrow: PublicKey,
+    escrowAssociatedToken: PublicKey,
+    job: PublicKey,
+    destinationToken: PublicKey,
+    config: PublicKey,
+    fee?: CancellationFeeAccounts,
+    escrowShard?: PublicKey
+): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.CancelJob,
+        },
+        data
+    );
+
+    const keys: AccountMeta[] = [
+        { pubkey: authority, isSigner: true, isWritable: true },
+        { pubkey: escrow, isSigner: false, isWritable: true },
+        { pubkey: escrowAssociatedToken, isSigner: false, isWritable: true },
+        { pubkey: job, isSigner: false, isWritable: true },
+        { pubkey: destinationToken, isSigner: false, isWritable: true },
+        { pubkey: config, isSigner: false, isWritable: false },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+        { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+    ];
+    if (fee) {
+        keys.push(
+            { pubkey: fee.nodeToken, isSigner: false, isWritable: true },
+            { pubkey: fee.treasury, isSigner: false, isWritable: false },
+            { pubkey: fee.treasuryAssociatedToken, isSigner: false, isWritable: true },
+            { pubkey: fee.tokenMint, isSigner: false, isWritable: true }
+        );
+    }
+    if (escrowShard) keys.push({ pubkey: escrowShard, isSigner: false, isWritable: true });
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
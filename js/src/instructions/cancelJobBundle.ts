@@ -0,0 +1,74 @@
+import { TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { PublicKey, SystemProgram, TransactionInstruction } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import {
+    findEscrowAddress,
+    findEscrowAssociatedTokenAddress,
+    findEscrowShardAddress,
+    findEscrowShardIndex,
+    findJobBundleAddress,
+} from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+}
+
+const DataLayout = struct<Data>([u8('instruction')]);
+
+/** Every job passed must still be open, jobs a node has committed to are cancelled with `cancelJob` */
+export const createCancelJobBundleInstruction = async (
+    authority: PublicKey,
+    bundleId: number | bigint,
+    destinationToken: PublicKey,
+    jobs: PublicKey[]
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [escrowAssociatedToken] = await findEscrowAssociatedTokenAddress(escrow, RNDR_TOKEN_MINT);
+    const [jobBundle] = await findJobBundleAddress(escrow, authority, bundleId);
+    const indexes = [...new Set(jobs.map(findEscrowShardIndex))];
+    const escrowShards = await Promise.all(
+        indexes.map(async (index) => {
+            const [escrowShard] = await findEscrowShardAddress(escrow, index);
+            return escrowShard;
+        })
+    );
+    return cancelJobBundle(authority, escrow, escrowAssociatedToken, jobBundle, destinationToken, jobs, escrowShards);
+};
+
+export const cancelJobBundle = (
+    authority: PublicKey,
+    escrow: PublicKey,
+    escrowAssociatedToken: PublicKey,
+    jobBundle: PublicKey,
+    destinationToken: PublicKey,
+    jobs: PublicKey[],
+    escrowShards: PublicKey[] = []
+): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.CancelJobBundle,
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: authority, isSigner: true, isWritable: true },
+        { pubkey: escrow, isSigner: false, isWritable: true },
+        { pubkey: escrowAssociatedToken, isSigner: false, isWritable: true },
+        { pubkey: jobBundle, isSigner: false, isWritable: true },
+        { pubkey: destinationToken, isSigner: false, isWritable: true },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+        { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+        ...jobs.map((job) => ({ pubkey: job, isSigner: false, isWritable: true })),
+        ...escrowShards.map((escrowShard) => ({ pubkey: escrowShard, isSigner: false, isWritable: true })),
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
@@ -0,0 +1,47 @@
+import { PublicKey, TransactionInstruction } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID } from '../constants';
+import { findConfigAddress, findQueuedActionAddress } from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+}
+
+const DataLayout = struct<Data>([u8('instruction')]);
+
+export const createCancelQueuedActionInstruction = async (
+    guardian: PublicKey,
+    target: PublicKey,
+    actionId: number | bigint
+): Promise<TransactionInstruction> => {
+    const [config] = await findConfigAddress();
+    const [queuedAction] = await findQueuedActionAddress(target, actionId);
+    return cancelQueuedAction(guardian, config, queuedAction);
+};
+
+export const cancelQueuedAction = (
+    guardian: PublicKey,
+    config: PublicKey,
+    queuedAction: PublicKey
+): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.CancelQueuedAction,
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: guardian, isSigner: true, isWritable: false },
+        { pubkey: config, isSigner: false, isWritable: false },
+        { pubkey: queuedAction, isSigner: false, isWritable: true },
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
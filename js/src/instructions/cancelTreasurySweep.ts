@@ -0,0 +1,50 @@
+import { PublicKey, TransactionInstruction } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import { findConfigAddress, findEscrowAddress, findTreasuryAddress, findTreasuryAssociatedTokenAddress } from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+}
+
+const DataLayout = struct<Data>([u8('instruction')]);
+
+/** Cancel the scheduled sweep of the treasury before it can be executed */
+export const createCancelTreasurySweepInstruction = async (guardian: PublicKey): Promise<TransactionInstruction> => {
+    const [config] = await findConfigAddress();
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [treasury] = await findTreasuryAddress(escrow);
+    const [treasuryAssociatedToken] = await findTreasuryAssociatedTokenAddress(treasury, RNDR_TOKEN_MINT);
+    return cancelTreasurySweep(guardian, config, escrow, treasury, treasuryAssociatedToken);
+};
+
+export const cancelTreasurySweep = (
+    guardian: PublicKey,
+    config: PublicKey,
+    escrow: PublicKey,
+    treasury: PublicKey,
+    treasuryAssociatedToken: PublicKey
+): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.CancelTreasurySweep,
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: guardian, isSigner: true, isWritable: false },
+        { pubkey: config, isSigner: false, isWritable: false },
+        { pubkey: escrow, isSigner: false, isWritable: false },
+        { pubkey: treasury, isSigner: false, isWritable: true },
+        { pubkey: treasuryAssociatedToken, isSigner: false, isWritable: false },
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
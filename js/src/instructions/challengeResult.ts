@@ -0,0 +1,52 @@
+import { PublicKey, TransactionInstruction } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import { findEscrowAddress, findJobAddress, findSessionKeyAddress } from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+}
+
+const DataLayout = struct<Data>([u8('instruction')]);
+
+/** Challenge with a session key of the authority instead if `sessionKey` is provided */
+export const createChallengeResultInstruction = async (
+    authority: PublicKey,
+    jobId: number | bigint,
+    sessionKey?: PublicKey
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [job] = await findJobAddress(escrow, authority, jobId);
+    if (!sessionKey) return challengeResult(authority, escrow, job);
+    const [sessionKeyAccount] = await findSessionKeyAddress(escrow, authority, sessionKey);
+    return challengeResult(sessionKey, escrow, job, sessionKeyAccount);
+};
+
+export const challengeResult = (
+    authority: PublicKey,
+    escrow: PublicKey,
+    job: PublicKey,
+    sessionKeyAccount?: PublicKey
+): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.ChallengeResult,
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: authority, isSigner: true, isWritable: false },
+        { pubkey: escrow, isSigner: false, isWritable: false },
+        { pubkey: job, isSigner: false, isWritable: true },
+    ];
+    if (sessionKeyAccount) keys.push({ pubkey: sessionKeyAccount, isSigner: false, isWritable: false });
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
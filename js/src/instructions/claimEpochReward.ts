@@ -0,0 +1,60 @@
+import { TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { PublicKey, TransactionInstruction } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import {
+    findEpochWorkAddress,
+    findEscrowAddress,
+    findRewardPoolAddress,
+    findRewardPoolAssociatedTokenAddress,
+} from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+}
+
+const DataLayout = struct<Data>([u8('instruction')]);
+
+export const createClaimEpochRewardInstruction = async (
+    node: PublicKey,
+    destinationToken: PublicKey,
+    epoch: number | bigint
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [rewardPool] = await findRewardPoolAddress(escrow, epoch);
+    const [rewardPoolAssociatedToken] = await findRewardPoolAssociatedTokenAddress(rewardPool, RNDR_TOKEN_MINT);
+    const [epochWork] = await findEpochWorkAddress(rewardPool, node);
+    return claimEpochReward(node, rewardPool, rewardPoolAssociatedToken, epochWork, destinationToken);
+};
+
+export const claimEpochReward = (
+    node: PublicKey,
+    rewardPool: PublicKey,
+    rewardPoolAssociatedToken: PublicKey,
+    epochWork: PublicKey,
+    destinationToken: PublicKey
+): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.ClaimEpochReward,
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: node, isSigner: true, isWritable: false },
+        { pubkey: rewardPool, isSigner: false, isWritable: true },
+        { pubkey: rewardPoolAssociatedToken, isSigner: false, isWritable: true },
+        { pubkey: epochWork, isSigner: false, isWritable: true },
+        { pubkey: destinationToken, isSigner: false, isWritable: true },
+        { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
@@ -0,0 +1,97 @@
+import { TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { PublicKey, SystemProgram, TransactionInstruction } from '@solana/web3.js';
+import { blob, seq, struct, u32, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import { claimBitmapChunk, MAX_MERKLE_PROOF_LEN } from '../state';
+import {
+    findClaimBitmapAddress,
+    findEscrowAddress,
+    findMerkleDistributionAddress,
+    findMerkleDistributionAssociatedTokenAddress,
+    u64,
+} from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+    index: number;
+    amount: bigint;
+    length: number;
+    proof: Uint8Array[];
+}
+
+export const createClaimMerkleRewardInstruction = async (
+    epoch: number | bigint,
+    index: number,
+    amount: number | bigint,
+    proof: Uint8Array[],
+    node: PublicKey,
+    destinationToken: PublicKey
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [merkleDistribution] = await findMerkleDistributionAddress(escrow, epoch);
+    const [merkleDistributionAssociatedToken] = await findMerkleDistributionAssociatedTokenAddress(
+        merkleDistribution,
+        RNDR_TOKEN_MINT
+    );
+    const [claimBitmap] = await findClaimBitmapAddress(merkleDistribution, claimBitmapChunk(index));
+    return claimMerkleReward(
+        index,
+        amount,
+        proof,
+        node,
+        merkleDistribution,
+        merkleDistributionAssociatedToken,
+        claimBitmap,
+        destinationToken
+    );
+};
+
+export const claimMerkleReward = (
+    index: number,
+    amount: number | bigint,
+    proof: Uint8Array[],
+    node: PublicKey,
+    merkleDistribution: PublicKey,
+    merkleDistributionAssociatedToken: PublicKey,
+    claimBitmap: PublicKey,
+    destinationToken: PublicKey
+): TransactionInstruction => {
+    if (proof.length > MAX_MERKLE_PROOF_LEN) throw new Error('Merkle proof is too long');
+
+    const DataLayout = struct<Data>([
+        u8('instruction'),
+        u32('index'),
+        u64('amount'),
+        u8('length'),
+        seq(blob(32), proof.length, 'proof'),
+    ]);
+
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.ClaimMerkleReward,
+            index,
+            amount: BigInt(amount),
+            length: proof.length,
+            proof,
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: node, isSigner: true, isWritable: true },
+        { pubkey: merkleDistribution, isSigner: false, isWritable: true },
+        { pubkey: merkleDistributionAssociatedToken, isSigner: false, isWritable: true },
+        { pubkey: claimBitmap, isSigner: false, isWritable: true },
+        { pubkey: destinationToken, isSigner: false, isWritable: true },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+        { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
@@ -0,0 +1,109 @@
+import { TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { AccountMeta, PublicKey, SystemProgram, TransactionInstruction } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import {
+    findConfigAddress,
+    findEpochWorkAddress,
+    findEscrowAddress,
+    findEscrowAssociatedTokenAddress,
+    findEscrowShardAddress,
+    findEscrowShardIndex,
+    findJobAddress,
+    findNodeAddress,
+    findRewardPoolAddress,
+} from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+}
+
+const DataLayout = struct<Data>([u8('instruction')]);
+
+export const createClaimPaymentInstruction = async (
+    node: PublicKey,
+    destinationToken: PublicKey,
+    authority: PublicKey,
+    jobId: number | bigint,
+    epoch?: number | bigint
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [escrowAssociatedToken] = await findEscrowAssociatedTokenAddress(escrow, RNDR_TOKEN_MINT);
+    const [job] = await findJobAddress(escrow, authority, jobId);
+    const [config] = await findConfigAddress();
+    const [nodeAccount] = await findNodeAddress(escrow, node);
+    const [escrowShard] = await findEscrowShardAddress(escrow, findEscrowShardIndex(job));
+    if (epoch === undefined) {
+        return claimPayment(
+            node,
+            escrow,
+            escrowAssociatedToken,
+            job,
+            destinationToken,
+            config,
+            nodeAccount,
+            escrowShard
+        );
+    }
+    const [rewardPool] = await findRewardPoolAddress(escrow, epoch);
+    const [epochWork] = await findEpochWorkAddress(rewardPool, node);
+    return claimPayment(
+        node,
+        escrow,
+        escrowAssociatedToken,
+        job,
+        destinationToken,
+        config,
+        nodeAccount,
+        escrowShard,
+        rewardPool,
+        epochWork
+    );
+};
+
+export const claimPayment = (
+    node: PublicKey,
+    escrow: PublicKey,
+    escrowAssociatedToken: PublicKey,
+    job: PublicKey,
+    destinationToken: PublicKey,
+    config: PublicKey,
+    nodeAccount: PublicKey,
+    escrowShard?: PublicKey,
+    rewardPool?: PublicKey,
+    epochWork?: PublicKey
+): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.ClaimPayment,
+        },
+        data
+    );
+
+    const keys: AccountMeta[] = [
+        { pubkey: node, isSigner: true, isWritable: !!(rewardPool && epochWork) },
+        { pubkey: escrow, isSigner: false, isWritable: true },
+        { pubkey: escrowAssociatedToken, isSigner: false, isWritable: true },
+        { pubkey: job, isSigner: false, isWritable: true },
+        { pubkey: destinationToken, isSigner: false, isWritable: true },
+        { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+        { pubkey: config, isSigner: false, isWritable: false },
+        { pubkey: nodeAccount, isSigner: false, isWritable: true },
+    ];
+    if (rewardPool && epochWork) {
+        keys.push(
+            { pubkey: rewardPool, isSigner: false, isWritable: true },
+            { pubkey: epochWork, isSigner: false, isWritable: true },
+            { pubkey: SystemProgram.programId, isSigner: false, isWritable: false }
+        );
+    }
+    if (escrowShard) keys.push({ pubkey: escrowShard, isSigner: false, isWritable: true });
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
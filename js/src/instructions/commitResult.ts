@@ -0,0 +1,40 @@
+import { PublicKey, SystemProgram, TransactionInstruction } from '@solana/web3.js';
+import { blob, struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID } from '../constants';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+    commitment: Uint8Array;
+}
+
+const DataLayout = struct<Data>([u8('instruction'), blob(32, 'commitment')]);
+
+export const commitResult = (
+    commitment: Uint8Array,
+    node: PublicKey,
+    job: PublicKey,
+    config: PublicKey
+): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.CommitResult,
+            commitment,
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: node, isSigner: true, isWritable: true },
+        { pubkey: job, isSigner: false, isWritable: true },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+        { pubkey: config, isSigner: false, isWritable: false },
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
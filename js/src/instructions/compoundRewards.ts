@@ -0,0 +1,72 @@
+import { TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { PublicKey, TransactionInstruction } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import {
+    findEpochWorkAddress,
+    findEscrowAddress,
+    findNodeAddress,
+    findNodeAssociatedTokenAddress,
+    findRewardPoolAddress,
+    findRewardPoolAssociatedTokenAddress,
+} from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+}
+
+const DataLayout = struct<Data>([u8('instruction')]);
+
+export const createCompoundRewardsInstruction = async (
+    nodeAuthority: PublicKey,
+    epoch: number | bigint
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [rewardPool] = await findRewardPoolAddress(escrow, epoch);
+    const [rewardPoolAssociatedToken] = await findRewardPoolAssociatedTokenAddress(rewardPool, RNDR_TOKEN_MINT);
+    const [epochWork] = await findEpochWorkAddress(rewardPool, nodeAuthority);
+    const [node] = await findNodeAddress(escrow, nodeAuthority);
+    const [nodeAssociatedToken] = await findNodeAssociatedTokenAddress(node, RNDR_TOKEN_MINT);
+    return compoundRewards(
+        RNDR_TOKEN_MINT,
+        rewardPool,
+        rewardPoolAssociatedToken,
+        epochWork,
+        node,
+        nodeAssociatedToken
+    );
+};
+
+export const compoundRewards = (
+    tokenMint: PublicKey,
+    rewardPool: PublicKey,
+    rewardPoolAssociatedToken: PublicKey,
+    epochWork: PublicKey,
+    node: PublicKey,
+    nodeAssociatedToken: PublicKey
+): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.CompoundRewards,
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: tokenMint, isSigner: false, isWritable: false },
+        { pubkey: rewardPool, isSigner: false, isWritable: true },
+        { pubkey: rewardPoolAssociatedToken, isSigner: false, isWritable: true },
+        { pubkey: epochWork, isSigner: false, isWritable: true },
+        { pubkey: node, isSigner: false, isWritable: true },
+        { pubkey: nodeAssociatedToken, isSigner: false, isWritable: true },
+        { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
@@ -0,0 +1,86 @@
+import { TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { PublicKey, SystemProgram, TransactionInstruction } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import {
+    ESCROW_SHARD_COUNT,
+    findAssociatedTokenAddress,
+    findEscrowAddress,
+    findEscrowShardAddress,
+    findMintMigrationAddress,
+} from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+}
+
+const DataLayout = struct<Data>([u8('instruction')]);
+
+/**
+ * `tokenAccount` is the token account currently holding the escrow's pooled tokens, `sourceToken` the owner's token
+ * account of the new mint paying the converted amount and `destinationToken` the token account receiving the old
+ * tokens
+ */
+export const createConvertEscrowMintVaultInstruction = async (
+    owner: PublicKey,
+    index: number,
+    tokenAccount: PublicKey,
+    newTokenMint: PublicKey,
+    sourceToken: PublicKey,
+    destinationToken: PublicKey
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [mintMigration] = await findMintMigrationAddress(escrow, index);
+    const [newEscrowAssociatedToken] = await findAssociatedTokenAddress(escrow, newTokenMint);
+    const escrowShards = [];
+    for (let shard = 0; shard < ESCROW_SHARD_COUNT; shard++) {
+        const [escrowShard] = await findEscrowShardAddress(escrow, shard);
+        escrowShards.push(escrowShard);
+    }
+    return convertEscrowMintVault(
+        escrow,
+        owner,
+        mintMigration,
+        tokenAccount,
+        newEscrowAssociatedToken,
+        sourceToken,
+        destinationToken,
+        escrowShards
+    );
+};
+
+export const convertEscrowMintVault = (
+    escrow: PublicKey,
+    owner: PublicKey,
+    mintMigration: PublicKey,
+    tokenAccount: PublicKey,
+    newEscrowAssociatedToken: PublicKey,
+    sourceToken: PublicKey,
+    destinationToken: PublicKey,
+    escrowShards: PublicKey[]
+): TransactionInstruction => {
+    if (escrowShards.length !== ESCROW_SHARD_COUNT) throw new Error('Every escrow shard is required');
+
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode({ instruction: RNDRInstruction.ConvertEscrowMintVault }, data);
+
+    const keys = [
+        { pubkey: escrow, isSigner: false, isWritable: true },
+        { pubkey: owner, isSigner: true, isWritable: true },
+        { pubkey: mintMigration, isSigner: false, isWritable: true },
+        { pubkey: tokenAccount, isSigner: false, isWritable: true },
+        { pubkey: newEscrowAssociatedToken, isSigner: false, isWritable: true },
+        { pubkey: sourceToken, isSigner: false, isWritable: true },
+        { pubkey: destinationToken, isSigner: false, isWritable: true },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+        { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+        ...escrowShards.map((pubkey) => ({ pubkey, isSigner: false, isWritable: true })),
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
@@ -0,0 +1,78 @@
+import { ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { PublicKey, SystemProgram, TransactionInstruction } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import { findEscrowAddress, findJobAssociatedTokenAddress, findMintMigrationAddress } from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+}
+
+const DataLayout = struct<Data>([u8('instruction')]);
+
+/**
+ * `previousTokenMint` is the token mint the escrow is migrated from, `sourceToken` the owner's token account of the
+ * new mint paying the converted amount and `destinationToken` the token account receiving the old tokens
+ */
+export const createConvertIsolatedJobMintInstruction = async (
+    owner: PublicKey,
+    index: number,
+    job: PublicKey,
+    previousTokenMint: PublicKey,
+    newTokenMint: PublicKey,
+    sourceToken: PublicKey,
+    destinationToken: PublicKey
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [mintMigration] = await findMintMigrationAddress(escrow, index);
+    const [jobAssociatedToken] = await findJobAssociatedTokenAddress(job, previousTokenMint);
+    const [newJobAssociatedToken] = await findJobAssociatedTokenAddress(job, newTokenMint);
+    return convertIsolatedJobMint(
+        escrow,
+        owner,
+        mintMigration,
+        job,
+        jobAssociatedToken,
+        newJobAssociatedToken,
+        newTokenMint,
+        sourceToken,
+        destinationToken
+    );
+};
+
+export const convertIsolatedJobMint = (
+    escrow: PublicKey,
+    owner: PublicKey,
+    mintMigration: PublicKey,
+    job: PublicKey,
+    jobAssociatedToken: PublicKey,
+    newJobAssociatedToken: PublicKey,
+    newTokenMint: PublicKey,
+    sourceToken: PublicKey,
+    destinationToken: PublicKey
+): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode({ instruction: RNDRInstruction.ConvertIsolatedJobMint }, data);
+
+    const keys = [
+        { pubkey: escrow, isSigner: false, isWritable: false },
+        { pubkey: owner, isSigner: true, isWritable: true },
+        { pubkey: mintMigration, isSigner: false, isWritable: true },
+        { pubkey: job, isSigner: false, isWritable: true },
+        { pubkey: jobAssociatedToken, isSigner: false, isWritable: true },
+        { pubkey: newJobAssociatedToken, isSigner: false, isWritable: true },
+        { pubkey: newTokenMint, isSigner: false, isWritable: false },
+        { pubkey: sourceToken, isSigner: false, isWritable: true },
+        { pubkey: destinationToken, isSigner: false, isWritable: true },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+        { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+        { pubkey: ASSOCIATED_TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
@@ -0,0 +1,68 @@
+import { PublicKey, SystemProgram, TransactionInstruction } from '@solana/web3.js';
+import { blob, struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import { findEscrowAddress, findJobBundleAddress, u64 } from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+    bundleId: bigint;
+    firstJobId: bigint;
+    jobCount: number;
+    metadataHash: Uint8Array;
+}
+
+const DataLayout = struct<Data>([
+    u8('instruction'),
+    u64('bundleId'),
+    u64('firstJobId'),
+    u8('jobCount'),
+    blob(32, 'metadataHash'),
+]);
+
+export const createCreateJobBundleInstruction = async (
+    bundleId: number | bigint,
+    firstJobId: number | bigint,
+    jobCount: number,
+    metadataHash: Uint8Array,
+    authority: PublicKey
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [jobBundle] = await findJobBundleAddress(escrow, authority, bundleId);
+    return createJobBundle(bundleId, firstJobId, jobCount, metadataHash, authority, escrow, jobBundle);
+};
+
+export const createJobBundle = (
+    bundleId: number | bigint,
+    firstJobId: number | bigint,
+    jobCount: number,
+    metadataHash: Uint8Array,
+    authority: PublicKey,
+    escrow: PublicKey,
+    jobBundle: PublicKey
+): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.CreateJobBundle,
+            bundleId: BigInt(bundleId),
+            firstJobId: BigInt(firstJobId),
+            jobCount,
+            metadataHash,
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: authority, isSigner: true, isWritable: true },
+        { pubkey: escrow, isSigner: false, isWritable: false },
+        { pubkey: jobBundle, isSigner: false, isWritable: true },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
@@ -0,0 +1,62 @@
+import { PublicKey, SystemProgram, TransactionInstruction } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import { findEscrowAddress, findSubscriptionAddress, u64 } from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+    jobId: bigint;
+    amount: bigint;
+    period: bigint;
+}
+
+const DataLayout = struct<Data>([u8('instruction'), u64('jobId'), u64('amount'), u64('period')]);
+
+/** The authority must also approve the subscription as the delegate of the source token account */
+export const createCreateSubscriptionInstruction = async (
+    jobId: number | bigint,
+    amount: number | bigint,
+    period: number | bigint,
+    authority: PublicKey,
+    sourceToken: PublicKey
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [subscription] = await findSubscriptionAddress(escrow, authority, jobId);
+    return createSubscriptionInstruction(jobId, amount, period, authority, escrow, sourceToken, subscription);
+};
+
+export const createSubscriptionInstruction = (
+    jobId: number | bigint,
+    amount: number | bigint,
+    period: number | bigint,
+    authority: PublicKey,
+    escrow: PublicKey,
+    sourceToken: PublicKey,
+    subscription: PublicKey
+): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.CreateSubscription,
+            jobId: BigInt(jobId),
+            amount: BigInt(amount),
+            period: BigInt(period),
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: authority, isSigner: true, isWritable: true },
+        { pubkey: escrow, isSigner: false, isWritable: false },
+        { pubkey: sourceToken, isSigner: false, isWritable: false },
+        { pubkey: subscription, isSigner: false, isWritable: true },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
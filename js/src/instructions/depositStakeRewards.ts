@@ -0,0 +1,55 @@
+import { TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { PublicKey, TransactionInstruction } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import { findEscrowAddress, findStakePoolAddress, findStakePoolAssociatedTokenAddress, u64 } from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+    amount: bigint;
+}
+
+const DataLayout = struct<Data>([u8('instruction'), u64('amount')]);
+
+export const createDepositStakeRewardsInstruction = async (
+    amount: number | bigint,
+    sourceToken: PublicKey,
+    authority: PublicKey
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [stakePool] = await findStakePoolAddress(escrow);
+    const [stakePoolAssociatedToken] = await findStakePoolAssociatedTokenAddress(stakePool, RNDR_TOKEN_MINT);
+    return depositStakeRewards(amount, sourceToken, authority, stakePool, stakePoolAssociatedToken);
+};
+
+export const depositStakeRewards = (
+    amount: number | bigint,
+    sourceToken: PublicKey,
+    authority: PublicKey,
+    stakePool: PublicKey,
+    stakePoolAssociatedToken: PublicKey
+): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.DepositStakeRewards,
+            amount: BigInt(amount),
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: sourceToken, isSigner: false, isWritable: true },
+        { pubkey: authority, isSigner: true, isWritable: false },
+        { pubkey: stakePool, isSigner: false, isWritable: true },
+        { pubkey: stakePoolAssociatedToken, isSigner: false, isWritable: true },
+        { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
@@ -0,0 +1,82 @@
+import { TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { PublicKey, TransactionInstruction } from '@solana/web3.js';
+import { seq, struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import {
+    findEscrowAddress,
+    findEscrowAssociatedTokenAddress,
+    findEscrowShardAddress,
+    findEscrowShardIndex,
+    findJobAddress,
+    u64,
+} from '../util';
+import { RNDRInstruction } from './instruction';
+
+export const MAX_DISBURSE_BATCH_LEN = 64;
+
+export interface Disbursement {
+    destinationToken: PublicKey;
+    amount: number | bigint;
+}
+
+interface Data {
+    instruction: number;
+    length: number;
+    amounts: bigint[];
+}
+
+export const createDisburseBatchInstruction = async (
+    owner: PublicKey,
+    authority: PublicKey,
+    jobId: number | bigint,
+    disbursements: Disbursement[]
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [escrowAssociatedToken] = await findEscrowAssociatedTokenAddress(escrow, RNDR_TOKEN_MINT);
+    const [job] = await findJobAddress(escrow, authority, jobId);
+    const [escrowShard] = await findEscrowShardAddress(escrow, findEscrowShardIndex(job));
+    return disburseBatch(escrow, owner, escrowAssociatedToken, job, disbursements, escrowShard);
+};
+
+export const disburseBatch = (
+    escrow: PublicKey,
+    owner: PublicKey,
+    escrowAssociatedToken: PublicKey,
+    job: PublicKey,
+    disbursements: Disbursement[],
+    escrowShard?: PublicKey
+): TransactionInstruction => {
+    if (disbursements.length > MAX_DISBURSE_BATCH_LEN) throw new Error('Too many disbursements');
+
+    const DataLayout = struct<Data>([u8('instruction'), u8('length'), seq(u64(), disbursements.length, 'amounts')]);
+
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.DisburseBatch,
+            length: disbursements.length,
+            amounts: disbursements.map(({ amount }) => BigInt(amount)),
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: escrow, isSigner: false, isWritable: true },
+        { pubkey: owner, isSigner: true, isWritable: false },
+        { pubkey: escrowAssociatedToken, isSigner: false, isWritable: true },
+        { pubkey: job, isSigner: false, isWritable: true },
+        { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+        ...disbursements.map(({ destinationToken }) => ({
+            pubkey: destinationToken,
+            isSigner: false,
+            isWritable: true,
+        })),
+    ];
+    if (escrowShard) keys.push({ pubkey: escrowShard, isSigner: false, isWritable: true });
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
@@ -2,7 +2,15 @@ import { TOKEN_PROGRAM_ID } from '@solana/spl-token';
 import { PublicKey, TransactionInstruction } from '@solana/web3.js';
 import { struct, u8 } from 'buffer-layout';
 import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
-import { findEscrowAddress, findEscrowAssociatedTokenAddress, findJobAddress, u64 } from '../util';
+import {
+    findDelegateAllowanceAddress,
+    findEscrowAddress,
+    findEscrowAssociatedTokenAddress,
+    findEscrowShardAddress,
+    findEscrowShardIndex,
+    findJobAddress,
+    u64,
+} from '../util';
 import { RNDRInstruction } from './instruction';
 
 interface Data {
@@ -12,26 +20,41 @@ interface Data {
 
 const DataLayout = struct<Data>([u8('instruction'), u64('amount')]);
 
+/** If `asDelegate` is set, `owner` is a delegate of the owner disbursing within its allowance */
 export const createDisburseFundsInstruction = async (
     amount: number | bigint,
     owner: PublicKey,
     destinationToken: PublicKey,
-    authority: PublicKey
+    authority: PublicKey,
+    jobId: number | bigint,
+    asDelegate = false
 ): Promise<TransactionInstruction> => {
     const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
     const [escrowAssociatedToken] = await findEscrowAssociatedTokenAddress(escrow, RNDR_TOKEN_MINT);
-    const [job] = await findJobAddress(escrow, authority);
-    return disburseFunds(amount, RNDR_TOKEN_MINT, escrow, owner, escrowAssociatedToken, job, destinationToken);
+    const [job] = await findJobAddress(escrow, authority, jobId);
+    const [delegateAllowance] = asDelegate ? await findDelegateAllowanceAddress(escrow, owner) : [undefined];
+    const [escrowShard] = await findEscrowShardAddress(escrow, findEscrowShardIndex(job));
+    return disburseFunds(
+        amount,
+        escrow,
+        owner,
+        escrowAssociatedToken,
+        job,
+        destinationToken,
+        delegateAllowance,
+        escrowShard
+    );
 };
 
 export const disburseFunds = (
     amount: number | bigint,
-    tokenMint: PublicKey,
     escrow: PublicKey,
     owner: PublicKey,
     escrowAssociatedToken: PublicKey,
     job: PublicKey,
-    destinationToken: PublicKey
+    destinationToken: PublicKey,
+    delegateAllowance?: PublicKey,
+    escrowShard?: PublicKey
 ): TransactionInstruction => {
     const data = Buffer.alloc(DataLayout.span);
     DataLayout.encode(
@@ -43,7 +66,6 @@ export const disburseFunds = (
     );
 
     const keys = [
-        { pubkey: tokenMint, isSigner: false, isWritable: false },
         { pubkey: escrow, isSigner: false, isWritable: true },
         { pubkey: owner, isSigner: true, isWritable: false },
         { pubkey: escrowAssociatedToken, isSigner: false, isWritable: true },
@@ -51,6 +73,8 @@ export const disburseFunds = (
         { pubkey: destinationToken, isSigner: false, isWritable: true },
         { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
     ];
+    if (delegateAllowance) keys.push({ pubkey: delegateAllowance, isSigner: false, isWritable: true });
+    if (escrowShard) keys.push({ pubkey: escrowShard, isSigner: false, isWritable: true });
 
     return new TransactionInstruction({
         keys,
@@ -0,0 +1,59 @@
+import { TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { PublicKey, TransactionInstruction } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import { findEscrowAddress, findJobAddress, findJobAssociatedTokenAddress, u64 } from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+    amount: bigint;
+}
+
+const DataLayout = struct<Data>([u8('instruction'), u64('amount')]);
+
+export const createDisburseIsolatedJobFundsInstruction = async (
+    amount: number | bigint,
+    owner: PublicKey,
+    destinationToken: PublicKey,
+    authority: PublicKey,
+    jobId: number | bigint
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [job] = await findJobAddress(escrow, authority, jobId);
+    const [jobAssociatedToken] = await findJobAssociatedTokenAddress(job, RNDR_TOKEN_MINT);
+    return disburseIsolatedJobFunds(amount, escrow, owner, job, jobAssociatedToken, destinationToken);
+};
+
+export const disburseIsolatedJobFunds = (
+    amount: number | bigint,
+    escrow: PublicKey,
+    owner: PublicKey,
+    job: PublicKey,
+    jobAssociatedToken: PublicKey,
+    destinationToken: PublicKey
+): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.DisburseIsolatedJobFunds,
+            amount: BigInt(amount),
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: escrow, isSigner: false, isWritable: false },
+        { pubkey: owner, isSigner: true, isWritable: false },
+        { pubkey: job, isSigner: false, isWritable: true },
+        { pubkey: jobAssociatedToken, isSigner: false, isWritable: true },
+        { pubkey: destinationToken, isSigner: false, isWritable: true },
+        { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
@@ -0,0 +1,78 @@
+import { ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { PublicKey, SystemProgram, TransactionInstruction } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import {
+    findEmissionScheduleAddress,
+    findEmissionScheduleAssociatedTokenAddress,
+    findEscrowAddress,
+    findRewardPoolAddress,
+    findRewardPoolAssociatedTokenAddress,
+} from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+}
+
+const DataLayout = struct<Data>([u8('instruction')]);
+
+export const createEmitRewardsInstruction = async (
+    funder: PublicKey,
+    epoch: number | bigint
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [emissionSchedule] = await findEmissionScheduleAddress(escrow);
+    const [emissionScheduleAssociatedToken] = await findEmissionScheduleAssociatedTokenAddress(
+        emissionSchedule,
+        RNDR_TOKEN_MINT
+    );
+    const [rewardPool] = await findRewardPoolAddress(escrow, epoch);
+    const [rewardPoolAssociatedToken] = await findRewardPoolAssociatedTokenAddress(rewardPool, RNDR_TOKEN_MINT);
+    return emitRewards(
+        RNDR_TOKEN_MINT,
+        funder,
+        escrow,
+        emissionSchedule,
+        emissionScheduleAssociatedToken,
+        rewardPool,
+        rewardPoolAssociatedToken
+    );
+};
+
+export const emitRewards = (
+    tokenMint: PublicKey,
+    funder: PublicKey,
+    escrow: PublicKey,
+    emissionSchedule: PublicKey,
+    emissionScheduleAssociatedToken: PublicKey,
+    rewardPool: PublicKey,
+    rewardPoolAssociatedToken: PublicKey
+): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.EmitRewards,
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: tokenMint, isSigner: false, isWritable: false },
+        { pubkey: funder, isSigner: true, isWritable: true },
+        { pubkey: escrow, isSigner: false, isWritable: false },
+        { pubkey: emissionSchedule, isSigner: false, isWritable: true },
+        { pubkey: emissionScheduleAssociatedToken, isSigner: false, isWritable: true },
+        { pubkey: rewardPool, isSigner: false, isWritable: true },
+        { pubkey: rewardPoolAssociatedToken, isSigner: false, isWritable: true },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+        { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+        { pubkey: ASSOCIATED_TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
@@ -0,0 +1,49 @@
+import { PublicKey, TransactionInstruction } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID } from '../constants';
+import { findConfigAddress, u64 } from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+    tier: number;
+    milliObh: bigint;
+}
+
+const DataLayout = struct<Data>([u8('instruction'), u8('tier'), u64('milliObh')]);
+
+export const createEstimateJobCostInstruction = async (
+    tier: number,
+    milliObh: number | bigint
+): Promise<TransactionInstruction> => {
+    const [config] = await findConfigAddress();
+    return estimateJobCost(tier, milliObh, config);
+};
+
+/**
+ * Estimate the funding amount of a job of work in thousandths of an OctaneBench-hour rendered in a price tier, which is
+ * set as the return data of the transaction as a little-endian u64 in RNDR base units
+ */
+export const estimateJobCost = (
+    tier: number,
+    milliObh: number | bigint,
+    config: PublicKey
+): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.EstimateJobCost,
+            tier,
+            milliObh: BigInt(milliObh),
+        },
+        data
+    );
+
+    const keys = [{ pubkey: config, isSigner: false, isWritable: false }];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
@@ -0,0 +1,70 @@
+import { TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { PublicKey, TransactionInstruction } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import {
+    findEscrowAddress,
+    findEscrowAssociatedTokenAddress,
+    findEscrowShardAddress,
+    findEscrowShardIndex,
+    findJobAddress,
+    findJobAssociatedTokenAddress,
+    findProposalAddress,
+} from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+}
+
+const DataLayout = struct<Data>([u8('instruction')]);
+
+export const createExecuteDisbursementInstruction = async (
+    proposalId: number | bigint,
+    authority: PublicKey,
+    jobId: number | bigint,
+    isolated: boolean,
+    destinationToken: PublicKey
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [job] = await findJobAddress(escrow, authority, jobId);
+    const [proposal] = await findProposalAddress(escrow, proposalId);
+    const [sourceToken] = isolated
+        ? await findJobAssociatedTokenAddress(job, RNDR_TOKEN_MINT)
+        : await findEscrowAssociatedTokenAddress(escrow, RNDR_TOKEN_MINT);
+    const [escrowShard] = isolated ? [undefined] : await findEscrowShardAddress(escrow, findEscrowShardIndex(job));
+    return executeDisbursement(escrow, sourceToken, job, proposal, destinationToken, escrowShard);
+};
+
+export const executeDisbursement = (
+    escrow: PublicKey,
+    sourceToken: PublicKey,
+    job: PublicKey,
+    proposal: PublicKey,
+    destinationToken: PublicKey,
+    escrowShard?: PublicKey
+): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.ExecuteDisbursement,
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: escrow, isSigner: false, isWritable: true },
+        { pubkey: sourceToken, isSigner: false, isWritable: true },
+        { pubkey: job, isSigner: false, isWritable: true },
+        { pubkey: proposal, isSigner: false, isWritable: true },
+        { pubkey: destinationToken, isSigner: false, isWritable: true },
+        { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+    ];
+    if (escrowShard) keys.push({ pubkey: escrowShard, isSigner: false, isWritable: true });
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
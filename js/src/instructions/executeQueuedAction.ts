@@ -0,0 +1,51 @@
+import { AccountMeta, PublicKey, SystemProgram, TransactionInstruction } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID } from '../constants';
+import { findQueuedActionAddress } from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+}
+
+const DataLayout = struct<Data>([u8('instruction')]);
+
+export const createExecuteQueuedActionInstruction = async (
+    target: PublicKey,
+    actionId: number | bigint,
+    payer?: PublicKey
+): Promise<TransactionInstruction> => {
+    const [queuedAction] = await findQueuedActionAddress(target, actionId);
+    return executeQueuedAction(queuedAction, target, payer);
+};
+
+export const executeQueuedAction = (
+    queuedAction: PublicKey,
+    target: PublicKey,
+    payer?: PublicKey
+): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.ExecuteQueuedAction,
+        },
+        data
+    );
+
+    const keys: AccountMeta[] = [
+        { pubkey: queuedAction, isSigner: false, isWritable: true },
+        { pubkey: target, isSigner: false, isWritable: true },
+    ];
+    if (payer) {
+        keys.push(
+            { pubkey: payer, isSigner: true, isWritable: true },
+            { pubkey: SystemProgram.programId, isSigner: false, isWritable: false }
+        );
+    }
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
@@ -0,0 +1,54 @@
+import { PublicKey, TransactionInstruction } from '@solana/web3.js';
+import { blob, struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import { findEscrowAddress, findJobAddress, findVerifierRegistryAddress } from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+    evidenceHash: Uint8Array;
+}
+
+const DataLayout = struct<Data>([u8('instruction'), blob(32, 'evidenceHash')]);
+
+export const createFlagJobInstruction = async (
+    evidenceHash: Uint8Array,
+    verifier: PublicKey,
+    authority: PublicKey,
+    jobId: number | bigint
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [verifierRegistry] = await findVerifierRegistryAddress(escrow);
+    const [job] = await findJobAddress(escrow, authority, jobId);
+    return flagJob(evidenceHash, verifier, escrow, verifierRegistry, job);
+};
+
+export const flagJob = (
+    evidenceHash: Uint8Array,
+    verifier: PublicKey,
+    escrow: PublicKey,
+    verifierRegistry: PublicKey,
+    job: PublicKey
+): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.FlagJob,
+            evidenceHash,
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: verifier, isSigner: true, isWritable: false },
+        { pubkey: escrow, isSigner: false, isWritable: false },
+        { pubkey: verifierRegistry, isSigner: false, isWritable: false },
+        { pubkey: job, isSigner: false, isWritable: true },
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
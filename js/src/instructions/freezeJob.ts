@@ -0,0 +1,52 @@
+import { PublicKey, SystemProgram, TransactionInstruction } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import { findConfigAddress, findEscrowAddress, findJobAddress } from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+}
+
+const DataLayout = struct<Data>([u8('instruction')]);
+
+/** Freeze a job under a legal hold, so its funds can't be disbursed or refunded until it's unfrozen */
+export const createFreezeJobInstruction = async (
+    compliance: PublicKey,
+    authority: PublicKey,
+    jobId: number | bigint
+): Promise<TransactionInstruction> => {
+    const [config] = await findConfigAddress();
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [job] = await findJobAddress(escrow, authority, jobId);
+    return freezeJob(compliance, config, escrow, job);
+};
+
+export const freezeJob = (
+    compliance: PublicKey,
+    config: PublicKey,
+    escrow: PublicKey,
+    job: PublicKey
+): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.FreezeJob,
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: compliance, isSigner: true, isWritable: true },
+        { pubkey: config, isSigner: false, isWritable: false },
+        { pubkey: escrow, isSigner: false, isWritable: false },
+        { pubkey: job, isSigner: false, isWritable: true },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
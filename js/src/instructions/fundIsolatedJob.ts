@@ -0,0 +1,78 @@
+import { ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { PublicKey, SystemProgram, TransactionInstruction } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import { findEscrowAddress, findJobAddress, findJobAssociatedTokenAddress, u64 } from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+    amount: bigint;
+    jobId: bigint;
+}
+
+const DataLayout = struct<Data>([u8('instruction'), u64('amount'), u64('jobId')]);
+
+export const createFundIsolatedJobInstruction = async (
+    amount: number | bigint,
+    funder: PublicKey,
+    sourceToken: PublicKey,
+    authority: PublicKey,
+    jobId: number | bigint
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [job] = await findJobAddress(escrow, authority, jobId);
+    const [jobAssociatedToken] = await findJobAssociatedTokenAddress(job, RNDR_TOKEN_MINT);
+    return fundIsolatedJobInstruction(
+        amount,
+        jobId,
+        RNDR_TOKEN_MINT,
+        funder,
+        sourceToken,
+        authority,
+        escrow,
+        job,
+        jobAssociatedToken
+    );
+};
+
+export const fundIsolatedJobInstruction = (
+    amount: number | bigint,
+    jobId: number | bigint,
+    tokenMint: PublicKey,
+    funder: PublicKey,
+    sourceToken: PublicKey,
+    authority: PublicKey,
+    escrow: PublicKey,
+    job: PublicKey,
+    jobAssociatedToken: PublicKey
+): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.FundIsolatedJob,
+            amount: BigInt(amount),
+            jobId: BigInt(jobId),
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: tokenMint, isSigner: false, isWritable: false },
+        { pubkey: funder, isSigner: true, isWritable: true },
+        { pubkey: sourceToken, isSigner: false, isWritable: true },
+        { pubkey: authority, isSigner: true, isWritable: false },
+        { pubkey: escrow, isSigner: false, isWritable: false },
+        { pubkey: job, isSigner: false, isWritable: true },
+        { pubkey: jobAssociatedToken, isSigner: false, isWritable: true },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+        { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+        { pubkey: ASSOCIATED_TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
@@ -1,69 +1,96 @@
 import { TOKEN_PROGRAM_ID } from '@solana/spl-token';
-import { PublicKey, SystemProgram, SYSVAR_RENT_PUBKEY, TransactionInstruction } from '@solana/web3.js';
+import { PublicKey, SystemProgram, TransactionInstruction } from '@solana/web3.js';
 import { struct, u8 } from 'buffer-layout';
 import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
-import { findEscrowAddress, findEscrowAssociatedTokenAddress, findJobAddress, u64 } from '../util';
+import { encodeJobMetadata, JobMetadata } from '../state';
+import {
+    findEscrowAddress,
+    findEscrowAssociatedTokenAddress,
+    findEscrowShardAddress,
+    findEscrowShardIndex,
+    findJobAddress,
+    findSessionKeyAddress,
+    u64,
+} from '../util';
 import { RNDRInstruction } from './instruction';
 
 interface Data {
     instruction: number;
     amount: bigint;
+    jobId: bigint;
 }
 
-const DataLayout = struct<Data>([u8('instruction'), u64('amount')]);
+const DataLayout = struct<Data>([u8('instruction'), u64('amount'), u64('jobId')]);
 
+/**
+ * Top up an existing job with a session key of the authority instead if `sessionKey` is provided. Metadata can only be
+ * set by the authority itself while the job is open.
+ */
 export const createFundJobInstruction = async (
     amount: number | bigint,
     funder: PublicKey,
     sourceToken: PublicKey,
-    authority: PublicKey
+    authority: PublicKey,
+    jobId: number | bigint,
+    sessionKey?: PublicKey,
+    metadata?: JobMetadata
 ): Promise<TransactionInstruction> => {
     const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
     const [escrowAssociatedToken] = await findEscrowAssociatedTokenAddress(escrow, RNDR_TOKEN_MINT);
-    const [job] = await findJobAddress(escrow, authority);
+    const [job] = await findJobAddress(escrow, authority, jobId);
+    const [escrowShard] = await findEscrowShardAddress(escrow, findEscrowShardIndex(job));
+    const [sessionKeyAccount] = sessionKey ? await findSessionKeyAddress(escrow, authority, sessionKey) : [undefined];
     return fundJobInstruction(
         amount,
-        RNDR_TOKEN_MINT,
+        jobId,
         funder,
         sourceToken,
-        authority,
+        sessionKey ?? authority,
         escrow,
         escrowAssociatedToken,
-        job
+        job,
+        escrowShard,
+        sessionKeyAccount,
+        metadata
     );
 };
 
 export const fundJobInstruction = (
     amount: number | bigint,
-    tokenMint: PublicKey,
+    jobId: number | bigint,
     funder: PublicKey,
     sourceToken: PublicKey,
     authority: PublicKey,
     escrow: PublicKey,
     escrowAssociatedToken: PublicKey,
-    job: PublicKey
+    job: PublicKey,
+    escrowShard: PublicKey,
+    sessionKeyAccount?: PublicKey,
+    metadata?: JobMetadata
 ): TransactionInstruction => {
-    const data = Buffer.alloc(DataLayout.span);
+    const fields = Buffer.alloc(DataLayout.span);
     DataLayout.encode(
         {
             instruction: RNDRInstruction.FundJob,
             amount: BigInt(amount),
+            jobId: BigInt(jobId),
         },
-        data
+        fields
     );
+    const data = metadata ? Buffer.concat([fields, encodeJobMetadata(metadata)]) : fields;
 
     const keys = [
-        { pubkey: tokenMint, isSigner: false, isWritable: false },
         { pubkey: funder, isSigner: true, isWritable: true },
         { pubkey: sourceToken, isSigner: false, isWritable: true },
         { pubkey: authority, isSigner: true, isWritable: false },
-        { pubkey: escrow, isSigner: false, isWritable: true },
+        { pubkey: escrow, isSigner: false, isWritable: false },
         { pubkey: escrowAssociatedToken, isSigner: false, isWritable: true },
         { pubkey: job, isSigner: false, isWritable: true },
-        { pubkey: SYSVAR_RENT_PUBKEY, isSigner: false, isWritable: false },
+        { pubkey: escrowShard, isSigner: false, isWritable: true },
         { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
         { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
     ];
+    if (sessionKeyAccount) keys.push({ pubkey: sessionKeyAccount, isSigner: false, isWritable: false });
 
     return new TransactionInstruction({
         keys,
@@ -0,0 +1,110 @@
+import { PublicKey, TransactionInstruction } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import {
+    findConfigAddress,
+    findEscrowAddress,
+    findEscrowAssociatedTokenAddress,
+    findEscrowShardAddress,
+    findEscrowShardIndex,
+    findJobAddress,
+    u64,
+} from '../util';
+import { fundJobInstruction } from './fundJob';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+    value: bigint;
+    maxRndrAmount: bigint;
+    jobId: bigint;
+}
+
+const DataLayout = struct<Data>([u8('instruction'), u64('value'), u64('maxRndrAmount'), u64('jobId')]);
+
+/**
+ * Fund a job with the amount of RNDR worth `value` in the quote currency of the escrow's price feed, with 6 decimals,
+ * which fails if the amount at the live price exceeds `maxRndrAmount`, or if the price is older or less certain than
+ * the oracle price guards of the config allow. Unless the job already has an exchange rate, the rate of the price and
+ * its publish slot are recorded on it.
+ */
+export const createFundJobAtOraclePriceInstruction = async (
+    value: number | bigint,
+    maxRndrAmount: number | bigint,
+    priceFeed: PublicKey,
+    funder: PublicKey,
+    sourceToken: PublicKey,
+    authority: PublicKey,
+    jobId: number | bigint
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [escrowAssociatedToken] = await findEscrowAssociatedTokenAddress(escrow, RNDR_TOKEN_MINT);
+    const [job] = await findJobAddress(escrow, authority, jobId);
+    const [escrowShard] = await findEscrowShardAddress(escrow, findEscrowShardIndex(job));
+    const [config] = await findConfigAddress();
+    return fundJobAtOraclePriceInstruction(
+        value,
+        maxRndrAmount,
+        jobId,
+        priceFeed,
+        RNDR_TOKEN_MINT,
+        config,
+        funder,
+        sourceToken,
+        authority,
+        escrow,
+        escrowAssociatedToken,
+        job,
+        escrowShard
+    );
+};
+
+export const fundJobAtOraclePriceInstruction = (
+    value: number | bigint,
+    maxRndrAmount: number | bigint,
+    jobId: number | bigint,
+    priceFeed: PublicKey,
+    tokenMint: PublicKey,
+    config: PublicKey,
+    funder: PublicKey,
+    sourceToken: PublicKey,
+    authority: PublicKey,
+    escrow: PublicKey,
+    escrowAssociatedToken: PublicKey,
+    job: PublicKey,
+    escrowShard: PublicKey
+): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.FundJobAtOraclePrice,
+            value: BigInt(value),
+            maxRndrAmount: BigInt(maxRndrAmount),
+            jobId: BigInt(jobId),
+        },
+        data
+    );
+
+    const { keys } = fundJobInstruction(
+        maxRndrAmount,
+        jobId,
+        funder,
+        sourceToken,
+        authority,
+        escrow,
+        escrowAssociatedToken,
+        job,
+        escrowShard
+    );
+
+    return new TransactionInstruction({
+        keys: [
+            { pubkey: priceFeed, isSigner: false, isWritable: false },
+            { pubkey: tokenMint, isSigner: false, isWritable: false },
+            { pubkey: config, isSigner: false, isWritable: false },
+            ...keys,
+        ],
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
@@ -0,0 +1,106 @@
+import { TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { PublicKey, SystemProgram, TransactionInstruction } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import {
+    findEscrowAddress,
+    findEscrowAssociatedTokenAddress,
+    findEscrowShardAddress,
+    findEscrowShardIndex,
+    findJobAddress,
+    findJobBundleAddress,
+    u64,
+} from '../util';
+import { MAX_FUND_JOBS_LEN } from './fundJobs';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+    firstJobId: bigint;
+    count: number;
+    amountPerJob: bigint;
+}
+
+const DataLayout = struct<Data>([u8('instruction'), u64('firstJobId'), u8('count'), u64('amountPerJob')]);
+
+/** Fund a number of consecutive jobs of a bundle from a job identifier with the same amount each */
+export const createFundJobBundleInstruction = async (
+    bundleId: number | bigint,
+    firstJobId: number | bigint,
+    count: number,
+    amountPerJob: number | bigint,
+    funder: PublicKey,
+    sourceToken: PublicKey,
+    sourceAuthority: PublicKey,
+    authority: PublicKey
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [escrowAssociatedToken] = await findEscrowAssociatedTokenAddress(escrow, RNDR_TOKEN_MINT);
+    const [jobBundle] = await findJobBundleAddress(escrow, authority, bundleId);
+    const jobAccounts: [PublicKey, PublicKey][] = [];
+    for (let index = 0; index < count; index++) {
+        const [job] = await findJobAddress(escrow, authority, BigInt(firstJobId) + BigInt(index));
+        const [escrowShard] = await findEscrowShardAddress(escrow, findEscrowShardIndex(job));
+        jobAccounts.push([job, escrowShard]);
+    }
+    return fundJobBundle(
+        firstJobId,
+        count,
+        amountPerJob,
+        funder,
+        sourceToken,
+        sourceAuthority,
+        jobBundle,
+        escrow,
+        escrowAssociatedToken,
+        jobAccounts
+    );
+};
+
+export const fundJobBundle = (
+    firstJobId: number | bigint,
+    count: number,
+    amountPerJob: number | bigint,
+    funder: PublicKey,
+    sourceToken: PublicKey,
+    sourceAuthority: PublicKey,
+    jobBundle: PublicKey,
+    escrow: PublicKey,
+    escrowAssociatedToken: PublicKey,
+    jobAccounts: [PublicKey, PublicKey][]
+): TransactionInstruction => {
+    if (count > MAX_FUND_JOBS_LEN) throw new Error('Too many jobs');
+    if (count !== jobAccounts.length) throw new Error('Job accounts do not match jobs');
+
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.FundJobBundle,
+            firstJobId: BigInt(firstJobId),
+            count,
+            amountPerJob: BigInt(amountPerJob),
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: funder, isSigner: true, isWritable: true },
+        { pubkey: sourceToken, isSigner: false, isWritable: true },
+        { pubkey: sourceAuthority, isSigner: true, isWritable: false },
+        { pubkey: jobBundle, isSigner: false, isWritable: true },
+        { pubkey: escrow, isSigner: false, isWritable: false },
+        { pubkey: escrowAssociatedToken, isSigner: false, isWritable: true },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+        { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+        ...jobAccounts.flatMap(([job, escrowShard]) => [
+            { pubkey: job, isSigner: false, isWritable: true },
+            { pubkey: escrowShard, isSigner: false, isWritable: true },
+        ]),
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
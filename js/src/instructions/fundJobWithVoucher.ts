@@ -0,0 +1,205 @@
+import { TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { PublicKey, SystemProgram, SYSVAR_INSTRUCTIONS_PUBKEY, TransactionInstruction } from '@solana/web3.js';
+import { blob, struct, u16, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import {
+    findEscrowAddress,
+    findEscrowAssociatedTokenAddress,
+    findEscrowShardAddress,
+    findEscrowShardIndex,
+    findJobAddress,
+    findVoucherNonceAddress,
+    i64,
+    publicKey,
+    u64,
+} from '../util';
+import { RNDRInstruction } from './instruction';
+
+export const VOUCHER_DOMAIN = Buffer.from('rndr:voucher', 'utf8');
+
+export const ED25519_PROGRAM_ID = new PublicKey('Ed25519SigVerify111111111111111111111111111');
+
+export interface Voucher {
+    escrow: PublicKey;
+    authority: PublicKey;
+    jobId: bigint;
+    amount: bigint;
+    nonce: bigint;
+    expiresAt: bigint;
+}
+
+/** @internal */
+export const VoucherLayout = struct<Voucher & { domain: Buffer }>([
+    blob(VOUCHER_DOMAIN.length, 'domain'),
+    publicKey('escrow'),
+    publicKey('authority'),
+    u64('jobId'),
+    u64('amount'),
+    u64('nonce'),
+    i64('expiresAt'),
+]);
+
+/** Message the Job authority signs to authorize a voucher */
+export const encodeVoucherMessage = (voucher: Voucher): Buffer => {
+    const message = Buffer.alloc(VoucherLayout.span);
+    VoucherLayout.encode({ domain: VOUCHER_DOMAIN, ...voucher }, message);
+    return message;
+};
+
+/** Create a voucher for the RNDR escrow */
+export const createVoucher = async (
+    authority: PublicKey,
+    jobId: number | bigint,
+    amount: number | bigint,
+    nonce: number | bigint,
+    expiresAt: number | bigint
+): Promise<Voucher> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    return {
+        escrow,
+        authority,
+        jobId: BigInt(jobId),
+        amount: BigInt(amount),
+        nonce: BigInt(nonce),
+        expiresAt: BigInt(expiresAt),
+    };
+};
+
+interface Ed25519Data {
+    numSignatures: number;
+    padding: number;
+    signatureOffset: number;
+    signatureInstructionIndex: number;
+    publicKeyOffset: number;
+    publicKeyInstructionIndex: number;
+    messageDataOffset: number;
+    messageDataSize: number;
+    messageInstructionIndex: number;
+}
+
+const Ed25519DataLayout = struct<Ed25519Data>([
+    u8('numSignatures'),
+    u8('padding'),
+    u16('signatureOffset'),
+    u16('signatureInstructionIndex'),
+    u16('publicKeyOffset'),
+    u16('publicKeyInstructionIndex'),
+    u16('messageDataOffset'),
+    u16('messageDataSize'),
+    u16('messageInstructionIndex'),
+]);
+
+/** Create the ed25519 program instruction that must precede `FundJobWithVoucher` */
+export const createVoucherSignatureInstruction = (voucher: Voucher, signature: Uint8Array): TransactionInstruction => {
+    const message = encodeVoucherMessage(voucher);
+    const publicKeyOffset = Ed25519DataLayout.span;
+    const signatureOffset = publicKeyOffset + 32;
+    const messageDataOffset = signatureOffset + 64;
+
+    const data = Buffer.alloc(messageDataOffset + message.length);
+    Ed25519DataLayout.encode(
+        {
+            numSignatures: 1,
+            padding: 0,
+            signatureOffset,
+            signatureInstructionIndex: 0xffff,
+            publicKeyOffset,
+            publicKeyInstructionIndex: 0xffff,
+            messageDataOffset,
+            messageDataSize: message.length,
+            messageInstructionIndex: 0xffff,
+        },
+        data
+    );
+    voucher.authority.toBuffer().copy(data, publicKeyOffset);
+    Buffer.from(signature).copy(data, signatureOffset);
+    message.copy(data, messageDataOffset);
+
+    return new TransactionInstruction({
+        keys: [],
+        programId: ED25519_PROGRAM_ID,
+        data,
+    });
+};
+
+interface Data {
+    instruction: number;
+    amount: bigint;
+    jobId: bigint;
+    nonce: bigint;
+    expiresAt: bigint;
+}
+
+const DataLayout = struct<Data>([u8('instruction'), u64('amount'), u64('jobId'), u64('nonce'), i64('expiresAt')]);
+
+export const createFundJobWithVoucherInstruction = async (
+    relayer: PublicKey,
+    sourceToken: PublicKey,
+    voucher: Voucher
+): Promise<TransactionInstruction> => {
+    const [escrowAssociatedToken] = await findEscrowAssociatedTokenAddress(voucher.escrow, RNDR_TOKEN_MINT);
+    const [job] = await findJobAddress(voucher.escrow, voucher.authority, voucher.jobId);
+    const [escrowShard] = await findEscrowShardAddress(voucher.escrow, findEscrowShardIndex(job));
+    const [voucherNonce] = await findVoucherNonceAddress(voucher.escrow, voucher.authority);
+    return fundJobWithVoucherInstruction(
+        voucher.amount,
+        voucher.jobId,
+        voucher.nonce,
+        voucher.expiresAt,
+        relayer,
+        sourceToken,
+        voucher.authority,
+        voucher.escrow,
+        escrowAssociatedToken,
+        job,
+        escrowShard,
+        voucherNonce
+    );
+};
+
+export const fundJobWithVoucherInstruction = (
+    amount: number | bigint,
+    jobId: number | bigint,
+    nonce: number | bigint,
+    expiresAt: number | bigint,
+    relayer: PublicKey,
+    sourceToken: PublicKey,
+    authority: PublicKey,
+    escrow: PublicKey,
+    escrowAssociatedToken: PublicKey,
+    job: PublicKey,
+    escrowShard: PublicKey,
+    voucherNonce: PublicKey
+): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.FundJobWithVoucher,
+            amount: BigInt(amount),
+            jobId: BigInt(jobId),
+            nonce: BigInt(nonce),
+            expiresAt: BigInt(expiresAt),
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: relayer, isSigner: true, isWritable: true },
+        { pubkey: sourceToken, isSigner: false, isWritable: true },
+        { pubkey: authority, isSigner: false, isWritable: false },
+        { pubkey: escrow, isSigner: false, isWritable: false },
+        { pubkey: escrowAssociatedToken, isSigner: false, isWritable: true },
+        { pubkey: job, isSigner: false, isWritable: true },
+        { pubkey: escrowShard, isSigner: false, isWritable: true },
+        { pubkey: voucherNonce, isSigner: false, isWritable: true },
+        { pubkey: SYSVAR_INSTRUCTIONS_PUBKEY, isSigner: false, isWritable: false },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+        { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
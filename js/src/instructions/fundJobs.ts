@@ -0,0 +1,94 @@
+import { TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { PublicKey, SystemProgram, TransactionInstruction } from '@solana/web3.js';
+import { seq, struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import {
+    findEscrowAddress,
+    findEscrowAssociatedTokenAddress,
+    findEscrowShardAddress,
+    findEscrowShardIndex,
+    findJobAddress,
+    u64,
+} from '../util';
+import { RNDRInstruction } from './instruction';
+
+export const MAX_FUND_JOBS_LEN = 10;
+
+export interface JobFunding {
+    jobId: number | bigint;
+    amount: number | bigint;
+}
+
+interface JobFundingData {
+    jobId: bigint;
+    amount: bigint;
+}
+
+interface Data {
+    instruction: number;
+    length: number;
+    jobs: JobFundingData[];
+}
+
+export const createFundJobsInstruction = async (
+    jobs: JobFunding[],
+    funder: PublicKey,
+    sourceToken: PublicKey,
+    authority: PublicKey
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [escrowAssociatedToken] = await findEscrowAssociatedTokenAddress(escrow, RNDR_TOKEN_MINT);
+    const jobAccounts: [PublicKey, PublicKey][] = [];
+    for (const { jobId } of jobs) {
+        const [job] = await findJobAddress(escrow, authority, jobId);
+        const [escrowShard] = await findEscrowShardAddress(escrow, findEscrowShardIndex(job));
+        jobAccounts.push([job, escrowShard]);
+    }
+    return fundJobsInstruction(jobs, funder, sourceToken, authority, escrow, escrowAssociatedToken, jobAccounts);
+};
+
+export const fundJobsInstruction = (
+    jobs: JobFunding[],
+    funder: PublicKey,
+    sourceToken: PublicKey,
+    authority: PublicKey,
+    escrow: PublicKey,
+    escrowAssociatedToken: PublicKey,
+    jobAccounts: [PublicKey, PublicKey][]
+): TransactionInstruction => {
+    if (jobs.length > MAX_FUND_JOBS_LEN) throw new Error('Too many jobs');
+    if (jobs.length !== jobAccounts.length) throw new Error('Job accounts do not match jobs');
+
+    const JobFundingLayout = struct<JobFundingData>([u64('jobId'), u64('amount')]);
+    const DataLayout = struct<Data>([u8('instruction'), u8('length'), seq(JobFundingLayout, jobs.length, 'jobs')]);
+
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.FundJobs,
+            length: jobs.length,
+            jobs: jobs.map(({ jobId, amount }) => ({ jobId: BigInt(jobId), amount: BigInt(amount) })),
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: funder, isSigner: true, isWritable: true },
+        { pubkey: sourceToken, isSigner: false, isWritable: true },
+        { pubkey: authority, isSigner: true, isWritable: false },
+        { pubkey: escrow, isSigner: false, isWritable: false },
+        { pubkey: escrowAssociatedToken, isSigner: false, isWritable: true },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+        { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+        ...jobAccounts.flatMap(([job, escrowShard]) => [
+            { pubkey: job, isSigner: false, isWritable: true },
+            { pubkey: escrowShard, isSigner: false, isWritable: true },
+        ]),
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
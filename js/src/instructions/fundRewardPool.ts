@@ -0,0 +1,78 @@
+import { ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { PublicKey, SystemProgram, TransactionInstruction } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import { findEscrowAddress, findRewardPoolAddress, findRewardPoolAssociatedTokenAddress, u64 } from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+    epoch: bigint;
+    amount: bigint;
+}
+
+const DataLayout = struct<Data>([u8('instruction'), u64('epoch'), u64('amount')]);
+
+export const createFundRewardPoolInstruction = async (
+    amount: number | bigint,
+    epoch: number | bigint,
+    funder: PublicKey,
+    sourceToken: PublicKey,
+    authority: PublicKey
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [rewardPool] = await findRewardPoolAddress(escrow, epoch);
+    const [rewardPoolAssociatedToken] = await findRewardPoolAssociatedTokenAddress(rewardPool, RNDR_TOKEN_MINT);
+    return fundRewardPool(
+        amount,
+        epoch,
+        RNDR_TOKEN_MINT,
+        funder,
+        sourceToken,
+        authority,
+        escrow,
+        rewardPool,
+        rewardPoolAssociatedToken
+    );
+};
+
+export const fundRewardPool = (
+    amount: number | bigint,
+    epoch: number | bigint,
+    tokenMint: PublicKey,
+    funder: PublicKey,
+    sourceToken: PublicKey,
+    authority: PublicKey,
+    escrow: PublicKey,
+    rewardPool: PublicKey,
+    rewardPoolAssociatedToken: PublicKey
+): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.FundRewardPool,
+            epoch: BigInt(epoch),
+            amount: BigInt(amount),
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: tokenMint, isSigner: false, isWritable: false },
+        { pubkey: funder, isSigner: true, isWritable: true },
+        { pubkey: sourceToken, isSigner: false, isWritable: true },
+        { pubkey: authority, isSigner: true, isWritable: false },
+        { pubkey: escrow, isSigner: false, isWritable: false },
+        { pubkey: rewardPool, isSigner: false, isWritable: true },
+        { pubkey: rewardPoolAssociatedToken, isSigner: false, isWritable: true },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+        { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+        { pubkey: ASSOCIATED_TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
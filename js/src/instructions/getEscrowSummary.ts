@@ -0,0 +1,65 @@
+import { PublicKey, TransactionInstruction } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import { IncidentMode } from '../state';
+import { findEscrowAddress, publicKey, u64 } from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+}
+
+const DataLayout = struct<Data>([u8('instruction')]);
+
+/** Summary of an escrow, set as the return data of a `GetEscrowSummary` instruction */
+export interface EscrowSummary {
+    tokenMint: PublicKey;
+    owner: PublicKey;
+    /** Token account the pooled tokens of the escrow are held in */
+    tokenAccount: PublicKey;
+    amount: bigint;
+    escrowId: bigint;
+    incidentMode: IncidentMode;
+    mintMigrations: number;
+}
+
+/** @internal */
+export const EscrowSummaryLayout = struct<EscrowSummary>([
+    publicKey('tokenMint'),
+    publicKey('owner'),
+    publicKey('tokenAccount'),
+    u64('amount'),
+    u64('escrowId'),
+    u8('incidentMode'),
+    u8('mintMigrations'),
+]);
+
+/** Parse the return data of a `GetEscrowSummary` instruction */
+export const parseEscrowSummary = (data: Buffer): EscrowSummary | undefined => {
+    if (data.length !== EscrowSummaryLayout.span) return;
+    return EscrowSummaryLayout.decode(data);
+};
+
+export const createGetEscrowSummaryInstruction = async (): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    return getEscrowSummary(escrow);
+};
+
+/** Report a summary of the escrow, which is set as the return data of the transaction */
+export const getEscrowSummary = (escrow: PublicKey): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.GetEscrowSummary,
+        },
+        data
+    );
+
+    const keys = [{ pubkey: escrow, isSigner: false, isWritable: false }];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
@@ -0,0 +1,52 @@
+import { PublicKey, TransactionInstruction } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import { findEscrowAddress, findJobAddress, u64 } from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+}
+
+const DataLayout = struct<Data>([u8('instruction')]);
+
+/** Parse the return data of a `GetEscrowedAmount` instruction */
+export const parseEscrowedAmount = (data: Buffer): bigint | undefined => {
+    const AmountLayout = u64('amount');
+    if (data.length !== AmountLayout.span) return;
+    return AmountLayout.decode(data);
+};
+
+export const createGetEscrowedAmountInstruction = async (
+    authority: PublicKey,
+    jobId: number | bigint
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [job] = await findJobAddress(escrow, authority, jobId);
+    return getEscrowedAmount(escrow, job);
+};
+
+/**
+ * Report the amount of tokens in escrow for a job, which is set as the return data of the transaction as a
+ * little-endian u64 in base units
+ */
+export const getEscrowedAmount = (escrow: PublicKey, job: PublicKey): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.GetEscrowedAmount,
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: escrow, isSigner: false, isWritable: false },
+        { pubkey: job, isSigner: false, isWritable: false },
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
@@ -0,0 +1,77 @@
+import { PublicKey, TransactionInstruction } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import { JobStatus } from '../state';
+import { bool, findEscrowAddress, findJobAddress, i64, publicKey, u64 } from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+}
+
+const DataLayout = struct<Data>([u8('instruction')]);
+
+/** Summary of a job, set as the return data of a `GetJobSummary` instruction */
+export interface JobSummary {
+    authority: PublicKey;
+    id: bigint;
+    amount: bigint;
+    status: JobStatus;
+    node: PublicKey;
+    isolated: boolean;
+    frozen: boolean;
+    priority: number;
+    tags: bigint;
+    fundedAt: bigint;
+}
+
+/** @internal */
+export const JobSummaryLayout = struct<JobSummary>([
+    publicKey('authority'),
+    u64('id'),
+    u64('amount'),
+    u8('status'),
+    publicKey('node'),
+    bool('isolated'),
+    bool('frozen'),
+    u8('priority'),
+    u64('tags'),
+    i64('fundedAt'),
+]);
+
+/** Parse the return data of a `GetJobSummary` instruction */
+export const parseJobSummary = (data: Buffer): JobSummary | undefined => {
+    if (data.length !== JobSummaryLayout.span) return;
+    return JobSummaryLayout.decode(data);
+};
+
+export const createGetJobSummaryInstruction = async (
+    authority: PublicKey,
+    jobId: number | bigint
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [job] = await findJobAddress(escrow, authority, jobId);
+    return getJobSummary(escrow, job);
+};
+
+/** Report a summary of a job, which is set as the return data of the transaction */
+export const getJobSummary = (escrow: PublicKey, job: PublicKey): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.GetJobSummary,
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: escrow, isSigner: false, isWritable: false },
+        { pubkey: job, isSigner: false, isWritable: false },
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
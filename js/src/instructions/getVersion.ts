@@ -0,0 +1,50 @@
+import { TransactionInstruction } from '@solana/web3.js';
+import { struct, u16, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID } from '../constants';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+}
+
+const DataLayout = struct<Data>([u8('instruction')]);
+
+/** Version of the program deployed at the program id */
+export interface ProgramVersion {
+    major: number;
+    minor: number;
+    patch: number;
+    /** Version of the packed layouts of the instructions and accounts */
+    layoutVersion: number;
+}
+
+/** @internal */
+export const ProgramVersionLayout = struct<ProgramVersion>([
+    u16('major'),
+    u16('minor'),
+    u16('patch'),
+    u8('layoutVersion'),
+]);
+
+/** Parse the return data of a `GetVersion` instruction */
+export const parseProgramVersion = (data: Buffer): ProgramVersion | undefined => {
+    if (data.length !== ProgramVersionLayout.span) return;
+    return ProgramVersionLayout.decode(data);
+};
+
+/** Report the version of the deployed program, which is set as the return data of the transaction */
+export const getVersion = (): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.GetVersion,
+        },
+        data
+    );
+
+    return new TransactionInstruction({
+        keys: [],
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
@@ -1,5 +1,105 @@
+export * from './acceptJobAuthority';
+export * from './addVerifier';
+export * from './aggregateEscrowShard';
+export * from './aggregateJobBundle';
+export * from './appendCompressedJob';
+export * from './approveDelegate';
+export * from './approveDisbursement';
+export * from './approveRecovery';
+export * from './burnTreasury';
+export * from './cancelJob';
+export * from './cancelJobBundle';
+export * from './cancelQueuedAction';
+export * from './cancelTreasurySweep';
+export * from './challengeResult';
+export * from './claimEpochReward';
+export * from './claimJob';
+export * from './claimMerkleReward';
+export * from './claimPayment';
+export * from './commitResult';
+export * from './compoundRewards';
+export * from './convertEscrowMintVault';
+export * from './convertIsolatedJobMint';
+export * from './createJobBundle';
+export * from './createSubscription';
+export * from './depositStakeRewards';
+export * from './disburseBatch';
 export * from './disburseFunds';
+export * from './disburseIsolatedJobFunds';
+export * from './emitRewards';
+export * from './estimateJobCost';
+export * from './executeDisbursement';
+export * from './executeQueuedAction';
+export * from './executeRecovery';
+export * from './flagJob';
+export * from './freezeJob';
+export * from './fundIsolatedJob';
 export * from './fundJob';
+export * from './fundJobAtOraclePrice';
+export * from './fundJobBundle';
+export * from './fundJobWithVoucher';
+export * from './fundJobs';
+export * from './fundRewardPool';
+export * from './getEscrowSummary';
+export * from './getEscrowedAmount';
+export * from './getJobSummary';
+export * from './getVersion';
+export * from './initConfig';
 export * from './initEscrow';
+export * from './initEscrowWithSeed';
+export * from './initJobIndex';
+export * from './initJobQueue';
+export * from './initJobTree';
+export * from './initStats';
 export * from './instruction';
+export * from './liquidStake';
+export * from './liquidUnstake';
+export * from './migrateEscrowMint';
+export * from './openDispute';
+export * from './processSubscription';
+export * from './proposeDisbursement';
+export * from './publishMerkleRoot';
+export * from './queueAction';
+export * from './raiseJobPriority';
+export * from './reconcileEscrow';
+export * from './redenominateJobs';
+export * from './registerColdWallet';
+export * from './registerSessionKey';
+export * from './removeVerifier';
+export * from './replaceCompressedJob';
+export * from './resolveDispute';
+export * from './revealResult';
+export * from './revokeDelegate';
+export * from './scheduleOwnerRotation';
+export * from './scheduleTreasurySweep';
+export * from './selectVerifiers';
+export * from './setApprover';
+export * from './setArbitrator';
+export * from './setAutoCompound';
+export * from './setChallengeWindow';
+export * from './setConfig';
+export * from './setCrankBounty';
+export * from './setEmissionSchedule';
 export * from './setEscrowOwner';
+export * from './setEscrowTokenAccount';
+export * from './syncIncidentMode';
+export * from './setJobExchangeRate';
+export * from './setPriceFeed';
+export * from './setProofVerifier';
+export * from './setRecoveryCouncil';
+export * from './setTreasuryBurnCap';
+export * from './settleCompressedJob';
+export * from './stake';
+export * from './submitEvidence';
+export * from './sweepToColdWallet';
+export * from './transferJobAuthority';
+export * from './unfreezeJob';
+export * from './unstake';
+export * from './updateJobMetadata';
+export * from './verifyUpgradeAuthority';
+export * from './withCrankBounty';
+export * from './withEscrowTokenAccount';
+export * from './withJobIndex';
+export * from './withJobQueue';
+export * from './withJobReceipt';
+export * from './withStats';
@@ -0,0 +1,53 @@
+import { PublicKey, SystemProgram, TransactionInstruction } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID } from '../constants';
+import { findConfigAddress, findProgramDataAddress, publicKey } from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+    governance: PublicKey;
+}
+
+const DataLayout = struct<Data>([u8('instruction'), publicKey('governance')]);
+
+export const createInitConfigInstruction = async (
+    governance: PublicKey,
+    funder: PublicKey,
+    upgradeAuthority: PublicKey
+): Promise<TransactionInstruction> => {
+    const [config] = await findConfigAddress();
+    const [programData] = await findProgramDataAddress();
+    return initConfig(governance, funder, config, programData, upgradeAuthority);
+};
+
+export const initConfig = (
+    governance: PublicKey,
+    funder: PublicKey,
+    config: PublicKey,
+    programData: PublicKey,
+    upgradeAuthority: PublicKey
+): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.InitConfig,
+            governance,
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: funder, isSigner: true, isWritable: true },
+        { pubkey: config, isSigner: false, isWritable: true },
+        { pubkey: programData, isSigner: false, isWritable: false },
+        { pubkey: upgradeAuthority, isSigner: true, isWritable: false },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
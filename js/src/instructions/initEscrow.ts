@@ -1,5 +1,5 @@
 import { ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_PROGRAM_ID } from '@solana/spl-token';
-import { PublicKey, SystemProgram, SYSVAR_RENT_PUBKEY, TransactionInstruction } from '@solana/web3.js';
+import { PublicKey, SystemProgram, TransactionInstruction } from '@solana/web3.js';
 import { struct, u8 } from 'buffer-layout';
 import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
 import { findEscrowAddress, findEscrowAssociatedTokenAddress, publicKey } from '../util';
@@ -42,7 +42,6 @@ export const initEscrowInstruction = (
         { pubkey: funder, isSigner: true, isWritable: true },
         { pubkey: escrow, isSigner: false, isWritable: true },
         { pubkey: escrowAssociatedToken, isSigner: false, isWritable: true },
-        { pubkey: SYSVAR_RENT_PUBKEY, isSigner: false, isWritable: false },
         { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
         { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
         { pubkey: ASSOCIATED_TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
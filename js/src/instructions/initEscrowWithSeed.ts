@@ -0,0 +1,59 @@
+import { ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { PublicKey, SystemProgram, TransactionInstruction } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import { findEscrowAddress, findEscrowAssociatedTokenAddress, publicKey, u64 } from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+    owner: PublicKey;
+    escrowId: bigint;
+}
+
+const DataLayout = struct<Data>([u8('instruction'), publicKey('owner'), u64('escrowId')]);
+
+export const createInitEscrowWithSeedInstruction = async (
+    owner: PublicKey,
+    funder: PublicKey,
+    escrowId: number | bigint
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT, escrowId);
+    const [escrowAssociatedToken] = await findEscrowAssociatedTokenAddress(escrow, RNDR_TOKEN_MINT);
+    return initEscrowWithSeedInstruction(owner, escrowId, RNDR_TOKEN_MINT, funder, escrow, escrowAssociatedToken);
+};
+
+export const initEscrowWithSeedInstruction = (
+    owner: PublicKey,
+    escrowId: number | bigint,
+    tokenMint: PublicKey,
+    funder: PublicKey,
+    escrow: PublicKey,
+    escrowAssociatedToken: PublicKey
+): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.InitEscrowWithSeed,
+            owner,
+            escrowId: BigInt(escrowId),
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: tokenMint, isSigner: false, isWritable: false },
+        { pubkey: funder, isSigner: true, isWritable: true },
+        { pubkey: escrow, isSigner: false, isWritable: true },
+        { pubkey: escrowAssociatedToken, isSigner: false, isWritable: true },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+        { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+        { pubkey: ASSOCIATED_TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
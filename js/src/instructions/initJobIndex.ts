@@ -0,0 +1,35 @@
+import { PublicKey, SystemProgram, TransactionInstruction } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import { findEscrowAddress, findJobIndexAddress } from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+}
+
+const DataLayout = struct<Data>([u8('instruction')]);
+
+export const createInitJobIndexInstruction = async (authority: PublicKey): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [jobIndex] = await findJobIndexAddress(escrow, authority);
+    return initJobIndex(authority, escrow, jobIndex);
+};
+
+export const initJobIndex = (authority: PublicKey, escrow: PublicKey, jobIndex: PublicKey): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode({ instruction: RNDRInstruction.InitJobIndex }, data);
+
+    const keys = [
+        { pubkey: authority, isSigner: true, isWritable: true },
+        { pubkey: escrow, isSigner: false, isWritable: false },
+        { pubkey: jobIndex, isSigner: false, isWritable: true },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
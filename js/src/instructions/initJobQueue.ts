@@ -0,0 +1,35 @@
+import { PublicKey, SystemProgram, TransactionInstruction } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import { findEscrowAddress, findJobQueueAddress } from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+}
+
+const DataLayout = struct<Data>([u8('instruction')]);
+
+export const createInitJobQueueInstruction = async (funder: PublicKey): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [jobQueue] = await findJobQueueAddress(escrow);
+    return initJobQueue(funder, escrow, jobQueue);
+};
+
+export const initJobQueue = (funder: PublicKey, escrow: PublicKey, jobQueue: PublicKey): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode({ instruction: RNDRInstruction.InitJobQueue }, data);
+
+    const keys = [
+        { pubkey: funder, isSigner: true, isWritable: true },
+        { pubkey: escrow, isSigner: false, isWritable: false },
+        { pubkey: jobQueue, isSigner: false, isWritable: true },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
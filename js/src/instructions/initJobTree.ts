@@ -0,0 +1,66 @@
+import { PublicKey, SystemProgram, TransactionInstruction } from '@solana/web3.js';
+import { struct, u32, u8 } from 'buffer-layout';
+import { ACCOUNT_COMPRESSION_PROGRAM_ID, NOOP_PROGRAM_ID, RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import { MAX_JOB_TREE_DEPTH } from '../state';
+import { findEscrowAddress, findJobTreeAddress } from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+    maxDepth: number;
+    maxBufferSize: number;
+}
+
+const DataLayout = struct<Data>([u8('instruction'), u8('maxDepth'), u32('maxBufferSize')]);
+
+/**
+ * Create the job tree of an authority with a concurrent merkle tree, which must already be created with the account
+ * compression program as its owner and the size from `getMerkleTreeAccountSize`
+ */
+export const createInitJobTreeInstruction = async (
+    maxDepth: number,
+    maxBufferSize: number,
+    authority: PublicKey,
+    merkleTree: PublicKey
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [jobTree] = await findJobTreeAddress(escrow, authority);
+    return initJobTree(maxDepth, maxBufferSize, authority, escrow, jobTree, merkleTree);
+};
+
+export const initJobTree = (
+    maxDepth: number,
+    maxBufferSize: number,
+    authority: PublicKey,
+    escrow: PublicKey,
+    jobTree: PublicKey,
+    merkleTree: PublicKey
+): TransactionInstruction => {
+    if (maxDepth < 1 || maxDepth > MAX_JOB_TREE_DEPTH) throw new Error('Job tree depth is invalid');
+
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.InitJobTree,
+            maxDepth,
+            maxBufferSize,
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: authority, isSigner: true, isWritable: true },
+        { pubkey: escrow, isSigner: false, isWritable: false },
+        { pubkey: jobTree, isSigner: false, isWritable: true },
+        { pubkey: merkleTree, isSigner: false, isWritable: true },
+        { pubkey: NOOP_PROGRAM_ID, isSigner: false, isWritable: false },
+        { pubkey: ACCOUNT_COMPRESSION_PROGRAM_ID, isSigner: false, isWritable: false },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
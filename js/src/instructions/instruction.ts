@@ -4,4 +4,98 @@ export enum RNDRInstruction {
     SetEscrowOwner = 1,
     FundJob = 2,
     DisburseFunds = 3,
+    AggregateEscrowShard = 4,
+    FundIsolatedJob = 5,
+    DisburseIsolatedJobFunds = 6,
+    DisburseBatch = 7,
+    FundJobs = 8,
+    CommitResult = 9,
+    RevealResult = 10,
+    ClaimPayment = 11,
+    SetChallengeWindow = 12,
+    ChallengeResult = 13,
+    AddVerifier = 14,
+    RemoveVerifier = 15,
+    FlagJob = 16,
+    SelectVerifiers = 17,
+    SetProofVerifier = 18,
+    SetArbitrator = 19,
+    OpenDispute = 20,
+    SubmitEvidence = 21,
+    ResolveDispute = 22,
+    InitConfig = 23,
+    SetConfig = 24,
+    VerifyUpgradeAuthority = 25,
+    SetApprover = 26,
+    ProposeDisbursement = 27,
+    ApproveDisbursement = 28,
+    ExecuteDisbursement = 29,
+    QueueAction = 30,
+    ExecuteQueuedAction = 31,
+    CancelQueuedAction = 32,
+    SetRecoveryCouncil = 33,
+    ApproveRecovery = 34,
+    ExecuteRecovery = 35,
+    ScheduleOwnerRotation = 36,
+    FundRewardPool = 37,
+    ClaimEpochReward = 38,
+    PublishMerkleRoot = 39,
+    ClaimMerkleReward = 40,
+    SetEmissionSchedule = 41,
+    EmitRewards = 42,
+    Stake = 43,
+    Unstake = 44,
+    SetAutoCompound = 45,
+    CompoundRewards = 46,
+    LiquidStake = 47,
+    LiquidUnstake = 48,
+    DepositStakeRewards = 49,
+    SetCrankBounty = 50,
+    SetTreasuryBurnCap = 51,
+    BurnTreasury = 52,
+    InitStats = 53,
+    FundJobWithVoucher = 54,
+    RegisterSessionKey = 55,
+    ApproveDelegate = 56,
+    RevokeDelegate = 57,
+    CreateSubscription = 58,
+    ProcessSubscription = 59,
+    CancelJob = 60,
+    TransferJobAuthority = 61,
+    AcceptJobAuthority = 62,
+    CreateJobBundle = 63,
+    FundJobBundle = 64,
+    CancelJobBundle = 65,
+    AggregateJobBundle = 66,
+    InitJobTree = 67,
+    AppendCompressedJob = 68,
+    ReplaceCompressedJob = 69,
+    SettleCompressedJob = 70,
+    InitJobIndex = 71,
+    UpdateJobMetadata = 72,
+    SetEscrowTokenAccount = 73,
+    MigrateEscrowMint = 74,
+    ConvertEscrowMintVault = 75,
+    RedenominateJobs = 76,
+    ConvertIsolatedJobMint = 77,
+    InitEscrowWithSeed = 78,
+    RaiseJobPriority = 79,
+    InitJobQueue = 80,
+    ClaimJob = 81,
+    SetJobExchangeRate = 82,
+    EstimateJobCost = 83,
+    SetPriceFeed = 84,
+    FundJobAtOraclePrice = 85,
+    FreezeJob = 86,
+    UnfreezeJob = 87,
+    SyncIncidentMode = 88,
+    RegisterColdWallet = 89,
+    ScheduleTreasurySweep = 90,
+    SweepToColdWallet = 91,
+    CancelTreasurySweep = 92,
+    GetVersion = 93,
+    GetEscrowSummary = 94,
+    GetJobSummary = 95,
+    GetEscrowedAmount = 96,
+    ReconcileEscrow = 97,
 }
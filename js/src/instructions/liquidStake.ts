@@ -0,0 +1,92 @@
+import { ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { PublicKey, SystemProgram, TransactionInstruction } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import {
+    findAssociatedTokenAddress,
+    findEscrowAddress,
+    findStakePoolAddress,
+    findStakePoolAssociatedTokenAddress,
+    findStakeReceiptMintAddress,
+    u64,
+} from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+    amount: bigint;
+}
+
+const DataLayout = struct<Data>([u8('instruction'), u64('amount')]);
+
+export const createLiquidStakeInstruction = async (
+    amount: number | bigint,
+    funder: PublicKey,
+    sourceToken: PublicKey,
+    authority: PublicKey,
+    receiptOwner: PublicKey
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [stakePool] = await findStakePoolAddress(escrow);
+    const [stakePoolAssociatedToken] = await findStakePoolAssociatedTokenAddress(stakePool, RNDR_TOKEN_MINT);
+    const [receiptMint] = await findStakeReceiptMintAddress(stakePool);
+    const [receiptOwnerAssociatedToken] = await findAssociatedTokenAddress(receiptOwner, receiptMint);
+    return liquidStake(
+        amount,
+        RNDR_TOKEN_MINT,
+        funder,
+        sourceToken,
+        authority,
+        escrow,
+        stakePool,
+        stakePoolAssociatedToken,
+        receiptMint,
+        receiptOwner,
+        receiptOwnerAssociatedToken
+    );
+};
+
+export const liquidStake = (
+    amount: number | bigint,
+    tokenMint: PublicKey,
+    funder: PublicKey,
+    sourceToken: PublicKey,
+    authority: PublicKey,
+    escrow: PublicKey,
+    stakePool: PublicKey,
+    stakePoolAssociatedToken: PublicKey,
+    receiptMint: PublicKey,
+    receiptOwner: PublicKey,
+    receiptOwnerAssociatedToken: PublicKey
+): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.LiquidStake,
+            amount: BigInt(amount),
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: tokenMint, isSigner: false, isWritable: false },
+        { pubkey: funder, isSigner: true, isWritable: true },
+        { pubkey: sourceToken, isSigner: false, isWritable: true },
+        { pubkey: authority, isSigner: true, isWritable: false },
+        { pubkey: escrow, isSigner: false, isWritable: false },
+        { pubkey: stakePool, isSigner: false, isWritable: true },
+        { pubkey: stakePoolAssociatedToken, isSigner: false, isWritable: true },
+        { pubkey: receiptMint, isSigner: false, isWritable: true },
+        { pubkey: receiptOwner, isSigner: false, isWritable: false },
+        { pubkey: receiptOwnerAssociatedToken, isSigner: false, isWritable: true },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+        { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+        { pubkey: ASSOCIATED_TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
@@ -0,0 +1,75 @@
+import { TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { PublicKey, TransactionInstruction } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import {
+    findEscrowAddress,
+    findStakePoolAddress,
+    findStakePoolAssociatedTokenAddress,
+    findStakeReceiptMintAddress,
+    u64,
+} from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+    receiptAmount: bigint;
+}
+
+const DataLayout = struct<Data>([u8('instruction'), u64('receiptAmount')]);
+
+export const createLiquidUnstakeInstruction = async (
+    receiptAmount: number | bigint,
+    sourceReceiptToken: PublicKey,
+    receiptAuthority: PublicKey,
+    destinationToken: PublicKey
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [stakePool] = await findStakePoolAddress(escrow);
+    const [stakePoolAssociatedToken] = await findStakePoolAssociatedTokenAddress(stakePool, RNDR_TOKEN_MINT);
+    const [receiptMint] = await findStakeReceiptMintAddress(stakePool);
+    return liquidUnstake(
+        receiptAmount,
+        stakePool,
+        stakePoolAssociatedToken,
+        receiptMint,
+        sourceReceiptToken,
+        receiptAuthority,
+        destinationToken
+    );
+};
+
+export const liquidUnstake = (
+    receiptAmount: number | bigint,
+    stakePool: PublicKey,
+    stakePoolAssociatedToken: PublicKey,
+    receiptMint: PublicKey,
+    sourceReceiptToken: PublicKey,
+    receiptAuthority: PublicKey,
+    destinationToken: PublicKey
+): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.LiquidUnstake,
+            receiptAmount: BigInt(receiptAmount),
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: stakePool, isSigner: false, isWritable: true },
+        { pubkey: stakePoolAssociatedToken, isSigner: false, isWritable: true },
+        { pubkey: receiptMint, isSigner: false, isWritable: true },
+        { pubkey: sourceReceiptToken, isSigner: false, isWritable: true },
+        { pubkey: receiptAuthority, isSigner: true, isWritable: false },
+        { pubkey: destinationToken, isSigner: false, isWritable: true },
+        { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
@@ -0,0 +1,98 @@
+import { ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { PublicKey, SystemProgram, TransactionInstruction } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import {
+    findAssociatedTokenAddress,
+    findConfigAddress,
+    findEscrowAddress,
+    findMintMigrationAddress,
+    u64,
+} from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+    rateNumerator: bigint;
+    rateDenominator: bigint;
+}
+
+const DataLayout = struct<Data>([u8('instruction'), u64('rateNumerator'), u64('rateDenominator')]);
+
+/**
+ * `index` is the number of mint migrations of the escrow whose pooled tokens have been converted, and
+ * `destinationToken` is the token account of the current mint that the escrow's old tokens will be sent to
+ */
+export const createMigrateEscrowMintInstruction = async (
+    owner: PublicKey,
+    governance: PublicKey,
+    index: number,
+    newTokenMint: PublicKey,
+    destinationToken: PublicKey,
+    rateNumerator: number | bigint,
+    rateDenominator: number | bigint,
+    approver?: PublicKey
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [config] = await findConfigAddress();
+    const [mintMigration] = await findMintMigrationAddress(escrow, index);
+    const [newEscrowAssociatedToken] = await findAssociatedTokenAddress(escrow, newTokenMint);
+    return migrateEscrowMint(
+        escrow,
+        owner,
+        config,
+        governance,
+        mintMigration,
+        newTokenMint,
+        newEscrowAssociatedToken,
+        destinationToken,
+        rateNumerator,
+        rateDenominator,
+        approver
+    );
+};
+
+export const migrateEscrowMint = (
+    escrow: PublicKey,
+    owner: PublicKey,
+    config: PublicKey,
+    governance: PublicKey,
+    mintMigration: PublicKey,
+    newTokenMint: PublicKey,
+    newEscrowAssociatedToken: PublicKey,
+    destinationToken: PublicKey,
+    rateNumerator: number | bigint,
+    rateDenominator: number | bigint,
+    approver?: PublicKey
+): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.MigrateEscrowMint,
+            rateNumerator: BigInt(rateNumerator),
+            rateDenominator: BigInt(rateDenominator),
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: escrow, isSigner: false, isWritable: false },
+        { pubkey: owner, isSigner: true, isWritable: true },
+        { pubkey: config, isSigner: false, isWritable: false },
+        { pubkey: governance, isSigner: true, isWritable: false },
+        { pubkey: mintMigration, isSigner: false, isWritable: true },
+        { pubkey: newTokenMint, isSigner: false, isWritable: false },
+        { pubkey: newEscrowAssociatedToken, isSigner: false, isWritable: true },
+        { pubkey: destinationToken, isSigner: false, isWritable: false },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+        { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+        { pubkey: ASSOCIATED_TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+    ];
+    if (approver) keys.push({ pubkey: approver, isSigner: true, isWritable: false });
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
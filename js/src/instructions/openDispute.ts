@@ -0,0 +1,54 @@
+import { PublicKey, SystemProgram, TransactionInstruction } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import { findConfigAddress, findDisputeAddress, findEscrowAddress, findJobAddress } from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+}
+
+const DataLayout = struct<Data>([u8('instruction')]);
+
+export const createOpenDisputeInstruction = async (
+    opener: PublicKey,
+    authority: PublicKey,
+    jobId: number | bigint
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [job] = await findJobAddress(escrow, authority, jobId);
+    const [config] = await findConfigAddress();
+    const [dispute] = await findDisputeAddress(job);
+    return openDispute(opener, escrow, config, job, dispute);
+};
+
+export const openDispute = (
+    opener: PublicKey,
+    escrow: PublicKey,
+    config: PublicKey,
+    job: PublicKey,
+    dispute: PublicKey
+): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.OpenDispute,
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: opener, isSigner: true, isWritable: true },
+        { pubkey: escrow, isSigner: false, isWritable: false },
+        { pubkey: config, isSigner: false, isWritable: false },
+        { pubkey: job, isSigner: false, isWritable: true },
+        { pubkey: dispute, isSigner: false, isWritable: true },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
@@ -0,0 +1,77 @@
+import { TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { PublicKey, SystemProgram, TransactionInstruction } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import {
+    findEscrowAddress,
+    findEscrowAssociatedTokenAddress,
+    findEscrowShardAddress,
+    findEscrowShardIndex,
+    findJobAddress,
+    findSubscriptionAddress,
+} from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+}
+
+const DataLayout = struct<Data>([u8('instruction')]);
+
+export const createProcessSubscriptionInstruction = async (
+    funder: PublicKey,
+    sourceToken: PublicKey,
+    authority: PublicKey,
+    jobId: number | bigint
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [escrowAssociatedToken] = await findEscrowAssociatedTokenAddress(escrow, RNDR_TOKEN_MINT);
+    const [subscription] = await findSubscriptionAddress(escrow, authority, jobId);
+    const [job] = await findJobAddress(escrow, authority, jobId);
+    const [escrowShard] = await findEscrowShardAddress(escrow, findEscrowShardIndex(job));
+    return processSubscriptionInstruction(
+        funder,
+        subscription,
+        sourceToken,
+        escrow,
+        escrowAssociatedToken,
+        job,
+        escrowShard
+    );
+};
+
+export const processSubscriptionInstruction = (
+    funder: PublicKey,
+    subscription: PublicKey,
+    sourceToken: PublicKey,
+    escrow: PublicKey,
+    escrowAssociatedToken: PublicKey,
+    job: PublicKey,
+    escrowShard: PublicKey
+): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.ProcessSubscription,
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: funder, isSigner: true, isWritable: true },
+        { pubkey: subscription, isSigner: false, isWritable: true },
+        { pubkey: sourceToken, isSigner: false, isWritable: true },
+        { pubkey: escrow, isSigner: false, isWritable: false },
+        { pubkey: escrowAssociatedToken, isSigner: false, isWritable: true },
+        { pubkey: job, isSigner: false, isWritable: true },
+        { pubkey: escrowShard, isSigner: false, isWritable: true },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+        { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
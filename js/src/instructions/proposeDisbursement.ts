@@ -0,0 +1,62 @@
+import { PublicKey, SystemProgram, TransactionInstruction } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import { findEscrowAddress, findJobAddress, findProposalAddress, u64 } from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+    proposalId: bigint;
+    amount: bigint;
+}
+
+const DataLayout = struct<Data>([u8('instruction'), u64('proposalId'), u64('amount')]);
+
+export const createProposeDisbursementInstruction = async (
+    proposalId: number | bigint,
+    amount: number | bigint,
+    owner: PublicKey,
+    authority: PublicKey,
+    jobId: number | bigint,
+    destinationToken: PublicKey
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [job] = await findJobAddress(escrow, authority, jobId);
+    const [proposal] = await findProposalAddress(escrow, proposalId);
+    return proposeDisbursement(proposalId, amount, owner, escrow, job, destinationToken, proposal);
+};
+
+export const proposeDisbursement = (
+    proposalId: number | bigint,
+    amount: number | bigint,
+    owner: PublicKey,
+    escrow: PublicKey,
+    job: PublicKey,
+    destinationToken: PublicKey,
+    proposal: PublicKey
+): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.ProposeDisbursement,
+            proposalId: BigInt(proposalId),
+            amount: BigInt(amount),
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: owner, isSigner: true, isWritable: true },
+        { pubkey: escrow, isSigner: false, isWritable: false },
+        { pubkey: job, isSigner: false, isWritable: false },
+        { pubkey: destinationToken, isSigner: false, isWritable: false },
+        { pubkey: proposal, isSigner: false, isWritable: true },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
@@ -0,0 +1,96 @@
+import { ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { PublicKey, SystemProgram, TransactionInstruction } from '@solana/web3.js';
+import { blob, struct, u32, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import {
+    findEscrowAddress,
+    findMerkleDistributionAddress,
+    findMerkleDistributionAssociatedTokenAddress,
+    u64,
+} from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+    epoch: bigint;
+    root: Uint8Array;
+    nodeCount: number;
+    amount: bigint;
+}
+
+const DataLayout = struct<Data>([u8('instruction'), u64('epoch'), blob(32, 'root'), u32('nodeCount'), u64('amount')]);
+
+export const createPublishMerkleRootInstruction = async (
+    epoch: number | bigint,
+    root: Uint8Array,
+    nodeCount: number,
+    amount: number | bigint,
+    owner: PublicKey,
+    sourceToken: PublicKey,
+    authority: PublicKey
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [merkleDistribution] = await findMerkleDistributionAddress(escrow, epoch);
+    const [merkleDistributionAssociatedToken] = await findMerkleDistributionAssociatedTokenAddress(
+        merkleDistribution,
+        RNDR_TOKEN_MINT
+    );
+    return publishMerkleRoot(
+        epoch,
+        root,
+        nodeCount,
+        amount,
+        RNDR_TOKEN_MINT,
+        owner,
+        sourceToken,
+        authority,
+        escrow,
+        merkleDistribution,
+        merkleDistributionAssociatedToken
+    );
+};
+
+export const publishMerkleRoot = (
+    epoch: number | bigint,
+    root: Uint8Array,
+    nodeCount: number,
+    amount: number | bigint,
+    tokenMint: PublicKey,
+    owner: PublicKey,
+    sourceToken: PublicKey,
+    authority: PublicKey,
+    escrow: PublicKey,
+    merkleDistribution: PublicKey,
+    merkleDistributionAssociatedToken: PublicKey
+): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.PublishMerkleRoot,
+            epoch: BigInt(epoch),
+            root,
+            nodeCount,
+            amount: BigInt(amount),
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: tokenMint, isSigner: false, isWritable: false },
+        { pubkey: owner, isSigner: true, isWritable: true },
+        { pubkey: sourceToken, isSigner: false, isWritable: true },
+        { pubkey: authority, isSigner: true, isWritable: false },
+        { pubkey: escrow, isSigner: false, isWritable: false },
+        { pubkey: merkleDistribution, isSigner: false, isWritable: true },
+        { pubkey: merkleDistributionAssociatedToken, isSigner: false, isWritable: true },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+        { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+        { pubkey: ASSOCIATED_TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
@@ -0,0 +1,72 @@
+import { PublicKey, SystemProgram, TransactionInstruction } from '@solana/web3.js';
+import { Layout, struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID } from '../constants';
+import { findConfigAddress, findQueuedActionAddress, publicKey, u64 } from '../util';
+import { RNDRInstruction } from './instruction';
+import { ConfigChange, configValueLayout } from './setConfig';
+
+export enum AdminActionType {
+    SetEscrowOwner = 0,
+    SetConfig = 1,
+}
+
+export type AdminAction =
+    | { type: AdminActionType.SetEscrowOwner; newOwner: PublicKey }
+    | { type: AdminActionType.SetConfig; change: ConfigChange };
+
+const actionLayouts = (action: AdminAction): Layout[] => {
+    switch (action.type) {
+        case AdminActionType.SetEscrowOwner:
+            return [u8('actionType'), publicKey('value')];
+        case AdminActionType.SetConfig:
+            return [u8('actionType'), u8('field'), configValueLayout(action.change.field)];
+    }
+};
+
+export const createQueueActionInstruction = async (
+    authority: PublicKey,
+    target: PublicKey,
+    actionId: number | bigint,
+    action: AdminAction
+): Promise<TransactionInstruction> => {
+    const [config] = await findConfigAddress();
+    const [queuedAction] = await findQueuedActionAddress(target, actionId);
+    return queueAction(actionId, action, authority, config, target, queuedAction);
+};
+
+export const queueAction = (
+    actionId: number | bigint,
+    action: AdminAction,
+    authority: PublicKey,
+    config: PublicKey,
+    target: PublicKey,
+    queuedAction: PublicKey
+): TransactionInstruction => {
+    const DataLayout = struct<Record<string, unknown>>([u8('instruction'), u64('actionId'), ...actionLayouts(action)]);
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.QueueAction,
+            actionId: BigInt(actionId),
+            actionType: action.type,
+            ...(action.type === AdminActionType.SetEscrowOwner
+                ? { value: action.newOwner }
+                : { field: action.change.field, value: action.change.value }),
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: authority, isSigner: true, isWritable: true },
+        { pubkey: config, isSigner: false, isWritable: false },
+        { pubkey: target, isSigner: false, isWritable: true },
+        { pubkey: queuedAction, isSigner: false, isWritable: true },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
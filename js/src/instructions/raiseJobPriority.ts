@@ -0,0 +1,71 @@
+import { TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { PublicKey, SystemProgram, TransactionInstruction } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import {
+    findConfigAddress,
+    findEscrowAddress,
+    findJobAddress,
+    findTreasuryAddress,
+    findTreasuryAssociatedTokenAddress,
+} from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+    priority: number;
+}
+
+const DataLayout = struct<Data>([u8('instruction'), u8('priority')]);
+
+export const createRaiseJobPriorityInstruction = async (
+    authority: PublicKey,
+    jobId: number | bigint,
+    sourceToken: PublicKey,
+    priority: number
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [job] = await findJobAddress(escrow, authority, jobId);
+    const [treasury] = await findTreasuryAddress(escrow);
+    const [treasuryAssociatedToken] = await findTreasuryAssociatedTokenAddress(treasury, RNDR_TOKEN_MINT);
+    const [config] = await findConfigAddress();
+    return raiseJobPriority(priority, authority, escrow, job, sourceToken, treasury, treasuryAssociatedToken, config);
+};
+
+export const raiseJobPriority = (
+    priority: number,
+    authority: PublicKey,
+    escrow: PublicKey,
+    job: PublicKey,
+    sourceToken: PublicKey,
+    treasury: PublicKey,
+    treasuryAssociatedToken: PublicKey,
+    config: PublicKey
+): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.RaiseJobPriority,
+            priority,
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: authority, isSigner: true, isWritable: true },
+        { pubkey: escrow, isSigner: false, isWritable: false },
+        { pubkey: job, isSigner: false, isWritable: true },
+        { pubkey: sourceToken, isSigner: false, isWritable: true },
+        { pubkey: treasury, isSigner: false, isWritable: false },
+        { pubkey: treasuryAssociatedToken, isSigner: false, isWritable: true },
+        { pubkey: config, isSigner: false, isWritable: false },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+        { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
@@ -0,0 +1,51 @@
+import { PublicKey, SystemProgram, TransactionInstruction } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import { ESCROW_SHARD_COUNT, findEscrowAddress, findEscrowShardAddress } from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+}
+
+const DataLayout = struct<Data>([u8('instruction')]);
+
+/** `tokenAccount` is the token account holding the escrow's pooled tokens */
+export const createReconcileEscrowInstruction = async (
+    tokenAccount: PublicKey,
+    payer: PublicKey
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const escrowShards = [];
+    for (let shard = 0; shard < ESCROW_SHARD_COUNT; shard++) {
+        const [escrowShard] = await findEscrowShardAddress(escrow, shard);
+        escrowShards.push(escrowShard);
+    }
+    return reconcileEscrow(escrow, tokenAccount, payer, escrowShards);
+};
+
+export const reconcileEscrow = (
+    escrow: PublicKey,
+    tokenAccount: PublicKey,
+    payer: PublicKey,
+    escrowShards: PublicKey[]
+): TransactionInstruction => {
+    if (escrowShards.length !== ESCROW_SHARD_COUNT) throw new Error('Every escrow shard is required');
+
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode({ instruction: RNDRInstruction.ReconcileEscrow }, data);
+
+    const keys = [
+        { pubkey: escrow, isSigner: false, isWritable: true },
+        { pubkey: tokenAccount, isSigner: false, isWritable: false },
+        { pubkey: payer, isSigner: true, isWritable: true },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+        ...escrowShards.map((pubkey) => ({ pubkey, isSigner: false, isWritable: false })),
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
@@ -0,0 +1,45 @@
+import { PublicKey, SystemProgram, TransactionInstruction } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import { findEscrowAddress, findMintMigrationAddress } from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+}
+
+const DataLayout = struct<Data>([u8('instruction')]);
+
+export const createRedenominateJobsInstruction = async (
+    payer: PublicKey,
+    index: number,
+    jobs: PublicKey[]
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [mintMigration] = await findMintMigrationAddress(escrow, index);
+    return redenominateJobs(escrow, mintMigration, payer, jobs);
+};
+
+export const redenominateJobs = (
+    escrow: PublicKey,
+    mintMigration: PublicKey,
+    payer: PublicKey,
+    jobs: PublicKey[]
+): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode({ instruction: RNDRInstruction.RedenominateJobs }, data);
+
+    const keys = [
+        { pubkey: escrow, isSigner: false, isWritable: false },
+        { pubkey: mintMigration, isSigner: false, isWritable: true },
+        { pubkey: payer, isSigner: true, isWritable: true },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+        ...jobs.map((pubkey) => ({ pubkey, isSigner: false, isWritable: true })),
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
@@ -0,0 +1,53 @@
+import { PublicKey, SystemProgram, TransactionInstruction } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import { findEscrowAddress, findTreasuryAddress, findTreasuryAssociatedTokenAddress } from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+}
+
+const DataLayout = struct<Data>([u8('instruction')]);
+
+/** Register the cold wallet the treasury can be swept to, cancelling any sweep scheduled to the previous one */
+export const createRegisterColdWalletInstruction = async (
+    owner: PublicKey,
+    coldWallet: PublicKey
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [treasury] = await findTreasuryAddress(escrow);
+    const [treasuryAssociatedToken] = await findTreasuryAssociatedTokenAddress(treasury, RNDR_TOKEN_MINT);
+    return registerColdWallet(owner, escrow, treasury, treasuryAssociatedToken, coldWallet);
+};
+
+export const registerColdWallet = (
+    owner: PublicKey,
+    escrow: PublicKey,
+    treasury: PublicKey,
+    treasuryAssociatedToken: PublicKey,
+    coldWallet: PublicKey
+): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.RegisterColdWallet,
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: owner, isSigner: true, isWritable: true },
+        { pubkey: escrow, isSigner: false, isWritable: false },
+        { pubkey: treasury, isSigner: false, isWritable: true },
+        { pubkey: treasuryAssociatedToken, isSigner: false, isWritable: false },
+        { pubkey: coldWallet, isSigner: false, isWritable: false },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
@@ -0,0 +1,64 @@
+import { PublicKey, SystemProgram, TransactionInstruction } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import { findEscrowAddress, findSessionKeyAddress, publicKey, u64 } from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+    sessionKey: PublicKey;
+    expirySlot: bigint;
+    instructionMask: bigint;
+}
+
+const DataLayout = struct<Data>([
+    u8('instruction'),
+    publicKey('sessionKey'),
+    u64('expirySlot'),
+    u64('instructionMask'),
+]);
+
+/** Register again with an `instructionMask` of zero to revoke the session key */
+export const createRegisterSessionKeyInstruction = async (
+    authority: PublicKey,
+    sessionKey: PublicKey,
+    expirySlot: number | bigint,
+    instructionMask: bigint
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [sessionKeyAccount] = await findSessionKeyAddress(escrow, authority, sessionKey);
+    return registerSessionKey(sessionKey, expirySlot, instructionMask, authority, escrow, sessionKeyAccount);
+};
+
+export const registerSessionKey = (
+    sessionKey: PublicKey,
+    expirySlot: number | bigint,
+    instructionMask: bigint,
+    authority: PublicKey,
+    escrow: PublicKey,
+    sessionKeyAccount: PublicKey
+): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.RegisterSessionKey,
+            sessionKey,
+            expirySlot: BigInt(expirySlot),
+            instructionMask,
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: authority, isSigner: true, isWritable: true },
+        { pubkey: escrow, isSigner: false, isWritable: false },
+        { pubkey: sessionKeyAccount, isSigner: false, isWritable: true },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
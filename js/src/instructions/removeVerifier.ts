@@ -0,0 +1,49 @@
+import { PublicKey, TransactionInstruction } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import { findEscrowAddress, findVerifierRegistryAddress, publicKey } from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+    verifier: PublicKey;
+}
+
+const DataLayout = struct<Data>([u8('instruction'), publicKey('verifier')]);
+
+export const createRemoveVerifierInstruction = async (
+    verifier: PublicKey,
+    owner: PublicKey
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [verifierRegistry] = await findVerifierRegistryAddress(escrow);
+    return removeVerifier(verifier, escrow, owner, verifierRegistry);
+};
+
+export const removeVerifier = (
+    verifier: PublicKey,
+    escrow: PublicKey,
+    owner: PublicKey,
+    verifierRegistry: PublicKey
+): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.RemoveVerifier,
+            verifier,
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: escrow, isSigner: false, isWritable: false },
+        { pubkey: owner, isSigner: true, isWritable: false },
+        { pubkey: verifierRegistry, isSigner: false, isWritable: true },
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
@@ -0,0 +1,92 @@
+import { PublicKey, TransactionInstruction } from '@solana/web3.js';
+import { blob, struct, u8 } from 'buffer-layout';
+import { ACCOUNT_COMPRESSION_PROGRAM_ID, NOOP_PROGRAM_ID, RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import { CompressedJob, JobStatus, MAX_JOB_TREE_DEPTH } from '../state';
+import { findEscrowAddress, findJobTreeAddress, publicKey, u64 } from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+    id: bigint;
+    amount: bigint;
+    status: number;
+    jobNode: PublicKey;
+    node: PublicKey;
+    root: Uint8Array;
+}
+
+const DataLayout = struct<Data>([
+    u8('instruction'),
+    u64('id'),
+    u64('amount'),
+    u8('status'),
+    publicKey('jobNode'),
+    publicKey('node'),
+    blob(32, 'root'),
+]);
+
+/**
+ * Assign a compressed job to a node, or back to open with the default public key. A committed job can only be
+ * reassigned or reopened with the signature of the node it's committed to. The proof of the job's current leaf is of a
+ * recent root of the merkle tree, and can stop at the tree's canopy.
+ */
+export const createReplaceCompressedJobInstruction = async (
+    job: CompressedJob,
+    node: PublicKey,
+    root: Uint8Array,
+    proof: Uint8Array[],
+    authority: PublicKey,
+    merkleTree: PublicKey
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [jobTree] = await findJobTreeAddress(escrow, authority);
+    return replaceCompressedJob(job, node, root, proof, authority, escrow, jobTree, merkleTree);
+};
+
+export const replaceCompressedJob = (
+    job: CompressedJob,
+    node: PublicKey,
+    root: Uint8Array,
+    proof: Uint8Array[],
+    authority: PublicKey,
+    escrow: PublicKey,
+    jobTree: PublicKey,
+    merkleTree: PublicKey
+): TransactionInstruction => {
+    if (proof.length > MAX_JOB_TREE_DEPTH) throw new Error('Job tree proof is too long');
+
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.ReplaceCompressedJob,
+            id: job.id,
+            amount: job.amount,
+            status: job.status,
+            jobNode: job.node,
+            node,
+            root,
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: authority, isSigner: true, isWritable: false },
+        { pubkey: escrow, isSigner: false, isWritable: false },
+        { pubkey: jobTree, isSigner: false, isWritable: false },
+        { pubkey: merkleTree, isSigner: false, isWritable: true },
+        { pubkey: NOOP_PROGRAM_ID, isSigner: false, isWritable: false },
+        { pubkey: ACCOUNT_COMPRESSION_PROGRAM_ID, isSigner: false, isWritable: false },
+    ];
+    if (job.status === JobStatus.Committed) {
+        keys.push({ pubkey: job.node, isSigner: true, isWritable: false });
+    }
+    for (const node of proof) {
+        keys.push({ pubkey: new PublicKey(node), isSigner: false, isWritable: false });
+    }
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
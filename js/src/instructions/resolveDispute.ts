@@ -0,0 +1,88 @@
+import { TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { PublicKey, TransactionInstruction } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import {
+    findAssociatedTokenAddress,
+    findDisputeAddress,
+    findEscrowAddress,
+    findEscrowAssociatedTokenAddress,
+    findEscrowShardAddress,
+    findEscrowShardIndex,
+    findJobAddress,
+    u64,
+} from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+    nodeAmount: bigint;
+}
+
+const DataLayout = struct<Data>([u8('instruction'), u64('nodeAmount')]);
+
+export const createResolveDisputeInstruction = async (
+    nodeAmount: number | bigint,
+    arbitrator: PublicKey,
+    node: PublicKey,
+    authority: PublicKey,
+    jobId: number | bigint
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [escrowAssociatedToken] = await findEscrowAssociatedTokenAddress(escrow, RNDR_TOKEN_MINT);
+    const [job] = await findJobAddress(escrow, authority, jobId);
+    const [dispute] = await findDisputeAddress(job);
+    const [nodeToken] = await findAssociatedTokenAddress(node, RNDR_TOKEN_MINT);
+    const [authorityToken] = await findAssociatedTokenAddress(authority, RNDR_TOKEN_MINT);
+    const [escrowShard] = await findEscrowShardAddress(escrow, findEscrowShardIndex(job));
+    return resolveDispute(
+        nodeAmount,
+        arbitrator,
+        escrow,
+        escrowAssociatedToken,
+        job,
+        dispute,
+        nodeToken,
+        authorityToken,
+        escrowShard
+    );
+};
+
+export const resolveDispute = (
+    nodeAmount: number | bigint,
+    arbitrator: PublicKey,
+    escrow: PublicKey,
+    escrowAssociatedToken: PublicKey,
+    job: PublicKey,
+    dispute: PublicKey,
+    nodeToken: PublicKey,
+    authorityToken: PublicKey,
+    escrowShard?: PublicKey
+): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.ResolveDispute,
+            nodeAmount: BigInt(nodeAmount),
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: arbitrator, isSigner: true, isWritable: false },
+        { pubkey: escrow, isSigner: false, isWritable: true },
+        { pubkey: escrowAssociatedToken, isSigner: false, isWritable: true },
+        { pubkey: job, isSigner: false, isWritable: true },
+        { pubkey: dispute, isSigner: false, isWritable: true },
+        { pubkey: nodeToken, isSigner: false, isWritable: true },
+        { pubkey: authorityToken, isSigner: false, isWritable: true },
+        { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+    ];
+    if (escrowShard) keys.push({ pubkey: escrowShard, isSigner: false, isWritable: true });
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
@@ -0,0 +1,67 @@
+import { AccountMeta, PublicKey, TransactionInstruction } from '@solana/web3.js';
+import { blob, struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import { findEscrowAddress } from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+    resultHash: Uint8Array;
+    salt: Uint8Array;
+}
+
+const DataLayout = struct<Data>([u8('instruction'), blob(32, 'resultHash'), blob(32, 'salt')]);
+
+/** Proof of a result for the escrow's proof verifier program */
+export interface Proof {
+    proofVerifier: PublicKey;
+    proof: Uint8Array;
+    /** Accounts passed through to the proof verifier */
+    accounts?: AccountMeta[];
+}
+
+export const createRevealResultInstruction = async (
+    resultHash: Uint8Array,
+    salt: Uint8Array,
+    node: PublicKey,
+    job: PublicKey,
+    proof?: Proof
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    return revealResult(resultHash, salt, node, job, escrow, proof);
+};
+
+export const revealResult = (
+    resultHash: Uint8Array,
+    salt: Uint8Array,
+    node: PublicKey,
+    job: PublicKey,
+    escrow: PublicKey,
+    proof?: Proof
+): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.RevealResult,
+            resultHash,
+            salt,
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: node, isSigner: true, isWritable: false },
+        { pubkey: job, isSigner: false, isWritable: true },
+        { pubkey: escrow, isSigner: false, isWritable: false },
+    ];
+    if (proof) {
+        keys.push({ pubkey: proof.proofVerifier, isSigner: false, isWritable: false });
+        keys.push(...(proof.accounts ?? []));
+    }
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data: proof ? Buffer.concat([data, Buffer.from(proof.proof)]) : data,
+    });
+};
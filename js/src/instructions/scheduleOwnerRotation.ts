@@ -0,0 +1,57 @@
+import { PublicKey, SystemProgram, TransactionInstruction } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import { findConfigAddress, findEscrowAddress, findQueuedActionAddress, publicKey, u64 } from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+    rotationId: bigint;
+    newOwner: PublicKey;
+}
+
+const DataLayout = struct<Data>([u8('instruction'), u64('rotationId'), publicKey('newOwner')]);
+
+export const createScheduleOwnerRotationInstruction = async (
+    rotationId: number | bigint,
+    newOwner: PublicKey,
+    owner: PublicKey
+): Promise<TransactionInstruction> => {
+    const [config] = await findConfigAddress();
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [queuedAction] = await findQueuedActionAddress(escrow, rotationId);
+    return scheduleOwnerRotation(rotationId, newOwner, owner, config, escrow, queuedAction);
+};
+
+export const scheduleOwnerRotation = (
+    rotationId: number | bigint,
+    newOwner: PublicKey,
+    owner: PublicKey,
+    config: PublicKey,
+    escrow: PublicKey,
+    queuedAction: PublicKey
+): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.ScheduleOwnerRotation,
+            rotationId: BigInt(rotationId),
+            newOwner,
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: owner, isSigner: true, isWritable: true },
+        { pubkey: config, isSigner: false, isWritable: false },
+        { pubkey: escrow, isSigner: false, isWritable: true },
+        { pubkey: queuedAction, isSigner: false, isWritable: true },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
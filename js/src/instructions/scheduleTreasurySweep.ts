@@ -0,0 +1,62 @@
+import { PublicKey, TransactionInstruction } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import {
+    findConfigAddress,
+    findEscrowAddress,
+    findTreasuryAddress,
+    findTreasuryAssociatedTokenAddress,
+    u64,
+} from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+    amount: bigint;
+}
+
+const DataLayout = struct<Data>([u8('instruction'), u64('amount')]);
+
+/** Schedule a sweep of the treasury to its cold wallet, which can be executed once the sweep delay of the config has passed */
+export const createScheduleTreasurySweepInstruction = async (
+    amount: number | bigint,
+    owner: PublicKey
+): Promise<TransactionInstruction> => {
+    const [config] = await findConfigAddress();
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [treasury] = await findTreasuryAddress(escrow);
+    const [treasuryAssociatedToken] = await findTreasuryAssociatedTokenAddress(treasury, RNDR_TOKEN_MINT);
+    return scheduleTreasurySweep(amount, owner, config, escrow, treasury, treasuryAssociatedToken);
+};
+
+export const scheduleTreasurySweep = (
+    amount: number | bigint,
+    owner: PublicKey,
+    config: PublicKey,
+    escrow: PublicKey,
+    treasury: PublicKey,
+    treasuryAssociatedToken: PublicKey
+): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.ScheduleTreasurySweep,
+            amount: BigInt(amount),
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: owner, isSigner: true, isWritable: false },
+        { pubkey: config, isSigner: false, isWritable: false },
+        { pubkey: escrow, isSigner: false, isWritable: false },
+        { pubkey: treasury, isSigner: false, isWritable: true },
+        { pubkey: treasuryAssociatedToken, isSigner: false, isWritable: false },
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
@@ -0,0 +1,59 @@
+import { PublicKey, SystemProgram, SYSVAR_SLOT_HASHES_PUBKEY, TransactionInstruction } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import { findEscrowAddress, findJobAddress, findVerifierRegistryAddress, findVerifierSelectionAddress } from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+    count: number;
+}
+
+const DataLayout = struct<Data>([u8('instruction'), u8('count')]);
+
+export const createSelectVerifiersInstruction = async (
+    count: number,
+    funder: PublicKey,
+    authority: PublicKey,
+    jobId: number | bigint
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [verifierRegistry] = await findVerifierRegistryAddress(escrow);
+    const [job] = await findJobAddress(escrow, authority, jobId);
+    const [verifierSelection] = await findVerifierSelectionAddress(job);
+    return selectVerifiers(count, funder, escrow, verifierRegistry, job, verifierSelection);
+};
+
+export const selectVerifiers = (
+    count: number,
+    funder: PublicKey,
+    escrow: PublicKey,
+    verifierRegistry: PublicKey,
+    job: PublicKey,
+    verifierSelection: PublicKey
+): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.SelectVerifiers,
+            count,
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: funder, isSigner: true, isWritable: true },
+        { pubkey: escrow, isSigner: false, isWritable: false },
+        { pubkey: verifierRegistry, isSigner: false, isWritable: false },
+        { pubkey: job, isSigner: false, isWritable: false },
+        { pubkey: verifierSelection, isSigner: false, isWritable: true },
+        { pubkey: SYSVAR_SLOT_HASHES_PUBKEY, isSigner: false, isWritable: false },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
@@ -0,0 +1,51 @@
+import { PublicKey, SystemProgram, TransactionInstruction } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import { findEscrowAddress, publicKey } from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+    approver: PublicKey;
+}
+
+const DataLayout = struct<Data>([u8('instruction'), publicKey('approver')]);
+
+/** If the escrow has an approver, `currentApprover` must sign to change or clear it */
+export const createSetApproverInstruction = async (
+    approver: PublicKey,
+    owner: PublicKey,
+    currentApprover?: PublicKey
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    return setApprover(approver, escrow, owner, currentApprover);
+};
+
+export const setApprover = (
+    approver: PublicKey,
+    escrow: PublicKey,
+    owner: PublicKey,
+    currentApprover?: PublicKey
+): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.SetApprover,
+            approver,
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: escrow, isSigner: false, isWritable: true },
+        { pubkey: owner, isSigner: true, isWritable: true },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ];
+    if (currentApprover) keys.push({ pubkey: currentApprover, isSigner: true, isWritable: false });
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
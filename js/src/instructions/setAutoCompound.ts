@@ -0,0 +1,47 @@
+import { PublicKey, TransactionInstruction } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import { bool, findEscrowAddress, findNodeAddress } from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+    autoCompound: boolean;
+}
+
+const DataLayout = struct<Data>([u8('instruction'), bool('autoCompound')]);
+
+export const createSetAutoCompoundInstruction = async (
+    nodeAuthority: PublicKey,
+    autoCompound: boolean
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [node] = await findNodeAddress(escrow, nodeAuthority);
+    return setAutoCompound(nodeAuthority, node, autoCompound);
+};
+
+export const setAutoCompound = (
+    nodeAuthority: PublicKey,
+    node: PublicKey,
+    autoCompound: boolean
+): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.SetAutoCompound,
+            autoCompound,
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: nodeAuthority, isSigner: true, isWritable: false },
+        { pubkey: node, isSigner: false, isWritable: true },
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
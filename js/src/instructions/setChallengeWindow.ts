@@ -0,0 +1,47 @@
+import { PublicKey, SystemProgram, TransactionInstruction } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import { findEscrowAddress, u64 } from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+    challengeWindow: bigint;
+}
+
+const DataLayout = struct<Data>([u8('instruction'), u64('challengeWindow')]);
+
+export const createSetChallengeWindowInstruction = async (
+    challengeWindow: number | bigint,
+    owner: PublicKey
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    return setChallengeWindow(challengeWindow, escrow, owner);
+};
+
+export const setChallengeWindow = (
+    challengeWindow: number | bigint,
+    escrow: PublicKey,
+    owner: PublicKey
+): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.SetChallengeWindow,
+            challengeWindow: BigInt(challengeWindow),
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: escrow, isSigner: false, isWritable: true },
+        { pubkey: owner, isSigner: true, isWritable: true },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
@@ -0,0 +1,151 @@
+import { AccountMeta, PublicKey, SystemProgram, TransactionInstruction } from '@solana/web3.js';
+import { Layout, struct, u16, u32, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID } from '../constants';
+import { IncidentMode, RewardTier, RoundingPolicy } from '../state';
+import { bool, findConfigAddress, publicKey, u64 } from '../util';
+import { RNDRInstruction } from './instruction';
+
+export enum ConfigField {
+    Governance = 0,
+    FeeBps = 1,
+    MinStake = 2,
+    DisputeWindow = 3,
+    TimelockDuration = 4,
+    RateLimitWindow = 5,
+    RateLimitMax = 6,
+    Guardian = 7,
+    InstantOwnerChange = 8,
+    TimelockedFields = 9,
+    RewardTier = 10,
+    CancelGracePeriod = 11,
+    CancelFeeBps = 12,
+    PrioritySurchargeBps = 13,
+    PriorityClaimDelay = 14,
+    RoundingPolicy = 15,
+    MinDisbursement = 16,
+    ObhPrice = 17,
+    MaxPriceAge = 18,
+    MaxPriceConfidenceBps = 19,
+    Compliance = 20,
+    SweepDelay = 21,
+    IncidentMode = 22,
+}
+
+export type ConfigChange =
+    | { field: ConfigField.Governance | ConfigField.Guardian | ConfigField.Compliance; value: PublicKey }
+    | {
+          field:
+              | ConfigField.FeeBps
+              | ConfigField.RateLimitMax
+              | ConfigField.TimelockedFields
+              | ConfigField.CancelFeeBps
+              | ConfigField.PrioritySurchargeBps
+              | ConfigField.MaxPriceConfidenceBps;
+          value: number;
+      }
+    | {
+          field:
+              | ConfigField.MinStake
+              | ConfigField.DisputeWindow
+              | ConfigField.TimelockDuration
+              | ConfigField.RateLimitWindow
+              | ConfigField.CancelGracePeriod
+              | ConfigField.PriorityClaimDelay
+              | ConfigField.MinDisbursement
+              | ConfigField.MaxPriceAge
+              | ConfigField.SweepDelay;
+          value: bigint;
+      }
+    | { field: ConfigField.InstantOwnerChange; value: boolean }
+    | { field: ConfigField.RoundingPolicy; value: RoundingPolicy }
+    | { field: ConfigField.IncidentMode; value: IncidentMode }
+    | { field: ConfigField.RewardTier; value: IndexedRewardTier }
+    | { field: ConfigField.ObhPrice; value: IndexedObhPrice };
+
+export interface IndexedRewardTier extends RewardTier {
+    index: number;
+}
+
+export interface IndexedObhPrice {
+    index: number;
+    price: bigint;
+}
+
+interface Data<T> {
+    instruction: number;
+    field: number;
+    value: T;
+}
+
+/** @internal */
+export const configValueLayout = (field: ConfigField): Layout => {
+    switch (field) {
+        case ConfigField.Governance:
+        case ConfigField.Guardian:
+        case ConfigField.Compliance:
+            return publicKey('value');
+        case ConfigField.FeeBps:
+        case ConfigField.CancelFeeBps:
+        case ConfigField.PrioritySurchargeBps:
+        case ConfigField.MaxPriceConfidenceBps:
+            return u16('value');
+        case ConfigField.RateLimitMax:
+        case ConfigField.TimelockedFields:
+            return u32('value');
+        case ConfigField.InstantOwnerChange:
+            return bool('value');
+        case ConfigField.RoundingPolicy:
+        case ConfigField.IncidentMode:
+            return u8('value');
+        case ConfigField.RewardTier:
+            return struct<IndexedRewardTier>([u8('index'), u64('minStake'), u16('multiplierBps')], 'value');
+        case ConfigField.ObhPrice:
+            return struct<IndexedObhPrice>([u8('index'), u64('price')], 'value');
+        default:
+            return u64('value');
+    }
+};
+
+export const createSetConfigInstruction = async (
+    change: ConfigChange,
+    governance: PublicKey,
+    payer?: PublicKey
+): Promise<TransactionInstruction> => {
+    const [config] = await findConfigAddress();
+    return setConfig(change, config, governance, payer);
+};
+
+export const setConfig = (
+    change: ConfigChange,
+    config: PublicKey,
+    governance: PublicKey,
+    payer?: PublicKey
+): TransactionInstruction => {
+    const DataLayout = struct<Data<unknown>>([u8('instruction'), u8('field'), configValueLayout(change.field)]);
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.SetConfig,
+            field: change.field,
+            value: change.value,
+        },
+        data
+    );
+
+    const keys: AccountMeta[] = [
+        { pubkey: config, isSigner: false, isWritable: true },
+        { pubkey: governance, isSigner: true, isWritable: false },
+    ];
+    if (payer) {
+        keys.push(
+            { pubkey: payer, isSigner: true, isWritable: true },
+            { pubkey: SystemProgram.programId, isSigner: false, isWritable: false }
+        );
+    }
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
@@ -0,0 +1,50 @@
+import { PublicKey, SystemProgram, TransactionInstruction } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import { findCrankVaultAddress, findEscrowAddress, u64 } from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+    bounty: bigint;
+}
+
+const DataLayout = struct<Data>([u8('instruction'), u64('bounty')]);
+
+export const createSetCrankBountyInstruction = async (
+    owner: PublicKey,
+    bounty: number | bigint
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [crankVault] = await findCrankVaultAddress(escrow);
+    return setCrankBounty(owner, escrow, crankVault, bounty);
+};
+
+export const setCrankBounty = (
+    owner: PublicKey,
+    escrow: PublicKey,
+    crankVault: PublicKey,
+    bounty: number | bigint
+): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.SetCrankBounty,
+            bounty: BigInt(bounty),
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: owner, isSigner: true, isWritable: true },
+        { pubkey: escrow, isSigner: false, isWritable: false },
+        { pubkey: crankVault, isSigner: false, isWritable: true },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
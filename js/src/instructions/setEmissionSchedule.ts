@@ -0,0 +1,58 @@
+import { PublicKey, SystemProgram, TransactionInstruction } from '@solana/web3.js';
+import { struct, u16, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import { findEmissionScheduleAddress, findEscrowAddress, u64 } from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+    startEpoch: bigint;
+    initialAmount: bigint;
+    decayBps: number;
+}
+
+const DataLayout = struct<Data>([u8('instruction'), u64('startEpoch'), u64('initialAmount'), u16('decayBps')]);
+
+export const createSetEmissionScheduleInstruction = async (
+    owner: PublicKey,
+    startEpoch: number | bigint,
+    initialAmount: number | bigint,
+    decayBps: number
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [emissionSchedule] = await findEmissionScheduleAddress(escrow);
+    return setEmissionSchedule(owner, escrow, emissionSchedule, startEpoch, initialAmount, decayBps);
+};
+
+export const setEmissionSchedule = (
+    owner: PublicKey,
+    escrow: PublicKey,
+    emissionSchedule: PublicKey,
+    startEpoch: number | bigint,
+    initialAmount: number | bigint,
+    decayBps: number
+): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.SetEmissionSchedule,
+            startEpoch: BigInt(startEpoch),
+            initialAmount: BigInt(initialAmount),
+            decayBps,
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: owner, isSigner: true, isWritable: true },
+        { pubkey: escrow, isSigner: false, isWritable: false },
+        { pubkey: emissionSchedule, isSigner: false, isWritable: true },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
@@ -1,7 +1,7 @@
-import { PublicKey, TransactionInstruction } from '@solana/web3.js';
+import { PublicKey, SystemProgram, TransactionInstruction } from '@solana/web3.js';
 import { struct, u8 } from 'buffer-layout';
 import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
-import { findEscrowAddress, publicKey } from '../util';
+import { findConfigAddress, findEscrowAddress, publicKey } from '../util';
 import { RNDRInstruction } from './instruction';
 
 interface Data {
@@ -16,13 +16,15 @@ export const createSetEscrowOwnerInstruction = async (
     currentOwner: PublicKey
 ): Promise<TransactionInstruction> => {
     const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
-    return setEscrowOwnerInstruction(newOwner, escrow, currentOwner);
+    const [config] = await findConfigAddress();
+    return setEscrowOwnerInstruction(newOwner, escrow, currentOwner, config);
 };
 
 export const setEscrowOwnerInstruction = (
     newOwner: PublicKey,
     escrow: PublicKey,
-    currentOwner: PublicKey
+    currentOwner: PublicKey,
+    config: PublicKey
 ): TransactionInstruction => {
     const data = Buffer.alloc(DataLayout.span);
     DataLayout.encode(
@@ -35,7 +37,9 @@ export const setEscrowOwnerInstruction = (
 
     const keys = [
         { pubkey: escrow, isSigner: false, isWritable: true },
-        { pubkey: currentOwner, isSigner: true, isWritable: false },
+        { pubkey: currentOwner, isSigner: true, isWritable: true },
+        { pubkey: config, isSigner: false, isWritable: false },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
     ];
 
     return new TransactionInstruction({
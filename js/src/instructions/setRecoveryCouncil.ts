@@ -0,0 +1,64 @@
+import { PublicKey, SystemProgram, TransactionInstruction } from '@solana/web3.js';
+import { seq, struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import { MAX_RECOVERY_GUARDIANS } from '../state';
+import { findEscrowAddress, findRecoveryAddress, publicKey } from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+    threshold: number;
+    length: number;
+    guardians: PublicKey[];
+}
+
+export const createSetRecoveryCouncilInstruction = async (
+    threshold: number,
+    guardians: PublicKey[],
+    owner: PublicKey
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [recovery] = await findRecoveryAddress(escrow);
+    return setRecoveryCouncil(threshold, guardians, escrow, owner, recovery);
+};
+
+export const setRecoveryCouncil = (
+    threshold: number,
+    guardians: PublicKey[],
+    escrow: PublicKey,
+    owner: PublicKey,
+    recovery: PublicKey
+): TransactionInstruction => {
+    if (guardians.length > MAX_RECOVERY_GUARDIANS) throw new Error('Too many recovery guardians');
+
+    const DataLayout = struct<Data>([
+        u8('instruction'),
+        u8('threshold'),
+        u8('length'),
+        seq(publicKey(), guardians.length, 'guardians'),
+    ]);
+
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.SetRecoveryCouncil,
+            threshold,
+            length: guardians.length,
+            guardians,
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: escrow, isSigner: false, isWritable: true },
+        { pubkey: owner, isSigner: true, isWritable: true },
+        { pubkey: recovery, isSigner: false, isWritable: true },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
@@ -0,0 +1,58 @@
+import { ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { PublicKey, SystemProgram, TransactionInstruction } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import { findEscrowAddress, findTreasuryAddress, findTreasuryAssociatedTokenAddress, u64 } from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+    epochBurnCap: bigint;
+}
+
+const DataLayout = struct<Data>([u8('instruction'), u64('epochBurnCap')]);
+
+export const createSetTreasuryBurnCapInstruction = async (
+    owner: PublicKey,
+    epochBurnCap: number | bigint
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [treasury] = await findTreasuryAddress(escrow);
+    const [treasuryAssociatedToken] = await findTreasuryAssociatedTokenAddress(treasury, RNDR_TOKEN_MINT);
+    return setTreasuryBurnCap(RNDR_TOKEN_MINT, owner, escrow, treasury, treasuryAssociatedToken, epochBurnCap);
+};
+
+export const setTreasuryBurnCap = (
+    tokenMint: PublicKey,
+    owner: PublicKey,
+    escrow: PublicKey,
+    treasury: PublicKey,
+    treasuryAssociatedToken: PublicKey,
+    epochBurnCap: number | bigint
+): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.SetTreasuryBurnCap,
+            epochBurnCap: BigInt(epochBurnCap),
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: tokenMint, isSigner: false, isWritable: false },
+        { pubkey: owner, isSigner: true, isWritable: true },
+        { pubkey: escrow, isSigner: false, isWritable: false },
+        { pubkey: treasury, isSigner: false, isWritable: true },
+        { pubkey: treasuryAssociatedToken, isSigner: false, isWritable: true },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+        { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+        { pubkey: ASSOCIATED_TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
@@ -0,0 +1,101 @@
+import { TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { PublicKey, TransactionInstruction } from '@solana/web3.js';
+import { blob, struct, u8 } from 'buffer-layout';
+import { ACCOUNT_COMPRESSION_PROGRAM_ID, NOOP_PROGRAM_ID, RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import { CompressedJob, MAX_JOB_TREE_DEPTH } from '../state';
+import { findEscrowAddress, findEscrowAssociatedTokenAddress, findJobTreeAddress, publicKey, u64 } from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+    id: bigint;
+    amount: bigint;
+    status: number;
+    node: PublicKey;
+    root: Uint8Array;
+}
+
+const DataLayout = struct<Data>([
+    u8('instruction'),
+    u64('id'),
+    u64('amount'),
+    u8('status'),
+    publicKey('node'),
+    blob(32, 'root'),
+]);
+
+/**
+ * Pay a compressed job to the node it's assigned to, or refund it if it's still open, with a proof of its current leaf
+ * of a recent root of the merkle tree
+ */
+export const createSettleCompressedJobInstruction = async (
+    job: CompressedJob,
+    root: Uint8Array,
+    proof: Uint8Array[],
+    authority: PublicKey,
+    destinationToken: PublicKey,
+    merkleTree: PublicKey
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [escrowAssociatedToken] = await findEscrowAssociatedTokenAddress(escrow, RNDR_TOKEN_MINT);
+    const [jobTree] = await findJobTreeAddress(escrow, authority);
+    return settleCompressedJob(
+        job,
+        root,
+        proof,
+        authority,
+        escrow,
+        escrowAssociatedToken,
+        jobTree,
+        destinationToken,
+        merkleTree
+    );
+};
+
+export const settleCompressedJob = (
+    job: CompressedJob,
+    root: Uint8Array,
+    proof: Uint8Array[],
+    authority: PublicKey,
+    escrow: PublicKey,
+    escrowAssociatedToken: PublicKey,
+    jobTree: PublicKey,
+    destinationToken: PublicKey,
+    merkleTree: PublicKey
+): TransactionInstruction => {
+    if (proof.length > MAX_JOB_TREE_DEPTH) throw new Error('Job tree proof is too long');
+
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.SettleCompressedJob,
+            id: job.id,
+            amount: job.amount,
+            status: job.status,
+            node: job.node,
+            root,
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: authority, isSigner: true, isWritable: false },
+        { pubkey: escrow, isSigner: false, isWritable: true },
+        { pubkey: escrowAssociatedToken, isSigner: false, isWritable: true },
+        { pubkey: jobTree, isSigner: false, isWritable: true },
+        { pubkey: destinationToken, isSigner: false, isWritable: true },
+        { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+        { pubkey: merkleTree, isSigner: false, isWritable: true },
+        { pubkey: NOOP_PROGRAM_ID, isSigner: false, isWritable: false },
+        { pubkey: ACCOUNT_COMPRESSION_PROGRAM_ID, isSigner: false, isWritable: false },
+    ];
+    for (const node of proof) {
+        keys.push({ pubkey: new PublicKey(node), isSigner: false, isWritable: false });
+    }
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
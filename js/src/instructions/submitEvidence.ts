@@ -0,0 +1,52 @@
+import { PublicKey, TransactionInstruction } from '@solana/web3.js';
+import { blob, struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import { findDisputeAddress, findEscrowAddress, findJobAddress } from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+    evidenceHash: Uint8Array;
+}
+
+const DataLayout = struct<Data>([u8('instruction'), blob(32, 'evidenceHash')]);
+
+export const createSubmitEvidenceInstruction = async (
+    evidenceHash: Uint8Array,
+    party: PublicKey,
+    authority: PublicKey,
+    jobId: number | bigint
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [job] = await findJobAddress(escrow, authority, jobId);
+    const [dispute] = await findDisputeAddress(job);
+    return submitEvidence(evidenceHash, party, job, dispute);
+};
+
+export const submitEvidence = (
+    evidenceHash: Uint8Array,
+    party: PublicKey,
+    job: PublicKey,
+    dispute: PublicKey
+): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.SubmitEvidence,
+            evidenceHash,
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: party, isSigner: true, isWritable: false },
+        { pubkey: job, isSigner: false, isWritable: false },
+        { pubkey: dispute, isSigner: false, isWritable: true },
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
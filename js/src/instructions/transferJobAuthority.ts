@@ -0,0 +1,50 @@
+import { PublicKey, SystemProgram, TransactionInstruction } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import { findEscrowAddress, publicKey } from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+    newAuthority: PublicKey;
+}
+
+const DataLayout = struct<Data>([u8('instruction'), publicKey('newAuthority')]);
+
+export const createTransferJobAuthorityInstruction = async (
+    authority: PublicKey,
+    job: PublicKey,
+    newAuthority: PublicKey
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    return transferJobAuthority(authority, escrow, job, newAuthority);
+};
+
+export const transferJobAuthority = (
+    authority: PublicKey,
+    escrow: PublicKey,
+    job: PublicKey,
+    newAuthority: PublicKey
+): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.TransferJobAuthority,
+            newAuthority,
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: authority, isSigner: true, isWritable: true },
+        { pubkey: escrow, isSigner: false, isWritable: false },
+        { pubkey: job, isSigner: false, isWritable: true },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
@@ -0,0 +1,55 @@
+import { TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { PublicKey, TransactionInstruction } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import { findEscrowAddress, findNodeAddress, findNodeAssociatedTokenAddress, u64 } from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+    amount: bigint;
+}
+
+const DataLayout = struct<Data>([u8('instruction'), u64('amount')]);
+
+export const createUnstakeInstruction = async (
+    amount: number | bigint,
+    nodeAuthority: PublicKey,
+    destinationToken: PublicKey
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [node] = await findNodeAddress(escrow, nodeAuthority);
+    const [nodeAssociatedToken] = await findNodeAssociatedTokenAddress(node, RNDR_TOKEN_MINT);
+    return unstake(amount, nodeAuthority, node, nodeAssociatedToken, destinationToken);
+};
+
+export const unstake = (
+    amount: number | bigint,
+    nodeAuthority: PublicKey,
+    node: PublicKey,
+    nodeAssociatedToken: PublicKey,
+    destinationToken: PublicKey
+): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.Unstake,
+            amount: BigInt(amount),
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: nodeAuthority, isSigner: true, isWritable: false },
+        { pubkey: node, isSigner: false, isWritable: true },
+        { pubkey: nodeAssociatedToken, isSigner: false, isWritable: true },
+        { pubkey: destinationToken, isSigner: false, isWritable: true },
+        { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
@@ -0,0 +1,37 @@
+import { PublicKey, SystemProgram, TransactionInstruction } from '@solana/web3.js';
+import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import { encodeJobMetadata, JobMetadata } from '../state';
+import { findEscrowAddress } from '../util';
+import { RNDRInstruction } from './instruction';
+
+/** Set the metadata and tags of an open job, or clear the metadata with an empty URI and a zeroed hash */
+export const createUpdateJobMetadataInstruction = async (
+    authority: PublicKey,
+    job: PublicKey,
+    metadata: JobMetadata
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    return updateJobMetadata(authority, escrow, job, metadata);
+};
+
+export const updateJobMetadata = (
+    authority: PublicKey,
+    escrow: PublicKey,
+    job: PublicKey,
+    metadata: JobMetadata
+): TransactionInstruction => {
+    const data = Buffer.concat([Buffer.from([RNDRInstruction.UpdateJobMetadata]), encodeJobMetadata(metadata)]);
+
+    const keys = [
+        { pubkey: authority, isSigner: true, isWritable: true },
+        { pubkey: escrow, isSigner: false, isWritable: false },
+        { pubkey: job, isSigner: false, isWritable: true },
+        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
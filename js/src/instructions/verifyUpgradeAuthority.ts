@@ -0,0 +1,38 @@
+import { PublicKey, TransactionInstruction } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { RNDR_PROGRAM_ID } from '../constants';
+import { findConfigAddress, findProgramDataAddress } from '../util';
+import { RNDRInstruction } from './instruction';
+
+interface Data {
+    instruction: number;
+}
+
+const DataLayout = struct<Data>([u8('instruction')]);
+
+export const createVerifyUpgradeAuthorityInstruction = async (): Promise<TransactionInstruction> => {
+    const [config] = await findConfigAddress();
+    const [programData] = await findProgramDataAddress();
+    return verifyUpgradeAuthority(config, programData);
+};
+
+export const verifyUpgradeAuthority = (config: PublicKey, programData: PublicKey): TransactionInstruction => {
+    const data = Buffer.alloc(DataLayout.span);
+    DataLayout.encode(
+        {
+            instruction: RNDRInstruction.VerifyUpgradeAuthority,
+        },
+        data
+    );
+
+    const keys = [
+        { pubkey: config, isSigner: false, isWritable: false },
+        { pubkey: programData, isSigner: false, isWritable: false },
+    ];
+
+    return new TransactionInstruction({
+        keys,
+        programId: RNDR_PROGRAM_ID,
+        data,
+    });
+};
@@ -0,0 +1,17 @@
+import { PublicKey, TransactionInstruction } from '@solana/web3.js';
+import { RNDR_TOKEN_MINT } from '../constants';
+import { findCrankVaultAddress, findEscrowAddress } from '../util';
+
+/** Append the accounts to a crank instruction of the escrow that pay its crank vault's bounty to a recipient */
+export const withCrankBounty = async (
+    instruction: TransactionInstruction,
+    recipient: PublicKey
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [crankVault] = await findCrankVaultAddress(escrow);
+    instruction.keys.push(
+        { pubkey: crankVault, isSigner: false, isWritable: true },
+        { pubkey: recipient, isSigner: false, isWritable: true }
+    );
+    return instruction;
+};
@@ -0,0 +1,19 @@
+import { PublicKey, TransactionInstruction } from '@solana/web3.js';
+import { RNDR_TOKEN_MINT } from '../constants';
+import { findEscrowAddress, findEscrowAssociatedTokenAddress } from '../util';
+
+/**
+ * Replace the associated token account of the escrow in an instruction with the token account set with
+ * `SetEscrowTokenAccount`, which holds the escrow's pooled tokens instead.
+ */
+export const withEscrowTokenAccount = async (
+    instruction: TransactionInstruction,
+    tokenAccount: PublicKey
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [escrowAssociatedToken] = await findEscrowAssociatedTokenAddress(escrow, RNDR_TOKEN_MINT);
+    for (const key of instruction.keys) {
+        if (key.pubkey.equals(escrowAssociatedToken)) key.pubkey = tokenAccount;
+    }
+    return instruction;
+};
@@ -0,0 +1,22 @@
+import { PublicKey, TransactionInstruction } from '@solana/web3.js';
+import { RNDR_TOKEN_MINT } from '../constants';
+import { findEscrowAddress, findJobIndexAddress, findJobIndexPageAddress } from '../util';
+
+/**
+ * Append the job index of an authority and its next page to an instruction that creates jobs of the authority, so the
+ * jobs are recorded. Append it before the stats account, with the page the index records its next job in.
+ */
+export const withJobIndex = async (
+    instruction: TransactionInstruction,
+    authority: PublicKey,
+    page: number
+): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [jobIndex] = await findJobIndexAddress(escrow, authority);
+    const [jobIndexPage] = await findJobIndexPageAddress(jobIndex, page);
+    instruction.keys.push(
+        { pubkey: jobIndex, isSigner: false, isWritable: true },
+        { pubkey: jobIndexPage, isSigner: false, isWritable: true }
+    );
+    return instruction;
+};
@@ -0,0 +1,14 @@
+import { TransactionInstruction } from '@solana/web3.js';
+import { RNDR_TOKEN_MINT } from '../constants';
+import { findEscrowAddress, findJobQueueAddress } from '../util';
+
+/**
+ * Append the job queue of the escrow to a `FundJob` instruction, so the job it creates is queued for nodes. Append it
+ * after the job index accounts and before the stats account.
+ */
+export const withJobQueue = async (instruction: TransactionInstruction): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [jobQueue] = await findJobQueueAddress(escrow);
+    instruction.keys.push({ pubkey: jobQueue, isSigner: false, isWritable: true });
+    return instruction;
+};
@@ -0,0 +1,25 @@
+import { PublicKey, TransactionInstruction } from '@solana/web3.js';
+import { ACCOUNT_COMPRESSION_PROGRAM_ID, BUBBLEGUM_PROGRAM_ID, NOOP_PROGRAM_ID } from '../constants';
+import { findReceiptAuthorityAddress, findTreeConfigAddress } from '../util';
+
+/**
+ * Append the accounts to mint a compressed NFT receipt of the job to the funder with Bubblegum to a `FundJob`
+ * instruction. The receipt authority must be the delegate of the merkle tree. Append them after the job queue and
+ * before the stats account.
+ */
+export const withJobReceipt = async (
+    instruction: TransactionInstruction,
+    merkleTree: PublicKey
+): Promise<TransactionInstruction> => {
+    const [receiptAuthority] = await findReceiptAuthorityAddress();
+    const [treeConfig] = await findTreeConfigAddress(merkleTree);
+    instruction.keys.push(
+        { pubkey: receiptAuthority, isSigner: false, isWritable: false },
+        { pubkey: treeConfig, isSigner: false, isWritable: true },
+        { pubkey: merkleTree, isSigner: false, isWritable: true },
+        { pubkey: NOOP_PROGRAM_ID, isSigner: false, isWritable: false },
+        { pubkey: ACCOUNT_COMPRESSION_PROGRAM_ID, isSigner: false, isWritable: false },
+        { pubkey: BUBBLEGUM_PROGRAM_ID, isSigner: false, isWritable: false }
+    );
+    return instruction;
+};
@@ -0,0 +1,11 @@
+import { TransactionInstruction } from '@solana/web3.js';
+import { RNDR_TOKEN_MINT } from '../constants';
+import { findEscrowAddress, findStatsAddress } from '../util';
+
+/** Append the stats account of the escrow to an instruction that moves its tokens, so the flow is recorded */
+export const withStats = async (instruction: TransactionInstruction): Promise<TransactionInstruction> => {
+    const [escrow] = await findEscrowAddress(RNDR_TOKEN_MINT);
+    const [stats] = await findStatsAddress(escrow);
+    instruction.keys.push({ pubkey: stats, isSigner: false, isWritable: true });
+    return instruction;
+};
@@ -2,4 +2,32 @@ export enum AccountType {
     Uninitialized = 0,
     EscrowV1 = 1,
     JobV1 = 2,
+    EscrowShardV1 = 3,
+    VerifierRegistryV1 = 4,
+    VerifierSelectionV1 = 5,
+    DisputeV1 = 6,
+    ConfigV1 = 7,
+    ProposalV1 = 8,
+    QueuedActionV1 = 9,
+    RecoveryV1 = 10,
+    RewardPoolV1 = 11,
+    EpochWorkV1 = 12,
+    MerkleDistributionV1 = 13,
+    ClaimBitmapV1 = 14,
+    EmissionScheduleV1 = 15,
+    NodeV1 = 16,
+    StakePoolV1 = 17,
+    CrankVaultV1 = 18,
+    TreasuryV1 = 19,
+    StatsV1 = 20,
+    VoucherNonceV1 = 21,
+    SessionKeyV1 = 22,
+    DelegateAllowanceV1 = 23,
+    SubscriptionV1 = 24,
+    JobBundleV1 = 25,
+    JobTreeV1 = 26,
+    JobIndexV1 = 27,
+    JobIndexPageV1 = 28,
+    MintMigrationV1 = 29,
+    JobQueueV1 = 30,
 }
@@ -0,0 +1,48 @@
+import { AccountInfo, PublicKey } from '@solana/web3.js';
+import { blob, struct, u32, u8 } from 'buffer-layout';
+import { Parser, publicKey } from '../util';
+import { AccountType } from './accountType';
+
+export const CLAIM_BITMAP_BYTES = 1024;
+
+export const CLAIM_BITMAP_BITS = CLAIM_BITMAP_BYTES * 8;
+
+export interface ClaimBitmap {
+    accountType: AccountType;
+    merkleDistribution: PublicKey;
+    chunk: number;
+    bitmap: Uint8Array;
+}
+
+/** @internal */
+export const ClaimBitmapLayout = struct<ClaimBitmap>([
+    u8('accountType'),
+    publicKey('merkleDistribution'),
+    u32('chunk'),
+    blob(CLAIM_BITMAP_BYTES, 'bitmap'),
+]);
+
+export const CLAIM_BITMAP_SIZE = ClaimBitmapLayout.span;
+
+/** Chunk of the claim bitmap that records the claim of a leaf index */
+export const claimBitmapChunk = (index: number): number => Math.floor(index / CLAIM_BITMAP_BITS);
+
+/** Whether a claim bitmap records the claim of a leaf index in its chunk */
+export const isClaimed = (claimBitmap: ClaimBitmap, index: number): boolean => {
+    const bit = index % CLAIM_BITMAP_BITS;
+    return (claimBitmap.bitmap[Math.floor(bit / 8)] & (1 << bit % 8)) !== 0;
+};
+
+export const isClaimBitmap = (info: AccountInfo<Buffer>): boolean => {
+    return info.data.length === CLAIM_BITMAP_SIZE && info.data.readUIntLE(0, 1) === AccountType.ClaimBitmapV1;
+};
+
+export const parseClaimBitmap: Parser<ClaimBitmap> = (pubkey: PublicKey, info: AccountInfo<Buffer>) => {
+    if (!isClaimBitmap(info)) return;
+    const data = ClaimBitmapLayout.decode(info.data);
+    return {
+        pubkey,
+        info,
+        data,
+    };
+};
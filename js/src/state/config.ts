@@ -0,0 +1,161 @@
+import { AccountInfo, PublicKey } from '@solana/web3.js';
+import { seq, struct, u16, u32, u8 } from 'buffer-layout';
+import { bool, Parser, publicKey, u64 } from '../util';
+import { AccountType } from './accountType';
+import { IncidentMode } from './incidentMode';
+import { RoundingPolicy } from './roundingPolicy';
+
+export const MAX_REWARD_TIERS = 4;
+
+export const BASE_REWARD_MULTIPLIER_BPS = 10_000;
+
+export const MAX_PRICE_TIERS = 3;
+
+export const MILLI_OBH_PER_OBH = BigInt(1_000);
+
+export interface RewardTier {
+    minStake: bigint;
+    multiplierBps: number;
+}
+
+export interface Config {
+    accountType: AccountType;
+    governance: PublicKey;
+    feeBps: number;
+    minStake: bigint;
+    disputeWindow: bigint;
+    timelockDuration: bigint;
+    rateLimitWindow: bigint;
+    rateLimitMax: number;
+    guardian: PublicKey;
+    instantOwnerChange: boolean;
+    timelockedFields: number;
+    rewardTiers: RewardTier[];
+    cancelGracePeriod: bigint;
+    cancelFeeBps: number;
+    prioritySurchargeBps: number;
+    priorityClaimDelay: bigint;
+    roundingPolicy: RoundingPolicy;
+    minDisbursement: bigint;
+    obhPrices: bigint[];
+    /** Slots an oracle price can be behind the current slot when it's consumed */
+    maxPriceAge: bigint;
+    /** Maximum confidence interval of an oracle price when it's consumed, in basis points of the price */
+    maxPriceConfidenceBps: number;
+    /** Compliance authority that can freeze jobs under a legal hold, or the default public key if there is none */
+    compliance: PublicKey;
+    /** Seconds a scheduled treasury sweep to a cold wallet must wait before it can be executed */
+    sweepDelay: bigint;
+    /** Direction funds can flow through escrows in during an incident, once synced to them with SyncIncidentMode */
+    incidentMode: IncidentMode;
+}
+
+/** @internal */
+interface PackedConfig extends Config {
+    timelockedFieldsHigh: number;
+}
+
+/** @internal */
+export const RewardTierLayout = struct<RewardTier>([u64('minStake'), u16('multiplierBps')]);
+
+/** @internal */
+export const ConfigLayout = struct<PackedConfig>([
+    u8('accountType'),
+    publicKey('governance'),
+    u16('feeBps'),
+    u64('minStake'),
+    u64('disputeWindow'),
+    u64('timelockDuration'),
+    u64('rateLimitWindow'),
+    u32('rateLimitMax'),
+    publicKey('guardian'),
+    bool('instantOwnerChange'),
+    u16('timelockedFields'),
+    seq(RewardTierLayout, MAX_REWARD_TIERS, 'rewardTiers'),
+    u64('cancelGracePeriod'),
+    u16('cancelFeeBps'),
+    u16('prioritySurchargeBps'),
+    u64('priorityClaimDelay'),
+    u8('roundingPolicy'),
+    u64('minDisbursement'),
+    u16('timelockedFieldsHigh'),
+    seq(u64(), MAX_PRICE_TIERS, 'obhPrices'),
+    u64('maxPriceAge'),
+    u16('maxPriceConfidenceBps'),
+    publicKey('compliance'),
+    u64('sweepDelay'),
+    u8('incidentMode'),
+]);
+
+export const CONFIG_SIZE = ConfigLayout.span;
+
+/** Size of configs created before the rounding policy was appended */
+export const LEGACY_CONFIG_SIZE = 166;
+
+/** Size of configs created before the minimum disbursement was appended */
+export const PRE_MIN_DISBURSEMENT_CONFIG_SIZE = 167;
+
+/** Size of configs created before the OctaneBench-hour prices were appended */
+export const PRE_OBH_PRICES_CONFIG_SIZE = 177;
+
+/** Size of configs created before the oracle price guards were appended */
+export const PRE_MAX_PRICE_AGE_CONFIG_SIZE = 201;
+
+/** Size of configs created before the compliance authority was appended */
+export const PRE_COMPLIANCE_CONFIG_SIZE = 211;
+
+/** Size of configs created before the sweep delay was appended */
+export const PRE_SWEEP_DELAY_CONFIG_SIZE = 243;
+
+/** Size of configs created before the incident mode was appended */
+export const PRE_INCIDENT_MODE_CONFIG_SIZE = 251;
+
+/** Reward multiplier of a node with a stake, in basis points */
+export const rewardMultiplierBps = (config: Config, stake: bigint): number => {
+    let tier: RewardTier | undefined;
+    for (const rewardTier of config.rewardTiers) {
+        if (rewardTier.multiplierBps === 0 || rewardTier.minStake > stake) continue;
+        if (!tier || rewardTier.minStake > tier.minStake) tier = rewardTier;
+    }
+    return tier ? tier.multiplierBps : BASE_REWARD_MULTIPLIER_BPS;
+};
+
+/**
+ * Funding amount of a job of work in thousandths of an OctaneBench-hour rendered in a price tier, in RNDR base units,
+ * rounded up like the program does, or undefined if the tier is disabled
+ */
+export const jobCost = (config: Config, tier: number, milliObh: number | bigint): bigint | undefined => {
+    const price = config.obhPrices[tier];
+    if (!price) return;
+    return (price * BigInt(milliObh) + MILLI_OBH_PER_OBH - BigInt(1)) / MILLI_OBH_PER_OBH;
+};
+
+export const isConfig = (info: AccountInfo<Buffer>): boolean => {
+    return (
+        (info.data.length === CONFIG_SIZE ||
+            info.data.length === LEGACY_CONFIG_SIZE ||
+            info.data.length === PRE_MIN_DISBURSEMENT_CONFIG_SIZE ||
+            info.data.length === PRE_OBH_PRICES_CONFIG_SIZE ||
+            info.data.length === PRE_MAX_PRICE_AGE_CONFIG_SIZE ||
+            info.data.length === PRE_COMPLIANCE_CONFIG_SIZE ||
+            info.data.length === PRE_SWEEP_DELAY_CONFIG_SIZE ||
+            info.data.length === PRE_INCIDENT_MODE_CONFIG_SIZE) &&
+        info.data.readUIntLE(0, 1) === AccountType.ConfigV1
+    );
+};
+
+export const parseConfig: Parser<Config> = (pubkey: PublicKey, info: AccountInfo<Buffer>) => {
+    if (!isConfig(info)) return;
+    const buffer = Buffer.alloc(CONFIG_SIZE);
+    info.data.copy(buffer);
+    const { timelockedFieldsHigh, ...rest } = ConfigLayout.decode(buffer);
+    const data = {
+        ...rest,
+        timelockedFields: ((timelockedFieldsHigh << 16) | rest.timelockedFields) >>> 0,
+    };
+    return {
+        pubkey,
+        info,
+        data,
+    };
+};
@@ -0,0 +1,35 @@
+import { AccountInfo, PublicKey } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { Parser, publicKey, u64 } from '../util';
+import { AccountType } from './accountType';
+
+export interface CrankVault {
+    accountType: AccountType;
+    escrow: PublicKey;
+    bounty: bigint;
+    paid: bigint;
+}
+
+/** @internal */
+export const CrankVaultLayout = struct<CrankVault>([
+    u8('accountType'),
+    publicKey('escrow'),
+    u64('bounty'),
+    u64('paid'),
+]);
+
+export const CRANK_VAULT_SIZE = CrankVaultLayout.span;
+
+export const isCrankVault = (info: AccountInfo<Buffer>): boolean => {
+    return info.data.length === CRANK_VAULT_SIZE && info.data.readUIntLE(0, 1) === AccountType.CrankVaultV1;
+};
+
+export const parseCrankVault: Parser<CrankVault> = (pubkey: PublicKey, info: AccountInfo<Buffer>) => {
+    if (!isCrankVault(info)) return;
+    const data = CrankVaultLayout.decode(info.data);
+    return {
+        pubkey,
+        info,
+        data,
+    };
+};
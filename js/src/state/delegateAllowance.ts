@@ -0,0 +1,39 @@
+import { AccountInfo, PublicKey } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { Parser, publicKey, u64 } from '../util';
+import { AccountType } from './accountType';
+
+export interface DelegateAllowance {
+    accountType: AccountType;
+    escrow: PublicKey;
+    delegate: PublicKey;
+    remaining: bigint;
+    expirySlot: bigint;
+}
+
+/** @internal */
+export const DelegateAllowanceLayout = struct<DelegateAllowance>([
+    u8('accountType'),
+    publicKey('escrow'),
+    publicKey('delegate'),
+    u64('remaining'),
+    u64('expirySlot'),
+]);
+
+export const DELEGATE_ALLOWANCE_SIZE = DelegateAllowanceLayout.span;
+
+export const isDelegateAllowance = (info: AccountInfo<Buffer>): boolean => {
+    return (
+        info.data.length === DELEGATE_ALLOWANCE_SIZE && info.data.readUIntLE(0, 1) === AccountType.DelegateAllowanceV1
+    );
+};
+
+export const parseDelegateAllowance: Parser<DelegateAllowance> = (pubkey: PublicKey, info: AccountInfo<Buffer>) => {
+    if (!isDelegateAllowance(info)) return;
+    const data = DelegateAllowanceLayout.decode(info.data);
+    return {
+        pubkey,
+        info,
+        data,
+    };
+};
@@ -0,0 +1,45 @@
+import { AccountInfo, PublicKey } from '@solana/web3.js';
+import { blob, ns64, struct, u8 } from 'buffer-layout';
+import { Parser, publicKey, u64 } from '../util';
+import { AccountType } from './accountType';
+
+export interface Dispute {
+    accountType: AccountType;
+    job: PublicKey;
+    opener: PublicKey;
+    openedAt: number;
+    authorityEvidenceHash: Uint8Array;
+    nodeEvidenceHash: Uint8Array;
+    resolvedAt: number;
+    nodeAmount: bigint;
+    authorityAmount: bigint;
+}
+
+/** @internal */
+export const DisputeLayout = struct<Dispute>([
+    u8('accountType'),
+    publicKey('job'),
+    publicKey('opener'),
+    ns64('openedAt'),
+    blob(32, 'authorityEvidenceHash'),
+    blob(32, 'nodeEvidenceHash'),
+    ns64('resolvedAt'),
+    u64('nodeAmount'),
+    u64('authorityAmount'),
+]);
+
+export const DISPUTE_SIZE = DisputeLayout.span;
+
+export const isDispute = (info: AccountInfo<Buffer>): boolean => {
+    return info.data.length === DISPUTE_SIZE && info.data.readUIntLE(0, 1) === AccountType.DisputeV1;
+};
+
+export const parseDispute: Parser<Dispute> = (pubkey: PublicKey, info: AccountInfo<Buffer>) => {
+    if (!isDispute(info)) return;
+    const data = DisputeLayout.decode(info.data);
+    return {
+        pubkey,
+        info,
+        data,
+    };
+};
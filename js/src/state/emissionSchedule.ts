@@ -0,0 +1,45 @@
+import { AccountInfo, PublicKey } from '@solana/web3.js';
+import { struct, u16, u8 } from 'buffer-layout';
+import { Parser, publicKey, u64 } from '../util';
+import { AccountType } from './accountType';
+
+export const MAX_DECAY_BPS = 10_000;
+
+export interface EmissionSchedule {
+    accountType: AccountType;
+    escrow: PublicKey;
+    startEpoch: bigint;
+    initialAmount: bigint;
+    decayBps: number;
+    nextEpoch: bigint;
+    emitted: bigint;
+}
+
+/** @internal */
+export const EmissionScheduleLayout = struct<EmissionSchedule>([
+    u8('accountType'),
+    publicKey('escrow'),
+    u64('startEpoch'),
+    u64('initialAmount'),
+    u16('decayBps'),
+    u64('nextEpoch'),
+    u64('emitted'),
+]);
+
+export const EMISSION_SCHEDULE_SIZE = EmissionScheduleLayout.span;
+
+export const isEmissionSchedule = (info: AccountInfo<Buffer>): boolean => {
+    return (
+        info.data.length === EMISSION_SCHEDULE_SIZE && info.data.readUIntLE(0, 1) === AccountType.EmissionScheduleV1
+    );
+};
+
+export const parseEmissionSchedule: Parser<EmissionSchedule> = (pubkey: PublicKey, info: AccountInfo<Buffer>) => {
+    if (!isEmissionSchedule(info)) return;
+    const data = EmissionScheduleLayout.decode(info.data);
+    return {
+        pubkey,
+        info,
+        data,
+    };
+};
@@ -0,0 +1,37 @@
+import { AccountInfo, PublicKey } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { bool, Parser, publicKey, u64 } from '../util';
+import { AccountType } from './accountType';
+
+export interface EpochWork {
+    accountType: AccountType;
+    rewardPool: PublicKey;
+    node: PublicKey;
+    work: bigint;
+    claimed: boolean;
+}
+
+/** @internal */
+export const EpochWorkLayout = struct<EpochWork>([
+    u8('accountType'),
+    publicKey('rewardPool'),
+    publicKey('node'),
+    u64('work'),
+    bool('claimed'),
+]);
+
+export const EPOCH_WORK_SIZE = EpochWorkLayout.span;
+
+export const isEpochWork = (info: AccountInfo<Buffer>): boolean => {
+    return info.data.length === EPOCH_WORK_SIZE && info.data.readUIntLE(0, 1) === AccountType.EpochWorkV1;
+};
+
+export const parseEpochWork: Parser<EpochWork> = (pubkey: PublicKey, info: AccountInfo<Buffer>) => {
+    if (!isEpochWork(info)) return;
+    const data = EpochWorkLayout.decode(info.data);
+    return {
+        pubkey,
+        info,
+        data,
+    };
+};
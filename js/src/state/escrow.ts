@@ -1,26 +1,124 @@
 import { AccountInfo, PublicKey } from '@solana/web3.js';
-import { struct, u8 } from 'buffer-layout';
-import { Parser, publicKey, u64 } from '../util';
+import { seq, struct, u8 } from 'buffer-layout';
+import { bool, Parser, publicKey, u64 } from '../util';
 import { AccountType } from './accountType';
+import { IncidentMode } from './incidentMode';
+
+export const MAX_OWNER_HISTORY = 8;
+
+export interface OwnerRotation {
+    previousOwner: PublicKey;
+    slot: bigint;
+}
 
 export interface Escrow {
     accountType: AccountType;
     amount: bigint;
     owner: PublicKey;
+    tokenMint: PublicKey;
+    challengeWindow: bigint;
+    proofVerifier: PublicKey;
+    arbitrator: PublicKey;
+    approver: PublicKey;
+    ownerHistory: OwnerRotation[];
+    /** Token account holding the escrow's pooled tokens, or the default public key for its associated token account */
+    tokenAccount: PublicKey;
+    /** Token mint the escrow's address was derived from before it was migrated, or the default public key */
+    originalTokenMint: PublicKey;
+    /** Number of mint migrations of the escrow whose pooled tokens have been converted */
+    mintMigrations: number;
+    /** Namespace the escrow's address was derived from, or zero for the default escrow of its token mint */
+    escrowId: bigint;
+    /** Oracle price account of the escrow's token that jobs are funded at, or the default public key */
+    priceFeed: PublicKey;
+    /** Incident mode of the config the escrow was last synced to with SyncIncidentMode */
+    incidentMode: IncidentMode;
+    /** Whether the last reconciliation found the escrow's token account holding fewer tokens than it tracks */
+    needsAttention: boolean;
+}
+
+interface RawEscrow extends Escrow {
+    ownerHistoryLen: number;
 }
 
 /** @internal */
-export const EscrowLayout = struct<Escrow>([u8('accountType'), u64('amount'), publicKey('owner')]);
+export const OwnerRotationLayout = struct<OwnerRotation>([publicKey('previousOwner'), u64('slot')]);
+
+/** @internal */
+export const EscrowLayout = struct<RawEscrow>([
+    u8('accountType'),
+    u64('amount'),
+    publicKey('owner'),
+    publicKey('tokenMint'),
+    u64('challengeWindow'),
+    publicKey('proofVerifier'),
+    publicKey('arbitrator'),
+    publicKey('approver'),
+    u8('ownerHistoryLen'),
+    seq(OwnerRotationLayout, MAX_OWNER_HISTORY, 'ownerHistory'),
+    publicKey('tokenAccount'),
+    publicKey('originalTokenMint'),
+    u8('mintMigrations'),
+    u64('escrowId'),
+    publicKey('priceFeed'),
+    u8('incidentMode'),
+    bool('needsAttention'),
+]);
 
 export const ESCROW_SIZE = EscrowLayout.span;
 
+/** Size of escrows created before the token mint and the following fields were appended */
+export const LEGACY_ESCROW_SIZE = 41;
+
+/** Size of escrows created before the challenge window and the following fields were appended */
+export const PRE_CHALLENGE_WINDOW_ESCROW_SIZE = 73;
+
+/** Size of escrows created before the owner history was appended */
+export const PRE_OWNER_HISTORY_ESCROW_SIZE = 177;
+
+/** Size of escrows created before the token account was appended */
+export const PRE_TOKEN_ACCOUNT_ESCROW_SIZE = 498;
+
+/** Size of escrows created before the mint migration fields were appended */
+export const PRE_MINT_MIGRATION_ESCROW_SIZE = 530;
+
+/** Size of escrows created before the escrow id was appended */
+export const PRE_ESCROW_ID_ESCROW_SIZE = 563;
+
+/** Size of escrows created before the price feed was appended */
+export const PRE_PRICE_FEED_ESCROW_SIZE = 571;
+
+/** Size of escrows created before the incident mode was appended */
+export const PRE_INCIDENT_MODE_ESCROW_SIZE = 603;
+
+/** Size of escrows created before the attention flag was appended */
+export const PRE_NEEDS_ATTENTION_ESCROW_SIZE = 604;
+
 export const isEscrow = (info: AccountInfo<Buffer>): boolean => {
-    return info.data.length === ESCROW_SIZE && info.data.readUIntLE(0, 1) === AccountType.EscrowV1;
+    return (
+        (info.data.length === ESCROW_SIZE ||
+            info.data.length === PRE_NEEDS_ATTENTION_ESCROW_SIZE ||
+            info.data.length === PRE_INCIDENT_MODE_ESCROW_SIZE ||
+            info.data.length === PRE_PRICE_FEED_ESCROW_SIZE ||
+            info.data.length === PRE_ESCROW_ID_ESCROW_SIZE ||
+            info.data.length === PRE_MINT_MIGRATION_ESCROW_SIZE ||
+            info.data.length === PRE_TOKEN_ACCOUNT_ESCROW_SIZE ||
+            info.data.length === PRE_OWNER_HISTORY_ESCROW_SIZE ||
+            info.data.length === PRE_CHALLENGE_WINDOW_ESCROW_SIZE ||
+            info.data.length === LEGACY_ESCROW_SIZE) &&
+        info.data.readUIntLE(0, 1) === AccountType.EscrowV1
+    );
 };
 
 export const parseEscrow: Parser<Escrow> = (pubkey: PublicKey, info: AccountInfo<Buffer>) => {
     if (!isEscrow(info)) return;
-    const data = EscrowLayout.decode(info.data);
+    const buffer = Buffer.alloc(ESCROW_SIZE);
+    info.data.copy(buffer);
+    const { ownerHistoryLen, ownerHistory, ...rest } = EscrowLayout.decode(buffer);
+    const data = {
+        ...rest,
+        ownerHistory: ownerHistory.slice(0, ownerHistoryLen),
+    };
     return {
         pubkey,
         info,
@@ -0,0 +1,35 @@
+import { AccountInfo, PublicKey } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { Parser, publicKey, u64 } from '../util';
+import { AccountType } from './accountType';
+
+export interface EscrowShard {
+    accountType: AccountType;
+    escrow: PublicKey;
+    index: number;
+    amount: bigint;
+}
+
+/** @internal */
+export const EscrowShardLayout = struct<EscrowShard>([
+    u8('accountType'),
+    publicKey('escrow'),
+    u8('index'),
+    u64('amount'),
+]);
+
+export const ESCROW_SHARD_SIZE = EscrowShardLayout.span;
+
+export const isEscrowShard = (info: AccountInfo<Buffer>): boolean => {
+    return info.data.length === ESCROW_SHARD_SIZE && info.data.readUIntLE(0, 1) === AccountType.EscrowShardV1;
+};
+
+export const parseEscrowShard: Parser<EscrowShard> = (pubkey: PublicKey, info: AccountInfo<Buffer>) => {
+    if (!isEscrowShard(info)) return;
+    const data = EscrowShardLayout.decode(info.data);
+    return {
+        pubkey,
+        info,
+        data,
+    };
+};
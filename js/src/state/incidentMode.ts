@@ -0,0 +1,6 @@
+/** Direction funds can flow through escrows in during an incident, set by the governance authority of the config */
+export enum IncidentMode {
+    Normal = 0,
+    FundOnly = 1,
+    DisburseOnly = 2,
+}
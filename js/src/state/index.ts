@@ -1,3 +1,33 @@
 export * from './accountType';
+export * from './claimBitmap';
+export * from './config';
+export * from './crankVault';
+export * from './delegateAllowance';
+export * from './dispute';
+export * from './emissionSchedule';
 export * from './escrow';
+export * from './epochWork';
+export * from './escrowShard';
+export * from './incidentMode';
 export * from './job';
+export * from './jobBundle';
+export * from './jobIndex';
+export * from './jobQueue';
+export * from './jobStatus';
+export * from './jobTree';
+export * from './merkleDistribution';
+export * from './mintMigration';
+export * from './node';
+export * from './proposal';
+export * from './queuedAction';
+export * from './recovery';
+export * from './rewardPool';
+export * from './roundingPolicy';
+export * from './sessionKey';
+export * from './stakePool';
+export * from './stats';
+export * from './subscription';
+export * from './treasury';
+export * from './verifierRegistry';
+export * from './verifierSelection';
+export * from './voucherNonce';
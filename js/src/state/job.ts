@@ -1,29 +1,231 @@
-import { AccountInfo, PublicKey } from '@solana/web3.js';
-import { struct, u8 } from 'buffer-layout';
-import { Parser, publicKey, u64 } from '../util';
+import { AccountInfo, GetProgramAccountsFilter, PublicKey } from '@solana/web3.js';
+import { toBufferLE } from 'bigint-buffer';
+import { blob, ns64, struct, u8 } from 'buffer-layout';
+import { bool, byteFilter, bytesFilter, Parser, publicKey, publicKeyFilter, u64 } from '../util';
 import { AccountType } from './accountType';
+import { JobStatus } from './jobStatus';
 
 export interface Job {
     accountType: AccountType;
     amount: bigint;
     authority: PublicKey;
+    isolated: boolean;
+    id: bigint;
+    status: JobStatus;
+    node: PublicKey;
+    resultCommitment: Uint8Array;
+    resultHash: Uint8Array;
+    revealedAt: number;
+    verifier: PublicKey;
+    evidenceHash: Uint8Array;
+    fundedAt: number;
+    pendingAuthority: PublicKey;
+    originalAuthority: PublicKey;
+    metadataUri: string;
+    metadataHash: Uint8Array;
+    tags: bigint;
+    /** Number of mint migrations of the escrow the job has been migrated by */
+    mintMigrations: number;
+    /** Priority of the job, up to `MAX_JOB_PRIORITY`, which nodes can commit to sooner the higher it is */
+    priority: number;
+    /**
+     * RNDR base units that `exchangeRateDenominator` base units of the job's token are worth, the rate a job funded in
+     * another token is settled at, or zero if no rate has been recorded
+     */
+    exchangeRateNumerator: bigint;
+    /** Base units of the job's token worth `exchangeRateNumerator` RNDR base units, or zero if no rate has been recorded */
+    exchangeRateDenominator: bigint;
+    /** Slot of the oracle price the exchange rate was recorded from */
+    exchangeRateSlot: bigint;
+    /** Whether the job is frozen under a legal hold, so its funds can't be disbursed or refunded */
+    frozen: boolean;
+}
+
+export const MAX_JOB_PRIORITY = 3;
+
+export const MAX_JOB_METADATA_URI_LEN = 96;
+
+/**
+ * Metadata of a job, pointing to its full off-chain manifest on Arweave, IPFS or elsewhere, with bitflags of the
+ * capabilities it requires from nodes, whose meaning is agreed on by clients and nodes
+ */
+export interface JobMetadata {
+    uri: string;
+    hash: Uint8Array;
+    tags: bigint;
+}
+
+interface RawJob extends Omit<Job, 'metadataUri'> {
+    metadataUriLen: number;
+    metadataUri: Uint8Array;
 }
 
 /** @internal */
-export const JobLayout = struct<Job>([u8('accountType'), u64('amount'), publicKey('authority')]);
+export const JobLayout = struct<RawJob>([
+    u8('accountType'),
+    u64('amount'),
+    publicKey('authority'),
+    bool('isolated'),
+    u64('id'),
+    u8('status'),
+    publicKey('node'),
+    blob(32, 'resultCommitment'),
+    blob(32, 'resultHash'),
+    ns64('revealedAt'),
+    publicKey('verifier'),
+    blob(32, 'evidenceHash'),
+    ns64('fundedAt'),
+    publicKey('pendingAuthority'),
+    publicKey('originalAuthority'),
+    u8('metadataUriLen'),
+    blob(MAX_JOB_METADATA_URI_LEN, 'metadataUri'),
+    blob(32, 'metadataHash'),
+    u64('tags'),
+    u8('mintMigrations'),
+    u8('priority'),
+    u64('exchangeRateNumerator'),
+    u64('exchangeRateDenominator'),
+    u64('exchangeRateSlot'),
+    bool('frozen'),
+]);
 
 export const JOB_SIZE = JobLayout.span;
 
+/** Size of jobs created before fields were appended */
+export const LEGACY_JOB_SIZE = 41;
+
+/** Size of jobs created before the id was appended */
+export const PRE_ID_JOB_SIZE = 42;
+
+/** Size of jobs created before the status and result fields were appended */
+export const PRE_STATUS_JOB_SIZE = 50;
+
+/** Size of jobs created before the funding timestamp was appended */
+export const PRE_FUNDED_AT_JOB_SIZE = 219;
+
+/** Size of jobs created before the authority transfer fields were appended */
+export const PRE_AUTHORITY_TRANSFER_JOB_SIZE = 227;
+
+/** Size of jobs created before the metadata fields were appended */
+export const PRE_METADATA_JOB_SIZE = 291;
+
+/** Size of jobs created before the tags were appended */
+export const PRE_TAGS_JOB_SIZE = 420;
+
+/** Size of jobs created before the mint migrations were appended */
+export const PRE_MINT_MIGRATIONS_JOB_SIZE = 428;
+
+/** Size of jobs created before the priority was appended */
+export const PRE_PRIORITY_JOB_SIZE = 429;
+
+/** Size of jobs created before the exchange rate fields were appended */
+export const PRE_EXCHANGE_RATE_JOB_SIZE = 430;
+
+/** Size of jobs created before the frozen flag was appended */
+export const PRE_FROZEN_JOB_SIZE = 454;
+
+/** Offset of the authority, which appended fields never move, so jobs can be filtered on it */
+export const JOB_AUTHORITY_OFFSET = 9;
+
+/** Offset of the status, which appended fields never move, so jobs can be filtered on it */
+export const JOB_STATUS_OFFSET = 50;
+
+/** Offset of the node, which appended fields never move, so jobs can be filtered on it */
+export const JOB_NODE_OFFSET = 51;
+
+/** Offset of the tags, which appended fields never move, so jobs can be filtered on them */
+export const JOB_TAGS_OFFSET = 420;
+
 export const isJob = (info: AccountInfo<Buffer>): boolean => {
-    return info.data.length === JOB_SIZE && info.data.readUIntLE(0, 1) === AccountType.JobV1;
+    return (
+        (info.data.length === JOB_SIZE ||
+            info.data.length === PRE_FROZEN_JOB_SIZE ||
+            info.data.length === PRE_EXCHANGE_RATE_JOB_SIZE ||
+            info.data.length === PRE_PRIORITY_JOB_SIZE ||
+            info.data.length === PRE_MINT_MIGRATIONS_JOB_SIZE ||
+            info.data.length === PRE_TAGS_JOB_SIZE ||
+            info.data.length === PRE_METADATA_JOB_SIZE ||
+            info.data.length === PRE_AUTHORITY_TRANSFER_JOB_SIZE ||
+            info.data.length === PRE_FUNDED_AT_JOB_SIZE ||
+            info.data.length === PRE_STATUS_JOB_SIZE ||
+            info.data.length === PRE_ID_JOB_SIZE ||
+            info.data.length === LEGACY_JOB_SIZE) &&
+        info.data.readUIntLE(0, 1) === AccountType.JobV1
+    );
 };
 
 export const parseJob: Parser<Job> = (pubkey: PublicKey, info: AccountInfo<Buffer>) => {
     if (!isJob(info)) return;
-    const data = JobLayout.decode(info.data);
+    const buffer = Buffer.alloc(JOB_SIZE);
+    info.data.copy(buffer);
+    const { metadataUriLen, metadataUri, ...rest } = JobLayout.decode(buffer);
+    const data = {
+        ...rest,
+        metadataUri: Buffer.from(metadataUri.slice(0, metadataUriLen)).toString('utf8'),
+    };
     return {
         pubkey,
         info,
         data,
     };
 };
+
+/** @internal */
+export const encodeJobMetadata = (metadata: JobMetadata): Buffer => {
+    const uri = Buffer.from(metadata.uri, 'utf8');
+    if (uri.length > MAX_JOB_METADATA_URI_LEN) throw new Error('Job metadata URI is too long');
+    if (metadata.hash.length !== 32) throw new Error('Job metadata hash is invalid');
+    return Buffer.concat([Buffer.from([uri.length]), uri, Buffer.from(metadata.hash), toBufferLE(metadata.tags, 8)]);
+};
+
+/** Value in RNDR of an amount of a job's token at its recorded exchange rate, rounded down like the program does */
+export const jobAmountToRndr = (job: Job, amount: bigint): bigint => {
+    if (job.exchangeRateDenominator === BigInt(0)) throw new Error('Job has no exchange rate');
+    return (amount * job.exchangeRateNumerator) / job.exchangeRateDenominator;
+};
+
+/** Amount of a job's token worth a value in RNDR at its recorded exchange rate, rounded up like the program does */
+export const jobAmountFromRndr = (job: Job, value: bigint): bigint => {
+    if (job.exchangeRateNumerator === BigInt(0)) throw new Error('Job has no exchange rate');
+    return (value * job.exchangeRateDenominator + job.exchangeRateNumerator - BigInt(1)) / job.exchangeRateNumerator;
+};
+
+/** Whether a node with the capabilities of a set of tags can run a job, which it can if it has every tag of the job */
+export const isJobSuitableFor = (job: Job, capabilities: bigint): boolean => {
+    return (job.tags & ~capabilities) === BigInt(0);
+};
+
+/**
+ * Filters for `getProgramAccounts` matching all jobs. Jobs don't store their escrow, and jobs of every size are
+ * matched, so results should be parsed with `parseJob`.
+ */
+export const jobFilters = (): GetProgramAccountsFilter[] => {
+    return [byteFilter(0, AccountType.JobV1)];
+};
+
+/** Filters for `getProgramAccounts` matching the jobs of an authority */
+export const jobsByAuthorityFilters = (authority: PublicKey): GetProgramAccountsFilter[] => {
+    return [...jobFilters(), publicKeyFilter(JOB_AUTHORITY_OFFSET, authority)];
+};
+
+/**
+ * Filters for `getProgramAccounts` matching jobs with a status, such as the open jobs no node has committed to yet.
+ * Jobs of the legacy size end before the status, so open jobs that haven't been written since aren't matched.
+ */
+export const jobsByStatusFilters = (status: JobStatus): GetProgramAccountsFilter[] => {
+    return [...jobFilters(), byteFilter(JOB_STATUS_OFFSET, status)];
+};
+
+/**
+ * Filters for `getProgramAccounts` matching jobs with exactly a set of tags. Jobs from before the tags were appended
+ * end before them, so they aren't matched. Nodes that can run jobs with any subset of their capabilities filter on the
+ * open status instead, and then with `isJobSuitableFor`.
+ */
+export const jobsByTagsFilters = (tags: bigint): GetProgramAccountsFilter[] => {
+    return [...jobFilters(), bytesFilter(JOB_TAGS_OFFSET, toBufferLE(tags, 8))];
+};
+
+/** Filters for `getProgramAccounts` matching the jobs a node has committed to a result for */
+export const jobsByNodeFilters = (node: PublicKey): GetProgramAccountsFilter[] => {
+    return [...jobFilters(), publicKeyFilter(JOB_NODE_OFFSET, node)];
+};
@@ -0,0 +1,51 @@
+import { AccountInfo, PublicKey } from '@solana/web3.js';
+import { blob, struct, u8 } from 'buffer-layout';
+import { bool, Parser, publicKey, u64 } from '../util';
+import { AccountType } from './accountType';
+
+export const MAX_BUNDLE_JOBS = 64;
+
+export interface JobBundle {
+    accountType: AccountType;
+    escrow: PublicKey;
+    authority: PublicKey;
+    id: bigint;
+    metadataHash: Uint8Array;
+    firstJobId: bigint;
+    jobCount: number;
+    amount: bigint;
+    completed: bigint;
+    cancelled: bigint;
+    isCancelled: boolean;
+}
+
+/** @internal */
+export const JobBundleLayout = struct<JobBundle>([
+    u8('accountType'),
+    publicKey('escrow'),
+    publicKey('authority'),
+    u64('id'),
+    blob(32, 'metadataHash'),
+    u64('firstJobId'),
+    u8('jobCount'),
+    u64('amount'),
+    u64('completed'),
+    u64('cancelled'),
+    bool('isCancelled'),
+]);
+
+export const JOB_BUNDLE_SIZE = JobBundleLayout.span;
+
+export const isJobBundle = (info: AccountInfo<Buffer>): boolean => {
+    return info.data.length === JOB_BUNDLE_SIZE && info.data.readUIntLE(0, 1) === AccountType.JobBundleV1;
+};
+
+export const parseJobBundle: Parser<JobBundle> = (pubkey: PublicKey, info: AccountInfo<Buffer>) => {
+    if (!isJobBundle(info)) return;
+    const data = JobBundleLayout.decode(info.data);
+    return {
+        pubkey,
+        info,
+        data,
+    };
+};
@@ -0,0 +1,77 @@
+import { AccountInfo, PublicKey } from '@solana/web3.js';
+import { seq, struct, u32, u8 } from 'buffer-layout';
+import { Parser, publicKey, u64 } from '../util';
+import { AccountType } from './accountType';
+
+export const JOB_INDEX_PAGE_LEN = 64;
+
+export interface JobIndex {
+    accountType: AccountType;
+    escrow: PublicKey;
+    authority: PublicKey;
+    jobCount: bigint;
+}
+
+export interface JobIndexPage {
+    accountType: AccountType;
+    jobIndex: PublicKey;
+    page: number;
+    jobIds: bigint[];
+}
+
+interface RawJobIndexPage extends JobIndexPage {
+    jobIdsLen: number;
+}
+
+/** @internal */
+export const JobIndexLayout = struct<JobIndex>([
+    u8('accountType'),
+    publicKey('escrow'),
+    publicKey('authority'),
+    u64('jobCount'),
+]);
+
+/** @internal */
+export const JobIndexPageLayout = struct<RawJobIndexPage>([
+    u8('accountType'),
+    publicKey('jobIndex'),
+    u32('page'),
+    u8('jobIdsLen'),
+    seq(u64(), JOB_INDEX_PAGE_LEN, 'jobIds'),
+]);
+
+export const JOB_INDEX_SIZE = JobIndexLayout.span;
+
+export const JOB_INDEX_PAGE_SIZE = JobIndexPageLayout.span;
+
+export const isJobIndex = (info: AccountInfo<Buffer>): boolean => {
+    return info.data.length === JOB_INDEX_SIZE && info.data.readUIntLE(0, 1) === AccountType.JobIndexV1;
+};
+
+export const isJobIndexPage = (info: AccountInfo<Buffer>): boolean => {
+    return info.data.length === JOB_INDEX_PAGE_SIZE && info.data.readUIntLE(0, 1) === AccountType.JobIndexPageV1;
+};
+
+export const parseJobIndex: Parser<JobIndex> = (pubkey: PublicKey, info: AccountInfo<Buffer>) => {
+    if (!isJobIndex(info)) return;
+    const data = JobIndexLayout.decode(info.data);
+    return {
+        pubkey,
+        info,
+        data,
+    };
+};
+
+export const parseJobIndexPage: Parser<JobIndexPage> = (pubkey: PublicKey, info: AccountInfo<Buffer>) => {
+    if (!isJobIndexPage(info)) return;
+    const { jobIdsLen, jobIds, ...rest } = JobIndexPageLayout.decode(info.data);
+    const data = {
+        ...rest,
+        jobIds: jobIds.slice(0, jobIdsLen),
+    };
+    return {
+        pubkey,
+        info,
+        data,
+    };
+};
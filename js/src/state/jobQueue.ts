@@ -0,0 +1,48 @@
+import { AccountInfo, PublicKey } from '@solana/web3.js';
+import { seq, struct, u8 } from 'buffer-layout';
+import { Parser, publicKey } from '../util';
+import { AccountType } from './accountType';
+
+export const JOB_QUEUE_LEN = 64;
+
+export interface JobQueue {
+    accountType: AccountType;
+    escrow: PublicKey;
+    /** Slot of the circular buffer the oldest job is packed in */
+    head: number;
+    /** Queued jobs, oldest first */
+    jobs: PublicKey[];
+}
+
+interface RawJobQueue extends JobQueue {
+    jobsLen: number;
+}
+
+/** @internal */
+export const JobQueueLayout = struct<RawJobQueue>([
+    u8('accountType'),
+    publicKey('escrow'),
+    u8('head'),
+    u8('jobsLen'),
+    seq(publicKey(), JOB_QUEUE_LEN, 'jobs'),
+]);
+
+export const JOB_QUEUE_SIZE = JobQueueLayout.span;
+
+export const isJobQueue = (info: AccountInfo<Buffer>): boolean => {
+    return info.data.length === JOB_QUEUE_SIZE && info.data.readUIntLE(0, 1) === AccountType.JobQueueV1;
+};
+
+export const parseJobQueue: Parser<JobQueue> = (pubkey: PublicKey, info: AccountInfo<Buffer>) => {
+    if (!isJobQueue(info)) return;
+    const { jobsLen, jobs, ...rest } = JobQueueLayout.decode(info.data);
+    const data = {
+        ...rest,
+        jobs: Array.from({ length: jobsLen }, (_, i) => jobs[(rest.head + i) % JOB_QUEUE_LEN]),
+    };
+    return {
+        pubkey,
+        info,
+        data,
+    };
+};
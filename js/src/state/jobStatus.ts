@@ -0,0 +1,10 @@
+export enum JobStatus {
+    Open = 0,
+    Committed = 1,
+    Revealed = 2,
+    Paid = 3,
+    Challenged = 4,
+    Disputed = 5,
+    Resolved = 6,
+    Cancelled = 7,
+}
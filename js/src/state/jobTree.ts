@@ -0,0 +1,59 @@
+import { AccountInfo, PublicKey } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { Parser, publicKey, u64 } from '../util';
+import { AccountType } from './accountType';
+import { JobStatus } from './jobStatus';
+
+export const MAX_JOB_TREE_DEPTH = 24;
+
+export interface JobTree {
+    accountType: AccountType;
+    escrow: PublicKey;
+    authority: PublicKey;
+    merkleTree: PublicKey;
+    maxDepth: number;
+    leafCount: bigint;
+    amount: bigint;
+}
+
+/** Job stored as a leaf of a job tree instead of in its own account */
+export interface CompressedJob {
+    id: bigint;
+    amount: bigint;
+    status: JobStatus;
+    node: PublicKey;
+}
+
+/** @internal */
+export const JobTreeLayout = struct<JobTree>([
+    u8('accountType'),
+    publicKey('escrow'),
+    publicKey('authority'),
+    publicKey('merkleTree'),
+    u8('maxDepth'),
+    u64('leafCount'),
+    u64('amount'),
+]);
+
+export const JOB_TREE_SIZE = JobTreeLayout.span;
+
+/** Size of the account of a concurrent merkle tree without a canopy, created before its job tree */
+export const getMerkleTreeAccountSize = (maxDepth: number, maxBufferSize: number): number => {
+    const changeLogSize = 32 + 32 * maxDepth + 8;
+    const rightmostPathSize = 32 * maxDepth + 32 + 8;
+    return 56 + 24 + changeLogSize * maxBufferSize + rightmostPathSize;
+};
+
+export const isJobTree = (info: AccountInfo<Buffer>): boolean => {
+    return info.data.length === JOB_TREE_SIZE && info.data.readUIntLE(0, 1) === AccountType.JobTreeV1;
+};
+
+export const parseJobTree: Parser<JobTree> = (pubkey: PublicKey, info: AccountInfo<Buffer>) => {
+    if (!isJobTree(info)) return;
+    const data = JobTreeLayout.decode(info.data);
+    return {
+        pubkey,
+        info,
+        data,
+    };
+};
@@ -0,0 +1,46 @@
+import { AccountInfo, PublicKey } from '@solana/web3.js';
+import { blob, struct, u32, u8 } from 'buffer-layout';
+import { Parser, publicKey, u64 } from '../util';
+import { AccountType } from './accountType';
+
+export const MAX_MERKLE_PROOF_LEN = 32;
+
+export interface MerkleDistribution {
+    accountType: AccountType;
+    escrow: PublicKey;
+    epoch: bigint;
+    root: Uint8Array;
+    nodeCount: number;
+    amount: bigint;
+    claimed: bigint;
+}
+
+/** @internal */
+export const MerkleDistributionLayout = struct<MerkleDistribution>([
+    u8('accountType'),
+    publicKey('escrow'),
+    u64('epoch'),
+    blob(32, 'root'),
+    u32('nodeCount'),
+    u64('amount'),
+    u64('claimed'),
+]);
+
+export const MERKLE_DISTRIBUTION_SIZE = MerkleDistributionLayout.span;
+
+export const isMerkleDistribution = (info: AccountInfo<Buffer>): boolean => {
+    return (
+        info.data.length === MERKLE_DISTRIBUTION_SIZE &&
+        info.data.readUIntLE(0, 1) === AccountType.MerkleDistributionV1
+    );
+};
+
+export const parseMerkleDistribution: Parser<MerkleDistribution> = (pubkey: PublicKey, info: AccountInfo<Buffer>) => {
+    if (!isMerkleDistribution(info)) return;
+    const data = MerkleDistributionLayout.decode(info.data);
+    return {
+        pubkey,
+        info,
+        data,
+    };
+};
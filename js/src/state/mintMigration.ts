@@ -0,0 +1,49 @@
+import { AccountInfo, PublicKey } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { bool, i64, Parser, publicKey, u64 } from '../util';
+import { AccountType } from './accountType';
+
+export interface MintMigration {
+    accountType: AccountType;
+    escrow: PublicKey;
+    index: number;
+    tokenMint: PublicKey;
+    newTokenMint: PublicKey;
+    rateNumerator: bigint;
+    rateDenominator: bigint;
+    vaultConverted: boolean;
+    jobsMigrated: bigint;
+    destination: PublicKey;
+    executableAt: bigint;
+}
+
+/** @internal */
+export const MintMigrationLayout = struct<MintMigration>([
+    u8('accountType'),
+    publicKey('escrow'),
+    u8('index'),
+    publicKey('tokenMint'),
+    publicKey('newTokenMint'),
+    u64('rateNumerator'),
+    u64('rateDenominator'),
+    bool('vaultConverted'),
+    u64('jobsMigrated'),
+    publicKey('destination'),
+    i64('executableAt'),
+]);
+
+export const MINT_MIGRATION_SIZE = MintMigrationLayout.span;
+
+export const isMintMigration = (info: AccountInfo<Buffer>): boolean => {
+    return info.data.length === MINT_MIGRATION_SIZE && info.data.readUIntLE(0, 1) === AccountType.MintMigrationV1;
+};
+
+export const parseMintMigration: Parser<MintMigration> = (pubkey: PublicKey, info: AccountInfo<Buffer>) => {
+    if (!isMintMigration(info)) return;
+    const data = MintMigrationLayout.decode(info.data);
+    return {
+        pubkey,
+        info,
+        data,
+    };
+};
@@ -0,0 +1,66 @@
+import { AccountInfo, PublicKey } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { bool, Parser, publicKey, u64 } from '../util';
+import { AccountType } from './accountType';
+
+export interface Node {
+    accountType: AccountType;
+    escrow: PublicKey;
+    authority: PublicKey;
+    stake: bigint;
+    autoCompound: boolean;
+    workCredits: bigint;
+    workEpoch: bigint;
+    epochWorkCredits: bigint;
+    stakeEpoch: bigint;
+    epochStaked: bigint;
+}
+
+/** @internal */
+export const NodeLayout = struct<Node>([
+    u8('accountType'),
+    publicKey('escrow'),
+    publicKey('authority'),
+    u64('stake'),
+    bool('autoCompound'),
+    u64('workCredits'),
+    u64('workEpoch'),
+    u64('epochWorkCredits'),
+    u64('stakeEpoch'),
+    u64('epochStaked'),
+]);
+
+export const NODE_SIZE = NodeLayout.span;
+
+/** Size of nodes created before the stake epoch was appended */
+export const LEGACY_NODE_SIZE = 98;
+
+/** Work credited to a node during an epoch, zero for any epoch but its work epoch */
+export const workCreditsInEpoch = (node: Node, epoch: bigint): bigint => {
+    return epoch === node.workEpoch ? node.epochWorkCredits : BigInt(0);
+};
+
+/** Stake that counts towards a node's reward tier in an epoch, excluding the tokens staked during that epoch */
+export const tierStake = (node: Node, epoch: bigint): bigint => {
+    if (epoch !== node.stakeEpoch) return node.stake;
+    return node.epochStaked < node.stake ? node.stake - node.epochStaked : BigInt(0);
+};
+
+export const isNode = (info: AccountInfo<Buffer>): boolean => {
+    return (
+        (info.data.length === NODE_SIZE || info.data.length === LEGACY_NODE_SIZE) &&
+        info.data.readUIntLE(0, 1) === AccountType.NodeV1
+    );
+};
+
+export const parseNode: Parser<Node> = (pubkey: PublicKey, info: AccountInfo<Buffer>) => {
+    if (!isNode(info)) return;
+    const buffer = Buffer.alloc(NODE_SIZE);
+    info.data.copy(buffer);
+    const data = NodeLayout.decode(buffer);
+    return {
+        pubkey,
+        info,
+        data,
+    };
+};
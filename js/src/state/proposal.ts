@@ -0,0 +1,43 @@
+import { AccountInfo, PublicKey } from '@solana/web3.js';
+import { ns64, struct, u8 } from 'buffer-layout';
+import { Parser, publicKey, u64 } from '../util';
+import { AccountType } from './accountType';
+
+export interface Proposal {
+    accountType: AccountType;
+    escrow: PublicKey;
+    id: bigint;
+    job: PublicKey;
+    destination: PublicKey;
+    amount: bigint;
+    approvedAt: number;
+    executedAt: number;
+}
+
+/** @internal */
+export const ProposalLayout = struct<Proposal>([
+    u8('accountType'),
+    publicKey('escrow'),
+    u64('id'),
+    publicKey('job'),
+    publicKey('destination'),
+    u64('amount'),
+    ns64('approvedAt'),
+    ns64('executedAt'),
+]);
+
+export const PROPOSAL_SIZE = ProposalLayout.span;
+
+export const isProposal = (info: AccountInfo<Buffer>): boolean => {
+    return info.data.length === PROPOSAL_SIZE && info.data.readUIntLE(0, 1) === AccountType.ProposalV1;
+};
+
+export const parseProposal: Parser<Proposal> = (pubkey: PublicKey, info: AccountInfo<Buffer>) => {
+    if (!isProposal(info)) return;
+    const data = ProposalLayout.decode(info.data);
+    return {
+        pubkey,
+        info,
+        data,
+    };
+};
@@ -0,0 +1,47 @@
+import { AccountInfo, PublicKey } from '@solana/web3.js';
+import { blob, ns64, struct, u8 } from 'buffer-layout';
+import { Parser, publicKey, u64 } from '../util';
+import { AccountType } from './accountType';
+
+export interface QueuedAction {
+    accountType: AccountType;
+    target: PublicKey;
+    id: bigint;
+    authority: PublicKey;
+    actionType: number;
+    actionValue: Uint8Array;
+    queuedAt: number;
+    executableAt: number;
+    executedAt: number;
+    cancelledAt: number;
+}
+
+/** @internal */
+export const QueuedActionLayout = struct<QueuedAction>([
+    u8('accountType'),
+    publicKey('target'),
+    u64('id'),
+    publicKey('authority'),
+    u8('actionType'),
+    blob(33, 'actionValue'),
+    ns64('queuedAt'),
+    ns64('executableAt'),
+    ns64('executedAt'),
+    ns64('cancelledAt'),
+]);
+
+export const QUEUED_ACTION_SIZE = QueuedActionLayout.span;
+
+export const isQueuedAction = (info: AccountInfo<Buffer>): boolean => {
+    return info.data.length === QUEUED_ACTION_SIZE && info.data.readUIntLE(0, 1) === AccountType.QueuedActionV1;
+};
+
+export const parseQueuedAction: Parser<QueuedAction> = (pubkey: PublicKey, info: AccountInfo<Buffer>) => {
+    if (!isQueuedAction(info)) return;
+    const data = QueuedActionLayout.decode(info.data);
+    return {
+        pubkey,
+        info,
+        data,
+    };
+};
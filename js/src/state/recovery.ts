@@ -0,0 +1,60 @@
+import { AccountInfo, PublicKey } from '@solana/web3.js';
+import { ns64, seq, struct, u8 } from 'buffer-layout';
+import { Parser, publicKey } from '../util';
+import { AccountType } from './accountType';
+
+export const MAX_RECOVERY_GUARDIANS = 10;
+
+export interface Recovery {
+    accountType: AccountType;
+    escrow: PublicKey;
+    threshold: number;
+    guardians: PublicKey[];
+    votes: PublicKey[];
+    newOwner: PublicKey;
+    executableAt: number;
+}
+
+interface RawRecovery {
+    accountType: AccountType;
+    escrow: PublicKey;
+    threshold: number;
+    guardiansLen: number;
+    guardians: PublicKey[];
+    votes: PublicKey[];
+    newOwner: PublicKey;
+    executableAt: number;
+}
+
+/** @internal */
+export const RecoveryLayout = struct<RawRecovery>([
+    u8('accountType'),
+    publicKey('escrow'),
+    u8('threshold'),
+    u8('guardiansLen'),
+    seq(publicKey(), MAX_RECOVERY_GUARDIANS, 'guardians'),
+    seq(publicKey(), MAX_RECOVERY_GUARDIANS, 'votes'),
+    publicKey('newOwner'),
+    ns64('executableAt'),
+]);
+
+export const RECOVERY_SIZE = RecoveryLayout.span;
+
+export const isRecovery = (info: AccountInfo<Buffer>): boolean => {
+    return info.data.length === RECOVERY_SIZE && info.data.readUIntLE(0, 1) === AccountType.RecoveryV1;
+};
+
+export const parseRecovery: Parser<Recovery> = (pubkey: PublicKey, info: AccountInfo<Buffer>) => {
+    if (!isRecovery(info)) return;
+    const { guardiansLen, guardians, votes, ...rest } = RecoveryLayout.decode(info.data);
+    const data = {
+        ...rest,
+        guardians: guardians.slice(0, guardiansLen),
+        votes: votes.slice(0, guardiansLen),
+    };
+    return {
+        pubkey,
+        info,
+        data,
+    };
+};
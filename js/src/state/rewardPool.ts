@@ -0,0 +1,39 @@
+import { AccountInfo, PublicKey } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { Parser, publicKey, u64 } from '../util';
+import { AccountType } from './accountType';
+
+export interface RewardPool {
+    accountType: AccountType;
+    escrow: PublicKey;
+    epoch: bigint;
+    amount: bigint;
+    totalWork: bigint;
+    claimed: bigint;
+}
+
+/** @internal */
+export const RewardPoolLayout = struct<RewardPool>([
+    u8('accountType'),
+    publicKey('escrow'),
+    u64('epoch'),
+    u64('amount'),
+    u64('totalWork'),
+    u64('claimed'),
+]);
+
+export const REWARD_POOL_SIZE = RewardPoolLayout.span;
+
+export const isRewardPool = (info: AccountInfo<Buffer>): boolean => {
+    return info.data.length === REWARD_POOL_SIZE && info.data.readUIntLE(0, 1) === AccountType.RewardPoolV1;
+};
+
+export const parseRewardPool: Parser<RewardPool> = (pubkey: PublicKey, info: AccountInfo<Buffer>) => {
+    if (!isRewardPool(info)) return;
+    const data = RewardPoolLayout.decode(info.data);
+    return {
+        pubkey,
+        info,
+        data,
+    };
+};
@@ -0,0 +1,5 @@
+export enum RoundingPolicy {
+    FirstRecipient = 0,
+    Treasury = 1,
+    Burn = 2,
+}
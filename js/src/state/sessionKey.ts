@@ -0,0 +1,45 @@
+import { AccountInfo, PublicKey } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { Parser, publicKey, u64 } from '../util';
+import { AccountType } from './accountType';
+
+/** Bit of a session key's instruction mask allowing it to top up an existing job */
+export const SESSION_KEY_FUND_JOB = BigInt(1) << BigInt(2);
+/** Bit of a session key's instruction mask allowing it to challenge a job's result */
+export const SESSION_KEY_CHALLENGE_RESULT = BigInt(1) << BigInt(13);
+export const SESSION_KEY_INSTRUCTIONS = SESSION_KEY_FUND_JOB | SESSION_KEY_CHALLENGE_RESULT;
+
+export interface SessionKey {
+    accountType: AccountType;
+    escrow: PublicKey;
+    authority: PublicKey;
+    sessionKey: PublicKey;
+    expirySlot: bigint;
+    instructionMask: bigint;
+}
+
+/** @internal */
+export const SessionKeyLayout = struct<SessionKey>([
+    u8('accountType'),
+    publicKey('escrow'),
+    publicKey('authority'),
+    publicKey('sessionKey'),
+    u64('expirySlot'),
+    u64('instructionMask'),
+]);
+
+export const SESSION_KEY_SIZE = SessionKeyLayout.span;
+
+export const isSessionKey = (info: AccountInfo<Buffer>): boolean => {
+    return info.data.length === SESSION_KEY_SIZE && info.data.readUIntLE(0, 1) === AccountType.SessionKeyV1;
+};
+
+export const parseSessionKey: Parser<SessionKey> = (pubkey: PublicKey, info: AccountInfo<Buffer>) => {
+    if (!isSessionKey(info)) return;
+    const data = SessionKeyLayout.decode(info.data);
+    return {
+        pubkey,
+        info,
+        data,
+    };
+};
@@ -0,0 +1,51 @@
+import { AccountInfo, PublicKey } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { Parser, publicKey, u64 } from '../util';
+import { AccountType } from './accountType';
+
+export interface StakePool {
+    accountType: AccountType;
+    escrow: PublicKey;
+    tokenMint: PublicKey;
+    receiptMint: PublicKey;
+    totalStaked: bigint;
+    receiptSupply: bigint;
+}
+
+/** @internal */
+export const StakePoolLayout = struct<StakePool>([
+    u8('accountType'),
+    publicKey('escrow'),
+    publicKey('tokenMint'),
+    publicKey('receiptMint'),
+    u64('totalStaked'),
+    u64('receiptSupply'),
+]);
+
+export const STAKE_POOL_SIZE = StakePoolLayout.span;
+
+/** Amount of receipt tokens minted for staking an amount of tokens, rounded down */
+export const receiptAmount = (stakePool: StakePool, amount: bigint): bigint => {
+    if (stakePool.receiptSupply === BigInt(0) || stakePool.totalStaked === BigInt(0)) return amount;
+    return (amount * stakePool.receiptSupply) / stakePool.totalStaked;
+};
+
+/** Amount of tokens withdrawn for burning an amount of receipt tokens, rounded down */
+export const stakeAmount = (stakePool: StakePool, receipts: bigint): bigint => {
+    if (stakePool.receiptSupply === BigInt(0)) return BigInt(0);
+    return (receipts * stakePool.totalStaked) / stakePool.receiptSupply;
+};
+
+export const isStakePool = (info: AccountInfo<Buffer>): boolean => {
+    return info.data.length === STAKE_POOL_SIZE && info.data.readUIntLE(0, 1) === AccountType.StakePoolV1;
+};
+
+export const parseStakePool: Parser<StakePool> = (pubkey: PublicKey, info: AccountInfo<Buffer>) => {
+    if (!isStakePool(info)) return;
+    const data = StakePoolLayout.decode(info.data);
+    return {
+        pubkey,
+        info,
+        data,
+    };
+};
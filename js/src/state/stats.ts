@@ -0,0 +1,77 @@
+import { AccountInfo, PublicKey } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { Parser, publicKey, u64 } from '../util';
+import { AccountType } from './accountType';
+
+export interface Stats {
+    accountType: AccountType;
+    escrow: PublicKey;
+    epoch: bigint;
+    totalEscrowed: bigint;
+    totalBurned: bigint;
+    totalEmitted: bigint;
+    totalDisbursed: bigint;
+    epochEscrowed: bigint;
+    epochBurned: bigint;
+    epochEmitted: bigint;
+    epochDisbursed: bigint;
+}
+
+/** @internal */
+interface PackedStats extends Stats {
+    totalEscrowedHigh: bigint;
+    totalBurnedHigh: bigint;
+    totalEmittedHigh: bigint;
+    totalDisbursedHigh: bigint;
+}
+
+/** @internal */
+export const StatsLayout = struct<PackedStats>([
+    u8('accountType'),
+    publicKey('escrow'),
+    u64('epoch'),
+    u64('totalEscrowed'),
+    u64('totalBurned'),
+    u64('totalEmitted'),
+    u64('totalDisbursed'),
+    u64('epochEscrowed'),
+    u64('epochBurned'),
+    u64('epochEmitted'),
+    u64('epochDisbursed'),
+    u64('totalEscrowedHigh'),
+    u64('totalBurnedHigh'),
+    u64('totalEmittedHigh'),
+    u64('totalDisbursedHigh'),
+]);
+
+export const STATS_SIZE = StatsLayout.span;
+
+/** Size of stats created before the lifetime totals were widened to 128 bits */
+export const LEGACY_STATS_SIZE = 105;
+
+export const isStats = (info: AccountInfo<Buffer>): boolean => {
+    return (
+        (info.data.length === STATS_SIZE || info.data.length === LEGACY_STATS_SIZE) &&
+        info.data.readUIntLE(0, 1) === AccountType.StatsV1
+    );
+};
+
+export const parseStats: Parser<Stats> = (pubkey: PublicKey, info: AccountInfo<Buffer>) => {
+    if (!isStats(info)) return;
+    const buffer = Buffer.alloc(STATS_SIZE);
+    info.data.copy(buffer);
+    const { totalEscrowedHigh, totalBurnedHigh, totalEmittedHigh, totalDisbursedHigh, ...rest } =
+        StatsLayout.decode(buffer);
+    const data = {
+        ...rest,
+        totalEscrowed: (totalEscrowedHigh << BigInt(64)) | rest.totalEscrowed,
+        totalBurned: (totalBurnedHigh << BigInt(64)) | rest.totalBurned,
+        totalEmitted: (totalEmittedHigh << BigInt(64)) | rest.totalEmitted,
+        totalDisbursed: (totalDisbursedHigh << BigInt(64)) | rest.totalDisbursed,
+    };
+    return {
+        pubkey,
+        info,
+        data,
+    };
+};
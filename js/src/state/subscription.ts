@@ -0,0 +1,43 @@
+import { AccountInfo, PublicKey } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { Parser, publicKey, u64 } from '../util';
+import { AccountType } from './accountType';
+
+export interface Subscription {
+    accountType: AccountType;
+    escrow: PublicKey;
+    authority: PublicKey;
+    jobId: bigint;
+    sourceToken: PublicKey;
+    amount: bigint;
+    period: bigint;
+    nextSlot: bigint;
+}
+
+/** @internal */
+export const SubscriptionLayout = struct<Subscription>([
+    u8('accountType'),
+    publicKey('escrow'),
+    publicKey('authority'),
+    u64('jobId'),
+    publicKey('sourceToken'),
+    u64('amount'),
+    u64('period'),
+    u64('nextSlot'),
+]);
+
+export const SUBSCRIPTION_SIZE = SubscriptionLayout.span;
+
+export const isSubscription = (info: AccountInfo<Buffer>): boolean => {
+    return info.data.length === SUBSCRIPTION_SIZE && info.data.readUIntLE(0, 1) === AccountType.SubscriptionV1;
+};
+
+export const parseSubscription: Parser<Subscription> = (pubkey: PublicKey, info: AccountInfo<Buffer>) => {
+    if (!isSubscription(info)) return;
+    const data = SubscriptionLayout.decode(info.data);
+    return {
+        pubkey,
+        info,
+        data,
+    };
+};
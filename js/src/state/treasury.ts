@@ -0,0 +1,80 @@
+import { AccountInfo, PublicKey } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { i64, Parser, publicKey, u64 } from '../util';
+import { AccountType } from './accountType';
+
+export interface Treasury {
+    accountType: AccountType;
+    escrow: PublicKey;
+    tokenMint: PublicKey;
+    epochBurnCap: bigint;
+    burnEpoch: bigint;
+    epochBurned: bigint;
+    totalBurned: bigint;
+    /** Pre-registered token account the treasury can be swept to, or the default public key if there is none */
+    coldWallet: PublicKey;
+    /** Amount of tokens of the pending sweep, or 0 if there is none */
+    sweepAmount: bigint;
+    /** Unix timestamp from which the pending sweep can be executed */
+    sweepExecutableAt: bigint;
+}
+
+/** @internal */
+interface PackedTreasury extends Treasury {
+    totalBurnedHigh: bigint;
+}
+
+/** @internal */
+export const TreasuryLayout = struct<PackedTreasury>([
+    u8('accountType'),
+    publicKey('escrow'),
+    publicKey('tokenMint'),
+    u64('epochBurnCap'),
+    u64('burnEpoch'),
+    u64('epochBurned'),
+    u64('totalBurned'),
+    u64('totalBurnedHigh'),
+    publicKey('coldWallet'),
+    u64('sweepAmount'),
+    i64('sweepExecutableAt'),
+]);
+
+export const TREASURY_SIZE = TreasuryLayout.span;
+
+/** Size of treasuries created before the lifetime amount burned was widened to 128 bits */
+export const LEGACY_TREASURY_SIZE = 97;
+
+/** Size of treasuries created before the cold wallet sweep was appended */
+export const PRE_COLD_WALLET_TREASURY_SIZE = 105;
+
+/** Amount of tokens a treasury can still burn in an epoch under its epoch burn cap, or undefined if uncapped */
+export const treasuryBurnable = (treasury: Treasury, epoch: bigint): bigint | undefined => {
+    if (treasury.epochBurnCap === BigInt(0)) return;
+    if (epoch !== treasury.burnEpoch) return treasury.epochBurnCap;
+    return treasury.epochBurned < treasury.epochBurnCap ? treasury.epochBurnCap - treasury.epochBurned : BigInt(0);
+};
+
+export const isTreasury = (info: AccountInfo<Buffer>): boolean => {
+    return (
+        (info.data.length === TREASURY_SIZE ||
+            info.data.length === LEGACY_TREASURY_SIZE ||
+            info.data.length === PRE_COLD_WALLET_TREASURY_SIZE) &&
+        info.data.readUIntLE(0, 1) === AccountType.TreasuryV1
+    );
+};
+
+export const parseTreasury: Parser<Treasury> = (pubkey: PublicKey, info: AccountInfo<Buffer>) => {
+    if (!isTreasury(info)) return;
+    const buffer = Buffer.alloc(TREASURY_SIZE);
+    info.data.copy(buffer);
+    const { totalBurnedHigh, ...rest } = TreasuryLayout.decode(buffer);
+    const data = {
+        ...rest,
+        totalBurned: (totalBurnedHigh << BigInt(64)) | rest.totalBurned,
+    };
+    return {
+        pubkey,
+        info,
+        data,
+    };
+};
@@ -0,0 +1,46 @@
+import { AccountInfo, PublicKey } from '@solana/web3.js';
+import { seq, struct, u8 } from 'buffer-layout';
+import { Parser, publicKey } from '../util';
+import { AccountType } from './accountType';
+
+export const MAX_VERIFIERS = 16;
+
+export interface VerifierRegistry {
+    accountType: AccountType;
+    escrow: PublicKey;
+    verifiers: PublicKey[];
+}
+
+interface RawVerifierRegistry {
+    accountType: AccountType;
+    escrow: PublicKey;
+    verifiersLen: number;
+    verifiers: PublicKey[];
+}
+
+/** @internal */
+export const VerifierRegistryLayout = struct<RawVerifierRegistry>([
+    u8('accountType'),
+    publicKey('escrow'),
+    u8('verifiersLen'),
+    seq(publicKey(), MAX_VERIFIERS, 'verifiers'),
+]);
+
+export const VERIFIER_REGISTRY_SIZE = VerifierRegistryLayout.span;
+
+export const isVerifierRegistry = (info: AccountInfo<Buffer>): boolean => {
+    return (
+        info.data.length === VERIFIER_REGISTRY_SIZE && info.data.readUIntLE(0, 1) === AccountType.VerifierRegistryV1
+    );
+};
+
+export const parseVerifierRegistry: Parser<VerifierRegistry> = (pubkey: PublicKey, info: AccountInfo<Buffer>) => {
+    if (!isVerifierRegistry(info)) return;
+    const { accountType, escrow, verifiersLen, verifiers } = VerifierRegistryLayout.decode(info.data);
+    const data = { accountType, escrow, verifiers: verifiers.slice(0, verifiersLen) };
+    return {
+        pubkey,
+        info,
+        data,
+    };
+};
@@ -0,0 +1,50 @@
+import { AccountInfo, PublicKey } from '@solana/web3.js';
+import { seq, struct, u8 } from 'buffer-layout';
+import { Parser, publicKey, u64 } from '../util';
+import { AccountType } from './accountType';
+
+export const MAX_SELECTED_VERIFIERS = 8;
+
+export interface VerifierSelection {
+    accountType: AccountType;
+    job: PublicKey;
+    slot: bigint;
+    verifiers: PublicKey[];
+}
+
+interface RawVerifierSelection {
+    accountType: AccountType;
+    job: PublicKey;
+    slot: bigint;
+    verifiersLen: number;
+    verifiers: PublicKey[];
+}
+
+/** @internal */
+export const VerifierSelectionLayout = struct<RawVerifierSelection>([
+    u8('accountType'),
+    publicKey('job'),
+    u64('slot'),
+    u8('verifiersLen'),
+    seq(publicKey(), MAX_SELECTED_VERIFIERS, 'verifiers'),
+]);
+
+export const VERIFIER_SELECTION_SIZE = VerifierSelectionLayout.span;
+
+export const isVerifierSelection = (info: AccountInfo<Buffer>): boolean => {
+    return (
+        info.data.length === VERIFIER_SELECTION_SIZE &&
+        info.data.readUIntLE(0, 1) === AccountType.VerifierSelectionV1
+    );
+};
+
+export const parseVerifierSelection: Parser<VerifierSelection> = (pubkey: PublicKey, info: AccountInfo<Buffer>) => {
+    if (!isVerifierSelection(info)) return;
+    const { accountType, job, slot, verifiersLen, verifiers } = VerifierSelectionLayout.decode(info.data);
+    const data = { accountType, job, slot, verifiers: verifiers.slice(0, verifiersLen) };
+    return {
+        pubkey,
+        info,
+        data,
+    };
+};
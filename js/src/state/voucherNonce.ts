@@ -0,0 +1,35 @@
+import { AccountInfo, PublicKey } from '@solana/web3.js';
+import { struct, u8 } from 'buffer-layout';
+import { Parser, publicKey, u64 } from '../util';
+import { AccountType } from './accountType';
+
+export interface VoucherNonce {
+    accountType: AccountType;
+    escrow: PublicKey;
+    authority: PublicKey;
+    nonce: bigint;
+}
+
+/** @internal */
+export const VoucherNonceLayout = struct<VoucherNonce>([
+    u8('accountType'),
+    publicKey('escrow'),
+    publicKey('authority'),
+    u64('nonce'),
+]);
+
+export const VOUCHER_NONCE_SIZE = VoucherNonceLayout.span;
+
+export const isVoucherNonce = (info: AccountInfo<Buffer>): boolean => {
+    return info.data.length === VOUCHER_NONCE_SIZE && info.data.readUIntLE(0, 1) === AccountType.VoucherNonceV1;
+};
+
+export const parseVoucherNonce: Parser<VoucherNonce> = (pubkey: PublicKey, info: AccountInfo<Buffer>) => {
+    if (!isVoucherNonce(info)) return;
+    const data = VoucherNonceLayout.decode(info.data);
+    return {
+        pubkey,
+        info,
+        data,
+    };
+};
@@ -0,0 +1,33 @@
+import { GetProgramAccountsFilter, PublicKey } from '@solana/web3.js';
+
+const BASE58_ALPHABET = '123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz';
+
+/** Filter matching accounts with a byte at an offset, which must be below 58 to encode as one base58 digit */
+export const byteFilter = (offset: number, byte: number): GetProgramAccountsFilter => {
+    if (byte >= BASE58_ALPHABET.length) throw new Error('Byte is too large to filter on');
+    return { memcmp: { offset, bytes: BASE58_ALPHABET[byte] } };
+};
+
+/** Filter matching accounts with bytes at an offset */
+export const bytesFilter = (offset: number, bytes: Uint8Array): GetProgramAccountsFilter => {
+    return { memcmp: { offset, bytes: encodeBase58(bytes) } };
+};
+
+const encodeBase58 = (bytes: Uint8Array): string => {
+    let value = BigInt('0x' + (Buffer.from(bytes).toString('hex') || '0'));
+    let encoded = '';
+    while (value > BigInt(0)) {
+        encoded = BASE58_ALPHABET[Number(value % BigInt(58))] + encoded;
+        value /= BigInt(58);
+    }
+    for (const byte of bytes) {
+        if (byte !== 0) break;
+        encoded = BASE58_ALPHABET[0] + encoded;
+    }
+    return encoded;
+};
+
+/** Filter matching accounts with a public key at an offset */
+export const publicKeyFilter = (offset: number, publicKey: PublicKey): GetProgramAccountsFilter => {
+    return { memcmp: { offset, bytes: publicKey.toBase58() } };
+};
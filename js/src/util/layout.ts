@@ -1,6 +1,6 @@
 import { AccountInfo, PublicKey } from '@solana/web3.js';
 import { toBigIntLE, toBufferLE } from 'bigint-buffer';
-import { blob, Layout } from 'buffer-layout';
+import { blob, Layout, u8 } from 'buffer-layout';
 
 export type Parser<T> = (
     pubkey: PublicKey,
@@ -70,3 +70,35 @@ export const bigInt =
 
 /** @internal */
 export const u64 = bigInt(8);
+
+/** @internal */
+export const i64 = (property = 'i64'): Layout<bigint> => {
+    const layout = u64(property);
+    const { encode, decode } = encodeDecode(layout);
+
+    layout.decode = (buffer: Buffer, offset: number) => BigInt.asIntN(64, decode(buffer, offset));
+    layout.encode = (value: bigint, buffer: Buffer, offset: number) =>
+        encode(BigInt.asUintN(64, value), buffer, offset);
+
+    return layout;
+};
+
+/** @internal */
+export const bool = (property = 'bool'): Layout<boolean> => {
+    const layout = u8(property);
+    const { encode, decode } = encodeDecode(layout);
+
+    const boolLayout = layout as Layout<unknown> as Layout<boolean>;
+
+    boolLayout.decode = (buffer: Buffer, offset: number) => {
+        const src = decode(buffer, offset);
+        return !!src;
+    };
+
+    boolLayout.encode = (bool: boolean, buffer: Buffer, offset: number) => {
+        const src = Number(bool);
+        return encode(src, buffer, offset);
+    };
+
+    return boolLayout;
+};
@@ -1,27 +1,345 @@
 import { ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_PROGRAM_ID } from '@solana/spl-token';
 import { PublicKey } from '@solana/web3.js';
-import { RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
+import { toBufferLE } from 'bigint-buffer';
+import { BPF_LOADER_UPGRADEABLE_PROGRAM_ID, BUBBLEGUM_PROGRAM_ID, RNDR_PROGRAM_ID, RNDR_TOKEN_MINT } from '../constants';
 
-export const findEscrowAddress = async (tokenMint: PublicKey = RNDR_TOKEN_MINT): Promise<[PublicKey, number]> => {
+export const findEscrowAddress = async (
+    tokenMint: PublicKey = RNDR_TOKEN_MINT,
+    escrowId: number | bigint = 0
+): Promise<[PublicKey, number]> => {
+    const seeds = [Buffer.from('escrow', 'utf8'), tokenMint.toBuffer(), TOKEN_PROGRAM_ID.toBuffer()];
+    // The default escrow of a token mint has no escrow id seed, so its address is unchanged
+    if (BigInt(escrowId) !== BigInt(0)) seeds.push(toBufferLE(BigInt(escrowId), 8));
+    return await PublicKey.findProgramAddress(seeds, RNDR_PROGRAM_ID);
+};
+
+export const findEscrowAssociatedTokenAddress = async (
+    escrow: PublicKey,
+    tokenMint: PublicKey = RNDR_TOKEN_MINT
+): Promise<[PublicKey, number]> => {
+    return await PublicKey.findProgramAddress(
+        [escrow.toBuffer(), TOKEN_PROGRAM_ID.toBuffer(), tokenMint.toBuffer()],
+        ASSOCIATED_TOKEN_PROGRAM_ID
+    );
+};
+
+export const findJobAddress = async (
+    escrow: PublicKey,
+    authority: PublicKey,
+    jobId: number | bigint
+): Promise<[PublicKey, number]> => {
+    const seeds = [Buffer.from('job', 'utf8'), escrow.toBuffer(), authority.toBuffer()];
+    // Job id zero has no job id seed, so jobs created before jobs had ids keep their addresses
+    if (BigInt(jobId) !== BigInt(0)) seeds.push(toBufferLE(BigInt(jobId), 8));
+    return await PublicKey.findProgramAddress(seeds, RNDR_PROGRAM_ID);
+};
+
+export const findJobAssociatedTokenAddress = async (
+    job: PublicKey,
+    tokenMint: PublicKey = RNDR_TOKEN_MINT
+): Promise<[PublicKey, number]> => {
+    return await PublicKey.findProgramAddress(
+        [job.toBuffer(), TOKEN_PROGRAM_ID.toBuffer(), tokenMint.toBuffer()],
+        ASSOCIATED_TOKEN_PROGRAM_ID
+    );
+};
+
+export const ESCROW_SHARD_COUNT = 8;
+
+export const findEscrowShardIndex = (job: PublicKey): number => {
+    return job.toBuffer()[0] % ESCROW_SHARD_COUNT;
+};
+
+export const findEscrowShardAddress = async (escrow: PublicKey, index: number): Promise<[PublicKey, number]> => {
     return await PublicKey.findProgramAddress(
-        [Buffer.from('escrow', 'utf8'), tokenMint.toBuffer(), TOKEN_PROGRAM_ID.toBuffer()],
+        [Buffer.from('escrow_shard', 'utf8'), escrow.toBuffer(), Buffer.from([index])],
         RNDR_PROGRAM_ID
     );
 };
 
-export const findEscrowAssociatedTokenAddress = async (
+export const findVerifierRegistryAddress = async (escrow: PublicKey): Promise<[PublicKey, number]> => {
+    return await PublicKey.findProgramAddress(
+        [Buffer.from('verifier_registry', 'utf8'), escrow.toBuffer()],
+        RNDR_PROGRAM_ID
+    );
+};
+
+export const findVerifierSelectionAddress = async (job: PublicKey): Promise<[PublicKey, number]> => {
+    return await PublicKey.findProgramAddress(
+        [Buffer.from('verifier_selection', 'utf8'), job.toBuffer()],
+        RNDR_PROGRAM_ID
+    );
+};
+
+export const findDisputeAddress = async (job: PublicKey): Promise<[PublicKey, number]> => {
+    return await PublicKey.findProgramAddress([Buffer.from('dispute', 'utf8'), job.toBuffer()], RNDR_PROGRAM_ID);
+};
+
+export const findProposalAddress = async (
     escrow: PublicKey,
+    proposalId: number | bigint
+): Promise<[PublicKey, number]> => {
+    return await PublicKey.findProgramAddress(
+        [Buffer.from('proposal', 'utf8'), escrow.toBuffer(), toBufferLE(BigInt(proposalId), 8)],
+        RNDR_PROGRAM_ID
+    );
+};
+
+export const findQueuedActionAddress = async (
+    target: PublicKey,
+    actionId: number | bigint
+): Promise<[PublicKey, number]> => {
+    return await PublicKey.findProgramAddress(
+        [Buffer.from('queued_action', 'utf8'), target.toBuffer(), toBufferLE(BigInt(actionId), 8)],
+        RNDR_PROGRAM_ID
+    );
+};
+
+export const findRecoveryAddress = async (escrow: PublicKey): Promise<[PublicKey, number]> => {
+    return await PublicKey.findProgramAddress([Buffer.from('recovery', 'utf8'), escrow.toBuffer()], RNDR_PROGRAM_ID);
+};
+
+export const findRewardPoolAddress = async (
+    escrow: PublicKey,
+    epoch: number | bigint
+): Promise<[PublicKey, number]> => {
+    return await PublicKey.findProgramAddress(
+        [Buffer.from('reward_pool', 'utf8'), escrow.toBuffer(), toBufferLE(BigInt(epoch), 8)],
+        RNDR_PROGRAM_ID
+    );
+};
+
+export const findRewardPoolAssociatedTokenAddress = async (
+    rewardPool: PublicKey,
     tokenMint: PublicKey = RNDR_TOKEN_MINT
 ): Promise<[PublicKey, number]> => {
     return await PublicKey.findProgramAddress(
-        [escrow.toBuffer(), TOKEN_PROGRAM_ID.toBuffer(), tokenMint.toBuffer()],
+        [rewardPool.toBuffer(), TOKEN_PROGRAM_ID.toBuffer(), tokenMint.toBuffer()],
+        ASSOCIATED_TOKEN_PROGRAM_ID
+    );
+};
+
+export const findEpochWorkAddress = async (rewardPool: PublicKey, node: PublicKey): Promise<[PublicKey, number]> => {
+    return await PublicKey.findProgramAddress(
+        [Buffer.from('epoch_work', 'utf8'), rewardPool.toBuffer(), node.toBuffer()],
+        RNDR_PROGRAM_ID
+    );
+};
+
+export const findMerkleDistributionAddress = async (
+    escrow: PublicKey,
+    epoch: number | bigint
+): Promise<[PublicKey, number]> => {
+    return await PublicKey.findProgramAddress(
+        [Buffer.from('merkle_distribution', 'utf8'), escrow.toBuffer(), toBufferLE(BigInt(epoch), 8)],
+        RNDR_PROGRAM_ID
+    );
+};
+
+export const findMerkleDistributionAssociatedTokenAddress = async (
+    merkleDistribution: PublicKey,
+    tokenMint: PublicKey = RNDR_TOKEN_MINT
+): Promise<[PublicKey, number]> => {
+    return await PublicKey.findProgramAddress(
+        [merkleDistribution.toBuffer(), TOKEN_PROGRAM_ID.toBuffer(), tokenMint.toBuffer()],
+        ASSOCIATED_TOKEN_PROGRAM_ID
+    );
+};
+
+export const findClaimBitmapAddress = async (
+    merkleDistribution: PublicKey,
+    chunk: number
+): Promise<[PublicKey, number]> => {
+    return await PublicKey.findProgramAddress(
+        [Buffer.from('claim_bitmap', 'utf8'), merkleDistribution.toBuffer(), toBufferLE(BigInt(chunk), 4)],
+        RNDR_PROGRAM_ID
+    );
+};
+
+export const findEmissionScheduleAddress = async (escrow: PublicKey): Promise<[PublicKey, number]> => {
+    return await PublicKey.findProgramAddress(
+        [Buffer.from('emission_schedule', 'utf8'), escrow.toBuffer()],
+        RNDR_PROGRAM_ID
+    );
+};
+
+export const findEmissionScheduleAssociatedTokenAddress = async (
+    emissionSchedule: PublicKey,
+    tokenMint: PublicKey = RNDR_TOKEN_MINT
+): Promise<[PublicKey, number]> => {
+    return await PublicKey.findProgramAddress(
+        [emissionSchedule.toBuffer(), TOKEN_PROGRAM_ID.toBuffer(), tokenMint.toBuffer()],
+        ASSOCIATED_TOKEN_PROGRAM_ID
+    );
+};
+
+export const findNodeAddress = async (escrow: PublicKey, authority: PublicKey): Promise<[PublicKey, number]> => {
+    return await PublicKey.findProgramAddress(
+        [Buffer.from('node', 'utf8'), escrow.toBuffer(), authority.toBuffer()],
+        RNDR_PROGRAM_ID
+    );
+};
+
+export const findNodeAssociatedTokenAddress = async (
+    node: PublicKey,
+    tokenMint: PublicKey = RNDR_TOKEN_MINT
+): Promise<[PublicKey, number]> => {
+    return await PublicKey.findProgramAddress(
+        [node.toBuffer(), TOKEN_PROGRAM_ID.toBuffer(), tokenMint.toBuffer()],
+        ASSOCIATED_TOKEN_PROGRAM_ID
+    );
+};
+
+export const findStakePoolAddress = async (escrow: PublicKey): Promise<[PublicKey, number]> => {
+    return await PublicKey.findProgramAddress([Buffer.from('stake_pool', 'utf8'), escrow.toBuffer()], RNDR_PROGRAM_ID);
+};
+
+export const findStakePoolAssociatedTokenAddress = async (
+    stakePool: PublicKey,
+    tokenMint: PublicKey = RNDR_TOKEN_MINT
+): Promise<[PublicKey, number]> => {
+    return await PublicKey.findProgramAddress(
+        [stakePool.toBuffer(), TOKEN_PROGRAM_ID.toBuffer(), tokenMint.toBuffer()],
+        ASSOCIATED_TOKEN_PROGRAM_ID
+    );
+};
+
+export const findStakeReceiptMintAddress = async (stakePool: PublicKey): Promise<[PublicKey, number]> => {
+    return await PublicKey.findProgramAddress(
+        [Buffer.from('stake_receipt_mint', 'utf8'), stakePool.toBuffer()],
+        RNDR_PROGRAM_ID
+    );
+};
+
+export const findCrankVaultAddress = async (escrow: PublicKey): Promise<[PublicKey, number]> => {
+    return await PublicKey.findProgramAddress([Buffer.from('crank_vault', 'utf8'), escrow.toBuffer()], RNDR_PROGRAM_ID);
+};
+
+export const findTreasuryAddress = async (escrow: PublicKey): Promise<[PublicKey, number]> => {
+    return await PublicKey.findProgramAddress([Buffer.from('treasury', 'utf8'), escrow.toBuffer()], RNDR_PROGRAM_ID);
+};
+
+export const findTreasuryAssociatedTokenAddress = async (
+    treasury: PublicKey,
+    tokenMint: PublicKey = RNDR_TOKEN_MINT
+): Promise<[PublicKey, number]> => {
+    return await PublicKey.findProgramAddress(
+        [treasury.toBuffer(), TOKEN_PROGRAM_ID.toBuffer(), tokenMint.toBuffer()],
+        ASSOCIATED_TOKEN_PROGRAM_ID
+    );
+};
+
+export const findStatsAddress = async (escrow: PublicKey): Promise<[PublicKey, number]> => {
+    return await PublicKey.findProgramAddress([Buffer.from('stats', 'utf8'), escrow.toBuffer()], RNDR_PROGRAM_ID);
+};
+
+export const findVoucherNonceAddress = async (
+    escrow: PublicKey,
+    authority: PublicKey
+): Promise<[PublicKey, number]> => {
+    return await PublicKey.findProgramAddress(
+        [Buffer.from('voucher_nonce', 'utf8'), escrow.toBuffer(), authority.toBuffer()],
+        RNDR_PROGRAM_ID
+    );
+};
+
+export const findDelegateAllowanceAddress = async (
+    escrow: PublicKey,
+    delegate: PublicKey
+): Promise<[PublicKey, number]> => {
+    return await PublicKey.findProgramAddress(
+        [Buffer.from('delegate_allowance', 'utf8'), escrow.toBuffer(), delegate.toBuffer()],
+        RNDR_PROGRAM_ID
+    );
+};
+
+export const findSessionKeyAddress = async (
+    escrow: PublicKey,
+    authority: PublicKey,
+    sessionKey: PublicKey
+): Promise<[PublicKey, number]> => {
+    return await PublicKey.findProgramAddress(
+        [Buffer.from('session_key', 'utf8'), escrow.toBuffer(), authority.toBuffer(), sessionKey.toBuffer()],
+        RNDR_PROGRAM_ID
+    );
+};
+
+export const findSubscriptionAddress = async (
+    escrow: PublicKey,
+    authority: PublicKey,
+    jobId: number | bigint
+): Promise<[PublicKey, number]> => {
+    return await PublicKey.findProgramAddress(
+        [Buffer.from('subscription', 'utf8'), escrow.toBuffer(), authority.toBuffer(), toBufferLE(BigInt(jobId), 8)],
+        RNDR_PROGRAM_ID
+    );
+};
+
+export const findJobBundleAddress = async (
+    escrow: PublicKey,
+    authority: PublicKey,
+    bundleId: number | bigint
+): Promise<[PublicKey, number]> => {
+    return await PublicKey.findProgramAddress(
+        [Buffer.from('job_bundle', 'utf8'), escrow.toBuffer(), authority.toBuffer(), toBufferLE(BigInt(bundleId), 8)],
+        RNDR_PROGRAM_ID
+    );
+};
+
+export const findJobTreeAddress = async (escrow: PublicKey, authority: PublicKey): Promise<[PublicKey, number]> => {
+    return await PublicKey.findProgramAddress(
+        [Buffer.from('job_tree', 'utf8'), escrow.toBuffer(), authority.toBuffer()],
+        RNDR_PROGRAM_ID
+    );
+};
+
+export const findJobIndexAddress = async (escrow: PublicKey, authority: PublicKey): Promise<[PublicKey, number]> => {
+    return await PublicKey.findProgramAddress(
+        [Buffer.from('job_index', 'utf8'), escrow.toBuffer(), authority.toBuffer()],
+        RNDR_PROGRAM_ID
+    );
+};
+
+export const findJobIndexPageAddress = async (jobIndex: PublicKey, page: number): Promise<[PublicKey, number]> => {
+    return await PublicKey.findProgramAddress(
+        [Buffer.from('job_index_page', 'utf8'), jobIndex.toBuffer(), toBufferLE(BigInt(page), 4)],
+        RNDR_PROGRAM_ID
+    );
+};
+
+export const findJobQueueAddress = async (escrow: PublicKey): Promise<[PublicKey, number]> => {
+    return await PublicKey.findProgramAddress([Buffer.from('job_queue', 'utf8'), escrow.toBuffer()], RNDR_PROGRAM_ID);
+};
+
+export const findReceiptAuthorityAddress = async (): Promise<[PublicKey, number]> => {
+    return await PublicKey.findProgramAddress([Buffer.from('receipt_authority', 'utf8')], RNDR_PROGRAM_ID);
+};
+
+export const findTreeConfigAddress = async (merkleTree: PublicKey): Promise<[PublicKey, number]> => {
+    return await PublicKey.findProgramAddress([merkleTree.toBuffer()], BUBBLEGUM_PROGRAM_ID);
+};
+
+export const findConfigAddress = async (): Promise<[PublicKey, number]> => {
+    return await PublicKey.findProgramAddress([Buffer.from('config', 'utf8')], RNDR_PROGRAM_ID);
+};
+
+export const findProgramDataAddress = async (): Promise<[PublicKey, number]> => {
+    return await PublicKey.findProgramAddress([RNDR_PROGRAM_ID.toBuffer()], BPF_LOADER_UPGRADEABLE_PROGRAM_ID);
+};
+
+export const findAssociatedTokenAddress = async (
+    owner: PublicKey,
+    tokenMint: PublicKey = RNDR_TOKEN_MINT
+): Promise<[PublicKey, number]> => {
+    return await PublicKey.findProgramAddress(
+        [owner.toBuffer(), TOKEN_PROGRAM_ID.toBuffer(), tokenMint.toBuffer()],
         ASSOCIATED_TOKEN_PROGRAM_ID
     );
 };
 
-export const findJobAddress = async (escrow: PublicKey, authority: PublicKey): Promise<[PublicKey, number]> => {
+export const findMintMigrationAddress = async (escrow: PublicKey, index: number): Promise<[PublicKey, number]> => {
     return await PublicKey.findProgramAddress(
-        [Buffer.from('job', 'utf8'), escrow.toBuffer(), authority.toBuffer()],
+        [Buffer.from('mint_migration', 'utf8'), escrow.toBuffer(), Buffer.from([index])],
         RNDR_PROGRAM_ID
     );
 };
@@ -0,0 +1,128 @@
+//! Concurrent merkle trees of SPL account compression, which job trees keep compressed jobs in
+//!
+//! A `JobTree` is the authority of a concurrent merkle tree of the account compression program,
+//! which appends and replaces its leaves, and the preimage of each leaf is logged through the noop
+//! program so indexers can rebuild the tree from the transactions' inner instructions. The
+//! `init_empty_merkle_tree`, `append` and `replace_leaf` instructions are encoded here in their
+//! Anchor layouts, so the program doesn't depend on the account compression crates.
+
+use solana_program::{
+    hash::HASH_BYTES,
+    instruction::{AccountMeta, Instruction},
+    pubkey,
+    pubkey::{Pubkey, PUBKEY_BYTES},
+};
+
+/// SPL account compression program id
+pub const ACCOUNT_COMPRESSION_PROGRAM_ID: Pubkey =
+    pubkey!("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK");
+/// SPL noop program id, which leaves are logged with
+pub const NOOP_PROGRAM_ID: Pubkey = pubkey!("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV");
+
+/// Anchor discriminator of the `InitEmptyMerkleTree` instruction, the first 8 bytes of the SHA-256
+/// hash of `global:init_empty_merkle_tree`
+pub const INIT_EMPTY_MERKLE_TREE_DISCRIMINATOR: [u8; 8] = [191, 11, 119, 7, 180, 107, 220, 110];
+/// Anchor discriminator of the `Append` instruction, the first 8 bytes of the SHA-256 hash of
+/// `global:append`
+pub const APPEND_DISCRIMINATOR: [u8; 8] = [149, 120, 18, 222, 236, 225, 88, 203];
+/// Anchor discriminator of the `ReplaceLeaf` instruction, the first 8 bytes of the SHA-256 hash of
+/// `global:replace_leaf`
+pub const REPLACE_LEAF_DISCRIMINATOR: [u8; 8] = [204, 165, 76, 100, 73, 147, 0, 128];
+
+/// Size of the header of a concurrent merkle tree account: its account type, header version,
+/// maximum buffer size, maximum depth, authority, creation slot and padding
+pub const MERKLE_TREE_HEADER_LEN: usize = 1 + 1 + 4 + 4 + PUBKEY_BYTES + 8 + 6;
+
+/// Size of a concurrent merkle tree account without a canopy, which has to be created with the
+/// account compression program as its owner before it's initialized
+pub fn merkle_tree_account_len(max_depth: u32, max_buffer_size: u32) -> usize {
+    let max_depth = max_depth as usize;
+    // Root, path and index of a change log, padded to 8 bytes
+    let change_log_len = HASH_BYTES + HASH_BYTES * max_depth + 8;
+    // Proof, leaf and index of the rightmost path, padded to 8 bytes
+    let rightmost_path_len = HASH_BYTES * max_depth + HASH_BYTES + 8;
+    // Sequence number, active index and buffer size
+    MERKLE_TREE_HEADER_LEN
+        + 8
+        + 8
+        + 8
+        + change_log_len * max_buffer_size as usize
+        + rightmost_path_len
+}
+
+/// Creates an account compression 'InitEmptyMerkleTree' instruction, setting the authority of the
+/// tree
+pub fn init_empty_merkle_tree(
+    merkle_tree: Pubkey,
+    authority: Pubkey,
+    max_depth: u32,
+    max_buffer_size: u32,
+) -> Instruction {
+    let mut data = INIT_EMPTY_MERKLE_TREE_DISCRIMINATOR.to_vec();
+    data.extend_from_slice(&max_depth.to_le_bytes());
+    data.extend_from_slice(&max_buffer_size.to_le_bytes());
+    Instruction {
+        program_id: ACCOUNT_COMPRESSION_PROGRAM_ID,
+        accounts: tree_accounts(merkle_tree, authority),
+        data,
+    }
+}
+
+/// Creates an account compression 'Append' instruction, appending a leaf to the tree
+pub fn append(merkle_tree: Pubkey, authority: Pubkey, leaf: [u8; HASH_BYTES]) -> Instruction {
+    let mut data = APPEND_DISCRIMINATOR.to_vec();
+    data.extend_from_slice(&leaf);
+    Instruction {
+        program_id: ACCOUNT_COMPRESSION_PROGRAM_ID,
+        accounts: tree_accounts(merkle_tree, authority),
+        data,
+    }
+}
+
+/// Creates an account compression 'ReplaceLeaf' instruction, replacing the leaf at an index with a
+/// proof of the previous leaf against a recent root of the tree. The proof nodes are passed as
+/// accounts, ordered from the leaf up, and can stop at the tree's canopy.
+pub fn replace_leaf(
+    merkle_tree: Pubkey,
+    authority: Pubkey,
+    root: [u8; HASH_BYTES],
+    previous_leaf: [u8; HASH_BYTES],
+    new_leaf: [u8; HASH_BYTES],
+    index: u32,
+    proof: &[Pubkey],
+) -> Instruction {
+    let mut data = REPLACE_LEAF_DISCRIMINATOR.to_vec();
+    data.extend_from_slice(&root);
+    data.extend_from_slice(&previous_leaf);
+    data.extend_from_slice(&new_leaf);
+    data.extend_from_slice(&index.to_le_bytes());
+    let mut accounts = tree_accounts(merkle_tree, authority);
+    accounts.extend(
+        proof
+            .iter()
+            .map(|node| AccountMeta::new_readonly(*node, false)),
+    );
+    Instruction {
+        program_id: ACCOUNT_COMPRESSION_PROGRAM_ID,
+        accounts,
+        data,
+    }
+}
+
+/// Creates a noop instruction logging data in the inner instructions of a transaction, where it
+/// isn't truncated like program logs can be
+pub fn wrap(data: Vec<u8>) -> Instruction {
+    Instruction {
+        program_id: NOOP_PROGRAM_ID,
+        accounts: vec![],
+        data,
+    }
+}
+
+fn tree_accounts(merkle_tree: Pubkey, authority: Pubkey) -> Vec<AccountMeta> {
+    vec![
+        AccountMeta::new(merkle_tree, false),
+        AccountMeta::new_readonly(authority, true),
+        AccountMeta::new_readonly(NOOP_PROGRAM_ID, false),
+    ]
+}
@@ -0,0 +1,99 @@
+//! Token amounts
+
+/// Amount of RNDR tokens in the mint's base units
+///
+/// Instruction builders and the client take amounts as `impl Into<RndrAmount>`, so raw `u64`
+/// amounts still convert, while UI amounts are converted explicitly with the mint's decimals.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct RndrAmount(pub u64);
+
+impl RndrAmount {
+    /// No tokens
+    pub const ZERO: Self = Self(0);
+
+    /// Amount of base units of a UI amount of tokens, such as `1.5`, for a mint with decimals,
+    /// rounded to the nearest base unit, or `None` if the UI amount is negative, not finite or
+    /// too large
+    pub fn from_ui_amount(ui_amount: f64, decimals: u8) -> Option<Self> {
+        let amount = (ui_amount * 10f64.powi(decimals as i32)).round();
+        if !amount.is_finite() || amount < 0.0 || amount >= u64::MAX as f64 {
+            return None;
+        }
+        Some(Self(amount as u64))
+    }
+
+    /// Amount of base units of a UI amount of tokens written in decimal, such as `"1.5"`, for a
+    /// mint with decimals, without the rounding of `from_ui_amount`, or `None` if it isn't a
+    /// decimal number, has more fractional digits than the mint's decimals or is too large
+    pub fn from_ui_amount_str(ui_amount: &str, decimals: u8) -> Option<Self> {
+        let (whole, fraction) = ui_amount.split_once('.').unwrap_or((ui_amount, ""));
+        if (whole.is_empty() && fraction.is_empty())
+            || fraction.len() > decimals as usize
+            || !whole
+                .bytes()
+                .chain(fraction.bytes())
+                .all(|b| b.is_ascii_digit())
+        {
+            return None;
+        }
+        // The base units are the digits with the fraction padded to the mint's decimals
+        format!("{}{:0<width$}", whole, fraction, width = decimals as usize)
+            .parse()
+            .ok()
+            .map(Self)
+    }
+
+    /// UI amount of tokens for a mint with decimals, which may lose precision for large amounts
+    pub fn to_ui_amount(self, decimals: u8) -> f64 {
+        self.0 as f64 / 10f64.powi(decimals as i32)
+    }
+
+    /// UI amount of tokens written in decimal for a mint with decimals, without trailing zeros
+    pub fn to_ui_amount_string(self, decimals: u8) -> String {
+        let scale = 10u128.pow(decimals as u32);
+        let whole = self.0 as u128 / scale;
+        let fraction = self.0 as u128 % scale;
+        if fraction == 0 {
+            return whole.to_string();
+        }
+        let fraction = format!("{:0width$}", fraction, width = decimals as usize);
+        format!("{}.{}", whole, fraction.trim_end_matches('0'))
+    }
+
+    /// Sum of two amounts, or `None` if it overflows
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        self.0.checked_add(other.0).map(Self)
+    }
+
+    /// Difference of two amounts, or `None` if it's negative
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.0.checked_sub(other.0).map(Self)
+    }
+
+    /// Amount multiplied by a factor, or `None` if it overflows
+    pub fn checked_mul(self, factor: u64) -> Option<Self> {
+        self.0.checked_mul(factor).map(Self)
+    }
+
+    /// Amount divided by a divisor, rounded down, or `None` if the divisor is zero
+    pub fn checked_div(self, divisor: u64) -> Option<Self> {
+        self.0.checked_div(divisor).map(Self)
+    }
+}
+
+impl From<u64> for RndrAmount {
+    fn from(amount: u64) -> Self {
+        Self(amount)
+    }
+}
+
+impl From<RndrAmount> for u64 {
+    fn from(amount: RndrAmount) -> Self {
+        amount.0
+    }
+}
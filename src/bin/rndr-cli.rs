@@ -0,0 +1,520 @@
+//! Command line interface for the RNDR program
+
+use {
+    clap::{crate_description, crate_version, App, AppSettings, Arg, ArgMatches, SubCommand},
+    rndr::{
+        amount::RndrAmount,
+        client::{snapshot::SnapshotFormat, Payers, RndrClient},
+        version::VERSION,
+    },
+    solana_clap_utils::{
+        input_parsers::{pubkey_of, value_of},
+        input_validators::{
+            is_amount, is_parsable, is_url_or_moniker, is_valid_pubkey, is_valid_signer,
+            normalize_to_url_if_moniker,
+        },
+        keypair::signer_from_path,
+    },
+    solana_client::nonblocking::rpc_client::RpcClient,
+    solana_program::{program_pack::Pack, pubkey::Pubkey},
+    solana_sdk::{commitment_config::CommitmentConfig, signer::Signer},
+    spl_associated_token_account::get_associated_token_address,
+    spl_token::state::Mint,
+    std::{
+        error::Error,
+        fs::File,
+        io::{self, BufWriter},
+        process::exit,
+        str::FromStr,
+    },
+};
+
+/// RNDR SPL Token mint used when `--mint` isn't provided
+const RNDR_TOKEN_MINT: &str = "EZPrZ4VS4XonzrcrsJaYVbXHtnAKw89wq5VN7beK4EJF";
+
+type CommandResult = Result<(), Box<dyn Error>>;
+
+fn app<'a, 'b>() -> App<'a, 'b> {
+    App::new("rndr-cli")
+        .about(crate_description!())
+        .version(crate_version!())
+        .setting(AppSettings::SubcommandRequiredElseHelp)
+        .arg(
+            Arg::with_name("config_file")
+                .short("C")
+                .long("config")
+                .value_name("PATH")
+                .takes_value(true)
+                .global(true)
+                .help("Configuration file to use"),
+        )
+        .arg(
+            Arg::with_name("json_rpc_url")
+                .short("u")
+                .long("url")
+                .value_name("URL_OR_MONIKER")
+                .takes_value(true)
+                .global(true)
+                .validator(is_url_or_moniker)
+                .help("URL for Solana's JSON RPC or moniker (or their first letter)"),
+        )
+        .arg(
+            Arg::with_name("keypair")
+                .short("k")
+                .long("keypair")
+                .value_name("KEYPAIR")
+                .takes_value(true)
+                .global(true)
+                .validator(is_valid_signer)
+                .help("Filepath or URL to a keypair that pays for transactions"),
+        )
+        .arg(
+            Arg::with_name("fee_payer")
+                .long("fee-payer")
+                .value_name("KEYPAIR")
+                .takes_value(true)
+                .global(true)
+                .validator(is_valid_signer)
+                .help("Filepath or URL to a keypair that pays transaction fees, leaving rent to the keypair [default: the keypair]"),
+        )
+        .arg(
+            Arg::with_name("mint")
+                .long("mint")
+                .value_name("MINT_ADDRESS")
+                .takes_value(true)
+                .global(true)
+                .validator(is_valid_pubkey)
+                .help("RNDR token mint of the escrow [default: the RNDR token mint]"),
+        )
+        .arg(
+            Arg::with_name("program_id")
+                .long("program-id")
+                .value_name("PROGRAM_ID")
+                .takes_value(true)
+                .global(true)
+                .validator(is_valid_pubkey)
+                .help("Address of the RNDR program [default: the deployed RNDR program]"),
+        )
+        .arg(
+            Arg::with_name("escrow_id")
+                .long("escrow-id")
+                .value_name("ESCROW_ID")
+                .takes_value(true)
+                .global(true)
+                .validator(is_parsable::<u64>)
+                .help("Namespace of the escrow of the token mint [default: 0, the default escrow]"),
+        )
+        .subcommand(
+            SubCommand::with_name("init-escrow")
+                .about("Initialize the escrow of the token mint")
+                .arg(
+                    Arg::with_name("owner")
+                        .long("owner")
+                        .value_name("OWNER_ADDRESS")
+                        .takes_value(true)
+                        .validator(is_valid_pubkey)
+                        .help("Owner authority that can disburse funds [default: the keypair]"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("fund-job")
+                .about("Transfer tokens into the escrow and credit a job")
+                .arg(job_id_arg(1))
+                .arg(amount_arg(2))
+                .arg(
+                    Arg::with_name("source")
+                        .long("source")
+                        .value_name("TOKEN_ADDRESS")
+                        .takes_value(true)
+                        .validator(is_valid_pubkey)
+                        .help("Source token account [default: associated token account of the authority]"),
+                )
+                .arg(
+                    Arg::with_name("authority")
+                        .long("authority")
+                        .value_name("KEYPAIR")
+                        .takes_value(true)
+                        .validator(is_valid_signer)
+                        .help("Authority of the job and the source token account [default: the keypair]"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("disburse")
+                .about("Transfer tokens from the escrow and debit a job")
+                .arg(authority_arg(1).required(true))
+                .arg(job_id_arg(2))
+                .arg(
+                    Arg::with_name("destination")
+                        .index(3)
+                        .value_name("TOKEN_ADDRESS")
+                        .takes_value(true)
+                        .required(true)
+                        .validator(is_valid_pubkey)
+                        .help("Destination token account"),
+                )
+                .arg(amount_arg(4))
+                .arg(
+                    Arg::with_name("owner")
+                        .long("owner")
+                        .value_name("KEYPAIR")
+                        .takes_value(true)
+                        .validator(is_valid_signer)
+                        .help("Owner authority of the escrow [default: the keypair]"),
+                ),
+        )
+        .subcommand(SubCommand::with_name("show-escrow").about("Show the escrow of the token mint"))
+        .subcommand(
+            SubCommand::with_name("verify-upgrade-authority").about(
+                "Verify that the program's upgrade authority is the config governance authority",
+            ),
+        )
+        .subcommand(
+            SubCommand::with_name("show-version")
+                .about("Show the version of the program deployed at the program id"),
+        )
+        .subcommand(
+            SubCommand::with_name("list-jobs")
+                .about("List the jobs of an authority")
+                .arg(authority_arg(1).help("Authority of the jobs [default: the keypair]")),
+        )
+        .subcommand(
+            SubCommand::with_name("export-snapshot")
+                .about("Export all escrow, escrow shard and job accounts of the program")
+                .arg(
+                    Arg::with_name("format")
+                        .long("format")
+                        .value_name("FORMAT")
+                        .takes_value(true)
+                        .possible_values(&["json", "csv"])
+                        .default_value("json")
+                        .help("Format of the snapshot"),
+                )
+                .arg(
+                    Arg::with_name("output")
+                        .long("output")
+                        .short("o")
+                        .value_name("PATH")
+                        .takes_value(true)
+                        .help("File to write the snapshot to [default: stdout]"),
+                ),
+        )
+}
+
+fn job_id_arg<'a, 'b>(index: u64) -> Arg<'a, 'b> {
+    Arg::with_name("job_id")
+        .index(index)
+        .value_name("JOB_ID")
+        .takes_value(true)
+        .required(true)
+        .validator(is_parsable::<u64>)
+        .help("Identifier of the job, unique for the authority")
+}
+
+fn amount_arg<'a, 'b>(index: u64) -> Arg<'a, 'b> {
+    Arg::with_name("amount")
+        .index(index)
+        .value_name("AMOUNT")
+        .takes_value(true)
+        .required(true)
+        .validator(is_amount)
+        .help("Amount of RNDR tokens")
+}
+
+fn authority_arg<'a, 'b>(index: u64) -> Arg<'a, 'b> {
+    Arg::with_name("authority")
+        .index(index)
+        .value_name("AUTHORITY_ADDRESS")
+        .takes_value(true)
+        .validator(is_valid_pubkey)
+        .help("Authority of the job")
+}
+
+struct Config {
+    client: RndrClient,
+    keypair_path: String,
+}
+
+impl Config {
+    fn new(matches: &ArgMatches) -> Result<Self, Box<dyn Error>> {
+        let cli_config = match matches.value_of("config_file") {
+            Some(config_file) => solana_cli_config::Config::load(config_file)?,
+            None => solana_cli_config::CONFIG_FILE
+                .as_ref()
+                .and_then(|config_file| solana_cli_config::Config::load(config_file).ok())
+                .unwrap_or_default(),
+        };
+        let json_rpc_url = normalize_to_url_if_moniker(
+            matches
+                .value_of("json_rpc_url")
+                .unwrap_or(&cli_config.json_rpc_url),
+        );
+        let keypair_path = matches
+            .value_of("keypair")
+            .unwrap_or(&cli_config.keypair_path)
+            .to_string();
+        let commitment = CommitmentConfig::from_str(&cli_config.commitment)?;
+        let token_mint = pubkey_of(matches, "mint")
+            .unwrap_or_else(|| Pubkey::from_str(RNDR_TOKEN_MINT).unwrap());
+
+        let rpc_client = RpcClient::new_with_commitment(json_rpc_url, commitment);
+        let mut client = RndrClient::new(rpc_client, token_mint);
+        if let Some(program_id) = pubkey_of(matches, "program_id") {
+            client = client.with_program_id(program_id);
+        }
+        if let Some(escrow_id) = value_of(matches, "escrow_id") {
+            client = client.with_escrow_id(escrow_id);
+        }
+
+        Ok(Self {
+            client,
+            keypair_path,
+        })
+    }
+
+    /// Load a signer from an argument, or the configured keypair if it's not provided
+    fn signer(&self, matches: &ArgMatches, name: &str) -> Result<Box<dyn Signer>, Box<dyn Error>> {
+        let path = matches.value_of(name).unwrap_or(&self.keypair_path);
+        signer_from_path(matches, path, name, &mut None)
+    }
+
+    /// Load the fee payer, or the configured keypair if it's not provided
+    fn fee_payer(&self, matches: &ArgMatches) -> Result<Box<dyn Signer>, Box<dyn Error>> {
+        self.signer(matches, "fee_payer")
+    }
+
+    async fn decimals(&self) -> Result<u8, Box<dyn Error>> {
+        let data = self
+            .client
+            .rpc_client()
+            .get_account_data(&self.client.token_mint())
+            .await?;
+        Ok(Mint::unpack(&data)?.decimals)
+    }
+
+    /// Parse the amount of RNDR tokens in the base units of the token mint
+    async fn amount(&self, matches: &ArgMatches<'_>) -> Result<RndrAmount, Box<dyn Error>> {
+        let ui_amount = value_of(matches, "amount").unwrap();
+        let amount = RndrAmount::from_ui_amount(ui_amount, self.decimals().await?)
+            .ok_or_else(|| format!("Amount {} is invalid", ui_amount))?;
+        Ok(amount)
+    }
+}
+
+async fn command_init_escrow(config: &Config, matches: &ArgMatches<'_>) -> CommandResult {
+    let funder = config.signer(matches, "keypair")?;
+    let fee_payer = config.fee_payer(matches)?;
+    let owner = pubkey_of(matches, "owner").unwrap_or_else(|| funder.pubkey());
+
+    let signature = config
+        .client
+        .init_escrow_with_payers(Payers::new(fee_payer.as_ref(), funder.as_ref()), &owner)
+        .await?;
+    println!("Escrow: {}", config.client.escrow_address());
+    println!("Signature: {}", signature);
+    Ok(())
+}
+
+async fn command_fund_job(config: &Config, matches: &ArgMatches<'_>) -> CommandResult {
+    let funder = config.signer(matches, "keypair")?;
+    let fee_payer = config.fee_payer(matches)?;
+    let authority = config.signer(matches, "authority")?;
+    let job_id = value_of::<u64>(matches, "job_id").unwrap();
+    let amount = config.amount(matches).await?;
+    let source_token = pubkey_of(matches, "source").unwrap_or_else(|| {
+        get_associated_token_address(&authority.pubkey(), &config.client.token_mint())
+    });
+
+    let signature = config
+        .client
+        .fund_job_with_payers(
+            Payers::new(fee_payer.as_ref(), funder.as_ref()),
+            &source_token,
+            authority.as_ref(),
+            job_id,
+            amount,
+        )
+        .await?;
+    println!(
+        "Job: {}",
+        config.client.job_address(&authority.pubkey(), job_id)
+    );
+    println!("Signature: {}", signature);
+    Ok(())
+}
+
+async fn command_disburse(config: &Config, matches: &ArgMatches<'_>) -> CommandResult {
+    let payer = config.fee_payer(matches)?;
+    let owner = config.signer(matches, "owner")?;
+    let authority = pubkey_of(matches, "authority").unwrap();
+    let job_id = value_of::<u64>(matches, "job_id").unwrap();
+    let destination_token = pubkey_of(matches, "destination").unwrap();
+    let amount = config.amount(matches).await?;
+
+    let signature = config
+        .client
+        .disburse_funds(
+            payer.as_ref(),
+            owner.as_ref(),
+            &authority,
+            job_id,
+            &destination_token,
+            amount,
+        )
+        .await?;
+    println!("Signature: {}", signature);
+    Ok(())
+}
+
+async fn command_show_escrow(config: &Config) -> CommandResult {
+    let escrow = config.client.get_escrow().await?;
+    let decimals = config.decimals().await?;
+
+    println!("Escrow: {}", config.client.escrow_address());
+    println!("Owner: {}", escrow.owner);
+    println!("Token mint: {}", escrow.token_mint);
+    if escrow.escrow_id != 0 {
+        println!("Escrow id: {}", escrow.escrow_id);
+    }
+    if escrow.mint_migrations > 0 {
+        println!(
+            "Migrated from token mint: {} ({} migrations)",
+            escrow.original_token_mint, escrow.mint_migrations
+        );
+    }
+    println!(
+        "Token account: {}",
+        escrow.token_account(&config.client.escrow_address())
+    );
+    println!(
+        "Amount: {}",
+        RndrAmount(escrow.amount).to_ui_amount_string(decimals)
+    );
+    println!("Challenge window: {} seconds", escrow.challenge_window);
+    match escrow.proof_verifier() {
+        Some(proof_verifier) => println!("Proof verifier: {}", proof_verifier),
+        None => println!("Proof verifier: none"),
+    }
+    match escrow.arbitrator() {
+        Some(arbitrator) => println!("Arbitrator: {}", arbitrator),
+        None => println!("Arbitrator: none"),
+    }
+    match escrow.approver() {
+        Some(approver) => println!("Approver: {}", approver),
+        None => println!("Approver: none"),
+    }
+    for rotation in &escrow.owner_history {
+        println!(
+            "Previous owner: {} until slot {}",
+            rotation.previous_owner, rotation.slot
+        );
+    }
+    Ok(())
+}
+
+async fn command_verify_upgrade_authority(
+    config: &Config,
+    matches: &ArgMatches<'_>,
+) -> CommandResult {
+    let payer = config.fee_payer(matches)?;
+    let governance = config.client.get_config().await?.governance;
+
+    println!("Governance: {}", governance);
+    config
+        .client
+        .verify_upgrade_authority(&payer.pubkey())
+        .await?;
+    println!("Verified: the program is immutable or upgradeable only by the governance authority");
+    Ok(())
+}
+
+async fn command_show_version(config: &Config, matches: &ArgMatches<'_>) -> CommandResult {
+    let payer = config.fee_payer(matches)?;
+    let version = config.client.get_version(&payer.pubkey()).await?;
+
+    println!("Program: {}", config.client.program_id());
+    println!(
+        "Deployed version: {}.{}.{}",
+        version.major, version.minor, version.patch
+    );
+    println!("Deployed layout version: {}", version.layout_version);
+    if version.layout_version != VERSION.layout_version {
+        println!(
+            "Warning: this CLI reads layout version {}",
+            VERSION.layout_version
+        );
+    }
+    Ok(())
+}
+
+async fn command_list_jobs(config: &Config, matches: &ArgMatches<'_>) -> CommandResult {
+    let authority = match pubkey_of(matches, "authority") {
+        Some(authority) => authority,
+        None => config.signer(matches, "keypair")?.pubkey(),
+    };
+    let mut jobs = config.client.list_jobs_by_authority(&authority).await?;
+    jobs.sort_by_key(|(_pubkey, job)| job.id);
+    let decimals = config.decimals().await?;
+
+    println!(
+        "{:<20} {:<44} {:<8} Amount",
+        "Job ID", "Address", "Isolated"
+    );
+    for (pubkey, job) in jobs {
+        println!(
+            "{:<20} {:<44} {:<8} {}",
+            job.id,
+            pubkey,
+            job.isolated,
+            RndrAmount(job.amount).to_ui_amount_string(decimals)
+        );
+    }
+    Ok(())
+}
+
+async fn command_export_snapshot(config: &Config, matches: &ArgMatches<'_>) -> CommandResult {
+    let format = value_of::<SnapshotFormat>(matches, "format").unwrap();
+    let snapshot = config.client.snapshot().await?;
+
+    match matches.value_of("output") {
+        Some(path) => {
+            snapshot.write(format, BufWriter::new(File::create(path)?))?;
+            eprintln!(
+                "Exported {} escrows, {} escrow shards and {} jobs at slot {} to {}",
+                snapshot.escrows.len(),
+                snapshot.escrow_shards.len(),
+                snapshot.jobs.len(),
+                snapshot.slot,
+                path
+            );
+        }
+        None => snapshot.write(format, io::stdout().lock())?,
+    }
+    Ok(())
+}
+
+#[tokio::main]
+async fn main() {
+    let matches = app().get_matches();
+
+    let result = match Config::new(&matches) {
+        Ok(config) => match matches.subcommand() {
+            ("init-escrow", Some(matches)) => command_init_escrow(&config, matches).await,
+            ("fund-job", Some(matches)) => command_fund_job(&config, matches).await,
+            ("disburse", Some(matches)) => command_disburse(&config, matches).await,
+            ("show-escrow", Some(_matches)) => command_show_escrow(&config).await,
+            ("verify-upgrade-authority", Some(matches)) => {
+                command_verify_upgrade_authority(&config, matches).await
+            }
+            ("show-version", Some(matches)) => command_show_version(&config, matches).await,
+            ("list-jobs", Some(matches)) => command_list_jobs(&config, matches).await,
+            ("export-snapshot", Some(matches)) => command_export_snapshot(&config, matches).await,
+            _ => unreachable!(),
+        },
+        Err(error) => Err(error),
+    };
+
+    if let Err(error) = result {
+        eprintln!("Error: {}", error);
+        exit(1);
+    }
+}
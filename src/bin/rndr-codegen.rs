@@ -0,0 +1,27 @@
+//! Generates the TypeScript bindings of the web client or the IDL from the program's definitions
+//!
+//! Prints the TypeScript bindings by default, or the IDL when run with `idl`.
+
+use {
+    rndr::codegen::{idl, typescript, Schema},
+    std::{env, process::exit},
+};
+
+fn main() {
+    let target = env::args().nth(1);
+    let output = Schema::parse().and_then(|schema| match target.as_deref() {
+        None | Some("typescript") => Ok(typescript::render(&schema)),
+        Some("idl") => idl::render(&schema),
+        Some(target) => Err(format!(
+            "{} is not a target, expected typescript or idl",
+            target
+        )),
+    });
+    match output {
+        Ok(output) => print!("{}", output),
+        Err(e) => {
+            eprintln!("error: {}", e);
+            exit(1);
+        }
+    }
+}
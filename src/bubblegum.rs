@@ -0,0 +1,120 @@
+//! Compressed NFT receipts minted with Metaplex Bubblegum
+//!
+//! `FundJob` can mint a compressed NFT to the funder as a receipt for the Job, a cheaper
+//! alternative to a full NFT since its metadata is only stored as a leaf of a concurrent merkle
+//! tree. The `MintV1` instruction of Bubblegum is encoded here in its Anchor layout, so the
+//! program doesn't depend on the Bubblegum crates. Bubblegum only mints to a tree from its
+//! creator or its delegate, so the receipt authority PDA of the program has to be set as the
+//! delegate of the trees receipts are minted to.
+
+pub use crate::account_compression::{ACCOUNT_COMPRESSION_PROGRAM_ID, NOOP_PROGRAM_ID};
+use {
+    crate::state::MAX_JOB_METADATA_URI_LEN,
+    solana_program::{
+        hash::HASH_BYTES,
+        instruction::{AccountMeta, Instruction},
+        pubkey,
+        pubkey::Pubkey,
+        system_program,
+    },
+};
+
+/// Metaplex Bubblegum program id
+pub const BUBBLEGUM_PROGRAM_ID: Pubkey = pubkey!("BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY");
+
+/// Anchor discriminator of the `MintV1` instruction, the first 8 bytes of the SHA-256 hash of
+/// `global:mint_v1`
+pub const MINT_V1_DISCRIMINATOR: [u8; 8] = [145, 98, 192, 118, 184, 147, 118, 104];
+
+/// Symbol of receipts
+pub const RECEIPT_SYMBOL: &str = "RNDR";
+/// Maximum length in bytes of the URI of a compressed NFT
+pub const MAX_RECEIPT_URI_LEN: usize = 200;
+
+// The manifest URI of a Job with its hash appended as a fragment always fits in a receipt
+const _: () = assert!(MAX_JOB_METADATA_URI_LEN + 1 + 2 * HASH_BYTES <= MAX_RECEIPT_URI_LEN);
+
+/// Metadata of the compressed NFT receipt of a Job
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReceiptMetadata {
+    /// Name of the receipt, with the identifier of the Job
+    pub name: String,
+    /// URI of the receipt, the URI of the Job's manifest with the hash of the manifest appended
+    /// as a fragment, so the receipt records the manifest it was funded for
+    pub uri: String,
+}
+
+impl ReceiptMetadata {
+    /// Metadata of the receipt of a Job with an identifier and the URI and hash of its manifest,
+    /// which are empty and zero if the Job has no metadata
+    pub fn new(job_id: u64, manifest_uri: &str, manifest_hash: &[u8; HASH_BYTES]) -> Self {
+        let mut uri = manifest_uri.to_string();
+        if manifest_hash != &[0; HASH_BYTES] {
+            uri.push('#');
+            for byte in manifest_hash {
+                uri.push_str(&format!("{:02x}", byte));
+            }
+        }
+        Self {
+            name: format!("RNDR Job {}", job_id),
+            uri,
+        }
+    }
+
+    /// Pack the metadata as the `MetadataArgs` of a non-fungible, immutable compressed NFT without
+    /// creators, collection or uses
+    pub fn pack(&self) -> Vec<u8> {
+        let mut output = Vec::with_capacity(
+            4 + self.name.len() + 4 + RECEIPT_SYMBOL.len() + 4 + self.uri.len() + 13,
+        );
+        for string in [self.name.as_str(), RECEIPT_SYMBOL, self.uri.as_str()] {
+            output.extend_from_slice(&(string.len() as u32).to_le_bytes());
+            output.extend_from_slice(string.as_bytes());
+        }
+        // Seller fee basis points
+        output.extend_from_slice(&0u16.to_le_bytes());
+        // Primary sale happened, is mutable
+        output.extend_from_slice(&[0, 0]);
+        // No edition nonce, a non-fungible token standard
+        output.extend_from_slice(&[0, 1, 0]);
+        // No collection or uses, the original token program version
+        output.extend_from_slice(&[0, 0, 0]);
+        // No creators
+        output.extend_from_slice(&0u32.to_le_bytes());
+        output
+    }
+}
+
+/// Find the tree config PDA of Bubblegum for a merkle tree
+pub fn find_tree_config_address(merkle_tree: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[merkle_tree.as_ref()], &BUBBLEGUM_PROGRAM_ID)
+}
+
+/// Creates a Bubblegum 'MintV1' instruction minting a receipt to a leaf owner, which is also its
+/// delegate, signed by the receipt authority as the delegate of the tree
+pub fn mint_v1(
+    merkle_tree: Pubkey,
+    leaf_owner: Pubkey,
+    payer: Pubkey,
+    receipt_authority: Pubkey,
+    metadata: &ReceiptMetadata,
+) -> Instruction {
+    let (tree_config, _bump_seed) = find_tree_config_address(&merkle_tree);
+    let mut data = MINT_V1_DISCRIMINATOR.to_vec();
+    data.extend_from_slice(&metadata.pack());
+    Instruction {
+        program_id: BUBBLEGUM_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(tree_config, false),
+            AccountMeta::new_readonly(leaf_owner, false),
+            AccountMeta::new_readonly(leaf_owner, false),
+            AccountMeta::new(merkle_tree, false),
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(receipt_authority, true),
+            AccountMeta::new_readonly(NOOP_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ACCOUNT_COMPRESSION_PROGRAM_ID, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data,
+    }
+}
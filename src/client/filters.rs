@@ -0,0 +1,124 @@
+//! Filters for `getProgramAccounts` queries, built from the packed state offsets
+
+use {
+    crate::state::{AccountType, Escrow, EscrowShard, Job, JobStatus, ACCOUNT_TYPE_OFFSET},
+    solana_client::rpc_filter::{Memcmp, RpcFilterType},
+    solana_program::{program_pack::Pack, pubkey::Pubkey},
+};
+
+/// Filter matching accounts of a type, of any length
+pub fn account_type_filter(account_type: AccountType) -> RpcFilterType {
+    RpcFilterType::Memcmp(Memcmp::new_base58_encoded(
+        ACCOUNT_TYPE_OFFSET,
+        &[u8::from(account_type)],
+    ))
+}
+
+/// Filters matching accounts of a type and packed length
+pub fn account_type_filters(account_type: AccountType, len: usize) -> Vec<RpcFilterType> {
+    vec![
+        RpcFilterType::DataSize(len as u64),
+        account_type_filter(account_type),
+    ]
+}
+
+/// Filters matching all escrows
+///
+/// Escrows with the legacy length are included too, so the data size isn't filtered on.
+pub fn escrow_filters() -> Vec<RpcFilterType> {
+    vec![account_type_filter(AccountType::EscrowV1)]
+}
+
+/// Filters matching escrows owned by an owner authority
+pub fn escrows_by_owner_filters(owner: &Pubkey) -> Vec<RpcFilterType> {
+    let mut filters = escrow_filters();
+    filters.push(RpcFilterType::Memcmp(Memcmp::new_base58_encoded(
+        Escrow::OWNER_OFFSET,
+        owner.as_ref(),
+    )));
+    filters
+}
+
+/// Filters matching all escrow shards
+pub fn escrow_shard_filters() -> Vec<RpcFilterType> {
+    account_type_filters(AccountType::EscrowShardV1, EscrowShard::LEN)
+}
+
+/// Filters matching the shards of an escrow
+pub fn escrow_shards_by_escrow_filters(escrow: &Pubkey) -> Vec<RpcFilterType> {
+    let mut filters = escrow_shard_filters();
+    filters.push(RpcFilterType::Memcmp(Memcmp::new_base58_encoded(
+        EscrowShard::ESCROW_OFFSET,
+        escrow.as_ref(),
+    )));
+    filters
+}
+
+/// Filters matching all jobs
+///
+/// Jobs don't store their escrow, so results include jobs of every escrow of the program. Jobs
+/// with the legacy length are included too, so the data size isn't filtered on.
+pub fn job_filters() -> Vec<RpcFilterType> {
+    vec![account_type_filter(AccountType::JobV1)]
+}
+
+/// Filters matching the jobs of an authority
+pub fn jobs_by_authority_filters(authority: &Pubkey) -> Vec<RpcFilterType> {
+    let mut filters = job_filters();
+    filters.push(RpcFilterType::Memcmp(Memcmp::new_base58_encoded(
+        Job::AUTHORITY_OFFSET,
+        authority.as_ref(),
+    )));
+    filters
+}
+
+/// Filters matching jobs that hold their tokens in their own token account, or in the escrow's
+///
+/// Jobs with the legacy length end before the isolated flag, so they aren't matched even though
+/// their tokens are held in the escrow's. They're only included by `job_filters`.
+pub fn jobs_by_isolated_filters(isolated: bool) -> Vec<RpcFilterType> {
+    let mut filters = job_filters();
+    filters.push(RpcFilterType::Memcmp(Memcmp::new_base58_encoded(
+        Job::ISOLATED_OFFSET,
+        &[isolated as u8],
+    )));
+    filters
+}
+
+/// Filters matching jobs with a status, such as the open jobs no node has committed to yet
+///
+/// Jobs with the legacy length end before the status, so open jobs that haven't been written
+/// since the status was appended aren't matched. They're only included by `job_filters`.
+pub fn jobs_by_status_filters(status: JobStatus) -> Vec<RpcFilterType> {
+    let mut filters = job_filters();
+    filters.push(RpcFilterType::Memcmp(Memcmp::new_base58_encoded(
+        Job::STATUS_OFFSET,
+        &[u8::from(status)],
+    )));
+    filters
+}
+
+/// Filters matching jobs with exactly a set of tags
+///
+/// Jobs created before the tags were appended to the layout, and that haven't been reallocated
+/// since, end before the tags, so they're not matched even with no tags. Nodes that can run jobs
+/// with any subset of their capabilities filter on the status instead, and then on
+/// `Job::is_suitable_for`.
+pub fn jobs_by_tags_filters(tags: u64) -> Vec<RpcFilterType> {
+    let mut filters = job_filters();
+    filters.push(RpcFilterType::Memcmp(Memcmp::new_base58_encoded(
+        Job::TAGS_OFFSET,
+        &tags.to_le_bytes(),
+    )));
+    filters
+}
+
+/// Filters matching the jobs a node has committed to a result for
+pub fn jobs_by_node_filters(node: &Pubkey) -> Vec<RpcFilterType> {
+    let mut filters = job_filters();
+    filters.push(RpcFilterType::Memcmp(Memcmp::new_base58_encoded(
+        Job::NODE_OFFSET,
+        node.as_ref(),
+    )));
+    filters
+}
@@ -0,0 +1,268 @@
+//! Decoding of the logs of a failed transaction into the RNDR instruction that failed and why
+//!
+//! Support dashboards and wallets usually only have the error and log messages of a transaction's
+//! status meta. The logs of the failed instruction are found by counting the top-level invokes,
+//! then the program's own logs name the instruction, and the last message it logged explains the
+//! failure. The account that message is about is found from its address when the message includes
+//! one, or from the phrasing of the account checks, such as "Job provided is not owned by the RNDR
+//! program".
+
+use {
+    super::parse::{account_names, parse},
+    crate::error::RNDRError,
+    num_traits::FromPrimitive,
+    serde_json::Value,
+    solana_program::{
+        instruction::{CompiledInstruction, InstructionError},
+        message::AccountKeys,
+        pubkey::Pubkey,
+    },
+    solana_sdk::transaction::TransactionError,
+    std::str::FromStr,
+};
+
+/// Phrases that follow the account an account check is about
+const ACCOUNT_CHECK_PHRASES: &[&str] = &[" provided ", " program derived address "];
+
+/// RNDR instruction that failed in a transaction, reconstructed from its logs
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FailedInstruction {
+    /// Index of the instruction in the transaction
+    pub index: u8,
+    /// Name of the RNDR instruction in Pascal case, such as `DisburseFunds`, unless the logs were
+    /// truncated before the program logged it
+    pub name: Option<String>,
+    /// Error the instruction failed with
+    pub error: InstructionError,
+    /// Error returned by the program, if it failed with one of its own
+    pub program_error: Option<RNDRError>,
+    /// Last message the program logged before failing
+    pub message: Option<String>,
+    /// Name of the account the message is about, as in the IDL
+    pub account_name: Option<String>,
+    /// Address of the account the message is about
+    pub account: Option<Pubkey>,
+}
+
+impl FailedInstruction {
+    /// Fill in the name of the instruction and the address of the account from the instructions and
+    /// account keys of the transaction's message
+    pub fn with_message(
+        mut self,
+        instructions: &[CompiledInstruction],
+        account_keys: &AccountKeys,
+    ) -> Self {
+        let parsed = match instructions
+            .get(self.index as usize)
+            .map(|instruction| parse(instruction, account_keys))
+        {
+            Some(Ok(parsed)) => parsed,
+            _ => return self,
+        };
+        if self.name.is_none() {
+            let instruction_type = &parsed.instruction_type;
+            self.name = Some(instruction_type[..1].to_uppercase() + &instruction_type[1..]);
+        }
+        if self.account.is_none() && self.account_name.is_none() {
+            self.account_name = self
+                .message
+                .as_deref()
+                .and_then(|message| checked_account_name(&parsed.instruction_type, message));
+        }
+        match (&self.account, &self.account_name) {
+            (Some(account), None) => {
+                let account = Value::String(account.to_string());
+                self.account_name = parsed.info.as_object().and_then(|info| {
+                    info.iter()
+                        .find(|(_, value)| match *value {
+                            Value::Array(values) => values.contains(&account),
+                            value => *value == account,
+                        })
+                        .map(|(name, _)| name.clone())
+                });
+            }
+            (None, Some(account_name)) => {
+                self.account = parsed.info[account_name]
+                    .as_str()
+                    .and_then(|account| Pubkey::from_str(account).ok());
+            }
+            _ => {}
+        }
+        self
+    }
+
+    /// Human-readable cause of the failure, the message the program logged when there is one
+    pub fn cause(&self) -> String {
+        match (&self.message, &self.program_error) {
+            (Some(message), _) => message.clone(),
+            (None, Some(program_error)) => describe_error(program_error),
+            (None, None) => self.error.to_string(),
+        }
+    }
+}
+
+/// Human-readable description of an error returned by the program, for when the logs that explain
+/// it were truncated
+pub fn describe_error(error: &RNDRError) -> String {
+    match error {
+        RNDRError::JobAlreadyAssigned => "Job is already assigned to another node",
+        RNDRError::JobFrozen => "Job is frozen under a legal hold",
+        RNDRError::IncidentModeActive => "Escrow incident mode does not allow the instruction",
+        RNDRError::InvalidApprover => "Approver of the escrow did not sign or does not match",
+        RNDRError::ExchangeRateAlreadySet => "Job exchange rate has already been recorded",
+        RNDRError::JobDisputed => "Job is disputed",
+        RNDRError::ChallengeWindowActive => "Job challenge window has not passed",
+        RNDRError::ApprovalRequired => "Escrow requires disbursements to be proposed and approved",
+        RNDRError::ProposalNotApproved => "Proposal has not been approved",
+        error => return error.to_string(),
+    }
+    .to_string()
+}
+
+/// Reconstruct the RNDR instruction that failed from the error and log messages of a transaction
+///
+/// Returns `None` if the transaction didn't fail in an instruction, or if the program wasn't
+/// invoked by the failed instruction.
+pub fn decode_failure(
+    program_id: &Pubkey,
+    error: &TransactionError,
+    logs: &[String],
+) -> Option<FailedInstruction> {
+    let (index, error) = match error {
+        TransactionError::InstructionError(index, error) => (*index, error),
+        _ => return None,
+    };
+    let program = program_id.to_string();
+
+    // Logs of the failed instruction, from its top-level invoke
+    let mut top_level = None;
+    let mut invoked = vec![];
+    let mut is_top_level_program = false;
+    let mut frame = None;
+    let mut failed = None;
+    for log in logs {
+        let (id, rest) = match log
+            .strip_prefix("Program ")
+            .and_then(|log| log.split_once(' '))
+        {
+            Some(line) => line,
+            None => continue,
+        };
+        if rest == "invoke [1]" {
+            top_level = Some(top_level.map_or(0, |index: u8| index.saturating_add(1)));
+            is_top_level_program = id == program;
+        }
+        if top_level != Some(index) {
+            continue;
+        }
+
+        if rest.starts_with("invoke [") {
+            invoked.push(id == program);
+            if id == program {
+                frame = Some(Frame::default());
+            }
+        } else if rest == "success" || rest.starts_with("failed: ") {
+            if invoked.pop() == Some(true) {
+                let ended = frame.take();
+                if rest != "success" {
+                    failed = ended;
+                }
+            }
+        } else if id == "log:" && invoked.last() == Some(&true) {
+            if let Some(frame) = frame.as_mut() {
+                frame.log(rest);
+            }
+        }
+    }
+
+    // Custom errors of other programs are passed through by the programs that invoke them, so the
+    // error is only the program's own if it failed, or if the logs were truncated before it did
+    let program_error = match error {
+        InstructionError::Custom(code) if failed.is_some() || is_top_level_program => {
+            RNDRError::from_u32(*code)
+        }
+        _ => None,
+    };
+    let frame = failed.or(frame)?;
+    let account = frame.message.as_deref().and_then(mentioned_account);
+    let account_name = match (&frame.name, &frame.message, account) {
+        (Some(name), Some(message), None) => {
+            checked_account_name(&(name[..1].to_lowercase() + &name[1..]), message)
+        }
+        _ => None,
+    };
+    Some(FailedInstruction {
+        index,
+        name: frame.name,
+        error: error.clone(),
+        program_error,
+        message: frame.message,
+        account_name,
+        account,
+    })
+}
+
+/// Logs of an invocation of the program
+#[derive(Default)]
+struct Frame {
+    name: Option<String>,
+    message: Option<String>,
+}
+
+impl Frame {
+    fn log(&mut self, text: &str) {
+        if let Some(name) = text.strip_prefix("Instruction: ") {
+            self.name = Some(name.to_string());
+        } else if !is_printed_error(text) {
+            self.message = Some(text.to_string());
+        }
+    }
+}
+
+/// Whether a message is an error printed by the program's entrypoint, rather than its cause
+fn is_printed_error(text: &str) -> bool {
+    text.starts_with("Error: ")
+        || (0..)
+            .map_while(RNDRError::from_u32)
+            .any(|error| error.to_string() == text)
+}
+
+/// First address mentioned in a message
+fn mentioned_account(message: &str) -> Option<Pubkey> {
+    message
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| (32..=44).contains(&word.len()))
+        .find_map(|word| Pubkey::from_str(word).ok())
+}
+
+/// Name of the account an account check of an instruction is about, such as `jobAccount` for "Job
+/// provided is not owned by the RNDR program"
+fn checked_account_name(instruction_type: &str, message: &str) -> Option<String> {
+    let subject = ACCOUNT_CHECK_PHRASES
+        .iter()
+        .find_map(|phrase| message.split_once(phrase))?
+        .0
+        .to_lowercase();
+    account_names(instruction_type)?
+        .into_iter()
+        .find(|name| {
+            let mut words = words(name);
+            if words.last().map(String::as_str) == Some("account") {
+                words.pop();
+            }
+            words.join(" ") == subject
+        })
+        .map(str::to_string)
+}
+
+/// Lowercase words of a camel case name
+fn words(name: &str) -> Vec<String> {
+    let mut words: Vec<String> = vec![];
+    for c in name.chars() {
+        match words.last_mut() {
+            Some(word) if !c.is_uppercase() => word.push(c),
+            _ => words.push(c.to_lowercase().collect()),
+        }
+    }
+    words
+}
@@ -0,0 +1,127 @@
+//! Address lookup tables for batch operations
+
+use {
+    super::{RndrClient, RndrClientResult},
+    crate::instruction::disburse_batch,
+    solana_program::{
+        address_lookup_table::{
+            instruction::{create_lookup_table, extend_lookup_table},
+            state::AddressLookupTable,
+            AddressLookupTableAccount,
+        },
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        system_program,
+    },
+    solana_sdk::{commitment_config::CommitmentConfig, signature::Signature, signer::Signer},
+    spl_associated_token_account::get_associated_token_address,
+};
+
+/// Maximum number of addresses added to a lookup table in one transaction
+pub const MAX_EXTEND_ADDRESSES: usize = 20;
+
+impl RndrClient {
+    /// Addresses used by every batch operation on the escrow: the escrow, its token account,
+    /// and the programs passed to it
+    pub fn escrow_lookup_table_addresses(&self) -> Vec<Pubkey> {
+        let escrow = self.escrow_address();
+        vec![
+            escrow,
+            get_associated_token_address(&escrow, &self.token_mint),
+            spl_token::id(),
+            system_program::id(),
+        ]
+    }
+
+    /// Create a lookup table with the escrow addresses and additional addresses, such as the
+    /// payout token accounts of frequently paid nodes, and return its address
+    pub async fn create_lookup_table(
+        &self,
+        payer: &dyn Signer,
+        authority: &dyn Signer,
+        addresses: &[Pubkey],
+    ) -> RndrClientResult<Pubkey> {
+        let recent_slot = self
+            .rpc_client
+            .get_slot_with_commitment(CommitmentConfig::finalized())
+            .await?;
+        let (instruction, lookup_table) =
+            create_lookup_table(authority.pubkey(), payer.pubkey(), recent_slot);
+        self.send(&[instruction], payer, &[authority]).await?;
+
+        let mut all_addresses = self.escrow_lookup_table_addresses();
+        all_addresses.extend_from_slice(addresses);
+        self.extend_lookup_table(payer, authority, &lookup_table, &all_addresses)
+            .await?;
+
+        Ok(lookup_table)
+    }
+
+    /// Add addresses a lookup table doesn't contain yet, over as many transactions as needed
+    pub async fn extend_lookup_table(
+        &self,
+        payer: &dyn Signer,
+        authority: &dyn Signer,
+        lookup_table: &Pubkey,
+        addresses: &[Pubkey],
+    ) -> RndrClientResult<()> {
+        let existing = self.get_lookup_table(lookup_table).await?.addresses;
+        let mut new_addresses = vec![];
+        for address in addresses {
+            if !existing.contains(address) && !new_addresses.contains(address) {
+                new_addresses.push(*address);
+            }
+        }
+
+        for chunk in new_addresses.chunks(MAX_EXTEND_ADDRESSES) {
+            let instruction = extend_lookup_table(
+                *lookup_table,
+                authority.pubkey(),
+                Some(payer.pubkey()),
+                chunk.to_vec(),
+            );
+            self.send(&[instruction], payer, &[authority]).await?;
+        }
+        Ok(())
+    }
+
+    /// Fetch a lookup table to use in versioned transactions
+    pub async fn get_lookup_table(
+        &self,
+        lookup_table: &Pubkey,
+    ) -> RndrClientResult<AddressLookupTableAccount> {
+        let data = self.rpc_client.get_account_data(lookup_table).await?;
+        let table = AddressLookupTable::deserialize(&data)
+            .map_err(|_error| ProgramError::InvalidAccountData)?;
+        Ok(AddressLookupTableAccount {
+            key: *lookup_table,
+            addresses: table.addresses.to_vec(),
+        })
+    }
+
+    /// Transfer funds from the escrow to multiple destinations and debit a job, loading
+    /// accounts from a lookup table so more destinations fit in the transaction
+    pub async fn disburse_batch(
+        &self,
+        payer: &dyn Signer,
+        owner: &dyn Signer,
+        authority: &Pubkey,
+        job_id: u64,
+        disbursements: &[(Pubkey, u64)],
+        lookup_table: &Pubkey,
+    ) -> RndrClientResult<Signature> {
+        let instruction = disburse_batch(
+            self.program_id,
+            self.escrow_seeds(),
+            self.job_address(authority, job_id),
+            owner.pubkey(),
+            disbursements,
+        );
+        let lookup_table = self.get_lookup_table(lookup_table).await?;
+        self.transaction()
+            .instruction(instruction)
+            .lookup_table(lookup_table)
+            .send_versioned(payer, &[owner])
+            .await
+    }
+}
@@ -0,0 +1,634 @@
+//! Off-chain RPC client for the RNDR program
+
+#![cfg(not(target_arch = "bpf"))]
+
+pub mod filters;
+pub mod logs;
+pub mod lookup_table;
+pub mod parse;
+pub mod preflight;
+pub mod pubsub;
+pub mod sdk;
+pub mod sender;
+pub mod snapshot;
+pub mod solana_pay;
+pub mod transaction;
+
+use {
+    crate::{
+        amount::RndrAmount,
+        error::RNDRError,
+        instruction::{
+            disburse_funds, fund_job, get_escrow_summary, get_escrowed_amount, get_job_summary,
+            get_version, init_escrow, init_escrow_with_seed, verify_upgrade_authority,
+        },
+        pda::{
+            find_config_address, find_escrow_address_with_id, find_job_address,
+            find_job_index_address, find_job_index_page_address, find_job_queue_address,
+            EscrowSeeds,
+        },
+        state::{Config, Escrow, Job, JobIndex, JobIndexPage, JobQueue, JobStatus},
+        summary::{EscrowSummary, JobSummary},
+        version::ProgramVersion,
+    },
+    base64::{engine::general_purpose::STANDARD, Engine},
+    sdk::JobHandle,
+    solana_account_decoder::UiAccountEncoding,
+    solana_client::{
+        client_error::ClientError,
+        nonblocking::rpc_client::RpcClient,
+        nonce_utils::Error as NonceError,
+        pubsub_client::PubsubClientError,
+        rpc_config::{
+            RpcAccountInfoConfig, RpcProgramAccountsConfig, RpcSimulateTransactionConfig,
+        },
+        rpc_filter::RpcFilterType,
+        rpc_request::MAX_MULTIPLE_ACCOUNTS,
+    },
+    solana_program::{
+        instruction::Instruction, message::CompileError, program_error::ProgramError,
+        pubkey::Pubkey,
+    },
+    solana_sdk::{
+        account::Account,
+        program_pack::Pack,
+        signature::Signature,
+        signer::{Signer, SignerError},
+        transaction::{Transaction, TransactionError},
+    },
+    std::convert::TryInto,
+    thiserror::Error,
+    transaction::{PriorityFee, TransactionBuilder},
+};
+
+/// Errors that may be returned by the client
+#[derive(Debug, Error)]
+pub enum RndrClientError {
+    /// The RPC request failed
+    #[error(transparent)]
+    Client(#[from] ClientError),
+    /// The account data or return data couldn't be unpacked
+    #[error(transparent)]
+    Program(#[from] ProgramError),
+    /// The transaction couldn't be signed
+    #[error(transparent)]
+    Signer(#[from] SignerError),
+    /// The versioned message couldn't be compiled with the lookup tables
+    #[error(transparent)]
+    Compile(#[from] CompileError),
+    /// The pubsub subscription failed
+    #[error(transparent)]
+    Pubsub(#[from] PubsubClientError),
+    /// The durable nonce account couldn't be read
+    #[error(transparent)]
+    Nonce(#[from] NonceError),
+    /// The transaction couldn't be serialized
+    #[error(transparent)]
+    Serialize(#[from] bincode::Error),
+    /// The return data couldn't be decoded
+    #[error(transparent)]
+    Decode(#[from] base64::DecodeError),
+    /// The transaction failed in simulation
+    #[error("transaction simulation failed: {0}")]
+    Simulation(TransactionError),
+    /// An account the client expected to exist wasn't found
+    #[error("account not found: {0}")]
+    AccountNotFound(Pubkey),
+    /// The program didn't set the return data the client expected
+    #[error("no return data from program: {0}")]
+    ReturnDataNotFound(Pubkey),
+}
+
+/// Result type returned by the client
+pub type RndrClientResult<T> = Result<T, RndrClientError>;
+
+/// Signers that pay for a transaction
+///
+/// The fee payer pays the transaction fee, while the rent payer funds the rent of the accounts an
+/// instruction creates. Custodial and treasury setups usually keep them apart, so that a hot fee
+/// payer doesn't have to hold the lamports for rent.
+#[derive(Clone, Copy)]
+pub struct Payers<'a> {
+    /// Signer that pays the transaction fee
+    pub fee_payer: &'a dyn Signer,
+    /// Signer that pays the rent of created accounts
+    pub rent_payer: &'a dyn Signer,
+}
+
+impl<'a> Payers<'a> {
+    /// Payers with a distinct fee payer and rent payer
+    pub fn new(fee_payer: &'a dyn Signer, rent_payer: &'a dyn Signer) -> Self {
+        Self {
+            fee_payer,
+            rent_payer,
+        }
+    }
+
+    /// Payers where a single signer pays both the transaction fee and the rent
+    pub fn single(payer: &'a dyn Signer) -> Self {
+        Self::new(payer, payer)
+    }
+}
+
+/// RPC client for the escrow of a RNDR token mint
+pub struct RndrClient {
+    rpc_client: RpcClient,
+    program_id: Pubkey,
+    token_mint: Pubkey,
+    escrow_id: u64,
+    priority_fee: PriorityFee,
+}
+
+impl RndrClient {
+    /// Create a client for the escrow of a token mint using the deployed RNDR program
+    pub fn new(rpc_client: RpcClient, token_mint: Pubkey) -> Self {
+        Self {
+            rpc_client,
+            program_id: crate::id(),
+            token_mint,
+            escrow_id: 0,
+            priority_fee: PriorityFee::None,
+        }
+    }
+
+    /// Use a RNDR program deployed at another address
+    pub fn with_program_id(mut self, program_id: Pubkey) -> Self {
+        self.program_id = program_id;
+        self
+    }
+
+    /// Use the escrow of the token mint in a namespace, created with `InitEscrowWithSeed`,
+    /// instead of its default escrow
+    pub fn with_escrow_id(mut self, escrow_id: u64) -> Self {
+        self.escrow_id = escrow_id;
+        self
+    }
+
+    /// Pay a priority fee in the transactions the client builds, such as
+    /// `PriorityFee::Estimated` so that funding and disbursements land during congestion
+    pub fn with_priority_fee(mut self, priority_fee: PriorityFee) -> Self {
+        self.priority_fee = priority_fee;
+        self
+    }
+
+    /// RPC client used to send requests
+    pub fn rpc_client(&self) -> &RpcClient {
+        &self.rpc_client
+    }
+
+    /// Address of the RNDR program
+    pub fn program_id(&self) -> Pubkey {
+        self.program_id
+    }
+
+    /// Priority fee paid by the transactions the client builds
+    pub fn priority_fee(&self) -> PriorityFee {
+        self.priority_fee
+    }
+
+    /// Address of the RNDR token mint
+    pub fn token_mint(&self) -> Pubkey {
+        self.token_mint
+    }
+
+    /// Namespace of the escrow, zero for the default escrow of the token mint
+    pub fn escrow_id(&self) -> u64 {
+        self.escrow_id
+    }
+
+    /// Seeds of the escrow PDA, which the instruction builders take in place of the token mint
+    pub fn escrow_seeds(&self) -> EscrowSeeds {
+        EscrowSeeds {
+            token_mint: self.token_mint,
+            escrow_id: self.escrow_id,
+        }
+    }
+
+    /// Address of the escrow PDA for the token mint
+    pub fn escrow_address(&self) -> Pubkey {
+        find_escrow_address_with_id(&self.program_id, &self.token_mint, self.escrow_id).0
+    }
+
+    /// Address of the job PDA for an authority and job identifier
+    pub fn job_address(&self, authority: &Pubkey, job_id: u64) -> Pubkey {
+        find_job_address(&self.program_id, &self.escrow_address(), authority, job_id).0
+    }
+
+    /// Fetch the escrow
+    pub async fn get_escrow(&self) -> RndrClientResult<Escrow> {
+        let data = self
+            .rpc_client
+            .get_account_data(&self.escrow_address())
+            .await?;
+        Ok(Escrow::unpack_account(&data)?)
+    }
+
+    /// Address of the config PDA of the program
+    pub fn config_address(&self) -> Pubkey {
+        find_config_address(&self.program_id).0
+    }
+
+    /// Fetch the config of the program
+    pub async fn get_config(&self) -> RndrClientResult<Config> {
+        let data = self
+            .rpc_client
+            .get_account_data(&self.config_address())
+            .await?;
+        Ok(Config::unpack_account(&data)?)
+    }
+
+    /// Fetch a job by its authority and job identifier
+    pub async fn get_job(&self, authority: &Pubkey, job_id: u64) -> RndrClientResult<Job> {
+        let data = self
+            .rpc_client
+            .get_account_data(&self.job_address(authority, job_id))
+            .await?;
+        Ok(Job::unpack_account(&data)?)
+    }
+
+    /// Handle to a job of an authority in the escrow, see `sdk::JobHandle`
+    pub fn job(&self, authority: Pubkey, job_id: u64) -> JobHandle<'_> {
+        JobHandle::new(self, authority, job_id)
+    }
+
+    /// Fetch all jobs of an authority in the escrow, with their addresses
+    pub async fn list_jobs_by_authority(
+        &self,
+        authority: &Pubkey,
+    ) -> RndrClientResult<Vec<(Pubkey, Job)>> {
+        self.list_jobs(filters::jobs_by_authority_filters(authority))
+            .await
+    }
+
+    /// Fetch all jobs with a status in the escrow, with their addresses
+    ///
+    /// Open jobs that still have the legacy length aren't included, see
+    /// `filters::jobs_by_status_filters`.
+    pub async fn list_jobs_by_status(
+        &self,
+        status: JobStatus,
+    ) -> RndrClientResult<Vec<(Pubkey, Job)>> {
+        self.list_jobs(filters::jobs_by_status_filters(status))
+            .await
+    }
+
+    /// Fetch all jobs a node has committed to a result for in the escrow, with their addresses
+    pub async fn list_jobs_by_node(&self, node: &Pubkey) -> RndrClientResult<Vec<(Pubkey, Job)>> {
+        self.list_jobs(filters::jobs_by_node_filters(node)).await
+    }
+
+    /// Fetch all jobs with exactly a set of tags in the escrow, with their addresses
+    ///
+    /// Jobs that still have a length from before the tags were appended aren't included, see
+    /// `filters::jobs_by_tags_filters`.
+    pub async fn list_jobs_by_tags(&self, tags: u64) -> RndrClientResult<Vec<(Pubkey, Job)>> {
+        self.list_jobs(filters::jobs_by_tags_filters(tags)).await
+    }
+
+    /// Fetch all open jobs in the escrow that a node with the capabilities of a set of tags can
+    /// run, with their addresses
+    pub async fn list_open_jobs_for_capabilities(
+        &self,
+        capabilities: u64,
+    ) -> RndrClientResult<Vec<(Pubkey, Job)>> {
+        let mut jobs = self.list_jobs_by_status(JobStatus::Open).await?;
+        jobs.retain(|(_pubkey, job)| job.is_suitable_for(capabilities));
+        Ok(jobs)
+    }
+
+    /// Address of the job index PDA of an authority
+    pub fn job_index_address(&self, authority: &Pubkey) -> Pubkey {
+        find_job_index_address(&self.program_id, &self.escrow_address(), authority).0
+    }
+
+    /// Fetch the job index of an authority
+    pub async fn get_job_index(&self, authority: &Pubkey) -> RndrClientResult<JobIndex> {
+        let data = self
+            .rpc_client
+            .get_account_data(&self.job_index_address(authority))
+            .await?;
+        Ok(JobIndex::unpack(&data)?)
+    }
+
+    /// Fetch the jobs recorded in the job index of an authority, in the order they were created,
+    /// with their addresses
+    ///
+    /// Unlike `list_jobs_by_authority`, only the index, its pages and the jobs are read, so RPC
+    /// nodes that don't serve `getProgramAccounts` can be used.
+    pub async fn list_indexed_jobs(
+        &self,
+        authority: &Pubkey,
+    ) -> RndrClientResult<Vec<(Pubkey, Job)>> {
+        let job_index_address = self.job_index_address(authority);
+        let job_index = self.get_job_index(authority).await?;
+
+        let page_addresses: Vec<_> = (0..job_index.page_count())
+            .map(|page| {
+                find_job_index_page_address(&self.program_id, &job_index_address, page as u32).0
+            })
+            .collect();
+        let mut job_addresses = Vec::with_capacity(job_index.job_count as usize);
+        for account in self.get_multiple_accounts(&page_addresses).await? {
+            let page = JobIndexPage::unpack(&account.data)?;
+            job_addresses.extend(
+                page.job_ids
+                    .iter()
+                    .map(|id| self.job_address(authority, *id)),
+            );
+        }
+
+        let accounts = self.get_multiple_accounts(&job_addresses).await?;
+        let mut jobs = Vec::with_capacity(accounts.len());
+        for (pubkey, account) in job_addresses.into_iter().zip(accounts) {
+            jobs.push((pubkey, Job::unpack_account(&account.data)?));
+        }
+        Ok(jobs)
+    }
+
+    /// Address of the job queue PDA of the escrow
+    pub fn job_queue_address(&self) -> Pubkey {
+        find_job_queue_address(&self.program_id, &self.escrow_address()).0
+    }
+
+    /// Fetch the job queue of the escrow, whose oldest job is claimed next
+    pub async fn get_job_queue(&self) -> RndrClientResult<JobQueue> {
+        let data = self
+            .rpc_client
+            .get_account_data(&self.job_queue_address())
+            .await?;
+        Ok(JobQueue::unpack(&data)?)
+    }
+
+    /// Fetch accounts that must all exist, in as many requests as the RPC limit on accounts per
+    /// request takes
+    async fn get_multiple_accounts(&self, pubkeys: &[Pubkey]) -> RndrClientResult<Vec<Account>> {
+        let mut accounts = Vec::with_capacity(pubkeys.len());
+        for chunk in pubkeys.chunks(MAX_MULTIPLE_ACCOUNTS) {
+            let chunk_accounts = self.rpc_client.get_multiple_accounts(chunk).await?;
+            for (pubkey, account) in chunk.iter().zip(chunk_accounts) {
+                accounts.push(account.ok_or(RndrClientError::AccountNotFound(*pubkey))?);
+            }
+        }
+        Ok(accounts)
+    }
+
+    /// Fetch all jobs matching filters in the escrow, with their addresses
+    async fn list_jobs(&self, filters: Vec<RpcFilterType>) -> RndrClientResult<Vec<(Pubkey, Job)>> {
+        let accounts = self
+            .get_program_accounts(filters, Job::unpack_account)
+            .await?;
+
+        let mut jobs = Vec::with_capacity(accounts.len());
+        for (pubkey, job) in accounts {
+            // Jobs don't store their escrow, so skip jobs of escrows for other token mints
+            if pubkey == self.job_address(job.address_authority(), job.id) {
+                jobs.push((pubkey, job));
+            }
+        }
+        Ok(jobs)
+    }
+
+    /// Fetch and unpack all program accounts matching filters, with their addresses
+    async fn get_program_accounts<T>(
+        &self,
+        filters: Vec<RpcFilterType>,
+        unpack: fn(&[u8]) -> Result<T, ProgramError>,
+    ) -> RndrClientResult<Vec<(Pubkey, T)>> {
+        let config = RpcProgramAccountsConfig {
+            filters: Some(filters),
+            account_config: RpcAccountInfoConfig {
+                encoding: Some(UiAccountEncoding::Base64),
+                ..RpcAccountInfoConfig::default()
+            },
+            ..RpcProgramAccountsConfig::default()
+        };
+        let accounts = self
+            .rpc_client
+            .get_program_accounts_with_config(&self.program_id, config)
+            .await?;
+
+        let mut unpacked = Vec::with_capacity(accounts.len());
+        for (pubkey, account) in accounts {
+            unpacked.push((pubkey, unpack(&account.data)?));
+        }
+        Ok(unpacked)
+    }
+
+    /// Initialize the escrow
+    pub async fn init_escrow(
+        &self,
+        funder: &dyn Signer,
+        owner: &Pubkey,
+    ) -> RndrClientResult<Signature> {
+        self.init_escrow_with_payers(Payers::single(funder), owner)
+            .await
+    }
+
+    /// Initialize the escrow, with the rent of the escrow and its token account paid by the rent
+    /// payer
+    pub async fn init_escrow_with_payers(
+        &self,
+        payers: Payers<'_>,
+        owner: &Pubkey,
+    ) -> RndrClientResult<Signature> {
+        let instruction = if self.escrow_id == 0 {
+            init_escrow(
+                self.program_id,
+                *owner,
+                self.token_mint,
+                payers.rent_payer.pubkey(),
+            )
+        } else {
+            init_escrow_with_seed(
+                self.program_id,
+                *owner,
+                self.token_mint,
+                payers.rent_payer.pubkey(),
+                self.escrow_id,
+            )
+        };
+        self.send(&[instruction], payers.fee_payer, &[payers.rent_payer])
+            .await
+    }
+
+    /// Transfer funds into the escrow and credit a job, creating it if needed
+    pub async fn fund_job(
+        &self,
+        funder: &dyn Signer,
+        source_token: &Pubkey,
+        authority: &dyn Signer,
+        job_id: u64,
+        amount: impl Into<RndrAmount>,
+    ) -> RndrClientResult<Signature> {
+        self.fund_job_with_payers(
+            Payers::single(funder),
+            source_token,
+            authority,
+            job_id,
+            amount,
+        )
+        .await
+    }
+
+    /// Transfer funds into the escrow and credit a job, creating it if needed with its rent paid
+    /// by the rent payer
+    pub async fn fund_job_with_payers(
+        &self,
+        payers: Payers<'_>,
+        source_token: &Pubkey,
+        authority: &dyn Signer,
+        job_id: u64,
+        amount: impl Into<RndrAmount>,
+    ) -> RndrClientResult<Signature> {
+        let instruction = fund_job(
+            self.program_id,
+            amount,
+            job_id,
+            self.escrow_seeds(),
+            payers.rent_payer.pubkey(),
+            *source_token,
+            authority.pubkey(),
+        );
+        self.send(
+            &[instruction],
+            payers.fee_payer,
+            &[payers.rent_payer, authority],
+        )
+        .await
+    }
+
+    /// Transfer funds from the escrow and debit a job
+    pub async fn disburse_funds(
+        &self,
+        payer: &dyn Signer,
+        owner: &dyn Signer,
+        authority: &Pubkey,
+        job_id: u64,
+        destination_token: &Pubkey,
+        amount: impl Into<RndrAmount>,
+    ) -> RndrClientResult<Signature> {
+        let instruction = disburse_funds(
+            self.program_id,
+            amount,
+            self.escrow_seeds(),
+            *destination_token,
+            self.job_address(authority, job_id),
+            owner.pubkey(),
+        );
+        self.send(&[instruction], payer, &[owner]).await
+    }
+
+    /// Check that the upgrade authority of the program is the governance authority of the config,
+    /// or that the program is immutable, by simulating a 'VerifyUpgradeAuthority' instruction
+    pub async fn verify_upgrade_authority(&self, payer: &Pubkey) -> RndrClientResult<()> {
+        let instruction = verify_upgrade_authority(self.program_id);
+        let transaction = Transaction::new_with_payer(&[instruction], Some(payer));
+        let config = RpcSimulateTransactionConfig {
+            sig_verify: false,
+            replace_recent_blockhash: true,
+            ..RpcSimulateTransactionConfig::default()
+        };
+        let result = self
+            .rpc_client
+            .simulate_transaction_with_config(&transaction, config)
+            .await?
+            .value;
+        match result.err {
+            Some(error) => Err(RndrClientError::Simulation(error)),
+            None => Ok(()),
+        }
+    }
+
+    /// Get the version of the program deployed at the program id, by simulating a 'GetVersion'
+    /// instruction
+    pub async fn get_version(&self, payer: &Pubkey) -> RndrClientResult<ProgramVersion> {
+        let data = self
+            .simulate_return_data(get_version(self.program_id), payer)
+            .await?;
+        Ok(ProgramVersion::unpack(&data)?)
+    }
+
+    /// Get the summary of the escrow, by simulating a 'GetEscrowSummary' instruction
+    pub async fn get_escrow_summary(&self, payer: &Pubkey) -> RndrClientResult<EscrowSummary> {
+        let instruction = get_escrow_summary(self.program_id, self.escrow_seeds());
+        let data = self.simulate_return_data(instruction, payer).await?;
+        Ok(EscrowSummary::unpack(&data)?)
+    }
+
+    /// Get the summary of a job, by simulating a 'GetJobSummary' instruction
+    pub async fn get_job_summary(
+        &self,
+        authority: &Pubkey,
+        job_id: u64,
+        payer: &Pubkey,
+    ) -> RndrClientResult<JobSummary> {
+        let instruction = get_job_summary(self.program_id, self.escrow_seeds(), *authority, job_id);
+        let data = self.simulate_return_data(instruction, payer).await?;
+        Ok(JobSummary::unpack(&data)?)
+    }
+
+    /// Get the amount of tokens in escrow for a job, by simulating a 'GetEscrowedAmount'
+    /// instruction
+    pub async fn get_escrowed_amount(
+        &self,
+        authority: &Pubkey,
+        job_id: u64,
+        payer: &Pubkey,
+    ) -> RndrClientResult<u64> {
+        let instruction =
+            get_escrowed_amount(self.program_id, self.escrow_seeds(), *authority, job_id);
+        let data = self.simulate_return_data(instruction, payer).await?;
+        let amount = data
+            .as_slice()
+            .try_into()
+            .map_err(|_| ProgramError::from(RNDRError::InstructionUnpackError))?;
+        Ok(u64::from_le_bytes(amount))
+    }
+
+    /// Simulate an instruction that sets return data, returning the data
+    async fn simulate_return_data(
+        &self,
+        instruction: Instruction,
+        payer: &Pubkey,
+    ) -> RndrClientResult<Vec<u8>> {
+        let transaction = Transaction::new_with_payer(&[instruction], Some(payer));
+        let config = RpcSimulateTransactionConfig {
+            sig_verify: false,
+            replace_recent_blockhash: true,
+            ..RpcSimulateTransactionConfig::default()
+        };
+        let result = self
+            .rpc_client
+            .simulate_transaction_with_config(&transaction, config)
+            .await?
+            .value;
+        if let Some(error) = result.err {
+            return Err(RndrClientError::Simulation(error));
+        }
+        let return_data = result
+            .return_data
+            .filter(|return_data| return_data.program_id == self.program_id.to_string())
+            .ok_or(RndrClientError::ReturnDataNotFound(self.program_id))?;
+        let (data, _encoding) = return_data.data;
+        Ok(STANDARD.decode(data)?)
+    }
+
+    /// Start building a transaction with compute budget instructions
+    pub fn transaction(&self) -> TransactionBuilder<'_> {
+        TransactionBuilder::new(self)
+    }
+
+    /// Build a transaction with compute budget instructions, then send it and wait for
+    /// confirmation
+    pub async fn send(
+        &self,
+        instructions: &[Instruction],
+        payer: &dyn Signer,
+        signers: &[&dyn Signer],
+    ) -> RndrClientResult<Signature> {
+        self.transaction()
+            .instructions(instructions.iter().cloned())
+            .send(payer, signers)
+            .await
+    }
+}
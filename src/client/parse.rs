@@ -0,0 +1,204 @@
+//! Parsed representations of RNDR instructions for `jsonParsed` RPC pipelines and explorers
+//!
+//! Like the parsers of `solana-transaction-status` for the SPL Token program, an instruction is
+//! parsed into its type and an `info` object of its args and named accounts. The account names
+//! are those of the checked-in IDL, see `idl/rndr.json`.
+
+use {
+    crate::{
+        instruction::RNDRInstruction, pda::find_escrow_shard_address, state::ESCROW_SHARD_COUNT,
+    },
+    serde::{Deserialize, Serialize},
+    serde_json::{Map, Value},
+    solana_program::{instruction::CompiledInstruction, message::AccountKeys, pubkey::Pubkey},
+    std::{str::FromStr, sync::OnceLock},
+    thiserror::Error,
+};
+
+const IDL: &str = include_str!("../../idl/rndr.json");
+
+/// Errors that may be returned when parsing an instruction
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+pub enum ParseInstructionError {
+    /// The instruction data isn't a RNDR instruction
+    #[error("instruction data is not a RNDR instruction")]
+    InstructionNotParsable,
+    /// The instruction refers to an account that isn't in the account keys
+    #[error("instruction account index {0} is not in the account keys")]
+    InstructionKeyMismatch(u8),
+}
+
+/// Parsed instruction, in the shape of `solana-transaction-status`'s `ParsedInstructionEnum`
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ParsedInstruction {
+    /// Name of the instruction in camel case, such as `fundJob`
+    #[serde(rename = "type")]
+    pub instruction_type: String,
+    /// Args of the instruction and its accounts by name, all keyed in camel case
+    pub info: Value,
+}
+
+/// Instruction in the IDL
+struct IdlInstruction {
+    name: String,
+    accounts: Vec<IdlAccount>,
+}
+
+/// Account of an instruction in the IDL
+struct IdlAccount {
+    name: String,
+    is_remaining: bool,
+}
+
+/// Parse a compiled RNDR instruction, naming its accounts from the account keys of its message
+///
+/// Optional accounts the instruction was compiled without are left out of the info, and any
+/// remaining accounts are listed under the name of the account that stands for them.
+pub fn parse(
+    instruction: &CompiledInstruction,
+    account_keys: &AccountKeys,
+) -> Result<ParsedInstruction, ParseInstructionError> {
+    let rndr_instruction = RNDRInstruction::unpack(&instruction.data)
+        .map_err(|_| ParseInstructionError::InstructionNotParsable)?;
+    let (name, mut info) = match serde_json::to_value(&rndr_instruction) {
+        Ok(Value::String(name)) => (name, Map::new()),
+        Ok(Value::Object(object)) => match object.into_iter().next() {
+            Some((name, Value::Object(args))) => (name, camel_case_keys(args)),
+            _ => return Err(ParseInstructionError::InstructionNotParsable),
+        },
+        _ => return Err(ParseInstructionError::InstructionNotParsable),
+    };
+
+    let keys = instruction
+        .accounts
+        .iter()
+        .map(|index| {
+            account_keys
+                .get(*index as usize)
+                .map(|key| Value::String(key.to_string()))
+                .ok_or(ParseInstructionError::InstructionKeyMismatch(*index))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let accounts = &idl_instructions()
+        .get(instruction.data[0] as usize)
+        .ok_or(ParseInstructionError::InstructionNotParsable)?
+        .accounts;
+    for (index, account) in accounts.iter().enumerate() {
+        if account.is_remaining {
+            let mut remaining = keys.get(index..).unwrap_or_default().to_vec();
+            // EscrowShard accounts appended after the remaining accounts aren't among them
+            let escrow_shards = escrow_shard_keys(&info);
+            let len = remaining.len()
+                - remaining
+                    .iter()
+                    .rev()
+                    .take_while(|key| escrow_shards.contains(key))
+                    .count();
+            let appended = remaining.split_off(len);
+            info.insert(account.name.clone(), Value::Array(remaining));
+            if !appended.is_empty() {
+                info.insert("escrowShardAccounts".to_string(), Value::Array(appended));
+            }
+        } else if let Some(key) = keys.get(index) {
+            info.insert(account.name.clone(), key.clone());
+        }
+    }
+
+    Ok(ParsedInstruction {
+        instruction_type: name[..1].to_lowercase() + &name[1..],
+        info: Value::Object(info),
+    })
+}
+
+/// Names of the accounts of an instruction in the IDL, by the name of its type such as `fundJob`
+pub fn account_names(instruction_type: &str) -> Option<Vec<&'static str>> {
+    idl_instructions()
+        .iter()
+        .find(|instruction| instruction.name == instruction_type)
+        .map(|instruction| {
+            instruction
+                .accounts
+                .iter()
+                .map(|account| account.name.as_str())
+                .collect()
+        })
+}
+
+/// Keys of the EscrowShard PDA accounts of the Escrow account named in the info, if any
+fn escrow_shard_keys(info: &Map<String, Value>) -> Vec<Value> {
+    let escrow = match info
+        .get("escrowAccount")
+        .and_then(Value::as_str)
+        .and_then(|escrow| Pubkey::from_str(escrow).ok())
+    {
+        Some(escrow) => escrow,
+        None => return vec![],
+    };
+    (0..ESCROW_SHARD_COUNT)
+        .map(|index| {
+            let (escrow_shard, _bump_seed) =
+                find_escrow_shard_address(&crate::id(), &escrow, index);
+            Value::String(escrow_shard.to_string())
+        })
+        .collect()
+}
+
+/// Instructions in the IDL, in tag order
+fn idl_instructions() -> &'static [IdlInstruction] {
+    static INSTRUCTIONS: OnceLock<Vec<IdlInstruction>> = OnceLock::new();
+    INSTRUCTIONS.get_or_init(|| {
+        let idl: Value = serde_json::from_str(IDL).expect("IDL is valid JSON");
+        idl["instructions"]
+            .as_array()
+            .expect("IDL has instructions")
+            .iter()
+            .map(|instruction| IdlInstruction {
+                name: instruction["name"].as_str().unwrap_or_default().to_string(),
+                accounts: instruction["accounts"]
+                    .as_array()
+                    .expect("IDL instructions have accounts")
+                    .iter()
+                    .map(|account| IdlAccount {
+                        name: account["name"].as_str().unwrap_or_default().to_string(),
+                        is_remaining: account["isRemaining"].as_bool().unwrap_or_default(),
+                    })
+                    .collect(),
+            })
+            .collect()
+    })
+}
+
+/// Rename the snake case keys of an object and the objects nested in it to camel case, which
+/// leaves the Pascal case variant names of enums as they are
+fn camel_case_keys(object: Map<String, Value>) -> Map<String, Value> {
+    object
+        .into_iter()
+        .map(|(key, value)| (camel_case(&key), camel_case_value(value)))
+        .collect()
+}
+
+fn camel_case_value(value: Value) -> Value {
+    match value {
+        Value::Object(object) => Value::Object(camel_case_keys(object)),
+        Value::Array(values) => Value::Array(values.into_iter().map(camel_case_value).collect()),
+        value => value,
+    }
+}
+
+/// Camel case of a snake case name
+fn camel_case(name: &str) -> String {
+    let mut out = String::with_capacity(name.len());
+    let mut upper = false;
+    for c in name.chars() {
+        if c == '_' {
+            upper = true;
+        } else if upper {
+            out.extend(c.to_uppercase());
+            upper = false;
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
@@ -0,0 +1,199 @@
+//! Simulation of a transaction before it's signed, reporting why it would fail
+//!
+//! The program logs the cause of a failure just before returning its error, such as "Job is
+//! disputed, so its funds can't be disbursed" with `JobDisputed`. The preflight reports that
+//! message as the cause, along with the decoded error and the decoded return data of a successful
+//! simulation, so wallets and services can explain a failure before asking the user to sign. The
+//! common failures have errors of their own, such as `JobAlreadyAssigned`, `JobFrozen` and
+//! `IncidentModeActive`, which can be matched on and are described even if the logs were
+//! truncated, while the rest return `UnspecifiedError`.
+
+use {
+    super::{
+        logs::{decode_failure, describe_error},
+        transaction::{ComputeUnitLimit, TransactionBuilder, MAX_COMPUTE_UNIT_LIMIT},
+        RndrClientResult,
+    },
+    crate::{
+        error::RNDRError,
+        instruction::RNDRInstruction,
+        summary::{EscrowSummary, JobSummary},
+        version::ProgramVersion,
+    },
+    base64::{engine::general_purpose::STANDARD, Engine},
+    num_traits::FromPrimitive,
+    solana_client::{
+        rpc_config::RpcSimulateTransactionConfig, rpc_response::RpcSimulateTransactionResult,
+    },
+    solana_program::{
+        instruction::{Instruction, InstructionError},
+        pubkey::Pubkey,
+    },
+    solana_sdk::transaction::{Transaction, TransactionError},
+    std::convert::TryInto,
+};
+
+/// Return data set by the program, decoded by the instruction that set it
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ReturnData {
+    /// Version set by 'GetVersion'
+    Version(ProgramVersion),
+    /// Summary set by 'GetEscrowSummary'
+    EscrowSummary(EscrowSummary),
+    /// Summary set by 'GetJobSummary'
+    JobSummary(JobSummary),
+    /// Amount of tokens set by 'GetEscrowedAmount'
+    EscrowedAmount(u64),
+    /// Data that couldn't be decoded as the return data of the instruction that set it
+    Unknown(Vec<u8>),
+}
+
+impl ReturnData {
+    /// Decode the return data set by an instruction of the program
+    pub fn decode(instruction: Option<&RNDRInstruction>, data: Vec<u8>) -> Self {
+        let decoded = match instruction {
+            Some(RNDRInstruction::GetVersion) => ProgramVersion::unpack(&data).map(Self::Version),
+            Some(RNDRInstruction::GetEscrowSummary) => {
+                EscrowSummary::unpack(&data).map(Self::EscrowSummary)
+            }
+            Some(RNDRInstruction::GetJobSummary) => JobSummary::unpack(&data).map(Self::JobSummary),
+            Some(RNDRInstruction::GetEscrowedAmount) => match data.as_slice().try_into() {
+                Ok(amount) => Ok(Self::EscrowedAmount(u64::from_le_bytes(amount))),
+                Err(_) => Err(RNDRError::InstructionUnpackError.into()),
+            },
+            _ => Err(RNDRError::InstructionUnpackError.into()),
+        };
+        decoded.unwrap_or(Self::Unknown(data))
+    }
+}
+
+/// Why a simulated transaction failed
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PreflightFailure {
+    /// Error the transaction failed with
+    pub error: TransactionError,
+    /// Index of the failed instruction in the transaction, including the compute budget
+    /// instructions the builder prepends
+    pub instruction_index: Option<u8>,
+    /// Error returned by the program, if the failed instruction is one of its own
+    pub program_error: Option<RNDRError>,
+    /// Human-readable cause, the last message the program logged before failing when there is one
+    pub cause: String,
+}
+
+/// Outcome of simulating a transaction
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Preflight {
+    /// Why the transaction would fail, or `None` if it would succeed
+    pub failure: Option<PreflightFailure>,
+    /// Return data set by the program
+    pub return_data: Option<ReturnData>,
+    /// Compute units the transaction consumed
+    pub units_consumed: Option<u64>,
+    /// Log messages of the transaction
+    pub logs: Vec<String>,
+}
+
+impl Preflight {
+    /// Interpret the result of simulating instructions
+    pub fn new(
+        program_id: &Pubkey,
+        instructions: &[Instruction],
+        result: RpcSimulateTransactionResult,
+    ) -> Self {
+        let logs = result.logs.unwrap_or_default();
+        let failure = result
+            .err
+            .map(|error| failure(program_id, instructions, &logs, error));
+        let return_data = result
+            .return_data
+            .filter(|return_data| return_data.program_id == program_id.to_string())
+            .and_then(|return_data| STANDARD.decode(return_data.data.0).ok())
+            .map(|data| {
+                // Return data is set by the last instruction of the program that sets any
+                let instruction = instructions
+                    .iter()
+                    .rev()
+                    .filter(|instruction| &instruction.program_id == program_id)
+                    .find_map(|instruction| RNDRInstruction::unpack(&instruction.data).ok());
+                ReturnData::decode(instruction.as_ref(), data)
+            });
+        Self {
+            failure,
+            return_data,
+            units_consumed: result.units_consumed,
+            logs,
+        }
+    }
+
+    /// Whether the transaction would succeed
+    pub fn is_ok(&self) -> bool {
+        self.failure.is_none()
+    }
+}
+
+fn failure(
+    program_id: &Pubkey,
+    instructions: &[Instruction],
+    logs: &[String],
+    error: TransactionError,
+) -> PreflightFailure {
+    let (instruction_index, program_error) = match &error {
+        TransactionError::InstructionError(index, instruction_error) => {
+            let is_program = instructions
+                .get(*index as usize)
+                .is_some_and(|instruction| &instruction.program_id == program_id);
+            let program_error = match instruction_error {
+                InstructionError::Custom(code) if is_program => RNDRError::from_u32(*code),
+                _ => None,
+            };
+            (Some(*index), program_error)
+        }
+        _ => (None, None),
+    };
+    let cause = decode_failure(program_id, &error, logs)
+        .and_then(|failed| failed.message)
+        .unwrap_or_else(|| match &program_error {
+            Some(program_error) => describe_error(program_error),
+            None => error.to_string(),
+        });
+    PreflightFailure {
+        error,
+        instruction_index,
+        program_error,
+        cause,
+    }
+}
+
+impl<'a> TransactionBuilder<'a> {
+    /// Simulate the transaction without signing it, reporting why it would fail and the return
+    /// data it would set
+    ///
+    /// The transaction is simulated with the maximum compute unit limit, so a failure is reported
+    /// rather than returned as an error while simulating the limit, and the units consumed can be
+    /// used to set a fixed one.
+    pub async fn preflight(&self, payer: &Pubkey) -> RndrClientResult<Preflight> {
+        let instructions = self
+            .clone()
+            .compute_unit_limit(ComputeUnitLimit::Fixed(MAX_COMPUTE_UNIT_LIMIT))
+            .build_instructions(payer)
+            .await?;
+        let transaction = Transaction::new_with_payer(&instructions, Some(payer));
+        let config = RpcSimulateTransactionConfig {
+            sig_verify: false,
+            replace_recent_blockhash: true,
+            ..RpcSimulateTransactionConfig::default()
+        };
+        let result = self
+            .client()
+            .rpc_client()
+            .simulate_transaction_with_config(&transaction, config)
+            .await?
+            .value;
+        Ok(Preflight::new(
+            &self.client().program_id(),
+            &instructions,
+            result,
+        ))
+    }
+}
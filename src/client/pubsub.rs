@@ -0,0 +1,183 @@
+//! Account subscriptions over the RPC pubsub websocket, for coordinators and dashboards that react
+//! to escrow, job and node changes as they land
+//!
+//! Updates are unpacked into the program's state types and yielded as a stream, which borrows the
+//! pubsub client it was subscribed with. Updates that can't be unpacked are yielded as errors
+//! rather than ending the stream, and the stream ends when the subscription is unsubscribed or
+//! the websocket closes.
+
+use {
+    super::{filters, RndrClient, RndrClientResult},
+    crate::{
+        pda::{find_job_address, find_node_address},
+        state::{Escrow, Job, JobStatus, Node},
+    },
+    futures_util::{
+        future::BoxFuture,
+        stream::{BoxStream, StreamExt},
+    },
+    solana_account_decoder::{UiAccount, UiAccountEncoding},
+    solana_client::{
+        nonblocking::pubsub_client::PubsubClient,
+        rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+        rpc_filter::RpcFilterType,
+        rpc_response::{Response, RpcKeyedAccount},
+    },
+    solana_program::{clock::Slot, program_error::ProgramError, pubkey::Pubkey},
+    solana_sdk::account::Account,
+    std::str::FromStr,
+};
+
+/// Update to an account, with the slot it was observed at
+#[derive(Clone, Debug, PartialEq)]
+pub struct AccountUpdate<T> {
+    /// Address of the account
+    pub pubkey: Pubkey,
+    /// Slot the update was observed at
+    pub slot: Slot,
+    /// Unpacked account
+    pub account: T,
+}
+
+/// Stream of updates to accounts of a type, with an error for each update that can't be unpacked
+pub type AccountUpdateStream<'a, T> = BoxStream<'a, Result<AccountUpdate<T>, ProgramError>>;
+
+/// Ends a subscription when called
+pub type Unsubscribe = Box<dyn FnOnce() -> BoxFuture<'static, ()> + Send>;
+
+impl RndrClient {
+    /// Subscribe to changes of the escrow
+    pub async fn subscribe_escrow<'a>(
+        &self,
+        pubsub_client: &'a PubsubClient,
+    ) -> RndrClientResult<(AccountUpdateStream<'a, Escrow>, Unsubscribe)> {
+        subscribe_account(pubsub_client, self.escrow_address(), Escrow::unpack_account).await
+    }
+
+    /// Subscribe to changes of a job by its authority and job identifier
+    pub async fn subscribe_job<'a>(
+        &self,
+        pubsub_client: &'a PubsubClient,
+        authority: &Pubkey,
+        job_id: u64,
+    ) -> RndrClientResult<(AccountUpdateStream<'a, Job>, Unsubscribe)> {
+        let job_address = self.job_address(authority, job_id);
+        subscribe_account(pubsub_client, job_address, Job::unpack_account).await
+    }
+
+    /// Subscribe to changes of the node account of a node authority in the escrow
+    pub async fn subscribe_node<'a>(
+        &self,
+        pubsub_client: &'a PubsubClient,
+        authority: &Pubkey,
+    ) -> RndrClientResult<(AccountUpdateStream<'a, Node>, Unsubscribe)> {
+        let (node_address, _bump_seed) =
+            find_node_address(&self.program_id, &self.escrow_address(), authority);
+        subscribe_account(pubsub_client, node_address, Node::unpack_account).await
+    }
+
+    /// Subscribe to changes of the jobs with a status in the escrow, such as the open jobs a
+    /// coordinator assigns to nodes
+    ///
+    /// Open jobs that still have the legacy length aren't included, see
+    /// `filters::jobs_by_status_filters`.
+    pub async fn subscribe_jobs_by_status<'a>(
+        &self,
+        pubsub_client: &'a PubsubClient,
+        status: JobStatus,
+    ) -> RndrClientResult<(AccountUpdateStream<'a, Job>, Unsubscribe)> {
+        self.subscribe_jobs(pubsub_client, filters::jobs_by_status_filters(status))
+            .await
+    }
+
+    /// Subscribe to changes of the jobs of an authority in the escrow
+    pub async fn subscribe_jobs_by_authority<'a>(
+        &self,
+        pubsub_client: &'a PubsubClient,
+        authority: &Pubkey,
+    ) -> RndrClientResult<(AccountUpdateStream<'a, Job>, Unsubscribe)> {
+        self.subscribe_jobs(pubsub_client, filters::jobs_by_authority_filters(authority))
+            .await
+    }
+
+    /// Subscribe to changes of the jobs matching filters in the escrow
+    async fn subscribe_jobs<'a>(
+        &self,
+        pubsub_client: &'a PubsubClient,
+        filters: Vec<RpcFilterType>,
+    ) -> RndrClientResult<(AccountUpdateStream<'a, Job>, Unsubscribe)> {
+        let config = RpcProgramAccountsConfig {
+            filters: Some(filters),
+            account_config: account_config(),
+            ..RpcProgramAccountsConfig::default()
+        };
+        let (stream, unsubscribe) = pubsub_client
+            .program_subscribe(&self.program_id, Some(config))
+            .await?;
+
+        // Jobs don't store their escrow, so skip jobs of escrows for other token mints
+        let program_id = self.program_id;
+        let escrow_address = self.escrow_address();
+        let stream = stream
+            .map(move |response| {
+                let update = decode_keyed_account(response)?;
+                let (job_address, _bump_seed) = find_job_address(
+                    &program_id,
+                    &escrow_address,
+                    update.account.address_authority(),
+                    update.account.id,
+                );
+                Ok((job_address == update.pubkey).then_some(update))
+            })
+            .filter_map(|update| async move { update.transpose() })
+            .boxed();
+        Ok((stream, unsubscribe))
+    }
+}
+
+/// Subscribe to changes of an account, unpacking each update
+pub async fn subscribe_account<'a, T: 'a>(
+    pubsub_client: &'a PubsubClient,
+    pubkey: Pubkey,
+    unpack: fn(&[u8]) -> Result<T, ProgramError>,
+) -> RndrClientResult<(AccountUpdateStream<'a, T>, Unsubscribe)> {
+    let (stream, unsubscribe) = pubsub_client
+        .account_subscribe(&pubkey, Some(account_config()))
+        .await?;
+    let stream = stream
+        .map(move |response| {
+            let account = decode_account(&response.value)?;
+            Ok(AccountUpdate {
+                pubkey,
+                slot: response.context.slot,
+                account: unpack(&account.data)?,
+            })
+        })
+        .boxed();
+    Ok((stream, unsubscribe))
+}
+
+/// Unpack an update of a job from a program subscription
+fn decode_keyed_account(
+    response: Response<RpcKeyedAccount>,
+) -> Result<AccountUpdate<Job>, ProgramError> {
+    let pubkey =
+        Pubkey::from_str(&response.value.pubkey).map_err(|_| ProgramError::InvalidAccountData)?;
+    let account = decode_account(&response.value.account)?;
+    Ok(AccountUpdate {
+        pubkey,
+        slot: response.context.slot,
+        account: Job::unpack_account(&account.data)?,
+    })
+}
+
+fn decode_account(account: &UiAccount) -> Result<Account, ProgramError> {
+    account.decode().ok_or(ProgramError::InvalidAccountData)
+}
+
+fn account_config() -> RpcAccountInfoConfig {
+    RpcAccountInfoConfig {
+        encoding: Some(UiAccountEncoding::Base64),
+        ..RpcAccountInfoConfig::default()
+    }
+}
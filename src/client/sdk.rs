@@ -0,0 +1,134 @@
+//! Typed handles over the client for services that integrate render payments
+//!
+//! The handles derive the PDAs, order the accounts and pack the instructions, so a backend only
+//! deals in job identifiers, signers and token accounts.
+
+use {
+    super::{Payers, RndrClient, RndrClientResult},
+    crate::{
+        amount::RndrAmount,
+        instruction::{cancel_job, fund_job},
+        state::{Escrow, Job, JobStatus},
+    },
+    solana_program::pubkey::Pubkey,
+    solana_sdk::{signature::Signature, signer::Signer},
+};
+
+impl Escrow {
+    /// Fetch the escrow of a client
+    pub async fn fetch(client: &RndrClient) -> RndrClientResult<Self> {
+        client.get_escrow().await
+    }
+}
+
+impl Job {
+    /// Fetch a job of the escrow of a client by its authority and job identifier
+    pub async fn fetch(
+        client: &RndrClient,
+        authority: &Pubkey,
+        job_id: u64,
+    ) -> RndrClientResult<Self> {
+        client.get_job(authority, job_id).await
+    }
+}
+
+/// Handle to a job of an authority in the escrow of a client, which may not have been created yet
+#[derive(Clone, Copy)]
+pub struct JobHandle<'a> {
+    client: &'a RndrClient,
+    authority: Pubkey,
+    job_id: u64,
+}
+
+impl<'a> JobHandle<'a> {
+    /// Create a handle to a job by its authority and job identifier
+    pub fn new(client: &'a RndrClient, authority: Pubkey, job_id: u64) -> Self {
+        Self {
+            client,
+            authority,
+            job_id,
+        }
+    }
+
+    /// Authority of the job
+    pub fn authority(&self) -> Pubkey {
+        self.authority
+    }
+
+    /// Identifier of the job
+    pub fn job_id(&self) -> u64 {
+        self.job_id
+    }
+
+    /// Address of the job PDA
+    pub fn address(&self) -> Pubkey {
+        self.client.job_address(&self.authority, self.job_id)
+    }
+
+    /// Fetch the job
+    pub async fn fetch(&self) -> RndrClientResult<Job> {
+        Job::fetch(self.client, &self.authority, self.job_id).await
+    }
+
+    /// Fetch the status of the job
+    pub async fn status(&self) -> RndrClientResult<JobStatus> {
+        Ok(self.fetch().await?.status)
+    }
+
+    /// Transfer funds from a token account of the authority into the escrow and credit the job,
+    /// creating it if needed
+    pub async fn top_up(
+        &self,
+        payer: &dyn Signer,
+        authority: &dyn Signer,
+        source_token: &Pubkey,
+        amount: impl Into<RndrAmount>,
+    ) -> RndrClientResult<Signature> {
+        self.top_up_with_payers(Payers::single(payer), authority, source_token, amount)
+            .await
+    }
+
+    /// Transfer funds into the escrow and credit the job, creating it if needed with its rent paid
+    /// by the rent payer
+    pub async fn top_up_with_payers(
+        &self,
+        payers: Payers<'_>,
+        authority: &dyn Signer,
+        source_token: &Pubkey,
+        amount: impl Into<RndrAmount>,
+    ) -> RndrClientResult<Signature> {
+        let instruction = fund_job(
+            self.client.program_id(),
+            amount,
+            self.job_id,
+            self.client.escrow_seeds(),
+            payers.rent_payer.pubkey(),
+            *source_token,
+            self.authority,
+        );
+        self.client
+            .send(
+                &[instruction],
+                payers.fee_payer,
+                &[payers.rent_payer, authority],
+            )
+            .await
+    }
+
+    /// Cancel the job, refunding its funds to a token account
+    pub async fn cancel(
+        &self,
+        payer: &dyn Signer,
+        authority: &dyn Signer,
+        destination_token: &Pubkey,
+    ) -> RndrClientResult<Signature> {
+        let instruction = cancel_job(
+            self.client.program_id(),
+            self.client.escrow_seeds(),
+            self.authority,
+            self.job_id,
+            *destination_token,
+        );
+        self.client.send(&[instruction], payer, &[authority]).await
+    }
+}
@@ -0,0 +1,260 @@
+//! Sending transactions with retries until they reach a terminal status
+//!
+//! A transaction is only re-signed with a new blockhash once the previous attempt can no longer
+//! land, because its blockhash expired or its durable nonce was advanced, so an instruction that
+//! moves funds is never executed twice. Until then the same signed transaction is rebroadcast, and
+//! attempts are spaced by an exponential backoff.
+
+use {
+    super::{
+        transaction::{DurableNonce, TransactionBuilder},
+        RndrClientResult,
+    },
+    crate::error::RNDRError,
+    num_traits::FromPrimitive,
+    solana_client::{
+        nonce_utils::nonblocking as nonce_utils, rpc_config::RpcSendTransactionConfig,
+    },
+    solana_program::instruction::InstructionError,
+    solana_sdk::{
+        signature::Signature,
+        signer::Signer,
+        transaction::{uses_durable_nonce, Transaction, TransactionError},
+    },
+    std::time::Duration,
+    tokio::time::sleep,
+};
+
+/// Number of attempts made by default
+pub const DEFAULT_MAX_ATTEMPTS: u32 = 4;
+
+/// How a transaction is retried
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Number of times the transaction is signed and sent with a new blockhash, and the number of
+    /// consecutive RPC errors tolerated while its status is polled
+    pub max_attempts: u32,
+    /// Delay before the second attempt, doubled for each attempt after it
+    pub initial_backoff: Duration,
+    /// Longest delay between attempts
+    pub max_backoff: Duration,
+    /// Delay between polls of the status of an attempt, after each of which it's rebroadcast
+    pub poll_interval: Duration,
+    /// Durable nonce the transaction is sent against once every attempt with a blockhash has
+    /// expired, whose authority must be one of the signers
+    pub nonce_fallback: Option<DurableNonce>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(8),
+            poll_interval: Duration::from_secs(2),
+            nonce_fallback: None,
+        }
+    }
+}
+
+/// Terminal status of a transaction sent with retries
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SendOutcome {
+    /// The transaction landed and succeeded
+    Confirmed(Signature),
+    /// An instruction of the RNDR program failed with one of its errors
+    ProgramError {
+        /// Signature of the transaction
+        signature: Signature,
+        /// Index of the failed instruction in the transaction, including the compute budget
+        /// instructions the builder prepends
+        instruction_index: u8,
+        /// Error returned by the program
+        error: RNDRError,
+    },
+    /// The transaction failed with an error that isn't one of the RNDR program's
+    Failed {
+        /// Signature of the transaction
+        signature: Signature,
+        /// Error the transaction failed with
+        error: TransactionError,
+    },
+    /// No attempt landed before it expired
+    Dropped {
+        /// Signatures of every attempt, none of which can land anymore
+        signatures: Vec<Signature>,
+    },
+}
+
+impl SendOutcome {
+    /// Signature of the transaction, unless it was dropped
+    pub fn signature(&self) -> Option<Signature> {
+        match self {
+            Self::Confirmed(signature)
+            | Self::ProgramError { signature, .. }
+            | Self::Failed { signature, .. } => Some(*signature),
+            Self::Dropped { .. } => None,
+        }
+    }
+}
+
+impl<'a> TransactionBuilder<'a> {
+    /// Build and sign the transaction, then send it until it lands or every attempt has expired,
+    /// classifying its terminal status
+    ///
+    /// Failures in preflight simulation are terminal, with the signature of a transaction that
+    /// never landed. RPC errors are only returned when the status of an attempt can't be polled
+    /// for `max_attempts` times in a row.
+    pub async fn send_with_retry(
+        &self,
+        payer: &dyn Signer,
+        signers: &[&dyn Signer],
+        policy: &RetryPolicy,
+    ) -> RndrClientResult<SendOutcome> {
+        let mut signatures = vec![];
+        let mut backoff = policy.initial_backoff;
+        for attempt in 0..policy.max_attempts {
+            if attempt > 0 {
+                sleep(backoff).await;
+                backoff = backoff.saturating_mul(2).min(policy.max_backoff);
+            }
+            let transaction = self.build(payer, signers).await?;
+            signatures.push(transaction.signatures[0]);
+            if let Some(outcome) = self.track(&transaction, policy).await? {
+                return Ok(outcome);
+            }
+        }
+
+        if let Some(durable_nonce) = policy.nonce_fallback {
+            let transaction = self
+                .clone()
+                .durable_nonce(durable_nonce.nonce_account, durable_nonce.nonce_authority)
+                .build(payer, signers)
+                .await?;
+            signatures.push(transaction.signatures[0]);
+            if let Some(outcome) = self.track(&transaction, policy).await? {
+                return Ok(outcome);
+            }
+        }
+
+        Ok(SendOutcome::Dropped { signatures })
+    }
+
+    /// Send a transaction and rebroadcast it until it lands, returning `None` once it can't
+    async fn track(
+        &self,
+        transaction: &Transaction,
+        policy: &RetryPolicy,
+    ) -> RndrClientResult<Option<SendOutcome>> {
+        let rpc_client = self.client().rpc_client();
+
+        // Other errors don't mean the transaction didn't reach the cluster, so it's still tracked
+        if let Err(error) = rpc_client
+            .send_transaction_with_config(transaction, RpcSendTransactionConfig::default())
+            .await
+        {
+            if let Some(error) = error.get_transaction_error() {
+                return Ok(Some(self.classify(transaction, Err(error))));
+            }
+        }
+
+        let rebroadcast_config = RpcSendTransactionConfig {
+            skip_preflight: true,
+            ..RpcSendTransactionConfig::default()
+        };
+        let mut errors = 0;
+        loop {
+            let can_land = match self.poll(transaction).await {
+                Ok(Some(outcome)) => return Ok(Some(outcome)),
+                Ok(None) => self.can_land(transaction).await,
+                Err(error) => Err(error),
+            };
+            match can_land {
+                Ok(true) => errors = 0,
+                // The transaction may have landed just before it expired
+                Ok(false) => return self.poll(transaction).await,
+                Err(error) => {
+                    errors += 1;
+                    if errors >= policy.max_attempts {
+                        return Err(error);
+                    }
+                }
+            }
+
+            sleep(policy.poll_interval).await;
+            let _ = rpc_client
+                .send_transaction_with_config(transaction, rebroadcast_config)
+                .await;
+        }
+    }
+
+    /// Fetch the status of a transaction, if it has landed
+    async fn poll(&self, transaction: &Transaction) -> RndrClientResult<Option<SendOutcome>> {
+        let rpc_client = self.client().rpc_client();
+        let status = rpc_client
+            .get_signature_status_with_commitment(
+                &transaction.signatures[0],
+                rpc_client.commitment(),
+            )
+            .await?;
+        Ok(status.map(|status| self.classify(transaction, status)))
+    }
+
+    /// Whether a transaction that hasn't landed still can, which is until its blockhash expires
+    /// or its durable nonce is advanced
+    async fn can_land(&self, transaction: &Transaction) -> RndrClientResult<bool> {
+        let rpc_client = self.client().rpc_client();
+        let blockhash = transaction.message.recent_blockhash;
+        match uses_durable_nonce(transaction) {
+            Some(advance_nonce) => {
+                let nonce_account =
+                    transaction.message.account_keys[advance_nonce.accounts[0] as usize];
+                let account = nonce_utils::get_account_with_commitment(
+                    rpc_client,
+                    &nonce_account,
+                    rpc_client.commitment(),
+                )
+                .await?;
+                Ok(nonce_utils::data_from_account(&account)?.blockhash() == blockhash)
+            }
+            None => Ok(rpc_client
+                .is_blockhash_valid(&blockhash, rpc_client.commitment())
+                .await?),
+        }
+    }
+
+    fn classify(
+        &self,
+        transaction: &Transaction,
+        status: Result<(), TransactionError>,
+    ) -> SendOutcome {
+        let signature = transaction.signatures[0];
+        match status {
+            Ok(()) => SendOutcome::Confirmed(signature),
+            Err(TransactionError::InstructionError(index, InstructionError::Custom(code))) => {
+                let program_id = transaction
+                    .message
+                    .instructions
+                    .get(index as usize)
+                    .map(|instruction| instruction.program_id(&transaction.message.account_keys));
+                match RNDRError::from_u32(code) {
+                    Some(error) if program_id == Some(&self.client().program_id()) => {
+                        SendOutcome::ProgramError {
+                            signature,
+                            instruction_index: index,
+                            error,
+                        }
+                    }
+                    _ => SendOutcome::Failed {
+                        signature,
+                        error: TransactionError::InstructionError(
+                            index,
+                            InstructionError::Custom(code),
+                        ),
+                    },
+                }
+            }
+            Err(error) => SendOutcome::Failed { signature, error },
+        }
+    }
+}
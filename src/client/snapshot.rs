@@ -0,0 +1,170 @@
+//! Snapshots of all RNDR program accounts for accounting and reporting
+
+use {
+    super::{filters, RndrClient, RndrClientResult},
+    crate::state::{Escrow, EscrowShard, Job},
+    serde::{Deserialize, Serialize},
+    solana_program::{hash::Hash, program_pack::Pack, pubkey::Pubkey},
+    std::{
+        fmt,
+        io::{self, Write},
+        str::FromStr,
+    },
+};
+
+/// Header of CSV snapshots, with a column for each field of every account type
+pub const CSV_HEADER: &str = "address,account_type,amount,owner,token_mint,challenge_window,\
+    proof_verifier,arbitrator,approver,escrow,shard_index,authority,job_id,isolated,status,node,result_commitment,result_hash,\
+    revealed_at,verifier,evidence_hash";
+
+/// Program account with its address
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SnapshotAccount<T> {
+    /// Address of the account
+    #[serde(with = "crate::serde_pubkey")]
+    pub address: Pubkey,
+    /// Unpacked account state
+    #[serde(flatten)]
+    pub account: T,
+}
+
+/// Decoded program accounts at a slot
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Snapshot {
+    /// Slot the snapshot was taken at or after
+    pub slot: u64,
+    /// All escrows
+    pub escrows: Vec<SnapshotAccount<Escrow>>,
+    /// All escrow shards
+    pub escrow_shards: Vec<SnapshotAccount<EscrowShard>>,
+    /// All jobs
+    pub jobs: Vec<SnapshotAccount<Job>>,
+}
+
+/// Format a snapshot is written in
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SnapshotFormat {
+    /// A JSON object with an array for each account type
+    Json,
+    /// A CSV table with a row for each account, leaving fields of other account types empty
+    Csv,
+}
+
+impl FromStr for SnapshotFormat {
+    type Err = String;
+
+    fn from_str(format: &str) -> Result<Self, Self::Err> {
+        match format {
+            "json" => Ok(Self::Json),
+            "csv" => Ok(Self::Csv),
+            _ => Err(format!("unknown snapshot format: {}", format)),
+        }
+    }
+}
+
+impl fmt::Display for SnapshotFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Json => f.write_str("json"),
+            Self::Csv => f.write_str("csv"),
+        }
+    }
+}
+
+impl Snapshot {
+    /// Write the snapshot in a format
+    pub fn write(&self, format: SnapshotFormat, writer: impl Write) -> io::Result<()> {
+        match format {
+            SnapshotFormat::Json => self.write_json(writer),
+            SnapshotFormat::Csv => self.write_csv(writer),
+        }
+    }
+
+    /// Write the snapshot as pretty printed JSON
+    pub fn write_json(&self, mut writer: impl Write) -> io::Result<()> {
+        serde_json::to_writer_pretty(&mut writer, self)?;
+        writeln!(writer)
+    }
+
+    /// Write the snapshot as CSV, with amounts in base units and hashes in base58
+    pub fn write_csv(&self, mut writer: impl Write) -> io::Result<()> {
+        writeln!(writer, "{}", CSV_HEADER)?;
+        for SnapshotAccount { address, account } in &self.escrows {
+            writeln!(
+                writer,
+                "{},{:?},{},{},{},{},{},{},{},,,,,,,,,,,,",
+                address,
+                account.account_type,
+                account.amount,
+                account.owner,
+                account.token_mint,
+                account.challenge_window,
+                account.proof_verifier,
+                account.arbitrator,
+                account.approver
+            )?;
+        }
+        for SnapshotAccount { address, account } in &self.escrow_shards {
+            writeln!(
+                writer,
+                "{},{:?},{},,,,,,,{},{},,,,,,,,,,",
+                address, account.account_type, account.amount, account.escrow, account.index
+            )?;
+        }
+        for SnapshotAccount { address, account } in &self.jobs {
+            writeln!(
+                writer,
+                "{},{:?},{},,,,,,,,,{},{},{},{:?},{},{},{},{},{},{}",
+                address,
+                account.account_type,
+                account.amount,
+                account.authority,
+                account.id,
+                account.isolated,
+                account.status,
+                account.node,
+                Hash::new_from_array(account.result_commitment),
+                Hash::new_from_array(account.result_hash),
+                account.revealed_at,
+                account.verifier,
+                Hash::new_from_array(account.evidence_hash)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl RndrClient {
+    /// Fetch and decode every escrow, escrow shard and job of the program
+    ///
+    /// Accounts of all token mints are included. The accounts are fetched in separate requests, so
+    /// they may be observed at slots after the one recorded.
+    pub async fn snapshot(&self) -> RndrClientResult<Snapshot> {
+        let slot = self.rpc_client.get_slot().await?;
+        let escrows = self
+            .get_program_accounts(filters::escrow_filters(), Escrow::unpack_account)
+            .await?;
+        let escrow_shards = self
+            .get_program_accounts(filters::escrow_shard_filters(), EscrowShard::unpack)
+            .await?;
+        let jobs = self
+            .get_program_accounts(filters::job_filters(), Job::unpack_account)
+            .await?;
+
+        Ok(Snapshot {
+            slot,
+            escrows: snapshot_accounts(escrows),
+            escrow_shards: snapshot_accounts(escrow_shards),
+            jobs: snapshot_accounts(jobs),
+        })
+    }
+}
+
+fn snapshot_accounts<T>(accounts: Vec<(Pubkey, T)>) -> Vec<SnapshotAccount<T>> {
+    let mut accounts: Vec<_> = accounts
+        .into_iter()
+        .map(|(address, account)| SnapshotAccount { address, account })
+        .collect();
+    accounts.sort_by_key(|account| account.address);
+    accounts
+}
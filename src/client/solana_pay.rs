@@ -0,0 +1,127 @@
+//! Solana Pay transaction requests for funding jobs
+//!
+//! A wallet scans a `solana:` URL pointing at a merchant server, posts its account to the server,
+//! and receives a `FundJob` transaction to sign. The transaction includes a reference key so the
+//! merchant can find it on chain and confirm that the job was funded.
+
+use {
+    super::{RndrClient, RndrClientResult},
+    base64::{engine::general_purpose::STANDARD, Engine},
+    serde::{Deserialize, Serialize},
+    solana_client::rpc_response::RpcConfirmedTransactionStatusWithSignature,
+    solana_program::{
+        instruction::AccountMeta,
+        pubkey::{ParsePubkeyError, Pubkey},
+    },
+    solana_sdk::signer::Signer,
+    spl_associated_token_account::get_associated_token_address,
+    std::str::FromStr,
+};
+
+/// Response to the GET request a wallet makes before requesting the transaction
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransactionRequestMetadata {
+    /// Label of the merchant shown by the wallet
+    pub label: String,
+    /// URL of an SVG, PNG or WebP icon of the merchant shown by the wallet
+    pub icon: String,
+}
+
+/// Body of the POST request a wallet makes to request the transaction
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransactionRequest {
+    /// Base58 encoded account of the wallet, which pays for and signs the transaction
+    pub account: String,
+}
+
+impl TransactionRequest {
+    /// Account of the wallet
+    pub fn account(&self) -> Result<Pubkey, ParsePubkeyError> {
+        Pubkey::from_str(&self.account)
+    }
+}
+
+/// Response to the POST request a wallet makes to request the transaction
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransactionRequestResponse {
+    /// Base64 encoded serialized transaction, partially signed by the merchant
+    pub transaction: String,
+    /// Message shown by the wallet
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// Solana Pay URL for a transaction request served at an HTTPS link
+pub fn transaction_request_url(link: &str) -> String {
+    if !link.contains('?') {
+        return format!("solana:{}", link);
+    }
+
+    let mut url = String::from("solana:");
+    for byte in link.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                url.push(byte as char)
+            }
+            _ => url.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    url
+}
+
+impl RndrClient {
+    /// Build the response to a transaction request funding a job from the wallet's associated
+    /// token account
+    ///
+    /// The job authority signs the transaction here, and the wallet signs it as the fee payer and
+    /// funder. The reference is added to the `FundJob` instruction as a read-only account.
+    pub async fn fund_job_transaction_request(
+        &self,
+        account: &Pubkey,
+        authority: &dyn Signer,
+        job_id: u64,
+        amount: u64,
+        reference: &Pubkey,
+        message: Option<String>,
+    ) -> RndrClientResult<TransactionRequestResponse> {
+        let source_token = get_associated_token_address(account, &self.token_mint);
+        let mut instruction = crate::instruction::fund_job(
+            self.program_id,
+            amount,
+            job_id,
+            self.escrow_seeds(),
+            *account,
+            source_token,
+            authority.pubkey(),
+        );
+        instruction
+            .accounts
+            .push(AccountMeta::new_readonly(*reference, false));
+
+        let mut transaction = self
+            .transaction()
+            .instruction(instruction)
+            .build_unsigned(account)
+            .await?;
+        let recent_blockhash = transaction.message.recent_blockhash;
+        transaction.try_partial_sign(&[authority], recent_blockhash)?;
+
+        let transaction = bincode::serialize(&transaction)?;
+        Ok(TransactionRequestResponse {
+            transaction: STANDARD.encode(transaction),
+            message,
+        })
+    }
+
+    /// Find the first transaction that includes a reference, if it has been processed
+    pub async fn find_reference(
+        &self,
+        reference: &Pubkey,
+    ) -> RndrClientResult<Option<RpcConfirmedTransactionStatusWithSignature>> {
+        let signatures = self
+            .rpc_client
+            .get_signatures_for_address(reference)
+            .await?;
+        Ok(signatures.into_iter().last())
+    }
+}
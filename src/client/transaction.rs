@@ -0,0 +1,394 @@
+//! Transaction builder that adds compute budget instructions, uses address lookup tables and
+//! supports durable nonces
+
+use {
+    super::{RndrClient, RndrClientError, RndrClientResult},
+    solana_client::{
+        nonce_utils::nonblocking as nonce_utils, rpc_config::RpcSimulateTransactionConfig,
+        rpc_response::RpcPrioritizationFee,
+    },
+    solana_program::{
+        address_lookup_table::AddressLookupTableAccount,
+        hash::Hash,
+        instruction::Instruction,
+        message::{v0, CompileError, VersionedMessage},
+        pubkey::Pubkey,
+        system_instruction,
+    },
+    solana_sdk::{
+        compute_budget::ComputeBudgetInstruction,
+        signature::Signature,
+        signer::Signer,
+        transaction::{Transaction, VersionedTransaction},
+    },
+};
+
+/// Maximum compute units a transaction can request
+pub const MAX_COMPUTE_UNIT_LIMIT: u32 = 1_400_000;
+
+/// Percentage added to the compute units consumed in simulation by default
+pub const DEFAULT_COMPUTE_UNIT_MARGIN_PERCENT: u32 = 10;
+
+/// How the compute unit limit of a transaction is set
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ComputeUnitLimit {
+    /// Don't request a limit, so the runtime default per instruction applies
+    Default,
+    /// Request a fixed limit
+    Fixed(u32),
+    /// Simulate the transaction and request the units it consumed plus a margin
+    Simulated {
+        /// Percentage added to the units consumed
+        margin_percent: u32,
+    },
+}
+
+impl Default for ComputeUnitLimit {
+    fn default() -> Self {
+        Self::Simulated {
+            margin_percent: DEFAULT_COMPUTE_UNIT_MARGIN_PERCENT,
+        }
+    }
+}
+
+/// Percentile of recent prioritization fees paid by default when the priority fee is estimated
+pub const DEFAULT_PRIORITY_FEE_PERCENTILE: u8 = 75;
+
+/// Maximum accounts the recent prioritization fees can be sampled for in one request
+pub const MAX_PRIORITIZATION_FEE_ACCOUNTS: usize = 128;
+
+/// Priority fee paid by a transaction
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PriorityFee {
+    /// Don't pay a priority fee
+    #[default]
+    None,
+    /// Pay a fixed price per compute unit, in micro-lamports
+    MicroLamportsPerUnit(u64),
+    /// Pay a percentile of the prioritization fees recently paid by transactions that wrote to the
+    /// accounts the instructions write to, such as the escrow and its token account
+    Estimated {
+        /// Percentile of the sampled fees to pay, from 0 to 100
+        percentile: u8,
+        /// Highest price per compute unit to pay, in micro-lamports
+        max_micro_lamports: u64,
+    },
+}
+
+/// Percentile of sampled prioritization fees, in micro-lamports per compute unit, or zero without
+/// samples
+pub fn priority_fee_percentile(fees: &[RpcPrioritizationFee], percentile: u8) -> u64 {
+    let mut fees: Vec<_> = fees.iter().map(|fee| fee.prioritization_fee).collect();
+    if fees.is_empty() {
+        return 0;
+    }
+    fees.sort_unstable();
+    let index = (fees.len() - 1) * percentile.min(100) as usize / 100;
+    fees[index]
+}
+
+/// Durable nonce used in place of a recent blockhash
+///
+/// Transactions built against a durable nonce don't expire until the nonce is advanced, so they can
+/// be signed over a longer period than a blockhash lifetime, e.g. in multisig signing ceremonies.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DurableNonce {
+    /// Nonce account whose stored blockhash is used
+    pub nonce_account: Pubkey,
+    /// Authority of the nonce account, which must sign the transaction
+    pub nonce_authority: Pubkey,
+}
+
+/// Builder for transactions of RNDR instructions with compute budget instructions prepended
+#[derive(Clone)]
+pub struct TransactionBuilder<'a> {
+    client: &'a RndrClient,
+    instructions: Vec<Instruction>,
+    compute_unit_limit: ComputeUnitLimit,
+    priority_fee: PriorityFee,
+    lookup_tables: Vec<AddressLookupTableAccount>,
+    durable_nonce: Option<DurableNonce>,
+}
+
+impl<'a> TransactionBuilder<'a> {
+    /// Create a builder that simulates the compute unit limit and pays the client's priority fee
+    pub fn new(client: &'a RndrClient) -> Self {
+        Self {
+            client,
+            instructions: vec![],
+            compute_unit_limit: ComputeUnitLimit::default(),
+            priority_fee: client.priority_fee(),
+            lookup_tables: vec![],
+            durable_nonce: None,
+        }
+    }
+
+    /// Client the transaction is built and sent with
+    pub fn client(&self) -> &'a RndrClient {
+        self.client
+    }
+
+    /// Add an instruction
+    pub fn instruction(mut self, instruction: Instruction) -> Self {
+        self.instructions.push(instruction);
+        self
+    }
+
+    /// Add instructions
+    pub fn instructions(mut self, instructions: impl IntoIterator<Item = Instruction>) -> Self {
+        self.instructions.extend(instructions);
+        self
+    }
+
+    /// Set how the compute unit limit is set
+    pub fn compute_unit_limit(mut self, compute_unit_limit: ComputeUnitLimit) -> Self {
+        self.compute_unit_limit = compute_unit_limit;
+        self
+    }
+
+    /// Set the priority fee
+    pub fn priority_fee(mut self, priority_fee: PriorityFee) -> Self {
+        self.priority_fee = priority_fee;
+        self
+    }
+
+    /// Add an address lookup table used by versioned transactions
+    pub fn lookup_table(mut self, lookup_table: AddressLookupTableAccount) -> Self {
+        self.lookup_tables.push(lookup_table);
+        self
+    }
+
+    /// Build the transaction against a durable nonce instead of a recent blockhash
+    ///
+    /// The advance nonce instruction is added as the first instruction, as the runtime requires.
+    pub fn durable_nonce(mut self, nonce_account: Pubkey, nonce_authority: Pubkey) -> Self {
+        self.durable_nonce = Some(DurableNonce {
+            nonce_account,
+            nonce_authority,
+        });
+        self
+    }
+
+    /// Build the instructions of the transaction, including advance nonce and compute budget
+    /// instructions
+    pub async fn build_instructions(&self, payer: &Pubkey) -> RndrClientResult<Vec<Instruction>> {
+        let mut instructions = self
+            .advance_nonce_instruction()
+            .into_iter()
+            .collect::<Vec<_>>();
+        match self.compute_unit_limit {
+            ComputeUnitLimit::Default => {}
+            ComputeUnitLimit::Fixed(units) => {
+                instructions.push(ComputeBudgetInstruction::set_compute_unit_limit(units));
+            }
+            ComputeUnitLimit::Simulated { margin_percent } => {
+                let units = self.simulate_compute_units(payer).await?;
+                let units = units.saturating_mul(100 + margin_percent as u64) / 100;
+                let units = units.min(MAX_COMPUTE_UNIT_LIMIT as u64) as u32;
+                instructions.push(ComputeBudgetInstruction::set_compute_unit_limit(units));
+            }
+        }
+        match self.priority_fee {
+            PriorityFee::None => {}
+            PriorityFee::MicroLamportsPerUnit(micro_lamports) => {
+                instructions.push(ComputeBudgetInstruction::set_compute_unit_price(
+                    micro_lamports,
+                ));
+            }
+            PriorityFee::Estimated {
+                percentile,
+                max_micro_lamports,
+            } => {
+                let micro_lamports = self
+                    .estimate_priority_fee(percentile)
+                    .await?
+                    .min(max_micro_lamports);
+                if micro_lamports > 0 {
+                    instructions.push(ComputeBudgetInstruction::set_compute_unit_price(
+                        micro_lamports,
+                    ));
+                }
+            }
+        }
+        instructions.extend(self.instructions.iter().cloned());
+        Ok(instructions)
+    }
+
+    /// Build and sign the transaction with a recent blockhash
+    pub async fn build(
+        &self,
+        payer: &dyn Signer,
+        signers: &[&dyn Signer],
+    ) -> RndrClientResult<Transaction> {
+        let mut transaction = self.build_unsigned(&payer.pubkey()).await?;
+        let recent_blockhash = transaction.message.recent_blockhash;
+        transaction.try_partial_sign(&[payer], recent_blockhash)?;
+        transaction.try_partial_sign(signers, recent_blockhash)?;
+        Ok(transaction)
+    }
+
+    /// Build the transaction without signing it
+    ///
+    /// The fee payer doesn't need to be available, so the transaction can be passed to each signer
+    /// in turn to be partially signed. This is usually combined with a durable nonce.
+    pub async fn build_unsigned(&self, payer: &Pubkey) -> RndrClientResult<Transaction> {
+        let instructions = self.build_instructions(payer).await?;
+        let mut transaction = Transaction::new_with_payer(&instructions, Some(payer));
+        transaction.message.recent_blockhash = self.blockhash().await?;
+        Ok(transaction)
+    }
+
+    /// Build and sign the transaction, then send it and wait for confirmation
+    pub async fn send(
+        &self,
+        payer: &dyn Signer,
+        signers: &[&dyn Signer],
+    ) -> RndrClientResult<Signature> {
+        let transaction = self.build(payer, signers).await?;
+        Ok(self
+            .client
+            .rpc_client()
+            .send_and_confirm_transaction(&transaction)
+            .await?)
+    }
+
+    /// Build and sign a versioned transaction that loads accounts from the lookup tables
+    pub async fn build_versioned(
+        &self,
+        payer: &dyn Signer,
+        signers: &[&dyn Signer],
+    ) -> RndrClientResult<VersionedTransaction> {
+        let instructions = self.build_instructions(&payer.pubkey()).await?;
+        let recent_blockhash = self.blockhash().await?;
+        let message = self.compile_versioned(&payer.pubkey(), &instructions, recent_blockhash)?;
+
+        let mut keypairs = vec![payer];
+        for signer in signers {
+            if keypairs
+                .iter()
+                .all(|keypair| keypair.pubkey() != signer.pubkey())
+            {
+                keypairs.push(*signer);
+            }
+        }
+        Ok(VersionedTransaction::try_new(message, &keypairs)?)
+    }
+
+    /// Build and sign a versioned transaction, then send it and wait for confirmation
+    pub async fn send_versioned(
+        &self,
+        payer: &dyn Signer,
+        signers: &[&dyn Signer],
+    ) -> RndrClientResult<Signature> {
+        let transaction = self.build_versioned(payer, signers).await?;
+        Ok(self
+            .client
+            .rpc_client()
+            .send_and_confirm_transaction(&transaction)
+            .await?)
+    }
+
+    /// Sample the recent prioritization fees paid for the accounts the instructions write to, and
+    /// return a percentile of them in micro-lamports per compute unit
+    pub async fn estimate_priority_fee(&self, percentile: u8) -> RndrClientResult<u64> {
+        let mut writable_accounts = vec![];
+        for account in self
+            .instructions
+            .iter()
+            .flat_map(|instruction| &instruction.accounts)
+        {
+            if account.is_writable && !writable_accounts.contains(&account.pubkey) {
+                writable_accounts.push(account.pubkey);
+            }
+        }
+        writable_accounts.truncate(MAX_PRIORITIZATION_FEE_ACCOUNTS);
+
+        let fees = self
+            .client
+            .rpc_client()
+            .get_recent_prioritization_fees(&writable_accounts)
+            .await?;
+        Ok(priority_fee_percentile(&fees, percentile))
+    }
+
+    /// Blockhash stored in the durable nonce account, or the latest blockhash
+    async fn blockhash(&self) -> RndrClientResult<Hash> {
+        let rpc_client = self.client.rpc_client();
+        match self.durable_nonce {
+            Some(durable_nonce) => {
+                let account = nonce_utils::get_account_with_commitment(
+                    rpc_client,
+                    &durable_nonce.nonce_account,
+                    rpc_client.commitment(),
+                )
+                .await?;
+                Ok(nonce_utils::data_from_account(&account)?.blockhash())
+            }
+            None => Ok(rpc_client.get_latest_blockhash().await?),
+        }
+    }
+
+    fn advance_nonce_instruction(&self) -> Option<Instruction> {
+        self.durable_nonce.map(|durable_nonce| {
+            system_instruction::advance_nonce_account(
+                &durable_nonce.nonce_account,
+                &durable_nonce.nonce_authority,
+            )
+        })
+    }
+
+    fn compile_versioned(
+        &self,
+        payer: &Pubkey,
+        instructions: &[Instruction],
+        recent_blockhash: Hash,
+    ) -> Result<VersionedMessage, CompileError> {
+        let message =
+            v0::Message::try_compile(payer, instructions, &self.lookup_tables, recent_blockhash)?;
+        Ok(VersionedMessage::V0(message))
+    }
+
+    /// Simulate the instructions with the maximum compute unit limit and return the units consumed
+    async fn simulate_compute_units(&self, payer: &Pubkey) -> RndrClientResult<u64> {
+        let mut instructions = self
+            .advance_nonce_instruction()
+            .into_iter()
+            .collect::<Vec<_>>();
+        instructions.push(ComputeBudgetInstruction::set_compute_unit_limit(
+            MAX_COMPUTE_UNIT_LIMIT,
+        ));
+        instructions.extend(self.instructions.iter().cloned());
+        let config = RpcSimulateTransactionConfig {
+            sig_verify: false,
+            replace_recent_blockhash: true,
+            ..RpcSimulateTransactionConfig::default()
+        };
+
+        let rpc_client = self.client.rpc_client();
+        let result = if self.lookup_tables.is_empty() {
+            let transaction = Transaction::new_with_payer(&instructions, Some(payer));
+            rpc_client
+                .simulate_transaction_with_config(&transaction, config)
+                .await?
+        } else {
+            let message = self.compile_versioned(payer, &instructions, Hash::default())?;
+            let transaction = VersionedTransaction {
+                signatures: vec![
+                    Signature::default();
+                    message.header().num_required_signatures as usize
+                ],
+                message,
+            };
+            rpc_client
+                .simulate_transaction_with_config(&transaction, config)
+                .await?
+        }
+        .value;
+        if let Some(error) = result.err {
+            return Err(RndrClientError::Simulation(error));
+        }
+        Ok(result
+            .units_consumed
+            .unwrap_or(MAX_COMPUTE_UNIT_LIMIT as u64))
+    }
+}
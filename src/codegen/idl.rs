@@ -0,0 +1,327 @@
+//! IDL describing the instructions, accounts and errors of the program
+//!
+//! The IDL follows the vocabulary of Shank IDLs, which explorers and generic decoders understand:
+//! instructions with their accounts, args and `u8` discriminant, the types their args refer to and
+//! the custom errors by code. Accounts are parsed from the "Accounts expected by this instruction"
+//! lists of the instruction docs. Args aren't Borsh encoded, see the encoding in the metadata.
+
+use {
+    super::{Field, Schema, Type, TypeDef, Variant, VariantFields},
+    serde_json::{json, Value},
+};
+
+const ACCOUNTS_HEADING: &str = "Accounts expected by this instruction:";
+
+const ENCODING: &str = "Instruction data is the discriminant followed by the args in order, \
+    little-endian, with vecs and strings prefixed by a u8 length, except a trailing bytes arg, \
+    which takes the rest of the data, and a trailing option, which is only encoded when it's \
+    present. Enums are encoded as the index of the variant followed by its fields.";
+
+/// Account an instruction expects, as listed in its docs
+#[derive(Clone, Debug, PartialEq)]
+pub struct Account {
+    /// Name of the account, derived from its description
+    pub name: String,
+    /// Whether the account is writable
+    pub is_mut: bool,
+    /// Whether the account signs
+    pub is_signer: bool,
+    /// Whether the account follows an "Optionally" paragraph, so it may be omitted
+    pub is_optional: bool,
+    /// Whether the account stands for any number of accounts after the others
+    pub is_remaining: bool,
+    /// Description of the account
+    pub docs: Vec<String>,
+}
+
+/// Render the IDL of a schema as pretty-printed JSON
+pub fn render(schema: &Schema) -> Result<String, String> {
+    let instructions = schema
+        .instructions
+        .iter()
+        .enumerate()
+        .map(|(tag, instruction)| render_instruction(tag, instruction))
+        .collect::<Result<Vec<_>, _>>()?;
+    let types = schema.types.iter().map(render_type_def).collect::<Vec<_>>();
+    let errors = schema
+        .errors
+        .iter()
+        .enumerate()
+        .map(|(code, error)| json!({ "code": code, "name": error.name, "msg": error.message }))
+        .collect::<Vec<_>>();
+
+    let idl = json!({
+        "version": env!("CARGO_PKG_VERSION"),
+        "name": "rndr",
+        "instructions": instructions,
+        "types": types,
+        "errors": errors,
+        "metadata": {
+            "origin": "rndr-codegen",
+            "address": crate::id().to_string(),
+            "encoding": ENCODING,
+        },
+    });
+    let mut out = serde_json::to_string_pretty(&idl).map_err(|e| e.to_string())?;
+    out.push('\n');
+    Ok(out)
+}
+
+/// Split the docs of an instruction into its description and the accounts it expects
+pub fn parse_accounts(docs: &[String]) -> Result<(Vec<String>, Vec<Account>), String> {
+    let heading = match docs.iter().position(|doc| doc == ACCOUNTS_HEADING) {
+        Some(heading) => heading,
+        None => return Ok((trim_blank(docs.to_vec()), vec![])),
+    };
+    let mut description = trim_blank(docs[..heading].to_vec());
+    let mut accounts: Vec<Account> = vec![];
+    let mut optional = false;
+    let mut paragraph: Vec<&str> = vec![];
+    for doc in docs[heading + 1..].iter().map(String::as_str) {
+        let trimmed = doc.trim_start();
+        if doc.starts_with("  ") && trimmed.starts_with(|c: char| c.is_ascii_digit()) {
+            let (index, rest) = trimmed
+                .split_once(". ")
+                .ok_or_else(|| format!("{} is not an account", trimmed))?;
+            if index.parse() != Ok(accounts.len()) {
+                return Err(format!("Account {} is out of order", index));
+            }
+            accounts.push(parse_account(rest, optional)?);
+        } else if doc.starts_with("  ") {
+            let account = accounts
+                .last_mut()
+                .ok_or_else(|| format!("{} does not describe an account", trimmed))?;
+            account.docs.push(trimmed.to_string());
+        } else if doc.is_empty() {
+            if paragraph
+                .first()
+                .is_some_and(|doc| doc.starts_with("Optionally"))
+            {
+                optional = true;
+            } else if !paragraph.is_empty() {
+                description.push(String::new());
+                description.extend(paragraph.iter().map(|doc| doc.to_string()));
+            }
+            paragraph.clear();
+        } else {
+            paragraph.push(doc);
+        }
+    }
+    if !paragraph.is_empty() {
+        description.push(String::new());
+        description.extend(paragraph.iter().map(|doc| doc.to_string()));
+    }
+    if let Some(index) = accounts
+        .iter()
+        .position(|account| account.is_remaining)
+        .filter(|index| index + 1 != accounts.len())
+    {
+        return Err(format!("Account {} is followed by other accounts", index));
+    }
+
+    let names = accounts
+        .iter()
+        .map(|account| account.name.clone())
+        .collect::<Vec<_>>();
+    for (index, account) in accounts.iter_mut().enumerate() {
+        let duplicates = names[..index]
+            .iter()
+            .filter(|name| **name == account.name)
+            .count();
+        if duplicates > 0 {
+            account.name = format!("{}{}", account.name, duplicates + 1);
+        }
+    }
+    Ok((trim_blank(description), accounts))
+}
+
+/// Parse an account from the rest of its line, after its index
+fn parse_account(line: &str, is_optional: bool) -> Result<Account, String> {
+    let (is_remaining, line) = match line.strip_prefix("..") {
+        Some(line) => match line.split_once(' ') {
+            Some((_, line)) => (true, line),
+            None => return Err(format!("{} is not an account", line)),
+        },
+        None => (false, line),
+    };
+    let (flags, description) = line
+        .strip_prefix("`[")
+        .and_then(|line| line.split_once("]` "))
+        .ok_or_else(|| format!("{} has no flags", line))?;
+    let flags = flags.split(',').filter(|flag| !flag.is_empty());
+    let (mut is_mut, mut is_signer) = (false, false);
+    for flag in flags {
+        match flag {
+            "writable" => is_mut = true,
+            "signer" => is_signer = true,
+            flag => return Err(format!("{} is not an account flag", flag)),
+        }
+    }
+    Ok(Account {
+        name: account_name(description),
+        is_mut,
+        is_signer,
+        is_optional,
+        is_remaining,
+        docs: vec![description.to_string()],
+    })
+}
+
+/// Name of an account: its description up to any qualification, in camel case without "PDA"
+fn account_name(description: &str) -> String {
+    let end = [
+        ",", " (", " of ", " for ", " on ", " to ", " with ", " that ",
+    ]
+    .iter()
+    .filter_map(|separator| description.find(separator))
+    .min()
+    .unwrap_or(description.len());
+    let mut name = String::new();
+    for word in description[..end]
+        .split(' ')
+        .filter(|word| !word.is_empty() && *word != "PDA")
+    {
+        let word = word.replace(['\'', '$'], "");
+        let word = if word.chars().all(|c| !c.is_lowercase()) {
+            word.to_lowercase()
+        } else {
+            word
+        };
+        let mut chars = word.chars();
+        if let Some(first) = chars.next() {
+            if name.is_empty() {
+                name.extend(first.to_lowercase());
+            } else {
+                name.extend(first.to_uppercase());
+            }
+            name.extend(chars);
+        }
+    }
+    name
+}
+
+fn trim_blank(mut docs: Vec<String>) -> Vec<String> {
+    while docs.last().is_some_and(String::is_empty) {
+        docs.pop();
+    }
+    docs
+}
+
+fn render_instruction(tag: usize, instruction: &Variant) -> Result<Value, String> {
+    let (docs, accounts) =
+        parse_accounts(&instruction.docs).map_err(|e| format!("{} of {}", e, instruction.name))?;
+    let args = match &instruction.fields {
+        VariantFields::Named(fields) => render_fields(fields),
+        _ => vec![],
+    };
+    let accounts = accounts
+        .iter()
+        .map(|account| {
+            let mut value = json!({
+                "name": account.name,
+                "isMut": account.is_mut,
+                "isSigner": account.is_signer,
+                "docs": account.docs,
+            });
+            if account.is_optional {
+                value["isOptional"] = json!(true);
+            }
+            if account.is_remaining {
+                value["isRemaining"] = json!(true);
+            }
+            value
+        })
+        .collect::<Vec<_>>();
+    Ok(json!({
+        "name": camel_case(&instruction.name),
+        "docs": docs,
+        "accounts": accounts,
+        "args": args,
+        "discriminant": { "type": "u8", "value": tag },
+    }))
+}
+
+fn render_type_def(type_def: &TypeDef) -> Value {
+    match type_def {
+        TypeDef::Struct { name, docs, fields } => json!({
+            "name": name,
+            "docs": docs,
+            "type": { "kind": "struct", "fields": render_fields(fields) },
+        }),
+        TypeDef::Enum {
+            name,
+            docs,
+            variants,
+        } => {
+            let variants = variants
+                .iter()
+                .map(|variant| match &variant.fields {
+                    VariantFields::Unit => json!({ "name": variant.name }),
+                    VariantFields::Named(fields) => {
+                        json!({ "name": variant.name, "fields": render_fields(fields) })
+                    }
+                    VariantFields::Unnamed(types) => json!({
+                        "name": variant.name,
+                        "fields": types.iter().map(idl_type).collect::<Vec<_>>(),
+                    }),
+                })
+                .collect::<Vec<_>>();
+            json!({
+                "name": name,
+                "docs": docs,
+                "type": { "kind": "enum", "variants": variants },
+            })
+        }
+    }
+}
+
+fn render_fields(fields: &[Field]) -> Vec<Value> {
+    fields
+        .iter()
+        .map(|field| {
+            json!({
+                "name": camel_case(&field.name),
+                "docs": field.docs,
+                "type": idl_type(&field.ty),
+            })
+        })
+        .collect()
+}
+
+fn idl_type(ty: &Type) -> Value {
+    match ty {
+        Type::U8 => json!("u8"),
+        Type::U16 => json!("u16"),
+        Type::U32 => json!("u32"),
+        Type::U64 => json!("u64"),
+        Type::I64 => json!("i64"),
+        Type::Bool => json!("bool"),
+        Type::Pubkey => json!("publicKey"),
+        Type::FixedBytes(len) => json!({ "array": ["u8", len] }),
+        Type::Bytes => json!("bytes"),
+        Type::String => json!("string"),
+        Type::Vec(ty) => json!({ "vec": idl_type(ty) }),
+        Type::Option(ty) => json!({ "option": idl_type(ty) }),
+        Type::Tuple(types) => json!({ "tuple": types.iter().map(idl_type).collect::<Vec<_>>() }),
+        Type::Named(name) => json!({ "defined": name }),
+    }
+}
+
+/// Camel case of a snake case or Pascal case name
+fn camel_case(name: &str) -> String {
+    let mut out = String::with_capacity(name.len());
+    let mut upper = false;
+    for c in name.chars() {
+        if c == '_' {
+            upper = true;
+        } else if out.is_empty() {
+            out.extend(c.to_lowercase());
+        } else if upper {
+            out.extend(c.to_uppercase());
+            upper = false;
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
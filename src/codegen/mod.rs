@@ -0,0 +1,435 @@
+//! Client bindings generated from the instruction and state definitions
+//!
+//! The sources of the program are parsed into a `Schema` of the instructions and the state types
+//! their data refers to, which bindings are rendered from. Instruction data is encoded the way
+//! `RNDRInstruction::pack` encodes it: a tag byte followed by the fields in order, little-endian,
+//! with vectors and strings prefixed by a `u8` length, except a trailing `Vec<u8>`, which takes the
+//! rest of the data, and a trailing `Option`, which is only encoded when it's `Some`. Enums are
+//! encoded as the index of the variant followed by its fields.
+
+pub mod idl;
+pub mod typescript;
+
+use {
+    solana_program::{hash::HASH_BYTES, pubkey::PUBKEY_BYTES},
+    syn::{
+        Attribute, Expr, ExprLit, Fields, File, GenericArgument, Item, Lit, LitStr, Meta,
+        PathArguments, Type as SynType,
+    },
+};
+
+const ERROR_SOURCE: &str = include_str!("../error.rs");
+const INSTRUCTION_SOURCE: &str = include_str!("../instruction.rs");
+const STATE_SOURCES: &[&str] = &[
+    include_str!("../state/config.rs"),
+    include_str!("../state/incident_mode.rs"),
+    include_str!("../state/job.rs"),
+    include_str!("../state/job_status.rs"),
+    include_str!("../state/job_tree.rs"),
+    include_str!("../state/queued_action.rs"),
+    include_str!("../math/split.rs"),
+];
+
+/// Type of a field of instruction data
+#[derive(Clone, Debug, PartialEq)]
+pub enum Type {
+    /// `u8`
+    U8,
+    /// `u16` or `Rate`
+    U16,
+    /// `u32`
+    U32,
+    /// `u64`, `Slot` or `Epoch`
+    U64,
+    /// `i64` or `UnixTimestamp`
+    I64,
+    /// `bool`, encoded as a `u8` of 0 or 1
+    Bool,
+    /// `Pubkey`
+    Pubkey,
+    /// Byte array of a fixed length
+    FixedBytes(usize),
+    /// Trailing `Vec<u8>` that takes the rest of the data
+    Bytes,
+    /// UTF-8 string prefixed by its `u8` length
+    String,
+    /// Vector prefixed by its `u8` length
+    Vec(Box<Type>),
+    /// Trailing option
+    Option(Box<Type>),
+    /// Tuple of its elements in order
+    Tuple(Vec<Type>),
+    /// State type of the schema
+    Named(String),
+}
+
+/// Field of a struct or struct variant
+#[derive(Clone, Debug, PartialEq)]
+pub struct Field {
+    /// Name of the field
+    pub name: String,
+    /// Doc comment lines of the field
+    pub docs: Vec<String>,
+    /// Type of the field
+    pub ty: Type,
+}
+
+/// Fields of an enum variant
+#[derive(Clone, Debug, PartialEq)]
+pub enum VariantFields {
+    /// Variant without fields
+    Unit,
+    /// Variant with named fields
+    Named(Vec<Field>),
+    /// Tuple variant
+    Unnamed(Vec<Type>),
+}
+
+/// Variant of an enum, whose index is its tag
+#[derive(Clone, Debug, PartialEq)]
+pub struct Variant {
+    /// Name of the variant
+    pub name: String,
+    /// Doc comment lines of the variant
+    pub docs: Vec<String>,
+    /// Fields of the variant
+    pub fields: VariantFields,
+}
+
+/// State type referred to by instruction data
+#[derive(Clone, Debug, PartialEq)]
+pub enum TypeDef {
+    /// Struct with named fields
+    Struct {
+        /// Name of the struct
+        name: String,
+        /// Doc comment lines of the struct
+        docs: Vec<String>,
+        /// Fields of the struct
+        fields: Vec<Field>,
+    },
+    /// Enum
+    Enum {
+        /// Name of the enum
+        name: String,
+        /// Doc comment lines of the enum
+        docs: Vec<String>,
+        /// Variants of the enum
+        variants: Vec<Variant>,
+    },
+}
+
+impl TypeDef {
+    /// Name of the type
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Struct { name, .. } | Self::Enum { name, .. } => name,
+        }
+    }
+
+    /// Whether the type is an enum without fields, encoded as a `u8`
+    pub fn is_unit_enum(&self) -> bool {
+        match self {
+            Self::Struct { .. } => false,
+            Self::Enum { variants, .. } => variants
+                .iter()
+                .all(|variant| variant.fields == VariantFields::Unit),
+        }
+    }
+}
+
+/// Custom error the program may return
+#[derive(Clone, Debug, PartialEq)]
+pub struct ErrorCode {
+    /// Name of the variant of `RNDRError`
+    pub name: String,
+    /// Message the error is displayed with
+    pub message: String,
+}
+
+/// Instructions of the program, the state types their data refers to and its errors
+#[derive(Clone, Debug, PartialEq)]
+pub struct Schema {
+    /// Variants of `RNDRInstruction`, in tag order
+    pub instructions: Vec<Variant>,
+    /// State types referred to by instructions, in the order they're first referred to
+    pub types: Vec<TypeDef>,
+    /// Variants of `RNDRError`, in code order
+    pub errors: Vec<ErrorCode>,
+}
+
+impl Schema {
+    /// Parse the schema from the sources of the program
+    pub fn parse() -> Result<Self, String> {
+        let error_file = syn::parse_file(ERROR_SOURCE).map_err(|e| e.to_string())?;
+        let instruction_file = syn::parse_file(INSTRUCTION_SOURCE).map_err(|e| e.to_string())?;
+        let state_files = STATE_SOURCES
+            .iter()
+            .map(|source| syn::parse_file(source).map_err(|e| e.to_string()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let instructions = match find_item(&instruction_file, "RNDRInstruction") {
+            Some(Item::Enum(item)) => parse_variants(item.variants.iter())?,
+            _ => return Err("RNDRInstruction is not defined".to_string()),
+        };
+        for instruction in &instructions {
+            match &instruction.fields {
+                VariantFields::Unit => {}
+                VariantFields::Named(fields) => check_trailing(&instruction.name, fields)?,
+                VariantFields::Unnamed(_) => {
+                    return Err(format!("{} must have named fields", instruction.name))
+                }
+            }
+        }
+
+        let errors = match find_item(&error_file, "RNDRError") {
+            Some(Item::Enum(item)) => item
+                .variants
+                .iter()
+                .map(parse_error)
+                .collect::<Result<_, _>>()?,
+            _ => return Err("RNDRError is not defined".to_string()),
+        };
+
+        let mut schema = Self {
+            instructions,
+            types: vec![],
+            errors,
+        };
+        let mut pending = schema
+            .instructions
+            .iter()
+            .rev()
+            .flat_map(|instruction| variant_types(&instruction.fields))
+            .collect::<Vec<_>>();
+        while let Some(ty) = pending.pop() {
+            let name = match ty {
+                Type::Named(name) => name,
+                Type::Vec(ty) | Type::Option(ty) => {
+                    pending.push(*ty);
+                    continue;
+                }
+                Type::Tuple(types) => {
+                    pending.extend(types.into_iter().rev());
+                    continue;
+                }
+                _ => continue,
+            };
+            if schema.type_def(&name).is_some() {
+                continue;
+            }
+            let type_def = state_files
+                .iter()
+                .find_map(|file| find_item(file, &name))
+                .ok_or_else(|| format!("{} is not a state type", name))
+                .and_then(parse_type_def)?;
+            match &type_def {
+                TypeDef::Struct { fields, .. } => {
+                    pending.extend(fields.iter().rev().map(|field| field.ty.clone()))
+                }
+                TypeDef::Enum { variants, .. } => pending.extend(
+                    variants
+                        .iter()
+                        .rev()
+                        .flat_map(|variant| variant_types(&variant.fields)),
+                ),
+            }
+            schema.types.push(type_def);
+        }
+        Ok(schema)
+    }
+
+    /// State type of the schema by name
+    pub fn type_def(&self, name: &str) -> Option<&TypeDef> {
+        self.types.iter().find(|type_def| type_def.name() == name)
+    }
+}
+
+fn find_item<'a>(file: &'a File, name: &str) -> Option<&'a Item> {
+    file.items.iter().find(|item| match item {
+        Item::Struct(item) => item.ident == name,
+        Item::Enum(item) => item.ident == name,
+        _ => false,
+    })
+}
+
+fn parse_type_def(item: &Item) -> Result<TypeDef, String> {
+    match item {
+        Item::Struct(item) => Ok(TypeDef::Struct {
+            name: item.ident.to_string(),
+            docs: parse_docs(&item.attrs),
+            fields: parse_fields(&item.fields)?,
+        }),
+        Item::Enum(item) => Ok(TypeDef::Enum {
+            name: item.ident.to_string(),
+            docs: parse_docs(&item.attrs),
+            variants: parse_variants(item.variants.iter())?,
+        }),
+        _ => Err("Only structs and enums are supported".to_string()),
+    }
+}
+
+fn parse_variants<'a>(
+    variants: impl Iterator<Item = &'a syn::Variant>,
+) -> Result<Vec<Variant>, String> {
+    variants
+        .map(|variant| {
+            if variant.discriminant.is_some() {
+                return Err(format!(
+                    "{} has an explicit discriminant, which is not supported",
+                    variant.ident
+                ));
+            }
+            let fields = match &variant.fields {
+                Fields::Unit => VariantFields::Unit,
+                Fields::Named(_) => VariantFields::Named(parse_fields(&variant.fields)?),
+                Fields::Unnamed(fields) => VariantFields::Unnamed(
+                    fields
+                        .unnamed
+                        .iter()
+                        .map(|field| parse_type(&field.ty))
+                        .collect::<Result<_, _>>()
+                        .map_err(|e| format!("{} of {}", e, variant.ident))?,
+                ),
+            };
+            Ok(Variant {
+                name: variant.ident.to_string(),
+                docs: parse_docs(&variant.attrs),
+                fields,
+            })
+        })
+        .collect()
+}
+
+fn parse_error(variant: &syn::Variant) -> Result<ErrorCode, String> {
+    if variant.fields != Fields::Unit || variant.discriminant.is_some() {
+        return Err(format!("{} must be a unit variant", variant.ident));
+    }
+    let message = variant
+        .attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("error"))
+        .and_then(|attr| attr.parse_args::<LitStr>().ok())
+        .ok_or_else(|| format!("{} has no error message", variant.ident))?;
+    Ok(ErrorCode {
+        name: variant.ident.to_string(),
+        message: message.value(),
+    })
+}
+
+fn parse_fields(fields: &Fields) -> Result<Vec<Field>, String> {
+    fields
+        .iter()
+        .map(|field| {
+            let name = field
+                .ident
+                .as_ref()
+                .ok_or_else(|| "Fields of structs must be named".to_string())?
+                .to_string();
+            let ty = parse_type(&field.ty).map_err(|e| format!("{} of {}", e, name))?;
+            Ok(Field {
+                name,
+                docs: parse_docs(&field.attrs),
+                ty,
+            })
+        })
+        .collect()
+}
+
+fn parse_docs(attrs: &[Attribute]) -> Vec<String> {
+    attrs
+        .iter()
+        .filter_map(|attr| match &attr.meta {
+            Meta::NameValue(meta) if meta.path.is_ident("doc") => match &meta.value {
+                Expr::Lit(ExprLit {
+                    lit: Lit::Str(doc), ..
+                }) => Some(doc.value()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .map(|doc| doc.strip_prefix(' ').unwrap_or(&doc).trim_end().to_string())
+        .collect()
+}
+
+fn parse_type(ty: &SynType) -> Result<Type, String> {
+    let unsupported = || "Type is not supported".to_string();
+    match ty {
+        SynType::Array(array) => match &*array.elem {
+            SynType::Path(path) if path.path.is_ident("u8") => Ok(Type::FixedBytes(
+                parse_len(&array.len).ok_or_else(unsupported)?,
+            )),
+            _ => Err(unsupported()),
+        },
+        SynType::Tuple(tuple) => Ok(Type::Tuple(
+            tuple
+                .elems
+                .iter()
+                .map(parse_type)
+                .collect::<Result<_, _>>()?,
+        )),
+        SynType::Path(path) => {
+            let segment = path.path.segments.last().ok_or_else(unsupported)?;
+            let argument = match &segment.arguments {
+                PathArguments::None => None,
+                PathArguments::AngleBracketed(arguments) => match arguments.args.first() {
+                    Some(GenericArgument::Type(ty)) if arguments.args.len() == 1 => {
+                        Some(parse_type(ty)?)
+                    }
+                    _ => return Err(unsupported()),
+                },
+                PathArguments::Parenthesized(_) => return Err(unsupported()),
+            };
+            Ok(match (segment.ident.to_string().as_str(), argument) {
+                ("u8", None) => Type::U8,
+                ("u16" | "Rate", None) => Type::U16,
+                ("u32", None) => Type::U32,
+                ("u64" | "Slot" | "Epoch", None) => Type::U64,
+                ("i64" | "UnixTimestamp", None) => Type::I64,
+                ("bool", None) => Type::Bool,
+                ("Pubkey", None) => Type::Pubkey,
+                ("String", None) => Type::String,
+                ("Vec", Some(Type::U8)) => Type::Bytes,
+                ("Vec", Some(ty)) => Type::Vec(Box::new(ty)),
+                ("Option", Some(ty)) => Type::Option(Box::new(ty)),
+                (name, None) => Type::Named(name.to_string()),
+                _ => return Err(unsupported()),
+            })
+        }
+        _ => Err(unsupported()),
+    }
+}
+
+/// Length of an array, as a literal or a constant of the program's dependencies
+fn parse_len(len: &Expr) -> Option<usize> {
+    match len {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(len), ..
+        }) => len.base10_parse().ok(),
+        Expr::Path(path) if path.path.is_ident("HASH_BYTES") => Some(HASH_BYTES),
+        Expr::Path(path) if path.path.is_ident("PUBKEY_BYTES") => Some(PUBKEY_BYTES),
+        _ => None,
+    }
+}
+
+/// Check that only the last field of instruction data takes the rest of it
+fn check_trailing(name: &str, fields: &[Field]) -> Result<(), String> {
+    let trailing = fields
+        .iter()
+        .position(|field| matches!(field.ty, Type::Bytes | Type::Option(_)));
+    match trailing {
+        Some(index) if index + 1 != fields.len() => Err(format!(
+            "{} has a trailing field before its last field",
+            name
+        )),
+        _ => Ok(()),
+    }
+}
+
+fn variant_types(fields: &VariantFields) -> Vec<Type> {
+    match fields {
+        VariantFields::Unit => vec![],
+        VariantFields::Named(fields) => fields.iter().rev().map(|field| field.ty.clone()).collect(),
+        VariantFields::Unnamed(types) => types.iter().rev().cloned().collect(),
+    }
+}
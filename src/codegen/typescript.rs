@@ -0,0 +1,350 @@
+//! TypeScript types and instruction data encoders
+
+use super::{Field, Schema, Type, TypeDef, Variant, VariantFields};
+
+/// Width that lines are kept within, matching the Prettier config of the web client
+const PRINT_WIDTH: usize = 120;
+
+const HEADER: &str = "\
+// Generated by rndr-codegen from the instruction and state definitions of the program, do not edit. Regenerate with
+// `cargo run --features codegen --bin rndr-codegen > js/src/generated.ts` from the repository root.
+import { PublicKey } from '@solana/web3.js';
+import { toBufferLE } from 'bigint-buffer';
+
+class Writer {
+    private readonly buffers: Buffer[] = [];
+
+    u8(value: number): void {
+        this.buffers.push(Buffer.from([value]));
+    }
+
+    u16(value: number): void {
+        const buffer = Buffer.alloc(2);
+        buffer.writeUInt16LE(value);
+        this.buffers.push(buffer);
+    }
+
+    u32(value: number): void {
+        const buffer = Buffer.alloc(4);
+        buffer.writeUInt32LE(value);
+        this.buffers.push(buffer);
+    }
+
+    u64(value: bigint): void {
+        this.buffers.push(toBufferLE(value, 8));
+    }
+
+    i64(value: bigint): void {
+        this.buffers.push(toBufferLE(BigInt.asUintN(64, value), 8));
+    }
+
+    bool(value: boolean): void {
+        this.u8(value ? 1 : 0);
+    }
+
+    publicKey(value: PublicKey): void {
+        this.buffers.push(value.toBuffer());
+    }
+
+    bytes(value: Uint8Array, length?: number): void {
+        if (length !== undefined && value.length !== length) throw new Error(`Expected ${length} bytes`);
+        this.buffers.push(Buffer.from(value));
+    }
+
+    string(value: string): void {
+        const buffer = Buffer.from(value, 'utf8');
+        this.u8(buffer.length);
+        this.buffers.push(buffer);
+    }
+
+    toBuffer(): Buffer {
+        return Buffer.concat(this.buffers);
+    }
+}
+";
+
+/// Render the TypeScript bindings of a schema
+pub fn render(schema: &Schema) -> String {
+    let mut out = vec![HEADER.to_string()];
+
+    let mut tags = vec!["export enum RNDRInstruction {".to_string()];
+    for (tag, instruction) in schema.instructions.iter().enumerate() {
+        tags.push(format!("    {} = {},", instruction.name, tag));
+    }
+    tags.push("}".to_string());
+    out.push(tags.join("\n"));
+
+    for type_def in &schema.types {
+        out.push(render_type_def(type_def));
+    }
+    for type_def in schema
+        .types
+        .iter()
+        .filter(|type_def| !type_def.is_unit_enum())
+    {
+        out.push(render_type_writer(schema, type_def));
+    }
+    for instruction in &schema.instructions {
+        out.push(render_instruction(schema, instruction));
+    }
+
+    let mut out = out.join("\n\n");
+    out.push('\n');
+    out
+}
+
+fn render_type_def(type_def: &TypeDef) -> String {
+    let mut out = vec![];
+    match type_def {
+        TypeDef::Struct { name, docs, fields } => {
+            render_docs(&mut out, docs, 0);
+            out.push(format!("export interface {} {{", name));
+            render_fields(&mut out, fields);
+            out.push("}".to_string());
+        }
+        TypeDef::Enum {
+            name,
+            docs,
+            variants,
+        } if type_def.is_unit_enum() => {
+            render_docs(&mut out, docs, 0);
+            out.push(format!("export enum {} {{", name));
+            for (index, variant) in variants.iter().enumerate() {
+                render_docs(&mut out, &variant.docs, 1);
+                out.push(format!("    {} = {},", variant.name, index));
+            }
+            out.push("}".to_string());
+        }
+        TypeDef::Enum {
+            name,
+            docs,
+            variants,
+        } => {
+            render_docs(&mut out, docs, 0);
+            let cases = variants.iter().map(render_variant_type).collect::<Vec<_>>();
+            let line = format!("export type {} = {};", name, cases.join(" | "));
+            if line.len() <= PRINT_WIDTH {
+                out.push(line);
+            } else {
+                out.push(format!("export type {} =", name));
+                let last = cases.len() - 1;
+                for (index, case) in cases.iter().enumerate() {
+                    let end = if index == last { ";" } else { "" };
+                    out.push(format!("    | {}{}", case, end));
+                }
+            }
+        }
+    }
+    out.join("\n")
+}
+
+fn render_variant_type(variant: &Variant) -> String {
+    let kind = format!("kind: '{}'", variant.name);
+    match &variant.fields {
+        VariantFields::Unit => format!("{{ {} }}", kind),
+        VariantFields::Named(fields) => {
+            let fields = fields
+                .iter()
+                .map(|field| format!("{}: {}", camel_case(&field.name), ts_type(&field.ty)))
+                .collect::<Vec<_>>();
+            format!("{{ {}; {} }}", kind, fields.join("; "))
+        }
+        VariantFields::Unnamed(types) => format!("{{ {}; value: {} }}", kind, ts_value_type(types)),
+    }
+}
+
+fn render_type_writer(schema: &Schema, type_def: &TypeDef) -> String {
+    let name = type_def.name();
+    let mut out = vec![format!(
+        "const write{} = (writer: Writer, value: {}): void => {{",
+        name, name
+    )];
+    match type_def {
+        TypeDef::Struct { fields, .. } => {
+            for field in fields {
+                let value = format!("value.{}", camel_case(&field.name));
+                render_write(&mut out, schema, &field.ty, &value, 1);
+            }
+        }
+        TypeDef::Enum { variants, .. } => {
+            out.push("    switch (value.kind) {".to_string());
+            for (index, variant) in variants.iter().enumerate() {
+                out.push(format!("        case '{}':", variant.name));
+                out.push(format!("            writer.u8({});", index));
+                match &variant.fields {
+                    VariantFields::Unit => {}
+                    VariantFields::Named(fields) => {
+                        for field in fields {
+                            let value = format!("value.{}", camel_case(&field.name));
+                            render_write(&mut out, schema, &field.ty, &value, 3);
+                        }
+                    }
+                    VariantFields::Unnamed(types) if types.len() == 1 => {
+                        render_write(&mut out, schema, &types[0], "value.value", 3);
+                    }
+                    VariantFields::Unnamed(types) => {
+                        for (index, ty) in types.iter().enumerate() {
+                            let value = format!("value.value[{}]", index);
+                            render_write(&mut out, schema, ty, &value, 3);
+                        }
+                    }
+                }
+                out.push("            break;".to_string());
+            }
+            out.push("    }".to_string());
+        }
+    }
+    out.push("};".to_string());
+    out.join("\n")
+}
+
+fn render_instruction(schema: &Schema, instruction: &Variant) -> String {
+    let mut out = vec![];
+    let name = &instruction.name;
+    let fields = match &instruction.fields {
+        VariantFields::Unit => vec![],
+        VariantFields::Named(fields) => fields.clone(),
+        VariantFields::Unnamed(_) => unreachable!("instructions have named fields"),
+    };
+
+    render_docs(&mut out, &instruction.docs, 0);
+    if fields.is_empty() {
+        out.push(format!(
+            "export const encode{}Data = (): Buffer => {{",
+            name
+        ));
+    } else {
+        out.push(format!("export interface {}Data {{", name));
+        render_fields(&mut out, &fields);
+        out.push("}".to_string());
+        out.push(String::new());
+        out.push(format!(
+            "export const encode{}Data = (data: {}Data): Buffer => {{",
+            name, name
+        ));
+    }
+    out.push("    const writer = new Writer();".to_string());
+    out.push(format!("    writer.u8(RNDRInstruction.{});", name));
+    for field in &fields {
+        let value = format!("data.{}", camel_case(&field.name));
+        render_write(&mut out, schema, &field.ty, &value, 1);
+    }
+    out.push("    return writer.toBuffer();".to_string());
+    out.push("};".to_string());
+    out.join("\n")
+}
+
+fn render_fields(out: &mut Vec<String>, fields: &[Field]) {
+    for field in fields {
+        render_docs(out, &field.docs, 1);
+        match &field.ty {
+            Type::Option(ty) => out.push(format!(
+                "    {}?: {};",
+                camel_case(&field.name),
+                ts_type(ty)
+            )),
+            ty => out.push(format!("    {}: {};", camel_case(&field.name), ts_type(ty))),
+        }
+    }
+}
+
+fn render_write(out: &mut Vec<String>, schema: &Schema, ty: &Type, value: &str, depth: usize) {
+    let indent = "    ".repeat(depth);
+    match ty {
+        Type::U8 => out.push(format!("{}writer.u8({});", indent, value)),
+        Type::U16 => out.push(format!("{}writer.u16({});", indent, value)),
+        Type::U32 => out.push(format!("{}writer.u32({});", indent, value)),
+        Type::U64 => out.push(format!("{}writer.u64({});", indent, value)),
+        Type::I64 => out.push(format!("{}writer.i64({});", indent, value)),
+        Type::Bool => out.push(format!("{}writer.bool({});", indent, value)),
+        Type::Pubkey => out.push(format!("{}writer.publicKey({});", indent, value)),
+        Type::FixedBytes(len) => out.push(format!("{}writer.bytes({}, {});", indent, value, len)),
+        Type::Bytes => out.push(format!("{}writer.bytes({});", indent, value)),
+        Type::String => out.push(format!("{}writer.string({});", indent, value)),
+        Type::Vec(ty) => {
+            out.push(format!("{}writer.u8({}.length);", indent, value));
+            out.push(format!("{}for (const item of {}) {{", indent, value));
+            render_write(out, schema, ty, "item", depth + 1);
+            out.push(format!("{}}}", indent));
+        }
+        Type::Option(ty) => {
+            out.push(format!("{}if ({} !== undefined) {{", indent, value));
+            render_write(out, schema, ty, value, depth + 1);
+            out.push(format!("{}}}", indent));
+        }
+        Type::Tuple(types) => {
+            for (index, ty) in types.iter().enumerate() {
+                let value = format!("{}[{}]", value, index);
+                render_write(out, schema, ty, &value, depth);
+            }
+        }
+        Type::Named(name) => match schema.type_def(name) {
+            Some(type_def) if type_def.is_unit_enum() => {
+                out.push(format!("{}writer.u8({});", indent, value))
+            }
+            _ => out.push(format!("{}write{}(writer, {});", indent, name, value)),
+        },
+    }
+}
+
+fn render_docs(out: &mut Vec<String>, docs: &[String], depth: usize) {
+    let indent = "    ".repeat(depth);
+    match docs {
+        [] => {}
+        [doc] if indent.len() + doc.len() + 7 <= PRINT_WIDTH => {
+            out.push(format!("{}/** {} */", indent, doc));
+        }
+        docs => {
+            out.push(format!("{}/**", indent));
+            for doc in docs {
+                if doc.is_empty() {
+                    out.push(format!("{} *", indent));
+                } else {
+                    out.push(format!("{} * {}", indent, doc));
+                }
+            }
+            out.push(format!("{} */", indent));
+        }
+    }
+}
+
+fn ts_type(ty: &Type) -> String {
+    match ty {
+        Type::U8 | Type::U16 | Type::U32 => "number".to_string(),
+        Type::U64 | Type::I64 => "bigint".to_string(),
+        Type::Bool => "boolean".to_string(),
+        Type::Pubkey => "PublicKey".to_string(),
+        Type::FixedBytes(_) | Type::Bytes => "Uint8Array".to_string(),
+        Type::String => "string".to_string(),
+        Type::Vec(ty) => format!("{}[]", ts_type(ty)),
+        Type::Option(ty) => format!("{} | undefined", ts_type(ty)),
+        Type::Tuple(types) => format!(
+            "[{}]",
+            types.iter().map(ts_type).collect::<Vec<_>>().join(", ")
+        ),
+        Type::Named(name) => name.clone(),
+    }
+}
+
+fn ts_value_type(types: &[Type]) -> String {
+    match types {
+        [ty] => ts_type(ty),
+        types => ts_type(&Type::Tuple(types.to_vec())),
+    }
+}
+
+fn camel_case(name: &str) -> String {
+    let mut out = String::with_capacity(name.len());
+    let mut upper = false;
+    for c in name.chars() {
+        if c == '_' {
+            upper = true;
+        } else if upper {
+            out.extend(c.to_uppercase());
+            upper = false;
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
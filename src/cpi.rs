@@ -0,0 +1,728 @@
+//! Cross-program invocation interface for programs that fund and manage jobs
+//!
+//! Each instruction has a struct of the accounts it expects, in the order documented on
+//! `RNDRInstruction`, with `invoke` and `invoke_signed` methods that pack the instruction and
+//! invoke the RNDR program account passed in the struct. A calling program signs for its PDAs,
+//! such as the authority of the jobs it funds, by passing their seeds to `invoke_signed`.
+//!
+//! A job authority can be a PDA of the calling program, which then manages its jobs in place of
+//! a user. A PDA can't sign the vouchers of `FundJobWithVoucher`, so it signs the instruction
+//! itself instead.
+//!
+//! `from_accounts` takes the RNDR program and then the accounts of an instruction in order from
+//! the accounts passed to the calling program, checking that the program is RNDR's, so a calling
+//! program can forward them without naming each one.
+//!
+//! `GetEscrowSummary`, `GetJobSummary` and `GetEscrowedAmount` only read accounts, so their
+//! `invoke` methods return what the RNDR program sets as return data instead, see the `summary`
+//! module.
+
+use {
+    crate::{
+        error::RNDRError,
+        instruction::RNDRInstruction,
+        state::JobMetadata,
+        summary::{EscrowSummary, JobSummary},
+    },
+    solana_program::{
+        account_info::{next_account_info, AccountInfo},
+        clock::UnixTimestamp,
+        entrypoint::ProgramResult,
+        instruction::{AccountMeta, Instruction},
+        msg, program,
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        system_program,
+    },
+    std::convert::TryInto,
+};
+
+/// Take the next account, which must be the RNDR program
+fn next_rndr_program<'a, 'info>(
+    account_info_iter: &mut impl Iterator<Item = &'a AccountInfo<'info>>,
+) -> Result<AccountInfo<'info>, ProgramError>
+where
+    'info: 'a,
+{
+    let rndr_program_info = next_account_info(account_info_iter)?;
+    if rndr_program_info.key != &crate::id() {
+        msg!("RNDR program provided is not the RNDR program");
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    Ok(rndr_program_info.clone())
+}
+
+/// Invoke an instruction of the RNDR program with its accounts, each with whether it's writable
+/// and whether it signs
+fn invoke_signed<'info>(
+    rndr_program: &AccountInfo<'info>,
+    accounts: &[(&AccountInfo<'info>, bool, bool)],
+    instruction: RNDRInstruction,
+    signers_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let instruction = Instruction {
+        program_id: *rndr_program.key,
+        accounts: accounts
+            .iter()
+            .map(|(info, is_writable, is_signer)| AccountMeta {
+                pubkey: *info.key,
+                is_signer: *is_signer,
+                is_writable: *is_writable,
+            })
+            .collect(),
+        data: instruction.pack(),
+    };
+    let mut account_infos: Vec<_> = accounts
+        .iter()
+        .map(|(info, _, _)| (*info).clone())
+        .collect();
+    account_infos.push(rndr_program.clone());
+    program::invoke_signed(&instruction, &account_infos, signers_seeds)
+}
+
+/// Take the return data set by the RNDR program
+fn rndr_return_data(rndr_program: &AccountInfo) -> Result<Vec<u8>, ProgramError> {
+    match program::get_return_data() {
+        Some((program_id, data)) if &program_id == rndr_program.key => Ok(data),
+        _ => {
+            msg!("RNDR program did not set return data");
+            Err(RNDRError::UnspecifiedError.into())
+        }
+    }
+}
+
+/// Accounts of `FundJob`
+///
+/// The authority must sign, and be the owner of the source token account or a delegate approved
+/// for the amount, such as a PDA a user approved to fund jobs for them. The funder must sign and
+/// pay to create the job, the escrow shard and any JobIndex page, so it's a system account, which
+/// a PDA holding no data can be.
+pub struct FundJob<'info> {
+    /// RNDR program
+    pub rndr_program: AccountInfo<'info>,
+    /// Funder SOL account, pays to create the job
+    pub funder: AccountInfo<'info>,
+    /// Source token account the authority can transfer the amount from
+    pub source_token: AccountInfo<'info>,
+    /// Authority of the source token account and the job
+    pub authority: AccountInfo<'info>,
+    /// Escrow PDA account
+    pub escrow: AccountInfo<'info>,
+    /// Escrow ATA account
+    pub escrow_token: AccountInfo<'info>,
+    /// Job PDA account of the escrow, authority and job identifier
+    pub job: AccountInfo<'info>,
+    /// Escrow shard PDA account for the job
+    pub escrow_shard: AccountInfo<'info>,
+    /// System program
+    pub system_program: AccountInfo<'info>,
+    /// Token program
+    pub token_program: AccountInfo<'info>,
+    /// Optional accounts appended after the others, such as the JobIndex, JobQueue and Stats PDA
+    /// accounts, passed with the signer and writable flags of their account infos
+    pub remaining_accounts: Vec<AccountInfo<'info>>,
+}
+
+impl<'info> FundJob<'info> {
+    /// Take the RNDR program and then the accounts of `FundJob` in order from a slice of accounts
+    pub fn from_accounts(accounts: &[AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_info_iter = &mut accounts.iter();
+        Ok(Self {
+            rndr_program: next_rndr_program(account_info_iter)?,
+            funder: next_account_info(account_info_iter)?.clone(),
+            source_token: next_account_info(account_info_iter)?.clone(),
+            authority: next_account_info(account_info_iter)?.clone(),
+            escrow: next_account_info(account_info_iter)?.clone(),
+            escrow_token: next_account_info(account_info_iter)?.clone(),
+            job: next_account_info(account_info_iter)?.clone(),
+            escrow_shard: next_account_info(account_info_iter)?.clone(),
+            system_program: next_account_info(account_info_iter)?.clone(),
+            token_program: next_account_info(account_info_iter)?.clone(),
+            remaining_accounts: account_info_iter.cloned().collect(),
+        })
+    }
+    /// Invoke `FundJob`, setting the metadata of the job if any
+    pub fn invoke(&self, amount: u64, job_id: u64, metadata: Option<JobMetadata>) -> ProgramResult {
+        self.invoke_signed(amount, job_id, metadata, &[])
+    }
+
+    /// Invoke `FundJob` with the seeds of the calling program's PDA signers
+    pub fn invoke_signed(
+        &self,
+        amount: u64,
+        job_id: u64,
+        metadata: Option<JobMetadata>,
+        signers_seeds: &[&[&[u8]]],
+    ) -> ProgramResult {
+        let mut accounts = vec![
+            (&self.funder, true, true),
+            (&self.source_token, true, false),
+            (&self.authority, false, true),
+            (&self.escrow, false, false),
+            (&self.escrow_token, true, false),
+            (&self.job, true, false),
+            (&self.escrow_shard, true, false),
+            (&self.system_program, false, false),
+            (&self.token_program, false, false),
+        ];
+        accounts.extend(
+            self.remaining_accounts
+                .iter()
+                .map(|info| (info, info.is_writable, info.is_signer)),
+        );
+        invoke_signed(
+            &self.rndr_program,
+            &accounts,
+            RNDRInstruction::FundJob {
+                amount,
+                job_id,
+                metadata,
+            },
+            signers_seeds,
+        )
+    }
+}
+
+/// Accounts of `FundJobWithVoucher`, authorized by the authority signing the instruction
+pub struct FundJobWithVoucher<'info> {
+    /// RNDR program
+    pub rndr_program: AccountInfo<'info>,
+    /// Relayer SOL account, pays to create the job, the escrow shard and the voucher nonce
+    pub relayer: AccountInfo<'info>,
+    /// Source token account with the escrow approved as its delegate for the amount
+    pub source_token: AccountInfo<'info>,
+    /// Owner of the source token account and authority of the job
+    pub authority: AccountInfo<'info>,
+    /// Escrow PDA account
+    pub escrow: AccountInfo<'info>,
+    /// Escrow ATA account
+    pub escrow_token: AccountInfo<'info>,
+    /// Job PDA account of the escrow, authority and job identifier
+    pub job: AccountInfo<'info>,
+    /// Escrow shard PDA account for the job
+    pub escrow_shard: AccountInfo<'info>,
+    /// VoucherNonce PDA account of the escrow and authority
+    pub voucher_nonce: AccountInfo<'info>,
+    /// Instructions sysvar
+    pub instructions_sysvar: AccountInfo<'info>,
+    /// System program
+    pub system_program: AccountInfo<'info>,
+    /// Token program
+    pub token_program: AccountInfo<'info>,
+}
+
+impl<'info> FundJobWithVoucher<'info> {
+    /// Take the RNDR program and then the accounts of `FundJobWithVoucher` in order from a slice of accounts
+    pub fn from_accounts(accounts: &[AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_info_iter = &mut accounts.iter();
+        Ok(Self {
+            rndr_program: next_rndr_program(account_info_iter)?,
+            relayer: next_account_info(account_info_iter)?.clone(),
+            source_token: next_account_info(account_info_iter)?.clone(),
+            authority: next_account_info(account_info_iter)?.clone(),
+            escrow: next_account_info(account_info_iter)?.clone(),
+            escrow_token: next_account_info(account_info_iter)?.clone(),
+            job: next_account_info(account_info_iter)?.clone(),
+            escrow_shard: next_account_info(account_info_iter)?.clone(),
+            voucher_nonce: next_account_info(account_info_iter)?.clone(),
+            instructions_sysvar: next_account_info(account_info_iter)?.clone(),
+            system_program: next_account_info(account_info_iter)?.clone(),
+            token_program: next_account_info(account_info_iter)?.clone(),
+        })
+    }
+    /// Invoke `FundJobWithVoucher` for the voucher with the next nonce of the authority, which
+    /// must sign as a PDA of the calling program
+    pub fn invoke_signed(
+        &self,
+        amount: u64,
+        job_id: u64,
+        nonce: u64,
+        expires_at: UnixTimestamp,
+        signers_seeds: &[&[&[u8]]],
+    ) -> ProgramResult {
+        invoke_signed(
+            &self.rndr_program,
+            &[
+                (&self.relayer, true, true),
+                (&self.source_token, true, false),
+                (&self.authority, false, true),
+                (&self.escrow, false, false),
+                (&self.escrow_token, true, false),
+                (&self.job, true, false),
+                (&self.escrow_shard, true, false),
+                (&self.voucher_nonce, true, false),
+                (&self.instructions_sysvar, false, false),
+                (&self.system_program, false, false),
+                (&self.token_program, false, false),
+            ],
+            RNDRInstruction::FundJobWithVoucher {
+                amount,
+                job_id,
+                nonce,
+                expires_at,
+            },
+            signers_seeds,
+        )
+    }
+}
+
+/// Accounts of `DisburseFunds`
+pub struct DisburseFunds<'info> {
+    /// RNDR program
+    pub rndr_program: AccountInfo<'info>,
+    /// Escrow PDA account
+    pub escrow: AccountInfo<'info>,
+    /// Owner authority of the escrow
+    pub owner: AccountInfo<'info>,
+    /// Escrow ATA account
+    pub escrow_token: AccountInfo<'info>,
+    /// Job PDA account
+    pub job: AccountInfo<'info>,
+    /// Destination token account
+    pub destination_token: AccountInfo<'info>,
+    /// Token program
+    pub token_program: AccountInfo<'info>,
+    /// Escrow shard PDA account the job is funded through, aggregated before the escrow is debited
+    pub escrow_shard: Option<AccountInfo<'info>>,
+}
+
+impl<'info> DisburseFunds<'info> {
+    /// Take the RNDR program and then the accounts of `DisburseFunds` in order from a slice of accounts
+    pub fn from_accounts(accounts: &[AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_info_iter = &mut accounts.iter();
+        Ok(Self {
+            rndr_program: next_rndr_program(account_info_iter)?,
+            escrow: next_account_info(account_info_iter)?.clone(),
+            owner: next_account_info(account_info_iter)?.clone(),
+            escrow_token: next_account_info(account_info_iter)?.clone(),
+            job: next_account_info(account_info_iter)?.clone(),
+            destination_token: next_account_info(account_info_iter)?.clone(),
+            token_program: next_account_info(account_info_iter)?.clone(),
+            escrow_shard: account_info_iter.next().cloned(),
+        })
+    }
+    /// Invoke `DisburseFunds`
+    pub fn invoke(&self, amount: u64) -> ProgramResult {
+        self.invoke_signed(amount, &[])
+    }
+
+    /// Invoke `DisburseFunds` with the seeds of the calling program's PDA signers
+    pub fn invoke_signed(&self, amount: u64, signers_seeds: &[&[&[u8]]]) -> ProgramResult {
+        let mut accounts = vec![
+            (&self.escrow, true, false),
+            (&self.owner, false, true),
+            (&self.escrow_token, true, false),
+            (&self.job, true, false),
+            (&self.destination_token, true, false),
+            (&self.token_program, false, false),
+        ];
+        if let Some(escrow_shard) = &self.escrow_shard {
+            accounts.push((escrow_shard, true, false));
+        }
+        invoke_signed(
+            &self.rndr_program,
+            &accounts,
+            RNDRInstruction::DisburseFunds { amount },
+            signers_seeds,
+        )
+    }
+}
+
+/// Accounts of `CancelJob`
+///
+/// The authority must sign and be writable. It only pays to reallocate a legacy job, so a PDA that
+/// holds data can cancel the jobs it funded. The refund is sent to the destination token account,
+/// which can be any token account of the escrow's mint.
+pub struct CancelJob<'info> {
+    /// RNDR program
+    pub rndr_program: AccountInfo<'info>,
+    /// Authority of the job, pays to reallocate a legacy job
+    pub authority: AccountInfo<'info>,
+    /// Escrow PDA account
+    pub escrow: AccountInfo<'info>,
+    /// Escrow ATA account
+    pub escrow_token: AccountInfo<'info>,
+    /// Job PDA account
+    pub job: AccountInfo<'info>,
+    /// Destination token account for the refund
+    pub destination_token: AccountInfo<'info>,
+    /// Config PDA account
+    pub config: AccountInfo<'info>,
+    /// System program
+    pub system_program: AccountInfo<'info>,
+    /// Token program
+    pub token_program: AccountInfo<'info>,
+    /// Token account of the job's node for the cancellation fee, required if one is charged
+    pub node_token: Option<AccountInfo<'info>>,
+    /// Escrow shard PDA account the job is funded through, aggregated before the escrow is debited
+    pub escrow_shard: Option<AccountInfo<'info>>,
+}
+
+impl<'info> CancelJob<'info> {
+    /// Take the RNDR program and then the accounts of `CancelJob` in order from a slice of accounts
+    pub fn from_accounts(accounts: &[AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_info_iter = &mut accounts.iter().peekable();
+        Ok(Self {
+            rndr_program: next_rndr_program(account_info_iter)?,
+            authority: next_account_info(account_info_iter)?.clone(),
+            escrow: next_account_info(account_info_iter)?.clone(),
+            escrow_token: next_account_info(account_info_iter)?.clone(),
+            job: next_account_info(account_info_iter)?.clone(),
+            destination_token: next_account_info(account_info_iter)?.clone(),
+            config: next_account_info(account_info_iter)?.clone(),
+            system_program: next_account_info(account_info_iter)?.clone(),
+            token_program: next_account_info(account_info_iter)?.clone(),
+            // Escrow shards are owned by the RNDR program, or by the system program until created
+            node_token: account_info_iter
+                .next_if(|account_info| {
+                    account_info.owner != &crate::id()
+                        && account_info.owner != &system_program::id()
+                })
+                .cloned(),
+            escrow_shard: account_info_iter.next().cloned(),
+        })
+    }
+    /// Invoke `CancelJob`
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invoke `CancelJob` with the seeds of the calling program's PDA signers
+    pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> ProgramResult {
+        let mut accounts = vec![
+            (&self.authority, true, true),
+            (&self.escrow, true, false),
+            (&self.escrow_token, true, false),
+            (&self.job, true, false),
+            (&self.destination_token, true, false),
+            (&self.config, false, false),
+            (&self.system_program, false, false),
+            (&self.token_program, false, false),
+        ];
+        if let Some(node_token) = &self.node_token {
+            accounts.push((node_token, true, false));
+        }
+        if let Some(escrow_shard) = &self.escrow_shard {
+            accounts.push((escrow_shard, true, false));
+        }
+        invoke_signed(
+            &self.rndr_program,
+            &accounts,
+            RNDRInstruction::CancelJob,
+            signers_seeds,
+        )
+    }
+}
+
+/// Accounts of `TransferJobAuthority`
+pub struct TransferJobAuthority<'info> {
+    /// RNDR program
+    pub rndr_program: AccountInfo<'info>,
+    /// Authority of the job, pays to reallocate a legacy job
+    pub authority: AccountInfo<'info>,
+    /// Escrow PDA account
+    pub escrow: AccountInfo<'info>,
+    /// Job PDA account
+    pub job: AccountInfo<'info>,
+    /// System program
+    pub system_program: AccountInfo<'info>,
+}
+
+impl<'info> TransferJobAuthority<'info> {
+    /// Take the RNDR program and then the accounts of `TransferJobAuthority` in order from a slice of accounts
+    pub fn from_accounts(accounts: &[AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_info_iter = &mut accounts.iter();
+        Ok(Self {
+            rndr_program: next_rndr_program(account_info_iter)?,
+            authority: next_account_info(account_info_iter)?.clone(),
+            escrow: next_account_info(account_info_iter)?.clone(),
+            job: next_account_info(account_info_iter)?.clone(),
+            system_program: next_account_info(account_info_iter)?.clone(),
+        })
+    }
+    /// Invoke `TransferJobAuthority`
+    pub fn invoke(&self, new_authority: Pubkey) -> ProgramResult {
+        self.invoke_signed(new_authority, &[])
+    }
+
+    /// Invoke `TransferJobAuthority` with the seeds of the calling program's PDA signers
+    pub fn invoke_signed(
+        &self,
+        new_authority: Pubkey,
+        signers_seeds: &[&[&[u8]]],
+    ) -> ProgramResult {
+        invoke_signed(
+            &self.rndr_program,
+            &[
+                (&self.authority, true, true),
+                (&self.escrow, false, false),
+                (&self.job, true, false),
+                (&self.system_program, false, false),
+            ],
+            RNDRInstruction::TransferJobAuthority { new_authority },
+            signers_seeds,
+        )
+    }
+}
+
+/// Accounts of `AcceptJobAuthority`
+pub struct AcceptJobAuthority<'info> {
+    /// RNDR program
+    pub rndr_program: AccountInfo<'info>,
+    /// Pending authority of the job
+    pub pending_authority: AccountInfo<'info>,
+    /// Escrow PDA account
+    pub escrow: AccountInfo<'info>,
+    /// Job PDA account
+    pub job: AccountInfo<'info>,
+}
+
+impl<'info> AcceptJobAuthority<'info> {
+    /// Take the RNDR program and then the accounts of `AcceptJobAuthority` in order from a slice of accounts
+    pub fn from_accounts(accounts: &[AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_info_iter = &mut accounts.iter();
+        Ok(Self {
+            rndr_program: next_rndr_program(account_info_iter)?,
+            pending_authority: next_account_info(account_info_iter)?.clone(),
+            escrow: next_account_info(account_info_iter)?.clone(),
+            job: next_account_info(account_info_iter)?.clone(),
+        })
+    }
+    /// Invoke `AcceptJobAuthority`
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invoke `AcceptJobAuthority` with the seeds of the calling program's PDA signers
+    pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> ProgramResult {
+        invoke_signed(
+            &self.rndr_program,
+            &[
+                (&self.pending_authority, false, true),
+                (&self.escrow, false, false),
+                (&self.job, true, false),
+            ],
+            RNDRInstruction::AcceptJobAuthority,
+            signers_seeds,
+        )
+    }
+}
+
+/// Accounts of `UpdateJobMetadata`
+pub struct UpdateJobMetadata<'info> {
+    /// RNDR program
+    pub rndr_program: AccountInfo<'info>,
+    /// Authority of the job, pays to reallocate a legacy job
+    pub authority: AccountInfo<'info>,
+    /// Escrow PDA account
+    pub escrow: AccountInfo<'info>,
+    /// Job PDA account
+    pub job: AccountInfo<'info>,
+    /// System program
+    pub system_program: AccountInfo<'info>,
+}
+
+impl<'info> UpdateJobMetadata<'info> {
+    /// Take the RNDR program and then the accounts of `UpdateJobMetadata` in order from a slice of accounts
+    pub fn from_accounts(accounts: &[AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_info_iter = &mut accounts.iter();
+        Ok(Self {
+            rndr_program: next_rndr_program(account_info_iter)?,
+            authority: next_account_info(account_info_iter)?.clone(),
+            escrow: next_account_info(account_info_iter)?.clone(),
+            job: next_account_info(account_info_iter)?.clone(),
+            system_program: next_account_info(account_info_iter)?.clone(),
+        })
+    }
+    /// Invoke `UpdateJobMetadata`
+    pub fn invoke(&self, metadata: JobMetadata) -> ProgramResult {
+        self.invoke_signed(metadata, &[])
+    }
+
+    /// Invoke `UpdateJobMetadata` with the seeds of the calling program's PDA signers
+    pub fn invoke_signed(
+        &self,
+        metadata: JobMetadata,
+        signers_seeds: &[&[&[u8]]],
+    ) -> ProgramResult {
+        invoke_signed(
+            &self.rndr_program,
+            &[
+                (&self.authority, true, true),
+                (&self.escrow, false, false),
+                (&self.job, true, false),
+                (&self.system_program, false, false),
+            ],
+            RNDRInstruction::UpdateJobMetadata { metadata },
+            signers_seeds,
+        )
+    }
+}
+
+/// Accounts of `RaiseJobPriority`
+pub struct RaiseJobPriority<'info> {
+    /// RNDR program
+    pub rndr_program: AccountInfo<'info>,
+    /// Authority of the job and the source token account, pays to reallocate a legacy job
+    pub authority: AccountInfo<'info>,
+    /// Escrow PDA account
+    pub escrow: AccountInfo<'info>,
+    /// Job PDA account
+    pub job: AccountInfo<'info>,
+    /// Source token account the authority can transfer the surcharge from
+    pub source_token: AccountInfo<'info>,
+    /// Treasury PDA account of the escrow
+    pub treasury: AccountInfo<'info>,
+    /// Treasury ATA account
+    pub treasury_token: AccountInfo<'info>,
+    /// Config PDA account
+    pub config: AccountInfo<'info>,
+    /// System program
+    pub system_program: AccountInfo<'info>,
+    /// Token program
+    pub token_program: AccountInfo<'info>,
+}
+
+impl<'info> RaiseJobPriority<'info> {
+    /// Take the RNDR program and then the accounts of `RaiseJobPriority` in order from a slice of accounts
+    pub fn from_accounts(accounts: &[AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_info_iter = &mut accounts.iter();
+        Ok(Self {
+            rndr_program: next_rndr_program(account_info_iter)?,
+            authority: next_account_info(account_info_iter)?.clone(),
+            escrow: next_account_info(account_info_iter)?.clone(),
+            job: next_account_info(account_info_iter)?.clone(),
+            source_token: next_account_info(account_info_iter)?.clone(),
+            treasury: next_account_info(account_info_iter)?.clone(),
+            treasury_token: next_account_info(account_info_iter)?.clone(),
+            config: next_account_info(account_info_iter)?.clone(),
+            system_program: next_account_info(account_info_iter)?.clone(),
+            token_program: next_account_info(account_info_iter)?.clone(),
+        })
+    }
+    /// Invoke `RaiseJobPriority`
+    pub fn invoke(&self, priority: u8) -> ProgramResult {
+        self.invoke_signed(priority, &[])
+    }
+
+    /// Invoke `RaiseJobPriority` with the seeds of the calling program's PDA signers
+    pub fn invoke_signed(&self, priority: u8, signers_seeds: &[&[&[u8]]]) -> ProgramResult {
+        invoke_signed(
+            &self.rndr_program,
+            &[
+                (&self.authority, true, true),
+                (&self.escrow, false, false),
+                (&self.job, true, false),
+                (&self.source_token, true, false),
+                (&self.treasury, false, false),
+                (&self.treasury_token, true, false),
+                (&self.config, false, false),
+                (&self.system_program, false, false),
+                (&self.token_program, false, false),
+            ],
+            RNDRInstruction::RaiseJobPriority { priority },
+            signers_seeds,
+        )
+    }
+}
+
+/// Accounts of `GetEscrowSummary`
+pub struct GetEscrowSummary<'info> {
+    /// RNDR program
+    pub rndr_program: AccountInfo<'info>,
+    /// Escrow PDA account
+    pub escrow: AccountInfo<'info>,
+}
+
+impl<'info> GetEscrowSummary<'info> {
+    /// Take the RNDR program and then the accounts of `GetEscrowSummary` in order from a slice of accounts
+    pub fn from_accounts(accounts: &[AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_info_iter = &mut accounts.iter();
+        Ok(Self {
+            rndr_program: next_rndr_program(account_info_iter)?,
+            escrow: next_account_info(account_info_iter)?.clone(),
+        })
+    }
+    /// Invoke `GetEscrowSummary`, returning the summary of the escrow
+    pub fn invoke(&self) -> Result<EscrowSummary, ProgramError> {
+        invoke_signed(
+            &self.rndr_program,
+            &[(&self.escrow, false, false)],
+            RNDRInstruction::GetEscrowSummary,
+            &[],
+        )?;
+        EscrowSummary::unpack(&rndr_return_data(&self.rndr_program)?)
+    }
+}
+
+/// Accounts of `GetJobSummary`
+pub struct GetJobSummary<'info> {
+    /// RNDR program
+    pub rndr_program: AccountInfo<'info>,
+    /// Escrow PDA account
+    pub escrow: AccountInfo<'info>,
+    /// Job PDA account of the escrow
+    pub job: AccountInfo<'info>,
+}
+
+impl<'info> GetJobSummary<'info> {
+    /// Take the RNDR program and then the accounts of `GetJobSummary` in order from a slice of accounts
+    pub fn from_accounts(accounts: &[AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_info_iter = &mut accounts.iter();
+        Ok(Self {
+            rndr_program: next_rndr_program(account_info_iter)?,
+            escrow: next_account_info(account_info_iter)?.clone(),
+            job: next_account_info(account_info_iter)?.clone(),
+        })
+    }
+    /// Invoke `GetJobSummary`, returning the summary of the job
+    pub fn invoke(&self) -> Result<JobSummary, ProgramError> {
+        invoke_signed(
+            &self.rndr_program,
+            &[(&self.escrow, false, false), (&self.job, false, false)],
+            RNDRInstruction::GetJobSummary,
+            &[],
+        )?;
+        JobSummary::unpack(&rndr_return_data(&self.rndr_program)?)
+    }
+}
+
+/// Accounts of `GetEscrowedAmount`
+///
+/// Lending, insurance and analytics programs read the amount of tokens in escrow for a job with
+/// `invoke`, such as to value it as collateral.
+pub struct GetEscrowedAmount<'info> {
+    /// RNDR program
+    pub rndr_program: AccountInfo<'info>,
+    /// Escrow PDA account
+    pub escrow: AccountInfo<'info>,
+    /// Job PDA account of the escrow
+    pub job: AccountInfo<'info>,
+}
+
+impl<'info> GetEscrowedAmount<'info> {
+    /// Take the RNDR program and then the accounts of `GetEscrowedAmount` in order from a slice of accounts
+    pub fn from_accounts(accounts: &[AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_info_iter = &mut accounts.iter();
+        Ok(Self {
+            rndr_program: next_rndr_program(account_info_iter)?,
+            escrow: next_account_info(account_info_iter)?.clone(),
+            job: next_account_info(account_info_iter)?.clone(),
+        })
+    }
+    /// Invoke `GetEscrowedAmount`, returning the amount of tokens in escrow for the job
+    pub fn invoke(&self) -> Result<u64, ProgramError> {
+        invoke_signed(
+            &self.rndr_program,
+            &[(&self.escrow, false, false), (&self.job, false, false)],
+            RNDRInstruction::GetEscrowedAmount,
+            &[],
+        )?;
+        let data = rndr_return_data(&self.rndr_program)?;
+        let amount = data.as_slice().try_into().map_err(|_| {
+            msg!("Escrowed amount is {} bytes, expected 8", data.len());
+            ProgramError::from(RNDRError::InstructionUnpackError)
+        })?;
+        Ok(u64::from_le_bytes(amount))
+    }
+}
@@ -8,6 +8,7 @@ use {
         account_info::AccountInfo, entrypoint, entrypoint::ProgramResult,
         program_error::PrintProgramError, pubkey::Pubkey,
     },
+    solana_security_txt::security_txt,
 };
 
 entrypoint!(process_instruction);
@@ -23,3 +24,103 @@ fn process_instruction(
     }
     Ok(())
 }
+
+// Disclosure channel compiled into the program binary, so it can be found from the deployed bytes
+security_txt! {
+    name: "RNDR",
+    project_url: env!("CARGO_PKG_REPOSITORY"),
+    contacts: "link:https://github.com/jordansexton/rndr/security/advisories/new",
+    policy: "https://github.com/jordansexton/rndr/blob/master/SECURITY.md",
+    preferred_languages: "en",
+    source_code: env!("CARGO_PKG_REPOSITORY")
+}
+
+#[cfg(feature = "custom-heap")]
+#[global_allocator]
+static ALLOCATOR: allocator::BumpAllocator = allocator::BumpAllocator;
+
+#[cfg(feature = "custom-heap")]
+mod allocator {
+    use {
+        solana_program::entrypoint::{HEAP_LENGTH, HEAP_START_ADDRESS},
+        std::{
+            alloc::{GlobalAlloc, Layout},
+            mem::size_of,
+            ptr::{copy_nonoverlapping, null_mut},
+        },
+    };
+
+    /// Start of the heap, where the position of the next free byte is stored
+    const HEAP_START: usize = HEAP_START_ADDRESS as usize;
+    /// End of the default 32KiB heap frame, which the largest batch instructions fit within
+    const HEAP_END: usize = HEAP_START + HEAP_LENGTH;
+
+    /// Bump allocator that grows upward from the start of the heap
+    ///
+    /// Unlike the default allocator, the most recent allocation can be grown or freed in place,
+    /// so pushing to a freshly allocated `Vec` (e.g. unpacking a batch instruction or building
+    /// CPI account lists) doesn't copy the buffer and leave the old one unreclaimed.
+    pub struct BumpAllocator;
+
+    impl BumpAllocator {
+        /// Position of the next free byte
+        #[inline]
+        unsafe fn position(&self) -> *mut usize {
+            HEAP_START as *mut usize
+        }
+
+        /// Whether an allocation ends at the position of the next free byte
+        #[inline]
+        unsafe fn is_last(&self, ptr: *mut u8, size: usize) -> bool {
+            ptr as usize + size == *self.position()
+        }
+    }
+
+    unsafe impl GlobalAlloc for BumpAllocator {
+        #[inline]
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let position = self.position();
+            // The heap is zeroed, so the position is unset until the first allocation
+            let free = match *position {
+                0 => HEAP_START + size_of::<usize>(),
+                free => free,
+            };
+            let start = (free + layout.align() - 1) & !(layout.align() - 1);
+            match start.checked_add(layout.size()) {
+                Some(end) if end <= HEAP_END => {
+                    *position = end;
+                    start as *mut u8
+                }
+                _ => null_mut(),
+            }
+        }
+
+        #[inline]
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            if self.is_last(ptr, layout.size()) {
+                *self.position() = ptr as usize;
+            }
+        }
+
+        #[inline]
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            if self.is_last(ptr, layout.size()) {
+                return match (ptr as usize).checked_add(new_size) {
+                    Some(end) if end <= HEAP_END => {
+                        *self.position() = end;
+                        ptr
+                    }
+                    _ => null_mut(),
+                };
+            }
+            if new_size <= layout.size() {
+                return ptr;
+            }
+            let new_ptr = self.alloc(Layout::from_size_align_unchecked(new_size, layout.align()));
+            if !new_ptr.is_null() {
+                copy_nonoverlapping(ptr, new_ptr, layout.size());
+            }
+            new_ptr
+        }
+    }
+}
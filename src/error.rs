@@ -24,6 +24,51 @@ pub enum RNDRError {
     /// MathError
     #[error("MathError")]
     MathError,
+    /// StaleOraclePrice
+    #[error("StaleOraclePrice")]
+    StaleOraclePrice,
+    /// UnreliableOraclePrice
+    #[error("UnreliableOraclePrice")]
+    UnreliableOraclePrice,
+
+    // 5
+    /// InvariantViolation
+    #[error("InvariantViolation")]
+    InvariantViolation,
+    /// DuplicateAccount
+    #[error("DuplicateAccount")]
+    DuplicateAccount,
+    /// JobAlreadyAssigned
+    #[error("JobAlreadyAssigned")]
+    JobAlreadyAssigned,
+    /// JobFrozen
+    #[error("JobFrozen")]
+    JobFrozen,
+    /// IncidentModeActive
+    #[error("IncidentModeActive")]
+    IncidentModeActive,
+
+    // 10
+    /// InvalidApprover
+    #[error("InvalidApprover")]
+    InvalidApprover,
+    /// ExchangeRateAlreadySet
+    #[error("ExchangeRateAlreadySet")]
+    ExchangeRateAlreadySet,
+    /// JobDisputed
+    #[error("JobDisputed")]
+    JobDisputed,
+    /// ChallengeWindowActive
+    #[error("ChallengeWindowActive")]
+    ChallengeWindowActive,
+    /// ApprovalRequired
+    #[error("ApprovalRequired")]
+    ApprovalRequired,
+
+    // 15
+    /// ProposalNotApproved
+    #[error("ProposalNotApproved")]
+    ProposalNotApproved,
 }
 
 impl From<RNDRError> for ProgramError {
@@ -0,0 +1,2062 @@
+//! Event types
+//!
+//! Events are logged with `sol_log_data`, so they appear in transaction logs as `Program data: `
+//! lines with the base64 encoded event. An event is encoded as the schema version, the
+//! discriminator of the event and its fields in order, with the little-endian encoding of
+//! instruction data. Fields may be appended to an event without bumping the version, so trailing
+//! bytes are ignored when an event is parsed, while any other change to the encoding of an event
+//! bumps the version.
+
+#[cfg(feature = "client")]
+use base64::{engine::general_purpose::STANDARD, Engine};
+use {
+    crate::{
+        error::RNDRError,
+        instruction::RNDRInstruction,
+        state::{AdminAction, ConfigChange, IncidentMode, JobMetadata},
+    },
+    solana_program::{
+        clock::{Epoch, Slot, UnixTimestamp},
+        log::sol_log_data,
+        msg,
+        program_error::ProgramError,
+        pubkey::Pubkey,
+    },
+    std::{convert::TryFrom, mem::size_of},
+};
+
+/// Version of the event schema, the first byte of every logged event
+pub const EVENT_VERSION: u8 = 1;
+
+/// Work of a node was credited when a job it completed was paid
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WorkCredited {
+    /// Amount of work credited, the value in RNDR of the tokens paid for the job
+    pub amount: u64,
+    /// Node account credited with the work
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub node: Pubkey,
+    /// Epoch the work is credited in
+    pub epoch: Epoch,
+}
+
+/// Reward multiplier of the stake tier of a node was applied to the work it recorded in an epoch
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RewardMultiplierApplied {
+    /// Multiplier applied, in basis points
+    pub multiplier_bps: u16,
+}
+
+/// A field of the config was changed
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ConfigChanged {
+    /// Previous value of the field
+    pub previous: ConfigChange,
+    /// New value of the field
+    pub change: ConfigChange,
+}
+
+/// An administrative action was queued behind the timelock
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ActionQueued {
+    /// Action that was queued
+    pub action: AdminAction,
+    /// Time the action can be executed at
+    pub executable_at: UnixTimestamp,
+}
+
+/// Rotation of the owner of an escrow was scheduled
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OwnerRotationScheduled {
+    /// Escrow whose owner rotates
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub escrow: Pubkey,
+    /// Owner that scheduled the rotation
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub previous_owner: Pubkey,
+    /// Owner the escrow rotates to
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub new_owner: Pubkey,
+    /// Time the rotation can be executed at
+    pub executable_at: UnixTimestamp,
+}
+
+/// A reward pool was funded
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RewardPoolFunded {
+    /// Amount of tokens funded
+    pub amount: u64,
+    /// Epoch of the reward pool
+    pub epoch: Epoch,
+}
+
+/// A Merkle root of node rewards was published for an epoch
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MerkleRootPublished {
+    /// Total amount of tokens distributed
+    pub amount: u64,
+    /// Number of nodes in the distribution
+    pub node_count: u32,
+    /// Epoch of the distribution
+    pub epoch: Epoch,
+}
+
+/// A node claimed its leaf of a Merkle distribution
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MerkleRewardClaimed {
+    /// Amount of tokens claimed
+    pub amount: u64,
+    /// Index of the claimed leaf
+    pub index: u32,
+    /// Epoch of the distribution
+    pub epoch: Epoch,
+}
+
+/// The emission schedule of an escrow was set
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EmissionScheduleSet {
+    /// Amount of tokens emitted in the start epoch
+    pub initial_amount: u64,
+    /// First epoch of emissions
+    pub start_epoch: Epoch,
+    /// Decay of the emitted amount per epoch, in basis points
+    pub decay_bps: u16,
+}
+
+/// Scheduled rewards were emitted into the reward pool of an epoch
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RewardsEmitted {
+    /// Amount of tokens emitted
+    pub amount: u64,
+    /// Epoch the rewards were emitted for
+    pub epoch: Epoch,
+}
+
+/// A node staked tokens
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Staked {
+    /// Amount of tokens staked
+    pub amount: u64,
+}
+
+/// A node unstaked tokens
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Unstaked {
+    /// Amount of tokens unstaked
+    pub amount: u64,
+}
+
+/// A node set whether its epoch rewards are compounded
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AutoCompoundSet {
+    /// Whether epoch rewards are compounded
+    pub auto_compound: bool,
+}
+
+/// Epoch rewards of a node were compounded into its stake
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RewardsCompounded {
+    /// Amount of tokens compounded
+    pub reward: u64,
+}
+
+/// Tokens were staked in the stake pool for receipt tokens
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LiquidStaked {
+    /// Amount of tokens staked
+    pub amount: u64,
+    /// Amount of receipt tokens minted
+    pub receipt_amount: u64,
+}
+
+/// Receipt tokens were redeemed from the stake pool
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LiquidUnstaked {
+    /// Amount of tokens unstaked
+    pub amount: u64,
+    /// Amount of receipt tokens burned
+    pub receipt_amount: u64,
+}
+
+/// Rewards were deposited into the stake pool
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StakeRewardsDeposited {
+    /// Amount of tokens deposited
+    pub amount: u64,
+}
+
+/// The crank bounty of an escrow was set
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CrankBountySet {
+    /// Bounty paid per crank, in lamports
+    pub bounty: u64,
+}
+
+/// The burn cap of a treasury was set
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TreasuryBurnCapSet {
+    /// Maximum amount of tokens burned per epoch
+    pub epoch_burn_cap: u64,
+}
+
+/// Tokens of a treasury were burned
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TreasuryBurned {
+    /// Amount of tokens burned
+    pub amount: u64,
+    /// Epoch the tokens were burned in
+    pub epoch: Epoch,
+}
+
+/// A job was funded with a signed voucher
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VoucherUsed {
+    /// Nonce of the voucher
+    pub nonce: u64,
+    /// Job authority that signed the voucher
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub authority: Pubkey,
+    /// Identifier of the funded job
+    pub job_id: u64,
+}
+
+/// A job authority registered a session key
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SessionKeyRegistered {
+    /// Session key registered
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub session_key: Pubkey,
+    /// Slot the session key expires at
+    pub expiry_slot: Slot,
+    /// Mask of the instructions the session key can sign
+    pub instruction_mask: u64,
+}
+
+/// An escrow owner approved a delegate
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DelegateApproved {
+    /// Delegate approved
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub delegate: Pubkey,
+    /// Amount of tokens the delegate can disburse
+    pub limit: u64,
+    /// Slot the allowance expires at
+    pub expiry_slot: Slot,
+}
+
+/// An escrow owner revoked a delegate
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DelegateRevoked {
+    /// Delegate revoked
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub delegate: Pubkey,
+}
+
+/// A recurring job subscription was created
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SubscriptionCreated {
+    /// Amount of tokens funded per period
+    pub amount: u64,
+    /// Slots between fundings
+    pub period: Slot,
+    /// Identifier of the job funded
+    pub job_id: u64,
+}
+
+/// A subscription funded its job for a period
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SubscriptionProcessed {
+    /// Amount of tokens funded
+    pub amount: u64,
+    /// Identifier of the job funded
+    pub job_id: u64,
+    /// Slot the next funding is due at
+    pub next_slot: Slot,
+}
+
+/// A job was cancelled
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct JobCancelled {
+    /// Job cancelled
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub job: Pubkey,
+    /// Amount of tokens refunded to the job authority
+    pub refund: u64,
+    /// Amount of tokens paid to the node as a cancellation fee
+    pub fee: u64,
+    /// Node paid the fee, or the default pubkey if the job was open
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub node: Pubkey,
+}
+
+/// Transfer of the authority of a job was started
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct JobAuthorityTransferStarted {
+    /// Job transferred
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub job: Pubkey,
+    /// Pending authority that must accept the transfer
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub new_authority: Pubkey,
+}
+
+/// Transfer of the authority of a job was accepted
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct JobAuthorityTransferred {
+    /// Job transferred
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub job: Pubkey,
+    /// Previous job authority
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub previous_authority: Pubkey,
+    /// New job authority
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub new_authority: Pubkey,
+}
+
+/// A job bundle was created
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct JobBundleCreated {
+    /// Number of jobs in the bundle
+    pub job_count: u8,
+    /// Identifier of the first job of the bundle
+    pub first_job_id: u64,
+}
+
+/// Jobs of a bundle were funded
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct JobBundleFunded {
+    /// Amount of tokens funded for each job
+    pub amount_per_job: u64,
+    /// Number of jobs funded
+    pub count: u8,
+    /// Identifier of the first job funded
+    pub first_job_id: u64,
+}
+
+/// Open jobs of a bundle were cancelled
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct JobBundleCancelled {
+    /// Number of jobs cancelled
+    pub cancelled: u8,
+    /// Amount of tokens refunded
+    pub refund: u64,
+}
+
+/// Statuses of the jobs of a bundle were aggregated
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct JobBundleAggregated {
+    /// Number of jobs completed
+    pub completed: u8,
+    /// Number of jobs cancelled
+    pub cancelled: u8,
+    /// Number of jobs in the bundle
+    pub job_count: u8,
+}
+
+/// Every job of a bundle was completed or cancelled
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct JobBundleCompleted {
+    /// Job bundle completed
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub job_bundle: Pubkey,
+}
+
+/// A job tree was created
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct JobTreeCreated {
+    /// Depth of the tree
+    pub max_depth: u8,
+}
+
+/// A compressed job was appended to a job tree
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CompressedJobAppended {
+    /// Identifier of the job
+    pub id: u64,
+    /// Amount of tokens funded
+    pub amount: u64,
+}
+
+/// A compressed job was assigned to a node
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CompressedJobReplaced {
+    /// Identifier of the job
+    pub id: u64,
+    /// Node assigned
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub node: Pubkey,
+}
+
+/// A compressed job was paid to its node
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CompressedJobPaid {
+    /// Identifier of the job
+    pub id: u64,
+    /// Amount of tokens paid
+    pub amount: u64,
+    /// Node paid
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub node: Pubkey,
+}
+
+/// An open compressed job was cancelled
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CompressedJobCancelled {
+    /// Identifier of the job
+    pub id: u64,
+    /// Amount of tokens refunded
+    pub amount: u64,
+}
+
+/// The owner of an escrow was rotated by a queued action
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OwnerRotated {
+    /// Escrow whose owner rotated
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub escrow: Pubkey,
+    /// Previous owner
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub previous_owner: Pubkey,
+    /// New owner
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub new_owner: Pubkey,
+}
+
+/// A queued administrative action was cancelled
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ActionCancelled {
+    /// Action that was cancelled
+    pub action: AdminAction,
+}
+
+/// The recovery council of an escrow was set
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RecoveryCouncilSet {
+    /// Number of guardian approvals needed to recover the escrow
+    pub threshold: u8,
+    /// Number of guardians in the council
+    pub guardian_count: u8,
+}
+
+/// Guardians approved the recovery of an escrow to a new owner
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RecoveryStarted {
+    /// Owner the escrow is recovered to
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub new_owner: Pubkey,
+    /// Time the recovery can be executed at
+    pub executable_at: UnixTimestamp,
+}
+
+/// The owner of an escrow was recovered
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EscrowOwnerRecovered {
+    /// New owner
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub owner: Pubkey,
+}
+
+/// A node claimed its share of an epoch reward pool
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EpochRewardClaimed {
+    /// Amount of tokens claimed
+    pub reward: u64,
+    /// Epoch of the reward pool
+    pub epoch: Epoch,
+}
+
+/// A crank bounty was paid
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CrankBountyPaid {
+    /// Bounty paid, in lamports
+    pub bounty: u64,
+    /// Account paid
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub recipient: Pubkey,
+}
+
+/// Work of a node was recorded for an epoch
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EpochWorkRecorded {
+    /// Total work of the node in the epoch
+    pub work: u64,
+    /// Epoch the work is recorded in
+    pub epoch: Epoch,
+}
+
+/// The off-chain metadata of a job was set
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct JobMetadataUpdated {
+    /// Job updated
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub job: Pubkey,
+    /// Metadata of the job
+    pub metadata: JobMetadata,
+}
+
+/// The pooled tokens of an escrow were moved to another token account
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EscrowTokenAccountSet {
+    /// Token account the tokens were held in
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub previous_token_account: Pubkey,
+    /// Token account the tokens are held in
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub token_account: Pubkey,
+    /// Amount of tokens moved
+    pub amount: u64,
+}
+
+/// The migration of an escrow to a new token mint was begun
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MintMigrationBegun {
+    /// Mint migration begun
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub mint_migration: Pubkey,
+    /// Token mint the escrow is migrated to
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub new_token_mint: Pubkey,
+    /// Numerator of the conversion rate
+    pub rate_numerator: u64,
+    /// Denominator of the conversion rate
+    pub rate_denominator: u64,
+}
+
+/// The tokens of an escrow or isolated job were converted to the new token mint of a migration
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MintMigrationTokensConverted {
+    /// Mint migration the tokens were converted by
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub mint_migration: Pubkey,
+    /// Token account of the old mint the tokens were held in
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub token_account: Pubkey,
+    /// Amount of old tokens sent to the owner
+    pub amount: u64,
+    /// Amount of new tokens deposited by the owner
+    pub converted_amount: u64,
+}
+
+/// Jobs were migrated to the new token mint of a migration
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct JobsMigrated {
+    /// Mint migration the jobs were migrated by
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub mint_migration: Pubkey,
+    /// Number of jobs migrated, not including jobs that were skipped
+    pub count: u64,
+    /// Number of jobs migrated by the mint migration so far
+    pub jobs_migrated: u64,
+}
+
+/// The priority of a job was raised
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct JobPriorityRaised {
+    /// Job raised
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub job: Pubkey,
+    /// Priority of the job
+    pub priority: u8,
+    /// Amount of tokens paid to the treasury for the levels raised
+    pub surcharge: u64,
+}
+
+/// The exchange rate a job is settled at was recorded
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct JobExchangeRateSet {
+    /// Job the rate was recorded for
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub job: Pubkey,
+    /// RNDR base units that `rate_denominator` base units of the job's token are worth
+    pub rate_numerator: u64,
+    /// Base units of the job's token worth `rate_numerator` RNDR base units
+    pub rate_denominator: u64,
+    /// Slot of the oracle price the rate was derived from
+    pub oracle_slot: Slot,
+}
+
+/// A job was funded with an amount of tokens converted from a value at an oracle price
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct JobFundedAtOraclePrice {
+    /// Job that was funded
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub job: Pubkey,
+    /// Value the job was funded with, in the quote currency of the price feed
+    pub value: u64,
+    /// Amount of tokens the value was converted to
+    pub amount: u64,
+    /// Oracle price of one whole token the value was converted at, see `OraclePrice`
+    pub price: u64,
+    /// Slot the oracle price was published at
+    pub oracle_slot: Slot,
+}
+
+/// A job was frozen under a legal hold
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct JobFrozen {
+    /// Job that was frozen
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub job: Pubkey,
+}
+
+/// A job's legal hold was released
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct JobUnfrozen {
+    /// Job that was unfrozen
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub job: Pubkey,
+}
+
+/// The incident mode of the config was synced to an escrow
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IncidentModeSet {
+    /// Escrow the incident mode was synced to
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub escrow: Pubkey,
+    /// Direction funds can flow through the escrow in
+    pub mode: IncidentMode,
+}
+
+/// A cold wallet was registered for a treasury, cancelling its scheduled sweep
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ColdWalletRegistered {
+    /// Treasury the cold wallet was registered for
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub treasury: Pubkey,
+    /// Token account the treasury's tokens can be swept to
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub cold_wallet: Pubkey,
+}
+
+/// A sweep of a treasury to its cold wallet was scheduled
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TreasurySweepScheduled {
+    /// Treasury the sweep was scheduled for
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub treasury: Pubkey,
+    /// Cold wallet the tokens will be swept to
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub cold_wallet: Pubkey,
+    /// Amount of tokens to sweep
+    pub amount: u64,
+    /// Unix timestamp from which the sweep can be executed
+    pub executable_at: UnixTimestamp,
+}
+
+/// Tokens of a treasury were swept to its cold wallet
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TreasurySwept {
+    /// Treasury the tokens were swept from
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub treasury: Pubkey,
+    /// Cold wallet the tokens were swept to
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub cold_wallet: Pubkey,
+    /// Amount of tokens swept
+    pub amount: u64,
+}
+
+/// The scheduled sweep of a treasury was cancelled by the guardian
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TreasurySweepCancelled {
+    /// Treasury the sweep was scheduled for
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub treasury: Pubkey,
+    /// Amount of tokens of the cancelled sweep
+    pub amount: u64,
+}
+
+/// A reconciliation found an escrow's token account holding fewer tokens than the escrow tracks
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EscrowDiscrepancyFound {
+    /// Escrow that was reconciled
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub escrow: Pubkey,
+    /// Balance of the escrow's token account
+    pub balance: u64,
+    /// Amount of tokens the escrow and its escrow shards track
+    pub tracked: u64,
+}
+
+/// The tokens of a job funded in a token other than RNDR were settled, valued in RNDR at the job's
+/// recorded exchange rate
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct JobSettledAtExchangeRate {
+    /// Job that was settled
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub job: Pubkey,
+    /// Amount of the job's tokens settled
+    pub amount: u64,
+    /// Value of the tokens in RNDR base units, rounded down
+    pub rndr_value: u64,
+}
+
+/// Events logged by the RNDR program, with the discriminator of each event
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RNDREvent {
+    // 0
+    /// Work of a node was credited when a job it completed was paid
+    WorkCredited(WorkCredited),
+
+    // 1
+    /// Reward multiplier of the stake tier of a node was applied to the work it recorded in an epoch
+    RewardMultiplierApplied(RewardMultiplierApplied),
+
+    // 2
+    /// A field of the config was changed
+    ConfigChanged(ConfigChanged),
+
+    // 3
+    /// An administrative action was queued behind the timelock
+    ActionQueued(ActionQueued),
+
+    // 4
+    /// Rotation of the owner of an escrow was scheduled
+    OwnerRotationScheduled(OwnerRotationScheduled),
+
+    // 5
+    /// A reward pool was funded
+    RewardPoolFunded(RewardPoolFunded),
+
+    // 6
+    /// A Merkle root of node rewards was published for an epoch
+    MerkleRootPublished(MerkleRootPublished),
+
+    // 7
+    /// A node claimed its leaf of a Merkle distribution
+    MerkleRewardClaimed(MerkleRewardClaimed),
+
+    // 8
+    /// The emission schedule of an escrow was set
+    EmissionScheduleSet(EmissionScheduleSet),
+
+    // 9
+    /// Scheduled rewards were emitted into the reward pool of an epoch
+    RewardsEmitted(RewardsEmitted),
+
+    // 10
+    /// A node staked tokens
+    Staked(Staked),
+
+    // 11
+    /// A node unstaked tokens
+    Unstaked(Unstaked),
+
+    // 12
+    /// A node set whether its epoch rewards are compounded
+    AutoCompoundSet(AutoCompoundSet),
+
+    // 13
+    /// Epoch rewards of a node were compounded into its stake
+    RewardsCompounded(RewardsCompounded),
+
+    // 14
+    /// Tokens were staked in the stake pool for receipt tokens
+    LiquidStaked(LiquidStaked),
+
+    // 15
+    /// Receipt tokens were redeemed from the stake pool
+    LiquidUnstaked(LiquidUnstaked),
+
+    // 16
+    /// Rewards were deposited into the stake pool
+    StakeRewardsDeposited(StakeRewardsDeposited),
+
+    // 17
+    /// The crank bounty of an escrow was set
+    CrankBountySet(CrankBountySet),
+
+    // 18
+    /// The burn cap of a treasury was set
+    TreasuryBurnCapSet(TreasuryBurnCapSet),
+
+    // 19
+    /// Tokens of a treasury were burned
+    TreasuryBurned(TreasuryBurned),
+
+    // 20
+    /// A job was funded with a signed voucher
+    VoucherUsed(VoucherUsed),
+
+    // 21
+    /// A job authority registered a session key
+    SessionKeyRegistered(SessionKeyRegistered),
+
+    // 22
+    /// An escrow owner approved a delegate
+    DelegateApproved(DelegateApproved),
+
+    // 23
+    /// An escrow owner revoked a delegate
+    DelegateRevoked(DelegateRevoked),
+
+    // 24
+    /// A recurring job subscription was created
+    SubscriptionCreated(SubscriptionCreated),
+
+    // 25
+    /// A subscription funded its job for a period
+    SubscriptionProcessed(SubscriptionProcessed),
+
+    // 26
+    /// A job was cancelled
+    JobCancelled(JobCancelled),
+
+    // 27
+    /// Transfer of the authority of a job was started
+    JobAuthorityTransferStarted(JobAuthorityTransferStarted),
+
+    // 28
+    /// Transfer of the authority of a job was accepted
+    JobAuthorityTransferred(JobAuthorityTransferred),
+
+    // 29
+    /// A job bundle was created
+    JobBundleCreated(JobBundleCreated),
+
+    // 30
+    /// Jobs of a bundle were funded
+    JobBundleFunded(JobBundleFunded),
+
+    // 31
+    /// Open jobs of a bundle were cancelled
+    JobBundleCancelled(JobBundleCancelled),
+
+    // 32
+    /// Statuses of the jobs of a bundle were aggregated
+    JobBundleAggregated(JobBundleAggregated),
+
+    // 33
+    /// Every job of a bundle was completed or cancelled
+    JobBundleCompleted(JobBundleCompleted),
+
+    // 34
+    /// A job tree was created
+    JobTreeCreated(JobTreeCreated),
+
+    // 35
+    /// A compressed job was appended to a job tree
+    CompressedJobAppended(CompressedJobAppended),
+
+    // 36
+    /// A compressed job was assigned to a node
+    CompressedJobReplaced(CompressedJobReplaced),
+
+    // 37
+    /// A compressed job was paid to its node
+    CompressedJobPaid(CompressedJobPaid),
+
+    // 38
+    /// An open compressed job was cancelled
+    CompressedJobCancelled(CompressedJobCancelled),
+
+    // 39
+    /// The owner of an escrow was rotated by a queued action
+    OwnerRotated(OwnerRotated),
+
+    // 40
+    /// A queued administrative action was cancelled
+    ActionCancelled(ActionCancelled),
+
+    // 41
+    /// The recovery council of an escrow was set
+    RecoveryCouncilSet(RecoveryCouncilSet),
+
+    // 42
+    /// Guardians approved the recovery of an escrow to a new owner
+    RecoveryStarted(RecoveryStarted),
+
+    // 43
+    /// The owner of an escrow was recovered
+    EscrowOwnerRecovered(EscrowOwnerRecovered),
+
+    // 44
+    /// A node claimed its share of an epoch reward pool
+    EpochRewardClaimed(EpochRewardClaimed),
+
+    // 45
+    /// A crank bounty was paid
+    CrankBountyPaid(CrankBountyPaid),
+
+    // 46
+    /// Work of a node was recorded for an epoch
+    EpochWorkRecorded(EpochWorkRecorded),
+
+    // 47
+    /// The off-chain metadata of a job was set
+    JobMetadataUpdated(JobMetadataUpdated),
+
+    // 48
+    /// The pooled tokens of an escrow were moved to another token account
+    EscrowTokenAccountSet(EscrowTokenAccountSet),
+
+    // 49
+    /// The migration of an escrow to a new token mint was begun
+    MintMigrationBegun(MintMigrationBegun),
+
+    // 50
+    /// The tokens of an escrow or isolated job were converted to the new token mint of a migration
+    MintMigrationTokensConverted(MintMigrationTokensConverted),
+
+    // 51
+    /// Jobs were migrated to the new token mint of a migration
+    JobsMigrated(JobsMigrated),
+
+    // 52
+    /// The priority of a job was raised
+    JobPriorityRaised(JobPriorityRaised),
+
+    // 53
+    /// The exchange rate a job is settled at was recorded
+    JobExchangeRateSet(JobExchangeRateSet),
+
+    // 54
+    /// A job was funded at an oracle price
+    JobFundedAtOraclePrice(JobFundedAtOraclePrice),
+
+    // 55
+    /// A job was frozen under a legal hold
+    JobFrozen(JobFrozen),
+
+    // 56
+    /// A job's legal hold was released
+    JobUnfrozen(JobUnfrozen),
+
+    // 57
+    /// The incident mode of the config was synced to an escrow
+    IncidentModeSet(IncidentModeSet),
+
+    // 58
+    /// A cold wallet was registered for a treasury, cancelling its scheduled sweep
+    ColdWalletRegistered(ColdWalletRegistered),
+
+    // 59
+    /// A sweep of a treasury to its cold wallet was scheduled
+    TreasurySweepScheduled(TreasurySweepScheduled),
+
+    // 60
+    /// Tokens of a treasury were swept to its cold wallet
+    TreasurySwept(TreasurySwept),
+
+    // 61
+    /// The scheduled sweep of a treasury was cancelled by the guardian
+    TreasurySweepCancelled(TreasurySweepCancelled),
+
+    // 62
+    /// A reconciliation found an escrow's token account holding fewer tokens than the escrow tracks
+    EscrowDiscrepancyFound(EscrowDiscrepancyFound),
+
+    // 63
+    /// The tokens of a job funded in another token were settled at its recorded exchange rate
+    JobSettledAtExchangeRate(JobSettledAtExchangeRate),
+}
+
+impl RNDREvent {
+    /// Unpacks an event from its logged bytes, rejecting events of other schema versions
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        let (version, rest) = RNDRInstruction::unpack_u8(input)?;
+        if version != EVENT_VERSION {
+            msg!("Event version is not supported");
+            return Err(RNDRError::InstructionUnpackError.into());
+        }
+        let (discriminator, rest) = RNDRInstruction::unpack_u8(rest)?;
+        Ok(match discriminator {
+            0 => {
+                let (amount, rest) = RNDRInstruction::unpack_u64(rest)?;
+                let (node, rest) = RNDRInstruction::unpack_pubkey(rest)?;
+                let (epoch, _rest) = RNDRInstruction::unpack_u64(rest)?;
+                Self::WorkCredited(WorkCredited {
+                    amount,
+                    node,
+                    epoch,
+                })
+            }
+            1 => {
+                let (multiplier_bps, _rest) = RNDRInstruction::unpack_u16(rest)?;
+                Self::RewardMultiplierApplied(RewardMultiplierApplied { multiplier_bps })
+            }
+            2 => {
+                let (previous, rest) = RNDRInstruction::unpack_config_change(rest)?;
+                let (change, _rest) = RNDRInstruction::unpack_config_change(rest)?;
+                Self::ConfigChanged(ConfigChanged { previous, change })
+            }
+            3 => {
+                let (action, rest) = RNDRInstruction::unpack_admin_action(rest)?;
+                let (executable_at, _rest) = RNDRInstruction::unpack_i64(rest)?;
+                Self::ActionQueued(ActionQueued {
+                    action,
+                    executable_at,
+                })
+            }
+            4 => {
+                let (escrow, rest) = RNDRInstruction::unpack_pubkey(rest)?;
+                let (previous_owner, rest) = RNDRInstruction::unpack_pubkey(rest)?;
+                let (new_owner, rest) = RNDRInstruction::unpack_pubkey(rest)?;
+                let (executable_at, _rest) = RNDRInstruction::unpack_i64(rest)?;
+                Self::OwnerRotationScheduled(OwnerRotationScheduled {
+                    escrow,
+                    previous_owner,
+                    new_owner,
+                    executable_at,
+                })
+            }
+            5 => {
+                let (amount, rest) = RNDRInstruction::unpack_u64(rest)?;
+                let (epoch, _rest) = RNDRInstruction::unpack_u64(rest)?;
+                Self::RewardPoolFunded(RewardPoolFunded { amount, epoch })
+            }
+            6 => {
+                let (amount, rest) = RNDRInstruction::unpack_u64(rest)?;
+                let (node_count, rest) = RNDRInstruction::unpack_u32(rest)?;
+                let (epoch, _rest) = RNDRInstruction::unpack_u64(rest)?;
+                Self::MerkleRootPublished(MerkleRootPublished {
+                    amount,
+                    node_count,
+                    epoch,
+                })
+            }
+            7 => {
+                let (amount, rest) = RNDRInstruction::unpack_u64(rest)?;
+                let (index, rest) = RNDRInstruction::unpack_u32(rest)?;
+                let (epoch, _rest) = RNDRInstruction::unpack_u64(rest)?;
+                Self::MerkleRewardClaimed(MerkleRewardClaimed {
+                    amount,
+                    index,
+                    epoch,
+                })
+            }
+            8 => {
+                let (initial_amount, rest) = RNDRInstruction::unpack_u64(rest)?;
+                let (start_epoch, rest) = RNDRInstruction::unpack_u64(rest)?;
+                let (decay_bps, _rest) = RNDRInstruction::unpack_u16(rest)?;
+                Self::EmissionScheduleSet(EmissionScheduleSet {
+                    initial_amount,
+                    start_epoch,
+                    decay_bps,
+                })
+            }
+            9 => {
+                let (amount, rest) = RNDRInstruction::unpack_u64(rest)?;
+                let (epoch, _rest) = RNDRInstruction::unpack_u64(rest)?;
+                Self::RewardsEmitted(RewardsEmitted { amount, epoch })
+            }
+            10 => {
+                let (amount, _rest) = RNDRInstruction::unpack_u64(rest)?;
+                Self::Staked(Staked { amount })
+            }
+            11 => {
+                let (amount, _rest) = RNDRInstruction::unpack_u64(rest)?;
+                Self::Unstaked(Unstaked { amount })
+            }
+            12 => {
+                let (auto_compound, _rest) = Self::unpack_bool(rest)?;
+                Self::AutoCompoundSet(AutoCompoundSet { auto_compound })
+            }
+            13 => {
+                let (reward, _rest) = RNDRInstruction::unpack_u64(rest)?;
+                Self::RewardsCompounded(RewardsCompounded { reward })
+            }
+            14 => {
+                let (amount, rest) = RNDRInstruction::unpack_u64(rest)?;
+                let (receipt_amount, _rest) = RNDRInstruction::unpack_u64(rest)?;
+                Self::LiquidStaked(LiquidStaked {
+                    amount,
+                    receipt_amount,
+                })
+            }
+            15 => {
+                let (amount, rest) = RNDRInstruction::unpack_u64(rest)?;
+                let (receipt_amount, _rest) = RNDRInstruction::unpack_u64(rest)?;
+                Self::LiquidUnstaked(LiquidUnstaked {
+                    amount,
+                    receipt_amount,
+                })
+            }
+            16 => {
+                let (amount, _rest) = RNDRInstruction::unpack_u64(rest)?;
+                Self::StakeRewardsDeposited(StakeRewardsDeposited { amount })
+            }
+            17 => {
+                let (bounty, _rest) = RNDRInstruction::unpack_u64(rest)?;
+                Self::CrankBountySet(CrankBountySet { bounty })
+            }
+            18 => {
+                let (epoch_burn_cap, _rest) = RNDRInstruction::unpack_u64(rest)?;
+                Self::TreasuryBurnCapSet(TreasuryBurnCapSet { epoch_burn_cap })
+            }
+            19 => {
+                let (amount, rest) = RNDRInstruction::unpack_u64(rest)?;
+                let (epoch, _rest) = RNDRInstruction::unpack_u64(rest)?;
+                Self::TreasuryBurned(TreasuryBurned { amount, epoch })
+            }
+            20 => {
+                let (nonce, rest) = RNDRInstruction::unpack_u64(rest)?;
+                let (authority, rest) = RNDRInstruction::unpack_pubkey(rest)?;
+                let (job_id, _rest) = RNDRInstruction::unpack_u64(rest)?;
+                Self::VoucherUsed(VoucherUsed {
+                    nonce,
+                    authority,
+                    job_id,
+                })
+            }
+            21 => {
+                let (session_key, rest) = RNDRInstruction::unpack_pubkey(rest)?;
+                let (expiry_slot, rest) = RNDRInstruction::unpack_u64(rest)?;
+                let (instruction_mask, _rest) = RNDRInstruction::unpack_u64(rest)?;
+                Self::SessionKeyRegistered(SessionKeyRegistered {
+                    session_key,
+                    expiry_slot,
+                    instruction_mask,
+                })
+            }
+            22 => {
+                let (delegate, rest) = RNDRInstruction::unpack_pubkey(rest)?;
+                let (limit, rest) = RNDRInstruction::unpack_u64(rest)?;
+                let (expiry_slot, _rest) = RNDRInstruction::unpack_u64(rest)?;
+                Self::DelegateApproved(DelegateApproved {
+                    delegate,
+                    limit,
+                    expiry_slot,
+                })
+            }
+            23 => {
+                let (delegate, _rest) = RNDRInstruction::unpack_pubkey(rest)?;
+                Self::DelegateRevoked(DelegateRevoked { delegate })
+            }
+            24 => {
+                let (amount, rest) = RNDRInstruction::unpack_u64(rest)?;
+                let (period, rest) = RNDRInstruction::unpack_u64(rest)?;
+                let (job_id, _rest) = RNDRInstruction::unpack_u64(rest)?;
+                Self::SubscriptionCreated(SubscriptionCreated {
+                    amount,
+                    period,
+                    job_id,
+                })
+            }
+            25 => {
+                let (amount, rest) = RNDRInstruction::unpack_u64(rest)?;
+                let (job_id, rest) = RNDRInstruction::unpack_u64(rest)?;
+                let (next_slot, _rest) = RNDRInstruction::unpack_u64(rest)?;
+                Self::SubscriptionProcessed(SubscriptionProcessed {
+                    amount,
+                    job_id,
+                    next_slot,
+                })
+            }
+            26 => {
+                let (job, rest) = RNDRInstruction::unpack_pubkey(rest)?;
+                let (refund, rest) = RNDRInstruction::unpack_u64(rest)?;
+                let (fee, rest) = RNDRInstruction::unpack_u64(rest)?;
+                let (node, _rest) = RNDRInstruction::unpack_pubkey(rest)?;
+                Self::JobCancelled(JobCancelled {
+                    job,
+                    refund,
+                    fee,
+                    node,
+                })
+            }
+            27 => {
+                let (job, rest) = RNDRInstruction::unpack_pubkey(rest)?;
+                let (new_authority, _rest) = RNDRInstruction::unpack_pubkey(rest)?;
+                Self::JobAuthorityTransferStarted(JobAuthorityTransferStarted {
+                    job,
+                    new_authority,
+                })
+            }
+            28 => {
+                let (job, rest) = RNDRInstruction::unpack_pubkey(rest)?;
+                let (previous_authority, rest) = RNDRInstruction::unpack_pubkey(rest)?;
+                let (new_authority, _rest) = RNDRInstruction::unpack_pubkey(rest)?;
+                Self::JobAuthorityTransferred(JobAuthorityTransferred {
+                    job,
+                    previous_authority,
+                    new_authority,
+                })
+            }
+            29 => {
+                let (job_count, rest) = RNDRInstruction::unpack_u8(rest)?;
+                let (first_job_id, _rest) = RNDRInstruction::unpack_u64(rest)?;
+                Self::JobBundleCreated(JobBundleCreated {
+                    job_count,
+                    first_job_id,
+                })
+            }
+            30 => {
+                let (amount_per_job, rest) = RNDRInstruction::unpack_u64(rest)?;
+                let (count, rest) = RNDRInstruction::unpack_u8(rest)?;
+                let (first_job_id, _rest) = RNDRInstruction::unpack_u64(rest)?;
+                Self::JobBundleFunded(JobBundleFunded {
+                    amount_per_job,
+                    count,
+                    first_job_id,
+                })
+            }
+            31 => {
+                let (cancelled, rest) = RNDRInstruction::unpack_u8(rest)?;
+                let (refund, _rest) = RNDRInstruction::unpack_u64(rest)?;
+                Self::JobBundleCancelled(JobBundleCancelled { cancelled, refund })
+            }
+            32 => {
+                let (completed, rest) = RNDRInstruction::unpack_u8(rest)?;
+                let (cancelled, rest) = RNDRInstruction::unpack_u8(rest)?;
+                let (job_count, _rest) = RNDRInstruction::unpack_u8(rest)?;
+                Self::JobBundleAggregated(JobBundleAggregated {
+                    completed,
+                    cancelled,
+                    job_count,
+                })
+            }
+            33 => {
+                let (job_bundle, _rest) = RNDRInstruction::unpack_pubkey(rest)?;
+                Self::JobBundleCompleted(JobBundleCompleted { job_bundle })
+            }
+            34 => {
+                let (max_depth, _rest) = RNDRInstruction::unpack_u8(rest)?;
+                Self::JobTreeCreated(JobTreeCreated { max_depth })
+            }
+            35 => {
+                let (id, rest) = RNDRInstruction::unpack_u64(rest)?;
+                let (amount, _rest) = RNDRInstruction::unpack_u64(rest)?;
+                Self::CompressedJobAppended(CompressedJobAppended { id, amount })
+            }
+            36 => {
+                let (id, rest) = RNDRInstruction::unpack_u64(rest)?;
+                let (node, _rest) = RNDRInstruction::unpack_pubkey(rest)?;
+                Self::CompressedJobReplaced(CompressedJobReplaced { id, node })
+            }
+            37 => {
+                let (id, rest) = RNDRInstruction::unpack_u64(rest)?;
+                let (amount, rest) = RNDRInstruction::unpack_u64(rest)?;
+                let (node, _rest) = RNDRInstruction::unpack_pubkey(rest)?;
+                Self::CompressedJobPaid(CompressedJobPaid { id, amount, node })
+            }
+            38 => {
+                let (id, rest) = RNDRInstruction::unpack_u64(rest)?;
+                let (amount, _rest) = RNDRInstruction::unpack_u64(rest)?;
+                Self::CompressedJobCancelled(CompressedJobCancelled { id, amount })
+            }
+            39 => {
+                let (escrow, rest) = RNDRInstruction::unpack_pubkey(rest)?;
+                let (previous_owner, rest) = RNDRInstruction::unpack_pubkey(rest)?;
+                let (new_owner, _rest) = RNDRInstruction::unpack_pubkey(rest)?;
+                Self::OwnerRotated(OwnerRotated {
+                    escrow,
+                    previous_owner,
+                    new_owner,
+                })
+            }
+            40 => {
+                let (action, _rest) = RNDRInstruction::unpack_admin_action(rest)?;
+                Self::ActionCancelled(ActionCancelled { action })
+            }
+            41 => {
+                let (threshold, rest) = RNDRInstruction::unpack_u8(rest)?;
+                let (guardian_count, _rest) = RNDRInstruction::unpack_u8(rest)?;
+                Self::RecoveryCouncilSet(RecoveryCouncilSet {
+                    threshold,
+                    guardian_count,
+                })
+            }
+            42 => {
+                let (new_owner, rest) = RNDRInstruction::unpack_pubkey(rest)?;
+                let (executable_at, _rest) = RNDRInstruction::unpack_i64(rest)?;
+                Self::RecoveryStarted(RecoveryStarted {
+                    new_owner,
+                    executable_at,
+                })
+            }
+            43 => {
+                let (owner, _rest) = RNDRInstruction::unpack_pubkey(rest)?;
+                Self::EscrowOwnerRecovered(EscrowOwnerRecovered { owner })
+            }
+            44 => {
+                let (reward, rest) = RNDRInstruction::unpack_u64(rest)?;
+                let (epoch, _rest) = RNDRInstruction::unpack_u64(rest)?;
+                Self::EpochRewardClaimed(EpochRewardClaimed { reward, epoch })
+            }
+            45 => {
+                let (bounty, rest) = RNDRInstruction::unpack_u64(rest)?;
+                let (recipient, _rest) = RNDRInstruction::unpack_pubkey(rest)?;
+                Self::CrankBountyPaid(CrankBountyPaid { bounty, recipient })
+            }
+            46 => {
+                let (work, rest) = RNDRInstruction::unpack_u64(rest)?;
+                let (epoch, _rest) = RNDRInstruction::unpack_u64(rest)?;
+                Self::EpochWorkRecorded(EpochWorkRecorded { work, epoch })
+            }
+            47 => {
+                let (job, rest) = RNDRInstruction::unpack_pubkey(rest)?;
+                let (metadata, _rest) = RNDRInstruction::unpack_job_metadata(rest)?;
+                Self::JobMetadataUpdated(JobMetadataUpdated { job, metadata })
+            }
+            48 => {
+                let (previous_token_account, rest) = RNDRInstruction::unpack_pubkey(rest)?;
+                let (token_account, rest) = RNDRInstruction::unpack_pubkey(rest)?;
+                let (amount, _rest) = RNDRInstruction::unpack_u64(rest)?;
+                Self::EscrowTokenAccountSet(EscrowTokenAccountSet {
+                    previous_token_account,
+                    token_account,
+                    amount,
+                })
+            }
+            49 => {
+                let (mint_migration, rest) = RNDRInstruction::unpack_pubkey(rest)?;
+                let (new_token_mint, rest) = RNDRInstruction::unpack_pubkey(rest)?;
+                let (rate_numerator, rest) = RNDRInstruction::unpack_u64(rest)?;
+                let (rate_denominator, _rest) = RNDRInstruction::unpack_u64(rest)?;
+                Self::MintMigrationBegun(MintMigrationBegun {
+                    mint_migration,
+                    new_token_mint,
+                    rate_numerator,
+                    rate_denominator,
+                })
+            }
+            50 => {
+                let (mint_migration, rest) = RNDRInstruction::unpack_pubkey(rest)?;
+                let (token_account, rest) = RNDRInstruction::unpack_pubkey(rest)?;
+                let (amount, rest) = RNDRInstruction::unpack_u64(rest)?;
+                let (converted_amount, _rest) = RNDRInstruction::unpack_u64(rest)?;
+                Self::MintMigrationTokensConverted(MintMigrationTokensConverted {
+                    mint_migration,
+                    token_account,
+                    amount,
+                    converted_amount,
+                })
+            }
+            51 => {
+                let (mint_migration, rest) = RNDRInstruction::unpack_pubkey(rest)?;
+                let (count, rest) = RNDRInstruction::unpack_u64(rest)?;
+                let (jobs_migrated, _rest) = RNDRInstruction::unpack_u64(rest)?;
+                Self::JobsMigrated(JobsMigrated {
+                    mint_migration,
+                    count,
+                    jobs_migrated,
+                })
+            }
+            52 => {
+                let (job, rest) = RNDRInstruction::unpack_pubkey(rest)?;
+                let (priority, rest) = RNDRInstruction::unpack_u8(rest)?;
+                let (surcharge, _rest) = RNDRInstruction::unpack_u64(rest)?;
+                Self::JobPriorityRaised(JobPriorityRaised {
+                    job,
+                    priority,
+                    surcharge,
+                })
+            }
+            53 => {
+                let (job, rest) = RNDRInstruction::unpack_pubkey(rest)?;
+                let (rate_numerator, rest) = RNDRInstruction::unpack_u64(rest)?;
+                let (rate_denominator, rest) = RNDRInstruction::unpack_u64(rest)?;
+                let (oracle_slot, _rest) = RNDRInstruction::unpack_u64(rest)?;
+                Self::JobExchangeRateSet(JobExchangeRateSet {
+                    job,
+                    rate_numerator,
+                    rate_denominator,
+                    oracle_slot,
+                })
+            }
+            54 => {
+                let (job, rest) = RNDRInstruction::unpack_pubkey(rest)?;
+                let (value, rest) = RNDRInstruction::unpack_u64(rest)?;
+                let (amount, rest) = RNDRInstruction::unpack_u64(rest)?;
+                let (price, rest) = RNDRInstruction::unpack_u64(rest)?;
+                let (oracle_slot, _rest) = RNDRInstruction::unpack_u64(rest)?;
+                Self::JobFundedAtOraclePrice(JobFundedAtOraclePrice {
+                    job,
+                    value,
+                    amount,
+                    price,
+                    oracle_slot,
+                })
+            }
+            55 => {
+                let (job, _rest) = RNDRInstruction::unpack_pubkey(rest)?;
+                Self::JobFrozen(JobFrozen { job })
+            }
+            56 => {
+                let (job, _rest) = RNDRInstruction::unpack_pubkey(rest)?;
+                Self::JobUnfrozen(JobUnfrozen { job })
+            }
+            57 => {
+                let (escrow, rest) = RNDRInstruction::unpack_pubkey(rest)?;
+                let (mode, _rest) = RNDRInstruction::unpack_u8(rest)?;
+                let mode = IncidentMode::try_from(mode).map_err(|_| {
+                    msg!("Incident mode cannot be unpacked");
+                    RNDRError::InstructionUnpackError
+                })?;
+                Self::IncidentModeSet(IncidentModeSet { escrow, mode })
+            }
+            58 => {
+                let (treasury, rest) = RNDRInstruction::unpack_pubkey(rest)?;
+                let (cold_wallet, _rest) = RNDRInstruction::unpack_pubkey(rest)?;
+                Self::ColdWalletRegistered(ColdWalletRegistered {
+                    treasury,
+                    cold_wallet,
+                })
+            }
+            59 => {
+                let (treasury, rest) = RNDRInstruction::unpack_pubkey(rest)?;
+                let (cold_wallet, rest) = RNDRInstruction::unpack_pubkey(rest)?;
+                let (amount, rest) = RNDRInstruction::unpack_u64(rest)?;
+                let (executable_at, _rest) = RNDRInstruction::unpack_i64(rest)?;
+                Self::TreasurySweepScheduled(TreasurySweepScheduled {
+                    treasury,
+                    cold_wallet,
+                    amount,
+                    executable_at,
+                })
+            }
+            60 => {
+                let (treasury, rest) = RNDRInstruction::unpack_pubkey(rest)?;
+                let (cold_wallet, rest) = RNDRInstruction::unpack_pubkey(rest)?;
+                let (amount, _rest) = RNDRInstruction::unpack_u64(rest)?;
+                Self::TreasurySwept(TreasurySwept {
+                    treasury,
+                    cold_wallet,
+                    amount,
+                })
+            }
+            61 => {
+                let (treasury, rest) = RNDRInstruction::unpack_pubkey(rest)?;
+                let (amount, _rest) = RNDRInstruction::unpack_u64(rest)?;
+                Self::TreasurySweepCancelled(TreasurySweepCancelled { treasury, amount })
+            }
+            62 => {
+                let (escrow, rest) = RNDRInstruction::unpack_pubkey(rest)?;
+                let (balance, rest) = RNDRInstruction::unpack_u64(rest)?;
+                let (tracked, _rest) = RNDRInstruction::unpack_u64(rest)?;
+                Self::EscrowDiscrepancyFound(EscrowDiscrepancyFound {
+                    escrow,
+                    balance,
+                    tracked,
+                })
+            }
+            63 => {
+                let (job, rest) = RNDRInstruction::unpack_pubkey(rest)?;
+                let (amount, rest) = RNDRInstruction::unpack_u64(rest)?;
+                let (rndr_value, _rest) = RNDRInstruction::unpack_u64(rest)?;
+                Self::JobSettledAtExchangeRate(JobSettledAtExchangeRate {
+                    job,
+                    amount,
+                    rndr_value,
+                })
+            }
+            _ => {
+                msg!("Event cannot be unpacked");
+                return Err(RNDRError::InstructionUnpackError.into());
+            }
+        })
+    }
+
+    fn unpack_bool(input: &[u8]) -> Result<(bool, &[u8]), ProgramError> {
+        let (value, rest) = RNDRInstruction::unpack_u8(input)?;
+        let value = match value {
+            0 => false,
+            1 => true,
+            _ => {
+                msg!("bool cannot be unpacked");
+                return Err(RNDRError::InstructionUnpackError.into());
+            }
+        };
+        Ok((value, rest))
+    }
+
+    /// Discriminator of the event, the byte after the schema version
+    pub fn discriminator(&self) -> u8 {
+        self.pack()[1]
+    }
+
+    /// Packs an event into the bytes that are logged, starting with the schema version
+    pub fn pack(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(size_of::<Self>());
+        buf.push(EVENT_VERSION);
+        match self {
+            Self::WorkCredited(WorkCredited {
+                amount,
+                node,
+                epoch,
+            }) => {
+                buf.push(0);
+                buf.extend_from_slice(&amount.to_le_bytes());
+                buf.extend_from_slice(node.as_ref());
+                buf.extend_from_slice(&epoch.to_le_bytes());
+            }
+            Self::RewardMultiplierApplied(RewardMultiplierApplied { multiplier_bps }) => {
+                buf.push(1);
+                buf.extend_from_slice(&multiplier_bps.to_le_bytes());
+            }
+            Self::ConfigChanged(ConfigChanged { previous, change }) => {
+                buf.push(2);
+                RNDRInstruction::pack_config_change(previous, &mut buf);
+                RNDRInstruction::pack_config_change(change, &mut buf);
+            }
+            Self::ActionQueued(ActionQueued {
+                action,
+                executable_at,
+            }) => {
+                buf.push(3);
+                RNDRInstruction::pack_admin_action(action, &mut buf);
+                buf.extend_from_slice(&executable_at.to_le_bytes());
+            }
+            Self::OwnerRotationScheduled(OwnerRotationScheduled {
+                escrow,
+                previous_owner,
+                new_owner,
+                executable_at,
+            }) => {
+                buf.push(4);
+                buf.extend_from_slice(escrow.as_ref());
+                buf.extend_from_slice(previous_owner.as_ref());
+                buf.extend_from_slice(new_owner.as_ref());
+                buf.extend_from_slice(&executable_at.to_le_bytes());
+            }
+            Self::RewardPoolFunded(RewardPoolFunded { amount, epoch }) => {
+                buf.push(5);
+                buf.extend_from_slice(&amount.to_le_bytes());
+                buf.extend_from_slice(&epoch.to_le_bytes());
+            }
+            Self::MerkleRootPublished(MerkleRootPublished {
+                amount,
+                node_count,
+                epoch,
+            }) => {
+                buf.push(6);
+                buf.extend_from_slice(&amount.to_le_bytes());
+                buf.extend_from_slice(&node_count.to_le_bytes());
+                buf.extend_from_slice(&epoch.to_le_bytes());
+            }
+            Self::MerkleRewardClaimed(MerkleRewardClaimed {
+                amount,
+                index,
+                epoch,
+            }) => {
+                buf.push(7);
+                buf.extend_from_slice(&amount.to_le_bytes());
+                buf.extend_from_slice(&index.to_le_bytes());
+                buf.extend_from_slice(&epoch.to_le_bytes());
+            }
+            Self::EmissionScheduleSet(EmissionScheduleSet {
+                initial_amount,
+                start_epoch,
+                decay_bps,
+            }) => {
+                buf.push(8);
+                buf.extend_from_slice(&initial_amount.to_le_bytes());
+                buf.extend_from_slice(&start_epoch.to_le_bytes());
+                buf.extend_from_slice(&decay_bps.to_le_bytes());
+            }
+            Self::RewardsEmitted(RewardsEmitted { amount, epoch }) => {
+                buf.push(9);
+                buf.extend_from_slice(&amount.to_le_bytes());
+                buf.extend_from_slice(&epoch.to_le_bytes());
+            }
+            Self::Staked(Staked { amount }) => {
+                buf.push(10);
+                buf.extend_from_slice(&amount.to_le_bytes());
+            }
+            Self::Unstaked(Unstaked { amount }) => {
+                buf.push(11);
+                buf.extend_from_slice(&amount.to_le_bytes());
+            }
+            Self::AutoCompoundSet(AutoCompoundSet { auto_compound }) => {
+                buf.push(12);
+                buf.push(u8::from(*auto_compound));
+            }
+            Self::RewardsCompounded(RewardsCompounded { reward }) => {
+                buf.push(13);
+                buf.extend_from_slice(&reward.to_le_bytes());
+            }
+            Self::LiquidStaked(LiquidStaked {
+                amount,
+                receipt_amount,
+            }) => {
+                buf.push(14);
+                buf.extend_from_slice(&amount.to_le_bytes());
+                buf.extend_from_slice(&receipt_amount.to_le_bytes());
+            }
+            Self::LiquidUnstaked(LiquidUnstaked {
+                amount,
+                receipt_amount,
+            }) => {
+                buf.push(15);
+                buf.extend_from_slice(&amount.to_le_bytes());
+                buf.extend_from_slice(&receipt_amount.to_le_bytes());
+            }
+            Self::StakeRewardsDeposited(StakeRewardsDeposited { amount }) => {
+                buf.push(16);
+                buf.extend_from_slice(&amount.to_le_bytes());
+            }
+            Self::CrankBountySet(CrankBountySet { bounty }) => {
+                buf.push(17);
+                buf.extend_from_slice(&bounty.to_le_bytes());
+            }
+            Self::TreasuryBurnCapSet(TreasuryBurnCapSet { epoch_burn_cap }) => {
+                buf.push(18);
+                buf.extend_from_slice(&epoch_burn_cap.to_le_bytes());
+            }
+            Self::TreasuryBurned(TreasuryBurned { amount, epoch }) => {
+                buf.push(19);
+                buf.extend_from_slice(&amount.to_le_bytes());
+                buf.extend_from_slice(&epoch.to_le_bytes());
+            }
+            Self::VoucherUsed(VoucherUsed {
+                nonce,
+                authority,
+                job_id,
+            }) => {
+                buf.push(20);
+                buf.extend_from_slice(&nonce.to_le_bytes());
+                buf.extend_from_slice(authority.as_ref());
+                buf.extend_from_slice(&job_id.to_le_bytes());
+            }
+            Self::SessionKeyRegistered(SessionKeyRegistered {
+                session_key,
+                expiry_slot,
+                instruction_mask,
+            }) => {
+                buf.push(21);
+                buf.extend_from_slice(session_key.as_ref());
+                buf.extend_from_slice(&expiry_slot.to_le_bytes());
+                buf.extend_from_slice(&instruction_mask.to_le_bytes());
+            }
+            Self::DelegateApproved(DelegateApproved {
+                delegate,
+                limit,
+                expiry_slot,
+            }) => {
+                buf.push(22);
+                buf.extend_from_slice(delegate.as_ref());
+                buf.extend_from_slice(&limit.to_le_bytes());
+                buf.extend_from_slice(&expiry_slot.to_le_bytes());
+            }
+            Self::DelegateRevoked(DelegateRevoked { delegate }) => {
+                buf.push(23);
+                buf.extend_from_slice(delegate.as_ref());
+            }
+            Self::SubscriptionCreated(SubscriptionCreated {
+                amount,
+                period,
+                job_id,
+            }) => {
+                buf.push(24);
+                buf.extend_from_slice(&amount.to_le_bytes());
+                buf.extend_from_slice(&period.to_le_bytes());
+                buf.extend_from_slice(&job_id.to_le_bytes());
+            }
+            Self::SubscriptionProcessed(SubscriptionProcessed {
+                amount,
+                job_id,
+                next_slot,
+            }) => {
+                buf.push(25);
+                buf.extend_from_slice(&amount.to_le_bytes());
+                buf.extend_from_slice(&job_id.to_le_bytes());
+                buf.extend_from_slice(&next_slot.to_le_bytes());
+            }
+            Self::JobCancelled(JobCancelled {
+                job,
+                refund,
+                fee,
+                node,
+            }) => {
+                buf.push(26);
+                buf.extend_from_slice(job.as_ref());
+                buf.extend_from_slice(&refund.to_le_bytes());
+                buf.extend_from_slice(&fee.to_le_bytes());
+                buf.extend_from_slice(node.as_ref());
+            }
+            Self::JobAuthorityTransferStarted(JobAuthorityTransferStarted {
+                job,
+                new_authority,
+            }) => {
+                buf.push(27);
+                buf.extend_from_slice(job.as_ref());
+                buf.extend_from_slice(new_authority.as_ref());
+            }
+            Self::JobAuthorityTransferred(JobAuthorityTransferred {
+                job,
+                previous_authority,
+                new_authority,
+            }) => {
+                buf.push(28);
+                buf.extend_from_slice(job.as_ref());
+                buf.extend_from_slice(previous_authority.as_ref());
+                buf.extend_from_slice(new_authority.as_ref());
+            }
+            Self::JobBundleCreated(JobBundleCreated {
+                job_count,
+                first_job_id,
+            }) => {
+                buf.push(29);
+                buf.push(*job_count);
+                buf.extend_from_slice(&first_job_id.to_le_bytes());
+            }
+            Self::JobBundleFunded(JobBundleFunded {
+                amount_per_job,
+                count,
+                first_job_id,
+            }) => {
+                buf.push(30);
+                buf.extend_from_slice(&amount_per_job.to_le_bytes());
+                buf.push(*count);
+                buf.extend_from_slice(&first_job_id.to_le_bytes());
+            }
+            Self::JobBundleCancelled(JobBundleCancelled { cancelled, refund }) => {
+                buf.push(31);
+                buf.push(*cancelled);
+                buf.extend_from_slice(&refund.to_le_bytes());
+            }
+            Self::JobBundleAggregated(JobBundleAggregated {
+                completed,
+                cancelled,
+                job_count,
+            }) => {
+                buf.push(32);
+                buf.push(*completed);
+                buf.push(*cancelled);
+                buf.push(*job_count);
+            }
+            Self::JobBundleCompleted(JobBundleCompleted { job_bundle }) => {
+                buf.push(33);
+                buf.extend_from_slice(job_bundle.as_ref());
+            }
+            Self::JobTreeCreated(JobTreeCreated { max_depth }) => {
+                buf.push(34);
+                buf.push(*max_depth);
+            }
+            Self::CompressedJobAppended(CompressedJobAppended { id, amount }) => {
+                buf.push(35);
+                buf.extend_from_slice(&id.to_le_bytes());
+                buf.extend_from_slice(&amount.to_le_bytes());
+            }
+            Self::CompressedJobReplaced(CompressedJobReplaced { id, node }) => {
+                buf.push(36);
+                buf.extend_from_slice(&id.to_le_bytes());
+                buf.extend_from_slice(node.as_ref());
+            }
+            Self::CompressedJobPaid(CompressedJobPaid { id, amount, node }) => {
+                buf.push(37);
+                buf.extend_from_slice(&id.to_le_bytes());
+                buf.extend_from_slice(&amount.to_le_bytes());
+                buf.extend_from_slice(node.as_ref());
+            }
+            Self::CompressedJobCancelled(CompressedJobCancelled { id, amount }) => {
+                buf.push(38);
+                buf.extend_from_slice(&id.to_le_bytes());
+                buf.extend_from_slice(&amount.to_le_bytes());
+            }
+            Self::OwnerRotated(OwnerRotated {
+                escrow,
+                previous_owner,
+                new_owner,
+            }) => {
+                buf.push(39);
+                buf.extend_from_slice(escrow.as_ref());
+                buf.extend_from_slice(previous_owner.as_ref());
+                buf.extend_from_slice(new_owner.as_ref());
+            }
+            Self::ActionCancelled(ActionCancelled { action }) => {
+                buf.push(40);
+                RNDRInstruction::pack_admin_action(action, &mut buf);
+            }
+            Self::RecoveryCouncilSet(RecoveryCouncilSet {
+                threshold,
+                guardian_count,
+            }) => {
+                buf.push(41);
+                buf.push(*threshold);
+                buf.push(*guardian_count);
+            }
+            Self::RecoveryStarted(RecoveryStarted {
+                new_owner,
+                executable_at,
+            }) => {
+                buf.push(42);
+                buf.extend_from_slice(new_owner.as_ref());
+                buf.extend_from_slice(&executable_at.to_le_bytes());
+            }
+            Self::EscrowOwnerRecovered(EscrowOwnerRecovered { owner }) => {
+                buf.push(43);
+                buf.extend_from_slice(owner.as_ref());
+            }
+            Self::EpochRewardClaimed(EpochRewardClaimed { reward, epoch }) => {
+                buf.push(44);
+                buf.extend_from_slice(&reward.to_le_bytes());
+                buf.extend_from_slice(&epoch.to_le_bytes());
+            }
+            Self::CrankBountyPaid(CrankBountyPaid { bounty, recipient }) => {
+                buf.push(45);
+                buf.extend_from_slice(&bounty.to_le_bytes());
+                buf.extend_from_slice(recipient.as_ref());
+            }
+            Self::EpochWorkRecorded(EpochWorkRecorded { work, epoch }) => {
+                buf.push(46);
+                buf.extend_from_slice(&work.to_le_bytes());
+                buf.extend_from_slice(&epoch.to_le_bytes());
+            }
+            Self::JobMetadataUpdated(JobMetadataUpdated { job, metadata }) => {
+                buf.push(47);
+                buf.extend_from_slice(job.as_ref());
+                RNDRInstruction::pack_job_metadata(metadata, &mut buf);
+            }
+            Self::EscrowTokenAccountSet(EscrowTokenAccountSet {
+                previous_token_account,
+                token_account,
+                amount,
+            }) => {
+                buf.push(48);
+                buf.extend_from_slice(previous_token_account.as_ref());
+                buf.extend_from_slice(token_account.as_ref());
+                buf.extend_from_slice(&amount.to_le_bytes());
+            }
+            Self::MintMigrationBegun(MintMigrationBegun {
+                mint_migration,
+                new_token_mint,
+                rate_numerator,
+                rate_denominator,
+            }) => {
+                buf.push(49);
+                buf.extend_from_slice(mint_migration.as_ref());
+                buf.extend_from_slice(new_token_mint.as_ref());
+                buf.extend_from_slice(&rate_numerator.to_le_bytes());
+                buf.extend_from_slice(&rate_denominator.to_le_bytes());
+            }
+            Self::MintMigrationTokensConverted(MintMigrationTokensConverted {
+                mint_migration,
+                token_account,
+                amount,
+                converted_amount,
+            }) => {
+                buf.push(50);
+                buf.extend_from_slice(mint_migration.as_ref());
+                buf.extend_from_slice(token_account.as_ref());
+                buf.extend_from_slice(&amount.to_le_bytes());
+                buf.extend_from_slice(&converted_amount.to_le_bytes());
+            }
+            Self::JobsMigrated(JobsMigrated {
+                mint_migration,
+                count,
+                jobs_migrated,
+            }) => {
+                buf.push(51);
+                buf.extend_from_slice(mint_migration.as_ref());
+                buf.extend_from_slice(&count.to_le_bytes());
+                buf.extend_from_slice(&jobs_migrated.to_le_bytes());
+            }
+            Self::JobPriorityRaised(JobPriorityRaised {
+                job,
+                priority,
+                surcharge,
+            }) => {
+                buf.push(52);
+                buf.extend_from_slice(job.as_ref());
+                buf.push(*priority);
+                buf.extend_from_slice(&surcharge.to_le_bytes());
+            }
+            Self::JobExchangeRateSet(JobExchangeRateSet {
+                job,
+                rate_numerator,
+                rate_denominator,
+                oracle_slot,
+            }) => {
+                buf.push(53);
+                buf.extend_from_slice(job.as_ref());
+                buf.extend_from_slice(&rate_numerator.to_le_bytes());
+                buf.extend_from_slice(&rate_denominator.to_le_bytes());
+                buf.extend_from_slice(&oracle_slot.to_le_bytes());
+            }
+            Self::JobFundedAtOraclePrice(JobFundedAtOraclePrice {
+                job,
+                value,
+                amount,
+                price,
+                oracle_slot,
+            }) => {
+                buf.push(54);
+                buf.extend_from_slice(job.as_ref());
+                buf.extend_from_slice(&value.to_le_bytes());
+                buf.extend_from_slice(&amount.to_le_bytes());
+                buf.extend_from_slice(&price.to_le_bytes());
+                buf.extend_from_slice(&oracle_slot.to_le_bytes());
+            }
+            Self::JobFrozen(JobFrozen { job }) => {
+                buf.push(55);
+                buf.extend_from_slice(job.as_ref());
+            }
+            Self::JobUnfrozen(JobUnfrozen { job }) => {
+                buf.push(56);
+                buf.extend_from_slice(job.as_ref());
+            }
+            Self::IncidentModeSet(IncidentModeSet { escrow, mode }) => {
+                buf.push(57);
+                buf.extend_from_slice(escrow.as_ref());
+                buf.push(u8::from(*mode));
+            }
+            Self::ColdWalletRegistered(ColdWalletRegistered {
+                treasury,
+                cold_wallet,
+            }) => {
+                buf.push(58);
+                buf.extend_from_slice(treasury.as_ref());
+                buf.extend_from_slice(cold_wallet.as_ref());
+            }
+            Self::TreasurySweepScheduled(TreasurySweepScheduled {
+                treasury,
+                cold_wallet,
+                amount,
+                executable_at,
+            }) => {
+                buf.push(59);
+                buf.extend_from_slice(treasury.as_ref());
+                buf.extend_from_slice(cold_wallet.as_ref());
+                buf.extend_from_slice(&amount.to_le_bytes());
+                buf.extend_from_slice(&executable_at.to_le_bytes());
+            }
+            Self::TreasurySwept(TreasurySwept {
+                treasury,
+                cold_wallet,
+                amount,
+            }) => {
+                buf.push(60);
+                buf.extend_from_slice(treasury.as_ref());
+                buf.extend_from_slice(cold_wallet.as_ref());
+                buf.extend_from_slice(&amount.to_le_bytes());
+            }
+            Self::TreasurySweepCancelled(TreasurySweepCancelled { treasury, amount }) => {
+                buf.push(61);
+                buf.extend_from_slice(treasury.as_ref());
+                buf.extend_from_slice(&amount.to_le_bytes());
+            }
+            Self::EscrowDiscrepancyFound(EscrowDiscrepancyFound {
+                escrow,
+                balance,
+                tracked,
+            }) => {
+                buf.push(62);
+                buf.extend_from_slice(escrow.as_ref());
+                buf.extend_from_slice(&balance.to_le_bytes());
+                buf.extend_from_slice(&tracked.to_le_bytes());
+            }
+            Self::JobSettledAtExchangeRate(JobSettledAtExchangeRate {
+                job,
+                amount,
+                rndr_value,
+            }) => {
+                buf.push(63);
+                buf.extend_from_slice(job.as_ref());
+                buf.extend_from_slice(&amount.to_le_bytes());
+                buf.extend_from_slice(&rndr_value.to_le_bytes());
+            }
+        }
+        buf
+    }
+
+    /// Logs the event as program data
+    pub fn emit(&self) {
+        sol_log_data(&[&self.pack()]);
+    }
+}
+
+/// Parse an event from the bytes logged by the program
+pub fn parse_event(input: &[u8]) -> Result<RNDREvent, ProgramError> {
+    RNDREvent::unpack(input)
+}
+
+/// Parse the events logged by a program from the log messages of a transaction, skipping data
+/// logged by programs it invoked or that invoked it. Failed transactions log the events of their
+/// instructions too, so only the logs of successful transactions should be indexed.
+#[cfg(feature = "client")]
+pub fn parse_logs(program_id: &Pubkey, logs: &[String]) -> Vec<RNDREvent> {
+    let program_id = program_id.to_string();
+    let mut invoked = vec![];
+    let mut events = vec![];
+    for log in logs {
+        if let Some(data) = log.strip_prefix("Program data: ") {
+            if invoked.last() == Some(&program_id.as_str()) {
+                if let Some(event) = STANDARD
+                    .decode(data)
+                    .ok()
+                    .and_then(|data| parse_event(&data).ok())
+                {
+                    events.push(event);
+                }
+            }
+        } else if let Some(log) = log.strip_prefix("Program ") {
+            let mut words = log.split(' ');
+            match (words.next(), words.next()) {
+                (Some(id), Some("invoke")) => invoked.push(id),
+                (Some(_), Some("success")) | (Some(_), Some("failed:")) => {
+                    invoked.pop();
+                }
+                _ => {}
+            }
+        }
+    }
+    events
+}
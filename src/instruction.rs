@@ -1,21 +1,72 @@
 //! Instruction types
 
 use {
-    crate::error::RNDRError,
+    crate::{
+        amount::RndrAmount,
+        bubblegum::{
+            find_tree_config_address, ACCOUNT_COMPRESSION_PROGRAM_ID, BUBBLEGUM_PROGRAM_ID,
+            NOOP_PROGRAM_ID,
+        },
+        error::RNDRError,
+        math::{Rate, RoundingPolicy},
+        pda::{
+            find_claim_bitmap_address, find_config_address, find_crank_vault_address,
+            find_delegate_allowance_address, find_dispute_address, find_emission_schedule_address,
+            find_epoch_work_address, find_escrow_address, find_escrow_address_with_id,
+            find_escrow_shard_address, find_job_address, find_job_bundle_address,
+            find_job_index_address, find_job_index_page_address, find_job_queue_address,
+            find_job_tree_address, find_merkle_distribution_address, find_mint_migration_address,
+            find_node_address, find_program_data_address, find_proposal_address,
+            find_queued_action_address, find_receipt_authority_address, find_recovery_address,
+            find_reward_pool_address, find_session_key_address, find_stake_pool_address,
+            find_stake_receipt_mint_address, find_stats_address, find_subscription_address,
+            find_treasury_address, find_verifier_registry_address, find_verifier_selection_address,
+            find_voucher_nonce_address, EscrowSeeds,
+        },
+        state::{
+            AdminAction, ClaimBitmap, CompressedJob, ConfigChange, EscrowShard, IncidentMode,
+            JobMetadata, JobStatus, RewardTier, Voucher, ESCROW_SHARD_COUNT,
+            MAX_JOB_METADATA_URI_LEN, MAX_MERKLE_PROOF_LEN, MAX_RECOVERY_GUARDIANS,
+        },
+    },
     solana_program::{
+        clock::{Slot, UnixTimestamp},
+        ed25519_program,
+        hash::HASH_BYTES,
         instruction::{AccountMeta, Instruction},
         msg,
         program_error::ProgramError,
         pubkey::{Pubkey, PUBKEY_BYTES},
-        system_program,
-        sysvar::rent,
+        system_program, sysvar,
     },
     spl_associated_token_account::get_associated_token_address,
-    std::{convert::TryInto, mem::size_of},
+    std::{
+        convert::{TryFrom, TryInto},
+        mem::size_of,
+    },
 };
 
+/// Maximum number of disbursements in a 'DisburseBatch' instruction, bounded by the number of
+/// accounts that fit in a versioned transaction that loads them from an address lookup table
+pub const MAX_DISBURSE_BATCH_LEN: usize = 64;
+
+/// Offset of the public key in the data of an ed25519 program instruction with one signature
+pub const ED25519_PUBLIC_KEY_OFFSET: usize = 16; // 1 + 1 + 14
+/// Offset of the signature in the data of an ed25519 program instruction with one signature
+pub const ED25519_SIGNATURE_OFFSET: usize = ED25519_PUBLIC_KEY_OFFSET + PUBKEY_BYTES;
+/// Offset of the message in the data of an ed25519 program instruction with one signature
+pub const ED25519_MESSAGE_OFFSET: usize = ED25519_SIGNATURE_OFFSET + 64;
+
+/// Maximum number of jobs in a 'FundJobs' instruction, bounded by the number of accounts that
+/// fit in a transaction
+pub const MAX_FUND_JOBS_LEN: usize = 10;
+
 /// Instructions supported by the RNDR program.
+///
+/// Instructions that previously expected the Rent sysvar account still accept it in its former
+/// position, so transactions built by older clients continue to work.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RNDRInstruction {
     // 0
     /// Initialize an Escrow.
@@ -26,46 +77,76 @@ pub enum RNDRInstruction {
     ///   1. `[writable,signer]` Funder SOL account
     ///   2. `[writable]` Escrow PDA account
     ///   3. `[writable]` Escrow ATA account
-    ///   4. `[]` Rent sysvar
-    ///   5. `[]` System program id
-    ///   6. `[]` Token program id
-    ///   7. `[]` Associated Token Account program id
+    ///   4. `[]` System program id
+    ///   5. `[]` Token program id
+    ///   6. `[]` Associated Token Account program id
     InitEscrow {
         /// Owner authority that can disburse funds
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
         owner: Pubkey,
     },
 
     // 1
-    /// Set the new owner of an Escrow.
+    /// Set the new owner of an Escrow immediately. Only allowed when instant owner changes are
+    /// enabled in the Config, otherwise owners are rotated with `ScheduleOwnerRotation`.
     ///
     /// Accounts expected by this instruction:
     ///
     ///   0. `[writable]` Escrow PDA account
-    ///   1. `[signer]` Current owner authority
+    ///   1. `[writable,signer]` Current owner authority, pays to reallocate legacy escrow accounts
+    ///   2. `[]` Config PDA account
+    ///   3. `[]` System program id
     SetEscrowOwner {
         /// New Escrow owner authority
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
         new_owner: Pubkey,
     },
 
     // 2
     /// Transfer funds into an Escrow and credit a Job.
     ///
+    /// The Job's amount is credited to its escrow shard rather than the Escrow, so the Escrow PDA
+    /// account is only read. The Escrow ATA account still receives the tokens and is write-locked
+    /// by every funding transaction of the Escrow.
+    ///
     /// Accounts expected by this instruction:
     ///
-    ///   0. `[]` RNDR SPL Token mint
-    ///   1. `[writable,signer]` Funder SOL account
-    ///   2. `[writable]` Source RNDR token account
-    ///                     $authority can transfer $amount
-    ///   3. `[signer]` Source token account authority ($authority)
-    ///   4. `[writable]` Escrow PDA account
-    ///   5. `[writable]` Escrow ATA account
-    ///   6. `[writable]` Job PDA account
-    ///   7. `[]` Rent sysvar
-    ///   8. `[]` System program id
-    ///   9. `[]` Token program id
+    ///   0. `[writable,signer]` Funder SOL account
+    ///   1. `[writable]` Source RNDR token account
+    ///      $authority can transfer $amount
+    ///   2. `[signer]` Source token account authority ($authority)
+    ///   3. `[]` Escrow PDA account
+    ///   4. `[writable]` Escrow ATA account
+    ///   5. `[writable]` Job PDA account
+    ///   6. `[writable]` Escrow shard PDA account for the job
+    ///   7. `[]` System program id
+    ///   8. `[]` Token program id
+    ///
+    /// Optionally, to top up an existing Job with a session key of its authority instead, in
+    /// which case the session key is account 2 and must be able to transfer $amount:
+    ///
+    ///   9. `[]` SessionKey PDA account of the Escrow, Job authority and session key
+    ///
+    /// Metadata can only be set by the Job authority itself, not a session key, while the Job is
+    /// open. The funder pays to reallocate legacy job accounts to store it.
+    ///
+    /// The JobQueue PDA account of the Escrow can be appended, writable, after all the other
+    /// accounts including any JobIndex accounts, and before the Stats PDA account, to push the
+    /// Job to the queue if it's created.
+    ///
+    /// To mint a compressed NFT receipt of the Job to the funder with Bubblegum, named after the
+    /// Job's identifier and with the URI and hash of its manifest, the receipt authority PDA
+    /// account, the writable Bubblegum tree config PDA and merkle tree accounts, and the SPL noop,
+    /// SPL account compression and Bubblegum program ids can be appended in that order after all
+    /// the other accounts including the JobQueue, and before the Stats PDA account. The receipt
+    /// authority must be the delegate of the merkle tree.
     FundJob {
         /// Amount of RNDR tokens to escrow
         amount: u64,
+        /// Identifier of the job, unique for the authority
+        job_id: u64,
+        /// Metadata and tags to set on the job, if any, packed after the other fields
+        metadata: Option<JobMetadata>,
     },
 
     // 3
@@ -73,202 +154,6348 @@ pub enum RNDRInstruction {
     ///
     /// Accounts expected by this instruction:
     ///
-    ///   0. `[]` RNDR SPL Token mint
-    ///   1. `[writable]` Escrow PDA account
-    ///   2. `[signer]` Escrow owner authority
-    ///   3. `[writable]` Escrow ATA account
-    ///   4. `[writable]` Job PDA account
-    ///   5. `[writable]` Destination RNDR token account
-    ///   6. `[]` Token program id
+    ///   0. `[writable]` Escrow PDA account
+    ///   1. `[signer]` Escrow owner authority
+    ///   2. `[writable]` Escrow ATA account
+    ///   3. `[writable]` Job PDA account
+    ///   4. `[writable]` Destination RNDR token account
+    ///   5. `[]` Token program id
+    ///
+    /// Optionally, to disburse as a delegate of the owner within its allowance instead, in which
+    /// case the delegate signs as account 1:
+    ///
+    ///   6. `[writable]` DelegateAllowance PDA account of the Escrow and delegate
+    ///
+    /// The EscrowShard PDA account the Job is funded through can be appended, writable, after all
+    /// the other accounts and before the Stats PDA account, so the tokens funded through it are
+    /// moved into the Escrow before it's debited.
     DisburseFunds {
         /// Amount of RNDR tokens to disburse
         amount: u64,
     },
-}
 
-impl RNDRInstruction {
-    /// Unpacks a byte buffer into a [RNDRInstruction](enum.RNDRInstruction.html).
-    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
-        let (&tag, rest) = input
-            .split_first()
-            .ok_or(RNDRError::InstructionUnpackError)?;
-        Ok(match tag {
-            0 => {
-                let (owner, _rest) = Self::unpack_pubkey(rest)?;
-                Self::InitEscrow { owner }
-            }
-            1 => {
-                let (new_owner, _rest) = Self::unpack_pubkey(rest)?;
-                Self::SetEscrowOwner { new_owner }
-            }
-            2 => {
-                let (amount, _rest) = Self::unpack_u64(rest)?;
-                Self::FundJob { amount }
-            }
-            3 => {
-                let (amount, _rest) = Self::unpack_u64(rest)?;
-                Self::DisburseFunds { amount }
-            }
-            _ => {
-                msg!("Instruction cannot be unpacked");
-                return Err(RNDRError::InstructionUnpackError.into());
-            }
-        })
-    }
+    // 4
+    /// Move the amount funded through an Escrow shard into the Escrow.
+    /// Anyone can call this instruction.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` Escrow PDA account
+    ///   1. `[writable]` Escrow shard PDA account
+    ///
+    /// Optionally, to be paid the bounty of the Escrow's CrankVault:
+    ///
+    ///   2. `[writable]` CrankVault PDA account of the Escrow
+    ///   3. `[writable]` Bounty recipient SOL account
+    AggregateEscrowShard,
 
-    fn unpack_u64(input: &[u8]) -> Result<(u64, &[u8]), ProgramError> {
-        if input.len() < 8 {
-            msg!("u64 cannot be unpacked");
-            return Err(RNDRError::InstructionUnpackError.into());
-        }
-        let (bytes, rest) = input.split_at(8);
-        let value = bytes
-            .get(..8)
-            .and_then(|slice| slice.try_into().ok())
-            .map(u64::from_le_bytes)
-            .ok_or(RNDRError::InstructionUnpackError)?;
-        Ok((value, rest))
-    }
+    // 5
+    /// Transfer funds into a Job's own token account and credit the Job.
+    /// The Job is created isolated from the Escrow's pooled token account if it doesn't exist.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[]` RNDR SPL Token mint
+    ///   1. `[writable,signer]` Funder SOL account
+    ///   2. `[writable]` Source RNDR token account
+    ///      $authority can transfer $amount
+    ///   3. `[signer]` Source token account authority ($authority)
+    ///   4. `[]` Escrow PDA account
+    ///   5. `[writable]` Job PDA account
+    ///   6. `[writable]` Job ATA account
+    ///   7. `[]` System program id
+    ///   8. `[]` Token program id
+    ///   9. `[]` Associated Token Account program id
+    FundIsolatedJob {
+        /// Amount of RNDR tokens to escrow
+        amount: u64,
+        /// Identifier of the job, unique for the authority
+        job_id: u64,
+    },
 
-    fn unpack_pubkey(input: &[u8]) -> Result<(Pubkey, &[u8]), ProgramError> {
-        if input.len() < PUBKEY_BYTES {
-            msg!("Pubkey cannot be unpacked");
-            return Err(RNDRError::InstructionUnpackError.into());
-        }
-        let (key, rest) = input.split_at(PUBKEY_BYTES);
-        let pk = Pubkey::new(key);
-        Ok((pk, rest))
-    }
+    // 6
+    /// Transfer funds from a Job's own token account and debit the Job
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[]` Escrow PDA account
+    ///   1. `[signer]` Escrow owner authority
+    ///   2. `[writable]` Job PDA account
+    ///   3. `[writable]` Job ATA account
+    ///   4. `[writable]` Destination RNDR token account
+    ///   5. `[]` Token program id
+    DisburseIsolatedJobFunds {
+        /// Amount of RNDR tokens to disburse
+        amount: u64,
+    },
 
-    /// Packs a [RNDRInstruction](enum.RNDRInstruction.html) into a byte buffer.
-    pub fn pack(&self) -> Vec<u8> {
-        let mut buf = Vec::with_capacity(size_of::<Self>());
-        match *self {
-            Self::InitEscrow { owner } => {
-                buf.push(0);
-                buf.extend_from_slice(&owner.to_bytes());
-            }
-            Self::SetEscrowOwner { new_owner } => {
-                buf.push(1);
-                buf.extend_from_slice(&new_owner.to_bytes());
-            }
-            Self::FundJob { amount } => {
-                buf.push(2);
-                buf.extend_from_slice(&amount.to_le_bytes());
-            }
-            Self::DisburseFunds { amount } => {
-                buf.push(3);
-                buf.extend_from_slice(&amount.to_le_bytes());
-            }
-        }
-        buf
-    }
-}
+    // 7
+    /// Transfer funds from an Escrow to multiple destinations and debit a Job
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` Escrow PDA account
+    ///   1. `[signer]` Escrow owner authority
+    ///   2. `[writable]` Escrow ATA account
+    ///   3. `[writable]` Job PDA account
+    ///   4. `[]` Token program id
+    ///   5. ..5+N `[writable]` Destination RNDR token accounts, one for each amount
+    ///
+    /// The EscrowShard PDA account the Job is funded through can be appended, writable, after all
+    /// the other accounts and before the Stats PDA account, so the tokens funded through it are
+    /// moved into the Escrow before it's debited.
+    DisburseBatch {
+        /// Amounts of RNDR tokens to disburse, at most `MAX_DISBURSE_BATCH_LEN`
+        amounts: Vec<u64>,
+    },
 
-/// Creates an 'InitEscrow' instruction.
-pub fn init_escrow(
-    program_id: Pubkey,
-    owner: Pubkey,
-    token_mint: Pubkey,
-    funder: Pubkey,
-) -> Instruction {
-    let (escrow, _bump_seed) = Pubkey::find_program_address(
-        &[b"escrow", token_mint.as_ref(), spl_token::id().as_ref()],
-        &program_id,
-    );
-    let escrow_associated_token = get_associated_token_address(&escrow, &token_mint);
-    Instruction {
-        program_id,
-        accounts: vec![
-            AccountMeta::new_readonly(token_mint, false),
-            AccountMeta::new(funder, true),
-            AccountMeta::new(escrow, false),
-            AccountMeta::new(escrow_associated_token, false),
-            AccountMeta::new_readonly(rent::id(), false),
-            AccountMeta::new_readonly(system_program::id(), false),
-            AccountMeta::new_readonly(spl_token::id(), false),
-            AccountMeta::new_readonly(spl_associated_token_account::id(), false),
-        ],
-        data: RNDRInstruction::InitEscrow { owner }.pack(),
-    }
-}
+    // 8
+    /// Transfer funds into an Escrow and credit multiple Jobs, creating them if needed
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable,signer]` Funder SOL account
+    ///   1. `[writable]` Source RNDR token account
+    ///      $authority can transfer the total amount
+    ///   2. `[signer]` Source token account authority ($authority)
+    ///   3. `[]` Escrow PDA account
+    ///   4. `[writable]` Escrow ATA account
+    ///   5. `[]` System program id
+    ///   6. `[]` Token program id
+    ///   7. ..7+2N `[writable]` Job PDA account and Escrow shard PDA account for each job
+    FundJobs {
+        /// Job identifiers and amounts of RNDR tokens to escrow, at most `MAX_FUND_JOBS_LEN`
+        jobs: Vec<(u64, u64)>,
+    },
 
-/// Creates a 'SetEscrowOwner' instruction.
-pub fn set_escrow_owner(
-    program_id: Pubkey,
-    escrow: Pubkey,
-    current_owner: Pubkey,
-    new_owner: Pubkey,
-) -> Instruction {
-    Instruction {
-        program_id,
-        accounts: vec![
-            AccountMeta::new(escrow, false),
-            AccountMeta::new_readonly(current_owner, true),
-        ],
-        data: RNDRInstruction::SetEscrowOwner { new_owner }.pack(),
-    }
-}
+    // 9
+    /// Commit to the result of a Job, assigning the Job to the node if it's open and the config's
+    /// priority claim delay for the Job's priority has passed since it was funded.
+    /// The node may replace its commitment until it reveals the result.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable,signer]` Node SOL account, pays to reallocate legacy Jobs
+    ///   1. `[writable]` Job PDA account
+    ///   2. `[]` System program id
+    ///   3. `[]` Config PDA account
+    CommitResult {
+        /// Commitment to the result, see `Job::result_commitment`
+        commitment: [u8; HASH_BYTES],
+    },
 
-/// Creates a 'FundJob' instruction.
-pub fn fund_job(
-    program_id: Pubkey,
-    amount: u64,
-    token_mint: Pubkey,
-    funder: Pubkey,
-    source_token: Pubkey,
-    authority: Pubkey,
-) -> Instruction {
-    let (escrow, _bump_seed) = Pubkey::find_program_address(
-        &[b"escrow", token_mint.as_ref(), spl_token::id().as_ref()],
-        &program_id,
-    );
-    let escrow_associated_token = get_associated_token_address(&escrow, &token_mint);
-    let (job, _bump_seed) =
-        Pubkey::find_program_address(&[b"job", escrow.as_ref(), authority.as_ref()], &program_id);
-    Instruction {
-        program_id,
-        accounts: vec![
-            AccountMeta::new_readonly(token_mint, false),
-            AccountMeta::new(funder, true),
-            AccountMeta::new(source_token, false),
-            AccountMeta::new_readonly(authority, true),
-            AccountMeta::new(escrow, false),
-            AccountMeta::new(escrow_associated_token, false),
-            AccountMeta::new(job, false),
-            AccountMeta::new_readonly(rent::id(), false),
-            AccountMeta::new_readonly(system_program::id(), false),
-            AccountMeta::new_readonly(spl_token::id(), false),
-        ],
-        data: RNDRInstruction::FundJob { amount }.pack(),
-    }
-}
+    // 10
+    /// Reveal the result a node committed to for a Job, starting its Escrow's challenge window.
+    /// If the Escrow has a proof verifier, the result is only revealed if the proof verifier
+    /// succeeds when invoked with the instruction created by `verify_proof`.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[signer]` Node authority
+    ///   1. `[writable]` Job PDA account
+    ///   2. `[]` Escrow PDA account
+    ///   3. `[]` Proof verifier program id, only if the Escrow has a proof verifier
+    ///   4. ..4+N `[]` Accounts passed through to the proof verifier
+    RevealResult {
+        /// Hash of the result
+        result_hash: [u8; HASH_BYTES],
+        /// Salt the commitment was made with
+        salt: [u8; HASH_BYTES],
+        /// Proof of the result for the Escrow's proof verifier, the rest of the instruction data
+        proof: Vec<u8>,
+    },
 
-/// Creates a 'DisburseFunds' instruction.
-pub fn disburse_funds(
-    program_id: Pubkey,
-    amount: u64,
-    token_mint: Pubkey,
-    destination_token: Pubkey,
-    job: Pubkey,
-    escrow_owner: Pubkey,
-) -> Instruction {
-    let (escrow, _bump_seed) = Pubkey::find_program_address(
-        &[b"escrow", token_mint.as_ref(), spl_token::id().as_ref()],
-        &program_id,
-    );
-    let escrow_associated_token = get_associated_token_address(&escrow, &token_mint);
-    Instruction {
-        program_id,
-        accounts: vec![
-            AccountMeta::new_readonly(token_mint, false),
-            AccountMeta::new(escrow, false),
-            AccountMeta::new_readonly(escrow_owner, true),
-            AccountMeta::new(escrow_associated_token, false),
-            AccountMeta::new(job, false),
-            AccountMeta::new(destination_token, false),
-            AccountMeta::new_readonly(spl_token::id(), false),
-        ],
-        data: RNDRInstruction::DisburseFunds { amount }.pack(),
+    // 11
+    /// Transfer a Job's funds from an Escrow to the node that revealed its result, once the
+    /// Escrow's challenge window has passed without the result being challenged
+    ///
+    /// The node is paid the Job's full amount. The Job's amount is credited in the ledger of the
+    /// node's Node account, if it has one, at its value in RNDR if the Job is funded in another
+    /// token and has an exchange rate recorded. Only the work recorded toward the reward pool is
+    /// weighted by the reward multiplier of the tier the node's stake reaches in the Config,
+    /// counting only tokens staked before the current epoch.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[signer]` Node authority
+    ///   1. `[writable]` Escrow PDA account
+    ///   2. `[writable]` Escrow ATA account
+    ///   3. `[writable]` Job PDA account
+    ///   4. `[writable]` Destination RNDR token account
+    ///   5. `[]` Token program id
+    ///   6. `[]` Config PDA account
+    ///   7. `[writable]` Node PDA account of the Escrow and node authority
+    ///
+    /// Optionally, to record the Job's weighted value as work of the node toward the reward pool of
+    /// the current epoch, which requires the node authority to be writable to pay for the
+    /// EpochWork:
+    ///
+    ///   8. `[writable]` RewardPool PDA account of the Escrow for the current epoch
+    ///   9. `[writable]` EpochWork PDA account
+    ///   10. `[]` System program id
+    ///
+    /// The EscrowShard PDA account the Job is funded through can be appended, writable, after all
+    /// the other accounts and before the Stats PDA account, so the tokens funded through it are
+    /// moved into the Escrow before it's debited.
+    ClaimPayment,
+
+    // 12
+    /// Set the challenge window of an Escrow
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` Escrow PDA account
+    ///   1. `[writable,signer]` Owner authority, pays to reallocate legacy Escrows
+    ///   2. `[]` System program id
+    SetChallengeWindow {
+        /// Seconds after a Job's result is revealed during which it can be challenged
+        challenge_window: u64,
+    },
+
+    // 13
+    /// Challenge the result revealed for a Job within its Escrow's challenge window, so the node
+    /// can't claim payment for it
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[signer]` Job authority
+    ///   1. `[]` Escrow PDA account
+    ///   2. `[writable]` Job PDA account
+    ///
+    /// Optionally, to challenge with a session key of the Job authority as account 0 instead:
+    ///
+    ///   3. `[]` SessionKey PDA account of the Escrow, Job authority and session key
+    ChallengeResult,
+
+    // 14
+    /// Register a verifier for an Escrow, creating its verifier registry if it doesn't exist yet
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[]` Escrow PDA account
+    ///   1. `[writable,signer]` Owner authority, pays to create the verifier registry
+    ///   2. `[writable]` Verifier registry PDA account
+    ///   3. `[]` System program id
+    AddVerifier {
+        /// Verifier authority that can flag jobs
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+        verifier: Pubkey,
+    },
+
+    // 15
+    /// Deregister a verifier of an Escrow
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[]` Escrow PDA account
+    ///   1. `[signer]` Owner authority
+    ///   2. `[writable]` Verifier registry PDA account
+    RemoveVerifier {
+        /// Verifier authority to deregister
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+        verifier: Pubkey,
+    },
+
+    // 16
+    /// Flag a Job whose result a registered verifier finds to be incorrect, freezing disbursement
+    /// of its funds until the dispute is resolved
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[signer]` Verifier authority
+    ///   1. `[]` Escrow PDA account
+    ///   2. `[]` Verifier registry PDA account
+    ///   3. `[writable]` Job PDA account
+    FlagJob {
+        /// Hash of the evidence that the result is incorrect
+        evidence_hash: [u8; HASH_BYTES],
+    },
+
+    // 17
+    /// Sample registered verifiers of an Escrow to spot-check the result of a Job, using the
+    /// most recent slot hash as entropy. Verifiers can only be selected once for each Job.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable,signer]` Funder SOL account, pays to create the verifier selection
+    ///   1. `[]` Escrow PDA account
+    ///   2. `[]` Verifier registry PDA account
+    ///   3. `[]` Job PDA account
+    ///   4. `[writable]` Verifier selection PDA account
+    ///   5. `[]` SlotHashes sysvar
+    ///   6. `[]` System program id
+    SelectVerifiers {
+        /// Number of verifiers to select, at most `MAX_SELECTED_VERIFIERS`
+        count: u8,
+    },
+
+    // 18
+    /// Set the proof verifier program of an Escrow, or the default pubkey to reveal results
+    /// without a proof
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` Escrow PDA account
+    ///   1. `[writable,signer]` Owner authority, pays to reallocate legacy Escrows
+    ///   2. `[]` System program id
+    SetProofVerifier {
+        /// Program that must verify the proof of a Job's result for it to be revealed
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+        proof_verifier: Pubkey,
+    },
+
+    // 19
+    /// Set the arbitrator of an Escrow that resolves disputes over its Jobs
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` Escrow PDA account
+    ///   1. `[writable,signer]` Owner authority, pays to reallocate legacy Escrows
+    ///   2. `[]` System program id
+    SetArbitrator {
+        /// Arbitrator authority that resolves disputes
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+        arbitrator: Pubkey,
+    },
+
+    // 20
+    /// Open a dispute over the result of a Job a node has committed to, freezing disbursement of
+    /// its funds until the Escrow's arbitrator resolves the dispute. Once the result is revealed,
+    /// a dispute can only be opened within the Config's dispute window.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable,signer]` Job authority or node, pays to create the dispute
+    ///   1. `[]` Escrow PDA account
+    ///   2. `[]` Config PDA account
+    ///   3. `[writable]` Job PDA account
+    ///   4. `[writable]` Dispute PDA account
+    ///   5. `[]` System program id
+    OpenDispute,
+
+    // 21
+    /// Submit the hash of evidence for a dispute, replacing any evidence previously submitted by
+    /// the same party
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[signer]` Job authority or node
+    ///   1. `[]` Job PDA account
+    ///   2. `[writable]` Dispute PDA account
+    SubmitEvidence {
+        /// Hash of the evidence
+        evidence_hash: [u8; HASH_BYTES],
+    },
+
+    // 22
+    /// Resolve a dispute, transferring the Job's funds from the Escrow to the node and the Job's
+    /// authority according to the arbitrator's ruling
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[signer]` Arbitrator authority
+    ///   1. `[writable]` Escrow PDA account
+    ///   2. `[writable]` Escrow ATA account
+    ///   3. `[writable]` Job PDA account
+    ///   4. `[writable]` Dispute PDA account
+    ///   5. `[writable]` Node ATA account
+    ///   6. `[writable]` Job authority ATA account
+    ///   7. `[]` Token program id
+    ///
+    /// The EscrowShard PDA account the Job is funded through can be appended, writable, after all
+    /// the other accounts and before the Stats PDA account, so the tokens funded through it are
+    /// moved into the Escrow before it's debited.
+    ResolveDispute {
+        /// Amount of the Job's tokens awarded to the node, the rest are returned to the authority
+        node_amount: u64,
+    },
+
+    // 23
+    /// Create the Config with the default parameters, which must be done by the upgrade authority
+    /// of the program when it's deployed
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable,signer]` Funder SOL account, pays to create the Config
+    ///   1. `[writable]` Config PDA account
+    ///   2. `[]` Program data account of the RNDR program
+    ///   3. `[signer]` Upgrade authority of the RNDR program
+    ///   4. `[]` System program id
+    InitConfig {
+        /// Governance authority that can change the Config
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+        governance: Pubkey,
+    },
+
+    // 24
+    /// Change a single field of the Config, which must be within the field's bounds. Changes to
+    /// fields that are timelocked in the Config must be queued with `QueueAction` instead.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` Config PDA account
+    ///   1. `[signer]` Governance authority of the Config
+    ///
+    /// Optionally, required to set fields appended after a legacy Config was created:
+    ///
+    ///   2. `[writable,signer]` Payer SOL account, pays to reallocate a legacy Config
+    ///   3. `[]` System program id
+    SetConfig {
+        /// Field of the Config to change and its new value
+        change: ConfigChange,
+    },
+
+    // 25
+    /// Verify that the upgrade authority of the program is the governance authority of the
+    /// Config, or that the program is immutable, failing otherwise. Monitoring tools can simulate
+    /// this and other programs can invoke it to confirm who can upgrade the program.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[]` Config PDA account
+    ///   1. `[]` Program data account of the RNDR program
+    VerifyUpgradeAuthority,
+
+    // 26
+    /// Set the approver of the Escrow, after which the owner's disbursements must be proposed and
+    /// approved instead of being made directly, or the default pubkey to disburse directly again.
+    /// Once an Escrow has an approver, the approver must also sign to change or clear it.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` Escrow PDA account
+    ///   1. `[writable,signer]` Escrow owner authority, pays to reallocate legacy escrow accounts
+    ///   2. `[]` System program id
+    ///
+    /// Optionally, required if the Escrow has an approver:
+    ///
+    ///   3. `[signer]` Current approver authority of the Escrow
+    SetApprover {
+        /// Approver authority, which must differ from the owner
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+        approver: Pubkey,
+    },
+
+    // 27
+    /// Propose a disbursement of a Job's funds, which the approver of the Escrow must approve
+    /// before it can be executed
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable,signer]` Escrow owner authority, pays to create the Proposal
+    ///   1. `[]` Escrow PDA account
+    ///   2. `[]` Job PDA account
+    ///   3. `[]` Destination SPL Token account
+    ///   4. `[writable]` Proposal PDA account
+    ///   5. `[]` System program id
+    ProposeDisbursement {
+        /// Identifier of the proposal, unique for the escrow
+        proposal_id: u64,
+        /// Amount of tokens to disburse
+        amount: u64,
+    },
+
+    // 28
+    /// Approve a proposed disbursement
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[signer]` Approver authority of the Escrow
+    ///   1. `[]` Escrow PDA account
+    ///   2. `[writable]` Proposal PDA account
+    ApproveDisbursement,
+
+    // 29
+    /// Execute an approved disbursement, transferring the funds from the Escrow, or from the Job's
+    /// own token account if the Job is isolated. Anyone can execute an approved disbursement.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` Escrow PDA account
+    ///   1. `[writable]` Escrow ATA account, or Job ATA account if the Job is isolated
+    ///   2. `[writable]` Job PDA account
+    ///   3. `[writable]` Proposal PDA account
+    ///   4. `[writable]` Destination SPL Token account
+    ///   5. `[]` Token program id
+    ///
+    /// The EscrowShard PDA account the Job is funded through, unless it's isolated, can be
+    /// appended, writable, after all the other accounts and before the Stats PDA account, so the
+    /// tokens funded through it are moved into the Escrow before it's debited.
+    ExecuteDisbursement,
+
+    // 30
+    /// Queue an administrative action, which can be executed once the timelock duration of the
+    /// Config has passed. Setting the owner of an Escrow is queued by its owner, and changing a
+    /// field of the Config is queued by its governance authority.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable,signer]` Escrow owner or Config governance authority, pays to create the
+    ///      QueuedAction
+    ///   1. `[]` Config PDA account
+    ///   2. `[writable]` Target Escrow PDA account, reallocated if it's a legacy escrow account, or
+    ///      the Config PDA account again
+    ///   3. `[writable]` QueuedAction PDA account
+    ///   4. `[]` System program id
+    QueueAction {
+        /// Identifier of the queued action, unique for the target
+        action_id: u64,
+        /// Action to apply once the timelock has passed
+        action: AdminAction,
+    },
+
+    // 31
+    /// Execute a queued action once its timelock has passed, if its authority still owns the
+    /// target. Anyone can execute a queued action.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` QueuedAction PDA account
+    ///   1. `[writable]` Target Escrow or Config PDA account
+    ///
+    /// Optionally, required to set fields appended after a legacy Config was created:
+    ///
+    ///   2. `[writable,signer]` Payer SOL account, pays to reallocate a legacy Config
+    ///   3. `[]` System program id
+    ExecuteQueuedAction,
+
+    // 32
+    /// Cancel a queued action before its timelock has passed
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[signer]` Guardian authority of the Config
+    ///   1. `[]` Config PDA account
+    ///   2. `[writable]` QueuedAction PDA account
+    CancelQueuedAction,
+
+    // 33
+    /// Create or replace the recovery council of an Escrow, clearing any votes and pending
+    /// recovery
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` Escrow PDA account
+    ///   1. `[writable,signer]` Escrow owner authority, pays to create the Recovery and to
+    ///      reallocate legacy escrow accounts
+    ///   2. `[writable]` Recovery PDA account
+    ///   3. `[]` System program id
+    SetRecoveryCouncil {
+        /// Number of guardians that must vote for the same new owner, between one and the number
+        /// of guardians
+        threshold: u8,
+        /// Distinct guardians on the council, at most `MAX_RECOVERY_GUARDIANS`
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey::vec"))]
+        guardians: Vec<Pubkey>,
+    },
+
+    // 34
+    /// Vote as a guardian of the recovery council for a new owner of the Escrow. Once the
+    /// threshold of guardians vote for the same new owner, it can be set after `RECOVERY_DELAY`.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[signer]` Guardian authority on the recovery council
+    ///   1. `[]` Escrow PDA account
+    ///   2. `[writable]` Recovery PDA account
+    ApproveRecovery {
+        /// New owner authority of the Escrow
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+        new_owner: Pubkey,
+    },
+
+    // 35
+    /// Set the owner of the Escrow to the new owner of a pending recovery once its delay has
+    /// passed, if the threshold of guardians still vote for it. Anyone can execute a recovery.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` Escrow PDA account
+    ///   1. `[writable]` Recovery PDA account
+    ExecuteRecovery,
+
+    // 36
+    /// Announce a new owner of an Escrow, which takes effect once the timelock duration of the
+    /// Config has passed. The rotation is a queued action, so it's applied with
+    /// `ExecuteQueuedAction` and can be cancelled by the guardian of the Config until then.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable,signer]` Escrow owner authority, pays to create the QueuedAction and to
+    ///      reallocate legacy escrow accounts
+    ///   1. `[]` Config PDA account
+    ///   2. `[writable]` Escrow PDA account
+    ///   3. `[writable]` QueuedAction PDA account
+    ///   4. `[]` System program id
+    ScheduleOwnerRotation {
+        /// Identifier of the queued action, unique for the Escrow
+        rotation_id: u64,
+        /// New Escrow owner authority
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+        new_owner: Pubkey,
+    },
+
+    // 37
+    /// Transfer rewards into the reward pool of an Escrow for an epoch that hasn't ended, such as
+    /// protocol fees or burn proceeds. The RewardPool is created if it doesn't exist.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[]` RNDR SPL Token mint
+    ///   1. `[writable,signer]` Funder SOL account
+    ///   2. `[writable]` Source RNDR token account
+    ///      $authority can transfer $amount
+    ///   3. `[signer]` Source token account authority ($authority)
+    ///   4. `[]` Escrow PDA account
+    ///   5. `[writable]` RewardPool PDA account
+    ///   6. `[writable]` RewardPool ATA account
+    ///   7. `[]` System program id
+    ///   8. `[]` Token program id
+    ///   9. `[]` Associated Token Account program id
+    FundRewardPool {
+        /// Epoch the rewards are paid for
+        epoch: u64,
+        /// Amount of tokens to transfer
+        amount: u64,
+    },
+
+    // 38
+    /// Transfer the share of a reward pool's rewards proportional to the work a node recorded
+    /// during its epoch to the node, once the epoch has ended
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[signer]` Node authority
+    ///   1. `[writable]` RewardPool PDA account
+    ///   2. `[writable]` RewardPool ATA account
+    ///   3. `[writable]` EpochWork PDA account
+    ///   4. `[writable]` Destination RNDR token account
+    ///   5. `[]` Token program id
+    ClaimEpochReward,
+
+    // 39
+    /// Publish the Merkle root of the rewards of every node for an epoch, computed off chain, and
+    /// transfer the sum of the rewards into the MerkleDistribution. The root of an epoch can only
+    /// be published once.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[]` RNDR SPL Token mint
+    ///   1. `[writable,signer]` Escrow owner authority, pays to create the MerkleDistribution
+    ///   2. `[writable]` Source RNDR token account
+    ///      $authority can transfer $amount
+    ///   3. `[signer]` Source token account authority ($authority)
+    ///   4. `[]` Escrow PDA account
+    ///   5. `[writable]` MerkleDistribution PDA account
+    ///   6. `[writable]` MerkleDistribution ATA account
+    ///   7. `[]` System program id
+    ///   8. `[]` Token program id
+    ///   9. `[]` Associated Token Account program id
+    PublishMerkleRoot {
+        /// Epoch the rewards are paid for
+        epoch: u64,
+        /// Merkle root of the `(index, node, amount)` leaves of every node's reward
+        root: [u8; HASH_BYTES],
+        /// Number of leaves in the tree
+        node_count: u32,
+        /// Sum of the amounts of every leaf, transferred from the source token account
+        amount: u64,
+    },
+
+    // 40
+    /// Transfer a node's reward from a MerkleDistribution with a proof of its leaf, recording the
+    /// claim in the ClaimBitmap covering the leaf's index so it can't be claimed again. The
+    /// ClaimBitmap is created if it doesn't exist.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable,signer]` Node authority, pays to create the ClaimBitmap
+    ///   1. `[writable]` MerkleDistribution PDA account
+    ///   2. `[writable]` MerkleDistribution ATA account
+    ///   3. `[writable]` ClaimBitmap PDA account of the leaf's chunk
+    ///   4. `[writable]` Destination RNDR token account
+    ///   5. `[]` System program id
+    ///   6. `[]` Token program id
+    ClaimMerkleReward {
+        /// Index of the node's leaf
+        index: u32,
+        /// Amount of tokens in the node's leaf
+        amount: u64,
+        /// Sibling hashes from the leaf up to the root, at most `MAX_MERKLE_PROOF_LEN`
+        proof: Vec<[u8; HASH_BYTES]>,
+    },
+
+    // 41
+    /// Set the emission schedule of an Escrow, creating the EmissionSchedule if it doesn't exist.
+    /// Changing an existing schedule keeps the epochs it has already emitted for. The rewards are
+    /// transferred by anyone into the EmissionSchedule ATA account, and emitted with
+    /// `EmitRewards`.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable,signer]` Escrow owner authority, pays to create the EmissionSchedule
+    ///   1. `[]` Escrow PDA account
+    ///   2. `[writable]` EmissionSchedule PDA account
+    ///   3. `[]` System program id
+    SetEmissionSchedule {
+        /// First epoch that emits rewards
+        start_epoch: u64,
+        /// Amount of tokens emitted in the first epoch
+        initial_amount: u64,
+        /// Basis points the emission decays by from each epoch to the next, at most
+        /// `MAX_DECAY_BPS`
+        decay_bps: Rate,
+    },
+
+    // 42
+    /// Transfer the emission of the current epoch from an EmissionSchedule into the reward pool
+    /// of the epoch, creating the RewardPool if it doesn't exist. Each epoch emits once, and
+    /// epochs that ended before their emission was transferred don't emit. Anyone can emit rewards.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[]` RNDR SPL Token mint
+    ///   1. `[writable,signer]` Funder SOL account
+    ///   2. `[]` Escrow PDA account
+    ///   3. `[writable]` EmissionSchedule PDA account
+    ///   4. `[writable]` EmissionSchedule ATA account
+    ///   5. `[writable]` RewardPool PDA account for the current epoch
+    ///   6. `[writable]` RewardPool ATA account
+    ///   7. `[]` System program id
+    ///   8. `[]` Token program id
+    ///   9. `[]` Associated Token Account program id
+    ///
+    /// Optionally, to be paid the bounty of the Escrow's CrankVault:
+    ///
+    ///   10. `[writable]` CrankVault PDA account of the Escrow
+    ///   11. `[writable]` Bounty recipient SOL account
+    EmitRewards,
+
+    // 43
+    /// Stake tokens for a node, creating the Node if it doesn't exist
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[]` RNDR SPL Token mint
+    ///   1. `[writable,signer]` Node authority, pays to create the Node
+    ///   2. `[writable]` Source RNDR token account
+    ///   3. `[signer]` Source RNDR token account authority
+    ///   4. `[]` Escrow PDA account
+    ///   5. `[writable]` Node PDA account
+    ///   6. `[writable]` Node ATA account
+    ///   7. `[]` System program id
+    ///   8. `[]` Token program id
+    ///   9. `[]` Associated Token Account program id
+    Stake {
+        /// Amount of tokens to stake
+        amount: u64,
+    },
+
+    // 44
+    /// Withdraw staked tokens of a node
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[signer]` Node authority
+    ///   1. `[writable]` Node PDA account
+    ///   2. `[writable]` Node ATA account
+    ///   3. `[writable]` Destination RNDR token account
+    ///   4. `[]` Token program id
+    Unstake {
+        /// Amount of tokens to withdraw
+        amount: u64,
+    },
+
+    // 45
+    /// Set whether anyone can compound the epoch rewards of a node into its stake
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[signer]` Node authority
+    ///   1. `[writable]` Node PDA account
+    SetAutoCompound {
+        /// Whether to compound the node's epoch rewards
+        auto_compound: bool,
+    },
+
+    // 46
+    /// Claim the reward of a node for an ended epoch into its stake, for a node that has
+    /// auto-compounding enabled. Anyone can compound rewards.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[]` RNDR SPL Token mint
+    ///   1. `[writable]` RewardPool PDA account
+    ///   2. `[writable]` RewardPool ATA account
+    ///   3. `[writable]` EpochWork PDA account of the node
+    ///   4. `[writable]` Node PDA account
+    ///   5. `[writable]` Node ATA account
+    ///   6. `[]` Token program id
+    ///
+    /// Optionally, to be paid the bounty of the Escrow's CrankVault:
+    ///
+    ///   7. `[writable]` CrankVault PDA account of the Escrow
+    ///   8. `[writable]` Bounty recipient SOL account
+    CompoundRewards,
+
+    // 47
+    /// Stake tokens in the liquid stake pool of an Escrow for receipt tokens, creating the
+    /// StakePool, its receipt mint and the receipt owner's receipt ATA if they don't exist.
+    /// Receipts are minted at the current exchange rate of the pool.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[]` RNDR SPL Token mint
+    ///   1. `[writable,signer]` Funder SOL account
+    ///   2. `[writable]` Source RNDR token account
+    ///   3. `[signer]` Source RNDR token account authority
+    ///   4. `[]` Escrow PDA account
+    ///   5. `[writable]` StakePool PDA account
+    ///   6. `[writable]` StakePool ATA account
+    ///   7. `[writable]` Stake receipt mint PDA account
+    ///   8. `[]` Receipt owner
+    ///   9. `[writable]` Receipt owner's ATA account of the stake receipt mint
+    ///   10. `[]` System program id
+    ///   11. `[]` Token program id
+    ///   12. `[]` Associated Token Account program id
+    LiquidStake {
+        /// Amount of tokens to stake
+        amount: u64,
+    },
+
+    // 48
+    /// Burn receipt tokens of a liquid stake pool to withdraw their share of its stake
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` StakePool PDA account
+    ///   1. `[writable]` StakePool ATA account
+    ///   2. `[writable]` Stake receipt mint PDA account
+    ///   3. `[writable]` Source receipt token account
+    ///   4. `[signer]` Source receipt token account authority
+    ///   5. `[writable]` Destination RNDR token account
+    ///   6. `[]` Token program id
+    LiquidUnstake {
+        /// Amount of receipt tokens to burn
+        receipt_amount: u64,
+    },
+
+    // 49
+    /// Deposit rewards into a liquid stake pool without minting receipts, raising the amount of
+    /// stake each receipt token can be exchanged for. Anyone can deposit rewards.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` Source RNDR token account
+    ///   1. `[signer]` Source RNDR token account authority
+    ///   2. `[writable]` StakePool PDA account
+    ///   3. `[writable]` StakePool ATA account
+    ///   4. `[]` Token program id
+    DepositStakeRewards {
+        /// Amount of tokens to deposit
+        amount: u64,
+    },
+
+    // 50
+    /// Set the bounty the CrankVault of an Escrow pays for each successfully executed crank,
+    /// creating the CrankVault if it doesn't exist. The cranks that pay bounties are
+    /// `AggregateEscrowShard` when it moves a nonzero amount, `EmitRewards`, `CompoundRewards` and
+    /// `ProcessSubscription`.
+    /// Anyone can fund the CrankVault by transferring lamports to it.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable,signer]` Escrow owner authority, pays to create the CrankVault
+    ///   1. `[]` Escrow PDA account
+    ///   2. `[writable]` CrankVault PDA account
+    ///   3. `[]` System program id
+    SetCrankBounty {
+        /// Lamports paid for each successfully executed crank
+        bounty: u64,
+    },
+
+    // 51
+    /// Set the maximum amount of tokens the Treasury of an Escrow can burn in each epoch, creating
+    /// the Treasury and the Treasury ATA if they don't exist. Anyone can fund the Treasury by
+    /// transferring tokens into the Treasury ATA.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[]` RNDR SPL Token mint
+    ///   1. `[writable,signer]` Escrow owner authority, pays to create the Treasury
+    ///   2. `[]` Escrow PDA account
+    ///   3. `[writable]` Treasury PDA account
+    ///   4. `[writable]` Treasury ATA account
+    ///   5. `[]` System program id
+    ///   6. `[]` Token program id
+    ///   7. `[]` Associated Token Account program id
+    SetTreasuryBurnCap {
+        /// Maximum amount of tokens burned in each epoch, where zero is uncapped
+        epoch_burn_cap: u64,
+    },
+
+    // 52
+    /// Burn tokens held by the Treasury of an Escrow, up to the amount its epoch burn cap leaves
+    /// for the current epoch, and record them in the Treasury's burn totals
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` RNDR SPL Token mint
+    ///   1. `[signer]` Escrow owner authority
+    ///   2. `[]` Escrow PDA account
+    ///   3. `[writable]` Treasury PDA account
+    ///   4. `[writable]` Treasury ATA account
+    ///   5. `[]` Token program id
+    BurnTreasury {
+        /// Amount of tokens to burn
+        amount: u64,
+    },
+
+    // 53
+    /// Create the Stats of an Escrow, which record the tokens escrowed, burned, emitted as rewards
+    /// and disbursed in total and during the latest epoch. Anyone can create the Stats.
+    ///
+    /// The Stats PDA account can then be appended, writable, after all the other accounts of
+    /// `FundJob`, `FundIsolatedJob`, `FundJobs`, `DisburseFunds`, `DisburseIsolatedJobFunds`,
+    /// `DisburseBatch`, `ClaimPayment`, `ExecuteDisbursement`, `EmitRewards` and `BurnTreasury`
+    /// to record their flows.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable,signer]` Funder SOL account
+    ///   1. `[]` Escrow PDA account
+    ///   2. `[writable]` Stats PDA account
+    ///   3. `[]` System program id
+    InitStats,
+
+    // 54
+    /// Transfer funds into an Escrow and credit a Job, authorized by a voucher the Job authority
+    /// signed offchain instead of a transaction signature, so that a relayer can pay the fees.
+    ///
+    /// The instruction immediately before this one must be an ed25519 program instruction that
+    /// verifies the authority's signature of the packed voucher, with its data in the same
+    /// instruction. The voucher's nonce must be the next nonce of the authority's VoucherNonce,
+    /// which is then incremented so the voucher can't be used again.
+    ///
+    /// An authority that signs this instruction, such as a PDA of another program signing with its
+    /// seeds through `invoke_signed`, authorizes the voucher without the ed25519 program
+    /// instruction, since a PDA has no private key to sign a voucher with.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable,signer]` Relayer SOL account, pays to create the Job, the escrow shard and
+    ///      the VoucherNonce
+    ///   1. `[writable]` Source RNDR token account, with the Escrow PDA approved as its delegate
+    ///      for at least $amount
+    ///   2. `[]` Source token account owner and Job authority that signed the voucher
+    ///   3. `[]` Escrow PDA account
+    ///   4. `[writable]` Escrow ATA account
+    ///   5. `[writable]` Job PDA account
+    ///   6. `[writable]` Escrow shard PDA account for the job
+    ///   7. `[writable]` VoucherNonce PDA account of the Escrow and authority
+    ///   8. `[]` Instructions sysvar
+    ///   9. `[]` System program id
+    ///   10. `[]` Token program id
+    FundJobWithVoucher {
+        /// Amount of tokens to fund
+        amount: u64,
+        /// Identifier of the Job, unique for the authority
+        job_id: u64,
+        /// Nonce of the voucher
+        nonce: u64,
+        /// Unix timestamp after which the voucher can't be used
+        expires_at: UnixTimestamp,
+    },
+
+    // 55
+    /// Register a session key that can sign the instructions in its mask in place of a Job
+    /// authority until its expiry slot, for the authority's Jobs in an Escrow. Registering the
+    /// same session key again replaces its expiry slot and mask, so registering it with an empty
+    /// mask revokes it.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable,signer]` Job authority, pays to create the SessionKey
+    ///   1. `[]` Escrow PDA account
+    ///   2. `[writable]` SessionKey PDA account of the Escrow, Job authority and session key
+    ///   3. `[]` System program id
+    RegisterSessionKey {
+        /// Session key accepted as a signer in place of the authority
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+        session_key: Pubkey,
+        /// Last slot the session key can be used in
+        expiry_slot: Slot,
+        /// Instructions the session key can sign, see `SESSION_KEY_INSTRUCTIONS`
+        instruction_mask: u64,
+    },
+
+    // 56
+    /// Approve a delegate that can disburse funds from an Escrow in place of its owner, up to a
+    /// cumulative limit and until an expiry slot. Approving the same delegate again replaces its
+    /// remaining allowance and expiry slot.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[]` Escrow PDA account
+    ///   1. `[writable,signer]` Owner authority, pays to create the DelegateAllowance
+    ///   2. `[writable]` DelegateAllowance PDA account of the Escrow and delegate
+    ///   3. `[]` System program id
+    ApproveDelegate {
+        /// Delegate authority that can disburse in place of the owner
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+        delegate: Pubkey,
+        /// Cumulative amount of tokens the delegate can disburse
+        limit: u64,
+        /// Last slot the delegate can disburse in
+        expiry_slot: Slot,
+    },
+
+    // 57
+    /// Revoke a delegate of an Escrow's owner, closing its DelegateAllowance
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[]` Escrow PDA account
+    ///   1. `[writable,signer]` Owner authority, receives the DelegateAllowance's lamports
+    ///   2. `[writable]` DelegateAllowance PDA account of the Escrow and delegate
+    RevokeDelegate,
+
+    // 58
+    /// Create a Subscription that funds a Job of the authority with an amount of tokens each
+    /// period, starting from the current slot. The authority approves the Subscription as the
+    /// SPL Token delegate of the source token account separately, so the delegated amount caps
+    /// the total the Subscription can transfer and revoking the delegation stops it. Creating the
+    /// Subscription of the same Job again replaces its source token account, amount and period.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable,signer]` Job authority, pays to create the Subscription
+    ///   1. `[]` Escrow PDA account
+    ///   2. `[]` Source SPL Token account
+    ///   3. `[writable]` Subscription PDA account of the Escrow, authority and job identifier
+    ///   4. `[]` System program id
+    CreateSubscription {
+        /// Identifier of the job to fund
+        job_id: u64,
+        /// Amount of tokens to fund each period
+        amount: u64,
+        /// Length of a period in slots
+        period: Slot,
+    },
+
+    // 59
+    /// Fund the Job of a Subscription for its next due period, transferring the tokens from its
+    /// source token account as its delegate. Anyone can crank a due Subscription, and each
+    /// period a crank missed can still be funded by cranking again.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable,signer]` Funder, pays to create the Job or EscrowShard if they don't exist
+    ///   1. `[writable]` Subscription PDA account
+    ///   2. `[writable]` Source SPL Token account of the Subscription
+    ///   3. `[]` Escrow PDA account
+    ///   4. `[writable]` Escrow ATA account
+    ///   5. `[writable]` Job PDA account of the Subscription
+    ///   6. `[writable]` Escrow shard PDA account of the Job
+    ///   7. `[]` System program id
+    ///   8. `[]` Token program id
+    ///
+    /// Optionally, to be paid the bounty of the Escrow's CrankVault:
+    ///
+    ///   9. `[writable]` CrankVault PDA account of the Escrow
+    ///   10. `[writable]` Bounty recipient SOL account
+    ProcessSubscription,
+
+    // 60
+    /// Cancel a Job whose result hasn't been revealed, refunding its tokens to the authority. A
+    /// Job that a node has committed to is charged the Config's cancellation fee once the grace
+    /// period after funding has passed, which is paid to the node as compensation for the
+    /// capacity it reserved. The remainder of rounding down the refund and the fee goes where the
+    /// Config's rounding policy sends it, and a refund or fee below the Config's minimum
+    /// disbursement is dust swept to the Treasury.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable,signer]` Job authority, pays to reallocate a legacy Job
+    ///   1. `[writable]` Escrow PDA account
+    ///   2. `[writable]` Escrow ATA account
+    ///   3. `[writable]` Job PDA account
+    ///   4. `[writable]` Destination SPL Token account for the refund
+    ///   5. `[]` Config PDA account
+    ///   6. `[]` System program id
+    ///   7. `[]` Token program id
+    ///
+    /// Optionally, required if a cancellation fee is charged:
+    ///
+    ///   8. `[writable]` SPL Token account of the Job's node for the fee
+    ///
+    /// Optionally, required if dust is swept to the Treasury, or a remainder is left and the
+    /// rounding policy pays it to the Treasury or burns it:
+    ///
+    ///   9. `[]` Treasury PDA account of the Escrow
+    ///   10. `[writable]` Treasury ATA account
+    ///   11. `[writable]` RNDR token mint
+    ///
+    /// The EscrowShard PDA account the Job is funded through can be appended, writable, after all
+    /// the other accounts and before the Stats PDA account, so the tokens funded through it are
+    /// moved into the Escrow before it's debited.
+    CancelJob,
+
+    // 61
+    /// Start transferring a Job to a new authority, which takes over managing it once it accepts
+    /// the transfer with `AcceptJobAuthority`. Starting another transfer replaces the pending
+    /// one, and the default pubkey cancels it. The Job keeps its address, which is derived from
+    /// the authority that initialized it.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable,signer]` Job authority, pays to reallocate a legacy Job
+    ///   1. `[]` Escrow PDA account
+    ///   2. `[writable]` Job PDA account
+    ///   3. `[]` System program id
+    TransferJobAuthority {
+        /// New authority of the job
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+        new_authority: Pubkey,
+    },
+
+    // 62
+    /// Accept the pending transfer of a Job, becoming its authority.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[signer]` Pending authority of the Job
+    ///   1. `[]` Escrow PDA account
+    ///   2. `[writable]` Job PDA account
+    AcceptJobAuthority,
+
+    // 63
+    /// Create a JobBundle grouping the consecutive Jobs of the authority from a first job
+    /// identifier, which share the bundle's metadata and are funded, cancelled and tracked to
+    /// completion through it.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable,signer]` Job authority, pays to create the JobBundle
+    ///   1. `[]` Escrow PDA account
+    ///   2. `[writable]` JobBundle PDA account of the Escrow, authority and bundle identifier
+    ///   3. `[]` System program id
+    CreateJobBundle {
+        /// Identifier of the bundle, unique for the authority
+        bundle_id: u64,
+        /// Identifier of the first job of the bundle
+        first_job_id: u64,
+        /// Number of jobs of the bundle, at most `MAX_BUNDLE_JOBS`
+        job_count: u8,
+        /// Hash of the metadata the jobs share
+        metadata_hash: [u8; HASH_BYTES],
+    },
+
+    // 64
+    /// Transfer funds into an Escrow from a single source and credit consecutive Jobs of a
+    /// JobBundle with the same amount each, creating them if needed
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable,signer]` Funder SOL account
+    ///   1. `[writable]` Source RNDR token account
+    ///      $authority can transfer the total amount
+    ///   2. `[signer]` Source token account authority ($authority)
+    ///   3. `[writable]` JobBundle PDA account
+    ///   4. `[]` Escrow PDA account
+    ///   5. `[writable]` Escrow ATA account
+    ///   6. `[]` System program id
+    ///   7. `[]` Token program id
+    ///   8. ..8+2N `[writable]` Job PDA account and Escrow shard PDA account for each job
+    FundJobBundle {
+        /// Identifier of the first job to fund, which must be part of the bundle
+        first_job_id: u64,
+        /// Number of consecutive jobs to fund, at most `MAX_FUND_JOBS_LEN`
+        count: u8,
+        /// Amount of RNDR tokens to fund each job with
+        amount_per_job: u64,
+    },
+
+    // 65
+    /// Cancel a JobBundle so its Jobs can't be funded through it anymore, cancelling the Jobs
+    /// provided that no node has committed to and refunding their tokens to the authority. Jobs
+    /// that a node has committed to are cancelled individually with `CancelJob`, which pays the
+    /// node any cancellation fee.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable,signer]` Job authority, pays to reallocate legacy Jobs
+    ///   1. `[writable]` Escrow PDA account
+    ///   2. `[writable]` Escrow ATA account
+    ///   3. `[writable]` JobBundle PDA account
+    ///   4. `[writable]` Destination SPL Token account for the refund
+    ///   5. `[]` System program id
+    ///   6. `[]` Token program id
+    ///   7. ..7+N `[writable]` Job PDA accounts of the bundle to cancel
+    ///
+    /// The EscrowShard PDA accounts the Jobs are funded through can be appended, writable, after
+    /// all the other accounts and before the Stats PDA account, so the tokens funded through them
+    /// are moved into the Escrow before it's debited.
+    CancelJobBundle,
+
+    // 66
+    /// Record the outcomes of Jobs of a JobBundle that have been paid, resolved or cancelled,
+    /// completing the bundle once every Job has an outcome. Anyone can aggregate a JobBundle.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` JobBundle PDA account
+    ///   1. `[]` Escrow PDA account
+    ///   2. ..2+N `[]` Job PDA accounts of the bundle
+    AggregateJobBundle,
+
+    // 67
+    /// Create the JobTree of an authority in an Escrow, which holds compressed Jobs as the leaves
+    /// of an spl-account-compression concurrent merkle tree instead of in their own accounts, and
+    /// initialize the merkle tree with the JobTree as its authority
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable,signer]` Job authority, pays to create the JobTree
+    ///   1. `[]` Escrow PDA account
+    ///   2. `[writable]` JobTree PDA account of the Escrow and authority
+    ///   3. `[writable]` Merkle tree account, created with the account compression program as its
+    ///      owner and the size of a tree of $max_depth and $max_buffer_size
+    ///   4. `[]` Noop program id
+    ///   5. `[]` Account compression program id
+    ///   6. `[]` System program id
+    InitJobTree {
+        /// Depth of the tree, at most `MAX_JOB_TREE_DEPTH`
+        max_depth: u8,
+        /// Number of recent roots of the merkle tree that proofs of leaves can be of
+        max_buffer_size: u32,
+    },
+
+    // 68
+    /// Transfer funds into an Escrow and append an open compressed Job with the amount to the
+    /// JobTree of the authority, identified by the index of its leaf. The leaf's preimage is logged
+    /// through the noop program so clients can build proofs of its leaf.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[signer]` Job authority
+    ///   1. `[writable]` Source RNDR token account
+    ///      $authority can transfer $amount
+    ///   2. `[signer]` Source token account authority ($authority)
+    ///   3. `[writable]` JobTree PDA account
+    ///   4. `[writable]` Escrow PDA account
+    ///   5. `[writable]` Escrow ATA account
+    ///   6. `[]` Token program id
+    ///   7. `[writable]` Merkle tree account of the JobTree
+    ///   8. `[]` Noop program id
+    ///   9. `[]` Account compression program id
+    AppendCompressedJob {
+        /// Amount of RNDR tokens to fund the job with
+        amount: u64,
+    },
+
+    // 69
+    /// Replace the leaf of an unsettled compressed Job with a proof of its current leaf, assigning
+    /// the job to a node, or back to open with the default pubkey. The Job authority can only
+    /// assign an open Job, while a committed Job can only be reassigned or reopened with the
+    /// signature of the node it's committed to.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[signer]` Job authority
+    ///   1. `[]` Escrow PDA account
+    ///   2. `[]` JobTree PDA account
+    ///   3. `[writable]` Merkle tree account of the JobTree
+    ///   4. `[]` Noop program id
+    ///   5. `[]` Account compression program id
+    ///
+    /// Optionally, if the Job is committed:
+    ///
+    ///   6. `[signer]` Node the Job is committed to
+    ///
+    /// Followed by the proof of the Job's current leaf:
+    ///
+    ///   7. ..7+N `[]` Sibling hashes from the leaf up, one for each level of the tree below its
+    ///      canopy
+    ReplaceCompressedJob {
+        /// Current state of the compressed job
+        job: CompressedJob,
+        /// Node to assign the job to
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+        node: Pubkey,
+        /// Recent root of the merkle tree that the proof is of
+        root: [u8; HASH_BYTES],
+    },
+
+    // 70
+    /// Settle a compressed Job with a proof of its current leaf, paying its tokens to the node it's
+    /// assigned to, or refunding them if it's still open, and replacing its leaf with a paid or
+    /// cancelled job of no tokens
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[signer]` Job authority
+    ///   1. `[writable]` Escrow PDA account
+    ///   2. `[writable]` Escrow ATA account
+    ///   3. `[writable]` JobTree PDA account
+    ///   4. `[writable]` Destination SPL Token account, owned by the node if the job is assigned
+    ///   5. `[]` Token program id
+    ///   6. `[writable]` Merkle tree account of the JobTree
+    ///   7. `[]` Noop program id
+    ///   8. `[]` Account compression program id
+    ///   9. ..9+N `[]` Sibling hashes from the leaf up, one for each level of the tree below its
+    ///      canopy
+    SettleCompressedJob {
+        /// Current state of the compressed job
+        job: CompressedJob,
+        /// Recent root of the merkle tree that the proof is of
+        root: [u8; HASH_BYTES],
+    },
+
+    // 71
+    /// Create the JobIndex of an authority in an Escrow, which records the identifiers of the
+    /// authority's jobs in JobIndexPage accounts as they're created.
+    ///
+    /// The JobIndex PDA account and the JobIndexPage PDA account of its next page can then be
+    /// appended, both writable, after all the other accounts of `FundJob`, `FundIsolatedJob`,
+    /// `FundJobs`, `FundJobWithVoucher`, `ProcessSubscription` and `FundJobBundle`, and before the
+    /// Stats PDA account, to record the jobs they create. The funder pays to create the page.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable,signer]` Job authority, pays to create the JobIndex
+    ///   1. `[]` Escrow PDA account
+    ///   2. `[writable]` JobIndex PDA account of the Escrow and authority
+    ///   3. `[]` System program id
+    InitJobIndex,
+
+    // 72
+    /// Set the metadata of an open Job, which is the URI and content hash of its off-chain
+    /// manifest, cleared with an empty URI, and the tags of the capabilities it requires
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable,signer]` Job authority, pays to reallocate legacy job accounts
+    ///   1. `[]` Escrow PDA account
+    ///   2. `[writable]` Job PDA account
+    ///   3. `[]` System program id
+    UpdateJobMetadata {
+        /// Metadata to set on the job
+        metadata: JobMetadata,
+    },
+
+    // 73
+    /// Move the pooled tokens of an Escrow to a new token account, which holds them from then on,
+    /// such as when moving them to or from its associated token account or after the token
+    /// account was compromised. The new token account must be owned by the Escrow PDA, without a
+    /// delegate or close authority. Isolated jobs keep their own token accounts.
+    ///
+    /// Instructions built for the Escrow afterwards must pass the new token account in place of
+    /// the Escrow ATA account, see `with_escrow_token_account`.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` Escrow PDA account
+    ///   1. `[writable,signer]` Owner authority, pays to reallocate legacy Escrows
+    ///   2. `[writable]` Current token account of the Escrow
+    ///   3. `[writable]` New token account of the Escrow
+    ///   4. `[]` System program id
+    ///   5. `[]` Token program id
+    SetEscrowTokenAccount,
+
+    // 74
+    /// Begin the migration of an Escrow to a new token mint of the token program, such as from
+    /// RNDR to RENDER, creating the MintMigration and the associated token account of the Escrow
+    /// for the new mint. Amounts are converted at the rate of the numerator over the denominator,
+    /// rounded down. The governance authority of the Config approves the migration and the token
+    /// account of the old mint that the Escrow's old tokens will be sent to, and the migration
+    /// can't begin during an incident. The migration continues with `ConvertEscrowMintVault` once
+    /// the timelock of the Config has passed, then `RedenominateJobs` and
+    /// `ConvertIsolatedJobMint`, which can each be resumed.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[]` Escrow PDA account
+    ///   1. `[writable,signer]` Owner authority, pays to create the MintMigration
+    ///   2. `[]` Config PDA account
+    ///   3. `[signer]` Governance authority of the Config
+    ///   4. `[writable]` MintMigration PDA account for the Escrow's number of migrations
+    ///   5. `[]` New SPL Token mint
+    ///   6. `[writable]` Escrow ATA account of the new mint
+    ///   7. `[]` Destination token account of the old mint
+    ///   8. `[]` System program id
+    ///   9. `[]` Token program id
+    ///   10. `[]` Associated Token Account program id
+    ///
+    /// Optionally, required if the Escrow has an approver:
+    ///
+    ///   11. `[signer]` Approver authority of the Escrow
+    MigrateEscrowMint {
+        /// Numerator of the conversion rate
+        rate_numerator: u64,
+        /// Denominator of the conversion rate, which can't be zero
+        rate_denominator: u64,
+    },
+
+    // 75
+    /// Convert the pooled tokens of an Escrow being migrated to a new token mint, aggregating its
+    /// escrow shards first. The owner deposits the converted amount of new tokens into the Escrow
+    /// ATA account of the new mint, and every old token is sent in exchange to the destination
+    /// token account approved when the migration began, to convert them off-chain. The Escrow then
+    /// uses the new mint, its tokens are held in the new ATA account and its jobs must be migrated
+    /// before they can be funded or paid. The timelock of the migration must have passed, and
+    /// the incident mode of the Escrow must allow disbursing.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` Escrow PDA account
+    ///   1. `[writable,signer]` Owner authority, pays to reallocate legacy Escrows
+    ///      $owner can transfer the converted amount from the source token account
+    ///   2. `[writable]` MintMigration PDA account
+    ///   3. `[writable]` Current token account of the Escrow
+    ///   4. `[writable]` Escrow ATA account of the new mint
+    ///   5. `[writable]` Source token account of the new mint
+    ///   6. `[writable]` Destination token account of the old mint approved for the MintMigration
+    ///   7. `[]` System program id
+    ///   8. `[]` Token program id
+    ///   9. ..9+N `[writable]` Every EscrowShard PDA account of the Escrow, in index order
+    ConvertEscrowMintVault,
+
+    // 76
+    /// Re-denominate the amounts of jobs of an Escrow whose pooled tokens were converted by a
+    /// mint migration, which jobs must be migrated by in order. Jobs that were already
+    /// re-denominated by the migration are skipped, so batches can be retried. Anyone can
+    /// re-denominate jobs.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[]` Escrow PDA account
+    ///   1. `[writable]` MintMigration PDA account
+    ///   2. `[writable,signer]` Payer SOL account, pays to reallocate legacy jobs
+    ///   3. `[]` System program id
+    ///   4. ..4+N `[writable]` Job PDA accounts whose tokens are held by the Escrow
+    RedenominateJobs,
+
+    // 77
+    /// Convert the tokens of an isolated job of an Escrow whose pooled tokens were converted by a
+    /// mint migration, like `ConvertEscrowMintVault` does for the Escrow, creating the job's ATA
+    /// account of the new mint. The job's old ATA account is left empty. Frozen jobs can't be
+    /// converted, and the incident mode of the Escrow must allow disbursing.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[]` Escrow PDA account
+    ///   1. `[writable,signer]` Owner authority, pays to create the new job ATA account and to
+    ///      reallocate legacy jobs
+    ///      $owner can transfer the converted amount from the source token account
+    ///   2. `[writable]` MintMigration PDA account
+    ///   3. `[writable]` Job PDA account
+    ///   4. `[writable]` Job ATA account of the old mint
+    ///   5. `[writable]` Job ATA account of the new mint
+    ///   6. `[]` New SPL Token mint
+    ///   7. `[writable]` Source token account of the new mint
+    ///   8. `[writable]` Destination token account of the old mint approved for the MintMigration
+    ///   9. `[]` System program id
+    ///   10. `[]` Token program id
+    ///   11. `[]` Associated Token Account program id
+    ConvertIsolatedJobMint,
+
+    // 78
+    /// Initialize an Escrow in a namespace, like `InitEscrow` does for the default escrow of the
+    /// token mint, whose address is derived from the escrow id so several escrows of the same
+    /// token mint can coexist. An escrow id of zero initializes the default escrow.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[]` RNDR SPL Token mint
+    ///   1. `[writable,signer]` Funder SOL account
+    ///   2. `[writable]` Escrow PDA account
+    ///   3. `[writable]` Escrow ATA account
+    ///   4. `[]` System program id
+    ///   5. `[]` Token program id
+    ///   6. `[]` Associated Token Account program id
+    InitEscrowWithSeed {
+        /// Owner authority that can disburse funds
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+        owner: Pubkey,
+        /// Namespace of the escrow
+        escrow_id: u64,
+    },
+
+    // 79
+    /// Raise the priority of an open Job, paying the config's priority surcharge on the Job's
+    /// amount for each level raised to the Treasury of its Escrow. Nodes can commit to a Job of a
+    /// lower priority only once the config's priority claim delay has passed for each level below
+    /// `MAX_JOB_PRIORITY`, so Jobs of a higher priority are claimed first.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable,signer]` Job authority ($authority), pays to reallocate legacy Jobs
+    ///   1. `[]` Escrow PDA account
+    ///   2. `[writable]` Job PDA account
+    ///   3. `[writable]` Source token account
+    ///      $authority can transfer the surcharge
+    ///   4. `[]` Treasury PDA account
+    ///   5. `[writable]` Treasury ATA account
+    ///   6. `[]` Config PDA account
+    ///   7. `[]` System program id
+    ///   8. `[]` Token program id
+    RaiseJobPriority {
+        /// Priority of the Job, at most `MAX_JOB_PRIORITY`
+        priority: u8,
+    },
+
+    // 80
+    /// Create the JobQueue of an Escrow, which Jobs created by `FundJob` are pushed to when it's
+    /// appended and which nodes claim them from with `ClaimJob`. Anyone can create the JobQueue.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable,signer]` Funder SOL account, pays to create the JobQueue
+    ///   1. `[]` Escrow PDA account
+    ///   2. `[writable]` JobQueue PDA account of the Escrow
+    ///   3. `[]` System program id
+    InitJobQueue,
+
+    // 81
+    /// Pop the oldest Job of a JobQueue and commit to its result, assigning the Job to the node
+    /// like `CommitResult` does. A Job that was cancelled or committed to since it was queued is
+    /// popped without being claimed, and a Job whose priority claim delay hasn't passed yet is
+    /// moved to the back of the queue, so neither blocks the Jobs behind it.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable,signer]` Node SOL account, pays to reallocate legacy Jobs
+    ///   1. `[writable]` JobQueue PDA account
+    ///   2. `[writable]` Job PDA account of the oldest Job of the JobQueue
+    ///   3. `[]` Config PDA account
+    ///   4. `[]` System program id
+    ClaimJob {
+        /// Commitment to the result, see `Job::result_commitment`
+        commitment: [u8; HASH_BYTES],
+    },
+
+    // 82
+    /// Record the exchange rate a Job funded in a token other than RNDR is settled at, in RNDR
+    /// base units per base unit of the Escrow's token, see `ExchangeRate`. The rate is derived from
+    /// the live price of the Escrow's price feed, read as the price of one whole token in RNDR, see
+    /// `ExchangeRate::try_from_price`, and the instruction fails if the price is older or less
+    /// certain than the oracle price guards of the Config allow. The rate and the publish slot of
+    /// the price can only be recorded once and before the Job is settled, so all of the Job's
+    /// amounts are converted at the same rate. Disbursing, claiming or cancelling the Job then logs
+    /// the value in RNDR of the tokens settled, the work credited for its payment is its value in
+    /// RNDR, and the Config's minimum disbursement is converted to the token when its cancellation
+    /// is split.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable,signer]` Owner authority, pays to reallocate legacy Jobs
+    ///   1. `[]` Escrow PDA account
+    ///   2. `[writable]` Job PDA account
+    ///   3. `[]` Price feed account of the Escrow
+    ///   4. `[]` Token mint of the Escrow
+    ///   5. `[]` Config PDA account
+    ///   6. `[]` System program id
+    SetJobExchangeRate,
+
+    // 83
+    /// Estimate the funding amount of a Job from the OctaneBench-hour price of a price tier in the
+    /// Config, see `Config::job_cost`, so clients derive it the same way the program does. The
+    /// amount is set as the return data of the transaction, as a little-endian u64 in RNDR base
+    /// units, and the instruction fails if the price tier is disabled.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[]` Config PDA account
+    EstimateJobCost {
+        /// Index of the price tier, below `MAX_PRICE_TIERS`
+        tier: u8,
+        /// Work of the Job, in thousandths of an OctaneBench-hour
+        milli_obh: u64,
+    },
+
+    // 84
+    /// Set the oracle price account of an Escrow's token that Jobs are funded at with
+    /// `FundJobAtOraclePrice`, or the default pubkey so Jobs can't be funded at an oracle price
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` Escrow PDA account
+    ///   1. `[writable,signer]` Owner authority, pays to reallocate legacy Escrows
+    ///   2. `[]` System program id
+    SetPriceFeed {
+        /// Price account of the Escrow's token, see `OraclePrice`
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+        price_feed: Pubkey,
+    },
+
+    // 85
+    /// Transfer funds into an Escrow and credit a Job like `FundJob` does, with the amount of
+    /// tokens converted from a value in the quote currency of the Escrow's price feed at its live
+    /// price, see `OraclePrice::try_token_amount`. The instruction fails if the amount exceeds the
+    /// maximum the funder signed for, so a price swing between signing and execution can't charge
+    /// the funder more than they expected, and if the price is older or less certain than the oracle
+    /// price guards of the Config allow. Unless the Job already has an exchange rate, the rate of
+    /// the price and its publish slot are recorded on it as with `SetJobExchangeRate`, so its
+    /// amounts are settled at the price it was funded at.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[]` Price feed account of the Escrow
+    ///   1. `[]` Token mint of the Escrow
+    ///   2. `[]` Config PDA account
+    ///   3. `[writable,signer]` Funder SOL account
+    ///   4. `[writable]` Source RNDR token account
+    ///      $authority can transfer the converted amount
+    ///   5. `[signer]` Source token account authority ($authority)
+    ///   6. `[]` Escrow PDA account
+    ///   7. `[writable]` Escrow ATA account
+    ///   8. `[writable]` Job PDA account
+    ///   9. `[writable]` Escrow shard PDA account for the job
+    ///   10. `[]` System program id
+    ///   11. `[]` Token program id
+    ///
+    /// Optionally, to top up an existing Job with a session key of its authority instead, as
+    /// with `FundJob`:
+    ///
+    ///   12. `[]` SessionKey PDA account of the Escrow, Job authority and session key
+    FundJobAtOraclePrice {
+        /// Value of the Job in the quote currency of the price feed, with
+        /// `PRICED_VALUE_DECIMALS` decimals
+        value: u64,
+        /// Maximum amount of RNDR tokens to escrow for the value
+        max_rndr_amount: u64,
+        /// Identifier of the job, unique for the authority
+        job_id: u64,
+    },
+
+    // 86
+    /// Freeze a Job under a legal hold, so its funds can't be disbursed or refunded until it's
+    /// unfrozen with `UnfreezeJob`. Only the Job is held, so the rest of the Escrow keeps working.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable,signer]` Compliance authority of the Config, pays to reallocate legacy Jobs
+    ///   1. `[]` Config PDA account
+    ///   2. `[]` Escrow PDA account
+    ///   3. `[writable]` Job PDA account
+    ///   4. `[]` System program id
+    FreezeJob,
+
+    // 87
+    /// Unfreeze a Job frozen with `FreezeJob`, releasing its legal hold
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[signer]` Compliance authority of the Config
+    ///   1. `[]` Config PDA account
+    ///   2. `[]` Escrow PDA account
+    ///   3. `[writable]` Job PDA account
+    UnfreezeJob,
+
+    // 88
+    /// Copy the incident mode of the Config, set by its governance authority with `SetConfig`, to
+    /// an Escrow, so Jobs can't be funded, or their funds can't be disbursed or refunded, while the
+    /// other direction keeps working, see `IncidentMode`
+    ///
+    /// Funding and disbursing instructions only unpack the Escrow, so they enforce the incident
+    /// mode it was last synced to. Anyone can sync an Escrow, so the governance authority should
+    /// sync every Escrow in the transaction that changes the incident mode, or right after a queued
+    /// change is applied.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` Escrow PDA account
+    ///   1. `[]` Config PDA account
+    ///   2. `[writable,signer]` Payer account, pays to reallocate legacy Escrows
+    ///   3. `[]` System program id
+    SyncIncidentMode,
+
+    // 89
+    /// Register the cold wallet the Treasury of an Escrow can be swept to with
+    /// `ScheduleTreasurySweep`, cancelling any sweep scheduled to the previous cold wallet
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable,signer]` Escrow owner authority, pays to reallocate legacy Treasuries
+    ///   1. `[]` Escrow PDA account
+    ///   2. `[writable]` Treasury PDA account
+    ///   3. `[]` Treasury ATA account
+    ///   4. `[]` Cold wallet token account of the Treasury's token mint
+    ///   5. `[]` System program id
+    RegisterColdWallet,
+
+    // 90
+    /// Schedule a sweep of tokens held by the Treasury of an Escrow to its registered cold wallet,
+    /// which can be executed with `SweepToColdWallet` once the sweep delay of the Config has
+    /// passed, and cancelled by the guardian of the Config with `CancelTreasurySweep` until then
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[signer]` Escrow owner authority
+    ///   1. `[]` Config PDA account
+    ///   2. `[]` Escrow PDA account
+    ///   3. `[writable]` Treasury PDA account
+    ///   4. `[]` Treasury ATA account
+    ScheduleTreasurySweep {
+        /// Amount of tokens to sweep
+        amount: u64,
+    },
+
+    // 91
+    /// Execute the scheduled sweep of the Treasury of an Escrow to its cold wallet once the sweep
+    /// delay has passed, which anyone can crank
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[]` Escrow PDA account
+    ///   1. `[writable]` Treasury PDA account
+    ///   2. `[writable]` Treasury ATA account
+    ///   3. `[writable]` Cold wallet token account of the Treasury
+    ///   4. `[]` Token program id
+    SweepToColdWallet,
+
+    // 92
+    /// Cancel the scheduled sweep of the Treasury of an Escrow before it can be executed
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[signer]` Guardian authority of the Config
+    ///   1. `[]` Config PDA account
+    ///   2. `[]` Escrow PDA account
+    ///   3. `[writable]` Treasury PDA account
+    ///   4. `[]` Treasury ATA account
+    CancelTreasurySweep,
+
+    // 93
+    /// Report the version of the deployed program, see `version::VERSION`. The crate version and
+    /// layout version are set as the return data of the transaction, packed as a
+    /// `ProgramVersion`.
+    ///
+    /// No accounts are expected by this instruction.
+    GetVersion,
+
+    // 94
+    /// Report a summary of an Escrow, see `summary::EscrowSummary`, so CPI callers and simulating
+    /// clients can read it without decoding the account. The summary is set as the return data
+    /// of the transaction.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[]` Escrow PDA account
+    GetEscrowSummary,
+
+    // 95
+    /// Report a summary of a Job, see `summary::JobSummary`, so CPI callers and simulating clients
+    /// can read it without decoding the account. The summary is set as the return data of the
+    /// transaction.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[]` Escrow PDA account
+    ///   1. `[]` Job PDA account of the Escrow
+    GetJobSummary,
+
+    // 96
+    /// Report the amount of tokens in escrow for a Job, so other programs can read it as
+    /// collateral data by CPI. The amount is set as the return data of the transaction, as a
+    /// little-endian u64 in base units of the Escrow's token mint, a layout that won't change.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[]` Escrow PDA account
+    ///   1. `[]` Job PDA account of the Escrow
+    GetEscrowedAmount,
+
+    // 97
+    /// Reconcile the balance of an Escrow's token account with the amount of tokens the Escrow
+    /// tracks, which is its amount and the amounts pending in its escrow shards. Anyone can
+    /// reconcile an Escrow. When the balance falls short, an `EscrowDiscrepancyFound` event is
+    /// logged and the Escrow is flagged as needing attention, and the flag is cleared by a later
+    /// reconciliation that finds the balance covers the tracked amount.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` Escrow PDA account
+    ///   1. `[]` Token account of the Escrow
+    ///   2. `[writable,signer]` Payer, pays to reallocate legacy Escrows
+    ///   3. `[]` System program id
+    ///   4. ..4+N `[]` Every EscrowShard PDA account of the Escrow, in index order
+    ReconcileEscrow,
+}
+
+impl RNDRInstruction {
+    /// Unpacks a byte buffer into a [RNDRInstruction](enum.RNDRInstruction.html).
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        let (&tag, rest) = input
+            .split_first()
+            .ok_or(RNDRError::InstructionUnpackError)?;
+        Ok(match tag {
+            0 => {
+                let (owner, _rest) = Self::unpack_pubkey(rest)?;
+                Self::InitEscrow { owner }
+            }
+            1 => {
+                let (new_owner, _rest) = Self::unpack_pubkey(rest)?;
+                Self::SetEscrowOwner { new_owner }
+            }
+            2 => {
+                let (amount, rest) = Self::unpack_u64(rest)?;
+                let (job_id, rest) = Self::unpack_u64(rest)?;
+                let metadata = if rest.is_empty() {
+                    None
+                } else {
+                    let (metadata, _rest) = Self::unpack_job_metadata(rest)?;
+                    Some(metadata)
+                };
+                Self::FundJob {
+                    amount,
+                    job_id,
+                    metadata,
+                }
+            }
+            3 => {
+                let (amount, _rest) = Self::unpack_u64(rest)?;
+                Self::DisburseFunds { amount }
+            }
+            4 => Self::AggregateEscrowShard,
+            5 => {
+                let (amount, rest) = Self::unpack_u64(rest)?;
+                let (job_id, _rest) = Self::unpack_u64(rest)?;
+                Self::FundIsolatedJob { amount, job_id }
+            }
+            6 => {
+                let (amount, _rest) = Self::unpack_u64(rest)?;
+                Self::DisburseIsolatedJobFunds { amount }
+            }
+            7 => {
+                let (len, mut rest) = Self::unpack_u8(rest)?;
+                let len = len as usize;
+                if len > MAX_DISBURSE_BATCH_LEN {
+                    msg!("Too many amounts to disburse");
+                    return Err(RNDRError::InstructionUnpackError.into());
+                }
+                let mut amounts = Vec::with_capacity(len);
+                for _ in 0..len {
+                    let (amount, next) = Self::unpack_u64(rest)?;
+                    amounts.push(amount);
+                    rest = next;
+                }
+                Self::DisburseBatch { amounts }
+            }
+            8 => {
+                let (len, mut rest) = Self::unpack_u8(rest)?;
+                let len = len as usize;
+                if len > MAX_FUND_JOBS_LEN {
+                    msg!("Too many jobs to fund");
+                    return Err(RNDRError::InstructionUnpackError.into());
+                }
+                let mut jobs = Vec::with_capacity(len);
+                for _ in 0..len {
+                    let (job_id, next) = Self::unpack_u64(rest)?;
+                    let (amount, next) = Self::unpack_u64(next)?;
+                    jobs.push((job_id, amount));
+                    rest = next;
+                }
+                Self::FundJobs { jobs }
+            }
+            9 => {
+                let (commitment, _rest) = Self::unpack_hash(rest)?;
+                Self::CommitResult { commitment }
+            }
+            10 => {
+                let (result_hash, rest) = Self::unpack_hash(rest)?;
+                let (salt, rest) = Self::unpack_hash(rest)?;
+                Self::RevealResult {
+                    result_hash,
+                    salt,
+                    proof: rest.to_vec(),
+                }
+            }
+            11 => Self::ClaimPayment,
+            12 => {
+                let (challenge_window, _rest) = Self::unpack_u64(rest)?;
+                Self::SetChallengeWindow { challenge_window }
+            }
+            13 => Self::ChallengeResult,
+            14 => {
+                let (verifier, _rest) = Self::unpack_pubkey(rest)?;
+                Self::AddVerifier { verifier }
+            }
+            15 => {
+                let (verifier, _rest) = Self::unpack_pubkey(rest)?;
+                Self::RemoveVerifier { verifier }
+            }
+            16 => {
+                let (evidence_hash, _rest) = Self::unpack_hash(rest)?;
+                Self::FlagJob { evidence_hash }
+            }
+            17 => {
+                let (count, _rest) = Self::unpack_u8(rest)?;
+                Self::SelectVerifiers { count }
+            }
+            18 => {
+                let (proof_verifier, _rest) = Self::unpack_pubkey(rest)?;
+                Self::SetProofVerifier { proof_verifier }
+            }
+            19 => {
+                let (arbitrator, _rest) = Self::unpack_pubkey(rest)?;
+                Self::SetArbitrator { arbitrator }
+            }
+            20 => Self::OpenDispute,
+            21 => {
+                let (evidence_hash, _rest) = Self::unpack_hash(rest)?;
+                Self::SubmitEvidence { evidence_hash }
+            }
+            22 => {
+                let (node_amount, _rest) = Self::unpack_u64(rest)?;
+                Self::ResolveDispute { node_amount }
+            }
+            23 => {
+                let (governance, _rest) = Self::unpack_pubkey(rest)?;
+                Self::InitConfig { governance }
+            }
+            24 => {
+                let (change, _rest) = Self::unpack_config_change(rest)?;
+                Self::SetConfig { change }
+            }
+            25 => Self::VerifyUpgradeAuthority,
+            26 => {
+                let (approver, _rest) = Self::unpack_pubkey(rest)?;
+                Self::SetApprover { approver }
+            }
+            27 => {
+                let (proposal_id, rest) = Self::unpack_u64(rest)?;
+                let (amount, _rest) = Self::unpack_u64(rest)?;
+                Self::ProposeDisbursement {
+                    proposal_id,
+                    amount,
+                }
+            }
+            28 => Self::ApproveDisbursement,
+            29 => Self::ExecuteDisbursement,
+            30 => {
+                let (action_id, rest) = Self::unpack_u64(rest)?;
+                let (action, _rest) = Self::unpack_admin_action(rest)?;
+                Self::QueueAction { action_id, action }
+            }
+            31 => Self::ExecuteQueuedAction,
+            32 => Self::CancelQueuedAction,
+            33 => {
+                let (threshold, rest) = Self::unpack_u8(rest)?;
+                let (len, mut rest) = Self::unpack_u8(rest)?;
+                let len = len as usize;
+                if len > MAX_RECOVERY_GUARDIANS {
+                    msg!("Too many recovery guardians");
+                    return Err(RNDRError::InstructionUnpackError.into());
+                }
+                let mut guardians = Vec::with_capacity(len);
+                for _ in 0..len {
+                    let (guardian, next) = Self::unpack_pubkey(rest)?;
+                    guardians.push(guardian);
+                    rest = next;
+                }
+                Self::SetRecoveryCouncil {
+                    threshold,
+                    guardians,
+                }
+            }
+            34 => {
+                let (new_owner, _rest) = Self::unpack_pubkey(rest)?;
+                Self::ApproveRecovery { new_owner }
+            }
+            35 => Self::ExecuteRecovery,
+            36 => {
+                let (rotation_id, rest) = Self::unpack_u64(rest)?;
+                let (new_owner, _rest) = Self::unpack_pubkey(rest)?;
+                Self::ScheduleOwnerRotation {
+                    rotation_id,
+                    new_owner,
+                }
+            }
+            37 => {
+                let (epoch, rest) = Self::unpack_u64(rest)?;
+                let (amount, _rest) = Self::unpack_u64(rest)?;
+                Self::FundRewardPool { epoch, amount }
+            }
+            38 => Self::ClaimEpochReward,
+            39 => {
+                let (epoch, rest) = Self::unpack_u64(rest)?;
+                let (root, rest) = Self::unpack_hash(rest)?;
+                let (node_count, rest) = Self::unpack_u32(rest)?;
+                let (amount, _rest) = Self::unpack_u64(rest)?;
+                Self::PublishMerkleRoot {
+                    epoch,
+                    root,
+                    node_count,
+                    amount,
+                }
+            }
+            40 => {
+                let (index, rest) = Self::unpack_u32(rest)?;
+                let (amount, rest) = Self::unpack_u64(rest)?;
+                let (len, mut rest) = Self::unpack_u8(rest)?;
+                let len = len as usize;
+                if len > MAX_MERKLE_PROOF_LEN {
+                    msg!("Merkle proof is too long");
+                    return Err(RNDRError::InstructionUnpackError.into());
+                }
+                let mut proof = Vec::with_capacity(len);
+                for _ in 0..len {
+                    let (hash, next) = Self::unpack_hash(rest)?;
+                    proof.push(hash);
+                    rest = next;
+                }
+                Self::ClaimMerkleReward {
+                    index,
+                    amount,
+                    proof,
+                }
+            }
+            41 => {
+                let (start_epoch, rest) = Self::unpack_u64(rest)?;
+                let (initial_amount, rest) = Self::unpack_u64(rest)?;
+                let (decay_bps, _rest) = Self::unpack_rate(rest)?;
+                Self::SetEmissionSchedule {
+                    start_epoch,
+                    initial_amount,
+                    decay_bps,
+                }
+            }
+            42 => Self::EmitRewards,
+            43 => {
+                let (amount, _rest) = Self::unpack_u64(rest)?;
+                Self::Stake { amount }
+            }
+            44 => {
+                let (amount, _rest) = Self::unpack_u64(rest)?;
+                Self::Unstake { amount }
+            }
+            45 => {
+                let (auto_compound, _rest) = Self::unpack_u8(rest)?;
+                let auto_compound = match auto_compound {
+                    0 => false,
+                    1 => true,
+                    _ => {
+                        msg!("Auto-compound flag cannot be unpacked");
+                        return Err(RNDRError::InstructionUnpackError.into());
+                    }
+                };
+                Self::SetAutoCompound { auto_compound }
+            }
+            46 => Self::CompoundRewards,
+            47 => {
+                let (amount, _rest) = Self::unpack_u64(rest)?;
+                Self::LiquidStake { amount }
+            }
+            48 => {
+                let (receipt_amount, _rest) = Self::unpack_u64(rest)?;
+                Self::LiquidUnstake { receipt_amount }
+            }
+            49 => {
+                let (amount, _rest) = Self::unpack_u64(rest)?;
+                Self::DepositStakeRewards { amount }
+            }
+            50 => {
+                let (bounty, _rest) = Self::unpack_u64(rest)?;
+                Self::SetCrankBounty { bounty }
+            }
+            51 => {
+                let (epoch_burn_cap, _rest) = Self::unpack_u64(rest)?;
+                Self::SetTreasuryBurnCap { epoch_burn_cap }
+            }
+            52 => {
+                let (amount, _rest) = Self::unpack_u64(rest)?;
+                Self::BurnTreasury { amount }
+            }
+            53 => Self::InitStats,
+            54 => {
+                let (amount, rest) = Self::unpack_u64(rest)?;
+                let (job_id, rest) = Self::unpack_u64(rest)?;
+                let (nonce, rest) = Self::unpack_u64(rest)?;
+                let (expires_at, _rest) = Self::unpack_i64(rest)?;
+                Self::FundJobWithVoucher {
+                    amount,
+                    job_id,
+                    nonce,
+                    expires_at,
+                }
+            }
+            55 => {
+                let (session_key, rest) = Self::unpack_pubkey(rest)?;
+                let (expiry_slot, rest) = Self::unpack_u64(rest)?;
+                let (instruction_mask, _rest) = Self::unpack_u64(rest)?;
+                Self::RegisterSessionKey {
+                    session_key,
+                    expiry_slot,
+                    instruction_mask,
+                }
+            }
+            56 => {
+                let (delegate, rest) = Self::unpack_pubkey(rest)?;
+                let (limit, rest) = Self::unpack_u64(rest)?;
+                let (expiry_slot, _rest) = Self::unpack_u64(rest)?;
+                Self::ApproveDelegate {
+                    delegate,
+                    limit,
+                    expiry_slot,
+                }
+            }
+            57 => Self::RevokeDelegate,
+            58 => {
+                let (job_id, rest) = Self::unpack_u64(rest)?;
+                let (amount, rest) = Self::unpack_u64(rest)?;
+                let (period, _rest) = Self::unpack_u64(rest)?;
+                Self::CreateSubscription {
+                    job_id,
+                    amount,
+                    period,
+                }
+            }
+            59 => Self::ProcessSubscription,
+            60 => Self::CancelJob,
+            61 => {
+                let (new_authority, _rest) = Self::unpack_pubkey(rest)?;
+                Self::TransferJobAuthority { new_authority }
+            }
+            62 => Self::AcceptJobAuthority,
+            63 => {
+                let (bundle_id, rest) = Self::unpack_u64(rest)?;
+                let (first_job_id, rest) = Self::unpack_u64(rest)?;
+                let (job_count, rest) = Self::unpack_u8(rest)?;
+                let (metadata_hash, _rest) = Self::unpack_hash(rest)?;
+                Self::CreateJobBundle {
+                    bundle_id,
+                    first_job_id,
+                    job_count,
+                    metadata_hash,
+                }
+            }
+            64 => {
+                let (first_job_id, rest) = Self::unpack_u64(rest)?;
+                let (count, rest) = Self::unpack_u8(rest)?;
+                let (amount_per_job, _rest) = Self::unpack_u64(rest)?;
+                Self::FundJobBundle {
+                    first_job_id,
+                    count,
+                    amount_per_job,
+                }
+            }
+            65 => Self::CancelJobBundle,
+            66 => Self::AggregateJobBundle,
+            67 => {
+                let (max_depth, rest) = Self::unpack_u8(rest)?;
+                let (max_buffer_size, _rest) = Self::unpack_u32(rest)?;
+                Self::InitJobTree {
+                    max_depth,
+                    max_buffer_size,
+                }
+            }
+            68 => {
+                let (amount, _rest) = Self::unpack_u64(rest)?;
+                Self::AppendCompressedJob { amount }
+            }
+            69 => {
+                let (job, rest) = Self::unpack_compressed_job(rest)?;
+                let (node, rest) = Self::unpack_pubkey(rest)?;
+                let (root, _rest) = Self::unpack_hash(rest)?;
+                Self::ReplaceCompressedJob { job, node, root }
+            }
+            70 => {
+                let (job, rest) = Self::unpack_compressed_job(rest)?;
+                let (root, _rest) = Self::unpack_hash(rest)?;
+                Self::SettleCompressedJob { job, root }
+            }
+            71 => Self::InitJobIndex,
+            72 => {
+                let (metadata, _rest) = Self::unpack_job_metadata(rest)?;
+                Self::UpdateJobMetadata { metadata }
+            }
+            73 => Self::SetEscrowTokenAccount,
+            74 => {
+                let (rate_numerator, rest) = Self::unpack_u64(rest)?;
+                let (rate_denominator, _rest) = Self::unpack_u64(rest)?;
+                Self::MigrateEscrowMint {
+                    rate_numerator,
+                    rate_denominator,
+                }
+            }
+            75 => Self::ConvertEscrowMintVault,
+            76 => Self::RedenominateJobs,
+            77 => Self::ConvertIsolatedJobMint,
+            78 => {
+                let (owner, rest) = Self::unpack_pubkey(rest)?;
+                let (escrow_id, _rest) = Self::unpack_u64(rest)?;
+                Self::InitEscrowWithSeed { owner, escrow_id }
+            }
+            79 => {
+                let (priority, _rest) = Self::unpack_u8(rest)?;
+                Self::RaiseJobPriority { priority }
+            }
+            80 => Self::InitJobQueue,
+            81 => {
+                let (commitment, _rest) = Self::unpack_hash(rest)?;
+                Self::ClaimJob { commitment }
+            }
+            82 => Self::SetJobExchangeRate,
+            83 => {
+                let (tier, rest) = Self::unpack_u8(rest)?;
+                let (milli_obh, _rest) = Self::unpack_u64(rest)?;
+                Self::EstimateJobCost { tier, milli_obh }
+            }
+            84 => {
+                let (price_feed, _rest) = Self::unpack_pubkey(rest)?;
+                Self::SetPriceFeed { price_feed }
+            }
+            85 => {
+                let (value, rest) = Self::unpack_u64(rest)?;
+                let (max_rndr_amount, rest) = Self::unpack_u64(rest)?;
+                let (job_id, _rest) = Self::unpack_u64(rest)?;
+                Self::FundJobAtOraclePrice {
+                    value,
+                    max_rndr_amount,
+                    job_id,
+                }
+            }
+            86 => Self::FreezeJob,
+            87 => Self::UnfreezeJob,
+            88 => Self::SyncIncidentMode,
+            89 => Self::RegisterColdWallet,
+            90 => {
+                let (amount, _rest) = Self::unpack_u64(rest)?;
+                Self::ScheduleTreasurySweep { amount }
+            }
+            91 => Self::SweepToColdWallet,
+            92 => Self::CancelTreasurySweep,
+            93 => Self::GetVersion,
+            94 => Self::GetEscrowSummary,
+            95 => Self::GetJobSummary,
+            96 => Self::GetEscrowedAmount,
+            97 => Self::ReconcileEscrow,
+            _ => {
+                msg!("Instruction cannot be unpacked");
+                return Err(RNDRError::InstructionUnpackError.into());
+            }
+        })
+    }
+
+    pub(crate) fn unpack_u8(input: &[u8]) -> Result<(u8, &[u8]), ProgramError> {
+        let (&value, rest) = input.split_first().ok_or_else(|| {
+            msg!("u8 cannot be unpacked");
+            RNDRError::InstructionUnpackError
+        })?;
+        Ok((value, rest))
+    }
+
+    pub(crate) fn unpack_u16(input: &[u8]) -> Result<(u16, &[u8]), ProgramError> {
+        if input.len() < 2 {
+            msg!("u16 cannot be unpacked");
+            return Err(RNDRError::InstructionUnpackError.into());
+        }
+        let (bytes, rest) = input.split_at(2);
+        let value = bytes
+            .try_into()
+            .map(u16::from_le_bytes)
+            .map_err(|_| RNDRError::InstructionUnpackError)?;
+        Ok((value, rest))
+    }
+
+    pub(crate) fn unpack_rate(input: &[u8]) -> Result<(Rate, &[u8]), ProgramError> {
+        let (bps, rest) = Self::unpack_u16(input)?;
+        let rate = Rate::from_bps(bps).ok_or_else(|| {
+            msg!("Rate cannot be unpacked");
+            RNDRError::InstructionUnpackError
+        })?;
+        Ok((rate, rest))
+    }
+
+    pub(crate) fn unpack_u32(input: &[u8]) -> Result<(u32, &[u8]), ProgramError> {
+        if input.len() < 4 {
+            msg!("u32 cannot be unpacked");
+            return Err(RNDRError::InstructionUnpackError.into());
+        }
+        let (bytes, rest) = input.split_at(4);
+        let value = bytes
+            .try_into()
+            .map(u32::from_le_bytes)
+            .map_err(|_| RNDRError::InstructionUnpackError)?;
+        Ok((value, rest))
+    }
+
+    pub(crate) fn unpack_u64(input: &[u8]) -> Result<(u64, &[u8]), ProgramError> {
+        if input.len() < 8 {
+            msg!("u64 cannot be unpacked");
+            return Err(RNDRError::InstructionUnpackError.into());
+        }
+        let (bytes, rest) = input.split_at(8);
+        let value = bytes
+            .get(..8)
+            .and_then(|slice| slice.try_into().ok())
+            .map(u64::from_le_bytes)
+            .ok_or(RNDRError::InstructionUnpackError)?;
+        Ok((value, rest))
+    }
+
+    pub(crate) fn unpack_i64(input: &[u8]) -> Result<(i64, &[u8]), ProgramError> {
+        if input.len() < 8 {
+            msg!("i64 cannot be unpacked");
+            return Err(RNDRError::InstructionUnpackError.into());
+        }
+        let (bytes, rest) = input.split_at(8);
+        let value = bytes
+            .try_into()
+            .map(i64::from_le_bytes)
+            .map_err(|_| RNDRError::InstructionUnpackError)?;
+        Ok((value, rest))
+    }
+
+    pub(crate) fn unpack_pubkey(input: &[u8]) -> Result<(Pubkey, &[u8]), ProgramError> {
+        if input.len() < PUBKEY_BYTES {
+            msg!("Pubkey cannot be unpacked");
+            return Err(RNDRError::InstructionUnpackError.into());
+        }
+        let (key, rest) = input.split_at(PUBKEY_BYTES);
+        let pk = key
+            .try_into()
+            .map(Pubkey::new_from_array)
+            .map_err(|_| RNDRError::InstructionUnpackError)?;
+        Ok((pk, rest))
+    }
+
+    pub(crate) fn unpack_hash(input: &[u8]) -> Result<([u8; HASH_BYTES], &[u8]), ProgramError> {
+        if input.len() < HASH_BYTES {
+            msg!("Hash cannot be unpacked");
+            return Err(RNDRError::InstructionUnpackError.into());
+        }
+        let (hash, rest) = input.split_at(HASH_BYTES);
+        let hash = hash
+            .try_into()
+            .map_err(|_| RNDRError::InstructionUnpackError)?;
+        Ok((hash, rest))
+    }
+
+    fn unpack_compressed_job(input: &[u8]) -> Result<(CompressedJob, &[u8]), ProgramError> {
+        let (id, rest) = Self::unpack_u64(input)?;
+        let (amount, rest) = Self::unpack_u64(rest)?;
+        let (status, rest) = Self::unpack_u8(rest)?;
+        let status = JobStatus::try_from(status).map_err(|_| {
+            msg!("Job status cannot be unpacked");
+            RNDRError::InstructionUnpackError
+        })?;
+        let (node, rest) = Self::unpack_pubkey(rest)?;
+        Ok((
+            CompressedJob {
+                id,
+                amount,
+                status,
+                node,
+            },
+            rest,
+        ))
+    }
+
+    pub(crate) fn unpack_job_metadata(input: &[u8]) -> Result<(JobMetadata, &[u8]), ProgramError> {
+        let (len, rest) = Self::unpack_u8(input)?;
+        let len = len as usize;
+        if len > MAX_JOB_METADATA_URI_LEN || rest.len() < len {
+            msg!("Job metadata URI cannot be unpacked");
+            return Err(RNDRError::InstructionUnpackError.into());
+        }
+        let (uri, rest) = rest.split_at(len);
+        let uri = String::from_utf8(uri.to_vec()).map_err(|_| {
+            msg!("Job metadata URI cannot be unpacked");
+            RNDRError::InstructionUnpackError
+        })?;
+        let (hash, rest) = Self::unpack_hash(rest)?;
+        let (tags, rest) = Self::unpack_u64(rest)?;
+        Ok((JobMetadata { uri, hash, tags }, rest))
+    }
+
+    pub(crate) fn unpack_config_change(
+        input: &[u8],
+    ) -> Result<(ConfigChange, &[u8]), ProgramError> {
+        let (field, rest) = Self::unpack_u8(input)?;
+        Ok(match field {
+            0 => {
+                let (governance, rest) = Self::unpack_pubkey(rest)?;
+                (ConfigChange::Governance(governance), rest)
+            }
+            1 => {
+                let (fee_bps, rest) = Self::unpack_rate(rest)?;
+                (ConfigChange::FeeBps(fee_bps), rest)
+            }
+            2 => {
+                let (min_stake, rest) = Self::unpack_u64(rest)?;
+                (ConfigChange::MinStake(min_stake), rest)
+            }
+            3 => {
+                let (dispute_window, rest) = Self::unpack_u64(rest)?;
+                (ConfigChange::DisputeWindow(dispute_window), rest)
+            }
+            4 => {
+                let (timelock_duration, rest) = Self::unpack_u64(rest)?;
+                (ConfigChange::TimelockDuration(timelock_duration), rest)
+            }
+            5 => {
+                let (rate_limit_window, rest) = Self::unpack_u64(rest)?;
+                (ConfigChange::RateLimitWindow(rate_limit_window), rest)
+            }
+            6 => {
+                let (rate_limit_max, rest) = Self::unpack_u32(rest)?;
+                (ConfigChange::RateLimitMax(rate_limit_max), rest)
+            }
+            7 => {
+                let (guardian, rest) = Self::unpack_pubkey(rest)?;
+                (ConfigChange::Guardian(guardian), rest)
+            }
+            8 => {
+                let (instant_owner_change, rest) = Self::unpack_u8(rest)?;
+                let instant_owner_change = match instant_owner_change {
+                    0 => false,
+                    1 => true,
+                    _ => {
+                        msg!("Instant owner change flag cannot be unpacked");
+                        return Err(RNDRError::InstructionUnpackError.into());
+                    }
+                };
+                (ConfigChange::InstantOwnerChange(instant_owner_change), rest)
+            }
+            9 => {
+                let (timelocked_fields, rest) = Self::unpack_u32(rest)?;
+                (ConfigChange::TimelockedFields(timelocked_fields), rest)
+            }
+            10 => {
+                let (index, rest) = Self::unpack_u8(rest)?;
+                let (min_stake, rest) = Self::unpack_u64(rest)?;
+                let (multiplier_bps, rest) = Self::unpack_u16(rest)?;
+                (
+                    ConfigChange::RewardTier(
+                        index,
+                        RewardTier {
+                            min_stake,
+                            multiplier_bps,
+                        },
+                    ),
+                    rest,
+                )
+            }
+            11 => {
+                let (cancel_grace_period, rest) = Self::unpack_u64(rest)?;
+                (ConfigChange::CancelGracePeriod(cancel_grace_period), rest)
+            }
+            12 => {
+                let (cancel_fee_bps, rest) = Self::unpack_rate(rest)?;
+                (ConfigChange::CancelFeeBps(cancel_fee_bps), rest)
+            }
+            13 => {
+                let (priority_surcharge_bps, rest) = Self::unpack_rate(rest)?;
+                (
+                    ConfigChange::PrioritySurchargeBps(priority_surcharge_bps),
+                    rest,
+                )
+            }
+            14 => {
+                let (priority_claim_delay, rest) = Self::unpack_u64(rest)?;
+                (ConfigChange::PriorityClaimDelay(priority_claim_delay), rest)
+            }
+            15 => {
+                let (rounding_policy, rest) = Self::unpack_u8(rest)?;
+                let rounding_policy = RoundingPolicy::try_from(rounding_policy).map_err(|_| {
+                    msg!("Rounding policy cannot be unpacked");
+                    RNDRError::InstructionUnpackError
+                })?;
+                (ConfigChange::RoundingPolicy(rounding_policy), rest)
+            }
+            16 => {
+                let (min_disbursement, rest) = Self::unpack_u64(rest)?;
+                (ConfigChange::MinDisbursement(min_disbursement), rest)
+            }
+            17 => {
+                let (index, rest) = Self::unpack_u8(rest)?;
+                let (obh_price, rest) = Self::unpack_u64(rest)?;
+                (ConfigChange::ObhPrice(index, obh_price), rest)
+            }
+            18 => {
+                let (max_price_age, rest) = Self::unpack_u64(rest)?;
+                (ConfigChange::MaxPriceAge(max_price_age), rest)
+            }
+            19 => {
+                let (max_price_confidence_bps, rest) = Self::unpack_rate(rest)?;
+                (
+                    ConfigChange::MaxPriceConfidenceBps(max_price_confidence_bps),
+                    rest,
+                )
+            }
+            20 => {
+                let (compliance, rest) = Self::unpack_pubkey(rest)?;
+                (ConfigChange::Compliance(compliance), rest)
+            }
+            21 => {
+                let (sweep_delay, rest) = Self::unpack_u64(rest)?;
+                (ConfigChange::SweepDelay(sweep_delay), rest)
+            }
+            22 => {
+                let (incident_mode, rest) = Self::unpack_u8(rest)?;
+                let incident_mode = IncidentMode::try_from(incident_mode).map_err(|_| {
+                    msg!("Incident mode cannot be unpacked");
+                    RNDRError::InstructionUnpackError
+                })?;
+                (ConfigChange::IncidentMode(incident_mode), rest)
+            }
+            _ => {
+                msg!("Config change cannot be unpacked");
+                return Err(RNDRError::InstructionUnpackError.into());
+            }
+        })
+    }
+
+    pub(crate) fn pack_config_change(change: &ConfigChange, buf: &mut Vec<u8>) {
+        match change {
+            ConfigChange::Governance(governance) => {
+                buf.push(0);
+                buf.extend_from_slice(governance.as_ref());
+            }
+            ConfigChange::FeeBps(fee_bps) => {
+                buf.push(1);
+                buf.extend_from_slice(&fee_bps.bps().to_le_bytes());
+            }
+            ConfigChange::MinStake(min_stake) => {
+                buf.push(2);
+                buf.extend_from_slice(&min_stake.to_le_bytes());
+            }
+            ConfigChange::DisputeWindow(dispute_window) => {
+                buf.push(3);
+                buf.extend_from_slice(&dispute_window.to_le_bytes());
+            }
+            ConfigChange::TimelockDuration(timelock_duration) => {
+                buf.push(4);
+                buf.extend_from_slice(&timelock_duration.to_le_bytes());
+            }
+            ConfigChange::RateLimitWindow(rate_limit_window) => {
+                buf.push(5);
+                buf.extend_from_slice(&rate_limit_window.to_le_bytes());
+            }
+            ConfigChange::RateLimitMax(rate_limit_max) => {
+                buf.push(6);
+                buf.extend_from_slice(&rate_limit_max.to_le_bytes());
+            }
+            ConfigChange::Guardian(guardian) => {
+                buf.push(7);
+                buf.extend_from_slice(guardian.as_ref());
+            }
+            ConfigChange::InstantOwnerChange(instant_owner_change) => {
+                buf.push(8);
+                buf.push(u8::from(*instant_owner_change));
+            }
+            ConfigChange::TimelockedFields(timelocked_fields) => {
+                buf.push(9);
+                buf.extend_from_slice(&timelocked_fields.to_le_bytes());
+            }
+            ConfigChange::RewardTier(index, reward_tier) => {
+                buf.push(10);
+                buf.push(*index);
+                buf.extend_from_slice(&reward_tier.min_stake.to_le_bytes());
+                buf.extend_from_slice(&reward_tier.multiplier_bps.to_le_bytes());
+            }
+            ConfigChange::CancelGracePeriod(cancel_grace_period) => {
+                buf.push(11);
+                buf.extend_from_slice(&cancel_grace_period.to_le_bytes());
+            }
+            ConfigChange::CancelFeeBps(cancel_fee_bps) => {
+                buf.push(12);
+                buf.extend_from_slice(&cancel_fee_bps.bps().to_le_bytes());
+            }
+            ConfigChange::PrioritySurchargeBps(priority_surcharge_bps) => {
+                buf.push(13);
+                buf.extend_from_slice(&priority_surcharge_bps.bps().to_le_bytes());
+            }
+            ConfigChange::PriorityClaimDelay(priority_claim_delay) => {
+                buf.push(14);
+                buf.extend_from_slice(&priority_claim_delay.to_le_bytes());
+            }
+            ConfigChange::RoundingPolicy(rounding_policy) => {
+                buf.push(15);
+                buf.push(u8::from(*rounding_policy));
+            }
+            ConfigChange::MinDisbursement(min_disbursement) => {
+                buf.push(16);
+                buf.extend_from_slice(&min_disbursement.to_le_bytes());
+            }
+            ConfigChange::ObhPrice(index, obh_price) => {
+                buf.push(17);
+                buf.push(*index);
+                buf.extend_from_slice(&obh_price.to_le_bytes());
+            }
+            ConfigChange::MaxPriceAge(max_price_age) => {
+                buf.push(18);
+                buf.extend_from_slice(&max_price_age.to_le_bytes());
+            }
+            ConfigChange::MaxPriceConfidenceBps(max_price_confidence_bps) => {
+                buf.push(19);
+                buf.extend_from_slice(&max_price_confidence_bps.bps().to_le_bytes());
+            }
+            ConfigChange::Compliance(compliance) => {
+                buf.push(20);
+                buf.extend_from_slice(compliance.as_ref());
+            }
+            ConfigChange::SweepDelay(sweep_delay) => {
+                buf.push(21);
+                buf.extend_from_slice(&sweep_delay.to_le_bytes());
+            }
+            ConfigChange::IncidentMode(incident_mode) => {
+                buf.push(22);
+                buf.push(u8::from(*incident_mode));
+            }
+        }
+    }
+
+    pub(crate) fn unpack_admin_action(input: &[u8]) -> Result<(AdminAction, &[u8]), ProgramError> {
+        let (action_type, rest) = Self::unpack_u8(input)?;
+        Ok(match action_type {
+            0 => {
+                let (new_owner, rest) = Self::unpack_pubkey(rest)?;
+                (AdminAction::SetEscrowOwner(new_owner), rest)
+            }
+            1 => {
+                let (change, rest) = Self::unpack_config_change(rest)?;
+                (AdminAction::SetConfig(change), rest)
+            }
+            _ => {
+                msg!("Administrative action cannot be unpacked");
+                return Err(RNDRError::InstructionUnpackError.into());
+            }
+        })
+    }
+
+    pub(crate) fn pack_admin_action(action: &AdminAction, buf: &mut Vec<u8>) {
+        match action {
+            AdminAction::SetEscrowOwner(new_owner) => {
+                buf.push(0);
+                buf.extend_from_slice(new_owner.as_ref());
+            }
+            AdminAction::SetConfig(change) => {
+                buf.push(1);
+                Self::pack_config_change(change, buf);
+            }
+        }
+    }
+
+    /// Packs a [RNDRInstruction](enum.RNDRInstruction.html) into a byte buffer.
+    pub fn pack(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(size_of::<Self>());
+        match self {
+            Self::InitEscrow { owner } => {
+                buf.push(0);
+                buf.extend_from_slice(&owner.to_bytes());
+            }
+            Self::SetEscrowOwner { new_owner } => {
+                buf.push(1);
+                buf.extend_from_slice(&new_owner.to_bytes());
+            }
+            Self::FundJob {
+                amount,
+                job_id,
+                metadata,
+            } => {
+                buf.push(2);
+                buf.extend_from_slice(&amount.to_le_bytes());
+                buf.extend_from_slice(&job_id.to_le_bytes());
+                if let Some(metadata) = metadata {
+                    Self::pack_job_metadata(metadata, &mut buf);
+                }
+            }
+            Self::DisburseFunds { amount } => {
+                buf.push(3);
+                buf.extend_from_slice(&amount.to_le_bytes());
+            }
+            Self::AggregateEscrowShard => {
+                buf.push(4);
+            }
+            Self::FundIsolatedJob { amount, job_id } => {
+                buf.push(5);
+                buf.extend_from_slice(&amount.to_le_bytes());
+                buf.extend_from_slice(&job_id.to_le_bytes());
+            }
+            Self::DisburseIsolatedJobFunds { amount } => {
+                buf.push(6);
+                buf.extend_from_slice(&amount.to_le_bytes());
+            }
+            Self::DisburseBatch { amounts } => {
+                buf.push(7);
+                buf.push(amounts.len() as u8);
+                for amount in amounts {
+                    buf.extend_from_slice(&amount.to_le_bytes());
+                }
+            }
+            Self::FundJobs { jobs } => {
+                buf.push(8);
+                buf.push(jobs.len() as u8);
+                for (job_id, amount) in jobs {
+                    buf.extend_from_slice(&job_id.to_le_bytes());
+                    buf.extend_from_slice(&amount.to_le_bytes());
+                }
+            }
+            Self::CommitResult { commitment } => {
+                buf.push(9);
+                buf.extend_from_slice(commitment);
+            }
+            Self::RevealResult {
+                result_hash,
+                salt,
+                proof,
+            } => {
+                buf.push(10);
+                buf.extend_from_slice(result_hash);
+                buf.extend_from_slice(salt);
+                buf.extend_from_slice(proof);
+            }
+            Self::ClaimPayment => {
+                buf.push(11);
+            }
+            Self::SetChallengeWindow { challenge_window } => {
+                buf.push(12);
+                buf.extend_from_slice(&challenge_window.to_le_bytes());
+            }
+            Self::ChallengeResult => {
+                buf.push(13);
+            }
+            Self::AddVerifier { verifier } => {
+                buf.push(14);
+                buf.extend_from_slice(verifier.as_ref());
+            }
+            Self::RemoveVerifier { verifier } => {
+                buf.push(15);
+                buf.extend_from_slice(verifier.as_ref());
+            }
+            Self::FlagJob { evidence_hash } => {
+                buf.push(16);
+                buf.extend_from_slice(evidence_hash);
+            }
+            Self::SelectVerifiers { count } => {
+                buf.push(17);
+                buf.push(*count);
+            }
+            Self::SetProofVerifier { proof_verifier } => {
+                buf.push(18);
+                buf.extend_from_slice(proof_verifier.as_ref());
+            }
+            Self::SetArbitrator { arbitrator } => {
+                buf.push(19);
+                buf.extend_from_slice(arbitrator.as_ref());
+            }
+            Self::OpenDispute => {
+                buf.push(20);
+            }
+            Self::SubmitEvidence { evidence_hash } => {
+                buf.push(21);
+                buf.extend_from_slice(evidence_hash);
+            }
+            Self::ResolveDispute { node_amount } => {
+                buf.push(22);
+                buf.extend_from_slice(&node_amount.to_le_bytes());
+            }
+            Self::InitConfig { governance } => {
+                buf.push(23);
+                buf.extend_from_slice(governance.as_ref());
+            }
+            Self::SetConfig { change } => {
+                buf.push(24);
+                Self::pack_config_change(change, &mut buf);
+            }
+            Self::VerifyUpgradeAuthority => {
+                buf.push(25);
+            }
+            Self::SetApprover { approver } => {
+                buf.push(26);
+                buf.extend_from_slice(approver.as_ref());
+            }
+            Self::ProposeDisbursement {
+                proposal_id,
+                amount,
+            } => {
+                buf.push(27);
+                buf.extend_from_slice(&proposal_id.to_le_bytes());
+                buf.extend_from_slice(&amount.to_le_bytes());
+            }
+            Self::ApproveDisbursement => {
+                buf.push(28);
+            }
+            Self::ExecuteDisbursement => {
+                buf.push(29);
+            }
+            Self::QueueAction { action_id, action } => {
+                buf.push(30);
+                buf.extend_from_slice(&action_id.to_le_bytes());
+                Self::pack_admin_action(action, &mut buf);
+            }
+            Self::ExecuteQueuedAction => {
+                buf.push(31);
+            }
+            Self::CancelQueuedAction => {
+                buf.push(32);
+            }
+            Self::SetRecoveryCouncil {
+                threshold,
+                guardians,
+            } => {
+                buf.push(33);
+                buf.push(*threshold);
+                buf.push(guardians.len() as u8);
+                for guardian in guardians {
+                    buf.extend_from_slice(guardian.as_ref());
+                }
+            }
+            Self::ApproveRecovery { new_owner } => {
+                buf.push(34);
+                buf.extend_from_slice(new_owner.as_ref());
+            }
+            Self::ExecuteRecovery => {
+                buf.push(35);
+            }
+            Self::ScheduleOwnerRotation {
+                rotation_id,
+                new_owner,
+            } => {
+                buf.push(36);
+                buf.extend_from_slice(&rotation_id.to_le_bytes());
+                buf.extend_from_slice(new_owner.as_ref());
+            }
+            Self::FundRewardPool { epoch, amount } => {
+                buf.push(37);
+                buf.extend_from_slice(&epoch.to_le_bytes());
+                buf.extend_from_slice(&amount.to_le_bytes());
+            }
+            Self::ClaimEpochReward => {
+                buf.push(38);
+            }
+            Self::PublishMerkleRoot {
+                epoch,
+                root,
+                node_count,
+                amount,
+            } => {
+                buf.push(39);
+                buf.extend_from_slice(&epoch.to_le_bytes());
+                buf.extend_from_slice(root);
+                buf.extend_from_slice(&node_count.to_le_bytes());
+                buf.extend_from_slice(&amount.to_le_bytes());
+            }
+            Self::ClaimMerkleReward {
+                index,
+                amount,
+                proof,
+            } => {
+                buf.push(40);
+                buf.extend_from_slice(&index.to_le_bytes());
+                buf.extend_from_slice(&amount.to_le_bytes());
+                buf.push(proof.len() as u8);
+                for hash in proof {
+                    buf.extend_from_slice(hash);
+                }
+            }
+            Self::SetEmissionSchedule {
+                start_epoch,
+                initial_amount,
+                decay_bps,
+            } => {
+                buf.push(41);
+                buf.extend_from_slice(&start_epoch.to_le_bytes());
+                buf.extend_from_slice(&initial_amount.to_le_bytes());
+                buf.extend_from_slice(&decay_bps.bps().to_le_bytes());
+            }
+            Self::EmitRewards => {
+                buf.push(42);
+            }
+            Self::Stake { amount } => {
+                buf.push(43);
+                buf.extend_from_slice(&amount.to_le_bytes());
+            }
+            Self::Unstake { amount } => {
+                buf.push(44);
+                buf.extend_from_slice(&amount.to_le_bytes());
+            }
+            Self::SetAutoCompound { auto_compound } => {
+                buf.push(45);
+                buf.push(u8::from(*auto_compound));
+            }
+            Self::CompoundRewards => {
+                buf.push(46);
+            }
+            Self::LiquidStake { amount } => {
+                buf.push(47);
+                buf.extend_from_slice(&amount.to_le_bytes());
+            }
+            Self::LiquidUnstake { receipt_amount } => {
+                buf.push(48);
+                buf.extend_from_slice(&receipt_amount.to_le_bytes());
+            }
+            Self::DepositStakeRewards { amount } => {
+                buf.push(49);
+                buf.extend_from_slice(&amount.to_le_bytes());
+            }
+            Self::SetCrankBounty { bounty } => {
+                buf.push(50);
+                buf.extend_from_slice(&bounty.to_le_bytes());
+            }
+            Self::SetTreasuryBurnCap { epoch_burn_cap } => {
+                buf.push(51);
+                buf.extend_from_slice(&epoch_burn_cap.to_le_bytes());
+            }
+            Self::BurnTreasury { amount } => {
+                buf.push(52);
+                buf.extend_from_slice(&amount.to_le_bytes());
+            }
+            Self::InitStats => {
+                buf.push(53);
+            }
+            Self::FundJobWithVoucher {
+                amount,
+                job_id,
+                nonce,
+                expires_at,
+            } => {
+                buf.push(54);
+                buf.extend_from_slice(&amount.to_le_bytes());
+                buf.extend_from_slice(&job_id.to_le_bytes());
+                buf.extend_from_slice(&nonce.to_le_bytes());
+                buf.extend_from_slice(&expires_at.to_le_bytes());
+            }
+            Self::RegisterSessionKey {
+                session_key,
+                expiry_slot,
+                instruction_mask,
+            } => {
+                buf.push(55);
+                buf.extend_from_slice(session_key.as_ref());
+                buf.extend_from_slice(&expiry_slot.to_le_bytes());
+                buf.extend_from_slice(&instruction_mask.to_le_bytes());
+            }
+            Self::ApproveDelegate {
+                delegate,
+                limit,
+                expiry_slot,
+            } => {
+                buf.push(56);
+                buf.extend_from_slice(delegate.as_ref());
+                buf.extend_from_slice(&limit.to_le_bytes());
+                buf.extend_from_slice(&expiry_slot.to_le_bytes());
+            }
+            Self::RevokeDelegate => {
+                buf.push(57);
+            }
+            Self::CreateSubscription {
+                job_id,
+                amount,
+                period,
+            } => {
+                buf.push(58);
+                buf.extend_from_slice(&job_id.to_le_bytes());
+                buf.extend_from_slice(&amount.to_le_bytes());
+                buf.extend_from_slice(&period.to_le_bytes());
+            }
+            Self::ProcessSubscription => {
+                buf.push(59);
+            }
+            Self::CancelJob => {
+                buf.push(60);
+            }
+            Self::TransferJobAuthority { new_authority } => {
+                buf.push(61);
+                buf.extend_from_slice(&new_authority.to_bytes());
+            }
+            Self::AcceptJobAuthority => {
+                buf.push(62);
+            }
+            Self::CreateJobBundle {
+                bundle_id,
+                first_job_id,
+                job_count,
+                metadata_hash,
+            } => {
+                buf.push(63);
+                buf.extend_from_slice(&bundle_id.to_le_bytes());
+                buf.extend_from_slice(&first_job_id.to_le_bytes());
+                buf.push(*job_count);
+                buf.extend_from_slice(metadata_hash);
+            }
+            Self::FundJobBundle {
+                first_job_id,
+                count,
+                amount_per_job,
+            } => {
+                buf.push(64);
+                buf.extend_from_slice(&first_job_id.to_le_bytes());
+                buf.push(*count);
+                buf.extend_from_slice(&amount_per_job.to_le_bytes());
+            }
+            Self::CancelJobBundle => {
+                buf.push(65);
+            }
+            Self::AggregateJobBundle => {
+                buf.push(66);
+            }
+            Self::InitJobTree {
+                max_depth,
+                max_buffer_size,
+            } => {
+                buf.push(67);
+                buf.push(*max_depth);
+                buf.extend_from_slice(&max_buffer_size.to_le_bytes());
+            }
+            Self::AppendCompressedJob { amount } => {
+                buf.push(68);
+                buf.extend_from_slice(&amount.to_le_bytes());
+            }
+            Self::ReplaceCompressedJob { job, node, root } => {
+                buf.push(69);
+                Self::pack_compressed_job(job, &mut buf);
+                buf.extend_from_slice(&node.to_bytes());
+                buf.extend_from_slice(root);
+            }
+            Self::SettleCompressedJob { job, root } => {
+                buf.push(70);
+                Self::pack_compressed_job(job, &mut buf);
+                buf.extend_from_slice(root);
+            }
+            Self::InitJobIndex => {
+                buf.push(71);
+            }
+            Self::UpdateJobMetadata { metadata } => {
+                buf.push(72);
+                Self::pack_job_metadata(metadata, &mut buf);
+            }
+            Self::SetEscrowTokenAccount => {
+                buf.push(73);
+            }
+            Self::MigrateEscrowMint {
+                rate_numerator,
+                rate_denominator,
+            } => {
+                buf.push(74);
+                buf.extend_from_slice(&rate_numerator.to_le_bytes());
+                buf.extend_from_slice(&rate_denominator.to_le_bytes());
+            }
+            Self::ConvertEscrowMintVault => {
+                buf.push(75);
+            }
+            Self::RedenominateJobs => {
+                buf.push(76);
+            }
+            Self::ConvertIsolatedJobMint => {
+                buf.push(77);
+            }
+            Self::InitEscrowWithSeed { owner, escrow_id } => {
+                buf.push(78);
+                buf.extend_from_slice(&owner.to_bytes());
+                buf.extend_from_slice(&escrow_id.to_le_bytes());
+            }
+            Self::RaiseJobPriority { priority } => {
+                buf.push(79);
+                buf.push(*priority);
+            }
+            Self::InitJobQueue => {
+                buf.push(80);
+            }
+            Self::ClaimJob { commitment } => {
+                buf.push(81);
+                buf.extend_from_slice(commitment);
+            }
+            Self::SetJobExchangeRate => {
+                buf.push(82);
+            }
+            Self::EstimateJobCost { tier, milli_obh } => {
+                buf.push(83);
+                buf.push(*tier);
+                buf.extend_from_slice(&milli_obh.to_le_bytes());
+            }
+            Self::SetPriceFeed { price_feed } => {
+                buf.push(84);
+                buf.extend_from_slice(price_feed.as_ref());
+            }
+            Self::FundJobAtOraclePrice {
+                value,
+                max_rndr_amount,
+                job_id,
+            } => {
+                buf.push(85);
+                buf.extend_from_slice(&value.to_le_bytes());
+                buf.extend_from_slice(&max_rndr_amount.to_le_bytes());
+                buf.extend_from_slice(&job_id.to_le_bytes());
+            }
+            Self::FreezeJob => {
+                buf.push(86);
+            }
+            Self::UnfreezeJob => {
+                buf.push(87);
+            }
+            Self::SyncIncidentMode => {
+                buf.push(88);
+            }
+            Self::RegisterColdWallet => {
+                buf.push(89);
+            }
+            Self::ScheduleTreasurySweep { amount } => {
+                buf.push(90);
+                buf.extend_from_slice(&amount.to_le_bytes());
+            }
+            Self::SweepToColdWallet => {
+                buf.push(91);
+            }
+            Self::CancelTreasurySweep => {
+                buf.push(92);
+            }
+            Self::GetVersion => {
+                buf.push(93);
+            }
+            Self::GetEscrowSummary => {
+                buf.push(94);
+            }
+            Self::GetJobSummary => {
+                buf.push(95);
+            }
+            Self::GetEscrowedAmount => {
+                buf.push(96);
+            }
+            Self::ReconcileEscrow => {
+                buf.push(97);
+            }
+        }
+        buf
+    }
+
+    fn pack_compressed_job(job: &CompressedJob, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&job.id.to_le_bytes());
+        buf.extend_from_slice(&job.amount.to_le_bytes());
+        buf.push(u8::from(job.status));
+        buf.extend_from_slice(&job.node.to_bytes());
+    }
+
+    pub(crate) fn pack_job_metadata(metadata: &JobMetadata, buf: &mut Vec<u8>) {
+        buf.push(metadata.uri.len() as u8);
+        buf.extend_from_slice(metadata.uri.as_bytes());
+        buf.extend_from_slice(&metadata.hash);
+        buf.extend_from_slice(&metadata.tags.to_le_bytes());
+    }
+}
+
+/// Creates an 'InitEscrow' instruction.
+pub fn init_escrow(
+    program_id: Pubkey,
+    owner: Pubkey,
+    token_mint: Pubkey,
+    funder: Pubkey,
+) -> Instruction {
+    let (escrow, _bump_seed) = find_escrow_address(&program_id, &token_mint);
+    let escrow_associated_token = get_associated_token_address(&escrow, &token_mint);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new(funder, true),
+            AccountMeta::new(escrow, false),
+            AccountMeta::new(escrow_associated_token, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+        ],
+        data: RNDRInstruction::InitEscrow { owner }.pack(),
+    }
+}
+
+/// Creates an 'InitEscrowWithSeed' instruction.
+pub fn init_escrow_with_seed(
+    program_id: Pubkey,
+    owner: Pubkey,
+    token_mint: Pubkey,
+    funder: Pubkey,
+    escrow_id: u64,
+) -> Instruction {
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let escrow_associated_token = get_associated_token_address(&escrow, &token_mint);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new(funder, true),
+            AccountMeta::new(escrow, false),
+            AccountMeta::new(escrow_associated_token, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+        ],
+        data: RNDRInstruction::InitEscrowWithSeed { owner, escrow_id }.pack(),
+    }
+}
+
+/// Creates a 'SetEscrowOwner' instruction.
+pub fn set_escrow_owner(
+    program_id: Pubkey,
+    escrow: Pubkey,
+    current_owner: Pubkey,
+    new_owner: Pubkey,
+) -> Instruction {
+    let (config, _bump_seed) = find_config_address(&program_id);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(escrow, false),
+            AccountMeta::new(current_owner, true),
+            AccountMeta::new_readonly(config, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: RNDRInstruction::SetEscrowOwner { new_owner }.pack(),
+    }
+}
+
+/// Creates a 'FundJob' instruction.
+pub fn fund_job(
+    program_id: Pubkey,
+    amount: impl Into<RndrAmount>,
+    job_id: u64,
+    token_mint: impl Into<EscrowSeeds>,
+    funder: Pubkey,
+    source_token: Pubkey,
+    authority: Pubkey,
+) -> Instruction {
+    let RndrAmount(amount) = amount.into();
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let escrow_associated_token = get_associated_token_address(&escrow, &token_mint);
+    let (job, _bump_seed) = find_job_address(&program_id, &escrow, &authority, job_id);
+    let (escrow_shard, _bump_seed) =
+        find_escrow_shard_address(&program_id, &escrow, EscrowShard::index_for_job(&job));
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(funder, true),
+            AccountMeta::new(source_token, false),
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new_readonly(escrow, false),
+            AccountMeta::new(escrow_associated_token, false),
+            AccountMeta::new(job, false),
+            AccountMeta::new(escrow_shard, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ],
+        data: RNDRInstruction::FundJob {
+            amount,
+            job_id,
+            metadata: None,
+        }
+        .pack(),
+    }
+}
+
+/// Creates a 'FundJob' instruction that also sets the off-chain metadata of the job.
+#[allow(clippy::too_many_arguments)]
+pub fn fund_job_with_metadata(
+    program_id: Pubkey,
+    amount: impl Into<RndrAmount>,
+    job_id: u64,
+    token_mint: impl Into<EscrowSeeds>,
+    funder: Pubkey,
+    source_token: Pubkey,
+    authority: Pubkey,
+    metadata: JobMetadata,
+) -> Instruction {
+    let RndrAmount(amount) = amount.into();
+    let mut instruction = fund_job(
+        program_id,
+        amount,
+        job_id,
+        token_mint,
+        funder,
+        source_token,
+        authority,
+    );
+    instruction.data = RNDRInstruction::FundJob {
+        amount,
+        job_id,
+        metadata: Some(metadata),
+    }
+    .pack();
+    instruction
+}
+
+/// Creates a 'FundJob' instruction that tops up an existing job with a session key of its
+/// authority, which transfers the tokens from the source token account.
+#[allow(clippy::too_many_arguments)]
+pub fn fund_job_with_session_key(
+    program_id: Pubkey,
+    amount: impl Into<RndrAmount>,
+    job_id: u64,
+    token_mint: impl Into<EscrowSeeds>,
+    funder: Pubkey,
+    source_token: Pubkey,
+    session_key: Pubkey,
+    authority: Pubkey,
+) -> Instruction {
+    let RndrAmount(amount) = amount.into();
+    let mut instruction = fund_job(
+        program_id,
+        amount,
+        job_id,
+        token_mint,
+        funder,
+        source_token,
+        authority,
+    );
+    let escrow = instruction.accounts[3].pubkey;
+    let (session_key_address, _bump_seed) =
+        find_session_key_address(&program_id, &escrow, &authority, &session_key);
+    instruction.accounts[2] = AccountMeta::new_readonly(session_key, true);
+    instruction
+        .accounts
+        .push(AccountMeta::new_readonly(session_key_address, false));
+    instruction
+}
+
+/// Creates a 'DisburseFunds' instruction.
+pub fn disburse_funds(
+    program_id: Pubkey,
+    amount: impl Into<RndrAmount>,
+    token_mint: impl Into<EscrowSeeds>,
+    destination_token: Pubkey,
+    job: Pubkey,
+    escrow_owner: Pubkey,
+) -> Instruction {
+    let RndrAmount(amount) = amount.into();
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let escrow_associated_token = get_associated_token_address(&escrow, &token_mint);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(escrow, false),
+            AccountMeta::new_readonly(escrow_owner, true),
+            AccountMeta::new(escrow_associated_token, false),
+            AccountMeta::new(job, false),
+            AccountMeta::new(destination_token, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            escrow_shard_account(&program_id, &escrow, &job),
+        ],
+        data: RNDRInstruction::DisburseFunds { amount }.pack(),
+    }
+}
+
+/// Creates a 'DisburseFunds' instruction signed by a delegate of the escrow owner, which is
+/// deducted from the delegate's allowance.
+pub fn disburse_funds_as_delegate(
+    program_id: Pubkey,
+    amount: impl Into<RndrAmount>,
+    token_mint: impl Into<EscrowSeeds>,
+    destination_token: Pubkey,
+    job: Pubkey,
+    delegate: Pubkey,
+) -> Instruction {
+    let RndrAmount(amount) = amount.into();
+    let mut instruction = disburse_funds(
+        program_id,
+        amount,
+        token_mint,
+        destination_token,
+        job,
+        delegate,
+    );
+    let escrow = instruction.accounts[0].pubkey;
+    let (delegate_allowance, _bump_seed) =
+        find_delegate_allowance_address(&program_id, &escrow, &delegate);
+    extend_before_escrow_shard(
+        &mut instruction,
+        [AccountMeta::new(delegate_allowance, false)],
+    );
+    instruction
+}
+
+/// Creates an 'AggregateEscrowShard' instruction.
+pub fn aggregate_escrow_shard(program_id: Pubkey, escrow: Pubkey, index: u8) -> Instruction {
+    let (escrow_shard, _bump_seed) = find_escrow_shard_address(&program_id, &escrow, index);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(escrow, false),
+            AccountMeta::new(escrow_shard, false),
+        ],
+        data: RNDRInstruction::AggregateEscrowShard.pack(),
+    }
+}
+
+/// Creates a 'FundIsolatedJob' instruction.
+pub fn fund_isolated_job(
+    program_id: Pubkey,
+    amount: impl Into<RndrAmount>,
+    job_id: u64,
+    token_mint: impl Into<EscrowSeeds>,
+    funder: Pubkey,
+    source_token: Pubkey,
+    authority: Pubkey,
+) -> Instruction {
+    let RndrAmount(amount) = amount.into();
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let (job, _bump_seed) = find_job_address(&program_id, &escrow, &authority, job_id);
+    let job_associated_token = get_associated_token_address(&job, &token_mint);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new(funder, true),
+            AccountMeta::new(source_token, false),
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new_readonly(escrow, false),
+            AccountMeta::new(job, false),
+            AccountMeta::new(job_associated_token, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+        ],
+        data: RNDRInstruction::FundIsolatedJob { amount, job_id }.pack(),
+    }
+}
+
+/// Creates a 'DisburseIsolatedJobFunds' instruction.
+pub fn disburse_isolated_job_funds(
+    program_id: Pubkey,
+    amount: impl Into<RndrAmount>,
+    token_mint: impl Into<EscrowSeeds>,
+    destination_token: Pubkey,
+    job: Pubkey,
+    escrow_owner: Pubkey,
+) -> Instruction {
+    let RndrAmount(amount) = amount.into();
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let job_associated_token = get_associated_token_address(&job, &token_mint);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(escrow, false),
+            AccountMeta::new_readonly(escrow_owner, true),
+            AccountMeta::new(job, false),
+            AccountMeta::new(job_associated_token, false),
+            AccountMeta::new(destination_token, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ],
+        data: RNDRInstruction::DisburseIsolatedJobFunds { amount }.pack(),
+    }
+}
+
+/// Creates a 'DisburseBatch' instruction.
+pub fn disburse_batch(
+    program_id: Pubkey,
+    token_mint: impl Into<EscrowSeeds>,
+    job: Pubkey,
+    escrow_owner: Pubkey,
+    disbursements: &[(Pubkey, u64)],
+) -> Instruction {
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let escrow_associated_token = get_associated_token_address(&escrow, &token_mint);
+    let mut accounts = vec![
+        AccountMeta::new(escrow, false),
+        AccountMeta::new_readonly(escrow_owner, true),
+        AccountMeta::new(escrow_associated_token, false),
+        AccountMeta::new(job, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+    ];
+    accounts.extend(
+        disbursements
+            .iter()
+            .map(|(destination_token, _amount)| AccountMeta::new(*destination_token, false)),
+    );
+    accounts.push(escrow_shard_account(&program_id, &escrow, &job));
+    Instruction {
+        program_id,
+        accounts,
+        data: RNDRInstruction::DisburseBatch {
+            amounts: disbursements.iter().map(|(_, amount)| *amount).collect(),
+        }
+        .pack(),
+    }
+}
+
+/// Creates a 'FundJobs' instruction.
+pub fn fund_jobs(
+    program_id: Pubkey,
+    jobs: &[(u64, u64)],
+    token_mint: impl Into<EscrowSeeds>,
+    funder: Pubkey,
+    source_token: Pubkey,
+    authority: Pubkey,
+) -> Instruction {
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let escrow_associated_token = get_associated_token_address(&escrow, &token_mint);
+    let mut accounts = vec![
+        AccountMeta::new(funder, true),
+        AccountMeta::new(source_token, false),
+        AccountMeta::new_readonly(authority, true),
+        AccountMeta::new_readonly(escrow, false),
+        AccountMeta::new(escrow_associated_token, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+    ];
+    for (job_id, _amount) in jobs {
+        let (job, _bump_seed) = find_job_address(&program_id, &escrow, &authority, *job_id);
+        let (escrow_shard, _bump_seed) =
+            find_escrow_shard_address(&program_id, &escrow, EscrowShard::index_for_job(&job));
+        accounts.push(AccountMeta::new(job, false));
+        accounts.push(AccountMeta::new(escrow_shard, false));
+    }
+    Instruction {
+        program_id,
+        accounts,
+        data: RNDRInstruction::FundJobs {
+            jobs: jobs.to_vec(),
+        }
+        .pack(),
+    }
+}
+
+/// Creates a 'CommitResult' instruction.
+pub fn commit_result(
+    program_id: Pubkey,
+    commitment: [u8; HASH_BYTES],
+    node: Pubkey,
+    job: Pubkey,
+) -> Instruction {
+    let (config, _bump_seed) = find_config_address(&program_id);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(node, true),
+            AccountMeta::new(job, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(config, false),
+        ],
+        data: RNDRInstruction::CommitResult { commitment }.pack(),
+    }
+}
+
+/// Creates a 'RevealResult' instruction.
+///
+/// `proof` is the proof verifier program of the escrow and the proof of the result, if the escrow
+/// has one. Accounts the proof verifier needs can be appended to the instruction's accounts.
+pub fn reveal_result(
+    program_id: Pubkey,
+    result_hash: [u8; HASH_BYTES],
+    salt: [u8; HASH_BYTES],
+    token_mint: impl Into<EscrowSeeds>,
+    node: Pubkey,
+    job: Pubkey,
+    proof: Option<(Pubkey, Vec<u8>)>,
+) -> Instruction {
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let mut accounts = vec![
+        AccountMeta::new_readonly(node, true),
+        AccountMeta::new(job, false),
+        AccountMeta::new_readonly(escrow, false),
+    ];
+    let proof = match proof {
+        Some((proof_verifier, proof)) => {
+            accounts.push(AccountMeta::new_readonly(proof_verifier, false));
+            proof
+        }
+        None => vec![],
+    };
+    Instruction {
+        program_id,
+        accounts,
+        data: RNDRInstruction::RevealResult {
+            result_hash,
+            salt,
+            proof,
+        }
+        .pack(),
+    }
+}
+
+/// Creates the instruction a proof verifier program is invoked with when a job's result is
+/// revealed. Its data is the result hash followed by the proof, and its accounts are the job
+/// followed by the accounts passed through to the proof verifier.
+pub fn verify_proof(
+    proof_verifier: Pubkey,
+    result_hash: &[u8; HASH_BYTES],
+    proof: &[u8],
+    job: Pubkey,
+    accounts: &[AccountMeta],
+) -> Instruction {
+    let mut data = Vec::with_capacity(HASH_BYTES + proof.len());
+    data.extend_from_slice(result_hash);
+    data.extend_from_slice(proof);
+    let mut metas = vec![AccountMeta::new_readonly(job, false)];
+    metas.extend_from_slice(accounts);
+    Instruction {
+        program_id: proof_verifier,
+        accounts: metas,
+        data,
+    }
+}
+
+/// Creates a 'ClaimPayment' instruction.
+pub fn claim_payment(
+    program_id: Pubkey,
+    token_mint: impl Into<EscrowSeeds>,
+    node: Pubkey,
+    job: Pubkey,
+    destination_token: Pubkey,
+) -> Instruction {
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let escrow_associated_token = get_associated_token_address(&escrow, &token_mint);
+    let (config, _bump_seed) = find_config_address(&program_id);
+    let (node_account, _bump_seed) = find_node_address(&program_id, &escrow, &node);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(node, true),
+            AccountMeta::new(escrow, false),
+            AccountMeta::new(escrow_associated_token, false),
+            AccountMeta::new(job, false),
+            AccountMeta::new(destination_token, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(config, false),
+            AccountMeta::new(node_account, false),
+            escrow_shard_account(&program_id, &escrow, &job),
+        ],
+        data: RNDRInstruction::ClaimPayment.pack(),
+    }
+}
+
+/// Creates a 'ClaimPayment' instruction that records the Job's weighted amount as work of the
+/// node toward the reward pool of the current epoch.
+pub fn claim_payment_with_work(
+    program_id: Pubkey,
+    token_mint: impl Into<EscrowSeeds>,
+    node: Pubkey,
+    job: Pubkey,
+    destination_token: Pubkey,
+    epoch: u64,
+) -> Instruction {
+    let seeds: EscrowSeeds = token_mint.into();
+    let mut instruction = claim_payment(program_id, seeds, node, job, destination_token);
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = seeds;
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let (reward_pool, _bump_seed) = find_reward_pool_address(&program_id, &escrow, epoch);
+    let (epoch_work, _bump_seed) = find_epoch_work_address(&program_id, &reward_pool, &node);
+    instruction.accounts[0] = AccountMeta::new(node, true);
+    extend_before_escrow_shard(
+        &mut instruction,
+        [
+            AccountMeta::new(reward_pool, false),
+            AccountMeta::new(epoch_work, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    );
+    instruction
+}
+
+/// Creates a 'SetChallengeWindow' instruction.
+pub fn set_challenge_window(
+    program_id: Pubkey,
+    escrow: Pubkey,
+    owner: Pubkey,
+    challenge_window: u64,
+) -> Instruction {
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(escrow, false),
+            AccountMeta::new(owner, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: RNDRInstruction::SetChallengeWindow { challenge_window }.pack(),
+    }
+}
+
+/// Creates a 'ChallengeResult' instruction.
+pub fn challenge_result(
+    program_id: Pubkey,
+    token_mint: impl Into<EscrowSeeds>,
+    authority: Pubkey,
+    job_id: u64,
+) -> Instruction {
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let (job, _bump_seed) = find_job_address(&program_id, &escrow, &authority, job_id);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new_readonly(escrow, false),
+            AccountMeta::new(job, false),
+        ],
+        data: RNDRInstruction::ChallengeResult.pack(),
+    }
+}
+
+/// Creates a 'ChallengeResult' instruction signed by a session key of the job authority.
+pub fn challenge_result_with_session_key(
+    program_id: Pubkey,
+    token_mint: impl Into<EscrowSeeds>,
+    session_key: Pubkey,
+    authority: Pubkey,
+    job_id: u64,
+) -> Instruction {
+    let mut instruction = challenge_result(program_id, token_mint, authority, job_id);
+    let escrow = instruction.accounts[1].pubkey;
+    let (session_key_address, _bump_seed) =
+        find_session_key_address(&program_id, &escrow, &authority, &session_key);
+    instruction.accounts[0] = AccountMeta::new_readonly(session_key, true);
+    instruction
+        .accounts
+        .push(AccountMeta::new_readonly(session_key_address, false));
+    instruction
+}
+
+/// Creates an 'AddVerifier' instruction.
+pub fn add_verifier(
+    program_id: Pubkey,
+    token_mint: impl Into<EscrowSeeds>,
+    owner: Pubkey,
+    verifier: Pubkey,
+) -> Instruction {
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let (verifier_registry, _bump_seed) = find_verifier_registry_address(&program_id, &escrow);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(escrow, false),
+            AccountMeta::new(owner, true),
+            AccountMeta::new(verifier_registry, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: RNDRInstruction::AddVerifier { verifier }.pack(),
+    }
+}
+
+/// Creates a 'RemoveVerifier' instruction.
+pub fn remove_verifier(
+    program_id: Pubkey,
+    token_mint: impl Into<EscrowSeeds>,
+    owner: Pubkey,
+    verifier: Pubkey,
+) -> Instruction {
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let (verifier_registry, _bump_seed) = find_verifier_registry_address(&program_id, &escrow);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(escrow, false),
+            AccountMeta::new_readonly(owner, true),
+            AccountMeta::new(verifier_registry, false),
+        ],
+        data: RNDRInstruction::RemoveVerifier { verifier }.pack(),
+    }
+}
+
+/// Creates a 'FlagJob' instruction.
+pub fn flag_job(
+    program_id: Pubkey,
+    evidence_hash: [u8; HASH_BYTES],
+    token_mint: impl Into<EscrowSeeds>,
+    verifier: Pubkey,
+    job: Pubkey,
+) -> Instruction {
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let (verifier_registry, _bump_seed) = find_verifier_registry_address(&program_id, &escrow);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(verifier, true),
+            AccountMeta::new_readonly(escrow, false),
+            AccountMeta::new_readonly(verifier_registry, false),
+            AccountMeta::new(job, false),
+        ],
+        data: RNDRInstruction::FlagJob { evidence_hash }.pack(),
+    }
+}
+
+/// Creates a 'SelectVerifiers' instruction.
+pub fn select_verifiers(
+    program_id: Pubkey,
+    count: u8,
+    token_mint: impl Into<EscrowSeeds>,
+    funder: Pubkey,
+    job: Pubkey,
+) -> Instruction {
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let (verifier_registry, _bump_seed) = find_verifier_registry_address(&program_id, &escrow);
+    let (verifier_selection, _bump_seed) = find_verifier_selection_address(&program_id, &job);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(funder, true),
+            AccountMeta::new_readonly(escrow, false),
+            AccountMeta::new_readonly(verifier_registry, false),
+            AccountMeta::new_readonly(job, false),
+            AccountMeta::new(verifier_selection, false),
+            AccountMeta::new_readonly(sysvar::slot_hashes::id(), false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: RNDRInstruction::SelectVerifiers { count }.pack(),
+    }
+}
+
+/// Creates a 'SetProofVerifier' instruction.
+pub fn set_proof_verifier(
+    program_id: Pubkey,
+    escrow: Pubkey,
+    owner: Pubkey,
+    proof_verifier: Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(escrow, false),
+            AccountMeta::new(owner, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: RNDRInstruction::SetProofVerifier { proof_verifier }.pack(),
+    }
+}
+
+/// Creates a 'SetArbitrator' instruction.
+pub fn set_arbitrator(
+    program_id: Pubkey,
+    escrow: Pubkey,
+    owner: Pubkey,
+    arbitrator: Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(escrow, false),
+            AccountMeta::new(owner, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: RNDRInstruction::SetArbitrator { arbitrator }.pack(),
+    }
+}
+
+/// Creates an 'OpenDispute' instruction.
+pub fn open_dispute(
+    program_id: Pubkey,
+    token_mint: impl Into<EscrowSeeds>,
+    opener: Pubkey,
+    job: Pubkey,
+) -> Instruction {
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let (config, _bump_seed) = find_config_address(&program_id);
+    let (dispute, _bump_seed) = find_dispute_address(&program_id, &job);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(opener, true),
+            AccountMeta::new_readonly(escrow, false),
+            AccountMeta::new_readonly(config, false),
+            AccountMeta::new(job, false),
+            AccountMeta::new(dispute, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: RNDRInstruction::OpenDispute.pack(),
+    }
+}
+
+/// Creates a 'SubmitEvidence' instruction.
+pub fn submit_evidence(
+    program_id: Pubkey,
+    evidence_hash: [u8; HASH_BYTES],
+    party: Pubkey,
+    job: Pubkey,
+) -> Instruction {
+    let (dispute, _bump_seed) = find_dispute_address(&program_id, &job);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(party, true),
+            AccountMeta::new_readonly(job, false),
+            AccountMeta::new(dispute, false),
+        ],
+        data: RNDRInstruction::SubmitEvidence { evidence_hash }.pack(),
+    }
+}
+
+/// Creates a 'ResolveDispute' instruction.
+pub fn resolve_dispute(
+    program_id: Pubkey,
+    node_amount: impl Into<RndrAmount>,
+    token_mint: impl Into<EscrowSeeds>,
+    arbitrator: Pubkey,
+    job: Pubkey,
+    node: Pubkey,
+    authority: Pubkey,
+) -> Instruction {
+    let RndrAmount(node_amount) = node_amount.into();
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let escrow_associated_token = get_associated_token_address(&escrow, &token_mint);
+    let (dispute, _bump_seed) = find_dispute_address(&program_id, &job);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(arbitrator, true),
+            AccountMeta::new(escrow, false),
+            AccountMeta::new(escrow_associated_token, false),
+            AccountMeta::new(job, false),
+            AccountMeta::new(dispute, false),
+            AccountMeta::new(get_associated_token_address(&node, &token_mint), false),
+            AccountMeta::new(get_associated_token_address(&authority, &token_mint), false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            escrow_shard_account(&program_id, &escrow, &job),
+        ],
+        data: RNDRInstruction::ResolveDispute { node_amount }.pack(),
+    }
+}
+
+/// Creates an 'InitConfig' instruction.
+pub fn init_config(
+    program_id: Pubkey,
+    funder: Pubkey,
+    upgrade_authority: Pubkey,
+    governance: Pubkey,
+) -> Instruction {
+    let (config, _bump_seed) = find_config_address(&program_id);
+    let (program_data, _bump_seed) = find_program_data_address(&program_id);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(funder, true),
+            AccountMeta::new(config, false),
+            AccountMeta::new_readonly(program_data, false),
+            AccountMeta::new_readonly(upgrade_authority, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: RNDRInstruction::InitConfig { governance }.pack(),
+    }
+}
+
+/// Creates a 'SetConfig' instruction.
+pub fn set_config(program_id: Pubkey, governance: Pubkey, change: ConfigChange) -> Instruction {
+    let (config, _bump_seed) = find_config_address(&program_id);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(config, false),
+            AccountMeta::new_readonly(governance, true),
+        ],
+        data: RNDRInstruction::SetConfig { change }.pack(),
+    }
+}
+
+/// Creates a 'SetConfig' instruction that reallocates a legacy Config, paid for by a payer, so
+/// fields appended to its layout can be set.
+pub fn set_config_with_payer(
+    program_id: Pubkey,
+    governance: Pubkey,
+    change: ConfigChange,
+    payer: Pubkey,
+) -> Instruction {
+    let mut instruction = set_config(program_id, governance, change);
+    instruction.accounts.extend([
+        AccountMeta::new(payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ]);
+    instruction
+}
+
+/// Creates a 'VerifyUpgradeAuthority' instruction.
+pub fn verify_upgrade_authority(program_id: Pubkey) -> Instruction {
+    let (config, _bump_seed) = find_config_address(&program_id);
+    let (program_data, _bump_seed) = find_program_data_address(&program_id);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(config, false),
+            AccountMeta::new_readonly(program_data, false),
+        ],
+        data: RNDRInstruction::VerifyUpgradeAuthority.pack(),
+    }
+}
+
+/// Creates a 'SetApprover' instruction.
+pub fn set_approver(
+    program_id: Pubkey,
+    escrow: Pubkey,
+    owner: Pubkey,
+    approver: Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(escrow, false),
+            AccountMeta::new(owner, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: RNDRInstruction::SetApprover { approver }.pack(),
+    }
+}
+
+/// Creates a 'SetApprover' instruction that changes or clears the approver of an Escrow that has
+/// one, signed by its current approver.
+pub fn set_approver_with_current_approver(
+    program_id: Pubkey,
+    escrow: Pubkey,
+    owner: Pubkey,
+    current_approver: Pubkey,
+    approver: Pubkey,
+) -> Instruction {
+    let mut instruction = set_approver(program_id, escrow, owner, approver);
+    instruction
+        .accounts
+        .push(AccountMeta::new_readonly(current_approver, true));
+    instruction
+}
+
+/// Creates a 'ProposeDisbursement' instruction.
+pub fn propose_disbursement(
+    program_id: Pubkey,
+    proposal_id: u64,
+    amount: impl Into<RndrAmount>,
+    token_mint: impl Into<EscrowSeeds>,
+    owner: Pubkey,
+    job: Pubkey,
+    destination_token: Pubkey,
+) -> Instruction {
+    let RndrAmount(amount) = amount.into();
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let (proposal, _bump_seed) = find_proposal_address(&program_id, &escrow, proposal_id);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(owner, true),
+            AccountMeta::new_readonly(escrow, false),
+            AccountMeta::new_readonly(job, false),
+            AccountMeta::new_readonly(destination_token, false),
+            AccountMeta::new(proposal, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: RNDRInstruction::ProposeDisbursement {
+            proposal_id,
+            amount,
+        }
+        .pack(),
+    }
+}
+
+/// Creates an 'ApproveDisbursement' instruction.
+pub fn approve_disbursement(
+    program_id: Pubkey,
+    token_mint: impl Into<EscrowSeeds>,
+    approver: Pubkey,
+    proposal_id: u64,
+) -> Instruction {
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let (proposal, _bump_seed) = find_proposal_address(&program_id, &escrow, proposal_id);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(approver, true),
+            AccountMeta::new_readonly(escrow, false),
+            AccountMeta::new(proposal, false),
+        ],
+        data: RNDRInstruction::ApproveDisbursement.pack(),
+    }
+}
+
+/// Creates an 'ExecuteDisbursement' instruction.
+pub fn execute_disbursement(
+    program_id: Pubkey,
+    token_mint: impl Into<EscrowSeeds>,
+    proposal_id: u64,
+    job: Pubkey,
+    isolated: bool,
+    destination_token: Pubkey,
+) -> Instruction {
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let (proposal, _bump_seed) = find_proposal_address(&program_id, &escrow, proposal_id);
+    let source_token = if isolated {
+        get_associated_token_address(&job, &token_mint)
+    } else {
+        get_associated_token_address(&escrow, &token_mint)
+    };
+    let mut accounts = vec![
+        AccountMeta::new(escrow, false),
+        AccountMeta::new(source_token, false),
+        AccountMeta::new(job, false),
+        AccountMeta::new(proposal, false),
+        AccountMeta::new(destination_token, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+    ];
+    if !isolated {
+        accounts.push(escrow_shard_account(&program_id, &escrow, &job));
+    }
+    Instruction {
+        program_id,
+        accounts,
+        data: RNDRInstruction::ExecuteDisbursement.pack(),
+    }
+}
+
+/// Creates a 'QueueAction' instruction.
+pub fn queue_action(
+    program_id: Pubkey,
+    authority: Pubkey,
+    target: Pubkey,
+    action_id: u64,
+    action: AdminAction,
+) -> Instruction {
+    let (config, _bump_seed) = find_config_address(&program_id);
+    let (queued_action, _bump_seed) = find_queued_action_address(&program_id, &target, action_id);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(authority, true),
+            AccountMeta::new_readonly(config, false),
+            AccountMeta::new(target, false),
+            AccountMeta::new(queued_action, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: RNDRInstruction::QueueAction { action_id, action }.pack(),
+    }
+}
+
+/// Creates an 'ExecuteQueuedAction' instruction.
+pub fn execute_queued_action(program_id: Pubkey, target: Pubkey, action_id: u64) -> Instruction {
+    let (queued_action, _bump_seed) = find_queued_action_address(&program_id, &target, action_id);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(queued_action, false),
+            AccountMeta::new(target, false),
+        ],
+        data: RNDRInstruction::ExecuteQueuedAction.pack(),
+    }
+}
+
+/// Creates an 'ExecuteQueuedAction' instruction that reallocates a legacy Config target, paid for
+/// by a payer, so fields appended to its layout can be set.
+pub fn execute_queued_action_with_payer(
+    program_id: Pubkey,
+    target: Pubkey,
+    action_id: u64,
+    payer: Pubkey,
+) -> Instruction {
+    let mut instruction = execute_queued_action(program_id, target, action_id);
+    instruction.accounts.extend([
+        AccountMeta::new(payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ]);
+    instruction
+}
+
+/// Creates a 'CancelQueuedAction' instruction.
+pub fn cancel_queued_action(
+    program_id: Pubkey,
+    guardian: Pubkey,
+    target: Pubkey,
+    action_id: u64,
+) -> Instruction {
+    let (config, _bump_seed) = find_config_address(&program_id);
+    let (queued_action, _bump_seed) = find_queued_action_address(&program_id, &target, action_id);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(guardian, true),
+            AccountMeta::new_readonly(config, false),
+            AccountMeta::new(queued_action, false),
+        ],
+        data: RNDRInstruction::CancelQueuedAction.pack(),
+    }
+}
+
+/// Creates a 'SetRecoveryCouncil' instruction.
+pub fn set_recovery_council(
+    program_id: Pubkey,
+    escrow: Pubkey,
+    owner: Pubkey,
+    threshold: u8,
+    guardians: Vec<Pubkey>,
+) -> Instruction {
+    let (recovery, _bump_seed) = find_recovery_address(&program_id, &escrow);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(escrow, false),
+            AccountMeta::new(owner, true),
+            AccountMeta::new(recovery, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: RNDRInstruction::SetRecoveryCouncil {
+            threshold,
+            guardians,
+        }
+        .pack(),
+    }
+}
+
+/// Creates an 'ApproveRecovery' instruction.
+pub fn approve_recovery(
+    program_id: Pubkey,
+    escrow: Pubkey,
+    guardian: Pubkey,
+    new_owner: Pubkey,
+) -> Instruction {
+    let (recovery, _bump_seed) = find_recovery_address(&program_id, &escrow);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(guardian, true),
+            AccountMeta::new_readonly(escrow, false),
+            AccountMeta::new(recovery, false),
+        ],
+        data: RNDRInstruction::ApproveRecovery { new_owner }.pack(),
+    }
+}
+
+/// Creates an 'ExecuteRecovery' instruction.
+pub fn execute_recovery(program_id: Pubkey, escrow: Pubkey) -> Instruction {
+    let (recovery, _bump_seed) = find_recovery_address(&program_id, &escrow);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(escrow, false),
+            AccountMeta::new(recovery, false),
+        ],
+        data: RNDRInstruction::ExecuteRecovery.pack(),
+    }
+}
+
+/// Creates a 'ScheduleOwnerRotation' instruction.
+pub fn schedule_owner_rotation(
+    program_id: Pubkey,
+    escrow: Pubkey,
+    owner: Pubkey,
+    rotation_id: u64,
+    new_owner: Pubkey,
+) -> Instruction {
+    let (config, _bump_seed) = find_config_address(&program_id);
+    let (queued_action, _bump_seed) = find_queued_action_address(&program_id, &escrow, rotation_id);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(owner, true),
+            AccountMeta::new_readonly(config, false),
+            AccountMeta::new(escrow, false),
+            AccountMeta::new(queued_action, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: RNDRInstruction::ScheduleOwnerRotation {
+            rotation_id,
+            new_owner,
+        }
+        .pack(),
+    }
+}
+
+/// Creates a 'FundRewardPool' instruction.
+pub fn fund_reward_pool(
+    program_id: Pubkey,
+    amount: impl Into<RndrAmount>,
+    epoch: u64,
+    token_mint: impl Into<EscrowSeeds>,
+    funder: Pubkey,
+    source_token: Pubkey,
+    authority: Pubkey,
+) -> Instruction {
+    let RndrAmount(amount) = amount.into();
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let (reward_pool, _bump_seed) = find_reward_pool_address(&program_id, &escrow, epoch);
+    let reward_pool_associated_token = get_associated_token_address(&reward_pool, &token_mint);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new(funder, true),
+            AccountMeta::new(source_token, false),
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new_readonly(escrow, false),
+            AccountMeta::new(reward_pool, false),
+            AccountMeta::new(reward_pool_associated_token, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+        ],
+        data: RNDRInstruction::FundRewardPool { epoch, amount }.pack(),
+    }
+}
+
+/// Creates a 'ClaimEpochReward' instruction.
+pub fn claim_epoch_reward(
+    program_id: Pubkey,
+    token_mint: impl Into<EscrowSeeds>,
+    node: Pubkey,
+    epoch: u64,
+    destination_token: Pubkey,
+) -> Instruction {
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let (reward_pool, _bump_seed) = find_reward_pool_address(&program_id, &escrow, epoch);
+    let reward_pool_associated_token = get_associated_token_address(&reward_pool, &token_mint);
+    let (epoch_work, _bump_seed) = find_epoch_work_address(&program_id, &reward_pool, &node);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(node, true),
+            AccountMeta::new(reward_pool, false),
+            AccountMeta::new(reward_pool_associated_token, false),
+            AccountMeta::new(epoch_work, false),
+            AccountMeta::new(destination_token, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ],
+        data: RNDRInstruction::ClaimEpochReward.pack(),
+    }
+}
+
+/// Creates a 'PublishMerkleRoot' instruction.
+#[allow(clippy::too_many_arguments)]
+pub fn publish_merkle_root(
+    program_id: Pubkey,
+    token_mint: impl Into<EscrowSeeds>,
+    owner: Pubkey,
+    source_token: Pubkey,
+    authority: Pubkey,
+    epoch: u64,
+    root: [u8; HASH_BYTES],
+    node_count: u32,
+    amount: impl Into<RndrAmount>,
+) -> Instruction {
+    let RndrAmount(amount) = amount.into();
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let (merkle_distribution, _bump_seed) =
+        find_merkle_distribution_address(&program_id, &escrow, epoch);
+    let merkle_distribution_associated_token =
+        get_associated_token_address(&merkle_distribution, &token_mint);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new(owner, true),
+            AccountMeta::new(source_token, false),
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new_readonly(escrow, false),
+            AccountMeta::new(merkle_distribution, false),
+            AccountMeta::new(merkle_distribution_associated_token, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+        ],
+        data: RNDRInstruction::PublishMerkleRoot {
+            epoch,
+            root,
+            node_count,
+            amount,
+        }
+        .pack(),
+    }
+}
+
+/// Creates a 'ClaimMerkleReward' instruction.
+#[allow(clippy::too_many_arguments)]
+pub fn claim_merkle_reward(
+    program_id: Pubkey,
+    token_mint: impl Into<EscrowSeeds>,
+    node: Pubkey,
+    epoch: u64,
+    destination_token: Pubkey,
+    index: u32,
+    amount: impl Into<RndrAmount>,
+    proof: Vec<[u8; HASH_BYTES]>,
+) -> Instruction {
+    let RndrAmount(amount) = amount.into();
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let (merkle_distribution, _bump_seed) =
+        find_merkle_distribution_address(&program_id, &escrow, epoch);
+    let merkle_distribution_associated_token =
+        get_associated_token_address(&merkle_distribution, &token_mint);
+    let (claim_bitmap, _bump_seed) = find_claim_bitmap_address(
+        &program_id,
+        &merkle_distribution,
+        ClaimBitmap::chunk_for_index(index),
+    );
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(node, true),
+            AccountMeta::new(merkle_distribution, false),
+            AccountMeta::new(merkle_distribution_associated_token, false),
+            AccountMeta::new(claim_bitmap, false),
+            AccountMeta::new(destination_token, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ],
+        data: RNDRInstruction::ClaimMerkleReward {
+            index,
+            amount,
+            proof,
+        }
+        .pack(),
+    }
+}
+
+/// Creates a 'SetEmissionSchedule' instruction.
+pub fn set_emission_schedule(
+    program_id: Pubkey,
+    escrow: Pubkey,
+    owner: Pubkey,
+    start_epoch: u64,
+    initial_amount: impl Into<RndrAmount>,
+    decay_bps: Rate,
+) -> Instruction {
+    let RndrAmount(initial_amount) = initial_amount.into();
+    let (emission_schedule, _bump_seed) = find_emission_schedule_address(&program_id, &escrow);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(owner, true),
+            AccountMeta::new_readonly(escrow, false),
+            AccountMeta::new(emission_schedule, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: RNDRInstruction::SetEmissionSchedule {
+            start_epoch,
+            initial_amount,
+            decay_bps,
+        }
+        .pack(),
+    }
+}
+
+/// Creates an 'EmitRewards' instruction for the current epoch.
+pub fn emit_rewards(
+    program_id: Pubkey,
+    token_mint: impl Into<EscrowSeeds>,
+    funder: Pubkey,
+    epoch: u64,
+) -> Instruction {
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let (emission_schedule, _bump_seed) = find_emission_schedule_address(&program_id, &escrow);
+    let emission_schedule_associated_token =
+        get_associated_token_address(&emission_schedule, &token_mint);
+    let (reward_pool, _bump_seed) = find_reward_pool_address(&program_id, &escrow, epoch);
+    let reward_pool_associated_token = get_associated_token_address(&reward_pool, &token_mint);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new(funder, true),
+            AccountMeta::new_readonly(escrow, false),
+            AccountMeta::new(emission_schedule, false),
+            AccountMeta::new(emission_schedule_associated_token, false),
+            AccountMeta::new(reward_pool, false),
+            AccountMeta::new(reward_pool_associated_token, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+        ],
+        data: RNDRInstruction::EmitRewards.pack(),
+    }
+}
+
+/// Creates a 'Stake' instruction.
+pub fn stake(
+    program_id: Pubkey,
+    amount: impl Into<RndrAmount>,
+    token_mint: impl Into<EscrowSeeds>,
+    node_authority: Pubkey,
+    source_token: Pubkey,
+    authority: Pubkey,
+) -> Instruction {
+    let RndrAmount(amount) = amount.into();
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let (node, _bump_seed) = find_node_address(&program_id, &escrow, &node_authority);
+    let node_associated_token = get_associated_token_address(&node, &token_mint);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new(node_authority, true),
+            AccountMeta::new(source_token, false),
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new_readonly(escrow, false),
+            AccountMeta::new(node, false),
+            AccountMeta::new(node_associated_token, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+        ],
+        data: RNDRInstruction::Stake { amount }.pack(),
+    }
+}
+
+/// Creates an 'Unstake' instruction.
+pub fn unstake(
+    program_id: Pubkey,
+    amount: impl Into<RndrAmount>,
+    token_mint: impl Into<EscrowSeeds>,
+    node_authority: Pubkey,
+    destination_token: Pubkey,
+) -> Instruction {
+    let RndrAmount(amount) = amount.into();
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let (node, _bump_seed) = find_node_address(&program_id, &escrow, &node_authority);
+    let node_associated_token = get_associated_token_address(&node, &token_mint);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(node_authority, true),
+            AccountMeta::new(node, false),
+            AccountMeta::new(node_associated_token, false),
+            AccountMeta::new(destination_token, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ],
+        data: RNDRInstruction::Unstake { amount }.pack(),
+    }
+}
+
+/// Creates a 'SetAutoCompound' instruction.
+pub fn set_auto_compound(
+    program_id: Pubkey,
+    escrow: Pubkey,
+    node_authority: Pubkey,
+    auto_compound: bool,
+) -> Instruction {
+    let (node, _bump_seed) = find_node_address(&program_id, &escrow, &node_authority);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(node_authority, true),
+            AccountMeta::new(node, false),
+        ],
+        data: RNDRInstruction::SetAutoCompound { auto_compound }.pack(),
+    }
+}
+
+/// Creates a 'CompoundRewards' instruction.
+pub fn compound_rewards(
+    program_id: Pubkey,
+    token_mint: impl Into<EscrowSeeds>,
+    node_authority: Pubkey,
+    epoch: u64,
+) -> Instruction {
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let (reward_pool, _bump_seed) = find_reward_pool_address(&program_id, &escrow, epoch);
+    let reward_pool_associated_token = get_associated_token_address(&reward_pool, &token_mint);
+    let (epoch_work, _bump_seed) =
+        find_epoch_work_address(&program_id, &reward_pool, &node_authority);
+    let (node, _bump_seed) = find_node_address(&program_id, &escrow, &node_authority);
+    let node_associated_token = get_associated_token_address(&node, &token_mint);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new(reward_pool, false),
+            AccountMeta::new(reward_pool_associated_token, false),
+            AccountMeta::new(epoch_work, false),
+            AccountMeta::new(node, false),
+            AccountMeta::new(node_associated_token, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ],
+        data: RNDRInstruction::CompoundRewards.pack(),
+    }
+}
+
+/// Creates a 'LiquidStake' instruction.
+pub fn liquid_stake(
+    program_id: Pubkey,
+    amount: impl Into<RndrAmount>,
+    token_mint: impl Into<EscrowSeeds>,
+    funder: Pubkey,
+    source_token: Pubkey,
+    authority: Pubkey,
+    receipt_owner: Pubkey,
+) -> Instruction {
+    let RndrAmount(amount) = amount.into();
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let (stake_pool, _bump_seed) = find_stake_pool_address(&program_id, &escrow);
+    let stake_pool_associated_token = get_associated_token_address(&stake_pool, &token_mint);
+    let (receipt_mint, _bump_seed) = find_stake_receipt_mint_address(&program_id, &stake_pool);
+    let receipt_owner_associated_token =
+        get_associated_token_address(&receipt_owner, &receipt_mint);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new(funder, true),
+            AccountMeta::new(source_token, false),
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new_readonly(escrow, false),
+            AccountMeta::new(stake_pool, false),
+            AccountMeta::new(stake_pool_associated_token, false),
+            AccountMeta::new(receipt_mint, false),
+            AccountMeta::new_readonly(receipt_owner, false),
+            AccountMeta::new(receipt_owner_associated_token, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+        ],
+        data: RNDRInstruction::LiquidStake { amount }.pack(),
+    }
+}
+
+/// Creates a 'LiquidUnstake' instruction.
+pub fn liquid_unstake(
+    program_id: Pubkey,
+    receipt_amount: u64,
+    token_mint: impl Into<EscrowSeeds>,
+    source_receipt_token: Pubkey,
+    receipt_authority: Pubkey,
+    destination_token: Pubkey,
+) -> Instruction {
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let (stake_pool, _bump_seed) = find_stake_pool_address(&program_id, &escrow);
+    let stake_pool_associated_token = get_associated_token_address(&stake_pool, &token_mint);
+    let (receipt_mint, _bump_seed) = find_stake_receipt_mint_address(&program_id, &stake_pool);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(stake_pool, false),
+            AccountMeta::new(stake_pool_associated_token, false),
+            AccountMeta::new(receipt_mint, false),
+            AccountMeta::new(source_receipt_token, false),
+            AccountMeta::new_readonly(receipt_authority, true),
+            AccountMeta::new(destination_token, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ],
+        data: RNDRInstruction::LiquidUnstake { receipt_amount }.pack(),
+    }
+}
+
+/// Creates a 'DepositStakeRewards' instruction.
+pub fn deposit_stake_rewards(
+    program_id: Pubkey,
+    amount: impl Into<RndrAmount>,
+    token_mint: impl Into<EscrowSeeds>,
+    source_token: Pubkey,
+    authority: Pubkey,
+) -> Instruction {
+    let RndrAmount(amount) = amount.into();
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let (stake_pool, _bump_seed) = find_stake_pool_address(&program_id, &escrow);
+    let stake_pool_associated_token = get_associated_token_address(&stake_pool, &token_mint);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(source_token, false),
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(stake_pool, false),
+            AccountMeta::new(stake_pool_associated_token, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ],
+        data: RNDRInstruction::DepositStakeRewards { amount }.pack(),
+    }
+}
+
+/// Creates a 'SetCrankBounty' instruction.
+pub fn set_crank_bounty(
+    program_id: Pubkey,
+    escrow: Pubkey,
+    owner: Pubkey,
+    bounty: u64,
+) -> Instruction {
+    let (crank_vault, _bump_seed) = find_crank_vault_address(&program_id, &escrow);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(owner, true),
+            AccountMeta::new_readonly(escrow, false),
+            AccountMeta::new(crank_vault, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: RNDRInstruction::SetCrankBounty { bounty }.pack(),
+    }
+}
+
+/// Appends the accounts to a crank instruction of an Escrow that pay the bounty of its CrankVault
+/// to a recipient.
+pub fn with_crank_bounty(
+    mut instruction: Instruction,
+    escrow: Pubkey,
+    recipient: Pubkey,
+) -> Instruction {
+    let (crank_vault, _bump_seed) = find_crank_vault_address(&instruction.program_id, &escrow);
+    instruction.accounts.extend([
+        AccountMeta::new(crank_vault, false),
+        AccountMeta::new(recipient, false),
+    ]);
+    instruction
+}
+
+/// Creates a 'SetTreasuryBurnCap' instruction.
+pub fn set_treasury_burn_cap(
+    program_id: Pubkey,
+    token_mint: impl Into<EscrowSeeds>,
+    owner: Pubkey,
+    epoch_burn_cap: u64,
+) -> Instruction {
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let (treasury, _bump_seed) = find_treasury_address(&program_id, &escrow);
+    let treasury_associated_token = get_associated_token_address(&treasury, &token_mint);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new(owner, true),
+            AccountMeta::new_readonly(escrow, false),
+            AccountMeta::new(treasury, false),
+            AccountMeta::new(treasury_associated_token, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+        ],
+        data: RNDRInstruction::SetTreasuryBurnCap { epoch_burn_cap }.pack(),
+    }
+}
+
+/// Creates a 'BurnTreasury' instruction.
+pub fn burn_treasury(
+    program_id: Pubkey,
+    amount: impl Into<RndrAmount>,
+    token_mint: impl Into<EscrowSeeds>,
+    owner: Pubkey,
+) -> Instruction {
+    let RndrAmount(amount) = amount.into();
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let (treasury, _bump_seed) = find_treasury_address(&program_id, &escrow);
+    let treasury_associated_token = get_associated_token_address(&treasury, &token_mint);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(token_mint, false),
+            AccountMeta::new_readonly(owner, true),
+            AccountMeta::new_readonly(escrow, false),
+            AccountMeta::new(treasury, false),
+            AccountMeta::new(treasury_associated_token, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ],
+        data: RNDRInstruction::BurnTreasury { amount }.pack(),
+    }
+}
+
+/// Creates an 'InitStats' instruction.
+pub fn init_stats(program_id: Pubkey, escrow: Pubkey, funder: Pubkey) -> Instruction {
+    let (stats, _bump_seed) = find_stats_address(&program_id, &escrow);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(funder, true),
+            AccountMeta::new_readonly(escrow, false),
+            AccountMeta::new(stats, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: RNDRInstruction::InitStats.pack(),
+    }
+}
+
+/// Appends the Stats account of an Escrow to an instruction, after all its other accounts, to
+/// record the instruction's flow of tokens.
+pub fn with_stats(mut instruction: Instruction, escrow: Pubkey) -> Instruction {
+    let (stats, _bump_seed) = find_stats_address(&instruction.program_id, &escrow);
+    instruction.accounts.push(AccountMeta::new(stats, false));
+    instruction
+}
+
+/// EscrowShard account that a Job is funded through, appended to instructions that debit the
+/// Escrow for the Job so the tokens funded through it are aggregated first
+fn escrow_shard_account(program_id: &Pubkey, escrow: &Pubkey, job: &Pubkey) -> AccountMeta {
+    let (escrow_shard, _bump_seed) =
+        find_escrow_shard_address(program_id, escrow, EscrowShard::index_for_job(job));
+    AccountMeta::new(escrow_shard, false)
+}
+
+/// Add optional accounts to an instruction before the EscrowShard account appended last
+fn extend_before_escrow_shard(
+    instruction: &mut Instruction,
+    accounts: impl IntoIterator<Item = AccountMeta>,
+) {
+    let escrow_shard = instruction.accounts.pop();
+    instruction.accounts.extend(accounts);
+    instruction.accounts.extend(escrow_shard);
+}
+
+/// Creates a 'FundJobWithVoucher' instruction for a voucher signed by its authority, which must be
+/// preceded by the instruction created by `ed25519_verify` for the voucher's message and signature.
+pub fn fund_job_with_voucher(
+    program_id: Pubkey,
+    token_mint: Pubkey,
+    relayer: Pubkey,
+    source_token: Pubkey,
+    voucher: &Voucher,
+) -> Instruction {
+    let escrow_associated_token = get_associated_token_address(&voucher.escrow, &token_mint);
+    let (job, _bump_seed) = find_job_address(
+        &program_id,
+        &voucher.escrow,
+        &voucher.authority,
+        voucher.job_id,
+    );
+    let (escrow_shard, _bump_seed) = find_escrow_shard_address(
+        &program_id,
+        &voucher.escrow,
+        EscrowShard::index_for_job(&job),
+    );
+    let (voucher_nonce, _bump_seed) =
+        find_voucher_nonce_address(&program_id, &voucher.escrow, &voucher.authority);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(relayer, true),
+            AccountMeta::new(source_token, false),
+            AccountMeta::new_readonly(voucher.authority, false),
+            AccountMeta::new_readonly(voucher.escrow, false),
+            AccountMeta::new(escrow_associated_token, false),
+            AccountMeta::new(job, false),
+            AccountMeta::new(escrow_shard, false),
+            AccountMeta::new(voucher_nonce, false),
+            AccountMeta::new_readonly(sysvar::instructions::id(), false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ],
+        data: RNDRInstruction::FundJobWithVoucher {
+            amount: voucher.amount,
+            job_id: voucher.job_id,
+            nonce: voucher.nonce,
+            expires_at: voucher.expires_at,
+        }
+        .pack(),
+    }
+}
+
+/// Creates an ed25519 program instruction that verifies a signature of a message, with the
+/// signer, signature and message in its own data as `FundJobWithVoucher` expects.
+pub fn ed25519_verify(signer: &Pubkey, signature: &[u8; 64], message: &[u8]) -> Instruction {
+    let mut data = Vec::with_capacity(ED25519_MESSAGE_OFFSET + message.len());
+    data.extend_from_slice(&[1, 0]);
+    for offset in [
+        ED25519_SIGNATURE_OFFSET,
+        u16::MAX as usize,
+        ED25519_PUBLIC_KEY_OFFSET,
+        u16::MAX as usize,
+        ED25519_MESSAGE_OFFSET,
+        message.len(),
+        u16::MAX as usize,
+    ] {
+        data.extend_from_slice(&(offset as u16).to_le_bytes());
+    }
+    data.extend_from_slice(signer.as_ref());
+    data.extend_from_slice(signature);
+    data.extend_from_slice(message);
+    Instruction {
+        program_id: ed25519_program::id(),
+        accounts: vec![],
+        data,
+    }
+}
+
+/// Creates a 'RegisterSessionKey' instruction.
+pub fn register_session_key(
+    program_id: Pubkey,
+    token_mint: impl Into<EscrowSeeds>,
+    authority: Pubkey,
+    session_key: Pubkey,
+    expiry_slot: Slot,
+    instruction_mask: u64,
+) -> Instruction {
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let (session_key_address, _bump_seed) =
+        find_session_key_address(&program_id, &escrow, &authority, &session_key);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(authority, true),
+            AccountMeta::new_readonly(escrow, false),
+            AccountMeta::new(session_key_address, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: RNDRInstruction::RegisterSessionKey {
+            session_key,
+            expiry_slot,
+            instruction_mask,
+        }
+        .pack(),
+    }
+}
+
+/// Creates an 'ApproveDelegate' instruction.
+pub fn approve_delegate(
+    program_id: Pubkey,
+    token_mint: impl Into<EscrowSeeds>,
+    owner: Pubkey,
+    delegate: Pubkey,
+    limit: u64,
+    expiry_slot: Slot,
+) -> Instruction {
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let (delegate_allowance, _bump_seed) =
+        find_delegate_allowance_address(&program_id, &escrow, &delegate);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(escrow, false),
+            AccountMeta::new(owner, true),
+            AccountMeta::new(delegate_allowance, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: RNDRInstruction::ApproveDelegate {
+            delegate,
+            limit,
+            expiry_slot,
+        }
+        .pack(),
+    }
+}
+
+/// Creates a 'RevokeDelegate' instruction.
+pub fn revoke_delegate(
+    program_id: Pubkey,
+    token_mint: impl Into<EscrowSeeds>,
+    owner: Pubkey,
+    delegate: Pubkey,
+) -> Instruction {
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let (delegate_allowance, _bump_seed) =
+        find_delegate_allowance_address(&program_id, &escrow, &delegate);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(escrow, false),
+            AccountMeta::new(owner, true),
+            AccountMeta::new(delegate_allowance, false),
+        ],
+        data: RNDRInstruction::RevokeDelegate.pack(),
+    }
+}
+
+/// Creates a 'CreateSubscription' instruction.
+#[allow(clippy::too_many_arguments)]
+pub fn create_subscription(
+    program_id: Pubkey,
+    token_mint: impl Into<EscrowSeeds>,
+    authority: Pubkey,
+    source_token: Pubkey,
+    job_id: u64,
+    amount: impl Into<RndrAmount>,
+    period: Slot,
+) -> Instruction {
+    let RndrAmount(amount) = amount.into();
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let (subscription, _bump_seed) =
+        find_subscription_address(&program_id, &escrow, &authority, job_id);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(authority, true),
+            AccountMeta::new_readonly(escrow, false),
+            AccountMeta::new_readonly(source_token, false),
+            AccountMeta::new(subscription, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: RNDRInstruction::CreateSubscription {
+            job_id,
+            amount,
+            period,
+        }
+        .pack(),
+    }
+}
+
+/// Creates a 'ProcessSubscription' instruction.
+pub fn process_subscription(
+    program_id: Pubkey,
+    token_mint: impl Into<EscrowSeeds>,
+    funder: Pubkey,
+    source_token: Pubkey,
+    authority: Pubkey,
+    job_id: u64,
+) -> Instruction {
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let escrow_associated_token = get_associated_token_address(&escrow, &token_mint);
+    let (subscription, _bump_seed) =
+        find_subscription_address(&program_id, &escrow, &authority, job_id);
+    let (job, _bump_seed) = find_job_address(&program_id, &escrow, &authority, job_id);
+    let (escrow_shard, _bump_seed) =
+        find_escrow_shard_address(&program_id, &escrow, EscrowShard::index_for_job(&job));
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(funder, true),
+            AccountMeta::new(subscription, false),
+            AccountMeta::new(source_token, false),
+            AccountMeta::new_readonly(escrow, false),
+            AccountMeta::new(escrow_associated_token, false),
+            AccountMeta::new(job, false),
+            AccountMeta::new(escrow_shard, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ],
+        data: RNDRInstruction::ProcessSubscription.pack(),
+    }
+}
+
+/// Creates a 'CancelJob' instruction.
+pub fn cancel_job(
+    program_id: Pubkey,
+    token_mint: impl Into<EscrowSeeds>,
+    authority: Pubkey,
+    job_id: u64,
+    destination_token: Pubkey,
+) -> Instruction {
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let escrow_associated_token = get_associated_token_address(&escrow, &token_mint);
+    let (job, _bump_seed) = find_job_address(&program_id, &escrow, &authority, job_id);
+    let (config, _bump_seed) = find_config_address(&program_id);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(authority, true),
+            AccountMeta::new(escrow, false),
+            AccountMeta::new(escrow_associated_token, false),
+            AccountMeta::new(job, false),
+            AccountMeta::new(destination_token, false),
+            AccountMeta::new_readonly(config, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            escrow_shard_account(&program_id, &escrow, &job),
+        ],
+        data: RNDRInstruction::CancelJob.pack(),
+    }
+}
+
+/// Creates a 'CancelJob' instruction for a Job that a node has committed to, paying any
+/// cancellation fee to a token account of the node. Dust is swept to the Escrow's Treasury, and any
+/// remainder of the split is paid to it or burned according to the Config's rounding policy.
+pub fn cancel_job_with_fee(
+    program_id: Pubkey,
+    token_mint: impl Into<EscrowSeeds>,
+    authority: Pubkey,
+    job_id: u64,
+    destination_token: Pubkey,
+    node_token: Pubkey,
+) -> Instruction {
+    let escrow_seeds = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(
+        &program_id,
+        &escrow_seeds.token_mint,
+        escrow_seeds.escrow_id,
+    );
+    let (treasury, _bump_seed) = find_treasury_address(&program_id, &escrow);
+    let treasury_associated_token =
+        get_associated_token_address(&treasury, &escrow_seeds.token_mint);
+    let mut instruction = cancel_job(
+        program_id,
+        escrow_seeds,
+        authority,
+        job_id,
+        destination_token,
+    );
+    extend_before_escrow_shard(
+        &mut instruction,
+        [
+            AccountMeta::new(node_token, false),
+            AccountMeta::new_readonly(treasury, false),
+            AccountMeta::new(treasury_associated_token, false),
+            AccountMeta::new(escrow_seeds.token_mint, false),
+        ],
+    );
+    instruction
+}
+
+/// Creates a 'TransferJobAuthority' instruction.
+pub fn transfer_job_authority(
+    program_id: Pubkey,
+    token_mint: impl Into<EscrowSeeds>,
+    authority: Pubkey,
+    job: Pubkey,
+    new_authority: Pubkey,
+) -> Instruction {
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(authority, true),
+            AccountMeta::new_readonly(escrow, false),
+            AccountMeta::new(job, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: RNDRInstruction::TransferJobAuthority { new_authority }.pack(),
+    }
+}
+
+/// Creates an 'UpdateJobMetadata' instruction.
+pub fn update_job_metadata(
+    program_id: Pubkey,
+    token_mint: impl Into<EscrowSeeds>,
+    authority: Pubkey,
+    job: Pubkey,
+    metadata: JobMetadata,
+) -> Instruction {
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(authority, true),
+            AccountMeta::new_readonly(escrow, false),
+            AccountMeta::new(job, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: RNDRInstruction::UpdateJobMetadata { metadata }.pack(),
+    }
+}
+
+/// Creates a 'RaiseJobPriority' instruction.
+pub fn raise_job_priority(
+    program_id: Pubkey,
+    token_mint: impl Into<EscrowSeeds>,
+    authority: Pubkey,
+    job_id: u64,
+    source_token: Pubkey,
+    priority: u8,
+) -> Instruction {
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let (job, _bump_seed) = find_job_address(&program_id, &escrow, &authority, job_id);
+    let (treasury, _bump_seed) = find_treasury_address(&program_id, &escrow);
+    let treasury_associated_token = get_associated_token_address(&treasury, &token_mint);
+    let (config, _bump_seed) = find_config_address(&program_id);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(authority, true),
+            AccountMeta::new_readonly(escrow, false),
+            AccountMeta::new(job, false),
+            AccountMeta::new(source_token, false),
+            AccountMeta::new_readonly(treasury, false),
+            AccountMeta::new(treasury_associated_token, false),
+            AccountMeta::new_readonly(config, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ],
+        data: RNDRInstruction::RaiseJobPriority { priority }.pack(),
+    }
+}
+
+/// Creates an 'InitJobQueue' instruction.
+pub fn init_job_queue(
+    program_id: Pubkey,
+    token_mint: impl Into<EscrowSeeds>,
+    funder: Pubkey,
+) -> Instruction {
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let (job_queue, _bump_seed) = find_job_queue_address(&program_id, &escrow);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(funder, true),
+            AccountMeta::new_readonly(escrow, false),
+            AccountMeta::new(job_queue, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: RNDRInstruction::InitJobQueue.pack(),
+    }
+}
+
+/// Creates a 'ClaimJob' instruction for the oldest Job of a JobQueue, see `JobQueue::front`.
+pub fn claim_job(
+    program_id: Pubkey,
+    commitment: [u8; HASH_BYTES],
+    node: Pubkey,
+    job_queue: Pubkey,
+    job: Pubkey,
+) -> Instruction {
+    let (config, _bump_seed) = find_config_address(&program_id);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(node, true),
+            AccountMeta::new(job_queue, false),
+            AccountMeta::new(job, false),
+            AccountMeta::new_readonly(config, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: RNDRInstruction::ClaimJob { commitment }.pack(),
+    }
+}
+
+/// Creates a 'SetJobExchangeRate' instruction.
+pub fn set_job_exchange_rate(
+    program_id: Pubkey,
+    token_mint: impl Into<EscrowSeeds>,
+    price_feed: Pubkey,
+    owner: Pubkey,
+    authority: Pubkey,
+    job_id: u64,
+) -> Instruction {
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let (job, _bump_seed) = find_job_address(&program_id, &escrow, &authority, job_id);
+    let (config, _bump_seed) = find_config_address(&program_id);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(owner, true),
+            AccountMeta::new_readonly(escrow, false),
+            AccountMeta::new(job, false),
+            AccountMeta::new_readonly(price_feed, false),
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new_readonly(config, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: RNDRInstruction::SetJobExchangeRate.pack(),
+    }
+}
+
+/// Creates an 'EstimateJobCost' instruction.
+pub fn estimate_job_cost(program_id: Pubkey, tier: u8, milli_obh: u64) -> Instruction {
+    let (config, _bump_seed) = find_config_address(&program_id);
+    Instruction {
+        program_id,
+        accounts: vec![AccountMeta::new_readonly(config, false)],
+        data: RNDRInstruction::EstimateJobCost { tier, milli_obh }.pack(),
+    }
+}
+
+/// Creates a 'SetPriceFeed' instruction.
+pub fn set_price_feed(
+    program_id: Pubkey,
+    escrow: Pubkey,
+    owner: Pubkey,
+    price_feed: Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(escrow, false),
+            AccountMeta::new(owner, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: RNDRInstruction::SetPriceFeed { price_feed }.pack(),
+    }
+}
+
+/// Creates a 'SyncIncidentMode' instruction.
+pub fn sync_incident_mode(program_id: Pubkey, escrow: Pubkey, payer: Pubkey) -> Instruction {
+    let (config, _bump_seed) = find_config_address(&program_id);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(escrow, false),
+            AccountMeta::new_readonly(config, false),
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: RNDRInstruction::SyncIncidentMode.pack(),
+    }
+}
+
+/// Creates a 'FundJobAtOraclePrice' instruction.
+#[allow(clippy::too_many_arguments)]
+pub fn fund_job_at_oracle_price(
+    program_id: Pubkey,
+    value: u64,
+    max_rndr_amount: impl Into<RndrAmount>,
+    job_id: u64,
+    token_mint: impl Into<EscrowSeeds>,
+    price_feed: Pubkey,
+    funder: Pubkey,
+    source_token: Pubkey,
+    authority: Pubkey,
+) -> Instruction {
+    let RndrAmount(max_rndr_amount) = max_rndr_amount.into();
+    let seeds: EscrowSeeds = token_mint.into();
+    let (config, _bump_seed) = find_config_address(&program_id);
+    let mut instruction = fund_job(
+        program_id,
+        max_rndr_amount,
+        job_id,
+        seeds,
+        funder,
+        source_token,
+        authority,
+    );
+    instruction.accounts.splice(
+        0..0,
+        [
+            AccountMeta::new_readonly(price_feed, false),
+            AccountMeta::new_readonly(seeds.token_mint, false),
+            AccountMeta::new_readonly(config, false),
+        ],
+    );
+    instruction.data = RNDRInstruction::FundJobAtOraclePrice {
+        value,
+        max_rndr_amount,
+        job_id,
+    }
+    .pack();
+    instruction
+}
+
+/// Creates a 'FreezeJob' instruction.
+pub fn freeze_job(
+    program_id: Pubkey,
+    token_mint: impl Into<EscrowSeeds>,
+    compliance: Pubkey,
+    authority: Pubkey,
+    job_id: u64,
+) -> Instruction {
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (config, _bump_seed) = find_config_address(&program_id);
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let (job, _bump_seed) = find_job_address(&program_id, &escrow, &authority, job_id);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(compliance, true),
+            AccountMeta::new_readonly(config, false),
+            AccountMeta::new_readonly(escrow, false),
+            AccountMeta::new(job, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: RNDRInstruction::FreezeJob.pack(),
+    }
+}
+
+/// Creates an 'UnfreezeJob' instruction.
+pub fn unfreeze_job(
+    program_id: Pubkey,
+    token_mint: impl Into<EscrowSeeds>,
+    compliance: Pubkey,
+    authority: Pubkey,
+    job_id: u64,
+) -> Instruction {
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (config, _bump_seed) = find_config_address(&program_id);
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let (job, _bump_seed) = find_job_address(&program_id, &escrow, &authority, job_id);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(compliance, true),
+            AccountMeta::new_readonly(config, false),
+            AccountMeta::new_readonly(escrow, false),
+            AccountMeta::new(job, false),
+        ],
+        data: RNDRInstruction::UnfreezeJob.pack(),
+    }
+}
+
+/// Creates a 'RegisterColdWallet' instruction.
+pub fn register_cold_wallet(
+    program_id: Pubkey,
+    token_mint: impl Into<EscrowSeeds>,
+    owner: Pubkey,
+    cold_wallet: Pubkey,
+) -> Instruction {
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let (treasury, _bump_seed) = find_treasury_address(&program_id, &escrow);
+    let treasury_associated_token = get_associated_token_address(&treasury, &token_mint);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(owner, true),
+            AccountMeta::new_readonly(escrow, false),
+            AccountMeta::new(treasury, false),
+            AccountMeta::new_readonly(treasury_associated_token, false),
+            AccountMeta::new_readonly(cold_wallet, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: RNDRInstruction::RegisterColdWallet.pack(),
+    }
+}
+
+/// Creates a 'ScheduleTreasurySweep' instruction.
+pub fn schedule_treasury_sweep(
+    program_id: Pubkey,
+    amount: impl Into<RndrAmount>,
+    token_mint: impl Into<EscrowSeeds>,
+    owner: Pubkey,
+) -> Instruction {
+    let RndrAmount(amount) = amount.into();
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (config, _bump_seed) = find_config_address(&program_id);
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let (treasury, _bump_seed) = find_treasury_address(&program_id, &escrow);
+    let treasury_associated_token = get_associated_token_address(&treasury, &token_mint);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(owner, true),
+            AccountMeta::new_readonly(config, false),
+            AccountMeta::new_readonly(escrow, false),
+            AccountMeta::new(treasury, false),
+            AccountMeta::new_readonly(treasury_associated_token, false),
+        ],
+        data: RNDRInstruction::ScheduleTreasurySweep { amount }.pack(),
+    }
+}
+
+/// Creates a 'SweepToColdWallet' instruction.
+pub fn sweep_to_cold_wallet(
+    program_id: Pubkey,
+    token_mint: impl Into<EscrowSeeds>,
+    cold_wallet: Pubkey,
+) -> Instruction {
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let (treasury, _bump_seed) = find_treasury_address(&program_id, &escrow);
+    let treasury_associated_token = get_associated_token_address(&treasury, &token_mint);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(escrow, false),
+            AccountMeta::new(treasury, false),
+            AccountMeta::new(treasury_associated_token, false),
+            AccountMeta::new(cold_wallet, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ],
+        data: RNDRInstruction::SweepToColdWallet.pack(),
+    }
+}
+
+/// Creates a 'CancelTreasurySweep' instruction.
+pub fn cancel_treasury_sweep(
+    program_id: Pubkey,
+    token_mint: impl Into<EscrowSeeds>,
+    guardian: Pubkey,
+) -> Instruction {
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (config, _bump_seed) = find_config_address(&program_id);
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let (treasury, _bump_seed) = find_treasury_address(&program_id, &escrow);
+    let treasury_associated_token = get_associated_token_address(&treasury, &token_mint);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(guardian, true),
+            AccountMeta::new_readonly(config, false),
+            AccountMeta::new_readonly(escrow, false),
+            AccountMeta::new(treasury, false),
+            AccountMeta::new_readonly(treasury_associated_token, false),
+        ],
+        data: RNDRInstruction::CancelTreasurySweep.pack(),
+    }
+}
+
+/// Creates a 'GetVersion' instruction.
+pub fn get_version(program_id: Pubkey) -> Instruction {
+    Instruction {
+        program_id,
+        accounts: vec![],
+        data: RNDRInstruction::GetVersion.pack(),
+    }
+}
+
+/// Creates a 'GetEscrowSummary' instruction.
+pub fn get_escrow_summary(program_id: Pubkey, token_mint: impl Into<EscrowSeeds>) -> Instruction {
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    Instruction {
+        program_id,
+        accounts: vec![AccountMeta::new_readonly(escrow, false)],
+        data: RNDRInstruction::GetEscrowSummary.pack(),
+    }
+}
+
+/// Creates a 'GetJobSummary' instruction.
+pub fn get_job_summary(
+    program_id: Pubkey,
+    token_mint: impl Into<EscrowSeeds>,
+    authority: Pubkey,
+    job_id: u64,
+) -> Instruction {
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let (job, _bump_seed) = find_job_address(&program_id, &escrow, &authority, job_id);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(escrow, false),
+            AccountMeta::new_readonly(job, false),
+        ],
+        data: RNDRInstruction::GetJobSummary.pack(),
+    }
+}
+
+/// Creates a 'GetEscrowedAmount' instruction.
+pub fn get_escrowed_amount(
+    program_id: Pubkey,
+    token_mint: impl Into<EscrowSeeds>,
+    authority: Pubkey,
+    job_id: u64,
+) -> Instruction {
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let (job, _bump_seed) = find_job_address(&program_id, &escrow, &authority, job_id);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(escrow, false),
+            AccountMeta::new_readonly(job, false),
+        ],
+        data: RNDRInstruction::GetEscrowedAmount.pack(),
+    }
+}
+
+/// Creates a 'ReconcileEscrow' instruction.
+pub fn reconcile_escrow(
+    program_id: Pubkey,
+    token_mint: impl Into<EscrowSeeds>,
+    token_account: Pubkey,
+    payer: Pubkey,
+) -> Instruction {
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let mut accounts = vec![
+        AccountMeta::new(escrow, false),
+        AccountMeta::new_readonly(token_account, false),
+        AccountMeta::new(payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    for index in 0..ESCROW_SHARD_COUNT {
+        let (escrow_shard, _bump_seed) = find_escrow_shard_address(&program_id, &escrow, index);
+        accounts.push(AccountMeta::new_readonly(escrow_shard, false));
+    }
+    Instruction {
+        program_id,
+        accounts,
+        data: RNDRInstruction::ReconcileEscrow.pack(),
+    }
+}
+
+/// Creates a 'SetEscrowTokenAccount' instruction.
+pub fn set_escrow_token_account(
+    program_id: Pubkey,
+    token_mint: impl Into<EscrowSeeds>,
+    owner: Pubkey,
+    token_account: Pubkey,
+    new_token_account: Pubkey,
+) -> Instruction {
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(escrow, false),
+            AccountMeta::new(owner, true),
+            AccountMeta::new(token_account, false),
+            AccountMeta::new(new_token_account, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ],
+        data: RNDRInstruction::SetEscrowTokenAccount.pack(),
+    }
+}
+
+/// Creates a 'MigrateEscrowMint' instruction, beginning the migration numbered `index`, which is
+/// the escrow's number of mint migrations. `destination_token_account` is the token account of the
+/// escrow's current mint that its old tokens will be sent to.
+#[allow(clippy::too_many_arguments)]
+pub fn migrate_escrow_mint(
+    program_id: Pubkey,
+    token_mint: impl Into<EscrowSeeds>,
+    owner: Pubkey,
+    governance: Pubkey,
+    index: u8,
+    new_token_mint: Pubkey,
+    destination_token_account: Pubkey,
+    rate_numerator: u64,
+    rate_denominator: u64,
+) -> Instruction {
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let (config, _bump_seed) = find_config_address(&program_id);
+    let (mint_migration, _bump_seed) = find_mint_migration_address(&program_id, &escrow, index);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(escrow, false),
+            AccountMeta::new(owner, true),
+            AccountMeta::new_readonly(config, false),
+            AccountMeta::new_readonly(governance, true),
+            AccountMeta::new(mint_migration, false),
+            AccountMeta::new_readonly(new_token_mint, false),
+            AccountMeta::new(
+                get_associated_token_address(&escrow, &new_token_mint),
+                false,
+            ),
+            AccountMeta::new_readonly(destination_token_account, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+        ],
+        data: RNDRInstruction::MigrateEscrowMint {
+            rate_numerator,
+            rate_denominator,
+        }
+        .pack(),
+    }
+}
+
+/// Creates a 'MigrateEscrowMint' instruction for an escrow that has an approver, signed by the
+/// approver.
+#[allow(clippy::too_many_arguments)]
+pub fn migrate_escrow_mint_with_approver(
+    program_id: Pubkey,
+    token_mint: impl Into<EscrowSeeds>,
+    owner: Pubkey,
+    governance: Pubkey,
+    approver: Pubkey,
+    index: u8,
+    new_token_mint: Pubkey,
+    destination_token_account: Pubkey,
+    rate_numerator: u64,
+    rate_denominator: u64,
+) -> Instruction {
+    let mut instruction = migrate_escrow_mint(
+        program_id,
+        token_mint,
+        owner,
+        governance,
+        index,
+        new_token_mint,
+        destination_token_account,
+        rate_numerator,
+        rate_denominator,
+    );
+    instruction
+        .accounts
+        .push(AccountMeta::new_readonly(approver, true));
+    instruction
+}
+
+/// Creates a 'ConvertEscrowMintVault' instruction. `token_account` is the token account currently
+/// holding the escrow's pooled tokens.
+#[allow(clippy::too_many_arguments)]
+pub fn convert_escrow_mint_vault(
+    program_id: Pubkey,
+    token_mint: impl Into<EscrowSeeds>,
+    owner: Pubkey,
+    index: u8,
+    token_account: Pubkey,
+    new_token_mint: Pubkey,
+    source_token_account: Pubkey,
+    destination_token_account: Pubkey,
+) -> Instruction {
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let (mint_migration, _bump_seed) = find_mint_migration_address(&program_id, &escrow, index);
+    let mut accounts = vec![
+        AccountMeta::new(escrow, false),
+        AccountMeta::new(owner, true),
+        AccountMeta::new(mint_migration, false),
+        AccountMeta::new(token_account, false),
+        AccountMeta::new(
+            get_associated_token_address(&escrow, &new_token_mint),
+            false,
+        ),
+        AccountMeta::new(source_token_account, false),
+        AccountMeta::new(destination_token_account, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+    ];
+    for index in 0..ESCROW_SHARD_COUNT {
+        let (escrow_shard, _bump_seed) = find_escrow_shard_address(&program_id, &escrow, index);
+        accounts.push(AccountMeta::new(escrow_shard, false));
+    }
+    Instruction {
+        program_id,
+        accounts,
+        data: RNDRInstruction::ConvertEscrowMintVault.pack(),
+    }
+}
+
+/// Creates a 'RedenominateJobs' instruction.
+pub fn redenominate_jobs(
+    program_id: Pubkey,
+    token_mint: impl Into<EscrowSeeds>,
+    payer: Pubkey,
+    index: u8,
+    jobs: &[Pubkey],
+) -> Instruction {
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let (mint_migration, _bump_seed) = find_mint_migration_address(&program_id, &escrow, index);
+    let mut accounts = vec![
+        AccountMeta::new_readonly(escrow, false),
+        AccountMeta::new(mint_migration, false),
+        AccountMeta::new(payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    accounts.extend(jobs.iter().map(|job| AccountMeta::new(*job, false)));
+    Instruction {
+        program_id,
+        accounts,
+        data: RNDRInstruction::RedenominateJobs.pack(),
+    }
+}
+
+/// Creates a 'ConvertIsolatedJobMint' instruction. `previous_token_mint` is the token mint the
+/// escrow is migrated from.
+#[allow(clippy::too_many_arguments)]
+pub fn convert_isolated_job_mint(
+    program_id: Pubkey,
+    token_mint: impl Into<EscrowSeeds>,
+    owner: Pubkey,
+    index: u8,
+    job: Pubkey,
+    previous_token_mint: Pubkey,
+    new_token_mint: Pubkey,
+    source_token_account: Pubkey,
+    destination_token_account: Pubkey,
+) -> Instruction {
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let (mint_migration, _bump_seed) = find_mint_migration_address(&program_id, &escrow, index);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(escrow, false),
+            AccountMeta::new(owner, true),
+            AccountMeta::new(mint_migration, false),
+            AccountMeta::new(job, false),
+            AccountMeta::new(
+                get_associated_token_address(&job, &previous_token_mint),
+                false,
+            ),
+            AccountMeta::new(get_associated_token_address(&job, &new_token_mint), false),
+            AccountMeta::new_readonly(new_token_mint, false),
+            AccountMeta::new(source_token_account, false),
+            AccountMeta::new(destination_token_account, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+        ],
+        data: RNDRInstruction::ConvertIsolatedJobMint.pack(),
+    }
+}
+
+/// Creates an 'AcceptJobAuthority' instruction.
+pub fn accept_job_authority(
+    program_id: Pubkey,
+    token_mint: impl Into<EscrowSeeds>,
+    new_authority: Pubkey,
+    job: Pubkey,
+) -> Instruction {
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(new_authority, true),
+            AccountMeta::new_readonly(escrow, false),
+            AccountMeta::new(job, false),
+        ],
+        data: RNDRInstruction::AcceptJobAuthority.pack(),
+    }
+}
+
+/// Creates a 'CreateJobBundle' instruction.
+pub fn create_job_bundle(
+    program_id: Pubkey,
+    token_mint: impl Into<EscrowSeeds>,
+    authority: Pubkey,
+    bundle_id: u64,
+    first_job_id: u64,
+    job_count: u8,
+    metadata_hash: [u8; HASH_BYTES],
+) -> Instruction {
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let (job_bundle, _bump_seed) =
+        find_job_bundle_address(&program_id, &escrow, &authority, bundle_id);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(authority, true),
+            AccountMeta::new_readonly(escrow, false),
+            AccountMeta::new(job_bundle, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: RNDRInstruction::CreateJobBundle {
+            bundle_id,
+            first_job_id,
+            job_count,
+            metadata_hash,
+        }
+        .pack(),
+    }
+}
+
+/// Creates a 'FundJobBundle' instruction.
+#[allow(clippy::too_many_arguments)]
+pub fn fund_job_bundle(
+    program_id: Pubkey,
+    token_mint: impl Into<EscrowSeeds>,
+    funder: Pubkey,
+    source_token: Pubkey,
+    source_authority: Pubkey,
+    authority: Pubkey,
+    bundle_id: u64,
+    first_job_id: u64,
+    count: u8,
+    amount_per_job: u64,
+) -> Instruction {
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let escrow_associated_token = get_associated_token_address(&escrow, &token_mint);
+    let (job_bundle, _bump_seed) =
+        find_job_bundle_address(&program_id, &escrow, &authority, bundle_id);
+    let mut accounts = vec![
+        AccountMeta::new(funder, true),
+        AccountMeta::new(source_token, false),
+        AccountMeta::new_readonly(source_authority, true),
+        AccountMeta::new(job_bundle, false),
+        AccountMeta::new_readonly(escrow, false),
+        AccountMeta::new(escrow_associated_token, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+    ];
+    for job_id in (first_job_id..).take(count as usize) {
+        let (job, _bump_seed) = find_job_address(&program_id, &escrow, &authority, job_id);
+        let (escrow_shard, _bump_seed) =
+            find_escrow_shard_address(&program_id, &escrow, EscrowShard::index_for_job(&job));
+        accounts.push(AccountMeta::new(job, false));
+        accounts.push(AccountMeta::new(escrow_shard, false));
+    }
+    Instruction {
+        program_id,
+        accounts,
+        data: RNDRInstruction::FundJobBundle {
+            first_job_id,
+            count,
+            amount_per_job,
+        }
+        .pack(),
+    }
+}
+
+/// Creates a 'CancelJobBundle' instruction.
+pub fn cancel_job_bundle(
+    program_id: Pubkey,
+    token_mint: impl Into<EscrowSeeds>,
+    authority: Pubkey,
+    bundle_id: u64,
+    destination_token: Pubkey,
+    jobs: &[Pubkey],
+) -> Instruction {
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let escrow_associated_token = get_associated_token_address(&escrow, &token_mint);
+    let (job_bundle, _bump_seed) =
+        find_job_bundle_address(&program_id, &escrow, &authority, bundle_id);
+    let mut accounts = vec![
+        AccountMeta::new(authority, true),
+        AccountMeta::new(escrow, false),
+        AccountMeta::new(escrow_associated_token, false),
+        AccountMeta::new(job_bundle, false),
+        AccountMeta::new(destination_token, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+    ];
+    for job in jobs {
+        accounts.push(AccountMeta::new(*job, false));
+    }
+    let mut escrow_shards: Vec<AccountMeta> = vec![];
+    for job in jobs {
+        let escrow_shard = escrow_shard_account(&program_id, &escrow, job);
+        if !escrow_shards.contains(&escrow_shard) {
+            escrow_shards.push(escrow_shard);
+        }
+    }
+    accounts.extend(escrow_shards);
+    Instruction {
+        program_id,
+        accounts,
+        data: RNDRInstruction::CancelJobBundle.pack(),
+    }
+}
+
+/// Creates an 'AggregateJobBundle' instruction.
+pub fn aggregate_job_bundle(
+    program_id: Pubkey,
+    token_mint: impl Into<EscrowSeeds>,
+    job_bundle: Pubkey,
+    jobs: &[Pubkey],
+) -> Instruction {
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let mut accounts = vec![
+        AccountMeta::new(job_bundle, false),
+        AccountMeta::new_readonly(escrow, false),
+    ];
+    for job in jobs {
+        accounts.push(AccountMeta::new_readonly(*job, false));
+    }
+    Instruction {
+        program_id,
+        accounts,
+        data: RNDRInstruction::AggregateJobBundle.pack(),
+    }
+}
+
+/// Creates an 'InitJobTree' instruction.
+pub fn init_job_tree(
+    program_id: Pubkey,
+    token_mint: impl Into<EscrowSeeds>,
+    authority: Pubkey,
+    merkle_tree: Pubkey,
+    max_depth: u8,
+    max_buffer_size: u32,
+) -> Instruction {
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let (job_tree, _bump_seed) = find_job_tree_address(&program_id, &escrow, &authority);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(authority, true),
+            AccountMeta::new_readonly(escrow, false),
+            AccountMeta::new(job_tree, false),
+            AccountMeta::new(merkle_tree, false),
+            AccountMeta::new_readonly(NOOP_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ACCOUNT_COMPRESSION_PROGRAM_ID, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: RNDRInstruction::InitJobTree {
+            max_depth,
+            max_buffer_size,
+        }
+        .pack(),
+    }
+}
+
+/// Creates an 'AppendCompressedJob' instruction.
+pub fn append_compressed_job(
+    program_id: Pubkey,
+    token_mint: impl Into<EscrowSeeds>,
+    authority: Pubkey,
+    merkle_tree: Pubkey,
+    source_token: Pubkey,
+    source_authority: Pubkey,
+    amount: impl Into<RndrAmount>,
+) -> Instruction {
+    let RndrAmount(amount) = amount.into();
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let escrow_associated_token = get_associated_token_address(&escrow, &token_mint);
+    let (job_tree, _bump_seed) = find_job_tree_address(&program_id, &escrow, &authority);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(source_token, false),
+            AccountMeta::new_readonly(source_authority, true),
+            AccountMeta::new(job_tree, false),
+            AccountMeta::new(escrow, false),
+            AccountMeta::new(escrow_associated_token, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new(merkle_tree, false),
+            AccountMeta::new_readonly(NOOP_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ACCOUNT_COMPRESSION_PROGRAM_ID, false),
+        ],
+        data: RNDRInstruction::AppendCompressedJob { amount }.pack(),
+    }
+}
+
+/// Creates a 'ReplaceCompressedJob' instruction with a proof of the job's current leaf against a
+/// recent root of the merkle tree
+#[allow(clippy::too_many_arguments)]
+pub fn replace_compressed_job(
+    program_id: Pubkey,
+    token_mint: impl Into<EscrowSeeds>,
+    authority: Pubkey,
+    merkle_tree: Pubkey,
+    job: CompressedJob,
+    node: Pubkey,
+    root: [u8; HASH_BYTES],
+    proof: Vec<[u8; HASH_BYTES]>,
+) -> Instruction {
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let (job_tree, _bump_seed) = find_job_tree_address(&program_id, &escrow, &authority);
+    let mut accounts = vec![
+        AccountMeta::new_readonly(authority, true),
+        AccountMeta::new_readonly(escrow, false),
+        AccountMeta::new_readonly(job_tree, false),
+        AccountMeta::new(merkle_tree, false),
+        AccountMeta::new_readonly(NOOP_PROGRAM_ID, false),
+        AccountMeta::new_readonly(ACCOUNT_COMPRESSION_PROGRAM_ID, false),
+    ];
+    if job.status == JobStatus::Committed {
+        accounts.push(AccountMeta::new_readonly(job.node, true));
+    }
+    accounts.extend(
+        proof
+            .into_iter()
+            .map(|node| AccountMeta::new_readonly(Pubkey::new_from_array(node), false)),
+    );
+    Instruction {
+        program_id,
+        accounts,
+        data: RNDRInstruction::ReplaceCompressedJob { job, node, root }.pack(),
+    }
+}
+
+/// Creates a 'SettleCompressedJob' instruction with a proof of the job's current leaf against a
+/// recent root of the merkle tree
+#[allow(clippy::too_many_arguments)]
+pub fn settle_compressed_job(
+    program_id: Pubkey,
+    token_mint: impl Into<EscrowSeeds>,
+    authority: Pubkey,
+    merkle_tree: Pubkey,
+    destination_token: Pubkey,
+    job: CompressedJob,
+    root: [u8; HASH_BYTES],
+    proof: Vec<[u8; HASH_BYTES]>,
+) -> Instruction {
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let escrow_associated_token = get_associated_token_address(&escrow, &token_mint);
+    let (job_tree, _bump_seed) = find_job_tree_address(&program_id, &escrow, &authority);
+    let mut accounts = vec![
+        AccountMeta::new_readonly(authority, true),
+        AccountMeta::new(escrow, false),
+        AccountMeta::new(escrow_associated_token, false),
+        AccountMeta::new(job_tree, false),
+        AccountMeta::new(destination_token, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new(merkle_tree, false),
+        AccountMeta::new_readonly(NOOP_PROGRAM_ID, false),
+        AccountMeta::new_readonly(ACCOUNT_COMPRESSION_PROGRAM_ID, false),
+    ];
+    accounts.extend(
+        proof
+            .into_iter()
+            .map(|node| AccountMeta::new_readonly(Pubkey::new_from_array(node), false)),
+    );
+    Instruction {
+        program_id,
+        accounts,
+        data: RNDRInstruction::SettleCompressedJob { job, root }.pack(),
+    }
+}
+
+/// Creates an 'InitJobIndex' instruction.
+pub fn init_job_index(
+    program_id: Pubkey,
+    token_mint: impl Into<EscrowSeeds>,
+    authority: Pubkey,
+) -> Instruction {
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) = find_escrow_address_with_id(&program_id, &token_mint, escrow_id);
+    let (job_index, _bump_seed) = find_job_index_address(&program_id, &escrow, &authority);
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(authority, true),
+            AccountMeta::new_readonly(escrow, false),
+            AccountMeta::new(job_index, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data: RNDRInstruction::InitJobIndex.pack(),
+    }
+}
+
+/// Appends the JobIndex of a job authority and its next page to an instruction that creates jobs
+/// of the authority, after all its other accounts and before the Stats account, to record the jobs
+/// it creates. The page is `JobIndex::next_page` of the index before the instruction.
+pub fn with_job_index(
+    mut instruction: Instruction,
+    escrow: Pubkey,
+    authority: Pubkey,
+    page: u32,
+) -> Instruction {
+    let (job_index, _bump_seed) =
+        find_job_index_address(&instruction.program_id, &escrow, &authority);
+    let (job_index_page, _bump_seed) =
+        find_job_index_page_address(&instruction.program_id, &job_index, page);
+    instruction.accounts.extend([
+        AccountMeta::new(job_index, false),
+        AccountMeta::new(job_index_page, false),
+    ]);
+    instruction
+}
+
+/// Appends the JobQueue of an Escrow to a 'FundJob' instruction, after all its other accounts
+/// including the JobIndex accounts and before the Stats account, to push the Job it creates.
+pub fn with_job_queue(mut instruction: Instruction, escrow: Pubkey) -> Instruction {
+    let (job_queue, _bump_seed) = find_job_queue_address(&instruction.program_id, &escrow);
+    instruction
+        .accounts
+        .push(AccountMeta::new(job_queue, false));
+    instruction
+}
+
+/// Appends the accounts to mint a compressed NFT receipt of the Job to the funder with Bubblegum
+/// to a 'FundJob' instruction, after all its other accounts including the JobQueue and before the
+/// Stats account. The receipt authority PDA must be the delegate of the merkle tree.
+pub fn with_job_receipt(mut instruction: Instruction, merkle_tree: Pubkey) -> Instruction {
+    let (receipt_authority, _bump_seed) = find_receipt_authority_address(&instruction.program_id);
+    let (tree_config, _bump_seed) = find_tree_config_address(&merkle_tree);
+    instruction.accounts.extend([
+        AccountMeta::new_readonly(receipt_authority, false),
+        AccountMeta::new(tree_config, false),
+        AccountMeta::new(merkle_tree, false),
+        AccountMeta::new_readonly(NOOP_PROGRAM_ID, false),
+        AccountMeta::new_readonly(ACCOUNT_COMPRESSION_PROGRAM_ID, false),
+        AccountMeta::new_readonly(BUBBLEGUM_PROGRAM_ID, false),
+    ]);
+    instruction
+}
+
+/// Replaces the associated token account of an Escrow in an instruction with the token account
+/// set with `SetEscrowTokenAccount`, which holds the Escrow's pooled tokens instead.
+pub fn with_escrow_token_account(
+    mut instruction: Instruction,
+    token_mint: impl Into<EscrowSeeds>,
+    token_account: Pubkey,
+) -> Instruction {
+    let EscrowSeeds {
+        token_mint,
+        escrow_id,
+    } = token_mint.into();
+    let (escrow, _bump_seed) =
+        find_escrow_address_with_id(&instruction.program_id, &token_mint, escrow_id);
+    let escrow_associated_token = get_associated_token_address(&escrow, &token_mint);
+    for account in &mut instruction.accounts {
+        if account.pubkey == escrow_associated_token {
+            account.pubkey = token_account;
+        }
     }
+    instruction
 }
@@ -0,0 +1,137 @@
+//! Accounting invariants checked at the end of instructions that move tokens
+//!
+//! The checks compare the balances of the token accounts the program holds tokens in against the
+//! amounts its accounts record, so an accounting bug fails the instruction that introduced it
+//! instead of surfacing later as a disbursement that can't be paid. They run in debug builds, which
+//! includes the integration tests, and in builds with the `invariants` feature, such as devnet
+//! deployments. Otherwise every check returns immediately, so mainnet builds don't spend compute
+//! units on them.
+
+use {
+    crate::{
+        error::RNDRError,
+        state::{Escrow, EscrowShard, Job, Treasury},
+    },
+    solana_program::{
+        account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+        program_pack::Pack,
+    },
+};
+
+/// Whether invariants are checked by this build of the program
+pub const ENABLED: bool = cfg!(any(debug_assertions, feature = "invariants"));
+
+/// Check that the token account of an escrow holds at least the escrow's amount plus the amounts
+/// of the escrow shards provided, and at least the sum of the amounts of the pooled jobs provided
+///
+/// Jobs and escrow shards provided more than once are only counted once, and escrow shards that
+/// haven't been created yet are skipped.
+pub fn check_escrow_vault(
+    escrow_info: &AccountInfo,
+    vault_info: &AccountInfo,
+    job_infos: &[&AccountInfo],
+    escrow_shard_infos: &[&AccountInfo],
+) -> ProgramResult {
+    if !ENABLED {
+        return Ok(());
+    }
+
+    let balance = token_balance(vault_info)?;
+    let escrow = Escrow::unpack_account(&escrow_info.try_borrow_data()?)?;
+    let mut escrow_amount = escrow.amount;
+    for (i, escrow_shard_info) in escrow_shard_infos.iter().enumerate() {
+        if escrow_shard_info.data_is_empty()
+            || escrow_shard_infos[..i]
+                .iter()
+                .any(|other_info| other_info.key == escrow_shard_info.key)
+        {
+            continue;
+        }
+        let escrow_shard = EscrowShard::unpack(&escrow_shard_info.try_borrow_data()?)?;
+        escrow_amount = escrow_amount
+            .checked_add(escrow_shard.amount)
+            .ok_or(RNDRError::MathError)?;
+    }
+    if balance < escrow_amount {
+        msg!(
+            "Escrow token account balance {} is less than the escrow amount {}",
+            balance,
+            escrow_amount
+        );
+        return Err(RNDRError::InvariantViolation.into());
+    }
+
+    let mut jobs_amount = 0u64;
+    for (i, job_info) in job_infos.iter().enumerate() {
+        if job_infos[..i]
+            .iter()
+            .any(|other_info| other_info.key == job_info.key)
+        {
+            continue;
+        }
+        let job = Job::unpack_account(&job_info.try_borrow_data()?)?;
+        if job.isolated {
+            continue;
+        }
+        jobs_amount = jobs_amount
+            .checked_add(job.amount)
+            .ok_or(RNDRError::MathError)?;
+    }
+    if balance < jobs_amount {
+        msg!(
+            "Escrow token account balance {} is less than the job amounts {}",
+            balance,
+            jobs_amount
+        );
+        return Err(RNDRError::InvariantViolation.into());
+    }
+
+    Ok(())
+}
+
+/// Check that the token account of an isolated job holds at least the job's amount
+pub fn check_isolated_job_vault(job_info: &AccountInfo, vault_info: &AccountInfo) -> ProgramResult {
+    if !ENABLED {
+        return Ok(());
+    }
+
+    let balance = token_balance(vault_info)?;
+    let job = Job::unpack_account(&job_info.try_borrow_data()?)?;
+    if balance < job.amount {
+        msg!(
+            "Job token account balance {} is less than the job amount {}",
+            balance,
+            job.amount
+        );
+        return Err(RNDRError::InvariantViolation.into());
+    }
+
+    Ok(())
+}
+
+/// Check that the token account of a treasury holds at least the amount of its scheduled sweep
+pub fn check_treasury_vault(
+    treasury_info: &AccountInfo,
+    vault_info: &AccountInfo,
+) -> ProgramResult {
+    if !ENABLED {
+        return Ok(());
+    }
+
+    let balance = token_balance(vault_info)?;
+    let treasury = Treasury::unpack_account(&treasury_info.try_borrow_data()?)?;
+    if balance < treasury.sweep_amount {
+        msg!(
+            "Treasury token account balance {} is less than the scheduled sweep amount {}",
+            balance,
+            treasury.sweep_amount
+        );
+        return Err(RNDRError::InvariantViolation.into());
+    }
+
+    Ok(())
+}
+
+fn token_balance(token_info: &AccountInfo) -> Result<u64, ProgramError> {
+    Ok(spl_token::state::Account::unpack(&token_info.try_borrow_data()?)?.amount)
+}
@@ -2,10 +2,30 @@
 
 //! A RNDR program for the Solana blockchain.
 
+pub mod account_compression;
+pub mod amount;
+pub mod bubblegum;
+#[cfg(feature = "client")]
+pub mod client;
+#[cfg(feature = "codegen")]
+pub mod codegen;
+pub mod cpi;
 pub mod entrypoint;
 pub mod error;
+pub mod events;
 pub mod instruction;
+pub mod invariants;
+pub mod math;
+pub mod oracle;
+pub mod pda;
+#[cfg(not(target_arch = "wasm32"))]
 pub mod processor;
+#[cfg(feature = "serde")]
+mod serde_pubkey;
 pub mod state;
+pub mod summary;
+#[cfg(feature = "test-fixtures")]
+pub mod test_fixtures;
+pub mod version;
 
 solana_program::declare_id!("7g4aX3DYhk6xHiGuoAbEnVTp9HMgLqyENoK53AVm267E");
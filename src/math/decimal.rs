@@ -0,0 +1,147 @@
+use {
+    super::u256::U256,
+    crate::error::RNDRError,
+    solana_program::program_error::ProgramError,
+    std::{convert::TryFrom, fmt},
+};
+
+/// Scale of a `Decimal`, the number of its units in one
+pub const WAD: u128 = 1_000_000_000_000_000_000;
+
+/// Half of the scale of a `Decimal`, added before rounding to the nearest integer
+const HALF_WAD: u128 = WAD / 2;
+
+/// Scale of a `Decimal` in basis points
+const BPS_SCALER: u128 = WAD / 10_000;
+
+/// Unsigned fixed-point number with 18 decimal places, scaled by `WAD`
+///
+/// Products and quotients are rounded down to the nearest 10^-18, and their intermediate values are
+/// 256 bits wide, so multiplying by an amount and then dividing by another is exact up to that
+/// last place. Converting back to an integer rounds explicitly, down with `try_floor_u64`, up with
+/// `try_ceil_u64` or to the nearest with `try_round_u64`, so every computation states which way
+/// its remainder goes.
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Decimal(U256);
+
+impl Decimal {
+    /// Zero
+    pub fn zero() -> Self {
+        Self(U256::ZERO)
+    }
+
+    /// One
+    pub fn one() -> Self {
+        Self(U256::from_u128(WAD))
+    }
+
+    /// Decimal of a value scaled by `WAD`
+    pub fn from_scaled_val(scaled_val: u128) -> Self {
+        Self(U256::from_u128(scaled_val))
+    }
+
+    /// Value scaled by `WAD`
+    pub fn try_to_scaled_val(self) -> Result<u128, ProgramError> {
+        self.0.to_u128().ok_or_else(|| RNDRError::MathError.into())
+    }
+
+    /// Decimal of a number of basis points, where 10,000 is one
+    pub fn from_bps(bps: u16) -> Self {
+        Self::from_scaled_val(u128::from(bps) * BPS_SCALER)
+    }
+
+    /// Sum of two decimals
+    pub fn try_add(self, other: impl Into<Self>) -> Result<Self, ProgramError> {
+        self.0
+            .checked_add(other.into().0)
+            .map(Self)
+            .ok_or_else(|| RNDRError::MathError.into())
+    }
+
+    /// Difference of two decimals, which fails if it's negative
+    pub fn try_sub(self, other: impl Into<Self>) -> Result<Self, ProgramError> {
+        self.0
+            .checked_sub(other.into().0)
+            .map(Self)
+            .ok_or_else(|| RNDRError::MathError.into())
+    }
+
+    /// Product of two decimals, rounded down
+    pub fn try_mul(self, other: impl Into<Self>) -> Result<Self, ProgramError> {
+        self.0
+            .checked_mul(other.into().0)
+            .and_then(|product| product.checked_div_rem(U256::from_u128(WAD)))
+            .map(|(quotient, _)| Self(quotient))
+            .ok_or_else(|| RNDRError::MathError.into())
+    }
+
+    /// Quotient of two decimals, rounded down, which fails if the divisor is zero
+    pub fn try_div(self, other: impl Into<Self>) -> Result<Self, ProgramError> {
+        self.0
+            .checked_mul(U256::from_u128(WAD))
+            .and_then(|dividend| dividend.checked_div_rem(other.into().0))
+            .map(|(quotient, _)| Self(quotient))
+            .ok_or_else(|| RNDRError::MathError.into())
+    }
+
+    /// Decimal rounded down to an integer
+    pub fn try_floor_u64(self) -> Result<u64, ProgramError> {
+        Self::to_u64(self.0)
+    }
+
+    /// Decimal rounded up to an integer
+    pub fn try_ceil_u64(self) -> Result<u64, ProgramError> {
+        let ceil = self
+            .0
+            .checked_add(U256::from_u128(WAD - 1))
+            .ok_or(RNDRError::MathError)?;
+        Self::to_u64(ceil)
+    }
+
+    /// Decimal rounded to the nearest integer, with halves rounded up
+    pub fn try_round_u64(self) -> Result<u64, ProgramError> {
+        let round = self
+            .0
+            .checked_add(U256::from_u128(HALF_WAD))
+            .ok_or(RNDRError::MathError)?;
+        Self::to_u64(round)
+    }
+
+    /// Integer part of a scaled value as a `u64`
+    fn to_u64(scaled_val: U256) -> Result<u64, ProgramError> {
+        scaled_val
+            .checked_div_rem(U256::from_u128(WAD))
+            .and_then(|(quotient, _)| quotient.to_u128())
+            .and_then(|quotient| u64::try_from(quotient).ok())
+            .ok_or_else(|| RNDRError::MathError.into())
+    }
+}
+
+impl From<u64> for Decimal {
+    fn from(value: u64) -> Self {
+        Self::from_scaled_val(u128::from(value) * WAD)
+    }
+}
+
+impl fmt::Debug for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl fmt::Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let wad = U256::from_u128(WAD);
+        let (integer, fraction) = self.0.checked_div_rem(wad).unwrap_or_default();
+        // The integer part can exceed a u128, so it's printed as its digits above and below 10^36
+        let (high, low) = integer
+            .checked_div_rem(wad.checked_mul(wad).unwrap_or_default())
+            .unwrap_or_default();
+        let fraction = fraction.to_u128().unwrap_or_default();
+        let low = low.to_u128().unwrap_or_default();
+        match high.to_u128().unwrap_or_default() {
+            0 => write!(f, "{}.{:018}", low, fraction),
+            high => write!(f, "{}{:036}.{:018}", high, low, fraction),
+        }
+    }
+}
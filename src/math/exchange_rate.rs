@@ -0,0 +1,90 @@
+use {
+    crate::error::RNDRError,
+    solana_program::{msg, program_error::ProgramError},
+    std::convert::TryFrom,
+};
+
+/// Decimals of the RNDR mint, whose base units exchange rates are quoted in
+pub const RNDR_DECIMALS: u8 = 8;
+
+/// Rate a token is exchanged for RNDR at, as a fraction of RNDR base units per base unit of the
+/// token
+///
+/// A rate per base unit already accounts for the decimals of both mints, so a job funded in another
+/// token converts every amount with the same two integers, and the rate recorded on the job is the
+/// one its amounts are settled at. Amounts are converted to RNDR rounded down with
+/// `ExchangeRate::try_to_rndr`, and back to the token rounded up with `ExchangeRate::try_from_rndr`,
+/// so a conversion never credits more RNDR than the tokens are worth, and converting a value in RNDR
+/// to tokens always covers it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ExchangeRate {
+    /// RNDR base units that `denominator` base units of the token are worth, which isn't zero
+    numerator: u64,
+    /// Base units of the token worth `numerator` RNDR base units, which isn't zero
+    denominator: u64,
+}
+
+impl ExchangeRate {
+    /// Rate of a fraction of RNDR base units per base unit of the token, or `None` if either part
+    /// of it is zero
+    pub const fn new(numerator: u64, denominator: u64) -> Option<Self> {
+        if numerator == 0 || denominator == 0 {
+            None
+        } else {
+            Some(Self {
+                numerator,
+                denominator,
+            })
+        }
+    }
+
+    /// Rate of a price of `price * 10^exponent` RNDR for one whole token, converted to base units
+    /// with the decimals of each mint, which fails if the price is zero or the rate doesn't fit
+    pub fn try_from_price(
+        price: u64,
+        exponent: i32,
+        token_decimals: u8,
+        rndr_decimals: u8,
+    ) -> Result<Self, ProgramError> {
+        let exponent = exponent
+            .checked_add(i32::from(rndr_decimals))
+            .and_then(|exponent| exponent.checked_sub(i32::from(token_decimals)))
+            .ok_or(RNDRError::MathError)?;
+        let scale = 10u64
+            .checked_pow(exponent.unsigned_abs())
+            .ok_or(RNDRError::MathError)?;
+        let (numerator, denominator) = if exponent >= 0 {
+            (price.checked_mul(scale).ok_or(RNDRError::MathError)?, 1)
+        } else {
+            (price, scale)
+        };
+        Self::new(numerator, denominator).ok_or_else(|| {
+            msg!("Price of an exchange rate can't be zero");
+            RNDRError::MathError.into()
+        })
+    }
+
+    /// RNDR base units that `denominator` base units of the token are worth
+    pub const fn numerator(self) -> u64 {
+        self.numerator
+    }
+
+    /// Base units of the token worth `numerator` RNDR base units
+    pub const fn denominator(self) -> u64 {
+        self.denominator
+    }
+
+    /// Value in RNDR of an amount of the token, rounded down
+    pub fn try_to_rndr(self, amount: u64) -> Result<u64, ProgramError> {
+        let value = u128::from(amount) * u128::from(self.numerator) / u128::from(self.denominator);
+        u64::try_from(value).map_err(|_| RNDRError::MathError.into())
+    }
+
+    /// Amount of the token worth a value in RNDR, rounded up
+    pub fn try_from_rndr(self, value: u64) -> Result<u64, ProgramError> {
+        // The product of two u64s is at most (2^64 - 1)^2, so it can't overflow a u128
+        let amount =
+            (u128::from(value) * u128::from(self.denominator)).div_ceil(u128::from(self.numerator));
+        u64::try_from(amount).map_err(|_| RNDRError::MathError.into())
+    }
+}
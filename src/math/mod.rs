@@ -0,0 +1,12 @@
+//! Fixed-point math for fees, rewards and exchange rates
+
+pub use decimal::*;
+pub use exchange_rate::*;
+pub use rate::*;
+pub use split::*;
+
+mod decimal;
+mod exchange_rate;
+mod rate;
+mod split;
+mod u256;
@@ -0,0 +1,88 @@
+use {
+    super::Decimal,
+    solana_program::{msg, program_error::ProgramError},
+    std::{convert::TryFrom, fmt},
+};
+
+/// Basis points in a rate of 100%
+pub const MAX_BPS: u16 = 10_000;
+
+/// Rate of an amount in basis points, at most 100%
+///
+/// Fees, surcharges, shares and decays are each a portion of the amount they're taken from, so a
+/// rate can only be constructed up to `MAX_BPS`, and instructions and accounts holding a rate above
+/// it fail to unpack instead of charging more than the amount.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "u16", try_from = "u16")
+)]
+pub struct Rate(u16);
+
+impl Rate {
+    /// Rate of 0%
+    pub const ZERO: Self = Self(0);
+    /// Rate of 100%
+    pub const ONE: Self = Self(MAX_BPS);
+
+    /// Rate of a number of basis points, or `None` if it's above `MAX_BPS`
+    pub const fn from_bps(bps: u16) -> Option<Self> {
+        if bps > MAX_BPS {
+            None
+        } else {
+            Some(Self(bps))
+        }
+    }
+
+    /// Rate of a number of basis points in a constant, which fails to compile if it's above
+    /// `MAX_BPS`
+    pub const fn new(bps: u16) -> Self {
+        assert!(bps <= MAX_BPS, "rate is above 100%");
+        Self(bps)
+    }
+
+    /// Number of basis points of the rate
+    pub const fn bps(self) -> u16 {
+        self.0
+    }
+
+    /// Rate of the rest of an amount after this rate is taken from it
+    pub const fn complement(self) -> Self {
+        Self(MAX_BPS - self.0)
+    }
+
+    /// Portion of an amount at the rate, rounded down, which never exceeds the amount
+    pub fn try_apply(self, amount: u64) -> Result<u64, ProgramError> {
+        Decimal::from(amount).try_mul(self)?.try_floor_u64()
+    }
+}
+
+impl TryFrom<u16> for Rate {
+    type Error = ProgramError;
+
+    fn try_from(bps: u16) -> Result<Self, Self::Error> {
+        Self::from_bps(bps).ok_or_else(|| {
+            msg!("Rate of {} basis points is above 100%", bps);
+            ProgramError::InvalidArgument
+        })
+    }
+}
+
+impl From<Rate> for u16 {
+    fn from(rate: Rate) -> Self {
+        rate.0
+    }
+}
+
+impl From<Rate> for Decimal {
+    fn from(rate: Rate) -> Self {
+        Decimal::from_bps(rate.0)
+    }
+}
+
+impl fmt::Display for Rate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{:02}%", self.0 / 100, self.0 % 100)
+    }
+}
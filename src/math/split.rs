@@ -0,0 +1,97 @@
+use {
+    super::{Rate, MAX_BPS},
+    crate::error::RNDRError,
+    num_enum::{IntoPrimitive, TryFromPrimitive},
+    solana_program::{msg, program_error::ProgramError},
+};
+
+/// Enum representing where the remainder of an amount split between recipients goes, which is
+/// left over when the portion of each recipient is rounded down
+#[derive(Clone, Copy, Debug, Eq, PartialEq, IntoPrimitive, TryFromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u8)]
+pub enum RoundingPolicy {
+    /// The remainder is added to the portion of the first recipient, the value will be 0
+    FirstRecipient,
+    /// The remainder is paid to the treasury of the escrow the amount is split from
+    Treasury,
+    /// The remainder is burned
+    Burn,
+}
+
+#[allow(clippy::derivable_impls)]
+impl Default for RoundingPolicy {
+    fn default() -> Self {
+        RoundingPolicy::FirstRecipient
+    }
+}
+
+/// Amount split between recipients by rate
+///
+/// Every split of an amount between recipients is made with `Split::try_new`, so the portions
+/// always add up to the amount and the remainder of rounding them down goes where the rounding
+/// policy sends it, instead of wherever each computation happened to leave it. Portions too small
+/// to be worth a transfer are then swept to the treasury with `Split::try_sweep_dust`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Split<const N: usize> {
+    /// Portion of each recipient, in the order of their rates
+    pub portions: [u64; N],
+    /// Remainder paid to the treasury
+    pub treasury: u64,
+    /// Remainder burned
+    pub burn: u64,
+}
+
+impl<const N: usize> Split<N> {
+    /// Split an amount between recipients at rates that add up to 100%, rounding each portion down
+    /// and sending the remainder where the rounding policy does
+    pub fn try_new(
+        amount: u64,
+        rates: [Rate; N],
+        policy: RoundingPolicy,
+    ) -> Result<Self, ProgramError> {
+        let total_bps: u32 = rates.iter().map(|rate| u32::from(rate.bps())).sum();
+        if total_bps != u32::from(MAX_BPS) {
+            msg!("Rates of a split must add up to 100%");
+            return Err(RNDRError::MathError.into());
+        }
+
+        let mut portions = [0; N];
+        let mut remainder = amount;
+        for (portion, rate) in portions.iter_mut().zip(rates.iter()) {
+            *portion = rate.try_apply(amount)?;
+            remainder = remainder
+                .checked_sub(*portion)
+                .ok_or(RNDRError::MathError)?;
+        }
+
+        let mut split = Self {
+            portions,
+            treasury: 0,
+            burn: 0,
+        };
+        match policy {
+            RoundingPolicy::FirstRecipient => {
+                // The rates add up to 100%, so there's at least one recipient
+                split.portions[0] += remainder;
+            }
+            RoundingPolicy::Treasury => split.treasury = remainder,
+            RoundingPolicy::Burn => split.burn = remainder,
+        }
+        Ok(split)
+    }
+
+    /// Sweep the portions below a minimum disbursement to the treasury, since transferring dust
+    /// costs more in fees than it's worth, where a minimum of zero sweeps nothing
+    pub fn try_sweep_dust(mut self, min_disbursement: u64) -> Result<Self, ProgramError> {
+        for portion in self.portions.iter_mut() {
+            if *portion < min_disbursement {
+                self.treasury = self
+                    .treasury
+                    .checked_add(std::mem::take(portion))
+                    .ok_or(RNDRError::MathError)?;
+            }
+        }
+        Ok(self)
+    }
+}
@@ -0,0 +1,150 @@
+//! 256-bit unsigned integers backing fixed-point math
+
+use std::cmp::Ordering;
+
+/// Unsigned 256-bit integer of little-endian 64-bit limbs, wide enough to hold the product of two
+/// WAD-scaled `u64` amounts before it's scaled back down
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub(crate) struct U256([u64; 4]);
+
+impl U256 {
+    /// Zero
+    pub const ZERO: Self = Self([0; 4]);
+
+    /// Integer of a `u128`
+    pub const fn from_u128(value: u128) -> Self {
+        Self([value as u64, (value >> 64) as u64, 0, 0])
+    }
+
+    /// Integer as a `u128`, or `None` if it's too large
+    pub fn to_u128(self) -> Option<u128> {
+        if self.0[2] != 0 || self.0[3] != 0 {
+            return None;
+        }
+        Some(u128::from(self.0[1]) << 64 | u128::from(self.0[0]))
+    }
+
+    /// Whether the integer is zero
+    pub fn is_zero(self) -> bool {
+        self == Self::ZERO
+    }
+
+    /// Sum of two integers, or `None` if it overflows
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        let mut sum = Self::ZERO;
+        let mut carry = false;
+        for i in 0..4 {
+            let (limb, carry_a) = self.0[i].overflowing_add(other.0[i]);
+            let (limb, carry_b) = limb.overflowing_add(carry as u64);
+            sum.0[i] = limb;
+            carry = carry_a || carry_b;
+        }
+        if carry {
+            None
+        } else {
+            Some(sum)
+        }
+    }
+
+    /// Difference of two integers, or `None` if it's negative
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        let (difference, borrow) = self.overflowing_sub(other);
+        if borrow {
+            None
+        } else {
+            Some(difference)
+        }
+    }
+
+    /// Product of two integers, or `None` if it overflows
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        let mut product = [0u64; 8];
+        for i in 0..4 {
+            let mut carry = 0u128;
+            for j in 0..4 {
+                let limb = u128::from(self.0[i]) * u128::from(other.0[j])
+                    + u128::from(product[i + j])
+                    + carry;
+                product[i + j] = limb as u64;
+                carry = limb >> 64;
+            }
+            product[i + 4] = carry as u64;
+        }
+        if product[4..].iter().any(|limb| *limb != 0) {
+            return None;
+        }
+        Some(Self([product[0], product[1], product[2], product[3]]))
+    }
+
+    /// Quotient, rounded down, and remainder of dividing by an integer, or `None` if it's zero
+    pub fn checked_div_rem(self, divisor: Self) -> Option<(Self, Self)> {
+        if divisor.is_zero() {
+            return None;
+        }
+        if let (Some(dividend), Some(divisor)) = (self.to_u128(), divisor.to_u128()) {
+            return Some((
+                Self::from_u128(dividend / divisor),
+                Self::from_u128(dividend % divisor),
+            ));
+        }
+
+        // Long division one bit at a time, from the dividend's highest set bit
+        let mut quotient = Self::ZERO;
+        let mut remainder = Self::ZERO;
+        for bit in (0..self.bits()).rev() {
+            let carry = remainder.0[3] >> 63 == 1;
+            remainder = remainder.shl1();
+            remainder.0[0] |= (self.0[bit / 64] >> (bit % 64)) & 1;
+            // A carry out of the top bit means the remainder exceeds the divisor, and the wrapped
+            // difference is then the correct remainder
+            if carry || remainder >= divisor {
+                remainder = remainder.overflowing_sub(divisor).0;
+                quotient.0[bit / 64] |= 1 << (bit % 64);
+            }
+        }
+        Some((quotient, remainder))
+    }
+
+    /// Number of bits up to the highest set bit
+    fn bits(self) -> usize {
+        (0..4)
+            .rev()
+            .find(|i| self.0[*i] != 0)
+            .map_or(0, |i| i * 64 + 64 - self.0[i].leading_zeros() as usize)
+    }
+
+    /// Integer shifted left by one bit, dropping the top bit
+    fn shl1(self) -> Self {
+        Self([
+            self.0[0] << 1,
+            self.0[1] << 1 | self.0[0] >> 63,
+            self.0[2] << 1 | self.0[1] >> 63,
+            self.0[3] << 1 | self.0[2] >> 63,
+        ])
+    }
+
+    /// Wrapping difference of two integers and whether it borrowed
+    fn overflowing_sub(self, other: Self) -> (Self, bool) {
+        let mut difference = Self::ZERO;
+        let mut borrow = false;
+        for i in 0..4 {
+            let (limb, borrow_a) = self.0[i].overflowing_sub(other.0[i]);
+            let (limb, borrow_b) = limb.overflowing_sub(borrow as u64);
+            difference.0[i] = limb;
+            borrow = borrow_a || borrow_b;
+        }
+        (difference, borrow)
+    }
+}
+
+impl Ord for U256 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.iter().rev().cmp(other.0.iter().rev())
+    }
+}
+
+impl PartialOrd for U256 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
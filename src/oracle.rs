@@ -0,0 +1,156 @@
+//! Prices read from oracle price accounts
+
+use {
+    crate::{
+        error::RNDRError,
+        math::{Rate, MAX_BPS},
+    },
+    arrayref::array_ref,
+    solana_program::{clock::Slot, entrypoint::ProgramResult, msg, program_error::ProgramError},
+    std::convert::TryFrom,
+};
+
+/// Decimals of values priced with an oracle price, so a value of `1_000_000` is one unit of the
+/// quote currency of the price, such as one US dollar
+pub const PRICED_VALUE_DECIMALS: u8 = 6;
+
+/// Aggregate price of a token published by an oracle, in units of its quote currency
+///
+/// Price accounts are read in the layout of Pyth price accounts, whose aggregate price is
+/// `price * 10^exponent` units of the quote currency for one whole token, give or take the
+/// confidence, as of the publish slot. Only prices of a price account that's trading are read, so
+/// a halted or unknown price can't fund a Job, and prices are checked against the oracle price
+/// guards of the config with `OraclePrice::check` before they're consumed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OraclePrice {
+    /// Price of one whole token, scaled by `10^exponent`, which isn't zero
+    pub price: u64,
+    /// Confidence interval around the price, in the same units
+    pub confidence: u64,
+    /// Exponent of the price and confidence
+    pub exponent: i32,
+    /// Slot the price was published at
+    pub publish_slot: Slot,
+}
+
+impl OraclePrice {
+    /// Magic number of a price account
+    pub const MAGIC: u32 = 0xa1b2_c3d4;
+    /// Version of the layout of a price account
+    pub const VERSION: u32 = 2;
+    /// Account type of a price account
+    pub const PRICE_ACCOUNT_TYPE: u32 = 3;
+    /// Status of the aggregate price of a price account that's trading
+    pub const TRADING_STATUS: u32 = 1;
+
+    /// Offset of the magic number in a price account
+    pub const MAGIC_OFFSET: usize = 0;
+    /// Offset of the version in a price account
+    pub const VERSION_OFFSET: usize = 4;
+    /// Offset of the account type in a price account
+    pub const ACCOUNT_TYPE_OFFSET: usize = 8;
+    /// Offset of the exponent in a price account
+    pub const EXPONENT_OFFSET: usize = 20;
+    /// Offset of the aggregate price in a price account
+    pub const PRICE_OFFSET: usize = 208;
+    /// Offset of the confidence of the aggregate price in a price account
+    pub const CONFIDENCE_OFFSET: usize = 216;
+    /// Offset of the status of the aggregate price in a price account
+    pub const STATUS_OFFSET: usize = 224;
+    /// Offset of the publish slot of the aggregate price in a price account
+    pub const PUBLISH_SLOT_OFFSET: usize = 232;
+    /// Minimum length of a price account holding the aggregate price
+    pub const MIN_LEN: usize = Self::PUBLISH_SLOT_OFFSET + 8;
+
+    /// Unpack the aggregate price of a price account, which fails if the account isn't a price
+    /// account, or its price isn't trading or isn't positive
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        if input.len() < Self::MIN_LEN {
+            msg!("Price account is too short");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        let read_u32 = |offset| u32::from_le_bytes(*array_ref![input, offset, 4]);
+        let read_u64 = |offset| u64::from_le_bytes(*array_ref![input, offset, 8]);
+
+        if read_u32(Self::MAGIC_OFFSET) != Self::MAGIC
+            || read_u32(Self::VERSION_OFFSET) != Self::VERSION
+            || read_u32(Self::ACCOUNT_TYPE_OFFSET) != Self::PRICE_ACCOUNT_TYPE
+        {
+            msg!("Price account is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if read_u32(Self::STATUS_OFFSET) != Self::TRADING_STATUS {
+            msg!("Price of the price account isn't trading");
+            return Err(RNDRError::UnspecifiedError.into());
+        }
+        let price = i64::from_le_bytes(*array_ref![input, Self::PRICE_OFFSET, 8]);
+        if price <= 0 {
+            msg!("Price of the price account isn't positive");
+            return Err(RNDRError::UnspecifiedError.into());
+        }
+
+        Ok(Self {
+            price: price as u64,
+            confidence: read_u64(Self::CONFIDENCE_OFFSET),
+            exponent: i32::from_le_bytes(*array_ref![input, Self::EXPONENT_OFFSET, 4]),
+            publish_slot: read_u64(Self::PUBLISH_SLOT_OFFSET),
+        })
+    }
+
+    /// Check that the price is fresh and reliable enough to be consumed at a slot: published at
+    /// most `max_age` slots before it, and with a confidence interval of at most
+    /// `max_confidence_bps` of the price
+    pub fn check(&self, slot: Slot, max_age: u64, max_confidence_bps: Rate) -> ProgramResult {
+        let age = slot.saturating_sub(self.publish_slot);
+        if age > max_age {
+            msg!(
+                "Oracle price is {} slots old, more than the maximum of {}",
+                age,
+                max_age
+            );
+            return Err(RNDRError::StaleOraclePrice.into());
+        }
+        // The product of a u64 and a u16 can't overflow a u128
+        if u128::from(self.confidence) * u128::from(MAX_BPS)
+            > u128::from(self.price) * u128::from(max_confidence_bps.bps())
+        {
+            msg!(
+                "Oracle price confidence {} is more than {} basis points of the price {}",
+                self.confidence,
+                max_confidence_bps.bps(),
+                self.price
+            );
+            return Err(RNDRError::UnreliableOraclePrice.into());
+        }
+        Ok(())
+    }
+
+    /// Amount of base units of a token with decimals worth a value in the quote currency, with
+    /// `PRICED_VALUE_DECIMALS` decimals, rounded up so the amount always covers the value
+    pub fn try_token_amount(&self, value: u64, token_decimals: u8) -> Result<u64, ProgramError> {
+        // amount = value * 10^token_decimals / (price * 10^exponent * 10^PRICED_VALUE_DECIMALS)
+        let exponent = i32::from(token_decimals)
+            .checked_sub(self.exponent)
+            .and_then(|exponent| exponent.checked_sub(i32::from(PRICED_VALUE_DECIMALS)))
+            .ok_or(RNDRError::MathError)?;
+        let scale = 10u128
+            .checked_pow(exponent.unsigned_abs())
+            .ok_or(RNDRError::MathError)?;
+        let (numerator, denominator) = if exponent >= 0 {
+            (
+                u128::from(value).checked_mul(scale),
+                Some(u128::from(self.price)),
+            )
+        } else {
+            (
+                Some(u128::from(value)),
+                u128::from(self.price).checked_mul(scale),
+            )
+        };
+        let amount = numerator
+            .zip(denominator)
+            .map(|(numerator, denominator)| numerator.div_ceil(denominator))
+            .ok_or(RNDRError::MathError)?;
+        u64::try_from(amount).map_err(|_| RNDRError::MathError.into())
+    }
+}
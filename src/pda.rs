@@ -0,0 +1,365 @@
+//! Program derived address helpers
+
+use solana_program::{bpf_loader_upgradeable, pubkey::Pubkey};
+
+/// Seeds of an escrow: its token mint and the identifier of its namespace, zero for the default
+/// escrow of the token mint
+///
+/// Instruction builders taking a token mint accept escrow seeds in its place, so they build
+/// instructions for the escrow of a namespace.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct EscrowSeeds {
+    /// Token mint of the escrow
+    pub token_mint: Pubkey,
+    /// Identifier of the escrow's namespace
+    pub escrow_id: u64,
+}
+
+impl From<Pubkey> for EscrowSeeds {
+    fn from(token_mint: Pubkey) -> Self {
+        Self {
+            token_mint,
+            escrow_id: 0,
+        }
+    }
+}
+
+/// Seed of an escrow identifier, which is empty for the default escrow of a token mint so its
+/// address is the one it had before escrows were namespaced
+pub fn escrow_id_seed(escrow_id: u64) -> Vec<u8> {
+    if escrow_id == 0 {
+        vec![]
+    } else {
+        escrow_id.to_le_bytes().to_vec()
+    }
+}
+
+/// Find the default escrow PDA and bump seed for a token mint
+pub fn find_escrow_address(program_id: &Pubkey, token_mint: &Pubkey) -> (Pubkey, u8) {
+    find_escrow_address_with_id(program_id, token_mint, 0)
+}
+
+/// Find the escrow PDA and bump seed for a token mint and escrow identifier
+pub fn find_escrow_address_with_id(
+    program_id: &Pubkey,
+    token_mint: &Pubkey,
+    escrow_id: u64,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"escrow",
+            token_mint.as_ref(),
+            spl_token::id().as_ref(),
+            &escrow_id_seed(escrow_id),
+        ],
+        program_id,
+    )
+}
+
+/// Seed of a job identifier, which is empty for identifier zero so the jobs created before jobs
+/// had identifiers keep their addresses
+pub fn job_id_seed(job_id: u64) -> Vec<u8> {
+    if job_id == 0 {
+        vec![]
+    } else {
+        job_id.to_le_bytes().to_vec()
+    }
+}
+
+/// Find the job PDA and bump seed for an escrow, authority and job identifier
+pub fn find_job_address(
+    program_id: &Pubkey,
+    escrow: &Pubkey,
+    authority: &Pubkey,
+    job_id: u64,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"job",
+            escrow.as_ref(),
+            authority.as_ref(),
+            &job_id_seed(job_id),
+        ],
+        program_id,
+    )
+}
+
+/// Find the escrow shard PDA and bump seed for an escrow and shard index
+pub fn find_escrow_shard_address(program_id: &Pubkey, escrow: &Pubkey, index: u8) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"escrow_shard", escrow.as_ref(), &[index]], program_id)
+}
+
+/// Find the verifier registry PDA and bump seed for an escrow
+pub fn find_verifier_registry_address(program_id: &Pubkey, escrow: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"verifier_registry", escrow.as_ref()], program_id)
+}
+
+/// Find the verifier selection PDA and bump seed for a job
+pub fn find_verifier_selection_address(program_id: &Pubkey, job: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"verifier_selection", job.as_ref()], program_id)
+}
+
+/// Find the dispute PDA and bump seed for a job
+pub fn find_dispute_address(program_id: &Pubkey, job: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"dispute", job.as_ref()], program_id)
+}
+
+/// Find the disbursement proposal PDA and bump seed for an escrow and proposal identifier
+pub fn find_proposal_address(
+    program_id: &Pubkey,
+    escrow: &Pubkey,
+    proposal_id: u64,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"proposal", escrow.as_ref(), &proposal_id.to_le_bytes()],
+        program_id,
+    )
+}
+
+/// Find the queued action PDA and bump seed for a target escrow or config and action identifier
+pub fn find_queued_action_address(
+    program_id: &Pubkey,
+    target: &Pubkey,
+    action_id: u64,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"queued_action", target.as_ref(), &action_id.to_le_bytes()],
+        program_id,
+    )
+}
+
+/// Find the receipt authority PDA and bump seed, the delegate of the Bubblegum trees job
+/// receipts are minted to
+pub fn find_receipt_authority_address(program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"receipt_authority"], program_id)
+}
+
+/// Find the recovery PDA and bump seed for an escrow
+pub fn find_recovery_address(program_id: &Pubkey, escrow: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"recovery", escrow.as_ref()], program_id)
+}
+
+/// Find the reward pool PDA and bump seed for an escrow and epoch
+pub fn find_reward_pool_address(program_id: &Pubkey, escrow: &Pubkey, epoch: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"reward_pool", escrow.as_ref(), &epoch.to_le_bytes()],
+        program_id,
+    )
+}
+
+/// Find the epoch work PDA and bump seed for a reward pool and node
+pub fn find_epoch_work_address(
+    program_id: &Pubkey,
+    reward_pool: &Pubkey,
+    node: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"epoch_work", reward_pool.as_ref(), node.as_ref()],
+        program_id,
+    )
+}
+
+/// Find the Merkle distribution PDA and bump seed for an escrow and epoch
+pub fn find_merkle_distribution_address(
+    program_id: &Pubkey,
+    escrow: &Pubkey,
+    epoch: u64,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"merkle_distribution",
+            escrow.as_ref(),
+            &epoch.to_le_bytes(),
+        ],
+        program_id,
+    )
+}
+
+/// Find the claim bitmap PDA and bump seed for a Merkle distribution and chunk index
+pub fn find_claim_bitmap_address(
+    program_id: &Pubkey,
+    merkle_distribution: &Pubkey,
+    chunk: u32,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"claim_bitmap",
+            merkle_distribution.as_ref(),
+            &chunk.to_le_bytes(),
+        ],
+        program_id,
+    )
+}
+
+/// Find the emission schedule PDA and bump seed for an escrow
+pub fn find_emission_schedule_address(program_id: &Pubkey, escrow: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"emission_schedule", escrow.as_ref()], program_id)
+}
+
+/// Find the node PDA and bump seed for an escrow and node authority
+pub fn find_node_address(program_id: &Pubkey, escrow: &Pubkey, authority: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"node", escrow.as_ref(), authority.as_ref()], program_id)
+}
+
+/// Find the stake pool PDA and bump seed for an escrow
+pub fn find_stake_pool_address(program_id: &Pubkey, escrow: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"stake_pool", escrow.as_ref()], program_id)
+}
+
+/// Find the stake receipt mint PDA and bump seed for a stake pool
+pub fn find_stake_receipt_mint_address(program_id: &Pubkey, stake_pool: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"stake_receipt_mint", stake_pool.as_ref()], program_id)
+}
+
+/// Find the crank vault PDA and bump seed for an escrow
+pub fn find_crank_vault_address(program_id: &Pubkey, escrow: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"crank_vault", escrow.as_ref()], program_id)
+}
+
+/// Find the treasury PDA and bump seed for an escrow
+pub fn find_treasury_address(program_id: &Pubkey, escrow: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"treasury", escrow.as_ref()], program_id)
+}
+
+/// Find the stats PDA and bump seed for an escrow
+pub fn find_stats_address(program_id: &Pubkey, escrow: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"stats", escrow.as_ref()], program_id)
+}
+
+/// Find the voucher nonce PDA and bump seed for an escrow and job authority
+pub fn find_voucher_nonce_address(
+    program_id: &Pubkey,
+    escrow: &Pubkey,
+    authority: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"voucher_nonce", escrow.as_ref(), authority.as_ref()],
+        program_id,
+    )
+}
+
+/// Find the session key PDA and bump seed for an escrow, job authority and session key
+pub fn find_session_key_address(
+    program_id: &Pubkey,
+    escrow: &Pubkey,
+    authority: &Pubkey,
+    session_key: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"session_key",
+            escrow.as_ref(),
+            authority.as_ref(),
+            session_key.as_ref(),
+        ],
+        program_id,
+    )
+}
+
+/// Find the delegate allowance PDA and bump seed for an escrow and delegate
+pub fn find_delegate_allowance_address(
+    program_id: &Pubkey,
+    escrow: &Pubkey,
+    delegate: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"delegate_allowance", escrow.as_ref(), delegate.as_ref()],
+        program_id,
+    )
+}
+
+/// Find the subscription PDA and bump seed for an escrow, job authority and job identifier
+pub fn find_subscription_address(
+    program_id: &Pubkey,
+    escrow: &Pubkey,
+    authority: &Pubkey,
+    job_id: u64,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"subscription",
+            escrow.as_ref(),
+            authority.as_ref(),
+            &job_id.to_le_bytes(),
+        ],
+        program_id,
+    )
+}
+
+/// Find the job bundle PDA and bump seed for an escrow, authority and bundle identifier
+pub fn find_job_bundle_address(
+    program_id: &Pubkey,
+    escrow: &Pubkey,
+    authority: &Pubkey,
+    bundle_id: u64,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"job_bundle",
+            escrow.as_ref(),
+            authority.as_ref(),
+            &bundle_id.to_le_bytes(),
+        ],
+        program_id,
+    )
+}
+
+/// Find the job tree PDA and bump seed for an escrow and job authority
+pub fn find_job_tree_address(
+    program_id: &Pubkey,
+    escrow: &Pubkey,
+    authority: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"job_tree", escrow.as_ref(), authority.as_ref()],
+        program_id,
+    )
+}
+
+/// Find the job index PDA and bump seed for an escrow and job authority
+pub fn find_job_index_address(
+    program_id: &Pubkey,
+    escrow: &Pubkey,
+    authority: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"job_index", escrow.as_ref(), authority.as_ref()],
+        program_id,
+    )
+}
+
+/// Find the job index page PDA and bump seed for a job index and page number
+pub fn find_job_index_page_address(
+    program_id: &Pubkey,
+    job_index: &Pubkey,
+    page: u32,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"job_index_page", job_index.as_ref(), &page.to_le_bytes()],
+        program_id,
+    )
+}
+
+/// Find the job queue PDA and bump seed for an escrow
+pub fn find_job_queue_address(program_id: &Pubkey, escrow: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"job_queue", escrow.as_ref()], program_id)
+}
+
+/// Find the mint migration PDA and bump seed for an escrow and migration number
+pub fn find_mint_migration_address(
+    program_id: &Pubkey,
+    escrow: &Pubkey,
+    index: u8,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"mint_migration", escrow.as_ref(), &[index]], program_id)
+}
+
+/// Find the config PDA and bump seed
+pub fn find_config_address(program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"config"], program_id)
+}
+
+/// Find the program data address of a program deployed with the upgradeable BPF loader
+pub fn find_program_data_address(program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[program_id.as_ref()], &bpf_loader_upgradeable::id())
+}
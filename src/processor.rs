@@ -2,25 +2,86 @@
 
 use {
     crate::{
+        account_compression::{self, ACCOUNT_COMPRESSION_PROGRAM_ID, NOOP_PROGRAM_ID},
+        bubblegum::{self, ReceiptMetadata},
         error::RNDRError,
-        instruction::RNDRInstruction,
-        state::{Escrow, InitEscrowParams, InitJobParams, Job},
+        events::{
+            ActionCancelled, ActionQueued, AutoCompoundSet, ColdWalletRegistered,
+            CompressedJobAppended, CompressedJobCancelled, CompressedJobPaid,
+            CompressedJobReplaced, ConfigChanged, CrankBountyPaid, CrankBountySet,
+            DelegateApproved, DelegateRevoked, EmissionScheduleSet, EpochRewardClaimed,
+            EpochWorkRecorded, EscrowDiscrepancyFound, EscrowOwnerRecovered, EscrowTokenAccountSet,
+            IncidentModeSet, JobAuthorityTransferStarted, JobAuthorityTransferred,
+            JobBundleAggregated, JobBundleCancelled, JobBundleCompleted, JobBundleCreated,
+            JobBundleFunded, JobCancelled, JobExchangeRateSet, JobFrozen, JobFundedAtOraclePrice,
+            JobMetadataUpdated, JobPriorityRaised, JobSettledAtExchangeRate, JobTreeCreated,
+            JobUnfrozen, JobsMigrated, LiquidStaked, LiquidUnstaked, MerkleRewardClaimed,
+            MerkleRootPublished, MintMigrationBegun, MintMigrationTokensConverted, OwnerRotated,
+            OwnerRotationScheduled, RNDREvent, RecoveryCouncilSet, RecoveryStarted,
+            RewardMultiplierApplied, RewardPoolFunded, RewardsCompounded, RewardsEmitted,
+            SessionKeyRegistered, StakeRewardsDeposited, Staked, SubscriptionCreated,
+            SubscriptionProcessed, TreasuryBurnCapSet, TreasuryBurned, TreasurySweepCancelled,
+            TreasurySweepScheduled, TreasurySwept, Unstaked, VoucherUsed, WorkCredited,
+        },
+        instruction::{
+            verify_proof, RNDRInstruction, ED25519_MESSAGE_OFFSET, ED25519_PUBLIC_KEY_OFFSET,
+            ED25519_SIGNATURE_OFFSET, MAX_DISBURSE_BATCH_LEN, MAX_FUND_JOBS_LEN,
+        },
+        invariants,
+        math::{ExchangeRate, Rate, Split, RNDR_DECIMALS},
+        oracle::OraclePrice,
+        pda::{
+            escrow_id_seed, find_escrow_address_with_id, find_escrow_shard_address, job_id_seed,
+        },
+        state::{
+            AccountType, AdminAction, ClaimBitmap, CompressedJob, Config, ConfigChange, CrankVault,
+            DelegateAllowance, Dispute, EmissionSchedule, EpochWork, Escrow, EscrowShard,
+            IncidentMode, InitClaimBitmapParams, InitConfigParams, InitCrankVaultParams,
+            InitDelegateAllowanceParams, InitDisputeParams, InitEmissionScheduleParams,
+            InitEpochWorkParams, InitEscrowParams, InitEscrowShardParams, InitJobBundleParams,
+            InitJobIndexPageParams, InitJobIndexParams, InitJobParams, InitJobQueueParams,
+            InitJobTreeParams, InitMerkleDistributionParams, InitMintMigrationParams,
+            InitNodeParams, InitProposalParams, InitQueuedActionParams, InitRecoveryParams,
+            InitRewardPoolParams, InitSessionKeyParams, InitStakePoolParams, InitStatsParams,
+            InitSubscriptionParams, InitTreasuryParams, InitVerifierRegistryParams,
+            InitVerifierSelectionParams, InitVoucherNonceParams, Job, JobBundle, JobIndex,
+            JobIndexPage, JobMetadata, JobQueue, JobStatus, JobTree, MerkleDistribution,
+            MintMigration, Node, Proposal, QueuedAction, Recovery, RewardPool, SessionKey,
+            StakePool, Stats, Subscription, TokenFlow, Treasury, VerifierRegistry,
+            VerifierSelection, Voucher, VoucherNonce, ACCOUNT_TYPE_OFFSET,
+            BASE_REWARD_MULTIPLIER_BPS, ESCROW_SHARD_COUNT, MAX_BUNDLE_JOBS, MAX_JOB_PRIORITY,
+            MAX_JOB_TREE_DEPTH, MAX_SELECTED_VERIFIERS, MAX_VERIFIERS, RECOVERY_DELAY,
+            SESSION_KEY_CHALLENGE_RESULT, SESSION_KEY_FUND_JOB, SESSION_KEY_INSTRUCTIONS,
+        },
+        summary::{EscrowSummary, JobSummary},
+        version::VERSION,
     },
+    arrayref::array_ref,
     solana_program::{
         account_info::{next_account_info, AccountInfo},
+        bpf_loader_upgradeable,
+        clock::{Clock, Epoch, Slot, UnixTimestamp},
+        ed25519_program,
         entrypoint::ProgramResult,
+        hash::{Hash, HASH_BYTES},
         instruction::{AccountMeta, Instruction},
         msg,
-        program::{invoke, invoke_signed},
+        program::{invoke, invoke_signed, set_return_data},
         program_error::ProgramError,
         program_pack::Pack,
         pubkey::Pubkey,
-        system_instruction,
-        sysvar::{rent::Rent, Sysvar},
+        system_instruction, system_program,
+        sysvar::{self, rent::Rent, Sysvar},
     },
     spl_associated_token_account::get_associated_token_address,
+    std::convert::TryFrom,
 };
 
+/// Length of the metadata at the start of a program data account, see `program_upgrade_authority`
+const PROGRAM_DATA_METADATA_LEN: usize = 45; // 4 + 8 + 1 + 32
+/// Number of accounts appended to `FundJob` to mint a receipt, see `split_job_receipt_accounts`
+const JOB_RECEIPT_ACCOUNTS_LEN: usize = 6;
+
 /// Processes an instruction
 pub fn process_instruction(
     program_id: &Pubkey,
@@ -31,20 +92,481 @@ pub fn process_instruction(
     match instruction {
         RNDRInstruction::InitEscrow { owner } => {
             msg!("Instruction: InitEscrow");
-            process_init_escrow(program_id, owner, accounts)
+            process_init_escrow(program_id, owner, 0, accounts)
         }
         RNDRInstruction::SetEscrowOwner { new_owner } => {
             msg!("Instruction: SetEscrowOwner");
             process_set_escrow_owner(program_id, new_owner, accounts)
         }
-        RNDRInstruction::FundJob { amount } => {
+        RNDRInstruction::FundJob {
+            amount,
+            job_id,
+            metadata,
+        } => {
             msg!("Instruction: FundJob");
-            process_fund_job(program_id, amount, accounts)
+            process_fund_job(program_id, amount, job_id, metadata, None, accounts)
         }
         RNDRInstruction::DisburseFunds { amount } => {
             msg!("Instruction: DisburseFunds");
             process_disburse_funds(program_id, amount, accounts)
         }
+        RNDRInstruction::AggregateEscrowShard => {
+            msg!("Instruction: AggregateEscrowShard");
+            process_aggregate_escrow_shard(program_id, accounts)
+        }
+        RNDRInstruction::FundIsolatedJob { amount, job_id } => {
+            msg!("Instruction: FundIsolatedJob");
+            process_fund_isolated_job(program_id, amount, job_id, accounts)
+        }
+        RNDRInstruction::DisburseIsolatedJobFunds { amount } => {
+            msg!("Instruction: DisburseIsolatedJobFunds");
+            process_disburse_isolated_job_funds(program_id, amount, accounts)
+        }
+        RNDRInstruction::DisburseBatch { amounts } => {
+            msg!("Instruction: DisburseBatch");
+            process_disburse_batch(program_id, &amounts, accounts)
+        }
+        RNDRInstruction::FundJobs { jobs } => {
+            msg!("Instruction: FundJobs");
+            process_fund_jobs(program_id, &jobs, accounts)
+        }
+        RNDRInstruction::CommitResult { commitment } => {
+            msg!("Instruction: CommitResult");
+            process_commit_result(program_id, commitment, accounts)
+        }
+        RNDRInstruction::RevealResult {
+            result_hash,
+            salt,
+            proof,
+        } => {
+            msg!("Instruction: RevealResult");
+            process_reveal_result(program_id, result_hash, salt, proof, accounts)
+        }
+        RNDRInstruction::ClaimPayment => {
+            msg!("Instruction: ClaimPayment");
+            process_claim_payment(program_id, accounts)
+        }
+        RNDRInstruction::SetChallengeWindow { challenge_window } => {
+            msg!("Instruction: SetChallengeWindow");
+            process_set_challenge_window(program_id, challenge_window, accounts)
+        }
+        RNDRInstruction::ChallengeResult => {
+            msg!("Instruction: ChallengeResult");
+            process_challenge_result(program_id, accounts)
+        }
+        RNDRInstruction::AddVerifier { verifier } => {
+            msg!("Instruction: AddVerifier");
+            process_add_verifier(program_id, verifier, accounts)
+        }
+        RNDRInstruction::RemoveVerifier { verifier } => {
+            msg!("Instruction: RemoveVerifier");
+            process_remove_verifier(program_id, verifier, accounts)
+        }
+        RNDRInstruction::FlagJob { evidence_hash } => {
+            msg!("Instruction: FlagJob");
+            process_flag_job(program_id, evidence_hash, accounts)
+        }
+        RNDRInstruction::SelectVerifiers { count } => {
+            msg!("Instruction: SelectVerifiers");
+            process_select_verifiers(program_id, count, accounts)
+        }
+        RNDRInstruction::SetProofVerifier { proof_verifier } => {
+            msg!("Instruction: SetProofVerifier");
+            process_set_proof_verifier(program_id, proof_verifier, accounts)
+        }
+        RNDRInstruction::SetArbitrator { arbitrator } => {
+            msg!("Instruction: SetArbitrator");
+            process_set_arbitrator(program_id, arbitrator, accounts)
+        }
+        RNDRInstruction::OpenDispute => {
+            msg!("Instruction: OpenDispute");
+            process_open_dispute(program_id, accounts)
+        }
+        RNDRInstruction::SubmitEvidence { evidence_hash } => {
+            msg!("Instruction: SubmitEvidence");
+            process_submit_evidence(program_id, evidence_hash, accounts)
+        }
+        RNDRInstruction::ResolveDispute { node_amount } => {
+            msg!("Instruction: ResolveDispute");
+            process_resolve_dispute(program_id, node_amount, accounts)
+        }
+        RNDRInstruction::InitConfig { governance } => {
+            msg!("Instruction: InitConfig");
+            process_init_config(program_id, governance, accounts)
+        }
+        RNDRInstruction::SetConfig { change } => {
+            msg!("Instruction: SetConfig");
+            process_set_config(program_id, change, accounts)
+        }
+        RNDRInstruction::VerifyUpgradeAuthority => {
+            msg!("Instruction: VerifyUpgradeAuthority");
+            process_verify_upgrade_authority(program_id, accounts)
+        }
+        RNDRInstruction::SetApprover { approver } => {
+            msg!("Instruction: SetApprover");
+            process_set_approver(program_id, approver, accounts)
+        }
+        RNDRInstruction::ProposeDisbursement {
+            proposal_id,
+            amount,
+        } => {
+            msg!("Instruction: ProposeDisbursement");
+            process_propose_disbursement(program_id, proposal_id, amount, accounts)
+        }
+        RNDRInstruction::ApproveDisbursement => {
+            msg!("Instruction: ApproveDisbursement");
+            process_approve_disbursement(program_id, accounts)
+        }
+        RNDRInstruction::ExecuteDisbursement => {
+            msg!("Instruction: ExecuteDisbursement");
+            process_execute_disbursement(program_id, accounts)
+        }
+        RNDRInstruction::QueueAction { action_id, action } => {
+            msg!("Instruction: QueueAction");
+            process_queue_action(program_id, action_id, action, accounts)
+        }
+        RNDRInstruction::ExecuteQueuedAction => {
+            msg!("Instruction: ExecuteQueuedAction");
+            process_execute_queued_action(program_id, accounts)
+        }
+        RNDRInstruction::CancelQueuedAction => {
+            msg!("Instruction: CancelQueuedAction");
+            process_cancel_queued_action(program_id, accounts)
+        }
+        RNDRInstruction::SetRecoveryCouncil {
+            threshold,
+            guardians,
+        } => {
+            msg!("Instruction: SetRecoveryCouncil");
+            process_set_recovery_council(program_id, threshold, guardians, accounts)
+        }
+        RNDRInstruction::ApproveRecovery { new_owner } => {
+            msg!("Instruction: ApproveRecovery");
+            process_approve_recovery(program_id, new_owner, accounts)
+        }
+        RNDRInstruction::ExecuteRecovery => {
+            msg!("Instruction: ExecuteRecovery");
+            process_execute_recovery(program_id, accounts)
+        }
+        RNDRInstruction::ScheduleOwnerRotation {
+            rotation_id,
+            new_owner,
+        } => {
+            msg!("Instruction: ScheduleOwnerRotation");
+            process_schedule_owner_rotation(program_id, rotation_id, new_owner, accounts)
+        }
+        RNDRInstruction::FundRewardPool { epoch, amount } => {
+            msg!("Instruction: FundRewardPool");
+            process_fund_reward_pool(program_id, epoch, amount, accounts)
+        }
+        RNDRInstruction::ClaimEpochReward => {
+            msg!("Instruction: ClaimEpochReward");
+            process_claim_epoch_reward(program_id, accounts)
+        }
+        RNDRInstruction::PublishMerkleRoot {
+            epoch,
+            root,
+            node_count,
+            amount,
+        } => {
+            msg!("Instruction: PublishMerkleRoot");
+            process_publish_merkle_root(program_id, epoch, root, node_count, amount, accounts)
+        }
+        RNDRInstruction::ClaimMerkleReward {
+            index,
+            amount,
+            proof,
+        } => {
+            msg!("Instruction: ClaimMerkleReward");
+            process_claim_merkle_reward(program_id, index, amount, &proof, accounts)
+        }
+        RNDRInstruction::SetEmissionSchedule {
+            start_epoch,
+            initial_amount,
+            decay_bps,
+        } => {
+            msg!("Instruction: SetEmissionSchedule");
+            process_set_emission_schedule(
+                program_id,
+                start_epoch,
+                initial_amount,
+                decay_bps,
+                accounts,
+            )
+        }
+        RNDRInstruction::EmitRewards => {
+            msg!("Instruction: EmitRewards");
+            process_emit_rewards(program_id, accounts)
+        }
+        RNDRInstruction::Stake { amount } => {
+            msg!("Instruction: Stake");
+            process_stake(program_id, amount, accounts)
+        }
+        RNDRInstruction::Unstake { amount } => {
+            msg!("Instruction: Unstake");
+            process_unstake(program_id, amount, accounts)
+        }
+        RNDRInstruction::SetAutoCompound { auto_compound } => {
+            msg!("Instruction: SetAutoCompound");
+            process_set_auto_compound(program_id, auto_compound, accounts)
+        }
+        RNDRInstruction::CompoundRewards => {
+            msg!("Instruction: CompoundRewards");
+            process_compound_rewards(program_id, accounts)
+        }
+        RNDRInstruction::LiquidStake { amount } => {
+            msg!("Instruction: LiquidStake");
+            process_liquid_stake(program_id, amount, accounts)
+        }
+        RNDRInstruction::LiquidUnstake { receipt_amount } => {
+            msg!("Instruction: LiquidUnstake");
+            process_liquid_unstake(program_id, receipt_amount, accounts)
+        }
+        RNDRInstruction::DepositStakeRewards { amount } => {
+            msg!("Instruction: DepositStakeRewards");
+            process_deposit_stake_rewards(program_id, amount, accounts)
+        }
+        RNDRInstruction::SetCrankBounty { bounty } => {
+            msg!("Instruction: SetCrankBounty");
+            process_set_crank_bounty(program_id, bounty, accounts)
+        }
+        RNDRInstruction::SetTreasuryBurnCap { epoch_burn_cap } => {
+            msg!("Instruction: SetTreasuryBurnCap");
+            process_set_treasury_burn_cap(program_id, epoch_burn_cap, accounts)
+        }
+        RNDRInstruction::BurnTreasury { amount } => {
+            msg!("Instruction: BurnTreasury");
+            process_burn_treasury(program_id, amount, accounts)
+        }
+        RNDRInstruction::InitStats => {
+            msg!("Instruction: InitStats");
+            process_init_stats(program_id, accounts)
+        }
+        RNDRInstruction::FundJobWithVoucher {
+            amount,
+            job_id,
+            nonce,
+            expires_at,
+        } => {
+            msg!("Instruction: FundJobWithVoucher");
+            process_fund_job_with_voucher(program_id, amount, job_id, nonce, expires_at, accounts)
+        }
+        RNDRInstruction::RegisterSessionKey {
+            session_key,
+            expiry_slot,
+            instruction_mask,
+        } => {
+            msg!("Instruction: RegisterSessionKey");
+            process_register_session_key(
+                program_id,
+                session_key,
+                expiry_slot,
+                instruction_mask,
+                accounts,
+            )
+        }
+        RNDRInstruction::ApproveDelegate {
+            delegate,
+            limit,
+            expiry_slot,
+        } => {
+            msg!("Instruction: ApproveDelegate");
+            process_approve_delegate(program_id, delegate, limit, expiry_slot, accounts)
+        }
+        RNDRInstruction::RevokeDelegate => {
+            msg!("Instruction: RevokeDelegate");
+            process_revoke_delegate(program_id, accounts)
+        }
+        RNDRInstruction::CreateSubscription {
+            job_id,
+            amount,
+            period,
+        } => {
+            msg!("Instruction: CreateSubscription");
+            process_create_subscription(program_id, job_id, amount, period, accounts)
+        }
+        RNDRInstruction::ProcessSubscription => {
+            msg!("Instruction: ProcessSubscription");
+            process_process_subscription(program_id, accounts)
+        }
+        RNDRInstruction::CancelJob => {
+            msg!("Instruction: CancelJob");
+            process_cancel_job(program_id, accounts)
+        }
+        RNDRInstruction::TransferJobAuthority { new_authority } => {
+            msg!("Instruction: TransferJobAuthority");
+            process_transfer_job_authority(program_id, new_authority, accounts)
+        }
+        RNDRInstruction::AcceptJobAuthority => {
+            msg!("Instruction: AcceptJobAuthority");
+            process_accept_job_authority(program_id, accounts)
+        }
+        RNDRInstruction::CreateJobBundle {
+            bundle_id,
+            first_job_id,
+            job_count,
+            metadata_hash,
+        } => {
+            msg!("Instruction: CreateJobBundle");
+            process_create_job_bundle(
+                program_id,
+                bundle_id,
+                first_job_id,
+                job_count,
+                metadata_hash,
+                accounts,
+            )
+        }
+        RNDRInstruction::FundJobBundle {
+            first_job_id,
+            count,
+            amount_per_job,
+        } => {
+            msg!("Instruction: FundJobBundle");
+            process_fund_job_bundle(program_id, first_job_id, count, amount_per_job, accounts)
+        }
+        RNDRInstruction::CancelJobBundle => {
+            msg!("Instruction: CancelJobBundle");
+            process_cancel_job_bundle(program_id, accounts)
+        }
+        RNDRInstruction::AggregateJobBundle => {
+            msg!("Instruction: AggregateJobBundle");
+            process_aggregate_job_bundle(program_id, accounts)
+        }
+        RNDRInstruction::InitJobTree {
+            max_depth,
+            max_buffer_size,
+        } => {
+            msg!("Instruction: InitJobTree");
+            process_init_job_tree(program_id, max_depth, max_buffer_size, accounts)
+        }
+        RNDRInstruction::AppendCompressedJob { amount } => {
+            msg!("Instruction: AppendCompressedJob");
+            process_append_compressed_job(program_id, amount, accounts)
+        }
+        RNDRInstruction::ReplaceCompressedJob { job, node, root } => {
+            msg!("Instruction: ReplaceCompressedJob");
+            process_replace_compressed_job(program_id, job, node, root, accounts)
+        }
+        RNDRInstruction::SettleCompressedJob { job, root } => {
+            msg!("Instruction: SettleCompressedJob");
+            process_settle_compressed_job(program_id, job, root, accounts)
+        }
+        RNDRInstruction::InitJobIndex => {
+            msg!("Instruction: InitJobIndex");
+            process_init_job_index(program_id, accounts)
+        }
+        RNDRInstruction::UpdateJobMetadata { metadata } => {
+            msg!("Instruction: UpdateJobMetadata");
+            process_update_job_metadata(program_id, metadata, accounts)
+        }
+        RNDRInstruction::SetEscrowTokenAccount => {
+            msg!("Instruction: SetEscrowTokenAccount");
+            process_set_escrow_token_account(program_id, accounts)
+        }
+        RNDRInstruction::MigrateEscrowMint {
+            rate_numerator,
+            rate_denominator,
+        } => {
+            msg!("Instruction: MigrateEscrowMint");
+            process_migrate_escrow_mint(program_id, rate_numerator, rate_denominator, accounts)
+        }
+        RNDRInstruction::ConvertEscrowMintVault => {
+            msg!("Instruction: ConvertEscrowMintVault");
+            process_convert_escrow_mint_vault(program_id, accounts)
+        }
+        RNDRInstruction::RedenominateJobs => {
+            msg!("Instruction: RedenominateJobs");
+            process_redenominate_jobs(program_id, accounts)
+        }
+        RNDRInstruction::ConvertIsolatedJobMint => {
+            msg!("Instruction: ConvertIsolatedJobMint");
+            process_convert_isolated_job_mint(program_id, accounts)
+        }
+        RNDRInstruction::InitEscrowWithSeed { owner, escrow_id } => {
+            msg!("Instruction: InitEscrowWithSeed");
+            process_init_escrow(program_id, owner, escrow_id, accounts)
+        }
+        RNDRInstruction::RaiseJobPriority { priority } => {
+            msg!("Instruction: RaiseJobPriority");
+            process_raise_job_priority(program_id, priority, accounts)
+        }
+        RNDRInstruction::InitJobQueue => {
+            msg!("Instruction: InitJobQueue");
+            process_init_job_queue(program_id, accounts)
+        }
+        RNDRInstruction::ClaimJob { commitment } => {
+            msg!("Instruction: ClaimJob");
+            process_claim_job(program_id, commitment, accounts)
+        }
+        RNDRInstruction::SetJobExchangeRate => {
+            msg!("Instruction: SetJobExchangeRate");
+            process_set_job_exchange_rate(program_id, accounts)
+        }
+        RNDRInstruction::EstimateJobCost { tier, milli_obh } => {
+            msg!("Instruction: EstimateJobCost");
+            process_estimate_job_cost(program_id, tier, milli_obh, accounts)
+        }
+        RNDRInstruction::SetPriceFeed { price_feed } => {
+            msg!("Instruction: SetPriceFeed");
+            process_set_price_feed(program_id, price_feed, accounts)
+        }
+        RNDRInstruction::FundJobAtOraclePrice {
+            value,
+            max_rndr_amount,
+            job_id,
+        } => {
+            msg!("Instruction: FundJobAtOraclePrice");
+            process_fund_job_at_oracle_price(program_id, value, max_rndr_amount, job_id, accounts)
+        }
+        RNDRInstruction::FreezeJob => {
+            msg!("Instruction: FreezeJob");
+            process_freeze_job(program_id, accounts)
+        }
+        RNDRInstruction::UnfreezeJob => {
+            msg!("Instruction: UnfreezeJob");
+            process_unfreeze_job(program_id, accounts)
+        }
+        RNDRInstruction::SyncIncidentMode => {
+            msg!("Instruction: SyncIncidentMode");
+            process_sync_incident_mode(program_id, accounts)
+        }
+        RNDRInstruction::RegisterColdWallet => {
+            msg!("Instruction: RegisterColdWallet");
+            process_register_cold_wallet(program_id, accounts)
+        }
+        RNDRInstruction::ScheduleTreasurySweep { amount } => {
+            msg!("Instruction: ScheduleTreasurySweep");
+            process_schedule_treasury_sweep(program_id, amount, accounts)
+        }
+        RNDRInstruction::SweepToColdWallet => {
+            msg!("Instruction: SweepToColdWallet");
+            process_sweep_to_cold_wallet(program_id, accounts)
+        }
+        RNDRInstruction::CancelTreasurySweep => {
+            msg!("Instruction: CancelTreasurySweep");
+            process_cancel_treasury_sweep(program_id, accounts)
+        }
+        RNDRInstruction::GetVersion => {
+            msg!("Instruction: GetVersion");
+            process_get_version()
+        }
+        RNDRInstruction::GetEscrowSummary => {
+            msg!("Instruction: GetEscrowSummary");
+            process_get_escrow_summary(program_id, accounts)
+        }
+        RNDRInstruction::GetJobSummary => {
+            msg!("Instruction: GetJobSummary");
+            process_get_job_summary(program_id, accounts)
+        }
+        RNDRInstruction::GetEscrowedAmount => {
+            msg!("Instruction: GetEscrowedAmount");
+            process_get_escrowed_amount(program_id, accounts)
+        }
+        RNDRInstruction::ReconcileEscrow => {
+            msg!("Instruction: ReconcileEscrow");
+            process_reconcile_escrow(program_id, accounts)
+        }
     }
 }
 
@@ -52,6 +574,7 @@ pub fn process_instruction(
 fn process_init_escrow(
     program_id: &Pubkey,
     owner: Pubkey,
+    escrow_id: u64,
     accounts: &[AccountInfo],
 ) -> ProgramResult {
     let account_info_iter = &mut accounts.iter();
@@ -63,26 +586,34 @@ fn process_init_escrow(
     let escrow_info = next_account_info(account_info_iter)?;
     let escrow_associated_token_info = next_account_info(account_info_iter)?;
     // Sysvars
-    let rent_info = next_account_info(account_info_iter)?;
+    skip_legacy_rent_sysvar(account_info_iter);
     // Programs
     let system_program_info = next_account_info(account_info_iter)?;
     let token_program_info = next_account_info(account_info_iter)?;
     let associated_token_program_info = next_account_info(account_info_iter)?;
 
-    let mut escrow_seeds: Vec<&[_]> = vec![
-        b"escrow",
-        token_mint_info.key.as_ref(),
-        token_program_info.key.as_ref(),
-    ];
-
-    let (escrow_address, bump_seed) = Pubkey::find_program_address(&escrow_seeds, program_id);
+    let escrow_id_seed = escrow_id_seed(escrow_id);
+    let (escrow_address, bump_seed) = Pubkey::find_program_address(
+        &[
+            b"escrow",
+            token_mint_info.key.as_ref(),
+            token_program_info.key.as_ref(),
+            &escrow_id_seed,
+        ],
+        program_id,
+    );
     if &escrow_address != escrow_info.key {
         msg!("Escrow program derived address does not match the escrow address provided");
         return Err(ProgramError::InvalidSeeds);
     }
 
-    let bump_seed = &[bump_seed];
-    escrow_seeds.push(bump_seed);
+    let escrow_seeds: &[&[_]] = &[
+        b"escrow",
+        token_mint_info.key.as_ref(),
+        token_program_info.key.as_ref(),
+        &escrow_id_seed,
+        &[bump_seed],
+    ];
 
     invoke(
         &Instruction {
@@ -94,7 +625,6 @@ fn process_init_escrow(
                 AccountMeta::new_readonly(*token_mint_info.key, false),
                 AccountMeta::new_readonly(*system_program_info.key, false),
                 AccountMeta::new_readonly(*token_program_info.key, false),
-                AccountMeta::new_readonly(*rent_info.key, false),
             ],
             data: vec![],
         },
@@ -105,39 +635,24 @@ fn process_init_escrow(
             token_mint_info.clone(),
             system_program_info.clone(),
             token_program_info.clone(),
-            rent_info.clone(),
         ],
     )?;
 
-    let rent = &Rent::from_account_info(rent_info)?;
-    let required_lamports = rent
-        .minimum_balance(Escrow::LEN)
-        .max(1)
-        .saturating_sub(escrow_info.lamports());
-    if required_lamports > 0 {
-        invoke(
-            &system_instruction::transfer(funder_info.key, escrow_info.key, required_lamports),
-            &[
-                funder_info.clone(),
-                escrow_info.clone(),
-                system_program_info.clone(),
-            ],
-        )?;
-    }
-
-    invoke_signed(
-        &system_instruction::allocate(escrow_info.key, Escrow::LEN as u64),
-        &[escrow_info.clone(), system_program_info.clone()],
-        &[&escrow_seeds],
-    )?;
-
-    invoke_signed(
-        &system_instruction::assign(escrow_info.key, program_id),
-        &[escrow_info.clone(), system_program_info.clone()],
-        &[&escrow_seeds],
+    create_program_account(
+        program_id,
+        funder_info,
+        escrow_info,
+        escrow_seeds,
+        Escrow::LEN,
+        &Rent::get()?,
+        system_program_info,
     )?;
 
-    let escrow = Escrow::new(InitEscrowParams { owner });
+    let escrow = Escrow::new(InitEscrowParams {
+        owner,
+        token_mint: *token_mint_info.key,
+        escrow_id,
+    });
     Escrow::pack(escrow, &mut escrow_info.try_borrow_mut_data()?)?;
 
     Ok(())
@@ -153,8 +668,19 @@ fn process_set_escrow_owner(
     // Accounts
     let escrow_info = next_account_info(account_info_iter)?;
     let current_owner_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    let config = load_config(program_id, config_info)?;
+    if !config.instant_owner_change {
+        msg!(
+            "Instant owner changes are disabled, owners must be rotated with ScheduleOwnerRotation"
+        );
+        return Err(RNDRError::UnspecifiedError.into());
+    }
 
-    let mut escrow = Escrow::unpack(&escrow_info.try_borrow_data()?)?;
+    let mut escrow = Escrow::unpack_account(&escrow_info.try_borrow_data()?)?;
     if escrow_info.owner != program_id {
         msg!("Escrow provided is not owned by the RNDR program");
         return Err(RNDRError::UnspecifiedError.into());
@@ -168,22 +694,39 @@ fn process_set_escrow_owner(
         return Err(RNDRError::UnspecifiedError.into());
     }
 
-    escrow.owner = new_owner;
-    Escrow::pack(escrow, &mut escrow_info.try_borrow_mut_data()?)?;
+    escrow.set_owner(new_owner, Clock::get()?.slot);
+
+    resize_account(
+        escrow_info,
+        Escrow::LEN,
+        current_owner_info,
+        &Rent::get()?,
+        system_program_info,
+    )?;
+    Escrow::pack_account(escrow, &mut escrow_info.try_borrow_mut_data()?)?;
 
     Ok(())
 }
 
 #[inline(never)] // avoid stack frame limit
-fn process_fund_job(program_id: &Pubkey, amount: u64, accounts: &[AccountInfo]) -> ProgramResult {
+fn process_fund_job(
+    program_id: &Pubkey,
+    amount: u64,
+    job_id: u64,
+    metadata: Option<JobMetadata>,
+    exchange_rate: Option<(ExchangeRate, Slot)>,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
     if amount == 0 {
         msg!("Amount of tokens to fund can't be zero");
         return Err(RNDRError::UnspecifiedError.into());
     }
 
+    let (accounts, stats_info) = split_stats_account(program_id, accounts);
+    let (accounts, receipt_infos) = split_job_receipt_accounts(accounts);
+    let (accounts, job_queue_info) = split_job_queue_account(program_id, accounts);
+    let (accounts, job_index_infos) = split_job_index_accounts(program_id, accounts);
     let account_info_iter = &mut accounts.iter();
-    // RNDR token mint
-    let token_mint_info = next_account_info(account_info_iter)?;
     // Source accounts
     let funder_info = next_account_info(account_info_iter)?;
     let source_token_info = next_account_info(account_info_iter)?;
@@ -192,17 +735,62 @@ fn process_fund_job(program_id: &Pubkey, amount: u64, accounts: &[AccountInfo])
     let escrow_info = next_account_info(account_info_iter)?;
     let escrow_associated_token_info = next_account_info(account_info_iter)?;
     let job_info = next_account_info(account_info_iter)?;
+    let escrow_shard_info = next_account_info(account_info_iter)?;
     // Sysvars
-    let rent_info = next_account_info(account_info_iter)?;
+    skip_legacy_rent_sysvar(account_info_iter);
     // Programs
     let system_program_info = next_account_info(account_info_iter)?;
     let token_program_info = next_account_info(account_info_iter)?;
+    // Optional accounts
+    let session_key_info = account_info_iter.next();
+
+    check_distinct_accounts(&[
+        source_token_info,
+        escrow_info,
+        escrow_associated_token_info,
+        job_info,
+        escrow_shard_info,
+    ])?;
+
+    let mut escrow = Escrow::unpack_account(&escrow_info.try_borrow_data()?)?;
+    resolve_legacy_escrow_token_mint(
+        program_id,
+        escrow_info,
+        &mut escrow,
+        escrow_associated_token_info,
+    )?;
+    check_escrow_allows_funding(&escrow)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    let address_token_mint = *escrow.address_token_mint();
+    let escrow_id_seed = escrow_id_seed(escrow.escrow_id);
+
+    // A session key can only top up Jobs its authority already created
+    let authority = match session_key_info {
+        Some(session_key_info) => {
+            if job_info.try_data_is_empty()? {
+                msg!("Session key can only top up an existing job");
+                return Err(RNDRError::UnspecifiedError.into());
+            }
+            load_session_key_authority(
+                program_id,
+                escrow_info.key,
+                authority_info,
+                session_key_info,
+                SESSION_KEY_FUND_JOB,
+            )?
+        }
+        None => *authority_info.key,
+    };
 
     let (escrow_address, _bump_seed) = Pubkey::find_program_address(
         &[
             b"escrow",
-            token_mint_info.key.as_ref(),
+            address_token_mint.as_ref(),
             token_program_info.key.as_ref(),
+            &escrow_id_seed,
         ],
         program_id,
     );
@@ -211,74 +799,70 @@ fn process_fund_job(program_id: &Pubkey, amount: u64, accounts: &[AccountInfo])
         return Err(ProgramError::InvalidSeeds);
     }
 
-    let mut escrow = Escrow::unpack(&escrow_info.try_borrow_data()?)?;
-    if escrow_info.owner != program_id {
-        msg!("Escrow provided is not owned by the RNDR program");
-        return Err(RNDRError::UnspecifiedError.into());
-    }
-
-    let escrow_associated_token_address =
-        get_associated_token_address(&escrow_address, token_mint_info.key);
-    if &escrow_associated_token_address != escrow_associated_token_info.key {
-        msg!(
-            "Escrow associated token address does not match the associated token address provided"
-        );
+    if &escrow.token_account(&escrow_address) != escrow_associated_token_info.key {
+        msg!("Escrow token account does not match the token account provided");
         return Err(ProgramError::InvalidSeeds);
     }
 
-    let mut job_seeds: Vec<&[_]> = vec![
-        b"job",
-        escrow_info.key.as_ref(),
-        authority_info.key.as_ref(),
-    ];
+    let rent = &Rent::get()?;
 
-    let (job_pubkey, bump_seed) = Pubkey::find_program_address(&job_seeds, program_id);
-    if &job_pubkey != job_info.key {
-        msg!("Job program derived address does not match the job address provided");
-        return Err(ProgramError::InvalidSeeds);
+    let is_new_job = job_info.try_data_is_empty()?;
+    let mut job = load_or_create_job(
+        program_id,
+        funder_info,
+        escrow_info,
+        &escrow,
+        &authority,
+        job_info,
+        job_id,
+        false,
+        job_index_infos,
+        rent,
+        system_program_info,
+    )?;
+    if is_new_job {
+        push_job_queue(program_id, escrow_info, job_info, job_queue_info)?;
     }
 
-    let mut job = if job_info.try_data_is_empty()? {
-        let bump_seed = &[bump_seed];
-        job_seeds.push(bump_seed);
-
-        let rent = &Rent::from_account_info(rent_info)?;
-        let required_lamports = rent
-            .minimum_balance(Job::LEN)
-            .max(1)
-            .saturating_sub(job_info.lamports());
-        if required_lamports > 0 {
-            invoke(
-                &system_instruction::transfer(funder_info.key, job_info.key, required_lamports),
-                &[
-                    funder_info.clone(),
-                    job_info.clone(),
-                    system_program_info.clone(),
-                ],
-            )?;
+    if let Some(metadata) = metadata {
+        if session_key_info.is_some() {
+            msg!("Job metadata can only be set by the job authority");
+            return Err(RNDRError::UnspecifiedError.into());
         }
-
-        invoke_signed(
-            &system_instruction::allocate(job_info.key, Job::LEN as u64),
-            &[job_info.clone(), system_program_info.clone()],
-            &[&job_seeds],
+        set_job_metadata(
+            job_info,
+            &mut job,
+            metadata,
+            funder_info,
+            rent,
+            system_program_info,
         )?;
+    }
 
-        invoke_signed(
-            &system_instruction::assign(job_info.key, program_id),
-            &[job_info.clone(), system_program_info.clone()],
-            &[&job_seeds],
-        )?;
+    // A job keeps the first rate recorded for it, so all of its amounts are settled at one rate
+    if let Some((exchange_rate, oracle_slot)) = exchange_rate {
+        if job.exchange_rate().is_none() {
+            set_job_exchange_rate(
+                job_info,
+                &mut job,
+                exchange_rate,
+                oracle_slot,
+                funder_info,
+                rent,
+                system_program_info,
+            )?;
+        }
+    }
 
-        Job::new(InitJobParams {
-            authority: *authority_info.key,
-        })
-    } else if job_info.owner != program_id {
-        msg!("Job provided is not owned by the RNDR program");
-        return Err(RNDRError::UnspecifiedError.into());
-    } else {
-        Job::unpack(&job_info.try_borrow_data()?)?
-    };
+    let mut escrow_shard = load_or_create_escrow_shard(
+        program_id,
+        funder_info,
+        escrow_info,
+        job_info,
+        escrow_shard_info,
+        rent,
+        system_program_info,
+    )?;
 
     invoke(
         &spl_token::instruction::transfer(
@@ -298,13 +882,39 @@ fn process_fund_job(program_id: &Pubkey, amount: u64, accounts: &[AccountInfo])
     )?;
 
     job.amount = job.amount.checked_add(amount).ok_or(RNDRError::MathError)?;
-    escrow.amount = escrow
+    escrow_shard.amount = escrow_shard
         .amount
         .checked_add(amount)
         .ok_or(RNDRError::MathError)?;
+    let receipt_metadata = ReceiptMetadata::new(job.id, &job.metadata_uri, &job.metadata_hash);
 
-    Job::pack(job, &mut job_info.try_borrow_mut_data()?)?;
-    Escrow::pack(escrow, &mut escrow_info.try_borrow_mut_data()?)?;
+    Job::pack_account(job, &mut job_info.try_borrow_mut_data()?)?;
+    EscrowShard::pack(escrow_shard, &mut escrow_shard_info.try_borrow_mut_data()?)?;
+
+    if let Some(receipt_infos) = receipt_infos {
+        mint_job_receipt(
+            program_id,
+            funder_info,
+            receipt_infos,
+            system_program_info,
+            &receipt_metadata,
+        )?;
+    }
+
+    record_stats(
+        program_id,
+        stats_info,
+        escrow_info.key,
+        TokenFlow::Escrowed,
+        amount,
+    )?;
+
+    invariants::check_escrow_vault(
+        escrow_info,
+        escrow_associated_token_info,
+        &[job_info],
+        &[escrow_shard_info],
+    )?;
 
     Ok(())
 }
@@ -320,9 +930,13 @@ fn process_disburse_funds(
         return Err(RNDRError::UnspecifiedError.into());
     }
 
+    let (accounts, stats_info) = split_stats_account(program_id, accounts);
+    let escrow = *accounts
+        .first()
+        .ok_or(ProgramError::NotEnoughAccountKeys)?
+        .key;
+    let (accounts, escrow_shard_infos) = split_escrow_shard_accounts(program_id, &escrow, accounts);
     let account_info_iter = &mut accounts.iter();
-    // RNDR token mint
-    let token_mint_info = next_account_info(account_info_iter)?;
     // Source accounts
     let escrow_info = next_account_info(account_info_iter)?;
     let escrow_owner_info = next_account_info(account_info_iter)?;
@@ -332,68 +946,145 @@ fn process_disburse_funds(
     let destination_token_info = next_account_info(account_info_iter)?;
     // Programs
     let token_program_info = next_account_info(account_info_iter)?;
+    // Optional accounts
+    let delegate_allowance_info = account_info_iter.next();
 
-    let mut escrow_seeds: Vec<&[_]> = vec![
-        b"escrow",
-        token_mint_info.key.as_ref(),
-        token_program_info.key.as_ref(),
-    ];
+    check_distinct_accounts(&[
+        escrow_info,
+        escrow_associated_token_info,
+        job_info,
+        destination_token_info,
+    ])?;
+
+    let mut escrow = Escrow::unpack_account(&escrow_info.try_borrow_data()?)?;
+    resolve_legacy_escrow_token_mint(
+        program_id,
+        escrow_info,
+        &mut escrow,
+        escrow_associated_token_info,
+    )?;
+    check_escrow_allows_disbursing(&escrow)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    let address_token_mint = *escrow.address_token_mint();
+    let escrow_id_seed = escrow_id_seed(escrow.escrow_id);
 
-    let (escrow_address, bump_seed) = Pubkey::find_program_address(&escrow_seeds, program_id);
+    let (escrow_address, bump_seed) = Pubkey::find_program_address(
+        &[
+            b"escrow",
+            address_token_mint.as_ref(),
+            token_program_info.key.as_ref(),
+            &escrow_id_seed,
+        ],
+        program_id,
+    );
     if &escrow_address != escrow_info.key {
         msg!("Escrow program derived address does not match the escrow address provided");
         return Err(ProgramError::InvalidSeeds);
     }
 
-    let bump_seed = &[bump_seed];
-    escrow_seeds.push(bump_seed);
+    let escrow_seeds: &[&[_]] = &[
+        b"escrow",
+        address_token_mint.as_ref(),
+        token_program_info.key.as_ref(),
+        &escrow_id_seed,
+        &[bump_seed],
+    ];
 
-    let mut escrow = Escrow::unpack(&escrow_info.try_borrow_data()?)?;
-    if escrow_info.owner != program_id {
-        msg!("Escrow provided is not owned by the RNDR program");
-        return Err(RNDRError::UnspecifiedError.into());
-    }
-    if &escrow.owner != escrow_owner_info.key {
-        msg!("Escrow owner does not match the escrow owner provided");
-        return Err(RNDRError::UnspecifiedError.into());
-    }
+    // A delegate of the owner signs in its place, within its remaining allowance
+    let delegate_allowance = match delegate_allowance_info {
+        Some(delegate_allowance_info) => {
+            check_delegate_allowance_address(
+                program_id,
+                escrow_info.key,
+                escrow_owner_info.key,
+                delegate_allowance_info,
+            )?;
+            let mut delegate_allowance =
+                load_delegate_allowance(program_id, delegate_allowance_info)?;
+            if !delegate_allowance.is_active(Clock::get()?.slot) {
+                msg!("Delegate allowance has expired");
+                return Err(RNDRError::UnspecifiedError.into());
+            }
+            if delegate_allowance.consume(amount).is_none() {
+                msg!(
+                    "Amount of tokens to disburse exceeds the remaining allowance {} of the delegate",
+                    delegate_allowance.remaining
+                );
+                return Err(RNDRError::UnspecifiedError.into());
+            }
+            Some((delegate_allowance, delegate_allowance_info))
+        }
+        None => {
+            if &escrow.owner != escrow_owner_info.key {
+                msg!("Escrow owner does not match the escrow owner provided");
+                return Err(RNDRError::UnspecifiedError.into());
+            }
+            None
+        }
+    };
     if !escrow_owner_info.is_signer {
         msg!("Escrow owner provided must be a signer");
         return Err(RNDRError::UnspecifiedError.into());
     }
+    if escrow.approver().is_some() {
+        msg!("Escrow requires disbursements to be proposed and approved");
+        return Err(RNDRError::ApprovalRequired.into());
+    }
 
-    let escrow_associated_token_address =
-        get_associated_token_address(&escrow_address, token_mint_info.key);
-    if &escrow_associated_token_address != escrow_associated_token_info.key {
-        msg!(
-            "Escrow associated token address does not match the associated token address provided"
-        );
+    if &escrow.token_account(&escrow_address) != escrow_associated_token_info.key {
+        msg!("Escrow token account does not match the token account provided");
         return Err(ProgramError::InvalidSeeds);
     }
 
-    let mut job = Job::unpack(&job_info.try_borrow_data()?)?;
+    let mut job = Job::unpack_account(&job_info.try_borrow_data()?)?;
+    check_job_mint_migrations(&job, &escrow)?;
+    check_job_not_frozen(&job)?;
     if job_info.owner != program_id {
         msg!("Job provided is not owned by the RNDR program");
         return Err(RNDRError::UnspecifiedError.into());
     }
 
     let (job_pubkey, _bump_seed) = Pubkey::find_program_address(
-        &[b"job", escrow_info.key.as_ref(), job.authority.as_ref()],
+        &[
+            b"job",
+            escrow_info.key.as_ref(),
+            job.address_authority().as_ref(),
+            &job_id_seed(job.id),
+        ],
         program_id,
     );
     if &job_pubkey != job_info.key {
         msg!("Job program derived address does not match the job address provided");
         return Err(ProgramError::InvalidSeeds);
     }
+    if job.isolated {
+        msg!("Job provided holds its tokens in its own token account");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if job.status == JobStatus::Disputed {
+        msg!("Job is disputed, so its funds can't be disbursed");
+        return Err(RNDRError::JobDisputed.into());
+    }
 
+    emit_job_settled(job_info, &job, amount)?;
     job.amount = job.amount.checked_sub(amount).ok_or(RNDRError::MathError)?;
+    aggregate_escrow_shards(program_id, escrow_info, &mut escrow, escrow_shard_infos)?;
     escrow.amount = escrow
         .amount
         .checked_sub(amount)
         .ok_or(RNDRError::MathError)?;
 
-    Job::pack(job, &mut job_info.try_borrow_mut_data()?)?;
-    Escrow::pack(escrow, &mut escrow_info.try_borrow_mut_data()?)?;
+    Job::pack_account(job, &mut job_info.try_borrow_mut_data()?)?;
+    Escrow::pack_account(escrow, &mut escrow_info.try_borrow_mut_data()?)?;
+    if let Some((delegate_allowance, delegate_allowance_info)) = delegate_allowance {
+        DelegateAllowance::pack(
+            delegate_allowance,
+            &mut delegate_allowance_info.try_borrow_mut_data()?,
+        )?;
+    }
 
     invoke_signed(
         &spl_token::instruction::transfer(
@@ -410,7 +1101,10221 @@ fn process_disburse_funds(
             escrow_info.clone(),
             token_program_info.clone(),
         ],
-        &[&escrow_seeds],
+        &[escrow_seeds],
+    )?;
+
+    record_stats(
+        program_id,
+        stats_info,
+        escrow_info.key,
+        TokenFlow::Disbursed,
+        amount,
+    )?;
+
+    invariants::check_escrow_vault(escrow_info, escrow_associated_token_info, &[job_info], &[])?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_aggregate_escrow_shard(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let escrow_info = next_account_info(account_info_iter)?;
+    let escrow_shard_info = next_account_info(account_info_iter)?;
+
+    let mut escrow = Escrow::unpack_account(&escrow_info.try_borrow_data()?)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let mut escrow_shard = EscrowShard::unpack(&escrow_shard_info.try_borrow_data()?)?;
+    if escrow_shard_info.owner != program_id {
+        msg!("Escrow shard provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if &escrow_shard.escrow != escrow_info.key {
+        msg!("Escrow shard escrow does not match the escrow provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let (escrow_shard_address, _bump_seed) = Pubkey::find_program_address(
+        &[
+            b"escrow_shard",
+            escrow_info.key.as_ref(),
+            &[escrow_shard.index],
+        ],
+        program_id,
+    );
+    if &escrow_shard_address != escrow_shard_info.key {
+        msg!(
+            "Escrow shard program derived address does not match the escrow shard address provided"
+        );
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let amount = escrow_shard.amount;
+    escrow.amount = escrow
+        .amount
+        .checked_add(amount)
+        .ok_or(RNDRError::MathError)?;
+    escrow_shard.amount = 0;
+
+    Escrow::pack_account(escrow, &mut escrow_info.try_borrow_mut_data()?)?;
+    EscrowShard::pack(escrow_shard, &mut escrow_shard_info.try_borrow_mut_data()?)?;
+
+    // Only aggregations that move tokens are paid, so the crank vault can't be drained by
+    // aggregating empty shards
+    if amount > 0 {
+        pay_crank_bounty(program_id, escrow_info.key, account_info_iter)?;
+    }
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_fund_isolated_job(
+    program_id: &Pubkey,
+    amount: u64,
+    job_id: u64,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    if amount == 0 {
+        msg!("Amount of tokens to fund can't be zero");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let (accounts, stats_info) = split_stats_account(program_id, accounts);
+    let (accounts, job_index_infos) = split_job_index_accounts(program_id, accounts);
+    let account_info_iter = &mut accounts.iter();
+    // RNDR token mint
+    let token_mint_info = next_account_info(account_info_iter)?;
+    // Source accounts
+    let funder_info = next_account_info(account_info_iter)?;
+    let source_token_info = next_account_info(account_info_iter)?;
+    let authority_info = next_account_info(account_info_iter)?;
+    // Destination accounts
+    let escrow_info = next_account_info(account_info_iter)?;
+    let job_info = next_account_info(account_info_iter)?;
+    let job_associated_token_info = next_account_info(account_info_iter)?;
+    // Sysvars
+    skip_legacy_rent_sysvar(account_info_iter);
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+    let token_program_info = next_account_info(account_info_iter)?;
+    let associated_token_program_info = next_account_info(account_info_iter)?;
+
+    check_distinct_accounts(&[
+        source_token_info,
+        escrow_info,
+        job_info,
+        job_associated_token_info,
+    ])?;
+
+    let mut escrow = Escrow::unpack_account(&escrow_info.try_borrow_data()?)?;
+    resolve_legacy_escrow_token_mint(program_id, escrow_info, &mut escrow, token_mint_info)?;
+    check_escrow_allows_funding(&escrow)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let (escrow_address, _bump_seed) = Pubkey::find_program_address(
+        &[
+            b"escrow",
+            escrow.address_token_mint().as_ref(),
+            token_program_info.key.as_ref(),
+            &escrow_id_seed(escrow.escrow_id),
+        ],
+        program_id,
+    );
+    if &escrow_address != escrow_info.key {
+        msg!("Escrow program derived address does not match the escrow address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if &escrow.token_mint != token_mint_info.key {
+        msg!("Escrow token mint does not match the token mint provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let mut job = load_or_create_job(
+        program_id,
+        funder_info,
+        escrow_info,
+        &escrow,
+        authority_info.key,
+        job_info,
+        job_id,
+        true,
+        job_index_infos,
+        &Rent::get()?,
+        system_program_info,
+    )?;
+
+    let job_associated_token_address =
+        get_associated_token_address(job_info.key, token_mint_info.key);
+    if &job_associated_token_address != job_associated_token_info.key {
+        msg!("Job associated token address does not match the associated token address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    if job_associated_token_info.try_data_is_empty()? {
+        invoke(
+            &Instruction {
+                program_id: *associated_token_program_info.key,
+                accounts: vec![
+                    AccountMeta::new(*funder_info.key, true),
+                    AccountMeta::new(*job_associated_token_info.key, false),
+                    AccountMeta::new_readonly(*job_info.key, false),
+                    AccountMeta::new_readonly(*token_mint_info.key, false),
+                    AccountMeta::new_readonly(*system_program_info.key, false),
+                    AccountMeta::new_readonly(*token_program_info.key, false),
+                ],
+                data: vec![],
+            },
+            &[
+                funder_info.clone(),
+                job_associated_token_info.clone(),
+                job_info.clone(),
+                token_mint_info.clone(),
+                system_program_info.clone(),
+                token_program_info.clone(),
+            ],
+        )?;
+    }
+
+    invoke(
+        &spl_token::instruction::transfer(
+            token_program_info.key,
+            source_token_info.key,
+            job_associated_token_info.key,
+            authority_info.key,
+            &[],
+            amount,
+        )?,
+        &[
+            source_token_info.clone(),
+            job_associated_token_info.clone(),
+            authority_info.clone(),
+            token_program_info.clone(),
+        ],
+    )?;
+
+    job.amount = job.amount.checked_add(amount).ok_or(RNDRError::MathError)?;
+    Job::pack_account(job, &mut job_info.try_borrow_mut_data()?)?;
+
+    record_stats(
+        program_id,
+        stats_info,
+        escrow_info.key,
+        TokenFlow::Escrowed,
+        amount,
+    )?;
+
+    invariants::check_isolated_job_vault(job_info, job_associated_token_info)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_disburse_isolated_job_funds(
+    program_id: &Pubkey,
+    amount: u64,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    if amount == 0 {
+        msg!("Amount of tokens to disburse can't be zero");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let (accounts, stats_info) = split_stats_account(program_id, accounts);
+    let account_info_iter = &mut accounts.iter();
+    // Source accounts
+    let escrow_info = next_account_info(account_info_iter)?;
+    let escrow_owner_info = next_account_info(account_info_iter)?;
+    let job_info = next_account_info(account_info_iter)?;
+    let job_associated_token_info = next_account_info(account_info_iter)?;
+    // Destination accounts
+    let destination_token_info = next_account_info(account_info_iter)?;
+    // Programs
+    let token_program_info = next_account_info(account_info_iter)?;
+
+    check_distinct_accounts(&[
+        escrow_info,
+        job_info,
+        job_associated_token_info,
+        destination_token_info,
+    ])?;
+
+    let mut escrow = Escrow::unpack_account(&escrow_info.try_borrow_data()?)?;
+    resolve_legacy_escrow_token_mint(
+        program_id,
+        escrow_info,
+        &mut escrow,
+        job_associated_token_info,
+    )?;
+    check_escrow_allows_disbursing(&escrow)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    let token_mint = escrow.token_mint;
+    let address_token_mint = *escrow.address_token_mint();
+    let escrow_id_seed = escrow_id_seed(escrow.escrow_id);
+
+    let (escrow_address, _bump_seed) = Pubkey::find_program_address(
+        &[
+            b"escrow",
+            address_token_mint.as_ref(),
+            token_program_info.key.as_ref(),
+            &escrow_id_seed,
+        ],
+        program_id,
+    );
+    if &escrow_address != escrow_info.key {
+        msg!("Escrow program derived address does not match the escrow address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    if &escrow.owner != escrow_owner_info.key {
+        msg!("Escrow owner does not match the escrow owner provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if !escrow_owner_info.is_signer {
+        msg!("Escrow owner provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if escrow.approver().is_some() {
+        msg!("Escrow requires disbursements to be proposed and approved");
+        return Err(RNDRError::ApprovalRequired.into());
+    }
+
+    let mut job = Job::unpack_account(&job_info.try_borrow_data()?)?;
+    check_job_mint_migrations(&job, &escrow)?;
+    check_job_not_frozen(&job)?;
+    if job_info.owner != program_id {
+        msg!("Job provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if !job.isolated {
+        msg!("Job provided holds its tokens in the escrow token account");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if job.status == JobStatus::Disputed {
+        msg!("Job is disputed, so its funds can't be disbursed");
+        return Err(RNDRError::JobDisputed.into());
+    }
+
+    let authority = *job.address_authority();
+    let job_id_seed = job_id_seed(job.id);
+
+    let (job_pubkey, bump_seed) = Pubkey::find_program_address(
+        &[
+            b"job",
+            escrow_info.key.as_ref(),
+            authority.as_ref(),
+            &job_id_seed,
+        ],
+        program_id,
+    );
+    if &job_pubkey != job_info.key {
+        msg!("Job program derived address does not match the job address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let job_seeds: &[&[_]] = &[
+        b"job",
+        escrow_info.key.as_ref(),
+        authority.as_ref(),
+        &job_id_seed,
+        &[bump_seed],
+    ];
+
+    let job_associated_token_address = get_associated_token_address(&job_pubkey, &token_mint);
+    if &job_associated_token_address != job_associated_token_info.key {
+        msg!("Job associated token address does not match the associated token address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    job.amount = job.amount.checked_sub(amount).ok_or(RNDRError::MathError)?;
+    Job::pack_account(job, &mut job_info.try_borrow_mut_data()?)?;
+
+    invoke_signed(
+        &spl_token::instruction::transfer(
+            token_program_info.key,
+            job_associated_token_info.key,
+            destination_token_info.key,
+            job_info.key,
+            &[],
+            amount,
+        )?,
+        &[
+            job_associated_token_info.clone(),
+            destination_token_info.clone(),
+            job_info.clone(),
+            token_program_info.clone(),
+        ],
+        &[job_seeds],
+    )?;
+
+    record_stats(
+        program_id,
+        stats_info,
+        escrow_info.key,
+        TokenFlow::Disbursed,
+        amount,
+    )?;
+
+    invariants::check_isolated_job_vault(job_info, job_associated_token_info)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_disburse_batch(
+    program_id: &Pubkey,
+    amounts: &[u64],
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    if amounts.is_empty() || amounts.len() > MAX_DISBURSE_BATCH_LEN {
+        msg!(
+            "Number of disbursements must be between 1 and {}",
+            MAX_DISBURSE_BATCH_LEN
+        );
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if amounts.contains(&0) {
+        msg!("Amount of tokens to disburse can't be zero");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    let total_amount = amounts
+        .iter()
+        .try_fold(0u64, |total, amount| total.checked_add(*amount))
+        .ok_or(RNDRError::MathError)?;
+
+    let (accounts, stats_info) = split_stats_account(program_id, accounts);
+    let escrow = *accounts
+        .first()
+        .ok_or(ProgramError::NotEnoughAccountKeys)?
+        .key;
+    let (accounts, escrow_shard_infos) = split_escrow_shard_accounts(program_id, &escrow, accounts);
+    let account_info_iter = &mut accounts.iter();
+    // Source accounts
+    let escrow_info = next_account_info(account_info_iter)?;
+    let escrow_owner_info = next_account_info(account_info_iter)?;
+    let escrow_associated_token_info = next_account_info(account_info_iter)?;
+    let job_info = next_account_info(account_info_iter)?;
+    // Programs
+    let token_program_info = next_account_info(account_info_iter)?;
+    // Destination accounts
+    let destination_token_infos = account_info_iter.as_slice();
+
+    if destination_token_infos.len() != amounts.len() {
+        msg!("Number of destination token accounts does not match the number of amounts");
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    // Destinations can repeat, as a node can be paid more than once in a batch
+    for destination_token_info in destination_token_infos {
+        check_distinct_accounts(&[
+            escrow_info,
+            escrow_associated_token_info,
+            job_info,
+            destination_token_info,
+        ])?;
+    }
+
+    let mut escrow = Escrow::unpack_account(&escrow_info.try_borrow_data()?)?;
+    resolve_legacy_escrow_token_mint(
+        program_id,
+        escrow_info,
+        &mut escrow,
+        escrow_associated_token_info,
+    )?;
+    check_escrow_allows_disbursing(&escrow)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    let address_token_mint = *escrow.address_token_mint();
+    let escrow_id_seed = escrow_id_seed(escrow.escrow_id);
+
+    let (escrow_address, bump_seed) = Pubkey::find_program_address(
+        &[
+            b"escrow",
+            address_token_mint.as_ref(),
+            token_program_info.key.as_ref(),
+            &escrow_id_seed,
+        ],
+        program_id,
+    );
+    if &escrow_address != escrow_info.key {
+        msg!("Escrow program derived address does not match the escrow address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let escrow_seeds: &[&[_]] = &[
+        b"escrow",
+        address_token_mint.as_ref(),
+        token_program_info.key.as_ref(),
+        &escrow_id_seed,
+        &[bump_seed],
+    ];
+
+    if &escrow.owner != escrow_owner_info.key {
+        msg!("Escrow owner does not match the escrow owner provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if !escrow_owner_info.is_signer {
+        msg!("Escrow owner provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if escrow.approver().is_some() {
+        msg!("Escrow requires disbursements to be proposed and approved");
+        return Err(RNDRError::ApprovalRequired.into());
+    }
+
+    if &escrow.token_account(&escrow_address) != escrow_associated_token_info.key {
+        msg!("Escrow token account does not match the token account provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let mut job = Job::unpack_account(&job_info.try_borrow_data()?)?;
+    check_job_mint_migrations(&job, &escrow)?;
+    check_job_not_frozen(&job)?;
+    if job_info.owner != program_id {
+        msg!("Job provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let (job_pubkey, _bump_seed) = Pubkey::find_program_address(
+        &[
+            b"job",
+            escrow_info.key.as_ref(),
+            job.address_authority().as_ref(),
+            &job_id_seed(job.id),
+        ],
+        program_id,
+    );
+    if &job_pubkey != job_info.key {
+        msg!("Job program derived address does not match the job address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if job.isolated {
+        msg!("Job provided holds its tokens in its own token account");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if job.status == JobStatus::Disputed {
+        msg!("Job is disputed, so its funds can't be disbursed");
+        return Err(RNDRError::JobDisputed.into());
+    }
+
+    emit_job_settled(job_info, &job, total_amount)?;
+    job.amount = job
+        .amount
+        .checked_sub(total_amount)
+        .ok_or(RNDRError::MathError)?;
+    aggregate_escrow_shards(program_id, escrow_info, &mut escrow, escrow_shard_infos)?;
+    escrow.amount = escrow
+        .amount
+        .checked_sub(total_amount)
+        .ok_or(RNDRError::MathError)?;
+
+    Job::pack_account(job, &mut job_info.try_borrow_mut_data()?)?;
+    Escrow::pack_account(escrow, &mut escrow_info.try_borrow_mut_data()?)?;
+
+    for (destination_token_info, amount) in destination_token_infos.iter().zip(amounts) {
+        invoke_signed(
+            &spl_token::instruction::transfer(
+                token_program_info.key,
+                escrow_associated_token_info.key,
+                destination_token_info.key,
+                escrow_info.key,
+                &[],
+                *amount,
+            )?,
+            &[
+                escrow_associated_token_info.clone(),
+                destination_token_info.clone(),
+                escrow_info.clone(),
+                token_program_info.clone(),
+            ],
+            &[escrow_seeds],
+        )?;
+    }
+
+    record_stats(
+        program_id,
+        stats_info,
+        escrow_info.key,
+        TokenFlow::Disbursed,
+        total_amount,
+    )?;
+
+    invariants::check_escrow_vault(escrow_info, escrow_associated_token_info, &[job_info], &[])?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_fund_jobs(
+    program_id: &Pubkey,
+    jobs: &[(u64, u64)],
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    if jobs.is_empty() || jobs.len() > MAX_FUND_JOBS_LEN {
+        msg!("Number of jobs must be between 1 and {}", MAX_FUND_JOBS_LEN);
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if jobs.iter().any(|(_job_id, amount)| *amount == 0) {
+        msg!("Amount of tokens to fund can't be zero");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    let total_amount = jobs
+        .iter()
+        .try_fold(0u64, |total, (_job_id, amount)| total.checked_add(*amount))
+        .ok_or(RNDRError::MathError)?;
+
+    let (accounts, stats_info) = split_stats_account(program_id, accounts);
+    let (accounts, job_index_infos) = split_job_index_accounts(program_id, accounts);
+    let account_info_iter = &mut accounts.iter();
+    // Source accounts
+    let funder_info = next_account_info(account_info_iter)?;
+    let source_token_info = next_account_info(account_info_iter)?;
+    let authority_info = next_account_info(account_info_iter)?;
+    // Destination accounts
+    let escrow_info = next_account_info(account_info_iter)?;
+    let escrow_associated_token_info = next_account_info(account_info_iter)?;
+    // Sysvars
+    skip_legacy_rent_sysvar(account_info_iter);
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+    let token_program_info = next_account_info(account_info_iter)?;
+
+    check_distinct_accounts(&[source_token_info, escrow_info, escrow_associated_token_info])?;
+
+    let mut escrow = Escrow::unpack_account(&escrow_info.try_borrow_data()?)?;
+    resolve_legacy_escrow_token_mint(
+        program_id,
+        escrow_info,
+        &mut escrow,
+        escrow_associated_token_info,
+    )?;
+    check_escrow_allows_funding(&escrow)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    let address_token_mint = *escrow.address_token_mint();
+    let escrow_id_seed = escrow_id_seed(escrow.escrow_id);
+
+    let (escrow_address, _bump_seed) = Pubkey::find_program_address(
+        &[
+            b"escrow",
+            address_token_mint.as_ref(),
+            token_program_info.key.as_ref(),
+            &escrow_id_seed,
+        ],
+        program_id,
+    );
+    if &escrow_address != escrow_info.key {
+        msg!("Escrow program derived address does not match the escrow address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    if &escrow.token_account(&escrow_address) != escrow_associated_token_info.key {
+        msg!("Escrow token account does not match the token account provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    invoke(
+        &spl_token::instruction::transfer(
+            token_program_info.key,
+            source_token_info.key,
+            escrow_associated_token_info.key,
+            authority_info.key,
+            &[],
+            total_amount,
+        )?,
+        &[
+            source_token_info.clone(),
+            escrow_associated_token_info.clone(),
+            authority_info.clone(),
+            token_program_info.clone(),
+        ],
+    )?;
+
+    let rent = &Rent::get()?;
+
+    let mut job_infos = Vec::with_capacity(jobs.len());
+    let mut escrow_shard_infos = Vec::with_capacity(jobs.len());
+    for (job_id, amount) in jobs {
+        // Job accounts
+        let job_info = next_account_info(account_info_iter)?;
+        let escrow_shard_info = next_account_info(account_info_iter)?;
+        job_infos.push(job_info);
+        escrow_shard_infos.push(escrow_shard_info);
+
+        let mut job = load_or_create_job(
+            program_id,
+            funder_info,
+            escrow_info,
+            &escrow,
+            authority_info.key,
+            job_info,
+            *job_id,
+            false,
+            job_index_infos,
+            rent,
+            system_program_info,
+        )?;
+
+        let mut escrow_shard = load_or_create_escrow_shard(
+            program_id,
+            funder_info,
+            escrow_info,
+            job_info,
+            escrow_shard_info,
+            rent,
+            system_program_info,
+        )?;
+
+        job.amount = job
+            .amount
+            .checked_add(*amount)
+            .ok_or(RNDRError::MathError)?;
+        escrow_shard.amount = escrow_shard
+            .amount
+            .checked_add(*amount)
+            .ok_or(RNDRError::MathError)?;
+
+        Job::pack_account(job, &mut job_info.try_borrow_mut_data()?)?;
+        EscrowShard::pack(escrow_shard, &mut escrow_shard_info.try_borrow_mut_data()?)?;
+    }
+
+    record_stats(
+        program_id,
+        stats_info,
+        escrow_info.key,
+        TokenFlow::Escrowed,
+        total_amount,
+    )?;
+
+    invariants::check_escrow_vault(
+        escrow_info,
+        escrow_associated_token_info,
+        &job_infos,
+        &escrow_shard_infos,
+    )?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_commit_result(
+    program_id: &Pubkey,
+    commitment: [u8; HASH_BYTES],
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    if commitment == [0; HASH_BYTES] {
+        msg!("Result commitment can't be zero");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let account_info_iter = &mut accounts.iter();
+    // Source accounts
+    let node_info = next_account_info(account_info_iter)?;
+    // Destination accounts
+    let job_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+    // Config
+    let config_info = next_account_info(account_info_iter)?;
+
+    if !node_info.is_signer {
+        msg!("Node provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let mut job = Job::unpack_account(&job_info.try_borrow_data()?)?;
+    if job_info.owner != program_id {
+        msg!("Job provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    match job.status {
+        JobStatus::Open => {
+            check_job_claimable(program_id, config_info, &job)?;
+            job.node = *node_info.key;
+        }
+        JobStatus::Committed => {
+            if &job.node != node_info.key {
+                msg!("Job result has been committed to by another node");
+                return Err(RNDRError::JobAlreadyAssigned.into());
+            }
+        }
+        JobStatus::Revealed | JobStatus::Paid | JobStatus::Challenged => {
+            msg!("Job result has already been revealed");
+            return Err(RNDRError::UnspecifiedError.into());
+        }
+        JobStatus::Disputed | JobStatus::Resolved => {
+            msg!("Job is disputed");
+            return Err(RNDRError::JobDisputed.into());
+        }
+        JobStatus::Cancelled => {
+            msg!("Job has been cancelled");
+            return Err(RNDRError::UnspecifiedError.into());
+        }
+    }
+    job.status = JobStatus::Committed;
+    job.result_commitment = commitment;
+
+    resize_account(
+        job_info,
+        Job::LEN,
+        node_info,
+        &Rent::get()?,
+        system_program_info,
+    )?;
+    Job::pack_account(job, &mut job_info.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_reveal_result(
+    program_id: &Pubkey,
+    result_hash: [u8; HASH_BYTES],
+    salt: [u8; HASH_BYTES],
+    proof: Vec<u8>,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Source accounts
+    let node_info = next_account_info(account_info_iter)?;
+    // Destination accounts
+    let job_info = next_account_info(account_info_iter)?;
+    // Accounts
+    let escrow_info = next_account_info(account_info_iter)?;
+
+    if !node_info.is_signer {
+        msg!("Node provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let escrow = Escrow::unpack_account(&escrow_info.try_borrow_data()?)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let mut job = Job::unpack_account(&job_info.try_borrow_data()?)?;
+    if job_info.owner != program_id {
+        msg!("Job provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let (job_pubkey, _bump_seed) = Pubkey::find_program_address(
+        &[
+            b"job",
+            escrow_info.key.as_ref(),
+            job.address_authority().as_ref(),
+            &job_id_seed(job.id),
+        ],
+        program_id,
+    );
+    if &job_pubkey != job_info.key {
+        msg!("Job program derived address does not match the job address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if job.status != JobStatus::Committed {
+        msg!("Job result has not been committed to or has already been revealed");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if &job.node != node_info.key {
+        msg!("Job node does not match the node provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if Job::result_commitment(job_info.key, node_info.key, &result_hash, &salt)
+        != job.result_commitment
+    {
+        msg!("Job result does not match the commitment");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    if let Some(proof_verifier) = escrow.proof_verifier() {
+        // Programs
+        let proof_verifier_info = next_account_info(account_info_iter)?;
+        if proof_verifier_info.key != proof_verifier {
+            msg!("Escrow proof verifier does not match the proof verifier provided");
+            return Err(RNDRError::UnspecifiedError.into());
+        }
+
+        let verifier_account_infos = account_info_iter.as_slice();
+        let verifier_accounts: Vec<AccountMeta> = verifier_account_infos
+            .iter()
+            .map(|account_info| AccountMeta {
+                pubkey: *account_info.key,
+                is_signer: account_info.is_signer,
+                is_writable: account_info.is_writable,
+            })
+            .collect();
+
+        let mut account_infos = vec![job_info.clone()];
+        account_infos.extend_from_slice(verifier_account_infos);
+        account_infos.push(proof_verifier_info.clone());
+
+        invoke(
+            &verify_proof(
+                *proof_verifier,
+                &result_hash,
+                &proof,
+                *job_info.key,
+                &verifier_accounts,
+            ),
+            &account_infos,
+        )?;
+    }
+
+    job.status = JobStatus::Revealed;
+    job.result_hash = result_hash;
+    job.revealed_at = Clock::get()?.unix_timestamp;
+
+    Job::pack_account(job, &mut job_info.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_claim_payment(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let (accounts, stats_info) = split_stats_account(program_id, accounts);
+    let escrow = *accounts
+        .get(1)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?
+        .key;
+    let (accounts, escrow_shard_infos) = split_escrow_shard_accounts(program_id, &escrow, accounts);
+    let account_info_iter = &mut accounts.iter();
+    // Source accounts
+    let node_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let escrow_associated_token_info = next_account_info(account_info_iter)?;
+    let job_info = next_account_info(account_info_iter)?;
+    // Destination accounts
+    let destination_token_info = next_account_info(account_info_iter)?;
+    // Programs
+    let token_program_info = next_account_info(account_info_iter)?;
+    // Reward tier accounts
+    let config_info = next_account_info(account_info_iter)?;
+    let node_account_info = next_account_info(account_info_iter)?;
+
+    check_distinct_accounts(&[
+        escrow_info,
+        escrow_associated_token_info,
+        job_info,
+        destination_token_info,
+        node_account_info,
+    ])?;
+
+    if !node_info.is_signer {
+        msg!("Node provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let mut escrow = Escrow::unpack_account(&escrow_info.try_borrow_data()?)?;
+    resolve_legacy_escrow_token_mint(
+        program_id,
+        escrow_info,
+        &mut escrow,
+        escrow_associated_token_info,
+    )?;
+    check_escrow_allows_disbursing(&escrow)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    let address_token_mint = *escrow.address_token_mint();
+    let escrow_id_seed = escrow_id_seed(escrow.escrow_id);
+
+    let (escrow_address, bump_seed) = Pubkey::find_program_address(
+        &[
+            b"escrow",
+            address_token_mint.as_ref(),
+            token_program_info.key.as_ref(),
+            &escrow_id_seed,
+        ],
+        program_id,
+    );
+    if &escrow_address != escrow_info.key {
+        msg!("Escrow program derived address does not match the escrow address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let escrow_seeds: &[&[_]] = &[
+        b"escrow",
+        address_token_mint.as_ref(),
+        token_program_info.key.as_ref(),
+        &escrow_id_seed,
+        &[bump_seed],
+    ];
+
+    if &escrow.token_account(&escrow_address) != escrow_associated_token_info.key {
+        msg!("Escrow token account does not match the token account provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let mut job = Job::unpack_account(&job_info.try_borrow_data()?)?;
+    check_job_mint_migrations(&job, &escrow)?;
+    check_job_not_frozen(&job)?;
+    if job_info.owner != program_id {
+        msg!("Job provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let (job_pubkey, _bump_seed) = Pubkey::find_program_address(
+        &[
+            b"job",
+            escrow_info.key.as_ref(),
+            job.address_authority().as_ref(),
+            &job_id_seed(job.id),
+        ],
+        program_id,
+    );
+    if &job_pubkey != job_info.key {
+        msg!("Job program derived address does not match the job address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if job.isolated {
+        msg!("Job provided holds its tokens in its own token account");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if job.status != JobStatus::Revealed {
+        msg!("Job result has not been revealed or payment has already been claimed");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if &job.node != node_info.key {
+        msg!("Job node does not match the node provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if Clock::get()?.unix_timestamp < challenge_deadline(&job, &escrow) {
+        msg!("Job challenge window has not passed");
+        return Err(RNDRError::ChallengeWindowActive.into());
+    }
+
+    let amount = job.amount;
+    if amount == 0 {
+        msg!("Job has no tokens to claim");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    // The node is paid the Job's full amount, and the reward multiplier of its tier only weights
+    // the work it records toward the epoch's reward emissions. Tokens staked during the current
+    // epoch don't count towards the tier, so a node can't stake just before it claims a payment
+    // and unstake right after.
+    let config = load_config(program_id, config_info)?;
+    check_node_address(
+        program_id,
+        escrow_info.key,
+        node_info.key,
+        node_account_info,
+    )?;
+    let epoch = Clock::get()?.epoch;
+    let node = if node_account_info.try_data_is_empty()? {
+        None
+    } else {
+        Some(load_node(program_id, node_account_info)?)
+    };
+    let tier_stake = node.as_ref().map_or(0, |node| node.tier_stake(epoch));
+
+    // Work is recorded at the value of the payment in RNDR, so jobs funded in other tokens earn
+    // rewards at the exchange rate they're settled at
+    let value = job.try_rndr_value(amount)?;
+    let work = config
+        .weighted_work(tier_stake, value)
+        .ok_or(RNDRError::MathError)?;
+    emit_job_settled(job_info, &job, amount)?;
+    job.amount = 0;
+    job.status = JobStatus::Paid;
+    aggregate_escrow_shards(program_id, escrow_info, &mut escrow, escrow_shard_infos)?;
+    escrow.amount = escrow
+        .amount
+        .checked_sub(amount)
+        .ok_or(RNDRError::MathError)?;
+
+    Job::pack_account(job, &mut job_info.try_borrow_mut_data()?)?;
+    Escrow::pack_account(escrow, &mut escrow_info.try_borrow_mut_data()?)?;
+
+    invoke_signed(
+        &spl_token::instruction::transfer(
+            token_program_info.key,
+            escrow_associated_token_info.key,
+            destination_token_info.key,
+            escrow_info.key,
+            &[],
+            amount,
+        )?,
+        &[
+            escrow_associated_token_info.clone(),
+            destination_token_info.clone(),
+            escrow_info.clone(),
+            token_program_info.clone(),
+        ],
+        &[escrow_seeds],
+    )?;
+
+    if let Some(mut node) = node {
+        node.credit_work(epoch, value).ok_or(RNDRError::MathError)?;
+        RNDREvent::WorkCredited(WorkCredited {
+            amount: value,
+            node: *node_account_info.key,
+            epoch,
+        })
+        .emit();
+
+        Node::pack_account(node, &mut node_account_info.try_borrow_mut_data()?)?;
+    }
+
+    // Optional accounts
+    if let Some(reward_pool_info) = account_info_iter.next() {
+        let epoch_work_info = next_account_info(account_info_iter)?;
+        let system_program_info = next_account_info(account_info_iter)?;
+
+        record_epoch_work(
+            program_id,
+            node_info,
+            escrow_info,
+            reward_pool_info,
+            epoch_work_info,
+            system_program_info,
+            work,
+        )?;
+
+        let multiplier_bps = config.reward_multiplier_bps(tier_stake);
+        if multiplier_bps != BASE_REWARD_MULTIPLIER_BPS {
+            RNDREvent::RewardMultiplierApplied(RewardMultiplierApplied { multiplier_bps }).emit();
+        }
+    }
+
+    record_stats(
+        program_id,
+        stats_info,
+        escrow_info.key,
+        TokenFlow::Disbursed,
+        amount,
+    )?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_set_challenge_window(
+    program_id: &Pubkey,
+    challenge_window: u64,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let escrow_info = next_account_info(account_info_iter)?;
+    let owner_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    let mut escrow = Escrow::unpack_account(&escrow_info.try_borrow_data()?)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if &escrow.owner != owner_info.key {
+        msg!("Escrow owner does not match the owner provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if !owner_info.is_signer {
+        msg!("Owner provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    escrow.challenge_window = challenge_window;
+
+    resize_account(
+        escrow_info,
+        Escrow::LEN,
+        owner_info,
+        &Rent::get()?,
+        system_program_info,
+    )?;
+    Escrow::pack_account(escrow, &mut escrow_info.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_set_proof_verifier(
+    program_id: &Pubkey,
+    proof_verifier: Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let escrow_info = next_account_info(account_info_iter)?;
+    let owner_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    check_escrow_owner(program_id, escrow_info, owner_info)?;
+    if &proof_verifier == program_id {
+        msg!("Proof verifier can't be the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let mut escrow = Escrow::unpack_account(&escrow_info.try_borrow_data()?)?;
+    escrow.proof_verifier = proof_verifier;
+
+    resize_account(
+        escrow_info,
+        Escrow::LEN,
+        owner_info,
+        &Rent::get()?,
+        system_program_info,
+    )?;
+    Escrow::pack_account(escrow, &mut escrow_info.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_set_arbitrator(
+    program_id: &Pubkey,
+    arbitrator: Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let escrow_info = next_account_info(account_info_iter)?;
+    let owner_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    check_escrow_owner(program_id, escrow_info, owner_info)?;
+
+    let mut escrow = Escrow::unpack_account(&escrow_info.try_borrow_data()?)?;
+    escrow.arbitrator = arbitrator;
+
+    resize_account(
+        escrow_info,
+        Escrow::LEN,
+        owner_info,
+        &Rent::get()?,
+        system_program_info,
+    )?;
+    Escrow::pack_account(escrow, &mut escrow_info.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_open_dispute(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Source accounts
+    let opener_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+    // Destination accounts
+    let job_info = next_account_info(account_info_iter)?;
+    let dispute_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if !opener_info.is_signer {
+        msg!("Opener provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    Escrow::unpack_account(&escrow_info.try_borrow_data()?)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let mut job = Job::unpack_account(&job_info.try_borrow_data()?)?;
+    if job_info.owner != program_id {
+        msg!("Job provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let (job_pubkey, _bump_seed) = Pubkey::find_program_address(
+        &[
+            b"job",
+            escrow_info.key.as_ref(),
+            job.address_authority().as_ref(),
+            &job_id_seed(job.id),
+        ],
+        program_id,
+    );
+    if &job_pubkey != job_info.key {
+        msg!("Job program derived address does not match the job address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if job.isolated {
+        msg!("Job provided holds its tokens in its own token account");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    match job.status {
+        JobStatus::Committed
+        | JobStatus::Revealed
+        | JobStatus::Challenged
+        | JobStatus::Disputed => {}
+        JobStatus::Open => {
+            msg!("Job result has not been committed to");
+            return Err(RNDRError::UnspecifiedError.into());
+        }
+        JobStatus::Paid => {
+            msg!("Job payment has already been claimed");
+            return Err(RNDRError::UnspecifiedError.into());
+        }
+        JobStatus::Resolved => {
+            msg!("Job dispute has already been resolved");
+            return Err(RNDRError::UnspecifiedError.into());
+        }
+        JobStatus::Cancelled => {
+            msg!("Job has been cancelled");
+            return Err(RNDRError::UnspecifiedError.into());
+        }
+    }
+    if &job.authority != opener_info.key && &job.node != opener_info.key {
+        msg!("Opener provided must be the job authority or node");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let config = load_config(program_id, config_info)?;
+    let now = Clock::get()?.unix_timestamp;
+    if job.status == JobStatus::Revealed && now >= dispute_deadline(&job, &config) {
+        msg!("Job dispute window has passed");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let bump_seed = check_dispute_address(program_id, job_info, dispute_info)?;
+    if !dispute_info.try_data_is_empty()? {
+        msg!("Dispute has already been opened for the job");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let dispute_seeds: &[&[_]] = &[b"dispute", job_info.key.as_ref(), &[bump_seed]];
+
+    create_program_account(
+        program_id,
+        opener_info,
+        dispute_info,
+        dispute_seeds,
+        Dispute::LEN,
+        &Rent::get()?,
+        system_program_info,
+    )?;
+
+    let dispute = Dispute::new(InitDisputeParams {
+        job: *job_info.key,
+        opener: *opener_info.key,
+        opened_at: now,
+    });
+    job.status = JobStatus::Disputed;
+
+    Dispute::pack(dispute, &mut dispute_info.try_borrow_mut_data()?)?;
+    Job::pack_account(job, &mut job_info.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_submit_evidence(
+    program_id: &Pubkey,
+    evidence_hash: [u8; HASH_BYTES],
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    if evidence_hash == [0; HASH_BYTES] {
+        msg!("Evidence hash can't be zero");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let account_info_iter = &mut accounts.iter();
+    // Source accounts
+    let party_info = next_account_info(account_info_iter)?;
+    let job_info = next_account_info(account_info_iter)?;
+    // Destination accounts
+    let dispute_info = next_account_info(account_info_iter)?;
+
+    if !party_info.is_signer {
+        msg!("Party provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let job = Job::unpack_account(&job_info.try_borrow_data()?)?;
+    if job_info.owner != program_id {
+        msg!("Job provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    check_dispute_address(program_id, job_info, dispute_info)?;
+    let mut dispute = load_dispute(program_id, dispute_info)?;
+    if dispute.is_resolved() {
+        msg!("Dispute has already been resolved");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    if &job.authority == party_info.key {
+        dispute.authority_evidence_hash = evidence_hash;
+    } else if &job.node == party_info.key {
+        dispute.node_evidence_hash = evidence_hash;
+    } else {
+        msg!("Party provided must be the job authority or node");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    Dispute::pack(dispute, &mut dispute_info.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_resolve_dispute(
+    program_id: &Pubkey,
+    node_amount: u64,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let escrow = *accounts
+        .get(1)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?
+        .key;
+    let (accounts, escrow_shard_infos) = split_escrow_shard_accounts(program_id, &escrow, accounts);
+    let account_info_iter = &mut accounts.iter();
+    // Source accounts
+    let arbitrator_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let escrow_associated_token_info = next_account_info(account_info_iter)?;
+    let job_info = next_account_info(account_info_iter)?;
+    let dispute_info = next_account_info(account_info_iter)?;
+    // Destination accounts
+    let node_token_info = next_account_info(account_info_iter)?;
+    let authority_token_info = next_account_info(account_info_iter)?;
+    // Programs
+    let token_program_info = next_account_info(account_info_iter)?;
+
+    if !arbitrator_info.is_signer {
+        msg!("Arbitrator provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let mut escrow = Escrow::unpack_account(&escrow_info.try_borrow_data()?)?;
+    resolve_legacy_escrow_token_mint(
+        program_id,
+        escrow_info,
+        &mut escrow,
+        escrow_associated_token_info,
+    )?;
+    check_escrow_allows_disbursing(&escrow)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if escrow.arbitrator() != Some(arbitrator_info.key) {
+        msg!("Escrow arbitrator does not match the arbitrator provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    let token_mint = escrow.token_mint;
+    let address_token_mint = *escrow.address_token_mint();
+    let escrow_id_seed = escrow_id_seed(escrow.escrow_id);
+
+    let (escrow_address, bump_seed) = Pubkey::find_program_address(
+        &[
+            b"escrow",
+            address_token_mint.as_ref(),
+            token_program_info.key.as_ref(),
+            &escrow_id_seed,
+        ],
+        program_id,
+    );
+    if &escrow_address != escrow_info.key {
+        msg!("Escrow program derived address does not match the escrow address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let escrow_seeds: &[&[_]] = &[
+        b"escrow",
+        address_token_mint.as_ref(),
+        token_program_info.key.as_ref(),
+        &escrow_id_seed,
+        &[bump_seed],
+    ];
+
+    if &escrow.token_account(&escrow_address) != escrow_associated_token_info.key {
+        msg!("Escrow token account does not match the token account provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let mut job = Job::unpack_account(&job_info.try_borrow_data()?)?;
+    check_job_mint_migrations(&job, &escrow)?;
+    check_job_not_frozen(&job)?;
+    if job_info.owner != program_id {
+        msg!("Job provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let (job_pubkey, _bump_seed) = Pubkey::find_program_address(
+        &[
+            b"job",
+            escrow_info.key.as_ref(),
+            job.address_authority().as_ref(),
+            &job_id_seed(job.id),
+        ],
+        program_id,
+    );
+    if &job_pubkey != job_info.key {
+        msg!("Job program derived address does not match the job address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if job.status != JobStatus::Disputed {
+        msg!("Job is not disputed");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    check_dispute_address(program_id, job_info, dispute_info)?;
+    if dispute_info.try_data_is_empty()? {
+        msg!("Dispute has not been opened for the job");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    let mut dispute = load_dispute(program_id, dispute_info)?;
+    if dispute.is_resolved() {
+        msg!("Dispute has already been resolved");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    if &get_associated_token_address(&job.node, &token_mint) != node_token_info.key {
+        msg!("Node associated token address does not match the token account provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if &get_associated_token_address(&job.authority, &token_mint) != authority_token_info.key {
+        msg!("Job authority associated token address does not match the token account provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let amount = job.amount;
+    let authority_amount = amount.checked_sub(node_amount).ok_or_else(|| {
+        msg!("Node amount exceeds the job amount");
+        RNDRError::UnspecifiedError
+    })?;
+
+    job.amount = 0;
+    job.status = JobStatus::Resolved;
+    aggregate_escrow_shards(program_id, escrow_info, &mut escrow, escrow_shard_infos)?;
+    escrow.amount = escrow
+        .amount
+        .checked_sub(amount)
+        .ok_or(RNDRError::MathError)?;
+    dispute.resolved_at = Clock::get()?.unix_timestamp;
+    dispute.node_amount = node_amount;
+    dispute.authority_amount = authority_amount;
+    msg!(
+        "Awarded {} tokens to the node and returned {} tokens to the job authority",
+        node_amount,
+        authority_amount
+    );
+
+    Job::pack_account(job, &mut job_info.try_borrow_mut_data()?)?;
+    Escrow::pack_account(escrow, &mut escrow_info.try_borrow_mut_data()?)?;
+    Dispute::pack(dispute, &mut dispute_info.try_borrow_mut_data()?)?;
+
+    for (destination_token_info, amount) in [
+        (node_token_info, node_amount),
+        (authority_token_info, authority_amount),
+    ] {
+        if amount == 0 {
+            continue;
+        }
+        invoke_signed(
+            &spl_token::instruction::transfer(
+                token_program_info.key,
+                escrow_associated_token_info.key,
+                destination_token_info.key,
+                escrow_info.key,
+                &[],
+                amount,
+            )?,
+            &[
+                escrow_associated_token_info.clone(),
+                destination_token_info.clone(),
+                escrow_info.clone(),
+                token_program_info.clone(),
+            ],
+            &[escrow_seeds],
+        )?;
+    }
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_init_config(
+    program_id: &Pubkey,
+    governance: Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Source accounts
+    let funder_info = next_account_info(account_info_iter)?;
+    // Destination accounts
+    let config_info = next_account_info(account_info_iter)?;
+    // Accounts
+    let program_data_info = next_account_info(account_info_iter)?;
+    let upgrade_authority_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if !upgrade_authority_info.is_signer {
+        msg!("Upgrade authority provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if program_upgrade_authority(program_id, program_data_info)?
+        != Some(*upgrade_authority_info.key)
+    {
+        msg!("Program upgrade authority does not match the upgrade authority provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let (config_address, bump_seed) = Pubkey::find_program_address(&[b"config"], program_id);
+    if &config_address != config_info.key {
+        msg!("Config program derived address does not match the config address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if !config_info.try_data_is_empty()? {
+        msg!("Config has already been created");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let config_seeds: &[&[_]] = &[b"config", &[bump_seed]];
+
+    create_program_account(
+        program_id,
+        funder_info,
+        config_info,
+        config_seeds,
+        Config::LEN,
+        &Rent::get()?,
+        system_program_info,
+    )?;
+
+    let config = Config::new(InitConfigParams { governance });
+
+    Config::pack(config, &mut config_info.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_set_config(
+    program_id: &Pubkey,
+    change: ConfigChange,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let config_info = next_account_info(account_info_iter)?;
+    let governance_info = next_account_info(account_info_iter)?;
+    // Optional accounts
+    let payer_info = account_info_iter.next();
+    let system_program_info = account_info_iter.next();
+
+    let mut config = load_config(program_id, config_info)?;
+    if &config.governance != governance_info.key {
+        msg!("Governance authority provided does not match the config governance authority");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if !governance_info.is_signer {
+        msg!("Governance authority provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    if config.is_timelocked(&change) {
+        msg!("Config field is timelocked, changes to it must be queued with QueueAction");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    change.validate()?;
+    let previous = config.apply(change);
+    RNDREvent::ConfigChanged(ConfigChanged { previous, change }).emit();
+
+    pack_config(config, config_info, payer_info, system_program_info)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_verify_upgrade_authority(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let config_info = next_account_info(account_info_iter)?;
+    let program_data_info = next_account_info(account_info_iter)?;
+
+    let config = load_config(program_id, config_info)?;
+    match program_upgrade_authority(program_id, program_data_info)? {
+        Some(upgrade_authority) if upgrade_authority == config.governance => {
+            msg!(
+                "Program upgrade authority is the config governance authority {}",
+                upgrade_authority
+            );
+        }
+        Some(upgrade_authority) => {
+            msg!(
+                "Program upgrade authority {} does not match the config governance authority {}",
+                upgrade_authority,
+                config.governance
+            );
+            return Err(RNDRError::UnspecifiedError.into());
+        }
+        None => {
+            msg!("Program is immutable");
+        }
+    }
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_set_approver(
+    program_id: &Pubkey,
+    approver: Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let escrow_info = next_account_info(account_info_iter)?;
+    let owner_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+    // Optional accounts
+    let current_approver_info = next_account_info(account_info_iter).ok();
+
+    check_escrow_owner(program_id, escrow_info, owner_info)?;
+
+    let mut escrow = Escrow::unpack_account(&escrow_info.try_borrow_data()?)?;
+    if approver == escrow.owner {
+        msg!("Approver must be a different authority than the escrow owner");
+        return Err(RNDRError::InvalidApprover.into());
+    }
+    // Otherwise the owner could replace or clear the approver to disburse without approval
+    if let Some(current_approver) = escrow.approver() {
+        let current_approver_info = match current_approver_info {
+            Some(current_approver_info) => current_approver_info,
+            None => {
+                msg!("Current approver must sign to change or clear the approver");
+                return Err(RNDRError::InvalidApprover.into());
+            }
+        };
+        if current_approver_info.key != current_approver {
+            msg!("Current approver provided does not match the approver of the escrow");
+            return Err(RNDRError::InvalidApprover.into());
+        }
+        if !current_approver_info.is_signer {
+            msg!("Current approver provided must be a signer");
+            return Err(RNDRError::InvalidApprover.into());
+        }
+    }
+    escrow.approver = approver;
+
+    resize_account(
+        escrow_info,
+        Escrow::LEN,
+        owner_info,
+        &Rent::get()?,
+        system_program_info,
+    )?;
+    Escrow::pack_account(escrow, &mut escrow_info.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_propose_disbursement(
+    program_id: &Pubkey,
+    proposal_id: u64,
+    amount: u64,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    if amount == 0 {
+        msg!("Amount of tokens to disburse can't be zero");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let account_info_iter = &mut accounts.iter();
+    // Source accounts
+    let owner_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let job_info = next_account_info(account_info_iter)?;
+    let destination_token_info = next_account_info(account_info_iter)?;
+    // Destination accounts
+    let proposal_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    check_escrow_owner(program_id, escrow_info, owner_info)?;
+
+    let escrow = Escrow::unpack_account(&escrow_info.try_borrow_data()?)?;
+    if escrow.approver().is_none() {
+        msg!("Escrow doesn't require disbursements to be approved");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    load_escrow_job(program_id, escrow_info, job_info)?;
+
+    let proposal_id_seed = proposal_id.to_le_bytes();
+    let (proposal_address, bump_seed) = Pubkey::find_program_address(
+        &[b"proposal", escrow_info.key.as_ref(), &proposal_id_seed],
+        program_id,
+    );
+    if &proposal_address != proposal_info.key {
+        msg!("Proposal program derived address does not match the proposal address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if !proposal_info.try_data_is_empty()? {
+        msg!("Proposal has already been created");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let proposal_seeds: &[&[_]] = &[
+        b"proposal",
+        escrow_info.key.as_ref(),
+        &proposal_id_seed,
+        &[bump_seed],
+    ];
+
+    create_program_account(
+        program_id,
+        owner_info,
+        proposal_info,
+        proposal_seeds,
+        Proposal::LEN,
+        &Rent::get()?,
+        system_program_info,
+    )?;
+
+    let proposal = Proposal::new(InitProposalParams {
+        escrow: *escrow_info.key,
+        id: proposal_id,
+        job: *job_info.key,
+        destination: *destination_token_info.key,
+        amount,
+    });
+
+    Proposal::pack(proposal, &mut proposal_info.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_approve_disbursement(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let approver_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let proposal_info = next_account_info(account_info_iter)?;
+
+    if !approver_info.is_signer {
+        msg!("Approver provided must be a signer");
+        return Err(RNDRError::InvalidApprover.into());
+    }
+
+    let escrow = Escrow::unpack_account(&escrow_info.try_borrow_data()?)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if escrow.approver() != Some(approver_info.key) {
+        msg!("Escrow approver does not match the approver provided");
+        return Err(RNDRError::InvalidApprover.into());
+    }
+
+    let mut proposal = load_proposal(program_id, escrow_info, proposal_info)?;
+    if proposal.is_approved() {
+        msg!("Proposal has already been approved");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    proposal.approved_at = Clock::get()?.unix_timestamp;
+
+    Proposal::pack(proposal, &mut proposal_info.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_execute_disbursement(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let (accounts, stats_info) = split_stats_account(program_id, accounts);
+    let escrow = *accounts
+        .first()
+        .ok_or(ProgramError::NotEnoughAccountKeys)?
+        .key;
+    let (accounts, escrow_shard_infos) = split_escrow_shard_accounts(program_id, &escrow, accounts);
+    let account_info_iter = &mut accounts.iter();
+    // Source accounts
+    let escrow_info = next_account_info(account_info_iter)?;
+    let source_token_info = next_account_info(account_info_iter)?;
+    let job_info = next_account_info(account_info_iter)?;
+    let proposal_info = next_account_info(account_info_iter)?;
+    // Destination accounts
+    let destination_token_info = next_account_info(account_info_iter)?;
+    // Programs
+    let token_program_info = next_account_info(account_info_iter)?;
+
+    check_distinct_accounts(&[
+        escrow_info,
+        source_token_info,
+        job_info,
+        proposal_info,
+        destination_token_info,
+    ])?;
+
+    let mut escrow = Escrow::unpack_account(&escrow_info.try_borrow_data()?)?;
+    resolve_legacy_escrow_token_mint(program_id, escrow_info, &mut escrow, source_token_info)?;
+    check_escrow_allows_disbursing(&escrow)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    let token_mint = escrow.token_mint;
+    let address_token_mint = *escrow.address_token_mint();
+    let escrow_id_seed = escrow_id_seed(escrow.escrow_id);
+
+    let (escrow_address, escrow_bump_seed) = Pubkey::find_program_address(
+        &[
+            b"escrow",
+            address_token_mint.as_ref(),
+            token_program_info.key.as_ref(),
+            &escrow_id_seed,
+        ],
+        program_id,
+    );
+    if &escrow_address != escrow_info.key {
+        msg!("Escrow program derived address does not match the escrow address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let mut proposal = load_proposal(program_id, escrow_info, proposal_info)?;
+    if !proposal.is_approved() {
+        msg!("Proposal has not been approved");
+        return Err(RNDRError::ProposalNotApproved.into());
+    }
+    if &proposal.job != job_info.key {
+        msg!("Proposal job does not match the job provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if &proposal.destination != destination_token_info.key {
+        msg!("Proposal destination does not match the token account provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let (mut job, job_bump_seed) = load_escrow_job(program_id, escrow_info, job_info)?;
+    check_job_mint_migrations(&job, &escrow)?;
+    check_job_not_frozen(&job)?;
+    if job.status == JobStatus::Disputed {
+        msg!("Job is disputed, so its funds can't be disbursed");
+        return Err(RNDRError::JobDisputed.into());
+    }
+
+    let authority = *job.address_authority();
+    let job_id_seed = job_id_seed(job.id);
+    let isolated = job.isolated;
+    let amount = proposal.amount;
+
+    let source_token_address = if isolated {
+        get_associated_token_address(job_info.key, &token_mint)
+    } else {
+        escrow.token_account(&escrow_address)
+    };
+    if &source_token_address != source_token_info.key {
+        msg!(
+            "Source associated token address does not match the associated token address provided"
+        );
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    emit_job_settled(job_info, &job, amount)?;
+    job.amount = job.amount.checked_sub(amount).ok_or(RNDRError::MathError)?;
+    if !isolated {
+        aggregate_escrow_shards(program_id, escrow_info, &mut escrow, escrow_shard_infos)?;
+        escrow.amount = escrow
+            .amount
+            .checked_sub(amount)
+            .ok_or(RNDRError::MathError)?;
+    }
+    proposal.executed_at = Clock::get()?.unix_timestamp;
+
+    Job::pack_account(job, &mut job_info.try_borrow_mut_data()?)?;
+    Escrow::pack_account(escrow, &mut escrow_info.try_borrow_mut_data()?)?;
+    Proposal::pack(proposal, &mut proposal_info.try_borrow_mut_data()?)?;
+
+    let escrow_seeds: &[&[_]] = &[
+        b"escrow",
+        address_token_mint.as_ref(),
+        token_program_info.key.as_ref(),
+        &escrow_id_seed,
+        &[escrow_bump_seed],
+    ];
+    let job_seeds: &[&[_]] = &[
+        b"job",
+        escrow_info.key.as_ref(),
+        authority.as_ref(),
+        &job_id_seed,
+        &[job_bump_seed],
+    ];
+    let (source_authority_info, source_authority_seeds) = if isolated {
+        (job_info, job_seeds)
+    } else {
+        (escrow_info, escrow_seeds)
+    };
+
+    invoke_signed(
+        &spl_token::instruction::transfer(
+            token_program_info.key,
+            source_token_info.key,
+            destination_token_info.key,
+            source_authority_info.key,
+            &[],
+            amount,
+        )?,
+        &[
+            source_token_info.clone(),
+            destination_token_info.clone(),
+            source_authority_info.clone(),
+            token_program_info.clone(),
+        ],
+        &[source_authority_seeds],
+    )?;
+
+    record_stats(
+        program_id,
+        stats_info,
+        escrow_info.key,
+        TokenFlow::Disbursed,
+        amount,
+    )?;
+
+    if isolated {
+        invariants::check_isolated_job_vault(job_info, source_token_info)?;
+    } else {
+        invariants::check_escrow_vault(escrow_info, source_token_info, &[job_info], &[])?;
+    }
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_queue_action(
+    program_id: &Pubkey,
+    action_id: u64,
+    action: AdminAction,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Source accounts
+    let authority_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+    let target_info = next_account_info(account_info_iter)?;
+    // Destination accounts
+    let queued_action_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    let config = load_config(program_id, config_info)?;
+    match action {
+        AdminAction::SetEscrowOwner(new_owner) => {
+            check_escrow_owner(program_id, target_info, authority_info)?;
+            if new_owner == Pubkey::default() {
+                msg!("New escrow owner must not be the default pubkey");
+                return Err(RNDRError::UnspecifiedError.into());
+            }
+
+            // Reallocate legacy escrows now, so the owner history can be written when the action
+            // is executed by anyone
+            resize_account(
+                target_info,
+                Escrow::LEN,
+                authority_info,
+                &Rent::get()?,
+                system_program_info,
+            )?;
+        }
+        AdminAction::SetConfig(change) => {
+            if target_info.key != config_info.key {
+                msg!("Target of a config change must be the config");
+                return Err(RNDRError::UnspecifiedError.into());
+            }
+            if &config.governance != authority_info.key {
+                msg!(
+                    "Governance authority provided does not match the config governance authority"
+                );
+                return Err(RNDRError::UnspecifiedError.into());
+            }
+            if !authority_info.is_signer {
+                msg!("Governance authority provided must be a signer");
+                return Err(RNDRError::UnspecifiedError.into());
+            }
+            change.validate()?;
+        }
+    }
+
+    let action_id_seed = action_id.to_le_bytes();
+    let (queued_action_address, bump_seed) = Pubkey::find_program_address(
+        &[b"queued_action", target_info.key.as_ref(), &action_id_seed],
+        program_id,
+    );
+    if &queued_action_address != queued_action_info.key {
+        msg!("Queued action program derived address does not match the queued action address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if !queued_action_info.try_data_is_empty()? {
+        msg!("Queued action has already been created");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let queued_action_seeds: &[&[_]] = &[
+        b"queued_action",
+        target_info.key.as_ref(),
+        &action_id_seed,
+        &[bump_seed],
+    ];
+
+    create_program_account(
+        program_id,
+        authority_info,
+        queued_action_info,
+        queued_action_seeds,
+        QueuedAction::LEN,
+        &Rent::get()?,
+        system_program_info,
+    )?;
+
+    let queued_at = Clock::get()?.unix_timestamp;
+    let timelock_duration = UnixTimestamp::try_from(config.timelock_duration).unwrap_or(i64::MAX);
+    let executable_at = queued_at.saturating_add(timelock_duration);
+    RNDREvent::ActionQueued(ActionQueued {
+        action,
+        executable_at,
+    })
+    .emit();
+    if let AdminAction::SetEscrowOwner(new_owner) = action {
+        RNDREvent::OwnerRotationScheduled(OwnerRotationScheduled {
+            escrow: *target_info.key,
+            previous_owner: *authority_info.key,
+            new_owner,
+            executable_at,
+        })
+        .emit();
+    }
+
+    let queued_action = QueuedAction::new(InitQueuedActionParams {
+        target: *target_info.key,
+        id: action_id,
+        authority: *authority_info.key,
+        action,
+        queued_at,
+        executable_at,
+    });
+
+    QueuedAction::pack(
+        queued_action,
+        &mut queued_action_info.try_borrow_mut_data()?,
+    )?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_schedule_owner_rotation(
+    program_id: &Pubkey,
+    rotation_id: u64,
+    new_owner: Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    // Owner rotations are queued actions, so they wait for the config timelock and can be
+    // cancelled by the guardian until then
+    process_queue_action(
+        program_id,
+        rotation_id,
+        AdminAction::SetEscrowOwner(new_owner),
+        accounts,
+    )
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_fund_reward_pool(
+    program_id: &Pubkey,
+    epoch: u64,
+    amount: u64,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    if amount == 0 {
+        msg!("Amount of tokens to fund can't be zero");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let account_info_iter = &mut accounts.iter();
+    // RNDR token mint
+    let token_mint_info = next_account_info(account_info_iter)?;
+    // Source accounts
+    let funder_info = next_account_info(account_info_iter)?;
+    let source_token_info = next_account_info(account_info_iter)?;
+    let authority_info = next_account_info(account_info_iter)?;
+    // Destination accounts
+    let escrow_info = next_account_info(account_info_iter)?;
+    let reward_pool_info = next_account_info(account_info_iter)?;
+    let reward_pool_associated_token_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+    let token_program_info = next_account_info(account_info_iter)?;
+    let associated_token_program_info = next_account_info(account_info_iter)?;
+
+    let mut escrow = Escrow::unpack_account(&escrow_info.try_borrow_data()?)?;
+    resolve_legacy_escrow_token_mint(program_id, escrow_info, &mut escrow, token_mint_info)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if &escrow.token_mint != token_mint_info.key {
+        msg!("Escrow token mint does not match the token mint provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if epoch < Clock::get()?.epoch {
+        msg!("Reward pools can't be funded once their epoch has ended");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let mut reward_pool = load_or_create_reward_pool(
+        program_id,
+        funder_info,
+        escrow_info,
+        epoch,
+        token_mint_info,
+        reward_pool_info,
+        reward_pool_associated_token_info,
+        system_program_info,
+        token_program_info,
+        associated_token_program_info,
+    )?;
+
+    invoke(
+        &spl_token::instruction::transfer(
+            token_program_info.key,
+            source_token_info.key,
+            reward_pool_associated_token_info.key,
+            authority_info.key,
+            &[],
+            amount,
+        )?,
+        &[
+            source_token_info.clone(),
+            reward_pool_associated_token_info.clone(),
+            authority_info.clone(),
+            token_program_info.clone(),
+        ],
+    )?;
+
+    reward_pool.amount = reward_pool
+        .amount
+        .checked_add(amount)
+        .ok_or(RNDRError::MathError)?;
+    RNDREvent::RewardPoolFunded(RewardPoolFunded { amount, epoch }).emit();
+
+    RewardPool::pack(reward_pool, &mut reward_pool_info.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_claim_epoch_reward(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Source accounts
+    let node_info = next_account_info(account_info_iter)?;
+    let reward_pool_info = next_account_info(account_info_iter)?;
+    let reward_pool_associated_token_info = next_account_info(account_info_iter)?;
+    let epoch_work_info = next_account_info(account_info_iter)?;
+    // Destination accounts
+    let destination_token_info = next_account_info(account_info_iter)?;
+    // Programs
+    let token_program_info = next_account_info(account_info_iter)?;
+
+    if !node_info.is_signer {
+        msg!("Node provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    claim_epoch_reward(
+        program_id,
+        node_info.key,
+        reward_pool_info,
+        reward_pool_associated_token_info,
+        epoch_work_info,
+        destination_token_info,
+        token_program_info,
+    )?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_publish_merkle_root(
+    program_id: &Pubkey,
+    epoch: u64,
+    root: [u8; HASH_BYTES],
+    node_count: u32,
+    amount: u64,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    if node_count == 0 {
+        msg!("Merkle distribution must have at least one node");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if amount == 0 {
+        msg!("Amount of tokens to distribute can't be zero");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let account_info_iter = &mut accounts.iter();
+    // RNDR token mint
+    let token_mint_info = next_account_info(account_info_iter)?;
+    // Source accounts
+    let owner_info = next_account_info(account_info_iter)?;
+    let source_token_info = next_account_info(account_info_iter)?;
+    let authority_info = next_account_info(account_info_iter)?;
+    // Destination accounts
+    let escrow_info = next_account_info(account_info_iter)?;
+    let merkle_distribution_info = next_account_info(account_info_iter)?;
+    let merkle_distribution_associated_token_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+    let token_program_info = next_account_info(account_info_iter)?;
+    let associated_token_program_info = next_account_info(account_info_iter)?;
+
+    let mut escrow = Escrow::unpack_account(&escrow_info.try_borrow_data()?)?;
+    resolve_legacy_escrow_token_mint(program_id, escrow_info, &mut escrow, token_mint_info)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if &escrow.owner != owner_info.key {
+        msg!("Escrow owner does not match the owner provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if !owner_info.is_signer {
+        msg!("Escrow owner provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if &escrow.token_mint != token_mint_info.key {
+        msg!("Escrow token mint does not match the token mint provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let bump_seed = check_merkle_distribution_address(
+        program_id,
+        escrow_info.key,
+        epoch,
+        merkle_distribution_info,
+    )?;
+    if !merkle_distribution_info.try_data_is_empty()? {
+        msg!("Merkle root has already been published for the epoch");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let merkle_distribution_associated_token_address =
+        get_associated_token_address(merkle_distribution_info.key, token_mint_info.key);
+    if &merkle_distribution_associated_token_address
+        != merkle_distribution_associated_token_info.key
+    {
+        msg!("Merkle distribution associated token address does not match the associated token address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let epoch_seed = epoch.to_le_bytes();
+    let merkle_distribution_seeds: &[&[_]] = &[
+        b"merkle_distribution",
+        escrow_info.key.as_ref(),
+        &epoch_seed,
+        &[bump_seed],
+    ];
+
+    create_program_account(
+        program_id,
+        owner_info,
+        merkle_distribution_info,
+        merkle_distribution_seeds,
+        MerkleDistribution::LEN,
+        &Rent::get()?,
+        system_program_info,
+    )?;
+
+    if merkle_distribution_associated_token_info.try_data_is_empty()? {
+        invoke(
+            &Instruction {
+                program_id: *associated_token_program_info.key,
+                accounts: vec![
+                    AccountMeta::new(*owner_info.key, true),
+                    AccountMeta::new(*merkle_distribution_associated_token_info.key, false),
+                    AccountMeta::new_readonly(*merkle_distribution_info.key, false),
+                    AccountMeta::new_readonly(*token_mint_info.key, false),
+                    AccountMeta::new_readonly(*system_program_info.key, false),
+                    AccountMeta::new_readonly(*token_program_info.key, false),
+                ],
+                data: vec![],
+            },
+            &[
+                owner_info.clone(),
+                merkle_distribution_associated_token_info.clone(),
+                merkle_distribution_info.clone(),
+                token_mint_info.clone(),
+                system_program_info.clone(),
+                token_program_info.clone(),
+            ],
+        )?;
+    }
+
+    invoke(
+        &spl_token::instruction::transfer(
+            token_program_info.key,
+            source_token_info.key,
+            merkle_distribution_associated_token_info.key,
+            authority_info.key,
+            &[],
+            amount,
+        )?,
+        &[
+            source_token_info.clone(),
+            merkle_distribution_associated_token_info.clone(),
+            authority_info.clone(),
+            token_program_info.clone(),
+        ],
+    )?;
+
+    let merkle_distribution = MerkleDistribution::new(InitMerkleDistributionParams {
+        escrow: *escrow_info.key,
+        epoch,
+        root,
+        node_count,
+        amount,
+    });
+    RNDREvent::MerkleRootPublished(MerkleRootPublished {
+        amount,
+        node_count,
+        epoch,
+    })
+    .emit();
+
+    MerkleDistribution::pack(
+        merkle_distribution,
+        &mut merkle_distribution_info.try_borrow_mut_data()?,
+    )?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_claim_merkle_reward(
+    program_id: &Pubkey,
+    index: u32,
+    amount: u64,
+    proof: &[[u8; HASH_BYTES]],
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Source accounts
+    let node_info = next_account_info(account_info_iter)?;
+    let merkle_distribution_info = next_account_info(account_info_iter)?;
+    let merkle_distribution_associated_token_info = next_account_info(account_info_iter)?;
+    let claim_bitmap_info = next_account_info(account_info_iter)?;
+    // Destination accounts
+    let destination_token_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+    let token_program_info = next_account_info(account_info_iter)?;
+
+    if !node_info.is_signer {
+        msg!("Node provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let mut merkle_distribution = load_merkle_distribution(program_id, merkle_distribution_info)?;
+    if index >= merkle_distribution.node_count {
+        msg!("Index is not a leaf of the Merkle distribution");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let chunk = ClaimBitmap::chunk_for_index(index);
+    let (claim_bitmap_address, bump_seed) = Pubkey::find_program_address(
+        &[
+            b"claim_bitmap",
+            merkle_distribution_info.key.as_ref(),
+            &chunk.to_le_bytes(),
+        ],
+        program_id,
+    );
+    if &claim_bitmap_address != claim_bitmap_info.key {
+        msg!(
+            "Claim bitmap program derived address does not match the claim bitmap address provided"
+        );
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let mut claim_bitmap = if claim_bitmap_info.try_data_is_empty()? {
+        let chunk_seed = chunk.to_le_bytes();
+        let claim_bitmap_seeds: &[&[_]] = &[
+            b"claim_bitmap",
+            merkle_distribution_info.key.as_ref(),
+            &chunk_seed,
+            &[bump_seed],
+        ];
+
+        create_program_account(
+            program_id,
+            node_info,
+            claim_bitmap_info,
+            claim_bitmap_seeds,
+            ClaimBitmap::LEN,
+            &Rent::get()?,
+            system_program_info,
+        )?;
+
+        ClaimBitmap::new(InitClaimBitmapParams {
+            merkle_distribution: *merkle_distribution_info.key,
+            chunk,
+        })
+    } else {
+        let claim_bitmap = ClaimBitmap::unpack(&claim_bitmap_info.try_borrow_data()?)?;
+        if claim_bitmap_info.owner != program_id {
+            msg!("Claim bitmap provided is not owned by the RNDR program");
+            return Err(RNDRError::UnspecifiedError.into());
+        }
+        claim_bitmap
+    };
+
+    if claim_bitmap.is_claimed(index) {
+        msg!("Merkle reward has already been claimed");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let leaf = MerkleDistribution::leaf(index, node_info.key, amount);
+    if !merkle_distribution.verify(proof, leaf) {
+        msg!("Merkle proof is invalid");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    claim_bitmap.set_claimed(index);
+    merkle_distribution.claimed = merkle_distribution
+        .claimed
+        .checked_add(amount)
+        .ok_or(RNDRError::MathError)?;
+    if merkle_distribution.claimed > merkle_distribution.amount {
+        msg!("Claims exceed the amount of the Merkle distribution");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    RNDREvent::MerkleRewardClaimed(MerkleRewardClaimed {
+        amount,
+        index,
+        epoch: merkle_distribution.epoch,
+    })
+    .emit();
+
+    let escrow = merkle_distribution.escrow;
+    let epoch_seed = merkle_distribution.epoch.to_le_bytes();
+    let bump_seed = check_merkle_distribution_address(
+        program_id,
+        &escrow,
+        merkle_distribution.epoch,
+        merkle_distribution_info,
+    )?;
+    let merkle_distribution_seeds: &[&[_]] = &[
+        b"merkle_distribution",
+        escrow.as_ref(),
+        &epoch_seed,
+        &[bump_seed],
+    ];
+
+    ClaimBitmap::pack(claim_bitmap, &mut claim_bitmap_info.try_borrow_mut_data()?)?;
+    MerkleDistribution::pack(
+        merkle_distribution,
+        &mut merkle_distribution_info.try_borrow_mut_data()?,
+    )?;
+
+    invoke_signed(
+        &spl_token::instruction::transfer(
+            token_program_info.key,
+            merkle_distribution_associated_token_info.key,
+            destination_token_info.key,
+            merkle_distribution_info.key,
+            &[],
+            amount,
+        )?,
+        &[
+            merkle_distribution_associated_token_info.clone(),
+            destination_token_info.clone(),
+            merkle_distribution_info.clone(),
+            token_program_info.clone(),
+        ],
+        &[merkle_distribution_seeds],
+    )?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_set_emission_schedule(
+    program_id: &Pubkey,
+    start_epoch: u64,
+    initial_amount: u64,
+    decay_bps: Rate,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let owner_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let emission_schedule_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    let escrow = Escrow::unpack_account(&escrow_info.try_borrow_data()?)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if &escrow.owner != owner_info.key {
+        msg!("Escrow owner does not match the owner provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if !owner_info.is_signer {
+        msg!("Escrow owner provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let bump_seed =
+        check_emission_schedule_address(program_id, escrow_info.key, emission_schedule_info)?;
+    let emission_schedule = if emission_schedule_info.try_data_is_empty()? {
+        let emission_schedule_seeds: &[&[_]] =
+            &[b"emission_schedule", escrow_info.key.as_ref(), &[bump_seed]];
+
+        create_program_account(
+            program_id,
+            owner_info,
+            emission_schedule_info,
+            emission_schedule_seeds,
+            EmissionSchedule::LEN,
+            &Rent::get()?,
+            system_program_info,
+        )?;
+
+        EmissionSchedule::new(InitEmissionScheduleParams {
+            escrow: *escrow_info.key,
+            start_epoch,
+            initial_amount,
+            decay_bps,
+        })
+    } else {
+        let mut emission_schedule = load_emission_schedule(program_id, emission_schedule_info)?;
+        emission_schedule.start_epoch = start_epoch;
+        emission_schedule.initial_amount = initial_amount;
+        emission_schedule.decay_bps = decay_bps;
+        emission_schedule.next_epoch = emission_schedule.next_epoch.max(start_epoch);
+        emission_schedule
+    };
+    RNDREvent::EmissionScheduleSet(EmissionScheduleSet {
+        initial_amount,
+        start_epoch,
+        decay_bps: decay_bps.bps(),
+    })
+    .emit();
+
+    EmissionSchedule::pack(
+        emission_schedule,
+        &mut emission_schedule_info.try_borrow_mut_data()?,
+    )?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_emit_rewards(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let (accounts, stats_info) = split_stats_account(program_id, accounts);
+    let account_info_iter = &mut accounts.iter();
+    // RNDR token mint
+    let token_mint_info = next_account_info(account_info_iter)?;
+    // Source accounts
+    let funder_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let emission_schedule_info = next_account_info(account_info_iter)?;
+    let emission_schedule_associated_token_info = next_account_info(account_info_iter)?;
+    // Destination accounts
+    let reward_pool_info = next_account_info(account_info_iter)?;
+    let reward_pool_associated_token_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+    let token_program_info = next_account_info(account_info_iter)?;
+    let associated_token_program_info = next_account_info(account_info_iter)?;
+
+    let mut escrow = Escrow::unpack_account(&escrow_info.try_borrow_data()?)?;
+    resolve_legacy_escrow_token_mint(program_id, escrow_info, &mut escrow, token_mint_info)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if &escrow.token_mint != token_mint_info.key {
+        msg!("Escrow token mint does not match the token mint provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let bump_seed =
+        check_emission_schedule_address(program_id, escrow_info.key, emission_schedule_info)?;
+    let mut emission_schedule = load_emission_schedule(program_id, emission_schedule_info)?;
+
+    let epoch = Clock::get()?.epoch;
+    if epoch < emission_schedule.next_epoch {
+        msg!("Rewards of the epoch have already been emitted or haven't started");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    let amount = emission_schedule.emission(epoch);
+    if amount == 0 {
+        msg!("Emission schedule has no rewards to emit for the epoch");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let emission_schedule_associated_token_address =
+        get_associated_token_address(emission_schedule_info.key, token_mint_info.key);
+    if &emission_schedule_associated_token_address != emission_schedule_associated_token_info.key {
+        msg!("Emission schedule associated token address does not match the associated token address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let mut reward_pool = load_or_create_reward_pool(
+        program_id,
+        funder_info,
+        escrow_info,
+        epoch,
+        token_mint_info,
+        reward_pool_info,
+        reward_pool_associated_token_info,
+        system_program_info,
+        token_program_info,
+        associated_token_program_info,
+    )?;
+
+    let emission_schedule_seeds: &[&[_]] =
+        &[b"emission_schedule", escrow_info.key.as_ref(), &[bump_seed]];
+
+    invoke_signed(
+        &spl_token::instruction::transfer(
+            token_program_info.key,
+            emission_schedule_associated_token_info.key,
+            reward_pool_associated_token_info.key,
+            emission_schedule_info.key,
+            &[],
+            amount,
+        )?,
+        &[
+            emission_schedule_associated_token_info.clone(),
+            reward_pool_associated_token_info.clone(),
+            emission_schedule_info.clone(),
+            token_program_info.clone(),
+        ],
+        &[emission_schedule_seeds],
+    )?;
+
+    reward_pool.amount = reward_pool
+        .amount
+        .checked_add(amount)
+        .ok_or(RNDRError::MathError)?;
+    emission_schedule.emitted = emission_schedule
+        .emitted
+        .checked_add(amount)
+        .ok_or(RNDRError::MathError)?;
+    emission_schedule.next_epoch = epoch.checked_add(1).ok_or(RNDRError::MathError)?;
+    RNDREvent::RewardsEmitted(RewardsEmitted { amount, epoch }).emit();
+
+    pay_crank_bounty(program_id, escrow_info.key, account_info_iter)?;
+
+    RewardPool::pack(reward_pool, &mut reward_pool_info.try_borrow_mut_data()?)?;
+    EmissionSchedule::pack(
+        emission_schedule,
+        &mut emission_schedule_info.try_borrow_mut_data()?,
+    )?;
+
+    record_stats(
+        program_id,
+        stats_info,
+        escrow_info.key,
+        TokenFlow::Emitted,
+        amount,
+    )?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_stake(program_id: &Pubkey, amount: u64, accounts: &[AccountInfo]) -> ProgramResult {
+    if amount == 0 {
+        msg!("Amount of tokens to stake can't be zero");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let account_info_iter = &mut accounts.iter();
+    // RNDR token mint
+    let token_mint_info = next_account_info(account_info_iter)?;
+    // Source accounts
+    let node_authority_info = next_account_info(account_info_iter)?;
+    let source_token_info = next_account_info(account_info_iter)?;
+    let authority_info = next_account_info(account_info_iter)?;
+    // Destination accounts
+    let escrow_info = next_account_info(account_info_iter)?;
+    let node_info = next_account_info(account_info_iter)?;
+    let node_associated_token_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+    let token_program_info = next_account_info(account_info_iter)?;
+    let associated_token_program_info = next_account_info(account_info_iter)?;
+
+    if !node_authority_info.is_signer {
+        msg!("Node authority provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let mut escrow = Escrow::unpack_account(&escrow_info.try_borrow_data()?)?;
+    resolve_legacy_escrow_token_mint(program_id, escrow_info, &mut escrow, token_mint_info)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if &escrow.token_mint != token_mint_info.key {
+        msg!("Escrow token mint does not match the token mint provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let bump_seed = check_node_address(
+        program_id,
+        escrow_info.key,
+        node_authority_info.key,
+        node_info,
+    )?;
+    let mut node = if node_info.try_data_is_empty()? {
+        let node_seeds: &[&[_]] = &[
+            b"node",
+            escrow_info.key.as_ref(),
+            node_authority_info.key.as_ref(),
+            &[bump_seed],
+        ];
+
+        create_program_account(
+            program_id,
+            node_authority_info,
+            node_info,
+            node_seeds,
+            Node::LEN,
+            &Rent::get()?,
+            system_program_info,
+        )?;
+
+        Node::new(InitNodeParams {
+            escrow: *escrow_info.key,
+            authority: *node_authority_info.key,
+        })
+    } else {
+        load_node(program_id, node_info)?
+    };
+
+    check_node_associated_token_address(
+        node_info,
+        token_mint_info.key,
+        node_associated_token_info,
+    )?;
+    if node_associated_token_info.try_data_is_empty()? {
+        invoke(
+            &Instruction {
+                program_id: *associated_token_program_info.key,
+                accounts: vec![
+                    AccountMeta::new(*node_authority_info.key, true),
+                    AccountMeta::new(*node_associated_token_info.key, false),
+                    AccountMeta::new_readonly(*node_info.key, false),
+                    AccountMeta::new_readonly(*token_mint_info.key, false),
+                    AccountMeta::new_readonly(*system_program_info.key, false),
+                    AccountMeta::new_readonly(*token_program_info.key, false),
+                ],
+                data: vec![],
+            },
+            &[
+                node_authority_info.clone(),
+                node_associated_token_info.clone(),
+                node_info.clone(),
+                token_mint_info.clone(),
+                system_program_info.clone(),
+                token_program_info.clone(),
+            ],
+        )?;
+    }
+
+    invoke(
+        &spl_token::instruction::transfer(
+            token_program_info.key,
+            source_token_info.key,
+            node_associated_token_info.key,
+            authority_info.key,
+            &[],
+            amount,
+        )?,
+        &[
+            source_token_info.clone(),
+            node_associated_token_info.clone(),
+            authority_info.clone(),
+            token_program_info.clone(),
+        ],
+    )?;
+
+    node.record_stake(Clock::get()?.epoch, amount)
+        .ok_or(RNDRError::MathError)?;
+    RNDREvent::Staked(Staked { amount }).emit();
+
+    resize_account(
+        node_info,
+        Node::LEN,
+        node_authority_info,
+        &Rent::get()?,
+        system_program_info,
+    )?;
+    Node::pack_account(node, &mut node_info.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_unstake(program_id: &Pubkey, amount: u64, accounts: &[AccountInfo]) -> ProgramResult {
+    if amount == 0 {
+        msg!("Amount of tokens to withdraw can't be zero");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let account_info_iter = &mut accounts.iter();
+    // Source accounts
+    let node_authority_info = next_account_info(account_info_iter)?;
+    let node_info = next_account_info(account_info_iter)?;
+    let node_associated_token_info = next_account_info(account_info_iter)?;
+    // Destination accounts
+    let destination_token_info = next_account_info(account_info_iter)?;
+    // Programs
+    let token_program_info = next_account_info(account_info_iter)?;
+
+    if !node_authority_info.is_signer {
+        msg!("Node authority provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let mut node = load_node(program_id, node_info)?;
+    if &node.authority != node_authority_info.key {
+        msg!("Node authority does not match the node authority provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    let bump_seed = check_node_address(program_id, &node.escrow, &node.authority, node_info)?;
+
+    node.record_unstake(amount).ok_or_else(|| {
+        msg!("Node doesn't have enough tokens staked");
+        RNDRError::UnspecifiedError
+    })?;
+    RNDREvent::Unstaked(Unstaked { amount }).emit();
+
+    let escrow = node.escrow;
+    let node_seeds: &[&[_]] = &[
+        b"node",
+        escrow.as_ref(),
+        node_authority_info.key.as_ref(),
+        &[bump_seed],
+    ];
+
+    Node::pack_account(node, &mut node_info.try_borrow_mut_data()?)?;
+
+    invoke_signed(
+        &spl_token::instruction::transfer(
+            token_program_info.key,
+            node_associated_token_info.key,
+            destination_token_info.key,
+            node_info.key,
+            &[],
+            amount,
+        )?,
+        &[
+            node_associated_token_info.clone(),
+            destination_token_info.clone(),
+            node_info.clone(),
+            token_program_info.clone(),
+        ],
+        &[node_seeds],
+    )?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_set_auto_compound(
+    program_id: &Pubkey,
+    auto_compound: bool,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let node_authority_info = next_account_info(account_info_iter)?;
+    let node_info = next_account_info(account_info_iter)?;
+
+    if !node_authority_info.is_signer {
+        msg!("Node authority provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let mut node = load_node(program_id, node_info)?;
+    if &node.authority != node_authority_info.key {
+        msg!("Node authority does not match the node authority provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    node.auto_compound = auto_compound;
+    RNDREvent::AutoCompoundSet(AutoCompoundSet { auto_compound }).emit();
+
+    Node::pack_account(node, &mut node_info.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_compound_rewards(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // RNDR token mint
+    let token_mint_info = next_account_info(account_info_iter)?;
+    // Source accounts
+    let reward_pool_info = next_account_info(account_info_iter)?;
+    let reward_pool_associated_token_info = next_account_info(account_info_iter)?;
+    let epoch_work_info = next_account_info(account_info_iter)?;
+    // Destination accounts
+    let node_info = next_account_info(account_info_iter)?;
+    let node_associated_token_info = next_account_info(account_info_iter)?;
+    // Programs
+    let token_program_info = next_account_info(account_info_iter)?;
+
+    let mut node = load_node(program_id, node_info)?;
+    if !node.auto_compound {
+        msg!("Node has not enabled auto-compounding");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if load_reward_pool(program_id, reward_pool_info)?.escrow != node.escrow {
+        msg!("Reward pool escrow does not match the node escrow");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    check_node_associated_token_address(
+        node_info,
+        token_mint_info.key,
+        node_associated_token_info,
+    )?;
+
+    let reward = claim_epoch_reward(
+        program_id,
+        &node.authority,
+        reward_pool_info,
+        reward_pool_associated_token_info,
+        epoch_work_info,
+        node_associated_token_info,
+        token_program_info,
+    )?;
+
+    node.stake = node.stake.checked_add(reward).ok_or(RNDRError::MathError)?;
+    RNDREvent::RewardsCompounded(RewardsCompounded { reward }).emit();
+
+    pay_crank_bounty(program_id, &node.escrow, account_info_iter)?;
+
+    Node::pack_account(node, &mut node_info.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_liquid_stake(
+    program_id: &Pubkey,
+    amount: u64,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    if amount == 0 {
+        msg!("Amount of tokens to stake can't be zero");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let account_info_iter = &mut accounts.iter();
+    // RNDR token mint
+    let token_mint_info = next_account_info(account_info_iter)?;
+    // Source accounts
+    let funder_info = next_account_info(account_info_iter)?;
+    let source_token_info = next_account_info(account_info_iter)?;
+    let authority_info = next_account_info(account_info_iter)?;
+    // Destination accounts
+    let escrow_info = next_account_info(account_info_iter)?;
+    let stake_pool_info = next_account_info(account_info_iter)?;
+    let stake_pool_associated_token_info = next_account_info(account_info_iter)?;
+    let receipt_mint_info = next_account_info(account_info_iter)?;
+    let receipt_owner_info = next_account_info(account_info_iter)?;
+    let receipt_owner_associated_token_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+    let token_program_info = next_account_info(account_info_iter)?;
+    let associated_token_program_info = next_account_info(account_info_iter)?;
+
+    let mut escrow = Escrow::unpack_account(&escrow_info.try_borrow_data()?)?;
+    resolve_legacy_escrow_token_mint(program_id, escrow_info, &mut escrow, token_mint_info)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if &escrow.token_mint != token_mint_info.key {
+        msg!("Escrow token mint does not match the token mint provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let bump_seed = check_stake_pool_address(program_id, escrow_info.key, stake_pool_info)?;
+    let stake_pool_seeds: &[&[_]] = &[b"stake_pool", escrow_info.key.as_ref(), &[bump_seed]];
+
+    let (receipt_mint_address, receipt_mint_bump_seed) = Pubkey::find_program_address(
+        &[b"stake_receipt_mint", stake_pool_info.key.as_ref()],
+        program_id,
+    );
+    if &receipt_mint_address != receipt_mint_info.key {
+        msg!("Stake receipt mint program derived address does not match the receipt mint address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let mut stake_pool = if stake_pool_info.try_data_is_empty()? {
+        let rent = &Rent::get()?;
+        create_program_account(
+            program_id,
+            funder_info,
+            stake_pool_info,
+            stake_pool_seeds,
+            StakePool::LEN,
+            rent,
+            system_program_info,
+        )?;
+
+        let receipt_mint_seeds: &[&[_]] = &[
+            b"stake_receipt_mint",
+            stake_pool_info.key.as_ref(),
+            &[receipt_mint_bump_seed],
+        ];
+        create_program_account(
+            token_program_info.key,
+            funder_info,
+            receipt_mint_info,
+            receipt_mint_seeds,
+            spl_token::state::Mint::LEN,
+            rent,
+            system_program_info,
+        )?;
+
+        // Receipts have the precision of the staked tokens
+        let decimals =
+            spl_token::state::Mint::unpack(&token_mint_info.try_borrow_data()?)?.decimals;
+        invoke(
+            &spl_token::instruction::initialize_mint2(
+                token_program_info.key,
+                receipt_mint_info.key,
+                stake_pool_info.key,
+                None,
+                decimals,
+            )?,
+            &[receipt_mint_info.clone(), token_program_info.clone()],
+        )?;
+
+        let stake_pool_associated_token_address =
+            get_associated_token_address(stake_pool_info.key, token_mint_info.key);
+        if &stake_pool_associated_token_address != stake_pool_associated_token_info.key {
+            msg!("Stake pool associated token address does not match the associated token address provided");
+            return Err(ProgramError::InvalidSeeds);
+        }
+
+        invoke(
+            &Instruction {
+                program_id: *associated_token_program_info.key,
+                accounts: vec![
+                    AccountMeta::new(*funder_info.key, true),
+                    AccountMeta::new(*stake_pool_associated_token_info.key, false),
+                    AccountMeta::new_readonly(*stake_pool_info.key, false),
+                    AccountMeta::new_readonly(*token_mint_info.key, false),
+                    AccountMeta::new_readonly(*system_program_info.key, false),
+                    AccountMeta::new_readonly(*token_program_info.key, false),
+                ],
+                data: vec![],
+            },
+            &[
+                funder_info.clone(),
+                stake_pool_associated_token_info.clone(),
+                stake_pool_info.clone(),
+                token_mint_info.clone(),
+                system_program_info.clone(),
+                token_program_info.clone(),
+            ],
+        )?;
+
+        StakePool::new(InitStakePoolParams {
+            escrow: *escrow_info.key,
+            token_mint: *token_mint_info.key,
+            receipt_mint: *receipt_mint_info.key,
+        })
+    } else {
+        load_stake_pool(
+            program_id,
+            stake_pool_info,
+            stake_pool_associated_token_info,
+        )?
+    };
+
+    let receipt_owner_associated_token_address =
+        get_associated_token_address(receipt_owner_info.key, receipt_mint_info.key);
+    if &receipt_owner_associated_token_address != receipt_owner_associated_token_info.key {
+        msg!("Receipt owner associated token address does not match the associated token address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    if receipt_owner_associated_token_info.try_data_is_empty()? {
+        invoke(
+            &Instruction {
+                program_id: *associated_token_program_info.key,
+                accounts: vec![
+                    AccountMeta::new(*funder_info.key, true),
+                    AccountMeta::new(*receipt_owner_associated_token_info.key, false),
+                    AccountMeta::new_readonly(*receipt_owner_info.key, false),
+                    AccountMeta::new_readonly(*receipt_mint_info.key, false),
+                    AccountMeta::new_readonly(*system_program_info.key, false),
+                    AccountMeta::new_readonly(*token_program_info.key, false),
+                ],
+                data: vec![],
+            },
+            &[
+                funder_info.clone(),
+                receipt_owner_associated_token_info.clone(),
+                receipt_owner_info.clone(),
+                receipt_mint_info.clone(),
+                system_program_info.clone(),
+                token_program_info.clone(),
+            ],
+        )?;
+    }
+
+    let receipt_amount = stake_pool
+        .receipt_amount(amount)
+        .ok_or(RNDRError::MathError)?;
+    if receipt_amount == 0 {
+        msg!("Amount of tokens to stake is too small for a receipt");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    invoke(
+        &spl_token::instruction::transfer(
+            token_program_info.key,
+            source_token_info.key,
+            stake_pool_associated_token_info.key,
+            authority_info.key,
+            &[],
+            amount,
+        )?,
+        &[
+            source_token_info.clone(),
+            stake_pool_associated_token_info.clone(),
+            authority_info.clone(),
+            token_program_info.clone(),
+        ],
+    )?;
+
+    invoke_signed(
+        &spl_token::instruction::mint_to(
+            token_program_info.key,
+            receipt_mint_info.key,
+            receipt_owner_associated_token_info.key,
+            stake_pool_info.key,
+            &[],
+            receipt_amount,
+        )?,
+        &[
+            receipt_mint_info.clone(),
+            receipt_owner_associated_token_info.clone(),
+            stake_pool_info.clone(),
+            token_program_info.clone(),
+        ],
+        &[stake_pool_seeds],
+    )?;
+
+    stake_pool.total_staked = stake_pool
+        .total_staked
+        .checked_add(amount)
+        .ok_or(RNDRError::MathError)?;
+    stake_pool.receipt_supply = stake_pool
+        .receipt_supply
+        .checked_add(receipt_amount)
+        .ok_or(RNDRError::MathError)?;
+    RNDREvent::LiquidStaked(LiquidStaked {
+        amount,
+        receipt_amount,
+    })
+    .emit();
+
+    StakePool::pack(stake_pool, &mut stake_pool_info.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_liquid_unstake(
+    program_id: &Pubkey,
+    receipt_amount: u64,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    if receipt_amount == 0 {
+        msg!("Amount of receipt tokens to burn can't be zero");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let account_info_iter = &mut accounts.iter();
+    // Source accounts
+    let stake_pool_info = next_account_info(account_info_iter)?;
+    let stake_pool_associated_token_info = next_account_info(account_info_iter)?;
+    let receipt_mint_info = next_account_info(account_info_iter)?;
+    let source_receipt_token_info = next_account_info(account_info_iter)?;
+    let receipt_authority_info = next_account_info(account_info_iter)?;
+    // Destination accounts
+    let destination_token_info = next_account_info(account_info_iter)?;
+    // Programs
+    let token_program_info = next_account_info(account_info_iter)?;
+
+    let mut stake_pool = load_stake_pool(
+        program_id,
+        stake_pool_info,
+        stake_pool_associated_token_info,
+    )?;
+    if &stake_pool.receipt_mint != receipt_mint_info.key {
+        msg!("Stake pool receipt mint does not match the receipt mint provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let amount = stake_pool
+        .stake_amount(receipt_amount)
+        .ok_or(RNDRError::MathError)?;
+    if amount == 0 {
+        msg!("Amount of receipt tokens to burn is too small to withdraw");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    invoke(
+        &spl_token::instruction::burn(
+            token_program_info.key,
+            source_receipt_token_info.key,
+            receipt_mint_info.key,
+            receipt_authority_info.key,
+            &[],
+            receipt_amount,
+        )?,
+        &[
+            source_receipt_token_info.clone(),
+            receipt_mint_info.clone(),
+            receipt_authority_info.clone(),
+            token_program_info.clone(),
+        ],
+    )?;
+
+    stake_pool.total_staked = stake_pool
+        .total_staked
+        .checked_sub(amount)
+        .ok_or(RNDRError::MathError)?;
+    stake_pool.receipt_supply = stake_pool
+        .receipt_supply
+        .checked_sub(receipt_amount)
+        .ok_or(RNDRError::MathError)?;
+    RNDREvent::LiquidUnstaked(LiquidUnstaked {
+        amount,
+        receipt_amount,
+    })
+    .emit();
+
+    let escrow = stake_pool.escrow;
+    let bump_seed = check_stake_pool_address(program_id, &escrow, stake_pool_info)?;
+    let stake_pool_seeds: &[&[_]] = &[b"stake_pool", escrow.as_ref(), &[bump_seed]];
+
+    StakePool::pack(stake_pool, &mut stake_pool_info.try_borrow_mut_data()?)?;
+
+    invoke_signed(
+        &spl_token::instruction::transfer(
+            token_program_info.key,
+            stake_pool_associated_token_info.key,
+            destination_token_info.key,
+            stake_pool_info.key,
+            &[],
+            amount,
+        )?,
+        &[
+            stake_pool_associated_token_info.clone(),
+            destination_token_info.clone(),
+            stake_pool_info.clone(),
+            token_program_info.clone(),
+        ],
+        &[stake_pool_seeds],
+    )?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_deposit_stake_rewards(
+    program_id: &Pubkey,
+    amount: u64,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    if amount == 0 {
+        msg!("Amount of tokens to deposit can't be zero");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let account_info_iter = &mut accounts.iter();
+    // Source accounts
+    let source_token_info = next_account_info(account_info_iter)?;
+    let authority_info = next_account_info(account_info_iter)?;
+    // Destination accounts
+    let stake_pool_info = next_account_info(account_info_iter)?;
+    let stake_pool_associated_token_info = next_account_info(account_info_iter)?;
+    // Programs
+    let token_program_info = next_account_info(account_info_iter)?;
+
+    let mut stake_pool = load_stake_pool(
+        program_id,
+        stake_pool_info,
+        stake_pool_associated_token_info,
+    )?;
+    if stake_pool.receipt_supply == 0 {
+        msg!("Stake pool has no receipts to accrue rewards to");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    invoke(
+        &spl_token::instruction::transfer(
+            token_program_info.key,
+            source_token_info.key,
+            stake_pool_associated_token_info.key,
+            authority_info.key,
+            &[],
+            amount,
+        )?,
+        &[
+            source_token_info.clone(),
+            stake_pool_associated_token_info.clone(),
+            authority_info.clone(),
+            token_program_info.clone(),
+        ],
+    )?;
+
+    stake_pool.total_staked = stake_pool
+        .total_staked
+        .checked_add(amount)
+        .ok_or(RNDRError::MathError)?;
+    RNDREvent::StakeRewardsDeposited(StakeRewardsDeposited { amount }).emit();
+
+    StakePool::pack(stake_pool, &mut stake_pool_info.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_set_crank_bounty(
+    program_id: &Pubkey,
+    bounty: u64,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let owner_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let crank_vault_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    let escrow = Escrow::unpack_account(&escrow_info.try_borrow_data()?)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if &escrow.owner != owner_info.key {
+        msg!("Escrow owner does not match the owner provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if !owner_info.is_signer {
+        msg!("Escrow owner provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let bump_seed = check_crank_vault_address(program_id, escrow_info.key, crank_vault_info)?;
+    let crank_vault = if crank_vault_info.try_data_is_empty()? {
+        let crank_vault_seeds: &[&[_]] = &[b"crank_vault", escrow_info.key.as_ref(), &[bump_seed]];
+
+        create_program_account(
+            program_id,
+            owner_info,
+            crank_vault_info,
+            crank_vault_seeds,
+            CrankVault::LEN,
+            &Rent::get()?,
+            system_program_info,
+        )?;
+
+        CrankVault::new(InitCrankVaultParams {
+            escrow: *escrow_info.key,
+            bounty,
+        })
+    } else {
+        let mut crank_vault = load_crank_vault(program_id, crank_vault_info)?;
+        crank_vault.bounty = bounty;
+        crank_vault
+    };
+    RNDREvent::CrankBountySet(CrankBountySet { bounty }).emit();
+
+    CrankVault::pack(crank_vault, &mut crank_vault_info.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_set_treasury_burn_cap(
+    program_id: &Pubkey,
+    epoch_burn_cap: u64,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // RNDR token mint
+    let token_mint_info = next_account_info(account_info_iter)?;
+    // Accounts
+    let owner_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let treasury_info = next_account_info(account_info_iter)?;
+    let treasury_associated_token_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+    let token_program_info = next_account_info(account_info_iter)?;
+    let associated_token_program_info = next_account_info(account_info_iter)?;
+
+    let mut escrow = Escrow::unpack_account(&escrow_info.try_borrow_data()?)?;
+    resolve_legacy_escrow_token_mint(program_id, escrow_info, &mut escrow, token_mint_info)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if &escrow.token_mint != token_mint_info.key {
+        msg!("Escrow token mint does not match the token mint provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if &escrow.owner != owner_info.key {
+        msg!("Escrow owner does not match the owner provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if !owner_info.is_signer {
+        msg!("Escrow owner provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let bump_seed = check_treasury_address(program_id, escrow_info.key, treasury_info)?;
+    let treasury = if treasury_info.try_data_is_empty()? {
+        let treasury_seeds: &[&[_]] = &[b"treasury", escrow_info.key.as_ref(), &[bump_seed]];
+
+        create_program_account(
+            program_id,
+            owner_info,
+            treasury_info,
+            treasury_seeds,
+            Treasury::LEN,
+            &Rent::get()?,
+            system_program_info,
+        )?;
+
+        let treasury_associated_token_address =
+            get_associated_token_address(treasury_info.key, token_mint_info.key);
+        if &treasury_associated_token_address != treasury_associated_token_info.key {
+            msg!("Treasury associated token address does not match the associated token address provided");
+            return Err(ProgramError::InvalidSeeds);
+        }
+
+        if treasury_associated_token_info.try_data_is_empty()? {
+            invoke(
+                &Instruction {
+                    program_id: *associated_token_program_info.key,
+                    accounts: vec![
+                        AccountMeta::new(*owner_info.key, true),
+                        AccountMeta::new(*treasury_associated_token_info.key, false),
+                        AccountMeta::new_readonly(*treasury_info.key, false),
+                        AccountMeta::new_readonly(*token_mint_info.key, false),
+                        AccountMeta::new_readonly(*system_program_info.key, false),
+                        AccountMeta::new_readonly(*token_program_info.key, false),
+                    ],
+                    data: vec![],
+                },
+                &[
+                    owner_info.clone(),
+                    treasury_associated_token_info.clone(),
+                    treasury_info.clone(),
+                    token_mint_info.clone(),
+                    system_program_info.clone(),
+                    token_program_info.clone(),
+                ],
+            )?;
+        }
+
+        Treasury::new(InitTreasuryParams {
+            escrow: *escrow_info.key,
+            token_mint: *token_mint_info.key,
+            epoch_burn_cap,
+        })
+    } else {
+        let mut treasury =
+            load_treasury(program_id, treasury_info, treasury_associated_token_info)?;
+        treasury.epoch_burn_cap = epoch_burn_cap;
+        treasury
+    };
+    RNDREvent::TreasuryBurnCapSet(TreasuryBurnCapSet { epoch_burn_cap }).emit();
+
+    Treasury::pack_account(treasury, &mut treasury_info.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_burn_treasury(
+    program_id: &Pubkey,
+    amount: u64,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    if amount == 0 {
+        msg!("Amount of tokens to burn can't be zero");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let (accounts, stats_info) = split_stats_account(program_id, accounts);
+    let account_info_iter = &mut accounts.iter();
+    // RNDR token mint
+    let token_mint_info = next_account_info(account_info_iter)?;
+    // Accounts
+    let owner_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let treasury_info = next_account_info(account_info_iter)?;
+    let treasury_associated_token_info = next_account_info(account_info_iter)?;
+    // Programs
+    let token_program_info = next_account_info(account_info_iter)?;
+
+    let escrow = Escrow::unpack_account(&escrow_info.try_borrow_data()?)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if &escrow.owner != owner_info.key {
+        msg!("Escrow owner does not match the owner provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if !owner_info.is_signer {
+        msg!("Escrow owner provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let bump_seed = check_treasury_address(program_id, escrow_info.key, treasury_info)?;
+    let mut treasury = load_treasury(program_id, treasury_info, treasury_associated_token_info)?;
+    if &treasury.token_mint != token_mint_info.key {
+        msg!("Treasury token mint does not match the token mint provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let epoch = Clock::get()?.epoch;
+    if amount > treasury.burnable(epoch) {
+        msg!("Amount of tokens to burn exceeds the epoch burn cap of the treasury");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let treasury_seeds: &[&[_]] = &[b"treasury", escrow_info.key.as_ref(), &[bump_seed]];
+
+    invoke_signed(
+        &spl_token::instruction::burn(
+            token_program_info.key,
+            treasury_associated_token_info.key,
+            token_mint_info.key,
+            treasury_info.key,
+            &[],
+            amount,
+        )?,
+        &[
+            treasury_associated_token_info.clone(),
+            token_mint_info.clone(),
+            treasury_info.clone(),
+            token_program_info.clone(),
+        ],
+        &[treasury_seeds],
+    )?;
+
+    treasury
+        .record_burn(epoch, amount)
+        .ok_or(RNDRError::MathError)?;
+    RNDREvent::TreasuryBurned(TreasuryBurned { amount, epoch }).emit();
+
+    Treasury::pack_account(treasury, &mut treasury_info.try_borrow_mut_data()?)?;
+
+    record_stats(
+        program_id,
+        stats_info,
+        escrow_info.key,
+        TokenFlow::Burned,
+        amount,
+    )?;
+
+    invariants::check_treasury_vault(treasury_info, treasury_associated_token_info)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_init_stats(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let funder_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let stats_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    Escrow::unpack_account(&escrow_info.try_borrow_data()?)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let bump_seed = check_stats_address(program_id, escrow_info.key, stats_info)?;
+    if !stats_info.try_data_is_empty()? {
+        msg!("Stats of the escrow already exist");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let stats_seeds: &[&[_]] = &[b"stats", escrow_info.key.as_ref(), &[bump_seed]];
+
+    create_program_account(
+        program_id,
+        funder_info,
+        stats_info,
+        stats_seeds,
+        Stats::LEN,
+        &Rent::get()?,
+        system_program_info,
+    )?;
+
+    let stats = Stats::new(InitStatsParams {
+        escrow: *escrow_info.key,
+        epoch: Clock::get()?.epoch,
+    });
+
+    Stats::pack(stats, &mut stats_info.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_fund_job_with_voucher(
+    program_id: &Pubkey,
+    amount: u64,
+    job_id: u64,
+    nonce: u64,
+    expires_at: UnixTimestamp,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    if amount == 0 {
+        msg!("Amount of tokens to fund can't be zero");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let (accounts, job_index_infos) = split_job_index_accounts(program_id, accounts);
+    let account_info_iter = &mut accounts.iter();
+    // Source accounts
+    let relayer_info = next_account_info(account_info_iter)?;
+    let source_token_info = next_account_info(account_info_iter)?;
+    let authority_info = next_account_info(account_info_iter)?;
+    // Destination accounts
+    let escrow_info = next_account_info(account_info_iter)?;
+    let escrow_associated_token_info = next_account_info(account_info_iter)?;
+    let job_info = next_account_info(account_info_iter)?;
+    let escrow_shard_info = next_account_info(account_info_iter)?;
+    // Accounts
+    let voucher_nonce_info = next_account_info(account_info_iter)?;
+    // Sysvars
+    let instructions_sysvar_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+    let token_program_info = next_account_info(account_info_iter)?;
+
+    if Clock::get()?.unix_timestamp > expires_at {
+        msg!("Voucher has expired");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let mut escrow = Escrow::unpack_account(&escrow_info.try_borrow_data()?)?;
+    resolve_legacy_escrow_token_mint(
+        program_id,
+        escrow_info,
+        &mut escrow,
+        escrow_associated_token_info,
+    )?;
+    check_escrow_allows_funding(&escrow)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    let address_token_mint = *escrow.address_token_mint();
+    let escrow_id_seed = escrow_id_seed(escrow.escrow_id);
+
+    let (escrow_address, bump_seed) = Pubkey::find_program_address(
+        &[
+            b"escrow",
+            address_token_mint.as_ref(),
+            token_program_info.key.as_ref(),
+            &escrow_id_seed,
+        ],
+        program_id,
+    );
+    if &escrow_address != escrow_info.key {
+        msg!("Escrow program derived address does not match the escrow address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let escrow_seeds: &[&[_]] = &[
+        b"escrow",
+        address_token_mint.as_ref(),
+        token_program_info.key.as_ref(),
+        &escrow_id_seed,
+        &[bump_seed],
+    ];
+
+    if &escrow.token_account(&escrow_address) != escrow_associated_token_info.key {
+        msg!("Escrow token account does not match the token account provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let source_token = spl_token::state::Account::unpack(&source_token_info.try_borrow_data()?)?;
+    if &source_token.owner != authority_info.key {
+        msg!("Source token account provided is not owned by the Job authority");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let voucher = Voucher {
+        escrow: *escrow_info.key,
+        authority: *authority_info.key,
+        job_id,
+        amount,
+        nonce,
+        expires_at,
+    };
+    // A program derived authority signs through invoke_signed instead of an ed25519 signature
+    if !authority_info.is_signer {
+        check_ed25519_signature(
+            instructions_sysvar_info,
+            authority_info.key,
+            &voucher.message(),
+        )?;
+    }
+
+    let rent = &Rent::get()?;
+
+    let voucher_nonce_bump_seed = check_voucher_nonce_address(
+        program_id,
+        escrow_info.key,
+        authority_info.key,
+        voucher_nonce_info,
+    )?;
+    let mut voucher_nonce = if voucher_nonce_info.try_data_is_empty()? {
+        let voucher_nonce_seeds: &[&[_]] = &[
+            b"voucher_nonce",
+            escrow_info.key.as_ref(),
+            authority_info.key.as_ref(),
+            &[voucher_nonce_bump_seed],
+        ];
+
+        create_program_account(
+            program_id,
+            relayer_info,
+            voucher_nonce_info,
+            voucher_nonce_seeds,
+            VoucherNonce::LEN,
+            rent,
+            system_program_info,
+        )?;
+
+        VoucherNonce::new(InitVoucherNonceParams {
+            escrow: *escrow_info.key,
+            authority: *authority_info.key,
+        })
+    } else {
+        load_voucher_nonce(program_id, voucher_nonce_info)?
+    };
+
+    if voucher_nonce.nonce != nonce {
+        msg!(
+            "Voucher nonce {} does not match the next nonce {} of the authority",
+            nonce,
+            voucher_nonce.nonce
+        );
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    voucher_nonce.nonce = voucher_nonce
+        .nonce
+        .checked_add(1)
+        .ok_or(RNDRError::MathError)?;
+
+    let mut job = load_or_create_job(
+        program_id,
+        relayer_info,
+        escrow_info,
+        &escrow,
+        authority_info.key,
+        job_info,
+        job_id,
+        false,
+        job_index_infos,
+        rent,
+        system_program_info,
+    )?;
+
+    let mut escrow_shard = load_or_create_escrow_shard(
+        program_id,
+        relayer_info,
+        escrow_info,
+        job_info,
+        escrow_shard_info,
+        rent,
+        system_program_info,
+    )?;
+
+    // The Escrow transfers the tokens as the delegate the authority approved for its token account
+    invoke_signed(
+        &spl_token::instruction::transfer(
+            token_program_info.key,
+            source_token_info.key,
+            escrow_associated_token_info.key,
+            escrow_info.key,
+            &[],
+            amount,
+        )?,
+        &[
+            source_token_info.clone(),
+            escrow_associated_token_info.clone(),
+            escrow_info.clone(),
+            token_program_info.clone(),
+        ],
+        &[escrow_seeds],
+    )?;
+
+    job.amount = job.amount.checked_add(amount).ok_or(RNDRError::MathError)?;
+    escrow_shard.amount = escrow_shard
+        .amount
+        .checked_add(amount)
+        .ok_or(RNDRError::MathError)?;
+    RNDREvent::VoucherUsed(VoucherUsed {
+        nonce,
+        authority: *authority_info.key,
+        job_id,
+    })
+    .emit();
+
+    Job::pack_account(job, &mut job_info.try_borrow_mut_data()?)?;
+    EscrowShard::pack(escrow_shard, &mut escrow_shard_info.try_borrow_mut_data()?)?;
+    VoucherNonce::pack(
+        voucher_nonce,
+        &mut voucher_nonce_info.try_borrow_mut_data()?,
+    )?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_register_session_key(
+    program_id: &Pubkey,
+    session_key: Pubkey,
+    expiry_slot: Slot,
+    instruction_mask: u64,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let authority_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let session_key_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if !authority_info.is_signer {
+        msg!("Authority provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if instruction_mask & !SESSION_KEY_INSTRUCTIONS != 0 {
+        msg!("Instruction mask allows instructions session keys can't sign");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    Escrow::unpack_account(&escrow_info.try_borrow_data()?)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let bump_seed = check_session_key_address(
+        program_id,
+        escrow_info.key,
+        authority_info.key,
+        &session_key,
+        session_key_info,
+    )?;
+    let session_key_account = if session_key_info.try_data_is_empty()? {
+        let session_key_seeds: &[&[_]] = &[
+            b"session_key",
+            escrow_info.key.as_ref(),
+            authority_info.key.as_ref(),
+            session_key.as_ref(),
+            &[bump_seed],
+        ];
+
+        create_program_account(
+            program_id,
+            authority_info,
+            session_key_info,
+            session_key_seeds,
+            SessionKey::LEN,
+            &Rent::get()?,
+            system_program_info,
+        )?;
+
+        SessionKey::new(InitSessionKeyParams {
+            escrow: *escrow_info.key,
+            authority: *authority_info.key,
+            session_key,
+            expiry_slot,
+            instruction_mask,
+        })
+    } else {
+        let mut session_key_account = load_session_key(program_id, session_key_info)?;
+        session_key_account.expiry_slot = expiry_slot;
+        session_key_account.instruction_mask = instruction_mask;
+        session_key_account
+    };
+    RNDREvent::SessionKeyRegistered(SessionKeyRegistered {
+        session_key,
+        expiry_slot,
+        instruction_mask,
+    })
+    .emit();
+
+    SessionKey::pack(
+        session_key_account,
+        &mut session_key_info.try_borrow_mut_data()?,
+    )?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_approve_delegate(
+    program_id: &Pubkey,
+    delegate: Pubkey,
+    limit: u64,
+    expiry_slot: Slot,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let escrow_info = next_account_info(account_info_iter)?;
+    let owner_info = next_account_info(account_info_iter)?;
+    let delegate_allowance_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    check_escrow_owner(program_id, escrow_info, owner_info)?;
+    if &delegate == owner_info.key {
+        msg!("Delegate must be a different authority than the escrow owner");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let bump_seed = check_delegate_allowance_address(
+        program_id,
+        escrow_info.key,
+        &delegate,
+        delegate_allowance_info,
+    )?;
+    let delegate_allowance = if delegate_allowance_info.try_data_is_empty()? {
+        let delegate_allowance_seeds: &[&[_]] = &[
+            b"delegate_allowance",
+            escrow_info.key.as_ref(),
+            delegate.as_ref(),
+            &[bump_seed],
+        ];
+
+        create_program_account(
+            program_id,
+            owner_info,
+            delegate_allowance_info,
+            delegate_allowance_seeds,
+            DelegateAllowance::LEN,
+            &Rent::get()?,
+            system_program_info,
+        )?;
+
+        DelegateAllowance::new(InitDelegateAllowanceParams {
+            escrow: *escrow_info.key,
+            delegate,
+            limit,
+            expiry_slot,
+        })
+    } else {
+        let mut delegate_allowance = load_delegate_allowance(program_id, delegate_allowance_info)?;
+        delegate_allowance.remaining = limit;
+        delegate_allowance.expiry_slot = expiry_slot;
+        delegate_allowance
+    };
+    RNDREvent::DelegateApproved(DelegateApproved {
+        delegate,
+        limit,
+        expiry_slot,
+    })
+    .emit();
+
+    DelegateAllowance::pack(
+        delegate_allowance,
+        &mut delegate_allowance_info.try_borrow_mut_data()?,
+    )?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_revoke_delegate(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let escrow_info = next_account_info(account_info_iter)?;
+    let owner_info = next_account_info(account_info_iter)?;
+    let delegate_allowance_info = next_account_info(account_info_iter)?;
+
+    check_escrow_owner(program_id, escrow_info, owner_info)?;
+
+    let delegate_allowance = load_delegate_allowance(program_id, delegate_allowance_info)?;
+    check_delegate_allowance_address(
+        program_id,
+        escrow_info.key,
+        &delegate_allowance.delegate,
+        delegate_allowance_info,
+    )?;
+    RNDREvent::DelegateRevoked(DelegateRevoked {
+        delegate: delegate_allowance.delegate,
+    })
+    .emit();
+
+    // Closing the allowance returns its rent to the owner, and the runtime deletes the account
+    // once it has no lamports left
+    **owner_info.try_borrow_mut_lamports()? = owner_info
+        .lamports()
+        .checked_add(delegate_allowance_info.lamports())
+        .ok_or(RNDRError::MathError)?;
+    **delegate_allowance_info.try_borrow_mut_lamports()? = 0;
+    delegate_allowance_info.try_borrow_mut_data()?.fill(0);
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_create_subscription(
+    program_id: &Pubkey,
+    job_id: u64,
+    amount: u64,
+    period: Slot,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    if amount == 0 {
+        msg!("Amount of tokens to fund each period can't be zero");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if period == 0 {
+        msg!("Subscription period can't be zero");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let authority_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let source_token_info = next_account_info(account_info_iter)?;
+    let subscription_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if !authority_info.is_signer {
+        msg!("Authority provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    Escrow::unpack_account(&escrow_info.try_borrow_data()?)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let bump_seed = check_subscription_address(
+        program_id,
+        escrow_info.key,
+        authority_info.key,
+        job_id,
+        subscription_info,
+    )?;
+    let subscription = if subscription_info.try_data_is_empty()? {
+        let job_id_seed = job_id.to_le_bytes();
+        let subscription_seeds: &[&[_]] = &[
+            b"subscription",
+            escrow_info.key.as_ref(),
+            authority_info.key.as_ref(),
+            &job_id_seed,
+            &[bump_seed],
+        ];
+
+        create_program_account(
+            program_id,
+            authority_info,
+            subscription_info,
+            subscription_seeds,
+            Subscription::LEN,
+            &Rent::get()?,
+            system_program_info,
+        )?;
+
+        Subscription::new(InitSubscriptionParams {
+            escrow: *escrow_info.key,
+            authority: *authority_info.key,
+            job_id,
+            source_token: *source_token_info.key,
+            amount,
+            period,
+            start_slot: Clock::get()?.slot,
+        })
+    } else {
+        let mut subscription = load_subscription(program_id, subscription_info)?;
+        subscription.source_token = *source_token_info.key;
+        subscription.amount = amount;
+        subscription.period = period;
+        subscription
+    };
+    RNDREvent::SubscriptionCreated(SubscriptionCreated {
+        amount,
+        period,
+        job_id,
+    })
+    .emit();
+
+    Subscription::pack(subscription, &mut subscription_info.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_process_subscription(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let (accounts, job_index_infos) = split_job_index_accounts(program_id, accounts);
+    let account_info_iter = &mut accounts.iter();
+    // Source accounts
+    let funder_info = next_account_info(account_info_iter)?;
+    let subscription_info = next_account_info(account_info_iter)?;
+    let source_token_info = next_account_info(account_info_iter)?;
+    // Destination accounts
+    let escrow_info = next_account_info(account_info_iter)?;
+    let escrow_associated_token_info = next_account_info(account_info_iter)?;
+    let job_info = next_account_info(account_info_iter)?;
+    let escrow_shard_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+    let token_program_info = next_account_info(account_info_iter)?;
+
+    let mut escrow = Escrow::unpack_account(&escrow_info.try_borrow_data()?)?;
+    resolve_legacy_escrow_token_mint(
+        program_id,
+        escrow_info,
+        &mut escrow,
+        escrow_associated_token_info,
+    )?;
+    check_escrow_allows_funding(&escrow)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    let address_token_mint = *escrow.address_token_mint();
+    let escrow_id_seed = escrow_id_seed(escrow.escrow_id);
+
+    let (escrow_address, _bump_seed) = Pubkey::find_program_address(
+        &[
+            b"escrow",
+            address_token_mint.as_ref(),
+            token_program_info.key.as_ref(),
+            &escrow_id_seed,
+        ],
+        program_id,
+    );
+    if &escrow_address != escrow_info.key {
+        msg!("Escrow program derived address does not match the escrow address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    if &escrow.token_account(&escrow_address) != escrow_associated_token_info.key {
+        msg!("Escrow token account does not match the token account provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let mut subscription = load_subscription(program_id, subscription_info)?;
+    let bump_seed = check_subscription_address(
+        program_id,
+        escrow_info.key,
+        &subscription.authority,
+        subscription.job_id,
+        subscription_info,
+    )?;
+    if &subscription.source_token != source_token_info.key {
+        msg!("Source token account provided is not the source token account of the subscription");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if !subscription.is_due(Clock::get()?.slot) {
+        msg!(
+            "Subscription is not due until slot {}",
+            subscription.next_slot
+        );
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    subscription.advance().ok_or(RNDRError::MathError)?;
+
+    let rent = &Rent::get()?;
+
+    let mut job = load_or_create_job(
+        program_id,
+        funder_info,
+        escrow_info,
+        &escrow,
+        &subscription.authority,
+        job_info,
+        subscription.job_id,
+        false,
+        job_index_infos,
+        rent,
+        system_program_info,
+    )?;
+
+    let mut escrow_shard = load_or_create_escrow_shard(
+        program_id,
+        funder_info,
+        escrow_info,
+        job_info,
+        escrow_shard_info,
+        rent,
+        system_program_info,
+    )?;
+
+    let job_id_seed = subscription.job_id.to_le_bytes();
+    let subscription_seeds: &[&[_]] = &[
+        b"subscription",
+        escrow_info.key.as_ref(),
+        subscription.authority.as_ref(),
+        &job_id_seed,
+        &[bump_seed],
+    ];
+
+    // The Subscription transfers the tokens as the delegate the authority approved for its token
+    // account
+    invoke_signed(
+        &spl_token::instruction::transfer(
+            token_program_info.key,
+            source_token_info.key,
+            escrow_associated_token_info.key,
+            subscription_info.key,
+            &[],
+            subscription.amount,
+        )?,
+        &[
+            source_token_info.clone(),
+            escrow_associated_token_info.clone(),
+            subscription_info.clone(),
+            token_program_info.clone(),
+        ],
+        &[subscription_seeds],
+    )?;
+
+    job.amount = job
+        .amount
+        .checked_add(subscription.amount)
+        .ok_or(RNDRError::MathError)?;
+    escrow_shard.amount = escrow_shard
+        .amount
+        .checked_add(subscription.amount)
+        .ok_or(RNDRError::MathError)?;
+    RNDREvent::SubscriptionProcessed(SubscriptionProcessed {
+        amount: subscription.amount,
+        job_id: subscription.job_id,
+        next_slot: subscription.next_slot,
+    })
+    .emit();
+
+    Job::pack_account(job, &mut job_info.try_borrow_mut_data()?)?;
+    EscrowShard::pack(escrow_shard, &mut escrow_shard_info.try_borrow_mut_data()?)?;
+    Subscription::pack(subscription, &mut subscription_info.try_borrow_mut_data()?)?;
+
+    pay_crank_bounty(program_id, escrow_info.key, account_info_iter)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_cancel_job(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let escrow = *accounts
+        .get(1)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?
+        .key;
+    let (accounts, escrow_shard_infos) = split_escrow_shard_accounts(program_id, &escrow, accounts);
+    let account_info_iter = &mut accounts.iter();
+    // Source accounts
+    let authority_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let escrow_associated_token_info = next_account_info(account_info_iter)?;
+    let job_info = next_account_info(account_info_iter)?;
+    // Destination accounts
+    let destination_token_info = next_account_info(account_info_iter)?;
+    // Accounts
+    let config_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+    let token_program_info = next_account_info(account_info_iter)?;
+    // Optional accounts
+    let node_token_info = account_info_iter.next();
+    let treasury_info = account_info_iter.next();
+    let treasury_associated_token_info = account_info_iter.next();
+    let token_mint_info = account_info_iter.next();
+
+    check_distinct_accounts(&[
+        escrow_info,
+        escrow_associated_token_info,
+        job_info,
+        destination_token_info,
+        config_info,
+    ])?;
+
+    if !authority_info.is_signer {
+        msg!("Job authority provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let mut escrow = Escrow::unpack_account(&escrow_info.try_borrow_data()?)?;
+    resolve_legacy_escrow_token_mint(
+        program_id,
+        escrow_info,
+        &mut escrow,
+        escrow_associated_token_info,
+    )?;
+    check_escrow_allows_disbursing(&escrow)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    let address_token_mint = *escrow.address_token_mint();
+    let escrow_id_seed = escrow_id_seed(escrow.escrow_id);
+
+    let (escrow_address, bump_seed) = Pubkey::find_program_address(
+        &[
+            b"escrow",
+            address_token_mint.as_ref(),
+            token_program_info.key.as_ref(),
+            &escrow_id_seed,
+        ],
+        program_id,
+    );
+    if &escrow_address != escrow_info.key {
+        msg!("Escrow program derived address does not match the escrow address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let escrow_seeds: &[&[_]] = &[
+        b"escrow",
+        address_token_mint.as_ref(),
+        token_program_info.key.as_ref(),
+        &escrow_id_seed,
+        &[bump_seed],
+    ];
+
+    if &escrow.token_account(&escrow_address) != escrow_associated_token_info.key {
+        msg!("Escrow token account does not match the token account provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let mut job = Job::unpack_account(&job_info.try_borrow_data()?)?;
+    check_job_mint_migrations(&job, &escrow)?;
+    check_job_not_frozen(&job)?;
+    if job_info.owner != program_id {
+        msg!("Job provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let (job_pubkey, _bump_seed) = Pubkey::find_program_address(
+        &[
+            b"job",
+            escrow_info.key.as_ref(),
+            job.address_authority().as_ref(),
+            &job_id_seed(job.id),
+        ],
+        program_id,
+    );
+    if &job_pubkey != job_info.key {
+        msg!("Job program derived address does not match the job address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if &job.authority != authority_info.key {
+        msg!("Job authority does not match the authority provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if job.isolated {
+        msg!("Job provided holds its tokens in its own token account");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let config = load_config(program_id, config_info)?;
+
+    let amount = job.amount;
+    let exchange_rate = job.exchange_rate();
+    let split = match job.status {
+        JobStatus::Open => Split {
+            portions: [amount, 0],
+            treasury: 0,
+            burn: 0,
+        }
+        .try_sweep_dust(
+            config
+                .min_disbursement_in(exchange_rate)
+                .ok_or(RNDRError::MathError)?,
+        )?,
+        JobStatus::Committed => config
+            .cancellation_split(
+                amount,
+                job.funded_at,
+                Clock::get()?.unix_timestamp,
+                exchange_rate,
+            )
+            .ok_or(RNDRError::MathError)?,
+        JobStatus::Revealed | JobStatus::Paid => {
+            msg!("Job result has already been revealed");
+            return Err(RNDRError::UnspecifiedError.into());
+        }
+        JobStatus::Challenged | JobStatus::Disputed | JobStatus::Resolved => {
+            msg!("Job is disputed");
+            return Err(RNDRError::JobDisputed.into());
+        }
+        JobStatus::Cancelled => {
+            msg!("Job has already been cancelled");
+            return Err(RNDRError::UnspecifiedError.into());
+        }
+    };
+    let [refund, fee] = split.portions;
+    let node = job.node;
+    let token_mint = escrow.token_mint;
+
+    emit_job_settled(job_info, &job, amount)?;
+    job.amount = 0;
+    job.status = JobStatus::Cancelled;
+    aggregate_escrow_shards(program_id, escrow_info, &mut escrow, escrow_shard_infos)?;
+    escrow.amount = escrow
+        .amount
+        .checked_sub(amount)
+        .ok_or(RNDRError::MathError)?;
+
+    resize_account(
+        job_info,
+        Job::LEN,
+        authority_info,
+        &Rent::get()?,
+        system_program_info,
+    )?;
+    Job::pack_account(job, &mut job_info.try_borrow_mut_data()?)?;
+    Escrow::pack_account(escrow, &mut escrow_info.try_borrow_mut_data()?)?;
+
+    if refund > 0 {
+        invoke_signed(
+            &spl_token::instruction::transfer(
+                token_program_info.key,
+                escrow_associated_token_info.key,
+                destination_token_info.key,
+                escrow_info.key,
+                &[],
+                refund,
+            )?,
+            &[
+                escrow_associated_token_info.clone(),
+                destination_token_info.clone(),
+                escrow_info.clone(),
+                token_program_info.clone(),
+            ],
+            &[escrow_seeds],
+        )?;
+    }
+
+    if fee > 0 {
+        let node_token_info = node_token_info.ok_or_else(|| {
+            msg!("Node token account must be provided to pay the cancellation fee");
+            ProgramError::NotEnoughAccountKeys
+        })?;
+        let node_token = spl_token::state::Account::unpack(&node_token_info.try_borrow_data()?)?;
+        if node_token.owner != node {
+            msg!("Node token account provided is not owned by the Job's node");
+            return Err(RNDRError::UnspecifiedError.into());
+        }
+
+        invoke_signed(
+            &spl_token::instruction::transfer(
+                token_program_info.key,
+                escrow_associated_token_info.key,
+                node_token_info.key,
+                escrow_info.key,
+                &[],
+                fee,
+            )?,
+            &[
+                escrow_associated_token_info.clone(),
+                node_token_info.clone(),
+                escrow_info.clone(),
+                token_program_info.clone(),
+            ],
+            &[escrow_seeds],
+        )?;
+    }
+
+    if split.treasury > 0 {
+        let (treasury_info, treasury_associated_token_info) = treasury_info
+            .zip(treasury_associated_token_info)
+            .ok_or_else(|| {
+                msg!("Treasury accounts must be provided to pay the remainder or dust of the cancellation");
+                ProgramError::NotEnoughAccountKeys
+            })?;
+        check_treasury_address(program_id, escrow_info.key, treasury_info)?;
+        let treasury = load_treasury(program_id, treasury_info, treasury_associated_token_info)?;
+        if treasury.token_mint != token_mint {
+            msg!("Treasury token mint does not match the token mint of the escrow");
+            return Err(RNDRError::UnspecifiedError.into());
+        }
+
+        invoke_signed(
+            &spl_token::instruction::transfer(
+                token_program_info.key,
+                escrow_associated_token_info.key,
+                treasury_associated_token_info.key,
+                escrow_info.key,
+                &[],
+                split.treasury,
+            )?,
+            &[
+                escrow_associated_token_info.clone(),
+                treasury_associated_token_info.clone(),
+                escrow_info.clone(),
+                token_program_info.clone(),
+            ],
+            &[escrow_seeds],
+        )?;
+    }
+
+    if split.burn > 0 {
+        let token_mint_info = token_mint_info.ok_or_else(|| {
+            msg!("Token mint must be provided to burn the remainder of the cancellation");
+            ProgramError::NotEnoughAccountKeys
+        })?;
+        if &token_mint != token_mint_info.key {
+            msg!("Escrow token mint does not match the token mint provided");
+            return Err(RNDRError::UnspecifiedError.into());
+        }
+
+        invoke_signed(
+            &spl_token::instruction::burn(
+                token_program_info.key,
+                escrow_associated_token_info.key,
+                token_mint_info.key,
+                escrow_info.key,
+                &[],
+                split.burn,
+            )?,
+            &[
+                escrow_associated_token_info.clone(),
+                token_mint_info.clone(),
+                escrow_info.clone(),
+                token_program_info.clone(),
+            ],
+            &[escrow_seeds],
+        )?;
+    }
+
+    RNDREvent::JobCancelled(JobCancelled {
+        job: *job_info.key,
+        refund,
+        fee,
+        node,
+    })
+    .emit();
+
+    invariants::check_escrow_vault(escrow_info, escrow_associated_token_info, &[job_info], &[])?;
+    if split.treasury > 0 {
+        if let Some((treasury_info, treasury_associated_token_info)) =
+            treasury_info.zip(treasury_associated_token_info)
+        {
+            invariants::check_treasury_vault(treasury_info, treasury_associated_token_info)?;
+        }
+    }
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_transfer_job_authority(
+    program_id: &Pubkey,
+    new_authority: Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let authority_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let job_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if !authority_info.is_signer {
+        msg!("Job authority provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let (mut job, _bump_seed) = load_escrow_job(program_id, escrow_info, job_info)?;
+    if &job.authority != authority_info.key {
+        msg!("Job authority does not match the authority provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if new_authority == job.authority {
+        msg!("New authority is already the job authority");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    job.pending_authority = new_authority;
+
+    resize_account(
+        job_info,
+        Job::LEN,
+        authority_info,
+        &Rent::get()?,
+        system_program_info,
+    )?;
+    Job::pack_account(job, &mut job_info.try_borrow_mut_data()?)?;
+
+    RNDREvent::JobAuthorityTransferStarted(JobAuthorityTransferStarted {
+        job: *job_info.key,
+        new_authority,
+    })
+    .emit();
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_update_job_metadata(
+    program_id: &Pubkey,
+    metadata: JobMetadata,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let authority_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let job_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if !authority_info.is_signer {
+        msg!("Job authority provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let (mut job, _bump_seed) = load_escrow_job(program_id, escrow_info, job_info)?;
+    if &job.authority != authority_info.key {
+        msg!("Job authority does not match the authority provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    set_job_metadata(
+        job_info,
+        &mut job,
+        metadata,
+        authority_info,
+        &Rent::get()?,
+        system_program_info,
+    )?;
+    Job::pack_account(job, &mut job_info.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_raise_job_priority(
+    program_id: &Pubkey,
+    priority: u8,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Source accounts
+    let authority_info = next_account_info(account_info_iter)?;
+    // Accounts
+    let escrow_info = next_account_info(account_info_iter)?;
+    let job_info = next_account_info(account_info_iter)?;
+    let source_token_info = next_account_info(account_info_iter)?;
+    // Destination accounts
+    let treasury_info = next_account_info(account_info_iter)?;
+    let treasury_associated_token_info = next_account_info(account_info_iter)?;
+    // Config
+    let config_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+    let token_program_info = next_account_info(account_info_iter)?;
+
+    if !authority_info.is_signer {
+        msg!("Job authority provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let mut escrow = Escrow::unpack_account(&escrow_info.try_borrow_data()?)?;
+    resolve_legacy_escrow_token_mint(program_id, escrow_info, &mut escrow, source_token_info)?;
+    let (mut job, _bump_seed) = load_escrow_job(program_id, escrow_info, job_info)?;
+    if &job.authority != authority_info.key {
+        msg!("Job authority does not match the authority provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if job.status != JobStatus::Open {
+        msg!("Only the priority of open jobs can be raised");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    check_job_mint_migrations(&job, &escrow)?;
+    if priority <= job.priority || priority > MAX_JOB_PRIORITY {
+        msg!(
+            "Job priority must be raised above its priority and be at most {}",
+            MAX_JOB_PRIORITY
+        );
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let config = load_config(program_id, config_info)?;
+    let surcharge = config
+        .priority_surcharge(job.amount, priority - job.priority)
+        .ok_or(RNDRError::MathError)?;
+
+    if surcharge > 0 {
+        check_treasury_address(program_id, escrow_info.key, treasury_info)?;
+        let treasury = load_treasury(program_id, treasury_info, treasury_associated_token_info)?;
+        if treasury.token_mint != escrow.token_mint {
+            msg!("Treasury token mint does not match the token mint of the escrow");
+            return Err(RNDRError::UnspecifiedError.into());
+        }
+
+        invoke(
+            &spl_token::instruction::transfer(
+                token_program_info.key,
+                source_token_info.key,
+                treasury_associated_token_info.key,
+                authority_info.key,
+                &[],
+                surcharge,
+            )?,
+            &[
+                source_token_info.clone(),
+                treasury_associated_token_info.clone(),
+                authority_info.clone(),
+                token_program_info.clone(),
+            ],
+        )?;
+    }
+
+    job.priority = priority;
+    RNDREvent::JobPriorityRaised(JobPriorityRaised {
+        job: *job_info.key,
+        priority,
+        surcharge,
+    })
+    .emit();
+
+    resize_account(
+        job_info,
+        Job::LEN,
+        authority_info,
+        &Rent::get()?,
+        system_program_info,
+    )?;
+    Job::pack_account(job, &mut job_info.try_borrow_mut_data()?)?;
+
+    if surcharge > 0 {
+        invariants::check_treasury_vault(treasury_info, treasury_associated_token_info)?;
+    }
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_set_escrow_token_account(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let escrow_info = next_account_info(account_info_iter)?;
+    let owner_info = next_account_info(account_info_iter)?;
+    let token_account_info = next_account_info(account_info_iter)?;
+    let new_token_account_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+    let token_program_info = next_account_info(account_info_iter)?;
+
+    check_escrow_owner(program_id, escrow_info, owner_info)?;
+
+    let mut escrow = Escrow::unpack_account(&escrow_info.try_borrow_data()?)?;
+    resolve_legacy_escrow_token_mint(program_id, escrow_info, &mut escrow, token_account_info)?;
+    let token_mint = escrow.token_mint;
+    let address_token_mint = *escrow.address_token_mint();
+    let escrow_id_seed = escrow_id_seed(escrow.escrow_id);
+
+    let (escrow_address, bump_seed) = Pubkey::find_program_address(
+        &[
+            b"escrow",
+            address_token_mint.as_ref(),
+            token_program_info.key.as_ref(),
+            &escrow_id_seed,
+        ],
+        program_id,
+    );
+    if &escrow_address != escrow_info.key {
+        msg!("Escrow program derived address does not match the escrow address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let escrow_seeds: &[&[_]] = &[
+        b"escrow",
+        address_token_mint.as_ref(),
+        token_program_info.key.as_ref(),
+        &escrow_id_seed,
+        &[bump_seed],
+    ];
+
+    if &escrow.token_account(&escrow_address) != token_account_info.key {
+        msg!("Escrow token account does not match the token account provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if new_token_account_info.key == token_account_info.key {
+        msg!("New token account must be a different token account than the current one");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    if new_token_account_info.owner != token_program_info.key {
+        msg!("New token account provided is not owned by the token program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    let new_token_account =
+        spl_token::state::Account::unpack(&new_token_account_info.try_borrow_data()?)?;
+    if new_token_account.mint != token_mint {
+        msg!("New token account mint does not match the escrow token mint");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if new_token_account.owner != escrow_address {
+        msg!("New token account provided is not owned by the escrow");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if new_token_account.delegate.is_some() || new_token_account.close_authority.is_some() {
+        msg!("New token account must not have a delegate or close authority");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let amount = spl_token::state::Account::unpack(&token_account_info.try_borrow_data()?)?.amount;
+    if amount > 0 {
+        invoke_signed(
+            &spl_token::instruction::transfer(
+                token_program_info.key,
+                token_account_info.key,
+                new_token_account_info.key,
+                escrow_info.key,
+                &[],
+                amount,
+            )?,
+            &[
+                token_account_info.clone(),
+                new_token_account_info.clone(),
+                escrow_info.clone(),
+                token_program_info.clone(),
+            ],
+            &[escrow_seeds],
+        )?;
+    }
+
+    // The associated token account is stored as the default pubkey, so legacy escrows that move
+    // back to it don't have to be reallocated
+    escrow.token_account = if new_token_account_info.key
+        == &get_associated_token_address(&escrow_address, &token_mint)
+    {
+        Pubkey::default()
+    } else {
+        *new_token_account_info.key
+    };
+
+    resize_account(
+        escrow_info,
+        Escrow::LEN,
+        owner_info,
+        &Rent::get()?,
+        system_program_info,
+    )?;
+    Escrow::pack_account(escrow, &mut escrow_info.try_borrow_mut_data()?)?;
+
+    RNDREvent::EscrowTokenAccountSet(EscrowTokenAccountSet {
+        previous_token_account: *token_account_info.key,
+        token_account: *new_token_account_info.key,
+        amount,
+    })
+    .emit();
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_migrate_escrow_mint(
+    program_id: &Pubkey,
+    rate_numerator: u64,
+    rate_denominator: u64,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let escrow_info = next_account_info(account_info_iter)?;
+    let owner_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+    let governance_info = next_account_info(account_info_iter)?;
+    let mint_migration_info = next_account_info(account_info_iter)?;
+    let new_token_mint_info = next_account_info(account_info_iter)?;
+    let new_escrow_associated_token_info = next_account_info(account_info_iter)?;
+    let destination_token_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+    let token_program_info = next_account_info(account_info_iter)?;
+    let associated_token_program_info = next_account_info(account_info_iter)?;
+    // Optional accounts
+    let approver_info = account_info_iter.next();
+
+    check_escrow_owner(program_id, escrow_info, owner_info)?;
+
+    let config = load_config(program_id, config_info)?;
+    if &config.governance != governance_info.key {
+        msg!("Governance authority provided does not match the config governance authority");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if !governance_info.is_signer {
+        msg!("Governance authority provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if config.incident_mode != IncidentMode::Normal {
+        msg!("Mint migrations can't begin during an incident");
+        return Err(RNDRError::IncidentModeActive.into());
+    }
+
+    let mut escrow = Escrow::unpack_account(&escrow_info.try_borrow_data()?)?;
+    resolve_legacy_escrow_token_mint(program_id, escrow_info, &mut escrow, destination_token_info)?;
+    check_escrow_allows_disbursing(&escrow)?;
+    // Otherwise the owner could send the escrow's tokens anywhere without approval
+    if let Some(approver) = escrow.approver() {
+        let approver_info = match approver_info {
+            Some(approver_info) => approver_info,
+            None => {
+                msg!("Approver must sign to begin a mint migration");
+                return Err(RNDRError::InvalidApprover.into());
+            }
+        };
+        if approver_info.key != approver {
+            msg!("Approver provided does not match the approver of the escrow");
+            return Err(RNDRError::InvalidApprover.into());
+        }
+        if !approver_info.is_signer {
+            msg!("Approver provided must be a signer");
+            return Err(RNDRError::InvalidApprover.into());
+        }
+    }
+
+    let (escrow_address, _bump_seed) = Pubkey::find_program_address(
+        &[
+            b"escrow",
+            escrow.address_token_mint().as_ref(),
+            token_program_info.key.as_ref(),
+            &escrow_id_seed(escrow.escrow_id),
+        ],
+        program_id,
+    );
+    if &escrow_address != escrow_info.key {
+        msg!("Escrow program derived address does not match the escrow address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    if rate_numerator == 0 || rate_denominator == 0 {
+        msg!("Mint migration rate must not be zero");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if new_token_mint_info.key == &escrow.token_mint {
+        msg!("New token mint must be a different mint than the escrow token mint");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if new_token_mint_info.owner != token_program_info.key {
+        msg!("New token mint provided is not owned by the token program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    spl_token::state::Mint::unpack(&new_token_mint_info.try_borrow_data()?)?;
+
+    if destination_token_info.owner != token_program_info.key {
+        msg!("Destination token account provided is not owned by the token program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    let destination_token =
+        spl_token::state::Account::unpack(&destination_token_info.try_borrow_data()?)?;
+    if destination_token.mint != escrow.token_mint {
+        msg!("Destination token account mint does not match the escrow token mint");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let index = escrow.mint_migrations;
+    let (mint_migration_address, bump_seed) = Pubkey::find_program_address(
+        &[b"mint_migration", escrow_info.key.as_ref(), &[index]],
+        program_id,
+    );
+    if &mint_migration_address != mint_migration_info.key {
+        msg!("Mint migration program derived address does not match the mint migration address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if !mint_migration_info.try_data_is_empty()? {
+        msg!("Mint migration has already begun");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    if &get_associated_token_address(&escrow_address, new_token_mint_info.key)
+        != new_escrow_associated_token_info.key
+    {
+        msg!(
+            "Escrow associated token address does not match the associated token address provided"
+        );
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if new_escrow_associated_token_info.try_data_is_empty()? {
+        invoke(
+            &Instruction {
+                program_id: *associated_token_program_info.key,
+                accounts: vec![
+                    AccountMeta::new(*owner_info.key, true),
+                    AccountMeta::new(*new_escrow_associated_token_info.key, false),
+                    AccountMeta::new_readonly(escrow_address, false),
+                    AccountMeta::new_readonly(*new_token_mint_info.key, false),
+                    AccountMeta::new_readonly(*system_program_info.key, false),
+                    AccountMeta::new_readonly(*token_program_info.key, false),
+                ],
+                data: vec![],
+            },
+            &[
+                owner_info.clone(),
+                new_escrow_associated_token_info.clone(),
+                escrow_info.clone(),
+                new_token_mint_info.clone(),
+                system_program_info.clone(),
+                token_program_info.clone(),
+            ],
+        )?;
+    }
+
+    let mint_migration_seeds: &[&[_]] = &[
+        b"mint_migration",
+        escrow_info.key.as_ref(),
+        &[index],
+        &[bump_seed],
+    ];
+    create_program_account(
+        program_id,
+        owner_info,
+        mint_migration_info,
+        mint_migration_seeds,
+        MintMigration::LEN,
+        &Rent::get()?,
+        system_program_info,
+    )?;
+
+    let timelock_duration = UnixTimestamp::try_from(config.timelock_duration).unwrap_or(i64::MAX);
+    let executable_at = Clock::get()?
+        .unix_timestamp
+        .saturating_add(timelock_duration);
+    let mint_migration = MintMigration::new(InitMintMigrationParams {
+        escrow: *escrow_info.key,
+        index,
+        token_mint: escrow.token_mint,
+        new_token_mint: *new_token_mint_info.key,
+        rate_numerator,
+        rate_denominator,
+        destination: *destination_token_info.key,
+        executable_at,
+    });
+    MintMigration::pack(
+        mint_migration,
+        &mut mint_migration_info.try_borrow_mut_data()?,
+    )?;
+
+    RNDREvent::MintMigrationBegun(MintMigrationBegun {
+        mint_migration: *mint_migration_info.key,
+        new_token_mint: *new_token_mint_info.key,
+        rate_numerator,
+        rate_denominator,
+    })
+    .emit();
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_convert_escrow_mint_vault(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let escrow_info = next_account_info(account_info_iter)?;
+    let owner_info = next_account_info(account_info_iter)?;
+    let mint_migration_info = next_account_info(account_info_iter)?;
+    let token_account_info = next_account_info(account_info_iter)?;
+    let new_escrow_associated_token_info = next_account_info(account_info_iter)?;
+    let source_token_info = next_account_info(account_info_iter)?;
+    let destination_token_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+    let token_program_info = next_account_info(account_info_iter)?;
+
+    check_escrow_owner(program_id, escrow_info, owner_info)?;
+
+    let mut escrow = Escrow::unpack_account(&escrow_info.try_borrow_data()?)?;
+    resolve_legacy_escrow_token_mint(program_id, escrow_info, &mut escrow, token_account_info)?;
+    let address_token_mint = *escrow.address_token_mint();
+    let escrow_id_seed = escrow_id_seed(escrow.escrow_id);
+
+    let (escrow_address, bump_seed) = Pubkey::find_program_address(
+        &[
+            b"escrow",
+            address_token_mint.as_ref(),
+            token_program_info.key.as_ref(),
+            &escrow_id_seed,
+        ],
+        program_id,
+    );
+    if &escrow_address != escrow_info.key {
+        msg!("Escrow program derived address does not match the escrow address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let escrow_seeds: &[&[_]] = &[
+        b"escrow",
+        address_token_mint.as_ref(),
+        token_program_info.key.as_ref(),
+        &escrow_id_seed,
+        &[bump_seed],
+    ];
+
+    let mut mint_migration = load_mint_migration(program_id, escrow_info, mint_migration_info)?;
+    if mint_migration.vault_converted {
+        msg!("Mint migration has already converted the escrow token account");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if Clock::get()?.unix_timestamp < mint_migration.executable_at {
+        msg!("Timelock of the mint migration has not passed");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if destination_token_info.key != &mint_migration.destination {
+        msg!("Mint migration destination does not match the destination token account provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    check_escrow_allows_disbursing(&escrow)?;
+
+    if &escrow.token_account(&escrow_address) != token_account_info.key {
+        msg!("Escrow token account does not match the token account provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if &get_associated_token_address(&escrow_address, &mint_migration.new_token_mint)
+        != new_escrow_associated_token_info.key
+    {
+        msg!(
+            "Escrow associated token address does not match the associated token address provided"
+        );
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    // Shards hold amounts of tokens that are already in the escrow token account, so they're
+    // aggregated to be converted with the escrow's amount
+    for index in 0..ESCROW_SHARD_COUNT {
+        let escrow_shard_info = next_account_info(account_info_iter)?;
+        let (escrow_shard_address, _bump_seed) = Pubkey::find_program_address(
+            &[b"escrow_shard", escrow_info.key.as_ref(), &[index]],
+            program_id,
+        );
+        if &escrow_shard_address != escrow_shard_info.key {
+            msg!(
+                "Escrow shard program derived address does not match the escrow shard address provided"
+            );
+            return Err(ProgramError::InvalidSeeds);
+        }
+        if escrow_shard_info.try_data_is_empty()? {
+            continue;
+        }
+        if escrow_shard_info.owner != program_id {
+            msg!("Escrow shard provided is not owned by the RNDR program");
+            return Err(RNDRError::UnspecifiedError.into());
+        }
+
+        let mut escrow_shard = EscrowShard::unpack(&escrow_shard_info.try_borrow_data()?)?;
+        escrow.amount = escrow
+            .amount
+            .checked_add(escrow_shard.amount)
+            .ok_or(RNDRError::MathError)?;
+        escrow_shard.amount = 0;
+        EscrowShard::pack(escrow_shard, &mut escrow_shard_info.try_borrow_mut_data()?)?;
+    }
+
+    let amount = spl_token::state::Account::unpack(&token_account_info.try_borrow_data()?)?.amount;
+    let converted_amount = mint_migration.convert(amount).ok_or(RNDRError::MathError)?;
+    escrow.amount = mint_migration
+        .convert(escrow.amount)
+        .ok_or(RNDRError::MathError)?;
+
+    if amount > 0 {
+        invoke_signed(
+            &spl_token::instruction::transfer(
+                token_program_info.key,
+                token_account_info.key,
+                destination_token_info.key,
+                escrow_info.key,
+                &[],
+                amount,
+            )?,
+            &[
+                token_account_info.clone(),
+                destination_token_info.clone(),
+                escrow_info.clone(),
+                token_program_info.clone(),
+            ],
+            &[escrow_seeds],
+        )?;
+    }
+    if converted_amount > 0 {
+        invoke(
+            &spl_token::instruction::transfer(
+                token_program_info.key,
+                source_token_info.key,
+                new_escrow_associated_token_info.key,
+                owner_info.key,
+                &[],
+                converted_amount,
+            )?,
+            &[
+                source_token_info.clone(),
+                new_escrow_associated_token_info.clone(),
+                owner_info.clone(),
+                token_program_info.clone(),
+            ],
+        )?;
+    }
+
+    escrow.original_token_mint = address_token_mint;
+    escrow.token_mint = mint_migration.new_token_mint;
+    escrow.token_account = Pubkey::default();
+    escrow.mint_migrations = escrow
+        .mint_migrations
+        .checked_add(1)
+        .ok_or(RNDRError::MathError)?;
+    mint_migration.vault_converted = true;
+
+    resize_account(
+        escrow_info,
+        Escrow::LEN,
+        owner_info,
+        &Rent::get()?,
+        system_program_info,
+    )?;
+    Escrow::pack_account(escrow, &mut escrow_info.try_borrow_mut_data()?)?;
+    MintMigration::pack(
+        mint_migration,
+        &mut mint_migration_info.try_borrow_mut_data()?,
+    )?;
+
+    RNDREvent::MintMigrationTokensConverted(MintMigrationTokensConverted {
+        mint_migration: *mint_migration_info.key,
+        token_account: *token_account_info.key,
+        amount,
+        converted_amount,
+    })
+    .emit();
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_redenominate_jobs(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let escrow_info = next_account_info(account_info_iter)?;
+    let mint_migration_info = next_account_info(account_info_iter)?;
+    let payer_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    Escrow::unpack_account(&escrow_info.try_borrow_data()?)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let mut mint_migration = load_mint_migration(program_id, escrow_info, mint_migration_info)?;
+    if !mint_migration.vault_converted {
+        msg!("Mint migration has not converted the escrow token account yet");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let rent = Rent::get()?;
+    let mut count = 0u64;
+    for job_info in account_info_iter {
+        let (mut job, _bump_seed) = load_escrow_job(program_id, escrow_info, job_info)?;
+        if job.mint_migrations > mint_migration.index {
+            continue;
+        }
+        check_job_migration(&job, &mint_migration, false)?;
+
+        job.amount = mint_migration
+            .convert(job.amount)
+            .ok_or(RNDRError::MathError)?;
+        job.mint_migrations = mint_migration.index + 1;
+
+        resize_account(job_info, Job::LEN, payer_info, &rent, system_program_info)?;
+        Job::pack_account(job, &mut job_info.try_borrow_mut_data()?)?;
+        count += 1;
+    }
+
+    mint_migration.jobs_migrated = mint_migration
+        .jobs_migrated
+        .checked_add(count)
+        .ok_or(RNDRError::MathError)?;
+    let jobs_migrated = mint_migration.jobs_migrated;
+    MintMigration::pack(
+        mint_migration,
+        &mut mint_migration_info.try_borrow_mut_data()?,
+    )?;
+
+    RNDREvent::JobsMigrated(JobsMigrated {
+        mint_migration: *mint_migration_info.key,
+        count,
+        jobs_migrated,
+    })
+    .emit();
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_convert_isolated_job_mint(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let escrow_info = next_account_info(account_info_iter)?;
+    let owner_info = next_account_info(account_info_iter)?;
+    let mint_migration_info = next_account_info(account_info_iter)?;
+    let job_info = next_account_info(account_info_iter)?;
+    let job_associated_token_info = next_account_info(account_info_iter)?;
+    let new_job_associated_token_info = next_account_info(account_info_iter)?;
+    let new_token_mint_info = next_account_info(account_info_iter)?;
+    let source_token_info = next_account_info(account_info_iter)?;
+    let destination_token_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+    let token_program_info = next_account_info(account_info_iter)?;
+    let associated_token_program_info = next_account_info(account_info_iter)?;
+
+    check_escrow_owner(program_id, escrow_info, owner_info)?;
+
+    let escrow = Escrow::unpack_account(&escrow_info.try_borrow_data()?)?;
+    check_escrow_allows_disbursing(&escrow)?;
+
+    let mut mint_migration = load_mint_migration(program_id, escrow_info, mint_migration_info)?;
+    if !mint_migration.vault_converted {
+        msg!("Mint migration has not converted the escrow token account yet");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if new_token_mint_info.key != &mint_migration.new_token_mint {
+        msg!("Mint migration new token mint does not match the token mint provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if destination_token_info.key != &mint_migration.destination {
+        msg!("Mint migration destination does not match the destination token account provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let (mut job, bump_seed) = load_escrow_job(program_id, escrow_info, job_info)?;
+    check_job_not_frozen(&job)?;
+    if job.mint_migrations > mint_migration.index {
+        msg!("Job has already been migrated by the mint migration");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    check_job_migration(&job, &mint_migration, true)?;
+
+    let authority = *job.address_authority();
+    let job_id_seed = job_id_seed(job.id);
+    let job_seeds: &[&[_]] = &[
+        b"job",
+        escrow_info.key.as_ref(),
+        authority.as_ref(),
+        &job_id_seed,
+        &[bump_seed],
+    ];
+
+    if &get_associated_token_address(job_info.key, &mint_migration.token_mint)
+        != job_associated_token_info.key
+    {
+        msg!("Job associated token address does not match the associated token address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if &get_associated_token_address(job_info.key, &mint_migration.new_token_mint)
+        != new_job_associated_token_info.key
+    {
+        msg!("Job associated token address does not match the associated token address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if new_job_associated_token_info.try_data_is_empty()? {
+        invoke(
+            &Instruction {
+                program_id: *associated_token_program_info.key,
+                accounts: vec![
+                    AccountMeta::new(*owner_info.key, true),
+                    AccountMeta::new(*new_job_associated_token_info.key, false),
+                    AccountMeta::new_readonly(*job_info.key, false),
+                    AccountMeta::new_readonly(*new_token_mint_info.key, false),
+                    AccountMeta::new_readonly(*system_program_info.key, false),
+                    AccountMeta::new_readonly(*token_program_info.key, false),
+                ],
+                data: vec![],
+            },
+            &[
+                owner_info.clone(),
+                new_job_associated_token_info.clone(),
+                job_info.clone(),
+                new_token_mint_info.clone(),
+                system_program_info.clone(),
+                token_program_info.clone(),
+            ],
+        )?;
+    }
+
+    let amount =
+        spl_token::state::Account::unpack(&job_associated_token_info.try_borrow_data()?)?.amount;
+    let converted_amount = mint_migration.convert(amount).ok_or(RNDRError::MathError)?;
+    job.amount = mint_migration
+        .convert(job.amount)
+        .ok_or(RNDRError::MathError)?;
+    job.mint_migrations = mint_migration.index + 1;
+
+    resize_account(
+        job_info,
+        Job::LEN,
+        owner_info,
+        &Rent::get()?,
+        system_program_info,
+    )?;
+    Job::pack_account(job, &mut job_info.try_borrow_mut_data()?)?;
+
+    if amount > 0 {
+        invoke_signed(
+            &spl_token::instruction::transfer(
+                token_program_info.key,
+                job_associated_token_info.key,
+                destination_token_info.key,
+                job_info.key,
+                &[],
+                amount,
+            )?,
+            &[
+                job_associated_token_info.clone(),
+                destination_token_info.clone(),
+                job_info.clone(),
+                token_program_info.clone(),
+            ],
+            &[job_seeds],
+        )?;
+    }
+    if converted_amount > 0 {
+        invoke(
+            &spl_token::instruction::transfer(
+                token_program_info.key,
+                source_token_info.key,
+                new_job_associated_token_info.key,
+                owner_info.key,
+                &[],
+                converted_amount,
+            )?,
+            &[
+                source_token_info.clone(),
+                new_job_associated_token_info.clone(),
+                owner_info.clone(),
+                token_program_info.clone(),
+            ],
+        )?;
+    }
+
+    mint_migration.jobs_migrated = mint_migration
+        .jobs_migrated
+        .checked_add(1)
+        .ok_or(RNDRError::MathError)?;
+    let jobs_migrated = mint_migration.jobs_migrated;
+    MintMigration::pack(
+        mint_migration,
+        &mut mint_migration_info.try_borrow_mut_data()?,
+    )?;
+
+    RNDREvent::MintMigrationTokensConverted(MintMigrationTokensConverted {
+        mint_migration: *mint_migration_info.key,
+        token_account: *job_associated_token_info.key,
+        amount,
+        converted_amount,
+    })
+    .emit();
+    RNDREvent::JobsMigrated(JobsMigrated {
+        mint_migration: *mint_migration_info.key,
+        count: 1,
+        jobs_migrated,
+    })
+    .emit();
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_accept_job_authority(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let new_authority_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let job_info = next_account_info(account_info_iter)?;
+
+    if !new_authority_info.is_signer {
+        msg!("New authority provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let (mut job, _bump_seed) = load_escrow_job(program_id, escrow_info, job_info)?;
+    if job.pending_authority == Pubkey::default() {
+        msg!("Job has no pending authority transfer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if &job.pending_authority != new_authority_info.key {
+        msg!("Job pending authority does not match the new authority provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let previous_authority = job.authority;
+    job.accept_authority();
+
+    Job::pack_account(job, &mut job_info.try_borrow_mut_data()?)?;
+
+    RNDREvent::JobAuthorityTransferred(JobAuthorityTransferred {
+        job: *job_info.key,
+        previous_authority,
+        new_authority: *new_authority_info.key,
+    })
+    .emit();
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_create_job_bundle(
+    program_id: &Pubkey,
+    bundle_id: u64,
+    first_job_id: u64,
+    job_count: u8,
+    metadata_hash: [u8; HASH_BYTES],
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    if job_count == 0 || job_count > MAX_BUNDLE_JOBS {
+        msg!("Number of jobs must be between 1 and {}", MAX_BUNDLE_JOBS);
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if first_job_id.checked_add(job_count as u64 - 1).is_none() {
+        msg!("Job identifiers of the bundle overflow");
+        return Err(RNDRError::MathError.into());
+    }
+
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let authority_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let job_bundle_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if !authority_info.is_signer {
+        msg!("Authority provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    Escrow::unpack_account(&escrow_info.try_borrow_data()?)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let bump_seed = check_job_bundle_address(
+        program_id,
+        escrow_info.key,
+        authority_info.key,
+        bundle_id,
+        job_bundle_info,
+    )?;
+    if !job_bundle_info.try_data_is_empty()? {
+        msg!("Job bundle already exists");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let bundle_id_seed = bundle_id.to_le_bytes();
+    let job_bundle_seeds: &[&[_]] = &[
+        b"job_bundle",
+        escrow_info.key.as_ref(),
+        authority_info.key.as_ref(),
+        &bundle_id_seed,
+        &[bump_seed],
+    ];
+
+    create_program_account(
+        program_id,
+        authority_info,
+        job_bundle_info,
+        job_bundle_seeds,
+        JobBundle::LEN,
+        &Rent::get()?,
+        system_program_info,
+    )?;
+
+    let job_bundle = JobBundle::new(InitJobBundleParams {
+        escrow: *escrow_info.key,
+        authority: *authority_info.key,
+        id: bundle_id,
+        metadata_hash,
+        first_job_id,
+        job_count,
+    });
+    RNDREvent::JobBundleCreated(JobBundleCreated {
+        job_count,
+        first_job_id,
+    })
+    .emit();
+
+    JobBundle::pack(job_bundle, &mut job_bundle_info.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_fund_job_bundle(
+    program_id: &Pubkey,
+    first_job_id: u64,
+    count: u8,
+    amount_per_job: u64,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    if count == 0 || count as usize > MAX_FUND_JOBS_LEN {
+        msg!("Number of jobs must be between 1 and {}", MAX_FUND_JOBS_LEN);
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if amount_per_job == 0 {
+        msg!("Amount of tokens to fund can't be zero");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    let total_amount = amount_per_job
+        .checked_mul(count as u64)
+        .ok_or(RNDRError::MathError)?;
+
+    let (accounts, stats_info) = split_stats_account(program_id, accounts);
+    let (accounts, job_index_infos) = split_job_index_accounts(program_id, accounts);
+    let account_info_iter = &mut accounts.iter();
+    // Source accounts
+    let funder_info = next_account_info(account_info_iter)?;
+    let source_token_info = next_account_info(account_info_iter)?;
+    let source_authority_info = next_account_info(account_info_iter)?;
+    // Destination accounts
+    let job_bundle_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let escrow_associated_token_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+    let token_program_info = next_account_info(account_info_iter)?;
+
+    let mut escrow = Escrow::unpack_account(&escrow_info.try_borrow_data()?)?;
+    resolve_legacy_escrow_token_mint(
+        program_id,
+        escrow_info,
+        &mut escrow,
+        escrow_associated_token_info,
+    )?;
+    check_escrow_allows_funding(&escrow)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    let address_token_mint = *escrow.address_token_mint();
+    let escrow_id_seed = escrow_id_seed(escrow.escrow_id);
+
+    let (escrow_address, _bump_seed) = Pubkey::find_program_address(
+        &[
+            b"escrow",
+            address_token_mint.as_ref(),
+            token_program_info.key.as_ref(),
+            &escrow_id_seed,
+        ],
+        program_id,
+    );
+    if &escrow_address != escrow_info.key {
+        msg!("Escrow program derived address does not match the escrow address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    if &escrow.token_account(&escrow_address) != escrow_associated_token_info.key {
+        msg!("Escrow token account does not match the token account provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let mut job_bundle = load_job_bundle(program_id, escrow_info, job_bundle_info)?;
+    if job_bundle.is_cancelled {
+        msg!("Job bundle has been cancelled");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    let in_bundle = job_bundle
+        .job_index(first_job_id)
+        .and_then(|index| index.checked_add(count - 1))
+        .is_some_and(|last_index| last_index < job_bundle.job_count);
+    if !in_bundle {
+        msg!("Jobs to fund are not all part of the job bundle");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    invoke(
+        &spl_token::instruction::transfer(
+            token_program_info.key,
+            source_token_info.key,
+            escrow_associated_token_info.key,
+            source_authority_info.key,
+            &[],
+            total_amount,
+        )?,
+        &[
+            source_token_info.clone(),
+            escrow_associated_token_info.clone(),
+            source_authority_info.clone(),
+            token_program_info.clone(),
+        ],
+    )?;
+
+    let rent = &Rent::get()?;
+
+    for job_id in (first_job_id..).take(count as usize) {
+        // Job accounts
+        let job_info = next_account_info(account_info_iter)?;
+        let escrow_shard_info = next_account_info(account_info_iter)?;
+
+        let mut job = load_or_create_job(
+            program_id,
+            funder_info,
+            escrow_info,
+            &escrow,
+            &job_bundle.authority,
+            job_info,
+            job_id,
+            false,
+            job_index_infos,
+            rent,
+            system_program_info,
+        )?;
+
+        let mut escrow_shard = load_or_create_escrow_shard(
+            program_id,
+            funder_info,
+            escrow_info,
+            job_info,
+            escrow_shard_info,
+            rent,
+            system_program_info,
+        )?;
+
+        job.amount = job
+            .amount
+            .checked_add(amount_per_job)
+            .ok_or(RNDRError::MathError)?;
+        escrow_shard.amount = escrow_shard
+            .amount
+            .checked_add(amount_per_job)
+            .ok_or(RNDRError::MathError)?;
+
+        Job::pack_account(job, &mut job_info.try_borrow_mut_data()?)?;
+        EscrowShard::pack(escrow_shard, &mut escrow_shard_info.try_borrow_mut_data()?)?;
+    }
+
+    job_bundle.amount = job_bundle
+        .amount
+        .checked_add(total_amount)
+        .ok_or(RNDRError::MathError)?;
+    RNDREvent::JobBundleFunded(JobBundleFunded {
+        amount_per_job,
+        count,
+        first_job_id,
+    })
+    .emit();
+
+    JobBundle::pack(job_bundle, &mut job_bundle_info.try_borrow_mut_data()?)?;
+
+    record_stats(
+        program_id,
+        stats_info,
+        escrow_info.key,
+        TokenFlow::Escrowed,
+        total_amount,
+    )?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_cancel_job_bundle(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let escrow = *accounts
+        .get(1)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?
+        .key;
+    let (accounts, escrow_shard_infos) = split_escrow_shard_accounts(program_id, &escrow, accounts);
+    let account_info_iter = &mut accounts.iter();
+    // Source accounts
+    let authority_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let escrow_associated_token_info = next_account_info(account_info_iter)?;
+    let job_bundle_info = next_account_info(account_info_iter)?;
+    // Destination accounts
+    let destination_token_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+    let token_program_info = next_account_info(account_info_iter)?;
+
+    if !authority_info.is_signer {
+        msg!("Job authority provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let mut escrow = Escrow::unpack_account(&escrow_info.try_borrow_data()?)?;
+    resolve_legacy_escrow_token_mint(
+        program_id,
+        escrow_info,
+        &mut escrow,
+        escrow_associated_token_info,
+    )?;
+    check_escrow_allows_disbursing(&escrow)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    let address_token_mint = *escrow.address_token_mint();
+    let escrow_id_seed = escrow_id_seed(escrow.escrow_id);
+
+    let (escrow_address, bump_seed) = Pubkey::find_program_address(
+        &[
+            b"escrow",
+            address_token_mint.as_ref(),
+            token_program_info.key.as_ref(),
+            &escrow_id_seed,
+        ],
+        program_id,
+    );
+    if &escrow_address != escrow_info.key {
+        msg!("Escrow program derived address does not match the escrow address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let escrow_seeds: &[&[_]] = &[
+        b"escrow",
+        address_token_mint.as_ref(),
+        token_program_info.key.as_ref(),
+        &escrow_id_seed,
+        &[bump_seed],
+    ];
+
+    if &escrow.token_account(&escrow_address) != escrow_associated_token_info.key {
+        msg!("Escrow token account does not match the token account provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let mut job_bundle = load_job_bundle(program_id, escrow_info, job_bundle_info)?;
+    if &job_bundle.authority != authority_info.key {
+        msg!("Job bundle authority does not match the authority provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let rent = &Rent::get()?;
+    let mut refund = 0u64;
+    let mut cancelled = 0u8;
+
+    for job_info in account_info_iter {
+        let (mut job, _bump_seed) = load_escrow_job(program_id, escrow_info, job_info)?;
+        check_job_mint_migrations(&job, &escrow)?;
+        check_job_not_frozen(&job)?;
+        let index = match job_bundle.job_index(job.id) {
+            Some(index) if job.address_authority() == &job_bundle.authority => index,
+            _ => {
+                msg!("Job {} is not part of the job bundle", job_info.key);
+                return Err(RNDRError::UnspecifiedError.into());
+            }
+        };
+        if &job.authority != authority_info.key {
+            msg!(
+                "Job {} has been transferred to another authority",
+                job_info.key
+            );
+            return Err(RNDRError::UnspecifiedError.into());
+        }
+        if job.status != JobStatus::Open {
+            msg!(
+                "Job {} is not open, cancel it individually with CancelJob",
+                job_info.key
+            );
+            return Err(RNDRError::UnspecifiedError.into());
+        }
+
+        refund = refund.checked_add(job.amount).ok_or(RNDRError::MathError)?;
+        job.amount = 0;
+        job.status = JobStatus::Cancelled;
+        job_bundle.record(index, JobStatus::Cancelled);
+        cancelled += 1;
+
+        resize_account(
+            job_info,
+            Job::LEN,
+            authority_info,
+            rent,
+            system_program_info,
+        )?;
+        Job::pack_account(job, &mut job_info.try_borrow_mut_data()?)?;
+    }
+
+    job_bundle.is_cancelled = true;
+    aggregate_escrow_shards(program_id, escrow_info, &mut escrow, escrow_shard_infos)?;
+    escrow.amount = escrow
+        .amount
+        .checked_sub(refund)
+        .ok_or(RNDRError::MathError)?;
+
+    JobBundle::pack(job_bundle, &mut job_bundle_info.try_borrow_mut_data()?)?;
+    Escrow::pack_account(escrow, &mut escrow_info.try_borrow_mut_data()?)?;
+
+    if refund > 0 {
+        invoke_signed(
+            &spl_token::instruction::transfer(
+                token_program_info.key,
+                escrow_associated_token_info.key,
+                destination_token_info.key,
+                escrow_info.key,
+                &[],
+                refund,
+            )?,
+            &[
+                escrow_associated_token_info.clone(),
+                destination_token_info.clone(),
+                escrow_info.clone(),
+                token_program_info.clone(),
+            ],
+            &[escrow_seeds],
+        )?;
+    }
+
+    RNDREvent::JobBundleCancelled(JobBundleCancelled { cancelled, refund }).emit();
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_aggregate_job_bundle(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let job_bundle_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+
+    let mut job_bundle = load_job_bundle(program_id, escrow_info, job_bundle_info)?;
+    let was_complete = job_bundle.is_complete();
+
+    for job_info in account_info_iter {
+        let (job, _bump_seed) = load_escrow_job(program_id, escrow_info, job_info)?;
+        let index = match job_bundle.job_index(job.id) {
+            Some(index) if job.address_authority() == &job_bundle.authority => index,
+            _ => {
+                msg!("Job {} is not part of the job bundle", job_info.key);
+                return Err(RNDRError::UnspecifiedError.into());
+            }
+        };
+        job_bundle.record(index, job.status);
+    }
+
+    RNDREvent::JobBundleAggregated(JobBundleAggregated {
+        completed: job_bundle.completed.count_ones() as u8,
+        cancelled: job_bundle.cancelled.count_ones() as u8,
+        job_count: job_bundle.job_count,
+    })
+    .emit();
+    if job_bundle.is_complete() && !was_complete {
+        RNDREvent::JobBundleCompleted(JobBundleCompleted {
+            job_bundle: *job_bundle_info.key,
+        })
+        .emit();
+    }
+
+    JobBundle::pack(job_bundle, &mut job_bundle_info.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_init_job_tree(
+    program_id: &Pubkey,
+    max_depth: u8,
+    max_buffer_size: u32,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    if max_depth == 0 || max_depth > MAX_JOB_TREE_DEPTH {
+        msg!(
+            "Job tree depth must be between 1 and {}",
+            MAX_JOB_TREE_DEPTH
+        );
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let authority_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let job_tree_info = next_account_info(account_info_iter)?;
+    let merkle_tree_info = next_account_info(account_info_iter)?;
+    // Programs
+    let noop_program_info = next_account_info(account_info_iter)?;
+    let compression_program_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if !authority_info.is_signer {
+        msg!("Authority provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    Escrow::unpack_account(&escrow_info.try_borrow_data()?)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    check_account_compression_programs(noop_program_info, compression_program_info)?;
+    if merkle_tree_info.owner != &ACCOUNT_COMPRESSION_PROGRAM_ID {
+        msg!("Merkle tree provided is not owned by the account compression program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let bump_seed = check_job_tree_address(
+        program_id,
+        escrow_info.key,
+        authority_info.key,
+        job_tree_info,
+    )?;
+    if !job_tree_info.try_data_is_empty()? {
+        msg!("Job tree already exists");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let job_tree_seeds: &[&[_]] = &[
+        b"job_tree",
+        escrow_info.key.as_ref(),
+        authority_info.key.as_ref(),
+        &[bump_seed],
+    ];
+
+    create_program_account(
+        program_id,
+        authority_info,
+        job_tree_info,
+        job_tree_seeds,
+        JobTree::LEN,
+        &Rent::get()?,
+        system_program_info,
+    )?;
+
+    // The job tree is the authority of its merkle tree, so leaves only change through the program
+    invoke_signed(
+        &account_compression::init_empty_merkle_tree(
+            *merkle_tree_info.key,
+            *job_tree_info.key,
+            max_depth as u32,
+            max_buffer_size,
+        ),
+        &[
+            merkle_tree_info.clone(),
+            job_tree_info.clone(),
+            noop_program_info.clone(),
+            compression_program_info.clone(),
+        ],
+        &[job_tree_seeds],
+    )?;
+
+    let job_tree = JobTree::new(InitJobTreeParams {
+        escrow: *escrow_info.key,
+        authority: *authority_info.key,
+        merkle_tree: *merkle_tree_info.key,
+        max_depth,
+    });
+    RNDREvent::JobTreeCreated(JobTreeCreated { max_depth }).emit();
+
+    JobTree::pack(job_tree, &mut job_tree_info.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_append_compressed_job(
+    program_id: &Pubkey,
+    amount: u64,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    if amount == 0 {
+        msg!("Amount of tokens to fund can't be zero");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let (accounts, stats_info) = split_stats_account(program_id, accounts);
+    let account_info_iter = &mut accounts.iter();
+    // Source accounts
+    let authority_info = next_account_info(account_info_iter)?;
+    let source_token_info = next_account_info(account_info_iter)?;
+    let source_authority_info = next_account_info(account_info_iter)?;
+    // Destination accounts
+    let job_tree_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let escrow_associated_token_info = next_account_info(account_info_iter)?;
+    // Programs
+    let token_program_info = next_account_info(account_info_iter)?;
+    // Merkle tree accounts
+    let merkle_tree_info = next_account_info(account_info_iter)?;
+    let noop_program_info = next_account_info(account_info_iter)?;
+    let compression_program_info = next_account_info(account_info_iter)?;
+
+    if !authority_info.is_signer {
+        msg!("Job authority provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let mut escrow = Escrow::unpack_account(&escrow_info.try_borrow_data()?)?;
+    resolve_legacy_escrow_token_mint(
+        program_id,
+        escrow_info,
+        &mut escrow,
+        escrow_associated_token_info,
+    )?;
+    check_escrow_allows_funding(&escrow)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    let address_token_mint = *escrow.address_token_mint();
+    let escrow_id_seed = escrow_id_seed(escrow.escrow_id);
+
+    let (escrow_address, _bump_seed) = Pubkey::find_program_address(
+        &[
+            b"escrow",
+            address_token_mint.as_ref(),
+            token_program_info.key.as_ref(),
+            &escrow_id_seed,
+        ],
+        program_id,
+    );
+    if &escrow_address != escrow_info.key {
+        msg!("Escrow program derived address does not match the escrow address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    if &escrow.token_account(&escrow_address) != escrow_associated_token_info.key {
+        msg!("Escrow token account does not match the token account provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let (mut job_tree, bump_seed) = load_job_tree(program_id, escrow_info, job_tree_info)?;
+    if &job_tree.authority != authority_info.key {
+        msg!("Job tree authority does not match the authority provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    check_job_tree_merkle_tree(&job_tree, merkle_tree_info)?;
+    check_account_compression_programs(noop_program_info, compression_program_info)?;
+    if job_tree.is_full() {
+        msg!("Job tree is full");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let job = CompressedJob {
+        id: job_tree.leaf_count,
+        amount,
+        status: JobStatus::Open,
+        node: Pubkey::default(),
+    };
+    let job_tree_seeds: &[&[_]] = &[
+        b"job_tree",
+        escrow_info.key.as_ref(),
+        authority_info.key.as_ref(),
+        &[bump_seed],
+    ];
+    invoke(
+        &account_compression::wrap(job.leaf_data(job_tree_info.key)),
+        std::slice::from_ref(noop_program_info),
+    )?;
+    invoke_signed(
+        &account_compression::append(
+            *merkle_tree_info.key,
+            *job_tree_info.key,
+            job.leaf(job_tree_info.key),
+        ),
+        &[
+            merkle_tree_info.clone(),
+            job_tree_info.clone(),
+            noop_program_info.clone(),
+            compression_program_info.clone(),
+        ],
+        &[job_tree_seeds],
+    )?;
+    job_tree.leaf_count += 1;
+    job_tree.amount = job_tree
+        .amount
+        .checked_add(amount)
+        .ok_or(RNDRError::MathError)?;
+    escrow.amount = escrow
+        .amount
+        .checked_add(amount)
+        .ok_or(RNDRError::MathError)?;
+
+    invoke(
+        &spl_token::instruction::transfer(
+            token_program_info.key,
+            source_token_info.key,
+            escrow_associated_token_info.key,
+            source_authority_info.key,
+            &[],
+            amount,
+        )?,
+        &[
+            source_token_info.clone(),
+            escrow_associated_token_info.clone(),
+            source_authority_info.clone(),
+            token_program_info.clone(),
+        ],
+    )?;
+
+    RNDREvent::CompressedJobAppended(CompressedJobAppended { id: job.id, amount }).emit();
+
+    JobTree::pack(job_tree, &mut job_tree_info.try_borrow_mut_data()?)?;
+    Escrow::pack_account(escrow, &mut escrow_info.try_borrow_mut_data()?)?;
+
+    record_stats(
+        program_id,
+        stats_info,
+        escrow_info.key,
+        TokenFlow::Escrowed,
+        amount,
+    )?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_replace_compressed_job(
+    program_id: &Pubkey,
+    job: CompressedJob,
+    node: Pubkey,
+    root: [u8; HASH_BYTES],
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let authority_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let job_tree_info = next_account_info(account_info_iter)?;
+    // Merkle tree accounts
+    let merkle_tree_info = next_account_info(account_info_iter)?;
+    let noop_program_info = next_account_info(account_info_iter)?;
+    let compression_program_info = next_account_info(account_info_iter)?;
+
+    if !authority_info.is_signer {
+        msg!("Job authority provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let (job_tree, bump_seed) = load_job_tree(program_id, escrow_info, job_tree_info)?;
+    if &job_tree.authority != authority_info.key {
+        msg!("Job tree authority does not match the authority provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    match job.status {
+        // The authority can assign an open job to a node
+        JobStatus::Open => {
+            if node == Pubkey::default() {
+                msg!("Compressed job is already open");
+                return Err(RNDRError::UnspecifiedError.into());
+            }
+        }
+        // Only the node a job is committed to can release it or hand it to another node
+        JobStatus::Committed => match account_info_iter.next() {
+            Some(node_info) if node_info.key == &job.node && node_info.is_signer => {}
+            _ => {
+                msg!("Node the compressed job is committed to must be a signer");
+                return Err(RNDRError::UnspecifiedError.into());
+            }
+        },
+        _ => {
+            msg!("Compressed job has already been settled");
+            return Err(RNDRError::UnspecifiedError.into());
+        }
+    }
+    // Proof accounts
+    let proof_infos = account_info_iter.as_slice();
+
+    let new_job = CompressedJob {
+        status: if node == Pubkey::default() {
+            JobStatus::Open
+        } else {
+            JobStatus::Committed
+        },
+        node,
+        ..job
+    };
+    replace_compressed_job_leaf(
+        job_tree_info,
+        &job_tree,
+        bump_seed,
+        merkle_tree_info,
+        noop_program_info,
+        compression_program_info,
+        proof_infos,
+        root,
+        &job,
+        &new_job,
+    )?;
+
+    RNDREvent::CompressedJobReplaced(CompressedJobReplaced { id: job.id, node }).emit();
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_settle_compressed_job(
+    program_id: &Pubkey,
+    job: CompressedJob,
+    root: [u8; HASH_BYTES],
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let (accounts, stats_info) = split_stats_account(program_id, accounts);
+    let account_info_iter = &mut accounts.iter();
+    // Source accounts
+    let authority_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let escrow_associated_token_info = next_account_info(account_info_iter)?;
+    let job_tree_info = next_account_info(account_info_iter)?;
+    // Destination accounts
+    let destination_token_info = next_account_info(account_info_iter)?;
+    // Programs
+    let token_program_info = next_account_info(account_info_iter)?;
+    // Merkle tree accounts
+    let merkle_tree_info = next_account_info(account_info_iter)?;
+    let noop_program_info = next_account_info(account_info_iter)?;
+    let compression_program_info = next_account_info(account_info_iter)?;
+    // Proof accounts
+    let proof_infos = account_info_iter.as_slice();
+
+    if !authority_info.is_signer {
+        msg!("Job authority provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let mut escrow = Escrow::unpack_account(&escrow_info.try_borrow_data()?)?;
+    resolve_legacy_escrow_token_mint(
+        program_id,
+        escrow_info,
+        &mut escrow,
+        escrow_associated_token_info,
+    )?;
+    check_escrow_allows_disbursing(&escrow)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    let address_token_mint = *escrow.address_token_mint();
+    let escrow_id_seed = escrow_id_seed(escrow.escrow_id);
+
+    let (escrow_address, bump_seed) = Pubkey::find_program_address(
+        &[
+            b"escrow",
+            address_token_mint.as_ref(),
+            token_program_info.key.as_ref(),
+            &escrow_id_seed,
+        ],
+        program_id,
+    );
+    if &escrow_address != escrow_info.key {
+        msg!("Escrow program derived address does not match the escrow address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let escrow_seeds: &[&[_]] = &[
+        b"escrow",
+        address_token_mint.as_ref(),
+        token_program_info.key.as_ref(),
+        &escrow_id_seed,
+        &[bump_seed],
+    ];
+
+    if &escrow.token_account(&escrow_address) != escrow_associated_token_info.key {
+        msg!("Escrow token account does not match the token account provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let (mut job_tree, job_tree_bump_seed) = load_job_tree(program_id, escrow_info, job_tree_info)?;
+    if &job_tree.authority != authority_info.key {
+        msg!("Job tree authority does not match the authority provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let status = match job.status {
+        JobStatus::Open => JobStatus::Cancelled,
+        JobStatus::Committed => {
+            let destination_token =
+                spl_token::state::Account::unpack(&destination_token_info.try_borrow_data()?)?;
+            if destination_token.owner != job.node {
+                msg!("Destination token account provided is not owned by the job's node");
+                return Err(RNDRError::UnspecifiedError.into());
+            }
+            JobStatus::Paid
+        }
+        _ => {
+            msg!("Compressed job has already been settled");
+            return Err(RNDRError::UnspecifiedError.into());
+        }
+    };
+
+    let settled_job = CompressedJob {
+        amount: 0,
+        status,
+        ..job
+    };
+    replace_compressed_job_leaf(
+        job_tree_info,
+        &job_tree,
+        job_tree_bump_seed,
+        merkle_tree_info,
+        noop_program_info,
+        compression_program_info,
+        proof_infos,
+        root,
+        &job,
+        &settled_job,
+    )?;
+    job_tree.amount = job_tree
+        .amount
+        .checked_sub(job.amount)
+        .ok_or(RNDRError::MathError)?;
+    escrow.amount = escrow
+        .amount
+        .checked_sub(job.amount)
+        .ok_or(RNDRError::MathError)?;
+
+    JobTree::pack(job_tree, &mut job_tree_info.try_borrow_mut_data()?)?;
+    Escrow::pack_account(escrow, &mut escrow_info.try_borrow_mut_data()?)?;
+
+    if job.amount > 0 {
+        invoke_signed(
+            &spl_token::instruction::transfer(
+                token_program_info.key,
+                escrow_associated_token_info.key,
+                destination_token_info.key,
+                escrow_info.key,
+                &[],
+                job.amount,
+            )?,
+            &[
+                escrow_associated_token_info.clone(),
+                destination_token_info.clone(),
+                escrow_info.clone(),
+                token_program_info.clone(),
+            ],
+            &[escrow_seeds],
+        )?;
+    }
+
+    if status == JobStatus::Paid {
+        RNDREvent::CompressedJobPaid(CompressedJobPaid {
+            id: job.id,
+            amount: job.amount,
+            node: job.node,
+        })
+        .emit();
+        record_stats(
+            program_id,
+            stats_info,
+            escrow_info.key,
+            TokenFlow::Disbursed,
+            job.amount,
+        )?;
+    } else {
+        RNDREvent::CompressedJobCancelled(CompressedJobCancelled {
+            id: job.id,
+            amount: job.amount,
+        })
+        .emit();
+    }
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_init_job_index(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let authority_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let job_index_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if !authority_info.is_signer {
+        msg!("Authority provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    Escrow::unpack_account(&escrow_info.try_borrow_data()?)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let bump_seed = check_job_index_address(
+        program_id,
+        escrow_info.key,
+        authority_info.key,
+        job_index_info,
+    )?;
+    if !job_index_info.try_data_is_empty()? {
+        msg!("Job index already exists");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let job_index_seeds: &[&[_]] = &[
+        b"job_index",
+        escrow_info.key.as_ref(),
+        authority_info.key.as_ref(),
+        &[bump_seed],
+    ];
+
+    create_program_account(
+        program_id,
+        authority_info,
+        job_index_info,
+        job_index_seeds,
+        JobIndex::LEN,
+        &Rent::get()?,
+        system_program_info,
+    )?;
+
+    let job_index = JobIndex::new(InitJobIndexParams {
+        escrow: *escrow_info.key,
+        authority: *authority_info.key,
+    });
+
+    JobIndex::pack(job_index, &mut job_index_info.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_init_job_queue(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let funder_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let job_queue_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if !funder_info.is_signer {
+        msg!("Funder provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    Escrow::unpack_account(&escrow_info.try_borrow_data()?)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let bump_seed = check_job_queue_address(program_id, escrow_info.key, job_queue_info)?;
+    if !job_queue_info.try_data_is_empty()? {
+        msg!("Job queue already exists");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let job_queue_seeds: &[&[_]] = &[b"job_queue", escrow_info.key.as_ref(), &[bump_seed]];
+
+    create_program_account(
+        program_id,
+        funder_info,
+        job_queue_info,
+        job_queue_seeds,
+        JobQueue::LEN,
+        &Rent::get()?,
+        system_program_info,
+    )?;
+
+    let job_queue = JobQueue::new(InitJobQueueParams {
+        escrow: *escrow_info.key,
+    });
+
+    JobQueue::pack(job_queue, &mut job_queue_info.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_claim_job(
+    program_id: &Pubkey,
+    commitment: [u8; HASH_BYTES],
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    if commitment == [0; HASH_BYTES] {
+        msg!("Result commitment can't be zero");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let account_info_iter = &mut accounts.iter();
+    // Source accounts
+    let node_info = next_account_info(account_info_iter)?;
+    let job_queue_info = next_account_info(account_info_iter)?;
+    // Destination accounts
+    let job_info = next_account_info(account_info_iter)?;
+    // Config
+    let config_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if !node_info.is_signer {
+        msg!("Node provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let mut job_queue = JobQueue::unpack(&job_queue_info.try_borrow_data()?)?;
+    if job_queue_info.owner != program_id {
+        msg!("Job queue provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    check_job_queue_address(program_id, &job_queue.escrow, job_queue_info)?;
+    let config = load_config(program_id, config_info)?;
+
+    match job_queue.pop() {
+        Some(job) if &job == job_info.key => {}
+        Some(_) => {
+            msg!("Job provided is not the oldest job of the job queue");
+            return Err(RNDRError::UnspecifiedError.into());
+        }
+        None => {
+            msg!("Job queue is empty");
+            return Err(RNDRError::UnspecifiedError.into());
+        }
+    }
+
+    let job = if job_info.owner == program_id && !job_info.try_data_is_empty()? {
+        Some(Job::unpack_account(&job_info.try_borrow_data()?)?)
+    } else {
+        None
+    };
+    match job {
+        Some(job) if job.status == JobStatus::Open && !is_job_claimable(&config, &job)? => {
+            msg!("Job is still in its priority claim delay, so it was moved to the back of the job queue");
+            // There is room for the job, as it was just popped
+            job_queue.push(*job_info.key);
+        }
+        Some(mut job) if job.status == JobStatus::Open => {
+            job.node = *node_info.key;
+            job.status = JobStatus::Committed;
+            job.result_commitment = commitment;
+
+            resize_account(
+                job_info,
+                Job::LEN,
+                node_info,
+                &Rent::get()?,
+                system_program_info,
+            )?;
+            Job::pack_account(job, &mut job_info.try_borrow_mut_data()?)?;
+        }
+        _ => msg!("Job is no longer open, so it was popped without being claimed"),
+    }
+
+    JobQueue::pack(job_queue, &mut job_queue_info.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_set_job_exchange_rate(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let owner_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let job_info = next_account_info(account_info_iter)?;
+    // Oracle accounts
+    let price_feed_info = next_account_info(account_info_iter)?;
+    let token_mint_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    check_escrow_owner(program_id, escrow_info, owner_info)?;
+
+    let mut escrow = Escrow::unpack_account(&escrow_info.try_borrow_data()?)?;
+    resolve_legacy_escrow_token_mint(program_id, escrow_info, &mut escrow, token_mint_info)?;
+    if escrow.price_feed() != Some(price_feed_info.key) {
+        msg!("Price feed does not match the price feed of the escrow");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if &escrow.token_mint != token_mint_info.key {
+        msg!("Token mint does not match the token mint of the escrow");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    let decimals = spl_token::state::Mint::unpack(&token_mint_info.try_borrow_data()?)?.decimals;
+
+    let (mut job, _bump_seed) = load_escrow_job(program_id, escrow_info, job_info)?;
+    check_job_mint_migrations(&job, &escrow)?;
+    if matches!(
+        job.status,
+        JobStatus::Paid | JobStatus::Resolved | JobStatus::Cancelled
+    ) {
+        msg!("Exchange rate of a settled job can't be recorded");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if job.exchange_rate().is_some() {
+        msg!("Job exchange rate has already been recorded");
+        return Err(RNDRError::ExchangeRateAlreadySet.into());
+    }
+
+    let config = load_config(program_id, config_info)?;
+
+    let oracle_price = OraclePrice::unpack(&price_feed_info.try_borrow_data()?)?;
+    oracle_price.check(
+        Clock::get()?.slot,
+        config.max_price_age,
+        config.max_price_confidence_bps,
+    )?;
+    let exchange_rate = ExchangeRate::try_from_price(
+        oracle_price.price,
+        oracle_price.exponent,
+        decimals,
+        RNDR_DECIMALS,
+    )?;
+
+    set_job_exchange_rate(
+        job_info,
+        &mut job,
+        exchange_rate,
+        oracle_price.publish_slot,
+        owner_info,
+        &Rent::get()?,
+        system_program_info,
+    )?;
+    Job::pack_account(job, &mut job_info.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_estimate_job_cost(
+    program_id: &Pubkey,
+    tier: u8,
+    milli_obh: u64,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let config_info = next_account_info(account_info_iter)?;
+
+    let config = load_config(program_id, config_info)?;
+    let cost = config.job_cost(tier, milli_obh).ok_or_else(|| {
+        msg!("Price tier {} is disabled or the cost overflows", tier);
+        RNDRError::MathError
+    })?;
+    set_return_data(&cost.to_le_bytes());
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_set_price_feed(
+    program_id: &Pubkey,
+    price_feed: Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let escrow_info = next_account_info(account_info_iter)?;
+    let owner_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    check_escrow_owner(program_id, escrow_info, owner_info)?;
+
+    let mut escrow = Escrow::unpack_account(&escrow_info.try_borrow_data()?)?;
+    escrow.price_feed = price_feed;
+
+    resize_account(
+        escrow_info,
+        Escrow::LEN,
+        owner_info,
+        &Rent::get()?,
+        system_program_info,
+    )?;
+    Escrow::pack_account(escrow, &mut escrow_info.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_sync_incident_mode(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let escrow_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+    let payer_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if !payer_info.is_signer {
+        msg!("Payer provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let config = load_config(program_id, config_info)?;
+    let mut escrow = Escrow::unpack_account(&escrow_info.try_borrow_data()?)?;
+    escrow.incident_mode = config.incident_mode;
+    RNDREvent::IncidentModeSet(IncidentModeSet {
+        escrow: *escrow_info.key,
+        mode: config.incident_mode,
+    })
+    .emit();
+
+    resize_account(
+        escrow_info,
+        Escrow::LEN,
+        payer_info,
+        &Rent::get()?,
+        system_program_info,
+    )?;
+    Escrow::pack_account(escrow, &mut escrow_info.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_fund_job_at_oracle_price(
+    program_id: &Pubkey,
+    value: u64,
+    max_rndr_amount: u64,
+    job_id: u64,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Oracle accounts
+    let price_feed_info = next_account_info(account_info_iter)?;
+    let token_mint_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+    // Accounts of FundJob, whose escrow and job are checked when it's processed
+    let fund_job_accounts = account_info_iter.as_slice();
+    let escrow_info = fund_job_accounts
+        .get(3)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let job_info = fund_job_accounts
+        .get(5)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+    let mut escrow = Escrow::unpack_account(&escrow_info.try_borrow_data()?)?;
+    resolve_legacy_escrow_token_mint(program_id, escrow_info, &mut escrow, token_mint_info)?;
+    if escrow.price_feed() != Some(price_feed_info.key) {
+        msg!("Price feed does not match the price feed of the escrow");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if &escrow.token_mint != token_mint_info.key {
+        msg!("Token mint does not match the token mint of the escrow");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    let decimals = spl_token::state::Mint::unpack(&token_mint_info.try_borrow_data()?)?.decimals;
+
+    let config = load_config(program_id, config_info)?;
+
+    let oracle_price = OraclePrice::unpack(&price_feed_info.try_borrow_data()?)?;
+    oracle_price.check(
+        Clock::get()?.slot,
+        config.max_price_age,
+        config.max_price_confidence_bps,
+    )?;
+    let amount = oracle_price.try_token_amount(value, decimals)?;
+    let exchange_rate = ExchangeRate::try_from_price(
+        oracle_price.price,
+        oracle_price.exponent,
+        decimals,
+        RNDR_DECIMALS,
+    )?;
+    if amount > max_rndr_amount {
+        msg!(
+            "Amount of tokens {} at the oracle price exceeds the maximum of {}",
+            amount,
+            max_rndr_amount
+        );
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    process_fund_job(
+        program_id,
+        amount,
+        job_id,
+        None,
+        Some((exchange_rate, oracle_price.publish_slot)),
+        fund_job_accounts,
+    )?;
+
+    RNDREvent::JobFundedAtOraclePrice(JobFundedAtOraclePrice {
+        job: *job_info.key,
+        value,
+        amount,
+        price: oracle_price.price,
+        oracle_slot: oracle_price.publish_slot,
+    })
+    .emit();
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_freeze_job(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let compliance_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let job_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    check_compliance(program_id, config_info, compliance_info)?;
+
+    let (mut job, _bump_seed) = load_escrow_job(program_id, escrow_info, job_info)?;
+    if job.frozen {
+        msg!("Job is already frozen");
+        return Err(RNDRError::JobFrozen.into());
+    }
+
+    job.frozen = true;
+    RNDREvent::JobFrozen(JobFrozen { job: *job_info.key }).emit();
+
+    resize_account(
+        job_info,
+        Job::LEN,
+        compliance_info,
+        &Rent::get()?,
+        system_program_info,
+    )?;
+    Job::pack_account(job, &mut job_info.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_unfreeze_job(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let compliance_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let job_info = next_account_info(account_info_iter)?;
+
+    check_compliance(program_id, config_info, compliance_info)?;
+
+    let (mut job, _bump_seed) = load_escrow_job(program_id, escrow_info, job_info)?;
+    if !job.frozen {
+        msg!("Job is not frozen");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    job.frozen = false;
+    RNDREvent::JobUnfrozen(JobUnfrozen { job: *job_info.key }).emit();
+
+    Job::pack_account(job, &mut job_info.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_register_cold_wallet(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let owner_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let treasury_info = next_account_info(account_info_iter)?;
+    let treasury_associated_token_info = next_account_info(account_info_iter)?;
+    let cold_wallet_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    check_escrow_owner(program_id, escrow_info, owner_info)?;
+    check_treasury_address(program_id, escrow_info.key, treasury_info)?;
+    let mut treasury = load_treasury(program_id, treasury_info, treasury_associated_token_info)?;
+
+    if cold_wallet_info.key == treasury_associated_token_info.key {
+        msg!("Cold wallet can't be the treasury associated token account");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if cold_wallet_info.owner != &spl_token::id() {
+        msg!("Cold wallet provided is not owned by the token program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    let cold_wallet = spl_token::state::Account::unpack(&cold_wallet_info.try_borrow_data()?)?;
+    if cold_wallet.mint != treasury.token_mint {
+        msg!("Cold wallet mint does not match the treasury token mint");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    // A sweep scheduled to the previous cold wallet is cancelled, so the cold wallet can't be
+    // swapped out from under it
+    treasury.cold_wallet = *cold_wallet_info.key;
+    treasury.sweep_amount = 0;
+    treasury.sweep_executable_at = 0;
+    RNDREvent::ColdWalletRegistered(ColdWalletRegistered {
+        treasury: *treasury_info.key,
+        cold_wallet: *cold_wallet_info.key,
+    })
+    .emit();
+
+    resize_account(
+        treasury_info,
+        Treasury::LEN,
+        owner_info,
+        &Rent::get()?,
+        system_program_info,
+    )?;
+    Treasury::pack_account(treasury, &mut treasury_info.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_schedule_treasury_sweep(
+    program_id: &Pubkey,
+    amount: u64,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    if amount == 0 {
+        msg!("Amount of tokens to sweep can't be zero");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let owner_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let treasury_info = next_account_info(account_info_iter)?;
+    let treasury_associated_token_info = next_account_info(account_info_iter)?;
+
+    let config = load_config(program_id, config_info)?;
+    check_escrow_owner(program_id, escrow_info, owner_info)?;
+    check_treasury_address(program_id, escrow_info.key, treasury_info)?;
+    let mut treasury = load_treasury(program_id, treasury_info, treasury_associated_token_info)?;
+
+    let cold_wallet = *treasury.cold_wallet().ok_or_else(|| {
+        msg!("Treasury has no cold wallet registered");
+        RNDRError::UnspecifiedError
+    })?;
+    if treasury.sweep_amount != 0 {
+        msg!("A sweep of the treasury is already scheduled");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    let balance =
+        spl_token::state::Account::unpack(&treasury_associated_token_info.try_borrow_data()?)?
+            .amount;
+    if amount > balance {
+        msg!("Amount of tokens to sweep exceeds the treasury balance");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let executable_at = config
+        .sweep_executable_at(Clock::get()?.unix_timestamp)
+        .ok_or(RNDRError::MathError)?;
+    treasury.sweep_amount = amount;
+    treasury.sweep_executable_at = executable_at;
+    RNDREvent::TreasurySweepScheduled(TreasurySweepScheduled {
+        treasury: *treasury_info.key,
+        cold_wallet,
+        amount,
+        executable_at,
+    })
+    .emit();
+
+    Treasury::pack_account(treasury, &mut treasury_info.try_borrow_mut_data()?)?;
+
+    invariants::check_treasury_vault(treasury_info, treasury_associated_token_info)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_sweep_to_cold_wallet(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let escrow_info = next_account_info(account_info_iter)?;
+    let treasury_info = next_account_info(account_info_iter)?;
+    let treasury_associated_token_info = next_account_info(account_info_iter)?;
+    let cold_wallet_info = next_account_info(account_info_iter)?;
+    // Programs
+    let token_program_info = next_account_info(account_info_iter)?;
+
+    Escrow::unpack_account(&escrow_info.try_borrow_data()?)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let bump_seed = check_treasury_address(program_id, escrow_info.key, treasury_info)?;
+    let mut treasury = load_treasury(program_id, treasury_info, treasury_associated_token_info)?;
+    if treasury.sweep_amount == 0 {
+        msg!("No sweep of the treasury is scheduled");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if &treasury.cold_wallet != cold_wallet_info.key {
+        msg!("Treasury cold wallet does not match the cold wallet provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if Clock::get()?.unix_timestamp < treasury.sweep_executable_at {
+        msg!("Sweep delay of the treasury has not passed");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let amount = treasury.sweep_amount;
+    let treasury_seeds: &[&[_]] = &[b"treasury", escrow_info.key.as_ref(), &[bump_seed]];
+
+    invoke_signed(
+        &spl_token::instruction::transfer(
+            token_program_info.key,
+            treasury_associated_token_info.key,
+            cold_wallet_info.key,
+            treasury_info.key,
+            &[],
+            amount,
+        )?,
+        &[
+            treasury_associated_token_info.clone(),
+            cold_wallet_info.clone(),
+            treasury_info.clone(),
+            token_program_info.clone(),
+        ],
+        &[treasury_seeds],
+    )?;
+
+    treasury.sweep_amount = 0;
+    treasury.sweep_executable_at = 0;
+    RNDREvent::TreasurySwept(TreasurySwept {
+        treasury: *treasury_info.key,
+        cold_wallet: *cold_wallet_info.key,
+        amount,
+    })
+    .emit();
+
+    Treasury::pack_account(treasury, &mut treasury_info.try_borrow_mut_data()?)?;
+
+    invariants::check_treasury_vault(treasury_info, treasury_associated_token_info)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_cancel_treasury_sweep(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let guardian_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let treasury_info = next_account_info(account_info_iter)?;
+    let treasury_associated_token_info = next_account_info(account_info_iter)?;
+
+    let config = load_config(program_id, config_info)?;
+    if config.guardian() != Some(guardian_info.key) {
+        msg!("Config guardian does not match the guardian provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if !guardian_info.is_signer {
+        msg!("Guardian provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    check_treasury_address(program_id, escrow_info.key, treasury_info)?;
+    let mut treasury = load_treasury(program_id, treasury_info, treasury_associated_token_info)?;
+    if treasury.sweep_amount == 0 {
+        msg!("No sweep of the treasury is scheduled");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if Clock::get()?.unix_timestamp >= treasury.sweep_executable_at {
+        msg!("Sweep delay of the treasury has passed, so the sweep can't be cancelled");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    RNDREvent::TreasurySweepCancelled(TreasurySweepCancelled {
+        treasury: *treasury_info.key,
+        amount: treasury.sweep_amount,
+    })
+    .emit();
+    treasury.sweep_amount = 0;
+    treasury.sweep_executable_at = 0;
+
+    Treasury::pack_account(treasury, &mut treasury_info.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_get_version() -> ProgramResult {
+    msg!(
+        "Version: {}.{}.{}, layout version: {}",
+        VERSION.major,
+        VERSION.minor,
+        VERSION.patch,
+        VERSION.layout_version
+    );
+    set_return_data(&VERSION.pack());
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_get_escrow_summary(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let escrow_info = next_account_info(account_info_iter)?;
+
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    let escrow = Escrow::unpack_account(&escrow_info.try_borrow_data()?)?;
+    set_return_data(&EscrowSummary::new(escrow_info.key, &escrow).pack());
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_get_job_summary(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let escrow_info = next_account_info(account_info_iter)?;
+    let job_info = next_account_info(account_info_iter)?;
+
+    let (job, _bump_seed) = load_escrow_job(program_id, escrow_info, job_info)?;
+    set_return_data(&JobSummary::new(&job).pack());
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_get_escrowed_amount(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let escrow_info = next_account_info(account_info_iter)?;
+    let job_info = next_account_info(account_info_iter)?;
+
+    let (job, _bump_seed) = load_escrow_job(program_id, escrow_info, job_info)?;
+    set_return_data(&job.amount.to_le_bytes());
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_reconcile_escrow(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let escrow_info = next_account_info(account_info_iter)?;
+    let token_account_info = next_account_info(account_info_iter)?;
+    let payer_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    let mut escrow = Escrow::unpack_account(&escrow_info.try_borrow_data()?)?;
+    resolve_legacy_escrow_token_mint(program_id, escrow_info, &mut escrow, token_account_info)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if &escrow.token_account(escrow_info.key) != token_account_info.key {
+        msg!("Escrow token account does not match the token account provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    // Shards hold amounts of tokens that are already in the escrow token account, so every one of
+    // them is counted, or the tracked amount could be understated to clear the flag
+    let mut tracked = escrow.amount;
+    for index in 0..ESCROW_SHARD_COUNT {
+        let escrow_shard_info = next_account_info(account_info_iter)?;
+        let (escrow_shard_address, _bump_seed) = Pubkey::find_program_address(
+            &[b"escrow_shard", escrow_info.key.as_ref(), &[index]],
+            program_id,
+        );
+        if &escrow_shard_address != escrow_shard_info.key {
+            msg!(
+                "Escrow shard program derived address does not match the escrow shard address provided"
+            );
+            return Err(ProgramError::InvalidSeeds);
+        }
+        if escrow_shard_info.try_data_is_empty()? {
+            continue;
+        }
+        if escrow_shard_info.owner != program_id {
+            msg!("Escrow shard provided is not owned by the RNDR program");
+            return Err(RNDRError::UnspecifiedError.into());
+        }
+
+        let escrow_shard = EscrowShard::unpack(&escrow_shard_info.try_borrow_data()?)?;
+        tracked = tracked
+            .checked_add(escrow_shard.amount)
+            .ok_or(RNDRError::MathError)?;
+    }
+
+    let balance = spl_token::state::Account::unpack(&token_account_info.try_borrow_data()?)?.amount;
+    let needs_attention = balance < tracked;
+    if needs_attention {
+        msg!(
+            "Escrow token account balance {} is less than the tracked amount {}",
+            balance,
+            tracked
+        );
+        RNDREvent::EscrowDiscrepancyFound(EscrowDiscrepancyFound {
+            escrow: *escrow_info.key,
+            balance,
+            tracked,
+        })
+        .emit();
+    }
+
+    if escrow.needs_attention != needs_attention {
+        escrow.needs_attention = needs_attention;
+        resize_account(
+            escrow_info,
+            Escrow::LEN,
+            payer_info,
+            &Rent::get()?,
+            system_program_info,
+        )?;
+        Escrow::pack_account(escrow, &mut escrow_info.try_borrow_mut_data()?)?;
+    }
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_execute_queued_action(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let queued_action_info = next_account_info(account_info_iter)?;
+    let target_info = next_account_info(account_info_iter)?;
+    // Optional accounts
+    let payer_info = account_info_iter.next();
+    let system_program_info = account_info_iter.next();
+
+    let mut queued_action = load_queued_action(program_id, queued_action_info)?;
+    if &queued_action.target != target_info.key {
+        msg!("Queued action target does not match the target provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    let now = Clock::get()?.unix_timestamp;
+    if now < queued_action.executable_at {
+        msg!("Timelock of the queued action has not passed");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    match queued_action.action {
+        AdminAction::SetEscrowOwner(new_owner) => {
+            let mut escrow = Escrow::unpack_account(&target_info.try_borrow_data()?)?;
+            if target_info.owner != program_id {
+                msg!("Escrow provided is not owned by the RNDR program");
+                return Err(RNDRError::UnspecifiedError.into());
+            }
+            if escrow.owner != queued_action.authority {
+                msg!("Escrow owner is no longer the authority that queued the action");
+                return Err(RNDRError::UnspecifiedError.into());
+            }
+
+            RNDREvent::OwnerRotated(OwnerRotated {
+                escrow: *target_info.key,
+                previous_owner: escrow.owner,
+                new_owner,
+            })
+            .emit();
+            escrow.set_owner(new_owner, Clock::get()?.slot);
+            Escrow::pack_account(escrow, &mut target_info.try_borrow_mut_data()?)?;
+        }
+        AdminAction::SetConfig(change) => {
+            let mut config = load_config(program_id, target_info)?;
+            if config.governance != queued_action.authority {
+                msg!(
+                    "Config governance authority is no longer the authority that queued the action"
+                );
+                return Err(RNDRError::UnspecifiedError.into());
+            }
+
+            change.validate()?;
+            let previous = config.apply(change);
+            RNDREvent::ConfigChanged(ConfigChanged { previous, change }).emit();
+
+            pack_config(config, target_info, payer_info, system_program_info)?;
+        }
+    }
+
+    queued_action.executed_at = now;
+    QueuedAction::pack(
+        queued_action,
+        &mut queued_action_info.try_borrow_mut_data()?,
+    )?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_cancel_queued_action(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let guardian_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+    let queued_action_info = next_account_info(account_info_iter)?;
+
+    let config = load_config(program_id, config_info)?;
+    if config.guardian() != Some(guardian_info.key) {
+        msg!("Config guardian does not match the guardian provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if !guardian_info.is_signer {
+        msg!("Guardian provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let mut queued_action = load_queued_action(program_id, queued_action_info)?;
+    let now = Clock::get()?.unix_timestamp;
+    if now >= queued_action.executable_at {
+        msg!("Timelock of the queued action has passed, so it can't be cancelled");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    queued_action.cancelled_at = now;
+    RNDREvent::ActionCancelled(ActionCancelled {
+        action: queued_action.action,
+    })
+    .emit();
+
+    QueuedAction::pack(
+        queued_action,
+        &mut queued_action_info.try_borrow_mut_data()?,
+    )?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_set_recovery_council(
+    program_id: &Pubkey,
+    threshold: u8,
+    guardians: Vec<Pubkey>,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    if threshold == 0 || threshold as usize > guardians.len() {
+        msg!("Recovery threshold must be between one and the number of guardians");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    for (i, guardian) in guardians.iter().enumerate() {
+        if guardian == &Pubkey::default() || guardians[..i].contains(guardian) {
+            msg!("Recovery guardians must be distinct and can't be the default pubkey");
+            return Err(RNDRError::UnspecifiedError.into());
+        }
+    }
+
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let escrow_info = next_account_info(account_info_iter)?;
+    let owner_info = next_account_info(account_info_iter)?;
+    let recovery_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    check_escrow_owner(program_id, escrow_info, owner_info)?;
+    let bump_seed = check_recovery_address(program_id, escrow_info, recovery_info)?;
+
+    // Reallocate legacy escrows now, so the owner history can be written when the recovery is
+    // executed by anyone
+    resize_account(
+        escrow_info,
+        Escrow::LEN,
+        owner_info,
+        &Rent::get()?,
+        system_program_info,
+    )?;
+
+    if recovery_info.try_data_is_empty()? {
+        let recovery_seeds: &[&[_]] = &[b"recovery", escrow_info.key.as_ref(), &[bump_seed]];
+
+        create_program_account(
+            program_id,
+            owner_info,
+            recovery_info,
+            recovery_seeds,
+            Recovery::LEN,
+            &Rent::get()?,
+            system_program_info,
+        )?;
+    } else {
+        load_recovery(program_id, recovery_info)?;
+    }
+
+    RNDREvent::RecoveryCouncilSet(RecoveryCouncilSet {
+        threshold,
+        guardian_count: guardians.len() as u8,
+    })
+    .emit();
+
+    let recovery = Recovery::new(InitRecoveryParams {
+        escrow: *escrow_info.key,
+        threshold,
+        guardians,
+    });
+
+    Recovery::pack(recovery, &mut recovery_info.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_approve_recovery(
+    program_id: &Pubkey,
+    new_owner: Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    if new_owner == Pubkey::default() {
+        msg!("New owner can't be the default pubkey");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let guardian_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let recovery_info = next_account_info(account_info_iter)?;
+
+    if !guardian_info.is_signer {
+        msg!("Guardian provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    Escrow::unpack_account(&escrow_info.try_borrow_data()?)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    check_recovery_address(program_id, escrow_info, recovery_info)?;
+
+    let mut recovery = load_recovery(program_id, recovery_info)?;
+    let index = recovery.guardian_index(guardian_info.key).ok_or_else(|| {
+        msg!("Guardian provided is not on the recovery council");
+        RNDRError::UnspecifiedError
+    })?;
+    recovery.votes[index] = new_owner;
+
+    // The delay restarts whenever the threshold agrees on a different new owner
+    if recovery.approvals(&new_owner) >= recovery.threshold as usize
+        && (!recovery.is_pending() || recovery.new_owner != new_owner)
+    {
+        let recovery_delay = UnixTimestamp::try_from(RECOVERY_DELAY).unwrap_or(i64::MAX);
+        recovery.new_owner = new_owner;
+        recovery.executable_at = Clock::get()?.unix_timestamp.saturating_add(recovery_delay);
+        RNDREvent::RecoveryStarted(RecoveryStarted {
+            new_owner,
+            executable_at: recovery.executable_at,
+        })
+        .emit();
+    }
+
+    Recovery::pack(recovery, &mut recovery_info.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_execute_recovery(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let escrow_info = next_account_info(account_info_iter)?;
+    let recovery_info = next_account_info(account_info_iter)?;
+
+    let mut escrow = Escrow::unpack_account(&escrow_info.try_borrow_data()?)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    check_recovery_address(program_id, escrow_info, recovery_info)?;
+
+    let mut recovery = load_recovery(program_id, recovery_info)?;
+    if !recovery.is_pending() {
+        msg!("Recovery is not pending");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if Clock::get()?.unix_timestamp < recovery.executable_at {
+        msg!("Delay of the recovery has not passed");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if recovery.approvals(&recovery.new_owner) < recovery.threshold as usize {
+        msg!("Recovery is no longer approved by the threshold of guardians");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    escrow.set_owner(recovery.new_owner, Clock::get()?.slot);
+    RNDREvent::EscrowOwnerRecovered(EscrowOwnerRecovered {
+        owner: escrow.owner,
+    })
+    .emit();
+
+    let guardians = std::mem::take(&mut recovery.guardians);
+    recovery.init(InitRecoveryParams {
+        escrow: *escrow_info.key,
+        threshold: recovery.threshold,
+        guardians,
+    });
+
+    Escrow::pack_account(escrow, &mut escrow_info.try_borrow_mut_data()?)?;
+    Recovery::pack(recovery, &mut recovery_info.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_challenge_result(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Source accounts
+    let authority_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    // Destination accounts
+    let job_info = next_account_info(account_info_iter)?;
+    // Optional accounts
+    let session_key_info = account_info_iter.next();
+
+    if !authority_info.is_signer {
+        msg!("Authority provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let escrow = Escrow::unpack_account(&escrow_info.try_borrow_data()?)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let mut job = Job::unpack_account(&job_info.try_borrow_data()?)?;
+    if job_info.owner != program_id {
+        msg!("Job provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let (job_pubkey, _bump_seed) = Pubkey::find_program_address(
+        &[
+            b"job",
+            escrow_info.key.as_ref(),
+            job.address_authority().as_ref(),
+            &job_id_seed(job.id),
+        ],
+        program_id,
+    );
+    if &job_pubkey != job_info.key {
+        msg!("Job program derived address does not match the job address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+    let authority = match session_key_info {
+        Some(session_key_info) => load_session_key_authority(
+            program_id,
+            escrow_info.key,
+            authority_info,
+            session_key_info,
+            SESSION_KEY_CHALLENGE_RESULT,
+        )?,
+        None => *authority_info.key,
+    };
+    if job.authority != authority {
+        msg!("Job authority does not match the authority provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if job.status != JobStatus::Revealed {
+        msg!("Job result has not been revealed or payment has already been claimed");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if Clock::get()?.unix_timestamp >= challenge_deadline(&job, &escrow) {
+        msg!("Job challenge window has passed");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    job.status = JobStatus::Challenged;
+
+    Job::pack_account(job, &mut job_info.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_add_verifier(
+    program_id: &Pubkey,
+    verifier: Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let escrow_info = next_account_info(account_info_iter)?;
+    let owner_info = next_account_info(account_info_iter)?;
+    let verifier_registry_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    check_escrow_owner(program_id, escrow_info, owner_info)?;
+    let bump_seed =
+        check_verifier_registry_address(program_id, escrow_info, verifier_registry_info)?;
+
+    let mut verifier_registry = if verifier_registry_info.try_data_is_empty()? {
+        let verifier_registry_seeds: &[&[_]] =
+            &[b"verifier_registry", escrow_info.key.as_ref(), &[bump_seed]];
+
+        create_program_account(
+            program_id,
+            owner_info,
+            verifier_registry_info,
+            verifier_registry_seeds,
+            VerifierRegistry::LEN,
+            &Rent::get()?,
+            system_program_info,
+        )?;
+
+        VerifierRegistry::new(InitVerifierRegistryParams {
+            escrow: *escrow_info.key,
+        })
+    } else {
+        load_verifier_registry(program_id, verifier_registry_info)?
+    };
+
+    if verifier_registry.contains(&verifier) {
+        msg!("Verifier is already registered");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if verifier_registry.verifiers.len() >= MAX_VERIFIERS {
+        msg!("Too many verifiers are registered");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    verifier_registry.verifiers.push(verifier);
+
+    VerifierRegistry::pack(
+        verifier_registry,
+        &mut verifier_registry_info.try_borrow_mut_data()?,
+    )?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_remove_verifier(
+    program_id: &Pubkey,
+    verifier: Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    // Accounts
+    let escrow_info = next_account_info(account_info_iter)?;
+    let owner_info = next_account_info(account_info_iter)?;
+    let verifier_registry_info = next_account_info(account_info_iter)?;
+
+    check_escrow_owner(program_id, escrow_info, owner_info)?;
+    check_verifier_registry_address(program_id, escrow_info, verifier_registry_info)?;
+
+    let mut verifier_registry = load_verifier_registry(program_id, verifier_registry_info)?;
+    let index = verifier_registry
+        .verifiers
+        .iter()
+        .position(|registered| registered == &verifier)
+        .ok_or_else(|| {
+            msg!("Verifier is not registered");
+            RNDRError::UnspecifiedError
+        })?;
+    verifier_registry.verifiers.remove(index);
+
+    VerifierRegistry::pack(
+        verifier_registry,
+        &mut verifier_registry_info.try_borrow_mut_data()?,
+    )?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_flag_job(
+    program_id: &Pubkey,
+    evidence_hash: [u8; HASH_BYTES],
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    if evidence_hash == [0; HASH_BYTES] {
+        msg!("Evidence hash can't be zero");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let account_info_iter = &mut accounts.iter();
+    // Source accounts
+    let verifier_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let verifier_registry_info = next_account_info(account_info_iter)?;
+    // Destination accounts
+    let job_info = next_account_info(account_info_iter)?;
+
+    if !verifier_info.is_signer {
+        msg!("Verifier provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    Escrow::unpack_account(&escrow_info.try_borrow_data()?)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    check_verifier_registry_address(program_id, escrow_info, verifier_registry_info)?;
+    let verifier_registry = load_verifier_registry(program_id, verifier_registry_info)?;
+    if !verifier_registry.contains(verifier_info.key) {
+        msg!("Verifier provided is not registered");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let mut job = Job::unpack_account(&job_info.try_borrow_data()?)?;
+    if job_info.owner != program_id {
+        msg!("Job provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let (job_pubkey, _bump_seed) = Pubkey::find_program_address(
+        &[
+            b"job",
+            escrow_info.key.as_ref(),
+            job.address_authority().as_ref(),
+            &job_id_seed(job.id),
+        ],
+        program_id,
+    );
+    if &job_pubkey != job_info.key {
+        msg!("Job program derived address does not match the job address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    match job.status {
+        JobStatus::Committed | JobStatus::Revealed | JobStatus::Challenged => {}
+        JobStatus::Open => {
+            msg!("Job result has not been committed to");
+            return Err(RNDRError::UnspecifiedError.into());
+        }
+        JobStatus::Paid => {
+            msg!("Job payment has already been claimed");
+            return Err(RNDRError::UnspecifiedError.into());
+        }
+        JobStatus::Disputed => {
+            msg!("Job is already disputed");
+            return Err(RNDRError::JobDisputed.into());
+        }
+        JobStatus::Resolved => {
+            msg!("Job dispute has already been resolved");
+            return Err(RNDRError::UnspecifiedError.into());
+        }
+        JobStatus::Cancelled => {
+            msg!("Job has been cancelled");
+            return Err(RNDRError::UnspecifiedError.into());
+        }
+    }
+
+    job.status = JobStatus::Disputed;
+    job.verifier = *verifier_info.key;
+    job.evidence_hash = evidence_hash;
+
+    Job::pack_account(job, &mut job_info.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
+
+#[inline(never)] // avoid stack frame limit
+fn process_select_verifiers(
+    program_id: &Pubkey,
+    count: u8,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let count = count as usize;
+    if count == 0 || count > MAX_SELECTED_VERIFIERS {
+        msg!("Number of verifiers to select is invalid");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let account_info_iter = &mut accounts.iter();
+    // Source accounts
+    let funder_info = next_account_info(account_info_iter)?;
+    let escrow_info = next_account_info(account_info_iter)?;
+    let verifier_registry_info = next_account_info(account_info_iter)?;
+    let job_info = next_account_info(account_info_iter)?;
+    // Destination accounts
+    let verifier_selection_info = next_account_info(account_info_iter)?;
+    // Sysvars
+    let slot_hashes_info = next_account_info(account_info_iter)?;
+    // Programs
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    Escrow::unpack_account(&escrow_info.try_borrow_data()?)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    check_verifier_registry_address(program_id, escrow_info, verifier_registry_info)?;
+    let verifier_registry = load_verifier_registry(program_id, verifier_registry_info)?;
+    if count > verifier_registry.verifiers.len() {
+        msg!("Not enough verifiers are registered");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let job = Job::unpack_account(&job_info.try_borrow_data()?)?;
+    if job_info.owner != program_id {
+        msg!("Job provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let (job_pubkey, _bump_seed) = Pubkey::find_program_address(
+        &[
+            b"job",
+            escrow_info.key.as_ref(),
+            job.address_authority().as_ref(),
+            &job_id_seed(job.id),
+        ],
+        program_id,
+    );
+    if &job_pubkey != job_info.key {
+        msg!("Job program derived address does not match the job address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if job.status != JobStatus::Committed && job.status != JobStatus::Revealed {
+        msg!("Job result has not been committed to or payment has already been claimed");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let (verifier_selection_address, bump_seed) =
+        Pubkey::find_program_address(&[b"verifier_selection", job_info.key.as_ref()], program_id);
+    if &verifier_selection_address != verifier_selection_info.key {
+        msg!("Verifier selection program derived address does not match the verifier selection address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if !verifier_selection_info.try_data_is_empty()? {
+        msg!("Verifiers have already been selected for the job");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let (slot, slot_hash) = most_recent_slot_hash(slot_hashes_info)?;
+
+    let verifier_selection_seeds: &[&[_]] =
+        &[b"verifier_selection", job_info.key.as_ref(), &[bump_seed]];
+
+    create_program_account(
+        program_id,
+        funder_info,
+        verifier_selection_info,
+        verifier_selection_seeds,
+        VerifierSelection::LEN,
+        &Rent::get()?,
+        system_program_info,
+    )?;
+
+    let verifier_selection = VerifierSelection::new(InitVerifierSelectionParams {
+        job: *job_info.key,
+        slot,
+        slot_hash,
+        registered: &verifier_registry.verifiers,
+        count,
+    });
+    msg!(
+        "Selected {} verifiers with the hash of slot {}",
+        verifier_selection.verifiers.len(),
+        slot
+    );
+
+    VerifierSelection::pack(
+        verifier_selection,
+        &mut verifier_selection_info.try_borrow_mut_data()?,
+    )?;
+
+    Ok(())
+}
+
+/// Read the most recent entry of the SlotHashes sysvar without deserializing all of it, which
+/// doesn't fit in the program's compute and heap budget
+fn most_recent_slot_hash(slot_hashes_info: &AccountInfo) -> Result<(Slot, Hash), ProgramError> {
+    if !sysvar::slot_hashes::check_id(slot_hashes_info.key) {
+        msg!("SlotHashes sysvar provided is invalid");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let data = slot_hashes_info.try_borrow_data()?;
+    // Entries are a u64 length followed by (slot, hash) pairs, most recent first
+    let entry = data.get(8..8 + 8 + HASH_BYTES).ok_or_else(|| {
+        msg!("SlotHashes sysvar has no entries");
+        ProgramError::InvalidAccountData
+    })?;
+    let (slot, hash) = entry.split_at(8);
+    let mut slot_bytes = [0; 8];
+    slot_bytes.copy_from_slice(slot);
+
+    Ok((Slot::from_le_bytes(slot_bytes), Hash::new(hash)))
+}
+
+/// Check that no account is passed in more than one of the roles provided, such as a token
+/// account passed as both the source and the destination of a transfer
+fn check_distinct_accounts(account_infos: &[&AccountInfo]) -> ProgramResult {
+    for (i, account_info) in account_infos.iter().enumerate() {
+        if account_infos[..i]
+            .iter()
+            .any(|other_info| other_info.key == account_info.key)
+        {
+            msg!(
+                "Account {} is provided in more than one role",
+                account_info.key
+            );
+            return Err(RNDRError::DuplicateAccount.into());
+        }
+    }
+
+    Ok(())
+}
+
+/// Check that an escrow is owned by the program and that its owner authority signed
+fn check_escrow_owner(
+    program_id: &Pubkey,
+    escrow_info: &AccountInfo,
+    owner_info: &AccountInfo,
+) -> ProgramResult {
+    let escrow = Escrow::unpack_account(&escrow_info.try_borrow_data()?)?;
+    if escrow_info.owner != program_id {
+        msg!("Escrow provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if &escrow.owner != owner_info.key {
+        msg!("Escrow owner does not match the owner provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if !owner_info.is_signer {
+        msg!("Owner provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    Ok(())
+}
+
+/// Check that a verifier registry is the PDA of an escrow, returning its bump seed
+fn check_verifier_registry_address(
+    program_id: &Pubkey,
+    escrow_info: &AccountInfo,
+    verifier_registry_info: &AccountInfo,
+) -> Result<u8, ProgramError> {
+    let (verifier_registry_address, bump_seed) = Pubkey::find_program_address(
+        &[b"verifier_registry", escrow_info.key.as_ref()],
+        program_id,
+    );
+    if &verifier_registry_address != verifier_registry_info.key {
+        msg!("Verifier registry program derived address does not match the verifier registry address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    Ok(bump_seed)
+}
+
+/// Load a verifier registry owned by the program
+fn load_verifier_registry(
+    program_id: &Pubkey,
+    verifier_registry_info: &AccountInfo,
+) -> Result<VerifierRegistry, ProgramError> {
+    let verifier_registry = VerifierRegistry::unpack(&verifier_registry_info.try_borrow_data()?)?;
+    if verifier_registry_info.owner != program_id {
+        msg!("Verifier registry provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    Ok(verifier_registry)
+}
+
+/// Check that a recovery is the PDA of an escrow, returning its bump seed
+fn check_recovery_address(
+    program_id: &Pubkey,
+    escrow_info: &AccountInfo,
+    recovery_info: &AccountInfo,
+) -> Result<u8, ProgramError> {
+    let (recovery_address, bump_seed) =
+        Pubkey::find_program_address(&[b"recovery", escrow_info.key.as_ref()], program_id);
+    if &recovery_address != recovery_info.key {
+        msg!("Recovery program derived address does not match the recovery address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    Ok(bump_seed)
+}
+
+/// Load a recovery owned by the program
+fn load_recovery(
+    program_id: &Pubkey,
+    recovery_info: &AccountInfo,
+) -> Result<Recovery, ProgramError> {
+    let recovery = Recovery::unpack(&recovery_info.try_borrow_data()?)?;
+    if recovery_info.owner != program_id {
+        msg!("Recovery provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    Ok(recovery)
+}
+
+/// Check that a reward pool is the PDA of an escrow and epoch, returning its bump seed
+fn check_reward_pool_address(
+    program_id: &Pubkey,
+    escrow: &Pubkey,
+    epoch: Epoch,
+    reward_pool_info: &AccountInfo,
+) -> Result<u8, ProgramError> {
+    let (reward_pool_address, bump_seed) = Pubkey::find_program_address(
+        &[b"reward_pool", escrow.as_ref(), &epoch.to_le_bytes()],
+        program_id,
+    );
+    if &reward_pool_address != reward_pool_info.key {
+        msg!("Reward pool program derived address does not match the reward pool address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    Ok(bump_seed)
+}
+
+/// Load the reward pool of an escrow for an epoch, creating it and its associated token account
+/// if they don't exist
+#[allow(clippy::too_many_arguments)]
+fn load_or_create_reward_pool<'a>(
+    program_id: &Pubkey,
+    funder_info: &AccountInfo<'a>,
+    escrow_info: &AccountInfo<'a>,
+    epoch: Epoch,
+    token_mint_info: &AccountInfo<'a>,
+    reward_pool_info: &AccountInfo<'a>,
+    reward_pool_associated_token_info: &AccountInfo<'a>,
+    system_program_info: &AccountInfo<'a>,
+    token_program_info: &AccountInfo<'a>,
+    associated_token_program_info: &AccountInfo<'a>,
+) -> Result<RewardPool, ProgramError> {
+    let bump_seed =
+        check_reward_pool_address(program_id, escrow_info.key, epoch, reward_pool_info)?;
+    let reward_pool = if reward_pool_info.try_data_is_empty()? {
+        let epoch_seed = epoch.to_le_bytes();
+        let reward_pool_seeds: &[&[_]] = &[
+            b"reward_pool",
+            escrow_info.key.as_ref(),
+            &epoch_seed,
+            &[bump_seed],
+        ];
+
+        create_program_account(
+            program_id,
+            funder_info,
+            reward_pool_info,
+            reward_pool_seeds,
+            RewardPool::LEN,
+            &Rent::get()?,
+            system_program_info,
+        )?;
+
+        RewardPool::new(InitRewardPoolParams {
+            escrow: *escrow_info.key,
+            epoch,
+        })
+    } else {
+        load_reward_pool(program_id, reward_pool_info)?
+    };
+
+    let reward_pool_associated_token_address =
+        get_associated_token_address(reward_pool_info.key, token_mint_info.key);
+    if &reward_pool_associated_token_address != reward_pool_associated_token_info.key {
+        msg!("Reward pool associated token address does not match the associated token address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    if reward_pool_associated_token_info.try_data_is_empty()? {
+        invoke(
+            &Instruction {
+                program_id: *associated_token_program_info.key,
+                accounts: vec![
+                    AccountMeta::new(*funder_info.key, true),
+                    AccountMeta::new(*reward_pool_associated_token_info.key, false),
+                    AccountMeta::new_readonly(*reward_pool_info.key, false),
+                    AccountMeta::new_readonly(*token_mint_info.key, false),
+                    AccountMeta::new_readonly(*system_program_info.key, false),
+                    AccountMeta::new_readonly(*token_program_info.key, false),
+                ],
+                data: vec![],
+            },
+            &[
+                funder_info.clone(),
+                reward_pool_associated_token_info.clone(),
+                reward_pool_info.clone(),
+                token_mint_info.clone(),
+                system_program_info.clone(),
+                token_program_info.clone(),
+            ],
+        )?;
+    }
+
+    Ok(reward_pool)
+}
+
+/// Load a reward pool owned by the program
+fn load_reward_pool(
+    program_id: &Pubkey,
+    reward_pool_info: &AccountInfo,
+) -> Result<RewardPool, ProgramError> {
+    let reward_pool = RewardPool::unpack(&reward_pool_info.try_borrow_data()?)?;
+    if reward_pool_info.owner != program_id {
+        msg!("Reward pool provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    Ok(reward_pool)
+}
+
+/// Check that a Merkle distribution is the PDA of an escrow and epoch, returning its bump seed
+fn check_merkle_distribution_address(
+    program_id: &Pubkey,
+    escrow: &Pubkey,
+    epoch: Epoch,
+    merkle_distribution_info: &AccountInfo,
+) -> Result<u8, ProgramError> {
+    let (merkle_distribution_address, bump_seed) = Pubkey::find_program_address(
+        &[
+            b"merkle_distribution",
+            escrow.as_ref(),
+            &epoch.to_le_bytes(),
+        ],
+        program_id,
+    );
+    if &merkle_distribution_address != merkle_distribution_info.key {
+        msg!("Merkle distribution program derived address does not match the Merkle distribution address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    Ok(bump_seed)
+}
+
+/// Load a Merkle distribution owned by the program
+fn load_merkle_distribution(
+    program_id: &Pubkey,
+    merkle_distribution_info: &AccountInfo,
+) -> Result<MerkleDistribution, ProgramError> {
+    let merkle_distribution =
+        MerkleDistribution::unpack(&merkle_distribution_info.try_borrow_data()?)?;
+    if merkle_distribution_info.owner != program_id {
+        msg!("Merkle distribution provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    Ok(merkle_distribution)
+}
+
+/// Check that an emission schedule is the PDA of an escrow, returning its bump seed
+fn check_emission_schedule_address(
+    program_id: &Pubkey,
+    escrow: &Pubkey,
+    emission_schedule_info: &AccountInfo,
+) -> Result<u8, ProgramError> {
+    let (emission_schedule_address, bump_seed) =
+        Pubkey::find_program_address(&[b"emission_schedule", escrow.as_ref()], program_id);
+    if &emission_schedule_address != emission_schedule_info.key {
+        msg!("Emission schedule program derived address does not match the emission schedule address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    Ok(bump_seed)
+}
+
+/// Load an emission schedule owned by the program
+fn load_emission_schedule(
+    program_id: &Pubkey,
+    emission_schedule_info: &AccountInfo,
+) -> Result<EmissionSchedule, ProgramError> {
+    let emission_schedule = EmissionSchedule::unpack(&emission_schedule_info.try_borrow_data()?)?;
+    if emission_schedule_info.owner != program_id {
+        msg!("Emission schedule provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    Ok(emission_schedule)
+}
+
+/// Check that an epoch work is the PDA of a reward pool and node, returning its bump seed
+fn check_epoch_work_address(
+    program_id: &Pubkey,
+    reward_pool_info: &AccountInfo,
+    node: &Pubkey,
+    epoch_work_info: &AccountInfo,
+) -> Result<u8, ProgramError> {
+    let (epoch_work_address, bump_seed) = Pubkey::find_program_address(
+        &[b"epoch_work", reward_pool_info.key.as_ref(), node.as_ref()],
+        program_id,
+    );
+    if &epoch_work_address != epoch_work_info.key {
+        msg!("Epoch work program derived address does not match the epoch work address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    Ok(bump_seed)
+}
+
+/// Load an epoch work owned by the program
+fn load_epoch_work(
+    program_id: &Pubkey,
+    epoch_work_info: &AccountInfo,
+) -> Result<EpochWork, ProgramError> {
+    let epoch_work = EpochWork::unpack(&epoch_work_info.try_borrow_data()?)?;
+    if epoch_work_info.owner != program_id {
+        msg!("Epoch work provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    Ok(epoch_work)
+}
+
+/// Claim the reward of a node for an ended epoch into a destination token account, returning the
+/// amount claimed
+fn claim_epoch_reward<'a>(
+    program_id: &Pubkey,
+    node: &Pubkey,
+    reward_pool_info: &AccountInfo<'a>,
+    reward_pool_associated_token_info: &AccountInfo<'a>,
+    epoch_work_info: &AccountInfo<'a>,
+    destination_token_info: &AccountInfo<'a>,
+    token_program_info: &AccountInfo<'a>,
+) -> Result<u64, ProgramError> {
+    let mut reward_pool = load_reward_pool(program_id, reward_pool_info)?;
+    if Clock::get()?.epoch <= reward_pool.epoch {
+        msg!("Epoch of the reward pool has not ended");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    check_epoch_work_address(program_id, reward_pool_info, node, epoch_work_info)?;
+    let mut epoch_work = load_epoch_work(program_id, epoch_work_info)?;
+    if epoch_work.claimed {
+        msg!("Epoch reward has already been claimed");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let reward = reward_pool
+        .reward(epoch_work.work)
+        .ok_or(RNDRError::MathError)?;
+    if reward == 0 {
+        msg!("Node has no reward to claim");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    epoch_work.claimed = true;
+    reward_pool.claimed = reward_pool
+        .claimed
+        .checked_add(reward)
+        .ok_or(RNDRError::MathError)?;
+    RNDREvent::EpochRewardClaimed(EpochRewardClaimed {
+        reward,
+        epoch: reward_pool.epoch,
+    })
+    .emit();
+
+    let escrow = reward_pool.escrow;
+    let epoch_seed = reward_pool.epoch.to_le_bytes();
+    let bump_seed =
+        check_reward_pool_address(program_id, &escrow, reward_pool.epoch, reward_pool_info)?;
+    let reward_pool_seeds: &[&[_]] = &[b"reward_pool", escrow.as_ref(), &epoch_seed, &[bump_seed]];
+
+    EpochWork::pack(epoch_work, &mut epoch_work_info.try_borrow_mut_data()?)?;
+    RewardPool::pack(reward_pool, &mut reward_pool_info.try_borrow_mut_data()?)?;
+
+    invoke_signed(
+        &spl_token::instruction::transfer(
+            token_program_info.key,
+            reward_pool_associated_token_info.key,
+            destination_token_info.key,
+            reward_pool_info.key,
+            &[],
+            reward,
+        )?,
+        &[
+            reward_pool_associated_token_info.clone(),
+            destination_token_info.clone(),
+            reward_pool_info.clone(),
+            token_program_info.clone(),
+        ],
+        &[reward_pool_seeds],
+    )?;
+
+    Ok(reward)
+}
+
+/// Check that a stake pool is the PDA of an escrow, returning its bump seed
+fn check_stake_pool_address(
+    program_id: &Pubkey,
+    escrow: &Pubkey,
+    stake_pool_info: &AccountInfo,
+) -> Result<u8, ProgramError> {
+    let (stake_pool_address, bump_seed) =
+        Pubkey::find_program_address(&[b"stake_pool", escrow.as_ref()], program_id);
+    if &stake_pool_address != stake_pool_info.key {
+        msg!("Stake pool program derived address does not match the stake pool address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    Ok(bump_seed)
+}
+
+/// Load a stake pool owned by the program, checking its associated token account
+fn load_stake_pool(
+    program_id: &Pubkey,
+    stake_pool_info: &AccountInfo,
+    stake_pool_associated_token_info: &AccountInfo,
+) -> Result<StakePool, ProgramError> {
+    let stake_pool = StakePool::unpack(&stake_pool_info.try_borrow_data()?)?;
+    if stake_pool_info.owner != program_id {
+        msg!("Stake pool provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let stake_pool_associated_token_address =
+        get_associated_token_address(stake_pool_info.key, &stake_pool.token_mint);
+    if &stake_pool_associated_token_address != stake_pool_associated_token_info.key {
+        msg!("Stake pool associated token address does not match the associated token address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    Ok(stake_pool)
+}
+
+/// Check that a treasury is the PDA of an escrow, returning its bump seed
+fn check_treasury_address(
+    program_id: &Pubkey,
+    escrow: &Pubkey,
+    treasury_info: &AccountInfo,
+) -> Result<u8, ProgramError> {
+    let (treasury_address, bump_seed) =
+        Pubkey::find_program_address(&[b"treasury", escrow.as_ref()], program_id);
+    if &treasury_address != treasury_info.key {
+        msg!("Treasury program derived address does not match the treasury address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    Ok(bump_seed)
+}
+
+/// Load a treasury owned by the program, checking its associated token account
+fn load_treasury(
+    program_id: &Pubkey,
+    treasury_info: &AccountInfo,
+    treasury_associated_token_info: &AccountInfo,
+) -> Result<Treasury, ProgramError> {
+    let treasury = Treasury::unpack_account(&treasury_info.try_borrow_data()?)?;
+    if treasury_info.owner != program_id {
+        msg!("Treasury provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let treasury_associated_token_address =
+        get_associated_token_address(treasury_info.key, &treasury.token_mint);
+    if &treasury_associated_token_address != treasury_associated_token_info.key {
+        msg!("Treasury associated token address does not match the associated token address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    Ok(treasury)
+}
+
+/// Split the stats account of an escrow off the end of an instruction's accounts, since it can be
+/// appended to any instruction that records token flows after that instruction's optional accounts
+fn split_stats_account<'a, 'b>(
+    program_id: &Pubkey,
+    accounts: &'b [AccountInfo<'a>],
+) -> (&'b [AccountInfo<'a>], Option<&'b AccountInfo<'a>>) {
+    if let Some((stats_info, rest)) = accounts.split_last() {
+        let is_stats = stats_info.owner == program_id
+            && stats_info
+                .try_borrow_data()
+                .map(|data| {
+                    (data.len() == Stats::LEN || data.len() == Stats::LEGACY_LEN)
+                        && data[ACCOUNT_TYPE_OFFSET] == u8::from(AccountType::StatsV1)
+                })
+                .unwrap_or(false);
+        if is_stats {
+            return (rest, Some(stats_info));
+        }
+    }
+    (accounts, None)
+}
+
+/// Split the escrow shards of an escrow off the end of an instruction's accounts, since they can be
+/// appended to any instruction that debits an escrow after that instruction's optional accounts, so
+/// the tokens funded through them are aggregated before the escrow is debited. The stats account
+/// must be split off first.
+///
+/// Accounts are only split off if their address is the PDA of one of the escrow's shards, so an
+/// optional account before them is never mistaken for one. Escrow shards that haven't been created
+/// yet have no data and hold no tokens, so they're split off as well, and skipped when aggregating.
+fn split_escrow_shard_accounts<'a, 'b>(
+    program_id: &Pubkey,
+    escrow: &Pubkey,
+    accounts: &'b [AccountInfo<'a>],
+) -> (&'b [AccountInfo<'a>], &'b [AccountInfo<'a>]) {
+    let is_escrow_shard_address = |account_info: &AccountInfo, index: u8| {
+        let (escrow_shard_address, _bump_seed) =
+            find_escrow_shard_address(program_id, escrow, index);
+        &escrow_shard_address == account_info.key
+    };
+    let is_escrow_shard = |account_info: &AccountInfo| {
+        if account_info.owner == program_id {
+            // An escrow shard stores its index, so only its own address is derived
+            let index = account_info
+                .try_borrow_data()
+                .ok()
+                .filter(|data| {
+                    data.len() == EscrowShard::LEN
+                        && data[ACCOUNT_TYPE_OFFSET] == u8::from(AccountType::EscrowShardV1)
+                })
+                .map(|data| data[EscrowShard::INDEX_OFFSET]);
+            index.is_some_and(|index| is_escrow_shard_address(account_info, index))
+        } else {
+            system_program::check_id(account_info.owner)
+                && account_info.data_is_empty()
+                && (0..ESCROW_SHARD_COUNT).any(|index| is_escrow_shard_address(account_info, index))
+        }
+    };
+    let shards = accounts
+        .iter()
+        .rev()
+        .take_while(|account_info| is_escrow_shard(account_info))
+        .count();
+    accounts.split_at(accounts.len() - shards)
+}
+
+/// Move the tokens funded through escrow shards into the escrow's amount, as `AggregateEscrowShard`
+/// does, so the escrow can be debited for jobs funded since the shards were last aggregated
+fn aggregate_escrow_shards(
+    program_id: &Pubkey,
+    escrow_info: &AccountInfo,
+    escrow: &mut Escrow,
+    escrow_shard_infos: &[AccountInfo],
+) -> ProgramResult {
+    for escrow_shard_info in escrow_shard_infos {
+        if escrow_shard_info.data_is_empty() {
+            continue;
+        }
+        let mut escrow_shard = EscrowShard::unpack(&escrow_shard_info.try_borrow_data()?)?;
+        if &escrow_shard.escrow != escrow_info.key {
+            msg!("Escrow shard escrow does not match the escrow provided");
+            return Err(RNDRError::UnspecifiedError.into());
+        }
+        let (escrow_shard_address, _bump_seed) = Pubkey::find_program_address(
+            &[
+                b"escrow_shard",
+                escrow_info.key.as_ref(),
+                &[escrow_shard.index],
+            ],
+            program_id,
+        );
+        if &escrow_shard_address != escrow_shard_info.key {
+            msg!(
+                "Escrow shard program derived address does not match the escrow shard address provided"
+            );
+            return Err(ProgramError::InvalidSeeds);
+        }
+
+        escrow.amount = escrow
+            .amount
+            .checked_add(escrow_shard.amount)
+            .ok_or(RNDRError::MathError)?;
+        escrow_shard.amount = 0;
+        EscrowShard::pack(escrow_shard, &mut escrow_shard_info.try_borrow_mut_data()?)?;
+    }
+
+    Ok(())
+}
+
+/// Log the value in RNDR of an amount of tokens a job is settled with, if it's funded in another
+/// token and has an exchange rate recorded
+fn emit_job_settled(job_info: &AccountInfo, job: &Job, amount: u64) -> ProgramResult {
+    if let Some(exchange_rate) = job.exchange_rate() {
+        RNDREvent::JobSettledAtExchangeRate(JobSettledAtExchangeRate {
+            job: *job_info.key,
+            amount,
+            rndr_value: exchange_rate.try_to_rndr(amount)?,
+        })
+        .emit();
+    }
+
+    Ok(())
+}
+
+/// Record a flow of tokens through an escrow in its stats, if they were provided
+fn record_stats(
+    program_id: &Pubkey,
+    stats_info: Option<&AccountInfo>,
+    escrow: &Pubkey,
+    flow: TokenFlow,
+    amount: u64,
+) -> ProgramResult {
+    let stats_info = match stats_info {
+        Some(stats_info) => stats_info,
+        None => return Ok(()),
+    };
+
+    check_stats_address(program_id, escrow, stats_info)?;
+    let mut stats = Stats::unpack_account(&stats_info.try_borrow_data()?)?;
+
+    stats
+        .record(Clock::get()?.epoch, flow, amount)
+        .ok_or(RNDRError::MathError)?;
+
+    Stats::pack_account(stats, &mut stats_info.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
+
+/// Check that stats are the PDA of an escrow, returning their bump seed
+fn check_stats_address(
+    program_id: &Pubkey,
+    escrow: &Pubkey,
+    stats_info: &AccountInfo,
+) -> Result<u8, ProgramError> {
+    let (stats_address, bump_seed) =
+        Pubkey::find_program_address(&[b"stats", escrow.as_ref()], program_id);
+    if &stats_address != stats_info.key {
+        msg!("Stats program derived address does not match the stats address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    Ok(bump_seed)
+}
+
+/// Check that a voucher nonce is the PDA of an escrow and job authority, returning its bump seed
+fn check_voucher_nonce_address(
+    program_id: &Pubkey,
+    escrow: &Pubkey,
+    authority: &Pubkey,
+    voucher_nonce_info: &AccountInfo,
+) -> Result<u8, ProgramError> {
+    let (voucher_nonce_address, bump_seed) = Pubkey::find_program_address(
+        &[b"voucher_nonce", escrow.as_ref(), authority.as_ref()],
+        program_id,
+    );
+    if &voucher_nonce_address != voucher_nonce_info.key {
+        msg!(
+            "Voucher nonce program derived address does not match the voucher nonce address provided"
+        );
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    Ok(bump_seed)
+}
+
+/// Load a voucher nonce owned by the program
+fn load_voucher_nonce(
+    program_id: &Pubkey,
+    voucher_nonce_info: &AccountInfo,
+) -> Result<VoucherNonce, ProgramError> {
+    let voucher_nonce = VoucherNonce::unpack(&voucher_nonce_info.try_borrow_data()?)?;
+    if voucher_nonce_info.owner != program_id {
+        msg!("Voucher nonce provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    Ok(voucher_nonce)
+}
+
+/// Check that a delegate allowance is the PDA of an escrow and delegate, returning its bump seed
+fn check_delegate_allowance_address(
+    program_id: &Pubkey,
+    escrow: &Pubkey,
+    delegate: &Pubkey,
+    delegate_allowance_info: &AccountInfo,
+) -> Result<u8, ProgramError> {
+    let (delegate_allowance_address, bump_seed) = Pubkey::find_program_address(
+        &[b"delegate_allowance", escrow.as_ref(), delegate.as_ref()],
+        program_id,
+    );
+    if &delegate_allowance_address != delegate_allowance_info.key {
+        msg!("Delegate allowance program derived address does not match the delegate allowance address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    Ok(bump_seed)
+}
+
+/// Load a delegate allowance owned by the program
+fn load_delegate_allowance(
+    program_id: &Pubkey,
+    delegate_allowance_info: &AccountInfo,
+) -> Result<DelegateAllowance, ProgramError> {
+    let delegate_allowance =
+        DelegateAllowance::unpack(&delegate_allowance_info.try_borrow_data()?)?;
+    if delegate_allowance_info.owner != program_id {
+        msg!("Delegate allowance provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    Ok(delegate_allowance)
+}
+
+/// Check that a subscription is the PDA of an escrow, job authority and job identifier, returning
+/// its bump seed
+fn check_subscription_address(
+    program_id: &Pubkey,
+    escrow: &Pubkey,
+    authority: &Pubkey,
+    job_id: u64,
+    subscription_info: &AccountInfo,
+) -> Result<u8, ProgramError> {
+    let (subscription_address, bump_seed) = Pubkey::find_program_address(
+        &[
+            b"subscription",
+            escrow.as_ref(),
+            authority.as_ref(),
+            &job_id.to_le_bytes(),
+        ],
+        program_id,
+    );
+    if &subscription_address != subscription_info.key {
+        msg!(
+            "Subscription program derived address does not match the subscription address provided"
+        );
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    Ok(bump_seed)
+}
+
+/// Check that a job bundle is the PDA of an escrow, job authority and bundle identifier, returning
+/// its bump seed
+fn check_job_bundle_address(
+    program_id: &Pubkey,
+    escrow: &Pubkey,
+    authority: &Pubkey,
+    bundle_id: u64,
+    job_bundle_info: &AccountInfo,
+) -> Result<u8, ProgramError> {
+    let (job_bundle_address, bump_seed) = Pubkey::find_program_address(
+        &[
+            b"job_bundle",
+            escrow.as_ref(),
+            authority.as_ref(),
+            &bundle_id.to_le_bytes(),
+        ],
+        program_id,
+    );
+    if &job_bundle_address != job_bundle_info.key {
+        msg!("Job bundle program derived address does not match the job bundle address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    Ok(bump_seed)
+}
+
+/// Load a job bundle, checking that it's the PDA of the escrow
+fn load_job_bundle(
+    program_id: &Pubkey,
+    escrow_info: &AccountInfo,
+    job_bundle_info: &AccountInfo,
+) -> Result<JobBundle, ProgramError> {
+    let job_bundle = JobBundle::unpack(&job_bundle_info.try_borrow_data()?)?;
+    if job_bundle_info.owner != program_id {
+        msg!("Job bundle provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if &job_bundle.escrow != escrow_info.key {
+        msg!("Job bundle escrow does not match the escrow provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    check_job_bundle_address(
+        program_id,
+        escrow_info.key,
+        &job_bundle.authority,
+        job_bundle.id,
+        job_bundle_info,
+    )?;
+
+    Ok(job_bundle)
+}
+
+/// Check that a job tree is the PDA of an escrow and job authority, returning its bump seed
+fn check_job_tree_address(
+    program_id: &Pubkey,
+    escrow: &Pubkey,
+    authority: &Pubkey,
+    job_tree_info: &AccountInfo,
+) -> Result<u8, ProgramError> {
+    let (job_tree_address, bump_seed) = Pubkey::find_program_address(
+        &[b"job_tree", escrow.as_ref(), authority.as_ref()],
+        program_id,
+    );
+    if &job_tree_address != job_tree_info.key {
+        msg!("Job tree program derived address does not match the job tree address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    Ok(bump_seed)
+}
+
+/// Load a job tree, checking that it's the PDA of the escrow, returning it with its bump seed
+fn load_job_tree(
+    program_id: &Pubkey,
+    escrow_info: &AccountInfo,
+    job_tree_info: &AccountInfo,
+) -> Result<(JobTree, u8), ProgramError> {
+    let job_tree = JobTree::unpack(&job_tree_info.try_borrow_data()?)?;
+    if job_tree_info.owner != program_id {
+        msg!("Job tree provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if &job_tree.escrow != escrow_info.key {
+        msg!("Job tree escrow does not match the escrow provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    let bump_seed = check_job_tree_address(
+        program_id,
+        escrow_info.key,
+        &job_tree.authority,
+        job_tree_info,
+    )?;
+
+    Ok((job_tree, bump_seed))
+}
+
+/// Check that a merkle tree is the one of a job tree
+fn check_job_tree_merkle_tree(job_tree: &JobTree, merkle_tree_info: &AccountInfo) -> ProgramResult {
+    if &job_tree.merkle_tree != merkle_tree_info.key {
+        msg!("Job tree merkle tree does not match the merkle tree provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    Ok(())
+}
+
+/// Check that the noop and account compression programs provided are the SPL programs, since the
+/// job tree signs for its merkle tree when invoking them
+fn check_account_compression_programs(
+    noop_program_info: &AccountInfo,
+    compression_program_info: &AccountInfo,
+) -> ProgramResult {
+    if noop_program_info.key != &NOOP_PROGRAM_ID {
+        msg!("Noop program provided is not the SPL noop program");
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    if compression_program_info.key != &ACCOUNT_COMPRESSION_PROGRAM_ID {
+        msg!("Account compression program provided is not the SPL account compression program");
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    Ok(())
+}
+
+/// Replace the leaf of a compressed job in the merkle tree of its job tree with a proof against a
+/// recent root, signed by the job tree, logging the new leaf's preimage through the noop program
+#[allow(clippy::too_many_arguments)]
+fn replace_compressed_job_leaf<'a>(
+    job_tree_info: &AccountInfo<'a>,
+    job_tree: &JobTree,
+    bump_seed: u8,
+    merkle_tree_info: &AccountInfo<'a>,
+    noop_program_info: &AccountInfo<'a>,
+    compression_program_info: &AccountInfo<'a>,
+    proof_infos: &[AccountInfo<'a>],
+    root: [u8; HASH_BYTES],
+    job: &CompressedJob,
+    new_job: &CompressedJob,
+) -> ProgramResult {
+    check_job_tree_merkle_tree(job_tree, merkle_tree_info)?;
+    check_account_compression_programs(noop_program_info, compression_program_info)?;
+    if job.id >= job_tree.leaf_count {
+        msg!("Compressed job has not been appended to the job tree");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let job_tree_seeds: &[&[_]] = &[
+        b"job_tree",
+        job_tree.escrow.as_ref(),
+        job_tree.authority.as_ref(),
+        &[bump_seed],
+    ];
+    let proof: Vec<_> = proof_infos.iter().map(|info| *info.key).collect();
+    let mut account_infos = vec![
+        merkle_tree_info.clone(),
+        job_tree_info.clone(),
+        noop_program_info.clone(),
+    ];
+    account_infos.extend_from_slice(proof_infos);
+    account_infos.push(compression_program_info.clone());
+
+    invoke(
+        &account_compression::wrap(new_job.leaf_data(job_tree_info.key)),
+        std::slice::from_ref(noop_program_info),
+    )?;
+    // The leaf count of a job tree never exceeds 2^MAX_JOB_TREE_DEPTH
+    invoke_signed(
+        &account_compression::replace_leaf(
+            *merkle_tree_info.key,
+            *job_tree_info.key,
+            root,
+            job.leaf(job_tree_info.key),
+            new_job.leaf(job_tree_info.key),
+            job.id as u32,
+            &proof,
+        ),
+        &account_infos,
+        &[job_tree_seeds],
+    )
+}
+
+/// Check that a job index is the PDA of an escrow and job authority, returning its bump seed
+fn check_job_index_address(
+    program_id: &Pubkey,
+    escrow: &Pubkey,
+    authority: &Pubkey,
+    job_index_info: &AccountInfo,
+) -> Result<u8, ProgramError> {
+    let (job_index_address, bump_seed) = Pubkey::find_program_address(
+        &[b"job_index", escrow.as_ref(), authority.as_ref()],
+        program_id,
+    );
+    if &job_index_address != job_index_info.key {
+        msg!("Job index program derived address does not match the job index address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    Ok(bump_seed)
+}
+
+/// Split the job index of a job authority and the page of its next job off the end of an
+/// instruction's accounts, since they can be appended to any instruction that creates jobs after
+/// that instruction's optional accounts. The stats account must be split off first.
+fn split_job_index_accounts<'a, 'b>(
+    program_id: &Pubkey,
+    accounts: &'b [AccountInfo<'a>],
+) -> (
+    &'b [AccountInfo<'a>],
+    Option<(&'b AccountInfo<'a>, &'b AccountInfo<'a>)>,
+) {
+    if let Some((job_index_info, job_index_page_info, rest)) = accounts
+        .split_last()
+        .and_then(|(last, rest)| rest.split_last().map(|(second, rest)| (second, last, rest)))
+    {
+        let is_job_index = job_index_info.owner == program_id
+            && job_index_info
+                .try_borrow_data()
+                .map(|data| {
+                    data.len() == JobIndex::LEN
+                        && data[ACCOUNT_TYPE_OFFSET] == u8::from(AccountType::JobIndexV1)
+                })
+                .unwrap_or(false);
+        if is_job_index {
+            return (rest, Some((job_index_info, job_index_page_info)));
+        }
+    }
+    (accounts, None)
+}
+
+/// Split the job queue of an escrow off the end of an instruction's accounts, since it can be
+/// appended to `FundJob` after its optional accounts and job index accounts. The stats account
+/// must be split off first.
+fn split_job_queue_account<'a, 'b>(
+    program_id: &Pubkey,
+    accounts: &'b [AccountInfo<'a>],
+) -> (&'b [AccountInfo<'a>], Option<&'b AccountInfo<'a>>) {
+    if let Some((job_queue_info, rest)) = accounts.split_last() {
+        let is_job_queue = job_queue_info.owner == program_id
+            && job_queue_info
+                .try_borrow_data()
+                .map(|data| {
+                    data.len() == JobQueue::LEN
+                        && data[ACCOUNT_TYPE_OFFSET] == u8::from(AccountType::JobQueueV1)
+                })
+                .unwrap_or(false);
+        if is_job_queue {
+            return (rest, Some(job_queue_info));
+        }
+    }
+    (accounts, None)
+}
+
+/// Check that a job queue is the PDA of an escrow, returning its bump seed
+fn check_job_queue_address(
+    program_id: &Pubkey,
+    escrow: &Pubkey,
+    job_queue_info: &AccountInfo,
+) -> Result<u8, ProgramError> {
+    let (job_queue_address, bump_seed) =
+        Pubkey::find_program_address(&[b"job_queue", escrow.as_ref()], program_id);
+    if &job_queue_address != job_queue_info.key {
+        msg!("Job queue program derived address does not match the job queue address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    Ok(bump_seed)
+}
+
+/// Push a job created in an escrow to the escrow's job queue, if it was provided
+fn push_job_queue(
+    program_id: &Pubkey,
+    escrow_info: &AccountInfo,
+    job_info: &AccountInfo,
+    job_queue_info: Option<&AccountInfo>,
+) -> ProgramResult {
+    let job_queue_info = match job_queue_info {
+        Some(job_queue_info) => job_queue_info,
+        None => return Ok(()),
+    };
+
+    check_job_queue_address(program_id, escrow_info.key, job_queue_info)?;
+    let mut job_queue = JobQueue::unpack(&job_queue_info.try_borrow_data()?)?;
+    if !job_queue.push(*job_info.key) {
+        msg!("Job queue is full");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    JobQueue::pack(job_queue, &mut job_queue_info.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
+
+/// Record a job created for an authority in its job index, if it was provided, creating the page
+/// the job is recorded in if it doesn't exist yet
+#[allow(clippy::too_many_arguments)]
+fn record_job_index<'a>(
+    program_id: &Pubkey,
+    funder_info: &AccountInfo<'a>,
+    escrow_info: &AccountInfo<'a>,
+    authority: &Pubkey,
+    job_id: u64,
+    job_index_infos: Option<(&AccountInfo<'a>, &AccountInfo<'a>)>,
+    rent: &Rent,
+    system_program_info: &AccountInfo<'a>,
+) -> ProgramResult {
+    let (job_index_info, job_index_page_info) = match job_index_infos {
+        Some(job_index_infos) => job_index_infos,
+        None => return Ok(()),
+    };
+
+    check_job_index_address(program_id, escrow_info.key, authority, job_index_info)?;
+    let mut job_index = JobIndex::unpack(&job_index_info.try_borrow_data()?)?;
+
+    let page = job_index.next_page().ok_or(RNDRError::MathError)?;
+    let page_seed = page.to_le_bytes();
+    let (job_index_page_address, bump_seed) = Pubkey::find_program_address(
+        &[b"job_index_page", job_index_info.key.as_ref(), &page_seed],
+        program_id,
+    );
+    if &job_index_page_address != job_index_page_info.key {
+        msg!(
+            "Job index page program derived address does not match the next page of the job index"
+        );
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let mut job_index_page = if job_index_page_info.try_data_is_empty()? {
+        let job_index_page_seeds: &[&[_]] = &[
+            b"job_index_page",
+            job_index_info.key.as_ref(),
+            &page_seed,
+            &[bump_seed],
+        ];
+
+        create_program_account(
+            program_id,
+            funder_info,
+            job_index_page_info,
+            job_index_page_seeds,
+            JobIndexPage::LEN,
+            rent,
+            system_program_info,
+        )?;
+
+        JobIndexPage::new(InitJobIndexPageParams {
+            job_index: *job_index_info.key,
+            page,
+        })
+    } else {
+        JobIndexPage::unpack(&job_index_page_info.try_borrow_data()?)?
+    };
+
+    if !job_index_page.push(job_id) {
+        msg!("Job index page is full");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    job_index.job_count = job_index
+        .job_count
+        .checked_add(1)
+        .ok_or(RNDRError::MathError)?;
+
+    JobIndexPage::pack(
+        job_index_page,
+        &mut job_index_page_info.try_borrow_mut_data()?,
+    )?;
+    JobIndex::pack(job_index, &mut job_index_info.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
+
+/// Split the accounts to mint a compressed NFT receipt with Bubblegum off the end of an
+/// instruction's accounts, since they can be appended to `FundJob` after its optional accounts,
+/// job index accounts and job queue, ending with the Bubblegum program. The stats account must be
+/// split off first.
+fn split_job_receipt_accounts<'a, 'b>(
+    accounts: &'b [AccountInfo<'a>],
+) -> (&'b [AccountInfo<'a>], Option<&'b [AccountInfo<'a>]>) {
+    let is_receipt = accounts
+        .last()
+        .is_some_and(|info| info.key == &bubblegum::BUBBLEGUM_PROGRAM_ID);
+    match accounts.len().checked_sub(JOB_RECEIPT_ACCOUNTS_LEN) {
+        Some(len) if is_receipt => {
+            let (rest, receipt_infos) = accounts.split_at(len);
+            (rest, Some(receipt_infos))
+        }
+        _ => (accounts, None),
+    }
+}
+
+/// Mint a compressed NFT receipt for a job to its funder with Bubblegum, signed by the receipt
+/// authority PDA, which must be the delegate of the merkle tree
+fn mint_job_receipt<'a>(
+    program_id: &Pubkey,
+    funder_info: &AccountInfo<'a>,
+    receipt_infos: &[AccountInfo<'a>],
+    system_program_info: &AccountInfo<'a>,
+    metadata: &ReceiptMetadata,
+) -> ProgramResult {
+    let account_info_iter = &mut receipt_infos.iter();
+    let receipt_authority_info = next_account_info(account_info_iter)?;
+    let tree_config_info = next_account_info(account_info_iter)?;
+    let merkle_tree_info = next_account_info(account_info_iter)?;
+    let log_wrapper_info = next_account_info(account_info_iter)?;
+    let compression_program_info = next_account_info(account_info_iter)?;
+    let bubblegum_program_info = next_account_info(account_info_iter)?;
+
+    let (receipt_authority_address, bump_seed) =
+        Pubkey::find_program_address(&[b"receipt_authority"], program_id);
+    if &receipt_authority_address != receipt_authority_info.key {
+        msg!("Receipt authority program derived address does not match the receipt authority address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    invoke_signed(
+        &bubblegum::mint_v1(
+            *merkle_tree_info.key,
+            *funder_info.key,
+            *funder_info.key,
+            receipt_authority_address,
+            metadata,
+        ),
+        &[
+            tree_config_info.clone(),
+            funder_info.clone(),
+            merkle_tree_info.clone(),
+            receipt_authority_info.clone(),
+            log_wrapper_info.clone(),
+            compression_program_info.clone(),
+            system_program_info.clone(),
+            bubblegum_program_info.clone(),
+        ],
+        &[&[b"receipt_authority", &[bump_seed]]],
+    )
+}
+
+/// Load a subscription owned by the program
+fn load_subscription(
+    program_id: &Pubkey,
+    subscription_info: &AccountInfo,
+) -> Result<Subscription, ProgramError> {
+    let subscription = Subscription::unpack(&subscription_info.try_borrow_data()?)?;
+    if subscription_info.owner != program_id {
+        msg!("Subscription provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    Ok(subscription)
+}
+
+/// Check that a session key account is the PDA of an escrow, job authority and session key,
+/// returning its bump seed
+fn check_session_key_address(
+    program_id: &Pubkey,
+    escrow: &Pubkey,
+    authority: &Pubkey,
+    session_key: &Pubkey,
+    session_key_info: &AccountInfo,
+) -> Result<u8, ProgramError> {
+    let (session_key_address, bump_seed) = Pubkey::find_program_address(
+        &[
+            b"session_key",
+            escrow.as_ref(),
+            authority.as_ref(),
+            session_key.as_ref(),
+        ],
+        program_id,
+    );
+    if &session_key_address != session_key_info.key {
+        msg!("Session key program derived address does not match the session key address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    Ok(bump_seed)
+}
+
+/// Load a session key owned by the program
+fn load_session_key(
+    program_id: &Pubkey,
+    session_key_info: &AccountInfo,
+) -> Result<SessionKey, ProgramError> {
+    let session_key = SessionKey::unpack(&session_key_info.try_borrow_data()?)?;
+    if session_key_info.owner != program_id {
+        msg!("Session key provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    Ok(session_key)
+}
+
+/// Check that a signer is a session key registered for an escrow that can sign an instruction,
+/// given its mask bit, in the current slot, returning the job authority it signs in place of
+fn load_session_key_authority(
+    program_id: &Pubkey,
+    escrow: &Pubkey,
+    signer_info: &AccountInfo,
+    session_key_info: &AccountInfo,
+    instruction: u64,
+) -> Result<Pubkey, ProgramError> {
+    if !signer_info.is_signer {
+        msg!("Session key provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let session_key = load_session_key(program_id, session_key_info)?;
+    check_session_key_address(
+        program_id,
+        escrow,
+        &session_key.authority,
+        signer_info.key,
+        session_key_info,
+    )?;
+    if !session_key.allows(instruction, Clock::get()?.slot) {
+        msg!("Session key has expired or can't sign this instruction");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    Ok(session_key.authority)
+}
+
+/// Check that the instruction before the current one is an ed25519 program instruction verifying
+/// a signature of a message by a signer, with its data in the same instruction
+///
+/// The ed25519 program verifies the signature itself when the transaction is processed, so only
+/// the signer and message it verified need to be checked here.
+fn check_ed25519_signature(
+    instructions_sysvar_info: &AccountInfo,
+    signer: &Pubkey,
+    message: &[u8],
+) -> ProgramResult {
+    if !sysvar::instructions::check_id(instructions_sysvar_info.key) {
+        msg!("Instructions sysvar provided is invalid");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let current_index = sysvar::instructions::load_current_index_checked(instructions_sysvar_info)?;
+    let instruction = current_index
+        .checked_sub(1)
+        .map(|index| {
+            sysvar::instructions::load_instruction_at_checked(
+                index as usize,
+                instructions_sysvar_info,
+            )
+        })
+        .transpose()?;
+    let instruction = match instruction {
+        Some(instruction) if ed25519_program::check_id(&instruction.program_id) => instruction,
+        _ => {
+            msg!("Instruction before this one must be an ed25519 program instruction");
+            return Err(RNDRError::UnspecifiedError.into());
+        }
+    };
+
+    let data = &instruction.data;
+    let expected_offsets = [
+        ED25519_SIGNATURE_OFFSET,
+        u16::MAX as usize,
+        ED25519_PUBLIC_KEY_OFFSET,
+        u16::MAX as usize,
+        ED25519_MESSAGE_OFFSET,
+        message.len(),
+        u16::MAX as usize,
+    ];
+    let valid = data.len() == ED25519_MESSAGE_OFFSET + message.len()
+        && data[0] == 1
+        && data[2..ED25519_PUBLIC_KEY_OFFSET]
+            .chunks_exact(2)
+            .zip(expected_offsets)
+            .all(|(offset, expected)| {
+                u16::from_le_bytes(*array_ref![offset, 0, 2]) as usize == expected
+            })
+        && &data[ED25519_PUBLIC_KEY_OFFSET..ED25519_SIGNATURE_OFFSET] == signer.as_ref()
+        && &data[ED25519_MESSAGE_OFFSET..] == message;
+    if !valid {
+        msg!(
+            "Ed25519 program instruction does not verify a signature of the message by the signer"
+        );
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    Ok(())
+}
+
+/// Check that a crank vault is the PDA of an escrow, returning its bump seed
+fn check_crank_vault_address(
+    program_id: &Pubkey,
+    escrow: &Pubkey,
+    crank_vault_info: &AccountInfo,
+) -> Result<u8, ProgramError> {
+    let (crank_vault_address, bump_seed) =
+        Pubkey::find_program_address(&[b"crank_vault", escrow.as_ref()], program_id);
+    if &crank_vault_address != crank_vault_info.key {
+        msg!("Crank vault program derived address does not match the crank vault address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    Ok(bump_seed)
+}
+
+/// Load a crank vault owned by the program
+fn load_crank_vault(
+    program_id: &Pubkey,
+    crank_vault_info: &AccountInfo,
+) -> Result<CrankVault, ProgramError> {
+    let crank_vault = CrankVault::unpack(&crank_vault_info.try_borrow_data()?)?;
+    if crank_vault_info.owner != program_id {
+        msg!("Crank vault provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    Ok(crank_vault)
+}
+
+/// Pay the bounty of an escrow's crank vault to the recipient of a crank, if the optional crank
+/// vault and recipient accounts follow the crank's accounts. A vault that doesn't hold enough
+/// lamports above its rent exemption pays nothing, without failing the crank.
+fn pay_crank_bounty(
+    program_id: &Pubkey,
+    escrow: &Pubkey,
+    account_info_iter: &mut std::slice::Iter<AccountInfo>,
+) -> ProgramResult {
+    // Optional accounts
+    let crank_vault_info = match account_info_iter.next() {
+        Some(crank_vault_info) => crank_vault_info,
+        None => return Ok(()),
+    };
+    let recipient_info = next_account_info(account_info_iter)?;
+
+    check_crank_vault_address(program_id, escrow, crank_vault_info)?;
+    let mut crank_vault = load_crank_vault(program_id, crank_vault_info)?;
+
+    let bounty = crank_vault.bounty;
+    let available = crank_vault_info
+        .lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(crank_vault_info.data_len()));
+    if bounty == 0 || available < bounty {
+        msg!("Crank vault can't pay the bounty");
+        return Ok(());
+    }
+
+    **crank_vault_info.try_borrow_mut_lamports()? = crank_vault_info
+        .lamports()
+        .checked_sub(bounty)
+        .ok_or(RNDRError::MathError)?;
+    **recipient_info.try_borrow_mut_lamports()? = recipient_info
+        .lamports()
+        .checked_add(bounty)
+        .ok_or(RNDRError::MathError)?;
+    crank_vault.paid = crank_vault
+        .paid
+        .checked_add(bounty)
+        .ok_or(RNDRError::MathError)?;
+    RNDREvent::CrankBountyPaid(CrankBountyPaid {
+        bounty,
+        recipient: *recipient_info.key,
+    })
+    .emit();
+
+    CrankVault::pack(crank_vault, &mut crank_vault_info.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
+
+/// Check that a node is the PDA of an escrow and node authority, returning its bump seed
+fn check_node_address(
+    program_id: &Pubkey,
+    escrow: &Pubkey,
+    authority: &Pubkey,
+    node_info: &AccountInfo,
+) -> Result<u8, ProgramError> {
+    let (node_address, bump_seed) =
+        Pubkey::find_program_address(&[b"node", escrow.as_ref(), authority.as_ref()], program_id);
+    if &node_address != node_info.key {
+        msg!("Node program derived address does not match the node address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    Ok(bump_seed)
+}
+
+/// Load a node owned by the program
+fn load_node(program_id: &Pubkey, node_info: &AccountInfo) -> Result<Node, ProgramError> {
+    let node = Node::unpack_account(&node_info.try_borrow_data()?)?;
+    if node_info.owner != program_id {
+        msg!("Node provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    Ok(node)
+}
+
+/// Check that a token account is the associated token account of a node for a token mint
+fn check_node_associated_token_address(
+    node_info: &AccountInfo,
+    token_mint: &Pubkey,
+    node_associated_token_info: &AccountInfo,
+) -> ProgramResult {
+    let node_associated_token_address = get_associated_token_address(node_info.key, token_mint);
+    if &node_associated_token_address != node_associated_token_info.key {
+        msg!("Node associated token address does not match the associated token address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    Ok(())
+}
+
+/// Record work completed by a node in the reward pool of the current epoch, creating the node's
+/// epoch work if it's the first work recorded for the pool
+fn record_epoch_work<'a>(
+    program_id: &Pubkey,
+    node_info: &AccountInfo<'a>,
+    escrow_info: &AccountInfo<'a>,
+    reward_pool_info: &AccountInfo<'a>,
+    epoch_work_info: &AccountInfo<'a>,
+    system_program_info: &AccountInfo<'a>,
+    work: u64,
+) -> ProgramResult {
+    let epoch = Clock::get()?.epoch;
+    check_reward_pool_address(program_id, escrow_info.key, epoch, reward_pool_info)?;
+    let mut reward_pool = load_reward_pool(program_id, reward_pool_info)?;
+
+    let bump_seed =
+        check_epoch_work_address(program_id, reward_pool_info, node_info.key, epoch_work_info)?;
+    let mut epoch_work = if epoch_work_info.try_data_is_empty()? {
+        let epoch_work_seeds: &[&[_]] = &[
+            b"epoch_work",
+            reward_pool_info.key.as_ref(),
+            node_info.key.as_ref(),
+            &[bump_seed],
+        ];
+
+        create_program_account(
+            program_id,
+            node_info,
+            epoch_work_info,
+            epoch_work_seeds,
+            EpochWork::LEN,
+            &Rent::get()?,
+            system_program_info,
+        )?;
+
+        EpochWork::new(InitEpochWorkParams {
+            reward_pool: *reward_pool_info.key,
+            node: *node_info.key,
+        })
+    } else {
+        load_epoch_work(program_id, epoch_work_info)?
+    };
+
+    epoch_work.work = epoch_work
+        .work
+        .checked_add(work)
+        .ok_or(RNDRError::MathError)?;
+    reward_pool.total_work = reward_pool
+        .total_work
+        .checked_add(work)
+        .ok_or(RNDRError::MathError)?;
+    RNDREvent::EpochWorkRecorded(EpochWorkRecorded { work, epoch }).emit();
+
+    EpochWork::pack(epoch_work, &mut epoch_work_info.try_borrow_mut_data()?)?;
+    RewardPool::pack(reward_pool, &mut reward_pool_info.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
+
+/// Check that a dispute is the PDA of a job, returning its bump seed
+fn check_dispute_address(
+    program_id: &Pubkey,
+    job_info: &AccountInfo,
+    dispute_info: &AccountInfo,
+) -> Result<u8, ProgramError> {
+    let (dispute_address, bump_seed) =
+        Pubkey::find_program_address(&[b"dispute", job_info.key.as_ref()], program_id);
+    if &dispute_address != dispute_info.key {
+        msg!("Dispute program derived address does not match the dispute address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    Ok(bump_seed)
+}
+
+/// Load a dispute owned by the program
+fn load_dispute(program_id: &Pubkey, dispute_info: &AccountInfo) -> Result<Dispute, ProgramError> {
+    let dispute = Dispute::unpack(&dispute_info.try_borrow_data()?)?;
+    if dispute_info.owner != program_id {
+        msg!("Dispute provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    Ok(dispute)
+}
+
+/// Load a job, checking that it's a PDA of the escrow, returning it with its bump seed
+fn load_escrow_job(
+    program_id: &Pubkey,
+    escrow_info: &AccountInfo,
+    job_info: &AccountInfo,
+) -> Result<(Job, u8), ProgramError> {
+    let job = Job::unpack_account(&job_info.try_borrow_data()?)?;
+    if job_info.owner != program_id {
+        msg!("Job provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let (job_pubkey, bump_seed) = Pubkey::find_program_address(
+        &[
+            b"job",
+            escrow_info.key.as_ref(),
+            job.address_authority().as_ref(),
+            &job_id_seed(job.id),
+        ],
+        program_id,
+    );
+    if &job_pubkey != job_info.key {
+        msg!("Job program derived address does not match the job address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    Ok((job, bump_seed))
+}
+
+/// Load a mint migration, checking that it's a PDA of the escrow
+fn load_mint_migration(
+    program_id: &Pubkey,
+    escrow_info: &AccountInfo,
+    mint_migration_info: &AccountInfo,
+) -> Result<MintMigration, ProgramError> {
+    let mint_migration = MintMigration::unpack(&mint_migration_info.try_borrow_data()?)?;
+    if mint_migration_info.owner != program_id {
+        msg!("Mint migration provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    let (mint_migration_address, _bump_seed) = Pubkey::find_program_address(
+        &[
+            b"mint_migration",
+            escrow_info.key.as_ref(),
+            &[mint_migration.index],
+        ],
+        program_id,
+    );
+    if &mint_migration_address != mint_migration_info.key {
+        msg!("Mint migration program derived address does not match the mint migration address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    Ok(mint_migration)
+}
+
+/// Check that a job can be migrated by a mint migration, which it must be by the earlier
+/// migrations first, and that it holds its tokens where the migration expects
+fn check_job_migration(job: &Job, mint_migration: &MintMigration, isolated: bool) -> ProgramResult {
+    if job.mint_migrations < mint_migration.index {
+        msg!("Job must be migrated by the earlier mint migrations first");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if job.isolated != isolated {
+        if job.isolated {
+            msg!("Job provided holds its tokens in its own token account");
+        } else {
+            msg!("Job provided holds its tokens in the escrow token account");
+        }
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    Ok(())
+}
+
+/// Check that a job's amount has been re-denominated by every mint migration of the escrow, so it's
+/// denominated in the escrow's token mint
+fn check_job_mint_migrations(job: &Job, escrow: &Escrow) -> ProgramResult {
+    if job.mint_migrations != escrow.mint_migrations {
+        msg!("Job has not been migrated to the escrow token mint");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    Ok(())
+}
+
+/// Check that the compliance authority of the config signed
+fn check_compliance(
+    program_id: &Pubkey,
+    config_info: &AccountInfo,
+    compliance_info: &AccountInfo,
+) -> ProgramResult {
+    let config = load_config(program_id, config_info)?;
+    if config.compliance() != Some(compliance_info.key) {
+        msg!("Config compliance authority does not match the compliance authority provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if !compliance_info.is_signer {
+        msg!("Compliance authority provided must be a signer");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    Ok(())
+}
+
+/// Check that the incident mode an escrow was last synced to allows jobs to be funded
+fn check_escrow_allows_funding(escrow: &Escrow) -> ProgramResult {
+    if !escrow.incident_mode.allows_funding() {
+        msg!("Escrow incident mode does not allow funding jobs");
+        return Err(RNDRError::IncidentModeActive.into());
+    }
+
+    Ok(())
+}
+
+/// Check that the incident mode an escrow was last synced to allows funds of jobs to be disbursed
+/// or refunded
+fn check_escrow_allows_disbursing(escrow: &Escrow) -> ProgramResult {
+    if !escrow.incident_mode.allows_disbursing() {
+        msg!("Escrow incident mode does not allow disbursing or refunding funds");
+        return Err(RNDRError::IncidentModeActive.into());
+    }
+
+    Ok(())
+}
+
+/// Resolve the token mint of an escrow created before its token mint was stored in it, from the
+/// token mint or a token account of the mint provided with it, checking that the escrow's address
+/// is derived from that mint. The token mint of other escrows is left as it is.
+fn resolve_legacy_escrow_token_mint(
+    program_id: &Pubkey,
+    escrow_info: &AccountInfo,
+    escrow: &mut Escrow,
+    token_info: &AccountInfo,
+) -> ProgramResult {
+    if escrow.token_mint != Pubkey::default() {
+        return Ok(());
+    }
+
+    let token_mint = if token_info.data_len() == spl_token::state::Account::LEN {
+        spl_token::state::Account::unpack(&token_info.try_borrow_data()?)?.mint
+    } else {
+        *token_info.key
+    };
+    let (escrow_address, _bump_seed) =
+        find_escrow_address_with_id(program_id, &token_mint, escrow.escrow_id);
+    if &escrow_address != escrow_info.key {
+        msg!("Escrow program derived address does not match the token mint provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    escrow.token_mint = token_mint;
+    Ok(())
+}
+
+/// Check that a job isn't frozen under a legal hold, so its funds can be disbursed or refunded
+fn check_job_not_frozen(job: &Job) -> ProgramResult {
+    if job.frozen {
+        msg!("Job is frozen under a legal hold");
+        return Err(RNDRError::JobFrozen.into());
+    }
+
+    Ok(())
+}
+
+/// Load a proposal, checking that it belongs to the escrow and hasn't been executed
+fn load_proposal(
+    program_id: &Pubkey,
+    escrow_info: &AccountInfo,
+    proposal_info: &AccountInfo,
+) -> Result<Proposal, ProgramError> {
+    let proposal = Proposal::unpack(&proposal_info.try_borrow_data()?)?;
+    if proposal_info.owner != program_id {
+        msg!("Proposal provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if &proposal.escrow != escrow_info.key {
+        msg!("Proposal escrow does not match the escrow provided");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if proposal.is_executed() {
+        msg!("Proposal has already been executed");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    Ok(proposal)
+}
+
+/// Load a queued action that hasn't been executed or cancelled
+fn load_queued_action(
+    program_id: &Pubkey,
+    queued_action_info: &AccountInfo,
+) -> Result<QueuedAction, ProgramError> {
+    let queued_action = QueuedAction::unpack(&queued_action_info.try_borrow_data()?)?;
+    if queued_action_info.owner != program_id {
+        msg!("Queued action provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if queued_action.is_executed() {
+        msg!("Queued action has already been executed");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if queued_action.is_cancelled() {
+        msg!("Queued action has been cancelled");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    Ok(queued_action)
+}
+
+/// Load the config, checking that it's the PDA of the program
+fn load_config(program_id: &Pubkey, config_info: &AccountInfo) -> Result<Config, ProgramError> {
+    let (config_address, _bump_seed) = Pubkey::find_program_address(&[b"config"], program_id);
+    if &config_address != config_info.key {
+        msg!("Config program derived address does not match the config address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+    let config = Config::unpack_account(&config_info.try_borrow_data()?)?;
+    if config_info.owner != program_id {
+        msg!("Config provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    Ok(config)
+}
+
+/// Pack a config into its account, first reallocating a legacy config to the current length if
+/// the optional payer and system program are provided
+fn pack_config<'a>(
+    config: Config,
+    config_info: &AccountInfo<'a>,
+    payer_info: Option<&AccountInfo<'a>>,
+    system_program_info: Option<&AccountInfo<'a>>,
+) -> ProgramResult {
+    if let (Some(payer_info), Some(system_program_info)) = (payer_info, system_program_info) {
+        if !payer_info.is_signer {
+            msg!("Payer provided must be a signer");
+            return Err(RNDRError::UnspecifiedError.into());
+        }
+        resize_account(
+            config_info,
+            Config::LEN,
+            payer_info,
+            &Rent::get()?,
+            system_program_info,
+        )?;
+    }
+    Config::pack_account(config, &mut config_info.try_borrow_mut_data()?)
+}
+
+/// Check that an open job can be committed to, which it can once the config's priority claim
+/// delay for its priority has passed since it was funded
+fn check_job_claimable(program_id: &Pubkey, config_info: &AccountInfo, job: &Job) -> ProgramResult {
+    let config = load_config(program_id, config_info)?;
+    if !is_job_claimable(&config, job)? {
+        msg!("Job can't be committed to until its priority claim delay has passed");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    Ok(())
+}
+
+/// Whether the priority claim delay of a job has passed, so a node can commit to it
+fn is_job_claimable(config: &Config, job: &Job) -> Result<bool, ProgramError> {
+    let claimable_at = config
+        .claimable_at(job.priority, job.funded_at)
+        .ok_or(RNDRError::MathError)?;
+    Ok(Clock::get()?.unix_timestamp >= claimable_at)
+}
+
+/// Read the upgrade authority of the program from its program data account, which is `None` if
+/// the program is immutable
+fn program_upgrade_authority(
+    program_id: &Pubkey,
+    program_data_info: &AccountInfo,
+) -> Result<Option<Pubkey>, ProgramError> {
+    let (program_data_address, _bump_seed) =
+        Pubkey::find_program_address(&[program_id.as_ref()], &bpf_loader_upgradeable::id());
+    if &program_data_address != program_data_info.key {
+        msg!("Program data address does not match the program data address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if program_data_info.owner != &bpf_loader_upgradeable::id() {
+        msg!("Program data provided is not owned by the upgradeable BPF loader");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    // The program data metadata is the bincode serialized `UpgradeableLoaderState::ProgramData`,
+    // a u32 variant index of 3, the u64 deployment slot, then an optional upgrade authority
+    let data = program_data_info.try_borrow_data()?;
+    if data.len() < PROGRAM_DATA_METADATA_LEN || data[..4] != 3u32.to_le_bytes() {
+        msg!("Program data provided is invalid");
+        return Err(ProgramError::InvalidAccountData);
+    }
+    match data[12] {
+        0 => Ok(None),
+        1 => Ok(Some(Pubkey::new_from_array(*array_ref![data, 13, 32]))),
+        _ => {
+            msg!("Program data provided is invalid");
+            Err(ProgramError::InvalidAccountData)
+        }
+    }
+}
+
+/// Unix timestamp at which the dispute window of a job's revealed result ends
+fn dispute_deadline(job: &Job, config: &Config) -> UnixTimestamp {
+    let dispute_window = UnixTimestamp::try_from(config.dispute_window).unwrap_or(i64::MAX);
+    job.revealed_at.saturating_add(dispute_window)
+}
+
+/// Unix timestamp at which the challenge window of a job's revealed result ends
+fn challenge_deadline(job: &Job, escrow: &Escrow) -> UnixTimestamp {
+    let challenge_window = UnixTimestamp::try_from(escrow.challenge_window).unwrap_or(i64::MAX);
+    job.revealed_at.saturating_add(challenge_window)
+}
+
+/// Load a job owned by the authority, creating it if it doesn't exist yet and recording it in the
+/// authority's job index if it was provided
+#[allow(clippy::too_many_arguments)]
+fn load_or_create_job<'a>(
+    program_id: &Pubkey,
+    funder_info: &AccountInfo<'a>,
+    escrow_info: &AccountInfo<'a>,
+    escrow: &Escrow,
+    authority: &Pubkey,
+    job_info: &AccountInfo<'a>,
+    job_id: u64,
+    isolated: bool,
+    job_index_infos: Option<(&AccountInfo<'a>, &AccountInfo<'a>)>,
+    rent: &Rent,
+    system_program_info: &AccountInfo<'a>,
+) -> Result<Job, ProgramError> {
+    let job_id_seed = job_id_seed(job_id);
+
+    let (job_pubkey, bump_seed) = Pubkey::find_program_address(
+        &[
+            b"job",
+            escrow_info.key.as_ref(),
+            authority.as_ref(),
+            &job_id_seed,
+        ],
+        program_id,
+    );
+    if &job_pubkey != job_info.key {
+        msg!("Job program derived address does not match the job address provided");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let job = if job_info.try_data_is_empty()? {
+        let job_seeds: &[&[_]] = &[
+            b"job",
+            escrow_info.key.as_ref(),
+            authority.as_ref(),
+            &job_id_seed,
+            &[bump_seed],
+        ];
+
+        create_program_account(
+            program_id,
+            funder_info,
+            job_info,
+            job_seeds,
+            Job::LEN,
+            rent,
+            system_program_info,
+        )?;
+
+        record_job_index(
+            program_id,
+            funder_info,
+            escrow_info,
+            authority,
+            job_id,
+            job_index_infos,
+            rent,
+            system_program_info,
+        )?;
+
+        let mut job = Job::new(InitJobParams {
+            authority: *authority,
+            id: job_id,
+            isolated,
+        });
+        job.funded_at = Clock::get()?.unix_timestamp;
+        job.mint_migrations = escrow.mint_migrations;
+        job
+    } else if job_info.owner != program_id {
+        msg!("Job provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    } else {
+        Job::unpack_account(&job_info.try_borrow_data()?)?
+    };
+
+    if job.isolated != isolated {
+        if job.isolated {
+            msg!("Job provided holds its tokens in its own token account");
+        } else {
+            msg!("Job provided holds its tokens in the escrow token account");
+        }
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if job.status == JobStatus::Cancelled {
+        msg!("Job has been cancelled, so it can't be funded");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    check_job_mint_migrations(&job, escrow)?;
+
+    Ok(job)
+}
+
+/// Set the off-chain metadata of an open job, reallocating a legacy job account to store it
+fn set_job_metadata<'a>(
+    job_info: &AccountInfo<'a>,
+    job: &mut Job,
+    metadata: JobMetadata,
+    payer_info: &AccountInfo<'a>,
+    rent: &Rent,
+    system_program_info: &AccountInfo<'a>,
+) -> ProgramResult {
+    if job.status != JobStatus::Open {
+        msg!("Job metadata can only be set while the job is open");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+    if !metadata.is_valid() {
+        msg!("Job metadata URI is too long");
+        return Err(RNDRError::UnspecifiedError.into());
+    }
+
+    resize_account(job_info, Job::LEN, payer_info, rent, system_program_info)?;
+
+    RNDREvent::JobMetadataUpdated(JobMetadataUpdated {
+        job: *job_info.key,
+        metadata: metadata.clone(),
+    })
+    .emit();
+    job.set_metadata(metadata);
+
+    Ok(())
+}
+
+/// Record the exchange rate a job's amounts are converted to RNDR at, reallocating a legacy job
+/// account to store it
+fn set_job_exchange_rate<'a>(
+    job_info: &AccountInfo<'a>,
+    job: &mut Job,
+    exchange_rate: ExchangeRate,
+    oracle_slot: Slot,
+    payer_info: &AccountInfo<'a>,
+    rent: &Rent,
+    system_program_info: &AccountInfo<'a>,
+) -> ProgramResult {
+    resize_account(job_info, Job::LEN, payer_info, rent, system_program_info)?;
+
+    RNDREvent::JobExchangeRateSet(JobExchangeRateSet {
+        job: *job_info.key,
+        rate_numerator: exchange_rate.numerator(),
+        rate_denominator: exchange_rate.denominator(),
+        oracle_slot,
+    })
+    .emit();
+    job.set_exchange_rate(exchange_rate, oracle_slot);
+
+    Ok(())
+}
+
+/// Load the escrow shard that a job is funded through, creating it if it doesn't exist yet
+fn load_or_create_escrow_shard<'a>(
+    program_id: &Pubkey,
+    funder_info: &AccountInfo<'a>,
+    escrow_info: &AccountInfo<'a>,
+    job_info: &AccountInfo<'a>,
+    escrow_shard_info: &AccountInfo<'a>,
+    rent: &Rent,
+    system_program_info: &AccountInfo<'a>,
+) -> Result<EscrowShard, ProgramError> {
+    let escrow_shard_index = EscrowShard::index_for_job(job_info.key);
+    let escrow_shard_index_seed = &[escrow_shard_index];
+
+    let (escrow_shard_address, bump_seed) = Pubkey::find_program_address(
+        &[
+            b"escrow_shard",
+            escrow_info.key.as_ref(),
+            escrow_shard_index_seed,
+        ],
+        program_id,
+    );
+    if &escrow_shard_address != escrow_shard_info.key {
+        msg!(
+            "Escrow shard program derived address does not match the escrow shard address provided"
+        );
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let escrow_shard = if escrow_shard_info.try_data_is_empty()? {
+        let escrow_shard_seeds: &[&[_]] = &[
+            b"escrow_shard",
+            escrow_info.key.as_ref(),
+            escrow_shard_index_seed,
+            &[bump_seed],
+        ];
+
+        create_program_account(
+            program_id,
+            funder_info,
+            escrow_shard_info,
+            escrow_shard_seeds,
+            EscrowShard::LEN,
+            rent,
+            system_program_info,
+        )?;
+
+        EscrowShard::new(InitEscrowShardParams {
+            escrow: *escrow_info.key,
+            index: escrow_shard_index,
+        })
+    } else if escrow_shard_info.owner != program_id {
+        msg!("Escrow shard provided is not owned by the RNDR program");
+        return Err(RNDRError::UnspecifiedError.into());
+    } else {
+        EscrowShard::unpack(&escrow_shard_info.try_borrow_data()?)?
+    };
+
+    Ok(escrow_shard)
+}
+
+/// Skip the Rent sysvar account if it's next, so that clients which still provide it keep working
+fn skip_legacy_rent_sysvar(account_info_iter: &mut std::slice::Iter<AccountInfo>) {
+    if let Some(account_info) = account_info_iter.as_slice().first() {
+        if sysvar::rent::check_id(account_info.key) {
+            account_info_iter.next();
+        }
+    }
+}
+
+/// Reallocate an account created with the legacy length of its type, so the fields appended to it
+/// can be written
+fn resize_account<'a>(
+    account_info: &AccountInfo<'a>,
+    len: usize,
+    payer_info: &AccountInfo<'a>,
+    rent: &Rent,
+    system_program_info: &AccountInfo<'a>,
+) -> ProgramResult {
+    if account_info.data_len() >= len {
+        return Ok(());
+    }
+
+    let required_lamports = rent
+        .minimum_balance(len)
+        .saturating_sub(account_info.lamports());
+    if required_lamports > 0 {
+        invoke(
+            &system_instruction::transfer(payer_info.key, account_info.key, required_lamports),
+            &[
+                payer_info.clone(),
+                account_info.clone(),
+                system_program_info.clone(),
+            ],
+        )?;
+    }
+
+    account_info.realloc(len, true)
+}
+
+/// Fund, allocate, and assign a program derived account to the program
+fn create_program_account<'a>(
+    program_id: &Pubkey,
+    funder_info: &AccountInfo<'a>,
+    account_info: &AccountInfo<'a>,
+    seeds: &[&[u8]],
+    len: usize,
+    rent: &Rent,
+    system_program_info: &AccountInfo<'a>,
+) -> ProgramResult {
+    let required_lamports = rent
+        .minimum_balance(len)
+        .max(1)
+        .saturating_sub(account_info.lamports());
+    if required_lamports > 0 {
+        invoke(
+            &system_instruction::transfer(funder_info.key, account_info.key, required_lamports),
+            &[
+                funder_info.clone(),
+                account_info.clone(),
+                system_program_info.clone(),
+            ],
+        )?;
+    }
+
+    invoke_signed(
+        &system_instruction::allocate(account_info.key, len as u64),
+        &[account_info.clone(), system_program_info.clone()],
+        &[seeds],
+    )?;
+
+    invoke_signed(
+        &system_instruction::assign(account_info.key, program_id),
+        &[account_info.clone(), system_program_info.clone()],
+        &[seeds],
     )?;
 
     Ok(())
@@ -0,0 +1,34 @@
+//! Serde helpers that serialize public keys as base58 strings instead of byte arrays
+
+use {
+    serde::{de::Error, Deserialize, Deserializer, Serializer},
+    solana_program::pubkey::Pubkey,
+    std::str::FromStr,
+};
+
+pub fn serialize<S: Serializer>(pubkey: &Pubkey, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(pubkey)
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Pubkey, D::Error> {
+    let pubkey = String::deserialize(deserializer)?;
+    Pubkey::from_str(&pubkey).map_err(D::Error::custom)
+}
+
+/// Serde helpers that serialize lists of public keys as base58 strings
+pub mod vec {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(pubkeys: &[Pubkey], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(pubkeys.iter().map(Pubkey::to_string))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<Pubkey>, D::Error> {
+        Vec::<String>::deserialize(deserializer)?
+            .iter()
+            .map(|pubkey| Pubkey::from_str(pubkey).map_err(D::Error::custom))
+            .collect()
+    }
+}
@@ -1,7 +1,11 @@
 use num_enum::{FromPrimitive, IntoPrimitive};
 
+/// Offset of the account type in every packed account
+pub const ACCOUNT_TYPE_OFFSET: usize = 0;
+
 /// Enum representing the account types managed by the program
 #[derive(Clone, Copy, Debug, Eq, PartialEq, IntoPrimitive, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 pub enum AccountType {
     /// If the account has not been initialized, the value will be 0
@@ -11,8 +15,65 @@ pub enum AccountType {
     EscrowV1,
     /// Job
     JobV1,
+    /// Escrow shard
+    EscrowShardV1,
+    /// Verifier registry
+    VerifierRegistryV1,
+    /// Verifier selection
+    VerifierSelectionV1,
+    /// Dispute
+    DisputeV1,
+    /// Config
+    ConfigV1,
+    /// Disbursement proposal
+    ProposalV1,
+    /// Queued administrative action
+    QueuedActionV1,
+    /// Escrow owner recovery council
+    RecoveryV1,
+    /// Epoch reward pool
+    RewardPoolV1,
+    /// Work of a node during an epoch
+    EpochWorkV1,
+    /// Merkle root of node rewards for an epoch
+    MerkleDistributionV1,
+    /// Claimed leaves of a Merkle distribution
+    ClaimBitmapV1,
+    /// Reward emissions of each epoch
+    EmissionScheduleV1,
+    /// Stake of a node
+    NodeV1,
+    /// Liquid stake of an escrow
+    StakePoolV1,
+    /// Lamports paying bounties for the cranks of an escrow
+    CrankVaultV1,
+    /// Burnable tokens of an escrow
+    TreasuryV1,
+    /// Burn-mint-equilibrium statistics of an escrow
+    StatsV1,
+    /// Next nonce of the vouchers a job authority signs
+    VoucherNonceV1,
+    /// Short-lived key a job authority registers to sign in its place
+    SessionKeyV1,
+    /// Tokens a delegate of an escrow owner can disburse
+    DelegateAllowanceV1,
+    /// Recurring funding of a job from a token account that delegated to it
+    SubscriptionV1,
+    /// Group of jobs of an authority that share metadata and funding
+    JobBundleV1,
+    /// Merkle tree of the compressed jobs of an authority
+    JobTreeV1,
+    /// Count of the indexed jobs of an authority
+    JobIndexV1,
+    /// Identifiers of a page of the indexed jobs of an authority
+    JobIndexPageV1,
+    /// Migration of an escrow to a new token mint
+    MintMigrationV1,
+    /// Queue of the jobs of an escrow waiting for a node
+    JobQueueV1,
 }
 
+#[allow(clippy::derivable_impls)]
 impl Default for AccountType {
     fn default() -> Self {
         AccountType::Uninitialized
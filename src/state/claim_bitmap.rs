@@ -0,0 +1,132 @@
+use {
+    super::*,
+    arrayref::{array_mut_ref, array_ref, array_refs, mut_array_refs},
+    solana_program::{
+        msg,
+        program_error::ProgramError,
+        program_pack::{IsInitialized, Pack, Sealed},
+        pubkey::{Pubkey, PUBKEY_BYTES},
+    },
+};
+
+/// Number of bytes in the bitmap of each claim bitmap
+pub const CLAIM_BITMAP_BYTES: usize = 1024;
+/// Number of leaf indices each claim bitmap records claims of
+pub const CLAIM_BITMAP_BITS: u32 = CLAIM_BITMAP_BYTES as u32 * 8;
+
+/// Claim bitmap state
+///
+/// Records which leaves of a Merkle distribution have been claimed, one bit per leaf index, for the
+/// `CLAIM_BITMAP_BITS` indices of a chunk. Splitting the bitmap into chunks keeps accounts small
+/// and lets nodes claim without locking the same account.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ClaimBitmap {
+    /// Account type, must be ClaimBitmapV1 currently
+    pub account_type: AccountType,
+    /// Merkle distribution the claims are recorded for
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub merkle_distribution: Pubkey,
+    /// Index of the chunk, covering leaf indices from `chunk * CLAIM_BITMAP_BITS`
+    pub chunk: u32,
+    /// Bits of the leaves that have been claimed, `CLAIM_BITMAP_BYTES` long
+    pub bitmap: Vec<u8>,
+}
+
+impl ClaimBitmap {
+    /// Offset of the Merkle distribution in a packed claim bitmap
+    pub const MERKLE_DISTRIBUTION_OFFSET: usize = ACCOUNT_TYPE_OFFSET + 1;
+    /// Offset of the chunk index in a packed claim bitmap
+    pub const CHUNK_OFFSET: usize = Self::MERKLE_DISTRIBUTION_OFFSET + PUBKEY_BYTES;
+    /// Offset of the bitmap in a packed claim bitmap
+    pub const BITMAP_OFFSET: usize = Self::CHUNK_OFFSET + 4;
+
+    /// Create a claim bitmap
+    pub fn new(params: InitClaimBitmapParams) -> Self {
+        let mut claim_bitmap = Self::default();
+        Self::init(&mut claim_bitmap, params);
+        claim_bitmap
+    }
+
+    /// Initialize a claim bitmap with no claims
+    pub fn init(&mut self, params: InitClaimBitmapParams) {
+        self.account_type = AccountType::ClaimBitmapV1;
+        self.merkle_distribution = params.merkle_distribution;
+        self.chunk = params.chunk;
+        self.bitmap = vec![0; CLAIM_BITMAP_BYTES];
+    }
+
+    /// Chunk of the claim bitmap that records the claim of a leaf index
+    pub fn chunk_for_index(index: u32) -> u32 {
+        index / CLAIM_BITMAP_BITS
+    }
+
+    /// Whether the leaf index has been claimed, the index must be in the chunk
+    pub fn is_claimed(&self, index: u32) -> bool {
+        let bit = (index % CLAIM_BITMAP_BITS) as usize;
+        self.bitmap[bit / 8] & (1 << (bit % 8)) != 0
+    }
+
+    /// Record the claim of a leaf index, the index must be in the chunk
+    pub fn set_claimed(&mut self, index: u32) {
+        let bit = (index % CLAIM_BITMAP_BITS) as usize;
+        self.bitmap[bit / 8] |= 1 << (bit % 8);
+    }
+}
+
+/// Initialize a claim bitmap
+pub struct InitClaimBitmapParams {
+    /// Merkle distribution the claims are recorded for
+    pub merkle_distribution: Pubkey,
+    /// Index of the chunk
+    pub chunk: u32,
+}
+
+impl Sealed for ClaimBitmap {}
+
+impl IsInitialized for ClaimBitmap {
+    fn is_initialized(&self) -> bool {
+        self.account_type != AccountType::Uninitialized
+    }
+}
+
+const CLAIM_BITMAP_LEN: usize = 1061; // 1 + 32 + 4 + 1024
+const _: () = assert!(ClaimBitmap::BITMAP_OFFSET + CLAIM_BITMAP_BYTES == CLAIM_BITMAP_LEN);
+impl Pack for ClaimBitmap {
+    const LEN: usize = CLAIM_BITMAP_LEN;
+
+    fn pack_into_slice(&self, output: &mut [u8]) {
+        let output = array_mut_ref![output, 0, CLAIM_BITMAP_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (account_type, merkle_distribution, chunk, bitmap) =
+            mut_array_refs![output, 1, PUBKEY_BYTES, 4, CLAIM_BITMAP_BYTES];
+
+        *account_type = u8::from(self.account_type).to_le_bytes();
+        merkle_distribution.copy_from_slice(&self.merkle_distribution.to_bytes());
+        *chunk = self.chunk.to_le_bytes();
+        bitmap.fill(0);
+        for (output, byte) in bitmap.iter_mut().zip(&self.bitmap) {
+            *output = *byte;
+        }
+    }
+
+    fn unpack_from_slice(input: &[u8]) -> Result<Self, ProgramError> {
+        let input = array_ref![input, 0, CLAIM_BITMAP_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (account_type, merkle_distribution, chunk, bitmap) =
+            array_refs![input, 1, PUBKEY_BYTES, 4, CLAIM_BITMAP_BYTES];
+
+        let account_type = AccountType::from(u8::from_le_bytes(*account_type));
+        if account_type != AccountType::ClaimBitmapV1 {
+            msg!("Claim bitmap account type is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self {
+            account_type,
+            merkle_distribution: Pubkey::new_from_array(*merkle_distribution),
+            chunk: u32::from_le_bytes(*chunk),
+            bitmap: bitmap.to_vec(),
+        })
+    }
+}
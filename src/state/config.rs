@@ -0,0 +1,995 @@
+use {
+    super::*,
+    crate::math::{Decimal, ExchangeRate, Rate, RoundingPolicy, Split},
+    arrayref::{array_mut_ref, array_ref, array_refs, mut_array_refs},
+    solana_program::{
+        clock::UnixTimestamp,
+        msg,
+        program_error::ProgramError,
+        program_pack::{IsInitialized, Pack, Sealed},
+        pubkey::{Pubkey, PUBKEY_BYTES},
+    },
+    std::convert::TryFrom,
+};
+
+/// Protocol fee charged when the config is created, in basis points
+pub const DEFAULT_FEE_BPS: Rate = Rate::ZERO;
+/// Minimum stake of a node when the config is created, in base units of the token
+pub const DEFAULT_MIN_STAKE: u64 = 0;
+/// Seconds after a job's result is revealed during which a dispute can be opened over it when the
+/// config is created
+pub const DEFAULT_DISPUTE_WINDOW: u64 = 7 * 24 * 60 * 60;
+/// Seconds queued administrative actions wait before they can be applied when the config is
+/// created
+pub const DEFAULT_TIMELOCK_DURATION: u64 = 2 * 24 * 60 * 60;
+/// Seconds in each rate limit window when the config is created
+pub const DEFAULT_RATE_LIMIT_WINDOW: u64 = 60;
+/// Maximum number of rate limited actions each authority can take per window when the config is
+/// created, where zero is unlimited
+pub const DEFAULT_RATE_LIMIT_MAX: u32 = 0;
+/// Seconds after a job is funded during which cancelling it refunds all of its tokens when the
+/// config is created
+pub const DEFAULT_CANCEL_GRACE_PERIOD: u64 = 60 * 60;
+/// Fee charged for cancelling a job after its grace period when the config is created, in basis
+/// points
+pub const DEFAULT_CANCEL_FEE_BPS: Rate = Rate::ZERO;
+/// Surcharge for raising the priority of a job by one level when the config is created, in basis
+/// points
+pub const DEFAULT_PRIORITY_SURCHARGE_BPS: Rate = Rate::ZERO;
+/// Seconds each level of priority below `MAX_JOB_PRIORITY` delays committing to a job when the
+/// config is created
+pub const DEFAULT_PRIORITY_CLAIM_DELAY: u64 = 0;
+/// Rounding policy of splits when the config is created, which adds the remainder to the first
+/// recipient as splits did before the policy was configurable
+pub const DEFAULT_ROUNDING_POLICY: RoundingPolicy = RoundingPolicy::FirstRecipient;
+/// Minimum amount of tokens disbursed by a transfer when the config is created, where zero
+/// disburses any amount
+pub const DEFAULT_MIN_DISBURSEMENT: u64 = 0;
+/// Slots an oracle price can be behind the current slot when it's consumed when the config is
+/// created, about ten seconds
+pub const DEFAULT_MAX_PRICE_AGE: u64 = 25;
+/// Maximum confidence interval of an oracle price when it's consumed when the config is created,
+/// in basis points of the price
+pub const DEFAULT_MAX_PRICE_CONFIDENCE_BPS: Rate = Rate::new(200);
+/// Seconds a sweep of a treasury to its cold wallet waits before it can be executed when the
+/// config is created
+pub const DEFAULT_SWEEP_DELAY: u64 = 2 * 24 * 60 * 60;
+/// Fields whose changes must be queued when the config is created: the fee, the rate limits, the
+/// timelock duration, instant owner changes, the timelocked fields themselves, the reward tiers,
+/// the cancellation fee, the priority surcharge, the rounding policy, the minimum disbursement,
+/// the OctaneBench-hour prices, the oracle price guards and the sweep delay
+pub const DEFAULT_TIMELOCKED_FIELDS: u32 = (1 << 1)
+    | (1 << 4)
+    | (1 << 5)
+    | (1 << 6)
+    | (1 << 8)
+    | (1 << 9)
+    | (1 << 10)
+    | (1 << 12)
+    | (1 << 13)
+    | (1 << 15)
+    | (1 << 16)
+    | (1 << 17)
+    | (1 << 18)
+    | (1 << 19)
+    | (1 << 21);
+
+/// Maximum protocol fee, in basis points
+pub const MAX_FEE_BPS: Rate = Rate::new(1_000);
+/// Minimum seconds of the dispute window
+pub const MIN_DISPUTE_WINDOW: u64 = 60 * 60;
+/// Maximum seconds of the dispute window
+pub const MAX_DISPUTE_WINDOW: u64 = 30 * 24 * 60 * 60;
+/// Minimum seconds of the timelock duration
+pub const MIN_TIMELOCK_DURATION: u64 = 60 * 60;
+/// Maximum seconds of the timelock duration
+pub const MAX_TIMELOCK_DURATION: u64 = 30 * 24 * 60 * 60;
+/// Maximum seconds in each rate limit window
+pub const MAX_RATE_LIMIT_WINDOW: u64 = 24 * 60 * 60;
+/// Maximum seconds of the cancellation grace period
+pub const MAX_CANCEL_GRACE_PERIOD: u64 = 30 * 24 * 60 * 60;
+/// Maximum cancellation fee, in basis points
+pub const MAX_CANCEL_FEE_BPS: Rate = Rate::new(5_000);
+/// Maximum surcharge for raising the priority of a job by one level, in basis points
+pub const MAX_PRIORITY_SURCHARGE_BPS: Rate = Rate::new(5_000);
+/// Maximum seconds each level of priority delays committing to a job
+pub const MAX_PRIORITY_CLAIM_DELAY: u64 = 24 * 60 * 60;
+/// Maximum minimum disbursement, a hundredth of a token with 8 decimals, so dust can't grow to
+/// swallow real payments
+pub const MAX_MIN_DISBURSEMENT: u64 = 1_000_000;
+/// Maximum slots an oracle price can be allowed to be behind the current slot, about ten minutes
+pub const MAX_MAX_PRICE_AGE: u64 = 1_500;
+/// Maximum confidence interval an oracle price can be allowed to have, in basis points of the
+/// price
+pub const MAX_MAX_PRICE_CONFIDENCE_BPS: Rate = Rate::new(1_000);
+/// Minimum seconds of the sweep delay
+pub const MIN_SWEEP_DELAY: u64 = 60 * 60;
+/// Maximum seconds of the sweep delay
+pub const MAX_SWEEP_DELAY: u64 = 30 * 24 * 60 * 60;
+/// Number of fields of the config that can be changed
+pub const CONFIG_FIELD_COUNT: u8 = 23;
+/// Number of reward tiers of the config
+pub const MAX_REWARD_TIERS: usize = 4;
+/// Reward multiplier of nodes that don't reach any reward tier, in basis points
+pub const BASE_REWARD_MULTIPLIER_BPS: u16 = 10_000;
+/// Maximum reward multiplier of a reward tier, in basis points
+pub const MAX_REWARD_MULTIPLIER_BPS: u16 = 50_000;
+/// Number of price tiers of the config
+pub const MAX_PRICE_TIERS: usize = 3;
+/// Units of work in an OctaneBench-hour, which jobs are priced in so they can be fractions of one
+pub const MILLI_OBH_PER_OBH: u64 = 1_000;
+
+/// Reward tier of nodes with at least a minimum stake, whose recorded work is weighted by a
+/// multiplier
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RewardTier {
+    /// Minimum stake of a node in the tier, in base units of the token
+    pub min_stake: u64,
+    /// Reward multiplier of nodes in the tier, in basis points, where zero disables the tier
+    pub multiplier_bps: u16,
+}
+
+impl RewardTier {
+    /// Length of a packed reward tier
+    pub const LEN: usize = 10; // 8 + 2
+
+    /// Whether the tier is enabled
+    pub fn is_enabled(&self) -> bool {
+        self.multiplier_bps != 0
+    }
+
+    fn pack_into(&self, output: &mut [u8; Self::LEN]) {
+        let (min_stake, multiplier_bps) = mut_array_refs![output, 8, 2];
+        *min_stake = self.min_stake.to_le_bytes();
+        *multiplier_bps = self.multiplier_bps.to_le_bytes();
+    }
+
+    fn unpack_from(input: &[u8; Self::LEN]) -> Self {
+        let (min_stake, multiplier_bps) = array_refs![input, 8, 2];
+        Self {
+            min_stake: u64::from_le_bytes(*min_stake),
+            multiplier_bps: u16::from_le_bytes(*multiplier_bps),
+        }
+    }
+}
+
+/// Config state
+///
+/// Protocol parameters shared by every escrow of the program, created once by the program's
+/// upgrade authority when the program is deployed.
+///
+/// Fields were appended to the original layout, which is `Config::LEGACY_LEN` bytes long, to the
+/// layout before the minimum disbursement, which is `Config::PRE_MIN_DISBURSEMENT_LEN` bytes long,
+/// to the layout before the OctaneBench-hour prices, which is `Config::PRE_OBH_PRICES_LEN` bytes
+/// long, to the layout before the oracle price guards, which is `Config::PRE_MAX_PRICE_AGE_LEN`
+/// bytes long, to the layout before the compliance authority, which is
+/// `Config::PRE_COMPLIANCE_LEN` bytes long, to the layout before the sweep delay, which is
+/// `Config::PRE_SWEEP_DELAY_LEN` bytes long, and to the layout before the incident mode, which is
+/// `Config::PRE_INCIDENT_MODE_LEN` bytes long. Configs created before then are unpacked with the
+/// appended fields set to their zero defaults, and are reallocated when one of them has to be
+/// written. The timelocked fields were widened to 32 bits by appending their high 16 bits, so
+/// fields from the minimum disbursement on aren't timelocked in those configs until the
+/// timelocked fields are set. Zero oracle price guards only accept prices published in the current
+/// slot with no confidence interval, so those configs fail closed until the guards are set. A zero
+/// sweep delay can't be set, so those configs wait for `DEFAULT_SWEEP_DELAY` instead. The zero
+/// incident mode is `IncidentMode::Normal`.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Config {
+    /// Account type, must be ConfigV1 currently
+    pub account_type: AccountType,
+    /// Governance authority that can change the config
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub governance: Pubkey,
+    /// Protocol fee, in basis points
+    pub fee_bps: Rate,
+    /// Minimum stake of a node, in base units of the token
+    pub min_stake: u64,
+    /// Seconds after a job's result is revealed during which a dispute can be opened over it
+    pub dispute_window: u64,
+    /// Seconds queued administrative actions wait before they can be applied
+    pub timelock_duration: u64,
+    /// Seconds in each rate limit window
+    pub rate_limit_window: u64,
+    /// Maximum number of rate limited actions each authority can take per window, where zero is
+    /// unlimited
+    pub rate_limit_max: u32,
+    /// Guardian authority that can cancel queued actions before they're applied, or the default
+    /// pubkey if there is none
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub guardian: Pubkey,
+    /// Whether escrow owners can be set instantly with `SetEscrowOwner`, instead of only being
+    /// rotated after the timelock with `ScheduleOwnerRotation`
+    pub instant_owner_change: bool,
+    /// Bit set of the fields whose changes must be queued with `QueueAction` instead of being set
+    /// directly with `SetConfig`, where each bit is the tag of a field returned by
+    /// `ConfigChange::field`
+    pub timelocked_fields: u32,
+    /// Reward tiers of nodes by stake, where the enabled tier with the highest minimum stake a node
+    /// reaches sets the multiplier of the work it records toward reward pools
+    pub reward_tiers: [RewardTier; MAX_REWARD_TIERS],
+    /// Seconds after a job is funded during which cancelling it refunds all of its tokens
+    pub cancel_grace_period: u64,
+    /// Fee charged for cancelling a job after its grace period, in basis points of its amount,
+    /// which is paid to the node that committed to the job
+    pub cancel_fee_bps: Rate,
+    /// Surcharge for raising the priority of a job by one level, in basis points of its amount,
+    /// which is paid to the treasury of its escrow
+    pub priority_surcharge_bps: Rate,
+    /// Seconds each level of priority below `MAX_JOB_PRIORITY` delays committing to a job after
+    /// it's funded, so nodes take urgent jobs first
+    pub priority_claim_delay: u64,
+    /// Where the remainder of rounding down the portions of a split goes, such as a cancelled
+    /// job's refund and cancellation fee
+    pub rounding_policy: RoundingPolicy,
+    /// Minimum amount of tokens disbursed by a transfer, below which a portion of a split is dust
+    /// that's swept to the treasury of its escrow instead, where zero disburses any amount. It's in
+    /// RNDR base units, and converted to the token of a job funded in another token at the job's
+    /// recorded exchange rate, see `Config::min_disbursement_in`.
+    pub min_disbursement: u64,
+    /// Price of an OctaneBench-hour of rendering in each price tier, in RNDR base units, which the
+    /// funding amount of a job is estimated from with `Config::job_cost`, where zero disables the
+    /// tier
+    pub obh_prices: [u64; MAX_PRICE_TIERS],
+    /// Slots an oracle price can be behind the current slot when it's consumed, beyond which it's
+    /// stale
+    pub max_price_age: u64,
+    /// Maximum confidence interval of an oracle price when it's consumed, in basis points of the
+    /// price, beyond which it's unreliable
+    pub max_price_confidence_bps: Rate,
+    /// Compliance authority that can freeze jobs under a legal hold with `FreezeJob`, or the
+    /// default pubkey if there is none
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub compliance: Pubkey,
+    /// Seconds a sweep of a treasury to its cold wallet waits after it's scheduled before it can
+    /// be executed, so monitoring has time to catch a sweep scheduled by an attacker
+    pub sweep_delay: u64,
+    /// Direction funds can flow through escrows in during an incident, which escrows enforce once
+    /// it's copied to them with `SyncIncidentMode`, see `IncidentMode`
+    pub incident_mode: IncidentMode,
+}
+
+impl Config {
+    /// Offset of the governance authority in a packed config
+    pub const GOVERNANCE_OFFSET: usize = ACCOUNT_TYPE_OFFSET + 1;
+    /// Offset of the fee in a packed config
+    pub const FEE_BPS_OFFSET: usize = Self::GOVERNANCE_OFFSET + PUBKEY_BYTES;
+    /// Offset of the minimum stake in a packed config
+    pub const MIN_STAKE_OFFSET: usize = Self::FEE_BPS_OFFSET + 2;
+    /// Offset of the dispute window in a packed config
+    pub const DISPUTE_WINDOW_OFFSET: usize = Self::MIN_STAKE_OFFSET + 8;
+    /// Offset of the timelock duration in a packed config
+    pub const TIMELOCK_DURATION_OFFSET: usize = Self::DISPUTE_WINDOW_OFFSET + 8;
+    /// Offset of the rate limit window in a packed config
+    pub const RATE_LIMIT_WINDOW_OFFSET: usize = Self::TIMELOCK_DURATION_OFFSET + 8;
+    /// Offset of the rate limit maximum in a packed config
+    pub const RATE_LIMIT_MAX_OFFSET: usize = Self::RATE_LIMIT_WINDOW_OFFSET + 8;
+    /// Offset of the guardian authority in a packed config
+    pub const GUARDIAN_OFFSET: usize = Self::RATE_LIMIT_MAX_OFFSET + 4;
+    /// Offset of the instant owner change flag in a packed config
+    pub const INSTANT_OWNER_CHANGE_OFFSET: usize = Self::GUARDIAN_OFFSET + PUBKEY_BYTES;
+    /// Offset of the timelocked fields in a packed config
+    pub const TIMELOCKED_FIELDS_OFFSET: usize = Self::INSTANT_OWNER_CHANGE_OFFSET + 1;
+    /// Offset of the reward tiers in a packed config
+    pub const REWARD_TIERS_OFFSET: usize = Self::TIMELOCKED_FIELDS_OFFSET + 2;
+    /// Offset of the cancellation grace period in a packed config
+    pub const CANCEL_GRACE_PERIOD_OFFSET: usize =
+        Self::REWARD_TIERS_OFFSET + MAX_REWARD_TIERS * RewardTier::LEN;
+    /// Offset of the cancellation fee in a packed config
+    pub const CANCEL_FEE_BPS_OFFSET: usize = Self::CANCEL_GRACE_PERIOD_OFFSET + 8;
+    /// Offset of the priority surcharge in a packed config
+    pub const PRIORITY_SURCHARGE_BPS_OFFSET: usize = Self::CANCEL_FEE_BPS_OFFSET + 2;
+    /// Offset of the priority claim delay in a packed config
+    pub const PRIORITY_CLAIM_DELAY_OFFSET: usize = Self::PRIORITY_SURCHARGE_BPS_OFFSET + 2;
+    /// Offset of the rounding policy in a packed config
+    pub const ROUNDING_POLICY_OFFSET: usize = Self::PRIORITY_CLAIM_DELAY_OFFSET + 8;
+    /// Offset of the minimum disbursement in a packed config
+    pub const MIN_DISBURSEMENT_OFFSET: usize = Self::ROUNDING_POLICY_OFFSET + 1;
+    /// Offset of the high 16 bits of the timelocked fields in a packed config
+    pub const TIMELOCKED_FIELDS_HIGH_OFFSET: usize = Self::MIN_DISBURSEMENT_OFFSET + 8;
+    /// Offset of the OctaneBench-hour prices in a packed config
+    pub const OBH_PRICES_OFFSET: usize = Self::TIMELOCKED_FIELDS_HIGH_OFFSET + 2;
+    /// Offset of the maximum oracle price age in a packed config
+    pub const MAX_PRICE_AGE_OFFSET: usize = Self::OBH_PRICES_OFFSET + MAX_PRICE_TIERS * 8;
+    /// Offset of the maximum oracle price confidence in a packed config
+    pub const MAX_PRICE_CONFIDENCE_BPS_OFFSET: usize = Self::MAX_PRICE_AGE_OFFSET + 8;
+    /// Offset of the compliance authority in a packed config
+    pub const COMPLIANCE_OFFSET: usize = Self::MAX_PRICE_CONFIDENCE_BPS_OFFSET + 2;
+    /// Offset of the sweep delay in a packed config
+    pub const SWEEP_DELAY_OFFSET: usize = Self::COMPLIANCE_OFFSET + PUBKEY_BYTES;
+    /// Offset of the incident mode in a packed config
+    pub const INCIDENT_MODE_OFFSET: usize = Self::SWEEP_DELAY_OFFSET + 8;
+
+    /// Length of configs created before the rounding policy was appended
+    pub const LEGACY_LEN: usize = Self::ROUNDING_POLICY_OFFSET;
+    /// Length of configs created before the minimum disbursement was appended
+    pub const PRE_MIN_DISBURSEMENT_LEN: usize = Self::MIN_DISBURSEMENT_OFFSET;
+    /// Length of configs created before the OctaneBench-hour prices were appended
+    pub const PRE_OBH_PRICES_LEN: usize = Self::OBH_PRICES_OFFSET;
+    /// Length of configs created before the oracle price guards were appended
+    pub const PRE_MAX_PRICE_AGE_LEN: usize = Self::MAX_PRICE_AGE_OFFSET;
+    /// Length of configs created before the compliance authority was appended
+    pub const PRE_COMPLIANCE_LEN: usize = Self::COMPLIANCE_OFFSET;
+    /// Length of configs created before the sweep delay was appended
+    pub const PRE_SWEEP_DELAY_LEN: usize = Self::SWEEP_DELAY_OFFSET;
+    /// Length of configs created before the incident mode was appended
+    pub const PRE_INCIDENT_MODE_LEN: usize = Self::INCIDENT_MODE_OFFSET;
+
+    /// Create a config with the default parameters
+    pub fn new(params: InitConfigParams) -> Self {
+        let mut config = Self::default();
+        Self::init(&mut config, params);
+        config
+    }
+
+    /// Initialize a config with the default parameters
+    pub fn init(&mut self, params: InitConfigParams) {
+        self.account_type = AccountType::ConfigV1;
+        self.governance = params.governance;
+        self.fee_bps = DEFAULT_FEE_BPS;
+        self.min_stake = DEFAULT_MIN_STAKE;
+        self.dispute_window = DEFAULT_DISPUTE_WINDOW;
+        self.timelock_duration = DEFAULT_TIMELOCK_DURATION;
+        self.rate_limit_window = DEFAULT_RATE_LIMIT_WINDOW;
+        self.rate_limit_max = DEFAULT_RATE_LIMIT_MAX;
+        self.guardian = Pubkey::default();
+        self.instant_owner_change = false;
+        self.timelocked_fields = DEFAULT_TIMELOCKED_FIELDS;
+        self.reward_tiers = [RewardTier::default(); MAX_REWARD_TIERS];
+        self.cancel_grace_period = DEFAULT_CANCEL_GRACE_PERIOD;
+        self.cancel_fee_bps = DEFAULT_CANCEL_FEE_BPS;
+        self.priority_surcharge_bps = DEFAULT_PRIORITY_SURCHARGE_BPS;
+        self.priority_claim_delay = DEFAULT_PRIORITY_CLAIM_DELAY;
+        self.rounding_policy = DEFAULT_ROUNDING_POLICY;
+        self.min_disbursement = DEFAULT_MIN_DISBURSEMENT;
+        self.obh_prices = [0; MAX_PRICE_TIERS];
+        self.max_price_age = DEFAULT_MAX_PRICE_AGE;
+        self.max_price_confidence_bps = DEFAULT_MAX_PRICE_CONFIDENCE_BPS;
+        self.compliance = Pubkey::default();
+        self.sweep_delay = DEFAULT_SWEEP_DELAY;
+        self.incident_mode = IncidentMode::Normal;
+    }
+
+    /// Whether a change to a field must wait for the timelock duration before it's applied
+    pub fn is_timelocked(&self, change: &ConfigChange) -> bool {
+        self.timelocked_fields & (1 << change.field()) != 0
+    }
+
+    /// Unpack a config from account data, which may have a legacy length
+    pub fn unpack_account(input: &[u8]) -> Result<Self, ProgramError> {
+        unpack_appended(
+            input,
+            &[
+                Self::LEGACY_LEN,
+                Self::PRE_MIN_DISBURSEMENT_LEN,
+                Self::PRE_OBH_PRICES_LEN,
+                Self::PRE_MAX_PRICE_AGE_LEN,
+                Self::PRE_COMPLIANCE_LEN,
+                Self::PRE_SWEEP_DELAY_LEN,
+                Self::PRE_INCIDENT_MODE_LEN,
+            ],
+        )
+    }
+
+    /// Pack a config into account data, which may have a legacy length if none of the fields
+    /// appended after it are set
+    pub fn pack_account(config: Self, output: &mut [u8]) -> Result<(), ProgramError> {
+        pack_appended(
+            config,
+            output,
+            &[
+                Self::LEGACY_LEN,
+                Self::PRE_MIN_DISBURSEMENT_LEN,
+                Self::PRE_OBH_PRICES_LEN,
+                Self::PRE_MAX_PRICE_AGE_LEN,
+                Self::PRE_COMPLIANCE_LEN,
+                Self::PRE_SWEEP_DELAY_LEN,
+                Self::PRE_INCIDENT_MODE_LEN,
+            ],
+        )
+    }
+
+    /// Guardian authority of the config, if it has one
+    pub fn guardian(&self) -> Option<&Pubkey> {
+        if self.guardian == Pubkey::default() {
+            None
+        } else {
+            Some(&self.guardian)
+        }
+    }
+
+    /// Compliance authority of the config, if it has one
+    pub fn compliance(&self) -> Option<&Pubkey> {
+        if self.compliance == Pubkey::default() {
+            None
+        } else {
+            Some(&self.compliance)
+        }
+    }
+
+    /// Unix timestamp from which a sweep of a treasury scheduled at a timestamp can be executed,
+    /// waiting for `DEFAULT_SWEEP_DELAY` in configs created before the sweep delay was appended
+    pub fn sweep_executable_at(&self, scheduled_at: UnixTimestamp) -> Option<UnixTimestamp> {
+        let sweep_delay = if self.sweep_delay == 0 {
+            DEFAULT_SWEEP_DELAY
+        } else {
+            self.sweep_delay
+        };
+        scheduled_at.checked_add(i64::try_from(sweep_delay).ok()?)
+    }
+
+    /// Reward multiplier of a node with a stake, in basis points
+    pub fn reward_multiplier_bps(&self, stake: u64) -> u16 {
+        self.reward_tiers
+            .iter()
+            .filter(|tier| tier.is_enabled() && tier.min_stake <= stake)
+            .max_by_key(|tier| tier.min_stake)
+            .map_or(BASE_REWARD_MULTIPLIER_BPS, |tier| tier.multiplier_bps)
+    }
+
+    /// Work of a node with a stake weighted by its reward multiplier, rounded down
+    pub fn weighted_work(&self, stake: u64, work: u64) -> Option<u64> {
+        Decimal::from(work)
+            .try_mul(Decimal::from_bps(self.reward_multiplier_bps(stake)))
+            .and_then(Decimal::try_floor_u64)
+            .ok()
+    }
+
+    /// Minimum disbursement in base units of a job's token, converted from RNDR at the job's
+    /// exchange rate rounded up, or in RNDR if the job has no exchange rate
+    pub fn min_disbursement_in(&self, exchange_rate: Option<ExchangeRate>) -> Option<u64> {
+        match exchange_rate {
+            Some(exchange_rate) => exchange_rate.try_from_rndr(self.min_disbursement).ok(),
+            None => Some(self.min_disbursement),
+        }
+    }
+
+    /// Split of a job with an amount of tokens that was funded at a timestamp when it's cancelled,
+    /// into the refund to its authority and the cancellation fee paid to its node, with dust swept
+    /// to the treasury, for a job settled at an exchange rate if it's funded in another token
+    pub fn cancellation_split(
+        &self,
+        amount: u64,
+        funded_at: UnixTimestamp,
+        now: UnixTimestamp,
+        exchange_rate: Option<ExchangeRate>,
+    ) -> Option<Split<2>> {
+        let fee_bps = self.cancellation_fee_bps(funded_at, now)?;
+        let min_disbursement = self.min_disbursement_in(exchange_rate)?;
+        Split::try_new(
+            amount,
+            [fee_bps.complement(), fee_bps],
+            self.rounding_policy,
+        )
+        .and_then(|split| split.try_sweep_dust(min_disbursement))
+        .ok()
+    }
+
+    /// Fee for cancelling a job with an amount of tokens that was funded at a timestamp, which is
+    /// zero until the grace period after funding has passed, rounded down
+    pub fn cancellation_fee(
+        &self,
+        amount: u64,
+        funded_at: UnixTimestamp,
+        now: UnixTimestamp,
+    ) -> Option<u64> {
+        self.cancellation_fee_bps(funded_at, now)?
+            .try_apply(amount)
+            .ok()
+    }
+
+    /// Rate of the cancellation fee of a job funded at a timestamp, which is zero until the grace
+    /// period after funding has passed
+    fn cancellation_fee_bps(&self, funded_at: UnixTimestamp, now: UnixTimestamp) -> Option<Rate> {
+        let grace_period = i64::try_from(self.cancel_grace_period).ok()?;
+        if now <= funded_at.saturating_add(grace_period) {
+            Some(Rate::ZERO)
+        } else {
+            Some(self.cancel_fee_bps)
+        }
+    }
+
+    /// Surcharge for raising the priority of a job with an amount of tokens by a number of levels,
+    /// rounded down
+    pub fn priority_surcharge(&self, amount: u64, levels: u8) -> Option<u64> {
+        Decimal::from(amount)
+            .try_mul(self.priority_surcharge_bps)
+            .and_then(|surcharge| surcharge.try_mul(u64::from(levels)))
+            .and_then(Decimal::try_floor_u64)
+            .ok()
+    }
+
+    /// Unix timestamp from which a job with a priority that was funded at a timestamp can be
+    /// committed to, which is later the lower its priority is
+    pub fn claimable_at(&self, priority: u8, funded_at: UnixTimestamp) -> Option<UnixTimestamp> {
+        let levels = MAX_JOB_PRIORITY.saturating_sub(priority);
+        let delay = self.priority_claim_delay.checked_mul(levels as u64)?;
+        funded_at.checked_add(i64::try_from(delay).ok()?)
+    }
+
+    /// Funding amount of a job of work in thousandths of an OctaneBench-hour rendered in a price
+    /// tier, in RNDR base units, rounded up so the amount always covers the work, or `None` if the
+    /// tier is disabled
+    pub fn job_cost(&self, tier: u8, milli_obh: u64) -> Option<u64> {
+        let price = *self
+            .obh_prices
+            .get(tier as usize)
+            .filter(|price| **price != 0)?;
+        // The product of two u64s is at most (2^64 - 1)^2, so it can't overflow a u128
+        let cost =
+            (u128::from(price) * u128::from(milli_obh)).div_ceil(u128::from(MILLI_OBH_PER_OBH));
+        u64::try_from(cost).ok()
+    }
+
+    /// Apply a change to a field of the config, returning the field's previous value
+    pub fn apply(&mut self, change: ConfigChange) -> ConfigChange {
+        match change {
+            ConfigChange::Governance(governance) => {
+                ConfigChange::Governance(std::mem::replace(&mut self.governance, governance))
+            }
+            ConfigChange::FeeBps(fee_bps) => {
+                ConfigChange::FeeBps(std::mem::replace(&mut self.fee_bps, fee_bps))
+            }
+            ConfigChange::MinStake(min_stake) => {
+                ConfigChange::MinStake(std::mem::replace(&mut self.min_stake, min_stake))
+            }
+            ConfigChange::DisputeWindow(dispute_window) => ConfigChange::DisputeWindow(
+                std::mem::replace(&mut self.dispute_window, dispute_window),
+            ),
+            ConfigChange::TimelockDuration(timelock_duration) => ConfigChange::TimelockDuration(
+                std::mem::replace(&mut self.timelock_duration, timelock_duration),
+            ),
+            ConfigChange::RateLimitWindow(rate_limit_window) => ConfigChange::RateLimitWindow(
+                std::mem::replace(&mut self.rate_limit_window, rate_limit_window),
+            ),
+            ConfigChange::RateLimitMax(rate_limit_max) => ConfigChange::RateLimitMax(
+                std::mem::replace(&mut self.rate_limit_max, rate_limit_max),
+            ),
+            ConfigChange::Guardian(guardian) => {
+                ConfigChange::Guardian(std::mem::replace(&mut self.guardian, guardian))
+            }
+            ConfigChange::InstantOwnerChange(instant_owner_change) => {
+                ConfigChange::InstantOwnerChange(std::mem::replace(
+                    &mut self.instant_owner_change,
+                    instant_owner_change,
+                ))
+            }
+            ConfigChange::TimelockedFields(timelocked_fields) => ConfigChange::TimelockedFields(
+                std::mem::replace(&mut self.timelocked_fields, timelocked_fields),
+            ),
+            ConfigChange::RewardTier(index, reward_tier) => ConfigChange::RewardTier(
+                index,
+                std::mem::replace(&mut self.reward_tiers[index as usize], reward_tier),
+            ),
+            ConfigChange::CancelGracePeriod(cancel_grace_period) => {
+                ConfigChange::CancelGracePeriod(std::mem::replace(
+                    &mut self.cancel_grace_period,
+                    cancel_grace_period,
+                ))
+            }
+            ConfigChange::CancelFeeBps(cancel_fee_bps) => ConfigChange::CancelFeeBps(
+                std::mem::replace(&mut self.cancel_fee_bps, cancel_fee_bps),
+            ),
+            ConfigChange::PrioritySurchargeBps(priority_surcharge_bps) => {
+                ConfigChange::PrioritySurchargeBps(std::mem::replace(
+                    &mut self.priority_surcharge_bps,
+                    priority_surcharge_bps,
+                ))
+            }
+            ConfigChange::PriorityClaimDelay(priority_claim_delay) => {
+                ConfigChange::PriorityClaimDelay(std::mem::replace(
+                    &mut self.priority_claim_delay,
+                    priority_claim_delay,
+                ))
+            }
+            ConfigChange::RoundingPolicy(rounding_policy) => ConfigChange::RoundingPolicy(
+                std::mem::replace(&mut self.rounding_policy, rounding_policy),
+            ),
+            ConfigChange::MinDisbursement(min_disbursement) => ConfigChange::MinDisbursement(
+                std::mem::replace(&mut self.min_disbursement, min_disbursement),
+            ),
+            ConfigChange::ObhPrice(index, obh_price) => ConfigChange::ObhPrice(
+                index,
+                std::mem::replace(&mut self.obh_prices[index as usize], obh_price),
+            ),
+            ConfigChange::MaxPriceAge(max_price_age) => {
+                ConfigChange::MaxPriceAge(std::mem::replace(&mut self.max_price_age, max_price_age))
+            }
+            ConfigChange::MaxPriceConfidenceBps(max_price_confidence_bps) => {
+                ConfigChange::MaxPriceConfidenceBps(std::mem::replace(
+                    &mut self.max_price_confidence_bps,
+                    max_price_confidence_bps,
+                ))
+            }
+            ConfigChange::Compliance(compliance) => {
+                ConfigChange::Compliance(std::mem::replace(&mut self.compliance, compliance))
+            }
+            ConfigChange::SweepDelay(sweep_delay) => {
+                ConfigChange::SweepDelay(std::mem::replace(&mut self.sweep_delay, sweep_delay))
+            }
+            ConfigChange::IncidentMode(incident_mode) => ConfigChange::IncidentMode(
+                std::mem::replace(&mut self.incident_mode, incident_mode),
+            ),
+        }
+    }
+}
+
+/// Change to a single field of a config
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ConfigChange {
+    /// Set the governance authority
+    Governance(#[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))] Pubkey),
+    /// Set the protocol fee, at most `MAX_FEE_BPS`
+    FeeBps(Rate),
+    /// Set the minimum stake of a node
+    MinStake(u64),
+    /// Set the dispute window, between `MIN_DISPUTE_WINDOW` and `MAX_DISPUTE_WINDOW`
+    DisputeWindow(u64),
+    /// Set the timelock duration, between `MIN_TIMELOCK_DURATION` and `MAX_TIMELOCK_DURATION`
+    TimelockDuration(u64),
+    /// Set the rate limit window, nonzero and at most `MAX_RATE_LIMIT_WINDOW`
+    RateLimitWindow(u64),
+    /// Set the rate limit maximum
+    RateLimitMax(u32),
+    /// Set the guardian authority, or the default pubkey to remove it
+    Guardian(#[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))] Pubkey),
+    /// Allow or disallow setting escrow owners instantly
+    InstantOwnerChange(bool),
+    /// Set which fields are timelocked, with no bits set beyond `CONFIG_FIELD_COUNT`
+    TimelockedFields(u32),
+    /// Set the reward tier at an index below `MAX_REWARD_TIERS`, with a multiplier of at most
+    /// `MAX_REWARD_MULTIPLIER_BPS`
+    RewardTier(u8, RewardTier),
+    /// Set the cancellation grace period, at most `MAX_CANCEL_GRACE_PERIOD`
+    CancelGracePeriod(u64),
+    /// Set the cancellation fee, at most `MAX_CANCEL_FEE_BPS`
+    CancelFeeBps(Rate),
+    /// Set the priority surcharge, at most `MAX_PRIORITY_SURCHARGE_BPS`
+    PrioritySurchargeBps(Rate),
+    /// Set the priority claim delay, at most `MAX_PRIORITY_CLAIM_DELAY`
+    PriorityClaimDelay(u64),
+    /// Set the rounding policy of splits
+    RoundingPolicy(RoundingPolicy),
+    /// Set the minimum disbursement, at most `MAX_MIN_DISBURSEMENT`
+    MinDisbursement(u64),
+    /// Set the OctaneBench-hour price of the price tier at an index below `MAX_PRICE_TIERS`, or
+    /// zero to disable it
+    ObhPrice(u8, u64),
+    /// Set the maximum oracle price age, at most `MAX_MAX_PRICE_AGE`
+    MaxPriceAge(u64),
+    /// Set the maximum oracle price confidence, at most `MAX_MAX_PRICE_CONFIDENCE_BPS`
+    MaxPriceConfidenceBps(Rate),
+    /// Set the compliance authority, or the default pubkey to remove it
+    Compliance(#[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))] Pubkey),
+    /// Set the sweep delay, between `MIN_SWEEP_DELAY` and `MAX_SWEEP_DELAY`
+    SweepDelay(u64),
+    /// Set the incident mode of escrows
+    IncidentMode(IncidentMode),
+}
+
+impl ConfigChange {
+    /// Tag of the field the change sets, as packed in instructions and queued actions
+    pub fn field(&self) -> u8 {
+        match self {
+            Self::Governance(_) => 0,
+            Self::FeeBps(_) => 1,
+            Self::MinStake(_) => 2,
+            Self::DisputeWindow(_) => 3,
+            Self::TimelockDuration(_) => 4,
+            Self::RateLimitWindow(_) => 5,
+            Self::RateLimitMax(_) => 6,
+            Self::Guardian(_) => 7,
+            Self::InstantOwnerChange(_) => 8,
+            Self::TimelockedFields(_) => 9,
+            Self::RewardTier(..) => 10,
+            Self::CancelGracePeriod(_) => 11,
+            Self::CancelFeeBps(_) => 12,
+            Self::PrioritySurchargeBps(_) => 13,
+            Self::PriorityClaimDelay(_) => 14,
+            Self::RoundingPolicy(_) => 15,
+            Self::MinDisbursement(_) => 16,
+            Self::ObhPrice(..) => 17,
+            Self::MaxPriceAge(_) => 18,
+            Self::MaxPriceConfidenceBps(_) => 19,
+            Self::Compliance(_) => 20,
+            Self::SweepDelay(_) => 21,
+            Self::IncidentMode(_) => 22,
+        }
+    }
+
+    /// Check that the new value of the field is within its bounds
+    pub fn validate(&self) -> Result<(), ProgramError> {
+        let valid = match *self {
+            Self::Governance(governance) => governance != Pubkey::default(),
+            Self::FeeBps(fee_bps) => fee_bps <= MAX_FEE_BPS,
+            Self::MinStake(_)
+            | Self::RateLimitMax(_)
+            | Self::Guardian(_)
+            | Self::Compliance(_)
+            | Self::InstantOwnerChange(_)
+            | Self::RoundingPolicy(_)
+            | Self::IncidentMode(_) => true,
+            Self::DisputeWindow(dispute_window) => {
+                (MIN_DISPUTE_WINDOW..=MAX_DISPUTE_WINDOW).contains(&dispute_window)
+            }
+            Self::TimelockDuration(timelock_duration) => {
+                (MIN_TIMELOCK_DURATION..=MAX_TIMELOCK_DURATION).contains(&timelock_duration)
+            }
+            Self::RateLimitWindow(rate_limit_window) => {
+                (1..=MAX_RATE_LIMIT_WINDOW).contains(&rate_limit_window)
+            }
+            Self::TimelockedFields(timelocked_fields) => {
+                timelocked_fields >> CONFIG_FIELD_COUNT == 0
+            }
+            Self::RewardTier(index, reward_tier) => {
+                (index as usize) < MAX_REWARD_TIERS
+                    && reward_tier.multiplier_bps <= MAX_REWARD_MULTIPLIER_BPS
+            }
+            Self::CancelGracePeriod(cancel_grace_period) => {
+                cancel_grace_period <= MAX_CANCEL_GRACE_PERIOD
+            }
+            Self::CancelFeeBps(cancel_fee_bps) => cancel_fee_bps <= MAX_CANCEL_FEE_BPS,
+            Self::PrioritySurchargeBps(priority_surcharge_bps) => {
+                priority_surcharge_bps <= MAX_PRIORITY_SURCHARGE_BPS
+            }
+            Self::PriorityClaimDelay(priority_claim_delay) => {
+                priority_claim_delay <= MAX_PRIORITY_CLAIM_DELAY
+            }
+            Self::MinDisbursement(min_disbursement) => min_disbursement <= MAX_MIN_DISBURSEMENT,
+            Self::ObhPrice(index, _) => (index as usize) < MAX_PRICE_TIERS,
+            Self::MaxPriceAge(max_price_age) => max_price_age <= MAX_MAX_PRICE_AGE,
+            Self::MaxPriceConfidenceBps(max_price_confidence_bps) => {
+                max_price_confidence_bps <= MAX_MAX_PRICE_CONFIDENCE_BPS
+            }
+            Self::SweepDelay(sweep_delay) => {
+                (MIN_SWEEP_DELAY..=MAX_SWEEP_DELAY).contains(&sweep_delay)
+            }
+        };
+        if !valid {
+            msg!("Config change {:?} is out of bounds", self);
+            return Err(ProgramError::InvalidArgument);
+        }
+        Ok(())
+    }
+}
+
+/// Initialize a config
+pub struct InitConfigParams {
+    /// Governance authority that can change the config
+    pub governance: Pubkey,
+}
+
+impl Sealed for Config {}
+
+impl IsInitialized for Config {
+    fn is_initialized(&self) -> bool {
+        self.account_type != AccountType::Uninitialized
+    }
+}
+
+const REWARD_TIERS_LEN: usize = MAX_REWARD_TIERS * RewardTier::LEN;
+const OBH_PRICES_LEN: usize = MAX_PRICE_TIERS * 8;
+const CONFIG_LEN: usize = 252; // 1 + 32 + 2 + 8 + 8 + 8 + 8 + 4 + 32 + 1 + 2 + (4 * 10) + 8 + 2 + 2 + 8 + 1 + 8 + 2 + (3 * 8) + 8 + 2 + 32 + 8 + 1
+const _: () = assert!(Config::INCIDENT_MODE_OFFSET + 1 == CONFIG_LEN);
+impl Pack for Config {
+    const LEN: usize = CONFIG_LEN;
+
+    fn pack_into_slice(&self, output: &mut [u8]) {
+        let output = array_mut_ref![output, 0, CONFIG_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (
+            account_type,
+            governance,
+            fee_bps,
+            min_stake,
+            dispute_window,
+            timelock_duration,
+            rate_limit_window,
+            rate_limit_max,
+            guardian,
+            instant_owner_change,
+            timelocked_fields,
+            reward_tiers,
+            cancel_grace_period,
+            cancel_fee_bps,
+            priority_surcharge_bps,
+            priority_claim_delay,
+            rounding_policy,
+            min_disbursement,
+            timelocked_fields_high,
+            obh_prices,
+            max_price_age,
+            max_price_confidence_bps,
+            compliance,
+            sweep_delay,
+            incident_mode,
+        ) = mut_array_refs![
+            output,
+            1,
+            PUBKEY_BYTES,
+            2,
+            8,
+            8,
+            8,
+            8,
+            4,
+            PUBKEY_BYTES,
+            1,
+            2,
+            REWARD_TIERS_LEN,
+            8,
+            2,
+            2,
+            8,
+            1,
+            8,
+            2,
+            OBH_PRICES_LEN,
+            8,
+            2,
+            PUBKEY_BYTES,
+            8,
+            1
+        ];
+
+        *account_type = u8::from(self.account_type).to_le_bytes();
+        governance.copy_from_slice(&self.governance.to_bytes());
+        *fee_bps = self.fee_bps.bps().to_le_bytes();
+        *min_stake = self.min_stake.to_le_bytes();
+        *dispute_window = self.dispute_window.to_le_bytes();
+        *timelock_duration = self.timelock_duration.to_le_bytes();
+        *rate_limit_window = self.rate_limit_window.to_le_bytes();
+        *rate_limit_max = self.rate_limit_max.to_le_bytes();
+        guardian.copy_from_slice(&self.guardian.to_bytes());
+        *instant_owner_change = u8::from(self.instant_owner_change).to_le_bytes();
+        *timelocked_fields = (self.timelocked_fields as u16).to_le_bytes();
+        for (output, reward_tier) in reward_tiers
+            .chunks_exact_mut(RewardTier::LEN)
+            .zip(&self.reward_tiers)
+        {
+            reward_tier.pack_into(array_mut_ref![output, 0, RewardTier::LEN]);
+        }
+        *cancel_grace_period = self.cancel_grace_period.to_le_bytes();
+        *cancel_fee_bps = self.cancel_fee_bps.bps().to_le_bytes();
+        *priority_surcharge_bps = self.priority_surcharge_bps.bps().to_le_bytes();
+        *priority_claim_delay = self.priority_claim_delay.to_le_bytes();
+        *rounding_policy = u8::from(self.rounding_policy).to_le_bytes();
+        *min_disbursement = self.min_disbursement.to_le_bytes();
+        *timelocked_fields_high = ((self.timelocked_fields >> 16) as u16).to_le_bytes();
+        for (output, obh_price) in obh_prices.chunks_exact_mut(8).zip(&self.obh_prices) {
+            output.copy_from_slice(&obh_price.to_le_bytes());
+        }
+        *max_price_age = self.max_price_age.to_le_bytes();
+        *max_price_confidence_bps = self.max_price_confidence_bps.bps().to_le_bytes();
+        compliance.copy_from_slice(&self.compliance.to_bytes());
+        *sweep_delay = self.sweep_delay.to_le_bytes();
+        *incident_mode = u8::from(self.incident_mode).to_le_bytes();
+    }
+
+    fn unpack_from_slice(input: &[u8]) -> Result<Self, ProgramError> {
+        let input = array_ref![input, 0, CONFIG_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (
+            account_type,
+            governance,
+            fee_bps,
+            min_stake,
+            dispute_window,
+            timelock_duration,
+            rate_limit_window,
+            rate_limit_max,
+            guardian,
+            instant_owner_change,
+            timelocked_fields,
+            reward_tiers,
+            cancel_grace_period,
+            cancel_fee_bps,
+            priority_surcharge_bps,
+            priority_claim_delay,
+            rounding_policy,
+            min_disbursement,
+            timelocked_fields_high,
+            obh_prices,
+            max_price_age,
+            max_price_confidence_bps,
+            compliance,
+            sweep_delay,
+            incident_mode,
+        ) = array_refs![
+            input,
+            1,
+            PUBKEY_BYTES,
+            2,
+            8,
+            8,
+            8,
+            8,
+            4,
+            PUBKEY_BYTES,
+            1,
+            2,
+            REWARD_TIERS_LEN,
+            8,
+            2,
+            2,
+            8,
+            1,
+            8,
+            2,
+            OBH_PRICES_LEN,
+            8,
+            2,
+            PUBKEY_BYTES,
+            8,
+            1
+        ];
+
+        let account_type = AccountType::from(u8::from_le_bytes(*account_type));
+        if account_type != AccountType::ConfigV1 {
+            msg!("Config account type is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let mut unpacked_reward_tiers = [RewardTier::default(); MAX_REWARD_TIERS];
+        for (reward_tier, input) in unpacked_reward_tiers
+            .iter_mut()
+            .zip(reward_tiers.chunks_exact(RewardTier::LEN))
+        {
+            *reward_tier = RewardTier::unpack_from(array_ref![input, 0, RewardTier::LEN]);
+        }
+
+        let mut unpacked_obh_prices = [0; MAX_PRICE_TIERS];
+        for (obh_price, input) in unpacked_obh_prices
+            .iter_mut()
+            .zip(obh_prices.chunks_exact(8))
+        {
+            *obh_price = u64::from_le_bytes(*array_ref![input, 0, 8]);
+        }
+
+        Ok(Self {
+            account_type,
+            governance: Pubkey::new_from_array(*governance),
+            fee_bps: unpack_rate(fee_bps)?,
+            min_stake: u64::from_le_bytes(*min_stake),
+            dispute_window: u64::from_le_bytes(*dispute_window),
+            timelock_duration: u64::from_le_bytes(*timelock_duration),
+            rate_limit_window: u64::from_le_bytes(*rate_limit_window),
+            rate_limit_max: u32::from_le_bytes(*rate_limit_max),
+            guardian: Pubkey::new_from_array(*guardian),
+            instant_owner_change: match u8::from_le_bytes(*instant_owner_change) {
+                0 => false,
+                1 => true,
+                _ => {
+                    msg!("Config instant owner change flag is invalid");
+                    return Err(ProgramError::InvalidAccountData);
+                }
+            },
+            timelocked_fields: u32::from(u16::from_le_bytes(*timelocked_fields_high)) << 16
+                | u32::from(u16::from_le_bytes(*timelocked_fields)),
+            reward_tiers: unpacked_reward_tiers,
+            cancel_grace_period: u64::from_le_bytes(*cancel_grace_period),
+            cancel_fee_bps: unpack_rate(cancel_fee_bps)?,
+            priority_surcharge_bps: unpack_rate(priority_surcharge_bps)?,
+            priority_claim_delay: u64::from_le_bytes(*priority_claim_delay),
+            rounding_policy: RoundingPolicy::try_from(u8::from_le_bytes(*rounding_policy))
+                .map_err(|_| {
+                    msg!("Config rounding policy is invalid");
+                    ProgramError::InvalidAccountData
+                })?,
+            min_disbursement: u64::from_le_bytes(*min_disbursement),
+            obh_prices: unpacked_obh_prices,
+            max_price_age: u64::from_le_bytes(*max_price_age),
+            max_price_confidence_bps: unpack_rate(max_price_confidence_bps)?,
+            compliance: Pubkey::new_from_array(*compliance),
+            sweep_delay: u64::from_le_bytes(*sweep_delay),
+            incident_mode: IncidentMode::try_from(u8::from_le_bytes(*incident_mode)).map_err(
+                |_| {
+                    msg!("Config incident mode is invalid");
+                    ProgramError::InvalidAccountData
+                },
+            )?,
+        })
+    }
+}
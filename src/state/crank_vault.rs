@@ -0,0 +1,105 @@
+use {
+    super::*,
+    arrayref::{array_mut_ref, array_ref, array_refs, mut_array_refs},
+    solana_program::{
+        msg,
+        program_error::ProgramError,
+        program_pack::{IsInitialized, Pack, Sealed},
+        pubkey::{Pubkey, PUBKEY_BYTES},
+    },
+};
+
+/// Crank vault state
+///
+/// Lamports an escrow sets aside to incentivize its permissionless cranks. Anyone can fund the
+/// vault by transferring lamports to it, and whoever successfully executes a crank of the escrow
+/// is paid the bounty from the lamports the vault holds above its rent exemption.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CrankVault {
+    /// Account type, must be CrankVaultV1 currently
+    pub account_type: AccountType,
+    /// Escrow whose cranks the vault pays for
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub escrow: Pubkey,
+    /// Lamports paid for each successfully executed crank
+    pub bounty: u64,
+    /// Lamports paid in bounties over the vault's lifetime
+    pub paid: u64,
+}
+
+impl CrankVault {
+    /// Offset of the escrow in a packed crank vault
+    pub const ESCROW_OFFSET: usize = ACCOUNT_TYPE_OFFSET + 1;
+    /// Offset of the bounty in a packed crank vault
+    pub const BOUNTY_OFFSET: usize = Self::ESCROW_OFFSET + PUBKEY_BYTES;
+    /// Offset of the lifetime bounties paid in a packed crank vault
+    pub const PAID_OFFSET: usize = Self::BOUNTY_OFFSET + 8;
+
+    /// Create a crank vault
+    pub fn new(params: InitCrankVaultParams) -> Self {
+        let mut crank_vault = Self::default();
+        Self::init(&mut crank_vault, params);
+        crank_vault
+    }
+
+    /// Initialize a crank vault that hasn't paid any bounties
+    pub fn init(&mut self, params: InitCrankVaultParams) {
+        self.account_type = AccountType::CrankVaultV1;
+        self.escrow = params.escrow;
+        self.bounty = params.bounty;
+        self.paid = 0;
+    }
+}
+
+/// Initialize a crank vault
+pub struct InitCrankVaultParams {
+    /// Escrow whose cranks the vault pays for
+    pub escrow: Pubkey,
+    /// Lamports paid for each successfully executed crank
+    pub bounty: u64,
+}
+
+impl Sealed for CrankVault {}
+
+impl IsInitialized for CrankVault {
+    fn is_initialized(&self) -> bool {
+        self.account_type != AccountType::Uninitialized
+    }
+}
+
+const CRANK_VAULT_LEN: usize = 49; // 1 + 32 + 8 + 8
+const _: () = assert!(CrankVault::PAID_OFFSET + 8 == CRANK_VAULT_LEN);
+impl Pack for CrankVault {
+    const LEN: usize = CRANK_VAULT_LEN;
+
+    fn pack_into_slice(&self, output: &mut [u8]) {
+        let output = array_mut_ref![output, 0, CRANK_VAULT_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (account_type, escrow, bounty, paid) = mut_array_refs![output, 1, PUBKEY_BYTES, 8, 8];
+
+        *account_type = u8::from(self.account_type).to_le_bytes();
+        escrow.copy_from_slice(&self.escrow.to_bytes());
+        *bounty = self.bounty.to_le_bytes();
+        *paid = self.paid.to_le_bytes();
+    }
+
+    fn unpack_from_slice(input: &[u8]) -> Result<Self, ProgramError> {
+        let input = array_ref![input, 0, CRANK_VAULT_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (account_type, escrow, bounty, paid) = array_refs![input, 1, PUBKEY_BYTES, 8, 8];
+
+        let account_type = AccountType::from(u8::from_le_bytes(*account_type));
+        if account_type != AccountType::CrankVaultV1 {
+            msg!("Crank vault account type is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self {
+            account_type,
+            escrow: Pubkey::new_from_array(*escrow),
+            bounty: u64::from_le_bytes(*bounty),
+            paid: u64::from_le_bytes(*paid),
+        })
+    }
+}
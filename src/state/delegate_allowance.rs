@@ -0,0 +1,127 @@
+use {
+    super::*,
+    arrayref::{array_mut_ref, array_ref, array_refs, mut_array_refs},
+    solana_program::{
+        clock::Slot,
+        msg,
+        program_error::ProgramError,
+        program_pack::{IsInitialized, Pack, Sealed},
+        pubkey::{Pubkey, PUBKEY_BYTES},
+    },
+};
+
+/// Delegate allowance state
+///
+/// Tokens that a delegate the escrow owner approved can still disburse from the escrow in the
+/// owner's place until its expiry slot, decremented by each disbursement.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DelegateAllowance {
+    /// Account type, must be DelegateAllowanceV1 currently
+    pub account_type: AccountType,
+    /// Escrow the delegate can disburse from
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub escrow: Pubkey,
+    /// Delegate authority that can disburse in place of the escrow owner
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub delegate: Pubkey,
+    /// Amount of tokens the delegate can still disburse
+    pub remaining: u64,
+    /// Last slot the delegate can disburse in
+    pub expiry_slot: Slot,
+}
+
+impl DelegateAllowance {
+    /// Offset of the escrow in a packed delegate allowance
+    pub const ESCROW_OFFSET: usize = ACCOUNT_TYPE_OFFSET + 1;
+    /// Offset of the delegate in a packed delegate allowance
+    pub const DELEGATE_OFFSET: usize = Self::ESCROW_OFFSET + PUBKEY_BYTES;
+
+    /// Create a delegate allowance
+    pub fn new(params: InitDelegateAllowanceParams) -> Self {
+        let mut delegate_allowance = Self::default();
+        Self::init(&mut delegate_allowance, params);
+        delegate_allowance
+    }
+
+    /// Initialize a delegate allowance
+    pub fn init(&mut self, params: InitDelegateAllowanceParams) {
+        self.account_type = AccountType::DelegateAllowanceV1;
+        self.escrow = params.escrow;
+        self.delegate = params.delegate;
+        self.remaining = params.limit;
+        self.expiry_slot = params.expiry_slot;
+    }
+
+    /// Check if the delegate can still disburse in a slot
+    pub fn is_active(&self, slot: Slot) -> bool {
+        slot <= self.expiry_slot
+    }
+
+    /// Deduct a disbursement from the remaining allowance, returning `None` if it exceeds it
+    pub fn consume(&mut self, amount: u64) -> Option<()> {
+        self.remaining = self.remaining.checked_sub(amount)?;
+        Some(())
+    }
+}
+
+/// Initialize a delegate allowance
+pub struct InitDelegateAllowanceParams {
+    /// Escrow the delegate can disburse from
+    pub escrow: Pubkey,
+    /// Delegate authority that can disburse in place of the escrow owner
+    pub delegate: Pubkey,
+    /// Cumulative amount of tokens the delegate can disburse
+    pub limit: u64,
+    /// Last slot the delegate can disburse in
+    pub expiry_slot: Slot,
+}
+
+impl Sealed for DelegateAllowance {}
+
+impl IsInitialized for DelegateAllowance {
+    fn is_initialized(&self) -> bool {
+        self.account_type != AccountType::Uninitialized
+    }
+}
+
+const DELEGATE_ALLOWANCE_LEN: usize = 81; // 1 + 32 + 32 + 8 + 8
+const _: () =
+    assert!(DelegateAllowance::DELEGATE_OFFSET + PUBKEY_BYTES + 16 == DELEGATE_ALLOWANCE_LEN);
+impl Pack for DelegateAllowance {
+    const LEN: usize = DELEGATE_ALLOWANCE_LEN;
+
+    fn pack_into_slice(&self, output: &mut [u8]) {
+        let output = array_mut_ref![output, 0, DELEGATE_ALLOWANCE_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (account_type, escrow, delegate, remaining, expiry_slot) =
+            mut_array_refs![output, 1, PUBKEY_BYTES, PUBKEY_BYTES, 8, 8];
+
+        *account_type = u8::from(self.account_type).to_le_bytes();
+        escrow.copy_from_slice(&self.escrow.to_bytes());
+        delegate.copy_from_slice(&self.delegate.to_bytes());
+        *remaining = self.remaining.to_le_bytes();
+        *expiry_slot = self.expiry_slot.to_le_bytes();
+    }
+
+    fn unpack_from_slice(input: &[u8]) -> Result<Self, ProgramError> {
+        let input = array_ref![input, 0, DELEGATE_ALLOWANCE_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (account_type, escrow, delegate, remaining, expiry_slot) =
+            array_refs![input, 1, PUBKEY_BYTES, PUBKEY_BYTES, 8, 8];
+
+        let account_type = AccountType::from(u8::from_le_bytes(*account_type));
+        if account_type != AccountType::DelegateAllowanceV1 {
+            msg!("Delegate allowance account type is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self {
+            account_type,
+            escrow: Pubkey::new_from_array(*escrow),
+            delegate: Pubkey::new_from_array(*delegate),
+            remaining: u64::from_le_bytes(*remaining),
+            expiry_slot: Slot::from_le_bytes(*expiry_slot),
+        })
+    }
+}
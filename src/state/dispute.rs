@@ -0,0 +1,191 @@
+use {
+    super::*,
+    arrayref::{array_mut_ref, array_ref, array_refs, mut_array_refs},
+    solana_program::{
+        clock::UnixTimestamp,
+        hash::HASH_BYTES,
+        msg,
+        program_error::ProgramError,
+        program_pack::{IsInitialized, Pack, Sealed},
+        pubkey::{Pubkey, PUBKEY_BYTES},
+    },
+};
+
+/// Dispute state
+///
+/// Records a dispute over the result of a job, the evidence submitted by the job's authority and
+/// node, and the arbitrator's ruling on how the job's funds are split between them.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Dispute {
+    /// Account type, must be DisputeV1 currently
+    pub account_type: AccountType,
+    /// Job the dispute is over
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub job: Pubkey,
+    /// Job authority or node that opened the dispute
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub opener: Pubkey,
+    /// Unix timestamp the dispute was opened at
+    pub opened_at: UnixTimestamp,
+    /// Hash of the evidence submitted by the job's authority, zero if none was submitted
+    pub authority_evidence_hash: [u8; HASH_BYTES],
+    /// Hash of the evidence submitted by the job's node, zero if none was submitted
+    pub node_evidence_hash: [u8; HASH_BYTES],
+    /// Unix timestamp the dispute was resolved at, zero if it hasn't been resolved
+    pub resolved_at: UnixTimestamp,
+    /// Amount of tokens the arbitrator awarded to the node
+    pub node_amount: u64,
+    /// Amount of tokens the arbitrator returned to the job's authority
+    pub authority_amount: u64,
+}
+
+impl Dispute {
+    /// Offset of the job in a packed dispute
+    pub const JOB_OFFSET: usize = ACCOUNT_TYPE_OFFSET + 1;
+    /// Offset of the opener in a packed dispute
+    pub const OPENER_OFFSET: usize = Self::JOB_OFFSET + PUBKEY_BYTES;
+    /// Offset of the opening timestamp in a packed dispute
+    pub const OPENED_AT_OFFSET: usize = Self::OPENER_OFFSET + PUBKEY_BYTES;
+    /// Offset of the authority's evidence hash in a packed dispute
+    pub const AUTHORITY_EVIDENCE_HASH_OFFSET: usize = Self::OPENED_AT_OFFSET + 8;
+    /// Offset of the node's evidence hash in a packed dispute
+    pub const NODE_EVIDENCE_HASH_OFFSET: usize = Self::AUTHORITY_EVIDENCE_HASH_OFFSET + HASH_BYTES;
+    /// Offset of the resolution timestamp in a packed dispute
+    pub const RESOLVED_AT_OFFSET: usize = Self::NODE_EVIDENCE_HASH_OFFSET + HASH_BYTES;
+    /// Offset of the node's amount in a packed dispute
+    pub const NODE_AMOUNT_OFFSET: usize = Self::RESOLVED_AT_OFFSET + 8;
+    /// Offset of the authority's amount in a packed dispute
+    pub const AUTHORITY_AMOUNT_OFFSET: usize = Self::NODE_AMOUNT_OFFSET + 8;
+
+    /// Create a dispute
+    pub fn new(params: InitDisputeParams) -> Self {
+        let mut dispute = Self::default();
+        Self::init(&mut dispute, params);
+        dispute
+    }
+
+    /// Initialize a dispute
+    pub fn init(&mut self, params: InitDisputeParams) {
+        self.account_type = AccountType::DisputeV1;
+        self.job = params.job;
+        self.opener = params.opener;
+        self.opened_at = params.opened_at;
+        self.authority_evidence_hash = [0; HASH_BYTES];
+        self.node_evidence_hash = [0; HASH_BYTES];
+        self.resolved_at = 0;
+        self.node_amount = 0;
+        self.authority_amount = 0;
+    }
+
+    /// Whether the arbitrator has ruled on the dispute
+    pub fn is_resolved(&self) -> bool {
+        self.resolved_at != 0
+    }
+}
+
+/// Initialize a dispute
+pub struct InitDisputeParams {
+    /// Job the dispute is over
+    pub job: Pubkey,
+    /// Job authority or node that opened the dispute
+    pub opener: Pubkey,
+    /// Unix timestamp the dispute was opened at
+    pub opened_at: UnixTimestamp,
+}
+
+impl Sealed for Dispute {}
+
+impl IsInitialized for Dispute {
+    fn is_initialized(&self) -> bool {
+        self.account_type != AccountType::Uninitialized
+    }
+}
+
+const DISPUTE_LEN: usize = 161; // 1 + 32 + 32 + 8 + 32 + 32 + 8 + 8 + 8
+const _: () = assert!(Dispute::AUTHORITY_AMOUNT_OFFSET + 8 == DISPUTE_LEN);
+impl Pack for Dispute {
+    const LEN: usize = DISPUTE_LEN;
+
+    fn pack_into_slice(&self, output: &mut [u8]) {
+        let output = array_mut_ref![output, 0, DISPUTE_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (
+            account_type,
+            job,
+            opener,
+            opened_at,
+            authority_evidence_hash,
+            node_evidence_hash,
+            resolved_at,
+            node_amount,
+            authority_amount,
+        ) = mut_array_refs![
+            output,
+            1,
+            PUBKEY_BYTES,
+            PUBKEY_BYTES,
+            8,
+            HASH_BYTES,
+            HASH_BYTES,
+            8,
+            8,
+            8
+        ];
+
+        *account_type = u8::from(self.account_type).to_le_bytes();
+        job.copy_from_slice(&self.job.to_bytes());
+        opener.copy_from_slice(&self.opener.to_bytes());
+        *opened_at = self.opened_at.to_le_bytes();
+        *authority_evidence_hash = self.authority_evidence_hash;
+        *node_evidence_hash = self.node_evidence_hash;
+        *resolved_at = self.resolved_at.to_le_bytes();
+        *node_amount = self.node_amount.to_le_bytes();
+        *authority_amount = self.authority_amount.to_le_bytes();
+    }
+
+    fn unpack_from_slice(input: &[u8]) -> Result<Self, ProgramError> {
+        let input = array_ref![input, 0, DISPUTE_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (
+            account_type,
+            job,
+            opener,
+            opened_at,
+            authority_evidence_hash,
+            node_evidence_hash,
+            resolved_at,
+            node_amount,
+            authority_amount,
+        ) = array_refs![
+            input,
+            1,
+            PUBKEY_BYTES,
+            PUBKEY_BYTES,
+            8,
+            HASH_BYTES,
+            HASH_BYTES,
+            8,
+            8,
+            8
+        ];
+
+        let account_type = AccountType::from(u8::from_le_bytes(*account_type));
+        if account_type != AccountType::DisputeV1 {
+            msg!("Dispute account type is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self {
+            account_type,
+            job: Pubkey::new_from_array(*job),
+            opener: Pubkey::new_from_array(*opener),
+            opened_at: UnixTimestamp::from_le_bytes(*opened_at),
+            authority_evidence_hash: *authority_evidence_hash,
+            node_evidence_hash: *node_evidence_hash,
+            resolved_at: UnixTimestamp::from_le_bytes(*resolved_at),
+            node_amount: u64::from_le_bytes(*node_amount),
+            authority_amount: u64::from_le_bytes(*authority_amount),
+        })
+    }
+}
@@ -0,0 +1,164 @@
+use {
+    super::*,
+    crate::math::{Decimal, Rate},
+    arrayref::{array_mut_ref, array_ref, array_refs, mut_array_refs},
+    solana_program::{
+        clock::Epoch,
+        msg,
+        program_error::ProgramError,
+        program_pack::{IsInitialized, Pack, Sealed},
+        pubkey::{Pubkey, PUBKEY_BYTES},
+    },
+};
+
+/// Basis points the emission of each epoch can decay by, at most all of it
+pub const MAX_DECAY_BPS: Rate = Rate::ONE;
+
+/// Emission schedule state
+///
+/// Rewards an escrow emits into the reward pool of each epoch, held in the schedule's associated
+/// token account. The first epoch emits the initial amount, and each later epoch emits `decay_bps`
+/// less than the one before, so the emission of any epoch can be computed from the schedule alone.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EmissionSchedule {
+    /// Account type, must be EmissionScheduleV1 currently
+    pub account_type: AccountType,
+    /// Escrow the rewards are emitted for
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub escrow: Pubkey,
+    /// First epoch that emits rewards
+    pub start_epoch: Epoch,
+    /// Amount of tokens emitted in the first epoch
+    pub initial_amount: u64,
+    /// Basis points the emission decays by from each epoch to the next, at most `MAX_DECAY_BPS`
+    pub decay_bps: Rate,
+    /// Earliest epoch that hasn't emitted rewards yet
+    pub next_epoch: Epoch,
+    /// Amount of tokens emitted over every epoch
+    pub emitted: u64,
+}
+
+impl EmissionSchedule {
+    /// Offset of the escrow in a packed emission schedule
+    pub const ESCROW_OFFSET: usize = ACCOUNT_TYPE_OFFSET + 1;
+    /// Offset of the start epoch in a packed emission schedule
+    pub const START_EPOCH_OFFSET: usize = Self::ESCROW_OFFSET + PUBKEY_BYTES;
+    /// Offset of the initial amount in a packed emission schedule
+    pub const INITIAL_AMOUNT_OFFSET: usize = Self::START_EPOCH_OFFSET + 8;
+    /// Offset of the decay in a packed emission schedule
+    pub const DECAY_BPS_OFFSET: usize = Self::INITIAL_AMOUNT_OFFSET + 8;
+    /// Offset of the next epoch in a packed emission schedule
+    pub const NEXT_EPOCH_OFFSET: usize = Self::DECAY_BPS_OFFSET + 2;
+    /// Offset of the emitted amount in a packed emission schedule
+    pub const EMITTED_OFFSET: usize = Self::NEXT_EPOCH_OFFSET + 8;
+
+    /// Create an emission schedule
+    pub fn new(params: InitEmissionScheduleParams) -> Self {
+        let mut emission_schedule = Self::default();
+        Self::init(&mut emission_schedule, params);
+        emission_schedule
+    }
+
+    /// Initialize an emission schedule that hasn't emitted rewards
+    pub fn init(&mut self, params: InitEmissionScheduleParams) {
+        self.account_type = AccountType::EmissionScheduleV1;
+        self.escrow = params.escrow;
+        self.start_epoch = params.start_epoch;
+        self.initial_amount = params.initial_amount;
+        self.decay_bps = params.decay_bps;
+        self.next_epoch = params.start_epoch;
+        self.emitted = 0;
+    }
+
+    /// Amount of tokens emitted in an epoch, the initial amount multiplied by the decay factor
+    /// once for every epoch since the start epoch, or zero before the start epoch
+    pub fn emission(&self, epoch: Epoch) -> u64 {
+        let elapsed = match epoch.checked_sub(self.start_epoch) {
+            Some(elapsed) => elapsed,
+            None => return 0,
+        };
+
+        // Exponentiation by squaring in fixed point, rounding down at each step
+        let mut factor = Decimal::from(self.decay_bps.complement());
+        let mut decay = Decimal::one();
+        let mut exponent = elapsed;
+        while exponent > 0 && decay > Decimal::zero() {
+            if exponent & 1 == 1 {
+                decay = decay.try_mul(factor).unwrap_or_default();
+            }
+            factor = factor.try_mul(factor).unwrap_or_default();
+            exponent >>= 1;
+        }
+
+        // The decay is at most one, so the emission is at most the initial amount
+        Decimal::from(self.initial_amount)
+            .try_mul(decay)
+            .and_then(Decimal::try_floor_u64)
+            .unwrap_or(0)
+    }
+}
+
+/// Initialize an emission schedule
+pub struct InitEmissionScheduleParams {
+    /// Escrow the rewards are emitted for
+    pub escrow: Pubkey,
+    /// First epoch that emits rewards
+    pub start_epoch: Epoch,
+    /// Amount of tokens emitted in the first epoch
+    pub initial_amount: u64,
+    /// Basis points the emission decays by from each epoch to the next
+    pub decay_bps: Rate,
+}
+
+impl Sealed for EmissionSchedule {}
+
+impl IsInitialized for EmissionSchedule {
+    fn is_initialized(&self) -> bool {
+        self.account_type != AccountType::Uninitialized
+    }
+}
+
+const EMISSION_SCHEDULE_LEN: usize = 67; // 1 + 32 + 8 + 8 + 2 + 8 + 8
+const _: () = assert!(EmissionSchedule::EMITTED_OFFSET + 8 == EMISSION_SCHEDULE_LEN);
+impl Pack for EmissionSchedule {
+    const LEN: usize = EMISSION_SCHEDULE_LEN;
+
+    fn pack_into_slice(&self, output: &mut [u8]) {
+        let output = array_mut_ref![output, 0, EMISSION_SCHEDULE_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (account_type, escrow, start_epoch, initial_amount, decay_bps, next_epoch, emitted) =
+            mut_array_refs![output, 1, PUBKEY_BYTES, 8, 8, 2, 8, 8];
+
+        *account_type = u8::from(self.account_type).to_le_bytes();
+        escrow.copy_from_slice(&self.escrow.to_bytes());
+        *start_epoch = self.start_epoch.to_le_bytes();
+        *initial_amount = self.initial_amount.to_le_bytes();
+        *decay_bps = self.decay_bps.bps().to_le_bytes();
+        *next_epoch = self.next_epoch.to_le_bytes();
+        *emitted = self.emitted.to_le_bytes();
+    }
+
+    fn unpack_from_slice(input: &[u8]) -> Result<Self, ProgramError> {
+        let input = array_ref![input, 0, EMISSION_SCHEDULE_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (account_type, escrow, start_epoch, initial_amount, decay_bps, next_epoch, emitted) =
+            array_refs![input, 1, PUBKEY_BYTES, 8, 8, 2, 8, 8];
+
+        let account_type = AccountType::from(u8::from_le_bytes(*account_type));
+        if account_type != AccountType::EmissionScheduleV1 {
+            msg!("Emission schedule account type is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self {
+            account_type,
+            escrow: Pubkey::new_from_array(*escrow),
+            start_epoch: Epoch::from_le_bytes(*start_epoch),
+            initial_amount: u64::from_le_bytes(*initial_amount),
+            decay_bps: unpack_rate(decay_bps)?,
+            next_epoch: Epoch::from_le_bytes(*next_epoch),
+            emitted: u64::from_le_bytes(*emitted),
+        })
+    }
+}
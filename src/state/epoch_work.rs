@@ -0,0 +1,121 @@
+use {
+    super::*,
+    arrayref::{array_mut_ref, array_ref, array_refs, mut_array_refs},
+    solana_program::{
+        msg,
+        program_error::ProgramError,
+        program_pack::{IsInitialized, Pack, Sealed},
+        pubkey::{Pubkey, PUBKEY_BYTES},
+    },
+};
+
+/// Epoch work state
+///
+/// Work a node recorded toward a reward pool during its epoch, from the amounts of the jobs the
+/// node was paid for.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EpochWork {
+    /// Account type, must be EpochWorkV1 currently
+    pub account_type: AccountType,
+    /// Reward pool the work is recorded toward
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub reward_pool: Pubkey,
+    /// Node authority that did the work
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub node: Pubkey,
+    /// Work recorded during the epoch
+    pub work: u64,
+    /// Whether the node has claimed its share of the rewards
+    pub claimed: bool,
+}
+
+impl EpochWork {
+    /// Offset of the reward pool in a packed epoch work
+    pub const REWARD_POOL_OFFSET: usize = ACCOUNT_TYPE_OFFSET + 1;
+    /// Offset of the node in a packed epoch work
+    pub const NODE_OFFSET: usize = Self::REWARD_POOL_OFFSET + PUBKEY_BYTES;
+    /// Offset of the work in a packed epoch work
+    pub const WORK_OFFSET: usize = Self::NODE_OFFSET + PUBKEY_BYTES;
+    /// Offset of the claimed flag in a packed epoch work
+    pub const CLAIMED_OFFSET: usize = Self::WORK_OFFSET + 8;
+
+    /// Create an epoch work
+    pub fn new(params: InitEpochWorkParams) -> Self {
+        let mut epoch_work = Self::default();
+        Self::init(&mut epoch_work, params);
+        epoch_work
+    }
+
+    /// Initialize an epoch work with no work
+    pub fn init(&mut self, params: InitEpochWorkParams) {
+        self.account_type = AccountType::EpochWorkV1;
+        self.reward_pool = params.reward_pool;
+        self.node = params.node;
+        self.work = 0;
+        self.claimed = false;
+    }
+}
+
+/// Initialize an epoch work
+pub struct InitEpochWorkParams {
+    /// Reward pool the work is recorded toward
+    pub reward_pool: Pubkey,
+    /// Node authority that did the work
+    pub node: Pubkey,
+}
+
+impl Sealed for EpochWork {}
+
+impl IsInitialized for EpochWork {
+    fn is_initialized(&self) -> bool {
+        self.account_type != AccountType::Uninitialized
+    }
+}
+
+const EPOCH_WORK_LEN: usize = 74; // 1 + 32 + 32 + 8 + 1
+const _: () = assert!(EpochWork::CLAIMED_OFFSET + 1 == EPOCH_WORK_LEN);
+impl Pack for EpochWork {
+    const LEN: usize = EPOCH_WORK_LEN;
+
+    fn pack_into_slice(&self, output: &mut [u8]) {
+        let output = array_mut_ref![output, 0, EPOCH_WORK_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (account_type, reward_pool, node, work, claimed) =
+            mut_array_refs![output, 1, PUBKEY_BYTES, PUBKEY_BYTES, 8, 1];
+
+        *account_type = u8::from(self.account_type).to_le_bytes();
+        reward_pool.copy_from_slice(&self.reward_pool.to_bytes());
+        node.copy_from_slice(&self.node.to_bytes());
+        *work = self.work.to_le_bytes();
+        *claimed = u8::from(self.claimed).to_le_bytes();
+    }
+
+    fn unpack_from_slice(input: &[u8]) -> Result<Self, ProgramError> {
+        let input = array_ref![input, 0, EPOCH_WORK_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (account_type, reward_pool, node, work, claimed) =
+            array_refs![input, 1, PUBKEY_BYTES, PUBKEY_BYTES, 8, 1];
+
+        let account_type = AccountType::from(u8::from_le_bytes(*account_type));
+        if account_type != AccountType::EpochWorkV1 {
+            msg!("Epoch work account type is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self {
+            account_type,
+            reward_pool: Pubkey::new_from_array(*reward_pool),
+            node: Pubkey::new_from_array(*node),
+            work: u64::from_le_bytes(*work),
+            claimed: match u8::from_le_bytes(*claimed) {
+                0 => false,
+                1 => true,
+                _ => {
+                    msg!("Epoch work claimed flag is invalid");
+                    return Err(ProgramError::InvalidAccountData);
+                }
+            },
+        })
+    }
+}
@@ -2,26 +2,148 @@ use {
     super::*,
     arrayref::{array_mut_ref, array_ref, array_refs, mut_array_refs},
     solana_program::{
+        clock::Slot,
         msg,
         program_error::ProgramError,
         program_pack::{IsInitialized, Pack, Sealed},
         pubkey::{Pubkey, PUBKEY_BYTES},
     },
+    spl_associated_token_account::get_associated_token_address,
     std::convert::TryFrom,
 };
 
+/// Number of previous owners recorded in the owner history of each escrow
+pub const MAX_OWNER_HISTORY: usize = 8;
+
 /// Escrow state
+///
+/// Fields were appended to the original layout, which is `Escrow::LEGACY_LEN` bytes long, in
+/// sections starting at these offsets:
+///
+/// - the token mint and the fields up to the approver, from `Escrow::LEGACY_LEN`
+/// - the owner history, from `Escrow::PRE_OWNER_HISTORY_LEN`
+/// - the token account, from `Escrow::PRE_TOKEN_ACCOUNT_LEN`
+/// - the mint migration fields, from `Escrow::PRE_MINT_MIGRATION_LEN`
+/// - the escrow id, from `Escrow::PRE_ESCROW_ID_LEN`
+/// - the price feed, from `Escrow::PRE_PRICE_FEED_LEN`
+/// - the incident mode, from `Escrow::PRE_INCIDENT_MODE_LEN`
+/// - the attention flag, from `Escrow::PRE_NEEDS_ATTENTION_LEN`
+///
+/// Escrows created before a section was appended are unpacked with its fields set to their zero
+/// defaults, and are reallocated when one of those fields has to be written. The token mint of
+/// legacy escrows is resolved from the accounts provided with them instead, see
+/// `Escrow::pack_account`.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Escrow {
     /// Account type, must be EscrowV1 currently
     pub account_type: AccountType,
-    /// Amount of tokens in escrow
+    /// Amount of tokens in escrow, not including amounts that haven't been aggregated from
+    /// escrow shards yet
     pub amount: u64,
     /// Owner authority that can disburse funds
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
     pub owner: Pubkey,
+    /// RNDR SPL Token mint of the tokens in escrow
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub token_mint: Pubkey,
+    /// Seconds after a job's result is revealed during which the job's authority can challenge it
+    /// before the node can claim payment
+    pub challenge_window: u64,
+    /// Program that must verify the proof of a job's result for it to be revealed, or the default
+    /// pubkey if results are revealed without a proof
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub proof_verifier: Pubkey,
+    /// Arbitrator authority that resolves disputes over jobs, or the default pubkey if disputes
+    /// can't be resolved
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub arbitrator: Pubkey,
+    /// Approver authority that must approve disbursements proposed by the owner, or the default
+    /// pubkey if the owner disburses funds directly
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub approver: Pubkey,
+    /// Most recent owner rotations, oldest first and at most `MAX_OWNER_HISTORY`, so the
+    /// provenance of the current owner can be verified without replaying the chain
+    pub owner_history: Vec<OwnerRotation>,
+    /// Token account the pooled tokens of the escrow are held in, or the default pubkey if they're
+    /// held in the associated token account of the escrow
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub token_account: Pubkey,
+    /// Token mint the escrow was created with and that its address is derived from, if it has
+    /// been migrated to a new token mint, or the default pubkey otherwise
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub original_token_mint: Pubkey,
+    /// Number of mint migrations whose tokens have been converted, see `MintMigration`
+    pub mint_migrations: u8,
+    /// Namespace of the escrow that its address is derived from, so test, staging and regional
+    /// escrows of the same token mint can coexist, or zero for the default escrow
+    pub escrow_id: u64,
+    /// Oracle price account of the escrow's token that Jobs are funded at with
+    /// `FundJobAtOraclePrice`, see `OraclePrice`, or the default pubkey if Jobs can't be funded
+    /// at an oracle price
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub price_feed: Pubkey,
+    /// Incident mode of the config the escrow was last synced to with `SyncIncidentMode`, which is
+    /// the direction funds can flow through the escrow in during an incident
+    pub incident_mode: IncidentMode,
+    /// Whether the last reconciliation found the escrow's token account holding fewer tokens
+    /// than the escrow tracks, see `ReconcileEscrow`
+    pub needs_attention: bool,
 }
 
 impl Escrow {
+    /// Offset of the amount in a packed escrow
+    pub const AMOUNT_OFFSET: usize = ACCOUNT_TYPE_OFFSET + 1;
+    /// Offset of the owner in a packed escrow
+    pub const OWNER_OFFSET: usize = Self::AMOUNT_OFFSET + 8;
+    /// Offset of the token mint in a packed escrow
+    pub const TOKEN_MINT_OFFSET: usize = Self::OWNER_OFFSET + PUBKEY_BYTES;
+    /// Offset of the challenge window in a packed escrow
+    pub const CHALLENGE_WINDOW_OFFSET: usize = Self::TOKEN_MINT_OFFSET + PUBKEY_BYTES;
+    /// Offset of the proof verifier in a packed escrow
+    pub const PROOF_VERIFIER_OFFSET: usize = Self::CHALLENGE_WINDOW_OFFSET + 8;
+    /// Offset of the arbitrator in a packed escrow
+    pub const ARBITRATOR_OFFSET: usize = Self::PROOF_VERIFIER_OFFSET + PUBKEY_BYTES;
+    /// Offset of the approver in a packed escrow
+    pub const APPROVER_OFFSET: usize = Self::ARBITRATOR_OFFSET + PUBKEY_BYTES;
+    /// Offset of the number of owner rotations in a packed escrow
+    pub const OWNER_HISTORY_LEN_OFFSET: usize = Self::APPROVER_OFFSET + PUBKEY_BYTES;
+    /// Offset of the owner rotations in a packed escrow
+    pub const OWNER_HISTORY_OFFSET: usize = Self::OWNER_HISTORY_LEN_OFFSET + 1;
+    /// Offset of the token account in a packed escrow
+    pub const TOKEN_ACCOUNT_OFFSET: usize = Self::OWNER_HISTORY_OFFSET + OWNER_HISTORY_LEN;
+    /// Offset of the original token mint in a packed escrow
+    pub const ORIGINAL_TOKEN_MINT_OFFSET: usize = Self::TOKEN_ACCOUNT_OFFSET + PUBKEY_BYTES;
+    /// Offset of the number of mint migrations in a packed escrow
+    pub const MINT_MIGRATIONS_OFFSET: usize = Self::ORIGINAL_TOKEN_MINT_OFFSET + PUBKEY_BYTES;
+    /// Offset of the escrow id in a packed escrow
+    pub const ESCROW_ID_OFFSET: usize = Self::MINT_MIGRATIONS_OFFSET + 1;
+    /// Offset of the price feed in a packed escrow
+    pub const PRICE_FEED_OFFSET: usize = Self::ESCROW_ID_OFFSET + 8;
+    /// Offset of the incident mode in a packed escrow
+    pub const INCIDENT_MODE_OFFSET: usize = Self::PRICE_FEED_OFFSET + PUBKEY_BYTES;
+    /// Offset of the attention flag in a packed escrow
+    pub const NEEDS_ATTENTION_OFFSET: usize = Self::INCIDENT_MODE_OFFSET + 1;
+
+    /// Length of escrows created before fields were appended to the layout
+    pub const LEGACY_LEN: usize = Self::TOKEN_MINT_OFFSET;
+    /// Length of escrows created before the challenge window was appended to the layout
+    pub const PRE_CHALLENGE_WINDOW_LEN: usize = Self::CHALLENGE_WINDOW_OFFSET;
+    /// Length of escrows created before the owner history was appended to the layout
+    pub const PRE_OWNER_HISTORY_LEN: usize = Self::OWNER_HISTORY_LEN_OFFSET;
+    /// Length of escrows created before the token account was appended to the layout
+    pub const PRE_TOKEN_ACCOUNT_LEN: usize = Self::TOKEN_ACCOUNT_OFFSET;
+    /// Length of escrows created before the mint migration fields were appended to the layout
+    pub const PRE_MINT_MIGRATION_LEN: usize = Self::ORIGINAL_TOKEN_MINT_OFFSET;
+    /// Length of escrows created before the escrow id was appended to the layout
+    pub const PRE_ESCROW_ID_LEN: usize = Self::ESCROW_ID_OFFSET;
+    /// Length of escrows created before the price feed was appended to the layout
+    pub const PRE_PRICE_FEED_LEN: usize = Self::PRICE_FEED_OFFSET;
+    /// Length of escrows created before the incident mode was appended to the layout
+    pub const PRE_INCIDENT_MODE_LEN: usize = Self::INCIDENT_MODE_OFFSET;
+    /// Length of escrows created before the attention flag was appended to the layout
+    pub const PRE_NEEDS_ATTENTION_LEN: usize = Self::NEEDS_ATTENTION_OFFSET;
+
     /// Create an escrow
     pub fn new(params: InitEscrowParams) -> Self {
         let mut escrow = Self::default();
@@ -34,13 +156,157 @@ impl Escrow {
         self.account_type = AccountType::EscrowV1;
         self.amount = 0;
         self.owner = params.owner;
+        self.token_mint = params.token_mint;
+        self.challenge_window = 0;
+        self.proof_verifier = Pubkey::default();
+        self.arbitrator = Pubkey::default();
+        self.approver = Pubkey::default();
+        self.owner_history = Vec::new();
+        self.token_account = Pubkey::default();
+        self.original_token_mint = Pubkey::default();
+        self.mint_migrations = 0;
+        self.escrow_id = params.escrow_id;
+        self.price_feed = Pubkey::default();
+        self.incident_mode = IncidentMode::Normal;
+        self.needs_attention = false;
+    }
+
+    /// Set the owner of the escrow, recording the previous owner in the owner history and dropping
+    /// the oldest rotation if it's full
+    pub fn set_owner(&mut self, owner: Pubkey, slot: Slot) {
+        if self.owner_history.len() == MAX_OWNER_HISTORY {
+            self.owner_history.remove(0);
+        }
+        self.owner_history.push(OwnerRotation {
+            previous_owner: self.owner,
+            slot,
+        });
+        self.owner = owner;
+    }
+
+    /// Proof verifier program of the escrow, if results must be verified
+    pub fn proof_verifier(&self) -> Option<&Pubkey> {
+        if self.proof_verifier == Pubkey::default() {
+            None
+        } else {
+            Some(&self.proof_verifier)
+        }
+    }
+
+    /// Arbitrator of the escrow, if disputes can be resolved
+    pub fn arbitrator(&self) -> Option<&Pubkey> {
+        if self.arbitrator == Pubkey::default() {
+            None
+        } else {
+            Some(&self.arbitrator)
+        }
+    }
+
+    /// Approver of the escrow, if disbursements must be proposed and approved
+    pub fn approver(&self) -> Option<&Pubkey> {
+        if self.approver == Pubkey::default() {
+            None
+        } else {
+            Some(&self.approver)
+        }
+    }
+
+    /// Price feed of the escrow, if Jobs can be funded at an oracle price
+    pub fn price_feed(&self) -> Option<&Pubkey> {
+        if self.price_feed == Pubkey::default() {
+            None
+        } else {
+            Some(&self.price_feed)
+        }
+    }
+
+    /// Token account the pooled tokens of the escrow are held in, which is its associated token
+    /// account unless another token account was set
+    pub fn token_account(&self, escrow_address: &Pubkey) -> Pubkey {
+        if self.token_account == Pubkey::default() {
+            get_associated_token_address(escrow_address, &self.token_mint)
+        } else {
+            self.token_account
+        }
+    }
+
+    /// Token mint that the escrow's address is derived from, which is its token mint unless it has
+    /// been migrated
+    pub fn address_token_mint(&self) -> &Pubkey {
+        if self.original_token_mint == Pubkey::default() {
+            &self.token_mint
+        } else {
+            &self.original_token_mint
+        }
+    }
+
+    /// Unpack an escrow from account data, which may have a legacy length
+    pub fn unpack_account(input: &[u8]) -> Result<Self, ProgramError> {
+        unpack_appended(
+            input,
+            &[
+                Self::LEGACY_LEN,
+                Self::PRE_CHALLENGE_WINDOW_LEN,
+                Self::PRE_OWNER_HISTORY_LEN,
+                Self::PRE_TOKEN_ACCOUNT_LEN,
+                Self::PRE_MINT_MIGRATION_LEN,
+                Self::PRE_ESCROW_ID_LEN,
+                Self::PRE_PRICE_FEED_LEN,
+                Self::PRE_INCIDENT_MODE_LEN,
+                Self::PRE_NEEDS_ATTENTION_LEN,
+            ],
+        )
+    }
+
+    /// Pack an escrow into account data, which may have a legacy length if none of the fields
+    /// appended after it are set. The token mint resolved for a legacy escrow isn't stored until
+    /// the escrow is reallocated.
+    pub fn pack_account(mut escrow: Self, output: &mut [u8]) -> Result<(), ProgramError> {
+        if output.len() == Self::LEGACY_LEN {
+            escrow.token_mint = Pubkey::default();
+        }
+        pack_appended(
+            escrow,
+            output,
+            &[
+                Self::LEGACY_LEN,
+                Self::PRE_CHALLENGE_WINDOW_LEN,
+                Self::PRE_OWNER_HISTORY_LEN,
+                Self::PRE_TOKEN_ACCOUNT_LEN,
+                Self::PRE_MINT_MIGRATION_LEN,
+                Self::PRE_ESCROW_ID_LEN,
+                Self::PRE_PRICE_FEED_LEN,
+                Self::PRE_INCIDENT_MODE_LEN,
+                Self::PRE_NEEDS_ATTENTION_LEN,
+            ],
+        )
     }
 }
 
+/// Owner rotation recorded in the owner history of an escrow
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OwnerRotation {
+    /// Owner authority that was replaced
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub previous_owner: Pubkey,
+    /// Slot the owner was replaced at
+    pub slot: Slot,
+}
+
+impl OwnerRotation {
+    /// Length of a packed owner rotation
+    pub const LEN: usize = PUBKEY_BYTES + 8;
+}
+
 /// Initialize a escrow
 pub struct InitEscrowParams {
     /// Owner authority that can disburse funds
     pub owner: Pubkey,
+    /// RNDR SPL Token mint of the tokens in escrow
+    pub token_mint: Pubkey,
+    /// Namespace of the escrow, or zero for the default escrow
+    pub escrow_id: u64,
 }
 
 impl Sealed for Escrow {}
@@ -51,36 +317,177 @@ impl IsInitialized for Escrow {
     }
 }
 
-const ESCROW_LEN: usize = 41; // 1 + 8 + 32
+const OWNER_HISTORY_LEN: usize = MAX_OWNER_HISTORY * OwnerRotation::LEN;
+const ESCROW_LEN: usize = 605; // 1 + 8 + 32 + 32 + 8 + 32 + 32 + 32 + 1 + 8 * (32 + 8) + 32 + 32 + 1 + 8 + 32 + 1 + 1
+const _: () = assert!(Escrow::NEEDS_ATTENTION_OFFSET + 1 == ESCROW_LEN);
 impl Pack for Escrow {
     const LEN: usize = ESCROW_LEN;
 
     fn pack_into_slice(&self, output: &mut [u8]) {
         let output = array_mut_ref![output, 0, ESCROW_LEN];
         #[allow(clippy::ptr_offset_with_cast)]
-        let (account_type, amount, owner) = mut_array_refs![output, 1, 8, PUBKEY_BYTES];
+        let (
+            account_type,
+            amount,
+            owner,
+            token_mint,
+            challenge_window,
+            proof_verifier,
+            arbitrator,
+            approver,
+            owner_history_len,
+            owner_history,
+            token_account,
+            original_token_mint,
+            mint_migrations,
+            escrow_id,
+            price_feed,
+            incident_mode,
+            needs_attention,
+        ) = mut_array_refs![
+            output,
+            1,
+            8,
+            PUBKEY_BYTES,
+            PUBKEY_BYTES,
+            8,
+            PUBKEY_BYTES,
+            PUBKEY_BYTES,
+            PUBKEY_BYTES,
+            1,
+            OWNER_HISTORY_LEN,
+            PUBKEY_BYTES,
+            PUBKEY_BYTES,
+            1,
+            8,
+            PUBKEY_BYTES,
+            1,
+            1
+        ];
 
         *account_type = u8::from(self.account_type).to_le_bytes();
         *amount = self.amount.to_le_bytes();
         owner.copy_from_slice(&self.owner.to_bytes());
+        token_mint.copy_from_slice(&self.token_mint.to_bytes());
+        *challenge_window = self.challenge_window.to_le_bytes();
+        proof_verifier.copy_from_slice(&self.proof_verifier.to_bytes());
+        arbitrator.copy_from_slice(&self.arbitrator.to_bytes());
+        approver.copy_from_slice(&self.approver.to_bytes());
+        *owner_history_len = (self.owner_history.len() as u8).to_le_bytes();
+        owner_history.fill(0);
+        for (output, rotation) in owner_history
+            .chunks_exact_mut(OwnerRotation::LEN)
+            .zip(&self.owner_history)
+        {
+            let output = array_mut_ref![output, 0, OwnerRotation::LEN];
+            let (previous_owner, slot) = mut_array_refs![output, PUBKEY_BYTES, 8];
+            previous_owner.copy_from_slice(rotation.previous_owner.as_ref());
+            *slot = rotation.slot.to_le_bytes();
+        }
+        token_account.copy_from_slice(&self.token_account.to_bytes());
+        original_token_mint.copy_from_slice(&self.original_token_mint.to_bytes());
+        *mint_migrations = self.mint_migrations.to_le_bytes();
+        *escrow_id = self.escrow_id.to_le_bytes();
+        price_feed.copy_from_slice(&self.price_feed.to_bytes());
+        *incident_mode = u8::from(self.incident_mode).to_le_bytes();
+        *needs_attention = u8::from(self.needs_attention).to_le_bytes();
     }
 
     fn unpack_from_slice(input: &[u8]) -> Result<Self, ProgramError> {
         let input = array_ref![input, 0, ESCROW_LEN];
         #[allow(clippy::ptr_offset_with_cast)]
-        let (account_type, amount, owner) = array_refs![input, 1, 8, PUBKEY_BYTES];
+        let (
+            account_type,
+            amount,
+            owner,
+            token_mint,
+            challenge_window,
+            proof_verifier,
+            arbitrator,
+            approver,
+            owner_history_len,
+            owner_history,
+            token_account,
+            original_token_mint,
+            mint_migrations,
+            escrow_id,
+            price_feed,
+            incident_mode,
+            needs_attention,
+        ) = array_refs![
+            input,
+            1,
+            8,
+            PUBKEY_BYTES,
+            PUBKEY_BYTES,
+            8,
+            PUBKEY_BYTES,
+            PUBKEY_BYTES,
+            PUBKEY_BYTES,
+            1,
+            OWNER_HISTORY_LEN,
+            PUBKEY_BYTES,
+            PUBKEY_BYTES,
+            1,
+            8,
+            PUBKEY_BYTES,
+            1,
+            1
+        ];
 
-        let account_type = AccountType::try_from(u8::from_le_bytes(*account_type))
-            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let account_type = AccountType::from(u8::from_le_bytes(*account_type));
         if account_type != AccountType::EscrowV1 {
             msg!("Escrow account type is invalid");
             return Err(ProgramError::InvalidAccountData);
         }
 
+        let owner_history_len = u8::from_le_bytes(*owner_history_len) as usize;
+        if owner_history_len > MAX_OWNER_HISTORY {
+            msg!("Escrow owner history length is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        let owner_history = owner_history
+            .chunks_exact(OwnerRotation::LEN)
+            .take(owner_history_len)
+            .map(|rotation| {
+                let rotation = array_ref![rotation, 0, OwnerRotation::LEN];
+                let (previous_owner, slot) = array_refs![rotation, PUBKEY_BYTES, 8];
+                OwnerRotation {
+                    previous_owner: Pubkey::new_from_array(*previous_owner),
+                    slot: Slot::from_le_bytes(*slot),
+                }
+            })
+            .collect();
+
         Ok(Self {
             account_type,
             amount: u64::from_le_bytes(*amount),
             owner: Pubkey::new_from_array(*owner),
+            token_mint: Pubkey::new_from_array(*token_mint),
+            challenge_window: u64::from_le_bytes(*challenge_window),
+            proof_verifier: Pubkey::new_from_array(*proof_verifier),
+            arbitrator: Pubkey::new_from_array(*arbitrator),
+            approver: Pubkey::new_from_array(*approver),
+            owner_history,
+            token_account: Pubkey::new_from_array(*token_account),
+            original_token_mint: Pubkey::new_from_array(*original_token_mint),
+            mint_migrations: u8::from_le_bytes(*mint_migrations),
+            escrow_id: u64::from_le_bytes(*escrow_id),
+            price_feed: Pubkey::new_from_array(*price_feed),
+            incident_mode: IncidentMode::try_from(u8::from_le_bytes(*incident_mode)).map_err(
+                |_| {
+                    msg!("Escrow incident mode is invalid");
+                    ProgramError::InvalidAccountData
+                },
+            )?,
+            needs_attention: match u8::from_le_bytes(*needs_attention) {
+                0 => false,
+                1 => true,
+                _ => {
+                    msg!("Escrow attention flag is invalid");
+                    return Err(ProgramError::InvalidAccountData);
+                }
+            },
         })
     }
 }
@@ -0,0 +1,115 @@
+use {
+    super::*,
+    arrayref::{array_mut_ref, array_ref, array_refs, mut_array_refs},
+    solana_program::{
+        msg,
+        program_error::ProgramError,
+        program_pack::{IsInitialized, Pack, Sealed},
+        pubkey::{Pubkey, PUBKEY_BYTES},
+    },
+};
+
+/// Number of shards that funding is spread across for each escrow
+pub const ESCROW_SHARD_COUNT: u8 = 8;
+
+/// Escrow shard state
+///
+/// Funding credits a shard selected by the job instead of the escrow itself, so that funding
+/// transactions for different jobs don't all write-lock the escrow state account. The tokens are
+/// still transferred to the escrow's token account, which every funding transaction of the escrow
+/// write-locks, so shards only take the escrow state out of their write set. Tokens credited to a
+/// shard are moved into the escrow amount by the permissionless `AggregateEscrowShard` crank.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EscrowShard {
+    /// Account type, must be EscrowShardV1 currently
+    pub account_type: AccountType,
+    /// Escrow the shard belongs to
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub escrow: Pubkey,
+    /// Index of the shard, less than `ESCROW_SHARD_COUNT`
+    pub index: u8,
+    /// Amount of tokens funded through the shard and not yet aggregated into the escrow
+    pub amount: u64,
+}
+
+impl EscrowShard {
+    /// Offset of the escrow in a packed escrow shard
+    pub const ESCROW_OFFSET: usize = ACCOUNT_TYPE_OFFSET + 1;
+    /// Offset of the index in a packed escrow shard
+    pub const INDEX_OFFSET: usize = Self::ESCROW_OFFSET + PUBKEY_BYTES;
+    /// Offset of the amount in a packed escrow shard
+    pub const AMOUNT_OFFSET: usize = Self::INDEX_OFFSET + 1;
+
+    /// Create an escrow shard
+    pub fn new(params: InitEscrowShardParams) -> Self {
+        let mut escrow_shard = Self::default();
+        Self::init(&mut escrow_shard, params);
+        escrow_shard
+    }
+
+    /// Initialize an escrow shard
+    pub fn init(&mut self, params: InitEscrowShardParams) {
+        self.account_type = AccountType::EscrowShardV1;
+        self.escrow = params.escrow;
+        self.index = params.index;
+        self.amount = 0;
+    }
+
+    /// Index of the shard that funding for a job is credited to
+    pub fn index_for_job(job: &Pubkey) -> u8 {
+        job.as_ref()[0] % ESCROW_SHARD_COUNT
+    }
+}
+
+/// Initialize an escrow shard
+pub struct InitEscrowShardParams {
+    /// Escrow the shard belongs to
+    pub escrow: Pubkey,
+    /// Index of the shard
+    pub index: u8,
+}
+
+impl Sealed for EscrowShard {}
+
+impl IsInitialized for EscrowShard {
+    fn is_initialized(&self) -> bool {
+        self.account_type != AccountType::Uninitialized
+    }
+}
+
+const ESCROW_SHARD_LEN: usize = 42; // 1 + 32 + 1 + 8
+const _: () = assert!(EscrowShard::AMOUNT_OFFSET + 8 == ESCROW_SHARD_LEN);
+impl Pack for EscrowShard {
+    const LEN: usize = ESCROW_SHARD_LEN;
+
+    fn pack_into_slice(&self, output: &mut [u8]) {
+        let output = array_mut_ref![output, 0, ESCROW_SHARD_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (account_type, escrow, index, amount) = mut_array_refs![output, 1, PUBKEY_BYTES, 1, 8];
+
+        *account_type = u8::from(self.account_type).to_le_bytes();
+        escrow.copy_from_slice(&self.escrow.to_bytes());
+        *index = self.index.to_le_bytes();
+        *amount = self.amount.to_le_bytes();
+    }
+
+    fn unpack_from_slice(input: &[u8]) -> Result<Self, ProgramError> {
+        let input = array_ref![input, 0, ESCROW_SHARD_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (account_type, escrow, index, amount) = array_refs![input, 1, PUBKEY_BYTES, 1, 8];
+
+        let account_type = AccountType::from(u8::from_le_bytes(*account_type));
+        if account_type != AccountType::EscrowShardV1 {
+            msg!("Escrow shard account type is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self {
+            account_type,
+            escrow: Pubkey::new_from_array(*escrow),
+            index: u8::from_le_bytes(*index),
+            amount: u64::from_le_bytes(*amount),
+        })
+    }
+}
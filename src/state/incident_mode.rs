@@ -0,0 +1,35 @@
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+
+/// Enum representing which direction funds can flow through escrows during an incident, so the
+/// governance authority of the config can stop one direction without pausing the other
+#[derive(Clone, Copy, Debug, Eq, PartialEq, IntoPrimitive, TryFromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u8)]
+pub enum IncidentMode {
+    /// Jobs can be funded and their funds disbursed or refunded, the value will be 0
+    Normal,
+    /// Jobs can be funded, but their funds can't be disbursed or refunded
+    FundOnly,
+    /// Funds of existing jobs can be disbursed or refunded, but no jobs can be funded, so
+    /// existing obligations settle without new money coming in
+    DisburseOnly,
+}
+
+impl IncidentMode {
+    /// Whether jobs can be funded
+    pub fn allows_funding(self) -> bool {
+        self != Self::DisburseOnly
+    }
+
+    /// Whether funds of jobs can be disbursed or refunded
+    pub fn allows_disbursing(self) -> bool {
+        self != Self::FundOnly
+    }
+}
+
+#[allow(clippy::derivable_impls)]
+impl Default for IncidentMode {
+    fn default() -> Self {
+        IncidentMode::Normal
+    }
+}
@@ -1,7 +1,10 @@
 use {
     super::*,
+    crate::math::ExchangeRate,
     arrayref::{array_mut_ref, array_ref, array_refs, mut_array_refs},
     solana_program::{
+        clock::{Slot, UnixTimestamp},
+        hash::{hashv, HASH_BYTES},
         msg,
         program_error::ProgramError,
         program_pack::{IsInitialized, Pack, Sealed},
@@ -10,18 +13,188 @@ use {
     std::convert::TryFrom,
 };
 
+/// Maximum length in bytes of the URI of a job's metadata
+pub const MAX_JOB_METADATA_URI_LEN: usize = 96;
+/// Highest priority level of a job, whose claim isn't delayed, see `Config::claimable_at`
+pub const MAX_JOB_PRIORITY: u8 = 3;
+
+/// Metadata of a job, pointing to its full off-chain manifest, with the tags nodes discover it by
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct JobMetadata {
+    /// URI of the job's manifest, such as an `ar://` or `ipfs://` URI, or empty if there is none
+    pub uri: String,
+    /// Hash of the content of the manifest, so it can be verified against the URI's content
+    pub hash: [u8; HASH_BYTES],
+    /// Bitflags of the capabilities the job requires, see `Job::tags`
+    pub tags: u64,
+}
+
+impl JobMetadata {
+    /// Whether the URI fits in a job
+    pub fn is_valid(&self) -> bool {
+        self.uri.len() <= MAX_JOB_METADATA_URI_LEN
+    }
+}
+
 /// Job state
+///
+/// Fields were appended to the original layout, which is `Job::LEGACY_LEN` bytes long, first the
+/// isolated flag, then the identifier, from `Job::PRE_ID_LEN`, then the status and result fields,
+/// from `Job::PRE_STATUS_LEN`,
+/// then the funding timestamp, from `Job::PRE_FUNDED_AT_LEN`, then the authority transfer fields, from
+/// `Job::PRE_AUTHORITY_TRANSFER_LEN`, then the metadata fields, from `Job::PRE_METADATA_LEN`, then
+/// the tags, from `Job::PRE_TAGS_LEN`, then the number of mint migrations, from
+/// `Job::PRE_MINT_MIGRATIONS_LEN`, then the priority, from `Job::PRE_PRIORITY_LEN`, then the
+/// exchange rate fields, from `Job::PRE_EXCHANGE_RATE_LEN`, and then the frozen flag, from
+/// `Job::PRE_FROZEN_LEN`. Jobs created before then are unpacked with the appended fields set to
+/// their zero defaults, and are reallocated when one of those fields has to be written.
+///
+/// Since fields are only appended, the authority, status, node and tags stay at the fixed offsets
+/// of `Job::AUTHORITY_OFFSET`, `Job::STATUS_OFFSET`, `Job::NODE_OFFSET` and `Job::TAGS_OFFSET`,
+/// which RPC queries can filter jobs on with `memcmp`.
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Job {
     /// Account type, must be JobV1 currently
     pub account_type: AccountType,
     /// Amount of tokens in escrow for the job
     pub amount: u64,
-    /// User authority that initialized the job
+    /// User authority that manages the job, which initialized it unless it has been transferred
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
     pub authority: Pubkey,
+    /// Whether the job's tokens are held in its own token account instead of the escrow's
+    pub isolated: bool,
+    /// Identifier of the job, unique for the authority, which is zero for jobs created before jobs
+    /// had identifiers
+    pub id: u64,
+    /// Status of the job's result
+    pub status: JobStatus,
+    /// Node that committed to a result, or the default pubkey if the job is open
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub node: Pubkey,
+    /// Commitment of the node to the result, see `Job::result_commitment`
+    pub result_commitment: [u8; HASH_BYTES],
+    /// Hash of the result revealed by the node
+    pub result_hash: [u8; HASH_BYTES],
+    /// Unix timestamp of when the result was revealed, which starts the escrow's challenge window
+    pub revealed_at: UnixTimestamp,
+    /// Verifier that flagged the job, or the default pubkey if it hasn't been flagged
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub verifier: Pubkey,
+    /// Hash of the evidence the verifier flagged the job with
+    pub evidence_hash: [u8; HASH_BYTES],
+    /// Unix timestamp of when the job was first funded, which starts the config's cancellation
+    /// grace period, or zero if it was funded before the timestamp was recorded
+    pub funded_at: UnixTimestamp,
+    /// Authority the job is being transferred to, which must accept the transfer, or the default
+    /// pubkey if there is none
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub pending_authority: Pubkey,
+    /// Authority that initialized the job and that its address is derived from, if the job has
+    /// been transferred, or the default pubkey otherwise
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub original_authority: Pubkey,
+    /// URI of the job's off-chain manifest, at most `MAX_JOB_METADATA_URI_LEN` bytes, or empty if
+    /// it has none
+    pub metadata_uri: String,
+    /// Hash of the content of the job's manifest
+    pub metadata_hash: [u8; HASH_BYTES],
+    /// Bitflags of the capabilities the job requires from a node, such as a GPU class, scene type
+    /// or priority class, whose meaning is agreed on by clients and nodes
+    pub tags: u64,
+    /// Number of mint migrations of the escrow the job's amount is denominated after, which must
+    /// be the escrow's number of mint migrations for the job to be funded or paid
+    pub mint_migrations: u8,
+    /// Priority level of the job, at most `MAX_JOB_PRIORITY`, which the job's authority raises
+    /// with `RaiseJobPriority` so nodes can commit to it sooner
+    pub priority: u8,
+    /// RNDR base units that `exchange_rate_denominator` base units of the job's token are worth,
+    /// the rate the job is settled at if it's funded in another token, or zero if no rate has been
+    /// recorded, see `Job::exchange_rate`
+    pub exchange_rate_numerator: u64,
+    /// Base units of the job's token worth `exchange_rate_numerator` RNDR base units, or zero if no
+    /// rate has been recorded
+    pub exchange_rate_denominator: u64,
+    /// Slot of the oracle price the exchange rate was recorded from
+    pub exchange_rate_slot: Slot,
+    /// Whether the job is frozen by the config's compliance authority under a legal hold, which
+    /// blocks disbursing and refunding its tokens until it's unfrozen
+    pub frozen: bool,
 }
 
 impl Job {
+    /// Offset of the amount in a packed job
+    pub const AMOUNT_OFFSET: usize = ACCOUNT_TYPE_OFFSET + 1;
+    /// Offset of the authority in a packed job
+    pub const AUTHORITY_OFFSET: usize = Self::AMOUNT_OFFSET + 8;
+    /// Offset of the isolated flag in a packed job
+    pub const ISOLATED_OFFSET: usize = Self::AUTHORITY_OFFSET + PUBKEY_BYTES;
+    /// Offset of the identifier in a packed job
+    pub const ID_OFFSET: usize = Self::ISOLATED_OFFSET + 1;
+    /// Offset of the status in a packed job
+    pub const STATUS_OFFSET: usize = Self::ID_OFFSET + 8;
+    /// Offset of the node in a packed job
+    pub const NODE_OFFSET: usize = Self::STATUS_OFFSET + 1;
+    /// Offset of the result commitment in a packed job
+    pub const RESULT_COMMITMENT_OFFSET: usize = Self::NODE_OFFSET + PUBKEY_BYTES;
+    /// Offset of the result hash in a packed job
+    pub const RESULT_HASH_OFFSET: usize = Self::RESULT_COMMITMENT_OFFSET + HASH_BYTES;
+    /// Offset of the reveal timestamp in a packed job
+    pub const REVEALED_AT_OFFSET: usize = Self::RESULT_HASH_OFFSET + HASH_BYTES;
+    /// Offset of the verifier in a packed job
+    pub const VERIFIER_OFFSET: usize = Self::REVEALED_AT_OFFSET + 8;
+    /// Offset of the evidence hash in a packed job
+    pub const EVIDENCE_HASH_OFFSET: usize = Self::VERIFIER_OFFSET + PUBKEY_BYTES;
+    /// Offset of the funding timestamp in a packed job
+    pub const FUNDED_AT_OFFSET: usize = Self::EVIDENCE_HASH_OFFSET + HASH_BYTES;
+    /// Offset of the pending authority in a packed job
+    pub const PENDING_AUTHORITY_OFFSET: usize = Self::FUNDED_AT_OFFSET + 8;
+    /// Offset of the original authority in a packed job
+    pub const ORIGINAL_AUTHORITY_OFFSET: usize = Self::PENDING_AUTHORITY_OFFSET + PUBKEY_BYTES;
+    /// Offset of the metadata URI length in a packed job, followed by the URI's bytes
+    pub const METADATA_URI_OFFSET: usize = Self::ORIGINAL_AUTHORITY_OFFSET + PUBKEY_BYTES;
+    /// Offset of the metadata hash in a packed job
+    pub const METADATA_HASH_OFFSET: usize =
+        Self::METADATA_URI_OFFSET + 1 + MAX_JOB_METADATA_URI_LEN;
+    /// Offset of the tags in a packed job
+    pub const TAGS_OFFSET: usize = Self::METADATA_HASH_OFFSET + HASH_BYTES;
+    /// Offset of the number of mint migrations in a packed job
+    pub const MINT_MIGRATIONS_OFFSET: usize = Self::TAGS_OFFSET + 8;
+    /// Offset of the priority in a packed job
+    pub const PRIORITY_OFFSET: usize = Self::MINT_MIGRATIONS_OFFSET + 1;
+    /// Offset of the exchange rate numerator in a packed job
+    pub const EXCHANGE_RATE_NUMERATOR_OFFSET: usize = Self::PRIORITY_OFFSET + 1;
+    /// Offset of the exchange rate denominator in a packed job
+    pub const EXCHANGE_RATE_DENOMINATOR_OFFSET: usize = Self::EXCHANGE_RATE_NUMERATOR_OFFSET + 8;
+    /// Offset of the exchange rate slot in a packed job
+    pub const EXCHANGE_RATE_SLOT_OFFSET: usize = Self::EXCHANGE_RATE_DENOMINATOR_OFFSET + 8;
+    /// Offset of the frozen flag in a packed job
+    pub const FROZEN_OFFSET: usize = Self::EXCHANGE_RATE_SLOT_OFFSET + 8;
+
+    /// Length of jobs created before fields were appended to the layout
+    pub const LEGACY_LEN: usize = Self::ISOLATED_OFFSET;
+    /// Length of jobs created before the identifier was appended to the layout
+    pub const PRE_ID_LEN: usize = Self::ID_OFFSET;
+    /// Length of jobs created before the status and result fields were appended to the layout
+    pub const PRE_STATUS_LEN: usize = Self::STATUS_OFFSET;
+    /// Length of jobs created before the funding timestamp was appended to the layout
+    pub const PRE_FUNDED_AT_LEN: usize = Self::FUNDED_AT_OFFSET;
+    /// Length of jobs created before the authority transfer fields were appended to the layout
+    pub const PRE_AUTHORITY_TRANSFER_LEN: usize = Self::PENDING_AUTHORITY_OFFSET;
+    /// Length of jobs created before the metadata fields were appended to the layout
+    pub const PRE_METADATA_LEN: usize = Self::METADATA_URI_OFFSET;
+    /// Length of jobs created before the tags were appended to the layout
+    pub const PRE_TAGS_LEN: usize = Self::TAGS_OFFSET;
+    /// Length of jobs created before the number of mint migrations was appended to the layout
+    pub const PRE_MINT_MIGRATIONS_LEN: usize = Self::MINT_MIGRATIONS_OFFSET;
+    /// Length of jobs created before the priority was appended to the layout
+    pub const PRE_PRIORITY_LEN: usize = Self::PRIORITY_OFFSET;
+    /// Length of jobs created before the exchange rate fields were appended to the layout
+    pub const PRE_EXCHANGE_RATE_LEN: usize = Self::EXCHANGE_RATE_NUMERATOR_OFFSET;
+    /// Length of jobs created before the frozen flag was appended to the layout
+    pub const PRE_FROZEN_LEN: usize = Self::FROZEN_OFFSET;
+
     /// Create a job
     pub fn new(params: InitJobParams) -> Self {
         let mut job = Self::default();
@@ -34,6 +207,133 @@ impl Job {
         self.account_type = AccountType::JobV1;
         self.amount = 0;
         self.authority = params.authority;
+        self.isolated = params.isolated;
+        self.id = params.id;
+        self.status = JobStatus::Open;
+        self.node = Pubkey::default();
+        self.result_commitment = [0; HASH_BYTES];
+        self.result_hash = [0; HASH_BYTES];
+        self.revealed_at = 0;
+        self.verifier = Pubkey::default();
+        self.evidence_hash = [0; HASH_BYTES];
+        self.funded_at = 0;
+        self.pending_authority = Pubkey::default();
+        self.original_authority = Pubkey::default();
+        self.metadata_uri = String::new();
+        self.metadata_hash = [0; HASH_BYTES];
+        self.tags = 0;
+        self.mint_migrations = 0;
+        self.priority = 0;
+        self.exchange_rate_numerator = 0;
+        self.exchange_rate_denominator = 0;
+        self.exchange_rate_slot = 0;
+        self.frozen = false;
+    }
+
+    /// Set the metadata and tags of the job
+    pub fn set_metadata(&mut self, metadata: JobMetadata) {
+        self.metadata_uri = metadata.uri;
+        self.metadata_hash = metadata.hash;
+        self.tags = metadata.tags;
+    }
+
+    /// Whether a node with the capabilities of a set of tags can run the job, which it can if it
+    /// has every tag of the job
+    pub fn is_suitable_for(&self, capabilities: u64) -> bool {
+        self.tags & !capabilities == 0
+    }
+
+    /// Exchange rate the job's amounts are converted to RNDR at, or `None` if no rate has been
+    /// recorded, such as for jobs funded in RNDR
+    pub fn exchange_rate(&self) -> Option<ExchangeRate> {
+        ExchangeRate::new(self.exchange_rate_numerator, self.exchange_rate_denominator)
+    }
+
+    /// Record the exchange rate the job's amounts are converted to RNDR at, from the slot of the
+    /// oracle price it was derived from
+    pub fn set_exchange_rate(&mut self, exchange_rate: ExchangeRate, slot: Slot) {
+        self.exchange_rate_numerator = exchange_rate.numerator();
+        self.exchange_rate_denominator = exchange_rate.denominator();
+        self.exchange_rate_slot = slot;
+    }
+
+    /// Value in RNDR of an amount of the job's tokens at its recorded exchange rate rounded down,
+    /// or the amount itself if no rate has been recorded
+    pub fn try_rndr_value(&self, amount: u64) -> Result<u64, ProgramError> {
+        match self.exchange_rate() {
+            Some(exchange_rate) => exchange_rate.try_to_rndr(amount),
+            None => Ok(amount),
+        }
+    }
+
+    /// Authority the job's address is derived from, which is the authority that initialized it
+    pub fn address_authority(&self) -> &Pubkey {
+        if self.original_authority == Pubkey::default() {
+            &self.authority
+        } else {
+            &self.original_authority
+        }
+    }
+
+    /// Complete the transfer of the job to its pending authority
+    pub fn accept_authority(&mut self) {
+        if self.original_authority == Pubkey::default() {
+            self.original_authority = self.authority;
+        }
+        self.authority = std::mem::take(&mut self.pending_authority);
+    }
+
+    /// Commitment to a result for a node that is binding to the job, so a commitment copied to
+    /// another job can't be revealed there
+    pub fn result_commitment(
+        job: &Pubkey,
+        node: &Pubkey,
+        result_hash: &[u8; HASH_BYTES],
+        salt: &[u8; HASH_BYTES],
+    ) -> [u8; HASH_BYTES] {
+        hashv(&[job.as_ref(), node.as_ref(), result_hash, salt]).to_bytes()
+    }
+
+    /// Unpack a job from account data, which may have the legacy length
+    pub fn unpack_account(input: &[u8]) -> Result<Self, ProgramError> {
+        unpack_appended(
+            input,
+            &[
+                Self::LEGACY_LEN,
+                Self::PRE_ID_LEN,
+                Self::PRE_STATUS_LEN,
+                Self::PRE_FUNDED_AT_LEN,
+                Self::PRE_AUTHORITY_TRANSFER_LEN,
+                Self::PRE_METADATA_LEN,
+                Self::PRE_TAGS_LEN,
+                Self::PRE_MINT_MIGRATIONS_LEN,
+                Self::PRE_PRIORITY_LEN,
+                Self::PRE_EXCHANGE_RATE_LEN,
+                Self::PRE_FROZEN_LEN,
+            ],
+        )
+    }
+
+    /// Pack a job into account data, which may have the legacy length if none of the appended
+    /// fields are set
+    pub fn pack_account(job: Self, output: &mut [u8]) -> Result<(), ProgramError> {
+        pack_appended(
+            job,
+            output,
+            &[
+                Self::LEGACY_LEN,
+                Self::PRE_ID_LEN,
+                Self::PRE_STATUS_LEN,
+                Self::PRE_FUNDED_AT_LEN,
+                Self::PRE_AUTHORITY_TRANSFER_LEN,
+                Self::PRE_METADATA_LEN,
+                Self::PRE_TAGS_LEN,
+                Self::PRE_MINT_MIGRATIONS_LEN,
+                Self::PRE_PRIORITY_LEN,
+                Self::PRE_EXCHANGE_RATE_LEN,
+                Self::PRE_FROZEN_LEN,
+            ],
+        )
     }
 }
 
@@ -41,6 +341,10 @@ impl Job {
 pub struct InitJobParams {
     /// User authority that initialized the job
     pub authority: Pubkey,
+    /// Identifier of the job, unique for the authority
+    pub id: u64,
+    /// Whether the job's tokens are held in its own token account
+    pub isolated: bool,
 }
 
 impl Sealed for Job {}
@@ -51,36 +355,223 @@ impl IsInitialized for Job {
     }
 }
 
-const JOB_LEN: usize = 41; // 1 + 8 + 32
+const JOB_LEN: usize = 455; // 1 + 8 + 32 + 1 + 8 + 1 + 32 + 32 + 32 + 8 + 32 + 32 + 8 + 32 + 32 + 1 + 96 + 32 + 8 + 1 + 1 + 8 + 8 + 8 + 1
+const _: () = assert!(Job::FROZEN_OFFSET + 1 == JOB_LEN);
 impl Pack for Job {
     const LEN: usize = JOB_LEN;
 
     fn pack_into_slice(&self, output: &mut [u8]) {
         let output = array_mut_ref![output, 0, JOB_LEN];
         #[allow(clippy::ptr_offset_with_cast)]
-        let (account_type, amount, authority) = mut_array_refs![output, 1, 8, PUBKEY_BYTES];
+        let (
+            account_type,
+            amount,
+            authority,
+            isolated,
+            id,
+            status,
+            node,
+            result_commitment,
+            result_hash,
+            revealed_at,
+            verifier,
+            evidence_hash,
+            funded_at,
+            pending_authority,
+            original_authority,
+            metadata_uri_len,
+            metadata_uri,
+            metadata_hash,
+            tags,
+            mint_migrations,
+            priority,
+            exchange_rate_numerator,
+            exchange_rate_denominator,
+            exchange_rate_slot,
+            frozen,
+        ) = mut_array_refs![
+            output,
+            1,
+            8,
+            PUBKEY_BYTES,
+            1,
+            8,
+            1,
+            PUBKEY_BYTES,
+            HASH_BYTES,
+            HASH_BYTES,
+            8,
+            PUBKEY_BYTES,
+            HASH_BYTES,
+            8,
+            PUBKEY_BYTES,
+            PUBKEY_BYTES,
+            1,
+            MAX_JOB_METADATA_URI_LEN,
+            HASH_BYTES,
+            8,
+            1,
+            1,
+            8,
+            8,
+            8,
+            1
+        ];
 
         *account_type = u8::from(self.account_type).to_le_bytes();
         *amount = self.amount.to_le_bytes();
         authority.copy_from_slice(&self.authority.to_bytes());
+        *isolated = u8::from(self.isolated).to_le_bytes();
+        *id = self.id.to_le_bytes();
+        *status = u8::from(self.status).to_le_bytes();
+        node.copy_from_slice(&self.node.to_bytes());
+        *result_commitment = self.result_commitment;
+        *result_hash = self.result_hash;
+        *revealed_at = self.revealed_at.to_le_bytes();
+        verifier.copy_from_slice(&self.verifier.to_bytes());
+        *evidence_hash = self.evidence_hash;
+        *funded_at = self.funded_at.to_le_bytes();
+        pending_authority.copy_from_slice(&self.pending_authority.to_bytes());
+        original_authority.copy_from_slice(&self.original_authority.to_bytes());
+        let uri = self.metadata_uri.as_bytes();
+        *metadata_uri_len = (uri.len() as u8).to_le_bytes();
+        let (metadata_uri, padding) = metadata_uri.split_at_mut(uri.len());
+        metadata_uri.copy_from_slice(uri);
+        padding.fill(0);
+        *metadata_hash = self.metadata_hash;
+        *tags = self.tags.to_le_bytes();
+        *mint_migrations = self.mint_migrations.to_le_bytes();
+        *priority = self.priority.to_le_bytes();
+        *exchange_rate_numerator = self.exchange_rate_numerator.to_le_bytes();
+        *exchange_rate_denominator = self.exchange_rate_denominator.to_le_bytes();
+        *exchange_rate_slot = self.exchange_rate_slot.to_le_bytes();
+        *frozen = u8::from(self.frozen).to_le_bytes();
     }
 
     fn unpack_from_slice(input: &[u8]) -> Result<Self, ProgramError> {
         let input = array_ref![input, 0, JOB_LEN];
         #[allow(clippy::ptr_offset_with_cast)]
-        let (account_type, amount, authority) = array_refs![input, 1, 8, PUBKEY_BYTES];
+        let (
+            account_type,
+            amount,
+            authority,
+            isolated,
+            id,
+            status,
+            node,
+            result_commitment,
+            result_hash,
+            revealed_at,
+            verifier,
+            evidence_hash,
+            funded_at,
+            pending_authority,
+            original_authority,
+            metadata_uri_len,
+            metadata_uri,
+            metadata_hash,
+            tags,
+            mint_migrations,
+            priority,
+            exchange_rate_numerator,
+            exchange_rate_denominator,
+            exchange_rate_slot,
+            frozen,
+        ) = array_refs![
+            input,
+            1,
+            8,
+            PUBKEY_BYTES,
+            1,
+            8,
+            1,
+            PUBKEY_BYTES,
+            HASH_BYTES,
+            HASH_BYTES,
+            8,
+            PUBKEY_BYTES,
+            HASH_BYTES,
+            8,
+            PUBKEY_BYTES,
+            PUBKEY_BYTES,
+            1,
+            MAX_JOB_METADATA_URI_LEN,
+            HASH_BYTES,
+            8,
+            1,
+            1,
+            8,
+            8,
+            8,
+            1
+        ];
 
-        let account_type = AccountType::try_from(u8::from_le_bytes(*account_type))
-            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let account_type = AccountType::from(u8::from_le_bytes(*account_type));
         if account_type != AccountType::JobV1 {
             msg!("Job account type is invalid");
             return Err(ProgramError::InvalidAccountData);
         }
 
+        let metadata_uri_len = u8::from_le_bytes(*metadata_uri_len) as usize;
+        if metadata_uri_len > MAX_JOB_METADATA_URI_LEN {
+            msg!("Job metadata URI length is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        let metadata_uri =
+            String::from_utf8(metadata_uri[..metadata_uri_len].to_vec()).map_err(|_| {
+                msg!("Job metadata URI is invalid");
+                ProgramError::InvalidAccountData
+            })?;
+
+        let exchange_rate_numerator = u64::from_le_bytes(*exchange_rate_numerator);
+        let exchange_rate_denominator = u64::from_le_bytes(*exchange_rate_denominator);
+        if (exchange_rate_numerator == 0) != (exchange_rate_denominator == 0) {
+            msg!("Job exchange rate is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
         Ok(Self {
             account_type,
             amount: u64::from_le_bytes(*amount),
             authority: Pubkey::new_from_array(*authority),
+            isolated: match u8::from_le_bytes(*isolated) {
+                0 => false,
+                1 => true,
+                _ => {
+                    msg!("Job isolated flag is invalid");
+                    return Err(ProgramError::InvalidAccountData);
+                }
+            },
+            id: u64::from_le_bytes(*id),
+            status: JobStatus::try_from(u8::from_le_bytes(*status)).map_err(|_| {
+                msg!("Job status is invalid");
+                ProgramError::InvalidAccountData
+            })?,
+            node: Pubkey::new_from_array(*node),
+            result_commitment: *result_commitment,
+            result_hash: *result_hash,
+            revealed_at: i64::from_le_bytes(*revealed_at),
+            verifier: Pubkey::new_from_array(*verifier),
+            evidence_hash: *evidence_hash,
+            funded_at: i64::from_le_bytes(*funded_at),
+            pending_authority: Pubkey::new_from_array(*pending_authority),
+            original_authority: Pubkey::new_from_array(*original_authority),
+            metadata_uri,
+            metadata_hash: *metadata_hash,
+            tags: u64::from_le_bytes(*tags),
+            mint_migrations: u8::from_le_bytes(*mint_migrations),
+            priority: u8::from_le_bytes(*priority),
+            exchange_rate_numerator,
+            exchange_rate_denominator,
+            exchange_rate_slot: Slot::from_le_bytes(*exchange_rate_slot),
+            frozen: match u8::from_le_bytes(*frozen) {
+                0 => false,
+                1 => true,
+                _ => {
+                    msg!("Job frozen flag is invalid");
+                    return Err(ProgramError::InvalidAccountData);
+                }
+            },
         })
     }
 }
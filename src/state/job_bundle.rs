@@ -0,0 +1,238 @@
+use {
+    super::*,
+    arrayref::{array_mut_ref, array_ref, array_refs, mut_array_refs},
+    solana_program::{
+        hash::HASH_BYTES,
+        msg,
+        program_error::ProgramError,
+        program_pack::{IsInitialized, Pack, Sealed},
+        pubkey::{Pubkey, PUBKEY_BYTES},
+    },
+};
+
+/// Maximum number of jobs in a job bundle
+pub const MAX_BUNDLE_JOBS: u8 = 64;
+
+/// Job bundle state
+///
+/// Group of jobs of an authority with consecutive identifiers, such as the frame ranges of a
+/// production, that share metadata and are funded, cancelled and tracked to completion together.
+/// The outcome of each job is recorded in a bitmap by its index in the bundle.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct JobBundle {
+    /// Account type, must be JobBundleV1 currently
+    pub account_type: AccountType,
+    /// Escrow the jobs are funded in
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub escrow: Pubkey,
+    /// Job authority that created the bundle and that the jobs' addresses are derived from
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub authority: Pubkey,
+    /// Identifier of the bundle, unique for the authority
+    pub id: u64,
+    /// Hash of the metadata the jobs share, such as the scene and render settings
+    pub metadata_hash: [u8; HASH_BYTES],
+    /// Identifier of the first job of the bundle
+    pub first_job_id: u64,
+    /// Number of jobs of the bundle, at most `MAX_BUNDLE_JOBS`
+    pub job_count: u8,
+    /// Total amount of tokens funded through the bundle
+    pub amount: u64,
+    /// Bitmap of the jobs that have been paid or resolved, by their index in the bundle
+    pub completed: u64,
+    /// Bitmap of the jobs that have been cancelled, by their index in the bundle
+    pub cancelled: u64,
+    /// Whether the bundle has been cancelled, so its jobs can't be funded through it anymore
+    pub is_cancelled: bool,
+}
+
+impl JobBundle {
+    /// Offset of the escrow in a packed job bundle
+    pub const ESCROW_OFFSET: usize = ACCOUNT_TYPE_OFFSET + 1;
+    /// Offset of the authority in a packed job bundle
+    pub const AUTHORITY_OFFSET: usize = Self::ESCROW_OFFSET + PUBKEY_BYTES;
+    /// Offset of the completed bitmap in a packed job bundle
+    pub const COMPLETED_OFFSET: usize =
+        Self::AUTHORITY_OFFSET + PUBKEY_BYTES + 8 + HASH_BYTES + 8 + 1 + 8;
+
+    /// Create a job bundle
+    pub fn new(params: InitJobBundleParams) -> Self {
+        let mut job_bundle = Self::default();
+        Self::init(&mut job_bundle, params);
+        job_bundle
+    }
+
+    /// Initialize a job bundle
+    pub fn init(&mut self, params: InitJobBundleParams) {
+        self.account_type = AccountType::JobBundleV1;
+        self.escrow = params.escrow;
+        self.authority = params.authority;
+        self.id = params.id;
+        self.metadata_hash = params.metadata_hash;
+        self.first_job_id = params.first_job_id;
+        self.job_count = params.job_count;
+        self.amount = 0;
+        self.completed = 0;
+        self.cancelled = 0;
+        self.is_cancelled = false;
+    }
+
+    /// Index of a job in the bundle, or `None` if the job isn't part of it
+    pub fn job_index(&self, job_id: u64) -> Option<u8> {
+        let index = job_id.checked_sub(self.first_job_id)?;
+        if index < self.job_count as u64 {
+            Some(index as u8)
+        } else {
+            None
+        }
+    }
+
+    /// Record the outcome of the job at an index from its status, returning whether the job has
+    /// reached an outcome
+    pub fn record(&mut self, index: u8, status: JobStatus) -> bool {
+        let bit = 1 << index;
+        match status {
+            JobStatus::Paid | JobStatus::Resolved => self.completed |= bit,
+            JobStatus::Cancelled => self.cancelled |= bit,
+            _ => return false,
+        }
+        true
+    }
+
+    /// Check if every job of the bundle has been completed or cancelled
+    pub fn is_complete(&self) -> bool {
+        (self.completed | self.cancelled).count_ones() == self.job_count as u32
+    }
+}
+
+/// Initialize a job bundle
+pub struct InitJobBundleParams {
+    /// Escrow the jobs are funded in
+    pub escrow: Pubkey,
+    /// Job authority that created the bundle
+    pub authority: Pubkey,
+    /// Identifier of the bundle, unique for the authority
+    pub id: u64,
+    /// Hash of the metadata the jobs share
+    pub metadata_hash: [u8; HASH_BYTES],
+    /// Identifier of the first job of the bundle
+    pub first_job_id: u64,
+    /// Number of jobs of the bundle
+    pub job_count: u8,
+}
+
+impl Sealed for JobBundle {}
+
+impl IsInitialized for JobBundle {
+    fn is_initialized(&self) -> bool {
+        self.account_type != AccountType::Uninitialized
+    }
+}
+
+const JOB_BUNDLE_LEN: usize = 139; // 1 + 32 + 32 + 8 + 32 + 8 + 1 + 8 + 8 + 8 + 1
+const _: () = assert!(JobBundle::COMPLETED_OFFSET + 8 + 8 + 1 == JOB_BUNDLE_LEN);
+impl Pack for JobBundle {
+    const LEN: usize = JOB_BUNDLE_LEN;
+
+    fn pack_into_slice(&self, output: &mut [u8]) {
+        let output = array_mut_ref![output, 0, JOB_BUNDLE_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (
+            account_type,
+            escrow,
+            authority,
+            id,
+            metadata_hash,
+            first_job_id,
+            job_count,
+            amount,
+            completed,
+            cancelled,
+            is_cancelled,
+        ) = mut_array_refs![
+            output,
+            1,
+            PUBKEY_BYTES,
+            PUBKEY_BYTES,
+            8,
+            HASH_BYTES,
+            8,
+            1,
+            8,
+            8,
+            8,
+            1
+        ];
+
+        *account_type = u8::from(self.account_type).to_le_bytes();
+        escrow.copy_from_slice(&self.escrow.to_bytes());
+        authority.copy_from_slice(&self.authority.to_bytes());
+        *id = self.id.to_le_bytes();
+        *metadata_hash = self.metadata_hash;
+        *first_job_id = self.first_job_id.to_le_bytes();
+        *job_count = self.job_count.to_le_bytes();
+        *amount = self.amount.to_le_bytes();
+        *completed = self.completed.to_le_bytes();
+        *cancelled = self.cancelled.to_le_bytes();
+        *is_cancelled = u8::from(self.is_cancelled).to_le_bytes();
+    }
+
+    fn unpack_from_slice(input: &[u8]) -> Result<Self, ProgramError> {
+        let input = array_ref![input, 0, JOB_BUNDLE_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (
+            account_type,
+            escrow,
+            authority,
+            id,
+            metadata_hash,
+            first_job_id,
+            job_count,
+            amount,
+            completed,
+            cancelled,
+            is_cancelled,
+        ) = array_refs![
+            input,
+            1,
+            PUBKEY_BYTES,
+            PUBKEY_BYTES,
+            8,
+            HASH_BYTES,
+            8,
+            1,
+            8,
+            8,
+            8,
+            1
+        ];
+
+        let account_type = AccountType::from(u8::from_le_bytes(*account_type));
+        if account_type != AccountType::JobBundleV1 {
+            msg!("Job bundle account type is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self {
+            account_type,
+            escrow: Pubkey::new_from_array(*escrow),
+            authority: Pubkey::new_from_array(*authority),
+            id: u64::from_le_bytes(*id),
+            metadata_hash: *metadata_hash,
+            first_job_id: u64::from_le_bytes(*first_job_id),
+            job_count: u8::from_le_bytes(*job_count),
+            amount: u64::from_le_bytes(*amount),
+            completed: u64::from_le_bytes(*completed),
+            cancelled: u64::from_le_bytes(*cancelled),
+            is_cancelled: match u8::from_le_bytes(*is_cancelled) {
+                0 => false,
+                1 => true,
+                _ => {
+                    msg!("Job bundle cancelled flag is invalid");
+                    return Err(ProgramError::InvalidAccountData);
+                }
+            },
+        })
+    }
+}
@@ -0,0 +1,240 @@
+use {
+    super::*,
+    arrayref::{array_mut_ref, array_ref, array_refs, mut_array_refs},
+    solana_program::{
+        msg,
+        program_error::ProgramError,
+        program_pack::{IsInitialized, Pack, Sealed},
+        pubkey::{Pubkey, PUBKEY_BYTES},
+    },
+    std::convert::TryFrom,
+};
+
+/// Number of job identifiers recorded in each page of a job index
+pub const JOB_INDEX_PAGE_LEN: usize = 64;
+
+/// Job index state
+///
+/// Index of the jobs of an authority in an escrow, so on-chain consumers and light clients can
+/// iterate them by address instead of with `getProgramAccounts`. The processor records each job
+/// created by an instruction the index is appended to in `JobIndexPage` accounts, in the order the
+/// jobs were created, filling each page of `JOB_INDEX_PAGE_LEN` jobs before the next.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct JobIndex {
+    /// Account type, must be JobIndexV1 currently
+    pub account_type: AccountType,
+    /// Escrow the jobs are funded in
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub escrow: Pubkey,
+    /// Job authority that the jobs' addresses are derived from
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub authority: Pubkey,
+    /// Number of jobs recorded in the pages of the index
+    pub job_count: u64,
+}
+
+impl JobIndex {
+    /// Offset of the escrow in a packed job index
+    pub const ESCROW_OFFSET: usize = ACCOUNT_TYPE_OFFSET + 1;
+    /// Offset of the authority in a packed job index
+    pub const AUTHORITY_OFFSET: usize = Self::ESCROW_OFFSET + PUBKEY_BYTES;
+
+    /// Create a job index
+    pub fn new(params: InitJobIndexParams) -> Self {
+        let mut job_index = Self::default();
+        Self::init(&mut job_index, params);
+        job_index
+    }
+
+    /// Initialize an empty job index
+    pub fn init(&mut self, params: InitJobIndexParams) {
+        self.account_type = AccountType::JobIndexV1;
+        self.escrow = params.escrow;
+        self.authority = params.authority;
+        self.job_count = 0;
+    }
+
+    /// Page the next job is recorded in, or `None` if the page number overflows
+    pub fn next_page(&self) -> Option<u32> {
+        u32::try_from(self.job_count / JOB_INDEX_PAGE_LEN as u64).ok()
+    }
+
+    /// Number of pages the jobs of the index are recorded in
+    pub fn page_count(&self) -> u64 {
+        self.job_count.div_ceil(JOB_INDEX_PAGE_LEN as u64)
+    }
+}
+
+/// Initialize a job index
+pub struct InitJobIndexParams {
+    /// Escrow the jobs are funded in
+    pub escrow: Pubkey,
+    /// Job authority of the jobs
+    pub authority: Pubkey,
+}
+
+impl Sealed for JobIndex {}
+
+impl IsInitialized for JobIndex {
+    fn is_initialized(&self) -> bool {
+        self.account_type != AccountType::Uninitialized
+    }
+}
+
+const JOB_INDEX_LEN: usize = 73; // 1 + 32 + 32 + 8
+const _: () = assert!(JobIndex::AUTHORITY_OFFSET + PUBKEY_BYTES + 8 == JOB_INDEX_LEN);
+impl Pack for JobIndex {
+    const LEN: usize = JOB_INDEX_LEN;
+
+    fn pack_into_slice(&self, output: &mut [u8]) {
+        let output = array_mut_ref![output, 0, JOB_INDEX_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (account_type, escrow, authority, job_count) =
+            mut_array_refs![output, 1, PUBKEY_BYTES, PUBKEY_BYTES, 8];
+
+        *account_type = u8::from(self.account_type).to_le_bytes();
+        escrow.copy_from_slice(&self.escrow.to_bytes());
+        authority.copy_from_slice(&self.authority.to_bytes());
+        *job_count = self.job_count.to_le_bytes();
+    }
+
+    fn unpack_from_slice(input: &[u8]) -> Result<Self, ProgramError> {
+        let input = array_ref![input, 0, JOB_INDEX_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (account_type, escrow, authority, job_count) =
+            array_refs![input, 1, PUBKEY_BYTES, PUBKEY_BYTES, 8];
+
+        let account_type = AccountType::from(u8::from_le_bytes(*account_type));
+        if account_type != AccountType::JobIndexV1 {
+            msg!("Job index account type is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self {
+            account_type,
+            escrow: Pubkey::new_from_array(*escrow),
+            authority: Pubkey::new_from_array(*authority),
+            job_count: u64::from_le_bytes(*job_count),
+        })
+    }
+}
+
+/// Job index page state
+///
+/// Identifiers of up to `JOB_INDEX_PAGE_LEN` consecutive jobs of a `JobIndex`, in the order they
+/// were created
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct JobIndexPage {
+    /// Account type, must be JobIndexPageV1 currently
+    pub account_type: AccountType,
+    /// Job index the page belongs to
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub job_index: Pubkey,
+    /// Number of the page in the job index
+    pub page: u32,
+    /// Identifiers of the jobs recorded in the page, at most `JOB_INDEX_PAGE_LEN`
+    pub job_ids: Vec<u64>,
+}
+
+impl JobIndexPage {
+    /// Offset of the job index in a packed job index page
+    pub const JOB_INDEX_OFFSET: usize = ACCOUNT_TYPE_OFFSET + 1;
+
+    /// Create a job index page
+    pub fn new(params: InitJobIndexPageParams) -> Self {
+        let mut job_index_page = Self::default();
+        Self::init(&mut job_index_page, params);
+        job_index_page
+    }
+
+    /// Initialize an empty job index page
+    pub fn init(&mut self, params: InitJobIndexPageParams) {
+        self.account_type = AccountType::JobIndexPageV1;
+        self.job_index = params.job_index;
+        self.page = params.page;
+        self.job_ids = Vec::new();
+    }
+
+    /// Record a job in the page, returning whether there was room for it
+    pub fn push(&mut self, job_id: u64) -> bool {
+        if self.job_ids.len() >= JOB_INDEX_PAGE_LEN {
+            return false;
+        }
+        self.job_ids.push(job_id);
+        true
+    }
+}
+
+/// Initialize a job index page
+pub struct InitJobIndexPageParams {
+    /// Job index the page belongs to
+    pub job_index: Pubkey,
+    /// Number of the page in the job index
+    pub page: u32,
+}
+
+impl Sealed for JobIndexPage {}
+
+impl IsInitialized for JobIndexPage {
+    fn is_initialized(&self) -> bool {
+        self.account_type != AccountType::Uninitialized
+    }
+}
+
+const JOB_IDS_LEN: usize = 8 * JOB_INDEX_PAGE_LEN;
+const JOB_INDEX_PAGE_LEN_BYTES: usize = 550; // 1 + 32 + 4 + 1 + (8 * 64)
+const _: () = assert!(
+    JobIndexPage::JOB_INDEX_OFFSET + PUBKEY_BYTES + 4 + 1 + JOB_IDS_LEN == JOB_INDEX_PAGE_LEN_BYTES
+);
+impl Pack for JobIndexPage {
+    const LEN: usize = JOB_INDEX_PAGE_LEN_BYTES;
+
+    fn pack_into_slice(&self, output: &mut [u8]) {
+        let output = array_mut_ref![output, 0, JOB_INDEX_PAGE_LEN_BYTES];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (account_type, job_index, page, len, job_ids) =
+            mut_array_refs![output, 1, PUBKEY_BYTES, 4, 1, JOB_IDS_LEN];
+
+        *account_type = u8::from(self.account_type).to_le_bytes();
+        job_index.copy_from_slice(&self.job_index.to_bytes());
+        *page = self.page.to_le_bytes();
+        *len = (self.job_ids.len() as u8).to_le_bytes();
+        job_ids.fill(0);
+        for (output, job_id) in job_ids.chunks_exact_mut(8).zip(&self.job_ids) {
+            output.copy_from_slice(&job_id.to_le_bytes());
+        }
+    }
+
+    fn unpack_from_slice(input: &[u8]) -> Result<Self, ProgramError> {
+        let input = array_ref![input, 0, JOB_INDEX_PAGE_LEN_BYTES];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (account_type, job_index, page, len, job_ids) =
+            array_refs![input, 1, PUBKEY_BYTES, 4, 1, JOB_IDS_LEN];
+
+        let account_type = AccountType::from(u8::from_le_bytes(*account_type));
+        if account_type != AccountType::JobIndexPageV1 {
+            msg!("Job index page account type is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let len = u8::from_le_bytes(*len) as usize;
+        if len > JOB_INDEX_PAGE_LEN {
+            msg!("Job index page length is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        let job_ids = job_ids
+            .chunks_exact(8)
+            .take(len)
+            .map(|input| u64::from_le_bytes(*array_ref![input, 0, 8]))
+            .collect();
+
+        Ok(Self {
+            account_type,
+            job_index: Pubkey::new_from_array(*job_index),
+            page: u32::from_le_bytes(*page),
+            job_ids,
+        })
+    }
+}
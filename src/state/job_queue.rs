@@ -0,0 +1,150 @@
+use {
+    super::*,
+    arrayref::{array_mut_ref, array_ref, array_refs, mut_array_refs},
+    solana_program::{
+        msg,
+        program_error::ProgramError,
+        program_pack::{IsInitialized, Pack, Sealed},
+        pubkey::{Pubkey, PUBKEY_BYTES},
+    },
+};
+
+/// Number of jobs a job queue can hold
+pub const JOB_QUEUE_LEN: usize = 64;
+
+/// Job queue state
+///
+/// Bounded first-in, first-out queue of the jobs of an escrow waiting for a node, so nodes can
+/// discover work on-chain without a coordinator. `FundJob` pushes each job it creates when the
+/// queue is appended to it, and `ClaimJob` pops the oldest job and commits the node to it, or
+/// pushes it back if its priority claim delay hasn't passed yet. The jobs are packed in a circular
+/// buffer of `JOB_QUEUE_LEN` slots starting at `head`.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct JobQueue {
+    /// Account type, must be JobQueueV1 currently
+    pub account_type: AccountType,
+    /// Escrow the jobs are funded in
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub escrow: Pubkey,
+    /// Slot of the circular buffer the oldest job is packed in
+    pub head: u8,
+    /// Queued jobs, oldest first, at most `JOB_QUEUE_LEN`
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey::vec"))]
+    pub jobs: Vec<Pubkey>,
+}
+
+impl JobQueue {
+    /// Offset of the escrow in a packed job queue
+    pub const ESCROW_OFFSET: usize = ACCOUNT_TYPE_OFFSET + 1;
+
+    /// Create a job queue
+    pub fn new(params: InitJobQueueParams) -> Self {
+        let mut job_queue = Self::default();
+        Self::init(&mut job_queue, params);
+        job_queue
+    }
+
+    /// Initialize an empty job queue
+    pub fn init(&mut self, params: InitJobQueueParams) {
+        self.account_type = AccountType::JobQueueV1;
+        self.escrow = params.escrow;
+        self.head = 0;
+        self.jobs = Vec::new();
+    }
+
+    /// Push a job to the back of the queue, returning whether there was room for it
+    pub fn push(&mut self, job: Pubkey) -> bool {
+        if self.jobs.len() >= JOB_QUEUE_LEN {
+            return false;
+        }
+        self.jobs.push(job);
+        true
+    }
+
+    /// Pop the oldest job from the front of the queue, if any
+    pub fn pop(&mut self) -> Option<Pubkey> {
+        if self.jobs.is_empty() {
+            return None;
+        }
+        self.head = ((self.head as usize + 1) % JOB_QUEUE_LEN) as u8;
+        Some(self.jobs.remove(0))
+    }
+
+    /// Oldest job of the queue, which is claimed next, if any
+    pub fn front(&self) -> Option<&Pubkey> {
+        self.jobs.first()
+    }
+}
+
+/// Initialize a job queue
+pub struct InitJobQueueParams {
+    /// Escrow the jobs are funded in
+    pub escrow: Pubkey,
+}
+
+impl Sealed for JobQueue {}
+
+impl IsInitialized for JobQueue {
+    fn is_initialized(&self) -> bool {
+        self.account_type != AccountType::Uninitialized
+    }
+}
+
+const JOBS_LEN: usize = PUBKEY_BYTES * JOB_QUEUE_LEN;
+const JOB_QUEUE_LEN_BYTES: usize = 2083; // 1 + 32 + 1 + 1 + (32 * 64)
+const _: () =
+    assert!(JobQueue::ESCROW_OFFSET + PUBKEY_BYTES + 1 + 1 + JOBS_LEN == JOB_QUEUE_LEN_BYTES);
+impl Pack for JobQueue {
+    const LEN: usize = JOB_QUEUE_LEN_BYTES;
+
+    fn pack_into_slice(&self, output: &mut [u8]) {
+        let output = array_mut_ref![output, 0, JOB_QUEUE_LEN_BYTES];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (account_type, escrow, head, len, jobs) =
+            mut_array_refs![output, 1, PUBKEY_BYTES, 1, 1, JOBS_LEN];
+
+        *account_type = u8::from(self.account_type).to_le_bytes();
+        escrow.copy_from_slice(&self.escrow.to_bytes());
+        *head = self.head.to_le_bytes();
+        *len = (self.jobs.len() as u8).to_le_bytes();
+        jobs.fill(0);
+        for (i, job) in self.jobs.iter().enumerate() {
+            let slot = (self.head as usize + i) % JOB_QUEUE_LEN;
+            jobs[slot * PUBKEY_BYTES..][..PUBKEY_BYTES].copy_from_slice(job.as_ref());
+        }
+    }
+
+    fn unpack_from_slice(input: &[u8]) -> Result<Self, ProgramError> {
+        let input = array_ref![input, 0, JOB_QUEUE_LEN_BYTES];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (account_type, escrow, head, len, jobs) =
+            array_refs![input, 1, PUBKEY_BYTES, 1, 1, JOBS_LEN];
+
+        let account_type = AccountType::from(u8::from_le_bytes(*account_type));
+        if account_type != AccountType::JobQueueV1 {
+            msg!("Job queue account type is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let head = u8::from_le_bytes(*head);
+        let len = u8::from_le_bytes(*len) as usize;
+        if head as usize >= JOB_QUEUE_LEN || len > JOB_QUEUE_LEN {
+            msg!("Job queue head or length is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        let jobs = (0..len)
+            .map(|i| {
+                let slot = (head as usize + i) % JOB_QUEUE_LEN;
+                Pubkey::new_from_array(*array_ref![jobs, slot * PUBKEY_BYTES, PUBKEY_BYTES])
+            })
+            .collect();
+
+        Ok(Self {
+            account_type,
+            escrow: Pubkey::new_from_array(*escrow),
+            head,
+            jobs,
+        })
+    }
+}
@@ -0,0 +1,33 @@
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+
+/// Enum representing the lifecycle of a job's result
+#[derive(Clone, Copy, Debug, Eq, PartialEq, IntoPrimitive, TryFromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u8)]
+pub enum JobStatus {
+    /// No node has committed to a result yet, the value will be 0
+    Open,
+    /// A node has committed to the hash of a result
+    Committed,
+    /// The node has revealed the result matching its commitment
+    Revealed,
+    /// The node has claimed payment for the result
+    Paid,
+    /// The job's authority challenged the result within the escrow's challenge window
+    Challenged,
+    /// A registered verifier flagged the job or a dispute was opened over it, freezing disbursement
+    /// of its funds
+    Disputed,
+    /// The escrow's arbitrator resolved the dispute over the job, splitting its funds between the
+    /// job's authority and node
+    Resolved,
+    /// The job's authority cancelled the job before a result was revealed, refunding its funds
+    Cancelled,
+}
+
+#[allow(clippy::derivable_impls)]
+impl Default for JobStatus {
+    fn default() -> Self {
+        JobStatus::Open
+    }
+}
@@ -0,0 +1,179 @@
+use {
+    super::*,
+    arrayref::{array_mut_ref, array_ref, array_refs, mut_array_refs},
+    solana_program::{
+        hash::{hash, HASH_BYTES},
+        msg,
+        program_error::ProgramError,
+        program_pack::{IsInitialized, Pack, Sealed},
+        pubkey::{Pubkey, PUBKEY_BYTES},
+    },
+};
+
+/// Maximum depth of a job tree, enough for millions of compressed jobs
+pub const MAX_JOB_TREE_DEPTH: u8 = 24;
+
+/// Job tree state
+///
+/// Compressed jobs of an authority, kept as the leaves of an spl-account-compression concurrent
+/// merkle tree for micro-jobs that would each cost more in rent as a `Job` account than they pay.
+/// The job tree is the authority of its merkle tree, so leaves are only appended and replaced
+/// through the program, which checks the escrow, authority and amount of the tree kept here. Each
+/// leaf's preimage is logged through the noop program, so clients build proofs from the leaves
+/// indexed from the transactions' inner instructions. A proof only has to be of a recent root of
+/// the merkle tree, so jobs of a tree can be replaced and settled concurrently.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct JobTree {
+    /// Account type, must be JobTreeV1 currently
+    pub account_type: AccountType,
+    /// Escrow the jobs are funded in
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub escrow: Pubkey,
+    /// Job authority that manages the jobs of the tree
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub authority: Pubkey,
+    /// Concurrent merkle tree of the account compression program holding the leaves
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub merkle_tree: Pubkey,
+    /// Depth of the tree, which holds up to `2^max_depth` jobs
+    pub max_depth: u8,
+    /// Number of jobs appended to the tree, which is the identifier of the next job
+    pub leaf_count: u64,
+    /// Amount of tokens in escrow for the unsettled jobs of the tree
+    pub amount: u64,
+}
+
+impl JobTree {
+    /// Offset of the escrow in a packed job tree
+    pub const ESCROW_OFFSET: usize = ACCOUNT_TYPE_OFFSET + 1;
+    /// Offset of the authority in a packed job tree
+    pub const AUTHORITY_OFFSET: usize = Self::ESCROW_OFFSET + PUBKEY_BYTES;
+    /// Offset of the merkle tree in a packed job tree
+    pub const MERKLE_TREE_OFFSET: usize = Self::AUTHORITY_OFFSET + PUBKEY_BYTES;
+
+    /// Create a job tree
+    pub fn new(params: InitJobTreeParams) -> Self {
+        let mut job_tree = Self::default();
+        Self::init(&mut job_tree, params);
+        job_tree
+    }
+
+    /// Initialize an empty job tree
+    pub fn init(&mut self, params: InitJobTreeParams) {
+        self.account_type = AccountType::JobTreeV1;
+        self.escrow = params.escrow;
+        self.authority = params.authority;
+        self.merkle_tree = params.merkle_tree;
+        self.max_depth = params.max_depth;
+        self.leaf_count = 0;
+        self.amount = 0;
+    }
+
+    /// Whether the tree has room for no more leaves
+    pub fn is_full(&self) -> bool {
+        self.leaf_count >= 1 << self.max_depth
+    }
+}
+
+/// Initialize a job tree
+pub struct InitJobTreeParams {
+    /// Escrow the jobs are funded in
+    pub escrow: Pubkey,
+    /// Job authority that manages the jobs of the tree
+    pub authority: Pubkey,
+    /// Concurrent merkle tree holding the leaves
+    pub merkle_tree: Pubkey,
+    /// Depth of the tree
+    pub max_depth: u8,
+}
+
+/// Compressed job, stored as a leaf of a `JobTree` instead of in a `Job` account
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CompressedJob {
+    /// Identifier of the job, which is the index of its leaf
+    pub id: u64,
+    /// Amount of tokens in escrow for the job
+    pub amount: u64,
+    /// Status of the job, which is Open until it's assigned to a node
+    pub status: JobStatus,
+    /// Node the job is assigned to, or the default pubkey if the job is open
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub node: Pubkey,
+}
+
+impl CompressedJob {
+    /// Preimage of the leaf of the job in a job tree, which is logged through the noop program
+    pub fn leaf_data(&self, job_tree: &Pubkey) -> Vec<u8> {
+        let mut data = Vec::with_capacity(PUBKEY_BYTES + 8 + 8 + 1 + PUBKEY_BYTES);
+        data.extend_from_slice(job_tree.as_ref());
+        data.extend_from_slice(&self.id.to_le_bytes());
+        data.extend_from_slice(&self.amount.to_le_bytes());
+        data.push(u8::from(self.status));
+        data.extend_from_slice(self.node.as_ref());
+        data
+    }
+
+    /// Hash of the leaf of the job in a job tree
+    pub fn leaf(&self, job_tree: &Pubkey) -> [u8; HASH_BYTES] {
+        hash(&self.leaf_data(job_tree)).to_bytes()
+    }
+}
+
+impl Sealed for JobTree {}
+
+impl IsInitialized for JobTree {
+    fn is_initialized(&self) -> bool {
+        self.account_type != AccountType::Uninitialized
+    }
+}
+
+const JOB_TREE_LEN: usize = 114; // 1 + 32 + 32 + 32 + 1 + 8 + 8
+impl Pack for JobTree {
+    const LEN: usize = JOB_TREE_LEN;
+
+    fn pack_into_slice(&self, output: &mut [u8]) {
+        let output = array_mut_ref![output, 0, JOB_TREE_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (account_type, escrow, authority, merkle_tree, max_depth, leaf_count, amount) =
+            mut_array_refs![output, 1, PUBKEY_BYTES, PUBKEY_BYTES, PUBKEY_BYTES, 1, 8, 8];
+
+        *account_type = u8::from(self.account_type).to_le_bytes();
+        escrow.copy_from_slice(&self.escrow.to_bytes());
+        authority.copy_from_slice(&self.authority.to_bytes());
+        merkle_tree.copy_from_slice(&self.merkle_tree.to_bytes());
+        *max_depth = self.max_depth.to_le_bytes();
+        *leaf_count = self.leaf_count.to_le_bytes();
+        *amount = self.amount.to_le_bytes();
+    }
+
+    fn unpack_from_slice(input: &[u8]) -> Result<Self, ProgramError> {
+        let input = array_ref![input, 0, JOB_TREE_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (account_type, escrow, authority, merkle_tree, max_depth, leaf_count, amount) =
+            array_refs![input, 1, PUBKEY_BYTES, PUBKEY_BYTES, PUBKEY_BYTES, 1, 8, 8];
+
+        let account_type = AccountType::from(u8::from_le_bytes(*account_type));
+        if account_type != AccountType::JobTreeV1 {
+            msg!("Job tree account type is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let max_depth = u8::from_le_bytes(*max_depth);
+        if max_depth > MAX_JOB_TREE_DEPTH {
+            msg!("Job tree depth is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self {
+            account_type,
+            escrow: Pubkey::new_from_array(*escrow),
+            authority: Pubkey::new_from_array(*authority),
+            merkle_tree: Pubkey::new_from_array(*merkle_tree),
+            max_depth,
+            leaf_count: u64::from_le_bytes(*leaf_count),
+            amount: u64::from_le_bytes(*amount),
+        })
+    }
+}
@@ -0,0 +1,159 @@
+use {
+    super::*,
+    arrayref::{array_mut_ref, array_ref, array_refs, mut_array_refs},
+    solana_program::{
+        clock::Epoch,
+        hash::{hashv, HASH_BYTES},
+        msg,
+        program_error::ProgramError,
+        program_pack::{IsInitialized, Pack, Sealed},
+        pubkey::{Pubkey, PUBKEY_BYTES},
+    },
+};
+
+/// Maximum number of hashes in the proof of a Merkle reward claim, enough for a tree with a leaf
+/// for every possible node index
+pub const MAX_MERKLE_PROOF_LEN: usize = 32;
+
+/// Merkle distribution state
+///
+/// Rewards of an escrow for an epoch published by its owner as the Merkle root of `(index, node,
+/// amount)` leaves, held in the distribution's associated token account. Shares are computed off
+/// chain, so each node claims its amount with a proof of its leaf, and the claim is recorded in
+/// the `ClaimBitmap` covering its index.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MerkleDistribution {
+    /// Account type, must be MerkleDistributionV1 currently
+    pub account_type: AccountType,
+    /// Escrow the rewards are paid for
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub escrow: Pubkey,
+    /// Epoch the rewards are paid for
+    pub epoch: Epoch,
+    /// Merkle root of the leaves of every node's reward
+    pub root: [u8; HASH_BYTES],
+    /// Number of leaves in the tree, each indexed from zero
+    pub node_count: u32,
+    /// Amount of tokens funded, the sum of the amounts of every leaf
+    pub amount: u64,
+    /// Amount of tokens claimed by nodes
+    pub claimed: u64,
+}
+
+impl MerkleDistribution {
+    /// Offset of the escrow in a packed Merkle distribution
+    pub const ESCROW_OFFSET: usize = ACCOUNT_TYPE_OFFSET + 1;
+    /// Offset of the epoch in a packed Merkle distribution
+    pub const EPOCH_OFFSET: usize = Self::ESCROW_OFFSET + PUBKEY_BYTES;
+    /// Offset of the root in a packed Merkle distribution
+    pub const ROOT_OFFSET: usize = Self::EPOCH_OFFSET + 8;
+    /// Offset of the number of leaves in a packed Merkle distribution
+    pub const NODE_COUNT_OFFSET: usize = Self::ROOT_OFFSET + HASH_BYTES;
+    /// Offset of the amount in a packed Merkle distribution
+    pub const AMOUNT_OFFSET: usize = Self::NODE_COUNT_OFFSET + 4;
+    /// Offset of the claimed amount in a packed Merkle distribution
+    pub const CLAIMED_OFFSET: usize = Self::AMOUNT_OFFSET + 8;
+
+    /// Create a Merkle distribution
+    pub fn new(params: InitMerkleDistributionParams) -> Self {
+        let mut merkle_distribution = Self::default();
+        Self::init(&mut merkle_distribution, params);
+        merkle_distribution
+    }
+
+    /// Initialize a Merkle distribution with no claims
+    pub fn init(&mut self, params: InitMerkleDistributionParams) {
+        self.account_type = AccountType::MerkleDistributionV1;
+        self.escrow = params.escrow;
+        self.epoch = params.epoch;
+        self.root = params.root;
+        self.node_count = params.node_count;
+        self.amount = params.amount;
+        self.claimed = 0;
+    }
+
+    /// Hash of the leaf of a node's reward
+    pub fn leaf(index: u32, node: &Pubkey, amount: u64) -> [u8; HASH_BYTES] {
+        hashv(&[&index.to_le_bytes(), node.as_ref(), &amount.to_le_bytes()]).to_bytes()
+    }
+
+    /// Whether a proof of sibling hashes, ordered from the leaf up, proves a leaf is in the tree.
+    /// Each pair of hashes is sorted before it's hashed, so the proof doesn't encode positions.
+    pub fn verify(&self, proof: &[[u8; HASH_BYTES]], leaf: [u8; HASH_BYTES]) -> bool {
+        let root = proof.iter().fold(leaf, |hash, sibling| {
+            if hash <= *sibling {
+                hashv(&[&hash, sibling]).to_bytes()
+            } else {
+                hashv(&[sibling, &hash]).to_bytes()
+            }
+        });
+        root == self.root
+    }
+}
+
+/// Initialize a Merkle distribution
+pub struct InitMerkleDistributionParams {
+    /// Escrow the rewards are paid for
+    pub escrow: Pubkey,
+    /// Epoch the rewards are paid for
+    pub epoch: Epoch,
+    /// Merkle root of the leaves of every node's reward
+    pub root: [u8; HASH_BYTES],
+    /// Number of leaves in the tree
+    pub node_count: u32,
+    /// Amount of tokens funded
+    pub amount: u64,
+}
+
+impl Sealed for MerkleDistribution {}
+
+impl IsInitialized for MerkleDistribution {
+    fn is_initialized(&self) -> bool {
+        self.account_type != AccountType::Uninitialized
+    }
+}
+
+const MERKLE_DISTRIBUTION_LEN: usize = 93; // 1 + 32 + 8 + 32 + 4 + 8 + 8
+const _: () = assert!(MerkleDistribution::CLAIMED_OFFSET + 8 == MERKLE_DISTRIBUTION_LEN);
+impl Pack for MerkleDistribution {
+    const LEN: usize = MERKLE_DISTRIBUTION_LEN;
+
+    fn pack_into_slice(&self, output: &mut [u8]) {
+        let output = array_mut_ref![output, 0, MERKLE_DISTRIBUTION_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (account_type, escrow, epoch, root, node_count, amount, claimed) =
+            mut_array_refs![output, 1, PUBKEY_BYTES, 8, HASH_BYTES, 4, 8, 8];
+
+        *account_type = u8::from(self.account_type).to_le_bytes();
+        escrow.copy_from_slice(&self.escrow.to_bytes());
+        *epoch = self.epoch.to_le_bytes();
+        *root = self.root;
+        *node_count = self.node_count.to_le_bytes();
+        *amount = self.amount.to_le_bytes();
+        *claimed = self.claimed.to_le_bytes();
+    }
+
+    fn unpack_from_slice(input: &[u8]) -> Result<Self, ProgramError> {
+        let input = array_ref![input, 0, MERKLE_DISTRIBUTION_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (account_type, escrow, epoch, root, node_count, amount, claimed) =
+            array_refs![input, 1, PUBKEY_BYTES, 8, HASH_BYTES, 4, 8, 8];
+
+        let account_type = AccountType::from(u8::from_le_bytes(*account_type));
+        if account_type != AccountType::MerkleDistributionV1 {
+            msg!("Merkle distribution account type is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self {
+            account_type,
+            escrow: Pubkey::new_from_array(*escrow),
+            epoch: Epoch::from_le_bytes(*epoch),
+            root: *root,
+            node_count: u32::from_le_bytes(*node_count),
+            amount: u64::from_le_bytes(*amount),
+            claimed: u64::from_le_bytes(*claimed),
+        })
+    }
+}
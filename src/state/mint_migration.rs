@@ -0,0 +1,245 @@
+use {
+    super::*,
+    crate::math::Decimal,
+    arrayref::{array_mut_ref, array_ref, array_refs, mut_array_refs},
+    solana_program::{
+        clock::UnixTimestamp,
+        msg,
+        program_error::ProgramError,
+        program_pack::{IsInitialized, Pack, Sealed},
+        pubkey::{Pubkey, PUBKEY_BYTES},
+    },
+};
+
+/// Mint migration state
+///
+/// Migration of an escrow from its token mint to a new one, such as from RNDR to RENDER, in
+/// resumable steps. The migration is begun by the owner and the governance authority of the config,
+/// and the approver of the escrow if it has one, with the rate tokens are converted at and the
+/// token account the old tokens are sent to, which creates the associated token account of the
+/// escrow for the new mint. Once the timelock of the config has passed, the owner converts the
+/// escrow's pooled tokens, depositing the converted amount of new tokens in exchange for the old
+/// ones, which switches the escrow to the new mint. The amounts of jobs are then
+/// re-denominated in batches, and isolated jobs converted one by one, each counted by the
+/// migration, and jobs can't be funded or paid until they are.
+///
+/// Migrations of an escrow are numbered from zero, and a job is re-denominated by migration `n`
+/// once `Job::mint_migrations` is `n + 1`.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MintMigration {
+    /// Account type, must be MintMigrationV1 currently
+    pub account_type: AccountType,
+    /// Escrow being migrated
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub escrow: Pubkey,
+    /// Number of the migration, the number of migrations of the escrow before it
+    pub index: u8,
+    /// Token mint the escrow is migrated from
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub token_mint: Pubkey,
+    /// Token mint the escrow is migrated to
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub new_token_mint: Pubkey,
+    /// Numerator of the rate amounts are converted at
+    pub rate_numerator: u64,
+    /// Denominator of the rate amounts are converted at, which isn't zero
+    pub rate_denominator: u64,
+    /// Whether the pooled tokens of the escrow have been converted, so the escrow uses the new
+    /// mint and jobs can be re-denominated
+    pub vault_converted: bool,
+    /// Number of jobs that have been re-denominated or converted
+    pub jobs_migrated: u64,
+    /// Token account of the old mint approved by the governance authority that the old tokens
+    /// of the escrow and its isolated jobs are sent to
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub destination: Pubkey,
+    /// Timestamp after which the pooled tokens of the escrow can be converted
+    pub executable_at: UnixTimestamp,
+}
+
+impl MintMigration {
+    /// Offset of the escrow in a packed mint migration
+    pub const ESCROW_OFFSET: usize = ACCOUNT_TYPE_OFFSET + 1;
+    /// Offset of the vault converted flag in a packed mint migration
+    pub const VAULT_CONVERTED_OFFSET: usize = Self::ESCROW_OFFSET + PUBKEY_BYTES * 3 + 1 + 8 + 8;
+
+    /// Create a mint migration
+    pub fn new(params: InitMintMigrationParams) -> Self {
+        let mut mint_migration = Self::default();
+        Self::init(&mut mint_migration, params);
+        mint_migration
+    }
+
+    /// Initialize a mint migration whose vault hasn't been converted
+    pub fn init(&mut self, params: InitMintMigrationParams) {
+        self.account_type = AccountType::MintMigrationV1;
+        self.escrow = params.escrow;
+        self.index = params.index;
+        self.token_mint = params.token_mint;
+        self.new_token_mint = params.new_token_mint;
+        self.rate_numerator = params.rate_numerator;
+        self.rate_denominator = params.rate_denominator;
+        self.vault_converted = false;
+        self.jobs_migrated = 0;
+        self.destination = params.destination;
+        self.executable_at = params.executable_at;
+    }
+
+    /// Amount of new tokens an amount of old tokens converts to, rounded down, or `None` if it
+    /// overflows
+    pub fn convert(&self, amount: u64) -> Option<u64> {
+        Decimal::from(amount)
+            .try_mul(self.rate_numerator)
+            .and_then(|converted| converted.try_div(self.rate_denominator))
+            .and_then(Decimal::try_floor_u64)
+            .ok()
+    }
+}
+
+/// Initialize a mint migration
+pub struct InitMintMigrationParams {
+    /// Escrow being migrated
+    pub escrow: Pubkey,
+    /// Number of the migration
+    pub index: u8,
+    /// Token mint the escrow is migrated from
+    pub token_mint: Pubkey,
+    /// Token mint the escrow is migrated to
+    pub new_token_mint: Pubkey,
+    /// Numerator of the conversion rate
+    pub rate_numerator: u64,
+    /// Denominator of the conversion rate
+    pub rate_denominator: u64,
+    /// Token account of the old mint that the old tokens are sent to
+    pub destination: Pubkey,
+    /// Timestamp after which the pooled tokens of the escrow can be converted
+    pub executable_at: UnixTimestamp,
+}
+
+impl Sealed for MintMigration {}
+
+impl IsInitialized for MintMigration {
+    fn is_initialized(&self) -> bool {
+        self.account_type != AccountType::Uninitialized
+    }
+}
+
+const MINT_MIGRATION_LEN: usize = 163; // 1 + 32 + 1 + 32 + 32 + 8 + 8 + 1 + 8 + 32 + 8
+const _: () = assert!(
+    MintMigration::ESCROW_OFFSET
+        + PUBKEY_BYTES
+        + 1
+        + PUBKEY_BYTES * 2
+        + 8
+        + 8
+        + 1
+        + 8
+        + PUBKEY_BYTES
+        + 8
+        == MINT_MIGRATION_LEN
+);
+impl Pack for MintMigration {
+    const LEN: usize = MINT_MIGRATION_LEN;
+
+    fn pack_into_slice(&self, output: &mut [u8]) {
+        let output = array_mut_ref![output, 0, MINT_MIGRATION_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (
+            account_type,
+            escrow,
+            index,
+            token_mint,
+            new_token_mint,
+            rate_numerator,
+            rate_denominator,
+            vault_converted,
+            jobs_migrated,
+            destination,
+            executable_at,
+        ) = mut_array_refs![
+            output,
+            1,
+            PUBKEY_BYTES,
+            1,
+            PUBKEY_BYTES,
+            PUBKEY_BYTES,
+            8,
+            8,
+            1,
+            8,
+            PUBKEY_BYTES,
+            8
+        ];
+
+        *account_type = u8::from(self.account_type).to_le_bytes();
+        escrow.copy_from_slice(&self.escrow.to_bytes());
+        *index = self.index.to_le_bytes();
+        token_mint.copy_from_slice(&self.token_mint.to_bytes());
+        new_token_mint.copy_from_slice(&self.new_token_mint.to_bytes());
+        *rate_numerator = self.rate_numerator.to_le_bytes();
+        *rate_denominator = self.rate_denominator.to_le_bytes();
+        *vault_converted = u8::from(self.vault_converted).to_le_bytes();
+        *jobs_migrated = self.jobs_migrated.to_le_bytes();
+        destination.copy_from_slice(&self.destination.to_bytes());
+        *executable_at = self.executable_at.to_le_bytes();
+    }
+
+    fn unpack_from_slice(input: &[u8]) -> Result<Self, ProgramError> {
+        let input = array_ref![input, 0, MINT_MIGRATION_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (
+            account_type,
+            escrow,
+            index,
+            token_mint,
+            new_token_mint,
+            rate_numerator,
+            rate_denominator,
+            vault_converted,
+            jobs_migrated,
+            destination,
+            executable_at,
+        ) = array_refs![
+            input,
+            1,
+            PUBKEY_BYTES,
+            1,
+            PUBKEY_BYTES,
+            PUBKEY_BYTES,
+            8,
+            8,
+            1,
+            8,
+            PUBKEY_BYTES,
+            8
+        ];
+
+        let account_type = AccountType::from(u8::from_le_bytes(*account_type));
+        if account_type != AccountType::MintMigrationV1 {
+            msg!("Mint migration account type is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self {
+            account_type,
+            escrow: Pubkey::new_from_array(*escrow),
+            index: u8::from_le_bytes(*index),
+            token_mint: Pubkey::new_from_array(*token_mint),
+            new_token_mint: Pubkey::new_from_array(*new_token_mint),
+            rate_numerator: u64::from_le_bytes(*rate_numerator),
+            rate_denominator: u64::from_le_bytes(*rate_denominator),
+            vault_converted: match u8::from_le_bytes(*vault_converted) {
+                0 => false,
+                1 => true,
+                _ => {
+                    msg!("Mint migration vault converted flag is invalid");
+                    return Err(ProgramError::InvalidAccountData);
+                }
+            },
+            jobs_migrated: u64::from_le_bytes(*jobs_migrated),
+            destination: Pubkey::new_from_array(*destination),
+            executable_at: UnixTimestamp::from_le_bytes(*executable_at),
+        })
+    }
+}
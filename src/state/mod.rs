@@ -1,9 +1,183 @@
 //! State types
 
 pub use account_type::*;
+pub use claim_bitmap::*;
+pub use config::*;
+pub use crank_vault::*;
+pub use delegate_allowance::*;
+pub use dispute::*;
+pub use emission_schedule::*;
+pub use epoch_work::*;
 pub use escrow::*;
+pub use escrow_shard::*;
+pub use incident_mode::*;
 pub use job::*;
+pub use job_bundle::*;
+pub use job_index::*;
+pub use job_queue::*;
+pub use job_status::*;
+pub use job_tree::*;
+pub use merkle_distribution::*;
+pub use mint_migration::*;
+pub use node::*;
+pub use proposal::*;
+pub use queued_action::*;
+pub use recovery::*;
+pub use reward_pool::*;
+pub use session_key::*;
+pub use stake_pool::*;
+pub use stats::*;
+pub use subscription::*;
+pub use treasury::*;
+pub use verifier_registry::*;
+pub use verifier_selection::*;
+pub use voucher::*;
 
 mod account_type;
+mod claim_bitmap;
+mod config;
+mod crank_vault;
+mod delegate_allowance;
+mod dispute;
+mod emission_schedule;
+mod epoch_work;
 mod escrow;
+mod escrow_shard;
+mod incident_mode;
 mod job;
+mod job_bundle;
+mod job_index;
+mod job_queue;
+mod job_status;
+mod job_tree;
+mod merkle_distribution;
+mod mint_migration;
+mod node;
+mod proposal;
+mod queued_action;
+mod recovery;
+mod reward_pool;
+mod session_key;
+mod stake_pool;
+mod stats;
+mod subscription;
+mod treasury;
+mod verifier_registry;
+mod verifier_selection;
+mod voucher;
+
+use {
+    crate::math::Rate,
+    solana_program::{
+        msg,
+        program_error::ProgramError,
+        program_pack::{IsInitialized, Pack},
+    },
+};
+
+/// Version of the packed layouts of the instructions and accounts, which integrators can rely on
+/// not shifting while it's unchanged. Appending fields to an instruction or account keeps the
+/// version, since data packed before still unpacks, while changing the encoding of an existing
+/// field bumps it.
+pub const LAYOUT_VERSION: u8 = 1;
+
+// Packed lengths of the accounts, which only grow as fields are appended
+const _: () = {
+    assert!(ClaimBitmap::LEN == 1061);
+    assert!(Config::LEN == 252);
+    assert!(CrankVault::LEN == 49);
+    assert!(DelegateAllowance::LEN == 81);
+    assert!(Dispute::LEN == 161);
+    assert!(EmissionSchedule::LEN == 67);
+    assert!(EpochWork::LEN == 74);
+    assert!(Escrow::LEN == 605);
+    assert!(EscrowShard::LEN == 42);
+    assert!(Job::LEN == 455);
+    assert!(JobBundle::LEN == 139);
+    assert!(JobIndex::LEN == 73);
+    assert!(JobIndexPage::LEN == 550);
+    assert!(JobQueue::LEN == 2083);
+    assert!(JobTree::LEN == 114);
+    assert!(MerkleDistribution::LEN == 93);
+    assert!(MintMigration::LEN == 163);
+    assert!(Node::LEN == 114);
+    assert!(Proposal::LEN == 129);
+    assert!(QueuedAction::LEN == 139);
+    assert!(Recovery::LEN == 715);
+    assert!(RewardPool::LEN == 65);
+    assert!(SessionKey::LEN == 113);
+    assert!(StakePool::LEN == 113);
+    assert!(Stats::LEN == 137);
+    assert!(Subscription::LEN == 129);
+    assert!(Treasury::LEN == 153);
+    assert!(VerifierRegistry::LEN == 546);
+    assert!(VerifierSelection::LEN == 298);
+    assert!(VoucherNonce::LEN == 73);
+};
+
+/// Unpack account data of a type that has had fields appended to its layout, which may have one of
+/// the legacy lengths. The appended fields of legacy accounts are unpacked as their zero defaults.
+fn unpack_appended<T: Pack + IsInitialized>(
+    input: &[u8],
+    legacy_lens: &[usize],
+) -> Result<T, ProgramError> {
+    if input.len() == T::LEN {
+        return T::unpack(input);
+    }
+    let legacy_len = input.len();
+    if !legacy_lens.contains(&legacy_len) {
+        msg!("Account data length is invalid");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let mut data = vec![0; T::LEN];
+    data[..legacy_len].copy_from_slice(input);
+    T::unpack(&data)
+}
+
+/// Pack into account data of a type that has had fields appended to its layout, which may have one
+/// of the legacy lengths if none of the fields appended after it are set
+fn pack_appended<T: Pack>(
+    src: T,
+    output: &mut [u8],
+    legacy_lens: &[usize],
+) -> Result<(), ProgramError> {
+    if output.len() == T::LEN {
+        return T::pack(src, output);
+    }
+    let legacy_len = output.len();
+    if !legacy_lens.contains(&legacy_len) {
+        msg!("Account data length is invalid");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let mut data = vec![0; T::LEN];
+    T::pack(src, &mut data)?;
+    if data[legacy_len..].iter().any(|byte| *byte != 0) {
+        msg!("Account must be reallocated");
+        return Err(ProgramError::AccountDataTooSmall);
+    }
+    output.copy_from_slice(&data[..legacy_len]);
+    Ok(())
+}
+
+/// Pack a cumulative counter that was widened to 128 bits, with its low 64 bits at the offset of
+/// its original field and its high 64 bits in a field appended to the layout
+fn pack_u128(value: u128, low: &mut [u8; 8], high: &mut [u8; 8]) {
+    *low = (value as u64).to_le_bytes();
+    *high = ((value >> 64) as u64).to_le_bytes();
+}
+
+/// Unpack a cumulative counter that was widened to 128 bits from its low and high 64 bits, where
+/// the high bits of legacy accounts are zero
+fn unpack_u128(low: &[u8; 8], high: &[u8; 8]) -> u128 {
+    u128::from(u64::from_le_bytes(*high)) << 64 | u128::from(u64::from_le_bytes(*low))
+}
+
+/// Unpack a rate from its basis points, which fails if it's above 100%
+fn unpack_rate(input: &[u8; 2]) -> Result<Rate, ProgramError> {
+    Rate::from_bps(u16::from_le_bytes(*input)).ok_or_else(|| {
+        msg!("Rate is above 100%");
+        ProgramError::InvalidAccountData
+    })
+}
@@ -0,0 +1,250 @@
+use {
+    super::*,
+    arrayref::{array_mut_ref, array_ref, array_refs, mut_array_refs},
+    solana_program::{
+        clock::Epoch,
+        msg,
+        program_error::ProgramError,
+        program_pack::{IsInitialized, Pack, Sealed},
+        pubkey::{Pubkey, PUBKEY_BYTES},
+    },
+};
+
+/// Node state
+///
+/// Tokens a node authority has staked with an escrow, held by the node's associated token account.
+/// A node that opts into auto-compounding lets anyone move its epoch rewards into its stake. The
+/// node also keeps a ledger of the work credited to it as its jobs are paid, in total and for the
+/// latest epoch it was credited in.
+///
+/// Tokens staked during an epoch are tracked so they only count towards the node's reward tier
+/// from the next epoch, which keeps a node from staking just before it claims a payment and
+/// unstaking right after. These fields were appended to the original layout, which is
+/// `Node::LEGACY_LEN` bytes long, and are unpacked as zero from nodes created before then, which
+/// are reallocated when they next stake.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Node {
+    /// Account type, must be NodeV1 currently
+    pub account_type: AccountType,
+    /// Escrow the node is staked with
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub escrow: Pubkey,
+    /// Node authority that owns the stake
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub authority: Pubkey,
+    /// Amount of tokens staked
+    pub stake: u64,
+    /// Whether anyone can compound the node's epoch rewards into its stake
+    pub auto_compound: bool,
+    /// Work credited to the node over its lifetime
+    pub work_credits: u64,
+    /// Epoch the node was last credited with work in
+    pub work_epoch: Epoch,
+    /// Work credited to the node during its work epoch
+    pub epoch_work_credits: u64,
+    /// Epoch the node last staked tokens in
+    pub stake_epoch: Epoch,
+    /// Amount of tokens staked during the stake epoch that are still staked
+    pub epoch_staked: u64,
+}
+
+impl Node {
+    /// Offset of the escrow in a packed node
+    pub const ESCROW_OFFSET: usize = ACCOUNT_TYPE_OFFSET + 1;
+    /// Offset of the authority in a packed node
+    pub const AUTHORITY_OFFSET: usize = Self::ESCROW_OFFSET + PUBKEY_BYTES;
+    /// Offset of the stake in a packed node
+    pub const STAKE_OFFSET: usize = Self::AUTHORITY_OFFSET + PUBKEY_BYTES;
+    /// Offset of the auto-compound flag in a packed node
+    pub const AUTO_COMPOUND_OFFSET: usize = Self::STAKE_OFFSET + 8;
+    /// Offset of the lifetime work credits in a packed node
+    pub const WORK_CREDITS_OFFSET: usize = Self::AUTO_COMPOUND_OFFSET + 1;
+    /// Offset of the work epoch in a packed node
+    pub const WORK_EPOCH_OFFSET: usize = Self::WORK_CREDITS_OFFSET + 8;
+    /// Offset of the work credits of the work epoch in a packed node
+    pub const EPOCH_WORK_CREDITS_OFFSET: usize = Self::WORK_EPOCH_OFFSET + 8;
+    /// Offset of the stake epoch in a packed node
+    pub const STAKE_EPOCH_OFFSET: usize = Self::EPOCH_WORK_CREDITS_OFFSET + 8;
+    /// Offset of the tokens staked during the stake epoch in a packed node
+    pub const EPOCH_STAKED_OFFSET: usize = Self::STAKE_EPOCH_OFFSET + 8;
+
+    /// Length of nodes created before the stake epoch fields were appended
+    pub const LEGACY_LEN: usize = Self::STAKE_EPOCH_OFFSET;
+
+    /// Create a node
+    pub fn new(params: InitNodeParams) -> Self {
+        let mut node = Self::default();
+        Self::init(&mut node, params);
+        node
+    }
+
+    /// Initialize a node with no stake or work
+    pub fn init(&mut self, params: InitNodeParams) {
+        self.account_type = AccountType::NodeV1;
+        self.escrow = params.escrow;
+        self.authority = params.authority;
+        self.stake = 0;
+        self.auto_compound = false;
+        self.work_credits = 0;
+        self.work_epoch = 0;
+        self.epoch_work_credits = 0;
+        self.stake_epoch = 0;
+        self.epoch_staked = 0;
+    }
+
+    /// Add tokens staked in an epoch to the node's stake, starting a new epoch total if the node
+    /// last staked in an earlier epoch, or return None on overflow
+    pub fn record_stake(&mut self, epoch: Epoch, amount: u64) -> Option<()> {
+        if epoch != self.stake_epoch {
+            self.stake_epoch = epoch;
+            self.epoch_staked = 0;
+        }
+        self.epoch_staked = self.epoch_staked.checked_add(amount)?;
+        self.stake = self.stake.checked_add(amount)?;
+        Some(())
+    }
+
+    /// Remove tokens from the node's stake, taking them from the tokens staked during the stake
+    /// epoch last, or return None if the node doesn't have enough staked
+    pub fn record_unstake(&mut self, amount: u64) -> Option<()> {
+        self.stake = self.stake.checked_sub(amount)?;
+        self.epoch_staked = self.epoch_staked.min(self.stake);
+        Some(())
+    }
+
+    /// Stake that counts towards the node's reward tier in an epoch, excluding the tokens staked
+    /// during that epoch
+    pub fn tier_stake(&self, epoch: Epoch) -> u64 {
+        if epoch == self.stake_epoch {
+            self.stake.saturating_sub(self.epoch_staked)
+        } else {
+            self.stake
+        }
+    }
+
+    /// Credit work to the node in an epoch, starting a new epoch total if the node was last
+    /// credited in an earlier epoch, or return None on overflow
+    pub fn credit_work(&mut self, epoch: Epoch, work: u64) -> Option<()> {
+        if epoch != self.work_epoch {
+            self.work_epoch = epoch;
+            self.epoch_work_credits = 0;
+        }
+        self.epoch_work_credits = self.epoch_work_credits.checked_add(work)?;
+        self.work_credits = self.work_credits.checked_add(work)?;
+        Some(())
+    }
+
+    /// Work credited to the node during an epoch, zero for any epoch but its work epoch
+    pub fn work_credits_in_epoch(&self, epoch: Epoch) -> u64 {
+        if epoch == self.work_epoch {
+            self.epoch_work_credits
+        } else {
+            0
+        }
+    }
+
+    /// Unpack a node from account data, which may have a legacy length
+    pub fn unpack_account(input: &[u8]) -> Result<Self, ProgramError> {
+        unpack_appended(input, &[Self::LEGACY_LEN])
+    }
+
+    /// Pack a node into account data, which may have a legacy length if none of the fields
+    /// appended after it are set
+    pub fn pack_account(node: Self, output: &mut [u8]) -> Result<(), ProgramError> {
+        pack_appended(node, output, &[Self::LEGACY_LEN])
+    }
+}
+
+/// Initialize a node
+pub struct InitNodeParams {
+    /// Escrow the node is staked with
+    pub escrow: Pubkey,
+    /// Node authority that owns the stake
+    pub authority: Pubkey,
+}
+
+impl Sealed for Node {}
+
+impl IsInitialized for Node {
+    fn is_initialized(&self) -> bool {
+        self.account_type != AccountType::Uninitialized
+    }
+}
+
+const NODE_LEN: usize = 114; // 1 + 32 + 32 + 8 + 1 + 8 + 8 + 8 + 8 + 8
+const _: () = assert!(Node::EPOCH_STAKED_OFFSET + 8 == NODE_LEN);
+impl Pack for Node {
+    const LEN: usize = NODE_LEN;
+
+    fn pack_into_slice(&self, output: &mut [u8]) {
+        let output = array_mut_ref![output, 0, NODE_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (
+            account_type,
+            escrow,
+            authority,
+            stake,
+            auto_compound,
+            work_credits,
+            work_epoch,
+            epoch_work_credits,
+            stake_epoch,
+            epoch_staked,
+        ) = mut_array_refs![output, 1, PUBKEY_BYTES, PUBKEY_BYTES, 8, 1, 8, 8, 8, 8, 8];
+
+        *account_type = u8::from(self.account_type).to_le_bytes();
+        escrow.copy_from_slice(&self.escrow.to_bytes());
+        authority.copy_from_slice(&self.authority.to_bytes());
+        *stake = self.stake.to_le_bytes();
+        *auto_compound = u8::from(self.auto_compound).to_le_bytes();
+        *work_credits = self.work_credits.to_le_bytes();
+        *work_epoch = self.work_epoch.to_le_bytes();
+        *epoch_work_credits = self.epoch_work_credits.to_le_bytes();
+        *stake_epoch = self.stake_epoch.to_le_bytes();
+        *epoch_staked = self.epoch_staked.to_le_bytes();
+    }
+
+    fn unpack_from_slice(input: &[u8]) -> Result<Self, ProgramError> {
+        let input = array_ref![input, 0, NODE_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (
+            account_type,
+            escrow,
+            authority,
+            stake,
+            auto_compound,
+            work_credits,
+            work_epoch,
+            epoch_work_credits,
+            stake_epoch,
+            epoch_staked,
+        ) = array_refs![input, 1, PUBKEY_BYTES, PUBKEY_BYTES, 8, 1, 8, 8, 8, 8, 8];
+
+        let account_type = AccountType::from(u8::from_le_bytes(*account_type));
+        if account_type != AccountType::NodeV1 {
+            msg!("Node account type is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self {
+            account_type,
+            escrow: Pubkey::new_from_array(*escrow),
+            authority: Pubkey::new_from_array(*authority),
+            stake: u64::from_le_bytes(*stake),
+            auto_compound: match u8::from_le_bytes(*auto_compound) {
+                0 => false,
+                1 => true,
+                _ => {
+                    msg!("Node auto-compound flag is invalid");
+                    return Err(ProgramError::InvalidAccountData);
+                }
+            },
+            work_credits: u64::from_le_bytes(*work_credits),
+            work_epoch: Epoch::from_le_bytes(*work_epoch),
+            epoch_work_credits: u64::from_le_bytes(*epoch_work_credits),
+            stake_epoch: Epoch::from_le_bytes(*stake_epoch),
+            epoch_staked: u64::from_le_bytes(*epoch_staked),
+        })
+    }
+}
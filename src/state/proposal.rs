@@ -0,0 +1,171 @@
+use {
+    super::*,
+    arrayref::{array_mut_ref, array_ref, array_refs, mut_array_refs},
+    solana_program::{
+        clock::UnixTimestamp,
+        msg,
+        program_error::ProgramError,
+        program_pack::{IsInitialized, Pack, Sealed},
+        pubkey::{Pubkey, PUBKEY_BYTES},
+    },
+};
+
+/// Disbursement proposal state
+///
+/// Records a disbursement of a job's funds proposed by the escrow's owner, which can be executed
+/// by anyone once the escrow's approver has approved it.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Proposal {
+    /// Account type, must be ProposalV1 currently
+    pub account_type: AccountType,
+    /// Escrow the proposal belongs to
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub escrow: Pubkey,
+    /// Identifier of the proposal, unique for the escrow
+    pub id: u64,
+    /// Job whose funds are disbursed
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub job: Pubkey,
+    /// SPL Token account the funds are disbursed to
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub destination: Pubkey,
+    /// Amount of tokens disbursed
+    pub amount: u64,
+    /// Unix timestamp the proposal was approved at, zero if it hasn't been approved
+    pub approved_at: UnixTimestamp,
+    /// Unix timestamp the proposal was executed at, zero if it hasn't been executed
+    pub executed_at: UnixTimestamp,
+}
+
+impl Proposal {
+    /// Offset of the escrow in a packed proposal
+    pub const ESCROW_OFFSET: usize = ACCOUNT_TYPE_OFFSET + 1;
+    /// Offset of the identifier in a packed proposal
+    pub const ID_OFFSET: usize = Self::ESCROW_OFFSET + PUBKEY_BYTES;
+    /// Offset of the job in a packed proposal
+    pub const JOB_OFFSET: usize = Self::ID_OFFSET + 8;
+    /// Offset of the destination in a packed proposal
+    pub const DESTINATION_OFFSET: usize = Self::JOB_OFFSET + PUBKEY_BYTES;
+    /// Offset of the amount in a packed proposal
+    pub const AMOUNT_OFFSET: usize = Self::DESTINATION_OFFSET + PUBKEY_BYTES;
+    /// Offset of the approval timestamp in a packed proposal
+    pub const APPROVED_AT_OFFSET: usize = Self::AMOUNT_OFFSET + 8;
+    /// Offset of the execution timestamp in a packed proposal
+    pub const EXECUTED_AT_OFFSET: usize = Self::APPROVED_AT_OFFSET + 8;
+
+    /// Create a proposal
+    pub fn new(params: InitProposalParams) -> Self {
+        let mut proposal = Self::default();
+        Self::init(&mut proposal, params);
+        proposal
+    }
+
+    /// Initialize a proposal
+    pub fn init(&mut self, params: InitProposalParams) {
+        self.account_type = AccountType::ProposalV1;
+        self.escrow = params.escrow;
+        self.id = params.id;
+        self.job = params.job;
+        self.destination = params.destination;
+        self.amount = params.amount;
+        self.approved_at = 0;
+        self.executed_at = 0;
+    }
+
+    /// Whether the approver has approved the proposal
+    pub fn is_approved(&self) -> bool {
+        self.approved_at != 0
+    }
+
+    /// Whether the proposal has been executed
+    pub fn is_executed(&self) -> bool {
+        self.executed_at != 0
+    }
+}
+
+/// Initialize a proposal
+pub struct InitProposalParams {
+    /// Escrow the proposal belongs to
+    pub escrow: Pubkey,
+    /// Identifier of the proposal
+    pub id: u64,
+    /// Job whose funds are disbursed
+    pub job: Pubkey,
+    /// SPL Token account the funds are disbursed to
+    pub destination: Pubkey,
+    /// Amount of tokens disbursed
+    pub amount: u64,
+}
+
+impl Sealed for Proposal {}
+
+impl IsInitialized for Proposal {
+    fn is_initialized(&self) -> bool {
+        self.account_type != AccountType::Uninitialized
+    }
+}
+
+const PROPOSAL_LEN: usize = 129; // 1 + 32 + 8 + 32 + 32 + 8 + 8 + 8
+const _: () = assert!(Proposal::EXECUTED_AT_OFFSET + 8 == PROPOSAL_LEN);
+impl Pack for Proposal {
+    const LEN: usize = PROPOSAL_LEN;
+
+    fn pack_into_slice(&self, output: &mut [u8]) {
+        let output = array_mut_ref![output, 0, PROPOSAL_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (account_type, escrow, id, job, destination, amount, approved_at, executed_at) = mut_array_refs![
+            output,
+            1,
+            PUBKEY_BYTES,
+            8,
+            PUBKEY_BYTES,
+            PUBKEY_BYTES,
+            8,
+            8,
+            8
+        ];
+
+        *account_type = u8::from(self.account_type).to_le_bytes();
+        escrow.copy_from_slice(&self.escrow.to_bytes());
+        *id = self.id.to_le_bytes();
+        job.copy_from_slice(&self.job.to_bytes());
+        destination.copy_from_slice(&self.destination.to_bytes());
+        *amount = self.amount.to_le_bytes();
+        *approved_at = self.approved_at.to_le_bytes();
+        *executed_at = self.executed_at.to_le_bytes();
+    }
+
+    fn unpack_from_slice(input: &[u8]) -> Result<Self, ProgramError> {
+        let input = array_ref![input, 0, PROPOSAL_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (account_type, escrow, id, job, destination, amount, approved_at, executed_at) = array_refs![
+            input,
+            1,
+            PUBKEY_BYTES,
+            8,
+            PUBKEY_BYTES,
+            PUBKEY_BYTES,
+            8,
+            8,
+            8
+        ];
+
+        let account_type = AccountType::from(u8::from_le_bytes(*account_type));
+        if account_type != AccountType::ProposalV1 {
+            msg!("Proposal account type is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self {
+            account_type,
+            escrow: Pubkey::new_from_array(*escrow),
+            id: u64::from_le_bytes(*id),
+            job: Pubkey::new_from_array(*job),
+            destination: Pubkey::new_from_array(*destination),
+            amount: u64::from_le_bytes(*amount),
+            approved_at: UnixTimestamp::from_le_bytes(*approved_at),
+            executed_at: UnixTimestamp::from_le_bytes(*executed_at),
+        })
+    }
+}
@@ -0,0 +1,420 @@
+use {
+    super::*,
+    crate::math::RoundingPolicy,
+    arrayref::{array_mut_ref, array_ref, array_refs, mut_array_refs},
+    solana_program::{
+        clock::UnixTimestamp,
+        msg,
+        program_error::ProgramError,
+        program_pack::{IsInitialized, Pack, Sealed},
+        pubkey::{Pubkey, PUBKEY_BYTES},
+    },
+    std::convert::TryFrom,
+};
+
+/// Administrative action that waits for the timelock duration of the config before it's applied
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AdminAction {
+    /// Set the owner of an escrow
+    SetEscrowOwner(#[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))] Pubkey),
+    /// Change a single field of the config
+    SetConfig(ConfigChange),
+}
+
+impl Default for AdminAction {
+    fn default() -> Self {
+        Self::SetEscrowOwner(Pubkey::default())
+    }
+}
+
+impl AdminAction {
+    /// Length of a packed action, a tag followed by its largest value padded with zeros
+    pub const LEN: usize = 34; // 1 + 1 + 32
+
+    fn pack_into(&self, output: &mut [u8; Self::LEN]) {
+        *output = [0; Self::LEN];
+        let (tag, value) = mut_array_refs![output, 1, 33];
+        match *self {
+            Self::SetEscrowOwner(new_owner) => {
+                tag[0] = 0;
+                value[..PUBKEY_BYTES].copy_from_slice(new_owner.as_ref());
+            }
+            Self::SetConfig(change) => {
+                tag[0] = 1;
+                let (field, value) = mut_array_refs![value, 1, PUBKEY_BYTES];
+                match change {
+                    ConfigChange::Governance(governance) => {
+                        field[0] = 0;
+                        value.copy_from_slice(governance.as_ref());
+                    }
+                    ConfigChange::FeeBps(fee_bps) => {
+                        field[0] = 1;
+                        value[..2].copy_from_slice(&fee_bps.bps().to_le_bytes());
+                    }
+                    ConfigChange::MinStake(min_stake) => {
+                        field[0] = 2;
+                        value[..8].copy_from_slice(&min_stake.to_le_bytes());
+                    }
+                    ConfigChange::DisputeWindow(dispute_window) => {
+                        field[0] = 3;
+                        value[..8].copy_from_slice(&dispute_window.to_le_bytes());
+                    }
+                    ConfigChange::TimelockDuration(timelock_duration) => {
+                        field[0] = 4;
+                        value[..8].copy_from_slice(&timelock_duration.to_le_bytes());
+                    }
+                    ConfigChange::RateLimitWindow(rate_limit_window) => {
+                        field[0] = 5;
+                        value[..8].copy_from_slice(&rate_limit_window.to_le_bytes());
+                    }
+                    ConfigChange::RateLimitMax(rate_limit_max) => {
+                        field[0] = 6;
+                        value[..4].copy_from_slice(&rate_limit_max.to_le_bytes());
+                    }
+                    ConfigChange::Guardian(guardian) => {
+                        field[0] = 7;
+                        value.copy_from_slice(guardian.as_ref());
+                    }
+                    ConfigChange::InstantOwnerChange(instant_owner_change) => {
+                        field[0] = 8;
+                        value[0] = u8::from(instant_owner_change);
+                    }
+                    ConfigChange::TimelockedFields(timelocked_fields) => {
+                        field[0] = 9;
+                        value[..4].copy_from_slice(&timelocked_fields.to_le_bytes());
+                    }
+                    ConfigChange::RewardTier(index, reward_tier) => {
+                        field[0] = 10;
+                        value[0] = index;
+                        value[1..9].copy_from_slice(&reward_tier.min_stake.to_le_bytes());
+                        value[9..11].copy_from_slice(&reward_tier.multiplier_bps.to_le_bytes());
+                    }
+                    ConfigChange::CancelGracePeriod(cancel_grace_period) => {
+                        field[0] = 11;
+                        value[..8].copy_from_slice(&cancel_grace_period.to_le_bytes());
+                    }
+                    ConfigChange::CancelFeeBps(cancel_fee_bps) => {
+                        field[0] = 12;
+                        value[..2].copy_from_slice(&cancel_fee_bps.bps().to_le_bytes());
+                    }
+                    ConfigChange::PrioritySurchargeBps(priority_surcharge_bps) => {
+                        field[0] = 13;
+                        value[..2].copy_from_slice(&priority_surcharge_bps.bps().to_le_bytes());
+                    }
+                    ConfigChange::PriorityClaimDelay(priority_claim_delay) => {
+                        field[0] = 14;
+                        value[..8].copy_from_slice(&priority_claim_delay.to_le_bytes());
+                    }
+                    ConfigChange::RoundingPolicy(rounding_policy) => {
+                        field[0] = 15;
+                        value[0] = u8::from(rounding_policy);
+                    }
+                    ConfigChange::MinDisbursement(min_disbursement) => {
+                        field[0] = 16;
+                        value[..8].copy_from_slice(&min_disbursement.to_le_bytes());
+                    }
+                    ConfigChange::ObhPrice(index, obh_price) => {
+                        field[0] = 17;
+                        value[0] = index;
+                        value[1..9].copy_from_slice(&obh_price.to_le_bytes());
+                    }
+                    ConfigChange::MaxPriceAge(max_price_age) => {
+                        field[0] = 18;
+                        value[..8].copy_from_slice(&max_price_age.to_le_bytes());
+                    }
+                    ConfigChange::MaxPriceConfidenceBps(max_price_confidence_bps) => {
+                        field[0] = 19;
+                        value[..2].copy_from_slice(&max_price_confidence_bps.bps().to_le_bytes());
+                    }
+                    ConfigChange::Compliance(compliance) => {
+                        field[0] = 20;
+                        value.copy_from_slice(compliance.as_ref());
+                    }
+                    ConfigChange::SweepDelay(sweep_delay) => {
+                        field[0] = 21;
+                        value[..8].copy_from_slice(&sweep_delay.to_le_bytes());
+                    }
+                    ConfigChange::IncidentMode(incident_mode) => {
+                        field[0] = 22;
+                        value[0] = u8::from(incident_mode);
+                    }
+                }
+            }
+        }
+    }
+
+    fn unpack_from(input: &[u8; Self::LEN]) -> Result<Self, ProgramError> {
+        let (tag, value) = array_refs![input, 1, 33];
+        Ok(match tag[0] {
+            0 => Self::SetEscrowOwner(Pubkey::new_from_array(*array_ref![value, 0, 32])),
+            1 => {
+                let (field, value) = array_refs![value, 1, PUBKEY_BYTES];
+                Self::SetConfig(match field[0] {
+                    0 => ConfigChange::Governance(Pubkey::new_from_array(*value)),
+                    1 => ConfigChange::FeeBps(unpack_rate(array_ref![value, 0, 2])?),
+                    2 => ConfigChange::MinStake(u64::from_le_bytes(*array_ref![value, 0, 8])),
+                    3 => ConfigChange::DisputeWindow(u64::from_le_bytes(*array_ref![value, 0, 8])),
+                    4 => {
+                        ConfigChange::TimelockDuration(u64::from_le_bytes(*array_ref![value, 0, 8]))
+                    }
+                    5 => {
+                        ConfigChange::RateLimitWindow(u64::from_le_bytes(*array_ref![value, 0, 8]))
+                    }
+                    6 => ConfigChange::RateLimitMax(u32::from_le_bytes(*array_ref![value, 0, 4])),
+                    7 => ConfigChange::Guardian(Pubkey::new_from_array(*value)),
+                    8 => ConfigChange::InstantOwnerChange(match value[0] {
+                        0 => false,
+                        1 => true,
+                        _ => {
+                            msg!("Queued instant owner change flag is invalid");
+                            return Err(ProgramError::InvalidAccountData);
+                        }
+                    }),
+                    9 => {
+                        ConfigChange::TimelockedFields(u32::from_le_bytes(*array_ref![value, 0, 4]))
+                    }
+                    10 => ConfigChange::RewardTier(
+                        value[0],
+                        RewardTier {
+                            min_stake: u64::from_le_bytes(*array_ref![value, 1, 8]),
+                            multiplier_bps: u16::from_le_bytes(*array_ref![value, 9, 2]),
+                        },
+                    ),
+                    11 => ConfigChange::CancelGracePeriod(u64::from_le_bytes(*array_ref![
+                        value, 0, 8
+                    ])),
+                    12 => ConfigChange::CancelFeeBps(unpack_rate(array_ref![value, 0, 2])?),
+                    13 => ConfigChange::PrioritySurchargeBps(unpack_rate(array_ref![value, 0, 2])?),
+                    14 => ConfigChange::PriorityClaimDelay(u64::from_le_bytes(*array_ref![
+                        value, 0, 8
+                    ])),
+                    15 => ConfigChange::RoundingPolicy(
+                        RoundingPolicy::try_from(value[0]).map_err(|_| {
+                            msg!("Queued rounding policy is invalid");
+                            ProgramError::InvalidAccountData
+                        })?,
+                    ),
+                    16 => {
+                        ConfigChange::MinDisbursement(u64::from_le_bytes(*array_ref![value, 0, 8]))
+                    }
+                    17 => ConfigChange::ObhPrice(
+                        value[0],
+                        u64::from_le_bytes(*array_ref![value, 1, 8]),
+                    ),
+                    18 => ConfigChange::MaxPriceAge(u64::from_le_bytes(*array_ref![value, 0, 8])),
+                    19 => {
+                        ConfigChange::MaxPriceConfidenceBps(unpack_rate(array_ref![value, 0, 2])?)
+                    }
+                    20 => ConfigChange::Compliance(Pubkey::new_from_array(*value)),
+                    21 => ConfigChange::SweepDelay(u64::from_le_bytes(*array_ref![value, 0, 8])),
+                    22 => ConfigChange::IncidentMode(IncidentMode::try_from(value[0]).map_err(
+                        |_| {
+                            msg!("Queued incident mode is invalid");
+                            ProgramError::InvalidAccountData
+                        },
+                    )?),
+                    _ => {
+                        msg!("Queued config change field is invalid");
+                        return Err(ProgramError::InvalidAccountData);
+                    }
+                })
+            }
+            _ => {
+                msg!("Queued action type is invalid");
+                return Err(ProgramError::InvalidAccountData);
+            }
+        })
+    }
+}
+
+/// Queued action state
+///
+/// Records an administrative action queued by the escrow's owner or the config's governance
+/// authority, which anyone can apply once the timelock has passed. The guardian of the config can
+/// cancel it until then.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QueuedAction {
+    /// Account type, must be QueuedActionV1 currently
+    pub account_type: AccountType,
+    /// Escrow or config the action applies to
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub target: Pubkey,
+    /// Identifier of the queued action, unique for the target
+    pub id: u64,
+    /// Escrow owner or governance authority that queued the action
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub authority: Pubkey,
+    /// Action to apply
+    pub action: AdminAction,
+    /// Unix timestamp the action was queued at
+    pub queued_at: UnixTimestamp,
+    /// Unix timestamp from which the action can be applied
+    pub executable_at: UnixTimestamp,
+    /// Unix timestamp the action was applied at, zero if it hasn't been applied
+    pub executed_at: UnixTimestamp,
+    /// Unix timestamp the guardian cancelled the action at, zero if it hasn't been cancelled
+    pub cancelled_at: UnixTimestamp,
+}
+
+impl QueuedAction {
+    /// Offset of the target in a packed queued action
+    pub const TARGET_OFFSET: usize = ACCOUNT_TYPE_OFFSET + 1;
+    /// Offset of the identifier in a packed queued action
+    pub const ID_OFFSET: usize = Self::TARGET_OFFSET + PUBKEY_BYTES;
+    /// Offset of the authority in a packed queued action
+    pub const AUTHORITY_OFFSET: usize = Self::ID_OFFSET + 8;
+    /// Offset of the action in a packed queued action
+    pub const ACTION_OFFSET: usize = Self::AUTHORITY_OFFSET + PUBKEY_BYTES;
+    /// Offset of the queueing timestamp in a packed queued action
+    pub const QUEUED_AT_OFFSET: usize = Self::ACTION_OFFSET + AdminAction::LEN;
+    /// Offset of the executable timestamp in a packed queued action
+    pub const EXECUTABLE_AT_OFFSET: usize = Self::QUEUED_AT_OFFSET + 8;
+    /// Offset of the execution timestamp in a packed queued action
+    pub const EXECUTED_AT_OFFSET: usize = Self::EXECUTABLE_AT_OFFSET + 8;
+    /// Offset of the cancellation timestamp in a packed queued action
+    pub const CANCELLED_AT_OFFSET: usize = Self::EXECUTED_AT_OFFSET + 8;
+
+    /// Create a queued action
+    pub fn new(params: InitQueuedActionParams) -> Self {
+        let mut queued_action = Self::default();
+        Self::init(&mut queued_action, params);
+        queued_action
+    }
+
+    /// Initialize a queued action
+    pub fn init(&mut self, params: InitQueuedActionParams) {
+        self.account_type = AccountType::QueuedActionV1;
+        self.target = params.target;
+        self.id = params.id;
+        self.authority = params.authority;
+        self.action = params.action;
+        self.queued_at = params.queued_at;
+        self.executable_at = params.executable_at;
+        self.executed_at = 0;
+        self.cancelled_at = 0;
+    }
+
+    /// Whether the action has been applied
+    pub fn is_executed(&self) -> bool {
+        self.executed_at != 0
+    }
+
+    /// Whether the guardian has cancelled the action
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled_at != 0
+    }
+}
+
+/// Initialize a queued action
+pub struct InitQueuedActionParams {
+    /// Escrow or config the action applies to
+    pub target: Pubkey,
+    /// Identifier of the queued action
+    pub id: u64,
+    /// Escrow owner or governance authority that queued the action
+    pub authority: Pubkey,
+    /// Action to apply
+    pub action: AdminAction,
+    /// Unix timestamp the action was queued at
+    pub queued_at: UnixTimestamp,
+    /// Unix timestamp from which the action can be applied
+    pub executable_at: UnixTimestamp,
+}
+
+impl Sealed for QueuedAction {}
+
+impl IsInitialized for QueuedAction {
+    fn is_initialized(&self) -> bool {
+        self.account_type != AccountType::Uninitialized
+    }
+}
+
+const QUEUED_ACTION_LEN: usize = 139; // 1 + 32 + 8 + 32 + 34 + 8 + 8 + 8 + 8
+const _: () = assert!(QueuedAction::CANCELLED_AT_OFFSET + 8 == QUEUED_ACTION_LEN);
+impl Pack for QueuedAction {
+    const LEN: usize = QUEUED_ACTION_LEN;
+
+    fn pack_into_slice(&self, output: &mut [u8]) {
+        let output = array_mut_ref![output, 0, QUEUED_ACTION_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (
+            account_type,
+            target,
+            id,
+            authority,
+            action,
+            queued_at,
+            executable_at,
+            executed_at,
+            cancelled_at,
+        ) = mut_array_refs![
+            output,
+            1,
+            PUBKEY_BYTES,
+            8,
+            PUBKEY_BYTES,
+            AdminAction::LEN,
+            8,
+            8,
+            8,
+            8
+        ];
+
+        *account_type = u8::from(self.account_type).to_le_bytes();
+        target.copy_from_slice(&self.target.to_bytes());
+        *id = self.id.to_le_bytes();
+        authority.copy_from_slice(&self.authority.to_bytes());
+        self.action.pack_into(action);
+        *queued_at = self.queued_at.to_le_bytes();
+        *executable_at = self.executable_at.to_le_bytes();
+        *executed_at = self.executed_at.to_le_bytes();
+        *cancelled_at = self.cancelled_at.to_le_bytes();
+    }
+
+    fn unpack_from_slice(input: &[u8]) -> Result<Self, ProgramError> {
+        let input = array_ref![input, 0, QUEUED_ACTION_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (
+            account_type,
+            target,
+            id,
+            authority,
+            action,
+            queued_at,
+            executable_at,
+            executed_at,
+            cancelled_at,
+        ) = array_refs![
+            input,
+            1,
+            PUBKEY_BYTES,
+            8,
+            PUBKEY_BYTES,
+            AdminAction::LEN,
+            8,
+            8,
+            8,
+            8
+        ];
+
+        let account_type = AccountType::from(u8::from_le_bytes(*account_type));
+        if account_type != AccountType::QueuedActionV1 {
+            msg!("Queued action account type is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self {
+            account_type,
+            target: Pubkey::new_from_array(*target),
+            id: u64::from_le_bytes(*id),
+            authority: Pubkey::new_from_array(*authority),
+            action: AdminAction::unpack_from(action)?,
+            queued_at: UnixTimestamp::from_le_bytes(*queued_at),
+            executable_at: UnixTimestamp::from_le_bytes(*executable_at),
+            executed_at: UnixTimestamp::from_le_bytes(*executed_at),
+            cancelled_at: UnixTimestamp::from_le_bytes(*cancelled_at),
+        })
+    }
+}
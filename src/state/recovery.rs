@@ -0,0 +1,219 @@
+use {
+    super::*,
+    arrayref::{array_mut_ref, array_ref, array_refs, mut_array_refs},
+    solana_program::{
+        clock::UnixTimestamp,
+        msg,
+        program_error::ProgramError,
+        program_pack::{IsInitialized, Pack, Sealed},
+        pubkey::{Pubkey, PUBKEY_BYTES},
+    },
+};
+
+/// Maximum number of guardians on the recovery council of each escrow
+pub const MAX_RECOVERY_GUARDIANS: usize = 10;
+/// Seconds a recovery waits after reaching its threshold of approvals before the escrow owner can
+/// be rotated, giving the owner time to replace the council if they still hold their key
+pub const RECOVERY_DELAY: u64 = 7 * 24 * 60 * 60;
+
+/// Recovery state
+///
+/// Recovery council set by the escrow owner, whose guardians can rotate the owner if its key is
+/// lost. Each guardian votes for a new owner, and once the threshold of guardians agree, the new
+/// owner can be set after `RECOVERY_DELAY`.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Recovery {
+    /// Account type, must be RecoveryV1 currently
+    pub account_type: AccountType,
+    /// Escrow the recovery council belongs to
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub escrow: Pubkey,
+    /// Number of guardians that must vote for the same new owner
+    pub threshold: u8,
+    /// Guardians on the council, at most `MAX_RECOVERY_GUARDIANS`
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey::vec"))]
+    pub guardians: Vec<Pubkey>,
+    /// New owner each guardian has voted for, in the same order as the guardians, or the default
+    /// pubkey if the guardian hasn't voted
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey::vec"))]
+    pub votes: Vec<Pubkey>,
+    /// New owner of the pending recovery, or the default pubkey if there is none
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub new_owner: Pubkey,
+    /// Unix timestamp from which the pending recovery can be executed, zero if there is none
+    pub executable_at: UnixTimestamp,
+}
+
+impl Recovery {
+    /// Offset of the escrow in a packed recovery
+    pub const ESCROW_OFFSET: usize = ACCOUNT_TYPE_OFFSET + 1;
+    /// Offset of the threshold in a packed recovery
+    pub const THRESHOLD_OFFSET: usize = Self::ESCROW_OFFSET + PUBKEY_BYTES;
+    /// Offset of the number of guardians in a packed recovery
+    pub const GUARDIANS_LEN_OFFSET: usize = Self::THRESHOLD_OFFSET + 1;
+    /// Offset of the guardians in a packed recovery
+    pub const GUARDIANS_OFFSET: usize = Self::GUARDIANS_LEN_OFFSET + 1;
+    /// Offset of the votes in a packed recovery
+    pub const VOTES_OFFSET: usize = Self::GUARDIANS_OFFSET + GUARDIANS_LEN;
+    /// Offset of the pending new owner in a packed recovery
+    pub const NEW_OWNER_OFFSET: usize = Self::VOTES_OFFSET + GUARDIANS_LEN;
+    /// Offset of the executable timestamp in a packed recovery
+    pub const EXECUTABLE_AT_OFFSET: usize = Self::NEW_OWNER_OFFSET + PUBKEY_BYTES;
+
+    /// Create a recovery
+    pub fn new(params: InitRecoveryParams) -> Self {
+        let mut recovery = Self::default();
+        Self::init(&mut recovery, params);
+        recovery
+    }
+
+    /// Initialize a recovery with no votes
+    pub fn init(&mut self, params: InitRecoveryParams) {
+        self.account_type = AccountType::RecoveryV1;
+        self.escrow = params.escrow;
+        self.threshold = params.threshold;
+        self.votes = vec![Pubkey::default(); params.guardians.len()];
+        self.guardians = params.guardians;
+        self.new_owner = Pubkey::default();
+        self.executable_at = 0;
+    }
+
+    /// Index of a guardian on the council
+    pub fn guardian_index(&self, guardian: &Pubkey) -> Option<usize> {
+        self.guardians.iter().position(|other| other == guardian)
+    }
+
+    /// Number of guardians that have voted for a new owner
+    pub fn approvals(&self, new_owner: &Pubkey) -> usize {
+        self.votes.iter().filter(|vote| *vote == new_owner).count()
+    }
+
+    /// Whether the threshold of guardians have agreed on a new owner
+    pub fn is_pending(&self) -> bool {
+        self.executable_at != 0
+    }
+}
+
+/// Initialize a recovery
+pub struct InitRecoveryParams {
+    /// Escrow the recovery council belongs to
+    pub escrow: Pubkey,
+    /// Number of guardians that must vote for the same new owner
+    pub threshold: u8,
+    /// Guardians on the council
+    pub guardians: Vec<Pubkey>,
+}
+
+impl Sealed for Recovery {}
+
+impl IsInitialized for Recovery {
+    fn is_initialized(&self) -> bool {
+        self.account_type != AccountType::Uninitialized
+    }
+}
+
+const GUARDIANS_LEN: usize = MAX_RECOVERY_GUARDIANS * PUBKEY_BYTES;
+const RECOVERY_LEN: usize = 715; // 1 + 32 + 1 + 1 + 10 * 32 + 10 * 32 + 32 + 8
+const _: () = assert!(Recovery::EXECUTABLE_AT_OFFSET + 8 == RECOVERY_LEN);
+impl Pack for Recovery {
+    const LEN: usize = RECOVERY_LEN;
+
+    fn pack_into_slice(&self, output: &mut [u8]) {
+        let output = array_mut_ref![output, 0, RECOVERY_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (
+            account_type,
+            escrow,
+            threshold,
+            guardians_len,
+            guardians,
+            votes,
+            new_owner,
+            executable_at,
+        ) = mut_array_refs![
+            output,
+            1,
+            PUBKEY_BYTES,
+            1,
+            1,
+            GUARDIANS_LEN,
+            GUARDIANS_LEN,
+            PUBKEY_BYTES,
+            8
+        ];
+
+        *account_type = u8::from(self.account_type).to_le_bytes();
+        escrow.copy_from_slice(&self.escrow.to_bytes());
+        *threshold = self.threshold.to_le_bytes();
+        *guardians_len = (self.guardians.len() as u8).to_le_bytes();
+        guardians.fill(0);
+        for (output, guardian) in guardians
+            .chunks_exact_mut(PUBKEY_BYTES)
+            .zip(&self.guardians)
+        {
+            output.copy_from_slice(guardian.as_ref());
+        }
+        votes.fill(0);
+        for (output, vote) in votes.chunks_exact_mut(PUBKEY_BYTES).zip(&self.votes) {
+            output.copy_from_slice(vote.as_ref());
+        }
+        new_owner.copy_from_slice(&self.new_owner.to_bytes());
+        *executable_at = self.executable_at.to_le_bytes();
+    }
+
+    fn unpack_from_slice(input: &[u8]) -> Result<Self, ProgramError> {
+        let input = array_ref![input, 0, RECOVERY_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (
+            account_type,
+            escrow,
+            threshold,
+            guardians_len,
+            guardians,
+            votes,
+            new_owner,
+            executable_at,
+        ) = array_refs![
+            input,
+            1,
+            PUBKEY_BYTES,
+            1,
+            1,
+            GUARDIANS_LEN,
+            GUARDIANS_LEN,
+            PUBKEY_BYTES,
+            8
+        ];
+
+        let account_type = AccountType::from(u8::from_le_bytes(*account_type));
+        if account_type != AccountType::RecoveryV1 {
+            msg!("Recovery account type is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let guardians_len = u8::from_le_bytes(*guardians_len) as usize;
+        if guardians_len > MAX_RECOVERY_GUARDIANS {
+            msg!("Recovery guardians length is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let unpack_pubkeys = |input: &[u8; GUARDIANS_LEN]| -> Vec<Pubkey> {
+            input
+                .chunks_exact(PUBKEY_BYTES)
+                .take(guardians_len)
+                .map(|pubkey| Pubkey::new_from_array(*array_ref![pubkey, 0, PUBKEY_BYTES]))
+                .collect()
+        };
+
+        Ok(Self {
+            account_type,
+            escrow: Pubkey::new_from_array(*escrow),
+            threshold: u8::from_le_bytes(*threshold),
+            guardians: unpack_pubkeys(guardians),
+            votes: unpack_pubkeys(votes),
+            new_owner: Pubkey::new_from_array(*new_owner),
+            executable_at: UnixTimestamp::from_le_bytes(*executable_at),
+        })
+    }
+}
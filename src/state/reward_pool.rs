@@ -0,0 +1,135 @@
+use {
+    super::*,
+    crate::math::Decimal,
+    arrayref::{array_mut_ref, array_ref, array_refs, mut_array_refs},
+    solana_program::{
+        clock::Epoch,
+        msg,
+        program_error::ProgramError,
+        program_pack::{IsInitialized, Pack, Sealed},
+        pubkey::{Pubkey, PUBKEY_BYTES},
+    },
+};
+
+/// Reward pool state
+///
+/// Rewards of an escrow for an epoch, held in the reward pool's associated token account. The pool
+/// is funded until the epoch ends, while nodes record the work of the jobs they're paid for, and
+/// then each node can claim a share of the rewards proportional to its work.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RewardPool {
+    /// Account type, must be RewardPoolV1 currently
+    pub account_type: AccountType,
+    /// Escrow the rewards are paid for
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub escrow: Pubkey,
+    /// Epoch the rewards are paid for
+    pub epoch: Epoch,
+    /// Amount of tokens funded
+    pub amount: u64,
+    /// Work recorded by every node during the epoch
+    pub total_work: u64,
+    /// Amount of tokens claimed by nodes
+    pub claimed: u64,
+}
+
+impl RewardPool {
+    /// Offset of the escrow in a packed reward pool
+    pub const ESCROW_OFFSET: usize = ACCOUNT_TYPE_OFFSET + 1;
+    /// Offset of the epoch in a packed reward pool
+    pub const EPOCH_OFFSET: usize = Self::ESCROW_OFFSET + PUBKEY_BYTES;
+    /// Offset of the amount in a packed reward pool
+    pub const AMOUNT_OFFSET: usize = Self::EPOCH_OFFSET + 8;
+    /// Offset of the total work in a packed reward pool
+    pub const TOTAL_WORK_OFFSET: usize = Self::AMOUNT_OFFSET + 8;
+    /// Offset of the claimed amount in a packed reward pool
+    pub const CLAIMED_OFFSET: usize = Self::TOTAL_WORK_OFFSET + 8;
+
+    /// Create a reward pool
+    pub fn new(params: InitRewardPoolParams) -> Self {
+        let mut reward_pool = Self::default();
+        Self::init(&mut reward_pool, params);
+        reward_pool
+    }
+
+    /// Initialize a reward pool with no rewards or work
+    pub fn init(&mut self, params: InitRewardPoolParams) {
+        self.account_type = AccountType::RewardPoolV1;
+        self.escrow = params.escrow;
+        self.epoch = params.epoch;
+        self.amount = 0;
+        self.total_work = 0;
+        self.claimed = 0;
+    }
+
+    /// Share of the rewards for an amount of work recorded during the epoch, rounded down
+    pub fn reward(&self, work: u64) -> Option<u64> {
+        if self.total_work == 0 {
+            return Some(0);
+        }
+        Decimal::from(self.amount)
+            .try_mul(work)
+            .and_then(|reward| reward.try_div(self.total_work))
+            .and_then(Decimal::try_floor_u64)
+            .ok()
+    }
+}
+
+/// Initialize a reward pool
+pub struct InitRewardPoolParams {
+    /// Escrow the rewards are paid for
+    pub escrow: Pubkey,
+    /// Epoch the rewards are paid for
+    pub epoch: Epoch,
+}
+
+impl Sealed for RewardPool {}
+
+impl IsInitialized for RewardPool {
+    fn is_initialized(&self) -> bool {
+        self.account_type != AccountType::Uninitialized
+    }
+}
+
+const REWARD_POOL_LEN: usize = 65; // 1 + 32 + 8 + 8 + 8 + 8
+const _: () = assert!(RewardPool::CLAIMED_OFFSET + 8 == REWARD_POOL_LEN);
+impl Pack for RewardPool {
+    const LEN: usize = REWARD_POOL_LEN;
+
+    fn pack_into_slice(&self, output: &mut [u8]) {
+        let output = array_mut_ref![output, 0, REWARD_POOL_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (account_type, escrow, epoch, amount, total_work, claimed) =
+            mut_array_refs![output, 1, PUBKEY_BYTES, 8, 8, 8, 8];
+
+        *account_type = u8::from(self.account_type).to_le_bytes();
+        escrow.copy_from_slice(&self.escrow.to_bytes());
+        *epoch = self.epoch.to_le_bytes();
+        *amount = self.amount.to_le_bytes();
+        *total_work = self.total_work.to_le_bytes();
+        *claimed = self.claimed.to_le_bytes();
+    }
+
+    fn unpack_from_slice(input: &[u8]) -> Result<Self, ProgramError> {
+        let input = array_ref![input, 0, REWARD_POOL_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (account_type, escrow, epoch, amount, total_work, claimed) =
+            array_refs![input, 1, PUBKEY_BYTES, 8, 8, 8, 8];
+
+        let account_type = AccountType::from(u8::from_le_bytes(*account_type));
+        if account_type != AccountType::RewardPoolV1 {
+            msg!("Reward pool account type is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self {
+            account_type,
+            escrow: Pubkey::new_from_array(*escrow),
+            epoch: Epoch::from_le_bytes(*epoch),
+            amount: u64::from_le_bytes(*amount),
+            total_work: u64::from_le_bytes(*total_work),
+            claimed: u64::from_le_bytes(*claimed),
+        })
+    }
+}
@@ -0,0 +1,138 @@
+use {
+    super::*,
+    arrayref::{array_mut_ref, array_ref, array_refs, mut_array_refs},
+    solana_program::{
+        clock::Slot,
+        msg,
+        program_error::ProgramError,
+        program_pack::{IsInitialized, Pack, Sealed},
+        pubkey::{Pubkey, PUBKEY_BYTES},
+    },
+};
+
+/// Bit of a session key's instruction mask allowing it to top up an existing job with `FundJob`
+pub const SESSION_KEY_FUND_JOB: u64 = 1 << 2;
+/// Bit of a session key's instruction mask allowing it to challenge a job's result with
+/// `ChallengeResult`
+pub const SESSION_KEY_CHALLENGE_RESULT: u64 = 1 << 13;
+/// Instruction mask bits a session key can be allowed, each the bit of the instruction's tag
+pub const SESSION_KEY_INSTRUCTIONS: u64 = SESSION_KEY_FUND_JOB | SESSION_KEY_CHALLENGE_RESULT;
+
+/// Session key state
+///
+/// Short-lived key that a job authority registers for an escrow, which is accepted in place of
+/// the authority's signature for the instructions in its mask until its expiry slot.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SessionKey {
+    /// Account type, must be SessionKeyV1 currently
+    pub account_type: AccountType,
+    /// Escrow of the jobs the session key manages
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub escrow: Pubkey,
+    /// Job authority that registered the session key
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub authority: Pubkey,
+    /// Session key accepted as a signer in place of the authority
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub session_key: Pubkey,
+    /// Last slot the session key can be used in
+    pub expiry_slot: Slot,
+    /// Instructions the session key can sign, see `SESSION_KEY_INSTRUCTIONS`
+    pub instruction_mask: u64,
+}
+
+impl SessionKey {
+    /// Offset of the escrow in a packed session key
+    pub const ESCROW_OFFSET: usize = ACCOUNT_TYPE_OFFSET + 1;
+    /// Offset of the authority in a packed session key
+    pub const AUTHORITY_OFFSET: usize = Self::ESCROW_OFFSET + PUBKEY_BYTES;
+    /// Offset of the session key in a packed session key
+    pub const SESSION_KEY_OFFSET: usize = Self::AUTHORITY_OFFSET + PUBKEY_BYTES;
+
+    /// Create a session key
+    pub fn new(params: InitSessionKeyParams) -> Self {
+        let mut session_key = Self::default();
+        Self::init(&mut session_key, params);
+        session_key
+    }
+
+    /// Initialize a session key
+    pub fn init(&mut self, params: InitSessionKeyParams) {
+        self.account_type = AccountType::SessionKeyV1;
+        self.escrow = params.escrow;
+        self.authority = params.authority;
+        self.session_key = params.session_key;
+        self.expiry_slot = params.expiry_slot;
+        self.instruction_mask = params.instruction_mask;
+    }
+
+    /// Check if the session key can sign an instruction, given its mask bit, in a slot
+    pub fn allows(&self, instruction: u64, slot: Slot) -> bool {
+        slot <= self.expiry_slot && self.instruction_mask & instruction != 0
+    }
+}
+
+/// Initialize a session key
+pub struct InitSessionKeyParams {
+    /// Escrow of the jobs the session key manages
+    pub escrow: Pubkey,
+    /// Job authority that registered the session key
+    pub authority: Pubkey,
+    /// Session key accepted as a signer in place of the authority
+    pub session_key: Pubkey,
+    /// Last slot the session key can be used in
+    pub expiry_slot: Slot,
+    /// Instructions the session key can sign
+    pub instruction_mask: u64,
+}
+
+impl Sealed for SessionKey {}
+
+impl IsInitialized for SessionKey {
+    fn is_initialized(&self) -> bool {
+        self.account_type != AccountType::Uninitialized
+    }
+}
+
+const SESSION_KEY_LEN: usize = 113; // 1 + 32 + 32 + 32 + 8 + 8
+const _: () = assert!(SessionKey::SESSION_KEY_OFFSET + PUBKEY_BYTES + 16 == SESSION_KEY_LEN);
+impl Pack for SessionKey {
+    const LEN: usize = SESSION_KEY_LEN;
+
+    fn pack_into_slice(&self, output: &mut [u8]) {
+        let output = array_mut_ref![output, 0, SESSION_KEY_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (account_type, escrow, authority, session_key, expiry_slot, instruction_mask) =
+            mut_array_refs![output, 1, PUBKEY_BYTES, PUBKEY_BYTES, PUBKEY_BYTES, 8, 8];
+
+        *account_type = u8::from(self.account_type).to_le_bytes();
+        escrow.copy_from_slice(&self.escrow.to_bytes());
+        authority.copy_from_slice(&self.authority.to_bytes());
+        session_key.copy_from_slice(&self.session_key.to_bytes());
+        *expiry_slot = self.expiry_slot.to_le_bytes();
+        *instruction_mask = self.instruction_mask.to_le_bytes();
+    }
+
+    fn unpack_from_slice(input: &[u8]) -> Result<Self, ProgramError> {
+        let input = array_ref![input, 0, SESSION_KEY_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (account_type, escrow, authority, session_key, expiry_slot, instruction_mask) =
+            array_refs![input, 1, PUBKEY_BYTES, PUBKEY_BYTES, PUBKEY_BYTES, 8, 8];
+
+        let account_type = AccountType::from(u8::from_le_bytes(*account_type));
+        if account_type != AccountType::SessionKeyV1 {
+            msg!("Session key account type is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self {
+            account_type,
+            escrow: Pubkey::new_from_array(*escrow),
+            authority: Pubkey::new_from_array(*authority),
+            session_key: Pubkey::new_from_array(*session_key),
+            expiry_slot: Slot::from_le_bytes(*expiry_slot),
+            instruction_mask: u64::from_le_bytes(*instruction_mask),
+        })
+    }
+}
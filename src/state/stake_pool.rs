@@ -0,0 +1,151 @@
+use {
+    super::*,
+    crate::math::Decimal,
+    arrayref::{array_mut_ref, array_ref, array_refs, mut_array_refs},
+    solana_program::{
+        msg,
+        program_error::ProgramError,
+        program_pack::{IsInitialized, Pack, Sealed},
+        pubkey::{Pubkey, PUBKEY_BYTES},
+    },
+};
+
+/// Stake pool state
+///
+/// Liquid stake of an escrow, held in the stake pool's associated token account. Stakers receive
+/// receipt tokens minted by the pool, which are burned to withdraw the stake. Rewards deposited
+/// into the pool raise the amount of stake each receipt token can be exchanged for.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StakePool {
+    /// Account type, must be StakePoolV1 currently
+    pub account_type: AccountType,
+    /// Escrow the tokens are staked with
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub escrow: Pubkey,
+    /// Mint of the staked tokens
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub token_mint: Pubkey,
+    /// Mint of the receipt tokens
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub receipt_mint: Pubkey,
+    /// Amount of tokens staked, including deposited rewards
+    pub total_staked: u64,
+    /// Amount of receipt tokens minted and not yet burned
+    pub receipt_supply: u64,
+}
+
+impl StakePool {
+    /// Offset of the escrow in a packed stake pool
+    pub const ESCROW_OFFSET: usize = ACCOUNT_TYPE_OFFSET + 1;
+    /// Offset of the token mint in a packed stake pool
+    pub const TOKEN_MINT_OFFSET: usize = Self::ESCROW_OFFSET + PUBKEY_BYTES;
+    /// Offset of the receipt mint in a packed stake pool
+    pub const RECEIPT_MINT_OFFSET: usize = Self::TOKEN_MINT_OFFSET + PUBKEY_BYTES;
+    /// Offset of the total staked amount in a packed stake pool
+    pub const TOTAL_STAKED_OFFSET: usize = Self::RECEIPT_MINT_OFFSET + PUBKEY_BYTES;
+    /// Offset of the receipt supply in a packed stake pool
+    pub const RECEIPT_SUPPLY_OFFSET: usize = Self::TOTAL_STAKED_OFFSET + 8;
+
+    /// Create a stake pool
+    pub fn new(params: InitStakePoolParams) -> Self {
+        let mut stake_pool = Self::default();
+        Self::init(&mut stake_pool, params);
+        stake_pool
+    }
+
+    /// Initialize a stake pool with no stake
+    pub fn init(&mut self, params: InitStakePoolParams) {
+        self.account_type = AccountType::StakePoolV1;
+        self.escrow = params.escrow;
+        self.token_mint = params.token_mint;
+        self.receipt_mint = params.receipt_mint;
+        self.total_staked = 0;
+        self.receipt_supply = 0;
+    }
+
+    /// Amount of receipt tokens minted for staking an amount of tokens, rounded down. Receipts are
+    /// minted one to one while the pool is empty.
+    pub fn receipt_amount(&self, amount: u64) -> Option<u64> {
+        if self.receipt_supply == 0 || self.total_staked == 0 {
+            return Some(amount);
+        }
+        Decimal::from(amount)
+            .try_mul(self.receipt_supply)
+            .and_then(|receipt_amount| receipt_amount.try_div(self.total_staked))
+            .and_then(Decimal::try_floor_u64)
+            .ok()
+    }
+
+    /// Amount of tokens withdrawn for burning an amount of receipt tokens, rounded down
+    pub fn stake_amount(&self, receipt_amount: u64) -> Option<u64> {
+        if self.receipt_supply == 0 {
+            return Some(0);
+        }
+        Decimal::from(receipt_amount)
+            .try_mul(self.total_staked)
+            .and_then(|amount| amount.try_div(self.receipt_supply))
+            .and_then(Decimal::try_floor_u64)
+            .ok()
+    }
+}
+
+/// Initialize a stake pool
+pub struct InitStakePoolParams {
+    /// Escrow the tokens are staked with
+    pub escrow: Pubkey,
+    /// Mint of the staked tokens
+    pub token_mint: Pubkey,
+    /// Mint of the receipt tokens
+    pub receipt_mint: Pubkey,
+}
+
+impl Sealed for StakePool {}
+
+impl IsInitialized for StakePool {
+    fn is_initialized(&self) -> bool {
+        self.account_type != AccountType::Uninitialized
+    }
+}
+
+const STAKE_POOL_LEN: usize = 113; // 1 + 32 + 32 + 32 + 8 + 8
+const _: () = assert!(StakePool::RECEIPT_SUPPLY_OFFSET + 8 == STAKE_POOL_LEN);
+impl Pack for StakePool {
+    const LEN: usize = STAKE_POOL_LEN;
+
+    fn pack_into_slice(&self, output: &mut [u8]) {
+        let output = array_mut_ref![output, 0, STAKE_POOL_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (account_type, escrow, token_mint, receipt_mint, total_staked, receipt_supply) =
+            mut_array_refs![output, 1, PUBKEY_BYTES, PUBKEY_BYTES, PUBKEY_BYTES, 8, 8];
+
+        *account_type = u8::from(self.account_type).to_le_bytes();
+        escrow.copy_from_slice(&self.escrow.to_bytes());
+        token_mint.copy_from_slice(&self.token_mint.to_bytes());
+        receipt_mint.copy_from_slice(&self.receipt_mint.to_bytes());
+        *total_staked = self.total_staked.to_le_bytes();
+        *receipt_supply = self.receipt_supply.to_le_bytes();
+    }
+
+    fn unpack_from_slice(input: &[u8]) -> Result<Self, ProgramError> {
+        let input = array_ref![input, 0, STAKE_POOL_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (account_type, escrow, token_mint, receipt_mint, total_staked, receipt_supply) =
+            array_refs![input, 1, PUBKEY_BYTES, PUBKEY_BYTES, PUBKEY_BYTES, 8, 8];
+
+        let account_type = AccountType::from(u8::from_le_bytes(*account_type));
+        if account_type != AccountType::StakePoolV1 {
+            msg!("Stake pool account type is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self {
+            account_type,
+            escrow: Pubkey::new_from_array(*escrow),
+            token_mint: Pubkey::new_from_array(*token_mint),
+            receipt_mint: Pubkey::new_from_array(*receipt_mint),
+            total_staked: u64::from_le_bytes(*total_staked),
+            receipt_supply: u64::from_le_bytes(*receipt_supply),
+        })
+    }
+}
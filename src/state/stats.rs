@@ -0,0 +1,282 @@
+use {
+    super::*,
+    arrayref::{array_mut_ref, array_ref, array_refs, mut_array_refs},
+    solana_program::{
+        clock::Epoch,
+        msg,
+        program_error::ProgramError,
+        program_pack::{IsInitialized, Pack, Sealed},
+        pubkey::{Pubkey, PUBKEY_BYTES},
+    },
+};
+
+/// Flow of tokens recorded in the stats of an escrow
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TokenFlow {
+    /// Tokens funded into the escrow or its isolated jobs
+    Escrowed,
+    /// Tokens burned from the escrow's treasury
+    Burned,
+    /// Tokens emitted as rewards into the escrow's reward pools
+    Emitted,
+    /// Tokens disbursed or paid out of the escrow or its isolated jobs
+    Disbursed,
+}
+
+/// Stats state
+///
+/// Burn-mint-equilibrium statistics of an escrow, the amounts of tokens that flowed through it in
+/// total and during its latest epoch. They're recorded by the instructions the stats account is
+/// appended to, so they only cover the flows of transactions that included it.
+///
+/// The lifetime totals were widened to 128 bits by appending their high 64 bits to the original
+/// layout, which is `Stats::LEGACY_LEN` bytes long. Stats created before then are unpacked with
+/// the high bits set to zero, and can only record lifetime totals that fit in 64 bits.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Stats {
+    /// Account type, must be StatsV1 currently
+    pub account_type: AccountType,
+    /// Escrow the stats are recorded for
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub escrow: Pubkey,
+    /// Epoch a flow was last recorded in
+    pub epoch: Epoch,
+    /// Amount of tokens escrowed over the escrow's lifetime
+    pub total_escrowed: u128,
+    /// Amount of tokens burned over the escrow's lifetime
+    pub total_burned: u128,
+    /// Amount of tokens emitted as rewards over the escrow's lifetime
+    pub total_emitted: u128,
+    /// Amount of tokens disbursed over the escrow's lifetime
+    pub total_disbursed: u128,
+    /// Amount of tokens escrowed during the epoch
+    pub epoch_escrowed: u64,
+    /// Amount of tokens burned during the epoch
+    pub epoch_burned: u64,
+    /// Amount of tokens emitted as rewards during the epoch
+    pub epoch_emitted: u64,
+    /// Amount of tokens disbursed during the epoch
+    pub epoch_disbursed: u64,
+}
+
+impl Stats {
+    /// Offset of the escrow in packed stats
+    pub const ESCROW_OFFSET: usize = ACCOUNT_TYPE_OFFSET + 1;
+    /// Offset of the epoch in packed stats
+    pub const EPOCH_OFFSET: usize = Self::ESCROW_OFFSET + PUBKEY_BYTES;
+    /// Offset of the low 64 bits of the lifetime amount escrowed in packed stats
+    pub const TOTAL_ESCROWED_OFFSET: usize = Self::EPOCH_OFFSET + 8;
+    /// Offset of the low 64 bits of the lifetime amount burned in packed stats
+    pub const TOTAL_BURNED_OFFSET: usize = Self::TOTAL_ESCROWED_OFFSET + 8;
+    /// Offset of the low 64 bits of the lifetime amount emitted in packed stats
+    pub const TOTAL_EMITTED_OFFSET: usize = Self::TOTAL_BURNED_OFFSET + 8;
+    /// Offset of the low 64 bits of the lifetime amount disbursed in packed stats
+    pub const TOTAL_DISBURSED_OFFSET: usize = Self::TOTAL_EMITTED_OFFSET + 8;
+    /// Offset of the amount escrowed during the epoch in packed stats
+    pub const EPOCH_ESCROWED_OFFSET: usize = Self::TOTAL_DISBURSED_OFFSET + 8;
+    /// Offset of the amount burned during the epoch in packed stats
+    pub const EPOCH_BURNED_OFFSET: usize = Self::EPOCH_ESCROWED_OFFSET + 8;
+    /// Offset of the amount emitted during the epoch in packed stats
+    pub const EPOCH_EMITTED_OFFSET: usize = Self::EPOCH_BURNED_OFFSET + 8;
+    /// Offset of the amount disbursed during the epoch in packed stats
+    pub const EPOCH_DISBURSED_OFFSET: usize = Self::EPOCH_EMITTED_OFFSET + 8;
+    /// Offset of the high 64 bits of the lifetime amount escrowed in packed stats
+    pub const TOTAL_ESCROWED_HIGH_OFFSET: usize = Self::EPOCH_DISBURSED_OFFSET + 8;
+    /// Offset of the high 64 bits of the lifetime amount burned in packed stats
+    pub const TOTAL_BURNED_HIGH_OFFSET: usize = Self::TOTAL_ESCROWED_HIGH_OFFSET + 8;
+    /// Offset of the high 64 bits of the lifetime amount emitted in packed stats
+    pub const TOTAL_EMITTED_HIGH_OFFSET: usize = Self::TOTAL_BURNED_HIGH_OFFSET + 8;
+    /// Offset of the high 64 bits of the lifetime amount disbursed in packed stats
+    pub const TOTAL_DISBURSED_HIGH_OFFSET: usize = Self::TOTAL_EMITTED_HIGH_OFFSET + 8;
+
+    /// Length of stats created before the lifetime totals were widened
+    pub const LEGACY_LEN: usize = Self::TOTAL_ESCROWED_HIGH_OFFSET;
+
+    /// Create stats
+    pub fn new(params: InitStatsParams) -> Self {
+        let mut stats = Self::default();
+        Self::init(&mut stats, params);
+        stats
+    }
+
+    /// Initialize stats with no recorded flows
+    pub fn init(&mut self, params: InitStatsParams) {
+        self.account_type = AccountType::StatsV1;
+        self.escrow = params.escrow;
+        self.epoch = params.epoch;
+        self.total_escrowed = 0;
+        self.total_burned = 0;
+        self.total_emitted = 0;
+        self.total_disbursed = 0;
+        self.epoch_escrowed = 0;
+        self.epoch_burned = 0;
+        self.epoch_emitted = 0;
+        self.epoch_disbursed = 0;
+    }
+
+    /// Record an amount of tokens that flowed through the escrow in an epoch, starting new epoch
+    /// totals if flows were last recorded in an earlier epoch, or return None on overflow
+    pub fn record(&mut self, epoch: Epoch, flow: TokenFlow, amount: u64) -> Option<()> {
+        if epoch != self.epoch {
+            self.epoch = epoch;
+            self.epoch_escrowed = 0;
+            self.epoch_burned = 0;
+            self.epoch_emitted = 0;
+            self.epoch_disbursed = 0;
+        }
+        let (total, epoch_total) = match flow {
+            TokenFlow::Escrowed => (&mut self.total_escrowed, &mut self.epoch_escrowed),
+            TokenFlow::Burned => (&mut self.total_burned, &mut self.epoch_burned),
+            TokenFlow::Emitted => (&mut self.total_emitted, &mut self.epoch_emitted),
+            TokenFlow::Disbursed => (&mut self.total_disbursed, &mut self.epoch_disbursed),
+        };
+        *total = total.checked_add(u128::from(amount))?;
+        *epoch_total = epoch_total.checked_add(amount)?;
+        Some(())
+    }
+
+    /// Unpack stats from account data, which may have the legacy length
+    pub fn unpack_account(input: &[u8]) -> Result<Self, ProgramError> {
+        unpack_appended(input, &[Self::LEGACY_LEN])
+    }
+
+    /// Pack stats into account data, which may have the legacy length if none of the lifetime
+    /// totals exceed 64 bits
+    pub fn pack_account(stats: Self, output: &mut [u8]) -> Result<(), ProgramError> {
+        pack_appended(stats, output, &[Self::LEGACY_LEN])
+    }
+}
+
+/// Initialize stats
+pub struct InitStatsParams {
+    /// Escrow the stats are recorded for
+    pub escrow: Pubkey,
+    /// Epoch the stats are created in
+    pub epoch: Epoch,
+}
+
+impl Sealed for Stats {}
+
+impl IsInitialized for Stats {
+    fn is_initialized(&self) -> bool {
+        self.account_type != AccountType::Uninitialized
+    }
+}
+
+const STATS_LEN: usize = 137; // 1 + 32 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8
+const _: () = assert!(Stats::TOTAL_DISBURSED_HIGH_OFFSET + 8 == STATS_LEN);
+impl Pack for Stats {
+    const LEN: usize = STATS_LEN;
+
+    fn pack_into_slice(&self, output: &mut [u8]) {
+        let output = array_mut_ref![output, 0, STATS_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (
+            account_type,
+            escrow,
+            epoch,
+            total_escrowed,
+            total_burned,
+            total_emitted,
+            total_disbursed,
+            epoch_escrowed,
+            epoch_burned,
+            epoch_emitted,
+            epoch_disbursed,
+            total_escrowed_high,
+            total_burned_high,
+            total_emitted_high,
+            total_disbursed_high,
+        ) = mut_array_refs![
+            output,
+            1,
+            PUBKEY_BYTES,
+            8,
+            8,
+            8,
+            8,
+            8,
+            8,
+            8,
+            8,
+            8,
+            8,
+            8,
+            8,
+            8
+        ];
+
+        *account_type = u8::from(self.account_type).to_le_bytes();
+        escrow.copy_from_slice(&self.escrow.to_bytes());
+        *epoch = self.epoch.to_le_bytes();
+        *epoch_escrowed = self.epoch_escrowed.to_le_bytes();
+        *epoch_burned = self.epoch_burned.to_le_bytes();
+        *epoch_emitted = self.epoch_emitted.to_le_bytes();
+        *epoch_disbursed = self.epoch_disbursed.to_le_bytes();
+        pack_u128(self.total_escrowed, total_escrowed, total_escrowed_high);
+        pack_u128(self.total_burned, total_burned, total_burned_high);
+        pack_u128(self.total_emitted, total_emitted, total_emitted_high);
+        pack_u128(self.total_disbursed, total_disbursed, total_disbursed_high);
+    }
+
+    fn unpack_from_slice(input: &[u8]) -> Result<Self, ProgramError> {
+        let input = array_ref![input, 0, STATS_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (
+            account_type,
+            escrow,
+            epoch,
+            total_escrowed,
+            total_burned,
+            total_emitted,
+            total_disbursed,
+            epoch_escrowed,
+            epoch_burned,
+            epoch_emitted,
+            epoch_disbursed,
+            total_escrowed_high,
+            total_burned_high,
+            total_emitted_high,
+            total_disbursed_high,
+        ) = array_refs![
+            input,
+            1,
+            PUBKEY_BYTES,
+            8,
+            8,
+            8,
+            8,
+            8,
+            8,
+            8,
+            8,
+            8,
+            8,
+            8,
+            8,
+            8
+        ];
+
+        let account_type = AccountType::from(u8::from_le_bytes(*account_type));
+        if account_type != AccountType::StatsV1 {
+            msg!("Stats account type is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self {
+            account_type,
+            escrow: Pubkey::new_from_array(*escrow),
+            epoch: Epoch::from_le_bytes(*epoch),
+            total_escrowed: unpack_u128(total_escrowed, total_escrowed_high),
+            total_burned: unpack_u128(total_burned, total_burned_high),
+            total_emitted: unpack_u128(total_emitted, total_emitted_high),
+            total_disbursed: unpack_u128(total_disbursed, total_disbursed_high),
+            epoch_escrowed: u64::from_le_bytes(*epoch_escrowed),
+            epoch_burned: u64::from_le_bytes(*epoch_burned),
+            epoch_emitted: u64::from_le_bytes(*epoch_emitted),
+            epoch_disbursed: u64::from_le_bytes(*epoch_disbursed),
+        })
+    }
+}
@@ -0,0 +1,169 @@
+use {
+    super::*,
+    arrayref::{array_mut_ref, array_ref, array_refs, mut_array_refs},
+    solana_program::{
+        clock::Slot,
+        msg,
+        program_error::ProgramError,
+        program_pack::{IsInitialized, Pack, Sealed},
+        pubkey::{Pubkey, PUBKEY_BYTES},
+    },
+};
+
+/// Subscription state
+///
+/// Recurring funding of a job with a fixed amount of tokens each period, transferred from a source
+/// token account whose owner approved the subscription as its SPL Token delegate.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Subscription {
+    /// Account type, must be SubscriptionV1 currently
+    pub account_type: AccountType,
+    /// Escrow the job is funded in
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub escrow: Pubkey,
+    /// Job authority that created the subscription
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub authority: Pubkey,
+    /// Identifier of the job of the authority to fund
+    pub job_id: u64,
+    /// Token account the subscription transfers from as its delegate
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub source_token: Pubkey,
+    /// Amount of tokens to fund each period
+    pub amount: u64,
+    /// Length of a period in slots
+    pub period: Slot,
+    /// First slot the next period can be funded in
+    pub next_slot: Slot,
+}
+
+impl Subscription {
+    /// Offset of the escrow in a packed subscription
+    pub const ESCROW_OFFSET: usize = ACCOUNT_TYPE_OFFSET + 1;
+    /// Offset of the authority in a packed subscription
+    pub const AUTHORITY_OFFSET: usize = Self::ESCROW_OFFSET + PUBKEY_BYTES;
+    /// Offset of the source token account in a packed subscription
+    pub const SOURCE_TOKEN_OFFSET: usize = Self::AUTHORITY_OFFSET + PUBKEY_BYTES + 8;
+
+    /// Create a subscription
+    pub fn new(params: InitSubscriptionParams) -> Self {
+        let mut subscription = Self::default();
+        Self::init(&mut subscription, params);
+        subscription
+    }
+
+    /// Initialize a subscription
+    pub fn init(&mut self, params: InitSubscriptionParams) {
+        self.account_type = AccountType::SubscriptionV1;
+        self.escrow = params.escrow;
+        self.authority = params.authority;
+        self.job_id = params.job_id;
+        self.source_token = params.source_token;
+        self.amount = params.amount;
+        self.period = params.period;
+        self.next_slot = params.start_slot;
+    }
+
+    /// Check if the next period can be funded in a slot
+    pub fn is_due(&self, slot: Slot) -> bool {
+        slot >= self.next_slot
+    }
+
+    /// Advance to the following period, returning `None` on overflow. Periods a crank missed stay
+    /// due, so each of them is still funded.
+    pub fn advance(&mut self) -> Option<()> {
+        self.next_slot = self.next_slot.checked_add(self.period)?;
+        Some(())
+    }
+}
+
+/// Initialize a subscription
+pub struct InitSubscriptionParams {
+    /// Escrow the job is funded in
+    pub escrow: Pubkey,
+    /// Job authority that created the subscription
+    pub authority: Pubkey,
+    /// Identifier of the job of the authority to fund
+    pub job_id: u64,
+    /// Token account the subscription transfers from as its delegate
+    pub source_token: Pubkey,
+    /// Amount of tokens to fund each period
+    pub amount: u64,
+    /// Length of a period in slots
+    pub period: Slot,
+    /// First slot the first period can be funded in
+    pub start_slot: Slot,
+}
+
+impl Sealed for Subscription {}
+
+impl IsInitialized for Subscription {
+    fn is_initialized(&self) -> bool {
+        self.account_type != AccountType::Uninitialized
+    }
+}
+
+const SUBSCRIPTION_LEN: usize = 129; // 1 + 32 + 32 + 8 + 32 + 8 + 8 + 8
+const _: () = assert!(Subscription::SOURCE_TOKEN_OFFSET + PUBKEY_BYTES + 24 == SUBSCRIPTION_LEN);
+impl Pack for Subscription {
+    const LEN: usize = SUBSCRIPTION_LEN;
+
+    fn pack_into_slice(&self, output: &mut [u8]) {
+        let output = array_mut_ref![output, 0, SUBSCRIPTION_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (account_type, escrow, authority, job_id, source_token, amount, period, next_slot) = mut_array_refs![
+            output,
+            1,
+            PUBKEY_BYTES,
+            PUBKEY_BYTES,
+            8,
+            PUBKEY_BYTES,
+            8,
+            8,
+            8
+        ];
+
+        *account_type = u8::from(self.account_type).to_le_bytes();
+        escrow.copy_from_slice(&self.escrow.to_bytes());
+        authority.copy_from_slice(&self.authority.to_bytes());
+        *job_id = self.job_id.to_le_bytes();
+        source_token.copy_from_slice(&self.source_token.to_bytes());
+        *amount = self.amount.to_le_bytes();
+        *period = self.period.to_le_bytes();
+        *next_slot = self.next_slot.to_le_bytes();
+    }
+
+    fn unpack_from_slice(input: &[u8]) -> Result<Self, ProgramError> {
+        let input = array_ref![input, 0, SUBSCRIPTION_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (account_type, escrow, authority, job_id, source_token, amount, period, next_slot) = array_refs![
+            input,
+            1,
+            PUBKEY_BYTES,
+            PUBKEY_BYTES,
+            8,
+            PUBKEY_BYTES,
+            8,
+            8,
+            8
+        ];
+
+        let account_type = AccountType::from(u8::from_le_bytes(*account_type));
+        if account_type != AccountType::SubscriptionV1 {
+            msg!("Subscription account type is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self {
+            account_type,
+            escrow: Pubkey::new_from_array(*escrow),
+            authority: Pubkey::new_from_array(*authority),
+            job_id: u64::from_le_bytes(*job_id),
+            source_token: Pubkey::new_from_array(*source_token),
+            amount: u64::from_le_bytes(*amount),
+            period: Slot::from_le_bytes(*period),
+            next_slot: Slot::from_le_bytes(*next_slot),
+        })
+    }
+}
@@ -0,0 +1,266 @@
+use {
+    super::*,
+    arrayref::{array_mut_ref, array_ref, array_refs, mut_array_refs},
+    solana_program::{
+        clock::{Epoch, UnixTimestamp},
+        msg,
+        program_error::ProgramError,
+        program_pack::{IsInitialized, Pack, Sealed},
+        pubkey::{Pubkey, PUBKEY_BYTES},
+    },
+};
+
+/// Treasury state
+///
+/// Tokens of an escrow's treasury, held by the treasury's associated token account, which anyone
+/// can fund and the escrow's owner can burn. Burns are recorded cumulatively and per epoch, up to
+/// an optional cap on the amount burned in each epoch. The escrow's owner can also sweep tokens to
+/// a cold wallet registered in advance, but only once the sweep delay of the config has passed
+/// since the sweep was scheduled, and the config's guardian can cancel the sweep until then.
+///
+/// The lifetime amount burned was widened to 128 bits by appending its high 64 bits to the
+/// original layout, which is `Treasury::LEGACY_LEN` bytes long. Treasuries created before then are
+/// unpacked with the high bits set to zero, and can only record lifetime burns that fit in 64 bits.
+/// The cold wallet fields were appended to the layout before them, which is
+/// `Treasury::PRE_COLD_WALLET_LEN` bytes long, and are unpacked as zero from treasuries created
+/// before then, which are reallocated when a cold wallet is registered.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Treasury {
+    /// Account type, must be TreasuryV1 currently
+    pub account_type: AccountType,
+    /// Escrow the treasury belongs to
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub escrow: Pubkey,
+    /// RNDR SPL Token mint of the tokens in the treasury
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub token_mint: Pubkey,
+    /// Maximum amount of tokens burned in each epoch, where zero is uncapped
+    pub epoch_burn_cap: u64,
+    /// Epoch tokens were last burned in
+    pub burn_epoch: Epoch,
+    /// Amount of tokens burned during the burn epoch
+    pub epoch_burned: u64,
+    /// Amount of tokens burned over the treasury's lifetime
+    pub total_burned: u128,
+    /// Token account the treasury's tokens can be swept to, or the default pubkey if none is
+    /// registered
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub cold_wallet: Pubkey,
+    /// Amount of tokens of the sweep to the cold wallet that's scheduled, or zero if there is none
+    pub sweep_amount: u64,
+    /// Unix timestamp from which the scheduled sweep can be executed
+    pub sweep_executable_at: UnixTimestamp,
+}
+
+impl Treasury {
+    /// Offset of the escrow in a packed treasury
+    pub const ESCROW_OFFSET: usize = ACCOUNT_TYPE_OFFSET + 1;
+    /// Offset of the token mint in a packed treasury
+    pub const TOKEN_MINT_OFFSET: usize = Self::ESCROW_OFFSET + PUBKEY_BYTES;
+    /// Offset of the epoch burn cap in a packed treasury
+    pub const EPOCH_BURN_CAP_OFFSET: usize = Self::TOKEN_MINT_OFFSET + PUBKEY_BYTES;
+    /// Offset of the burn epoch in a packed treasury
+    pub const BURN_EPOCH_OFFSET: usize = Self::EPOCH_BURN_CAP_OFFSET + 8;
+    /// Offset of the amount burned during the burn epoch in a packed treasury
+    pub const EPOCH_BURNED_OFFSET: usize = Self::BURN_EPOCH_OFFSET + 8;
+    /// Offset of the low 64 bits of the lifetime amount burned in a packed treasury
+    pub const TOTAL_BURNED_OFFSET: usize = Self::EPOCH_BURNED_OFFSET + 8;
+    /// Offset of the high 64 bits of the lifetime amount burned in a packed treasury
+    pub const TOTAL_BURNED_HIGH_OFFSET: usize = Self::TOTAL_BURNED_OFFSET + 8;
+    /// Offset of the cold wallet in a packed treasury
+    pub const COLD_WALLET_OFFSET: usize = Self::TOTAL_BURNED_HIGH_OFFSET + 8;
+    /// Offset of the amount of the scheduled sweep in a packed treasury
+    pub const SWEEP_AMOUNT_OFFSET: usize = Self::COLD_WALLET_OFFSET + PUBKEY_BYTES;
+    /// Offset of the Unix timestamp the scheduled sweep can be executed from in a packed treasury
+    pub const SWEEP_EXECUTABLE_AT_OFFSET: usize = Self::SWEEP_AMOUNT_OFFSET + 8;
+
+    /// Length of treasuries created before the lifetime amount burned was widened
+    pub const LEGACY_LEN: usize = Self::TOTAL_BURNED_HIGH_OFFSET;
+    /// Length of treasuries created before the cold wallet fields were appended
+    pub const PRE_COLD_WALLET_LEN: usize = Self::COLD_WALLET_OFFSET;
+
+    /// Create a treasury
+    pub fn new(params: InitTreasuryParams) -> Self {
+        let mut treasury = Self::default();
+        Self::init(&mut treasury, params);
+        treasury
+    }
+
+    /// Initialize a treasury that hasn't burned any tokens
+    pub fn init(&mut self, params: InitTreasuryParams) {
+        self.account_type = AccountType::TreasuryV1;
+        self.escrow = params.escrow;
+        self.token_mint = params.token_mint;
+        self.epoch_burn_cap = params.epoch_burn_cap;
+        self.burn_epoch = 0;
+        self.epoch_burned = 0;
+        self.total_burned = 0;
+        self.cold_wallet = Pubkey::default();
+        self.sweep_amount = 0;
+        self.sweep_executable_at = 0;
+    }
+
+    /// Amount of tokens that can still be burned in an epoch under the epoch burn cap
+    pub fn burnable(&self, epoch: Epoch) -> u64 {
+        if self.epoch_burn_cap == 0 {
+            u64::MAX
+        } else if epoch == self.burn_epoch {
+            self.epoch_burn_cap.saturating_sub(self.epoch_burned)
+        } else {
+            self.epoch_burn_cap
+        }
+    }
+
+    /// Record an amount of tokens burned in an epoch, starting a new epoch total if tokens were
+    /// last burned in an earlier epoch, or return None on overflow
+    pub fn record_burn(&mut self, epoch: Epoch, amount: u64) -> Option<()> {
+        if epoch != self.burn_epoch {
+            self.burn_epoch = epoch;
+            self.epoch_burned = 0;
+        }
+        self.epoch_burned = self.epoch_burned.checked_add(amount)?;
+        self.total_burned = self.total_burned.checked_add(u128::from(amount))?;
+        Some(())
+    }
+
+    /// Cold wallet of the treasury, if one is registered
+    pub fn cold_wallet(&self) -> Option<&Pubkey> {
+        if self.cold_wallet == Pubkey::default() {
+            None
+        } else {
+            Some(&self.cold_wallet)
+        }
+    }
+
+    /// Unpack a treasury from account data, which may have a legacy length
+    pub fn unpack_account(input: &[u8]) -> Result<Self, ProgramError> {
+        unpack_appended(input, &[Self::LEGACY_LEN, Self::PRE_COLD_WALLET_LEN])
+    }
+
+    /// Pack a treasury into account data, which may have a legacy length if none of the fields
+    /// appended after it are set
+    pub fn pack_account(treasury: Self, output: &mut [u8]) -> Result<(), ProgramError> {
+        pack_appended(
+            treasury,
+            output,
+            &[Self::LEGACY_LEN, Self::PRE_COLD_WALLET_LEN],
+        )
+    }
+}
+
+/// Initialize a treasury
+pub struct InitTreasuryParams {
+    /// Escrow the treasury belongs to
+    pub escrow: Pubkey,
+    /// RNDR SPL Token mint of the tokens in the treasury
+    pub token_mint: Pubkey,
+    /// Maximum amount of tokens burned in each epoch, where zero is uncapped
+    pub epoch_burn_cap: u64,
+}
+
+impl Sealed for Treasury {}
+
+impl IsInitialized for Treasury {
+    fn is_initialized(&self) -> bool {
+        self.account_type != AccountType::Uninitialized
+    }
+}
+
+const TREASURY_LEN: usize = 153; // 1 + 32 + 32 + 8 + 8 + 8 + 8 + 8 + 32 + 8 + 8
+const _: () = assert!(Treasury::SWEEP_EXECUTABLE_AT_OFFSET + 8 == TREASURY_LEN);
+impl Pack for Treasury {
+    const LEN: usize = TREASURY_LEN;
+
+    fn pack_into_slice(&self, output: &mut [u8]) {
+        let output = array_mut_ref![output, 0, TREASURY_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (
+            account_type,
+            escrow,
+            token_mint,
+            epoch_burn_cap,
+            burn_epoch,
+            epoch_burned,
+            total_burned,
+            total_burned_high,
+            cold_wallet,
+            sweep_amount,
+            sweep_executable_at,
+        ) = mut_array_refs![
+            output,
+            1,
+            PUBKEY_BYTES,
+            PUBKEY_BYTES,
+            8,
+            8,
+            8,
+            8,
+            8,
+            PUBKEY_BYTES,
+            8,
+            8
+        ];
+
+        *account_type = u8::from(self.account_type).to_le_bytes();
+        escrow.copy_from_slice(&self.escrow.to_bytes());
+        token_mint.copy_from_slice(&self.token_mint.to_bytes());
+        *epoch_burn_cap = self.epoch_burn_cap.to_le_bytes();
+        *burn_epoch = self.burn_epoch.to_le_bytes();
+        *epoch_burned = self.epoch_burned.to_le_bytes();
+        pack_u128(self.total_burned, total_burned, total_burned_high);
+        cold_wallet.copy_from_slice(&self.cold_wallet.to_bytes());
+        *sweep_amount = self.sweep_amount.to_le_bytes();
+        *sweep_executable_at = self.sweep_executable_at.to_le_bytes();
+    }
+
+    fn unpack_from_slice(input: &[u8]) -> Result<Self, ProgramError> {
+        let input = array_ref![input, 0, TREASURY_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (
+            account_type,
+            escrow,
+            token_mint,
+            epoch_burn_cap,
+            burn_epoch,
+            epoch_burned,
+            total_burned,
+            total_burned_high,
+            cold_wallet,
+            sweep_amount,
+            sweep_executable_at,
+        ) = array_refs![
+            input,
+            1,
+            PUBKEY_BYTES,
+            PUBKEY_BYTES,
+            8,
+            8,
+            8,
+            8,
+            8,
+            PUBKEY_BYTES,
+            8,
+            8
+        ];
+
+        let account_type = AccountType::from(u8::from_le_bytes(*account_type));
+        if account_type != AccountType::TreasuryV1 {
+            msg!("Treasury account type is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self {
+            account_type,
+            escrow: Pubkey::new_from_array(*escrow),
+            token_mint: Pubkey::new_from_array(*token_mint),
+            epoch_burn_cap: u64::from_le_bytes(*epoch_burn_cap),
+            burn_epoch: Epoch::from_le_bytes(*burn_epoch),
+            epoch_burned: u64::from_le_bytes(*epoch_burned),
+            total_burned: unpack_u128(total_burned, total_burned_high),
+            cold_wallet: Pubkey::new_from_array(*cold_wallet),
+            sweep_amount: u64::from_le_bytes(*sweep_amount),
+            sweep_executable_at: UnixTimestamp::from_le_bytes(*sweep_executable_at),
+        })
+    }
+}
@@ -0,0 +1,126 @@
+use {
+    super::*,
+    arrayref::{array_mut_ref, array_ref, array_refs, mut_array_refs},
+    solana_program::{
+        msg,
+        program_error::ProgramError,
+        program_pack::{IsInitialized, Pack, Sealed},
+        pubkey::{Pubkey, PUBKEY_BYTES},
+    },
+};
+
+/// Maximum number of verifiers registered for each escrow
+pub const MAX_VERIFIERS: usize = 16;
+
+/// Verifier registry state
+///
+/// Verifiers are registered by the escrow owner, and can flag jobs of the escrow whose results
+/// they find to be incorrect.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VerifierRegistry {
+    /// Account type, must be VerifierRegistryV1 currently
+    pub account_type: AccountType,
+    /// Escrow the registry belongs to
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub escrow: Pubkey,
+    /// Registered verifiers, at most `MAX_VERIFIERS`
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey::vec"))]
+    pub verifiers: Vec<Pubkey>,
+}
+
+impl VerifierRegistry {
+    /// Offset of the escrow in a packed verifier registry
+    pub const ESCROW_OFFSET: usize = ACCOUNT_TYPE_OFFSET + 1;
+    /// Offset of the number of verifiers in a packed verifier registry
+    pub const VERIFIERS_LEN_OFFSET: usize = Self::ESCROW_OFFSET + PUBKEY_BYTES;
+    /// Offset of the verifiers in a packed verifier registry
+    pub const VERIFIERS_OFFSET: usize = Self::VERIFIERS_LEN_OFFSET + 1;
+
+    /// Create a verifier registry
+    pub fn new(params: InitVerifierRegistryParams) -> Self {
+        let mut verifier_registry = Self::default();
+        Self::init(&mut verifier_registry, params);
+        verifier_registry
+    }
+
+    /// Initialize a verifier registry
+    pub fn init(&mut self, params: InitVerifierRegistryParams) {
+        self.account_type = AccountType::VerifierRegistryV1;
+        self.escrow = params.escrow;
+        self.verifiers = vec![];
+    }
+
+    /// Whether a verifier is registered
+    pub fn contains(&self, verifier: &Pubkey) -> bool {
+        self.verifiers.contains(verifier)
+    }
+}
+
+/// Initialize a verifier registry
+pub struct InitVerifierRegistryParams {
+    /// Escrow the registry belongs to
+    pub escrow: Pubkey,
+}
+
+impl Sealed for VerifierRegistry {}
+
+impl IsInitialized for VerifierRegistry {
+    fn is_initialized(&self) -> bool {
+        self.account_type != AccountType::Uninitialized
+    }
+}
+
+const VERIFIERS_LEN: usize = MAX_VERIFIERS * PUBKEY_BYTES;
+const VERIFIER_REGISTRY_LEN: usize = 546; // 1 + 32 + 1 + 16 * 32
+const _: () = assert!(VerifierRegistry::VERIFIERS_OFFSET + VERIFIERS_LEN == VERIFIER_REGISTRY_LEN);
+impl Pack for VerifierRegistry {
+    const LEN: usize = VERIFIER_REGISTRY_LEN;
+
+    fn pack_into_slice(&self, output: &mut [u8]) {
+        let output = array_mut_ref![output, 0, VERIFIER_REGISTRY_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (account_type, escrow, verifiers_len, verifiers) =
+            mut_array_refs![output, 1, PUBKEY_BYTES, 1, VERIFIERS_LEN];
+
+        *account_type = u8::from(self.account_type).to_le_bytes();
+        escrow.copy_from_slice(&self.escrow.to_bytes());
+        *verifiers_len = (self.verifiers.len() as u8).to_le_bytes();
+        verifiers.fill(0);
+        for (output, verifier) in verifiers
+            .chunks_exact_mut(PUBKEY_BYTES)
+            .zip(&self.verifiers)
+        {
+            output.copy_from_slice(verifier.as_ref());
+        }
+    }
+
+    fn unpack_from_slice(input: &[u8]) -> Result<Self, ProgramError> {
+        let input = array_ref![input, 0, VERIFIER_REGISTRY_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (account_type, escrow, verifiers_len, verifiers) =
+            array_refs![input, 1, PUBKEY_BYTES, 1, VERIFIERS_LEN];
+
+        let account_type = AccountType::from(u8::from_le_bytes(*account_type));
+        if account_type != AccountType::VerifierRegistryV1 {
+            msg!("Verifier registry account type is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let verifiers_len = u8::from_le_bytes(*verifiers_len) as usize;
+        if verifiers_len > MAX_VERIFIERS {
+            msg!("Verifier registry length is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self {
+            account_type,
+            escrow: Pubkey::new_from_array(*escrow),
+            verifiers: verifiers
+                .chunks_exact(PUBKEY_BYTES)
+                .take(verifiers_len)
+                .map(|verifier| Pubkey::new_from_array(*array_ref![verifier, 0, PUBKEY_BYTES]))
+                .collect(),
+        })
+    }
+}
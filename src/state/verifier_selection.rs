@@ -0,0 +1,165 @@
+use {
+    super::*,
+    arrayref::{array_mut_ref, array_ref, array_refs, mut_array_refs},
+    solana_program::{
+        clock::Slot,
+        hash::{hashv, Hash},
+        msg,
+        program_error::ProgramError,
+        program_pack::{IsInitialized, Pack, Sealed},
+        pubkey::{Pubkey, PUBKEY_BYTES},
+    },
+};
+
+/// Maximum number of verifiers selected to spot-check each job
+pub const MAX_SELECTED_VERIFIERS: usize = 8;
+
+/// Verifier selection state
+///
+/// Records the registered verifiers sampled to spot-check the result of a job, so anyone can
+/// check which verifiers were selected and recompute the sample from the recorded slot hash.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VerifierSelection {
+    /// Account type, must be VerifierSelectionV1 currently
+    pub account_type: AccountType,
+    /// Job the verifiers were selected for
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub job: Pubkey,
+    /// Slot of the slot hash used as entropy
+    pub slot: Slot,
+    /// Selected verifiers, at most `MAX_SELECTED_VERIFIERS`
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey::vec"))]
+    pub verifiers: Vec<Pubkey>,
+}
+
+impl VerifierSelection {
+    /// Offset of the job in a packed verifier selection
+    pub const JOB_OFFSET: usize = ACCOUNT_TYPE_OFFSET + 1;
+    /// Offset of the slot in a packed verifier selection
+    pub const SLOT_OFFSET: usize = Self::JOB_OFFSET + PUBKEY_BYTES;
+    /// Offset of the number of verifiers in a packed verifier selection
+    pub const VERIFIERS_LEN_OFFSET: usize = Self::SLOT_OFFSET + 8;
+    /// Offset of the verifiers in a packed verifier selection
+    pub const VERIFIERS_OFFSET: usize = Self::VERIFIERS_LEN_OFFSET + 1;
+
+    /// Create a verifier selection by sampling registered verifiers
+    pub fn new(params: InitVerifierSelectionParams) -> Self {
+        let mut verifier_selection = Self::default();
+        Self::init(&mut verifier_selection, params);
+        verifier_selection
+    }
+
+    /// Initialize a verifier selection by sampling registered verifiers
+    pub fn init(&mut self, params: InitVerifierSelectionParams) {
+        self.account_type = AccountType::VerifierSelectionV1;
+        self.job = params.job;
+        self.slot = params.slot;
+        self.verifiers = Self::sample(
+            &params.job,
+            &params.slot_hash,
+            params.registered,
+            params.count,
+        );
+    }
+
+    /// Deterministically sample `count` distinct verifiers with a partial Fisher-Yates shuffle,
+    /// drawing an index for each position from the hash of the slot hash, the job and the position
+    pub fn sample(
+        job: &Pubkey,
+        slot_hash: &Hash,
+        registered: &[Pubkey],
+        count: usize,
+    ) -> Vec<Pubkey> {
+        let mut verifiers = registered.to_vec();
+        let count = count.min(verifiers.len());
+        for position in 0..count {
+            let entropy = hashv(&[slot_hash.as_ref(), job.as_ref(), &[position as u8]]);
+            let entropy = u64::from_le_bytes(*array_ref![entropy.as_ref(), 0, 8]);
+            let remaining = (verifiers.len() - position) as u64;
+            let index = position + (entropy % remaining) as usize;
+            verifiers.swap(position, index);
+        }
+        verifiers.truncate(count);
+        verifiers
+    }
+}
+
+/// Initialize a verifier selection
+pub struct InitVerifierSelectionParams<'a> {
+    /// Job the verifiers are selected for
+    pub job: Pubkey,
+    /// Slot of the slot hash used as entropy
+    pub slot: Slot,
+    /// Slot hash used as entropy
+    pub slot_hash: Hash,
+    /// Registered verifiers to sample from
+    pub registered: &'a [Pubkey],
+    /// Number of verifiers to select
+    pub count: usize,
+}
+
+impl Sealed for VerifierSelection {}
+
+impl IsInitialized for VerifierSelection {
+    fn is_initialized(&self) -> bool {
+        self.account_type != AccountType::Uninitialized
+    }
+}
+
+const VERIFIERS_LEN: usize = MAX_SELECTED_VERIFIERS * PUBKEY_BYTES;
+const VERIFIER_SELECTION_LEN: usize = 298; // 1 + 32 + 8 + 1 + 8 * 32
+const _: () =
+    assert!(VerifierSelection::VERIFIERS_OFFSET + VERIFIERS_LEN == VERIFIER_SELECTION_LEN);
+impl Pack for VerifierSelection {
+    const LEN: usize = VERIFIER_SELECTION_LEN;
+
+    fn pack_into_slice(&self, output: &mut [u8]) {
+        let output = array_mut_ref![output, 0, VERIFIER_SELECTION_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (account_type, job, slot, verifiers_len, verifiers) =
+            mut_array_refs![output, 1, PUBKEY_BYTES, 8, 1, VERIFIERS_LEN];
+
+        *account_type = u8::from(self.account_type).to_le_bytes();
+        job.copy_from_slice(&self.job.to_bytes());
+        *slot = self.slot.to_le_bytes();
+        *verifiers_len = (self.verifiers.len() as u8).to_le_bytes();
+        verifiers.fill(0);
+        for (output, verifier) in verifiers
+            .chunks_exact_mut(PUBKEY_BYTES)
+            .zip(&self.verifiers)
+        {
+            output.copy_from_slice(verifier.as_ref());
+        }
+    }
+
+    fn unpack_from_slice(input: &[u8]) -> Result<Self, ProgramError> {
+        let input = array_ref![input, 0, VERIFIER_SELECTION_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (account_type, job, slot, verifiers_len, verifiers) =
+            array_refs![input, 1, PUBKEY_BYTES, 8, 1, VERIFIERS_LEN];
+
+        let account_type = AccountType::from(u8::from_le_bytes(*account_type));
+        if account_type != AccountType::VerifierSelectionV1 {
+            msg!("Verifier selection account type is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let verifiers_len = u8::from_le_bytes(*verifiers_len) as usize;
+        if verifiers_len > MAX_SELECTED_VERIFIERS {
+            msg!("Verifier selection length is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self {
+            account_type,
+            job: Pubkey::new_from_array(*job),
+            slot: u64::from_le_bytes(*slot),
+            verifiers: verifiers
+                .chunks_exact(PUBKEY_BYTES)
+                .take(verifiers_len)
+                .map(|verifier| Pubkey::new_from_array(*array_ref![verifier, 0, PUBKEY_BYTES]))
+                .collect(),
+        })
+    }
+}
@@ -0,0 +1,153 @@
+use {
+    super::*,
+    arrayref::{array_mut_ref, array_ref, array_refs, mut_array_refs},
+    solana_program::{
+        clock::UnixTimestamp,
+        msg,
+        program_error::ProgramError,
+        program_pack::{IsInitialized, Pack, Sealed},
+        pubkey::{Pubkey, PUBKEY_BYTES},
+    },
+};
+
+/// Domain prefix of a packed voucher, so its signature can't be replayed as another message
+pub const VOUCHER_DOMAIN: &[u8; 12] = b"rndr:voucher";
+
+/// Voucher signed offchain by a job authority, authorizing a relayer to fund the job from the
+/// authority's token account on its behalf
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Voucher {
+    /// Escrow the job is funded in
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub escrow: Pubkey,
+    /// Job authority that signs the voucher
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub authority: Pubkey,
+    /// Identifier of the job, unique for the authority
+    pub job_id: u64,
+    /// Amount of tokens to fund
+    pub amount: u64,
+    /// Nonce of the voucher, which must be the next nonce of the authority's voucher nonce
+    pub nonce: u64,
+    /// Unix timestamp after which the voucher can't be used
+    pub expires_at: UnixTimestamp,
+}
+
+impl Voucher {
+    /// Length of a packed voucher, the message the authority signs
+    pub const LEN: usize = 108; // 12 + 32 + 32 + 8 + 8 + 8 + 8
+
+    /// Pack the voucher into the message the authority signs
+    pub fn message(&self) -> [u8; Self::LEN] {
+        let mut output = [0; Self::LEN];
+        let (domain, escrow, authority, job_id, amount, nonce, expires_at) =
+            mut_array_refs![&mut output, 12, PUBKEY_BYTES, PUBKEY_BYTES, 8, 8, 8, 8];
+
+        domain.copy_from_slice(VOUCHER_DOMAIN);
+        escrow.copy_from_slice(self.escrow.as_ref());
+        authority.copy_from_slice(self.authority.as_ref());
+        *job_id = self.job_id.to_le_bytes();
+        *amount = self.amount.to_le_bytes();
+        *nonce = self.nonce.to_le_bytes();
+        *expires_at = self.expires_at.to_le_bytes();
+        output
+    }
+}
+
+/// Voucher nonce state
+///
+/// Next nonce of the vouchers a job authority signs for an escrow, incremented each time one is
+/// used so it can't be replayed.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VoucherNonce {
+    /// Account type, must be VoucherNonceV1 currently
+    pub account_type: AccountType,
+    /// Escrow the vouchers fund jobs in
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub escrow: Pubkey,
+    /// Job authority that signs the vouchers
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_pubkey"))]
+    pub authority: Pubkey,
+    /// Nonce the next voucher must have
+    pub nonce: u64,
+}
+
+impl VoucherNonce {
+    /// Offset of the escrow in a packed voucher nonce
+    pub const ESCROW_OFFSET: usize = ACCOUNT_TYPE_OFFSET + 1;
+    /// Offset of the authority in a packed voucher nonce
+    pub const AUTHORITY_OFFSET: usize = Self::ESCROW_OFFSET + PUBKEY_BYTES;
+    /// Offset of the next nonce in a packed voucher nonce
+    pub const NONCE_OFFSET: usize = Self::AUTHORITY_OFFSET + PUBKEY_BYTES;
+
+    /// Create a voucher nonce
+    pub fn new(params: InitVoucherNonceParams) -> Self {
+        let mut voucher_nonce = Self::default();
+        Self::init(&mut voucher_nonce, params);
+        voucher_nonce
+    }
+
+    /// Initialize a voucher nonce that hasn't had any vouchers used
+    pub fn init(&mut self, params: InitVoucherNonceParams) {
+        self.account_type = AccountType::VoucherNonceV1;
+        self.escrow = params.escrow;
+        self.authority = params.authority;
+        self.nonce = 0;
+    }
+}
+
+/// Initialize a voucher nonce
+pub struct InitVoucherNonceParams {
+    /// Escrow the vouchers fund jobs in
+    pub escrow: Pubkey,
+    /// Job authority that signs the vouchers
+    pub authority: Pubkey,
+}
+
+impl Sealed for VoucherNonce {}
+
+impl IsInitialized for VoucherNonce {
+    fn is_initialized(&self) -> bool {
+        self.account_type != AccountType::Uninitialized
+    }
+}
+
+const VOUCHER_NONCE_LEN: usize = 73; // 1 + 32 + 32 + 8
+const _: () = assert!(VoucherNonce::NONCE_OFFSET + 8 == VOUCHER_NONCE_LEN);
+impl Pack for VoucherNonce {
+    const LEN: usize = VOUCHER_NONCE_LEN;
+
+    fn pack_into_slice(&self, output: &mut [u8]) {
+        let output = array_mut_ref![output, 0, VOUCHER_NONCE_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (account_type, escrow, authority, nonce) =
+            mut_array_refs![output, 1, PUBKEY_BYTES, PUBKEY_BYTES, 8];
+
+        *account_type = u8::from(self.account_type).to_le_bytes();
+        escrow.copy_from_slice(&self.escrow.to_bytes());
+        authority.copy_from_slice(&self.authority.to_bytes());
+        *nonce = self.nonce.to_le_bytes();
+    }
+
+    fn unpack_from_slice(input: &[u8]) -> Result<Self, ProgramError> {
+        let input = array_ref![input, 0, VOUCHER_NONCE_LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (account_type, escrow, authority, nonce) =
+            array_refs![input, 1, PUBKEY_BYTES, PUBKEY_BYTES, 8];
+
+        let account_type = AccountType::from(u8::from_le_bytes(*account_type));
+        if account_type != AccountType::VoucherNonceV1 {
+            msg!("Voucher nonce account type is invalid");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self {
+            account_type,
+            escrow: Pubkey::new_from_array(*escrow),
+            authority: Pubkey::new_from_array(*authority),
+            nonce: u64::from_le_bytes(*nonce),
+        })
+    }
+}
@@ -0,0 +1,219 @@
+//! Compact summaries of accounts, set as return data by the `GetEscrowSummary` and
+//! `GetJobSummary` instructions
+//!
+//! CPI callers read a summary with `get_return_data` after invoking the instruction, and clients
+//! read it from the return data of a simulated transaction, so neither has to decode the account
+//! layouts, which grow as fields are appended.
+
+use {
+    crate::{
+        error::RNDRError,
+        state::{Escrow, IncidentMode, Job, JobStatus},
+    },
+    arrayref::{array_mut_ref, array_ref, array_refs, mut_array_refs},
+    solana_program::{
+        clock::UnixTimestamp,
+        msg,
+        program_error::ProgramError,
+        pubkey::{Pubkey, PUBKEY_BYTES},
+    },
+    std::convert::TryFrom,
+};
+
+/// Summary of an Escrow
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EscrowSummary {
+    /// RNDR SPL Token mint of the tokens in escrow
+    pub token_mint: Pubkey,
+    /// Owner authority that can disburse funds
+    pub owner: Pubkey,
+    /// Token account the pooled tokens of the escrow are held in
+    pub token_account: Pubkey,
+    /// Amount of tokens in escrow, not including amounts that haven't been aggregated from
+    /// escrow shards yet
+    pub amount: u64,
+    /// Namespace of the escrow, or zero for the default escrow
+    pub escrow_id: u64,
+    /// Incident mode the escrow was last synced to, the direction funds can flow through it in
+    pub incident_mode: IncidentMode,
+    /// Number of mint migrations whose tokens have been converted
+    pub mint_migrations: u8,
+}
+
+impl EscrowSummary {
+    /// Length of a packed escrow summary
+    pub const LEN: usize = 3 * PUBKEY_BYTES + 8 + 8 + 1 + 1;
+
+    /// Summarize the escrow at an address
+    pub fn new(escrow_address: &Pubkey, escrow: &Escrow) -> Self {
+        Self {
+            token_mint: escrow.token_mint,
+            owner: escrow.owner,
+            token_account: escrow.token_account(escrow_address),
+            amount: escrow.amount,
+            escrow_id: escrow.escrow_id,
+            incident_mode: escrow.incident_mode,
+            mint_migrations: escrow.mint_migrations,
+        }
+    }
+
+    /// Pack the summary
+    pub fn pack(&self) -> [u8; Self::LEN] {
+        let mut buf = [0; Self::LEN];
+        let output = array_mut_ref![buf, 0, EscrowSummary::LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (token_mint, owner, token_account, amount, escrow_id, incident_mode, mint_migrations) =
+            mut_array_refs![output, PUBKEY_BYTES, PUBKEY_BYTES, PUBKEY_BYTES, 8, 8, 1, 1];
+
+        token_mint.copy_from_slice(self.token_mint.as_ref());
+        owner.copy_from_slice(self.owner.as_ref());
+        token_account.copy_from_slice(self.token_account.as_ref());
+        *amount = self.amount.to_le_bytes();
+        *escrow_id = self.escrow_id.to_le_bytes();
+        *incident_mode = u8::from(self.incident_mode).to_le_bytes();
+        *mint_migrations = self.mint_migrations.to_le_bytes();
+        buf
+    }
+
+    /// Unpack a summary from the return data of a `GetEscrowSummary` instruction
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        if input.len() != Self::LEN {
+            msg!(
+                "Escrow summary is {} bytes, expected {}",
+                input.len(),
+                Self::LEN
+            );
+            return Err(RNDRError::InstructionUnpackError.into());
+        }
+        let input = array_ref![input, 0, EscrowSummary::LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (token_mint, owner, token_account, amount, escrow_id, incident_mode, mint_migrations) =
+            array_refs![input, PUBKEY_BYTES, PUBKEY_BYTES, PUBKEY_BYTES, 8, 8, 1, 1];
+
+        Ok(Self {
+            token_mint: Pubkey::new_from_array(*token_mint),
+            owner: Pubkey::new_from_array(*owner),
+            token_account: Pubkey::new_from_array(*token_account),
+            amount: u64::from_le_bytes(*amount),
+            escrow_id: u64::from_le_bytes(*escrow_id),
+            incident_mode: IncidentMode::try_from(u8::from_le_bytes(*incident_mode)).map_err(
+                |_| {
+                    msg!("Escrow summary incident mode is invalid");
+                    ProgramError::InvalidAccountData
+                },
+            )?,
+            mint_migrations: u8::from_le_bytes(*mint_migrations),
+        })
+    }
+}
+
+/// Summary of a Job
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct JobSummary {
+    /// User authority that manages the job
+    pub authority: Pubkey,
+    /// Identifier of the job, unique for the authority that initialized it
+    pub id: u64,
+    /// Amount of tokens in escrow for the job
+    pub amount: u64,
+    /// Status of the job's result
+    pub status: JobStatus,
+    /// Node that committed to a result, or the default pubkey if the job is open
+    pub node: Pubkey,
+    /// Whether the job's tokens are held in its own token account instead of the escrow's
+    pub isolated: bool,
+    /// Whether the job is frozen under a legal hold
+    pub frozen: bool,
+    /// Priority level of the job
+    pub priority: u8,
+    /// Bitflags of the capabilities the job requires from a node
+    pub tags: u64,
+    /// Unix timestamp of when the job was first funded, or zero if it was funded before the
+    /// timestamp was recorded
+    pub funded_at: UnixTimestamp,
+}
+
+impl JobSummary {
+    /// Length of a packed job summary
+    pub const LEN: usize = PUBKEY_BYTES + 8 + 8 + 1 + PUBKEY_BYTES + 1 + 1 + 1 + 8 + 8;
+
+    /// Summarize a job
+    pub fn new(job: &Job) -> Self {
+        Self {
+            authority: job.authority,
+            id: job.id,
+            amount: job.amount,
+            status: job.status,
+            node: job.node,
+            isolated: job.isolated,
+            frozen: job.frozen,
+            priority: job.priority,
+            tags: job.tags,
+            funded_at: job.funded_at,
+        }
+    }
+
+    /// Pack the summary
+    pub fn pack(&self) -> [u8; Self::LEN] {
+        let mut buf = [0; Self::LEN];
+        let output = array_mut_ref![buf, 0, JobSummary::LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (authority, id, amount, status, node, isolated, frozen, priority, tags, funded_at) =
+            mut_array_refs![output, PUBKEY_BYTES, 8, 8, 1, PUBKEY_BYTES, 1, 1, 1, 8, 8];
+
+        authority.copy_from_slice(self.authority.as_ref());
+        *id = self.id.to_le_bytes();
+        *amount = self.amount.to_le_bytes();
+        *status = u8::from(self.status).to_le_bytes();
+        node.copy_from_slice(self.node.as_ref());
+        *isolated = u8::from(self.isolated).to_le_bytes();
+        *frozen = u8::from(self.frozen).to_le_bytes();
+        *priority = self.priority.to_le_bytes();
+        *tags = self.tags.to_le_bytes();
+        *funded_at = self.funded_at.to_le_bytes();
+        buf
+    }
+
+    /// Unpack a summary from the return data of a `GetJobSummary` instruction
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        if input.len() != Self::LEN {
+            msg!(
+                "Job summary is {} bytes, expected {}",
+                input.len(),
+                Self::LEN
+            );
+            return Err(RNDRError::InstructionUnpackError.into());
+        }
+        let input = array_ref![input, 0, JobSummary::LEN];
+        #[allow(clippy::ptr_offset_with_cast)]
+        let (authority, id, amount, status, node, isolated, frozen, priority, tags, funded_at) =
+            array_refs![input, PUBKEY_BYTES, 8, 8, 1, PUBKEY_BYTES, 1, 1, 1, 8, 8];
+
+        Ok(Self {
+            authority: Pubkey::new_from_array(*authority),
+            id: u64::from_le_bytes(*id),
+            amount: u64::from_le_bytes(*amount),
+            status: JobStatus::try_from(u8::from_le_bytes(*status)).map_err(|_| {
+                msg!("Job summary status is invalid");
+                ProgramError::InvalidAccountData
+            })?,
+            node: Pubkey::new_from_array(*node),
+            isolated: unpack_bool(isolated[0])?,
+            frozen: unpack_bool(frozen[0])?,
+            priority: u8::from_le_bytes(*priority),
+            tags: u64::from_le_bytes(*tags),
+            funded_at: i64::from_le_bytes(*funded_at),
+        })
+    }
+}
+
+fn unpack_bool(value: u8) -> Result<bool, ProgramError> {
+    match value {
+        0 => Ok(false),
+        1 => Ok(true),
+        _ => {
+            msg!("Job summary flag is invalid");
+            Err(ProgramError::InvalidAccountData)
+        }
+    }
+}
@@ -0,0 +1,2651 @@
+//! Fixtures for integration tests of the RNDR program with `solana-program-test`
+//!
+//! Accounts are added to the `ProgramTest` in an initialized state before it's started, so tests
+//! can exercise an instruction without first sending the instructions that set up its accounts.
+
+#![cfg(not(target_arch = "bpf"))]
+
+use {
+    crate::{
+        account_compression::{
+            merkle_tree_account_len, ACCOUNT_COMPRESSION_PROGRAM_ID, APPEND_DISCRIMINATOR,
+            INIT_EMPTY_MERKLE_TREE_DISCRIMINATOR, NOOP_PROGRAM_ID, REPLACE_LEAF_DISCRIMINATOR,
+        },
+        error::RNDRError,
+        instruction::ed25519_verify,
+        math::{Rate, RoundingPolicy},
+        oracle::OraclePrice,
+        pda,
+        processor::process_instruction,
+        state::{
+            AdminAction, ClaimBitmap, CompressedJob, Config, CrankVault, DelegateAllowance,
+            Dispute, EmissionSchedule, EpochWork, Escrow, EscrowShard, InitConfigParams,
+            InitCrankVaultParams, InitDelegateAllowanceParams, InitDisputeParams,
+            InitEmissionScheduleParams, InitEpochWorkParams, InitEscrowParams,
+            InitEscrowShardParams, InitJobBundleParams, InitJobIndexPageParams, InitJobIndexParams,
+            InitJobParams, InitJobQueueParams, InitJobTreeParams, InitMerkleDistributionParams,
+            InitNodeParams, InitProposalParams, InitQueuedActionParams, InitRecoveryParams,
+            InitRewardPoolParams, InitSessionKeyParams, InitStakePoolParams, InitStatsParams,
+            InitSubscriptionParams, InitTreasuryParams, InitVerifierRegistryParams, Job, JobBundle,
+            JobIndex, JobIndexPage, JobQueue, JobStatus, JobTree, MerkleDistribution,
+            MintMigration, Node, Proposal, QueuedAction, Recovery, RewardPool, RewardTier,
+            SessionKey, StakePool, Stats, Subscription, Treasury, VerifierRegistry,
+            VerifierSelection, Voucher, VoucherNonce, JOB_INDEX_PAGE_LEN, MAX_PRICE_TIERS,
+            MAX_REWARD_TIERS,
+        },
+    },
+    num_traits::FromPrimitive,
+    solana_program::{
+        account_info::AccountInfo,
+        bpf_loader_upgradeable,
+        clock::{Slot, UnixTimestamp},
+        entrypoint::ProgramResult,
+        hash::{hashv, HASH_BYTES},
+        instruction::Instruction,
+        keccak,
+        program_error::ProgramError,
+        program_option::COption,
+        program_pack::Pack,
+        pubkey::Pubkey,
+        rent::Rent,
+    },
+    solana_program_test::{processor, BanksClient, BanksClientError, ProgramTest},
+    solana_sdk::{
+        account::Account,
+        instruction::InstructionError,
+        signature::{Keypair, Signer},
+        transaction::TransactionError,
+    },
+    spl_associated_token_account::get_associated_token_address,
+    spl_token::state::{Account as Token, AccountState, Mint},
+    std::convert::TryInto,
+};
+
+/// Create a `ProgramTest` with the RNDR program at its deployed address
+pub fn program_test() -> ProgramTest {
+    ProgramTest::new("rndr", crate::id(), processor!(process_instruction))
+}
+
+trait AddPacked {
+    fn add_packable_account<T: Pack>(
+        &mut self,
+        pubkey: Pubkey,
+        amount: u64,
+        data: &T,
+        owner: &Pubkey,
+    );
+}
+
+impl AddPacked for ProgramTest {
+    fn add_packable_account<T: Pack>(
+        &mut self,
+        pubkey: Pubkey,
+        amount: u64,
+        data: &T,
+        owner: &Pubkey,
+    ) {
+        let mut account = Account::new(amount, T::get_packed_len(), owner);
+        data.pack_into_slice(&mut account.data);
+        self.add_account(pubkey, account);
+    }
+}
+
+/// Error of the program a transaction failed with, if it failed with one of its own
+pub fn program_error(result: Result<(), BanksClientError>) -> Option<RNDRError> {
+    match result.err()?.unwrap() {
+        TransactionError::InstructionError(_, InstructionError::Custom(code)) => {
+            RNDRError::from_u32(code)
+        }
+        _ => None,
+    }
+}
+
+/// Fetch an account that must exist
+pub async fn get_account(banks_client: &mut BanksClient, pubkey: Pubkey) -> Account {
+    banks_client.get_account(pubkey).await.unwrap().unwrap()
+}
+
+/// Fetch and unpack a token mint
+pub async fn get_mint(banks_client: &mut BanksClient, pubkey: Pubkey) -> Mint {
+    let account = get_account(banks_client, pubkey).await;
+    Mint::unpack(&account.data).unwrap()
+}
+
+/// Fetch and unpack a token account
+pub async fn get_token(banks_client: &mut BanksClient, pubkey: Pubkey) -> Token {
+    let account = get_account(banks_client, pubkey).await;
+    Token::unpack(&account.data).unwrap()
+}
+
+/// Fetch the balance of a token account
+pub async fn get_token_balance(banks_client: &mut BanksClient, pubkey: Pubkey) -> u64 {
+    get_token(banks_client, pubkey).await.amount
+}
+
+/// Fetch and unpack an escrow
+pub async fn get_escrow(banks_client: &mut BanksClient, pubkey: Pubkey) -> Escrow {
+    let account = get_account(banks_client, pubkey).await;
+    Escrow::unpack_account(&account.data).unwrap()
+}
+
+/// Fetch and unpack a job
+pub async fn get_job(banks_client: &mut BanksClient, pubkey: Pubkey) -> Job {
+    let account = get_account(banks_client, pubkey).await;
+    Job::unpack_account(&account.data).unwrap()
+}
+
+/// Fetch and unpack an escrow shard
+pub async fn get_escrow_shard(banks_client: &mut BanksClient, pubkey: Pubkey) -> EscrowShard {
+    let account = get_account(banks_client, pubkey).await;
+    EscrowShard::unpack(&account.data).unwrap()
+}
+
+/// Fetch and unpack a verifier registry
+pub async fn get_verifier_registry(
+    banks_client: &mut BanksClient,
+    pubkey: Pubkey,
+) -> VerifierRegistry {
+    let account = get_account(banks_client, pubkey).await;
+    VerifierRegistry::unpack(&account.data).unwrap()
+}
+
+/// Fetch and unpack a verifier selection
+pub async fn get_verifier_selection(
+    banks_client: &mut BanksClient,
+    pubkey: Pubkey,
+) -> VerifierSelection {
+    let account = get_account(banks_client, pubkey).await;
+    VerifierSelection::unpack(&account.data).unwrap()
+}
+
+/// Fetch and unpack the config
+pub async fn get_config(banks_client: &mut BanksClient) -> Config {
+    let account = get_account(banks_client, find_config_address().0).await;
+    Config::unpack_account(&account.data).unwrap()
+}
+
+/// Fetch and unpack a dispute
+pub async fn get_dispute(banks_client: &mut BanksClient, pubkey: Pubkey) -> Dispute {
+    let account = get_account(banks_client, pubkey).await;
+    Dispute::unpack(&account.data).unwrap()
+}
+
+/// Fetch and unpack a proposal
+pub async fn get_proposal(banks_client: &mut BanksClient, pubkey: Pubkey) -> Proposal {
+    let account = get_account(banks_client, pubkey).await;
+    Proposal::unpack(&account.data).unwrap()
+}
+
+/// Fetch and unpack a queued action
+pub async fn get_queued_action(banks_client: &mut BanksClient, pubkey: Pubkey) -> QueuedAction {
+    let account = get_account(banks_client, pubkey).await;
+    QueuedAction::unpack(&account.data).unwrap()
+}
+
+/// Fetch and unpack a recovery
+pub async fn get_recovery(banks_client: &mut BanksClient, pubkey: Pubkey) -> Recovery {
+    let account = get_account(banks_client, pubkey).await;
+    Recovery::unpack(&account.data).unwrap()
+}
+
+/// Fetch and unpack a reward pool
+pub async fn get_reward_pool(banks_client: &mut BanksClient, pubkey: Pubkey) -> RewardPool {
+    let account = get_account(banks_client, pubkey).await;
+    RewardPool::unpack(&account.data).unwrap()
+}
+
+/// Fetch and unpack an epoch work
+pub async fn get_epoch_work(banks_client: &mut BanksClient, pubkey: Pubkey) -> EpochWork {
+    let account = get_account(banks_client, pubkey).await;
+    EpochWork::unpack(&account.data).unwrap()
+}
+
+/// Fetch and unpack a Merkle distribution
+pub async fn get_merkle_distribution(
+    banks_client: &mut BanksClient,
+    pubkey: Pubkey,
+) -> MerkleDistribution {
+    let account = get_account(banks_client, pubkey).await;
+    MerkleDistribution::unpack(&account.data).unwrap()
+}
+
+/// Fetch and unpack a claim bitmap
+pub async fn get_claim_bitmap(banks_client: &mut BanksClient, pubkey: Pubkey) -> ClaimBitmap {
+    let account = get_account(banks_client, pubkey).await;
+    ClaimBitmap::unpack(&account.data).unwrap()
+}
+
+/// Fetch and unpack an emission schedule
+pub async fn get_emission_schedule(
+    banks_client: &mut BanksClient,
+    pubkey: Pubkey,
+) -> EmissionSchedule {
+    let account = get_account(banks_client, pubkey).await;
+    EmissionSchedule::unpack(&account.data).unwrap()
+}
+
+/// Fetch and unpack a node
+pub async fn get_node(banks_client: &mut BanksClient, pubkey: Pubkey) -> Node {
+    let account = get_account(banks_client, pubkey).await;
+    Node::unpack_account(&account.data).unwrap()
+}
+
+/// Fetch and unpack a stake pool
+pub async fn get_stake_pool(banks_client: &mut BanksClient, pubkey: Pubkey) -> StakePool {
+    let account = get_account(banks_client, pubkey).await;
+    StakePool::unpack(&account.data).unwrap()
+}
+
+/// Fetch and unpack a mint migration
+pub async fn get_mint_migration(banks_client: &mut BanksClient, pubkey: Pubkey) -> MintMigration {
+    let account = get_account(banks_client, pubkey).await;
+    MintMigration::unpack(&account.data).unwrap()
+}
+
+/// Fetch and unpack a crank vault
+pub async fn get_crank_vault(banks_client: &mut BanksClient, pubkey: Pubkey) -> CrankVault {
+    let account = get_account(banks_client, pubkey).await;
+    CrankVault::unpack(&account.data).unwrap()
+}
+
+/// Fetch and unpack a treasury
+pub async fn get_treasury(banks_client: &mut BanksClient, pubkey: Pubkey) -> Treasury {
+    let account = get_account(banks_client, pubkey).await;
+    Treasury::unpack_account(&account.data).unwrap()
+}
+
+/// Fetch and unpack stats
+pub async fn get_stats(banks_client: &mut BanksClient, pubkey: Pubkey) -> Stats {
+    let account = get_account(banks_client, pubkey).await;
+    Stats::unpack_account(&account.data).unwrap()
+}
+
+/// Fetch and unpack a voucher nonce
+pub async fn get_voucher_nonce(banks_client: &mut BanksClient, pubkey: Pubkey) -> VoucherNonce {
+    let account = get_account(banks_client, pubkey).await;
+    VoucherNonce::unpack(&account.data).unwrap()
+}
+
+/// Fetch and unpack a delegate allowance
+pub async fn get_delegate_allowance(
+    banks_client: &mut BanksClient,
+    pubkey: Pubkey,
+) -> DelegateAllowance {
+    let account = get_account(banks_client, pubkey).await;
+    DelegateAllowance::unpack(&account.data).unwrap()
+}
+
+/// Fetch and unpack a subscription
+pub async fn get_subscription(banks_client: &mut BanksClient, pubkey: Pubkey) -> Subscription {
+    let account = get_account(banks_client, pubkey).await;
+    Subscription::unpack(&account.data).unwrap()
+}
+
+/// Fetch and unpack a job bundle
+pub async fn get_job_bundle(banks_client: &mut BanksClient, pubkey: Pubkey) -> JobBundle {
+    let account = get_account(banks_client, pubkey).await;
+    JobBundle::unpack(&account.data).unwrap()
+}
+
+/// Fetch and unpack a job index
+pub async fn get_job_index(banks_client: &mut BanksClient, pubkey: Pubkey) -> JobIndex {
+    let account = get_account(banks_client, pubkey).await;
+    JobIndex::unpack(&account.data).unwrap()
+}
+
+/// Fetch and unpack a job index page
+pub async fn get_job_index_page(banks_client: &mut BanksClient, pubkey: Pubkey) -> JobIndexPage {
+    let account = get_account(banks_client, pubkey).await;
+    JobIndexPage::unpack(&account.data).unwrap()
+}
+
+/// Fetch and unpack a job queue
+pub async fn get_job_queue(banks_client: &mut BanksClient, pubkey: Pubkey) -> JobQueue {
+    let account = get_account(banks_client, pubkey).await;
+    JobQueue::unpack(&account.data).unwrap()
+}
+
+/// Fetch and unpack a job tree
+pub async fn get_job_tree(banks_client: &mut BanksClient, pubkey: Pubkey) -> JobTree {
+    let account = get_account(banks_client, pubkey).await;
+    JobTree::unpack(&account.data).unwrap()
+}
+
+/// Fetch and unpack a session key
+pub async fn get_session_key(banks_client: &mut BanksClient, pubkey: Pubkey) -> SessionKey {
+    let account = get_account(banks_client, pubkey).await;
+    SessionKey::unpack(&account.data).unwrap()
+}
+
+/// Create the ed25519 program instruction verifying an authority's signature of a voucher
+pub fn sign_voucher(authority: &Keypair, voucher: &Voucher) -> Instruction {
+    let message = voucher.message();
+    let signature = authority.sign_message(&message);
+    ed25519_verify(
+        &authority.pubkey(),
+        signature.as_ref().try_into().unwrap(),
+        &message,
+    )
+}
+
+/// Find the job PDA of an escrow, authority and job identifier
+pub fn find_job_address(escrow: Pubkey, authority: Pubkey, id: u64) -> (Pubkey, u8) {
+    pda::find_job_address(&crate::id(), &escrow, &authority, id)
+}
+
+/// Find the PDA of the escrow shard a job is funded through
+pub fn find_escrow_shard_address(escrow: Pubkey, job: Pubkey) -> (Pubkey, u8) {
+    pda::find_escrow_shard_address(&crate::id(), &escrow, EscrowShard::index_for_job(&job))
+}
+
+/// Find the verifier registry PDA of an escrow
+pub fn find_verifier_registry_address(escrow: Pubkey) -> (Pubkey, u8) {
+    pda::find_verifier_registry_address(&crate::id(), &escrow)
+}
+
+/// Find the verifier selection PDA of a job
+pub fn find_verifier_selection_address(job: Pubkey) -> (Pubkey, u8) {
+    pda::find_verifier_selection_address(&crate::id(), &job)
+}
+
+/// Find the config PDA
+pub fn find_config_address() -> (Pubkey, u8) {
+    pda::find_config_address(&crate::id())
+}
+
+/// Add the program data account the upgradeable BPF loader would create for the RNDR program,
+/// with an upgrade authority or none if the program is immutable
+pub fn add_program_data(test: &mut ProgramTest, upgrade_authority: Option<Pubkey>) -> Pubkey {
+    let (pubkey, _bump_seed) = pda::find_program_data_address(&crate::id());
+
+    // Bincode serialized `UpgradeableLoaderState::ProgramData` without the program's bytecode
+    let mut data = vec![0; 45];
+    data[..4].copy_from_slice(&3u32.to_le_bytes());
+    if let Some(upgrade_authority) = upgrade_authority {
+        data[12] = 1;
+        data[13..].copy_from_slice(upgrade_authority.as_ref());
+    }
+
+    let mut account = Account::new(u32::MAX as u64, data.len(), &bpf_loader_upgradeable::id());
+    account.data = data;
+    test.add_account(pubkey, account);
+
+    pubkey
+}
+
+/// Find the dispute PDA of a job
+pub fn find_dispute_address(job: Pubkey) -> (Pubkey, u8) {
+    pda::find_dispute_address(&crate::id(), &job)
+}
+
+/// Find the proposal PDA of an escrow and proposal identifier
+pub fn find_proposal_address(escrow: Pubkey, proposal_id: u64) -> (Pubkey, u8) {
+    pda::find_proposal_address(&crate::id(), &escrow, proposal_id)
+}
+
+/// Find the queued action PDA of a target escrow or config and action identifier
+pub fn find_queued_action_address(target: Pubkey, action_id: u64) -> (Pubkey, u8) {
+    pda::find_queued_action_address(&crate::id(), &target, action_id)
+}
+
+/// Find the recovery PDA of an escrow
+pub fn find_recovery_address(escrow: Pubkey) -> (Pubkey, u8) {
+    pda::find_recovery_address(&crate::id(), &escrow)
+}
+
+/// Find the reward pool PDA of an escrow and epoch
+pub fn find_reward_pool_address(escrow: Pubkey, epoch: u64) -> (Pubkey, u8) {
+    pda::find_reward_pool_address(&crate::id(), &escrow, epoch)
+}
+
+/// Find the epoch work PDA of a reward pool and node
+pub fn find_epoch_work_address(reward_pool: Pubkey, node: Pubkey) -> (Pubkey, u8) {
+    pda::find_epoch_work_address(&crate::id(), &reward_pool, &node)
+}
+
+/// Find the emission schedule PDA of an escrow
+pub fn find_emission_schedule_address(escrow: Pubkey) -> (Pubkey, u8) {
+    pda::find_emission_schedule_address(&crate::id(), &escrow)
+}
+
+/// Find the node PDA of an escrow and node authority
+pub fn find_node_address(escrow: Pubkey, authority: Pubkey) -> (Pubkey, u8) {
+    pda::find_node_address(&crate::id(), &escrow, &authority)
+}
+
+/// Find the stake pool PDA of an escrow
+pub fn find_stake_pool_address(escrow: Pubkey) -> (Pubkey, u8) {
+    pda::find_stake_pool_address(&crate::id(), &escrow)
+}
+
+/// Find the stake receipt mint PDA of a stake pool
+pub fn find_stake_receipt_mint_address(stake_pool: Pubkey) -> (Pubkey, u8) {
+    pda::find_stake_receipt_mint_address(&crate::id(), &stake_pool)
+}
+
+/// Find the crank vault PDA of an escrow
+pub fn find_crank_vault_address(escrow: Pubkey) -> (Pubkey, u8) {
+    pda::find_crank_vault_address(&crate::id(), &escrow)
+}
+
+/// Find the treasury PDA of an escrow
+pub fn find_treasury_address(escrow: Pubkey) -> (Pubkey, u8) {
+    pda::find_treasury_address(&crate::id(), &escrow)
+}
+
+/// Find the stats PDA of an escrow
+pub fn find_stats_address(escrow: Pubkey) -> (Pubkey, u8) {
+    pda::find_stats_address(&crate::id(), &escrow)
+}
+
+/// Find the voucher nonce PDA of an escrow and job authority
+pub fn find_voucher_nonce_address(escrow: Pubkey, authority: Pubkey) -> (Pubkey, u8) {
+    pda::find_voucher_nonce_address(&crate::id(), &escrow, &authority)
+}
+
+/// Find the delegate allowance PDA of an escrow and delegate
+pub fn find_delegate_allowance_address(escrow: Pubkey, delegate: Pubkey) -> (Pubkey, u8) {
+    pda::find_delegate_allowance_address(&crate::id(), &escrow, &delegate)
+}
+
+/// Find the subscription PDA of an escrow, job authority and job identifier
+pub fn find_subscription_address(escrow: Pubkey, authority: Pubkey, job_id: u64) -> (Pubkey, u8) {
+    pda::find_subscription_address(&crate::id(), &escrow, &authority, job_id)
+}
+
+/// Find the job bundle PDA of an escrow, job authority and bundle identifier
+pub fn find_job_bundle_address(escrow: Pubkey, authority: Pubkey, bundle_id: u64) -> (Pubkey, u8) {
+    pda::find_job_bundle_address(&crate::id(), &escrow, &authority, bundle_id)
+}
+
+/// Find the job index PDA of an escrow and job authority
+pub fn find_job_index_address(escrow: Pubkey, authority: Pubkey) -> (Pubkey, u8) {
+    pda::find_job_index_address(&crate::id(), &escrow, &authority)
+}
+
+/// Find the job index page PDA of a job index and page number
+pub fn find_job_index_page_address(job_index: Pubkey, page: u32) -> (Pubkey, u8) {
+    pda::find_job_index_page_address(&crate::id(), &job_index, page)
+}
+
+/// Find the job queue PDA of an escrow
+pub fn find_job_queue_address(escrow: Pubkey) -> (Pubkey, u8) {
+    pda::find_job_queue_address(&crate::id(), &escrow)
+}
+
+/// Find the receipt authority PDA
+pub fn find_receipt_authority_address() -> (Pubkey, u8) {
+    pda::find_receipt_authority_address(&crate::id())
+}
+
+/// Find the job tree PDA of an escrow and job authority
+pub fn find_job_tree_address(escrow: Pubkey, authority: Pubkey) -> (Pubkey, u8) {
+    pda::find_job_tree_address(&crate::id(), &escrow, &authority)
+}
+
+/// Find the session key PDA of an escrow, job authority and session key
+pub fn find_session_key_address(
+    escrow: Pubkey,
+    authority: Pubkey,
+    session_key: Pubkey,
+) -> (Pubkey, u8) {
+    pda::find_session_key_address(&crate::id(), &escrow, &authority, &session_key)
+}
+
+/// Find the Merkle distribution PDA of an escrow and epoch
+pub fn find_merkle_distribution_address(escrow: Pubkey, epoch: u64) -> (Pubkey, u8) {
+    pda::find_merkle_distribution_address(&crate::id(), &escrow, epoch)
+}
+
+/// Find the PDA of a mint migration of an escrow by its number
+pub fn find_mint_migration_address(escrow: Pubkey, index: u8) -> (Pubkey, u8) {
+    pda::find_mint_migration_address(&crate::id(), &escrow, index)
+}
+
+/// Find the claim bitmap PDA of a Merkle distribution covering a leaf index
+pub fn find_claim_bitmap_address(merkle_distribution: Pubkey, index: u32) -> (Pubkey, u8) {
+    pda::find_claim_bitmap_address(
+        &crate::id(),
+        &merkle_distribution,
+        ClaimBitmap::chunk_for_index(index),
+    )
+}
+
+/// Config with the default parameters, a guardian and a compliance authority
+pub struct TestConfig {
+    /// Address of the config
+    pub pubkey: Pubkey,
+    /// Governance authority of the config
+    pub governance: Keypair,
+    /// Guardian authority of the config
+    pub guardian: Keypair,
+    /// Compliance authority of the config
+    pub compliance: Keypair,
+}
+
+impl TestConfig {
+    /// Add the config with the default parameters and a guardian
+    pub fn add(test: &mut ProgramTest) -> Self {
+        Self::add_with_instant_owner_change(test, false)
+    }
+
+    /// Add the config with the default parameters and a guardian, allowing or disallowing
+    /// instant owner changes
+    pub fn add_with_instant_owner_change(
+        test: &mut ProgramTest,
+        instant_owner_change: bool,
+    ) -> Self {
+        Self::add_with(test, |config| {
+            config.instant_owner_change = instant_owner_change;
+        })
+    }
+
+    /// Add the config with the default parameters, a guardian and reward tiers
+    pub fn add_with_reward_tiers(
+        test: &mut ProgramTest,
+        reward_tiers: [RewardTier; MAX_REWARD_TIERS],
+    ) -> Self {
+        Self::add_with(test, |config| config.reward_tiers = reward_tiers)
+    }
+
+    /// Add the config with the default parameters, a guardian and a cancellation grace period
+    /// and fee
+    pub fn add_with_cancellation(
+        test: &mut ProgramTest,
+        cancel_grace_period: u64,
+        cancel_fee_bps: Rate,
+    ) -> Self {
+        Self::add_with(test, |config| {
+            config.cancel_grace_period = cancel_grace_period;
+            config.cancel_fee_bps = cancel_fee_bps;
+        })
+    }
+
+    /// Add the config with the default parameters, a guardian, a cancellation fee without a grace
+    /// period and a rounding policy
+    pub fn add_with_rounding_policy(
+        test: &mut ProgramTest,
+        cancel_fee_bps: Rate,
+        rounding_policy: RoundingPolicy,
+    ) -> Self {
+        Self::add_with(test, |config| {
+            config.cancel_fee_bps = cancel_fee_bps;
+            config.rounding_policy = rounding_policy;
+        })
+    }
+
+    /// Add the config with the default parameters, a guardian, a cancellation fee without a grace
+    /// period and a minimum disbursement
+    pub fn add_with_min_disbursement(
+        test: &mut ProgramTest,
+        cancel_fee_bps: Rate,
+        min_disbursement: u64,
+    ) -> Self {
+        Self::add_with(test, |config| {
+            config.cancel_fee_bps = cancel_fee_bps;
+            config.min_disbursement = min_disbursement;
+        })
+    }
+
+    /// Add the config with the default parameters, a guardian and a priority surcharge and claim
+    /// delay
+    pub fn add_with_priority(
+        test: &mut ProgramTest,
+        priority_surcharge_bps: Rate,
+        priority_claim_delay: u64,
+    ) -> Self {
+        Self::add_with(test, |config| {
+            config.priority_surcharge_bps = priority_surcharge_bps;
+            config.priority_claim_delay = priority_claim_delay;
+        })
+    }
+
+    /// Add the config with the default parameters, a guardian and OctaneBench-hour prices
+    pub fn add_with_obh_prices(test: &mut ProgramTest, obh_prices: [u64; MAX_PRICE_TIERS]) -> Self {
+        Self::add_with(test, |config| config.obh_prices = obh_prices)
+    }
+
+    /// Add the config with the default parameters, a guardian and oracle price guards
+    pub fn add_with_price_guards(
+        test: &mut ProgramTest,
+        max_price_age: u64,
+        max_price_confidence_bps: Rate,
+    ) -> Self {
+        Self::add_with(test, |config| {
+            config.max_price_age = max_price_age;
+            config.max_price_confidence_bps = max_price_confidence_bps;
+        })
+    }
+
+    /// Add the config with the default parameters, a guardian and a timelock duration
+    pub fn add_with_timelock(test: &mut ProgramTest, timelock_duration: u64) -> Self {
+        Self::add_with(test, |config| config.timelock_duration = timelock_duration)
+    }
+
+    /// Add the config with the default parameters, a guardian and no timelocked fields, with the
+    /// legacy length it had before the rounding policy was appended
+    pub fn add_legacy(test: &mut ProgramTest) -> Self {
+        let (test_config, mut config) = Self::new();
+        config.timelocked_fields = 0;
+        let mut data = vec![0; Config::LEN];
+        config.pack_into_slice(&mut data);
+        data.truncate(Config::LEGACY_LEN);
+
+        let mut account = Account::new(u32::MAX as u64, Config::LEGACY_LEN, &crate::id());
+        account.data = data;
+        test.add_account(test_config.pubkey, account);
+
+        test_config
+    }
+
+    fn add_with(test: &mut ProgramTest, f: impl FnOnce(&mut Config)) -> Self {
+        let (test_config, mut config) = Self::new();
+        f(&mut config);
+        test.add_packable_account(test_config.pubkey, u32::MAX as u64, &config, &crate::id());
+
+        test_config
+    }
+
+    fn new() -> (Self, Config) {
+        let governance = Keypair::new();
+        let guardian = Keypair::new();
+        let compliance = Keypair::new();
+
+        let (pubkey, _bump_seed) = find_config_address();
+
+        let mut config = Config::new(InitConfigParams {
+            governance: governance.pubkey(),
+        });
+        config.guardian = guardian.pubkey();
+        config.compliance = compliance.pubkey();
+
+        (
+            Self {
+                pubkey,
+                governance,
+                guardian,
+                compliance,
+            },
+            config,
+        )
+    }
+
+    /// Fetch the config
+    pub async fn get(&self, banks_client: &mut BanksClient) -> Config {
+        get_config(banks_client).await
+    }
+}
+
+/// Initialized token mint
+pub struct TestMint {
+    /// Address of the mint
+    pub pubkey: Pubkey,
+    /// Mint authority
+    pub authority: Keypair,
+    /// Number of decimals of the mint
+    pub decimals: u8,
+}
+
+impl TestMint {
+    /// Add a mint with a supply of tokens
+    pub fn add(test: &mut ProgramTest, decimals: u8, supply: u64) -> Self {
+        let pubkey = Pubkey::new_unique();
+        let authority = Keypair::new();
+
+        test.add_packable_account(
+            pubkey,
+            u32::MAX as u64,
+            &Mint {
+                is_initialized: true,
+                decimals,
+                mint_authority: COption::Some(authority.pubkey()),
+                supply,
+                ..Mint::default()
+            },
+            &spl_token::id(),
+        );
+
+        Self {
+            pubkey,
+            authority,
+            decimals,
+        }
+    }
+
+    /// Fetch the mint
+    pub async fn get(&self, banks_client: &mut BanksClient) -> Mint {
+        get_mint(banks_client, self.pubkey).await
+    }
+}
+
+/// Add a token account of a mint owned by an address without a keypair, such as a PDA of another
+/// program, holding an amount of tokens with a delegate approved to transfer all of them
+pub fn add_delegated_token_account(
+    test: &mut ProgramTest,
+    mint: Pubkey,
+    owner: Pubkey,
+    amount: u64,
+    delegate: Pubkey,
+) -> Pubkey {
+    let pubkey = Pubkey::new_unique();
+
+    test.add_packable_account(
+        pubkey,
+        u32::MAX as u64,
+        &Token {
+            mint,
+            owner,
+            amount,
+            delegate: COption::Some(delegate),
+            state: AccountState::Initialized,
+            is_native: COption::None,
+            delegated_amount: amount,
+            ..Token::default()
+        },
+        &spl_token::id(),
+    );
+
+    pubkey
+}
+
+/// Oracle price account in the layout of a Pyth price account
+pub struct TestPriceFeed {
+    /// Address of the price account
+    pub pubkey: Pubkey,
+}
+
+impl TestPriceFeed {
+    /// Length of a Pyth price account
+    pub const LEN: usize = 3312;
+
+    /// Add a price account whose aggregate price is trading
+    pub fn add(test: &mut ProgramTest, oracle_price: OraclePrice) -> Self {
+        Self::add_with_status(test, oracle_price, OraclePrice::TRADING_STATUS)
+    }
+
+    /// Add a price account whose aggregate price has a status
+    pub fn add_with_status(test: &mut ProgramTest, oracle_price: OraclePrice, status: u32) -> Self {
+        let pubkey = Pubkey::new_unique();
+
+        let mut data = vec![0; Self::LEN];
+        let mut write = |offset: usize, bytes: &[u8]| {
+            data[offset..offset + bytes.len()].copy_from_slice(bytes);
+        };
+        write(OraclePrice::MAGIC_OFFSET, &OraclePrice::MAGIC.to_le_bytes());
+        write(
+            OraclePrice::VERSION_OFFSET,
+            &OraclePrice::VERSION.to_le_bytes(),
+        );
+        write(
+            OraclePrice::ACCOUNT_TYPE_OFFSET,
+            &OraclePrice::PRICE_ACCOUNT_TYPE.to_le_bytes(),
+        );
+        write(
+            OraclePrice::EXPONENT_OFFSET,
+            &oracle_price.exponent.to_le_bytes(),
+        );
+        write(OraclePrice::PRICE_OFFSET, &oracle_price.price.to_le_bytes());
+        write(
+            OraclePrice::CONFIDENCE_OFFSET,
+            &oracle_price.confidence.to_le_bytes(),
+        );
+        write(OraclePrice::STATUS_OFFSET, &status.to_le_bytes());
+        write(
+            OraclePrice::PUBLISH_SLOT_OFFSET,
+            &oracle_price.publish_slot.to_le_bytes(),
+        );
+
+        let mut account = Account::new(u32::MAX as u64, Self::LEN, &Pubkey::new_unique());
+        account.data = data;
+        test.add_account(pubkey, account);
+
+        Self { pubkey }
+    }
+}
+
+/// Initialized token account funded with tokens
+pub struct TestToken {
+    /// Address of the token account
+    pub pubkey: Pubkey,
+    /// Mint of the token account
+    pub mint: Pubkey,
+    /// Owner of the token account
+    pub owner: Keypair,
+}
+
+impl TestToken {
+    /// Add a token account of a mint holding an amount of tokens
+    pub fn add(test: &mut ProgramTest, mint: Pubkey, amount: u64) -> Self {
+        let pubkey = Pubkey::new_unique();
+        let owner = Keypair::new();
+
+        test.add_packable_account(
+            pubkey,
+            u32::MAX as u64,
+            &Token {
+                mint,
+                owner: owner.pubkey(),
+                amount,
+                state: AccountState::Initialized,
+                is_native: COption::None,
+                ..Token::default()
+            },
+            &spl_token::id(),
+        );
+
+        Self {
+            pubkey,
+            mint,
+            owner,
+        }
+    }
+
+    /// Add a token account of a mint holding an amount of tokens, with a delegate approved to
+    /// transfer some of them
+    pub fn add_delegated(
+        test: &mut ProgramTest,
+        mint: Pubkey,
+        amount: u64,
+        delegate: Pubkey,
+        delegated_amount: u64,
+    ) -> Self {
+        let pubkey = Pubkey::new_unique();
+        let owner = Keypair::new();
+
+        test.add_packable_account(
+            pubkey,
+            u32::MAX as u64,
+            &Token {
+                mint,
+                owner: owner.pubkey(),
+                amount,
+                delegate: COption::Some(delegate),
+                state: AccountState::Initialized,
+                is_native: COption::None,
+                delegated_amount,
+                ..Token::default()
+            },
+            &spl_token::id(),
+        );
+
+        Self {
+            pubkey,
+            mint,
+            owner,
+        }
+    }
+
+    /// Add the associated token account of an owner for a mint holding an amount of tokens
+    pub fn add_associated(
+        test: &mut ProgramTest,
+        mint: Pubkey,
+        owner: Keypair,
+        amount: u64,
+    ) -> Self {
+        let pubkey = get_associated_token_address(&owner.pubkey(), &mint);
+
+        test.add_packable_account(
+            pubkey,
+            u32::MAX as u64,
+            &Token {
+                mint,
+                owner: owner.pubkey(),
+                amount,
+                state: AccountState::Initialized,
+                is_native: COption::None,
+                ..Token::default()
+            },
+            &spl_token::id(),
+        );
+
+        Self {
+            pubkey,
+            mint,
+            owner,
+        }
+    }
+
+    /// Fetch the token account
+    pub async fn get(&self, banks_client: &mut BanksClient) -> Token {
+        get_token(banks_client, self.pubkey).await
+    }
+}
+
+/// Initialized escrow with its associated token account
+pub struct TestEscrow {
+    /// Address of the escrow
+    pub pubkey: Pubkey,
+    /// Associated token account of the escrow
+    pub associated_token: Pubkey,
+    /// Owner authority of the escrow
+    pub owner: Keypair,
+}
+
+impl TestEscrow {
+    /// Add the escrow of a token mint holding an amount of tokens, already aggregated
+    pub fn add(test: &mut ProgramTest, token_mint: Pubkey, amount: u64) -> Self {
+        Self::add_with_challenge_window(test, token_mint, amount, 0)
+    }
+
+    /// Add the escrow of a token mint holding an amount of tokens, with a challenge window
+    pub fn add_with_challenge_window(
+        test: &mut ProgramTest,
+        token_mint: Pubkey,
+        amount: u64,
+        challenge_window: u64,
+    ) -> Self {
+        let (test_escrow, mut escrow) = Self::add_token(test, token_mint, amount);
+        escrow.challenge_window = challenge_window;
+        test.add_packable_account(test_escrow.pubkey, u32::MAX as u64, &escrow, &crate::id());
+        test_escrow
+    }
+
+    /// Add the escrow of a token mint holding an amount of tokens, with a proof verifier program
+    pub fn add_with_proof_verifier(
+        test: &mut ProgramTest,
+        token_mint: Pubkey,
+        amount: u64,
+        proof_verifier: Pubkey,
+    ) -> Self {
+        let (test_escrow, mut escrow) = Self::add_token(test, token_mint, amount);
+        escrow.proof_verifier = proof_verifier;
+        test.add_packable_account(test_escrow.pubkey, u32::MAX as u64, &escrow, &crate::id());
+        test_escrow
+    }
+
+    /// Add the escrow of a token mint holding an amount of tokens, with an arbitrator
+    pub fn add_with_arbitrator(
+        test: &mut ProgramTest,
+        token_mint: Pubkey,
+        amount: u64,
+        arbitrator: Pubkey,
+    ) -> Self {
+        let (test_escrow, mut escrow) = Self::add_token(test, token_mint, amount);
+        escrow.arbitrator = arbitrator;
+        test.add_packable_account(test_escrow.pubkey, u32::MAX as u64, &escrow, &crate::id());
+        test_escrow
+    }
+
+    /// Add the escrow of a token mint holding an amount of tokens, with an approver of
+    /// disbursements
+    pub fn add_with_approver(
+        test: &mut ProgramTest,
+        token_mint: Pubkey,
+        amount: u64,
+        approver: Pubkey,
+    ) -> Self {
+        let (test_escrow, mut escrow) = Self::add_token(test, token_mint, amount);
+        escrow.approver = approver;
+        test.add_packable_account(test_escrow.pubkey, u32::MAX as u64, &escrow, &crate::id());
+        test_escrow
+    }
+
+    /// Add the escrow of a token mint holding an amount of tokens, with a price feed
+    pub fn add_with_price_feed(
+        test: &mut ProgramTest,
+        token_mint: Pubkey,
+        amount: u64,
+        price_feed: Pubkey,
+    ) -> Self {
+        let (test_escrow, mut escrow) = Self::add_token(test, token_mint, amount);
+        escrow.price_feed = price_feed;
+        test.add_packable_account(test_escrow.pubkey, u32::MAX as u64, &escrow, &crate::id());
+        test_escrow
+    }
+
+    /// Add the escrow of a token mint created before fields were appended to its layout
+    pub fn add_legacy(test: &mut ProgramTest, token_mint: Pubkey, amount: u64) -> Self {
+        let (test_escrow, escrow) = Self::add_token(test, token_mint, amount);
+        let mut data = vec![0; Escrow::LEN];
+        escrow.pack_into_slice(&mut data);
+        data.truncate(Escrow::LEGACY_LEN);
+
+        let mut account = Account::new(u32::MAX as u64, Escrow::LEGACY_LEN, &crate::id());
+        account.data = data;
+        test.add_account(test_escrow.pubkey, account);
+
+        test_escrow
+    }
+
+    fn add_token(test: &mut ProgramTest, token_mint: Pubkey, amount: u64) -> (Self, Escrow) {
+        let owner = Keypair::new();
+
+        let (pubkey, _bump_seed) = pda::find_escrow_address(&crate::id(), &token_mint);
+
+        let associated_token = get_associated_token_address(&pubkey, &token_mint);
+
+        test.add_packable_account(
+            associated_token,
+            u32::MAX as u64,
+            &Token {
+                mint: token_mint,
+                owner: pubkey,
+                amount,
+                state: AccountState::Initialized,
+                is_native: COption::None,
+                ..Token::default()
+            },
+            &spl_token::id(),
+        );
+
+        let mut escrow = Escrow::new(InitEscrowParams {
+            owner: owner.pubkey(),
+            token_mint,
+            escrow_id: 0,
+        });
+        escrow.amount = amount;
+
+        (
+            Self {
+                pubkey,
+                associated_token,
+                owner,
+            },
+            escrow,
+        )
+    }
+
+    /// Add a token account of a mint owned by the escrow other than its associated token account,
+    /// holding an amount of tokens
+    pub fn add_token_account(
+        &self,
+        test: &mut ProgramTest,
+        token_mint: Pubkey,
+        amount: u64,
+    ) -> Pubkey {
+        let pubkey = Pubkey::new_unique();
+
+        test.add_packable_account(
+            pubkey,
+            u32::MAX as u64,
+            &Token {
+                mint: token_mint,
+                owner: self.pubkey,
+                amount,
+                state: AccountState::Initialized,
+                is_native: COption::None,
+                ..Token::default()
+            },
+            &spl_token::id(),
+        );
+
+        pubkey
+    }
+
+    /// Fetch the escrow
+    pub async fn get(&self, banks_client: &mut BanksClient) -> Escrow {
+        get_escrow(banks_client, self.pubkey).await
+    }
+}
+
+/// Job with identifier 0 funded with tokens
+pub struct TestJob {
+    /// Address of the job
+    pub pubkey: Pubkey,
+    /// Authority of the job
+    pub authority: Pubkey,
+}
+
+impl TestJob {
+    /// Add a job of an authority credited with an amount of tokens held by the escrow
+    pub fn add(test: &mut ProgramTest, escrow: Pubkey, authority: Pubkey, amount: u64) -> Self {
+        let (pubkey, _bump_seed) = find_job_address(escrow, authority, 0);
+
+        let mut job = Job::new(InitJobParams {
+            authority,
+            id: 0,
+            isolated: false,
+        });
+        job.amount = amount;
+        test.add_packable_account(pubkey, u32::MAX as u64, &job, &crate::id());
+
+        Self { pubkey, authority }
+    }
+
+    /// Add an isolated job of an authority holding an amount of tokens in its own token account
+    pub fn add_isolated(
+        test: &mut ProgramTest,
+        escrow: Pubkey,
+        token_mint: Pubkey,
+        authority: Pubkey,
+        amount: u64,
+    ) -> Self {
+        let (pubkey, _bump_seed) = find_job_address(escrow, authority, 0);
+
+        test.add_packable_account(
+            get_associated_token_address(&pubkey, &token_mint),
+            u32::MAX as u64,
+            &Token {
+                mint: token_mint,
+                owner: pubkey,
+                amount,
+                state: AccountState::Initialized,
+                is_native: COption::None,
+                ..Token::default()
+            },
+            &spl_token::id(),
+        );
+
+        let mut job = Job::new(InitJobParams {
+            authority,
+            id: 0,
+            isolated: true,
+        });
+        job.amount = amount;
+        test.add_packable_account(pubkey, u32::MAX as u64, &job, &crate::id());
+
+        Self { pubkey, authority }
+    }
+
+    /// Add a job of an authority with an identifier and status, credited with an amount of tokens
+    /// held by the escrow
+    pub fn add_with_id(
+        test: &mut ProgramTest,
+        escrow: Pubkey,
+        authority: Pubkey,
+        id: u64,
+        status: JobStatus,
+        amount: u64,
+    ) -> Self {
+        let (pubkey, _bump_seed) = find_job_address(escrow, authority, id);
+
+        let mut job = Job::new(InitJobParams {
+            authority,
+            id,
+            isolated: false,
+        });
+        job.amount = amount;
+        job.status = status;
+        test.add_packable_account(pubkey, u32::MAX as u64, &job, &crate::id());
+
+        Self { pubkey, authority }
+    }
+
+    /// Add a job of an authority created before fields were appended to its layout
+    pub fn add_legacy(
+        test: &mut ProgramTest,
+        escrow: Pubkey,
+        authority: Pubkey,
+        amount: u64,
+    ) -> Self {
+        let (pubkey, _bump_seed) = find_job_address(escrow, authority, 0);
+
+        let mut job = Job::new(InitJobParams {
+            authority,
+            id: 0,
+            isolated: false,
+        });
+        job.amount = amount;
+        let mut data = vec![0; Job::LEN];
+        job.pack_into_slice(&mut data);
+        data.truncate(Job::LEGACY_LEN);
+
+        let mut account = Account::new(u32::MAX as u64, Job::LEGACY_LEN, &crate::id());
+        account.data = data;
+        test.add_account(pubkey, account);
+
+        Self { pubkey, authority }
+    }
+
+    /// Add a job of an authority that a node has committed to a result for
+    pub fn add_committed(
+        test: &mut ProgramTest,
+        escrow: Pubkey,
+        authority: Pubkey,
+        node: Pubkey,
+        commitment: [u8; HASH_BYTES],
+        amount: u64,
+    ) -> Self {
+        Self::add_with_result(test, escrow, authority, amount, |job| {
+            job.status = JobStatus::Committed;
+            job.node = node;
+            job.result_commitment = commitment;
+        })
+    }
+
+    /// Add a job of an authority first funded at a timestamp that a node has committed to a
+    /// result for
+    pub fn add_committed_funded_at(
+        test: &mut ProgramTest,
+        escrow: Pubkey,
+        authority: Pubkey,
+        node: Pubkey,
+        amount: u64,
+        funded_at: UnixTimestamp,
+    ) -> Self {
+        Self::add_with_result(test, escrow, authority, amount, |job| {
+            job.status = JobStatus::Committed;
+            job.node = node;
+            job.result_commitment = [1; HASH_BYTES];
+            job.funded_at = funded_at;
+        })
+    }
+
+    /// Add a job of an authority that a node has revealed a result for
+    pub fn add_revealed(
+        test: &mut ProgramTest,
+        escrow: Pubkey,
+        authority: Pubkey,
+        node: Pubkey,
+        amount: u64,
+    ) -> Self {
+        Self::add_with_result(test, escrow, authority, amount, |job| {
+            job.status = JobStatus::Revealed;
+            job.node = node;
+            job.result_commitment = [1; HASH_BYTES];
+            job.result_hash = [2; HASH_BYTES];
+        })
+    }
+
+    /// Add a job of an authority whose revealed result is disputed
+    pub fn add_disputed(
+        test: &mut ProgramTest,
+        escrow: Pubkey,
+        authority: Pubkey,
+        node: Pubkey,
+        amount: u64,
+    ) -> Self {
+        Self::add_with_result(test, escrow, authority, amount, |job| {
+            job.status = JobStatus::Disputed;
+            job.node = node;
+            job.result_commitment = [1; HASH_BYTES];
+            job.result_hash = [2; HASH_BYTES];
+        })
+    }
+
+    fn add_with_result(
+        test: &mut ProgramTest,
+        escrow: Pubkey,
+        authority: Pubkey,
+        amount: u64,
+        set_result: impl FnOnce(&mut Job),
+    ) -> Self {
+        let (pubkey, _bump_seed) = find_job_address(escrow, authority, 0);
+
+        let mut job = Job::new(InitJobParams {
+            authority,
+            id: 0,
+            isolated: false,
+        });
+        job.amount = amount;
+        set_result(&mut job);
+        test.add_packable_account(pubkey, u32::MAX as u64, &job, &crate::id());
+
+        Self { pubkey, authority }
+    }
+
+    /// Fetch the job
+    pub async fn get(&self, banks_client: &mut BanksClient) -> Job {
+        get_job(banks_client, self.pubkey).await
+    }
+}
+
+/// Escrow shard with tokens not yet aggregated
+pub struct TestEscrowShard {
+    /// Address of the escrow shard
+    pub pubkey: Pubkey,
+    /// Escrow the shard belongs to
+    pub escrow: Pubkey,
+    /// Index of the shard
+    pub index: u8,
+}
+
+impl TestEscrowShard {
+    /// Add an escrow shard credited with an amount of tokens
+    pub fn add(test: &mut ProgramTest, escrow: Pubkey, index: u8, amount: u64) -> Self {
+        let (pubkey, _bump_seed) = pda::find_escrow_shard_address(&crate::id(), &escrow, index);
+
+        let mut escrow_shard = EscrowShard::new(InitEscrowShardParams { escrow, index });
+        escrow_shard.amount = amount;
+        test.add_packable_account(pubkey, u32::MAX as u64, &escrow_shard, &crate::id());
+
+        Self {
+            pubkey,
+            escrow,
+            index,
+        }
+    }
+
+    /// Fetch the escrow shard
+    pub async fn get(&self, banks_client: &mut BanksClient) -> EscrowShard {
+        get_escrow_shard(banks_client, self.pubkey).await
+    }
+}
+
+/// Verifier registry of an escrow
+pub struct TestVerifierRegistry {
+    /// Address of the verifier registry
+    pub pubkey: Pubkey,
+    /// Escrow the registry belongs to
+    pub escrow: Pubkey,
+}
+
+impl TestVerifierRegistry {
+    /// Add the verifier registry of an escrow with verifiers registered
+    pub fn add(test: &mut ProgramTest, escrow: Pubkey, verifiers: Vec<Pubkey>) -> Self {
+        let (pubkey, _bump_seed) = find_verifier_registry_address(escrow);
+
+        let mut verifier_registry = VerifierRegistry::new(InitVerifierRegistryParams { escrow });
+        verifier_registry.verifiers = verifiers;
+        test.add_packable_account(pubkey, u32::MAX as u64, &verifier_registry, &crate::id());
+
+        Self { pubkey, escrow }
+    }
+
+    /// Fetch the verifier registry
+    pub async fn get(&self, banks_client: &mut BanksClient) -> VerifierRegistry {
+        get_verifier_registry(banks_client, self.pubkey).await
+    }
+}
+
+/// Dispute over a job
+pub struct TestDispute {
+    /// Address of the dispute
+    pub pubkey: Pubkey,
+    /// Job the dispute is over
+    pub job: Pubkey,
+}
+
+impl TestDispute {
+    /// Add a dispute opened over a job, which must also be added with its status set to disputed
+    pub fn add(test: &mut ProgramTest, job: Pubkey, opener: Pubkey) -> Self {
+        let (pubkey, _bump_seed) = find_dispute_address(job);
+
+        let dispute = Dispute::new(InitDisputeParams {
+            job,
+            opener,
+            opened_at: 0,
+        });
+        test.add_packable_account(pubkey, u32::MAX as u64, &dispute, &crate::id());
+
+        Self { pubkey, job }
+    }
+
+    /// Fetch the dispute
+    pub async fn get(&self, banks_client: &mut BanksClient) -> Dispute {
+        get_dispute(banks_client, self.pubkey).await
+    }
+}
+
+/// Disbursement proposal of a job's funds
+pub struct TestProposal {
+    /// Address of the proposal
+    pub pubkey: Pubkey,
+    /// Identifier of the proposal
+    pub id: u64,
+}
+
+impl TestProposal {
+    /// Add a proposal to disburse an amount of a job's tokens that hasn't been approved
+    pub fn add(
+        test: &mut ProgramTest,
+        escrow: Pubkey,
+        id: u64,
+        job: Pubkey,
+        destination: Pubkey,
+        amount: u64,
+    ) -> Self {
+        Self::add_with_approval(test, escrow, id, job, destination, amount, 0)
+    }
+
+    /// Add a proposal to disburse an amount of a job's tokens that has been approved
+    pub fn add_approved(
+        test: &mut ProgramTest,
+        escrow: Pubkey,
+        id: u64,
+        job: Pubkey,
+        destination: Pubkey,
+        amount: u64,
+    ) -> Self {
+        Self::add_with_approval(test, escrow, id, job, destination, amount, 1)
+    }
+
+    fn add_with_approval(
+        test: &mut ProgramTest,
+        escrow: Pubkey,
+        id: u64,
+        job: Pubkey,
+        destination: Pubkey,
+        amount: u64,
+        approved_at: i64,
+    ) -> Self {
+        let (pubkey, _bump_seed) = find_proposal_address(escrow, id);
+
+        let mut proposal = Proposal::new(InitProposalParams {
+            escrow,
+            id,
+            job,
+            destination,
+            amount,
+        });
+        proposal.approved_at = approved_at;
+        test.add_packable_account(pubkey, u32::MAX as u64, &proposal, &crate::id());
+
+        Self { pubkey, id }
+    }
+
+    /// Fetch the proposal
+    pub async fn get(&self, banks_client: &mut BanksClient) -> Proposal {
+        get_proposal(banks_client, self.pubkey).await
+    }
+}
+
+/// Queued administrative action
+pub struct TestQueuedAction {
+    /// Address of the queued action
+    pub pubkey: Pubkey,
+    /// Identifier of the queued action
+    pub id: u64,
+}
+
+impl TestQueuedAction {
+    /// Add an action queued by an authority, which can be executed from a Unix timestamp
+    pub fn add(
+        test: &mut ProgramTest,
+        target: Pubkey,
+        id: u64,
+        authority: Pubkey,
+        action: AdminAction,
+        executable_at: i64,
+    ) -> Self {
+        let (pubkey, _bump_seed) = find_queued_action_address(target, id);
+
+        let queued_action = QueuedAction::new(InitQueuedActionParams {
+            target,
+            id,
+            authority,
+            action,
+            queued_at: 0,
+            executable_at,
+        });
+        test.add_packable_account(pubkey, u32::MAX as u64, &queued_action, &crate::id());
+
+        Self { pubkey, id }
+    }
+
+    /// Fetch the queued action
+    pub async fn get(&self, banks_client: &mut BanksClient) -> QueuedAction {
+        get_queued_action(banks_client, self.pubkey).await
+    }
+}
+
+/// Recovery council of an escrow
+pub struct TestRecovery {
+    /// Address of the recovery
+    pub pubkey: Pubkey,
+    /// Guardians on the council
+    pub guardians: Vec<Keypair>,
+}
+
+impl TestRecovery {
+    /// Add a council of guardians with no votes
+    pub fn add(
+        test: &mut ProgramTest,
+        escrow: Pubkey,
+        threshold: u8,
+        guardian_count: usize,
+    ) -> Self {
+        Self::add_pending(
+            test,
+            escrow,
+            threshold,
+            guardian_count,
+            Pubkey::default(),
+            0,
+        )
+    }
+
+    /// Add a council of guardians, the threshold of which have voted for a new owner that can be
+    /// set from a Unix timestamp
+    pub fn add_pending(
+        test: &mut ProgramTest,
+        escrow: Pubkey,
+        threshold: u8,
+        guardian_count: usize,
+        new_owner: Pubkey,
+        executable_at: i64,
+    ) -> Self {
+        let guardians: Vec<Keypair> = (0..guardian_count).map(|_| Keypair::new()).collect();
+
+        let (pubkey, _bump_seed) = find_recovery_address(escrow);
+
+        let mut recovery = Recovery::new(InitRecoveryParams {
+            escrow,
+            threshold,
+            guardians: guardians.iter().map(|guardian| guardian.pubkey()).collect(),
+        });
+        if executable_at != 0 {
+            recovery.votes[..threshold as usize].fill(new_owner);
+            recovery.new_owner = new_owner;
+            recovery.executable_at = executable_at;
+        }
+        test.add_packable_account(pubkey, u32::MAX as u64, &recovery, &crate::id());
+
+        Self { pubkey, guardians }
+    }
+
+    /// Fetch the recovery
+    pub async fn get(&self, banks_client: &mut BanksClient) -> Recovery {
+        get_recovery(banks_client, self.pubkey).await
+    }
+}
+
+/// Reward pool of an escrow for an epoch with its associated token account
+pub struct TestRewardPool {
+    /// Address of the reward pool
+    pub pubkey: Pubkey,
+    /// Associated token account of the reward pool
+    pub associated_token: Pubkey,
+}
+
+impl TestRewardPool {
+    /// Add the reward pool of an escrow for an epoch holding an amount of tokens, with the total
+    /// work recorded for the epoch
+    pub fn add(
+        test: &mut ProgramTest,
+        escrow: Pubkey,
+        token_mint: Pubkey,
+        epoch: u64,
+        amount: u64,
+        total_work: u64,
+    ) -> Self {
+        let (pubkey, _bump_seed) = find_reward_pool_address(escrow, epoch);
+
+        let associated_token = get_associated_token_address(&pubkey, &token_mint);
+
+        test.add_packable_account(
+            associated_token,
+            u32::MAX as u64,
+            &Token {
+                mint: token_mint,
+                owner: pubkey,
+                amount,
+                state: AccountState::Initialized,
+                is_native: COption::None,
+                ..Token::default()
+            },
+            &spl_token::id(),
+        );
+
+        let mut reward_pool = RewardPool::new(InitRewardPoolParams { escrow, epoch });
+        reward_pool.amount = amount;
+        reward_pool.total_work = total_work;
+        test.add_packable_account(pubkey, u32::MAX as u64, &reward_pool, &crate::id());
+
+        Self {
+            pubkey,
+            associated_token,
+        }
+    }
+
+    /// Fetch the reward pool
+    pub async fn get(&self, banks_client: &mut BanksClient) -> RewardPool {
+        get_reward_pool(banks_client, self.pubkey).await
+    }
+}
+
+/// Work completed by a node in the epoch of a reward pool
+pub struct TestEpochWork {
+    /// Address of the epoch work
+    pub pubkey: Pubkey,
+}
+
+impl TestEpochWork {
+    /// Add the work completed by a node in the epoch of a reward pool
+    pub fn add(test: &mut ProgramTest, reward_pool: Pubkey, node: Pubkey, work: u64) -> Self {
+        let (pubkey, _bump_seed) = find_epoch_work_address(reward_pool, node);
+
+        let mut epoch_work = EpochWork::new(InitEpochWorkParams { reward_pool, node });
+        epoch_work.work = work;
+        test.add_packable_account(pubkey, u32::MAX as u64, &epoch_work, &crate::id());
+
+        Self { pubkey }
+    }
+
+    /// Fetch the epoch work
+    pub async fn get(&self, banks_client: &mut BanksClient) -> EpochWork {
+        get_epoch_work(banks_client, self.pubkey).await
+    }
+}
+
+/// Merkle tree of node rewards with the proofs of its leaves, for building distributions in tests
+pub struct TestMerkleTree {
+    /// Merkle root of the tree
+    pub root: [u8; HASH_BYTES],
+    /// Proof of each leaf, in the order of the leaves
+    pub proofs: Vec<Vec<[u8; HASH_BYTES]>>,
+}
+
+impl TestMerkleTree {
+    /// Build the tree of `(node, amount)` rewards, indexing the leaves in order
+    pub fn new(rewards: &[(Pubkey, u64)]) -> Self {
+        let hash_pair = |a: [u8; HASH_BYTES], b: [u8; HASH_BYTES]| {
+            if a <= b {
+                hashv(&[&a, &b]).to_bytes()
+            } else {
+                hashv(&[&b, &a]).to_bytes()
+            }
+        };
+
+        let mut level: Vec<_> = rewards
+            .iter()
+            .enumerate()
+            .map(|(index, (node, amount))| MerkleDistribution::leaf(index as u32, node, *amount))
+            .collect();
+        let mut positions: Vec<usize> = (0..rewards.len()).collect();
+        let mut proofs = vec![vec![]; rewards.len()];
+        while level.len() > 1 {
+            for (proof, position) in proofs.iter_mut().zip(&mut positions) {
+                if let Some(sibling) = level.get(*position ^ 1) {
+                    proof.push(*sibling);
+                }
+                *position /= 2;
+            }
+            level = level
+                .chunks(2)
+                .map(|pair| match pair {
+                    [a, b] => hash_pair(*a, *b),
+                    [a] => *a,
+                    _ => unreachable!(),
+                })
+                .collect();
+        }
+
+        Self {
+            root: level[0],
+            proofs,
+        }
+    }
+}
+
+/// Merkle distribution of an escrow for an epoch with its associated token account
+pub struct TestMerkleDistribution {
+    /// Address of the Merkle distribution
+    pub pubkey: Pubkey,
+    /// Associated token account of the Merkle distribution
+    pub associated_token: Pubkey,
+}
+
+impl TestMerkleDistribution {
+    /// Add the Merkle distribution of an escrow for an epoch holding the amount of tokens of its
+    /// leaves
+    pub fn add(
+        test: &mut ProgramTest,
+        escrow: Pubkey,
+        token_mint: Pubkey,
+        epoch: u64,
+        root: [u8; HASH_BYTES],
+        node_count: u32,
+        amount: u64,
+    ) -> Self {
+        let (pubkey, _bump_seed) = find_merkle_distribution_address(escrow, epoch);
+
+        let associated_token = get_associated_token_address(&pubkey, &token_mint);
+
+        test.add_packable_account(
+            associated_token,
+            u32::MAX as u64,
+            &Token {
+                mint: token_mint,
+                owner: pubkey,
+                amount,
+                state: AccountState::Initialized,
+                is_native: COption::None,
+                ..Token::default()
+            },
+            &spl_token::id(),
+        );
+
+        let merkle_distribution = MerkleDistribution::new(InitMerkleDistributionParams {
+            escrow,
+            epoch,
+            root,
+            node_count,
+            amount,
+        });
+        test.add_packable_account(pubkey, u32::MAX as u64, &merkle_distribution, &crate::id());
+
+        Self {
+            pubkey,
+            associated_token,
+        }
+    }
+
+    /// Fetch the Merkle distribution
+    pub async fn get(&self, banks_client: &mut BanksClient) -> MerkleDistribution {
+        get_merkle_distribution(banks_client, self.pubkey).await
+    }
+}
+
+/// Emission schedule of an escrow with its associated token account
+pub struct TestEmissionSchedule {
+    /// Address of the emission schedule
+    pub pubkey: Pubkey,
+    /// Associated token account of the emission schedule
+    pub associated_token: Pubkey,
+}
+
+impl TestEmissionSchedule {
+    /// Add the emission schedule of an escrow, holding an amount of tokens to emit
+    pub fn add(
+        test: &mut ProgramTest,
+        escrow: Pubkey,
+        token_mint: Pubkey,
+        start_epoch: u64,
+        initial_amount: u64,
+        decay_bps: Rate,
+        amount: u64,
+    ) -> Self {
+        let (pubkey, _bump_seed) = find_emission_schedule_address(escrow);
+
+        let associated_token = get_associated_token_address(&pubkey, &token_mint);
+
+        test.add_packable_account(
+            associated_token,
+            u32::MAX as u64,
+            &Token {
+                mint: token_mint,
+                owner: pubkey,
+                amount,
+                state: AccountState::Initialized,
+                is_native: COption::None,
+                ..Token::default()
+            },
+            &spl_token::id(),
+        );
+
+        let emission_schedule = EmissionSchedule::new(InitEmissionScheduleParams {
+            escrow,
+            start_epoch,
+            initial_amount,
+            decay_bps,
+        });
+        test.add_packable_account(pubkey, u32::MAX as u64, &emission_schedule, &crate::id());
+
+        Self {
+            pubkey,
+            associated_token,
+        }
+    }
+
+    /// Fetch the emission schedule
+    pub async fn get(&self, banks_client: &mut BanksClient) -> EmissionSchedule {
+        get_emission_schedule(banks_client, self.pubkey).await
+    }
+}
+
+/// Node of an escrow with its associated token account holding the stake
+pub struct TestNode {
+    /// Address of the node
+    pub pubkey: Pubkey,
+    /// Associated token account of the node
+    pub associated_token: Pubkey,
+}
+
+impl TestNode {
+    /// Add the node of an authority with an amount of tokens staked
+    pub fn add(
+        test: &mut ProgramTest,
+        escrow: Pubkey,
+        token_mint: Pubkey,
+        authority: Pubkey,
+        stake: u64,
+        auto_compound: bool,
+    ) -> Self {
+        let (pubkey, _bump_seed) = find_node_address(escrow, authority);
+
+        let associated_token = get_associated_token_address(&pubkey, &token_mint);
+
+        test.add_packable_account(
+            associated_token,
+            u32::MAX as u64,
+            &Token {
+                mint: token_mint,
+                owner: pubkey,
+                amount: stake,
+                state: AccountState::Initialized,
+                is_native: COption::None,
+                ..Token::default()
+            },
+            &spl_token::id(),
+        );
+
+        let mut node = Node::new(InitNodeParams { escrow, authority });
+        node.stake = stake;
+        node.auto_compound = auto_compound;
+        test.add_packable_account(pubkey, u32::MAX as u64, &node, &crate::id());
+
+        Self {
+            pubkey,
+            associated_token,
+        }
+    }
+
+    /// Add the node of an authority created before the stake epoch fields were appended to its
+    /// layout, with an amount of tokens staked
+    pub fn add_legacy(
+        test: &mut ProgramTest,
+        escrow: Pubkey,
+        token_mint: Pubkey,
+        authority: Pubkey,
+        stake: u64,
+    ) -> Self {
+        let test_node = Self::add(test, escrow, token_mint, authority, stake, false);
+
+        let mut node = Node::new(InitNodeParams { escrow, authority });
+        node.stake = stake;
+        let mut data = vec![0; Node::LEN];
+        node.pack_into_slice(&mut data);
+        data.truncate(Node::LEGACY_LEN);
+
+        let mut account = Account::new(u32::MAX as u64, Node::LEGACY_LEN, &crate::id());
+        account.data = data;
+        test.add_account(test_node.pubkey, account);
+
+        test_node
+    }
+
+    /// Fetch the node
+    pub async fn get(&self, banks_client: &mut BanksClient) -> Node {
+        get_node(banks_client, self.pubkey).await
+    }
+}
+
+/// Crank vault of an escrow
+pub struct TestCrankVault {
+    /// Address of the crank vault
+    pub pubkey: Pubkey,
+}
+
+impl TestCrankVault {
+    /// Add the crank vault of an escrow paying a bounty, holding an amount of lamports above its
+    /// rent exemption
+    pub fn add(test: &mut ProgramTest, escrow: Pubkey, bounty: u64, lamports: u64) -> Self {
+        let (pubkey, _bump_seed) = find_crank_vault_address(escrow);
+
+        let crank_vault = CrankVault::new(InitCrankVaultParams { escrow, bounty });
+        test.add_packable_account(
+            pubkey,
+            Rent::default().minimum_balance(CrankVault::LEN) + lamports,
+            &crank_vault,
+            &crate::id(),
+        );
+
+        Self { pubkey }
+    }
+
+    /// Fetch the crank vault
+    pub async fn get(&self, banks_client: &mut BanksClient) -> CrankVault {
+        get_crank_vault(banks_client, self.pubkey).await
+    }
+}
+
+/// Treasury of an escrow with its associated token account
+pub struct TestTreasury {
+    /// Address of the treasury
+    pub pubkey: Pubkey,
+    /// Associated token account of the treasury
+    pub associated_token: Pubkey,
+}
+
+impl TestTreasury {
+    /// Add the treasury of an escrow with an epoch burn cap, holding an amount of tokens
+    pub fn add(
+        test: &mut ProgramTest,
+        escrow: Pubkey,
+        token_mint: Pubkey,
+        epoch_burn_cap: u64,
+        amount: u64,
+    ) -> Self {
+        let (test_treasury, treasury) = Self::add_token(test, escrow, token_mint, amount);
+        let treasury = Treasury {
+            epoch_burn_cap,
+            ..treasury
+        };
+        test.add_packable_account(
+            test_treasury.pubkey,
+            u32::MAX as u64,
+            &treasury,
+            &crate::id(),
+        );
+        test_treasury
+    }
+
+    /// Add the treasury of an escrow holding an amount of tokens, with a cold wallet registered
+    /// and a sweep of an amount of tokens to it executable from a Unix timestamp
+    pub fn add_with_sweep(
+        test: &mut ProgramTest,
+        escrow: Pubkey,
+        token_mint: Pubkey,
+        amount: u64,
+        cold_wallet: Pubkey,
+        sweep_amount: u64,
+        sweep_executable_at: UnixTimestamp,
+    ) -> Self {
+        let (test_treasury, treasury) = Self::add_token(test, escrow, token_mint, amount);
+        let treasury = Treasury {
+            cold_wallet,
+            sweep_amount,
+            sweep_executable_at,
+            ..treasury
+        };
+        test.add_packable_account(
+            test_treasury.pubkey,
+            u32::MAX as u64,
+            &treasury,
+            &crate::id(),
+        );
+        test_treasury
+    }
+
+    /// Add the treasury of an escrow created before the cold wallet fields were appended to its
+    /// layout, holding an amount of tokens
+    pub fn add_legacy(
+        test: &mut ProgramTest,
+        escrow: Pubkey,
+        token_mint: Pubkey,
+        amount: u64,
+    ) -> Self {
+        let (test_treasury, treasury) = Self::add_token(test, escrow, token_mint, amount);
+        let mut data = vec![0; Treasury::LEN];
+        treasury.pack_into_slice(&mut data);
+        data.truncate(Treasury::PRE_COLD_WALLET_LEN);
+
+        let mut account =
+            Account::new(u32::MAX as u64, Treasury::PRE_COLD_WALLET_LEN, &crate::id());
+        account.data = data;
+        test.add_account(test_treasury.pubkey, account);
+
+        test_treasury
+    }
+
+    fn add_token(
+        test: &mut ProgramTest,
+        escrow: Pubkey,
+        token_mint: Pubkey,
+        amount: u64,
+    ) -> (Self, Treasury) {
+        let (pubkey, _bump_seed) = find_treasury_address(escrow);
+
+        let associated_token = get_associated_token_address(&pubkey, &token_mint);
+
+        test.add_packable_account(
+            associated_token,
+            u32::MAX as u64,
+            &Token {
+                mint: token_mint,
+                owner: pubkey,
+                amount,
+                state: AccountState::Initialized,
+                is_native: COption::None,
+                ..Token::default()
+            },
+            &spl_token::id(),
+        );
+
+        let treasury = Treasury::new(InitTreasuryParams {
+            escrow,
+            token_mint,
+            epoch_burn_cap: 0,
+        });
+
+        (
+            Self {
+                pubkey,
+                associated_token,
+            },
+            treasury,
+        )
+    }
+
+    /// Fetch the treasury
+    pub async fn get(&self, banks_client: &mut BanksClient) -> Treasury {
+        get_treasury(banks_client, self.pubkey).await
+    }
+}
+
+/// Stats of an escrow
+pub struct TestStats {
+    /// Address of the stats
+    pub pubkey: Pubkey,
+}
+
+impl TestStats {
+    /// Add the stats of an escrow with no recorded flows
+    pub fn add(test: &mut ProgramTest, escrow: Pubkey) -> Self {
+        let (pubkey, _bump_seed) = find_stats_address(escrow);
+
+        let stats = Stats::new(InitStatsParams { escrow, epoch: 0 });
+        test.add_packable_account(pubkey, u32::MAX as u64, &stats, &crate::id());
+
+        Self { pubkey }
+    }
+
+    /// Fetch the stats
+    pub async fn get(&self, banks_client: &mut BanksClient) -> Stats {
+        get_stats(banks_client, self.pubkey).await
+    }
+}
+
+/// Delegate that the owner of an escrow approved to disburse in its place
+pub struct TestDelegateAllowance {
+    /// Address of the delegate allowance
+    pub pubkey: Pubkey,
+    /// Delegate that signs in place of the owner
+    pub delegate: Keypair,
+}
+
+impl TestDelegateAllowance {
+    /// Add a delegate of an escrow owner that can still disburse an amount of tokens until a slot
+    pub fn add(test: &mut ProgramTest, escrow: Pubkey, remaining: u64, expiry_slot: Slot) -> Self {
+        let delegate = Keypair::new();
+        let (pubkey, _bump_seed) = find_delegate_allowance_address(escrow, delegate.pubkey());
+
+        let delegate_allowance = DelegateAllowance::new(InitDelegateAllowanceParams {
+            escrow,
+            delegate: delegate.pubkey(),
+            limit: remaining,
+            expiry_slot,
+        });
+        test.add_packable_account(pubkey, u32::MAX as u64, &delegate_allowance, &crate::id());
+
+        Self { pubkey, delegate }
+    }
+
+    /// Fetch the delegate allowance
+    pub async fn get(&self, banks_client: &mut BanksClient) -> DelegateAllowance {
+        get_delegate_allowance(banks_client, self.pubkey).await
+    }
+}
+
+/// Subscription of a job authority that funds its job each period
+pub struct TestSubscription {
+    /// Address of the subscription
+    pub pubkey: Pubkey,
+}
+
+impl TestSubscription {
+    /// Add a subscription that funds the first job of an authority with an amount of tokens from a
+    /// source token account each period, next due at a slot
+    pub fn add(
+        test: &mut ProgramTest,
+        escrow: Pubkey,
+        authority: Pubkey,
+        source_token: Pubkey,
+        amount: u64,
+        period: Slot,
+        next_slot: Slot,
+    ) -> Self {
+        let (pubkey, _bump_seed) = find_subscription_address(escrow, authority, 0);
+
+        let subscription = Subscription::new(InitSubscriptionParams {
+            escrow,
+            authority,
+            job_id: 0,
+            source_token,
+            amount,
+            period,
+            start_slot: next_slot,
+        });
+        test.add_packable_account(pubkey, u32::MAX as u64, &subscription, &crate::id());
+
+        Self { pubkey }
+    }
+
+    /// Fetch the subscription
+    pub async fn get(&self, banks_client: &mut BanksClient) -> Subscription {
+        get_subscription(banks_client, self.pubkey).await
+    }
+}
+
+/// Bundle of consecutive jobs of a job authority
+pub struct TestJobBundle {
+    /// Address of the job bundle
+    pub pubkey: Pubkey,
+}
+
+impl TestJobBundle {
+    /// Add the first job bundle of an authority, grouping a number of jobs from a job identifier
+    pub fn add(
+        test: &mut ProgramTest,
+        escrow: Pubkey,
+        authority: Pubkey,
+        first_job_id: u64,
+        job_count: u8,
+    ) -> Self {
+        let (pubkey, _bump_seed) = find_job_bundle_address(escrow, authority, 0);
+
+        let job_bundle = JobBundle::new(InitJobBundleParams {
+            escrow,
+            authority,
+            id: 0,
+            metadata_hash: [0; HASH_BYTES],
+            first_job_id,
+            job_count,
+        });
+        test.add_packable_account(pubkey, u32::MAX as u64, &job_bundle, &crate::id());
+
+        Self { pubkey }
+    }
+
+    /// Fetch the job bundle
+    pub async fn get(&self, banks_client: &mut BanksClient) -> JobBundle {
+        get_job_bundle(banks_client, self.pubkey).await
+    }
+}
+
+/// Job index of a job authority
+pub struct TestJobIndex {
+    /// Address of the job index
+    pub pubkey: Pubkey,
+}
+
+impl TestJobIndex {
+    /// Add the job index of an authority with jobs already recorded in order, adding the pages they
+    /// are recorded in
+    pub fn add(test: &mut ProgramTest, escrow: Pubkey, authority: Pubkey, job_ids: &[u64]) -> Self {
+        let (pubkey, _bump_seed) = find_job_index_address(escrow, authority);
+
+        let mut job_index = JobIndex::new(InitJobIndexParams { escrow, authority });
+        job_index.job_count = job_ids.len() as u64;
+        test.add_packable_account(pubkey, u32::MAX as u64, &job_index, &crate::id());
+
+        for (page, job_ids) in job_ids.chunks(JOB_INDEX_PAGE_LEN).enumerate() {
+            let mut job_index_page = JobIndexPage::new(InitJobIndexPageParams {
+                job_index: pubkey,
+                page: page as u32,
+            });
+            job_index_page.job_ids = job_ids.to_vec();
+            let (page_pubkey, _bump_seed) = find_job_index_page_address(pubkey, page as u32);
+            test.add_packable_account(page_pubkey, u32::MAX as u64, &job_index_page, &crate::id());
+        }
+
+        Self { pubkey }
+    }
+
+    /// Address of a page of the job index
+    pub fn page_address(&self, page: u32) -> Pubkey {
+        find_job_index_page_address(self.pubkey, page).0
+    }
+
+    /// Fetch the job index
+    pub async fn get(&self, banks_client: &mut BanksClient) -> JobIndex {
+        get_job_index(banks_client, self.pubkey).await
+    }
+
+    /// Fetch a page of the job index
+    pub async fn get_page(&self, banks_client: &mut BanksClient, page: u32) -> JobIndexPage {
+        get_job_index_page(banks_client, self.page_address(page)).await
+    }
+}
+
+/// Job queue of an escrow
+pub struct TestJobQueue {
+    /// Address of the job queue
+    pub pubkey: Pubkey,
+}
+
+impl TestJobQueue {
+    /// Add the job queue of an escrow with jobs already queued, oldest first
+    pub fn add(test: &mut ProgramTest, escrow: Pubkey, jobs: &[Pubkey]) -> Self {
+        let (pubkey, _bump_seed) = find_job_queue_address(escrow);
+
+        let mut job_queue = JobQueue::new(InitJobQueueParams { escrow });
+        job_queue.jobs = jobs.to_vec();
+        test.add_packable_account(pubkey, u32::MAX as u64, &job_queue, &crate::id());
+
+        Self { pubkey }
+    }
+
+    /// Fetch the job queue
+    pub async fn get(&self, banks_client: &mut BanksClient) -> JobQueue {
+        get_job_queue(banks_client, self.pubkey).await
+    }
+}
+
+/// Merkle tree account of the account compression stand-in, which keeps the authority, depth and
+/// leaf count of the tree with its root and the rightmost node of each level, hashed with keccak as
+/// concurrent merkle trees are. Only proofs of the current root are accepted.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct StandInMerkleTree {
+    /// Authority that signs for changes to the tree
+    pub authority: Pubkey,
+    /// Depth of the tree
+    pub max_depth: u32,
+    /// Number of leaves appended
+    pub leaf_count: u64,
+    /// Root of the tree, with empty leaves zeroed
+    pub root: [u8; HASH_BYTES],
+    /// Left node of each level that the next appended leaf is hashed with, where it has one
+    pub rightmost_nodes: Vec<[u8; HASH_BYTES]>,
+}
+
+impl StandInMerkleTree {
+    const HEADER_LEN: usize = 32 + 4 + 8 + HASH_BYTES;
+
+    /// Hash of a node from its left and right children
+    pub fn hash_pair(left: &[u8; HASH_BYTES], right: &[u8; HASH_BYTES]) -> [u8; HASH_BYTES] {
+        keccak::hashv(&[left, right]).to_bytes()
+    }
+
+    /// Hash of the root of an empty subtree of a depth
+    pub fn empty_node(depth: u32) -> [u8; HASH_BYTES] {
+        (0..depth).fold([0; HASH_BYTES], |node, _| Self::hash_pair(&node, &node))
+    }
+
+    fn new(authority: Pubkey, max_depth: u32) -> Self {
+        Self {
+            authority,
+            max_depth,
+            leaf_count: 0,
+            root: Self::empty_node(max_depth),
+            rightmost_nodes: vec![[0; HASH_BYTES]; max_depth as usize],
+        }
+    }
+
+    fn append(&mut self, leaf: [u8; HASH_BYTES]) -> ProgramResult {
+        if self.leaf_count >= 1 << self.max_depth {
+            return Err(ProgramError::InvalidArgument);
+        }
+        let index = self.leaf_count;
+        let mut node = leaf;
+        let mut empty_node = [0; HASH_BYTES];
+        for level in 0..self.max_depth as usize {
+            if (index >> level) & 1 == 0 {
+                self.rightmost_nodes[level] = node;
+                node = Self::hash_pair(&node, &empty_node);
+            } else {
+                node = Self::hash_pair(&self.rightmost_nodes[level], &node);
+            }
+            empty_node = Self::hash_pair(&empty_node, &empty_node);
+        }
+        self.root = node;
+        self.leaf_count += 1;
+        Ok(())
+    }
+
+    fn replace(
+        &mut self,
+        root: [u8; HASH_BYTES],
+        index: u64,
+        leaf: [u8; HASH_BYTES],
+        new_leaf: [u8; HASH_BYTES],
+        proof: &[[u8; HASH_BYTES]],
+    ) -> ProgramResult {
+        let root_from_proof = |leaf| {
+            proof
+                .iter()
+                .enumerate()
+                .fold(leaf, |node, (level, sibling)| {
+                    if (index >> level) & 1 == 0 {
+                        Self::hash_pair(&node, sibling)
+                    } else {
+                        Self::hash_pair(sibling, &node)
+                    }
+                })
+        };
+        if index >= self.leaf_count
+            || proof.len() != self.max_depth as usize
+            || root != self.root
+            || root_from_proof(leaf) != root
+        {
+            return Err(ProgramError::InvalidArgument);
+        }
+        // Rightmost nodes on the path of the leaf are updated too, so later appends hash with the
+        // replaced leaf
+        let mut node = new_leaf;
+        for (level, sibling) in proof.iter().enumerate() {
+            let position = index >> level;
+            let next_position = self.leaf_count >> level;
+            if next_position & 1 == 1 && position == next_position - 1 {
+                self.rightmost_nodes[level] = node;
+            }
+            node = if position & 1 == 0 {
+                Self::hash_pair(&node, sibling)
+            } else {
+                Self::hash_pair(sibling, &node)
+            };
+        }
+        self.root = node;
+        Ok(())
+    }
+
+    /// Unpack the tree of an account, or `None` if it hasn't been initialized
+    pub fn unpack(data: &[u8]) -> Option<Self> {
+        let max_depth = u32::from_le_bytes(data[32..36].try_into().unwrap());
+        if max_depth == 0 {
+            return None;
+        }
+        Some(Self {
+            authority: Pubkey::new_from_array(data[..32].try_into().unwrap()),
+            max_depth,
+            leaf_count: u64::from_le_bytes(data[36..44].try_into().unwrap()),
+            root: data[44..Self::HEADER_LEN].try_into().unwrap(),
+            rightmost_nodes: data[Self::HEADER_LEN..]
+                .chunks_exact(HASH_BYTES)
+                .take(max_depth as usize)
+                .map(|node| node.try_into().unwrap())
+                .collect(),
+        })
+    }
+
+    fn pack(&self, data: &mut [u8]) {
+        data[..32].copy_from_slice(self.authority.as_ref());
+        data[32..36].copy_from_slice(&self.max_depth.to_le_bytes());
+        data[36..44].copy_from_slice(&self.leaf_count.to_le_bytes());
+        data[44..Self::HEADER_LEN].copy_from_slice(&self.root);
+        for (output, node) in data[Self::HEADER_LEN..]
+            .chunks_exact_mut(HASH_BYTES)
+            .zip(&self.rightmost_nodes)
+        {
+            output.copy_from_slice(node);
+        }
+    }
+}
+
+/// Stand-in for the SPL account compression program, which initializes, appends to and replaces
+/// the leaves of `StandInMerkleTree` accounts signed by their authority
+fn process_account_compression(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    input: &[u8],
+) -> ProgramResult {
+    let (merkle_tree_info, authority_info) = (&accounts[0], &accounts[1]);
+    if !authority_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    let mut data = merkle_tree_info.try_borrow_mut_data()?;
+    let (discriminator, input) = input.split_at(8);
+    let hash = |offset: usize| -> [u8; HASH_BYTES] {
+        input[offset..offset + HASH_BYTES].try_into().unwrap()
+    };
+
+    if discriminator == INIT_EMPTY_MERKLE_TREE_DISCRIMINATOR {
+        let max_depth = u32::from_le_bytes(input[..4].try_into().unwrap());
+        if StandInMerkleTree::unpack(&data).is_some() {
+            return Err(ProgramError::AccountAlreadyInitialized);
+        }
+        if max_depth == 0
+            || data.len() < StandInMerkleTree::HEADER_LEN + HASH_BYTES * max_depth as usize
+        {
+            return Err(ProgramError::InvalidArgument);
+        }
+        StandInMerkleTree::new(*authority_info.key, max_depth).pack(&mut data);
+        return Ok(());
+    }
+
+    let mut merkle_tree =
+        StandInMerkleTree::unpack(&data).ok_or(ProgramError::UninitializedAccount)?;
+    if &merkle_tree.authority != authority_info.key {
+        return Err(ProgramError::IllegalOwner);
+    }
+    if discriminator == APPEND_DISCRIMINATOR {
+        merkle_tree.append(hash(0))?;
+    } else if discriminator == REPLACE_LEAF_DISCRIMINATOR {
+        let index = u32::from_le_bytes(input[96..100].try_into().unwrap());
+        let proof: Vec<_> = accounts[3..]
+            .iter()
+            .map(|info| info.key.to_bytes())
+            .collect();
+        merkle_tree.replace(hash(0), index as u64, hash(32), hash(64), &proof)?;
+    } else {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    merkle_tree.pack(&mut data);
+    Ok(())
+}
+
+/// Stand-in for the SPL noop program
+fn process_noop(_program_id: &Pubkey, _accounts: &[AccountInfo], _input: &[u8]) -> ProgramResult {
+    Ok(())
+}
+
+/// Add stand-ins for the SPL account compression and noop programs, which job trees invoke
+pub fn add_account_compression(test: &mut ProgramTest) {
+    test.add_program(
+        "spl_account_compression",
+        ACCOUNT_COMPRESSION_PROGRAM_ID,
+        processor!(process_account_compression),
+    );
+    test.add_program("spl_noop", NOOP_PROGRAM_ID, processor!(process_noop));
+}
+
+/// Add an uninitialized merkle tree account owned by the account compression program, the size of
+/// a concurrent merkle tree of a depth and buffer size
+pub fn add_merkle_tree(test: &mut ProgramTest, max_depth: u32, max_buffer_size: u32) -> Pubkey {
+    let pubkey = Pubkey::new_unique();
+    test.add_account(
+        pubkey,
+        Account::new(
+            u32::MAX as u64,
+            merkle_tree_account_len(max_depth, max_buffer_size),
+            &ACCOUNT_COMPRESSION_PROGRAM_ID,
+        ),
+    );
+    pubkey
+}
+
+/// Fetch the stand-in merkle tree of an account
+pub async fn get_merkle_tree(banks_client: &mut BanksClient, pubkey: Pubkey) -> StandInMerkleTree {
+    let account = get_account(banks_client, pubkey).await;
+    StandInMerkleTree::unpack(&account.data).unwrap()
+}
+
+/// Job tree of a job authority with the compressed jobs of its leaves, for building proofs in tests
+pub struct TestJobTree {
+    /// Address of the job tree
+    pub pubkey: Pubkey,
+    /// Address of the merkle tree of the job tree
+    pub merkle_tree: Pubkey,
+    /// Depth of the tree
+    pub max_depth: u8,
+    /// Compressed jobs of the leaves, in the order of the leaves
+    pub jobs: Vec<CompressedJob>,
+}
+
+impl TestJobTree {
+    /// Buffer size of the merkle trees of job trees in tests
+    pub const MAX_BUFFER_SIZE: u32 = 8;
+
+    /// Add the job tree of an authority with compressed jobs appended in order, whose identifiers
+    /// are set to their indexes, with its merkle tree
+    pub fn add(
+        test: &mut ProgramTest,
+        escrow: Pubkey,
+        authority: Pubkey,
+        max_depth: u8,
+        jobs: &[CompressedJob],
+    ) -> Self {
+        let (pubkey, _bump_seed) = find_job_tree_address(escrow, authority);
+
+        let jobs: Vec<_> = jobs
+            .iter()
+            .enumerate()
+            .map(|(id, job)| CompressedJob {
+                id: id as u64,
+                ..*job
+            })
+            .collect();
+        let mut merkle_tree = StandInMerkleTree::new(pubkey, max_depth as u32);
+        for job in &jobs {
+            merkle_tree.append(job.leaf(&pubkey)).unwrap();
+        }
+        let merkle_tree_pubkey = Pubkey::new_unique();
+        let mut account = Account::new(
+            u32::MAX as u64,
+            merkle_tree_account_len(max_depth as u32, Self::MAX_BUFFER_SIZE),
+            &ACCOUNT_COMPRESSION_PROGRAM_ID,
+        );
+        merkle_tree.pack(&mut account.data);
+        test.add_account(merkle_tree_pubkey, account);
+
+        let mut job_tree = JobTree::new(InitJobTreeParams {
+            escrow,
+            authority,
+            merkle_tree: merkle_tree_pubkey,
+            max_depth,
+        });
+        job_tree.leaf_count = jobs.len() as u64;
+        job_tree.amount = jobs.iter().map(|job| job.amount).sum();
+        test.add_packable_account(pubkey, u32::MAX as u64, &job_tree, &crate::id());
+
+        Self {
+            pubkey,
+            merkle_tree: merkle_tree_pubkey,
+            max_depth,
+            jobs,
+        }
+    }
+
+    /// Root of the tree and the proof of the leaf of a job, recomputed from the leaves of all the
+    /// jobs
+    pub fn proof(&self, id: u64) -> ([u8; HASH_BYTES], Vec<[u8; HASH_BYTES]>) {
+        let mut level: Vec<_> = self.jobs.iter().map(|job| job.leaf(&self.pubkey)).collect();
+        let mut position = id as usize;
+        let mut proof = vec![];
+        for depth in 0..self.max_depth as u32 {
+            let empty_node = StandInMerkleTree::empty_node(depth);
+            proof.push(level.get(position ^ 1).copied().unwrap_or(empty_node));
+            level = level
+                .chunks(2)
+                .map(|pair| match pair {
+                    [a, b] => StandInMerkleTree::hash_pair(a, b),
+                    [a] => StandInMerkleTree::hash_pair(a, &empty_node),
+                    _ => unreachable!(),
+                })
+                .collect();
+            position /= 2;
+        }
+        let root = level
+            .first()
+            .copied()
+            .unwrap_or_else(|| StandInMerkleTree::empty_node(self.max_depth as u32));
+        (root, proof)
+    }
+
+    /// Fetch the job tree
+    pub async fn get(&self, banks_client: &mut BanksClient) -> JobTree {
+        get_job_tree(banks_client, self.pubkey).await
+    }
+
+    /// Fetch the root of the merkle tree of the job tree
+    pub async fn get_root(&self, banks_client: &mut BanksClient) -> [u8; HASH_BYTES] {
+        get_merkle_tree(banks_client, self.merkle_tree).await.root
+    }
+}
+
+/// Session key a job authority registered for an escrow
+pub struct TestSessionKey {
+    /// Address of the session key account
+    pub pubkey: Pubkey,
+    /// Session key that signs in place of the authority
+    pub session_key: Keypair,
+}
+
+impl TestSessionKey {
+    /// Add a session key of a job authority that can sign the instructions in a mask until a slot
+    pub fn add(
+        test: &mut ProgramTest,
+        escrow: Pubkey,
+        authority: Pubkey,
+        expiry_slot: Slot,
+        instruction_mask: u64,
+    ) -> Self {
+        let session_key = Keypair::new();
+        let (pubkey, _bump_seed) =
+            find_session_key_address(escrow, authority, session_key.pubkey());
+
+        let session_key_account = SessionKey::new(InitSessionKeyParams {
+            escrow,
+            authority,
+            session_key: session_key.pubkey(),
+            expiry_slot,
+            instruction_mask,
+        });
+        test.add_packable_account(pubkey, u32::MAX as u64, &session_key_account, &crate::id());
+
+        Self {
+            pubkey,
+            session_key,
+        }
+    }
+
+    /// Fetch the session key account
+    pub async fn get(&self, banks_client: &mut BanksClient) -> SessionKey {
+        get_session_key(banks_client, self.pubkey).await
+    }
+}
+
+/// Liquid stake pool of an escrow with its associated token account and receipt mint
+pub struct TestStakePool {
+    /// Address of the stake pool
+    pub pubkey: Pubkey,
+    /// Associated token account of the stake pool
+    pub associated_token: Pubkey,
+    /// Mint of the receipt tokens
+    pub receipt_mint: Pubkey,
+}
+
+impl TestStakePool {
+    /// Add the stake pool of an escrow holding an amount of tokens staked for a supply of receipts
+    pub fn add(
+        test: &mut ProgramTest,
+        escrow: Pubkey,
+        token_mint: Pubkey,
+        decimals: u8,
+        total_staked: u64,
+        receipt_supply: u64,
+    ) -> Self {
+        let (pubkey, _bump_seed) = find_stake_pool_address(escrow);
+        let (receipt_mint, _bump_seed) = find_stake_receipt_mint_address(pubkey);
+
+        let associated_token = get_associated_token_address(&pubkey, &token_mint);
+
+        test.add_packable_account(
+            associated_token,
+            u32::MAX as u64,
+            &Token {
+                mint: token_mint,
+                owner: pubkey,
+                amount: total_staked,
+                state: AccountState::Initialized,
+                is_native: COption::None,
+                ..Token::default()
+            },
+            &spl_token::id(),
+        );
+
+        test.add_packable_account(
+            receipt_mint,
+            u32::MAX as u64,
+            &Mint {
+                is_initialized: true,
+                decimals,
+                mint_authority: COption::Some(pubkey),
+                supply: receipt_supply,
+                ..Mint::default()
+            },
+            &spl_token::id(),
+        );
+
+        let mut stake_pool = StakePool::new(InitStakePoolParams {
+            escrow,
+            token_mint,
+            receipt_mint,
+        });
+        stake_pool.total_staked = total_staked;
+        stake_pool.receipt_supply = receipt_supply;
+        test.add_packable_account(pubkey, u32::MAX as u64, &stake_pool, &crate::id());
+
+        Self {
+            pubkey,
+            associated_token,
+            receipt_mint,
+        }
+    }
+
+    /// Fetch the stake pool
+    pub async fn get(&self, banks_client: &mut BanksClient) -> StakePool {
+        get_stake_pool(banks_client, self.pubkey).await
+    }
+}
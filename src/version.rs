@@ -0,0 +1,78 @@
+//! Version of the program build, reported by the `GetVersion` instruction
+
+use {
+    crate::{error::RNDRError, state::LAYOUT_VERSION},
+    arrayref::{array_ref, array_refs},
+    solana_program::{msg, program_error::ProgramError},
+};
+
+/// Version of this build of the program
+pub const VERSION: ProgramVersion = ProgramVersion {
+    major: parse_u16(env!("CARGO_PKG_VERSION_MAJOR")),
+    minor: parse_u16(env!("CARGO_PKG_VERSION_MINOR")),
+    patch: parse_u16(env!("CARGO_PKG_VERSION_PATCH")),
+    layout_version: LAYOUT_VERSION,
+};
+
+/// Crate version and layout version of a build of the program
+///
+/// Set as the return data of the `GetVersion` instruction, so clients and monitoring can detect
+/// which build is deployed at the program id.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ProgramVersion {
+    /// Major version of the crate
+    pub major: u16,
+    /// Minor version of the crate
+    pub minor: u16,
+    /// Patch version of the crate
+    pub patch: u16,
+    /// Version of the account and instruction layouts, see `state::LAYOUT_VERSION`
+    pub layout_version: u8,
+}
+
+impl ProgramVersion {
+    /// Length of a packed version
+    pub const LEN: usize = 7;
+
+    /// Pack the version into little-endian u16s followed by the layout version
+    pub fn pack(&self) -> [u8; Self::LEN] {
+        let mut buf = [0; Self::LEN];
+        buf[0..2].copy_from_slice(&self.major.to_le_bytes());
+        buf[2..4].copy_from_slice(&self.minor.to_le_bytes());
+        buf[4..6].copy_from_slice(&self.patch.to_le_bytes());
+        buf[6] = self.layout_version;
+        buf
+    }
+
+    /// Unpack a version from the return data of a `GetVersion` instruction
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        if input.len() != Self::LEN {
+            msg!(
+                "Version data is {} bytes, expected {}",
+                input.len(),
+                Self::LEN
+            );
+            return Err(RNDRError::InstructionUnpackError.into());
+        }
+        let input = array_ref![input, 0, ProgramVersion::LEN];
+        let (major, minor, patch, layout_version) = array_refs![input, 2, 2, 2, 1];
+        Ok(Self {
+            major: u16::from_le_bytes(*major),
+            minor: u16::from_le_bytes(*minor),
+            patch: u16::from_le_bytes(*patch),
+            layout_version: layout_version[0],
+        })
+    }
+}
+
+/// Parse a decimal version component of the crate version at compile time
+const fn parse_u16(digits: &str) -> u16 {
+    let digits = digits.as_bytes();
+    let mut value = 0;
+    let mut i = 0;
+    while i < digits.len() {
+        value = value * 10 + (digits[i] - b'0') as u16;
+        i += 1;
+    }
+    value
+}
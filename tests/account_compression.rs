@@ -0,0 +1,45 @@
+use {
+    rndr::account_compression::{
+        merkle_tree_account_len, replace_leaf, APPEND_DISCRIMINATOR,
+        INIT_EMPTY_MERKLE_TREE_DISCRIMINATOR, REPLACE_LEAF_DISCRIMINATOR,
+    },
+    solana_program::{hash::hash, pubkey::Pubkey},
+};
+
+#[test]
+fn test_discriminators() {
+    assert_eq!(
+        INIT_EMPTY_MERKLE_TREE_DISCRIMINATOR,
+        hash(b"global:init_empty_merkle_tree").to_bytes()[..8]
+    );
+    assert_eq!(APPEND_DISCRIMINATOR, hash(b"global:append").to_bytes()[..8]);
+    assert_eq!(
+        REPLACE_LEAF_DISCRIMINATOR,
+        hash(b"global:replace_leaf").to_bytes()[..8]
+    );
+}
+
+#[test]
+fn test_merkle_tree_account_len() {
+    // Sizes of concurrent merkle trees without a canopy, as the account compression SDK computes
+    // them
+    assert_eq!(merkle_tree_account_len(3, 8), 1_304);
+    assert_eq!(merkle_tree_account_len(14, 64), 31_800);
+}
+
+#[test]
+fn test_replace_leaf() {
+    let merkle_tree = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let proof = [Pubkey::new_unique(), Pubkey::new_unique()];
+    let instruction = replace_leaf(merkle_tree, authority, [1; 32], [2; 32], [3; 32], 5, &proof);
+
+    assert_eq!(instruction.data.len(), 8 + 32 + 32 + 32 + 4);
+    assert_eq!(&instruction.data[..8], &REPLACE_LEAF_DISCRIMINATOR);
+    assert_eq!(&instruction.data[104..], &5u32.to_le_bytes());
+    // The proof follows the merkle tree, authority and noop program
+    assert_eq!(instruction.accounts.len(), 3 + proof.len());
+    assert!(instruction.accounts[1].is_signer);
+    assert_eq!(instruction.accounts[3].pubkey, proof[0]);
+    assert!(!instruction.accounts[3].is_writable);
+}
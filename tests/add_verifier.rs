@@ -0,0 +1,52 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{instruction::add_verifier, processor::process_instruction, test_fixtures::*},
+    solana_program_test::*,
+    solana_sdk::{pubkey::Pubkey, signature::Signer, system_instruction, transaction::Transaction},
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const AMOUNT: u64 = 1_000_000_000;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let verifier = Pubkey::new_unique();
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let owner = &test_escrow.owner;
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            system_instruction::transfer(&payer.pubkey(), &owner.pubkey(), 1_000_000_000),
+            add_verifier(rndr::id(), test_mint.pubkey, owner.pubkey(), verifier),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let (verifier_registry_pubkey, _bump_seed) = find_verifier_registry_address(test_escrow.pubkey);
+    let verifier_registry =
+        get_verifier_registry(&mut banks_client, verifier_registry_pubkey).await;
+    assert_eq!(verifier_registry.escrow, test_escrow.pubkey);
+    assert_eq!(verifier_registry.verifiers, vec![verifier]);
+
+    // A verifier can't be registered twice
+    let mut transaction = Transaction::new_with_payer(
+        &[add_verifier(
+            rndr::id(),
+            test_mint.pubkey,
+            owner.pubkey(),
+            verifier,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
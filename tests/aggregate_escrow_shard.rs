@@ -0,0 +1,159 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{
+        instruction::{aggregate_escrow_shard, with_crank_bounty},
+        processor::process_instruction,
+        test_fixtures::*,
+    },
+    solana_program_test::*,
+    solana_sdk::{
+        native_token::LAMPORTS_PER_SOL, pubkey::Pubkey, signature::Signer, transaction::Transaction,
+    },
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_escrow_shard = TestEscrowShard::add(&mut test, test_escrow.pubkey, 3, AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let escrow = test_escrow.get(&mut banks_client).await;
+    assert_eq!(escrow.amount, AMOUNT);
+
+    let escrow_shard = test_escrow_shard.get(&mut banks_client).await;
+    assert_eq!(escrow_shard.amount, AMOUNT);
+
+    let mut transaction = Transaction::new_with_payer(
+        &[aggregate_escrow_shard(
+            rndr::id(),
+            test_escrow.pubkey,
+            test_escrow_shard.index,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let escrow = test_escrow.get(&mut banks_client).await;
+    assert_eq!(escrow.amount, 2 * AMOUNT);
+
+    let escrow_shard = test_escrow_shard.get(&mut banks_client).await;
+    assert_eq!(escrow_shard.amount, ZERO);
+}
+
+#[tokio::test]
+async fn test_success_with_crank_bounty() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+    const BOUNTY: u64 = LAMPORTS_PER_SOL / 100;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_escrow_shard = TestEscrowShard::add(&mut test, test_escrow.pubkey, 3, AMOUNT);
+    let test_crank_vault = TestCrankVault::add(&mut test, test_escrow.pubkey, BOUNTY, BOUNTY);
+    let recipient = Pubkey::new_unique();
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[with_crank_bounty(
+            aggregate_escrow_shard(rndr::id(), test_escrow.pubkey, test_escrow_shard.index),
+            test_escrow.pubkey,
+            recipient,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let recipient_balance = banks_client.get_balance(recipient).await.unwrap();
+    assert_eq!(recipient_balance, BOUNTY);
+
+    let crank_vault = test_crank_vault.get(&mut banks_client).await;
+    assert_eq!(crank_vault.paid, BOUNTY);
+}
+
+#[tokio::test]
+async fn test_empty_shard_pays_no_bounty() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+    const BOUNTY: u64 = LAMPORTS_PER_SOL / 100;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_escrow_shard = TestEscrowShard::add(&mut test, test_escrow.pubkey, 3, ZERO);
+    let test_crank_vault = TestCrankVault::add(&mut test, test_escrow.pubkey, BOUNTY, BOUNTY);
+    let recipient = Pubkey::new_unique();
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[with_crank_bounty(
+            aggregate_escrow_shard(rndr::id(), test_escrow.pubkey, test_escrow_shard.index),
+            test_escrow.pubkey,
+            recipient,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let recipient_balance = banks_client.get_balance(recipient).await.unwrap();
+    assert_eq!(recipient_balance, ZERO);
+
+    let crank_vault = test_crank_vault.get(&mut banks_client).await;
+    assert_eq!(crank_vault.paid, ZERO);
+}
+
+#[tokio::test]
+async fn test_unfunded_crank_vault_pays_no_bounty() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+    const BOUNTY: u64 = LAMPORTS_PER_SOL / 100;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_escrow_shard = TestEscrowShard::add(&mut test, test_escrow.pubkey, 3, AMOUNT);
+    let test_crank_vault = TestCrankVault::add(&mut test, test_escrow.pubkey, BOUNTY, BOUNTY - 1);
+    let recipient = Pubkey::new_unique();
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[with_crank_bounty(
+            aggregate_escrow_shard(rndr::id(), test_escrow.pubkey, test_escrow_shard.index),
+            test_escrow.pubkey,
+            recipient,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let escrow = test_escrow.get(&mut banks_client).await;
+    assert_eq!(escrow.amount, 2 * AMOUNT);
+
+    let crank_vault = test_crank_vault.get(&mut banks_client).await;
+    assert_eq!(crank_vault.paid, ZERO);
+}
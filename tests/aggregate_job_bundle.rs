@@ -0,0 +1,154 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{
+        instruction::aggregate_job_bundle, processor::process_instruction, state::JobStatus,
+        test_fixtures::*,
+    },
+    solana_program::pubkey::Pubkey,
+    solana_program_test::*,
+    solana_sdk::{signature::Signer, transaction::Transaction},
+};
+
+const ZERO: u64 = 0;
+const DECIMALS: u64 = 1_000_000_000;
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let authority = Pubkey::new_unique();
+    let statuses = [JobStatus::Paid, JobStatus::Resolved, JobStatus::Cancelled];
+    let test_job_bundle = TestJobBundle::add(
+        &mut test,
+        test_escrow.pubkey,
+        authority,
+        ZERO,
+        statuses.len() as u8 + 1,
+    );
+    let mut test_jobs = statuses
+        .iter()
+        .enumerate()
+        .map(|(id, &status)| {
+            TestJob::add_with_id(
+                &mut test,
+                test_escrow.pubkey,
+                authority,
+                id as u64,
+                status,
+                ZERO,
+            )
+        })
+        .collect::<Vec<_>>();
+    test_jobs.push(TestJob::add_with_id(
+        &mut test,
+        test_escrow.pubkey,
+        authority,
+        statuses.len() as u64,
+        JobStatus::Revealed,
+        ZERO,
+    ));
+    let job_pubkeys = test_jobs
+        .iter()
+        .map(|test_job| test_job.pubkey)
+        .collect::<Vec<_>>();
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[aggregate_job_bundle(
+            rndr::id(),
+            test_mint.pubkey,
+            test_job_bundle.pubkey,
+            &job_pubkeys,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    // The revealed job hasn't been paid yet, so the bundle isn't complete
+    let job_bundle = test_job_bundle.get(&mut banks_client).await;
+    assert_eq!(job_bundle.completed, 0b011);
+    assert_eq!(job_bundle.cancelled, 0b100);
+    assert!(!job_bundle.is_complete());
+}
+
+#[tokio::test]
+async fn test_success_complete() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let authority = Pubkey::new_unique();
+    let test_job_bundle = TestJobBundle::add(&mut test, test_escrow.pubkey, authority, ZERO, 2);
+    let job_pubkeys = [JobStatus::Paid, JobStatus::Cancelled]
+        .iter()
+        .enumerate()
+        .map(|(id, &status)| {
+            TestJob::add_with_id(
+                &mut test,
+                test_escrow.pubkey,
+                authority,
+                id as u64,
+                status,
+                ZERO,
+            )
+            .pubkey
+        })
+        .collect::<Vec<_>>();
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[aggregate_job_bundle(
+            rndr::id(),
+            test_mint.pubkey,
+            test_job_bundle.pubkey,
+            &job_pubkeys,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let job_bundle = test_job_bundle.get(&mut banks_client).await;
+    assert!(job_bundle.is_complete());
+}
+
+#[tokio::test]
+async fn test_job_of_another_authority() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let test_job_bundle =
+        TestJobBundle::add(&mut test, test_escrow.pubkey, Pubkey::new_unique(), ZERO, 1);
+    let test_job = TestJob::add_with_id(
+        &mut test,
+        test_escrow.pubkey,
+        Pubkey::new_unique(),
+        ZERO,
+        JobStatus::Paid,
+        ZERO,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[aggregate_job_bundle(
+            rndr::id(),
+            test_mint.pubkey,
+            test_job_bundle.pubkey,
+            &[test_job.pubkey],
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
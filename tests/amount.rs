@@ -0,0 +1,94 @@
+use {proptest::prelude::*, rndr::amount::RndrAmount};
+
+#[test]
+fn test_ui_amount() {
+    assert_eq!(
+        RndrAmount::from_ui_amount(1.5, 9),
+        Some(RndrAmount(1_500_000_000))
+    );
+    assert_eq!(
+        RndrAmount::from_ui_amount(0.1, 9),
+        Some(RndrAmount(100_000_000))
+    );
+    assert_eq!(
+        RndrAmount::from_ui_amount(0.0000000004, 9),
+        Some(RndrAmount::ZERO)
+    );
+    assert_eq!(RndrAmount::from_ui_amount(-1.0, 9), None);
+    assert_eq!(RndrAmount::from_ui_amount(f64::NAN, 9), None);
+    assert_eq!(RndrAmount::from_ui_amount(f64::INFINITY, 9), None);
+    assert_eq!(RndrAmount::from_ui_amount(1e11, 9), None);
+    assert_eq!(RndrAmount(1_500_000_000).to_ui_amount(9), 1.5);
+
+    assert_eq!(
+        RndrAmount::from_ui_amount_str("1.5", 9),
+        Some(RndrAmount(1_500_000_000))
+    );
+    assert_eq!(
+        RndrAmount::from_ui_amount_str("2", 9),
+        Some(RndrAmount(2_000_000_000))
+    );
+    assert_eq!(
+        RndrAmount::from_ui_amount_str(".25", 2),
+        Some(RndrAmount(25))
+    );
+    assert_eq!(RndrAmount::from_ui_amount_str("7.", 0), Some(RndrAmount(7)));
+    assert_eq!(
+        RndrAmount::from_ui_amount_str("18446744073.709551615", 9),
+        Some(RndrAmount(u64::MAX))
+    );
+    for invalid in [
+        "",
+        ".",
+        "-1",
+        "+1",
+        "1.2.3",
+        "1e9",
+        "0.0000000001",
+        "18446744073.709551616",
+    ] {
+        assert_eq!(
+            RndrAmount::from_ui_amount_str(invalid, 9),
+            None,
+            "{}",
+            invalid
+        );
+    }
+
+    assert_eq!(RndrAmount(1_500_000_000).to_ui_amount_string(9), "1.5");
+    assert_eq!(RndrAmount(2_000_000_000).to_ui_amount_string(9), "2");
+    assert_eq!(RndrAmount(1).to_ui_amount_string(9), "0.000000001");
+    assert_eq!(RndrAmount(7).to_ui_amount_string(0), "7");
+    assert_eq!(
+        RndrAmount(u64::MAX).to_ui_amount_string(9),
+        "18446744073.709551615"
+    );
+}
+
+#[test]
+fn test_checked_arithmetic() {
+    let amount = RndrAmount(10);
+    assert_eq!(amount.checked_add(RndrAmount(5)), Some(RndrAmount(15)));
+    assert_eq!(amount.checked_sub(RndrAmount(5)), Some(RndrAmount(5)));
+    assert_eq!(amount.checked_mul(3), Some(RndrAmount(30)));
+    assert_eq!(amount.checked_div(3), Some(RndrAmount(3)));
+
+    assert_eq!(RndrAmount(u64::MAX).checked_add(RndrAmount(1)), None);
+    assert_eq!(amount.checked_sub(RndrAmount(11)), None);
+    assert_eq!(RndrAmount(u64::MAX).checked_mul(2), None);
+    assert_eq!(amount.checked_div(0), None);
+
+    assert_eq!(RndrAmount::from(10), amount);
+    assert_eq!(u64::from(amount), 10);
+}
+
+proptest! {
+    #[test]
+    fn test_ui_amount_string_round_trip(amount in any::<u64>(), decimals in 0u8..=9) {
+        let ui_amount = RndrAmount(amount).to_ui_amount_string(decimals);
+        prop_assert_eq!(
+            RndrAmount::from_ui_amount_str(&ui_amount, decimals),
+            Some(RndrAmount(amount))
+        );
+    }
+}
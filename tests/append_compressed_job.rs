@@ -0,0 +1,121 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{
+        instruction::append_compressed_job,
+        processor::process_instruction,
+        state::{CompressedJob, JobStatus},
+        test_fixtures::*,
+    },
+    solana_program_test::*,
+    solana_sdk::{signature::Signer, transaction::Transaction},
+};
+
+const ZERO: u64 = 0;
+const DECIMALS: u64 = 1_000_000_000;
+const AMOUNT: u64 = 1 * DECIMALS;
+const MAX_DEPTH: u8 = 3;
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+    add_account_compression(&mut test);
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_source_token = TestToken::add(&mut test, test_mint.pubkey, 2 * AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let authority = &test_source_token.owner;
+    let mut test_job_tree = TestJobTree::add(
+        &mut test,
+        test_escrow.pubkey,
+        authority.pubkey(),
+        MAX_DEPTH,
+        &[],
+    );
+
+    let (mut banks_client, payer, mut recent_blockhash) = test.start().await;
+
+    for id in 0..2 {
+        let mut transaction = Transaction::new_with_payer(
+            &[append_compressed_job(
+                rndr::id(),
+                test_mint.pubkey,
+                authority.pubkey(),
+                test_job_tree.merkle_tree,
+                test_source_token.pubkey,
+                authority.pubkey(),
+                AMOUNT,
+            )],
+            Some(&payer.pubkey()),
+        );
+        transaction.sign(&[&payer, authority], recent_blockhash);
+
+        assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+        test_job_tree.jobs.push(CompressedJob {
+            id,
+            amount: AMOUNT,
+            status: JobStatus::Open,
+            ..CompressedJob::default()
+        });
+        recent_blockhash = banks_client
+            .get_new_latest_blockhash(&recent_blockhash)
+            .await
+            .unwrap();
+    }
+
+    let source_token_balance_after =
+        get_token_balance(&mut banks_client, test_source_token.pubkey).await;
+    let escrow_balance_after =
+        get_token_balance(&mut banks_client, test_escrow.associated_token).await;
+
+    assert_eq!(source_token_balance_after, ZERO);
+    assert_eq!(escrow_balance_after, 2 * AMOUNT);
+
+    let escrow = get_escrow(&mut banks_client, test_escrow.pubkey).await;
+    assert_eq!(escrow.amount, 2 * AMOUNT);
+
+    let job_tree = test_job_tree.get(&mut banks_client).await;
+    assert_eq!(job_tree.leaf_count, 2);
+    assert_eq!(job_tree.amount, 2 * AMOUNT);
+
+    // The leaves are appended to the merkle tree of the job tree
+    let (root, _proof) = test_job_tree.proof(0);
+    assert_eq!(test_job_tree.get_root(&mut banks_client).await, root);
+}
+
+#[tokio::test]
+async fn test_full_tree() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+    add_account_compression(&mut test);
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_source_token = TestToken::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let authority = &test_source_token.owner;
+    let test_job_tree = TestJobTree::add(
+        &mut test,
+        test_escrow.pubkey,
+        authority.pubkey(),
+        1,
+        &[CompressedJob::default(), CompressedJob::default()],
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[append_compressed_job(
+            rndr::id(),
+            test_mint.pubkey,
+            authority.pubkey(),
+            test_job_tree.merkle_tree,
+            test_source_token.pubkey,
+            authority.pubkey(),
+            AMOUNT,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
@@ -0,0 +1,129 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{
+        instruction::approve_delegate, processor::process_instruction, state::AccountType,
+        test_fixtures::*,
+    },
+    solana_program::pubkey::Pubkey,
+    solana_program_test::*,
+    solana_sdk::{signature::Signer, system_instruction, transaction::Transaction},
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const AMOUNT: u64 = 1_000_000_000;
+    const LIMIT: u64 = 5 * AMOUNT;
+    const EXPIRY_SLOT: u64 = 1_000;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let delegate = Pubkey::new_unique();
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let owner = &test_escrow.owner;
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            system_instruction::transfer(&payer.pubkey(), &owner.pubkey(), 1_000_000_000),
+            approve_delegate(
+                rndr::id(),
+                test_mint.pubkey,
+                owner.pubkey(),
+                delegate,
+                LIMIT,
+                EXPIRY_SLOT,
+            ),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let (delegate_allowance_pubkey, _bump_seed) =
+        find_delegate_allowance_address(test_escrow.pubkey, delegate);
+    let delegate_allowance =
+        get_delegate_allowance(&mut banks_client, delegate_allowance_pubkey).await;
+
+    assert_eq!(
+        delegate_allowance.account_type,
+        AccountType::DelegateAllowanceV1
+    );
+    assert_eq!(delegate_allowance.escrow, test_escrow.pubkey);
+    assert_eq!(delegate_allowance.delegate, delegate);
+    assert_eq!(delegate_allowance.remaining, LIMIT);
+    assert_eq!(delegate_allowance.expiry_slot, EXPIRY_SLOT);
+}
+
+#[tokio::test]
+async fn test_success_replace() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const AMOUNT: u64 = 1_000_000_000;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_delegate_allowance =
+        TestDelegateAllowance::add(&mut test, test_escrow.pubkey, AMOUNT, 0);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let owner = &test_escrow.owner;
+    let mut transaction = Transaction::new_with_payer(
+        &[approve_delegate(
+            rndr::id(),
+            test_mint.pubkey,
+            owner.pubkey(),
+            test_delegate_allowance.delegate.pubkey(),
+            3 * AMOUNT,
+            u64::MAX,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let delegate_allowance = test_delegate_allowance.get(&mut banks_client).await;
+    assert_eq!(delegate_allowance.remaining, 3 * AMOUNT);
+    assert_eq!(delegate_allowance.expiry_slot, u64::MAX);
+}
+
+#[tokio::test]
+async fn test_not_owner() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const AMOUNT: u64 = 1_000_000_000;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // Only the escrow owner can approve delegates
+    let mut transaction = Transaction::new_with_payer(
+        &[approve_delegate(
+            rndr::id(),
+            test_mint.pubkey,
+            payer.pubkey(),
+            payer.pubkey(),
+            AMOUNT,
+            u64::MAX,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let (delegate_allowance_pubkey, _bump_seed) =
+        find_delegate_allowance_address(test_escrow.pubkey, payer.pubkey());
+    assert!(banks_client
+        .get_account(delegate_allowance_pubkey)
+        .await
+        .unwrap()
+        .is_none());
+}
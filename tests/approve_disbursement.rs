@@ -0,0 +1,367 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{
+        error::RNDRError,
+        instruction::{
+            approve_disbursement, disburse_funds, execute_disbursement, set_approver,
+            set_approver_with_current_approver,
+        },
+        processor::process_instruction,
+        test_fixtures::*,
+    },
+    solana_program_test::*,
+    solana_sdk::{
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        system_instruction,
+        transaction::Transaction,
+    },
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const AMOUNT: u64 = 1_000_000_000;
+
+    let approver = Keypair::new();
+    let test_mint = TestMint::add(&mut test, 9, 100 * AMOUNT);
+    let test_escrow =
+        TestEscrow::add_with_approver(&mut test, test_mint.pubkey, AMOUNT, approver.pubkey());
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, Pubkey::new_unique(), AMOUNT);
+    let test_proposal = TestProposal::add(
+        &mut test,
+        test_escrow.pubkey,
+        1,
+        test_job.pubkey,
+        Pubkey::new_unique(),
+        AMOUNT,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // The escrow owner can't approve their own proposal
+    let owner = &test_escrow.owner;
+    let mut transaction = Transaction::new_with_payer(
+        &[approve_disbursement(
+            rndr::id(),
+            test_mint.pubkey,
+            owner.pubkey(),
+            test_proposal.id,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let mut transaction = Transaction::new_with_payer(
+        &[approve_disbursement(
+            rndr::id(),
+            test_mint.pubkey,
+            approver.pubkey(),
+            test_proposal.id,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &approver], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let proposal = test_proposal.get(&mut banks_client).await;
+    assert!(proposal.is_approved());
+    assert!(!proposal.is_executed());
+}
+
+#[tokio::test]
+async fn test_invalid_approver() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const AMOUNT: u64 = 1_000_000_000;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * AMOUNT);
+    let test_escrow =
+        TestEscrow::add_with_approver(&mut test, test_mint.pubkey, AMOUNT, Pubkey::new_unique());
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, Pubkey::new_unique(), AMOUNT);
+    let test_proposal = TestProposal::add(
+        &mut test,
+        test_escrow.pubkey,
+        1,
+        test_job.pubkey,
+        Pubkey::new_unique(),
+        AMOUNT,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // A signer that isn't the escrow's approver can't approve the proposal
+    let approver = Keypair::new();
+    let mut transaction = Transaction::new_with_payer(
+        &[approve_disbursement(
+            rndr::id(),
+            test_mint.pubkey,
+            approver.pubkey(),
+            test_proposal.id,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &approver], recent_blockhash);
+
+    assert_eq!(
+        program_error(banks_client.process_transaction(transaction).await),
+        Some(RNDRError::InvalidApprover)
+    );
+
+    let proposal = test_proposal.get(&mut banks_client).await;
+    assert!(!proposal.is_approved());
+}
+
+#[tokio::test]
+async fn test_disburse_without_approval() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const AMOUNT: u64 = 1_000_000_000;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * AMOUNT);
+    let test_escrow =
+        TestEscrow::add_with_approver(&mut test, test_mint.pubkey, AMOUNT, Pubkey::new_unique());
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let test_job = TestJob::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_destination_token.owner.pubkey(),
+        AMOUNT,
+    );
+    let test_proposal = TestProposal::add(
+        &mut test,
+        test_escrow.pubkey,
+        1,
+        test_job.pubkey,
+        test_destination_token.pubkey,
+        AMOUNT,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // The owner can't disburse the funds directly, nor execute the proposal before it's approved
+    let mut transaction = Transaction::new_with_payer(
+        &[disburse_funds(
+            rndr::id(),
+            AMOUNT,
+            test_mint.pubkey,
+            test_destination_token.pubkey,
+            test_job.pubkey,
+            test_escrow.owner.pubkey(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert_eq!(
+        program_error(banks_client.process_transaction(transaction).await),
+        Some(RNDRError::ApprovalRequired)
+    );
+
+    let mut transaction = Transaction::new_with_payer(
+        &[execute_disbursement(
+            rndr::id(),
+            test_mint.pubkey,
+            test_proposal.id,
+            test_job.pubkey,
+            false,
+            test_destination_token.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert_eq!(
+        program_error(banks_client.process_transaction(transaction).await),
+        Some(RNDRError::ProposalNotApproved)
+    );
+
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_destination_token.pubkey).await,
+        ZERO
+    );
+    let job = test_job.get(&mut banks_client).await;
+    assert_eq!(job.amount, AMOUNT);
+}
+
+#[tokio::test]
+async fn test_replayed_approval() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const AMOUNT: u64 = 1_000_000_000;
+
+    let approver = Keypair::new();
+    let test_mint = TestMint::add(&mut test, 9, 100 * AMOUNT);
+    let test_escrow =
+        TestEscrow::add_with_approver(&mut test, test_mint.pubkey, 2 * AMOUNT, approver.pubkey());
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let test_job = TestJob::add(
+        &mut test,
+        test_escrow.pubkey,
+        Pubkey::new_unique(),
+        2 * AMOUNT,
+    );
+    let test_proposal = TestProposal::add(
+        &mut test,
+        test_escrow.pubkey,
+        1,
+        test_job.pubkey,
+        test_destination_token.pubkey,
+        AMOUNT,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let approve = || {
+        approve_disbursement(
+            rndr::id(),
+            test_mint.pubkey,
+            approver.pubkey(),
+            test_proposal.id,
+        )
+    };
+    let execute = || {
+        execute_disbursement(
+            rndr::id(),
+            test_mint.pubkey,
+            test_proposal.id,
+            test_job.pubkey,
+            false,
+            test_destination_token.pubkey,
+        )
+    };
+
+    let mut transaction =
+        Transaction::new_with_payer(&[approve(), execute()], Some(&payer.pubkey()));
+    transaction.sign(&[&payer, &approver], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let approved_at = test_proposal.get(&mut banks_client).await.approved_at;
+
+    // The approval can't be given again, nor used to execute the proposal a second time, even
+    // though the job has funds left
+    let recent_blockhash = banks_client
+        .get_new_latest_blockhash(&recent_blockhash)
+        .await
+        .unwrap();
+    let mut transaction = Transaction::new_with_payer(&[approve()], Some(&payer.pubkey()));
+    transaction.sign(&[&payer, &approver], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let mut transaction = Transaction::new_with_payer(&[execute()], Some(&payer.pubkey()));
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let proposal = test_proposal.get(&mut banks_client).await;
+    assert_eq!(proposal.approved_at, approved_at);
+    assert!(proposal.is_executed());
+
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_destination_token.pubkey).await,
+        AMOUNT
+    );
+    let job = test_job.get(&mut banks_client).await;
+    assert_eq!(job.amount, AMOUNT);
+}
+
+#[tokio::test]
+async fn test_missing_approver_after_set_approver() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const AMOUNT: u64 = 1_000_000_000;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, Pubkey::new_unique(), AMOUNT);
+    let test_proposal = TestProposal::add(
+        &mut test,
+        test_escrow.pubkey,
+        1,
+        test_job.pubkey,
+        Pubkey::new_unique(),
+        AMOUNT,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let owner = &test_escrow.owner;
+    let approver = Keypair::new();
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            system_instruction::transfer(&payer.pubkey(), &owner.pubkey(), 1_000_000_000),
+            set_approver(
+                rndr::id(),
+                test_escrow.pubkey,
+                owner.pubkey(),
+                approver.pubkey(),
+            ),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    // The approver set must sign the approval
+    let mut instruction = approve_disbursement(
+        rndr::id(),
+        test_mint.pubkey,
+        approver.pubkey(),
+        test_proposal.id,
+    );
+    instruction.accounts[0].is_signer = false;
+    let mut transaction = Transaction::new_with_payer(&[instruction], Some(&payer.pubkey()));
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert_eq!(
+        program_error(banks_client.process_transaction(transaction).await),
+        Some(RNDRError::InvalidApprover)
+    );
+
+    // Once the approver is cleared, the escrow has no approver left to approve the proposal
+    let mut transaction = Transaction::new_with_payer(
+        &[set_approver_with_current_approver(
+            rndr::id(),
+            test_escrow.pubkey,
+            owner.pubkey(),
+            approver.pubkey(),
+            Pubkey::default(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, owner, &approver], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let escrow = test_escrow.get(&mut banks_client).await;
+    assert_eq!(escrow.approver(), None);
+
+    let mut transaction = Transaction::new_with_payer(
+        &[approve_disbursement(
+            rndr::id(),
+            test_mint.pubkey,
+            approver.pubkey(),
+            test_proposal.id,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &approver], recent_blockhash);
+
+    assert_eq!(
+        program_error(banks_client.process_transaction(transaction).await),
+        Some(RNDRError::InvalidApprover)
+    );
+
+    let proposal = test_proposal.get(&mut banks_client).await;
+    assert!(!proposal.is_approved());
+}
@@ -0,0 +1,86 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{
+        instruction::approve_recovery, processor::process_instruction, state::RECOVERY_DELAY,
+        test_fixtures::*,
+    },
+    solana_program_test::*,
+    solana_sdk::{
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        transaction::Transaction,
+    },
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const AMOUNT: u64 = 1_000_000_000;
+
+    let test_mint = TestMint::add(&mut test, 9, AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_recovery = TestRecovery::add(&mut test, test_escrow.pubkey, 2, 3);
+    let new_owner = Pubkey::new_unique();
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // Only guardians on the council can approve a recovery
+    let impostor = Keypair::new();
+    let mut transaction = Transaction::new_with_payer(
+        &[approve_recovery(
+            rndr::id(),
+            test_escrow.pubkey,
+            impostor.pubkey(),
+            new_owner,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &impostor], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    // A single approval doesn't reach the threshold
+    let guardian = &test_recovery.guardians[0];
+    let mut transaction = Transaction::new_with_payer(
+        &[approve_recovery(
+            rndr::id(),
+            test_escrow.pubkey,
+            guardian.pubkey(),
+            new_owner,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, guardian], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let recovery = test_recovery.get(&mut banks_client).await;
+    assert_eq!(recovery.approvals(&new_owner), 1);
+    assert!(!recovery.is_pending());
+
+    let guardian = &test_recovery.guardians[2];
+    let mut transaction = Transaction::new_with_payer(
+        &[approve_recovery(
+            rndr::id(),
+            test_escrow.pubkey,
+            guardian.pubkey(),
+            new_owner,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, guardian], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let recovery = test_recovery.get(&mut banks_client).await;
+    assert_eq!(recovery.approvals(&new_owner), 2);
+    assert!(recovery.is_pending());
+    assert_eq!(recovery.new_owner, new_owner);
+    assert!(recovery.executable_at >= RECOVERY_DELAY as i64);
+
+    // The owner isn't rotated until the recovery is executed
+    let escrow = test_escrow.get(&mut banks_client).await;
+    assert_eq!(escrow.owner, test_escrow.owner.pubkey());
+}
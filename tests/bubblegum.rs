@@ -0,0 +1,40 @@
+use {
+    rndr::bubblegum::{ReceiptMetadata, MINT_V1_DISCRIMINATOR, RECEIPT_SYMBOL},
+    solana_program::hash::hash,
+    std::convert::TryInto,
+};
+
+#[test]
+fn test_mint_v1_discriminator() {
+    assert_eq!(
+        MINT_V1_DISCRIMINATOR,
+        hash(b"global:mint_v1").to_bytes()[..8]
+    );
+}
+
+#[test]
+fn test_receipt_metadata() {
+    // A job without metadata has an empty URI
+    let metadata = ReceiptMetadata::new(u64::MAX, "", &[0; 32]);
+    assert_eq!(metadata.name, "RNDR Job 18446744073709551615");
+    assert!(metadata.name.len() <= 32);
+    assert_eq!(metadata.uri, "");
+
+    let metadata = ReceiptMetadata::new(42, "ar://manifest", &[0xab; 32]);
+    assert_eq!(metadata.uri, format!("ar://manifest#{}", "ab".repeat(32)));
+
+    let data = metadata.pack();
+    let mut offset = 0;
+    for string in [
+        metadata.name.as_str(),
+        RECEIPT_SYMBOL,
+        metadata.uri.as_str(),
+    ] {
+        let len = u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
+        assert_eq!(&data[offset + 4..offset + 4 + len], string.as_bytes());
+        offset += 4 + len;
+    }
+    // Seller fee, primary sale, mutability, edition nonce, token standard, collection, uses,
+    // token program version and creators
+    assert_eq!(&data[offset..], &[0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0]);
+}
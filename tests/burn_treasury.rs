@@ -0,0 +1,191 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{
+        instruction::{burn_treasury, with_stats},
+        processor::process_instruction,
+        test_fixtures::*,
+    },
+    solana_program_test::*,
+    solana_sdk::{
+        signature::{Keypair, Signer},
+        transaction::Transaction,
+    },
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+    const SUPPLY: u64 = 100 * DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, SUPPLY);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let owner = &test_escrow.owner;
+    let test_treasury = TestTreasury::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_mint.pubkey,
+        ZERO,
+        2 * AMOUNT,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[burn_treasury(
+            rndr::id(),
+            AMOUNT,
+            test_mint.pubkey,
+            owner.pubkey(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let treasury_balance =
+        get_token_balance(&mut banks_client, test_treasury.associated_token).await;
+    assert_eq!(treasury_balance, AMOUNT);
+
+    let mint = get_mint(&mut banks_client, test_mint.pubkey).await;
+    assert_eq!(mint.supply, SUPPLY - AMOUNT);
+
+    let treasury = test_treasury.get(&mut banks_client).await;
+    assert_eq!(treasury.epoch_burned, AMOUNT);
+    assert_eq!(treasury.total_burned, u128::from(AMOUNT));
+}
+
+#[tokio::test]
+async fn test_epoch_burn_cap() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let owner = &test_escrow.owner;
+    let test_treasury = TestTreasury::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_mint.pubkey,
+        AMOUNT,
+        2 * AMOUNT,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[burn_treasury(
+            rndr::id(),
+            AMOUNT,
+            test_mint.pubkey,
+            owner.pubkey(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let recent_blockhash = banks_client
+        .get_new_latest_blockhash(&recent_blockhash)
+        .await
+        .unwrap();
+
+    let mut transaction = Transaction::new_with_payer(
+        &[burn_treasury(
+            rndr::id(),
+            1,
+            test_mint.pubkey,
+            owner.pubkey(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let treasury_balance =
+        get_token_balance(&mut banks_client, test_treasury.associated_token).await;
+    assert_eq!(treasury_balance, AMOUNT);
+}
+
+#[tokio::test]
+async fn test_invalid_owner() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    TestTreasury::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_mint.pubkey,
+        ZERO,
+        AMOUNT,
+    );
+    let impostor = Keypair::new();
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[burn_treasury(
+            rndr::id(),
+            AMOUNT,
+            test_mint.pubkey,
+            impostor.pubkey(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &impostor], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+#[tokio::test]
+async fn test_success_with_stats() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let owner = &test_escrow.owner;
+    TestTreasury::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_mint.pubkey,
+        ZERO,
+        AMOUNT,
+    );
+    let test_stats = TestStats::add(&mut test, test_escrow.pubkey);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[with_stats(
+            burn_treasury(rndr::id(), AMOUNT, test_mint.pubkey, owner.pubkey()),
+            test_escrow.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let stats = test_stats.get(&mut banks_client).await;
+    assert_eq!(stats.total_burned, u128::from(AMOUNT));
+    assert_eq!(stats.epoch_burned, AMOUNT);
+}
@@ -0,0 +1,728 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{
+        instruction::{cancel_job, cancel_job_with_fee, set_job_exchange_rate},
+        math::{Rate, RoundingPolicy},
+        oracle::OraclePrice,
+        processor::process_instruction,
+        state::{Job, JobStatus, MAX_CANCEL_GRACE_PERIOD},
+        test_fixtures::*,
+    },
+    solana_program::program_pack::Pack,
+    solana_program_test::*,
+    solana_sdk::{
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        transaction::Transaction,
+    },
+    std::time::{SystemTime, UNIX_EPOCH},
+};
+
+const ZERO: u64 = 0;
+const DECIMALS: u64 = 1_000_000_000;
+const AMOUNT: u64 = 1 * DECIMALS;
+const FEE_BPS: Rate = Rate::new(1_000);
+const FEE: u64 = AMOUNT / 10;
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    TestConfig::add_with_cancellation(&mut test, ZERO, FEE_BPS);
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let authority = &test_destination_token.owner;
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, authority.pubkey(), AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // A job no node has committed to is refunded in full even after the grace period
+    let mut transaction = Transaction::new_with_payer(
+        &[cancel_job(
+            rndr::id(),
+            test_mint.pubkey,
+            authority.pubkey(),
+            0,
+            test_destination_token.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let escrow_balance_after =
+        get_token_balance(&mut banks_client, test_escrow.associated_token).await;
+    let destination_token_balance_after =
+        get_token_balance(&mut banks_client, test_destination_token.pubkey).await;
+
+    assert_eq!(escrow_balance_after, ZERO);
+    assert_eq!(destination_token_balance_after, AMOUNT);
+
+    let escrow = get_escrow(&mut banks_client, test_escrow.pubkey).await;
+    assert_eq!(escrow.amount, ZERO);
+
+    let job = get_job(&mut banks_client, test_job.pubkey).await;
+    assert_eq!(job.amount, ZERO);
+    assert_eq!(job.status, JobStatus::Cancelled);
+
+    // A cancelled job can't be cancelled again
+    let recent_blockhash = banks_client
+        .get_new_latest_blockhash(&recent_blockhash)
+        .await
+        .unwrap();
+    let mut transaction = Transaction::new_with_payer(
+        &[cancel_job(
+            rndr::id(),
+            test_mint.pubkey,
+            authority.pubkey(),
+            0,
+            test_destination_token.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+#[tokio::test]
+async fn test_success_within_grace_period() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let funded_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as i64;
+
+    TestConfig::add_with_cancellation(&mut test, MAX_CANCEL_GRACE_PERIOD, FEE_BPS);
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let test_node_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let authority = &test_destination_token.owner;
+    let test_job = TestJob::add_committed_funded_at(
+        &mut test,
+        test_escrow.pubkey,
+        authority.pubkey(),
+        test_node_token.owner.pubkey(),
+        AMOUNT,
+        funded_at,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[cancel_job_with_fee(
+            rndr::id(),
+            test_mint.pubkey,
+            authority.pubkey(),
+            0,
+            test_destination_token.pubkey,
+            test_node_token.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let destination_token_balance_after =
+        get_token_balance(&mut banks_client, test_destination_token.pubkey).await;
+    let node_token_balance_after =
+        get_token_balance(&mut banks_client, test_node_token.pubkey).await;
+
+    assert_eq!(destination_token_balance_after, AMOUNT);
+    assert_eq!(node_token_balance_after, ZERO);
+
+    let job = get_job(&mut banks_client, test_job.pubkey).await;
+    assert_eq!(job.status, JobStatus::Cancelled);
+}
+
+#[tokio::test]
+async fn test_success_with_fee() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    TestConfig::add_with_cancellation(&mut test, ZERO, FEE_BPS);
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let test_node_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let authority = &test_destination_token.owner;
+    let test_job = TestJob::add_committed_funded_at(
+        &mut test,
+        test_escrow.pubkey,
+        authority.pubkey(),
+        test_node_token.owner.pubkey(),
+        AMOUNT,
+        0,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[cancel_job_with_fee(
+            rndr::id(),
+            test_mint.pubkey,
+            authority.pubkey(),
+            0,
+            test_destination_token.pubkey,
+            test_node_token.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let escrow_balance_after =
+        get_token_balance(&mut banks_client, test_escrow.associated_token).await;
+    let destination_token_balance_after =
+        get_token_balance(&mut banks_client, test_destination_token.pubkey).await;
+    let node_token_balance_after =
+        get_token_balance(&mut banks_client, test_node_token.pubkey).await;
+
+    assert_eq!(escrow_balance_after, ZERO);
+    assert_eq!(destination_token_balance_after, AMOUNT - FEE);
+    assert_eq!(node_token_balance_after, FEE);
+
+    let escrow = get_escrow(&mut banks_client, test_escrow.pubkey).await;
+    assert_eq!(escrow.amount, ZERO);
+
+    let job = get_job(&mut banks_client, test_job.pubkey).await;
+    assert_eq!(job.amount, ZERO);
+    assert_eq!(job.status, JobStatus::Cancelled);
+}
+
+#[tokio::test]
+async fn test_success_remainder_to_treasury() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    // A third of the amount can't be split evenly, leaving a remainder of one token
+    const AMOUNT: u64 = 1_000;
+    TestConfig::add_with_rounding_policy(&mut test, Rate::new(3_333), RoundingPolicy::Treasury);
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_treasury = TestTreasury::add(&mut test, test_escrow.pubkey, test_mint.pubkey, 0, 0);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let test_node_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let authority = &test_destination_token.owner;
+    TestJob::add_committed_funded_at(
+        &mut test,
+        test_escrow.pubkey,
+        authority.pubkey(),
+        test_node_token.owner.pubkey(),
+        AMOUNT,
+        0,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[cancel_job_with_fee(
+            rndr::id(),
+            test_mint.pubkey,
+            authority.pubkey(),
+            0,
+            test_destination_token.pubkey,
+            test_node_token.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_escrow.associated_token).await,
+        ZERO
+    );
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_destination_token.pubkey).await,
+        666
+    );
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_node_token.pubkey).await,
+        333
+    );
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_treasury.associated_token).await,
+        1
+    );
+}
+
+#[tokio::test]
+async fn test_success_remainder_burned() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const AMOUNT: u64 = 1_000;
+    const SUPPLY: u64 = 100 * DECIMALS;
+    TestConfig::add_with_rounding_policy(&mut test, Rate::new(3_333), RoundingPolicy::Burn);
+    let test_mint = TestMint::add(&mut test, 9, SUPPLY);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let test_node_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let authority = &test_destination_token.owner;
+    TestJob::add_committed_funded_at(
+        &mut test,
+        test_escrow.pubkey,
+        authority.pubkey(),
+        test_node_token.owner.pubkey(),
+        AMOUNT,
+        0,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // The remainder can't be burned without the token mint
+    let mut instruction = cancel_job_with_fee(
+        rndr::id(),
+        test_mint.pubkey,
+        authority.pubkey(),
+        0,
+        test_destination_token.pubkey,
+        test_node_token.pubkey,
+    );
+    instruction
+        .accounts
+        .retain(|account| account.pubkey != test_mint.pubkey);
+    let mut transaction = Transaction::new_with_payer(&[instruction], Some(&payer.pubkey()));
+    transaction.sign(&[&payer, authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let recent_blockhash = banks_client
+        .get_new_latest_blockhash(&recent_blockhash)
+        .await
+        .unwrap();
+    let mut transaction = Transaction::new_with_payer(
+        &[cancel_job_with_fee(
+            rndr::id(),
+            test_mint.pubkey,
+            authority.pubkey(),
+            0,
+            test_destination_token.pubkey,
+            test_node_token.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_escrow.associated_token).await,
+        ZERO
+    );
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_destination_token.pubkey).await,
+        666
+    );
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_node_token.pubkey).await,
+        333
+    );
+
+    let mint = get_mint(&mut banks_client, test_mint.pubkey).await;
+    assert_eq!(mint.supply, SUPPLY - 1);
+}
+
+#[tokio::test]
+async fn test_success_dust_to_treasury() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    // The fee is below the minimum disbursement, so it's swept to the treasury instead of the node
+    const AMOUNT: u64 = 1_000;
+    TestConfig::add_with_min_disbursement(&mut test, FEE_BPS, AMOUNT / 10 + 1);
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_treasury = TestTreasury::add(&mut test, test_escrow.pubkey, test_mint.pubkey, 0, 0);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let test_node_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let authority = &test_destination_token.owner;
+    TestJob::add_committed_funded_at(
+        &mut test,
+        test_escrow.pubkey,
+        authority.pubkey(),
+        test_node_token.owner.pubkey(),
+        AMOUNT,
+        0,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[cancel_job_with_fee(
+            rndr::id(),
+            test_mint.pubkey,
+            authority.pubkey(),
+            0,
+            test_destination_token.pubkey,
+            test_node_token.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_escrow.associated_token).await,
+        ZERO
+    );
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_destination_token.pubkey).await,
+        AMOUNT - AMOUNT / 10
+    );
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_node_token.pubkey).await,
+        ZERO
+    );
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_treasury.associated_token).await,
+        AMOUNT / 10
+    );
+}
+
+#[tokio::test]
+async fn test_success_fee_at_exchange_rate() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    // The fee is below the minimum disbursement in RNDR base units, but is worth more than it at
+    // the job's exchange rate, so it's paid to the node rather than swept to the treasury
+    const AMOUNT: u64 = 1_000;
+    const RATE: u64 = 50;
+    TestConfig::add_with_min_disbursement(&mut test, FEE_BPS, AMOUNT / 10 + 1);
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    // RATE RNDR base units per base unit of a token of 9 decimals
+    let test_price_feed = TestPriceFeed::add(
+        &mut test,
+        OraclePrice {
+            price: RATE,
+            confidence: 0,
+            exponent: 1,
+            publish_slot: 0,
+        },
+    );
+    let test_escrow = TestEscrow::add_with_price_feed(
+        &mut test,
+        test_mint.pubkey,
+        AMOUNT,
+        test_price_feed.pubkey,
+    );
+    let test_treasury = TestTreasury::add(&mut test, test_escrow.pubkey, test_mint.pubkey, 0, 0);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let test_node_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let authority = &test_destination_token.owner;
+    TestJob::add_committed_funded_at(
+        &mut test,
+        test_escrow.pubkey,
+        authority.pubkey(),
+        test_node_token.owner.pubkey(),
+        AMOUNT,
+        0,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            set_job_exchange_rate(
+                rndr::id(),
+                test_mint.pubkey,
+                test_price_feed.pubkey,
+                test_escrow.owner.pubkey(),
+                authority.pubkey(),
+                0,
+            ),
+            cancel_job_with_fee(
+                rndr::id(),
+                test_mint.pubkey,
+                authority.pubkey(),
+                0,
+                test_destination_token.pubkey,
+                test_node_token.pubkey,
+            ),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner, authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_destination_token.pubkey).await,
+        AMOUNT - AMOUNT / 10
+    );
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_node_token.pubkey).await,
+        AMOUNT / 10
+    );
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_treasury.associated_token).await,
+        ZERO
+    );
+}
+
+#[tokio::test]
+async fn test_success_dust_at_exchange_rate() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    // The refund and fee are above the minimum disbursement in RNDR base units, but are worth less
+    // than it at the job's exchange rate, so they're both swept to the treasury
+    const AMOUNT: u64 = 1_000;
+    const RATE_DENOMINATOR: u64 = 100;
+    TestConfig::add_with_min_disbursement(&mut test, FEE_BPS, AMOUNT / RATE_DENOMINATOR);
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    // One RNDR base unit per RATE_DENOMINATOR base units of a token of 9 decimals
+    let test_price_feed = TestPriceFeed::add(
+        &mut test,
+        OraclePrice {
+            price: 1,
+            confidence: 0,
+            exponent: -1,
+            publish_slot: 0,
+        },
+    );
+    let test_escrow = TestEscrow::add_with_price_feed(
+        &mut test,
+        test_mint.pubkey,
+        AMOUNT,
+        test_price_feed.pubkey,
+    );
+    let test_treasury = TestTreasury::add(&mut test, test_escrow.pubkey, test_mint.pubkey, 0, 0);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let test_node_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let authority = &test_destination_token.owner;
+    let test_job = TestJob::add_committed_funded_at(
+        &mut test,
+        test_escrow.pubkey,
+        authority.pubkey(),
+        test_node_token.owner.pubkey(),
+        AMOUNT,
+        0,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            set_job_exchange_rate(
+                rndr::id(),
+                test_mint.pubkey,
+                test_price_feed.pubkey,
+                test_escrow.owner.pubkey(),
+                authority.pubkey(),
+                0,
+            ),
+            cancel_job_with_fee(
+                rndr::id(),
+                test_mint.pubkey,
+                authority.pubkey(),
+                0,
+                test_destination_token.pubkey,
+                test_node_token.pubkey,
+            ),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner, authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_destination_token.pubkey).await,
+        ZERO
+    );
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_node_token.pubkey).await,
+        ZERO
+    );
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_treasury.associated_token).await,
+        AMOUNT
+    );
+
+    let job = get_job(&mut banks_client, test_job.pubkey).await;
+    assert_eq!(job.status, JobStatus::Cancelled);
+}
+
+#[tokio::test]
+async fn test_success_legacy() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    TestConfig::add(&mut test);
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let authority = &test_destination_token.owner;
+    let test_job = TestJob::add_legacy(&mut test, test_escrow.pubkey, authority.pubkey(), AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[cancel_job(
+            rndr::id(),
+            test_mint.pubkey,
+            authority.pubkey(),
+            0,
+            test_destination_token.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let destination_token_balance_after =
+        get_token_balance(&mut banks_client, test_destination_token.pubkey).await;
+    assert_eq!(destination_token_balance_after, AMOUNT);
+
+    // The legacy job was reallocated to record its cancellation
+    let account = banks_client
+        .get_account(test_job.pubkey)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(account.data.len(), Job::LEN);
+
+    let job = get_job(&mut banks_client, test_job.pubkey).await;
+    assert_eq!(job.status, JobStatus::Cancelled);
+}
+
+#[tokio::test]
+async fn test_missing_node_token() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    TestConfig::add_with_cancellation(&mut test, ZERO, FEE_BPS);
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let authority = &test_destination_token.owner;
+    TestJob::add_committed_funded_at(
+        &mut test,
+        test_escrow.pubkey,
+        authority.pubkey(),
+        Pubkey::new_unique(),
+        AMOUNT,
+        0,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[cancel_job(
+            rndr::id(),
+            test_mint.pubkey,
+            authority.pubkey(),
+            0,
+            test_destination_token.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+#[tokio::test]
+async fn test_wrong_node_token() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    TestConfig::add_with_cancellation(&mut test, ZERO, FEE_BPS);
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let test_other_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let authority = &test_destination_token.owner;
+    TestJob::add_committed_funded_at(
+        &mut test,
+        test_escrow.pubkey,
+        authority.pubkey(),
+        Pubkey::new_unique(),
+        AMOUNT,
+        0,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[cancel_job_with_fee(
+            rndr::id(),
+            test_mint.pubkey,
+            authority.pubkey(),
+            0,
+            test_destination_token.pubkey,
+            test_other_token.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+#[tokio::test]
+async fn test_revealed() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    TestConfig::add(&mut test);
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let authority = &test_destination_token.owner;
+    TestJob::add_revealed(
+        &mut test,
+        test_escrow.pubkey,
+        authority.pubkey(),
+        Pubkey::new_unique(),
+        AMOUNT,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[cancel_job(
+            rndr::id(),
+            test_mint.pubkey,
+            authority.pubkey(),
+            0,
+            test_destination_token.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+#[tokio::test]
+async fn test_not_authority() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    TestConfig::add(&mut test);
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let authority = Pubkey::new_unique();
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, authority, AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // The job's address is derived from its authority, so sign as another keypair for it
+    let not_authority = Keypair::new();
+    let mut instruction = cancel_job(
+        rndr::id(),
+        test_mint.pubkey,
+        not_authority.pubkey(),
+        0,
+        test_destination_token.pubkey,
+    );
+    instruction.accounts[3].pubkey = test_job.pubkey;
+    let mut transaction = Transaction::new_with_payer(&[instruction], Some(&payer.pubkey()));
+    transaction.sign(&[&payer, &not_authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
@@ -0,0 +1,234 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{
+        instruction::{cancel_job_bundle, fund_job_bundle},
+        processor::process_instruction,
+        state::JobStatus,
+        test_fixtures::*,
+    },
+    solana_program_test::*,
+    solana_sdk::{
+        signature::{Keypair, Signer},
+        transaction::Transaction,
+    },
+};
+
+const ZERO: u64 = 0;
+const DECIMALS: u64 = 1_000_000_000;
+const AMOUNT: u64 = 1 * DECIMALS;
+const JOB_COUNT: u8 = 2;
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, 2 * AMOUNT);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, AMOUNT);
+    let authority = &test_destination_token.owner;
+    let test_job_bundle = TestJobBundle::add(
+        &mut test,
+        test_escrow.pubkey,
+        authority.pubkey(),
+        ZERO,
+        JOB_COUNT,
+    );
+    let test_jobs = [0, 1].map(|id| {
+        TestJob::add_with_id(
+            &mut test,
+            test_escrow.pubkey,
+            authority.pubkey(),
+            id,
+            JobStatus::Open,
+            AMOUNT,
+        )
+    });
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[cancel_job_bundle(
+            rndr::id(),
+            test_mint.pubkey,
+            authority.pubkey(),
+            0,
+            test_destination_token.pubkey,
+            &test_jobs.each_ref().map(|test_job| test_job.pubkey),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let escrow_balance_after =
+        get_token_balance(&mut banks_client, test_escrow.associated_token).await;
+    let destination_token_balance_after =
+        get_token_balance(&mut banks_client, test_destination_token.pubkey).await;
+
+    assert_eq!(escrow_balance_after, ZERO);
+    assert_eq!(destination_token_balance_after, 3 * AMOUNT);
+
+    let escrow = get_escrow(&mut banks_client, test_escrow.pubkey).await;
+    assert_eq!(escrow.amount, ZERO);
+
+    for test_job in &test_jobs {
+        let job = test_job.get(&mut banks_client).await;
+        assert_eq!(job.amount, ZERO);
+        assert_eq!(job.status, JobStatus::Cancelled);
+    }
+
+    let job_bundle = test_job_bundle.get(&mut banks_client).await;
+    assert!(job_bundle.is_cancelled);
+    assert!(job_bundle.is_complete());
+
+    // A cancelled bundle can't be funded again
+    let mut transaction = Transaction::new_with_payer(
+        &[fund_job_bundle(
+            rndr::id(),
+            test_mint.pubkey,
+            payer.pubkey(),
+            test_destination_token.pubkey,
+            authority.pubkey(),
+            authority.pubkey(),
+            0,
+            0,
+            JOB_COUNT,
+            AMOUNT,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+#[tokio::test]
+async fn test_job_not_open() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, 2 * AMOUNT);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let authority = &test_destination_token.owner;
+    TestJobBundle::add(
+        &mut test,
+        test_escrow.pubkey,
+        authority.pubkey(),
+        ZERO,
+        JOB_COUNT,
+    );
+    let test_jobs = [JobStatus::Open, JobStatus::Committed]
+        .iter()
+        .enumerate()
+        .map(|(id, &status)| {
+            TestJob::add_with_id(
+                &mut test,
+                test_escrow.pubkey,
+                authority.pubkey(),
+                id as u64,
+                status,
+                AMOUNT,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // A job a node has committed to must be cancelled individually
+    let mut transaction = Transaction::new_with_payer(
+        &[cancel_job_bundle(
+            rndr::id(),
+            test_mint.pubkey,
+            authority.pubkey(),
+            0,
+            test_destination_token.pubkey,
+            &test_jobs
+                .iter()
+                .map(|test_job| test_job.pubkey)
+                .collect::<Vec<_>>(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+#[tokio::test]
+async fn test_job_outside_bundle() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let authority = &test_destination_token.owner;
+    TestJobBundle::add(
+        &mut test,
+        test_escrow.pubkey,
+        authority.pubkey(),
+        ZERO,
+        JOB_COUNT,
+    );
+    let test_job = TestJob::add_with_id(
+        &mut test,
+        test_escrow.pubkey,
+        authority.pubkey(),
+        JOB_COUNT as u64,
+        JobStatus::Open,
+        AMOUNT,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[cancel_job_bundle(
+            rndr::id(),
+            test_mint.pubkey,
+            authority.pubkey(),
+            0,
+            test_destination_token.pubkey,
+            &[test_job.pubkey],
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+#[tokio::test]
+async fn test_not_authority() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let authority = Keypair::new();
+    TestJobBundle::add(
+        &mut test,
+        test_escrow.pubkey,
+        authority.pubkey(),
+        ZERO,
+        JOB_COUNT,
+    );
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, authority.pubkey(), AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // Only the authority of the bundle can cancel it
+    let mut instruction = cancel_job_bundle(
+        rndr::id(),
+        test_mint.pubkey,
+        authority.pubkey(),
+        0,
+        test_destination_token.pubkey,
+        &[test_job.pubkey],
+    );
+    instruction.accounts[0].pubkey = test_destination_token.owner.pubkey();
+    let mut transaction = Transaction::new_with_payer(&[instruction], Some(&payer.pubkey()));
+    transaction.sign(&[&payer, &test_destination_token.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
@@ -0,0 +1,90 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{
+        instruction::cancel_queued_action,
+        processor::process_instruction,
+        state::{AdminAction, ConfigChange},
+        test_fixtures::*,
+    },
+    solana_program_test::*,
+    solana_sdk::{
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        transaction::Transaction,
+    },
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let test_config = TestConfig::add(&mut test);
+    let test_queued_action = TestQueuedAction::add(
+        &mut test,
+        test_config.pubkey,
+        1,
+        test_config.governance.pubkey(),
+        AdminAction::SetConfig(ConfigChange::Governance(Pubkey::new_unique())),
+        i64::MAX,
+    );
+    let test_expired_action = TestQueuedAction::add(
+        &mut test,
+        test_config.pubkey,
+        2,
+        test_config.governance.pubkey(),
+        AdminAction::SetConfig(ConfigChange::Governance(Pubkey::new_unique())),
+        1,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // Only the guardian can cancel queued actions
+    let impostor = Keypair::new();
+    let mut transaction = Transaction::new_with_payer(
+        &[cancel_queued_action(
+            rndr::id(),
+            impostor.pubkey(),
+            test_config.pubkey,
+            test_queued_action.id,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &impostor], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let guardian = &test_config.guardian;
+    let mut transaction = Transaction::new_with_payer(
+        &[cancel_queued_action(
+            rndr::id(),
+            guardian.pubkey(),
+            test_config.pubkey,
+            test_queued_action.id,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, guardian], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let queued_action = test_queued_action.get(&mut banks_client).await;
+    assert!(queued_action.is_cancelled());
+
+    // Actions can only be cancelled during their timelock window
+    let mut transaction = Transaction::new_with_payer(
+        &[cancel_queued_action(
+            rndr::id(),
+            guardian.pubkey(),
+            test_config.pubkey,
+            test_expired_action.id,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, guardian], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let queued_action = test_expired_action.get(&mut banks_client).await;
+    assert!(!queued_action.is_cancelled());
+}
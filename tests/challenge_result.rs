@@ -0,0 +1,253 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{
+        error::RNDRError,
+        instruction::{
+            challenge_result, challenge_result_with_session_key, claim_payment, reveal_result,
+        },
+        processor::process_instruction,
+        state::{Job, JobStatus, SESSION_KEY_CHALLENGE_RESULT, SESSION_KEY_FUND_JOB},
+        test_fixtures::*,
+    },
+    solana_program_test::*,
+    solana_sdk::{
+        clock::Slot,
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        transaction::Transaction,
+    },
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const AMOUNT: u64 = 1_000_000_000;
+    const CHALLENGE_WINDOW: u64 = 24 * 60 * 60;
+    const RESULT_HASH: [u8; 32] = [2; 32];
+    const SALT: [u8; 32] = [3; 32];
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * AMOUNT);
+    let test_escrow = TestEscrow::add_with_challenge_window(
+        &mut test,
+        test_mint.pubkey,
+        AMOUNT,
+        CHALLENGE_WINDOW,
+    );
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let node = &test_destination_token.owner;
+    let authority = Keypair::new();
+    let (job_pubkey, _bump_seed) = find_job_address(test_escrow.pubkey, authority.pubkey(), 0);
+    let commitment = Job::result_commitment(&job_pubkey, &node.pubkey(), &RESULT_HASH, &SALT);
+    let test_job = TestJob::add_committed(
+        &mut test,
+        test_escrow.pubkey,
+        authority.pubkey(),
+        node.pubkey(),
+        commitment,
+        AMOUNT,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[reveal_result(
+            rndr::id(),
+            RESULT_HASH,
+            SALT,
+            test_mint.pubkey,
+            node.pubkey(),
+            test_job.pubkey,
+            None,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, node], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    // The node can't claim payment while the result can still be challenged
+    let mut transaction = Transaction::new_with_payer(
+        &[claim_payment(
+            rndr::id(),
+            test_mint.pubkey,
+            node.pubkey(),
+            test_job.pubkey,
+            test_destination_token.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, node], recent_blockhash);
+
+    assert_eq!(
+        program_error(banks_client.process_transaction(transaction).await),
+        Some(RNDRError::ChallengeWindowActive)
+    );
+
+    let mut transaction = Transaction::new_with_payer(
+        &[challenge_result(
+            rndr::id(),
+            test_mint.pubkey,
+            authority.pubkey(),
+            0,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let job = get_job(&mut banks_client, test_job.pubkey).await;
+    assert_eq!(job.status, JobStatus::Challenged);
+    assert_eq!(job.amount, AMOUNT);
+
+    let destination_token_balance_after =
+        get_token_balance(&mut banks_client, test_destination_token.pubkey).await;
+    assert_eq!(destination_token_balance_after, ZERO);
+}
+
+#[tokio::test]
+async fn test_success_with_session_key() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const AMOUNT: u64 = 1_000_000_000;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * AMOUNT);
+    let test_escrow =
+        TestEscrow::add_with_challenge_window(&mut test, test_mint.pubkey, AMOUNT, u64::MAX);
+    let authority = Keypair::new();
+    let test_job = TestJob::add_revealed(
+        &mut test,
+        test_escrow.pubkey,
+        authority.pubkey(),
+        Pubkey::new_unique(),
+        AMOUNT,
+    );
+    let test_session_key = TestSessionKey::add(
+        &mut test,
+        test_escrow.pubkey,
+        authority.pubkey(),
+        Slot::MAX,
+        SESSION_KEY_CHALLENGE_RESULT,
+    );
+    let session_key = &test_session_key.session_key;
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[challenge_result_with_session_key(
+            rndr::id(),
+            test_mint.pubkey,
+            session_key.pubkey(),
+            authority.pubkey(),
+            0,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, session_key], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let job = get_job(&mut banks_client, test_job.pubkey).await;
+    assert_eq!(job.status, JobStatus::Challenged);
+}
+
+#[tokio::test]
+async fn test_session_key_expired() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const AMOUNT: u64 = 1_000_000_000;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * AMOUNT);
+    let test_escrow =
+        TestEscrow::add_with_challenge_window(&mut test, test_mint.pubkey, AMOUNT, u64::MAX);
+    let authority = Keypair::new();
+    let test_job = TestJob::add_revealed(
+        &mut test,
+        test_escrow.pubkey,
+        authority.pubkey(),
+        Pubkey::new_unique(),
+        AMOUNT,
+    );
+    let test_session_key = TestSessionKey::add(
+        &mut test,
+        test_escrow.pubkey,
+        authority.pubkey(),
+        0,
+        SESSION_KEY_CHALLENGE_RESULT,
+    );
+    let session_key = &test_session_key.session_key;
+
+    let mut context = test.start_with_context().await;
+    context.warp_to_slot(2).unwrap();
+
+    let mut transaction = Transaction::new_with_payer(
+        &[challenge_result_with_session_key(
+            rndr::id(),
+            test_mint.pubkey,
+            session_key.pubkey(),
+            authority.pubkey(),
+            0,
+        )],
+        Some(&context.payer.pubkey()),
+    );
+    transaction.sign(&[&context.payer, session_key], context.last_blockhash);
+
+    assert!(context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .is_err());
+
+    let job = get_job(&mut context.banks_client, test_job.pubkey).await;
+    assert_eq!(job.status, JobStatus::Revealed);
+}
+
+#[tokio::test]
+async fn test_session_key_not_allowed() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const AMOUNT: u64 = 1_000_000_000;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * AMOUNT);
+    let test_escrow =
+        TestEscrow::add_with_challenge_window(&mut test, test_mint.pubkey, AMOUNT, u64::MAX);
+    let authority = Keypair::new();
+    let test_job = TestJob::add_revealed(
+        &mut test,
+        test_escrow.pubkey,
+        authority.pubkey(),
+        Pubkey::new_unique(),
+        AMOUNT,
+    );
+    // The session key can only top up jobs
+    let test_session_key = TestSessionKey::add(
+        &mut test,
+        test_escrow.pubkey,
+        authority.pubkey(),
+        Slot::MAX,
+        SESSION_KEY_FUND_JOB,
+    );
+    let session_key = &test_session_key.session_key;
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[challenge_result_with_session_key(
+            rndr::id(),
+            test_mint.pubkey,
+            session_key.pubkey(),
+            authority.pubkey(),
+            0,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, session_key], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let job = get_job(&mut banks_client, test_job.pubkey).await;
+    assert_eq!(job.status, JobStatus::Revealed);
+}
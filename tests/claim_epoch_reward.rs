@@ -0,0 +1,108 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{instruction::claim_epoch_reward, processor::process_instruction, test_fixtures::*},
+    solana_program_test::*,
+    solana_sdk::{signature::Signer, transaction::Transaction},
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+    const EPOCH: u64 = 0;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let node = &test_destination_token.owner;
+    let test_reward_pool = TestRewardPool::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_mint.pubkey,
+        EPOCH,
+        AMOUNT,
+        4,
+    );
+    let test_epoch_work = TestEpochWork::add(&mut test, test_reward_pool.pubkey, node.pubkey(), 1);
+
+    let mut context = test.start_with_context().await;
+    let first_normal_slot = context.genesis_config().epoch_schedule.first_normal_slot;
+    context.warp_to_slot(first_normal_slot + 1).unwrap();
+
+    let mut transaction = Transaction::new_with_payer(
+        &[claim_epoch_reward(
+            rndr::id(),
+            test_mint.pubkey,
+            node.pubkey(),
+            EPOCH,
+            test_destination_token.pubkey,
+        )],
+        Some(&context.payer.pubkey()),
+    );
+    transaction.sign(&[&context.payer, node], context.last_blockhash);
+
+    assert!(context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .is_ok());
+
+    let banks_client = &mut context.banks_client;
+    let reward_pool_balance_after =
+        get_token_balance(banks_client, test_reward_pool.associated_token).await;
+    let destination_token_balance_after =
+        get_token_balance(banks_client, test_destination_token.pubkey).await;
+
+    assert_eq!(reward_pool_balance_after, AMOUNT - AMOUNT / 4);
+    assert_eq!(destination_token_balance_after, AMOUNT / 4);
+
+    let reward_pool = test_reward_pool.get(banks_client).await;
+    assert_eq!(reward_pool.claimed, AMOUNT / 4);
+
+    let epoch_work = test_epoch_work.get(banks_client).await;
+    assert!(epoch_work.claimed);
+}
+
+#[tokio::test]
+async fn test_epoch_not_ended() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+    const EPOCH: u64 = 0;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let node = &test_destination_token.owner;
+    let test_reward_pool = TestRewardPool::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_mint.pubkey,
+        EPOCH,
+        AMOUNT,
+        4,
+    );
+    TestEpochWork::add(&mut test, test_reward_pool.pubkey, node.pubkey(), 1);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[claim_epoch_reward(
+            rndr::id(),
+            test_mint.pubkey,
+            node.pubkey(),
+            EPOCH,
+            test_destination_token.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, node], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
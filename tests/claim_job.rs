@@ -0,0 +1,250 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{
+        instruction::{claim_job, fund_job, init_job_queue, raise_job_priority, with_job_queue},
+        math::Rate,
+        processor::process_instruction,
+        state::{JobStatus, MAX_JOB_PRIORITY},
+        test_fixtures::*,
+    },
+    solana_program_test::*,
+    solana_sdk::{signature::Signer, transaction::Transaction},
+};
+
+const ZERO: u64 = 0;
+const DECIMALS: u64 = 1_000_000_000;
+const AMOUNT: u64 = 1 * DECIMALS;
+const COMMITMENT: [u8; 32] = [1; 32];
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    TestConfig::add(&mut test);
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let test_source_token = TestToken::add(&mut test, test_mint.pubkey, 2 * AMOUNT);
+    let authority = test_source_token.owner.pubkey();
+    let (job_queue_pubkey, _bump_seed) = find_job_queue_address(test_escrow.pubkey);
+    let jobs = [0, 1].map(|job_id| find_job_address(test_escrow.pubkey, authority, job_id).0);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut instructions = vec![init_job_queue(rndr::id(), test_mint.pubkey, payer.pubkey())];
+    for job_id in [0, 1] {
+        instructions.push(with_job_queue(
+            fund_job(
+                rndr::id(),
+                AMOUNT,
+                job_id,
+                test_mint.pubkey,
+                payer.pubkey(),
+                test_source_token.pubkey,
+                authority,
+            ),
+            test_escrow.pubkey,
+        ));
+    }
+    let mut transaction = Transaction::new_with_payer(&instructions, Some(&payer.pubkey()));
+    transaction.sign(&[&payer, &test_source_token.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let job_queue = get_job_queue(&mut banks_client, job_queue_pubkey).await;
+    assert_eq!(job_queue.jobs, jobs);
+
+    // Jobs are claimed in the order they were funded
+    let mut transaction = Transaction::new_with_payer(
+        &[claim_job(
+            rndr::id(),
+            COMMITMENT,
+            payer.pubkey(),
+            job_queue_pubkey,
+            jobs[1],
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let mut transaction = Transaction::new_with_payer(
+        &[claim_job(
+            rndr::id(),
+            COMMITMENT,
+            payer.pubkey(),
+            job_queue_pubkey,
+            jobs[0],
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let job = get_job(&mut banks_client, jobs[0]).await;
+    assert_eq!(job.status, JobStatus::Committed);
+    assert_eq!(job.node, payer.pubkey());
+    assert_eq!(job.result_commitment, COMMITMENT);
+
+    let job = get_job(&mut banks_client, jobs[1]).await;
+    assert_eq!(job.status, JobStatus::Open);
+
+    let job_queue = get_job_queue(&mut banks_client, job_queue_pubkey).await;
+    assert_eq!(job_queue.head, 1);
+    assert_eq!(job_queue.jobs, [jobs[1]]);
+}
+
+#[tokio::test]
+async fn test_delayed_job_is_requeued() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const PRIORITY_CLAIM_DELAY: u64 = 60 * 60;
+
+    TestConfig::add_with_priority(&mut test, Rate::ZERO, PRIORITY_CLAIM_DELAY);
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let test_source_token = TestToken::add(&mut test, test_mint.pubkey, 2 * AMOUNT);
+    let authority = test_source_token.owner.pubkey();
+    let (job_queue_pubkey, _bump_seed) = find_job_queue_address(test_escrow.pubkey);
+    let jobs = [0, 1].map(|job_id| find_job_address(test_escrow.pubkey, authority, job_id).0);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // Only the newer job is raised to the maximum priority, so it's the only one claimable
+    let mut instructions = vec![init_job_queue(rndr::id(), test_mint.pubkey, payer.pubkey())];
+    for job_id in [0, 1] {
+        instructions.push(with_job_queue(
+            fund_job(
+                rndr::id(),
+                AMOUNT,
+                job_id,
+                test_mint.pubkey,
+                payer.pubkey(),
+                test_source_token.pubkey,
+                authority,
+            ),
+            test_escrow.pubkey,
+        ));
+    }
+    instructions.push(raise_job_priority(
+        rndr::id(),
+        test_mint.pubkey,
+        authority,
+        1,
+        test_source_token.pubkey,
+        MAX_JOB_PRIORITY,
+    ));
+    let mut transaction = Transaction::new_with_payer(&instructions, Some(&payer.pubkey()));
+    transaction.sign(&[&payer, &test_source_token.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    // The oldest job is still in its claim delay, so it's moved to the back of the queue instead
+    // of blocking the job behind it
+    let mut transaction = Transaction::new_with_payer(
+        &[claim_job(
+            rndr::id(),
+            COMMITMENT,
+            payer.pubkey(),
+            job_queue_pubkey,
+            jobs[0],
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let job = get_job(&mut banks_client, jobs[0]).await;
+    assert_eq!(job.status, JobStatus::Open);
+    assert_ne!(job.node, payer.pubkey());
+
+    let job_queue = get_job_queue(&mut banks_client, job_queue_pubkey).await;
+    assert_eq!(job_queue.jobs, [jobs[1], jobs[0]]);
+
+    let mut transaction = Transaction::new_with_payer(
+        &[claim_job(
+            rndr::id(),
+            COMMITMENT,
+            payer.pubkey(),
+            job_queue_pubkey,
+            jobs[1],
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let job = get_job(&mut banks_client, jobs[1]).await;
+    assert_eq!(job.status, JobStatus::Committed);
+    assert_eq!(job.node, payer.pubkey());
+
+    let job_queue = get_job_queue(&mut banks_client, job_queue_pubkey).await;
+    assert_eq!(job_queue.jobs, [jobs[0]]);
+}
+
+#[tokio::test]
+async fn test_stale_job_is_popped() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    TestConfig::add(&mut test);
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_authority = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let authority = test_authority.owner.pubkey();
+    let test_job = TestJob::add_with_id(
+        &mut test,
+        test_escrow.pubkey,
+        authority,
+        0,
+        JobStatus::Cancelled,
+        ZERO,
+    );
+    let test_job_queue = TestJobQueue::add(&mut test, test_escrow.pubkey, &[test_job.pubkey]);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[claim_job(
+            rndr::id(),
+            COMMITMENT,
+            payer.pubkey(),
+            test_job_queue.pubkey,
+            test_job.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    // The cancelled job is popped without the node being committed to it
+    let job = get_job(&mut banks_client, test_job.pubkey).await;
+    assert_eq!(job.status, JobStatus::Cancelled);
+    assert_ne!(job.node, payer.pubkey());
+
+    let job_queue = test_job_queue.get(&mut banks_client).await;
+    assert!(job_queue.jobs.is_empty());
+
+    // Once empty, there is nothing left to claim
+    let recent_blockhash = banks_client
+        .get_new_latest_blockhash(&recent_blockhash)
+        .await
+        .unwrap();
+    let mut transaction = Transaction::new_with_payer(
+        &[claim_job(
+            rndr::id(),
+            COMMITMENT,
+            payer.pubkey(),
+            test_job_queue.pubkey,
+            test_job.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
@@ -0,0 +1,157 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{instruction::claim_merkle_reward, processor::process_instruction, test_fixtures::*},
+    solana_program_test::*,
+    solana_sdk::{
+        native_token::LAMPORTS_PER_SOL, pubkey::Pubkey, signature::Signer, system_instruction,
+        transaction::Transaction,
+    },
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+    const EPOCH: u64 = 0;
+    const INDEX: u32 = 2;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let node = &test_destination_token.owner;
+    let tree = TestMerkleTree::new(&[
+        (Pubkey::new_unique(), AMOUNT / 4),
+        (Pubkey::new_unique(), AMOUNT / 4),
+        (node.pubkey(), AMOUNT / 2),
+    ]);
+    let test_merkle_distribution = TestMerkleDistribution::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_mint.pubkey,
+        EPOCH,
+        tree.root,
+        3,
+        AMOUNT,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            system_instruction::transfer(&payer.pubkey(), &node.pubkey(), LAMPORTS_PER_SOL),
+            claim_merkle_reward(
+                rndr::id(),
+                test_mint.pubkey,
+                node.pubkey(),
+                EPOCH,
+                test_destination_token.pubkey,
+                INDEX,
+                AMOUNT / 2,
+                tree.proofs[INDEX as usize].clone(),
+            ),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, node], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let merkle_distribution_balance_after =
+        get_token_balance(&mut banks_client, test_merkle_distribution.associated_token).await;
+    let destination_token_balance_after =
+        get_token_balance(&mut banks_client, test_destination_token.pubkey).await;
+
+    assert_eq!(merkle_distribution_balance_after, AMOUNT / 2);
+    assert_eq!(destination_token_balance_after, AMOUNT / 2);
+
+    let merkle_distribution = test_merkle_distribution.get(&mut banks_client).await;
+    assert_eq!(merkle_distribution.claimed, AMOUNT / 2);
+
+    let (claim_bitmap_pubkey, _bump_seed) =
+        find_claim_bitmap_address(test_merkle_distribution.pubkey, INDEX);
+    let claim_bitmap = get_claim_bitmap(&mut banks_client, claim_bitmap_pubkey).await;
+    assert_eq!(
+        claim_bitmap.merkle_distribution,
+        test_merkle_distribution.pubkey
+    );
+    assert_eq!(claim_bitmap.chunk, 0);
+    assert!(claim_bitmap.is_claimed(INDEX));
+    assert!(!claim_bitmap.is_claimed(0));
+
+    // Claiming the same leaf again fails
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            system_instruction::transfer(&payer.pubkey(), &node.pubkey(), LAMPORTS_PER_SOL + 1),
+            claim_merkle_reward(
+                rndr::id(),
+                test_mint.pubkey,
+                node.pubkey(),
+                EPOCH,
+                test_destination_token.pubkey,
+                INDEX,
+                AMOUNT / 2,
+                tree.proofs[INDEX as usize].clone(),
+            ),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, node], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+#[tokio::test]
+async fn test_invalid_proof() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+    const EPOCH: u64 = 0;
+    const INDEX: u32 = 2;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let node = &test_destination_token.owner;
+    let tree = TestMerkleTree::new(&[
+        (Pubkey::new_unique(), AMOUNT / 4),
+        (Pubkey::new_unique(), AMOUNT / 4),
+        (node.pubkey(), AMOUNT / 2),
+    ]);
+    TestMerkleDistribution::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_mint.pubkey,
+        EPOCH,
+        tree.root,
+        3,
+        AMOUNT,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            system_instruction::transfer(&payer.pubkey(), &node.pubkey(), LAMPORTS_PER_SOL),
+            claim_merkle_reward(
+                rndr::id(),
+                test_mint.pubkey,
+                node.pubkey(),
+                EPOCH,
+                test_destination_token.pubkey,
+                INDEX,
+                AMOUNT,
+                tree.proofs[INDEX as usize].clone(),
+            ),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, node], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
@@ -0,0 +1,569 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{
+        instruction::{claim_payment, claim_payment_with_work, set_job_exchange_rate, stake},
+        oracle::OraclePrice,
+        processor::process_instruction,
+        state::{JobStatus, RewardTier},
+        test_fixtures::*,
+    },
+    solana_program_test::*,
+    solana_sdk::{
+        native_token::LAMPORTS_PER_SOL, pubkey::Pubkey, signature::Signer, system_instruction,
+        transaction::Transaction,
+    },
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    TestConfig::add(&mut test);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let node = &test_destination_token.owner;
+    let test_job = TestJob::add_revealed(
+        &mut test,
+        test_escrow.pubkey,
+        Pubkey::new_unique(),
+        node.pubkey(),
+        AMOUNT,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // The Node and Treasury accounts haven't been created, so they're empty system accounts like
+    // the escrow shard of the job, and aren't mistaken for escrow shards
+    let mut transaction = Transaction::new_with_payer(
+        &[claim_payment(
+            rndr::id(),
+            test_mint.pubkey,
+            node.pubkey(),
+            test_job.pubkey,
+            test_destination_token.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, node], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let escrow_balance_after =
+        get_token_balance(&mut banks_client, test_escrow.associated_token).await;
+    let destination_token_balance_after =
+        get_token_balance(&mut banks_client, test_destination_token.pubkey).await;
+
+    assert_eq!(escrow_balance_after, ZERO);
+    assert_eq!(destination_token_balance_after, AMOUNT);
+
+    let escrow = get_escrow(&mut banks_client, test_escrow.pubkey).await;
+    assert_eq!(escrow.amount, ZERO);
+
+    let job = get_job(&mut banks_client, test_job.pubkey).await;
+    assert_eq!(job.amount, ZERO);
+    assert_eq!(job.status, JobStatus::Paid);
+}
+
+#[tokio::test]
+async fn test_success_with_work() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+    const EPOCH: u64 = 0;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    TestConfig::add(&mut test);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let node = &test_destination_token.owner;
+    let test_job = TestJob::add_revealed(
+        &mut test,
+        test_escrow.pubkey,
+        Pubkey::new_unique(),
+        node.pubkey(),
+        AMOUNT,
+    );
+    let test_reward_pool = TestRewardPool::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_mint.pubkey,
+        EPOCH,
+        ZERO,
+        AMOUNT,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            system_instruction::transfer(&payer.pubkey(), &node.pubkey(), LAMPORTS_PER_SOL),
+            claim_payment_with_work(
+                rndr::id(),
+                test_mint.pubkey,
+                node.pubkey(),
+                test_job.pubkey,
+                test_destination_token.pubkey,
+                EPOCH,
+            ),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, node], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let destination_token_balance_after =
+        get_token_balance(&mut banks_client, test_destination_token.pubkey).await;
+    assert_eq!(destination_token_balance_after, AMOUNT);
+
+    let reward_pool = test_reward_pool.get(&mut banks_client).await;
+    assert_eq!(reward_pool.total_work, 2 * AMOUNT);
+
+    let (epoch_work_pubkey, _bump_seed) =
+        find_epoch_work_address(test_reward_pool.pubkey, node.pubkey());
+    let epoch_work = get_epoch_work(&mut banks_client, epoch_work_pubkey).await;
+    assert_eq!(epoch_work.reward_pool, test_reward_pool.pubkey);
+    assert_eq!(epoch_work.node, node.pubkey());
+    assert_eq!(epoch_work.work, AMOUNT);
+    assert!(!epoch_work.claimed);
+}
+
+#[tokio::test]
+async fn test_success_with_work_credits() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+    const EPOCH: u64 = 0;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    TestConfig::add(&mut test);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let node = &test_destination_token.owner;
+    let test_job = TestJob::add_revealed(
+        &mut test,
+        test_escrow.pubkey,
+        Pubkey::new_unique(),
+        node.pubkey(),
+        AMOUNT,
+    );
+    let test_reward_pool = TestRewardPool::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_mint.pubkey,
+        EPOCH,
+        ZERO,
+        ZERO,
+    );
+    let test_node = TestNode::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_mint.pubkey,
+        node.pubkey(),
+        ZERO,
+        false,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            system_instruction::transfer(&payer.pubkey(), &node.pubkey(), LAMPORTS_PER_SOL),
+            claim_payment_with_work(
+                rndr::id(),
+                test_mint.pubkey,
+                node.pubkey(),
+                test_job.pubkey,
+                test_destination_token.pubkey,
+                EPOCH,
+            ),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, node], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let reward_pool = test_reward_pool.get(&mut banks_client).await;
+    assert_eq!(reward_pool.total_work, AMOUNT);
+
+    let node_account = test_node.get(&mut banks_client).await;
+    assert_eq!(node_account.work_credits, AMOUNT);
+    assert_eq!(node_account.work_epoch, EPOCH);
+    assert_eq!(node_account.epoch_work_credits, AMOUNT);
+    assert_eq!(node_account.work_credits_in_epoch(EPOCH), AMOUNT);
+}
+
+#[tokio::test]
+async fn test_success_with_work_at_exchange_rate() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+    const EPOCH: u64 = 0;
+    // 50 RNDR base units per base unit of the job's token
+    const RATE: u64 = 50;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    // Priced at RATE RNDR base units per base unit of a token of 9 decimals
+    let test_price_feed = TestPriceFeed::add(
+        &mut test,
+        OraclePrice {
+            price: RATE,
+            confidence: 0,
+            exponent: 1,
+            publish_slot: 0,
+        },
+    );
+    let test_escrow = TestEscrow::add_with_price_feed(
+        &mut test,
+        test_mint.pubkey,
+        AMOUNT,
+        test_price_feed.pubkey,
+    );
+    TestConfig::add(&mut test);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let node = &test_destination_token.owner;
+    let authority = Pubkey::new_unique();
+    let test_job = TestJob::add_revealed(
+        &mut test,
+        test_escrow.pubkey,
+        authority,
+        node.pubkey(),
+        AMOUNT,
+    );
+    let test_reward_pool = TestRewardPool::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_mint.pubkey,
+        EPOCH,
+        ZERO,
+        ZERO,
+    );
+    let test_node = TestNode::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_mint.pubkey,
+        node.pubkey(),
+        ZERO,
+        false,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            system_instruction::transfer(&payer.pubkey(), &node.pubkey(), LAMPORTS_PER_SOL),
+            set_job_exchange_rate(
+                rndr::id(),
+                test_mint.pubkey,
+                test_price_feed.pubkey,
+                test_escrow.owner.pubkey(),
+                authority,
+                0,
+            ),
+            claim_payment_with_work(
+                rndr::id(),
+                test_mint.pubkey,
+                node.pubkey(),
+                test_job.pubkey,
+                test_destination_token.pubkey,
+                EPOCH,
+            ),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner, node], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    // The node is paid in the job's token, but credited with the payment's value in RNDR
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_destination_token.pubkey).await,
+        AMOUNT
+    );
+
+    let reward_pool = test_reward_pool.get(&mut banks_client).await;
+    assert_eq!(reward_pool.total_work, RATE * AMOUNT);
+
+    let node_account = test_node.get(&mut banks_client).await;
+    assert_eq!(node_account.work_credits, RATE * AMOUNT);
+    assert_eq!(node_account.work_credits_in_epoch(EPOCH), RATE * AMOUNT);
+}
+
+#[tokio::test]
+async fn test_success_with_reward_multiplier() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+    const STAKE: u64 = 10 * DECIMALS;
+    const EPOCH: u64 = 0;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let node = &test_destination_token.owner;
+    let test_job = TestJob::add_revealed(
+        &mut test,
+        test_escrow.pubkey,
+        Pubkey::new_unique(),
+        node.pubkey(),
+        AMOUNT,
+    );
+    let test_reward_pool = TestRewardPool::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_mint.pubkey,
+        EPOCH,
+        ZERO,
+        ZERO,
+    );
+    let test_node = TestNode::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_mint.pubkey,
+        node.pubkey(),
+        STAKE,
+        false,
+    );
+    let test_treasury = TestTreasury::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_mint.pubkey,
+        ZERO,
+        AMOUNT,
+    );
+    TestConfig::add_with_reward_tiers(
+        &mut test,
+        [
+            RewardTier {
+                min_stake: STAKE / 2,
+                multiplier_bps: 12_500,
+            },
+            RewardTier {
+                min_stake: STAKE,
+                multiplier_bps: 15_000,
+            },
+            RewardTier {
+                min_stake: STAKE * 2,
+                multiplier_bps: 20_000,
+            },
+            RewardTier::default(),
+        ],
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            system_instruction::transfer(&payer.pubkey(), &node.pubkey(), LAMPORTS_PER_SOL),
+            claim_payment_with_work(
+                rndr::id(),
+                test_mint.pubkey,
+                node.pubkey(),
+                test_job.pubkey,
+                test_destination_token.pubkey,
+                EPOCH,
+            ),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, node], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    // Only the work recorded toward the epoch's reward emissions is weighted by the tier
+    let reward_pool = test_reward_pool.get(&mut banks_client).await;
+    assert_eq!(reward_pool.total_work, AMOUNT * 3 / 2);
+
+    let node_account = test_node.get(&mut banks_client).await;
+    assert_eq!(node_account.work_credits, AMOUNT);
+
+    // The node is paid the Job's amount, and the treasury isn't touched
+    let destination_token = test_destination_token.get(&mut banks_client).await;
+    assert_eq!(destination_token.amount, AMOUNT);
+
+    let treasury_balance =
+        get_token_balance(&mut banks_client, test_treasury.associated_token).await;
+    assert_eq!(treasury_balance, AMOUNT);
+}
+
+#[tokio::test]
+async fn test_success_below_base_tier() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+    const EPOCH: u64 = 0;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let node = &test_destination_token.owner;
+    let test_job = TestJob::add_revealed(
+        &mut test,
+        test_escrow.pubkey,
+        Pubkey::new_unique(),
+        node.pubkey(),
+        AMOUNT,
+    );
+    let test_reward_pool = TestRewardPool::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_mint.pubkey,
+        EPOCH,
+        ZERO,
+        ZERO,
+    );
+    let test_treasury =
+        TestTreasury::add(&mut test, test_escrow.pubkey, test_mint.pubkey, ZERO, ZERO);
+    TestConfig::add_with_reward_tiers(
+        &mut test,
+        [
+            RewardTier {
+                min_stake: ZERO,
+                multiplier_bps: 8_000,
+            },
+            RewardTier::default(),
+            RewardTier::default(),
+            RewardTier::default(),
+        ],
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // The node hasn't staked, so its work is weighted by the lowest tier's multiplier, but none
+    // of its payment is withheld
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            system_instruction::transfer(&payer.pubkey(), &node.pubkey(), LAMPORTS_PER_SOL),
+            claim_payment_with_work(
+                rndr::id(),
+                test_mint.pubkey,
+                node.pubkey(),
+                test_job.pubkey,
+                test_destination_token.pubkey,
+                EPOCH,
+            ),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, node], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let reward_pool = test_reward_pool.get(&mut banks_client).await;
+    assert_eq!(reward_pool.total_work, AMOUNT * 4 / 5);
+
+    let destination_token_balance =
+        get_token_balance(&mut banks_client, test_destination_token.pubkey).await;
+    assert_eq!(destination_token_balance, AMOUNT);
+
+    let treasury_balance =
+        get_token_balance(&mut banks_client, test_treasury.associated_token).await;
+    assert_eq!(treasury_balance, ZERO);
+
+    let escrow = get_escrow(&mut banks_client, test_escrow.pubkey).await;
+    assert_eq!(escrow.amount, ZERO);
+}
+
+#[tokio::test]
+async fn test_stake_in_claim_epoch_not_counted() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+    const STAKE: u64 = 10 * DECIMALS;
+    const EPOCH: u64 = 0;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let test_source_token = TestToken::add(&mut test, test_mint.pubkey, STAKE);
+    let node = &test_destination_token.owner;
+    let test_job = TestJob::add_revealed(
+        &mut test,
+        test_escrow.pubkey,
+        Pubkey::new_unique(),
+        node.pubkey(),
+        AMOUNT,
+    );
+    let test_reward_pool = TestRewardPool::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_mint.pubkey,
+        EPOCH,
+        ZERO,
+        ZERO,
+    );
+    TestConfig::add_with_reward_tiers(
+        &mut test,
+        [
+            RewardTier {
+                min_stake: STAKE,
+                multiplier_bps: 20_000,
+            },
+            RewardTier::default(),
+            RewardTier::default(),
+            RewardTier::default(),
+        ],
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // Staking just before claiming doesn't reach the tier until the next epoch
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            system_instruction::transfer(&payer.pubkey(), &node.pubkey(), LAMPORTS_PER_SOL),
+            stake(
+                rndr::id(),
+                STAKE,
+                test_mint.pubkey,
+                node.pubkey(),
+                test_source_token.pubkey,
+                test_source_token.owner.pubkey(),
+            ),
+            claim_payment_with_work(
+                rndr::id(),
+                test_mint.pubkey,
+                node.pubkey(),
+                test_job.pubkey,
+                test_destination_token.pubkey,
+                EPOCH,
+            ),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, node, &test_source_token.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let reward_pool = test_reward_pool.get(&mut banks_client).await;
+    assert_eq!(reward_pool.total_work, AMOUNT);
+
+    let destination_token_balance =
+        get_token_balance(&mut banks_client, test_destination_token.pubkey).await;
+    assert_eq!(destination_token_balance, AMOUNT);
+
+    let (node_pubkey, _bump_seed) = find_node_address(test_escrow.pubkey, node.pubkey());
+    let node_account = get_node(&mut banks_client, node_pubkey).await;
+    assert_eq!(node_account.stake, STAKE);
+    assert_eq!(node_account.epoch_staked, STAKE);
+    assert_eq!(node_account.tier_stake(node_account.stake_epoch), ZERO);
+    assert_eq!(node_account.tier_stake(node_account.stake_epoch + 1), STAKE);
+}
@@ -0,0 +1,276 @@
+#![cfg(all(feature = "codegen", feature = "serde"))]
+
+use {
+    rndr::{
+        codegen::{
+            idl::{self, Account},
+            typescript, Schema, Type, TypeDef, VariantFields,
+        },
+        instruction::RNDRInstruction,
+    },
+    serde_json::{json, Map, Value},
+    solana_program::pubkey::Pubkey,
+};
+
+/// Sample of a value of a type, varied by a seed so every variant of an enum is covered
+fn sample(schema: &Schema, ty: &Type, seed: u64) -> Value {
+    match ty {
+        Type::U8 => json!(seed % 256),
+        Type::U16 => json!(seed * 257 % 65536),
+        Type::U32 => json!(seed * 16_843_009 % (1 << 32)),
+        Type::U64 => json!(seed.wrapping_mul(0x0102_0304_0506_0708)),
+        Type::I64 => json!(-(seed as i64) * 1_000_003),
+        Type::Bool => json!(seed % 2 == 1),
+        Type::Pubkey => json!(Pubkey::new_unique().to_string()),
+        Type::FixedBytes(len) => json!((0..*len)
+            .map(|i| (seed as usize + i) % 256)
+            .collect::<Vec<_>>()),
+        Type::Bytes => json!([1, 2, seed % 256]),
+        Type::String => json!(format!("ar://{}", seed)),
+        Type::Vec(ty) => json!([sample(schema, ty, seed), sample(schema, ty, seed + 1)]),
+        Type::Option(_) if seed.is_multiple_of(2) => Value::Null,
+        Type::Option(ty) => sample(schema, ty, seed),
+        Type::Tuple(types) => Value::Array(
+            types
+                .iter()
+                .enumerate()
+                .map(|(i, ty)| sample(schema, ty, seed + i as u64))
+                .collect(),
+        ),
+        Type::Named(name) => match schema.type_def(name).unwrap() {
+            TypeDef::Struct { fields, .. } => Value::Object(
+                fields
+                    .iter()
+                    .map(|field| (field.name.clone(), sample(schema, &field.ty, seed)))
+                    .collect(),
+            ),
+            TypeDef::Enum { variants, .. } => {
+                let variant = &variants[seed as usize % variants.len()];
+                let value = match &variant.fields {
+                    VariantFields::Unit => return json!(variant.name),
+                    VariantFields::Named(fields) => Value::Object(
+                        fields
+                            .iter()
+                            .map(|field| (field.name.clone(), sample(schema, &field.ty, seed)))
+                            .collect(),
+                    ),
+                    VariantFields::Unnamed(types) if types.len() == 1 => {
+                        sample(schema, &types[0], seed)
+                    }
+                    VariantFields::Unnamed(types) => {
+                        sample(schema, &Type::Tuple(types.clone()), seed)
+                    }
+                };
+                json!({ variant.name.clone(): value })
+            }
+        },
+    }
+}
+
+/// Encode a value the way the generated bindings do, by walking the schema
+fn encode(schema: &Schema, ty: &Type, value: &Value, buf: &mut Vec<u8>) {
+    match ty {
+        Type::U8 => buf.push(value.as_u64().unwrap() as u8),
+        Type::U16 => buf.extend_from_slice(&(value.as_u64().unwrap() as u16).to_le_bytes()),
+        Type::U32 => buf.extend_from_slice(&(value.as_u64().unwrap() as u32).to_le_bytes()),
+        Type::U64 => buf.extend_from_slice(&value.as_u64().unwrap().to_le_bytes()),
+        Type::I64 => buf.extend_from_slice(&value.as_i64().unwrap().to_le_bytes()),
+        Type::Bool => buf.push(u8::from(value.as_bool().unwrap())),
+        Type::Pubkey => {
+            let pubkey: Pubkey = value.as_str().unwrap().parse().unwrap();
+            buf.extend_from_slice(pubkey.as_ref());
+        }
+        Type::FixedBytes(_) | Type::Bytes => {
+            for byte in value.as_array().unwrap() {
+                buf.push(byte.as_u64().unwrap() as u8);
+            }
+        }
+        Type::String => {
+            let value = value.as_str().unwrap();
+            buf.push(value.len() as u8);
+            buf.extend_from_slice(value.as_bytes());
+        }
+        Type::Vec(ty) => {
+            let items = value.as_array().unwrap();
+            buf.push(items.len() as u8);
+            for item in items {
+                encode(schema, ty, item, buf);
+            }
+        }
+        Type::Option(ty) => {
+            if !value.is_null() {
+                encode(schema, ty, value, buf);
+            }
+        }
+        Type::Tuple(types) => {
+            for (ty, value) in types.iter().zip(value.as_array().unwrap()) {
+                encode(schema, ty, value, buf);
+            }
+        }
+        Type::Named(name) => match schema.type_def(name).unwrap() {
+            TypeDef::Struct { fields, .. } => {
+                for field in fields {
+                    encode(schema, &field.ty, &value[&field.name], buf);
+                }
+            }
+            TypeDef::Enum { variants, .. } => {
+                let (name, value) = match value {
+                    Value::String(name) => (name, &Value::Null),
+                    Value::Object(object) => object.iter().next().unwrap(),
+                    _ => panic!("enum values are strings or objects"),
+                };
+                let index = variants.iter().position(|v| &v.name == name).unwrap();
+                buf.push(index as u8);
+                match &variants[index].fields {
+                    VariantFields::Unit => {}
+                    VariantFields::Named(fields) => {
+                        for field in fields {
+                            encode(schema, &field.ty, &value[&field.name], buf);
+                        }
+                    }
+                    VariantFields::Unnamed(types) if types.len() == 1 => {
+                        encode(schema, &types[0], value, buf)
+                    }
+                    VariantFields::Unnamed(types) => {
+                        encode(schema, &Type::Tuple(types.clone()), value, buf)
+                    }
+                }
+            }
+        },
+    }
+}
+
+#[test]
+fn test_schema_encodes_like_pack() {
+    let schema = Schema::parse().unwrap();
+    assert_eq!(schema.instructions.len(), 98);
+
+    for (tag, instruction) in schema.instructions.iter().enumerate() {
+        for seed in 0..16 {
+            let mut expected = vec![tag as u8];
+            let value = match &instruction.fields {
+                VariantFields::Named(fields) => {
+                    let mut data = Map::new();
+                    for field in fields {
+                        let value = sample(&schema, &field.ty, seed);
+                        encode(&schema, &field.ty, &value, &mut expected);
+                        data.insert(field.name.clone(), value);
+                    }
+                    json!({ instruction.name.clone(): data })
+                }
+                _ => json!(instruction.name),
+            };
+
+            let unpacked: RNDRInstruction = serde_json::from_value(value).unwrap();
+            assert_eq!(unpacked.pack(), expected, "{}", instruction.name);
+        }
+    }
+}
+
+#[test]
+fn test_generated_bindings_are_current() {
+    let schema = Schema::parse().unwrap();
+    assert!(
+        typescript::render(&schema) == include_str!("../js/src/generated.ts"),
+        "js/src/generated.ts is stale, regenerate it with rndr-codegen"
+    );
+}
+
+#[test]
+fn test_parse_accounts() {
+    let docs = [
+        "Transfer funds.",
+        "",
+        "Accounts expected by this instruction:",
+        "",
+        "  0. `[writable,signer]` Funder SOL account",
+        "  1. `[writable]` Source RNDR token account",
+        "     $authority can transfer $amount",
+        "  2. `[signer]` Source token account authority ($authority)",
+        "",
+        "Optionally, to be paid a bounty:",
+        "",
+        "  3. `[]` Escrow PDA account",
+        "  4. ..4+N `[writable]` Escrow PDA accounts of the other jobs",
+        "",
+        "Funds are credited once.",
+    ]
+    .map(String::from);
+    let account =
+        |name: &str, is_mut, is_signer, is_optional, is_remaining, docs: &[&str]| Account {
+            name: name.to_string(),
+            is_mut,
+            is_signer,
+            is_optional,
+            is_remaining,
+            docs: docs.iter().map(|doc| doc.to_string()).collect(),
+        };
+
+    let (description, accounts) = idl::parse_accounts(&docs).unwrap();
+    assert_eq!(
+        description,
+        ["Transfer funds.", "", "Funds are credited once."]
+    );
+    assert_eq!(
+        accounts,
+        [
+            account(
+                "funderSolAccount",
+                true,
+                true,
+                false,
+                false,
+                &["Funder SOL account"]
+            ),
+            account(
+                "sourceRndrTokenAccount",
+                true,
+                false,
+                false,
+                false,
+                &[
+                    "Source RNDR token account",
+                    "$authority can transfer $amount"
+                ]
+            ),
+            account(
+                "sourceTokenAccountAuthority",
+                false,
+                true,
+                false,
+                false,
+                &["Source token account authority ($authority)"]
+            ),
+            account(
+                "escrowAccount",
+                false,
+                false,
+                true,
+                false,
+                &["Escrow PDA account"]
+            ),
+            account(
+                "escrowAccounts",
+                true,
+                false,
+                true,
+                true,
+                &["Escrow PDA accounts of the other jobs"]
+            ),
+        ]
+    );
+
+    let mut docs = docs.to_vec();
+    docs.swap(11, 12);
+    assert!(idl::parse_accounts(&docs).is_err());
+}
+
+#[test]
+fn test_generated_idl_is_current() {
+    let schema = Schema::parse().unwrap();
+    assert_eq!(schema.errors.len(), 7);
+    assert!(
+        idl::render(&schema).unwrap() == include_str!("../idl/rndr.json"),
+        "idl/rndr.json is stale, regenerate it with rndr-codegen"
+    );
+}
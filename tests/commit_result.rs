@@ -0,0 +1,108 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{
+        error::RNDRError,
+        instruction::commit_result,
+        processor::process_instruction,
+        state::{Job, JobStatus},
+        test_fixtures::*,
+    },
+    solana_program::program_pack::Pack,
+    solana_program_test::*,
+    solana_sdk::{
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        transaction::Transaction,
+    },
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const AMOUNT: u64 = 1_000_000_000;
+    const COMMITMENT: [u8; 32] = [1; 32];
+
+    TestConfig::add(&mut test);
+    let test_mint = TestMint::add(&mut test, 9, 100 * AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, Pubkey::new_unique(), AMOUNT);
+    let node = Keypair::new();
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[commit_result(
+            rndr::id(),
+            COMMITMENT,
+            payer.pubkey(),
+            test_job.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let job = get_job(&mut banks_client, test_job.pubkey).await;
+    assert_eq!(job.status, JobStatus::Committed);
+    assert_eq!(job.node, payer.pubkey());
+    assert_eq!(job.result_commitment, COMMITMENT);
+    assert_eq!(job.amount, AMOUNT);
+
+    // Another node can't take over the job once it's committed to
+    let mut transaction = Transaction::new_with_payer(
+        &[commit_result(
+            rndr::id(),
+            COMMITMENT,
+            node.pubkey(),
+            test_job.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &node], recent_blockhash);
+
+    assert_eq!(
+        program_error(banks_client.process_transaction(transaction).await),
+        Some(RNDRError::JobAlreadyAssigned)
+    );
+}
+
+#[tokio::test]
+async fn test_success_with_legacy_job() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const AMOUNT: u64 = 1_000_000_000;
+    const COMMITMENT: [u8; 32] = [1; 32];
+
+    TestConfig::add(&mut test);
+    let test_mint = TestMint::add(&mut test, 9, 100 * AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_job = TestJob::add_legacy(&mut test, test_escrow.pubkey, Pubkey::new_unique(), AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let account = get_account(&mut banks_client, test_job.pubkey).await;
+    assert_eq!(account.data.len(), Job::LEGACY_LEN);
+
+    let mut transaction = Transaction::new_with_payer(
+        &[commit_result(
+            rndr::id(),
+            COMMITMENT,
+            payer.pubkey(),
+            test_job.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let account = get_account(&mut banks_client, test_job.pubkey).await;
+    assert_eq!(account.data.len(), Job::LEN);
+
+    let job = get_job(&mut banks_client, test_job.pubkey).await;
+    assert_eq!(job.status, JobStatus::Committed);
+    assert_eq!(job.amount, AMOUNT);
+}
@@ -0,0 +1,125 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{instruction::compound_rewards, processor::process_instruction, test_fixtures::*},
+    solana_program_test::*,
+    solana_sdk::{pubkey::Pubkey, signature::Signer, transaction::Transaction},
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+    const EPOCH: u64 = 0;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let node_authority = Pubkey::new_unique();
+    let test_node = TestNode::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_mint.pubkey,
+        node_authority,
+        AMOUNT,
+        true,
+    );
+    let test_reward_pool = TestRewardPool::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_mint.pubkey,
+        EPOCH,
+        AMOUNT,
+        4,
+    );
+    let test_epoch_work = TestEpochWork::add(&mut test, test_reward_pool.pubkey, node_authority, 1);
+
+    let mut context = test.start_with_context().await;
+    let first_normal_slot = context.genesis_config().epoch_schedule.first_normal_slot;
+    context.warp_to_slot(first_normal_slot + 1).unwrap();
+
+    let mut transaction = Transaction::new_with_payer(
+        &[compound_rewards(
+            rndr::id(),
+            test_mint.pubkey,
+            node_authority,
+            EPOCH,
+        )],
+        Some(&context.payer.pubkey()),
+    );
+    transaction.sign(&[&context.payer], context.last_blockhash);
+
+    assert!(context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .is_ok());
+
+    let banks_client = &mut context.banks_client;
+    let reward_pool_balance_after =
+        get_token_balance(banks_client, test_reward_pool.associated_token).await;
+    let node_balance_after = get_token_balance(banks_client, test_node.associated_token).await;
+
+    assert_eq!(reward_pool_balance_after, AMOUNT - AMOUNT / 4);
+    assert_eq!(node_balance_after, AMOUNT + AMOUNT / 4);
+
+    let node = test_node.get(banks_client).await;
+    assert_eq!(node.stake, AMOUNT + AMOUNT / 4);
+
+    let epoch_work = test_epoch_work.get(banks_client).await;
+    assert!(epoch_work.claimed);
+}
+
+#[tokio::test]
+async fn test_auto_compound_disabled() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+    const EPOCH: u64 = 0;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let node_authority = Pubkey::new_unique();
+    TestNode::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_mint.pubkey,
+        node_authority,
+        AMOUNT,
+        false,
+    );
+    let test_reward_pool = TestRewardPool::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_mint.pubkey,
+        EPOCH,
+        AMOUNT,
+        4,
+    );
+    TestEpochWork::add(&mut test, test_reward_pool.pubkey, node_authority, 1);
+
+    let mut context = test.start_with_context().await;
+    let first_normal_slot = context.genesis_config().epoch_schedule.first_normal_slot;
+    context.warp_to_slot(first_normal_slot + 1).unwrap();
+
+    let mut transaction = Transaction::new_with_payer(
+        &[compound_rewards(
+            rndr::id(),
+            test_mint.pubkey,
+            node_authority,
+            EPOCH,
+        )],
+        Some(&context.payer.pubkey()),
+    );
+    transaction.sign(&[&context.payer], context.last_blockhash);
+
+    assert!(context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .is_err());
+}
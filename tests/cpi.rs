@@ -0,0 +1,454 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{
+        cpi,
+        instruction::{accept_job_authority, aggregate_escrow_shard},
+        processor::process_instruction,
+        state::EscrowShard,
+        test_fixtures::*,
+    },
+    solana_program::{
+        account_info::AccountInfo,
+        entrypoint::ProgramResult,
+        instruction::{AccountMeta, Instruction},
+        program_error::ProgramError,
+        system_program, sysvar,
+    },
+    solana_program_test::*,
+    solana_sdk::{pubkey::Pubkey, signature::Signer, transaction::Transaction},
+    spl_associated_token_account::get_associated_token_address,
+    std::convert::TryInto,
+};
+
+/// Program that funds jobs with tokens its vault PDA is delegated, then hands them over to
+/// another authority
+fn process_integrator(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    input: &[u8],
+) -> ProgramResult {
+    let (vault, bump_seed) = Pubkey::find_program_address(&[b"vault"], program_id);
+    let vault_seeds: &[&[u8]] = &[b"vault", &[bump_seed]];
+    if accounts[3].key != &vault {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let job_id = u64::from_le_bytes(input[..8].try_into().unwrap());
+    let amount = u64::from_le_bytes(input[8..16].try_into().unwrap());
+    let new_authority = Pubkey::new_from_array(input[16..48].try_into().unwrap());
+
+    cpi::FundJob {
+        rndr_program: accounts[0].clone(),
+        funder: accounts[1].clone(),
+        source_token: accounts[2].clone(),
+        authority: accounts[3].clone(),
+        escrow: accounts[4].clone(),
+        escrow_token: accounts[5].clone(),
+        job: accounts[6].clone(),
+        escrow_shard: accounts[7].clone(),
+        system_program: accounts[8].clone(),
+        token_program: accounts[9].clone(),
+        remaining_accounts: vec![],
+    }
+    .invoke_signed(amount, job_id, None, &[vault_seeds])?;
+
+    cpi::TransferJobAuthority {
+        rndr_program: accounts[0].clone(),
+        authority: accounts[3].clone(),
+        escrow: accounts[4].clone(),
+        job: accounts[6].clone(),
+        system_program: accounts[8].clone(),
+    }
+    .invoke_signed(new_authority, &[vault_seeds])
+}
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+    let integrator = Pubkey::new_unique();
+    test.add_program("integrator", integrator, processor!(process_integrator));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+    const JOB_ID: u64 = 1;
+
+    let (vault, _bump_seed) = Pubkey::find_program_address(&[b"vault"], &integrator);
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let test_source_token =
+        TestToken::add_delegated(&mut test, test_mint.pubkey, AMOUNT, vault, AMOUNT);
+    let (job_pubkey, _bump_seed) = find_job_address(test_escrow.pubkey, vault, JOB_ID);
+    let (escrow_shard, _bump_seed) = find_escrow_shard_address(test_escrow.pubkey, job_pubkey);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut data = JOB_ID.to_le_bytes().to_vec();
+    data.extend_from_slice(&AMOUNT.to_le_bytes());
+    data.extend_from_slice(payer.pubkey().as_ref());
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            Instruction {
+                program_id: integrator,
+                accounts: vec![
+                    AccountMeta::new_readonly(rndr::id(), false),
+                    AccountMeta::new(payer.pubkey(), true),
+                    AccountMeta::new(test_source_token.pubkey, false),
+                    AccountMeta::new(vault, false),
+                    AccountMeta::new_readonly(test_escrow.pubkey, false),
+                    AccountMeta::new(
+                        get_associated_token_address(&test_escrow.pubkey, &test_mint.pubkey),
+                        false,
+                    ),
+                    AccountMeta::new(job_pubkey, false),
+                    AccountMeta::new(escrow_shard, false),
+                    AccountMeta::new_readonly(system_program::id(), false),
+                    AccountMeta::new_readonly(spl_token::id(), false),
+                ],
+                data,
+            },
+            accept_job_authority(rndr::id(), test_mint.pubkey, payer.pubkey(), job_pubkey),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let job = get_job(&mut banks_client, job_pubkey).await;
+    assert_eq!(job.amount, AMOUNT);
+    assert_eq!(job.authority, payer.pubkey());
+    assert_eq!(job.original_authority, vault);
+
+    let source_token_balance = get_token_balance(&mut banks_client, test_source_token.pubkey).await;
+    assert_eq!(source_token_balance, ZERO);
+}
+
+/// Program whose vault PDA authorizes jobs funded with vouchers, which a relayer pays for
+fn process_voucher_vault(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    input: &[u8],
+) -> ProgramResult {
+    let (vault, bump_seed) = Pubkey::find_program_address(&[b"vault"], program_id);
+    if accounts[3].key != &vault {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let job_id = u64::from_le_bytes(input[..8].try_into().unwrap());
+    let amount = u64::from_le_bytes(input[8..16].try_into().unwrap());
+    let nonce = u64::from_le_bytes(input[16..24].try_into().unwrap());
+
+    cpi::FundJobWithVoucher {
+        rndr_program: accounts[0].clone(),
+        relayer: accounts[1].clone(),
+        source_token: accounts[2].clone(),
+        authority: accounts[3].clone(),
+        escrow: accounts[4].clone(),
+        escrow_token: accounts[5].clone(),
+        job: accounts[6].clone(),
+        escrow_shard: accounts[7].clone(),
+        voucher_nonce: accounts[8].clone(),
+        instructions_sysvar: accounts[9].clone(),
+        system_program: accounts[10].clone(),
+        token_program: accounts[11].clone(),
+    }
+    .invoke_signed(
+        amount,
+        job_id,
+        nonce,
+        i64::MAX,
+        &[&[b"vault", &[bump_seed]]],
+    )
+}
+
+#[tokio::test]
+async fn test_program_derived_authority() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+    let voucher_vault = Pubkey::new_unique();
+    test.add_program(
+        "voucher_vault",
+        voucher_vault,
+        processor!(process_voucher_vault),
+    );
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+    const JOB_ID: u64 = 1;
+
+    let (vault, _bump_seed) = Pubkey::find_program_address(&[b"vault"], &voucher_vault);
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let source_token = add_delegated_token_account(
+        &mut test,
+        test_mint.pubkey,
+        vault,
+        AMOUNT,
+        test_escrow.pubkey,
+    );
+    let (job_pubkey, _bump_seed) = find_job_address(test_escrow.pubkey, vault, JOB_ID);
+    let (escrow_shard, _bump_seed) = find_escrow_shard_address(test_escrow.pubkey, job_pubkey);
+    let (voucher_nonce, _bump_seed) = find_voucher_nonce_address(test_escrow.pubkey, vault);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // The vault signs with its seeds in place of an ed25519 signature of the voucher
+    let mut data = JOB_ID.to_le_bytes().to_vec();
+    data.extend_from_slice(&AMOUNT.to_le_bytes());
+    data.extend_from_slice(&ZERO.to_le_bytes());
+    let mut transaction = Transaction::new_with_payer(
+        &[Instruction {
+            program_id: voucher_vault,
+            accounts: vec![
+                AccountMeta::new_readonly(rndr::id(), false),
+                AccountMeta::new(payer.pubkey(), true),
+                AccountMeta::new(source_token, false),
+                AccountMeta::new_readonly(vault, false),
+                AccountMeta::new_readonly(test_escrow.pubkey, false),
+                AccountMeta::new(test_escrow.associated_token, false),
+                AccountMeta::new(job_pubkey, false),
+                AccountMeta::new(escrow_shard, false),
+                AccountMeta::new(voucher_nonce, false),
+                AccountMeta::new_readonly(sysvar::instructions::id(), false),
+                AccountMeta::new_readonly(system_program::id(), false),
+                AccountMeta::new_readonly(spl_token::id(), false),
+            ],
+            data,
+        }],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let job = get_job(&mut banks_client, job_pubkey).await;
+    assert_eq!(job.authority, vault);
+    assert_eq!(job.amount, AMOUNT);
+
+    let voucher_nonce = get_voucher_nonce(&mut banks_client, voucher_nonce).await;
+    assert_eq!(voucher_nonce.nonce, 1);
+
+    let escrow_balance = get_token_balance(&mut banks_client, test_escrow.associated_token).await;
+    assert_eq!(escrow_balance, AMOUNT);
+}
+
+/// Program that funds and cancels the jobs of its vault PDA with the accounts it's passed
+fn process_job_vault(program_id: &Pubkey, accounts: &[AccountInfo], input: &[u8]) -> ProgramResult {
+    let (_vault, bump_seed) = Pubkey::find_program_address(&[b"vault"], program_id);
+    let vault_seeds: &[&[u8]] = &[b"vault", &[bump_seed]];
+
+    match input[0] {
+        0 => {
+            let job_id = u64::from_le_bytes(input[1..9].try_into().unwrap());
+            let amount = u64::from_le_bytes(input[9..17].try_into().unwrap());
+            cpi::FundJob::from_accounts(accounts)?.invoke_signed(
+                amount,
+                job_id,
+                None,
+                &[vault_seeds],
+            )
+        }
+        _ => cpi::CancelJob::from_accounts(accounts)?.invoke_signed(&[vault_seeds]),
+    }
+}
+
+#[tokio::test]
+async fn test_from_accounts() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+    let job_vault = Pubkey::new_unique();
+    test.add_program("job_vault", job_vault, processor!(process_job_vault));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+    const JOB_ID: u64 = 1;
+
+    let (vault, _bump_seed) = Pubkey::find_program_address(&[b"vault"], &job_vault);
+    TestConfig::add(&mut test);
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let source_token =
+        add_delegated_token_account(&mut test, test_mint.pubkey, vault, AMOUNT, vault);
+    let (job_pubkey, _bump_seed) = find_job_address(test_escrow.pubkey, vault, JOB_ID);
+    let (escrow_shard, _bump_seed) = find_escrow_shard_address(test_escrow.pubkey, job_pubkey);
+    let (config, _bump_seed) = find_config_address();
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut fund_data = vec![0];
+    fund_data.extend_from_slice(&JOB_ID.to_le_bytes());
+    fund_data.extend_from_slice(&AMOUNT.to_le_bytes());
+    let fund_accounts = |rndr_program| {
+        vec![
+            AccountMeta::new_readonly(rndr_program, false),
+            AccountMeta::new(payer.pubkey(), true),
+            AccountMeta::new(source_token, false),
+            AccountMeta::new(vault, false),
+            AccountMeta::new(test_escrow.pubkey, false),
+            AccountMeta::new(test_escrow.associated_token, false),
+            AccountMeta::new(job_pubkey, false),
+            AccountMeta::new(escrow_shard, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ]
+    };
+
+    // The accounts must start with the RNDR program
+    let mut transaction = Transaction::new_with_payer(
+        &[Instruction {
+            program_id: job_vault,
+            accounts: fund_accounts(spl_token::id()),
+            data: fund_data.clone(),
+        }],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    // A job funded by the vault can be cancelled by it, refunding the tokens to its token account
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            Instruction {
+                program_id: job_vault,
+                accounts: fund_accounts(rndr::id()),
+                data: fund_data,
+            },
+            aggregate_escrow_shard(
+                rndr::id(),
+                test_escrow.pubkey,
+                EscrowShard::index_for_job(&job_pubkey),
+            ),
+            Instruction {
+                program_id: job_vault,
+                accounts: vec![
+                    AccountMeta::new_readonly(rndr::id(), false),
+                    AccountMeta::new(vault, false),
+                    AccountMeta::new(test_escrow.pubkey, false),
+                    AccountMeta::new(test_escrow.associated_token, false),
+                    AccountMeta::new(job_pubkey, false),
+                    AccountMeta::new(source_token, false),
+                    AccountMeta::new_readonly(config, false),
+                    AccountMeta::new_readonly(system_program::id(), false),
+                    AccountMeta::new_readonly(spl_token::id(), false),
+                ],
+                data: vec![1],
+            },
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let job = get_job(&mut banks_client, job_pubkey).await;
+    assert_eq!(job.authority, vault);
+    assert_eq!(job.amount, ZERO);
+
+    let source_token_balance = get_token_balance(&mut banks_client, source_token).await;
+    assert_eq!(source_token_balance, AMOUNT);
+}
+
+/// Program that only accepts jobs of an escrow funded with at least an amount, reading them from
+/// the summaries instead of decoding the accounts
+fn process_job_checker(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    input: &[u8],
+) -> ProgramResult {
+    let min_amount = u64::from_le_bytes(input[..8].try_into().unwrap());
+
+    let escrow_summary = cpi::GetEscrowSummary::from_accounts(&accounts[..2])?.invoke()?;
+    let job_summary = cpi::GetJobSummary::from_accounts(accounts)?.invoke()?;
+    if job_summary.amount < min_amount || escrow_summary.amount < job_summary.amount {
+        return Err(ProgramError::InsufficientFunds);
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_summaries() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+    let job_checker = Pubkey::new_unique();
+    test.add_program("job_checker", job_checker, processor!(process_job_checker));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let authority = Pubkey::new_unique();
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, authority, AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let check = |min_amount: u64| Instruction {
+        program_id: job_checker,
+        accounts: vec![
+            AccountMeta::new_readonly(rndr::id(), false),
+            AccountMeta::new_readonly(test_escrow.pubkey, false),
+            AccountMeta::new_readonly(test_job.pubkey, false),
+        ],
+        data: min_amount.to_le_bytes().to_vec(),
+    };
+
+    let mut transaction = Transaction::new_with_payer(&[check(AMOUNT)], Some(&payer.pubkey()));
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let mut transaction = Transaction::new_with_payer(&[check(AMOUNT + 1)], Some(&payer.pubkey()));
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+/// Program that lends against the tokens in escrow for a job, up to the escrowed amount
+fn process_lender(_program_id: &Pubkey, accounts: &[AccountInfo], input: &[u8]) -> ProgramResult {
+    let loan_amount = u64::from_le_bytes(input[..8].try_into().unwrap());
+
+    let collateral = cpi::GetEscrowedAmount::from_accounts(accounts)?.invoke()?;
+    if loan_amount > collateral {
+        return Err(ProgramError::InsufficientFunds);
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_escrowed_amount() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+    let lender = Pubkey::new_unique();
+    test.add_program("lender", lender, processor!(process_lender));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let authority = Pubkey::new_unique();
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, authority, AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let borrow = |loan_amount: u64| Instruction {
+        program_id: lender,
+        accounts: vec![
+            AccountMeta::new_readonly(rndr::id(), false),
+            AccountMeta::new_readonly(test_escrow.pubkey, false),
+            AccountMeta::new_readonly(test_job.pubkey, false),
+        ],
+        data: loan_amount.to_le_bytes().to_vec(),
+    };
+
+    let mut transaction = Transaction::new_with_payer(&[borrow(AMOUNT)], Some(&payer.pubkey()));
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let mut transaction = Transaction::new_with_payer(&[borrow(AMOUNT + 1)], Some(&payer.pubkey()));
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
@@ -0,0 +1,131 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{
+        instruction::create_job_bundle,
+        processor::process_instruction,
+        state::{AccountType, MAX_BUNDLE_JOBS},
+        test_fixtures::*,
+    },
+    solana_program_test::*,
+    solana_sdk::{signature::Signer, transaction::Transaction},
+};
+
+const ZERO: u64 = 0;
+const DECIMALS: u64 = 1_000_000_000;
+const BUNDLE_ID: u64 = 1;
+const FIRST_JOB_ID: u64 = 10;
+const JOB_COUNT: u8 = 4;
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[create_job_bundle(
+            rndr::id(),
+            test_mint.pubkey,
+            payer.pubkey(),
+            BUNDLE_ID,
+            FIRST_JOB_ID,
+            JOB_COUNT,
+            [1; 32],
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let (job_bundle_pubkey, _bump_seed) =
+        find_job_bundle_address(test_escrow.pubkey, payer.pubkey(), BUNDLE_ID);
+    let job_bundle = get_job_bundle(&mut banks_client, job_bundle_pubkey).await;
+
+    assert_eq!(job_bundle.account_type, AccountType::JobBundleV1);
+    assert_eq!(job_bundle.escrow, test_escrow.pubkey);
+    assert_eq!(job_bundle.authority, payer.pubkey());
+    assert_eq!(job_bundle.id, BUNDLE_ID);
+    assert_eq!(job_bundle.metadata_hash, [1; 32]);
+    assert_eq!(job_bundle.first_job_id, FIRST_JOB_ID);
+    assert_eq!(job_bundle.job_count, JOB_COUNT);
+    assert_eq!(job_bundle.amount, ZERO);
+    assert!(!job_bundle.is_complete());
+
+    // A bundle with the same identifier can't be created again
+    let recent_blockhash = banks_client
+        .get_new_latest_blockhash(&recent_blockhash)
+        .await
+        .unwrap();
+    let mut transaction = Transaction::new_with_payer(
+        &[create_job_bundle(
+            rndr::id(),
+            test_mint.pubkey,
+            payer.pubkey(),
+            BUNDLE_ID,
+            FIRST_JOB_ID,
+            JOB_COUNT,
+            [1; 32],
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+#[tokio::test]
+async fn test_too_many_jobs() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[create_job_bundle(
+            rndr::id(),
+            test_mint.pubkey,
+            payer.pubkey(),
+            BUNDLE_ID,
+            FIRST_JOB_ID,
+            MAX_BUNDLE_JOBS + 1,
+            [1; 32],
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+#[tokio::test]
+async fn test_overflowing_job_ids() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[create_job_bundle(
+            rndr::id(),
+            test_mint.pubkey,
+            payer.pubkey(),
+            BUNDLE_ID,
+            u64::MAX,
+            JOB_COUNT,
+            [1; 32],
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
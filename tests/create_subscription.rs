@@ -0,0 +1,141 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{
+        instruction::create_subscription, processor::process_instruction, state::AccountType,
+        test_fixtures::*,
+    },
+    solana_program::pubkey::Pubkey,
+    solana_program_test::*,
+    solana_sdk::{
+        signature::{Keypair, Signer},
+        transaction::Transaction,
+    },
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const AMOUNT: u64 = 1_000_000_000;
+    const PERIOD: u64 = 1_000;
+    const JOB_ID: u64 = 1;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let source_token = Pubkey::new_unique();
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[create_subscription(
+            rndr::id(),
+            test_mint.pubkey,
+            payer.pubkey(),
+            source_token,
+            JOB_ID,
+            AMOUNT,
+            PERIOD,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let (subscription_pubkey, _bump_seed) =
+        find_subscription_address(test_escrow.pubkey, payer.pubkey(), JOB_ID);
+    let subscription = get_subscription(&mut banks_client, subscription_pubkey).await;
+
+    assert_eq!(subscription.account_type, AccountType::SubscriptionV1);
+    assert_eq!(subscription.escrow, test_escrow.pubkey);
+    assert_eq!(subscription.authority, payer.pubkey());
+    assert_eq!(subscription.job_id, JOB_ID);
+    assert_eq!(subscription.source_token, source_token);
+    assert_eq!(subscription.amount, AMOUNT);
+    assert_eq!(subscription.period, PERIOD);
+}
+
+#[tokio::test]
+async fn test_success_replace() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const AMOUNT: u64 = 1_000_000_000;
+    const PERIOD: u64 = 1_000;
+    const NEXT_SLOT: u64 = 5_000;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let authority = Keypair::new();
+    let test_subscription = TestSubscription::add(
+        &mut test,
+        test_escrow.pubkey,
+        authority.pubkey(),
+        Pubkey::new_unique(),
+        AMOUNT,
+        PERIOD,
+        NEXT_SLOT,
+    );
+    let source_token = Pubkey::new_unique();
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[create_subscription(
+            rndr::id(),
+            test_mint.pubkey,
+            authority.pubkey(),
+            source_token,
+            0,
+            2 * AMOUNT,
+            2 * PERIOD,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    // Replacing the terms keeps the slot the next period is due at
+    let subscription = test_subscription.get(&mut banks_client).await;
+    assert_eq!(subscription.source_token, source_token);
+    assert_eq!(subscription.amount, 2 * AMOUNT);
+    assert_eq!(subscription.period, 2 * PERIOD);
+    assert_eq!(subscription.next_slot, NEXT_SLOT);
+}
+
+#[tokio::test]
+async fn test_zero_period() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const AMOUNT: u64 = 1_000_000_000;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[create_subscription(
+            rndr::id(),
+            test_mint.pubkey,
+            payer.pubkey(),
+            Pubkey::new_unique(),
+            0,
+            AMOUNT,
+            0,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let (subscription_pubkey, _bump_seed) =
+        find_subscription_address(test_escrow.pubkey, payer.pubkey(), 0);
+    assert!(banks_client
+        .get_account(subscription_pubkey)
+        .await
+        .unwrap()
+        .is_none());
+}
@@ -0,0 +1,90 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{instruction::deposit_stake_rewards, processor::process_instruction, test_fixtures::*},
+    solana_program_test::*,
+    solana_sdk::{signature::Signer, transaction::Transaction},
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_source_token = TestToken::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let test_stake_pool = TestStakePool::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_mint.pubkey,
+        test_mint.decimals,
+        AMOUNT,
+        AMOUNT,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[deposit_stake_rewards(
+            rndr::id(),
+            AMOUNT,
+            test_mint.pubkey,
+            test_source_token.pubkey,
+            test_source_token.owner.pubkey(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_source_token.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let stake_pool_balance_after =
+        get_token_balance(&mut banks_client, test_stake_pool.associated_token).await;
+    assert_eq!(stake_pool_balance_after, 2 * AMOUNT);
+
+    let stake_pool = test_stake_pool.get(&mut banks_client).await;
+    assert_eq!(stake_pool.total_staked, 2 * AMOUNT);
+    assert_eq!(stake_pool.receipt_supply, AMOUNT);
+    assert_eq!(stake_pool.stake_amount(AMOUNT), Some(2 * AMOUNT));
+}
+
+#[tokio::test]
+async fn test_no_receipts() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_source_token = TestToken::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    TestStakePool::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_mint.pubkey,
+        test_mint.decimals,
+        ZERO,
+        ZERO,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[deposit_stake_rewards(
+            rndr::id(),
+            AMOUNT,
+            test_mint.pubkey,
+            test_source_token.pubkey,
+            test_source_token.owner.pubkey(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_source_token.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
@@ -0,0 +1,66 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{instruction::disburse_batch, processor::process_instruction, test_fixtures::*},
+    solana_program_test::*,
+    solana_sdk::{signature::Signer, transaction::Transaction},
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 3 * DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_destination_tokens = [
+        TestToken::add(&mut test, test_mint.pubkey, ZERO),
+        TestToken::add(&mut test, test_mint.pubkey, ZERO),
+    ];
+    let test_job = TestJob::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_destination_tokens[0].owner.pubkey(),
+        AMOUNT,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[disburse_batch(
+            rndr::id(),
+            test_mint.pubkey,
+            test_job.pubkey,
+            test_escrow.owner.pubkey(),
+            &[
+                (test_destination_tokens[0].pubkey, DECIMALS),
+                (test_destination_tokens[1].pubkey, 2 * DECIMALS),
+            ],
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let escrow_balance_after =
+        get_token_balance(&mut banks_client, test_escrow.associated_token).await;
+    assert_eq!(escrow_balance_after, ZERO);
+
+    let destination_token_balance_after =
+        get_token_balance(&mut banks_client, test_destination_tokens[0].pubkey).await;
+    assert_eq!(destination_token_balance_after, DECIMALS);
+
+    let destination_token_balance_after =
+        get_token_balance(&mut banks_client, test_destination_tokens[1].pubkey).await;
+    assert_eq!(destination_token_balance_after, 2 * DECIMALS);
+
+    let escrow = test_escrow.get(&mut banks_client).await;
+    assert_eq!(escrow.amount, ZERO);
+
+    let job = test_job.get(&mut banks_client).await;
+    assert_eq!(job.amount, ZERO);
+}
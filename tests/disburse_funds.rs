@@ -1,10 +1,15 @@
 #![cfg(feature = "test-bpf")]
 
-mod helpers;
-
 use {
-    helpers::*,
-    rndr::{instruction::disburse_funds, processor::process_instruction},
+    rndr::{
+        error::RNDRError,
+        instruction::{
+            disburse_funds, disburse_funds_as_delegate, fund_job, set_job_exchange_rate, with_stats,
+        },
+        oracle::OraclePrice,
+        processor::process_instruction,
+        test_fixtures::*,
+    },
     solana_program_test::*,
     solana_sdk::{pubkey::Pubkey, signature::Signer, transaction::Transaction},
 };
@@ -72,3 +77,494 @@ async fn test_success() {
     let job = get_job(&mut banks_client, test_job.pubkey).await;
     assert_eq!(job.amount, ZERO);
 }
+
+#[tokio::test]
+async fn test_success_with_legacy_escrow() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add_legacy(&mut test, test_mint.pubkey, AMOUNT);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let test_job = TestJob::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_destination_token.owner.pubkey(),
+        AMOUNT,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[disburse_funds(
+            rndr::id(),
+            AMOUNT,
+            test_mint.pubkey,
+            test_destination_token.pubkey,
+            test_job.pubkey,
+            test_escrow.owner.pubkey(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let destination_token_balance =
+        get_token_balance(&mut banks_client, test_destination_token.pubkey).await;
+    assert_eq!(destination_token_balance, AMOUNT);
+
+    let escrow = get_escrow(&mut banks_client, test_escrow.pubkey).await;
+    assert_eq!(escrow.amount, ZERO);
+}
+
+#[tokio::test]
+async fn test_wrong_token_mint_with_legacy_escrow() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let other_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add_legacy(&mut test, test_mint.pubkey, AMOUNT);
+    let other_escrow_token = TestToken::add(&mut test, other_mint.pubkey, AMOUNT);
+    let test_destination_token = TestToken::add(&mut test, other_mint.pubkey, ZERO);
+    let test_job = TestJob::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_destination_token.owner.pubkey(),
+        AMOUNT,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // A token account of another mint can't stand in for the legacy escrow's token account
+    let mut instruction = disburse_funds(
+        rndr::id(),
+        AMOUNT,
+        test_mint.pubkey,
+        test_destination_token.pubkey,
+        test_job.pubkey,
+        test_escrow.owner.pubkey(),
+    );
+    instruction.accounts[2].pubkey = other_escrow_token.pubkey;
+    let mut transaction = Transaction::new_with_payer(&[instruction], Some(&payer.pubkey()));
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+#[tokio::test]
+async fn test_success_at_exchange_rate() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+
+    TestConfig::add(&mut test);
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    // 0.2 RNDR base units per base unit of a token of 9 decimals
+    let test_price_feed = TestPriceFeed::add(
+        &mut test,
+        OraclePrice {
+            price: 2,
+            confidence: 0,
+            exponent: 0,
+            publish_slot: 0,
+        },
+    );
+    let test_escrow = TestEscrow::add_with_price_feed(
+        &mut test,
+        test_mint.pubkey,
+        AMOUNT,
+        test_price_feed.pubkey,
+    );
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let authority = test_destination_token.owner.pubkey();
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, authority, AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // A job with an exchange rate is disbursed in its own token, with its value in RNDR logged
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            set_job_exchange_rate(
+                rndr::id(),
+                test_mint.pubkey,
+                test_price_feed.pubkey,
+                test_escrow.owner.pubkey(),
+                authority,
+                0,
+            ),
+            disburse_funds(
+                rndr::id(),
+                AMOUNT,
+                test_mint.pubkey,
+                test_destination_token.pubkey,
+                test_job.pubkey,
+                test_escrow.owner.pubkey(),
+            ),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_destination_token.pubkey).await,
+        AMOUNT
+    );
+
+    let job = get_job(&mut banks_client, test_job.pubkey).await;
+    assert_eq!(job.amount, ZERO);
+}
+
+#[tokio::test]
+async fn test_success_funded_through_escrow_shard() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+    const JOB_ID: u64 = 1;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_source_token = TestToken::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let authority = test_source_token.owner.pubkey();
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[fund_job(
+            rndr::id(),
+            AMOUNT,
+            JOB_ID,
+            test_mint.pubkey,
+            payer.pubkey(),
+            test_source_token.pubkey,
+            authority,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_source_token.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let (job_pubkey, _bump_seed) = find_job_address(test_escrow.pubkey, authority, JOB_ID);
+    let (escrow_shard_pubkey, _bump_seed) =
+        find_escrow_shard_address(test_escrow.pubkey, job_pubkey);
+
+    let escrow = get_escrow(&mut banks_client, test_escrow.pubkey).await;
+    assert_eq!(escrow.amount, ZERO);
+
+    let escrow_shard = get_escrow_shard(&mut banks_client, escrow_shard_pubkey).await;
+    assert_eq!(escrow_shard.amount, AMOUNT);
+
+    // The Job is disbursed without aggregating its EscrowShard first
+    let mut transaction = Transaction::new_with_payer(
+        &[disburse_funds(
+            rndr::id(),
+            AMOUNT,
+            test_mint.pubkey,
+            test_destination_token.pubkey,
+            job_pubkey,
+            test_escrow.owner.pubkey(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let escrow_balance_after =
+        get_token_balance(&mut banks_client, test_escrow.associated_token).await;
+    let destination_token_balance_after =
+        get_token_balance(&mut banks_client, test_destination_token.pubkey).await;
+
+    assert_eq!(escrow_balance_after, ZERO);
+    assert_eq!(destination_token_balance_after, AMOUNT);
+
+    let escrow = get_escrow(&mut banks_client, test_escrow.pubkey).await;
+    assert_eq!(escrow.amount, ZERO);
+
+    let escrow_shard = get_escrow_shard(&mut banks_client, escrow_shard_pubkey).await;
+    assert_eq!(escrow_shard.amount, ZERO);
+
+    let job = get_job(&mut banks_client, job_pubkey).await;
+    assert_eq!(job.amount, ZERO);
+}
+
+#[tokio::test]
+async fn test_requires_approval() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const AMOUNT: u64 = 1_000_000_000;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * AMOUNT);
+    let test_escrow =
+        TestEscrow::add_with_approver(&mut test, test_mint.pubkey, AMOUNT, Pubkey::new_unique());
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, 0);
+    let test_job = TestJob::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_destination_token.owner.pubkey(),
+        AMOUNT,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // Escrows with an approver only disburse funds through approved proposals
+    let mut transaction = Transaction::new_with_payer(
+        &[disburse_funds(
+            rndr::id(),
+            AMOUNT,
+            test_mint.pubkey,
+            test_destination_token.pubkey,
+            test_job.pubkey,
+            test_escrow.owner.pubkey(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert_eq!(
+        program_error(banks_client.process_transaction(transaction).await),
+        Some(RNDRError::ApprovalRequired)
+    );
+
+    let job = get_job(&mut banks_client, test_job.pubkey).await;
+    assert_eq!(job.amount, AMOUNT);
+}
+
+#[tokio::test]
+async fn test_destination_is_escrow_token_account() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const AMOUNT: u64 = 1_000_000_000;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, Pubkey::new_unique(), AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // Disbursing into the escrow token account would debit the job without moving any tokens
+    let mut transaction = Transaction::new_with_payer(
+        &[disburse_funds(
+            rndr::id(),
+            AMOUNT,
+            test_mint.pubkey,
+            test_escrow.associated_token,
+            test_job.pubkey,
+            test_escrow.owner.pubkey(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let escrow = get_escrow(&mut banks_client, test_escrow.pubkey).await;
+    assert_eq!(escrow.amount, AMOUNT);
+
+    let job = get_job(&mut banks_client, test_job.pubkey).await;
+    assert_eq!(job.amount, AMOUNT);
+}
+
+#[tokio::test]
+async fn test_success_with_stats() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let test_job = TestJob::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_destination_token.owner.pubkey(),
+        AMOUNT,
+    );
+    let test_stats = TestStats::add(&mut test, test_escrow.pubkey);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[with_stats(
+            disburse_funds(
+                rndr::id(),
+                AMOUNT,
+                test_mint.pubkey,
+                test_destination_token.pubkey,
+                test_job.pubkey,
+                test_escrow.owner.pubkey(),
+            ),
+            test_escrow.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let stats = test_stats.get(&mut banks_client).await;
+    assert_eq!(stats.total_disbursed, u128::from(AMOUNT));
+    assert_eq!(stats.epoch_disbursed, AMOUNT);
+    assert_eq!(stats.total_escrowed, 0);
+}
+
+#[tokio::test]
+async fn test_success_as_delegate() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+    const LIMIT: u64 = 3 * DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let test_job = TestJob::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_destination_token.owner.pubkey(),
+        AMOUNT,
+    );
+    let test_delegate_allowance =
+        TestDelegateAllowance::add(&mut test, test_escrow.pubkey, LIMIT, u64::MAX);
+    let delegate = &test_delegate_allowance.delegate;
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[disburse_funds_as_delegate(
+            rndr::id(),
+            AMOUNT,
+            test_mint.pubkey,
+            test_destination_token.pubkey,
+            test_job.pubkey,
+            delegate.pubkey(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, delegate], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let destination_token_balance_after =
+        get_token_balance(&mut banks_client, test_destination_token.pubkey).await;
+    assert_eq!(destination_token_balance_after, AMOUNT);
+
+    let job = get_job(&mut banks_client, test_job.pubkey).await;
+    assert_eq!(job.amount, ZERO);
+
+    let delegate_allowance = test_delegate_allowance.get(&mut banks_client).await;
+    assert_eq!(delegate_allowance.remaining, LIMIT - AMOUNT);
+}
+
+#[tokio::test]
+async fn test_exceeds_delegate_allowance() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let test_job = TestJob::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_destination_token.owner.pubkey(),
+        AMOUNT,
+    );
+    let test_delegate_allowance =
+        TestDelegateAllowance::add(&mut test, test_escrow.pubkey, AMOUNT - 1, u64::MAX);
+    let delegate = &test_delegate_allowance.delegate;
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[disburse_funds_as_delegate(
+            rndr::id(),
+            AMOUNT,
+            test_mint.pubkey,
+            test_destination_token.pubkey,
+            test_job.pubkey,
+            delegate.pubkey(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, delegate], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let escrow_balance_after =
+        get_token_balance(&mut banks_client, test_escrow.associated_token).await;
+    assert_eq!(escrow_balance_after, AMOUNT);
+
+    let delegate_allowance = test_delegate_allowance.get(&mut banks_client).await;
+    assert_eq!(delegate_allowance.remaining, AMOUNT - 1);
+}
+
+#[tokio::test]
+async fn test_expired_delegate_allowance() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let test_job = TestJob::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_destination_token.owner.pubkey(),
+        AMOUNT,
+    );
+    let test_delegate_allowance =
+        TestDelegateAllowance::add(&mut test, test_escrow.pubkey, AMOUNT, 0);
+    let delegate = &test_delegate_allowance.delegate;
+
+    let mut context = test.start_with_context().await;
+    context.warp_to_slot(2).unwrap();
+
+    let mut transaction = Transaction::new_with_payer(
+        &[disburse_funds_as_delegate(
+            rndr::id(),
+            AMOUNT,
+            test_mint.pubkey,
+            test_destination_token.pubkey,
+            test_job.pubkey,
+            delegate.pubkey(),
+        )],
+        Some(&context.payer.pubkey()),
+    );
+    transaction.sign(&[&context.payer, delegate], context.last_blockhash);
+
+    assert!(context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .is_err());
+
+    let escrow_balance_after =
+        get_token_balance(&mut context.banks_client, test_escrow.associated_token).await;
+    assert_eq!(escrow_balance_after, AMOUNT);
+}
@@ -0,0 +1,61 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{
+        instruction::disburse_isolated_job_funds, processor::process_instruction, test_fixtures::*,
+    },
+    solana_program_test::*,
+    solana_sdk::{signature::Signer, transaction::Transaction},
+    spl_associated_token_account::get_associated_token_address,
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let test_job = TestJob::add_isolated(
+        &mut test,
+        test_escrow.pubkey,
+        test_mint.pubkey,
+        test_destination_token.owner.pubkey(),
+        AMOUNT,
+    );
+    let job_associated_token = get_associated_token_address(&test_job.pubkey, &test_mint.pubkey);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let job_balance_before = get_token_balance(&mut banks_client, job_associated_token).await;
+    assert_eq!(job_balance_before, AMOUNT);
+
+    let mut transaction = Transaction::new_with_payer(
+        &[disburse_isolated_job_funds(
+            rndr::id(),
+            AMOUNT,
+            test_mint.pubkey,
+            test_destination_token.pubkey,
+            test_job.pubkey,
+            test_escrow.owner.pubkey(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let job_balance_after = get_token_balance(&mut banks_client, job_associated_token).await;
+    let destination_token_balance_after =
+        get_token_balance(&mut banks_client, test_destination_token.pubkey).await;
+
+    assert_eq!(job_balance_after, ZERO);
+    assert_eq!(destination_token_balance_after, AMOUNT);
+
+    let job = test_job.get(&mut banks_client).await;
+    assert_eq!(job.amount, ZERO);
+}
@@ -0,0 +1,119 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{
+        instruction::emit_rewards, math::Rate, processor::process_instruction, test_fixtures::*,
+    },
+    solana_program_test::*,
+    solana_sdk::{signature::Signer, transaction::Transaction},
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+    const EPOCH: u64 = 0;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let test_emission_schedule = TestEmissionSchedule::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_mint.pubkey,
+        EPOCH,
+        AMOUNT,
+        Rate::new(100),
+        10 * AMOUNT,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[emit_rewards(
+            rndr::id(),
+            test_mint.pubkey,
+            payer.pubkey(),
+            EPOCH,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let (reward_pool_pubkey, _bump_seed) = find_reward_pool_address(test_escrow.pubkey, EPOCH);
+    let reward_pool_associated_token = spl_associated_token_account::get_associated_token_address(
+        &reward_pool_pubkey,
+        &test_mint.pubkey,
+    );
+
+    let emission_schedule_balance_after =
+        get_token_balance(&mut banks_client, test_emission_schedule.associated_token).await;
+    let reward_pool_balance_after =
+        get_token_balance(&mut banks_client, reward_pool_associated_token).await;
+
+    assert_eq!(emission_schedule_balance_after, 9 * AMOUNT);
+    assert_eq!(reward_pool_balance_after, AMOUNT);
+
+    let reward_pool = get_reward_pool(&mut banks_client, reward_pool_pubkey).await;
+    assert_eq!(reward_pool.amount, AMOUNT);
+
+    let emission_schedule = test_emission_schedule.get(&mut banks_client).await;
+    assert_eq!(emission_schedule.next_epoch, EPOCH + 1);
+    assert_eq!(emission_schedule.emitted, AMOUNT);
+}
+
+#[tokio::test]
+async fn test_already_emitted() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+    const EPOCH: u64 = 0;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    TestEmissionSchedule::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_mint.pubkey,
+        EPOCH,
+        AMOUNT,
+        Rate::new(100),
+        10 * AMOUNT,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[emit_rewards(
+            rndr::id(),
+            test_mint.pubkey,
+            payer.pubkey(),
+            EPOCH,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let recent_blockhash = banks_client
+        .get_new_latest_blockhash(&recent_blockhash)
+        .await
+        .unwrap();
+    let mut transaction = Transaction::new_with_payer(
+        &[emit_rewards(
+            rndr::id(),
+            test_mint.pubkey,
+            payer.pubkey(),
+            EPOCH,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
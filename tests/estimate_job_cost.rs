@@ -0,0 +1,76 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{instruction::estimate_job_cost, processor::process_instruction, test_fixtures::*},
+    solana_program_test::*,
+    solana_sdk::{signature::Signer, transaction::Transaction},
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    // 1.5 and 4 RNDR of 8 decimals per OctaneBench-hour, with the last tier disabled
+    const OBH_PRICES: [u64; 3] = [150_000_000, 400_000_000, 0];
+
+    TestConfig::add_with_obh_prices(&mut test, OBH_PRICES);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // 2.345 OctaneBench-hours, rounded up to a whole base unit in the first tier
+    for (tier, milli_obh, cost) in [
+        (0, 2_345, 351_750_000),
+        (1, 2_345, 938_000_000),
+        (0, 1, 150_000),
+        (1, 0, 0),
+    ] {
+        let mut transaction = Transaction::new_with_payer(
+            &[estimate_job_cost(rndr::id(), tier, milli_obh)],
+            Some(&payer.pubkey()),
+        );
+        transaction.sign(&[&payer], recent_blockhash);
+
+        let simulation = banks_client
+            .simulate_transaction(transaction)
+            .await
+            .unwrap();
+        assert!(simulation.result.unwrap().is_ok());
+        let return_data = simulation.simulation_details.unwrap().return_data.unwrap();
+        assert_eq!(return_data.program_id, rndr::id());
+        assert_eq!(return_data.data, u64::to_le_bytes(cost));
+    }
+
+    // Disabled and unknown tiers have no price
+    for tier in [2, 3] {
+        let mut transaction = Transaction::new_with_payer(
+            &[estimate_job_cost(rndr::id(), tier, 1_000)],
+            Some(&payer.pubkey()),
+        );
+        transaction.sign(&[&payer], recent_blockhash);
+
+        assert!(banks_client.process_transaction(transaction).await.is_err());
+    }
+}
+
+#[tokio::test]
+async fn test_rounding() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    TestConfig::add_with_obh_prices(&mut test, [3, 0, 0]);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // A thousandth of an OctaneBench-hour at 3 base units costs a whole base unit
+    let mut transaction = Transaction::new_with_payer(
+        &[estimate_job_cost(rndr::id(), 0, 1)],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    let simulation = banks_client
+        .simulate_transaction(transaction)
+        .await
+        .unwrap();
+    let return_data = simulation.simulation_details.unwrap().return_data.unwrap();
+    assert_eq!(return_data.data, u64::to_le_bytes(1));
+}
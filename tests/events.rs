@@ -0,0 +1,48 @@
+#![cfg(feature = "client")]
+
+use {
+    base64::{engine::general_purpose::STANDARD, Engine},
+    rndr::events::{parse_logs, JobCancelled, RNDREvent, Staked},
+    solana_program::pubkey::Pubkey,
+};
+
+fn data(event: &RNDREvent) -> String {
+    format!("Program data: {}", STANDARD.encode(event.pack()))
+}
+
+#[test]
+fn test_parse_logs() {
+    let program_id = rndr::id();
+    let other_program_id = Pubkey::new_unique();
+    let staked = RNDREvent::Staked(Staked { amount: 42 });
+    let job_cancelled = RNDREvent::JobCancelled(JobCancelled {
+        job: Pubkey::new_unique(),
+        refund: 9,
+        fee: 1,
+        node: Pubkey::new_unique(),
+    });
+
+    let logs = vec![
+        format!("Program {} invoke [1]", program_id),
+        "Program log: Instruction: Stake".to_string(),
+        data(&staked),
+        format!("Program {} invoke [2]", other_program_id),
+        data(&staked),
+        format!("Program {} success", other_program_id),
+        "Program data: not an event".to_string(),
+        data(&job_cancelled),
+        format!(
+            "Program {} consumed 4200 of 200000 compute units",
+            program_id
+        ),
+        format!("Program {} success", program_id),
+        format!("Program {} invoke [1]", other_program_id),
+        data(&staked),
+        format!(
+            "Program {} failed: custom program error: 0x0",
+            other_program_id
+        ),
+    ];
+
+    assert_eq!(parse_logs(&program_id, &logs), vec![staked, job_cancelled]);
+}
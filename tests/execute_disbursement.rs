@@ -0,0 +1,135 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{
+        error::RNDRError, instruction::execute_disbursement, processor::process_instruction,
+        test_fixtures::*,
+    },
+    solana_program_test::*,
+    solana_sdk::{
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        system_instruction,
+        transaction::Transaction,
+    },
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const AMOUNT: u64 = 1_000_000_000;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * AMOUNT);
+    let test_escrow = TestEscrow::add_with_approver(
+        &mut test,
+        test_mint.pubkey,
+        2 * AMOUNT,
+        Pubkey::new_unique(),
+    );
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let test_job = TestJob::add(
+        &mut test,
+        test_escrow.pubkey,
+        Pubkey::new_unique(),
+        2 * AMOUNT,
+    );
+    let test_proposal = TestProposal::add(
+        &mut test,
+        test_escrow.pubkey,
+        1,
+        test_job.pubkey,
+        test_destination_token.pubkey,
+        AMOUNT,
+    );
+    let test_approved_proposal = TestProposal::add_approved(
+        &mut test,
+        test_escrow.pubkey,
+        2,
+        test_job.pubkey,
+        test_destination_token.pubkey,
+        AMOUNT,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // Proposals must be approved before they can be executed
+    let mut transaction = Transaction::new_with_payer(
+        &[execute_disbursement(
+            rndr::id(),
+            test_mint.pubkey,
+            test_proposal.id,
+            test_job.pubkey,
+            false,
+            test_destination_token.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert_eq!(
+        program_error(banks_client.process_transaction(transaction).await),
+        Some(RNDRError::ProposalNotApproved)
+    );
+
+    // Anyone can execute an approved proposal
+    let executor = Keypair::new();
+    let mut transaction = Transaction::new_with_payer(
+        &[system_instruction::transfer(
+            &payer.pubkey(),
+            &executor.pubkey(),
+            1_000_000_000,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let mut transaction = Transaction::new_with_payer(
+        &[execute_disbursement(
+            rndr::id(),
+            test_mint.pubkey,
+            test_approved_proposal.id,
+            test_job.pubkey,
+            false,
+            test_destination_token.pubkey,
+        )],
+        Some(&executor.pubkey()),
+    );
+    transaction.sign(&[&executor], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let escrow_balance = get_token_balance(&mut banks_client, test_escrow.associated_token).await;
+    let destination_token_balance =
+        get_token_balance(&mut banks_client, test_destination_token.pubkey).await;
+    assert_eq!(escrow_balance, AMOUNT);
+    assert_eq!(destination_token_balance, AMOUNT);
+
+    let escrow = test_escrow.get(&mut banks_client).await;
+    assert_eq!(escrow.amount, AMOUNT);
+
+    let job = test_job.get(&mut banks_client).await;
+    assert_eq!(job.amount, AMOUNT);
+
+    let proposal = test_approved_proposal.get(&mut banks_client).await;
+    assert!(proposal.is_executed());
+
+    // Proposals can only be executed once
+    let mut transaction = Transaction::new_with_payer(
+        &[execute_disbursement(
+            rndr::id(),
+            test_mint.pubkey,
+            test_approved_proposal.id,
+            test_job.pubkey,
+            false,
+            test_destination_token.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
@@ -0,0 +1,125 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{
+        instruction::execute_queued_action,
+        processor::process_instruction,
+        state::{AdminAction, ConfigChange, MAX_FEE_BPS},
+        test_fixtures::*,
+    },
+    solana_program_test::*,
+    solana_sdk::{pubkey::Pubkey, signature::Signer, transaction::Transaction},
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const AMOUNT: u64 = 1_000_000_000;
+
+    let test_config = TestConfig::add(&mut test);
+    let test_mint = TestMint::add(&mut test, 9, AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let new_owner = Pubkey::new_unique();
+    let test_pending_action = TestQueuedAction::add(
+        &mut test,
+        test_escrow.pubkey,
+        1,
+        test_escrow.owner.pubkey(),
+        AdminAction::SetEscrowOwner(new_owner),
+        i64::MAX,
+    );
+    let test_owner_action = TestQueuedAction::add(
+        &mut test,
+        test_escrow.pubkey,
+        2,
+        test_escrow.owner.pubkey(),
+        AdminAction::SetEscrowOwner(new_owner),
+        1,
+    );
+    let test_config_action = TestQueuedAction::add(
+        &mut test,
+        test_config.pubkey,
+        1,
+        test_config.governance.pubkey(),
+        AdminAction::SetConfig(ConfigChange::FeeBps(MAX_FEE_BPS)),
+        1,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // Queued actions can't be executed before their timelock has passed
+    let mut transaction = Transaction::new_with_payer(
+        &[execute_queued_action(
+            rndr::id(),
+            test_escrow.pubkey,
+            test_pending_action.id,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            execute_queued_action(rndr::id(), test_escrow.pubkey, test_owner_action.id),
+            execute_queued_action(rndr::id(), test_config.pubkey, test_config_action.id),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let escrow = test_escrow.get(&mut banks_client).await;
+    assert_eq!(escrow.owner, new_owner);
+    assert_eq!(escrow.owner_history.len(), 1);
+    assert_eq!(
+        escrow.owner_history[0].previous_owner,
+        test_escrow.owner.pubkey()
+    );
+    let config = test_config.get(&mut banks_client).await;
+    assert_eq!(config.fee_bps, MAX_FEE_BPS);
+    assert!(test_owner_action.get(&mut banks_client).await.is_executed());
+    assert!(test_config_action
+        .get(&mut banks_client)
+        .await
+        .is_executed());
+}
+
+#[tokio::test]
+async fn test_authority_changed() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const AMOUNT: u64 = 1_000_000_000;
+
+    let test_mint = TestMint::add(&mut test, 9, AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    // Queued by a previous owner of the escrow
+    let test_queued_action = TestQueuedAction::add(
+        &mut test,
+        test_escrow.pubkey,
+        1,
+        Pubkey::new_unique(),
+        AdminAction::SetEscrowOwner(Pubkey::new_unique()),
+        1,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[execute_queued_action(
+            rndr::id(),
+            test_escrow.pubkey,
+            test_queued_action.id,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let escrow = test_escrow.get(&mut banks_client).await;
+    assert_eq!(escrow.owner, test_escrow.owner.pubkey());
+}
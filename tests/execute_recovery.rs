@@ -0,0 +1,113 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{
+        instruction::{approve_recovery, execute_recovery},
+        processor::process_instruction,
+        test_fixtures::*,
+    },
+    solana_program_test::*,
+    solana_sdk::{pubkey::Pubkey, signature::Signer, transaction::Transaction},
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const AMOUNT: u64 = 1_000_000_000;
+
+    let test_mint = TestMint::add(&mut test, 9, AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let new_owner = Pubkey::new_unique();
+    let test_recovery =
+        TestRecovery::add_pending(&mut test, test_escrow.pubkey, 2, 3, new_owner, 1);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[execute_recovery(rndr::id(), test_escrow.pubkey)],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let escrow = test_escrow.get(&mut banks_client).await;
+    assert_eq!(escrow.owner, new_owner);
+    assert_eq!(escrow.owner_history.len(), 1);
+    assert_eq!(
+        escrow.owner_history[0].previous_owner,
+        test_escrow.owner.pubkey()
+    );
+
+    // Votes are cleared once the recovery is executed
+    let recovery = test_recovery.get(&mut banks_client).await;
+    assert_eq!(recovery.approvals(&new_owner), 0);
+    assert!(!recovery.is_pending());
+}
+
+#[tokio::test]
+async fn test_delay_not_passed() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const AMOUNT: u64 = 1_000_000_000;
+
+    let test_mint = TestMint::add(&mut test, 9, AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    TestRecovery::add_pending(
+        &mut test,
+        test_escrow.pubkey,
+        2,
+        3,
+        Pubkey::new_unique(),
+        i64::MAX,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[execute_recovery(rndr::id(), test_escrow.pubkey)],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let escrow = test_escrow.get(&mut banks_client).await;
+    assert_eq!(escrow.owner, test_escrow.owner.pubkey());
+}
+
+#[tokio::test]
+async fn test_approval_withdrawn() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const AMOUNT: u64 = 1_000_000_000;
+
+    let test_mint = TestMint::add(&mut test, 9, AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_recovery =
+        TestRecovery::add_pending(&mut test, test_escrow.pubkey, 2, 3, Pubkey::new_unique(), 1);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // A guardian that votes for another owner withdraws their approval of the pending recovery
+    let guardian = &test_recovery.guardians[0];
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            approve_recovery(
+                rndr::id(),
+                test_escrow.pubkey,
+                guardian.pubkey(),
+                Pubkey::new_unique(),
+            ),
+            execute_recovery(rndr::id(), test_escrow.pubkey),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, guardian], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let escrow = test_escrow.get(&mut banks_client).await;
+    assert_eq!(escrow.owner, test_escrow.owner.pubkey());
+}
@@ -0,0 +1,103 @@
+#![cfg(feature = "client")]
+
+use {
+    rndr::{
+        client::filters::{
+            job_filters, jobs_by_authority_filters, jobs_by_isolated_filters, jobs_by_node_filters,
+            jobs_by_status_filters, jobs_by_tags_filters,
+        },
+        state::{InitJobParams, Job, JobStatus},
+    },
+    solana_client::rpc_filter::RpcFilterType,
+    solana_program::{program_pack::Pack, pubkey::Pubkey},
+};
+
+fn matches(filters: &[RpcFilterType], data: &[u8]) -> bool {
+    filters.iter().all(|filter| match filter {
+        RpcFilterType::DataSize(len) => data.len() as u64 == *len,
+        RpcFilterType::Memcmp(memcmp) => memcmp.bytes_match(data),
+        RpcFilterType::TokenAccountState => false,
+    })
+}
+
+fn pack(job: &Job) -> Vec<u8> {
+    let mut data = vec![0; Job::LEN];
+    job.pack_into_slice(&mut data);
+    data
+}
+
+#[test]
+fn test_jobs_by_status_and_node() {
+    let authority = Pubkey::new_unique();
+    let node = Pubkey::new_unique();
+    let mut job = Job::new(InitJobParams {
+        authority,
+        id: 0,
+        isolated: false,
+    });
+
+    let data = pack(&job);
+    assert!(matches(&job_filters(), &data));
+    assert!(matches(&jobs_by_authority_filters(&authority), &data));
+    assert!(matches(&jobs_by_status_filters(JobStatus::Open), &data));
+    assert!(!matches(
+        &jobs_by_status_filters(JobStatus::Committed),
+        &data
+    ));
+    assert!(!matches(&jobs_by_node_filters(&node), &data));
+
+    job.status = JobStatus::Committed;
+    job.node = node;
+    let data = pack(&job);
+    assert!(!matches(&jobs_by_status_filters(JobStatus::Open), &data));
+    assert!(matches(
+        &jobs_by_status_filters(JobStatus::Committed),
+        &data
+    ));
+    assert!(matches(&jobs_by_node_filters(&node), &data));
+    assert!(!matches(&jobs_by_node_filters(&authority), &data));
+}
+
+#[test]
+fn test_jobs_by_tags() {
+    const GPU_CLASS: u64 = 1 << 0;
+    const PRIORITY: u64 = 1 << 40;
+
+    let mut job = Job::new(InitJobParams {
+        authority: Pubkey::new_unique(),
+        id: 0,
+        isolated: false,
+    });
+
+    let data = pack(&job);
+    assert!(matches(&jobs_by_tags_filters(0), &data));
+    assert!(!matches(&jobs_by_tags_filters(GPU_CLASS), &data));
+
+    job.tags = GPU_CLASS | PRIORITY;
+    let data = pack(&job);
+    assert!(matches(&jobs_by_tags_filters(GPU_CLASS | PRIORITY), &data));
+    assert!(!matches(&jobs_by_tags_filters(GPU_CLASS), &data));
+
+    // Jobs from before the tags were appended end before them
+    assert!(!matches(
+        &jobs_by_tags_filters(0),
+        &data[..Job::PRE_TAGS_LEN]
+    ));
+}
+
+#[test]
+fn test_legacy_jobs() {
+    let authority = Pubkey::new_unique();
+    let job = Job::new(InitJobParams {
+        authority,
+        id: 0,
+        isolated: false,
+    });
+    let data = &pack(&job)[..Job::LEGACY_LEN];
+
+    // Legacy jobs end before the isolated flag, so they're only matched by the filters before it
+    assert!(matches(&job_filters(), data));
+    assert!(matches(&jobs_by_authority_filters(&authority), data));
+    assert!(!matches(&jobs_by_isolated_filters(false), data));
+    assert!(!matches(&jobs_by_status_filters(JobStatus::Open), data));
+}
@@ -0,0 +1,78 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{
+        instruction::{disburse_funds, flag_job},
+        processor::process_instruction,
+        state::JobStatus,
+        test_fixtures::*,
+    },
+    solana_program_test::*,
+    solana_sdk::{
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        transaction::Transaction,
+    },
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const AMOUNT: u64 = 1_000_000_000;
+    const EVIDENCE_HASH: [u8; 32] = [5; 32];
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let verifier = Keypair::new();
+    TestVerifierRegistry::add(&mut test, test_escrow.pubkey, vec![verifier.pubkey()]);
+    let test_job = TestJob::add_revealed(
+        &mut test,
+        test_escrow.pubkey,
+        Pubkey::new_unique(),
+        test_destination_token.owner.pubkey(),
+        AMOUNT,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[flag_job(
+            rndr::id(),
+            EVIDENCE_HASH,
+            test_mint.pubkey,
+            verifier.pubkey(),
+            test_job.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &verifier], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let job = get_job(&mut banks_client, test_job.pubkey).await;
+    assert_eq!(job.status, JobStatus::Disputed);
+    assert_eq!(job.verifier, verifier.pubkey());
+    assert_eq!(job.evidence_hash, EVIDENCE_HASH);
+
+    // Disbursement of the job's funds is frozen
+    let mut transaction = Transaction::new_with_payer(
+        &[disburse_funds(
+            rndr::id(),
+            AMOUNT,
+            test_mint.pubkey,
+            test_destination_token.pubkey,
+            test_job.pubkey,
+            test_escrow.owner.pubkey(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let job = get_job(&mut banks_client, test_job.pubkey).await;
+    assert_eq!(job.amount, AMOUNT);
+}
@@ -0,0 +1,231 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{
+        error::RNDRError,
+        instruction::{cancel_job, disburse_funds, freeze_job, unfreeze_job},
+        processor::process_instruction,
+        state::Job,
+        test_fixtures::*,
+    },
+    solana_program::program_pack::Pack,
+    solana_program_test::*,
+    solana_sdk::{pubkey::Pubkey, signature::Signer, system_instruction, transaction::Transaction},
+};
+
+const ZERO: u64 = 0;
+const DECIMALS: u64 = 1_000_000_000;
+const AMOUNT: u64 = 1 * DECIMALS;
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let test_config = TestConfig::add(&mut test);
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let authority = &test_destination_token.owner;
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, authority.pubkey(), AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let compliance = &test_config.compliance;
+    let mut transaction = Transaction::new_with_payer(
+        &[freeze_job(
+            rndr::id(),
+            test_mint.pubkey,
+            compliance.pubkey(),
+            authority.pubkey(),
+            0,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, compliance], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let job = get_job(&mut banks_client, test_job.pubkey).await;
+    assert!(job.frozen);
+
+    // A frozen job can't be frozen again, disbursed or refunded
+    for (instruction, signer) in [
+        (
+            freeze_job(
+                rndr::id(),
+                test_mint.pubkey,
+                compliance.pubkey(),
+                authority.pubkey(),
+                0,
+            ),
+            compliance,
+        ),
+        (
+            disburse_funds(
+                rndr::id(),
+                AMOUNT,
+                test_mint.pubkey,
+                test_destination_token.pubkey,
+                test_job.pubkey,
+                test_escrow.owner.pubkey(),
+            ),
+            &test_escrow.owner,
+        ),
+        (
+            cancel_job(
+                rndr::id(),
+                test_mint.pubkey,
+                authority.pubkey(),
+                0,
+                test_destination_token.pubkey,
+            ),
+            authority,
+        ),
+    ] {
+        let recent_blockhash = banks_client
+            .get_new_latest_blockhash(&recent_blockhash)
+            .await
+            .unwrap();
+        let mut transaction = Transaction::new_with_payer(&[instruction], Some(&payer.pubkey()));
+        transaction.sign(&[&payer, signer], recent_blockhash);
+
+        assert_eq!(
+            program_error(banks_client.process_transaction(transaction).await),
+            Some(RNDRError::JobFrozen)
+        );
+    }
+
+    let job = get_job(&mut banks_client, test_job.pubkey).await;
+    assert_eq!(job.amount, AMOUNT);
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_destination_token.pubkey).await,
+        ZERO
+    );
+
+    // Once the hold is released, the job's funds can be disbursed again
+    let recent_blockhash = banks_client
+        .get_new_latest_blockhash(&recent_blockhash)
+        .await
+        .unwrap();
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            unfreeze_job(
+                rndr::id(),
+                test_mint.pubkey,
+                compliance.pubkey(),
+                authority.pubkey(),
+                0,
+            ),
+            disburse_funds(
+                rndr::id(),
+                AMOUNT,
+                test_mint.pubkey,
+                test_destination_token.pubkey,
+                test_job.pubkey,
+                test_escrow.owner.pubkey(),
+            ),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, compliance, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let job = get_job(&mut banks_client, test_job.pubkey).await;
+    assert!(!job.frozen);
+    assert_eq!(job.amount, ZERO);
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_destination_token.pubkey).await,
+        AMOUNT
+    );
+
+    // An unfrozen job can't be unfrozen again
+    let recent_blockhash = banks_client
+        .get_new_latest_blockhash(&recent_blockhash)
+        .await
+        .unwrap();
+    let mut transaction = Transaction::new_with_payer(
+        &[unfreeze_job(
+            rndr::id(),
+            test_mint.pubkey,
+            compliance.pubkey(),
+            authority.pubkey(),
+            0,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, compliance], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+#[tokio::test]
+async fn test_success_with_legacy_job() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let test_config = TestConfig::add(&mut test);
+    let test_mint = TestMint::add(&mut test, 9, AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let authority = Pubkey::new_unique();
+    let test_job = TestJob::add_legacy(&mut test, test_escrow.pubkey, authority, AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let compliance = &test_config.compliance;
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            system_instruction::transfer(&payer.pubkey(), &compliance.pubkey(), 1_000_000_000),
+            freeze_job(
+                rndr::id(),
+                test_mint.pubkey,
+                compliance.pubkey(),
+                authority,
+                0,
+            ),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, compliance], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    // The legacy job is reallocated to hold the frozen flag
+    let account = get_account(&mut banks_client, test_job.pubkey).await;
+    assert_eq!(account.data.len(), Job::LEN);
+    let job = get_job(&mut banks_client, test_job.pubkey).await;
+    assert!(job.frozen);
+    assert_eq!(job.amount, AMOUNT);
+}
+
+#[tokio::test]
+async fn test_invalid_compliance() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let test_config = TestConfig::add(&mut test);
+    let test_mint = TestMint::add(&mut test, 9, AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let authority = Pubkey::new_unique();
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, authority, AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // Only the compliance authority can freeze a job, not the guardian or the escrow owner
+    for signer in [&test_config.guardian, &test_escrow.owner] {
+        let mut transaction = Transaction::new_with_payer(
+            &[freeze_job(
+                rndr::id(),
+                test_mint.pubkey,
+                signer.pubkey(),
+                authority,
+                0,
+            )],
+            Some(&payer.pubkey()),
+        );
+        transaction.sign(&[&payer, signer], recent_blockhash);
+
+        assert!(banks_client.process_transaction(transaction).await.is_err());
+    }
+
+    let job = get_job(&mut banks_client, test_job.pubkey).await;
+    assert!(!job.frozen);
+}
@@ -1,15 +1,61 @@
 #![cfg(feature = "test-bpf")]
 
-mod helpers;
-
-use rndr::state::AccountType;
+use rndr::state::{
+    AccountType, Escrow, EscrowShard, Job, JobMetadata, JOB_INDEX_PAGE_LEN, SESSION_KEY_FUND_JOB,
+};
 use {
-    helpers::*,
-    rndr::{instruction::fund_job, processor::process_instruction},
+    rndr::{
+        bubblegum::{self, ReceiptMetadata, BUBBLEGUM_PROGRAM_ID},
+        instruction::{
+            fund_job, fund_job_with_metadata, fund_job_with_session_key, with_job_index,
+            with_job_receipt, with_stats,
+        },
+        processor::process_instruction,
+        test_fixtures::*,
+    },
+    solana_program::{
+        account_info::AccountInfo, clock::Slot, entrypoint::ProgramResult,
+        program_error::ProgramError, pubkey::Pubkey, pubkey::PUBKEY_BYTES,
+    },
     solana_program_test::*,
-    solana_sdk::{pubkey::Pubkey, signature::Signer, transaction::Transaction},
+    solana_sdk::{
+        account::Account,
+        instruction::AccountMeta,
+        signature::{Keypair, Signer},
+        sysvar,
+        transaction::Transaction,
+    },
 };
 
+/// Stand-in for Bubblegum, which records the data of a 'MintV1' instruction in the merkle tree if
+/// the payer and tree delegate signed it
+fn process_bubblegum(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    input: &[u8],
+) -> ProgramResult {
+    if !accounts[4].is_signer || !accounts[5].is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    let mut data = accounts[3].try_borrow_mut_data()?;
+    data[..input.len()].copy_from_slice(input);
+    Ok(())
+}
+
+fn add_bubblegum(test: &mut ProgramTest) -> Pubkey {
+    test.add_program(
+        "bubblegum",
+        BUBBLEGUM_PROGRAM_ID,
+        processor!(process_bubblegum),
+    );
+    let merkle_tree = Pubkey::new_unique();
+    test.add_account(
+        merkle_tree,
+        Account::new(u32::MAX as u64, 512, &BUBBLEGUM_PROGRAM_ID),
+    );
+    merkle_tree
+}
+
 #[tokio::test]
 async fn test_success() {
     let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
@@ -17,6 +63,7 @@ async fn test_success() {
     const ZERO: u64 = 0;
     const DECIMALS: u64 = 1_000_000_000;
     const AMOUNT: u64 = 1 * DECIMALS;
+    const JOB_ID: u64 = 1;
 
     let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
     let test_source_token = TestToken::add(&mut test, test_mint.pubkey, AMOUNT);
@@ -40,6 +87,7 @@ async fn test_success() {
         &[fund_job(
             rndr::id(),
             AMOUNT,
+            JOB_ID,
             test_mint.pubkey,
             payer.pubkey(),
             test_source_token.pubkey,
@@ -60,15 +108,558 @@ async fn test_success() {
     assert_eq!(escrow_balance_after, AMOUNT);
 
     let escrow = get_escrow(&mut banks_client, test_escrow.pubkey).await;
-    assert_eq!(escrow.amount, AMOUNT);
+    assert_eq!(escrow.amount, ZERO);
+
+    let (job_pubkey, _bump_seed) = find_job_address(test_escrow.pubkey, authority, JOB_ID);
+    let job = get_job(&mut banks_client, job_pubkey).await;
+
+    assert_eq!(job.account_type, AccountType::JobV1);
+    assert_eq!(job.authority, authority);
+    assert_eq!(job.id, JOB_ID);
+    assert_eq!(job.amount, AMOUNT);
+
+    // Filters for getProgramAccounts queries rely on these offsets
+    let job_data = get_account(&mut banks_client, job_pubkey).await.data;
+    assert_eq!(
+        job_data[Job::AUTHORITY_OFFSET..][..PUBKEY_BYTES],
+        authority.to_bytes()
+    );
+    assert_eq!(job_data[Job::ISOLATED_OFFSET], 0);
+    assert_eq!(job_data[Job::ID_OFFSET..][..8], JOB_ID.to_le_bytes());
+
+    let (escrow_shard_pubkey, _bump_seed) =
+        find_escrow_shard_address(test_escrow.pubkey, job_pubkey);
+    let escrow_shard = get_escrow_shard(&mut banks_client, escrow_shard_pubkey).await;
+
+    assert_eq!(escrow_shard.account_type, AccountType::EscrowShardV1);
+    assert_eq!(escrow_shard.escrow, test_escrow.pubkey);
+    assert_eq!(escrow_shard.index, EscrowShard::index_for_job(&job_pubkey));
+    assert_eq!(escrow_shard.amount, AMOUNT);
+}
+
+#[tokio::test]
+async fn test_success_with_legacy_rent_sysvar() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+    const JOB_ID: u64 = 1;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_source_token = TestToken::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let authority = test_source_token.owner.pubkey();
 
-    let (job_pubkey, _bump_seed) = Pubkey::find_program_address(
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut instruction = fund_job(
+        rndr::id(),
+        AMOUNT,
+        JOB_ID,
+        test_mint.pubkey,
+        payer.pubkey(),
+        test_source_token.pubkey,
+        authority,
+    );
+    // Clients built before the Rent sysvar was dropped provide it after the escrow shard
+    instruction
+        .accounts
+        .insert(7, AccountMeta::new_readonly(sysvar::rent::id(), false));
+
+    let mut transaction = Transaction::new_with_payer(&[instruction], Some(&payer.pubkey()));
+    transaction.sign(&[&payer, &test_source_token.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let escrow_balance_after =
+        get_token_balance(&mut banks_client, test_escrow.associated_token).await;
+    assert_eq!(escrow_balance_after, AMOUNT);
+
+    let (job_pubkey, _bump_seed) = find_job_address(test_escrow.pubkey, authority, JOB_ID);
+    let job = get_job(&mut banks_client, job_pubkey).await;
+    assert_eq!(job.amount, AMOUNT);
+}
+
+#[tokio::test]
+async fn test_success_with_legacy_job() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_source_token = TestToken::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let authority = test_source_token.owner.pubkey();
+    let test_job = TestJob::add_legacy(&mut test, test_escrow.pubkey, authority, AMOUNT);
+
+    // Jobs with identifier zero keep the address they had before jobs had identifiers
+    let (legacy_pubkey, _bump_seed) = Pubkey::find_program_address(
         &[b"job", test_escrow.pubkey.as_ref(), authority.as_ref()],
         &rndr::id(),
     );
-    let job = get_job(&mut banks_client, job_pubkey).await;
+    assert_eq!(test_job.pubkey, legacy_pubkey);
 
-    assert_eq!(job.account_type, AccountType::JobV1);
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let account = get_account(&mut banks_client, test_job.pubkey).await;
+    assert_eq!(account.data.len(), Job::LEGACY_LEN);
+
+    let mut transaction = Transaction::new_with_payer(
+        &[fund_job(
+            rndr::id(),
+            AMOUNT,
+            0,
+            test_mint.pubkey,
+            payer.pubkey(),
+            test_source_token.pubkey,
+            authority,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_source_token.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let job = get_job(&mut banks_client, test_job.pubkey).await;
     assert_eq!(job.authority, authority);
+    assert_eq!(job.id, 0);
+    assert!(!job.isolated);
+    assert_eq!(job.amount, 2 * AMOUNT);
+}
+
+#[tokio::test]
+async fn test_success_with_legacy_escrow() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+    const JOB_ID: u64 = 1;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_source_token = TestToken::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_escrow = TestEscrow::add_legacy(&mut test, test_mint.pubkey, ZERO);
+    let authority = test_source_token.owner.pubkey();
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // The token mint of a legacy escrow is resolved from its token account
+    let account = get_account(&mut banks_client, test_escrow.pubkey).await;
+    assert_eq!(account.data.len(), Escrow::LEGACY_LEN);
+
+    let mut transaction = Transaction::new_with_payer(
+        &[fund_job(
+            rndr::id(),
+            AMOUNT,
+            JOB_ID,
+            test_mint.pubkey,
+            payer.pubkey(),
+            test_source_token.pubkey,
+            authority,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_source_token.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let account = get_account(&mut banks_client, test_escrow.pubkey).await;
+    assert_eq!(account.data.len(), Escrow::LEGACY_LEN);
+
+    let (job_pubkey, _bump_seed) = find_job_address(test_escrow.pubkey, authority, JOB_ID);
+    let job = get_job(&mut banks_client, job_pubkey).await;
     assert_eq!(job.amount, AMOUNT);
+
+    let escrow_balance = get_token_balance(&mut banks_client, test_escrow.associated_token).await;
+    assert_eq!(escrow_balance, AMOUNT);
+}
+
+#[tokio::test]
+async fn test_success_with_stats() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+    const JOB_ID: u64 = 1;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_source_token = TestToken::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let test_stats = TestStats::add(&mut test, test_escrow.pubkey);
+    let authority = test_source_token.owner.pubkey();
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[with_stats(
+            fund_job(
+                rndr::id(),
+                AMOUNT,
+                JOB_ID,
+                test_mint.pubkey,
+                payer.pubkey(),
+                test_source_token.pubkey,
+                authority,
+            ),
+            test_escrow.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_source_token.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let stats = test_stats.get(&mut banks_client).await;
+    assert_eq!(stats.total_escrowed, u128::from(AMOUNT));
+    assert_eq!(stats.epoch_escrowed, AMOUNT);
+    assert_eq!(stats.total_disbursed, 0);
+}
+
+#[tokio::test]
+async fn test_success_with_metadata() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+    const JOB_ID: u64 = 1;
+    const URI: &str = "ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi";
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_source_token = TestToken::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let authority = test_source_token.owner.pubkey();
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[fund_job_with_metadata(
+            rndr::id(),
+            AMOUNT,
+            JOB_ID,
+            test_mint.pubkey,
+            payer.pubkey(),
+            test_source_token.pubkey,
+            authority,
+            JobMetadata {
+                uri: URI.to_string(),
+                hash: [7; 32],
+                tags: 1 << 3,
+            },
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_source_token.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let (job_pubkey, _bump_seed) = find_job_address(test_escrow.pubkey, authority, JOB_ID);
+    let job = get_job(&mut banks_client, job_pubkey).await;
+    assert_eq!(job.amount, AMOUNT);
+    assert_eq!(job.metadata_uri, URI);
+    assert_eq!(job.metadata_hash, [7; 32]);
+    assert_eq!(job.tags, 1 << 3);
+}
+
+#[tokio::test]
+async fn test_success_with_receipt() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+    const JOB_ID: u64 = 1;
+    const URI: &str = "ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi";
+
+    let merkle_tree = add_bubblegum(&mut test);
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_source_token = TestToken::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let authority = test_source_token.owner.pubkey();
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[with_job_receipt(
+            fund_job_with_metadata(
+                rndr::id(),
+                AMOUNT,
+                JOB_ID,
+                test_mint.pubkey,
+                payer.pubkey(),
+                test_source_token.pubkey,
+                authority,
+                JobMetadata {
+                    uri: URI.to_string(),
+                    hash: [7; 32],
+                    tags: 0,
+                },
+            ),
+            merkle_tree,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_source_token.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let (job_pubkey, _bump_seed) = find_job_address(test_escrow.pubkey, authority, JOB_ID);
+    let job = get_job(&mut banks_client, job_pubkey).await;
+    assert_eq!(job.amount, AMOUNT);
+
+    // The receipt is minted to the funder, signed by the receipt authority as the tree delegate
+    let metadata = ReceiptMetadata::new(JOB_ID, URI, &[7; 32]);
+    assert_eq!(metadata.name, "RNDR Job 1");
+    assert_eq!(metadata.uri, format!("{}#{}", URI, "07".repeat(32)));
+    let (receipt_authority, _bump_seed) = find_receipt_authority_address();
+    let mint = bubblegum::mint_v1(
+        merkle_tree,
+        payer.pubkey(),
+        payer.pubkey(),
+        receipt_authority,
+        &metadata,
+    );
+    let account = get_account(&mut banks_client, merkle_tree).await;
+    assert_eq!(&account.data[..mint.data.len()], &mint.data[..]);
+}
+
+#[tokio::test]
+async fn test_receipt_wrong_authority() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+
+    let merkle_tree = add_bubblegum(&mut test);
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_source_token = TestToken::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let authority = test_source_token.owner.pubkey();
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut instruction = with_job_receipt(
+        fund_job(
+            rndr::id(),
+            AMOUNT,
+            0,
+            test_mint.pubkey,
+            payer.pubkey(),
+            test_source_token.pubkey,
+            authority,
+        ),
+        merkle_tree,
+    );
+    let receipt_authority = instruction.accounts.len() - 6;
+    instruction.accounts[receipt_authority].pubkey = Pubkey::new_unique();
+    let mut transaction = Transaction::new_with_payer(&[instruction], Some(&payer.pubkey()));
+    transaction.sign(&[&payer, &test_source_token.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let account = get_account(&mut banks_client, merkle_tree).await;
+    assert!(account.data.iter().all(|byte| *byte == 0));
+}
+
+#[tokio::test]
+async fn test_success_with_job_index() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+    const JOB_ID: u64 = 1;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_source_token = TestToken::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let test_stats = TestStats::add(&mut test, test_escrow.pubkey);
+    let authority = test_source_token.owner.pubkey();
+    let test_job_index = TestJobIndex::add(&mut test, test_escrow.pubkey, authority, &[]);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[with_stats(
+            with_job_index(
+                fund_job(
+                    rndr::id(),
+                    AMOUNT,
+                    JOB_ID,
+                    test_mint.pubkey,
+                    payer.pubkey(),
+                    test_source_token.pubkey,
+                    authority,
+                ),
+                test_escrow.pubkey,
+                authority,
+                0,
+            ),
+            test_escrow.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_source_token.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let job_index = test_job_index.get(&mut banks_client).await;
+    assert_eq!(job_index.job_count, 1);
+
+    let job_index_page = test_job_index.get_page(&mut banks_client, 0).await;
+    assert_eq!(job_index_page.job_index, test_job_index.pubkey);
+    assert_eq!(job_index_page.page, 0);
+    assert_eq!(job_index_page.job_ids, vec![JOB_ID]);
+
+    let stats = test_stats.get(&mut banks_client).await;
+    assert_eq!(stats.total_escrowed, u128::from(AMOUNT));
+}
+
+#[tokio::test]
+async fn test_job_index_wrong_page() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+    const JOB_ID: u64 = 1_000;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_source_token = TestToken::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let authority = test_source_token.owner.pubkey();
+    // A full first page, so the job must be recorded in the second
+    let job_ids: Vec<u64> = (0..JOB_INDEX_PAGE_LEN as u64).collect();
+    TestJobIndex::add(&mut test, test_escrow.pubkey, authority, &job_ids);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[with_job_index(
+            fund_job(
+                rndr::id(),
+                AMOUNT,
+                JOB_ID,
+                test_mint.pubkey,
+                payer.pubkey(),
+                test_source_token.pubkey,
+                authority,
+            ),
+            test_escrow.pubkey,
+            authority,
+            0,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_source_token.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+#[tokio::test]
+async fn test_success_with_session_key() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let authority = Keypair::new().pubkey();
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, authority, AMOUNT);
+    let test_session_key = TestSessionKey::add(
+        &mut test,
+        test_escrow.pubkey,
+        authority,
+        Slot::MAX,
+        SESSION_KEY_FUND_JOB,
+    );
+    let session_key = &test_session_key.session_key;
+    // The session key transfers the tokens as the delegate of the source token account
+    let test_source_token = TestToken::add_delegated(
+        &mut test,
+        test_mint.pubkey,
+        AMOUNT,
+        session_key.pubkey(),
+        AMOUNT,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[fund_job_with_session_key(
+            rndr::id(),
+            AMOUNT,
+            0,
+            test_mint.pubkey,
+            payer.pubkey(),
+            test_source_token.pubkey,
+            session_key.pubkey(),
+            authority,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, session_key], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let job = test_job.get(&mut banks_client).await;
+    assert_eq!(job.authority, authority);
+    assert_eq!(job.amount, 2 * AMOUNT);
+}
+
+#[tokio::test]
+async fn test_session_key_new_job() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let authority = Keypair::new().pubkey();
+    let test_session_key = TestSessionKey::add(
+        &mut test,
+        test_escrow.pubkey,
+        authority,
+        Slot::MAX,
+        SESSION_KEY_FUND_JOB,
+    );
+    let session_key = &test_session_key.session_key;
+    let test_source_token = TestToken::add_delegated(
+        &mut test,
+        test_mint.pubkey,
+        AMOUNT,
+        session_key.pubkey(),
+        AMOUNT,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // Session keys can only top up jobs the authority created
+    let mut transaction = Transaction::new_with_payer(
+        &[fund_job_with_session_key(
+            rndr::id(),
+            AMOUNT,
+            0,
+            test_mint.pubkey,
+            payer.pubkey(),
+            test_source_token.pubkey,
+            session_key.pubkey(),
+            authority,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, session_key], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let escrow_balance_after =
+        get_token_balance(&mut banks_client, test_escrow.associated_token).await;
+    assert_eq!(escrow_balance_after, ZERO);
 }
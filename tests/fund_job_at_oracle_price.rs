@@ -0,0 +1,309 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{
+        instruction::fund_job_at_oracle_price,
+        math::{ExchangeRate, Rate, RNDR_DECIMALS},
+        oracle::OraclePrice,
+        processor::process_instruction,
+        state::{DEFAULT_MAX_PRICE_AGE, DEFAULT_MAX_PRICE_CONFIDENCE_BPS},
+        test_fixtures::*,
+    },
+    solana_program::pubkey::Pubkey,
+    solana_program_test::*,
+    solana_sdk::{signature::Signer, transaction::Transaction},
+};
+
+const DECIMALS: u64 = 100_000_000;
+const BALANCE: u64 = 20 * DECIMALS;
+const JOB_ID: u64 = 1;
+// 100 US dollars with 6 decimals
+const VALUE: u64 = 100_000_000;
+// 7.318624055 US dollars per RNDR
+const ORACLE_PRICE: OraclePrice = OraclePrice {
+    price: 7_318_624_055,
+    confidence: 1_000_000,
+    exponent: -9,
+    publish_slot: 0,
+};
+// 100 / 7.318624055 RNDR, rounded up
+const AMOUNT: u64 = 1_366_377_058;
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    TestConfig::add(&mut test);
+    let test_mint = TestMint::add(&mut test, 8, 100 * DECIMALS);
+    let test_source_token = TestToken::add(&mut test, test_mint.pubkey, BALANCE);
+    let test_price_feed = TestPriceFeed::add(&mut test, ORACLE_PRICE);
+    let test_escrow =
+        TestEscrow::add_with_price_feed(&mut test, test_mint.pubkey, 0, test_price_feed.pubkey);
+    let authority = test_source_token.owner.pubkey();
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // The amount at the oracle price can't exceed the maximum the funder signed for
+    let mut transaction = Transaction::new_with_payer(
+        &[fund_job_at_oracle_price(
+            rndr::id(),
+            VALUE,
+            AMOUNT - 1,
+            JOB_ID,
+            test_mint.pubkey,
+            test_price_feed.pubkey,
+            payer.pubkey(),
+            test_source_token.pubkey,
+            authority,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_source_token.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_source_token.pubkey).await,
+        BALANCE
+    );
+
+    let mut transaction = Transaction::new_with_payer(
+        &[fund_job_at_oracle_price(
+            rndr::id(),
+            VALUE,
+            AMOUNT,
+            JOB_ID,
+            test_mint.pubkey,
+            test_price_feed.pubkey,
+            payer.pubkey(),
+            test_source_token.pubkey,
+            authority,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_source_token.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_source_token.pubkey).await,
+        BALANCE - AMOUNT
+    );
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_escrow.associated_token).await,
+        AMOUNT
+    );
+    let (job_pubkey, _bump_seed) = find_job_address(test_escrow.pubkey, authority, JOB_ID);
+    let job = get_job(&mut banks_client, job_pubkey).await;
+    assert_eq!(job.amount, AMOUNT);
+
+    // The job is settled at the rate of the price it was funded at
+    assert_eq!(
+        job.exchange_rate(),
+        ExchangeRate::try_from_price(ORACLE_PRICE.price, ORACLE_PRICE.exponent, 8, RNDR_DECIMALS)
+            .ok()
+    );
+    assert_eq!(job.exchange_rate_slot, ORACLE_PRICE.publish_slot);
+}
+
+#[tokio::test]
+async fn test_invalid_price_feed() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    TestConfig::add(&mut test);
+    let test_mint = TestMint::add(&mut test, 8, 100 * DECIMALS);
+    let test_source_token = TestToken::add(&mut test, test_mint.pubkey, BALANCE);
+    let test_price_feed = TestPriceFeed::add(&mut test, ORACLE_PRICE);
+    let test_halted_price_feed = TestPriceFeed::add_with_status(&mut test, ORACLE_PRICE, 2);
+    let test_escrow =
+        TestEscrow::add_with_price_feed(&mut test, test_mint.pubkey, 0, test_price_feed.pubkey);
+    let test_other_mint = TestMint::add(&mut test, 8, 100 * DECIMALS);
+    let test_other_source_token = TestToken::add(&mut test, test_other_mint.pubkey, BALANCE);
+    let test_other_escrow = TestEscrow::add_with_price_feed(
+        &mut test,
+        test_other_mint.pubkey,
+        0,
+        test_halted_price_feed.pubkey,
+    );
+    let test_unpriced_mint = TestMint::add(&mut test, 8, 100 * DECIMALS);
+    let test_unpriced_source_token = TestToken::add(&mut test, test_unpriced_mint.pubkey, BALANCE);
+    TestEscrow::add(&mut test, test_unpriced_mint.pubkey, 0);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // The price feed must be the escrow's, be trading and be set at all
+    for (test_mint, price_feed, test_source_token) in [
+        (&test_mint, Pubkey::new_unique(), &test_source_token),
+        (
+            &test_other_mint,
+            test_halted_price_feed.pubkey,
+            &test_other_source_token,
+        ),
+        (
+            &test_unpriced_mint,
+            test_price_feed.pubkey,
+            &test_unpriced_source_token,
+        ),
+    ] {
+        let mut transaction = Transaction::new_with_payer(
+            &[fund_job_at_oracle_price(
+                rndr::id(),
+                VALUE,
+                BALANCE,
+                JOB_ID,
+                test_mint.pubkey,
+                price_feed,
+                payer.pubkey(),
+                test_source_token.pubkey,
+                test_source_token.owner.pubkey(),
+            )],
+            Some(&payer.pubkey()),
+        );
+        transaction.sign(&[&payer, &test_source_token.owner], recent_blockhash);
+
+        assert!(banks_client.process_transaction(transaction).await.is_err());
+        assert_eq!(
+            get_token_balance(&mut banks_client, test_source_token.pubkey).await,
+            BALANCE
+        );
+    }
+
+    let escrow = get_escrow(&mut banks_client, test_other_escrow.pubkey).await;
+    assert_eq!(escrow.price_feed, test_halted_price_feed.pubkey);
+    let escrow = get_escrow(&mut banks_client, test_escrow.pubkey).await;
+    assert_eq!(escrow.amount, 0);
+}
+
+#[tokio::test]
+async fn test_stale_price() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    TestConfig::add(&mut test);
+    let test_mint = TestMint::add(&mut test, 8, 100 * DECIMALS);
+    let test_source_token = TestToken::add(&mut test, test_mint.pubkey, BALANCE);
+    let test_price_feed = TestPriceFeed::add(&mut test, ORACLE_PRICE);
+    TestEscrow::add_with_price_feed(&mut test, test_mint.pubkey, 0, test_price_feed.pubkey);
+
+    let mut context = test.start_with_context().await;
+    // The price was published at slot zero, which is then too many slots ago
+    context.warp_to_slot(DEFAULT_MAX_PRICE_AGE + 1).unwrap();
+
+    let mut transaction = Transaction::new_with_payer(
+        &[fund_job_at_oracle_price(
+            rndr::id(),
+            VALUE,
+            AMOUNT,
+            JOB_ID,
+            test_mint.pubkey,
+            test_price_feed.pubkey,
+            context.payer.pubkey(),
+            test_source_token.pubkey,
+            test_source_token.owner.pubkey(),
+        )],
+        Some(&context.payer.pubkey()),
+    );
+    transaction.sign(
+        &[&context.payer, &test_source_token.owner],
+        context.last_blockhash,
+    );
+
+    assert!(context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .is_err());
+    assert_eq!(
+        get_token_balance(&mut context.banks_client, test_source_token.pubkey).await,
+        BALANCE
+    );
+}
+
+#[tokio::test]
+async fn test_unreliable_price() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    // The confidence is 3% of the price, more than the default maximum
+    const UNRELIABLE_PRICE: OraclePrice = OraclePrice {
+        confidence: ORACLE_PRICE.price * 3 / 100,
+        ..ORACLE_PRICE
+    };
+    assert!(DEFAULT_MAX_PRICE_CONFIDENCE_BPS < Rate::new(300));
+
+    TestConfig::add(&mut test);
+    let test_mint = TestMint::add(&mut test, 8, 100 * DECIMALS);
+    let test_source_token = TestToken::add(&mut test, test_mint.pubkey, BALANCE);
+    let test_price_feed = TestPriceFeed::add(&mut test, UNRELIABLE_PRICE);
+    TestEscrow::add_with_price_feed(&mut test, test_mint.pubkey, 0, test_price_feed.pubkey);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[fund_job_at_oracle_price(
+            rndr::id(),
+            VALUE,
+            AMOUNT,
+            JOB_ID,
+            test_mint.pubkey,
+            test_price_feed.pubkey,
+            payer.pubkey(),
+            test_source_token.pubkey,
+            test_source_token.owner.pubkey(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_source_token.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_source_token.pubkey).await,
+        BALANCE
+    );
+}
+
+#[tokio::test]
+async fn test_success_with_price_guards() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    // An older and less certain price is consumed when the guards of the config allow it
+    const UNCERTAIN_PRICE: OraclePrice = OraclePrice {
+        confidence: ORACLE_PRICE.price * 3 / 100,
+        ..ORACLE_PRICE
+    };
+
+    TestConfig::add_with_price_guards(&mut test, 100, Rate::new(300));
+    let test_mint = TestMint::add(&mut test, 8, 100 * DECIMALS);
+    let test_source_token = TestToken::add(&mut test, test_mint.pubkey, BALANCE);
+    let test_price_feed = TestPriceFeed::add(&mut test, UNCERTAIN_PRICE);
+    TestEscrow::add_with_price_feed(&mut test, test_mint.pubkey, 0, test_price_feed.pubkey);
+
+    let mut context = test.start_with_context().await;
+    context.warp_to_slot(100).unwrap();
+
+    let mut transaction = Transaction::new_with_payer(
+        &[fund_job_at_oracle_price(
+            rndr::id(),
+            VALUE,
+            AMOUNT,
+            JOB_ID,
+            test_mint.pubkey,
+            test_price_feed.pubkey,
+            context.payer.pubkey(),
+            test_source_token.pubkey,
+            test_source_token.owner.pubkey(),
+        )],
+        Some(&context.payer.pubkey()),
+    );
+    transaction.sign(
+        &[&context.payer, &test_source_token.owner],
+        context.last_blockhash,
+    );
+
+    assert!(context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .is_ok());
+    assert_eq!(
+        get_token_balance(&mut context.banks_client, test_source_token.pubkey).await,
+        BALANCE - AMOUNT
+    );
+}
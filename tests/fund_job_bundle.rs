@@ -0,0 +1,98 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{instruction::fund_job_bundle, processor::process_instruction, test_fixtures::*},
+    solana_program_test::*,
+    solana_sdk::{signature::Signer, transaction::Transaction},
+};
+
+const ZERO: u64 = 0;
+const DECIMALS: u64 = 1_000_000_000;
+const AMOUNT: u64 = 3 * DECIMALS;
+const JOB_COUNT: u8 = 4;
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_source_token = TestToken::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let authority = test_source_token.owner.pubkey();
+    let test_job_bundle =
+        TestJobBundle::add(&mut test, test_escrow.pubkey, authority, ZERO, JOB_COUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[fund_job_bundle(
+            rndr::id(),
+            test_mint.pubkey,
+            payer.pubkey(),
+            test_source_token.pubkey,
+            authority,
+            authority,
+            0,
+            1,
+            3,
+            DECIMALS,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_source_token.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let source_token_balance_after =
+        get_token_balance(&mut banks_client, test_source_token.pubkey).await;
+    let escrow_balance_after =
+        get_token_balance(&mut banks_client, test_escrow.associated_token).await;
+
+    assert_eq!(source_token_balance_after, ZERO);
+    assert_eq!(escrow_balance_after, AMOUNT);
+
+    for job_id in 1..=3 {
+        let (job_pubkey, _bump_seed) = find_job_address(test_escrow.pubkey, authority, job_id);
+        let job = get_job(&mut banks_client, job_pubkey).await;
+
+        assert_eq!(job.authority, authority);
+        assert_eq!(job.id, job_id);
+        assert_eq!(job.amount, DECIMALS);
+    }
+
+    let job_bundle = test_job_bundle.get(&mut banks_client).await;
+    assert_eq!(job_bundle.amount, AMOUNT);
+}
+
+#[tokio::test]
+async fn test_jobs_outside_bundle() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_source_token = TestToken::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let authority = test_source_token.owner.pubkey();
+    TestJobBundle::add(&mut test, test_escrow.pubkey, authority, ZERO, JOB_COUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // Jobs 2 through 4 run past the last job of the bundle
+    let mut transaction = Transaction::new_with_payer(
+        &[fund_job_bundle(
+            rndr::id(),
+            test_mint.pubkey,
+            payer.pubkey(),
+            test_source_token.pubkey,
+            authority,
+            authority,
+            0,
+            2,
+            3,
+            DECIMALS,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_source_token.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
@@ -0,0 +1,303 @@
+#![cfg(feature = "test-bpf")]
+
+use rndr::state::{AccountType, Voucher};
+use {
+    rndr::{instruction::fund_job_with_voucher, processor::process_instruction, test_fixtures::*},
+    solana_program_test::*,
+    solana_sdk::{
+        signature::{Keypair, Signer},
+        transaction::Transaction,
+    },
+};
+
+const ZERO: u64 = 0;
+const DECIMALS: u64 = 1_000_000_000;
+const AMOUNT: u64 = 1 * DECIMALS;
+const JOB_ID: u64 = 1;
+
+struct Setup {
+    test: ProgramTest,
+    test_mint: TestMint,
+    test_source_token: TestToken,
+    test_escrow: TestEscrow,
+    voucher: Voucher,
+}
+
+fn setup() -> Setup {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let test_source_token = TestToken::add_delegated(
+        &mut test,
+        test_mint.pubkey,
+        2 * AMOUNT,
+        test_escrow.pubkey,
+        2 * AMOUNT,
+    );
+    let voucher = Voucher {
+        escrow: test_escrow.pubkey,
+        authority: test_source_token.owner.pubkey(),
+        job_id: JOB_ID,
+        amount: AMOUNT,
+        nonce: 0,
+        expires_at: i64::MAX,
+    };
+
+    Setup {
+        test,
+        test_mint,
+        test_source_token,
+        test_escrow,
+        voucher,
+    }
+}
+
+#[tokio::test]
+async fn test_success() {
+    let Setup {
+        test,
+        test_mint,
+        test_source_token,
+        test_escrow,
+        voucher,
+    } = setup();
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            sign_voucher(&test_source_token.owner, &voucher),
+            fund_job_with_voucher(
+                rndr::id(),
+                test_mint.pubkey,
+                payer.pubkey(),
+                test_source_token.pubkey,
+                &voucher,
+            ),
+        ],
+        Some(&payer.pubkey()),
+    );
+    // Only the relayer signs the transaction
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_source_token.pubkey).await,
+        AMOUNT
+    );
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_escrow.associated_token).await,
+        AMOUNT
+    );
+
+    let (job_pubkey, _bump_seed) = find_job_address(test_escrow.pubkey, voucher.authority, JOB_ID);
+    let job = get_job(&mut banks_client, job_pubkey).await;
+
+    assert_eq!(job.authority, voucher.authority);
+    assert_eq!(job.id, JOB_ID);
+    assert_eq!(job.amount, AMOUNT);
+
+    let (voucher_nonce_pubkey, _bump_seed) =
+        find_voucher_nonce_address(test_escrow.pubkey, voucher.authority);
+    let voucher_nonce = get_voucher_nonce(&mut banks_client, voucher_nonce_pubkey).await;
+
+    assert_eq!(voucher_nonce.account_type, AccountType::VoucherNonceV1);
+    assert_eq!(voucher_nonce.escrow, test_escrow.pubkey);
+    assert_eq!(voucher_nonce.authority, voucher.authority);
+    assert_eq!(voucher_nonce.nonce, 1);
+}
+
+#[tokio::test]
+async fn test_replayed() {
+    let Setup {
+        test,
+        test_mint,
+        test_source_token,
+        test_escrow,
+        voucher,
+    } = setup();
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let instructions = [
+        sign_voucher(&test_source_token.owner, &voucher),
+        fund_job_with_voucher(
+            rndr::id(),
+            test_mint.pubkey,
+            payer.pubkey(),
+            test_source_token.pubkey,
+            &voucher,
+        ),
+    ];
+
+    let mut transaction = Transaction::new_with_payer(&instructions, Some(&payer.pubkey()));
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let recent_blockhash = banks_client
+        .get_new_latest_blockhash(&recent_blockhash)
+        .await
+        .unwrap();
+
+    let mut transaction = Transaction::new_with_payer(&instructions, Some(&payer.pubkey()));
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_escrow.associated_token).await,
+        AMOUNT
+    );
+}
+
+#[tokio::test]
+async fn test_expired() {
+    let Setup {
+        test,
+        test_mint,
+        test_source_token,
+        test_escrow,
+        voucher,
+    } = setup();
+
+    let voucher = Voucher {
+        expires_at: 0,
+        ..voucher
+    };
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            sign_voucher(&test_source_token.owner, &voucher),
+            fund_job_with_voucher(
+                rndr::id(),
+                test_mint.pubkey,
+                payer.pubkey(),
+                test_source_token.pubkey,
+                &voucher,
+            ),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_escrow.associated_token).await,
+        ZERO
+    );
+}
+
+#[tokio::test]
+async fn test_wrong_signer() {
+    let Setup {
+        test,
+        test_mint,
+        test_source_token,
+        test_escrow,
+        voucher,
+    } = setup();
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            sign_voucher(&Keypair::new(), &voucher),
+            fund_job_with_voucher(
+                rndr::id(),
+                test_mint.pubkey,
+                payer.pubkey(),
+                test_source_token.pubkey,
+                &voucher,
+            ),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_escrow.associated_token).await,
+        ZERO
+    );
+}
+
+#[tokio::test]
+async fn test_missing_signature() {
+    let Setup {
+        test,
+        test_mint,
+        test_source_token,
+        test_escrow,
+        voucher,
+    } = setup();
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[fund_job_with_voucher(
+            rndr::id(),
+            test_mint.pubkey,
+            payer.pubkey(),
+            test_source_token.pubkey,
+            &voucher,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_escrow.associated_token).await,
+        ZERO
+    );
+}
+
+#[tokio::test]
+async fn test_source_token_not_owned_by_authority() {
+    let Setup {
+        test,
+        test_mint,
+        test_source_token,
+        test_escrow,
+        voucher,
+    } = setup();
+
+    // A voucher of another authority can't spend the tokens the owner delegated to the Escrow
+    let authority = Keypair::new();
+    let voucher = Voucher {
+        authority: authority.pubkey(),
+        ..voucher
+    };
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            sign_voucher(&authority, &voucher),
+            fund_job_with_voucher(
+                rndr::id(),
+                test_mint.pubkey,
+                payer.pubkey(),
+                test_source_token.pubkey,
+                &voucher,
+            ),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_escrow.associated_token).await,
+        ZERO
+    );
+}
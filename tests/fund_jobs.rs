@@ -0,0 +1,71 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{instruction::fund_jobs, processor::process_instruction, test_fixtures::*},
+    solana_program_test::*,
+    solana_sdk::{signature::Signer, transaction::Transaction},
+    std::collections::HashSet,
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 3 * DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_source_token = TestToken::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let authority = test_source_token.owner.pubkey();
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let jobs = [(1, DECIMALS), (2, 2 * DECIMALS)];
+
+    let mut transaction = Transaction::new_with_payer(
+        &[fund_jobs(
+            rndr::id(),
+            &jobs,
+            test_mint.pubkey,
+            payer.pubkey(),
+            test_source_token.pubkey,
+            authority,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_source_token.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let source_token_balance_after =
+        get_token_balance(&mut banks_client, test_source_token.pubkey).await;
+    let escrow_balance_after =
+        get_token_balance(&mut banks_client, test_escrow.associated_token).await;
+
+    assert_eq!(source_token_balance_after, ZERO);
+    assert_eq!(escrow_balance_after, AMOUNT);
+
+    let mut escrow_shard_pubkeys = HashSet::new();
+    for (job_id, amount) in jobs {
+        let (job_pubkey, _bump_seed) = find_job_address(test_escrow.pubkey, authority, job_id);
+        let job = get_job(&mut banks_client, job_pubkey).await;
+
+        assert_eq!(job.authority, authority);
+        assert_eq!(job.id, job_id);
+        assert_eq!(job.amount, amount);
+
+        let (escrow_shard_pubkey, _bump_seed) =
+            find_escrow_shard_address(test_escrow.pubkey, job_pubkey);
+        escrow_shard_pubkeys.insert(escrow_shard_pubkey);
+    }
+
+    let mut escrow_shard_amount = ZERO;
+    for escrow_shard_pubkey in escrow_shard_pubkeys {
+        escrow_shard_amount += get_escrow_shard(&mut banks_client, escrow_shard_pubkey)
+            .await
+            .amount;
+    }
+    assert_eq!(escrow_shard_amount, AMOUNT);
+}
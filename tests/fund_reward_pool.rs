@@ -0,0 +1,115 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{
+        instruction::fund_reward_pool, processor::process_instruction, state::AccountType,
+        test_fixtures::*,
+    },
+    solana_program_test::*,
+    solana_sdk::{signature::Signer, transaction::Transaction},
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+    const EPOCH: u64 = 0;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_source_token = TestToken::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let authority = test_source_token.owner.pubkey();
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[fund_reward_pool(
+            rndr::id(),
+            AMOUNT,
+            EPOCH,
+            test_mint.pubkey,
+            payer.pubkey(),
+            test_source_token.pubkey,
+            authority,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_source_token.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let (reward_pool_pubkey, _bump_seed) = find_reward_pool_address(test_escrow.pubkey, EPOCH);
+    let reward_pool_associated_token = spl_associated_token_account::get_associated_token_address(
+        &reward_pool_pubkey,
+        &test_mint.pubkey,
+    );
+
+    let source_token_balance_after =
+        get_token_balance(&mut banks_client, test_source_token.pubkey).await;
+    let reward_pool_balance_after =
+        get_token_balance(&mut banks_client, reward_pool_associated_token).await;
+
+    assert_eq!(source_token_balance_after, ZERO);
+    assert_eq!(reward_pool_balance_after, AMOUNT);
+
+    let reward_pool = get_reward_pool(&mut banks_client, reward_pool_pubkey).await;
+    assert_eq!(reward_pool.account_type, AccountType::RewardPoolV1);
+    assert_eq!(reward_pool.escrow, test_escrow.pubkey);
+    assert_eq!(reward_pool.epoch, EPOCH);
+    assert_eq!(reward_pool.amount, AMOUNT);
+    assert_eq!(reward_pool.total_work, ZERO);
+    assert_eq!(reward_pool.claimed, ZERO);
+}
+
+#[tokio::test]
+async fn test_epoch_ended() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+    const EPOCH: u64 = 0;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_source_token = TestToken::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    TestRewardPool::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_mint.pubkey,
+        EPOCH,
+        ZERO,
+        ZERO,
+    );
+    let authority = test_source_token.owner.pubkey();
+
+    let mut context = test.start_with_context().await;
+    let first_normal_slot = context.genesis_config().epoch_schedule.first_normal_slot;
+    context.warp_to_slot(first_normal_slot + 1).unwrap();
+
+    let mut transaction = Transaction::new_with_payer(
+        &[fund_reward_pool(
+            rndr::id(),
+            AMOUNT,
+            EPOCH,
+            test_mint.pubkey,
+            context.payer.pubkey(),
+            test_source_token.pubkey,
+            authority,
+        )],
+        Some(&context.payer.pubkey()),
+    );
+    transaction.sign(
+        &[&context.payer, &test_source_token.owner],
+        context.last_blockhash,
+    );
+
+    assert!(context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .is_err());
+}
@@ -0,0 +1,70 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{
+        instruction::get_escrow_summary, processor::process_instruction, state::IncidentMode,
+        summary::EscrowSummary, test_fixtures::*,
+    },
+    solana_program_test::*,
+    solana_sdk::{pubkey::Pubkey, signature::Signer, transaction::Transaction},
+};
+
+const DECIMALS: u64 = 1_000_000_000;
+const AMOUNT: u64 = 1 * DECIMALS;
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[get_escrow_summary(rndr::id(), test_mint.pubkey)],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    let simulation = banks_client
+        .simulate_transaction(transaction)
+        .await
+        .unwrap();
+    assert!(simulation.result.unwrap().is_ok());
+    let return_data = simulation.simulation_details.unwrap().return_data.unwrap();
+    assert_eq!(return_data.program_id, rndr::id());
+
+    let summary = EscrowSummary::unpack(&return_data.data).unwrap();
+    assert_eq!(
+        summary,
+        EscrowSummary {
+            token_mint: test_mint.pubkey,
+            owner: test_escrow.owner.pubkey(),
+            token_account: test_escrow.associated_token,
+            amount: AMOUNT,
+            escrow_id: 0,
+            incident_mode: IncidentMode::Normal,
+            mint_migrations: 0,
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_invalid_escrow() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    // No escrow has been created for the token mint
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    TestEscrow::add(&mut test, Pubkey::new_unique(), AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[get_escrow_summary(rndr::id(), test_mint.pubkey)],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
@@ -0,0 +1,75 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{instruction::get_escrowed_amount, processor::process_instruction, test_fixtures::*},
+    solana_program::instruction::AccountMeta,
+    solana_program_test::*,
+    solana_sdk::{pubkey::Pubkey, signature::Signer, transaction::Transaction},
+};
+
+const DECIMALS: u64 = 1_000_000_000;
+const AMOUNT: u64 = 1 * DECIMALS;
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, 3 * AMOUNT);
+    let authority = Pubkey::new_unique();
+    TestJob::add(&mut test, test_escrow.pubkey, authority, AMOUNT);
+    // Tokens of isolated jobs are held in their own token accounts, but still count
+    let isolated_authority = Pubkey::new_unique();
+    TestJob::add_isolated(
+        &mut test,
+        test_escrow.pubkey,
+        test_mint.pubkey,
+        isolated_authority,
+        2 * AMOUNT,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    for (authority, amount) in [(authority, AMOUNT), (isolated_authority, 2 * AMOUNT)] {
+        let mut transaction = Transaction::new_with_payer(
+            &[get_escrowed_amount(
+                rndr::id(),
+                test_mint.pubkey,
+                authority,
+                0,
+            )],
+            Some(&payer.pubkey()),
+        );
+        transaction.sign(&[&payer], recent_blockhash);
+
+        let simulation = banks_client
+            .simulate_transaction(transaction)
+            .await
+            .unwrap();
+        assert!(simulation.result.unwrap().is_ok());
+        let return_data = simulation.simulation_details.unwrap().return_data.unwrap();
+        assert_eq!(return_data.program_id, rndr::id());
+        assert_eq!(return_data.data, u64::to_le_bytes(amount));
+    }
+}
+
+#[tokio::test]
+async fn test_invalid_job() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let authority = Pubkey::new_unique();
+    TestJob::add(&mut test, test_escrow.pubkey, authority, AMOUNT);
+    let test_token = TestToken::add(&mut test, test_mint.pubkey, AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // A token account holding the same amount can't pass as a job
+    let mut instruction = get_escrowed_amount(rndr::id(), test_mint.pubkey, authority, 0);
+    instruction.accounts[1] = AccountMeta::new_readonly(test_token.pubkey, false);
+    let mut transaction = Transaction::new_with_payer(&[instruction], Some(&payer.pubkey()));
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
@@ -0,0 +1,69 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{
+        instruction::get_job_summary, processor::process_instruction, state::JobStatus,
+        summary::JobSummary, test_fixtures::*,
+    },
+    solana_program::instruction::AccountMeta,
+    solana_program_test::*,
+    solana_sdk::{pubkey::Pubkey, signature::Signer, transaction::Transaction},
+};
+
+const DECIMALS: u64 = 1_000_000_000;
+const AMOUNT: u64 = 1 * DECIMALS;
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let authority = Pubkey::new_unique();
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, authority, AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[get_job_summary(rndr::id(), test_mint.pubkey, authority, 0)],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    let simulation = banks_client
+        .simulate_transaction(transaction)
+        .await
+        .unwrap();
+    assert!(simulation.result.unwrap().is_ok());
+    let return_data = simulation.simulation_details.unwrap().return_data.unwrap();
+    assert_eq!(return_data.program_id, rndr::id());
+
+    let summary = JobSummary::unpack(&return_data.data).unwrap();
+    let job = get_job(&mut banks_client, test_job.pubkey).await;
+    assert_eq!(summary, JobSummary::new(&job));
+    assert_eq!(summary.authority, authority);
+    assert_eq!(summary.amount, AMOUNT);
+    assert_eq!(summary.status, JobStatus::Open);
+}
+
+#[tokio::test]
+async fn test_invalid_escrow() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let other_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let other_escrow = TestEscrow::add(&mut test, other_mint.pubkey, AMOUNT);
+    let authority = Pubkey::new_unique();
+    TestJob::add(&mut test, test_escrow.pubkey, authority, AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // The job isn't a PDA of another escrow
+    let mut instruction = get_job_summary(rndr::id(), test_mint.pubkey, authority, 0);
+    instruction.accounts[0] = AccountMeta::new_readonly(other_escrow.pubkey, false);
+    let mut transaction = Transaction::new_with_payer(&[instruction], Some(&payer.pubkey()));
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
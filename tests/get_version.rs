@@ -0,0 +1,39 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{
+        instruction::get_version,
+        processor::process_instruction,
+        state::LAYOUT_VERSION,
+        version::{ProgramVersion, VERSION},
+    },
+    solana_program_test::*,
+    solana_sdk::{signature::Signer, transaction::Transaction},
+};
+
+#[tokio::test]
+async fn test_success() {
+    let test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction =
+        Transaction::new_with_payer(&[get_version(rndr::id())], Some(&payer.pubkey()));
+    transaction.sign(&[&payer], recent_blockhash);
+
+    let simulation = banks_client
+        .simulate_transaction(transaction)
+        .await
+        .unwrap();
+    assert!(simulation.result.unwrap().is_ok());
+    let return_data = simulation.simulation_details.unwrap().return_data.unwrap();
+    assert_eq!(return_data.program_id, rndr::id());
+
+    let version = ProgramVersion::unpack(&return_data.data).unwrap();
+    assert_eq!(version, VERSION);
+    assert_eq!(
+        format!("{}.{}.{}", version.major, version.minor, version.patch),
+        env!("CARGO_PKG_VERSION")
+    );
+    assert_eq!(version.layout_version, LAYOUT_VERSION);
+}
@@ -0,0 +1,105 @@
+#![cfg(feature = "serde")]
+
+use {
+    rndr::{
+        instruction::RNDRInstruction,
+        state::{
+            ClaimBitmap, Config, CrankVault, DelegateAllowance, Dispute, EmissionSchedule,
+            EpochWork, Escrow, EscrowShard, Job, JobBundle, JobIndex, JobIndexPage, JobQueue,
+            JobTree, MerkleDistribution, MintMigration, Node, Proposal, QueuedAction, Recovery,
+            RewardPool, SessionKey, StakePool, Stats, Subscription, Treasury, VerifierRegistry,
+            VerifierSelection, VoucherNonce, LAYOUT_VERSION,
+        },
+    },
+    serde::de::DeserializeOwned,
+    serde_json::Value,
+    solana_program::program_pack::Pack,
+    std::fmt::Debug,
+};
+
+/// Golden vectors of the packed instructions or accounts, which pin the wire format so it doesn't
+/// shift between releases. A vector only changes when fields are appended to its instruction or
+/// account, keeping its old data as a prefix, or when `LAYOUT_VERSION` is bumped.
+fn golden(json: &str) -> Value {
+    let golden: Value = serde_json::from_str(json).unwrap();
+    assert_eq!(golden["layout_version"], u64::from(LAYOUT_VERSION));
+    golden
+}
+
+fn hex(data: &[u8]) -> String {
+    data.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+fn unhex(hex: &str) -> Vec<u8> {
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+#[test]
+fn test_instructions() {
+    let golden = golden(include_str!("golden/instructions.json"));
+    let vectors = golden["instructions"].as_array().unwrap();
+
+    // Every instruction has a vector, in the order of their tags
+    assert_eq!(vectors.len(), 98);
+    for (tag, vector) in vectors.iter().enumerate() {
+        let instruction: RNDRInstruction =
+            serde_json::from_value(vector["instruction"].clone()).unwrap();
+        let data = unhex(vector["data"].as_str().unwrap());
+
+        assert_eq!(data[0] as usize, tag);
+        assert_eq!(hex(&instruction.pack()), hex(&data), "{:?}", instruction);
+        assert_eq!(RNDRInstruction::unpack(&data).unwrap(), instruction);
+    }
+}
+
+fn check_account<T: Pack + DeserializeOwned + PartialEq + Debug>(golden: &Value, name: &str) {
+    let vector = &golden["accounts"][name];
+    let account: T = serde_json::from_value(vector["account"].clone()).unwrap();
+    let data = unhex(vector["data"].as_str().unwrap());
+
+    let mut packed = vec![0; T::LEN];
+    account.pack_into_slice(&mut packed);
+    assert_eq!(hex(&packed), hex(&data), "{}", name);
+    assert_eq!(T::unpack_from_slice(&data).unwrap(), account, "{}", name);
+}
+
+#[test]
+fn test_accounts() {
+    let golden = golden(include_str!("golden/accounts.json"));
+
+    // Every account type has a vector
+    assert_eq!(golden["accounts"].as_object().unwrap().len(), 30);
+    check_account::<ClaimBitmap>(&golden, "ClaimBitmap");
+    check_account::<Config>(&golden, "Config");
+    check_account::<CrankVault>(&golden, "CrankVault");
+    check_account::<DelegateAllowance>(&golden, "DelegateAllowance");
+    check_account::<Dispute>(&golden, "Dispute");
+    check_account::<EmissionSchedule>(&golden, "EmissionSchedule");
+    check_account::<EpochWork>(&golden, "EpochWork");
+    check_account::<Escrow>(&golden, "Escrow");
+    check_account::<EscrowShard>(&golden, "EscrowShard");
+    check_account::<Job>(&golden, "Job");
+    check_account::<JobBundle>(&golden, "JobBundle");
+    check_account::<JobIndex>(&golden, "JobIndex");
+    check_account::<JobIndexPage>(&golden, "JobIndexPage");
+    check_account::<JobQueue>(&golden, "JobQueue");
+    check_account::<JobTree>(&golden, "JobTree");
+    check_account::<MerkleDistribution>(&golden, "MerkleDistribution");
+    check_account::<MintMigration>(&golden, "MintMigration");
+    check_account::<Node>(&golden, "Node");
+    check_account::<Proposal>(&golden, "Proposal");
+    check_account::<QueuedAction>(&golden, "QueuedAction");
+    check_account::<Recovery>(&golden, "Recovery");
+    check_account::<RewardPool>(&golden, "RewardPool");
+    check_account::<SessionKey>(&golden, "SessionKey");
+    check_account::<StakePool>(&golden, "StakePool");
+    check_account::<Stats>(&golden, "Stats");
+    check_account::<Subscription>(&golden, "Subscription");
+    check_account::<Treasury>(&golden, "Treasury");
+    check_account::<VerifierRegistry>(&golden, "VerifierRegistry");
+    check_account::<VerifierSelection>(&golden, "VerifierSelection");
+    check_account::<VoucherNonce>(&golden, "VoucherNonce");
+}
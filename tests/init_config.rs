@@ -0,0 +1,96 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{
+        instruction::init_config,
+        processor::process_instruction,
+        state::{
+            IncidentMode, DEFAULT_DISPUTE_WINDOW, DEFAULT_FEE_BPS, DEFAULT_MAX_PRICE_AGE,
+            DEFAULT_MAX_PRICE_CONFIDENCE_BPS, DEFAULT_MIN_DISBURSEMENT, DEFAULT_ROUNDING_POLICY,
+            DEFAULT_SWEEP_DELAY, DEFAULT_TIMELOCKED_FIELDS, DEFAULT_TIMELOCK_DURATION,
+            MAX_PRICE_TIERS,
+        },
+        test_fixtures::*,
+    },
+    solana_program::pubkey::Pubkey,
+    solana_program_test::*,
+    solana_sdk::{
+        signature::{Keypair, Signer},
+        transaction::Transaction,
+    },
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const GOVERNANCE: Pubkey = Pubkey::new_from_array([7; 32]);
+
+    let upgrade_authority = Keypair::new();
+    add_program_data(&mut test, Some(upgrade_authority.pubkey()));
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // Only the program's upgrade authority can create the config
+    let impostor = Keypair::new();
+    let mut transaction = Transaction::new_with_payer(
+        &[init_config(
+            rndr::id(),
+            payer.pubkey(),
+            impostor.pubkey(),
+            GOVERNANCE,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &impostor], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let mut transaction = Transaction::new_with_payer(
+        &[init_config(
+            rndr::id(),
+            payer.pubkey(),
+            upgrade_authority.pubkey(),
+            GOVERNANCE,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &upgrade_authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let config = get_config(&mut banks_client).await;
+    assert_eq!(config.governance, GOVERNANCE);
+    assert_eq!(config.fee_bps, DEFAULT_FEE_BPS);
+    assert_eq!(config.dispute_window, DEFAULT_DISPUTE_WINDOW);
+    assert_eq!(config.timelock_duration, DEFAULT_TIMELOCK_DURATION);
+    assert_eq!(config.timelocked_fields, DEFAULT_TIMELOCKED_FIELDS);
+    assert_eq!(config.rounding_policy, DEFAULT_ROUNDING_POLICY);
+    assert_eq!(config.min_disbursement, DEFAULT_MIN_DISBURSEMENT);
+    assert_eq!(config.obh_prices, [0; MAX_PRICE_TIERS]);
+    assert_eq!(config.max_price_age, DEFAULT_MAX_PRICE_AGE);
+    assert_eq!(
+        config.max_price_confidence_bps,
+        DEFAULT_MAX_PRICE_CONFIDENCE_BPS
+    );
+    assert_eq!(config.compliance(), None);
+    assert_eq!(config.sweep_delay, DEFAULT_SWEEP_DELAY);
+    assert_eq!(config.incident_mode, IncidentMode::Normal);
+
+    // The config can only be created once
+    let mut transaction = Transaction::new_with_payer(
+        &[init_config(
+            rndr::id(),
+            payer.pubkey(),
+            upgrade_authority.pubkey(),
+            Pubkey::new_unique(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &upgrade_authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let config = get_config(&mut banks_client).await;
+    assert_eq!(config.governance, GOVERNANCE);
+}
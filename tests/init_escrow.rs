@@ -1,11 +1,8 @@
 #![cfg(feature = "test-bpf")]
 
-mod helpers;
-
 use rndr::state::AccountType;
 use {
-    helpers::*,
-    rndr::{instruction::init_escrow, processor::process_instruction},
+    rndr::{instruction::init_escrow, processor::process_instruction, test_fixtures::*},
     solana_program_test::*,
     solana_sdk::{
         pubkey::Pubkey,
@@ -51,5 +48,6 @@ async fn test_success() {
 
     assert_eq!(escrow.account_type, AccountType::EscrowV1);
     assert_eq!(escrow.owner, owner.pubkey());
+    assert_eq!(escrow.token_mint, test_mint.pubkey);
     assert_eq!(escrow.amount, ZERO);
 }
@@ -0,0 +1,176 @@
+#![cfg(feature = "test-bpf")]
+
+use rndr::state::AccountType;
+use {
+    rndr::{
+        instruction::{disburse_funds, fund_job, init_escrow, init_escrow_with_seed},
+        pda::{find_escrow_address, find_escrow_address_with_id, EscrowSeeds},
+        processor::process_instruction,
+        test_fixtures::*,
+    },
+    solana_program_test::*,
+    solana_sdk::{
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        transaction::Transaction,
+    },
+    spl_associated_token_account::get_associated_token_address,
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ESCROW_ID: u64 = 1;
+    const DECIMALS: u64 = 1_000_000_000;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let owner = Keypair::new();
+    let staging_owner = Keypair::new();
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            init_escrow(rndr::id(), owner.pubkey(), test_mint.pubkey, payer.pubkey()),
+            init_escrow_with_seed(
+                rndr::id(),
+                staging_owner.pubkey(),
+                test_mint.pubkey,
+                payer.pubkey(),
+                ESCROW_ID,
+            ),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let (escrow_pubkey, _bump_seed) = Pubkey::find_program_address(
+        &[
+            b"escrow",
+            test_mint.pubkey.as_ref(),
+            spl_token::id().as_ref(),
+            &ESCROW_ID.to_le_bytes(),
+        ],
+        &rndr::id(),
+    );
+    assert_eq!(
+        find_escrow_address_with_id(&rndr::id(), &test_mint.pubkey, ESCROW_ID).0,
+        escrow_pubkey
+    );
+    let escrow = get_escrow(&mut banks_client, escrow_pubkey).await;
+
+    assert_eq!(escrow.account_type, AccountType::EscrowV1);
+    assert_eq!(escrow.owner, staging_owner.pubkey());
+    assert_eq!(escrow.token_mint, test_mint.pubkey);
+    assert_eq!(escrow.escrow_id, ESCROW_ID);
+
+    // The default escrow of the token mint keeps its address and coexists with the namespaced one
+    let (default_escrow_pubkey, _bump_seed) = find_escrow_address(&rndr::id(), &test_mint.pubkey);
+    assert_ne!(default_escrow_pubkey, escrow_pubkey);
+    let default_escrow = get_escrow(&mut banks_client, default_escrow_pubkey).await;
+
+    assert_eq!(default_escrow.owner, owner.pubkey());
+    assert_eq!(default_escrow.escrow_id, 0);
+}
+
+#[tokio::test]
+async fn test_jobs_are_namespaced() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const ESCROW_ID: u64 = 7;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+    const JOB_ID: u64 = 1;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_source_token = TestToken::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let owner = Keypair::new();
+    let authority = test_source_token.owner.pubkey();
+    let seeds = EscrowSeeds {
+        token_mint: test_mint.pubkey,
+        escrow_id: ESCROW_ID,
+    };
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            init_escrow(rndr::id(), owner.pubkey(), test_mint.pubkey, payer.pubkey()),
+            init_escrow_with_seed(
+                rndr::id(),
+                owner.pubkey(),
+                test_mint.pubkey,
+                payer.pubkey(),
+                ESCROW_ID,
+            ),
+            fund_job(
+                rndr::id(),
+                AMOUNT,
+                JOB_ID,
+                seeds,
+                payer.pubkey(),
+                test_source_token.pubkey,
+                authority,
+            ),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_source_token.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let (escrow_pubkey, _bump_seed) =
+        find_escrow_address_with_id(&rndr::id(), &test_mint.pubkey, ESCROW_ID);
+    let (default_escrow_pubkey, _bump_seed) = find_escrow_address(&rndr::id(), &test_mint.pubkey);
+
+    // The job is funded in the namespaced escrow only
+    let escrow_balance = get_token_balance(
+        &mut banks_client,
+        get_associated_token_address(&escrow_pubkey, &test_mint.pubkey),
+    )
+    .await;
+    let default_escrow_balance = get_token_balance(
+        &mut banks_client,
+        get_associated_token_address(&default_escrow_pubkey, &test_mint.pubkey),
+    )
+    .await;
+
+    assert_eq!(escrow_balance, AMOUNT);
+    assert_eq!(default_escrow_balance, ZERO);
+
+    let (job_pubkey, _bump_seed) = find_job_address(escrow_pubkey, authority, JOB_ID);
+    let job = get_job(&mut banks_client, job_pubkey).await;
+    assert_eq!(job.amount, AMOUNT);
+
+    let (default_job_pubkey, _bump_seed) =
+        find_job_address(default_escrow_pubkey, authority, JOB_ID);
+    assert!(banks_client
+        .get_account(default_job_pubkey)
+        .await
+        .unwrap()
+        .is_none());
+
+    // Funds of the job can't be disbursed from the default escrow
+    let mut transaction = Transaction::new_with_payer(
+        &[disburse_funds(
+            rndr::id(),
+            AMOUNT,
+            test_mint.pubkey,
+            test_destination_token.pubkey,
+            job_pubkey,
+            owner.pubkey(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let job = get_job(&mut banks_client, job_pubkey).await;
+    assert_eq!(job.amount, AMOUNT);
+}
@@ -0,0 +1,52 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{
+        instruction::init_job_index, processor::process_instruction, state::AccountType,
+        test_fixtures::*,
+    },
+    solana_program_test::*,
+    solana_sdk::{signature::Signer, transaction::Transaction},
+};
+
+const ZERO: u64 = 0;
+const DECIMALS: u64 = 1_000_000_000;
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[init_job_index(rndr::id(), test_mint.pubkey, payer.pubkey())],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let (job_index_pubkey, _bump_seed) = find_job_index_address(test_escrow.pubkey, payer.pubkey());
+    let job_index = get_job_index(&mut banks_client, job_index_pubkey).await;
+
+    assert_eq!(job_index.account_type, AccountType::JobIndexV1);
+    assert_eq!(job_index.escrow, test_escrow.pubkey);
+    assert_eq!(job_index.authority, payer.pubkey());
+    assert_eq!(job_index.job_count, ZERO);
+
+    // The index of an authority can't be created again
+    let recent_blockhash = banks_client
+        .get_new_latest_blockhash(&recent_blockhash)
+        .await
+        .unwrap();
+    let mut transaction = Transaction::new_with_payer(
+        &[init_job_index(rndr::id(), test_mint.pubkey, payer.pubkey())],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
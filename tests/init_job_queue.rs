@@ -0,0 +1,52 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{
+        instruction::init_job_queue, processor::process_instruction, state::AccountType,
+        test_fixtures::*,
+    },
+    solana_program_test::*,
+    solana_sdk::{signature::Signer, transaction::Transaction},
+};
+
+const ZERO: u64 = 0;
+const DECIMALS: u64 = 1_000_000_000;
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[init_job_queue(rndr::id(), test_mint.pubkey, payer.pubkey())],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let (job_queue_pubkey, _bump_seed) = find_job_queue_address(test_escrow.pubkey);
+    let job_queue = get_job_queue(&mut banks_client, job_queue_pubkey).await;
+
+    assert_eq!(job_queue.account_type, AccountType::JobQueueV1);
+    assert_eq!(job_queue.escrow, test_escrow.pubkey);
+    assert_eq!(job_queue.head, 0);
+    assert!(job_queue.jobs.is_empty());
+
+    // The queue of an escrow can't be created again
+    let recent_blockhash = banks_client
+        .get_new_latest_blockhash(&recent_blockhash)
+        .await
+        .unwrap();
+    let mut transaction = Transaction::new_with_payer(
+        &[init_job_queue(rndr::id(), test_mint.pubkey, payer.pubkey())],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
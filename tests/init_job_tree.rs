@@ -0,0 +1,112 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{
+        instruction::init_job_tree,
+        processor::process_instruction,
+        state::{AccountType, MAX_JOB_TREE_DEPTH},
+        test_fixtures::*,
+    },
+    solana_program_test::*,
+    solana_sdk::{signature::Signer, transaction::Transaction},
+};
+
+const ZERO: u64 = 0;
+const DECIMALS: u64 = 1_000_000_000;
+const MAX_DEPTH: u8 = 14;
+const MAX_BUFFER_SIZE: u32 = 64;
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+    add_account_compression(&mut test);
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let merkle_tree = add_merkle_tree(&mut test, MAX_DEPTH as u32, MAX_BUFFER_SIZE);
+    let other_merkle_tree = add_merkle_tree(&mut test, MAX_DEPTH as u32, MAX_BUFFER_SIZE);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[init_job_tree(
+            rndr::id(),
+            test_mint.pubkey,
+            payer.pubkey(),
+            merkle_tree,
+            MAX_DEPTH,
+            MAX_BUFFER_SIZE,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let (job_tree_pubkey, _bump_seed) = find_job_tree_address(test_escrow.pubkey, payer.pubkey());
+    let job_tree = get_job_tree(&mut banks_client, job_tree_pubkey).await;
+
+    assert_eq!(job_tree.account_type, AccountType::JobTreeV1);
+    assert_eq!(job_tree.escrow, test_escrow.pubkey);
+    assert_eq!(job_tree.authority, payer.pubkey());
+    assert_eq!(job_tree.merkle_tree, merkle_tree);
+    assert_eq!(job_tree.max_depth, MAX_DEPTH);
+    assert_eq!(job_tree.leaf_count, ZERO);
+    assert_eq!(job_tree.amount, ZERO);
+
+    // The job tree is the authority of its empty merkle tree
+    let merkle_tree = get_merkle_tree(&mut banks_client, merkle_tree).await;
+    assert_eq!(merkle_tree.authority, job_tree_pubkey);
+    assert_eq!(merkle_tree.max_depth, MAX_DEPTH as u32);
+    assert_eq!(
+        merkle_tree.root,
+        StandInMerkleTree::empty_node(MAX_DEPTH as u32)
+    );
+
+    // The tree of an authority can't be created again, even with another merkle tree
+    let recent_blockhash = banks_client
+        .get_new_latest_blockhash(&recent_blockhash)
+        .await
+        .unwrap();
+    let mut transaction = Transaction::new_with_payer(
+        &[init_job_tree(
+            rndr::id(),
+            test_mint.pubkey,
+            payer.pubkey(),
+            other_merkle_tree,
+            MAX_DEPTH,
+            MAX_BUFFER_SIZE,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+#[tokio::test]
+async fn test_too_deep() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+    add_account_compression(&mut test);
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let merkle_tree = add_merkle_tree(&mut test, MAX_DEPTH as u32, MAX_BUFFER_SIZE);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[init_job_tree(
+            rndr::id(),
+            test_mint.pubkey,
+            payer.pubkey(),
+            merkle_tree,
+            MAX_JOB_TREE_DEPTH + 1,
+            MAX_BUFFER_SIZE,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
@@ -0,0 +1,62 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{
+        instruction::init_stats, processor::process_instruction, state::AccountType,
+        test_fixtures::*,
+    },
+    solana_program_test::*,
+    solana_sdk::{signature::Signer, transaction::Transaction},
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[init_stats(rndr::id(), test_escrow.pubkey, payer.pubkey())],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let (stats_pubkey, _bump_seed) = find_stats_address(test_escrow.pubkey);
+    let stats = get_stats(&mut banks_client, stats_pubkey).await;
+    assert_eq!(stats.account_type, AccountType::StatsV1);
+    assert_eq!(stats.escrow, test_escrow.pubkey);
+    assert_eq!(stats.total_escrowed, 0);
+    assert_eq!(stats.total_burned, 0);
+    assert_eq!(stats.total_emitted, 0);
+    assert_eq!(stats.total_disbursed, 0);
+}
+
+#[tokio::test]
+async fn test_already_exists() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    TestStats::add(&mut test, test_escrow.pubkey);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[init_stats(rndr::id(), test_escrow.pubkey, payer.pubkey())],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
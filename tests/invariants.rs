@@ -0,0 +1,201 @@
+use {
+    rndr::{
+        error::RNDRError,
+        invariants::{check_escrow_vault, check_isolated_job_vault, check_treasury_vault},
+        state::{AccountType, Escrow, EscrowShard, Job, Treasury},
+    },
+    solana_program::{
+        account_info::AccountInfo, program_error::ProgramError, program_pack::Pack, pubkey::Pubkey,
+    },
+    spl_token::state::{Account as TokenAccount, AccountState},
+};
+
+struct TestAccount {
+    key: Pubkey,
+    owner: Pubkey,
+    lamports: u64,
+    data: Vec<u8>,
+}
+
+impl TestAccount {
+    fn new(data: Vec<u8>) -> Self {
+        Self {
+            key: Pubkey::new_unique(),
+            owner: rndr::id(),
+            lamports: 0,
+            data,
+        }
+    }
+
+    fn escrow(amount: u64) -> Self {
+        let mut data = vec![0; Escrow::LEN];
+        let escrow = Escrow {
+            account_type: AccountType::EscrowV1,
+            amount,
+            ..Escrow::default()
+        };
+        Escrow::pack_account(escrow, &mut data).unwrap();
+        Self::new(data)
+    }
+
+    fn job(amount: u64, isolated: bool) -> Self {
+        let mut data = vec![0; Job::LEN];
+        let job = Job {
+            account_type: AccountType::JobV1,
+            amount,
+            isolated,
+            ..Job::default()
+        };
+        Job::pack_account(job, &mut data).unwrap();
+        Self::new(data)
+    }
+
+    fn escrow_shard(amount: u64) -> Self {
+        let mut data = vec![0; EscrowShard::LEN];
+        let escrow_shard = EscrowShard {
+            account_type: AccountType::EscrowShardV1,
+            amount,
+            ..EscrowShard::default()
+        };
+        EscrowShard::pack(escrow_shard, &mut data).unwrap();
+        Self::new(data)
+    }
+
+    fn treasury(sweep_amount: u64) -> Self {
+        let mut data = vec![0; Treasury::LEN];
+        let treasury = Treasury {
+            account_type: AccountType::TreasuryV1,
+            sweep_amount,
+            ..Treasury::default()
+        };
+        Treasury::pack_account(treasury, &mut data).unwrap();
+        Self::new(data)
+    }
+
+    fn token_account(amount: u64) -> Self {
+        let mut data = vec![0; TokenAccount::LEN];
+        let token_account = TokenAccount {
+            amount,
+            state: AccountState::Initialized,
+            ..TokenAccount::default()
+        };
+        TokenAccount::pack(token_account, &mut data).unwrap();
+        Self::new(data)
+    }
+
+    fn info(&mut self) -> AccountInfo<'_> {
+        AccountInfo::new(
+            &self.key,
+            false,
+            true,
+            &mut self.lamports,
+            &mut self.data,
+            &self.owner,
+            false,
+            0,
+        )
+    }
+}
+
+fn invariant_violation() -> Result<(), ProgramError> {
+    Err(RNDRError::InvariantViolation.into())
+}
+
+#[test]
+fn test_check_escrow_vault() {
+    let mut escrow = TestAccount::escrow(100);
+    let mut vault = TestAccount::token_account(100);
+    let mut job_1 = TestAccount::job(60, false);
+    let mut job_2 = TestAccount::job(40, false);
+    let mut isolated_job = TestAccount::job(50, true);
+    let (escrow_info, vault_info) = (escrow.info(), vault.info());
+    let (job_1_info, job_2_info, isolated_job_info) =
+        (job_1.info(), job_2.info(), isolated_job.info());
+
+    assert_eq!(
+        check_escrow_vault(
+            &escrow_info,
+            &vault_info,
+            &[&job_1_info, &job_2_info, &isolated_job_info],
+            &[],
+        ),
+        Ok(())
+    );
+    // A job provided twice is only counted once
+    assert_eq!(
+        check_escrow_vault(&escrow_info, &vault_info, &[&job_1_info, &job_1_info], &[]),
+        Ok(())
+    );
+
+    let mut short_vault = TestAccount::token_account(99);
+    assert_eq!(
+        check_escrow_vault(&escrow_info, &short_vault.info(), &[], &[]),
+        invariant_violation()
+    );
+
+    // Job amounts that add up to more than the vault holds
+    let mut job_3 = TestAccount::job(1, false);
+    assert_eq!(
+        check_escrow_vault(
+            &TestAccount::escrow(0).info(),
+            &vault_info,
+            &[&job_1_info, &job_2_info, &job_3.info()],
+            &[],
+        ),
+        invariant_violation()
+    );
+
+    // Tokens funded through escrow shards are held by the vault too, counted once per shard
+    let mut escrow_shard = TestAccount::escrow_shard(1);
+    let escrow_shard_info = escrow_shard.info();
+    assert_eq!(
+        check_escrow_vault(
+            &TestAccount::escrow(99).info(),
+            &vault_info,
+            &[],
+            &[&escrow_shard_info, &escrow_shard_info],
+        ),
+        Ok(())
+    );
+    assert_eq!(
+        check_escrow_vault(&escrow_info, &vault_info, &[], &[&escrow_shard_info]),
+        invariant_violation()
+    );
+}
+
+#[test]
+fn test_check_isolated_job_vault() {
+    let mut job = TestAccount::job(100, true);
+    let job_info = job.info();
+
+    assert_eq!(
+        check_isolated_job_vault(&job_info, &TestAccount::token_account(100).info()),
+        Ok(())
+    );
+    assert_eq!(
+        check_isolated_job_vault(&job_info, &TestAccount::token_account(99).info()),
+        invariant_violation()
+    );
+}
+
+#[test]
+fn test_check_treasury_vault() {
+    let mut treasury = TestAccount::treasury(100);
+    let treasury_info = treasury.info();
+
+    assert_eq!(
+        check_treasury_vault(&treasury_info, &TestAccount::token_account(100).info()),
+        Ok(())
+    );
+    assert_eq!(
+        check_treasury_vault(&treasury_info, &TestAccount::token_account(99).info()),
+        invariant_violation()
+    );
+    assert_eq!(
+        check_treasury_vault(
+            &TestAccount::treasury(0).info(),
+            &TestAccount::token_account(0).info()
+        ),
+        Ok(())
+    );
+}
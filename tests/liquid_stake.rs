@@ -0,0 +1,124 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{
+        instruction::liquid_stake, processor::process_instruction, state::AccountType,
+        test_fixtures::*,
+    },
+    solana_program_test::*,
+    solana_sdk::{pubkey::Pubkey, signature::Signer, transaction::Transaction},
+    spl_associated_token_account::get_associated_token_address,
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_source_token = TestToken::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let authority = test_source_token.owner.pubkey();
+    let receipt_owner = Pubkey::new_unique();
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[liquid_stake(
+            rndr::id(),
+            AMOUNT,
+            test_mint.pubkey,
+            payer.pubkey(),
+            test_source_token.pubkey,
+            authority,
+            receipt_owner,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_source_token.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let (stake_pool_pubkey, _bump_seed) = find_stake_pool_address(test_escrow.pubkey);
+    let (receipt_mint, _bump_seed) = find_stake_receipt_mint_address(stake_pool_pubkey);
+    let stake_pool_associated_token =
+        get_associated_token_address(&stake_pool_pubkey, &test_mint.pubkey);
+    let receipt_owner_associated_token =
+        get_associated_token_address(&receipt_owner, &receipt_mint);
+
+    let source_token_balance_after =
+        get_token_balance(&mut banks_client, test_source_token.pubkey).await;
+    let stake_pool_balance_after =
+        get_token_balance(&mut banks_client, stake_pool_associated_token).await;
+    let receipt_balance_after =
+        get_token_balance(&mut banks_client, receipt_owner_associated_token).await;
+
+    assert_eq!(source_token_balance_after, ZERO);
+    assert_eq!(stake_pool_balance_after, AMOUNT);
+    assert_eq!(receipt_balance_after, AMOUNT);
+
+    let receipt_mint = get_mint(&mut banks_client, receipt_mint).await;
+    assert_eq!(receipt_mint.supply, AMOUNT);
+    assert_eq!(receipt_mint.decimals, test_mint.decimals);
+
+    let stake_pool = get_stake_pool(&mut banks_client, stake_pool_pubkey).await;
+    assert_eq!(stake_pool.account_type, AccountType::StakePoolV1);
+    assert_eq!(stake_pool.escrow, test_escrow.pubkey);
+    assert_eq!(stake_pool.token_mint, test_mint.pubkey);
+    assert_eq!(stake_pool.total_staked, AMOUNT);
+    assert_eq!(stake_pool.receipt_supply, AMOUNT);
+}
+
+#[tokio::test]
+async fn test_success_exchange_rate() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_source_token = TestToken::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let authority = test_source_token.owner.pubkey();
+    let test_stake_pool = TestStakePool::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_mint.pubkey,
+        test_mint.decimals,
+        2 * AMOUNT,
+        AMOUNT,
+    );
+    let receipt_owner = Pubkey::new_unique();
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[liquid_stake(
+            rndr::id(),
+            AMOUNT,
+            test_mint.pubkey,
+            payer.pubkey(),
+            test_source_token.pubkey,
+            authority,
+            receipt_owner,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_source_token.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let receipt_owner_associated_token =
+        get_associated_token_address(&receipt_owner, &test_stake_pool.receipt_mint);
+    let receipt_balance_after =
+        get_token_balance(&mut banks_client, receipt_owner_associated_token).await;
+    assert_eq!(receipt_balance_after, AMOUNT / 2);
+
+    let stake_pool = test_stake_pool.get(&mut banks_client).await;
+    assert_eq!(stake_pool.total_staked, 3 * AMOUNT);
+    assert_eq!(stake_pool.receipt_supply, AMOUNT + AMOUNT / 2);
+}
@@ -0,0 +1,100 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{instruction::liquid_unstake, processor::process_instruction, test_fixtures::*},
+    solana_program_test::*,
+    solana_sdk::{signature::Signer, transaction::Transaction},
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let test_stake_pool = TestStakePool::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_mint.pubkey,
+        test_mint.decimals,
+        2 * AMOUNT,
+        AMOUNT,
+    );
+    let test_receipt_token = TestToken::add(&mut test, test_stake_pool.receipt_mint, AMOUNT);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[liquid_unstake(
+            rndr::id(),
+            AMOUNT / 2,
+            test_mint.pubkey,
+            test_receipt_token.pubkey,
+            test_receipt_token.owner.pubkey(),
+            test_destination_token.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_receipt_token.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let stake_pool_balance_after =
+        get_token_balance(&mut banks_client, test_stake_pool.associated_token).await;
+    let receipt_balance_after =
+        get_token_balance(&mut banks_client, test_receipt_token.pubkey).await;
+    let destination_token_balance_after =
+        get_token_balance(&mut banks_client, test_destination_token.pubkey).await;
+
+    assert_eq!(stake_pool_balance_after, AMOUNT);
+    assert_eq!(receipt_balance_after, AMOUNT / 2);
+    assert_eq!(destination_token_balance_after, AMOUNT);
+
+    let stake_pool = test_stake_pool.get(&mut banks_client).await;
+    assert_eq!(stake_pool.total_staked, AMOUNT);
+    assert_eq!(stake_pool.receipt_supply, AMOUNT / 2);
+}
+
+#[tokio::test]
+async fn test_insufficient_receipts() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let test_stake_pool = TestStakePool::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_mint.pubkey,
+        test_mint.decimals,
+        2 * AMOUNT,
+        2 * AMOUNT,
+    );
+    let test_receipt_token = TestToken::add(&mut test, test_stake_pool.receipt_mint, AMOUNT);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[liquid_unstake(
+            rndr::id(),
+            2 * AMOUNT,
+            test_mint.pubkey,
+            test_receipt_token.pubkey,
+            test_receipt_token.owner.pubkey(),
+            test_destination_token.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_receipt_token.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
@@ -0,0 +1,184 @@
+#![cfg(feature = "client")]
+
+use {
+    rndr::{
+        client::logs::decode_failure, error::RNDRError, instruction::disburse_funds,
+        pda::EscrowSeeds,
+    },
+    solana_sdk::{
+        compute_budget::ComputeBudgetInstruction,
+        instruction::InstructionError,
+        message::{AccountKeys, Message},
+        pubkey::Pubkey,
+        transaction::TransactionError,
+    },
+};
+
+fn logs(lines: &[&str]) -> Vec<String> {
+    lines.iter().map(|line| line.to_string()).collect()
+}
+
+fn disburse_message(job: Pubkey) -> Message {
+    let instructions = [
+        ComputeBudgetInstruction::set_compute_unit_limit(200_000),
+        disburse_funds(
+            rndr::id(),
+            1,
+            EscrowSeeds::from(Pubkey::new_unique()),
+            Pubkey::new_unique(),
+            job,
+            Pubkey::new_unique(),
+        ),
+    ];
+    Message::new(&instructions, Some(&Pubkey::new_unique()))
+}
+
+#[test]
+fn test_account_check() {
+    let program_id = rndr::id();
+    let error = TransactionError::InstructionError(1, InstructionError::Custom(1));
+    let logs = logs(&[
+        "Program ComputeBudget111111111111111111111111111111 invoke [1]",
+        "Program ComputeBudget111111111111111111111111111111 success",
+        &format!("Program {} invoke [1]", program_id),
+        "Program log: Instruction: DisburseFunds",
+        "Program log: Job provided is not owned by the RNDR program",
+        "Program log: UnspecifiedError",
+        &format!(
+            "Program {} consumed 3012 of 199850 compute units",
+            program_id
+        ),
+        &format!("Program {} failed: custom program error: 0x1", program_id),
+    ]);
+
+    let failed = decode_failure(&program_id, &error, &logs).unwrap();
+    assert_eq!(failed.index, 1);
+    assert_eq!(failed.name.as_deref(), Some("DisburseFunds"));
+    assert_eq!(failed.error, InstructionError::Custom(1));
+    assert_eq!(failed.program_error, Some(RNDRError::UnspecifiedError));
+    assert_eq!(
+        failed.cause(),
+        "Job provided is not owned by the RNDR program"
+    );
+    assert_eq!(failed.account_name.as_deref(), Some("jobAccount"));
+    assert_eq!(failed.account, None);
+
+    let job = Pubkey::new_unique();
+    let message = disburse_message(job);
+    let account_keys = AccountKeys::new(&message.account_keys, None);
+    let failed = failed.with_message(&message.instructions, &account_keys);
+    assert_eq!(failed.account_name.as_deref(), Some("jobAccount"));
+    assert_eq!(failed.account, Some(job));
+}
+
+#[test]
+fn test_mentioned_account() {
+    let program_id = rndr::id();
+    let job = Pubkey::new_unique();
+    let error = TransactionError::InstructionError(1, InstructionError::Custom(6));
+    let logs = logs(&[
+        "Program ComputeBudget111111111111111111111111111111 invoke [1]",
+        "Program ComputeBudget111111111111111111111111111111 success",
+        &format!("Program {} invoke [1]", program_id),
+        "Program log: Instruction: DisburseFunds",
+        &format!(
+            "Program log: Account {} is provided in more than one role",
+            job
+        ),
+        "Program log: DuplicateAccount",
+        &format!("Program {} failed: custom program error: 0x6", program_id),
+    ]);
+
+    let failed = decode_failure(&program_id, &error, &logs).unwrap();
+    assert_eq!(failed.program_error, Some(RNDRError::DuplicateAccount));
+    assert_eq!(failed.account, Some(job));
+    assert_eq!(failed.account_name, None);
+
+    let message = disburse_message(job);
+    let account_keys = AccountKeys::new(&message.account_keys, None);
+    let failed = failed.with_message(&message.instructions, &account_keys);
+    assert_eq!(failed.account_name.as_deref(), Some("jobAccount"));
+}
+
+#[test]
+fn test_truncated_logs() {
+    let program_id = rndr::id();
+    let error = TransactionError::InstructionError(1, InstructionError::Custom(1));
+    let logs = logs(&[
+        "Program ComputeBudget111111111111111111111111111111 invoke [1]",
+        "Program ComputeBudget111111111111111111111111111111 success",
+        &format!("Program {} invoke [1]", program_id),
+        "Log truncated",
+    ]);
+
+    let failed = decode_failure(&program_id, &error, &logs).unwrap();
+    assert_eq!(failed.name, None);
+    assert_eq!(failed.program_error, Some(RNDRError::UnspecifiedError));
+    assert_eq!(failed.cause(), "UnspecifiedError");
+
+    let message = disburse_message(Pubkey::new_unique());
+    let account_keys = AccountKeys::new(&message.account_keys, None);
+    let failed = failed.with_message(&message.instructions, &account_keys);
+    assert_eq!(failed.name.as_deref(), Some("DisburseFunds"));
+}
+
+#[test]
+fn test_truncated_logs_with_error() {
+    let program_id = rndr::id();
+    let error = TransactionError::InstructionError(
+        1,
+        InstructionError::Custom(RNDRError::JobDisputed as u32),
+    );
+    let logs = logs(&[
+        "Program ComputeBudget111111111111111111111111111111 invoke [1]",
+        "Program ComputeBudget111111111111111111111111111111 success",
+        &format!("Program {} invoke [1]", program_id),
+        "Log truncated",
+    ]);
+
+    // Errors of their own are described without the message the program logged
+    let failed = decode_failure(&program_id, &error, &logs).unwrap();
+    assert_eq!(failed.program_error, Some(RNDRError::JobDisputed));
+    assert_eq!(failed.message, None);
+    assert_eq!(failed.cause(), "Job is disputed");
+}
+
+#[test]
+fn test_cpi() {
+    let program_id = rndr::id();
+    let caller = Pubkey::new_unique();
+    let error = TransactionError::InstructionError(0, InstructionError::Custom(1));
+    let logs = logs(&[
+        &format!("Program {} invoke [1]", caller),
+        &format!("Program {} invoke [2]", program_id),
+        "Program log: Instruction: FundJob",
+        "Program log: Amount of tokens to fund can't be zero",
+        "Program log: UnspecifiedError",
+        &format!("Program {} failed: custom program error: 0x1", program_id),
+        &format!("Program {} failed: custom program error: 0x1", caller),
+    ]);
+
+    let failed = decode_failure(&program_id, &error, &logs).unwrap();
+    assert_eq!(failed.index, 0);
+    assert_eq!(failed.name.as_deref(), Some("FundJob"));
+    assert_eq!(failed.program_error, Some(RNDRError::UnspecifiedError));
+    assert_eq!(failed.cause(), "Amount of tokens to fund can't be zero");
+}
+
+#[test]
+fn test_other_program() {
+    let program_id = rndr::id();
+    let other = Pubkey::new_unique();
+    let error = TransactionError::InstructionError(1, InstructionError::Custom(1));
+    let logs = logs(&[
+        &format!("Program {} invoke [1]", program_id),
+        "Program log: Instruction: GetVersion",
+        &format!("Program {} success", program_id),
+        &format!("Program {} invoke [1]", other),
+        &format!("Program {} failed: custom program error: 0x1", other),
+    ]);
+    assert_eq!(decode_failure(&program_id, &error, &logs), None);
+
+    let error = TransactionError::BlockhashNotFound;
+    assert_eq!(decode_failure(&program_id, &error, &logs), None);
+}
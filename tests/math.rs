@@ -0,0 +1,331 @@
+use {
+    proptest::prelude::*,
+    rndr::{
+        error::RNDRError,
+        math::{Decimal, ExchangeRate, Rate, RoundingPolicy, Split, MAX_BPS, WAD},
+    },
+    solana_program::program_error::ProgramError,
+    std::convert::TryFrom,
+};
+
+#[test]
+fn test_decimal() {
+    let half = Decimal::from_bps(5_000);
+    assert_eq!(half.try_to_scaled_val().unwrap(), WAD / 2);
+    assert_eq!(Decimal::from(1), Decimal::one());
+    assert_eq!(Decimal::from(0), Decimal::zero());
+    assert_eq!(
+        Decimal::from(3).try_mul(half).unwrap().to_string(),
+        "1.500000000000000000"
+    );
+    assert_eq!(
+        Decimal::one().try_div(3).unwrap().to_string(),
+        "0.333333333333333333"
+    );
+    assert_eq!(
+        Decimal::from(u64::MAX)
+            .try_mul(u64::MAX)
+            .unwrap()
+            .to_string(),
+        "340282366920938463426481119284349108225.000000000000000000"
+    );
+
+    let error = ProgramError::from(RNDRError::MathError);
+    assert_eq!(Decimal::one().try_div(0), Err(error.clone()));
+    assert_eq!(Decimal::zero().try_sub(1), Err(error.clone()));
+    assert_eq!(
+        Decimal::from(u64::MAX).try_add(1).unwrap().try_floor_u64(),
+        Err(error)
+    );
+    assert!(Decimal::from(u64::MAX)
+        .try_mul(u64::MAX)
+        .unwrap()
+        .try_mul(u64::MAX)
+        .is_err());
+}
+
+#[test]
+fn test_decimal_rounding() {
+    let third = Decimal::from(10).try_div(3).unwrap();
+    assert_eq!(third.try_floor_u64(), Ok(3));
+    assert_eq!(third.try_ceil_u64(), Ok(4));
+    assert_eq!(third.try_round_u64(), Ok(3));
+
+    let half = Decimal::from(5).try_div(2).unwrap();
+    assert_eq!(half.try_floor_u64(), Ok(2));
+    assert_eq!(half.try_ceil_u64(), Ok(3));
+    assert_eq!(half.try_round_u64(), Ok(3));
+
+    let whole = Decimal::from(7);
+    assert_eq!(whole.try_floor_u64(), Ok(7));
+    assert_eq!(whole.try_ceil_u64(), Ok(7));
+    assert_eq!(whole.try_round_u64(), Ok(7));
+
+    // The smallest fraction still rounds up
+    let tiny = Decimal::from_scaled_val(1);
+    assert_eq!(tiny.try_floor_u64(), Ok(0));
+    assert_eq!(tiny.try_ceil_u64(), Ok(1));
+    assert_eq!(tiny.try_round_u64(), Ok(0));
+}
+
+#[test]
+fn test_rate() {
+    assert_eq!(Rate::from_bps(0), Some(Rate::ZERO));
+    assert_eq!(Rate::from_bps(MAX_BPS), Some(Rate::ONE));
+    assert_eq!(Rate::from_bps(MAX_BPS + 1), None);
+    assert_eq!(Rate::from_bps(u16::MAX), None);
+    assert!(Rate::try_from(MAX_BPS + 1).is_err());
+
+    let rate = Rate::new(250);
+    assert_eq!(rate.bps(), 250);
+    assert_eq!(rate.complement(), Rate::new(9_750));
+    assert_eq!(rate.to_string(), "2.50%");
+    assert_eq!(Decimal::from(rate), Decimal::from_bps(250));
+    assert_eq!(rate.try_apply(1_000), Ok(25));
+    assert_eq!(rate.try_apply(39), Ok(0));
+    assert_eq!(Rate::ONE.try_apply(u64::MAX), Ok(u64::MAX));
+}
+
+#[test]
+fn test_split() {
+    let rates = [Rate::new(3_333), Rate::new(3_333), Rate::new(3_334)];
+    assert_eq!(
+        Split::try_new(100, rates, RoundingPolicy::FirstRecipient),
+        Ok(Split {
+            portions: [34, 33, 33],
+            treasury: 0,
+            burn: 0,
+        })
+    );
+    assert_eq!(
+        Split::try_new(100, rates, RoundingPolicy::Treasury),
+        Ok(Split {
+            portions: [33, 33, 33],
+            treasury: 1,
+            burn: 0,
+        })
+    );
+    assert_eq!(
+        Split::try_new(100, rates, RoundingPolicy::Burn),
+        Ok(Split {
+            portions: [33, 33, 33],
+            treasury: 0,
+            burn: 1,
+        })
+    );
+
+    // Rates that don't add up to 100% can't split an amount
+    let error = ProgramError::from(RNDRError::MathError);
+    assert_eq!(
+        Split::try_new(100, [Rate::new(5_000)], RoundingPolicy::FirstRecipient),
+        Err(error.clone())
+    );
+    assert_eq!(
+        Split::try_new(
+            100,
+            [Rate::ONE, Rate::new(1)],
+            RoundingPolicy::FirstRecipient
+        ),
+        Err(error)
+    );
+}
+
+#[test]
+fn test_split_sweep_dust() {
+    let split = Split::try_new(
+        1_005,
+        [Rate::new(9_950), Rate::new(50)],
+        RoundingPolicy::Treasury,
+    )
+    .unwrap();
+    assert_eq!(split.portions, [999, 5]);
+    assert_eq!(split.treasury, 1);
+
+    // Portions below the minimum disbursement join the remainder in the treasury
+    assert_eq!(split.try_sweep_dust(0), Ok(split));
+    assert_eq!(split.try_sweep_dust(5), Ok(split));
+    assert_eq!(
+        split.try_sweep_dust(6),
+        Ok(Split {
+            portions: [999, 0],
+            treasury: 6,
+            burn: 0,
+        })
+    );
+    assert_eq!(
+        split.try_sweep_dust(u64::MAX),
+        Ok(Split {
+            portions: [0, 0],
+            treasury: 1_005,
+            burn: 0,
+        })
+    );
+}
+
+#[test]
+fn test_exchange_rate() {
+    assert_eq!(ExchangeRate::new(0, 1), None);
+    assert_eq!(ExchangeRate::new(1, 0), None);
+
+    // 0.5 RNDR of 8 decimals for a token of 6 decimals is 50 RNDR base units per base unit
+    let rate = ExchangeRate::try_from_price(5, -1, 6, 8).unwrap();
+    assert_eq!(rate, ExchangeRate::new(50, 1).unwrap());
+    assert_eq!(rate.try_to_rndr(1_000_000), Ok(50_000_000));
+    assert_eq!(rate.try_from_rndr(50_000_000), Ok(1_000_000));
+    assert_eq!(rate.try_from_rndr(50_000_001), Ok(1_000_001));
+
+    // 2 RNDR for a token of 18 decimals is 1 RNDR base unit per 5 * 10^9 base units
+    let rate = ExchangeRate::try_from_price(2, 0, 18, 8).unwrap();
+    assert_eq!(rate, ExchangeRate::new(2, 10_000_000_000).unwrap());
+    assert_eq!(rate.try_to_rndr(1_000_000_000_000_000_000), Ok(200_000_000));
+    assert_eq!(rate.try_to_rndr(4_999_999_999), Ok(0));
+    assert_eq!(rate.try_from_rndr(1), Ok(5_000_000_000));
+
+    let error = ProgramError::from(RNDRError::MathError);
+    assert_eq!(ExchangeRate::try_from_price(0, 0, 6, 8), Err(error.clone()));
+    assert_eq!(
+        ExchangeRate::try_from_price(u64::MAX, 1, 8, 8),
+        Err(error.clone())
+    );
+    assert_eq!(
+        ExchangeRate::try_from_price(1, -20, 8, 8),
+        Err(error.clone())
+    );
+    let rate = ExchangeRate::new(u64::MAX, 1).unwrap();
+    assert_eq!(rate.try_to_rndr(1), Ok(u64::MAX));
+    assert_eq!(rate.try_to_rndr(2), Err(error.clone()));
+    let rate = ExchangeRate::new(1, u64::MAX).unwrap();
+    assert_eq!(rate.try_from_rndr(1), Ok(u64::MAX));
+    assert_eq!(rate.try_from_rndr(2), Err(error));
+}
+
+proptest! {
+    #[test]
+    fn test_decimal_mul_div(a in any::<u64>(), b in any::<u64>(), c in 1..=u64::MAX) {
+        // Multiplying and then dividing amounts is exact before rounding to an integer
+        let quotient = Decimal::from(a).try_mul(b).unwrap().try_div(c).unwrap();
+        let product = a as u128 * b as u128;
+        let floor = product / c as u128;
+        let ceil = product.div_ceil(c as u128);
+        prop_assert_eq!(quotient.try_floor_u64().ok(), u64::try_from(floor).ok());
+        prop_assert_eq!(quotient.try_ceil_u64().ok(), u64::try_from(ceil).ok());
+    }
+
+    #[test]
+    fn test_decimal_add_sub(a in any::<u128>(), b in any::<u128>()) {
+        let (a, b) = (Decimal::from_scaled_val(a), Decimal::from_scaled_val(b));
+        let sum = a.try_add(b).unwrap();
+        prop_assert_eq!(sum.try_sub(b).unwrap(), a);
+        prop_assert_eq!(sum.try_sub(a).unwrap(), b);
+        prop_assert!(sum >= a && sum >= b);
+    }
+
+    #[test]
+    fn test_decimal_mul_rounds_down(a in any::<u128>(), b in any::<u128>()) {
+        let (a, b) = (Decimal::from_scaled_val(a), Decimal::from_scaled_val(b));
+        if let Ok(quotient) = a.try_div(b) {
+            // The quotient times the divisor never exceeds the dividend
+            prop_assert!(quotient.try_mul(b).unwrap() <= a);
+        }
+    }
+
+    #[test]
+    fn test_rate_apply(bps in 0..=MAX_BPS, amount in any::<u64>()) {
+        // A rate's portion of an amount and its complement's never exceed the amount together
+        let rate = Rate::new(bps);
+        let portion = rate.try_apply(amount).unwrap();
+        prop_assert_eq!(portion, (amount as u128 * bps as u128 / 10_000) as u64);
+        prop_assert!(portion + rate.complement().try_apply(amount).unwrap() <= amount);
+    }
+
+    #[test]
+    fn test_split_adds_up(
+        amount in any::<u64>(),
+        bps in 0..=MAX_BPS,
+        policy in prop_oneof![
+            Just(RoundingPolicy::FirstRecipient),
+            Just(RoundingPolicy::Treasury),
+            Just(RoundingPolicy::Burn),
+        ],
+    ) {
+        // The portions and the remainder always add up to the amount, and the remainder only goes
+        // where the policy sends it
+        let rate = Rate::new(bps);
+        let split = Split::try_new(amount, [rate.complement(), rate], policy).unwrap();
+        let total = split.portions.iter().map(|portion| *portion as u128).sum::<u128>()
+            + split.treasury as u128
+            + split.burn as u128;
+        prop_assert_eq!(total, amount as u128);
+        prop_assert_eq!(split.portions[1], rate.try_apply(amount).unwrap());
+        prop_assert!(split.treasury <= 1 && split.burn <= 1);
+        match policy {
+            RoundingPolicy::FirstRecipient => prop_assert_eq!((split.treasury, split.burn), (0, 0)),
+            RoundingPolicy::Treasury => prop_assert_eq!(split.burn, 0),
+            RoundingPolicy::Burn => prop_assert_eq!(split.treasury, 0),
+        }
+    }
+
+    #[test]
+    fn test_exchange_rate_from_price(
+        price in 1..=u64::MAX,
+        exponent in -18..=18i32,
+        token_decimals in 0..=18u8,
+        rndr_decimals in 0..=18u8,
+    ) {
+        // One whole token converts to the price in RNDR base units, whatever the decimals of each
+        // mint, whenever the rate between their base units fits
+        if let Ok(rate) = ExchangeRate::try_from_price(price, exponent, token_decimals, rndr_decimals) {
+            let whole = 10u64.pow(u32::from(token_decimals));
+            let exponent = exponent + i32::from(rndr_decimals);
+            let scale = 10u128.pow(exponent.unsigned_abs());
+            let expected = if exponent >= 0 {
+                (price as u128).checked_mul(scale)
+            } else {
+                Some(price as u128 / scale)
+            };
+            prop_assert_eq!(
+                rate.try_to_rndr(whole).ok(),
+                expected.and_then(|expected| u64::try_from(expected).ok())
+            );
+        } else {
+            let exponent = exponent + i32::from(rndr_decimals) - i32::from(token_decimals);
+            // Only a scale or a numerator that doesn't fit in a u64 fails
+            let failed = match 10u64.checked_pow(exponent.unsigned_abs()) {
+                Some(scale) => exponent >= 0 && price.checked_mul(scale).is_none(),
+                None => true,
+            };
+            prop_assert!(failed);
+        }
+    }
+
+    #[test]
+    fn test_exchange_rate_converts_consistently(
+        rate_numerator in 1..=u64::MAX,
+        rate_denominator in 1..=u64::MAX,
+        amount in any::<u64>(),
+    ) {
+        // Amounts convert to RNDR rounded down and back rounded up, exactly
+        let rate = ExchangeRate::new(rate_numerator, rate_denominator).unwrap();
+        let product = amount as u128 * rate_numerator as u128;
+        let value = rate.try_to_rndr(amount);
+        prop_assert_eq!(
+            value.clone().ok(),
+            u64::try_from(product / rate_denominator as u128).ok()
+        );
+        let product = amount as u128 * rate_denominator as u128;
+        let tokens = rate.try_from_rndr(amount);
+        prop_assert_eq!(
+            tokens.clone().ok(),
+            u64::try_from(product.div_ceil(rate_numerator as u128)).ok()
+        );
+
+        // Converting a value in RNDR back never takes more tokens than it came from, and the
+        // tokens a value in RNDR converts to are always worth at least that value
+        if let Ok(value) = value {
+            prop_assert!(rate.try_from_rndr(value).unwrap() <= amount);
+        }
+        if let Ok(tokens) = tokens {
+            prop_assert!(rate.try_to_rndr(tokens).map_or(true, |value| value >= amount));
+        }
+    }
+}
@@ -0,0 +1,811 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{
+        error::RNDRError,
+        instruction::{
+            convert_escrow_mint_vault, convert_isolated_job_mint, disburse_funds,
+            disburse_isolated_job_funds, freeze_job, migrate_escrow_mint,
+            migrate_escrow_mint_with_approver, redenominate_jobs, set_config,
+            with_escrow_token_account,
+        },
+        processor::process_instruction,
+        state::{ConfigChange, Escrow, IncidentMode, Job},
+        test_fixtures::*,
+    },
+    solana_program::{program_pack::Pack, pubkey::Pubkey},
+    solana_program_test::*,
+    solana_sdk::{
+        signature::{Keypair, Signer},
+        system_instruction,
+        transaction::Transaction,
+    },
+    spl_associated_token_account::get_associated_token_address,
+};
+
+const AMOUNT: u64 = 1_000_000_000;
+const RATE_NUMERATOR: u64 = 3;
+const RATE_DENOMINATOR: u64 = 2;
+const CONVERTED_AMOUNT: u64 = AMOUNT * RATE_NUMERATOR / RATE_DENOMINATOR;
+
+async fn fund_owner(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    recent_blockhash: solana_sdk::hash::Hash,
+    owner: &Keypair,
+) {
+    let mut transaction = Transaction::new_with_payer(
+        &[system_instruction::transfer(
+            &payer.pubkey(),
+            &owner.pubkey(),
+            1_000_000_000,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let test_config = TestConfig::add_with_timelock(&mut test, 0);
+    let test_mint = TestMint::add(&mut test, 9, 100 * AMOUNT);
+    let test_new_mint = TestMint::add(&mut test, 9, 100 * AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let owner = &test_escrow.owner;
+    let test_source_token = TestToken::add_delegated(
+        &mut test,
+        test_new_mint.pubkey,
+        CONVERTED_AMOUNT,
+        owner.pubkey(),
+        CONVERTED_AMOUNT,
+    );
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, 0);
+    let test_job_token = TestToken::add(&mut test, test_new_mint.pubkey, 0);
+    let test_job = TestJob::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_job_token.owner.pubkey(),
+        AMOUNT,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+    fund_owner(&mut banks_client, &payer, recent_blockhash, owner).await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[migrate_escrow_mint(
+            rndr::id(),
+            test_mint.pubkey,
+            owner.pubkey(),
+            test_config.governance.pubkey(),
+            0,
+            test_new_mint.pubkey,
+            test_destination_token.pubkey,
+            RATE_NUMERATOR,
+            RATE_DENOMINATOR,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, owner, &test_config.governance], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let (mint_migration_address, _bump_seed) = find_mint_migration_address(test_escrow.pubkey, 0);
+    let mint_migration = get_mint_migration(&mut banks_client, mint_migration_address).await;
+    assert_eq!(mint_migration.escrow, test_escrow.pubkey);
+    assert_eq!(mint_migration.token_mint, test_mint.pubkey);
+    assert_eq!(mint_migration.new_token_mint, test_new_mint.pubkey);
+    assert!(!mint_migration.vault_converted);
+
+    let new_associated_token =
+        get_associated_token_address(&test_escrow.pubkey, &test_new_mint.pubkey);
+    assert_eq!(
+        get_token_balance(&mut banks_client, new_associated_token).await,
+        0
+    );
+
+    // Jobs can't be re-denominated before the escrow's tokens are converted
+    let mut transaction = Transaction::new_with_payer(
+        &[redenominate_jobs(
+            rndr::id(),
+            test_mint.pubkey,
+            payer.pubkey(),
+            0,
+            &[test_job.pubkey],
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let mut transaction = Transaction::new_with_payer(
+        &[convert_escrow_mint_vault(
+            rndr::id(),
+            test_mint.pubkey,
+            owner.pubkey(),
+            0,
+            test_escrow.associated_token,
+            test_new_mint.pubkey,
+            test_source_token.pubkey,
+            test_destination_token.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let account = get_account(&mut banks_client, test_escrow.pubkey).await;
+    assert_eq!(account.data.len(), Escrow::LEN);
+
+    let escrow = test_escrow.get(&mut banks_client).await;
+    assert_eq!(escrow.token_mint, test_new_mint.pubkey);
+    assert_eq!(escrow.original_token_mint, test_mint.pubkey);
+    assert_eq!(escrow.address_token_mint(), &test_mint.pubkey);
+    assert_eq!(escrow.mint_migrations, 1);
+    assert_eq!(escrow.amount, CONVERTED_AMOUNT);
+    assert_eq!(
+        escrow.token_account(&test_escrow.pubkey),
+        new_associated_token
+    );
+
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_escrow.associated_token).await,
+        0
+    );
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_destination_token.pubkey).await,
+        AMOUNT
+    );
+    assert_eq!(
+        get_token_balance(&mut banks_client, new_associated_token).await,
+        CONVERTED_AMOUNT
+    );
+
+    // Jobs can't be paid until they're re-denominated
+    let disburse = |amount| {
+        with_escrow_token_account(
+            disburse_funds(
+                rndr::id(),
+                amount,
+                test_mint.pubkey,
+                test_job_token.pubkey,
+                test_job.pubkey,
+                owner.pubkey(),
+            ),
+            test_mint.pubkey,
+            new_associated_token,
+        )
+    };
+    let mut transaction = Transaction::new_with_payer(&[disburse(AMOUNT)], Some(&payer.pubkey()));
+    transaction.sign(&[&payer, owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let recent_blockhash = banks_client
+        .get_new_latest_blockhash(&recent_blockhash)
+        .await
+        .unwrap();
+    let mut transaction = Transaction::new_with_payer(
+        &[redenominate_jobs(
+            rndr::id(),
+            test_mint.pubkey,
+            payer.pubkey(),
+            0,
+            &[test_job.pubkey],
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let account = get_account(&mut banks_client, test_job.pubkey).await;
+    assert_eq!(account.data.len(), Job::LEN);
+
+    let job = test_job.get(&mut banks_client).await;
+    assert_eq!(job.amount, CONVERTED_AMOUNT);
+    assert_eq!(job.mint_migrations, 1);
+
+    let mint_migration = get_mint_migration(&mut banks_client, mint_migration_address).await;
+    assert!(mint_migration.vault_converted);
+    assert_eq!(mint_migration.jobs_migrated, 1);
+
+    let mut transaction =
+        Transaction::new_with_payer(&[disburse(CONVERTED_AMOUNT)], Some(&payer.pubkey()));
+    transaction.sign(&[&payer, owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_job_token.pubkey).await,
+        CONVERTED_AMOUNT
+    );
+    assert_eq!(
+        get_token_balance(&mut banks_client, new_associated_token).await,
+        0
+    );
+}
+
+#[tokio::test]
+async fn test_success_isolated_job() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let test_config = TestConfig::add_with_timelock(&mut test, 0);
+    let test_mint = TestMint::add(&mut test, 9, 100 * AMOUNT);
+    let test_new_mint = TestMint::add(&mut test, 9, 100 * AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, 0);
+    let owner = &test_escrow.owner;
+    let test_source_token = TestToken::add_delegated(
+        &mut test,
+        test_new_mint.pubkey,
+        CONVERTED_AMOUNT,
+        owner.pubkey(),
+        CONVERTED_AMOUNT,
+    );
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, 0);
+    let test_job_token = TestToken::add(&mut test, test_new_mint.pubkey, 0);
+    let test_job = TestJob::add_isolated(
+        &mut test,
+        test_escrow.pubkey,
+        test_mint.pubkey,
+        test_job_token.owner.pubkey(),
+        AMOUNT,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+    fund_owner(&mut banks_client, &payer, recent_blockhash, owner).await;
+
+    let convert_isolated_job = convert_isolated_job_mint(
+        rndr::id(),
+        test_mint.pubkey,
+        owner.pubkey(),
+        0,
+        test_job.pubkey,
+        test_mint.pubkey,
+        test_new_mint.pubkey,
+        test_source_token.pubkey,
+        test_destination_token.pubkey,
+    );
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            migrate_escrow_mint(
+                rndr::id(),
+                test_mint.pubkey,
+                owner.pubkey(),
+                test_config.governance.pubkey(),
+                0,
+                test_new_mint.pubkey,
+                test_destination_token.pubkey,
+                RATE_NUMERATOR,
+                RATE_DENOMINATOR,
+            ),
+            convert_escrow_mint_vault(
+                rndr::id(),
+                test_mint.pubkey,
+                owner.pubkey(),
+                0,
+                test_escrow.associated_token,
+                test_new_mint.pubkey,
+                test_source_token.pubkey,
+                test_destination_token.pubkey,
+            ),
+            convert_isolated_job.clone(),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, owner, &test_config.governance], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let job = test_job.get(&mut banks_client).await;
+    assert_eq!(job.amount, CONVERTED_AMOUNT);
+    assert_eq!(job.mint_migrations, 1);
+
+    let job_associated_token = get_associated_token_address(&test_job.pubkey, &test_mint.pubkey);
+    let new_job_associated_token =
+        get_associated_token_address(&test_job.pubkey, &test_new_mint.pubkey);
+    assert_eq!(
+        get_token_balance(&mut banks_client, job_associated_token).await,
+        0
+    );
+    assert_eq!(
+        get_token_balance(&mut banks_client, new_job_associated_token).await,
+        CONVERTED_AMOUNT
+    );
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_destination_token.pubkey).await,
+        AMOUNT
+    );
+
+    let (mint_migration_address, _bump_seed) = find_mint_migration_address(test_escrow.pubkey, 0);
+    let mint_migration = get_mint_migration(&mut banks_client, mint_migration_address).await;
+    assert_eq!(mint_migration.jobs_migrated, 1);
+
+    // Isolated jobs are converted only once
+    let mut transaction =
+        Transaction::new_with_payer(&[convert_isolated_job], Some(&payer.pubkey()));
+    transaction.sign(&[&payer, owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    // The converted job pays out of its new token account
+    let mut instruction = disburse_isolated_job_funds(
+        rndr::id(),
+        CONVERTED_AMOUNT,
+        test_mint.pubkey,
+        test_job_token.pubkey,
+        test_job.pubkey,
+        owner.pubkey(),
+    );
+    instruction.accounts[3].pubkey = new_job_associated_token;
+    let mut transaction = Transaction::new_with_payer(&[instruction], Some(&payer.pubkey()));
+    transaction.sign(&[&payer, owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_job_token.pubkey).await,
+        CONVERTED_AMOUNT
+    );
+}
+
+#[tokio::test]
+async fn test_invalid_migration() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let test_config = TestConfig::add_with_timelock(&mut test, 0);
+    let test_mint = TestMint::add(&mut test, 9, 100 * AMOUNT);
+    let test_new_mint = TestMint::add(&mut test, 9, 100 * AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let owner = &test_escrow.owner;
+    let test_source_token = TestToken::add_delegated(
+        &mut test,
+        test_new_mint.pubkey,
+        CONVERTED_AMOUNT - 1,
+        owner.pubkey(),
+        CONVERTED_AMOUNT - 1,
+    );
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, 0);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+    fund_owner(&mut banks_client, &payer, recent_blockhash, owner).await;
+
+    // The rate must not be zero
+    let mut transaction = Transaction::new_with_payer(
+        &[migrate_escrow_mint(
+            rndr::id(),
+            test_mint.pubkey,
+            owner.pubkey(),
+            test_config.governance.pubkey(),
+            0,
+            test_new_mint.pubkey,
+            test_destination_token.pubkey,
+            RATE_NUMERATOR,
+            0,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, owner, &test_config.governance], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    // The escrow can't be migrated to its own mint
+    let mut transaction = Transaction::new_with_payer(
+        &[migrate_escrow_mint(
+            rndr::id(),
+            test_mint.pubkey,
+            owner.pubkey(),
+            test_config.governance.pubkey(),
+            0,
+            test_mint.pubkey,
+            test_destination_token.pubkey,
+            RATE_NUMERATOR,
+            RATE_DENOMINATOR,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, owner, &test_config.governance], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    // Only the owner can migrate the escrow
+    let mut transaction = Transaction::new_with_payer(
+        &[migrate_escrow_mint(
+            rndr::id(),
+            test_mint.pubkey,
+            payer.pubkey(),
+            test_config.governance.pubkey(),
+            0,
+            test_new_mint.pubkey,
+            test_destination_token.pubkey,
+            RATE_NUMERATOR,
+            RATE_DENOMINATOR,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_config.governance], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    // Only the governance authority can approve the migration
+    let mut transaction = Transaction::new_with_payer(
+        &[migrate_escrow_mint(
+            rndr::id(),
+            test_mint.pubkey,
+            owner.pubkey(),
+            payer.pubkey(),
+            0,
+            test_new_mint.pubkey,
+            test_destination_token.pubkey,
+            RATE_NUMERATOR,
+            RATE_DENOMINATOR,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    // The old tokens must be sent to a token account of the escrow's mint
+    let mut transaction = Transaction::new_with_payer(
+        &[migrate_escrow_mint(
+            rndr::id(),
+            test_mint.pubkey,
+            owner.pubkey(),
+            test_config.governance.pubkey(),
+            0,
+            test_new_mint.pubkey,
+            test_source_token.pubkey,
+            RATE_NUMERATOR,
+            RATE_DENOMINATOR,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, owner, &test_config.governance], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let mut transaction = Transaction::new_with_payer(
+        &[migrate_escrow_mint(
+            rndr::id(),
+            test_mint.pubkey,
+            owner.pubkey(),
+            test_config.governance.pubkey(),
+            0,
+            test_new_mint.pubkey,
+            test_destination_token.pubkey,
+            RATE_NUMERATOR,
+            RATE_DENOMINATOR,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, owner, &test_config.governance], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    // The migration can only be begun once
+    let mut transaction = Transaction::new_with_payer(
+        &[migrate_escrow_mint(
+            rndr::id(),
+            test_mint.pubkey,
+            owner.pubkey(),
+            test_config.governance.pubkey(),
+            0,
+            test_new_mint.pubkey,
+            test_destination_token.pubkey,
+            RATE_NUMERATOR,
+            RATE_NUMERATOR,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, owner, &test_config.governance], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    // The owner must deposit the full converted amount
+    let mut transaction = Transaction::new_with_payer(
+        &[convert_escrow_mint_vault(
+            rndr::id(),
+            test_mint.pubkey,
+            owner.pubkey(),
+            0,
+            test_escrow.associated_token,
+            test_new_mint.pubkey,
+            test_source_token.pubkey,
+            test_destination_token.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    // The old tokens must be sent to the destination approved for the migration
+    let other_destination_token = Keypair::new();
+    let mut transaction = Transaction::new_with_payer(
+        &[convert_escrow_mint_vault(
+            rndr::id(),
+            test_mint.pubkey,
+            owner.pubkey(),
+            0,
+            test_escrow.associated_token,
+            test_new_mint.pubkey,
+            test_source_token.pubkey,
+            other_destination_token.pubkey(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    // The escrow's tokens must be converted from its current token account
+    let mut transaction = Transaction::new_with_payer(
+        &[convert_escrow_mint_vault(
+            rndr::id(),
+            test_mint.pubkey,
+            owner.pubkey(),
+            0,
+            Pubkey::new_unique(),
+            test_new_mint.pubkey,
+            test_source_token.pubkey,
+            test_destination_token.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let escrow = test_escrow.get(&mut banks_client).await;
+    assert_eq!(escrow.token_mint, test_mint.pubkey);
+    assert_eq!(escrow.mint_migrations, 0);
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_escrow.associated_token).await,
+        AMOUNT
+    );
+}
+
+#[tokio::test]
+async fn test_timelock() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let test_config = TestConfig::add(&mut test);
+    let test_mint = TestMint::add(&mut test, 9, 100 * AMOUNT);
+    let test_new_mint = TestMint::add(&mut test, 9, 100 * AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let owner = &test_escrow.owner;
+    let test_source_token = TestToken::add_delegated(
+        &mut test,
+        test_new_mint.pubkey,
+        CONVERTED_AMOUNT,
+        owner.pubkey(),
+        CONVERTED_AMOUNT,
+    );
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, 0);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+    fund_owner(&mut banks_client, &payer, recent_blockhash, owner).await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[migrate_escrow_mint(
+            rndr::id(),
+            test_mint.pubkey,
+            owner.pubkey(),
+            test_config.governance.pubkey(),
+            0,
+            test_new_mint.pubkey,
+            test_destination_token.pubkey,
+            RATE_NUMERATOR,
+            RATE_DENOMINATOR,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, owner, &test_config.governance], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let (mint_migration_address, _bump_seed) = find_mint_migration_address(test_escrow.pubkey, 0);
+    let mint_migration = get_mint_migration(&mut banks_client, mint_migration_address).await;
+    assert_eq!(mint_migration.destination, test_destination_token.pubkey);
+    assert!(mint_migration.executable_at > 0);
+
+    // The escrow's tokens can't be converted until the timelock of the config has passed
+    let mut transaction = Transaction::new_with_payer(
+        &[convert_escrow_mint_vault(
+            rndr::id(),
+            test_mint.pubkey,
+            owner.pubkey(),
+            0,
+            test_escrow.associated_token,
+            test_new_mint.pubkey,
+            test_source_token.pubkey,
+            test_destination_token.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_escrow.associated_token).await,
+        AMOUNT
+    );
+}
+
+#[tokio::test]
+async fn test_requires_approver() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let approver = Keypair::new();
+    let test_config = TestConfig::add_with_timelock(&mut test, 0);
+    let test_mint = TestMint::add(&mut test, 9, 100 * AMOUNT);
+    let test_new_mint = TestMint::add(&mut test, 9, 100 * AMOUNT);
+    let test_escrow =
+        TestEscrow::add_with_approver(&mut test, test_mint.pubkey, AMOUNT, approver.pubkey());
+    let owner = &test_escrow.owner;
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, 0);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+    fund_owner(&mut banks_client, &payer, recent_blockhash, owner).await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[migrate_escrow_mint(
+            rndr::id(),
+            test_mint.pubkey,
+            owner.pubkey(),
+            test_config.governance.pubkey(),
+            0,
+            test_new_mint.pubkey,
+            test_destination_token.pubkey,
+            RATE_NUMERATOR,
+            RATE_DENOMINATOR,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, owner, &test_config.governance], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let mut transaction = Transaction::new_with_payer(
+        &[migrate_escrow_mint_with_approver(
+            rndr::id(),
+            test_mint.pubkey,
+            owner.pubkey(),
+            test_config.governance.pubkey(),
+            approver.pubkey(),
+            0,
+            test_new_mint.pubkey,
+            test_destination_token.pubkey,
+            RATE_NUMERATOR,
+            RATE_DENOMINATOR,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(
+        &[&payer, owner, &test_config.governance, &approver],
+        recent_blockhash,
+    );
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_incident_mode_and_frozen_job() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let test_config = TestConfig::add_with_timelock(&mut test, 0);
+    let test_mint = TestMint::add(&mut test, 9, 100 * AMOUNT);
+    let test_new_mint = TestMint::add(&mut test, 9, 100 * AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, 0);
+    let owner = &test_escrow.owner;
+    let test_source_token = TestToken::add_delegated(
+        &mut test,
+        test_new_mint.pubkey,
+        CONVERTED_AMOUNT,
+        owner.pubkey(),
+        CONVERTED_AMOUNT,
+    );
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, 0);
+    let test_job_token = TestToken::add(&mut test, test_new_mint.pubkey, 0);
+    let authority = test_job_token.owner.pubkey();
+    let test_job = TestJob::add_isolated(
+        &mut test,
+        test_escrow.pubkey,
+        test_mint.pubkey,
+        authority,
+        AMOUNT,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+    fund_owner(&mut banks_client, &payer, recent_blockhash, owner).await;
+
+    let governance = &test_config.governance;
+    let migrate = migrate_escrow_mint(
+        rndr::id(),
+        test_mint.pubkey,
+        owner.pubkey(),
+        governance.pubkey(),
+        0,
+        test_new_mint.pubkey,
+        test_destination_token.pubkey,
+        RATE_NUMERATOR,
+        RATE_DENOMINATOR,
+    );
+
+    // The migration can't begin during an incident
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            set_config(
+                rndr::id(),
+                governance.pubkey(),
+                ConfigChange::IncidentMode(IncidentMode::DisburseOnly),
+            ),
+            migrate.clone(),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, owner, governance], recent_blockhash);
+
+    assert_eq!(
+        program_error(banks_client.process_transaction(transaction).await),
+        Some(RNDRError::IncidentModeActive)
+    );
+
+    // Frozen isolated jobs can't be converted
+    let compliance = &test_config.compliance;
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            migrate,
+            convert_escrow_mint_vault(
+                rndr::id(),
+                test_mint.pubkey,
+                owner.pubkey(),
+                0,
+                test_escrow.associated_token,
+                test_new_mint.pubkey,
+                test_source_token.pubkey,
+                test_destination_token.pubkey,
+            ),
+            freeze_job(
+                rndr::id(),
+                test_mint.pubkey,
+                compliance.pubkey(),
+                authority,
+                0,
+            ),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, owner, governance, compliance], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let mut transaction = Transaction::new_with_payer(
+        &[convert_isolated_job_mint(
+            rndr::id(),
+            test_mint.pubkey,
+            owner.pubkey(),
+            0,
+            test_job.pubkey,
+            test_mint.pubkey,
+            test_new_mint.pubkey,
+            test_source_token.pubkey,
+            test_destination_token.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let job = test_job.get(&mut banks_client).await;
+    assert_eq!(job.amount, AMOUNT);
+    assert_eq!(job.mint_migrations, 0);
+}
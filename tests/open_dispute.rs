@@ -0,0 +1,118 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{
+        instruction::open_dispute, processor::process_instruction, state::JobStatus,
+        test_fixtures::*,
+    },
+    solana_program_test::*,
+    solana_sdk::{
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        system_instruction,
+        transaction::Transaction,
+    },
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const AMOUNT: u64 = 1_000_000_000;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    TestConfig::add(&mut test);
+    let authority = Keypair::new();
+    let test_job = TestJob::add_committed(
+        &mut test,
+        test_escrow.pubkey,
+        authority.pubkey(),
+        Pubkey::new_unique(),
+        [1; 32],
+        AMOUNT,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // Only the job's authority or node can open a dispute
+    let mut transaction = Transaction::new_with_payer(
+        &[open_dispute(
+            rndr::id(),
+            test_mint.pubkey,
+            payer.pubkey(),
+            test_job.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            system_instruction::transfer(&payer.pubkey(), &authority.pubkey(), 1_000_000_000),
+            open_dispute(
+                rndr::id(),
+                test_mint.pubkey,
+                authority.pubkey(),
+                test_job.pubkey,
+            ),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let job = get_job(&mut banks_client, test_job.pubkey).await;
+    assert_eq!(job.status, JobStatus::Disputed);
+
+    let (dispute_pubkey, _bump_seed) = find_dispute_address(test_job.pubkey);
+    let dispute = get_dispute(&mut banks_client, dispute_pubkey).await;
+    assert_eq!(dispute.job, test_job.pubkey);
+    assert_eq!(dispute.opener, authority.pubkey());
+    assert!(dispute.opened_at > 0);
+    assert!(!dispute.is_resolved());
+}
+
+#[tokio::test]
+async fn test_dispute_window_passed() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const AMOUNT: u64 = 1_000_000_000;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    TestConfig::add(&mut test);
+    let authority = Keypair::new();
+    // Revealed at the epoch, long before the default dispute window
+    let test_job = TestJob::add_revealed(
+        &mut test,
+        test_escrow.pubkey,
+        authority.pubkey(),
+        Pubkey::new_unique(),
+        AMOUNT,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            system_instruction::transfer(&payer.pubkey(), &authority.pubkey(), 1_000_000_000),
+            open_dispute(
+                rndr::id(),
+                test_mint.pubkey,
+                authority.pubkey(),
+                test_job.pubkey,
+            ),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let job = get_job(&mut banks_client, test_job.pubkey).await;
+    assert_eq!(job.status, JobStatus::Revealed);
+}
@@ -0,0 +1,58 @@
+use {
+    rndr::{error::RNDRError, math::Rate, oracle::OraclePrice},
+    solana_program::program_error::ProgramError,
+};
+
+// 7.318624055 US dollars per token
+const ORACLE_PRICE: OraclePrice = OraclePrice {
+    price: 7_318_624_055,
+    confidence: 7_318_624,
+    exponent: -9,
+    publish_slot: 100,
+};
+
+#[test]
+fn test_try_token_amount() {
+    // 100 US dollars of a token with 8 decimals, rounded up
+    assert_eq!(
+        ORACLE_PRICE.try_token_amount(100_000_000, 8),
+        Ok(1_366_377_058)
+    );
+    assert_eq!(ORACLE_PRICE.try_token_amount(0, 8), Ok(0));
+    // The net exponent is positive with enough decimals, and the value is scaled up instead
+    assert_eq!(
+        ORACLE_PRICE.try_token_amount(100_000_000, 18),
+        Ok(13_663_770_573_333_541_725)
+    );
+    assert_eq!(
+        ORACLE_PRICE.try_token_amount(u64::MAX, 18),
+        Err(RNDRError::MathError.into())
+    );
+}
+
+#[test]
+fn test_check() {
+    const MAX_CONFIDENCE_BPS: Rate = Rate::new(10);
+
+    assert_eq!(ORACLE_PRICE.check(125, 25, MAX_CONFIDENCE_BPS), Ok(()));
+    // A price published after the slot isn't stale
+    assert_eq!(ORACLE_PRICE.check(0, 0, MAX_CONFIDENCE_BPS), Ok(()));
+    assert_eq!(
+        ORACLE_PRICE.check(126, 25, MAX_CONFIDENCE_BPS),
+        Err(ProgramError::from(RNDRError::StaleOraclePrice))
+    );
+    assert_eq!(
+        ORACLE_PRICE.check(100, 0, Rate::new(9)),
+        Err(ProgramError::from(RNDRError::UnreliableOraclePrice))
+    );
+    // Zero guards only accept a price published at the slot without a confidence interval
+    let certain_price = OraclePrice {
+        confidence: 0,
+        ..ORACLE_PRICE
+    };
+    assert_eq!(certain_price.check(100, 0, Rate::ZERO), Ok(()));
+    assert_eq!(
+        ORACLE_PRICE.check(100, 0, Rate::ZERO),
+        Err(ProgramError::from(RNDRError::UnreliableOraclePrice))
+    );
+}
@@ -0,0 +1,3475 @@
+use {
+    proptest::{collection::vec, prelude::*},
+    rndr::{
+        events::{self, parse_event, RNDREvent, EVENT_VERSION},
+        instruction::{RNDRInstruction, MAX_DISBURSE_BATCH_LEN, MAX_FUND_JOBS_LEN},
+        math::{ExchangeRate, Rate, RoundingPolicy, MAX_BPS},
+        state::{
+            AccountType, AdminAction, ClaimBitmap, CompressedJob, Config, ConfigChange, CrankVault,
+            DelegateAllowance, Dispute, EmissionSchedule, EpochWork, Escrow, EscrowShard,
+            IncidentMode, InitClaimBitmapParams, InitConfigParams, InitJobIndexPageParams,
+            InitJobIndexParams, InitJobQueueParams, InitNodeParams, InitStatsParams,
+            InitTreasuryParams, Job, JobBundle, JobIndex, JobIndexPage, JobMetadata, JobQueue,
+            JobStatus, JobTree, MerkleDistribution, MintMigration, Node, OwnerRotation, Proposal,
+            QueuedAction, Recovery, RewardPool, RewardTier, SessionKey, StakePool, Stats,
+            Subscription, TokenFlow, Treasury, VerifierRegistry, VerifierSelection, Voucher,
+            VoucherNonce, BASE_REWARD_MULTIPLIER_BPS, CLAIM_BITMAP_BITS, CLAIM_BITMAP_BYTES,
+            ESCROW_SHARD_COUNT, JOB_INDEX_PAGE_LEN, JOB_QUEUE_LEN, MAX_BUNDLE_JOBS,
+            MAX_CANCEL_FEE_BPS, MAX_CANCEL_GRACE_PERIOD, MAX_DECAY_BPS, MAX_JOB_METADATA_URI_LEN,
+            MAX_JOB_PRIORITY, MAX_JOB_TREE_DEPTH, MAX_MERKLE_PROOF_LEN, MAX_MIN_DISBURSEMENT,
+            MAX_OWNER_HISTORY, MAX_PRICE_TIERS, MAX_PRIORITY_CLAIM_DELAY,
+            MAX_PRIORITY_SURCHARGE_BPS, MAX_RECOVERY_GUARDIANS, MAX_SELECTED_VERIFIERS,
+            MAX_VERIFIERS, SESSION_KEY_CHALLENGE_RESULT, SESSION_KEY_FUND_JOB, VOUCHER_DOMAIN,
+        },
+        summary::{EscrowSummary, JobSummary},
+        version::ProgramVersion,
+    },
+    solana_program::{
+        hash::{hash, hashv, Hash},
+        program_error::ProgramError,
+        program_pack::Pack,
+        pubkey::Pubkey,
+    },
+    std::convert::TryFrom,
+};
+
+fn pubkey() -> impl Strategy<Value = Pubkey> {
+    any::<[u8; 32]>().prop_map(Pubkey::new_from_array)
+}
+
+fn rate(max: Rate) -> impl Strategy<Value = Rate> {
+    (0..=max.bps()).prop_map(Rate::new)
+}
+
+fn instruction() -> impl Strategy<Value = RNDRInstruction> {
+    prop_oneof![
+        pubkey().prop_map(|owner| RNDRInstruction::InitEscrow { owner }),
+        pubkey().prop_map(|new_owner| RNDRInstruction::SetEscrowOwner { new_owner }),
+        (
+            any::<u64>(),
+            any::<u64>(),
+            proptest::option::of(job_metadata())
+        )
+            .prop_map(|(amount, job_id, metadata)| RNDRInstruction::FundJob {
+                amount,
+                job_id,
+                metadata
+            }),
+        any::<u64>().prop_map(|amount| RNDRInstruction::DisburseFunds { amount }),
+        Just(RNDRInstruction::AggregateEscrowShard),
+        (any::<u64>(), any::<u64>())
+            .prop_map(|(amount, job_id)| RNDRInstruction::FundIsolatedJob { amount, job_id }),
+        any::<u64>().prop_map(|amount| RNDRInstruction::DisburseIsolatedJobFunds { amount }),
+        vec(any::<u64>(), 0..=MAX_DISBURSE_BATCH_LEN)
+            .prop_map(|amounts| RNDRInstruction::DisburseBatch { amounts }),
+        vec(any::<(u64, u64)>(), 0..=MAX_FUND_JOBS_LEN)
+            .prop_map(|jobs| RNDRInstruction::FundJobs { jobs }),
+        any::<[u8; 32]>().prop_map(|commitment| RNDRInstruction::CommitResult { commitment }),
+        (
+            any::<[u8; 32]>(),
+            any::<[u8; 32]>(),
+            vec(any::<u8>(), 0..256)
+        )
+            .prop_map(|(result_hash, salt, proof)| RNDRInstruction::RevealResult {
+                result_hash,
+                salt,
+                proof,
+            }),
+        Just(RNDRInstruction::ClaimPayment),
+        any::<u64>()
+            .prop_map(|challenge_window| RNDRInstruction::SetChallengeWindow { challenge_window }),
+        Just(RNDRInstruction::ChallengeResult),
+        pubkey().prop_map(|verifier| RNDRInstruction::AddVerifier { verifier }),
+        pubkey().prop_map(|verifier| RNDRInstruction::RemoveVerifier { verifier }),
+        any::<[u8; 32]>().prop_map(|evidence_hash| RNDRInstruction::FlagJob { evidence_hash }),
+        any::<u8>().prop_map(|count| RNDRInstruction::SelectVerifiers { count }),
+        pubkey().prop_map(|proof_verifier| RNDRInstruction::SetProofVerifier { proof_verifier }),
+        pubkey().prop_map(|arbitrator| RNDRInstruction::SetArbitrator { arbitrator }),
+        Just(RNDRInstruction::OpenDispute),
+        any::<[u8; 32]>()
+            .prop_map(|evidence_hash| RNDRInstruction::SubmitEvidence { evidence_hash }),
+        any::<u64>().prop_map(|node_amount| RNDRInstruction::ResolveDispute { node_amount }),
+        pubkey().prop_map(|governance| RNDRInstruction::InitConfig { governance }),
+        config_change().prop_map(|change| RNDRInstruction::SetConfig { change }),
+        Just(RNDRInstruction::VerifyUpgradeAuthority),
+        pubkey().prop_map(|approver| RNDRInstruction::SetApprover { approver }),
+        (any::<u64>(), any::<u64>()).prop_map(|(proposal_id, amount)| {
+            RNDRInstruction::ProposeDisbursement {
+                proposal_id,
+                amount,
+            }
+        }),
+        Just(RNDRInstruction::ApproveDisbursement),
+        Just(RNDRInstruction::ExecuteDisbursement),
+        (any::<u64>(), admin_action())
+            .prop_map(|(action_id, action)| RNDRInstruction::QueueAction { action_id, action }),
+        Just(RNDRInstruction::ExecuteQueuedAction),
+        Just(RNDRInstruction::CancelQueuedAction),
+        (any::<u8>(), vec(pubkey(), 0..=MAX_RECOVERY_GUARDIANS)).prop_map(
+            |(threshold, guardians)| RNDRInstruction::SetRecoveryCouncil {
+                threshold,
+                guardians,
+            }
+        ),
+        pubkey().prop_map(|new_owner| RNDRInstruction::ApproveRecovery { new_owner }),
+        Just(RNDRInstruction::ExecuteRecovery),
+        (any::<u64>(), pubkey()).prop_map(|(rotation_id, new_owner)| {
+            RNDRInstruction::ScheduleOwnerRotation {
+                rotation_id,
+                new_owner,
+            }
+        }),
+        (any::<u64>(), any::<u64>())
+            .prop_map(|(epoch, amount)| RNDRInstruction::FundRewardPool { epoch, amount }),
+        Just(RNDRInstruction::ClaimEpochReward),
+        (any::<u64>(), any::<[u8; 32]>(), any::<u32>(), any::<u64>()).prop_map(
+            |(epoch, root, node_count, amount)| RNDRInstruction::PublishMerkleRoot {
+                epoch,
+                root,
+                node_count,
+                amount,
+            }
+        ),
+        (
+            any::<u32>(),
+            any::<u64>(),
+            vec(any::<[u8; 32]>(), 0..=MAX_MERKLE_PROOF_LEN)
+        )
+            .prop_map(
+                |(index, amount, proof)| RNDRInstruction::ClaimMerkleReward {
+                    index,
+                    amount,
+                    proof,
+                }
+            ),
+        (any::<u64>(), any::<u64>(), rate(Rate::ONE)).prop_map(
+            |(start_epoch, initial_amount, decay_bps)| RNDRInstruction::SetEmissionSchedule {
+                start_epoch,
+                initial_amount,
+                decay_bps,
+            }
+        ),
+        Just(RNDRInstruction::EmitRewards),
+        any::<u64>().prop_map(|amount| RNDRInstruction::Stake { amount }),
+        any::<u64>().prop_map(|amount| RNDRInstruction::Unstake { amount }),
+        any::<bool>().prop_map(|auto_compound| RNDRInstruction::SetAutoCompound { auto_compound }),
+        Just(RNDRInstruction::CompoundRewards),
+        any::<u64>().prop_map(|amount| RNDRInstruction::LiquidStake { amount }),
+        any::<u64>().prop_map(|receipt_amount| RNDRInstruction::LiquidUnstake { receipt_amount }),
+        any::<u64>().prop_map(|amount| RNDRInstruction::DepositStakeRewards { amount }),
+        any::<u64>().prop_map(|bounty| RNDRInstruction::SetCrankBounty { bounty }),
+        any::<u64>()
+            .prop_map(|epoch_burn_cap| RNDRInstruction::SetTreasuryBurnCap { epoch_burn_cap }),
+        any::<u64>().prop_map(|amount| RNDRInstruction::BurnTreasury { amount }),
+        Just(RNDRInstruction::InitStats),
+        (any::<u64>(), any::<u64>(), any::<u64>(), any::<i64>()).prop_map(
+            |(amount, job_id, nonce, expires_at)| RNDRInstruction::FundJobWithVoucher {
+                amount,
+                job_id,
+                nonce,
+                expires_at,
+            }
+        ),
+        (pubkey(), any::<u64>(), any::<u64>()).prop_map(
+            |(session_key, expiry_slot, instruction_mask)| RNDRInstruction::RegisterSessionKey {
+                session_key,
+                expiry_slot,
+                instruction_mask,
+            }
+        ),
+        (pubkey(), any::<u64>(), any::<u64>()).prop_map(|(delegate, limit, expiry_slot)| {
+            RNDRInstruction::ApproveDelegate {
+                delegate,
+                limit,
+                expiry_slot,
+            }
+        }),
+        Just(RNDRInstruction::RevokeDelegate),
+        (any::<u64>(), any::<u64>(), any::<u64>()).prop_map(|(job_id, amount, period)| {
+            RNDRInstruction::CreateSubscription {
+                job_id,
+                amount,
+                period,
+            }
+        }),
+        Just(RNDRInstruction::ProcessSubscription),
+        Just(RNDRInstruction::CancelJob),
+        pubkey().prop_map(|new_authority| RNDRInstruction::TransferJobAuthority { new_authority }),
+        Just(RNDRInstruction::AcceptJobAuthority),
+        (any::<u64>(), any::<u64>(), any::<u8>(), any::<[u8; 32]>()).prop_map(
+            |(bundle_id, first_job_id, job_count, metadata_hash)| {
+                RNDRInstruction::CreateJobBundle {
+                    bundle_id,
+                    first_job_id,
+                    job_count,
+                    metadata_hash,
+                }
+            }
+        ),
+        (any::<u64>(), any::<u8>(), any::<u64>()).prop_map(
+            |(first_job_id, count, amount_per_job)| RNDRInstruction::FundJobBundle {
+                first_job_id,
+                count,
+                amount_per_job,
+            }
+        ),
+        Just(RNDRInstruction::CancelJobBundle),
+        Just(RNDRInstruction::AggregateJobBundle),
+        (0..=MAX_JOB_TREE_DEPTH, any::<u32>()).prop_map(|(max_depth, max_buffer_size)| {
+            RNDRInstruction::InitJobTree {
+                max_depth,
+                max_buffer_size,
+            }
+        }),
+        any::<u64>().prop_map(|amount| RNDRInstruction::AppendCompressedJob { amount }),
+        (compressed_job(), pubkey(), any::<[u8; 32]>()).prop_map(|(job, node, root)| {
+            RNDRInstruction::ReplaceCompressedJob { job, node, root }
+        }),
+        (compressed_job(), any::<[u8; 32]>())
+            .prop_map(|(job, root)| RNDRInstruction::SettleCompressedJob { job, root }),
+        Just(RNDRInstruction::InitJobIndex),
+        job_metadata().prop_map(|metadata| RNDRInstruction::UpdateJobMetadata { metadata }),
+        Just(RNDRInstruction::SetEscrowTokenAccount),
+        (any::<u64>(), any::<u64>()).prop_map(|(rate_numerator, rate_denominator)| {
+            RNDRInstruction::MigrateEscrowMint {
+                rate_numerator,
+                rate_denominator,
+            }
+        }),
+        Just(RNDRInstruction::ConvertEscrowMintVault),
+        Just(RNDRInstruction::RedenominateJobs),
+        Just(RNDRInstruction::ConvertIsolatedJobMint),
+        (pubkey(), any::<u64>()).prop_map(|(owner, escrow_id)| {
+            RNDRInstruction::InitEscrowWithSeed { owner, escrow_id }
+        }),
+        any::<u8>().prop_map(|priority| RNDRInstruction::RaiseJobPriority { priority }),
+        Just(RNDRInstruction::InitJobQueue),
+        any::<[u8; 32]>().prop_map(|commitment| RNDRInstruction::ClaimJob { commitment }),
+        Just(RNDRInstruction::SetJobExchangeRate),
+        (any::<u8>(), any::<u64>())
+            .prop_map(|(tier, milli_obh)| RNDRInstruction::EstimateJobCost { tier, milli_obh }),
+        pubkey().prop_map(|price_feed| RNDRInstruction::SetPriceFeed { price_feed }),
+        (any::<u64>(), any::<u64>(), any::<u64>()).prop_map(|(value, max_rndr_amount, job_id)| {
+            RNDRInstruction::FundJobAtOraclePrice {
+                value,
+                max_rndr_amount,
+                job_id,
+            }
+        }),
+        Just(RNDRInstruction::FreezeJob),
+        Just(RNDRInstruction::UnfreezeJob),
+        Just(RNDRInstruction::SyncIncidentMode),
+        Just(RNDRInstruction::RegisterColdWallet),
+        any::<u64>().prop_map(|amount| RNDRInstruction::ScheduleTreasurySweep { amount }),
+        Just(RNDRInstruction::SweepToColdWallet),
+        Just(RNDRInstruction::CancelTreasurySweep),
+        Just(RNDRInstruction::GetVersion),
+        Just(RNDRInstruction::GetEscrowSummary),
+        Just(RNDRInstruction::GetJobSummary),
+        Just(RNDRInstruction::GetEscrowedAmount),
+        Just(RNDRInstruction::ReconcileEscrow),
+    ]
+}
+
+/// Tag each instruction is packed with. The match is exhaustive so that adding a variant fails to
+/// compile until it's covered here and in `instruction()`.
+fn tag(instruction: &RNDRInstruction) -> u8 {
+    match instruction {
+        RNDRInstruction::InitEscrow { .. } => 0,
+        RNDRInstruction::SetEscrowOwner { .. } => 1,
+        RNDRInstruction::FundJob { .. } => 2,
+        RNDRInstruction::DisburseFunds { .. } => 3,
+        RNDRInstruction::AggregateEscrowShard => 4,
+        RNDRInstruction::FundIsolatedJob { .. } => 5,
+        RNDRInstruction::DisburseIsolatedJobFunds { .. } => 6,
+        RNDRInstruction::DisburseBatch { .. } => 7,
+        RNDRInstruction::FundJobs { .. } => 8,
+        RNDRInstruction::CommitResult { .. } => 9,
+        RNDRInstruction::RevealResult { .. } => 10,
+        RNDRInstruction::ClaimPayment => 11,
+        RNDRInstruction::SetChallengeWindow { .. } => 12,
+        RNDRInstruction::ChallengeResult => 13,
+        RNDRInstruction::AddVerifier { .. } => 14,
+        RNDRInstruction::RemoveVerifier { .. } => 15,
+        RNDRInstruction::FlagJob { .. } => 16,
+        RNDRInstruction::SelectVerifiers { .. } => 17,
+        RNDRInstruction::SetProofVerifier { .. } => 18,
+        RNDRInstruction::SetArbitrator { .. } => 19,
+        RNDRInstruction::OpenDispute => 20,
+        RNDRInstruction::SubmitEvidence { .. } => 21,
+        RNDRInstruction::ResolveDispute { .. } => 22,
+        RNDRInstruction::InitConfig { .. } => 23,
+        RNDRInstruction::SetConfig { .. } => 24,
+        RNDRInstruction::VerifyUpgradeAuthority => 25,
+        RNDRInstruction::SetApprover { .. } => 26,
+        RNDRInstruction::ProposeDisbursement { .. } => 27,
+        RNDRInstruction::ApproveDisbursement => 28,
+        RNDRInstruction::ExecuteDisbursement => 29,
+        RNDRInstruction::QueueAction { .. } => 30,
+        RNDRInstruction::ExecuteQueuedAction => 31,
+        RNDRInstruction::CancelQueuedAction => 32,
+        RNDRInstruction::SetRecoveryCouncil { .. } => 33,
+        RNDRInstruction::ApproveRecovery { .. } => 34,
+        RNDRInstruction::ExecuteRecovery => 35,
+        RNDRInstruction::ScheduleOwnerRotation { .. } => 36,
+        RNDRInstruction::FundRewardPool { .. } => 37,
+        RNDRInstruction::ClaimEpochReward => 38,
+        RNDRInstruction::PublishMerkleRoot { .. } => 39,
+        RNDRInstruction::ClaimMerkleReward { .. } => 40,
+        RNDRInstruction::SetEmissionSchedule { .. } => 41,
+        RNDRInstruction::EmitRewards => 42,
+        RNDRInstruction::Stake { .. } => 43,
+        RNDRInstruction::Unstake { .. } => 44,
+        RNDRInstruction::SetAutoCompound { .. } => 45,
+        RNDRInstruction::CompoundRewards => 46,
+        RNDRInstruction::LiquidStake { .. } => 47,
+        RNDRInstruction::LiquidUnstake { .. } => 48,
+        RNDRInstruction::DepositStakeRewards { .. } => 49,
+        RNDRInstruction::SetCrankBounty { .. } => 50,
+        RNDRInstruction::SetTreasuryBurnCap { .. } => 51,
+        RNDRInstruction::BurnTreasury { .. } => 52,
+        RNDRInstruction::InitStats => 53,
+        RNDRInstruction::FundJobWithVoucher { .. } => 54,
+        RNDRInstruction::RegisterSessionKey { .. } => 55,
+        RNDRInstruction::ApproveDelegate { .. } => 56,
+        RNDRInstruction::RevokeDelegate => 57,
+        RNDRInstruction::CreateSubscription { .. } => 58,
+        RNDRInstruction::ProcessSubscription => 59,
+        RNDRInstruction::CancelJob => 60,
+        RNDRInstruction::TransferJobAuthority { .. } => 61,
+        RNDRInstruction::AcceptJobAuthority => 62,
+        RNDRInstruction::CreateJobBundle { .. } => 63,
+        RNDRInstruction::FundJobBundle { .. } => 64,
+        RNDRInstruction::CancelJobBundle => 65,
+        RNDRInstruction::AggregateJobBundle => 66,
+        RNDRInstruction::InitJobTree { .. } => 67,
+        RNDRInstruction::AppendCompressedJob { .. } => 68,
+        RNDRInstruction::ReplaceCompressedJob { .. } => 69,
+        RNDRInstruction::SettleCompressedJob { .. } => 70,
+        RNDRInstruction::InitJobIndex => 71,
+        RNDRInstruction::UpdateJobMetadata { .. } => 72,
+        RNDRInstruction::SetEscrowTokenAccount => 73,
+        RNDRInstruction::MigrateEscrowMint { .. } => 74,
+        RNDRInstruction::ConvertEscrowMintVault => 75,
+        RNDRInstruction::RedenominateJobs => 76,
+        RNDRInstruction::ConvertIsolatedJobMint => 77,
+        RNDRInstruction::InitEscrowWithSeed { .. } => 78,
+        RNDRInstruction::RaiseJobPriority { .. } => 79,
+        RNDRInstruction::InitJobQueue => 80,
+        RNDRInstruction::ClaimJob { .. } => 81,
+        RNDRInstruction::SetJobExchangeRate => 82,
+        RNDRInstruction::EstimateJobCost { .. } => 83,
+        RNDRInstruction::SetPriceFeed { .. } => 84,
+        RNDRInstruction::FundJobAtOraclePrice { .. } => 85,
+        RNDRInstruction::FreezeJob => 86,
+        RNDRInstruction::UnfreezeJob => 87,
+        RNDRInstruction::SyncIncidentMode => 88,
+        RNDRInstruction::RegisterColdWallet => 89,
+        RNDRInstruction::ScheduleTreasurySweep { .. } => 90,
+        RNDRInstruction::SweepToColdWallet => 91,
+        RNDRInstruction::CancelTreasurySweep => 92,
+        RNDRInstruction::GetVersion => 93,
+        RNDRInstruction::GetEscrowSummary => 94,
+        RNDRInstruction::GetJobSummary => 95,
+        RNDRInstruction::GetEscrowedAmount => 96,
+        RNDRInstruction::ReconcileEscrow => 97,
+    }
+}
+
+fn owner_rotation() -> impl Strategy<Value = OwnerRotation> {
+    (pubkey(), any::<u64>()).prop_map(|(previous_owner, slot)| OwnerRotation {
+        previous_owner,
+        slot,
+    })
+}
+
+fn escrow() -> impl Strategy<Value = Escrow> {
+    (
+        any::<u64>(),
+        pubkey(),
+        pubkey(),
+        any::<u64>(),
+        pubkey(),
+        pubkey(),
+        pubkey(),
+        vec(owner_rotation(), 0..=MAX_OWNER_HISTORY),
+        (pubkey(), pubkey(), any::<u8>(), any::<u64>(), pubkey()),
+        (incident_mode(), any::<bool>()),
+    )
+        .prop_map(
+            |(
+                amount,
+                owner,
+                token_mint,
+                challenge_window,
+                proof_verifier,
+                arbitrator,
+                approver,
+                owner_history,
+                (token_account, original_token_mint, mint_migrations, escrow_id, price_feed),
+                (incident_mode, needs_attention),
+            )| {
+                Escrow {
+                    account_type: AccountType::EscrowV1,
+                    amount,
+                    owner,
+                    token_mint,
+                    challenge_window,
+                    proof_verifier,
+                    arbitrator,
+                    approver,
+                    owner_history,
+                    token_account,
+                    original_token_mint,
+                    mint_migrations,
+                    escrow_id,
+                    price_feed,
+                    incident_mode,
+                    needs_attention,
+                }
+            },
+        )
+}
+
+fn incident_mode() -> impl Strategy<Value = IncidentMode> {
+    prop_oneof![
+        Just(IncidentMode::Normal),
+        Just(IncidentMode::FundOnly),
+        Just(IncidentMode::DisburseOnly),
+    ]
+}
+
+fn job_status() -> impl Strategy<Value = JobStatus> {
+    prop_oneof![
+        Just(JobStatus::Open),
+        Just(JobStatus::Committed),
+        Just(JobStatus::Revealed),
+        Just(JobStatus::Paid),
+        Just(JobStatus::Challenged),
+        Just(JobStatus::Disputed),
+        Just(JobStatus::Resolved),
+        Just(JobStatus::Cancelled),
+    ]
+}
+
+fn job() -> impl Strategy<Value = Job> {
+    (
+        (any::<u64>(), pubkey(), any::<bool>(), any::<u64>()),
+        (
+            job_status(),
+            pubkey(),
+            any::<[u8; 32]>(),
+            any::<[u8; 32]>(),
+            any::<i64>(),
+        ),
+        (
+            pubkey(),
+            any::<[u8; 32]>(),
+            any::<i64>(),
+            pubkey(),
+            pubkey(),
+        ),
+        job_metadata(),
+        (any::<u8>(), any::<u8>()),
+        (
+            prop_oneof![Just((0, 0)), (1..=u64::MAX, 1..=u64::MAX)],
+            any::<u64>(),
+            any::<bool>(),
+        ),
+    )
+        .prop_map(
+            |(
+                (amount, authority, isolated, id),
+                (status, node, result_commitment, result_hash, revealed_at),
+                (verifier, evidence_hash, funded_at, pending_authority, original_authority),
+                metadata,
+                (mint_migrations, priority),
+                ((exchange_rate_numerator, exchange_rate_denominator), exchange_rate_slot, frozen),
+            )| Job {
+                account_type: AccountType::JobV1,
+                amount,
+                authority,
+                isolated,
+                id,
+                status,
+                node,
+                result_commitment,
+                result_hash,
+                revealed_at,
+                verifier,
+                evidence_hash,
+                funded_at,
+                pending_authority,
+                original_authority,
+                metadata_uri: metadata.uri,
+                metadata_hash: metadata.hash,
+                tags: metadata.tags,
+                mint_migrations,
+                priority,
+                exchange_rate_numerator,
+                exchange_rate_denominator,
+                exchange_rate_slot,
+                frozen,
+            },
+        )
+}
+
+fn job_metadata() -> impl Strategy<Value = JobMetadata> {
+    ("[a-z0-9:/.]{0,96}", any::<[u8; 32]>(), any::<u64>())
+        .prop_map(|(uri, hash, tags)| JobMetadata { uri, hash, tags })
+}
+
+fn escrow_shard() -> impl Strategy<Value = EscrowShard> {
+    (pubkey(), 0..ESCROW_SHARD_COUNT, any::<u64>()).prop_map(|(escrow, index, amount)| {
+        EscrowShard {
+            account_type: AccountType::EscrowShardV1,
+            escrow,
+            index,
+            amount,
+        }
+    })
+}
+
+fn verifier_registry() -> impl Strategy<Value = VerifierRegistry> {
+    (pubkey(), vec(pubkey(), 0..=MAX_VERIFIERS)).prop_map(|(escrow, verifiers)| VerifierRegistry {
+        account_type: AccountType::VerifierRegistryV1,
+        escrow,
+        verifiers,
+    })
+}
+
+fn verifier_selection() -> impl Strategy<Value = VerifierSelection> {
+    (
+        pubkey(),
+        any::<u64>(),
+        vec(pubkey(), 0..=MAX_SELECTED_VERIFIERS),
+    )
+        .prop_map(|(job, slot, verifiers)| VerifierSelection {
+            account_type: AccountType::VerifierSelectionV1,
+            job,
+            slot,
+            verifiers,
+        })
+}
+
+fn dispute() -> impl Strategy<Value = Dispute> {
+    (
+        (pubkey(), pubkey(), any::<i64>()),
+        (any::<[u8; 32]>(), any::<[u8; 32]>()),
+        (any::<i64>(), any::<u64>(), any::<u64>()),
+    )
+        .prop_map(
+            |(
+                (job, opener, opened_at),
+                (authority_evidence_hash, node_evidence_hash),
+                (resolved_at, node_amount, authority_amount),
+            )| Dispute {
+                account_type: AccountType::DisputeV1,
+                job,
+                opener,
+                opened_at,
+                authority_evidence_hash,
+                node_evidence_hash,
+                resolved_at,
+                node_amount,
+                authority_amount,
+            },
+        )
+}
+
+fn proposal() -> impl Strategy<Value = Proposal> {
+    (
+        (pubkey(), any::<u64>(), pubkey(), pubkey()),
+        (any::<u64>(), any::<i64>(), any::<i64>()),
+    )
+        .prop_map(
+            |((escrow, id, job, destination), (amount, approved_at, executed_at))| Proposal {
+                account_type: AccountType::ProposalV1,
+                escrow,
+                id,
+                job,
+                destination,
+                amount,
+                approved_at,
+                executed_at,
+            },
+        )
+}
+
+fn config_change() -> impl Strategy<Value = ConfigChange> {
+    prop_oneof![
+        pubkey().prop_map(ConfigChange::Governance),
+        rate(Rate::ONE).prop_map(ConfigChange::FeeBps),
+        any::<u64>().prop_map(ConfigChange::MinStake),
+        any::<u64>().prop_map(ConfigChange::DisputeWindow),
+        any::<u64>().prop_map(ConfigChange::TimelockDuration),
+        any::<u64>().prop_map(ConfigChange::RateLimitWindow),
+        any::<u32>().prop_map(ConfigChange::RateLimitMax),
+        pubkey().prop_map(ConfigChange::Guardian),
+        any::<bool>().prop_map(ConfigChange::InstantOwnerChange),
+        any::<u32>().prop_map(ConfigChange::TimelockedFields),
+        (any::<u8>(), reward_tier())
+            .prop_map(|(index, reward_tier)| ConfigChange::RewardTier(index, reward_tier)),
+        any::<u64>().prop_map(ConfigChange::CancelGracePeriod),
+        rate(Rate::ONE).prop_map(ConfigChange::CancelFeeBps),
+        rate(Rate::ONE).prop_map(ConfigChange::PrioritySurchargeBps),
+        any::<u64>().prop_map(ConfigChange::PriorityClaimDelay),
+        rounding_policy().prop_map(ConfigChange::RoundingPolicy),
+        any::<u64>().prop_map(ConfigChange::MinDisbursement),
+        (any::<u8>(), any::<u64>())
+            .prop_map(|(index, obh_price)| ConfigChange::ObhPrice(index, obh_price)),
+        any::<u64>().prop_map(ConfigChange::MaxPriceAge),
+        rate(Rate::ONE).prop_map(ConfigChange::MaxPriceConfidenceBps),
+        pubkey().prop_map(ConfigChange::Compliance),
+        any::<u64>().prop_map(ConfigChange::SweepDelay),
+        incident_mode().prop_map(ConfigChange::IncidentMode),
+    ]
+}
+
+fn rounding_policy() -> impl Strategy<Value = RoundingPolicy> {
+    prop_oneof![
+        Just(RoundingPolicy::FirstRecipient),
+        Just(RoundingPolicy::Treasury),
+        Just(RoundingPolicy::Burn),
+    ]
+}
+
+fn reward_tier() -> impl Strategy<Value = RewardTier> {
+    (any::<u64>(), any::<u16>()).prop_map(|(min_stake, multiplier_bps)| RewardTier {
+        min_stake,
+        multiplier_bps,
+    })
+}
+
+fn admin_action() -> impl Strategy<Value = AdminAction> {
+    prop_oneof![
+        pubkey().prop_map(AdminAction::SetEscrowOwner),
+        config_change().prop_map(AdminAction::SetConfig),
+    ]
+}
+
+fn event() -> impl Strategy<Value = RNDREvent> {
+    prop_oneof![
+        (any::<u64>(), pubkey(), any::<u64>()).prop_map(|(amount, node, epoch)| {
+            RNDREvent::WorkCredited(events::WorkCredited {
+                amount,
+                node,
+                epoch,
+            })
+        }),
+        any::<u16>().prop_map(|multiplier_bps| RNDREvent::RewardMultiplierApplied(
+            events::RewardMultiplierApplied { multiplier_bps }
+        )),
+        (config_change(), config_change()).prop_map(|(previous, change)| RNDREvent::ConfigChanged(
+            events::ConfigChanged { previous, change }
+        )),
+        (admin_action(), any::<i64>()).prop_map(|(action, executable_at)| RNDREvent::ActionQueued(
+            events::ActionQueued {
+                action,
+                executable_at
+            }
+        )),
+        (pubkey(), pubkey(), pubkey(), any::<i64>()).prop_map(
+            |(escrow, previous_owner, new_owner, executable_at)| RNDREvent::OwnerRotationScheduled(
+                events::OwnerRotationScheduled {
+                    escrow,
+                    previous_owner,
+                    new_owner,
+                    executable_at
+                }
+            )
+        ),
+        (any::<u64>(), any::<u64>()).prop_map(|(amount, epoch)| RNDREvent::RewardPoolFunded(
+            events::RewardPoolFunded { amount, epoch }
+        )),
+        (any::<u64>(), any::<u32>(), any::<u64>()).prop_map(|(amount, node_count, epoch)| {
+            RNDREvent::MerkleRootPublished(events::MerkleRootPublished {
+                amount,
+                node_count,
+                epoch,
+            })
+        }),
+        (any::<u64>(), any::<u32>(), any::<u64>()).prop_map(|(amount, index, epoch)| {
+            RNDREvent::MerkleRewardClaimed(events::MerkleRewardClaimed {
+                amount,
+                index,
+                epoch,
+            })
+        }),
+        (any::<u64>(), any::<u64>(), any::<u16>()).prop_map(
+            |(initial_amount, start_epoch, decay_bps)| RNDREvent::EmissionScheduleSet(
+                events::EmissionScheduleSet {
+                    initial_amount,
+                    start_epoch,
+                    decay_bps
+                }
+            )
+        ),
+        (any::<u64>(), any::<u64>()).prop_map(|(amount, epoch)| RNDREvent::RewardsEmitted(
+            events::RewardsEmitted { amount, epoch }
+        )),
+        any::<u64>().prop_map(|amount| RNDREvent::Staked(events::Staked { amount })),
+        any::<u64>().prop_map(|amount| RNDREvent::Unstaked(events::Unstaked { amount })),
+        any::<bool>().prop_map(|auto_compound| RNDREvent::AutoCompoundSet(
+            events::AutoCompoundSet { auto_compound }
+        )),
+        any::<u64>()
+            .prop_map(|reward| RNDREvent::RewardsCompounded(events::RewardsCompounded { reward })),
+        (any::<u64>(), any::<u64>()).prop_map(|(amount, receipt_amount)| RNDREvent::LiquidStaked(
+            events::LiquidStaked {
+                amount,
+                receipt_amount
+            }
+        )),
+        (any::<u64>(), any::<u64>()).prop_map(
+            |(amount, receipt_amount)| RNDREvent::LiquidUnstaked(events::LiquidUnstaked {
+                amount,
+                receipt_amount
+            })
+        ),
+        any::<u64>().prop_map(|amount| RNDREvent::StakeRewardsDeposited(
+            events::StakeRewardsDeposited { amount }
+        )),
+        any::<u64>()
+            .prop_map(|bounty| RNDREvent::CrankBountySet(events::CrankBountySet { bounty })),
+        any::<u64>().prop_map(|epoch_burn_cap| RNDREvent::TreasuryBurnCapSet(
+            events::TreasuryBurnCapSet { epoch_burn_cap }
+        )),
+        (any::<u64>(), any::<u64>()).prop_map(|(amount, epoch)| RNDREvent::TreasuryBurned(
+            events::TreasuryBurned { amount, epoch }
+        )),
+        (any::<u64>(), pubkey(), any::<u64>()).prop_map(|(nonce, authority, job_id)| {
+            RNDREvent::VoucherUsed(events::VoucherUsed {
+                nonce,
+                authority,
+                job_id,
+            })
+        }),
+        (pubkey(), any::<u64>(), any::<u64>()).prop_map(
+            |(session_key, expiry_slot, instruction_mask)| RNDREvent::SessionKeyRegistered(
+                events::SessionKeyRegistered {
+                    session_key,
+                    expiry_slot,
+                    instruction_mask
+                }
+            )
+        ),
+        (pubkey(), any::<u64>(), any::<u64>()).prop_map(|(delegate, limit, expiry_slot)| {
+            RNDREvent::DelegateApproved(events::DelegateApproved {
+                delegate,
+                limit,
+                expiry_slot,
+            })
+        }),
+        pubkey()
+            .prop_map(|delegate| RNDREvent::DelegateRevoked(events::DelegateRevoked { delegate })),
+        (any::<u64>(), any::<u64>(), any::<u64>()).prop_map(|(amount, period, job_id)| {
+            RNDREvent::SubscriptionCreated(events::SubscriptionCreated {
+                amount,
+                period,
+                job_id,
+            })
+        }),
+        (any::<u64>(), any::<u64>(), any::<u64>()).prop_map(|(amount, job_id, next_slot)| {
+            RNDREvent::SubscriptionProcessed(events::SubscriptionProcessed {
+                amount,
+                job_id,
+                next_slot,
+            })
+        }),
+        (pubkey(), any::<u64>(), any::<u64>(), pubkey()).prop_map(|(job, refund, fee, node)| {
+            RNDREvent::JobCancelled(events::JobCancelled {
+                job,
+                refund,
+                fee,
+                node,
+            })
+        }),
+        (pubkey(), pubkey()).prop_map(|(job, new_authority)| {
+            RNDREvent::JobAuthorityTransferStarted(events::JobAuthorityTransferStarted {
+                job,
+                new_authority,
+            })
+        }),
+        (pubkey(), pubkey(), pubkey()).prop_map(|(job, previous_authority, new_authority)| {
+            RNDREvent::JobAuthorityTransferred(events::JobAuthorityTransferred {
+                job,
+                previous_authority,
+                new_authority,
+            })
+        }),
+        (any::<u8>(), any::<u64>()).prop_map(|(job_count, first_job_id)| {
+            RNDREvent::JobBundleCreated(events::JobBundleCreated {
+                job_count,
+                first_job_id,
+            })
+        }),
+        (any::<u64>(), any::<u8>(), any::<u64>()).prop_map(
+            |(amount_per_job, count, first_job_id)| RNDREvent::JobBundleFunded(
+                events::JobBundleFunded {
+                    amount_per_job,
+                    count,
+                    first_job_id
+                }
+            )
+        ),
+        (any::<u8>(), any::<u64>()).prop_map(|(cancelled, refund)| RNDREvent::JobBundleCancelled(
+            events::JobBundleCancelled { cancelled, refund }
+        )),
+        (any::<u8>(), any::<u8>(), any::<u8>()).prop_map(|(completed, cancelled, job_count)| {
+            RNDREvent::JobBundleAggregated(events::JobBundleAggregated {
+                completed,
+                cancelled,
+                job_count,
+            })
+        }),
+        pubkey().prop_map(
+            |job_bundle| RNDREvent::JobBundleCompleted(events::JobBundleCompleted { job_bundle })
+        ),
+        any::<u8>()
+            .prop_map(|max_depth| RNDREvent::JobTreeCreated(events::JobTreeCreated { max_depth })),
+        (any::<u64>(), any::<u64>()).prop_map(|(id, amount)| RNDREvent::CompressedJobAppended(
+            events::CompressedJobAppended { id, amount }
+        )),
+        (any::<u64>(), pubkey()).prop_map(|(id, node)| RNDREvent::CompressedJobReplaced(
+            events::CompressedJobReplaced { id, node }
+        )),
+        (any::<u64>(), any::<u64>(), pubkey()).prop_map(|(id, amount, node)| {
+            RNDREvent::CompressedJobPaid(events::CompressedJobPaid { id, amount, node })
+        }),
+        (any::<u64>(), any::<u64>()).prop_map(|(id, amount)| RNDREvent::CompressedJobCancelled(
+            events::CompressedJobCancelled { id, amount }
+        )),
+        (pubkey(), pubkey(), pubkey()).prop_map(|(escrow, previous_owner, new_owner)| {
+            RNDREvent::OwnerRotated(events::OwnerRotated {
+                escrow,
+                previous_owner,
+                new_owner,
+            })
+        }),
+        admin_action()
+            .prop_map(|action| RNDREvent::ActionCancelled(events::ActionCancelled { action })),
+        (any::<u8>(), any::<u8>()).prop_map(|(threshold, guardian_count)| {
+            RNDREvent::RecoveryCouncilSet(events::RecoveryCouncilSet {
+                threshold,
+                guardian_count,
+            })
+        }),
+        (pubkey(), any::<i64>()).prop_map(|(new_owner, executable_at)| RNDREvent::RecoveryStarted(
+            events::RecoveryStarted {
+                new_owner,
+                executable_at
+            }
+        )),
+        pubkey().prop_map(
+            |owner| RNDREvent::EscrowOwnerRecovered(events::EscrowOwnerRecovered { owner })
+        ),
+        (any::<u64>(), any::<u64>()).prop_map(|(reward, epoch)| RNDREvent::EpochRewardClaimed(
+            events::EpochRewardClaimed { reward, epoch }
+        )),
+        (any::<u64>(), pubkey()).prop_map(|(bounty, recipient)| RNDREvent::CrankBountyPaid(
+            events::CrankBountyPaid { bounty, recipient }
+        )),
+        (any::<u64>(), any::<u64>()).prop_map(|(work, epoch)| RNDREvent::EpochWorkRecorded(
+            events::EpochWorkRecorded { work, epoch }
+        )),
+        (pubkey(), job_metadata()).prop_map(|(job, metadata)| RNDREvent::JobMetadataUpdated(
+            events::JobMetadataUpdated { job, metadata }
+        )),
+        (pubkey(), pubkey(), any::<u64>()).prop_map(
+            |(previous_token_account, token_account, amount)| RNDREvent::EscrowTokenAccountSet(
+                events::EscrowTokenAccountSet {
+                    previous_token_account,
+                    token_account,
+                    amount,
+                }
+            )
+        ),
+        (pubkey(), pubkey(), any::<u64>(), any::<u64>()).prop_map(
+            |(mint_migration, new_token_mint, rate_numerator, rate_denominator)| {
+                RNDREvent::MintMigrationBegun(events::MintMigrationBegun {
+                    mint_migration,
+                    new_token_mint,
+                    rate_numerator,
+                    rate_denominator,
+                })
+            }
+        ),
+        (pubkey(), pubkey(), any::<u64>(), any::<u64>()).prop_map(
+            |(mint_migration, token_account, amount, converted_amount)| {
+                RNDREvent::MintMigrationTokensConverted(events::MintMigrationTokensConverted {
+                    mint_migration,
+                    token_account,
+                    amount,
+                    converted_amount,
+                })
+            }
+        ),
+        (pubkey(), any::<u64>(), any::<u64>()).prop_map(
+            |(mint_migration, count, jobs_migrated)| RNDREvent::JobsMigrated(
+                events::JobsMigrated {
+                    mint_migration,
+                    count,
+                    jobs_migrated,
+                }
+            )
+        ),
+        (pubkey(), any::<u8>(), any::<u64>()).prop_map(|(job, priority, surcharge)| {
+            RNDREvent::JobPriorityRaised(events::JobPriorityRaised {
+                job,
+                priority,
+                surcharge,
+            })
+        }),
+        (pubkey(), any::<u64>(), any::<u64>(), any::<u64>()).prop_map(
+            |(job, rate_numerator, rate_denominator, oracle_slot)| {
+                RNDREvent::JobExchangeRateSet(events::JobExchangeRateSet {
+                    job,
+                    rate_numerator,
+                    rate_denominator,
+                    oracle_slot,
+                })
+            }
+        ),
+        (
+            pubkey(),
+            any::<u64>(),
+            any::<u64>(),
+            any::<u64>(),
+            any::<u64>()
+        )
+            .prop_map(|(job, value, amount, price, oracle_slot)| {
+                RNDREvent::JobFundedAtOraclePrice(events::JobFundedAtOraclePrice {
+                    job,
+                    value,
+                    amount,
+                    price,
+                    oracle_slot,
+                })
+            }),
+        pubkey().prop_map(|job| RNDREvent::JobFrozen(events::JobFrozen { job })),
+        pubkey().prop_map(|job| RNDREvent::JobUnfrozen(events::JobUnfrozen { job })),
+        (pubkey(), incident_mode()).prop_map(|(escrow, mode)| {
+            RNDREvent::IncidentModeSet(events::IncidentModeSet { escrow, mode })
+        }),
+        (pubkey(), pubkey()).prop_map(|(treasury, cold_wallet)| {
+            RNDREvent::ColdWalletRegistered(events::ColdWalletRegistered {
+                treasury,
+                cold_wallet,
+            })
+        }),
+        (pubkey(), pubkey(), any::<u64>(), any::<i64>()).prop_map(
+            |(treasury, cold_wallet, amount, executable_at)| {
+                RNDREvent::TreasurySweepScheduled(events::TreasurySweepScheduled {
+                    treasury,
+                    cold_wallet,
+                    amount,
+                    executable_at,
+                })
+            }
+        ),
+        (pubkey(), pubkey(), any::<u64>()).prop_map(|(treasury, cold_wallet, amount)| {
+            RNDREvent::TreasurySwept(events::TreasurySwept {
+                treasury,
+                cold_wallet,
+                amount,
+            })
+        }),
+        (pubkey(), any::<u64>()).prop_map(|(treasury, amount)| {
+            RNDREvent::TreasurySweepCancelled(events::TreasurySweepCancelled { treasury, amount })
+        }),
+        (pubkey(), any::<u64>(), any::<u64>()).prop_map(|(escrow, balance, tracked)| {
+            RNDREvent::EscrowDiscrepancyFound(events::EscrowDiscrepancyFound {
+                escrow,
+                balance,
+                tracked,
+            })
+        }),
+        (pubkey(), any::<u64>(), any::<u64>()).prop_map(|(job, amount, rndr_value)| {
+            RNDREvent::JobSettledAtExchangeRate(events::JobSettledAtExchangeRate {
+                job,
+                amount,
+                rndr_value,
+            })
+        }),
+    ]
+}
+
+/// Discriminator each event is packed with. The match is exhaustive so that adding a variant fails to
+/// compile until it's covered here and in `event()`.
+fn discriminator(event: &RNDREvent) -> u8 {
+    match event {
+        RNDREvent::WorkCredited(_) => 0,
+        RNDREvent::RewardMultiplierApplied(_) => 1,
+        RNDREvent::ConfigChanged(_) => 2,
+        RNDREvent::ActionQueued(_) => 3,
+        RNDREvent::OwnerRotationScheduled(_) => 4,
+        RNDREvent::RewardPoolFunded(_) => 5,
+        RNDREvent::MerkleRootPublished(_) => 6,
+        RNDREvent::MerkleRewardClaimed(_) => 7,
+        RNDREvent::EmissionScheduleSet(_) => 8,
+        RNDREvent::RewardsEmitted(_) => 9,
+        RNDREvent::Staked(_) => 10,
+        RNDREvent::Unstaked(_) => 11,
+        RNDREvent::AutoCompoundSet(_) => 12,
+        RNDREvent::RewardsCompounded(_) => 13,
+        RNDREvent::LiquidStaked(_) => 14,
+        RNDREvent::LiquidUnstaked(_) => 15,
+        RNDREvent::StakeRewardsDeposited(_) => 16,
+        RNDREvent::CrankBountySet(_) => 17,
+        RNDREvent::TreasuryBurnCapSet(_) => 18,
+        RNDREvent::TreasuryBurned(_) => 19,
+        RNDREvent::VoucherUsed(_) => 20,
+        RNDREvent::SessionKeyRegistered(_) => 21,
+        RNDREvent::DelegateApproved(_) => 22,
+        RNDREvent::DelegateRevoked(_) => 23,
+        RNDREvent::SubscriptionCreated(_) => 24,
+        RNDREvent::SubscriptionProcessed(_) => 25,
+        RNDREvent::JobCancelled(_) => 26,
+        RNDREvent::JobAuthorityTransferStarted(_) => 27,
+        RNDREvent::JobAuthorityTransferred(_) => 28,
+        RNDREvent::JobBundleCreated(_) => 29,
+        RNDREvent::JobBundleFunded(_) => 30,
+        RNDREvent::JobBundleCancelled(_) => 31,
+        RNDREvent::JobBundleAggregated(_) => 32,
+        RNDREvent::JobBundleCompleted(_) => 33,
+        RNDREvent::JobTreeCreated(_) => 34,
+        RNDREvent::CompressedJobAppended(_) => 35,
+        RNDREvent::CompressedJobReplaced(_) => 36,
+        RNDREvent::CompressedJobPaid(_) => 37,
+        RNDREvent::CompressedJobCancelled(_) => 38,
+        RNDREvent::OwnerRotated(_) => 39,
+        RNDREvent::ActionCancelled(_) => 40,
+        RNDREvent::RecoveryCouncilSet(_) => 41,
+        RNDREvent::RecoveryStarted(_) => 42,
+        RNDREvent::EscrowOwnerRecovered(_) => 43,
+        RNDREvent::EpochRewardClaimed(_) => 44,
+        RNDREvent::CrankBountyPaid(_) => 45,
+        RNDREvent::EpochWorkRecorded(_) => 46,
+        RNDREvent::JobMetadataUpdated(_) => 47,
+        RNDREvent::EscrowTokenAccountSet(_) => 48,
+        RNDREvent::MintMigrationBegun(_) => 49,
+        RNDREvent::MintMigrationTokensConverted(_) => 50,
+        RNDREvent::JobsMigrated(_) => 51,
+        RNDREvent::JobPriorityRaised(_) => 52,
+        RNDREvent::JobExchangeRateSet(_) => 53,
+        RNDREvent::JobFundedAtOraclePrice(_) => 54,
+        RNDREvent::JobFrozen(_) => 55,
+        RNDREvent::JobUnfrozen(_) => 56,
+        RNDREvent::IncidentModeSet(_) => 57,
+        RNDREvent::ColdWalletRegistered(_) => 58,
+        RNDREvent::TreasurySweepScheduled(_) => 59,
+        RNDREvent::TreasurySwept(_) => 60,
+        RNDREvent::TreasurySweepCancelled(_) => 61,
+        RNDREvent::EscrowDiscrepancyFound(_) => 62,
+        RNDREvent::JobSettledAtExchangeRate(_) => 63,
+    }
+}
+
+fn queued_action() -> impl Strategy<Value = QueuedAction> {
+    (
+        (pubkey(), any::<u64>(), pubkey(), admin_action()),
+        (any::<i64>(), any::<i64>(), any::<i64>(), any::<i64>()),
+    )
+        .prop_map(
+            |(
+                (target, id, authority, action),
+                (queued_at, executable_at, executed_at, cancelled_at),
+            )| QueuedAction {
+                account_type: AccountType::QueuedActionV1,
+                target,
+                id,
+                authority,
+                action,
+                queued_at,
+                executable_at,
+                executed_at,
+                cancelled_at,
+            },
+        )
+}
+
+fn recovery() -> impl Strategy<Value = Recovery> {
+    vec((pubkey(), pubkey()), 0..=MAX_RECOVERY_GUARDIANS)
+        .prop_flat_map(|guardian_votes| {
+            (
+                (pubkey(), any::<u8>(), Just(guardian_votes)),
+                (pubkey(), any::<i64>()),
+            )
+        })
+        .prop_map(
+            |((escrow, threshold, guardian_votes), (new_owner, executable_at))| {
+                let (guardians, votes) = guardian_votes.into_iter().unzip();
+                Recovery {
+                    account_type: AccountType::RecoveryV1,
+                    escrow,
+                    threshold,
+                    guardians,
+                    votes,
+                    new_owner,
+                    executable_at,
+                }
+            },
+        )
+}
+
+fn reward_pool() -> impl Strategy<Value = RewardPool> {
+    (
+        pubkey(),
+        any::<u64>(),
+        any::<u64>(),
+        any::<u64>(),
+        any::<u64>(),
+    )
+        .prop_map(|(escrow, epoch, amount, total_work, claimed)| RewardPool {
+            account_type: AccountType::RewardPoolV1,
+            escrow,
+            epoch,
+            amount,
+            total_work,
+            claimed,
+        })
+}
+
+fn epoch_work() -> impl Strategy<Value = EpochWork> {
+    (pubkey(), pubkey(), any::<u64>(), any::<bool>()).prop_map(
+        |(reward_pool, node, work, claimed)| EpochWork {
+            account_type: AccountType::EpochWorkV1,
+            reward_pool,
+            node,
+            work,
+            claimed,
+        },
+    )
+}
+
+fn merkle_distribution() -> impl Strategy<Value = MerkleDistribution> {
+    (
+        pubkey(),
+        any::<u64>(),
+        any::<[u8; 32]>(),
+        any::<u32>(),
+        any::<u64>(),
+        any::<u64>(),
+    )
+        .prop_map(
+            |(escrow, epoch, root, node_count, amount, claimed)| MerkleDistribution {
+                account_type: AccountType::MerkleDistributionV1,
+                escrow,
+                epoch,
+                root,
+                node_count,
+                amount,
+                claimed,
+            },
+        )
+}
+
+fn claim_bitmap() -> impl Strategy<Value = ClaimBitmap> {
+    (pubkey(), any::<u32>(), vec(any::<u8>(), CLAIM_BITMAP_BYTES)).prop_map(
+        |(merkle_distribution, chunk, bitmap)| ClaimBitmap {
+            account_type: AccountType::ClaimBitmapV1,
+            merkle_distribution,
+            chunk,
+            bitmap,
+        },
+    )
+}
+
+fn emission_schedule() -> impl Strategy<Value = EmissionSchedule> {
+    (
+        pubkey(),
+        any::<u64>(),
+        any::<u64>(),
+        rate(MAX_DECAY_BPS),
+        any::<u64>(),
+        any::<u64>(),
+    )
+        .prop_map(
+            |(escrow, start_epoch, initial_amount, decay_bps, next_epoch, emitted)| {
+                EmissionSchedule {
+                    account_type: AccountType::EmissionScheduleV1,
+                    escrow,
+                    start_epoch,
+                    initial_amount,
+                    decay_bps,
+                    next_epoch,
+                    emitted,
+                }
+            },
+        )
+}
+
+fn node() -> impl Strategy<Value = Node> {
+    (
+        pubkey(),
+        pubkey(),
+        any::<u64>(),
+        any::<bool>(),
+        any::<u64>(),
+        any::<u64>(),
+        any::<u64>(),
+        any::<u64>(),
+        any::<u64>(),
+    )
+        .prop_map(
+            |(
+                escrow,
+                authority,
+                stake,
+                auto_compound,
+                work_credits,
+                work_epoch,
+                epoch_work_credits,
+                stake_epoch,
+                epoch_staked,
+            )| Node {
+                account_type: AccountType::NodeV1,
+                escrow,
+                authority,
+                stake,
+                auto_compound,
+                work_credits,
+                work_epoch,
+                epoch_work_credits,
+                stake_epoch,
+                epoch_staked,
+            },
+        )
+}
+
+fn crank_vault() -> impl Strategy<Value = CrankVault> {
+    (pubkey(), any::<u64>(), any::<u64>()).prop_map(|(escrow, bounty, paid)| CrankVault {
+        account_type: AccountType::CrankVaultV1,
+        escrow,
+        bounty,
+        paid,
+    })
+}
+
+fn voucher_nonce() -> impl Strategy<Value = VoucherNonce> {
+    (pubkey(), pubkey(), any::<u64>()).prop_map(|(escrow, authority, nonce)| VoucherNonce {
+        account_type: AccountType::VoucherNonceV1,
+        escrow,
+        authority,
+        nonce,
+    })
+}
+
+fn delegate_allowance() -> impl Strategy<Value = DelegateAllowance> {
+    (pubkey(), pubkey(), any::<u64>(), any::<u64>()).prop_map(
+        |(escrow, delegate, remaining, expiry_slot)| DelegateAllowance {
+            account_type: AccountType::DelegateAllowanceV1,
+            escrow,
+            delegate,
+            remaining,
+            expiry_slot,
+        },
+    )
+}
+
+fn subscription() -> impl Strategy<Value = Subscription> {
+    (
+        pubkey(),
+        pubkey(),
+        any::<u64>(),
+        pubkey(),
+        any::<u64>(),
+        any::<u64>(),
+        any::<u64>(),
+    )
+        .prop_map(
+            |(escrow, authority, job_id, source_token, amount, period, next_slot)| Subscription {
+                account_type: AccountType::SubscriptionV1,
+                escrow,
+                authority,
+                job_id,
+                source_token,
+                amount,
+                period,
+                next_slot,
+            },
+        )
+}
+
+fn job_bundle() -> impl Strategy<Value = JobBundle> {
+    (
+        (pubkey(), pubkey(), any::<u64>(), any::<[u8; 32]>()),
+        (any::<u64>(), 1..=MAX_BUNDLE_JOBS, any::<u64>()),
+        (any::<u64>(), any::<u64>(), any::<bool>()),
+    )
+        .prop_map(
+            |(
+                (escrow, authority, id, metadata_hash),
+                (first_job_id, job_count, amount),
+                (completed, cancelled, is_cancelled),
+            )| {
+                // Only the bits of jobs in the bundle can be set, and a job can't be both
+                let mask = u64::MAX >> (64 - job_count as u32);
+                JobBundle {
+                    account_type: AccountType::JobBundleV1,
+                    escrow,
+                    authority,
+                    id,
+                    metadata_hash,
+                    first_job_id,
+                    job_count,
+                    amount,
+                    completed: completed & mask,
+                    cancelled: cancelled & mask & !completed,
+                    is_cancelled,
+                }
+            },
+        )
+}
+
+fn compressed_job() -> impl Strategy<Value = CompressedJob> {
+    (any::<u64>(), any::<u64>(), job_status(), pubkey()).prop_map(|(id, amount, status, node)| {
+        CompressedJob {
+            id,
+            amount,
+            status,
+            node,
+        }
+    })
+}
+
+fn job_tree() -> impl Strategy<Value = JobTree> {
+    (
+        (pubkey(), pubkey(), pubkey()),
+        (0..=MAX_JOB_TREE_DEPTH, any::<u64>(), any::<u64>()),
+    )
+        .prop_map(
+            |((escrow, authority, merkle_tree), (max_depth, leaf_count, amount))| JobTree {
+                account_type: AccountType::JobTreeV1,
+                escrow,
+                authority,
+                merkle_tree,
+                max_depth,
+                leaf_count,
+                amount,
+            },
+        )
+}
+
+fn job_index() -> impl Strategy<Value = JobIndex> {
+    (pubkey(), pubkey(), any::<u64>()).prop_map(|(escrow, authority, job_count)| JobIndex {
+        account_type: AccountType::JobIndexV1,
+        escrow,
+        authority,
+        job_count,
+    })
+}
+
+fn job_index_page() -> impl Strategy<Value = JobIndexPage> {
+    (
+        pubkey(),
+        any::<u32>(),
+        vec(any::<u64>(), 0..=JOB_INDEX_PAGE_LEN),
+    )
+        .prop_map(|(job_index, page, job_ids)| JobIndexPage {
+            account_type: AccountType::JobIndexPageV1,
+            job_index,
+            page,
+            job_ids,
+        })
+}
+
+fn job_queue() -> impl Strategy<Value = JobQueue> {
+    (
+        pubkey(),
+        0..JOB_QUEUE_LEN as u8,
+        vec(pubkey(), 0..=JOB_QUEUE_LEN),
+    )
+        .prop_map(|(escrow, head, jobs)| JobQueue {
+            account_type: AccountType::JobQueueV1,
+            escrow,
+            head,
+            jobs,
+        })
+}
+
+fn session_key() -> impl Strategy<Value = SessionKey> {
+    (pubkey(), pubkey(), pubkey(), any::<u64>(), any::<u64>()).prop_map(
+        |(escrow, authority, session_key, expiry_slot, instruction_mask)| SessionKey {
+            account_type: AccountType::SessionKeyV1,
+            escrow,
+            authority,
+            session_key,
+            expiry_slot,
+            instruction_mask,
+        },
+    )
+}
+
+fn treasury() -> impl Strategy<Value = Treasury> {
+    (
+        pubkey(),
+        pubkey(),
+        any::<u64>(),
+        any::<u64>(),
+        any::<u64>(),
+        any::<u128>(),
+        pubkey(),
+        any::<u64>(),
+        any::<i64>(),
+    )
+        .prop_map(
+            |(
+                escrow,
+                token_mint,
+                epoch_burn_cap,
+                burn_epoch,
+                epoch_burned,
+                total_burned,
+                cold_wallet,
+                sweep_amount,
+                sweep_executable_at,
+            )| Treasury {
+                account_type: AccountType::TreasuryV1,
+                escrow,
+                token_mint,
+                epoch_burn_cap,
+                burn_epoch,
+                epoch_burned,
+                total_burned,
+                cold_wallet,
+                sweep_amount,
+                sweep_executable_at,
+            },
+        )
+}
+
+fn stats() -> impl Strategy<Value = Stats> {
+    (
+        pubkey(),
+        any::<u64>(),
+        any::<[u128; 4]>(),
+        any::<[u64; 4]>(),
+    )
+        .prop_map(|(escrow, epoch, totals, amounts)| Stats {
+            account_type: AccountType::StatsV1,
+            escrow,
+            epoch,
+            total_escrowed: totals[0],
+            total_burned: totals[1],
+            total_emitted: totals[2],
+            total_disbursed: totals[3],
+            epoch_escrowed: amounts[0],
+            epoch_burned: amounts[1],
+            epoch_emitted: amounts[2],
+            epoch_disbursed: amounts[3],
+        })
+}
+
+fn stake_pool() -> impl Strategy<Value = StakePool> {
+    (pubkey(), pubkey(), pubkey(), any::<u64>(), any::<u64>()).prop_map(
+        |(escrow, token_mint, receipt_mint, total_staked, receipt_supply)| StakePool {
+            account_type: AccountType::StakePoolV1,
+            escrow,
+            token_mint,
+            receipt_mint,
+            total_staked,
+            receipt_supply,
+        },
+    )
+}
+
+fn mint_migration() -> impl Strategy<Value = MintMigration> {
+    (
+        (pubkey(), any::<u8>(), pubkey(), pubkey()),
+        (any::<u64>(), any::<u64>(), any::<bool>(), any::<u64>()),
+        (pubkey(), any::<i64>()),
+    )
+        .prop_map(
+            |(
+                (escrow, index, token_mint, new_token_mint),
+                (rate_numerator, rate_denominator, vault_converted, jobs_migrated),
+                (destination, executable_at),
+            )| MintMigration {
+                account_type: AccountType::MintMigrationV1,
+                escrow,
+                index,
+                token_mint,
+                new_token_mint,
+                rate_numerator,
+                rate_denominator,
+                vault_converted,
+                jobs_migrated,
+                destination,
+                executable_at,
+            },
+        )
+}
+
+/// Root of the Merkle tree of rewards and the proof of each leaf, pairing leaves in order and
+/// promoting the last hash of a level with an odd length
+fn merkle_tree(rewards: &[(Pubkey, u64)]) -> ([u8; 32], Vec<Vec<[u8; 32]>>) {
+    let mut level: Vec<_> = rewards
+        .iter()
+        .enumerate()
+        .map(|(index, (node, amount))| MerkleDistribution::leaf(index as u32, node, *amount))
+        .collect();
+    let mut positions: Vec<usize> = (0..rewards.len()).collect();
+    let mut proofs = vec![vec![]; rewards.len()];
+    while level.len() > 1 {
+        for (proof, position) in proofs.iter_mut().zip(&mut positions) {
+            if let Some(sibling) = level.get(*position ^ 1) {
+                proof.push(*sibling);
+            }
+            *position /= 2;
+        }
+        level = level
+            .chunks(2)
+            .map(|pair| match pair {
+                [a, b] if a <= b => hashv(&[a, b]).to_bytes(),
+                [a, b] => hashv(&[b, a]).to_bytes(),
+                [a] => *a,
+                _ => unreachable!(),
+            })
+            .collect();
+    }
+    (level[0], proofs)
+}
+
+fn config() -> impl Strategy<Value = Config> {
+    (
+        pubkey(),
+        rate(Rate::ONE),
+        any::<u64>(),
+        any::<u64>(),
+        any::<u64>(),
+        any::<u64>(),
+        any::<u32>(),
+        pubkey(),
+        any::<bool>(),
+        any::<u32>(),
+        [reward_tier(), reward_tier(), reward_tier(), reward_tier()],
+        (
+            any::<u64>(),
+            rate(Rate::ONE),
+            rate(Rate::ONE),
+            any::<u64>(),
+            rounding_policy(),
+            any::<u64>(),
+            any::<[u64; MAX_PRICE_TIERS]>(),
+            any::<u64>(),
+            rate(Rate::ONE),
+            pubkey(),
+            any::<u64>(),
+            incident_mode(),
+        ),
+    )
+        .prop_map(
+            |(
+                governance,
+                fee_bps,
+                min_stake,
+                dispute_window,
+                timelock_duration,
+                rate_limit_window,
+                rate_limit_max,
+                guardian,
+                instant_owner_change,
+                timelocked_fields,
+                reward_tiers,
+                (
+                    cancel_grace_period,
+                    cancel_fee_bps,
+                    priority_surcharge_bps,
+                    priority_claim_delay,
+                    rounding_policy,
+                    min_disbursement,
+                    obh_prices,
+                    max_price_age,
+                    max_price_confidence_bps,
+                    compliance,
+                    sweep_delay,
+                    incident_mode,
+                ),
+            )| Config {
+                account_type: AccountType::ConfigV1,
+                governance,
+                fee_bps,
+                min_stake,
+                dispute_window,
+                timelock_duration,
+                rate_limit_window,
+                rate_limit_max,
+                guardian,
+                instant_owner_change,
+                timelocked_fields,
+                reward_tiers,
+                cancel_grace_period,
+                cancel_fee_bps,
+                priority_surcharge_bps,
+                priority_claim_delay,
+                rounding_policy,
+                min_disbursement,
+                obh_prices,
+                max_price_age,
+                max_price_confidence_bps,
+                compliance,
+                sweep_delay,
+                incident_mode,
+            },
+        )
+}
+
+fn pack<T: Pack>(state: &T) -> Vec<u8> {
+    let mut data = vec![0; T::LEN];
+    state.pack_into_slice(&mut data);
+    data
+}
+
+proptest! {
+    #[test]
+    fn test_instruction_round_trip(instruction in instruction()) {
+        let packed = instruction.pack();
+        prop_assert_eq!(packed[0], tag(&instruction));
+        prop_assert_eq!(RNDRInstruction::unpack(&packed).unwrap(), instruction);
+    }
+
+    #[test]
+    fn test_instruction_unpack_pack(data in vec(any::<u8>(), 0..1024)) {
+        if let Ok(instruction) = RNDRInstruction::unpack(&data) {
+            // Trailing bytes are ignored by unpack, so only the packed prefix must match
+            let packed = instruction.pack();
+            prop_assert_eq!(&data[..packed.len()], &packed[..]);
+        }
+    }
+
+    #[test]
+    fn test_event_round_trip(event in event()) {
+        let packed = event.pack();
+        prop_assert_eq!(packed[0], EVENT_VERSION);
+        prop_assert_eq!(packed[1], discriminator(&event));
+        prop_assert_eq!(event.discriminator(), discriminator(&event));
+        prop_assert_eq!(parse_event(&packed).unwrap(), event);
+    }
+
+    #[test]
+    fn test_event_other_version(event in event(), version in any::<u8>()) {
+        prop_assume!(version != EVENT_VERSION);
+        let mut packed = event.pack();
+        packed[0] = version;
+        prop_assert!(parse_event(&packed).is_err());
+    }
+
+    #[test]
+    fn test_event_unpack_pack(mut data in vec(any::<u8>(), 2..256)) {
+        data[0] = EVENT_VERSION;
+        if let Ok(event) = parse_event(&data) {
+            // Fields may be appended to events, so trailing bytes are ignored too
+            let packed = event.pack();
+            prop_assert_eq!(&data[..packed.len()], &packed[..]);
+        }
+    }
+
+    #[test]
+    fn test_program_version_round_trip(
+        major in any::<u16>(),
+        minor in any::<u16>(),
+        patch in any::<u16>(),
+        layout_version in any::<u8>(),
+    ) {
+        let version = ProgramVersion { major, minor, patch, layout_version };
+        prop_assert_eq!(ProgramVersion::unpack(&version.pack()), Ok(version));
+    }
+
+    #[test]
+    fn test_escrow_summary_round_trip(escrow in escrow(), escrow_address in pubkey()) {
+        let summary = EscrowSummary::new(&escrow_address, &escrow);
+        prop_assert_eq!(EscrowSummary::unpack(&summary.pack()), Ok(summary));
+        prop_assert_eq!(summary.token_account, escrow.token_account(&escrow_address));
+    }
+
+    #[test]
+    fn test_job_summary_round_trip(job in job()) {
+        let summary = JobSummary::new(&job);
+        prop_assert_eq!(JobSummary::unpack(&summary.pack()), Ok(summary));
+    }
+
+    #[test]
+    fn test_escrow_round_trip(escrow in escrow()) {
+        let data = pack(&escrow);
+        prop_assert_eq!(&data[Escrow::AMOUNT_OFFSET..][..8], &escrow.amount.to_le_bytes()[..]);
+        prop_assert_eq!(&data[Escrow::OWNER_OFFSET..][..32], escrow.owner.as_ref());
+        prop_assert_eq!(&data[Escrow::TOKEN_MINT_OFFSET..][..32], escrow.token_mint.as_ref());
+        prop_assert_eq!(
+            &data[Escrow::CHALLENGE_WINDOW_OFFSET..][..8],
+            &escrow.challenge_window.to_le_bytes()[..]
+        );
+        prop_assert_eq!(
+            &data[Escrow::PROOF_VERIFIER_OFFSET..][..32],
+            escrow.proof_verifier.as_ref()
+        );
+        prop_assert_eq!(&data[Escrow::ARBITRATOR_OFFSET..][..32], escrow.arbitrator.as_ref());
+        prop_assert_eq!(&data[Escrow::APPROVER_OFFSET..][..32], escrow.approver.as_ref());
+        prop_assert_eq!(
+            data[Escrow::OWNER_HISTORY_LEN_OFFSET] as usize,
+            escrow.owner_history.len()
+        );
+        if let Some(rotation) = escrow.owner_history.first() {
+            prop_assert_eq!(
+                &data[Escrow::OWNER_HISTORY_OFFSET..][..32],
+                rotation.previous_owner.as_ref()
+            );
+        }
+        prop_assert_eq!(
+            &data[Escrow::TOKEN_ACCOUNT_OFFSET..][..32],
+            escrow.token_account.as_ref()
+        );
+        prop_assert_eq!(
+            &data[Escrow::ORIGINAL_TOKEN_MINT_OFFSET..][..32],
+            escrow.original_token_mint.as_ref()
+        );
+        prop_assert_eq!(data[Escrow::MINT_MIGRATIONS_OFFSET], escrow.mint_migrations);
+        prop_assert_eq!(&data[Escrow::ESCROW_ID_OFFSET..][..8], &escrow.escrow_id.to_le_bytes()[..]);
+        prop_assert_eq!(&data[Escrow::PRICE_FEED_OFFSET..][..32], escrow.price_feed.as_ref());
+        prop_assert_eq!(data[Escrow::INCIDENT_MODE_OFFSET], u8::from(escrow.incident_mode));
+        prop_assert_eq!(data[Escrow::NEEDS_ATTENTION_OFFSET], u8::from(escrow.needs_attention));
+        prop_assert_eq!(Escrow::unpack_from_slice(&data).unwrap(), escrow.clone());
+
+        // Escrows with a legacy length unpack with the appended fields set to their defaults
+        let legacy = Escrow::unpack_account(&data[..Escrow::PRE_NEEDS_ATTENTION_LEN]).unwrap();
+        let escrow = Escrow { needs_attention: false, ..escrow };
+        prop_assert_eq!(legacy, escrow.clone());
+        let legacy = Escrow::unpack_account(&data[..Escrow::PRE_INCIDENT_MODE_LEN]).unwrap();
+        let escrow = Escrow { incident_mode: IncidentMode::Normal, ..escrow };
+        prop_assert_eq!(legacy, escrow.clone());
+        let legacy = Escrow::unpack_account(&data[..Escrow::PRE_PRICE_FEED_LEN]).unwrap();
+        prop_assert_eq!(legacy, Escrow { price_feed: Pubkey::default(), ..escrow.clone() });
+        let legacy = Escrow::unpack_account(&data[..Escrow::PRE_ESCROW_ID_LEN]).unwrap();
+        prop_assert_eq!(
+            legacy,
+            Escrow {
+                escrow_id: 0,
+                price_feed: Pubkey::default(),
+                ..escrow.clone()
+            }
+        );
+        let legacy = Escrow::unpack_account(&data[..Escrow::PRE_MINT_MIGRATION_LEN]).unwrap();
+        prop_assert_eq!(
+            legacy,
+            Escrow {
+                original_token_mint: Pubkey::default(),
+                mint_migrations: 0,
+                escrow_id: 0,
+                price_feed: Pubkey::default(),
+                ..escrow.clone()
+            }
+        );
+        let legacy = Escrow::unpack_account(&data[..Escrow::PRE_TOKEN_ACCOUNT_LEN]).unwrap();
+        prop_assert_eq!(
+            legacy,
+            Escrow {
+                token_account: Pubkey::default(),
+                original_token_mint: Pubkey::default(),
+                mint_migrations: 0,
+                escrow_id: 0,
+                price_feed: Pubkey::default(),
+                ..escrow.clone()
+            }
+        );
+        let legacy = Escrow::unpack_account(&data[..Escrow::PRE_OWNER_HISTORY_LEN]).unwrap();
+        prop_assert_eq!(
+            legacy,
+            Escrow {
+                owner_history: vec![],
+                token_account: Pubkey::default(),
+                original_token_mint: Pubkey::default(),
+                mint_migrations: 0,
+                escrow_id: 0,
+                price_feed: Pubkey::default(),
+                ..escrow.clone()
+            }
+        );
+        let legacy = Escrow::unpack_account(&data[..Escrow::PRE_CHALLENGE_WINDOW_LEN]).unwrap();
+        let escrow = Escrow {
+            challenge_window: 0,
+            proof_verifier: Pubkey::default(),
+            arbitrator: Pubkey::default(),
+            approver: Pubkey::default(),
+            owner_history: vec![],
+            token_account: Pubkey::default(),
+            original_token_mint: Pubkey::default(),
+            mint_migrations: 0,
+            escrow_id: 0,
+            price_feed: Pubkey::default(),
+            ..escrow
+        };
+        prop_assert_eq!(legacy, escrow.clone());
+        let legacy = Escrow::unpack_account(&data[..Escrow::LEGACY_LEN]).unwrap();
+        prop_assert_eq!(legacy, Escrow { token_mint: Pubkey::default(), ..escrow.clone() });
+
+        // The token mint resolved for a legacy escrow isn't stored until it's reallocated
+        let mut legacy_data = vec![0; Escrow::LEGACY_LEN];
+        Escrow::pack_account(escrow, &mut legacy_data).unwrap();
+        prop_assert_eq!(&legacy_data[..], &data[..Escrow::LEGACY_LEN]);
+    }
+
+    #[test]
+    fn test_job_round_trip(job in job()) {
+        let data = pack(&job);
+        prop_assert_eq!(&data[Job::AMOUNT_OFFSET..][..8], &job.amount.to_le_bytes()[..]);
+        prop_assert_eq!(&data[Job::AUTHORITY_OFFSET..][..32], job.authority.as_ref());
+        prop_assert_eq!(data[Job::ISOLATED_OFFSET], job.isolated as u8);
+        prop_assert_eq!(&data[Job::ID_OFFSET..][..8], &job.id.to_le_bytes()[..]);
+        prop_assert_eq!(data[Job::STATUS_OFFSET], u8::from(job.status));
+        prop_assert_eq!(&data[Job::NODE_OFFSET..][..32], job.node.as_ref());
+        prop_assert_eq!(&data[Job::RESULT_COMMITMENT_OFFSET..][..32], &job.result_commitment[..]);
+        prop_assert_eq!(&data[Job::RESULT_HASH_OFFSET..][..32], &job.result_hash[..]);
+        prop_assert_eq!(&data[Job::REVEALED_AT_OFFSET..][..8], &job.revealed_at.to_le_bytes()[..]);
+        prop_assert_eq!(&data[Job::VERIFIER_OFFSET..][..32], job.verifier.as_ref());
+        prop_assert_eq!(&data[Job::EVIDENCE_HASH_OFFSET..][..32], &job.evidence_hash[..]);
+        prop_assert_eq!(&data[Job::FUNDED_AT_OFFSET..][..8], &job.funded_at.to_le_bytes()[..]);
+        prop_assert_eq!(
+            &data[Job::PENDING_AUTHORITY_OFFSET..][..32],
+            job.pending_authority.as_ref()
+        );
+        prop_assert_eq!(
+            &data[Job::ORIGINAL_AUTHORITY_OFFSET..][..32],
+            job.original_authority.as_ref()
+        );
+        prop_assert_eq!(data[Job::METADATA_URI_OFFSET] as usize, job.metadata_uri.len());
+        prop_assert_eq!(
+            &data[Job::METADATA_URI_OFFSET + 1..][..job.metadata_uri.len()],
+            job.metadata_uri.as_bytes()
+        );
+        prop_assert_eq!(&data[Job::METADATA_HASH_OFFSET..][..32], &job.metadata_hash[..]);
+        prop_assert_eq!(&data[Job::TAGS_OFFSET..][..8], &job.tags.to_le_bytes()[..]);
+        prop_assert_eq!(data[Job::MINT_MIGRATIONS_OFFSET], job.mint_migrations);
+        prop_assert_eq!(data[Job::PRIORITY_OFFSET], job.priority);
+        prop_assert_eq!(
+            &data[Job::EXCHANGE_RATE_NUMERATOR_OFFSET..][..8],
+            &job.exchange_rate_numerator.to_le_bytes()[..]
+        );
+        prop_assert_eq!(
+            &data[Job::EXCHANGE_RATE_DENOMINATOR_OFFSET..][..8],
+            &job.exchange_rate_denominator.to_le_bytes()[..]
+        );
+        prop_assert_eq!(
+            &data[Job::EXCHANGE_RATE_SLOT_OFFSET..][..8],
+            &job.exchange_rate_slot.to_le_bytes()[..]
+        );
+        prop_assert_eq!(data[Job::FROZEN_OFFSET], u8::from(job.frozen));
+        prop_assert_eq!(Job::unpack_from_slice(&data).unwrap(), job.clone());
+
+        // Jobs with a legacy length unpack with the appended fields set to their defaults
+        let legacy = Job::unpack_account(&data[..Job::PRE_FROZEN_LEN]).unwrap();
+        let job = Job { frozen: false, ..job };
+        prop_assert_eq!(legacy, job.clone());
+        let legacy = Job::unpack_account(&data[..Job::PRE_EXCHANGE_RATE_LEN]).unwrap();
+        let job = Job {
+            exchange_rate_numerator: 0,
+            exchange_rate_denominator: 0,
+            exchange_rate_slot: 0,
+            ..job
+        };
+        prop_assert_eq!(legacy, job.clone());
+        let legacy = Job::unpack_account(&data[..Job::PRE_PRIORITY_LEN]).unwrap();
+        prop_assert_eq!(legacy, Job { priority: 0, ..job.clone() });
+        let legacy = Job::unpack_account(&data[..Job::PRE_MINT_MIGRATIONS_LEN]).unwrap();
+        prop_assert_eq!(legacy, Job { mint_migrations: 0, priority: 0, ..job.clone() });
+        let legacy = Job::unpack_account(&data[..Job::PRE_TAGS_LEN]).unwrap();
+        prop_assert_eq!(legacy, Job { tags: 0, mint_migrations: 0, priority: 0, ..job.clone() });
+        let legacy = Job::unpack_account(&data[..Job::PRE_METADATA_LEN]).unwrap();
+        prop_assert_eq!(
+            legacy,
+            Job {
+                metadata_uri: String::new(),
+                metadata_hash: [0; 32],
+                tags: 0,
+                mint_migrations: 0,
+                priority: 0,
+                ..job.clone()
+            }
+        );
+        let legacy = Job::unpack_account(&data[..Job::PRE_AUTHORITY_TRANSFER_LEN]).unwrap();
+        prop_assert_eq!(
+            legacy,
+            Job {
+                pending_authority: Pubkey::default(),
+                original_authority: Pubkey::default(),
+                metadata_uri: String::new(),
+                metadata_hash: [0; 32],
+                tags: 0,
+                mint_migrations: 0,
+                priority: 0,
+                ..job.clone()
+            }
+        );
+        let legacy = Job::unpack_account(&data[..Job::PRE_FUNDED_AT_LEN]).unwrap();
+        prop_assert_eq!(
+            legacy,
+            Job {
+                funded_at: 0,
+                pending_authority: Pubkey::default(),
+                original_authority: Pubkey::default(),
+                metadata_uri: String::new(),
+                metadata_hash: [0; 32],
+                tags: 0,
+                mint_migrations: 0,
+                priority: 0,
+                ..job.clone()
+            }
+        );
+        let legacy = Job::unpack_account(&data[..Job::PRE_STATUS_LEN]).unwrap();
+        let job = Job {
+            status: JobStatus::Open,
+            node: Pubkey::default(),
+            result_commitment: [0; 32],
+            result_hash: [0; 32],
+            revealed_at: 0,
+            verifier: Pubkey::default(),
+            evidence_hash: [0; 32],
+            funded_at: 0,
+            pending_authority: Pubkey::default(),
+            original_authority: Pubkey::default(),
+            metadata_uri: String::new(),
+            metadata_hash: [0; 32],
+            tags: 0,
+            mint_migrations: 0,
+            priority: 0,
+            ..job
+        };
+        prop_assert_eq!(legacy, job.clone());
+        let legacy = Job::unpack_account(&data[..Job::PRE_ID_LEN]).unwrap();
+        prop_assert_eq!(legacy, Job { id: 0, ..job.clone() });
+        let legacy = Job::unpack_account(&data[..Job::LEGACY_LEN]).unwrap();
+        prop_assert_eq!(legacy, Job { isolated: false, id: 0, ..job });
+    }
+
+    #[test]
+    fn test_job_exchange_rate(
+        mut job in job(),
+        rate_numerator in 1..=u64::MAX,
+        rate_denominator in 1..=u64::MAX,
+        slot in any::<u64>(),
+    ) {
+        let exchange_rate = ExchangeRate::new(rate_numerator, rate_denominator).unwrap();
+        job.set_exchange_rate(exchange_rate, slot);
+        prop_assert_eq!(job.exchange_rate(), Some(exchange_rate));
+        prop_assert_eq!(job.exchange_rate_slot, slot);
+        let data = pack(&job);
+        prop_assert_eq!(Job::unpack_from_slice(&data).unwrap().exchange_rate(), Some(exchange_rate));
+
+        // A rate with only one of its parts set is invalid
+        job.exchange_rate_denominator = 0;
+        prop_assert_eq!(
+            Job::unpack_from_slice(&pack(&job)),
+            Err(ProgramError::InvalidAccountData)
+        );
+        job.exchange_rate_numerator = 0;
+        prop_assert_eq!(Job::unpack_from_slice(&pack(&job)).unwrap().exchange_rate(), None);
+    }
+
+    #[test]
+    fn test_escrow_needs_attention_invalid(escrow in escrow(), needs_attention in 2u8..) {
+        let mut data = pack(&escrow);
+        data[Escrow::NEEDS_ATTENTION_OFFSET] = needs_attention;
+        prop_assert_eq!(
+            Escrow::unpack_from_slice(&data),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
+
+    #[test]
+    fn test_escrow_incident_mode_invalid(escrow in escrow(), incident_mode in 3u8..) {
+        let mut data = pack(&escrow);
+        data[Escrow::INCIDENT_MODE_OFFSET] = incident_mode;
+        prop_assert_eq!(
+            Escrow::unpack_from_slice(&data),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
+
+    #[test]
+    fn test_job_frozen_invalid(job in job(), frozen in 2u8..) {
+        let mut data = pack(&job);
+        data[Job::FROZEN_OFFSET] = frozen;
+        prop_assert_eq!(
+            Job::unpack_from_slice(&data),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
+
+    #[test]
+    fn test_job_is_suitable_for(mut job in job(), capabilities in any::<u64>(), extra in any::<u64>()) {
+        job.tags = capabilities;
+        prop_assert!(job.is_suitable_for(capabilities));
+        prop_assert!(job.is_suitable_for(capabilities | extra));
+        job.tags = capabilities | extra;
+        prop_assert_eq!(job.is_suitable_for(capabilities), extra & !capabilities == 0);
+        job.tags = 0;
+        prop_assert!(job.is_suitable_for(0));
+    }
+
+    #[test]
+    fn test_job_accept_authority(mut job in job(), first in pubkey(), second in pubkey()) {
+        job.original_authority = Pubkey::default();
+        let initial_authority = job.authority;
+        prop_assert_eq!(job.address_authority(), &initial_authority);
+
+        // The address keeps being derived from the initial authority across transfers
+        job.pending_authority = first;
+        job.accept_authority();
+        prop_assert_eq!(job.authority, first);
+        prop_assert_eq!(job.pending_authority, Pubkey::default());
+        prop_assert_eq!(job.address_authority(), &initial_authority);
+
+        job.pending_authority = second;
+        job.accept_authority();
+        prop_assert_eq!(job.authority, second);
+        prop_assert_eq!(job.address_authority(), &initial_authority);
+    }
+
+    #[test]
+    fn test_escrow_shard_round_trip(escrow_shard in escrow_shard()) {
+        let data = pack(&escrow_shard);
+        prop_assert_eq!(&data[EscrowShard::ESCROW_OFFSET..][..32], escrow_shard.escrow.as_ref());
+        prop_assert_eq!(data[EscrowShard::INDEX_OFFSET], escrow_shard.index);
+        prop_assert_eq!(
+            &data[EscrowShard::AMOUNT_OFFSET..][..8],
+            &escrow_shard.amount.to_le_bytes()[..]
+        );
+        prop_assert_eq!(EscrowShard::unpack_from_slice(&data).unwrap(), escrow_shard);
+    }
+
+    #[test]
+    fn test_verifier_registry_round_trip(verifier_registry in verifier_registry()) {
+        let data = pack(&verifier_registry);
+        prop_assert_eq!(
+            &data[VerifierRegistry::ESCROW_OFFSET..][..32],
+            verifier_registry.escrow.as_ref()
+        );
+        prop_assert_eq!(
+            data[VerifierRegistry::VERIFIERS_LEN_OFFSET] as usize,
+            verifier_registry.verifiers.len()
+        );
+        prop_assert_eq!(VerifierRegistry::unpack_from_slice(&data).unwrap(), verifier_registry);
+    }
+
+    #[test]
+    fn test_verifier_selection_round_trip(verifier_selection in verifier_selection()) {
+        let data = pack(&verifier_selection);
+        prop_assert_eq!(
+            &data[VerifierSelection::JOB_OFFSET..][..32],
+            verifier_selection.job.as_ref()
+        );
+        prop_assert_eq!(
+            &data[VerifierSelection::SLOT_OFFSET..][..8],
+            &verifier_selection.slot.to_le_bytes()[..]
+        );
+        prop_assert_eq!(VerifierSelection::unpack_from_slice(&data).unwrap(), verifier_selection);
+    }
+
+    #[test]
+    fn test_dispute_round_trip(dispute in dispute()) {
+        let data = pack(&dispute);
+        prop_assert_eq!(&data[Dispute::JOB_OFFSET..][..32], dispute.job.as_ref());
+        prop_assert_eq!(&data[Dispute::OPENER_OFFSET..][..32], dispute.opener.as_ref());
+        prop_assert_eq!(
+            &data[Dispute::NODE_EVIDENCE_HASH_OFFSET..][..32],
+            &dispute.node_evidence_hash[..]
+        );
+        prop_assert_eq!(
+            &data[Dispute::AUTHORITY_AMOUNT_OFFSET..][..8],
+            &dispute.authority_amount.to_le_bytes()[..]
+        );
+        prop_assert_eq!(Dispute::unpack_from_slice(&data).unwrap(), dispute);
+    }
+
+    #[test]
+    fn test_proposal_round_trip(proposal in proposal()) {
+        let data = pack(&proposal);
+        prop_assert_eq!(&data[Proposal::ESCROW_OFFSET..][..32], proposal.escrow.as_ref());
+        prop_assert_eq!(&data[Proposal::ID_OFFSET..][..8], &proposal.id.to_le_bytes()[..]);
+        prop_assert_eq!(&data[Proposal::DESTINATION_OFFSET..][..32], proposal.destination.as_ref());
+        prop_assert_eq!(
+            &data[Proposal::EXECUTED_AT_OFFSET..][..8],
+            &proposal.executed_at.to_le_bytes()[..]
+        );
+        prop_assert_eq!(Proposal::unpack_from_slice(&data).unwrap(), proposal);
+    }
+
+    #[test]
+    fn test_config_change_field(change in config_change()) {
+        let data = RNDRInstruction::SetConfig { change }.pack();
+        prop_assert_eq!(data[1], change.field());
+    }
+
+    #[test]
+    fn test_config_round_trip(config in config()) {
+        let data = pack(&config);
+        prop_assert_eq!(&data[Config::GOVERNANCE_OFFSET..][..32], config.governance.as_ref());
+        prop_assert_eq!(&data[Config::FEE_BPS_OFFSET..][..2], &config.fee_bps.bps().to_le_bytes()[..]);
+        prop_assert_eq!(
+            &data[Config::DISPUTE_WINDOW_OFFSET..][..8],
+            &config.dispute_window.to_le_bytes()[..]
+        );
+        prop_assert_eq!(
+            &data[Config::RATE_LIMIT_MAX_OFFSET..][..4],
+            &config.rate_limit_max.to_le_bytes()[..]
+        );
+        prop_assert_eq!(&data[Config::GUARDIAN_OFFSET..][..32], config.guardian.as_ref());
+        prop_assert_eq!(
+            data[Config::INSTANT_OWNER_CHANGE_OFFSET],
+            u8::from(config.instant_owner_change)
+        );
+        prop_assert_eq!(
+            &data[Config::TIMELOCKED_FIELDS_OFFSET..][..2],
+            &config.timelocked_fields.to_le_bytes()[..2]
+        );
+        prop_assert_eq!(
+            &data[Config::REWARD_TIERS_OFFSET..][..8],
+            &config.reward_tiers[0].min_stake.to_le_bytes()[..]
+        );
+        prop_assert_eq!(
+            &data[Config::CANCEL_GRACE_PERIOD_OFFSET..][..8],
+            &config.cancel_grace_period.to_le_bytes()[..]
+        );
+        prop_assert_eq!(
+            &data[Config::CANCEL_FEE_BPS_OFFSET..][..2],
+            &config.cancel_fee_bps.bps().to_le_bytes()[..]
+        );
+        prop_assert_eq!(
+            &data[Config::PRIORITY_SURCHARGE_BPS_OFFSET..][..2],
+            &config.priority_surcharge_bps.bps().to_le_bytes()[..]
+        );
+        prop_assert_eq!(
+            &data[Config::PRIORITY_CLAIM_DELAY_OFFSET..][..8],
+            &config.priority_claim_delay.to_le_bytes()[..]
+        );
+        prop_assert_eq!(
+            data[Config::ROUNDING_POLICY_OFFSET],
+            u8::from(config.rounding_policy)
+        );
+        prop_assert_eq!(
+            &data[Config::MIN_DISBURSEMENT_OFFSET..][..8],
+            &config.min_disbursement.to_le_bytes()[..]
+        );
+        prop_assert_eq!(
+            &data[Config::TIMELOCKED_FIELDS_HIGH_OFFSET..][..2],
+            &config.timelocked_fields.to_le_bytes()[2..]
+        );
+        prop_assert_eq!(
+            &data[Config::OBH_PRICES_OFFSET..][..8],
+            &config.obh_prices[0].to_le_bytes()[..]
+        );
+        prop_assert_eq!(
+            &data[Config::MAX_PRICE_AGE_OFFSET..][..8],
+            &config.max_price_age.to_le_bytes()[..]
+        );
+        prop_assert_eq!(
+            &data[Config::MAX_PRICE_CONFIDENCE_BPS_OFFSET..][..2],
+            &config.max_price_confidence_bps.bps().to_le_bytes()[..]
+        );
+        prop_assert_eq!(
+            &data[Config::COMPLIANCE_OFFSET..][..32],
+            config.compliance.as_ref()
+        );
+        prop_assert_eq!(
+            &data[Config::SWEEP_DELAY_OFFSET..][..8],
+            &config.sweep_delay.to_le_bytes()[..]
+        );
+        prop_assert_eq!(
+            data[Config::INCIDENT_MODE_OFFSET],
+            u8::from(config.incident_mode)
+        );
+        prop_assert_eq!(Config::unpack_from_slice(&data).unwrap(), config.clone());
+
+        // Configs with a legacy length unpack with the fields appended after it set to zero, and
+        // only pack configs where those fields are zero
+        let legacy = Config::unpack_account(&data[..Config::LEGACY_LEN]).unwrap();
+        prop_assert_eq!(
+            legacy,
+            Config {
+                timelocked_fields: config.timelocked_fields & 0xffff,
+                rounding_policy: RoundingPolicy::default(),
+                min_disbursement: 0,
+                obh_prices: [0; MAX_PRICE_TIERS],
+                max_price_age: 0,
+                max_price_confidence_bps: Rate::ZERO,
+                compliance: Pubkey::default(),
+                sweep_delay: 0,
+                incident_mode: IncidentMode::Normal,
+                ..config.clone()
+            }
+        );
+        let mut legacy_data = vec![0; Config::LEGACY_LEN];
+        prop_assert_eq!(
+            Config::pack_account(config.clone(), &mut legacy_data).is_ok(),
+            config.rounding_policy == RoundingPolicy::default()
+                && config.min_disbursement == 0
+                && config.timelocked_fields >> 16 == 0
+                && config.obh_prices == [0; MAX_PRICE_TIERS]
+                && config.max_price_age == 0
+                && config.max_price_confidence_bps == Rate::ZERO
+                && config.compliance == Pubkey::default()
+                && config.sweep_delay == 0
+                && config.incident_mode == IncidentMode::Normal
+        );
+
+        let legacy = Config::unpack_account(&data[..Config::PRE_MIN_DISBURSEMENT_LEN]).unwrap();
+        prop_assert_eq!(
+            legacy,
+            Config {
+                timelocked_fields: config.timelocked_fields & 0xffff,
+                min_disbursement: 0,
+                obh_prices: [0; MAX_PRICE_TIERS],
+                max_price_age: 0,
+                max_price_confidence_bps: Rate::ZERO,
+                compliance: Pubkey::default(),
+                sweep_delay: 0,
+                incident_mode: IncidentMode::Normal,
+                ..config.clone()
+            }
+        );
+        let mut legacy_data = vec![0; Config::PRE_MIN_DISBURSEMENT_LEN];
+        prop_assert_eq!(
+            Config::pack_account(config.clone(), &mut legacy_data).is_ok(),
+            config.min_disbursement == 0
+                && config.timelocked_fields >> 16 == 0
+                && config.obh_prices == [0; MAX_PRICE_TIERS]
+                && config.max_price_age == 0
+                && config.max_price_confidence_bps == Rate::ZERO
+                && config.compliance == Pubkey::default()
+                && config.sweep_delay == 0
+                && config.incident_mode == IncidentMode::Normal
+        );
+
+        let legacy = Config::unpack_account(&data[..Config::PRE_OBH_PRICES_LEN]).unwrap();
+        prop_assert_eq!(
+            legacy,
+            Config {
+                obh_prices: [0; MAX_PRICE_TIERS],
+                max_price_age: 0,
+                max_price_confidence_bps: Rate::ZERO,
+                compliance: Pubkey::default(),
+                sweep_delay: 0,
+                incident_mode: IncidentMode::Normal,
+                ..config.clone()
+            }
+        );
+        let mut legacy_data = vec![0; Config::PRE_OBH_PRICES_LEN];
+        prop_assert_eq!(
+            Config::pack_account(config.clone(), &mut legacy_data).is_ok(),
+            config.obh_prices == [0; MAX_PRICE_TIERS]
+                && config.max_price_age == 0
+                && config.max_price_confidence_bps == Rate::ZERO
+                && config.compliance == Pubkey::default()
+                && config.sweep_delay == 0
+                && config.incident_mode == IncidentMode::Normal
+        );
+
+        let legacy = Config::unpack_account(&data[..Config::PRE_MAX_PRICE_AGE_LEN]).unwrap();
+        prop_assert_eq!(
+            legacy,
+            Config {
+                max_price_age: 0,
+                max_price_confidence_bps: Rate::ZERO,
+                compliance: Pubkey::default(),
+                sweep_delay: 0,
+                incident_mode: IncidentMode::Normal,
+                ..config.clone()
+            }
+        );
+        let mut legacy_data = vec![0; Config::PRE_MAX_PRICE_AGE_LEN];
+        prop_assert_eq!(
+            Config::pack_account(config.clone(), &mut legacy_data).is_ok(),
+            config.max_price_age == 0
+                && config.max_price_confidence_bps == Rate::ZERO
+                && config.compliance == Pubkey::default()
+                && config.sweep_delay == 0
+                && config.incident_mode == IncidentMode::Normal
+        );
+
+        let legacy = Config::unpack_account(&data[..Config::PRE_COMPLIANCE_LEN]).unwrap();
+        prop_assert_eq!(
+            legacy,
+            Config {
+                compliance: Pubkey::default(),
+                sweep_delay: 0,
+                incident_mode: IncidentMode::Normal,
+                ..config.clone()
+            }
+        );
+        let mut legacy_data = vec![0; Config::PRE_COMPLIANCE_LEN];
+        prop_assert_eq!(
+            Config::pack_account(config.clone(), &mut legacy_data).is_ok(),
+            config.compliance == Pubkey::default()
+                && config.sweep_delay == 0
+                && config.incident_mode == IncidentMode::Normal
+        );
+
+        let legacy = Config::unpack_account(&data[..Config::PRE_SWEEP_DELAY_LEN]).unwrap();
+        prop_assert_eq!(
+            legacy,
+            Config {
+                sweep_delay: 0,
+                incident_mode: IncidentMode::Normal,
+                ..config.clone()
+            }
+        );
+        let mut legacy_data = vec![0; Config::PRE_SWEEP_DELAY_LEN];
+        prop_assert_eq!(
+            Config::pack_account(config.clone(), &mut legacy_data).is_ok(),
+            config.sweep_delay == 0 && config.incident_mode == IncidentMode::Normal
+        );
+
+        let legacy = Config::unpack_account(&data[..Config::PRE_INCIDENT_MODE_LEN]).unwrap();
+        prop_assert_eq!(
+            legacy,
+            Config {
+                incident_mode: IncidentMode::Normal,
+                ..config.clone()
+            }
+        );
+        let mut legacy_data = vec![0; Config::PRE_INCIDENT_MODE_LEN];
+        prop_assert_eq!(
+            Config::pack_account(config.clone(), &mut legacy_data).is_ok(),
+            config.incident_mode == IncidentMode::Normal
+        );
+    }
+
+    #[test]
+    fn test_config_rounding_policy_invalid(config in config(), policy in 3u8..) {
+        let mut data = pack(&config);
+        data[Config::ROUNDING_POLICY_OFFSET] = policy;
+        prop_assert_eq!(
+            Config::unpack_from_slice(&data),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
+
+    #[test]
+    fn test_config_incident_mode_invalid(config in config(), incident_mode in 3u8..) {
+        let mut data = pack(&config);
+        data[Config::INCIDENT_MODE_OFFSET] = incident_mode;
+        prop_assert_eq!(
+            Config::unpack_from_slice(&data),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
+
+    #[test]
+    fn test_config_rate_above_max(config in config(), bps in MAX_BPS + 1..) {
+        // A fee above 100% in the packed account or in a config change fails to unpack
+        let mut data = pack(&config);
+        data[Config::FEE_BPS_OFFSET..][..2].copy_from_slice(&bps.to_le_bytes());
+        prop_assert_eq!(
+            Config::unpack_from_slice(&data),
+            Err(ProgramError::InvalidAccountData)
+        );
+
+        let mut packed = RNDRInstruction::SetConfig {
+            change: ConfigChange::FeeBps(Rate::ONE),
+        }
+        .pack();
+        let len = packed.len();
+        packed[len - 2..].copy_from_slice(&bps.to_le_bytes());
+        prop_assert!(RNDRInstruction::unpack(&packed).is_err());
+    }
+
+    #[test]
+    fn test_config_reward_multiplier(
+        stake in any::<u64>(),
+        work in 0..u64::MAX / 7,
+        tiers in [reward_tier(), reward_tier(), reward_tier(), reward_tier()],
+    ) {
+        let mut config = Config::new(InitConfigParams {
+            governance: Pubkey::new_unique(),
+        });
+        prop_assert_eq!(config.reward_multiplier_bps(stake), BASE_REWARD_MULTIPLIER_BPS);
+        prop_assert_eq!(config.weighted_work(stake, work), Some(work));
+
+        config.reward_tiers = tiers;
+        let multiplier_bps = config.reward_multiplier_bps(stake);
+        match tiers
+            .iter()
+            .filter(|tier| tier.multiplier_bps != 0 && tier.min_stake <= stake)
+            .map(|tier| tier.min_stake)
+            .max()
+        {
+            Some(min_stake) => prop_assert!(tiers
+                .iter()
+                .any(|tier| tier.min_stake == min_stake && tier.multiplier_bps == multiplier_bps)),
+            None => prop_assert_eq!(multiplier_bps, BASE_REWARD_MULTIPLIER_BPS),
+        }
+        prop_assert_eq!(
+            config.weighted_work(stake, work),
+            Some((work as u128 * multiplier_bps as u128 / 10_000) as u64)
+        );
+    }
+
+    #[test]
+    fn test_config_cancellation_fee(
+        amount in any::<u64>(),
+        funded_at in 0..i64::MAX / 2,
+        elapsed in 0..MAX_CANCEL_GRACE_PERIOD as i64 * 2,
+        cancel_grace_period in 0..=MAX_CANCEL_GRACE_PERIOD,
+        cancel_fee_bps in rate(MAX_CANCEL_FEE_BPS),
+    ) {
+        let mut config = Config::new(InitConfigParams {
+            governance: Pubkey::new_unique(),
+        });
+        config.cancel_grace_period = cancel_grace_period;
+        config.cancel_fee_bps = cancel_fee_bps;
+
+        let fee = config.cancellation_fee(amount, funded_at, funded_at + elapsed).unwrap();
+        if elapsed <= cancel_grace_period as i64 {
+            prop_assert_eq!(fee, 0);
+        } else {
+            prop_assert_eq!(fee, (amount as u128 * cancel_fee_bps.bps() as u128 / 10_000) as u64);
+        }
+        prop_assert!(fee <= amount);
+
+        // The refund takes the remainder under the default rounding policy, which leaves none
+        let split = config
+            .cancellation_split(amount, funded_at, funded_at + elapsed, None)
+            .unwrap();
+        prop_assert_eq!(split.portions, [amount - fee, fee]);
+        prop_assert_eq!((split.treasury, split.burn), (0, 0));
+    }
+
+    #[test]
+    fn test_config_cancellation_dust(
+        amount in any::<u64>(),
+        cancel_fee_bps in rate(MAX_CANCEL_FEE_BPS),
+        min_disbursement in 0..=MAX_MIN_DISBURSEMENT,
+    ) {
+        let mut config = Config::new(InitConfigParams {
+            governance: Pubkey::new_unique(),
+        });
+        config.cancel_fee_bps = cancel_fee_bps;
+        config.min_disbursement = min_disbursement;
+
+        // Portions below the minimum disbursement are swept to the treasury
+        let fee = config.cancellation_fee(amount, 0, 1).unwrap();
+        let refund = amount - fee;
+        let split = config.cancellation_split(amount, 0, 1, None).unwrap();
+        let swept = |portion: u64| if portion < min_disbursement { 0 } else { portion };
+        prop_assert_eq!(split.portions, [swept(refund), swept(fee)]);
+        prop_assert_eq!(split.treasury, amount - swept(refund) - swept(fee));
+    }
+
+    #[test]
+    fn test_config_cancellation_dust_at_exchange_rate(
+        amount in any::<u64>(),
+        min_disbursement in 0..=MAX_MIN_DISBURSEMENT,
+        rate_numerator in 1..=u64::MAX,
+        rate_denominator in 1..=u64::MAX,
+    ) {
+        let mut config = Config::new(InitConfigParams {
+            governance: Pubkey::new_unique(),
+        });
+        config.min_disbursement = min_disbursement;
+        let exchange_rate = ExchangeRate::new(rate_numerator, rate_denominator).unwrap();
+        prop_assume!(config.min_disbursement_in(Some(exchange_rate)).is_some());
+
+        // A portion is dust exactly when it's worth less than the minimum disbursement in RNDR
+        let split = config.cancellation_split(amount, 0, 1, Some(exchange_rate)).unwrap();
+        let value = u128::from(amount) * u128::from(rate_numerator) / u128::from(rate_denominator);
+        let refund = if value < u128::from(min_disbursement) { 0 } else { amount };
+        prop_assert_eq!(split.portions, [refund, 0]);
+        prop_assert_eq!(split.treasury, amount - refund);
+    }
+
+    #[test]
+    fn test_config_job_cost(
+        tier in 0..MAX_PRICE_TIERS as u8,
+        obh_price in 1..=u64::MAX,
+        milli_obh in any::<u64>(),
+    ) {
+        let mut config = Config::new(InitConfigParams {
+            governance: Pubkey::new_unique(),
+        });
+        // Every price tier is disabled when the config is created
+        prop_assert_eq!(config.job_cost(tier, milli_obh), None);
+
+        config.obh_prices[tier as usize] = obh_price;
+        prop_assert_eq!(config.job_cost(MAX_PRICE_TIERS as u8, milli_obh), None);
+
+        // The cost is rounded up, so it covers the price of the work and is less than a base unit
+        // more than it
+        let price = u128::from(obh_price) * u128::from(milli_obh);
+        match config.job_cost(tier, milli_obh) {
+            Some(cost) => {
+                prop_assert!(u128::from(cost) * 1_000 >= price);
+                prop_assert!(u128::from(cost) * 1_000 < price + 1_000);
+            }
+            None => prop_assert!(price.div_ceil(1_000) > u128::from(u64::MAX)),
+        }
+        prop_assert_eq!(config.job_cost(tier, 0), Some(0));
+        prop_assert_eq!(config.job_cost(tier, 1_000), Some(obh_price));
+    }
+
+    #[test]
+    fn test_config_job_priority(
+        amount in 0..u64::MAX / 2,
+        funded_at in 0..i64::MAX / 2,
+        priority in 0..=MAX_JOB_PRIORITY,
+        priority_surcharge_bps in rate(MAX_PRIORITY_SURCHARGE_BPS),
+        priority_claim_delay in 0..=MAX_PRIORITY_CLAIM_DELAY,
+    ) {
+        let mut config = Config::new(InitConfigParams {
+            governance: Pubkey::new_unique(),
+        });
+        prop_assert_eq!(config.priority_surcharge(amount, MAX_JOB_PRIORITY), Some(0));
+        prop_assert_eq!(config.claimable_at(priority, funded_at), Some(funded_at));
+
+        config.priority_surcharge_bps = priority_surcharge_bps;
+        config.priority_claim_delay = priority_claim_delay;
+
+        let levels = MAX_JOB_PRIORITY - priority;
+        prop_assert_eq!(
+            config.priority_surcharge(amount, levels),
+            Some((amount as u128 * priority_surcharge_bps.bps() as u128 * levels as u128 / 10_000) as u64)
+        );
+        let claimable_at = config.claimable_at(priority, funded_at).unwrap();
+        prop_assert_eq!(claimable_at, funded_at + (priority_claim_delay * levels as u64) as i64);
+        prop_assert!(config.claimable_at(MAX_JOB_PRIORITY, funded_at).unwrap() <= claimable_at);
+    }
+
+    #[test]
+    fn test_queued_action_round_trip(queued_action in queued_action()) {
+        let data = pack(&queued_action);
+        prop_assert_eq!(&data[QueuedAction::TARGET_OFFSET..][..32], queued_action.target.as_ref());
+        prop_assert_eq!(&data[QueuedAction::ID_OFFSET..][..8], &queued_action.id.to_le_bytes()[..]);
+        prop_assert_eq!(
+            &data[QueuedAction::AUTHORITY_OFFSET..][..32],
+            queued_action.authority.as_ref()
+        );
+        prop_assert_eq!(
+            &data[QueuedAction::EXECUTABLE_AT_OFFSET..][..8],
+            &queued_action.executable_at.to_le_bytes()[..]
+        );
+        prop_assert_eq!(
+            &data[QueuedAction::CANCELLED_AT_OFFSET..][..8],
+            &queued_action.cancelled_at.to_le_bytes()[..]
+        );
+        prop_assert_eq!(QueuedAction::unpack_from_slice(&data).unwrap(), queued_action);
+    }
+
+    #[test]
+    fn test_recovery_round_trip(recovery in recovery()) {
+        let data = pack(&recovery);
+        prop_assert_eq!(&data[Recovery::ESCROW_OFFSET..][..32], recovery.escrow.as_ref());
+        prop_assert_eq!(data[Recovery::THRESHOLD_OFFSET], recovery.threshold);
+        prop_assert_eq!(data[Recovery::GUARDIANS_LEN_OFFSET] as usize, recovery.guardians.len());
+        prop_assert_eq!(&data[Recovery::NEW_OWNER_OFFSET..][..32], recovery.new_owner.as_ref());
+        prop_assert_eq!(
+            &data[Recovery::EXECUTABLE_AT_OFFSET..][..8],
+            &recovery.executable_at.to_le_bytes()[..]
+        );
+        prop_assert_eq!(Recovery::unpack_from_slice(&data).unwrap(), recovery);
+    }
+
+    #[test]
+    fn test_reward_pool_round_trip(reward_pool in reward_pool()) {
+        let data = pack(&reward_pool);
+        prop_assert_eq!(&data[RewardPool::ESCROW_OFFSET..][..32], reward_pool.escrow.as_ref());
+        prop_assert_eq!(
+            &data[RewardPool::EPOCH_OFFSET..][..8],
+            &reward_pool.epoch.to_le_bytes()[..]
+        );
+        prop_assert_eq!(
+            &data[RewardPool::TOTAL_WORK_OFFSET..][..8],
+            &reward_pool.total_work.to_le_bytes()[..]
+        );
+        prop_assert_eq!(
+            &data[RewardPool::CLAIMED_OFFSET..][..8],
+            &reward_pool.claimed.to_le_bytes()[..]
+        );
+        prop_assert_eq!(RewardPool::unpack_from_slice(&data).unwrap(), reward_pool);
+    }
+
+    #[test]
+    fn test_reward_pool_reward(
+        amount in any::<u64>(),
+        total_work in 1..=u64::MAX,
+        work in any::<u64>(),
+    ) {
+        let work = work % total_work;
+        let reward_pool = RewardPool {
+            amount,
+            total_work,
+            ..RewardPool::default()
+        };
+        let reward = reward_pool.reward(work).unwrap();
+        prop_assert!(reward <= amount);
+        prop_assert_eq!(
+            reward as u128,
+            amount as u128 * work as u128 / total_work as u128
+        );
+    }
+
+    #[test]
+    fn test_epoch_work_round_trip(epoch_work in epoch_work()) {
+        let data = pack(&epoch_work);
+        prop_assert_eq!(
+            &data[EpochWork::REWARD_POOL_OFFSET..][..32],
+            epoch_work.reward_pool.as_ref()
+        );
+        prop_assert_eq!(&data[EpochWork::NODE_OFFSET..][..32], epoch_work.node.as_ref());
+        prop_assert_eq!(&data[EpochWork::WORK_OFFSET..][..8], &epoch_work.work.to_le_bytes()[..]);
+        prop_assert_eq!(data[EpochWork::CLAIMED_OFFSET], u8::from(epoch_work.claimed));
+        prop_assert_eq!(EpochWork::unpack_from_slice(&data).unwrap(), epoch_work);
+    }
+
+    #[test]
+    fn test_merkle_distribution_round_trip(merkle_distribution in merkle_distribution()) {
+        let data = pack(&merkle_distribution);
+        prop_assert_eq!(
+            &data[MerkleDistribution::ESCROW_OFFSET..][..32],
+            merkle_distribution.escrow.as_ref()
+        );
+        prop_assert_eq!(
+            &data[MerkleDistribution::ROOT_OFFSET..][..32],
+            &merkle_distribution.root[..]
+        );
+        prop_assert_eq!(
+            &data[MerkleDistribution::NODE_COUNT_OFFSET..][..4],
+            &merkle_distribution.node_count.to_le_bytes()[..]
+        );
+        prop_assert_eq!(
+            &data[MerkleDistribution::CLAIMED_OFFSET..][..8],
+            &merkle_distribution.claimed.to_le_bytes()[..]
+        );
+        prop_assert_eq!(
+            MerkleDistribution::unpack_from_slice(&data).unwrap(),
+            merkle_distribution
+        );
+    }
+
+    #[test]
+    fn test_merkle_distribution_verify(
+        rewards in vec((pubkey(), any::<u64>()), 1..=64),
+        index in any::<prop::sample::Index>(),
+    ) {
+        let (root, proofs) = merkle_tree(&rewards);
+        let merkle_distribution = MerkleDistribution {
+            root,
+            ..MerkleDistribution::default()
+        };
+        let index = index.index(rewards.len());
+        let (node, amount) = rewards[index];
+        let proof = &proofs[index];
+        prop_assert!(proof.len() <= MAX_MERKLE_PROOF_LEN);
+        prop_assert!(merkle_distribution.verify(
+            proof,
+            MerkleDistribution::leaf(index as u32, &node, amount)
+        ));
+        prop_assert!(!merkle_distribution.verify(
+            proof,
+            MerkleDistribution::leaf(index as u32, &node, amount.wrapping_add(1))
+        ));
+    }
+
+    #[test]
+    fn test_claim_bitmap_round_trip(claim_bitmap in claim_bitmap()) {
+        let data = pack(&claim_bitmap);
+        prop_assert_eq!(
+            &data[ClaimBitmap::MERKLE_DISTRIBUTION_OFFSET..][..32],
+            claim_bitmap.merkle_distribution.as_ref()
+        );
+        prop_assert_eq!(
+            &data[ClaimBitmap::CHUNK_OFFSET..][..4],
+            &claim_bitmap.chunk.to_le_bytes()[..]
+        );
+        prop_assert_eq!(&data[ClaimBitmap::BITMAP_OFFSET..], &claim_bitmap.bitmap[..]);
+        prop_assert_eq!(ClaimBitmap::unpack_from_slice(&data).unwrap(), claim_bitmap);
+    }
+
+    #[test]
+    fn test_claim_bitmap_set_claimed(index in any::<u32>(), other in any::<u32>()) {
+        let mut claim_bitmap = ClaimBitmap::new(InitClaimBitmapParams {
+            merkle_distribution: Pubkey::default(),
+            chunk: ClaimBitmap::chunk_for_index(index),
+        });
+        prop_assert!(!claim_bitmap.is_claimed(index));
+        claim_bitmap.set_claimed(index);
+        prop_assert!(claim_bitmap.is_claimed(index));
+        prop_assert_eq!(
+            claim_bitmap.is_claimed(other),
+            other % CLAIM_BITMAP_BITS == index % CLAIM_BITMAP_BITS
+        );
+    }
+
+    #[test]
+    fn test_emission_schedule_round_trip(emission_schedule in emission_schedule()) {
+        let data = pack(&emission_schedule);
+        prop_assert_eq!(
+            &data[EmissionSchedule::ESCROW_OFFSET..][..32],
+            emission_schedule.escrow.as_ref()
+        );
+        prop_assert_eq!(
+            &data[EmissionSchedule::DECAY_BPS_OFFSET..][..2],
+            &emission_schedule.decay_bps.bps().to_le_bytes()[..]
+        );
+        prop_assert_eq!(
+            &data[EmissionSchedule::EMITTED_OFFSET..][..8],
+            &emission_schedule.emitted.to_le_bytes()[..]
+        );
+        prop_assert_eq!(
+            EmissionSchedule::unpack_from_slice(&data).unwrap(),
+            emission_schedule
+        );
+    }
+
+    #[test]
+    fn test_emission_schedule_emission(
+        start_epoch in 0..1_000u64,
+        initial_amount in any::<u64>(),
+        decay_bps in rate(MAX_DECAY_BPS),
+        epoch in 0..2_000u64,
+    ) {
+        let emission_schedule = EmissionSchedule {
+            start_epoch,
+            initial_amount,
+            decay_bps,
+            ..EmissionSchedule::default()
+        };
+        let emission = emission_schedule.emission(epoch);
+        if epoch < start_epoch {
+            prop_assert_eq!(emission, 0);
+        } else if epoch == start_epoch || decay_bps == Rate::ZERO {
+            prop_assert_eq!(emission, initial_amount);
+        } else {
+            let retained = f64::from(decay_bps.complement().bps()) / f64::from(MAX_BPS);
+            let expected =
+                initial_amount as f64 * retained.powi((epoch - start_epoch) as i32);
+            prop_assert!(emission <= initial_amount);
+            prop_assert!((emission as f64 - expected).abs() <= initial_amount as f64 * 1e-9 + 1.0);
+        }
+    }
+
+    #[test]
+    fn test_node_round_trip(node in node()) {
+        let data = pack(&node);
+        prop_assert_eq!(&data[Node::ESCROW_OFFSET..][..32], node.escrow.as_ref());
+        prop_assert_eq!(&data[Node::AUTHORITY_OFFSET..][..32], node.authority.as_ref());
+        prop_assert_eq!(&data[Node::STAKE_OFFSET..][..8], &node.stake.to_le_bytes()[..]);
+        prop_assert_eq!(data[Node::AUTO_COMPOUND_OFFSET], u8::from(node.auto_compound));
+        prop_assert_eq!(
+            &data[Node::WORK_CREDITS_OFFSET..][..8],
+            &node.work_credits.to_le_bytes()[..]
+        );
+        prop_assert_eq!(&data[Node::WORK_EPOCH_OFFSET..][..8], &node.work_epoch.to_le_bytes()[..]);
+        prop_assert_eq!(
+            &data[Node::EPOCH_WORK_CREDITS_OFFSET..][..8],
+            &node.epoch_work_credits.to_le_bytes()[..]
+        );
+        prop_assert_eq!(&data[Node::STAKE_EPOCH_OFFSET..][..8], &node.stake_epoch.to_le_bytes()[..]);
+        prop_assert_eq!(
+            &data[Node::EPOCH_STAKED_OFFSET..][..8],
+            &node.epoch_staked.to_le_bytes()[..]
+        );
+        prop_assert_eq!(Node::unpack_from_slice(&data).unwrap(), node.clone());
+
+        // Nodes with a legacy length unpack with the stake epoch fields set to zero
+        let legacy = Node::unpack_account(&data[..Node::LEGACY_LEN]).unwrap();
+        prop_assert_eq!(legacy, Node { stake_epoch: 0, epoch_staked: 0, ..node });
+    }
+
+    #[test]
+    fn test_node_tier_stake(
+        epoch in 1..u64::MAX / 2,
+        staked in 0..u64::MAX / 4,
+        added in 0..u64::MAX / 4,
+        removed in 0..u64::MAX / 4,
+    ) {
+        let mut node = Node::new(InitNodeParams {
+            escrow: Pubkey::new_unique(),
+            authority: Pubkey::new_unique(),
+        });
+        node.record_stake(epoch - 1, staked).unwrap();
+        node.record_stake(epoch, added).unwrap();
+        prop_assert_eq!(node.tier_stake(epoch), staked);
+        prop_assert_eq!(node.tier_stake(epoch + 1), staked + added);
+
+        // Unstaked tokens are taken from the tokens staked during the epoch last
+        let removed = removed.min(staked + added);
+        node.record_unstake(removed).unwrap();
+        prop_assert_eq!(node.tier_stake(epoch), staked.saturating_sub(removed));
+        prop_assert!(node.record_unstake(node.stake + 1).is_none());
+    }
+
+    #[test]
+    fn test_node_credit_work(
+        epoch in 0..u64::MAX / 2,
+        first in 0..u64::MAX / 4,
+        second in 0..u64::MAX / 4,
+    ) {
+        let mut node = Node::new(InitNodeParams {
+            escrow: Pubkey::new_unique(),
+            authority: Pubkey::new_unique(),
+        });
+        node.credit_work(epoch, first).unwrap();
+        node.credit_work(epoch, second).unwrap();
+        prop_assert_eq!(node.work_credits_in_epoch(epoch), first + second);
+
+        node.credit_work(epoch + 1, second).unwrap();
+        prop_assert_eq!(node.work_credits_in_epoch(epoch), 0);
+        prop_assert_eq!(node.work_credits_in_epoch(epoch + 1), second);
+        prop_assert_eq!(node.work_credits, first + 2 * second);
+    }
+
+    #[test]
+    fn test_crank_vault_round_trip(crank_vault in crank_vault()) {
+        let data = pack(&crank_vault);
+        prop_assert_eq!(&data[CrankVault::ESCROW_OFFSET..][..32], crank_vault.escrow.as_ref());
+        prop_assert_eq!(
+            &data[CrankVault::BOUNTY_OFFSET..][..8],
+            &crank_vault.bounty.to_le_bytes()[..]
+        );
+        prop_assert_eq!(&data[CrankVault::PAID_OFFSET..][..8], &crank_vault.paid.to_le_bytes()[..]);
+        prop_assert_eq!(CrankVault::unpack_from_slice(&data).unwrap(), crank_vault);
+    }
+
+    #[test]
+    fn test_voucher_nonce_round_trip(voucher_nonce in voucher_nonce()) {
+        let data = pack(&voucher_nonce);
+        prop_assert_eq!(
+            &data[VoucherNonce::ESCROW_OFFSET..][..32],
+            voucher_nonce.escrow.as_ref()
+        );
+        prop_assert_eq!(
+            &data[VoucherNonce::AUTHORITY_OFFSET..][..32],
+            voucher_nonce.authority.as_ref()
+        );
+        prop_assert_eq!(
+            &data[VoucherNonce::NONCE_OFFSET..][..8],
+            &voucher_nonce.nonce.to_le_bytes()[..]
+        );
+        prop_assert_eq!(VoucherNonce::unpack_from_slice(&data).unwrap(), voucher_nonce);
+    }
+
+    #[test]
+    fn test_voucher_message(
+        escrow in pubkey(),
+        authority in pubkey(),
+        job_id in any::<u64>(),
+        amount in any::<u64>(),
+        nonce in any::<u64>(),
+        expires_at in any::<i64>(),
+    ) {
+        let voucher = Voucher {
+            escrow,
+            authority,
+            job_id,
+            amount,
+            nonce,
+            expires_at,
+        };
+        let message = voucher.message();
+        prop_assert_eq!(&message[..12], &VOUCHER_DOMAIN[..]);
+        prop_assert_eq!(&message[12..44], escrow.as_ref());
+        prop_assert_eq!(&message[44..76], authority.as_ref());
+        prop_assert_eq!(&message[100..], &expires_at.to_le_bytes()[..]);
+
+        // Vouchers that differ in any field have different messages
+        let other = Voucher {
+            nonce: nonce.wrapping_add(1),
+            ..voucher
+        };
+        prop_assert_ne!(&other.message()[..], &message[..]);
+    }
+
+    #[test]
+    fn test_delegate_allowance_round_trip(delegate_allowance in delegate_allowance()) {
+        let data = pack(&delegate_allowance);
+        prop_assert_eq!(
+            &data[DelegateAllowance::ESCROW_OFFSET..][..32],
+            delegate_allowance.escrow.as_ref()
+        );
+        prop_assert_eq!(
+            &data[DelegateAllowance::DELEGATE_OFFSET..][..32],
+            delegate_allowance.delegate.as_ref()
+        );
+        prop_assert_eq!(
+            DelegateAllowance::unpack_from_slice(&data).unwrap(),
+            delegate_allowance
+        );
+    }
+
+    #[test]
+    fn test_delegate_allowance_consume(
+        mut delegate_allowance in delegate_allowance(),
+        amount in any::<u64>(),
+    ) {
+        let remaining = delegate_allowance.remaining;
+        let consumed = delegate_allowance.consume(amount);
+        if amount <= remaining {
+            prop_assert!(consumed.is_some());
+            prop_assert_eq!(delegate_allowance.remaining, remaining - amount);
+        } else {
+            // Disbursements beyond the allowance leave it unchanged
+            prop_assert!(consumed.is_none());
+            prop_assert_eq!(delegate_allowance.remaining, remaining);
+        }
+    }
+
+    #[test]
+    fn test_delegate_allowance_is_active(
+        delegate_allowance in delegate_allowance(),
+        slot in any::<u64>(),
+    ) {
+        prop_assert_eq!(
+            delegate_allowance.is_active(slot),
+            slot <= delegate_allowance.expiry_slot
+        );
+    }
+
+    #[test]
+    fn test_subscription_round_trip(subscription in subscription()) {
+        let data = pack(&subscription);
+        prop_assert_eq!(
+            &data[Subscription::ESCROW_OFFSET..][..32],
+            subscription.escrow.as_ref()
+        );
+        prop_assert_eq!(
+            &data[Subscription::AUTHORITY_OFFSET..][..32],
+            subscription.authority.as_ref()
+        );
+        prop_assert_eq!(
+            &data[Subscription::SOURCE_TOKEN_OFFSET..][..32],
+            subscription.source_token.as_ref()
+        );
+        prop_assert_eq!(Subscription::unpack_from_slice(&data).unwrap(), subscription);
+    }
+
+    #[test]
+    fn test_subscription_advance(mut subscription in subscription(), slot in any::<u64>()) {
+        prop_assert_eq!(subscription.is_due(slot), slot >= subscription.next_slot);
+        let next_slot = subscription.next_slot;
+        match next_slot.checked_add(subscription.period) {
+            Some(advanced) => {
+                prop_assert!(subscription.advance().is_some());
+                prop_assert_eq!(subscription.next_slot, advanced);
+            }
+            None => prop_assert!(subscription.advance().is_none()),
+        }
+    }
+
+    #[test]
+    fn test_job_bundle_round_trip(job_bundle in job_bundle()) {
+        let data = pack(&job_bundle);
+        prop_assert_eq!(
+            &data[JobBundle::ESCROW_OFFSET..][..32],
+            job_bundle.escrow.as_ref()
+        );
+        prop_assert_eq!(
+            &data[JobBundle::AUTHORITY_OFFSET..][..32],
+            job_bundle.authority.as_ref()
+        );
+        prop_assert_eq!(
+            &data[JobBundle::COMPLETED_OFFSET..][..8],
+            &job_bundle.completed.to_le_bytes()[..]
+        );
+        prop_assert_eq!(JobBundle::unpack_from_slice(&data).unwrap(), job_bundle);
+    }
+
+    #[test]
+    fn test_job_bundle_record(
+        mut job_bundle in job_bundle(),
+        offset in any::<u64>(),
+        status in job_status(),
+    ) {
+        let job_id = job_bundle.first_job_id.wrapping_add(offset);
+        let index = job_bundle.job_index(job_id);
+        prop_assert_eq!(
+            index.is_some(),
+            job_id >= job_bundle.first_job_id && offset < job_bundle.job_count as u64
+        );
+        if let Some(index) = index {
+            let recorded = job_bundle.record(index, status);
+            prop_assert_eq!(
+                recorded,
+                matches!(status, JobStatus::Paid | JobStatus::Resolved | JobStatus::Cancelled)
+            );
+            if recorded {
+                prop_assert_ne!((job_bundle.completed | job_bundle.cancelled) & 1 << index, 0);
+            }
+        }
+
+        // Recording every job of the bundle as paid completes it
+        for index in 0..job_bundle.job_count {
+            job_bundle.record(index, JobStatus::Paid);
+        }
+        prop_assert!(job_bundle.is_complete());
+    }
+
+    #[test]
+    fn test_job_index_round_trip(job_index in job_index()) {
+        let data = pack(&job_index);
+        prop_assert_eq!(
+            &data[JobIndex::ESCROW_OFFSET..][..32],
+            job_index.escrow.as_ref()
+        );
+        prop_assert_eq!(
+            &data[JobIndex::AUTHORITY_OFFSET..][..32],
+            job_index.authority.as_ref()
+        );
+        prop_assert_eq!(JobIndex::unpack_from_slice(&data).unwrap(), job_index);
+    }
+
+    #[test]
+    fn test_job_index_page_round_trip(job_index_page in job_index_page()) {
+        let data = pack(&job_index_page);
+        prop_assert_eq!(
+            &data[JobIndexPage::JOB_INDEX_OFFSET..][..32],
+            job_index_page.job_index.as_ref()
+        );
+        prop_assert_eq!(JobIndexPage::unpack_from_slice(&data).unwrap(), job_index_page);
+    }
+
+    #[test]
+    fn test_job_index_page_push(job_ids in vec(any::<u64>(), 0..=2 * JOB_INDEX_PAGE_LEN)) {
+        let mut job_index = JobIndex::new(InitJobIndexParams {
+            escrow: Pubkey::default(),
+            authority: Pubkey::default(),
+        });
+        let mut pages: Vec<JobIndexPage> = Vec::new();
+        for job_id in &job_ids {
+            let page = job_index.next_page().unwrap();
+            if pages.len() <= page as usize {
+                pages.push(JobIndexPage::new(InitJobIndexPageParams {
+                    job_index: Pubkey::default(),
+                    page,
+                }));
+            }
+            prop_assert!(pages[page as usize].push(*job_id));
+            job_index.job_count += 1;
+        }
+        prop_assert_eq!(job_index.page_count(), pages.len() as u64);
+        let recorded: Vec<u64> = pages.iter().flat_map(|page| page.job_ids.clone()).collect();
+        prop_assert_eq!(recorded, job_ids);
+        if let Some(page) = pages.last_mut() {
+            if page.job_ids.len() == JOB_INDEX_PAGE_LEN {
+                prop_assert!(!page.push(0));
+            }
+        }
+    }
+
+    #[test]
+    fn test_job_queue_round_trip(job_queue in job_queue()) {
+        let data = pack(&job_queue);
+        prop_assert_eq!(
+            &data[JobQueue::ESCROW_OFFSET..][..32],
+            job_queue.escrow.as_ref()
+        );
+        prop_assert_eq!(JobQueue::unpack_from_slice(&data).unwrap(), job_queue);
+    }
+
+    #[test]
+    fn test_job_queue_push_pop(
+        pushes in vec(vec(pubkey(), 0..=JOB_QUEUE_LEN), 1..4),
+        pops in vec(0..=JOB_QUEUE_LEN, 1..4),
+    ) {
+        let mut job_queue = JobQueue::new(InitJobQueueParams {
+            escrow: Pubkey::default(),
+        });
+        let mut expected = std::collections::VecDeque::new();
+        for (jobs, pops) in pushes.iter().zip(&pops) {
+            for job in jobs {
+                let pushed = job_queue.push(*job);
+                prop_assert_eq!(pushed, expected.len() < JOB_QUEUE_LEN);
+                if pushed {
+                    expected.push_back(*job);
+                }
+            }
+            for _ in 0..*pops {
+                prop_assert_eq!(job_queue.pop(), expected.pop_front());
+            }
+
+            // Jobs wrap around the circular buffer from wherever the head is
+            let data = pack(&job_queue);
+            let unpacked = JobQueue::unpack_from_slice(&data).unwrap();
+            prop_assert_eq!(&unpacked, &job_queue);
+            prop_assert_eq!(unpacked.front(), expected.front());
+            prop_assert_eq!(unpacked.jobs, Vec::from(expected.clone()));
+        }
+    }
+
+    #[test]
+    fn test_job_tree_round_trip(job_tree in job_tree()) {
+        let data = pack(&job_tree);
+        prop_assert_eq!(
+            &data[JobTree::ESCROW_OFFSET..][..32],
+            job_tree.escrow.as_ref()
+        );
+        prop_assert_eq!(
+            &data[JobTree::AUTHORITY_OFFSET..][..32],
+            job_tree.authority.as_ref()
+        );
+        prop_assert_eq!(
+            &data[JobTree::MERKLE_TREE_OFFSET..][..32],
+            job_tree.merkle_tree.as_ref()
+        );
+        prop_assert_eq!(JobTree::unpack_from_slice(&data).unwrap(), job_tree);
+    }
+
+    #[test]
+    fn test_compressed_job_leaf(job in compressed_job(), job_tree in pubkey()) {
+        // The leaf is the hash of the preimage logged through the noop program
+        let data = job.leaf_data(&job_tree);
+        prop_assert_eq!(data.len(), 32 + 8 + 8 + 1 + 32);
+        prop_assert_eq!(&data[..32], job_tree.as_ref());
+        prop_assert_eq!(job.leaf(&job_tree), hash(&data).to_bytes());
+    }
+
+    #[test]
+    fn test_session_key_round_trip(session_key in session_key()) {
+        let data = pack(&session_key);
+        prop_assert_eq!(
+            &data[SessionKey::ESCROW_OFFSET..][..32],
+            session_key.escrow.as_ref()
+        );
+        prop_assert_eq!(
+            &data[SessionKey::AUTHORITY_OFFSET..][..32],
+            session_key.authority.as_ref()
+        );
+        prop_assert_eq!(
+            &data[SessionKey::SESSION_KEY_OFFSET..][..32],
+            session_key.session_key.as_ref()
+        );
+        prop_assert_eq!(SessionKey::unpack_from_slice(&data).unwrap(), session_key);
+    }
+
+    #[test]
+    fn test_session_key_allows(
+        mut session_key in session_key(),
+        expiry_slot in 0..u64::MAX,
+        amount in any::<u64>(),
+        job_id in any::<u64>(),
+    ) {
+        // Each bit of the mask is the bit of the tag of the instruction it allows
+        prop_assert_eq!(
+            SESSION_KEY_FUND_JOB,
+            1 << tag(&RNDRInstruction::FundJob {
+                amount,
+                job_id,
+                metadata: None,
+            })
+        );
+        prop_assert_eq!(
+            SESSION_KEY_CHALLENGE_RESULT,
+            1 << tag(&RNDRInstruction::ChallengeResult)
+        );
+
+        session_key.expiry_slot = expiry_slot;
+        session_key.instruction_mask = SESSION_KEY_FUND_JOB;
+        prop_assert!(session_key.allows(SESSION_KEY_FUND_JOB, expiry_slot));
+        prop_assert!(!session_key.allows(SESSION_KEY_FUND_JOB, expiry_slot + 1));
+        prop_assert!(!session_key.allows(SESSION_KEY_CHALLENGE_RESULT, expiry_slot));
+    }
+
+    #[test]
+    fn test_treasury_round_trip(treasury in treasury()) {
+        let data = pack(&treasury);
+        prop_assert_eq!(&data[Treasury::ESCROW_OFFSET..][..32], treasury.escrow.as_ref());
+        prop_assert_eq!(&data[Treasury::TOKEN_MINT_OFFSET..][..32], treasury.token_mint.as_ref());
+        prop_assert_eq!(
+            &data[Treasury::EPOCH_BURN_CAP_OFFSET..][..8],
+            &treasury.epoch_burn_cap.to_le_bytes()[..]
+        );
+        prop_assert_eq!(
+            &data[Treasury::BURN_EPOCH_OFFSET..][..8],
+            &treasury.burn_epoch.to_le_bytes()[..]
+        );
+        prop_assert_eq!(
+            &data[Treasury::EPOCH_BURNED_OFFSET..][..8],
+            &treasury.epoch_burned.to_le_bytes()[..]
+        );
+        prop_assert_eq!(
+            &data[Treasury::TOTAL_BURNED_OFFSET..][..8],
+            &treasury.total_burned.to_le_bytes()[..8]
+        );
+        prop_assert_eq!(
+            &data[Treasury::TOTAL_BURNED_HIGH_OFFSET..][..8],
+            &treasury.total_burned.to_le_bytes()[8..]
+        );
+        prop_assert_eq!(
+            &data[Treasury::COLD_WALLET_OFFSET..][..32],
+            treasury.cold_wallet.as_ref()
+        );
+        prop_assert_eq!(
+            &data[Treasury::SWEEP_AMOUNT_OFFSET..][..8],
+            &treasury.sweep_amount.to_le_bytes()[..]
+        );
+        prop_assert_eq!(
+            &data[Treasury::SWEEP_EXECUTABLE_AT_OFFSET..][..8],
+            &treasury.sweep_executable_at.to_le_bytes()[..]
+        );
+        prop_assert_eq!(Treasury::unpack_from_slice(&data).unwrap(), treasury.clone());
+
+        // Treasuries with the legacy length unpack with the high bits of their totals and the
+        // cold wallet fields set to zero, and only pack treasuries where those are zero
+        let legacy = Treasury::unpack_account(&data[..Treasury::LEGACY_LEN]).unwrap();
+        prop_assert_eq!(
+            legacy,
+            Treasury {
+                total_burned: treasury.total_burned & u128::from(u64::MAX),
+                cold_wallet: Pubkey::default(),
+                sweep_amount: 0,
+                sweep_executable_at: 0,
+                ..treasury.clone()
+            }
+        );
+        let mut legacy_data = vec![0; Treasury::LEGACY_LEN];
+        prop_assert_eq!(
+            Treasury::pack_account(treasury.clone(), &mut legacy_data).is_ok(),
+            treasury.total_burned <= u128::from(u64::MAX)
+                && treasury.cold_wallet == Pubkey::default()
+                && treasury.sweep_amount == 0
+                && treasury.sweep_executable_at == 0
+        );
+
+        let legacy = Treasury::unpack_account(&data[..Treasury::PRE_COLD_WALLET_LEN]).unwrap();
+        prop_assert_eq!(
+            legacy,
+            Treasury {
+                cold_wallet: Pubkey::default(),
+                sweep_amount: 0,
+                sweep_executable_at: 0,
+                ..treasury.clone()
+            }
+        );
+        let mut legacy_data = vec![0; Treasury::PRE_COLD_WALLET_LEN];
+        prop_assert_eq!(
+            Treasury::pack_account(treasury.clone(), &mut legacy_data).is_ok(),
+            treasury.cold_wallet == Pubkey::default()
+                && treasury.sweep_amount == 0
+                && treasury.sweep_executable_at == 0
+        );
+    }
+
+    #[test]
+    fn test_treasury_burnable(
+        epoch in 0..u64::MAX / 2,
+        epoch_burn_cap in 1..u64::MAX / 2,
+        burned in 0..u64::MAX / 2,
+    ) {
+        let mut treasury = Treasury::new(InitTreasuryParams {
+            escrow: Pubkey::new_unique(),
+            token_mint: Pubkey::new_unique(),
+            epoch_burn_cap,
+        });
+        treasury.record_burn(epoch, burned).unwrap();
+        prop_assert_eq!(treasury.burnable(epoch), epoch_burn_cap.saturating_sub(burned));
+        prop_assert_eq!(treasury.burnable(epoch + 1), epoch_burn_cap);
+
+        treasury.epoch_burn_cap = 0;
+        prop_assert_eq!(treasury.burnable(epoch), u64::MAX);
+    }
+
+    #[test]
+    fn test_stats_round_trip(stats in stats()) {
+        let data = pack(&stats);
+        prop_assert_eq!(&data[Stats::ESCROW_OFFSET..][..32], stats.escrow.as_ref());
+        prop_assert_eq!(&data[Stats::EPOCH_OFFSET..][..8], &stats.epoch.to_le_bytes()[..]);
+        prop_assert_eq!(
+            &data[Stats::TOTAL_ESCROWED_OFFSET..][..8],
+            &stats.total_escrowed.to_le_bytes()[..8]
+        );
+        prop_assert_eq!(
+            &data[Stats::EPOCH_DISBURSED_OFFSET..][..8],
+            &stats.epoch_disbursed.to_le_bytes()[..]
+        );
+        prop_assert_eq!(
+            &data[Stats::TOTAL_ESCROWED_HIGH_OFFSET..][..8],
+            &stats.total_escrowed.to_le_bytes()[8..]
+        );
+        prop_assert_eq!(
+            &data[Stats::TOTAL_DISBURSED_HIGH_OFFSET..][..8],
+            &stats.total_disbursed.to_le_bytes()[8..]
+        );
+        prop_assert_eq!(Stats::unpack_from_slice(&data).unwrap(), stats.clone());
+
+        // Stats with the legacy length unpack with the high bits of their totals set to zero
+        let low = u128::from(u64::MAX);
+        let legacy = Stats::unpack_account(&data[..Stats::LEGACY_LEN]).unwrap();
+        prop_assert_eq!(
+            legacy,
+            Stats {
+                total_escrowed: stats.total_escrowed & low,
+                total_burned: stats.total_burned & low,
+                total_emitted: stats.total_emitted & low,
+                total_disbursed: stats.total_disbursed & low,
+                ..stats
+            }
+        );
+    }
+
+    #[test]
+    fn test_stats_record(epoch in 0..u64::MAX / 2, amount in 0..u64::MAX / 4) {
+        let mut stats = Stats::new(InitStatsParams {
+            escrow: Pubkey::new_unique(),
+            epoch,
+        });
+        stats.record(epoch, TokenFlow::Escrowed, amount).unwrap();
+        stats.record(epoch, TokenFlow::Disbursed, amount).unwrap();
+        prop_assert_eq!(stats.epoch_escrowed, amount);
+        prop_assert_eq!(stats.epoch_disbursed, amount);
+
+        stats.record(epoch + 1, TokenFlow::Escrowed, amount).unwrap();
+        prop_assert_eq!(stats.epoch, epoch + 1);
+        prop_assert_eq!(stats.epoch_escrowed, amount);
+        prop_assert_eq!(stats.epoch_disbursed, 0);
+        prop_assert_eq!(stats.total_escrowed, 2 * u128::from(amount));
+        prop_assert_eq!(stats.total_disbursed, u128::from(amount));
+    }
+
+    #[test]
+    fn test_stats_record_past_u64(epoch in any::<u64>(), amount in 1..=u64::MAX) {
+        let mut stats = Stats::new(InitStatsParams {
+            escrow: Pubkey::new_unique(),
+            epoch,
+        });
+        stats.total_escrowed = u128::from(u64::MAX);
+
+        // Lifetime totals grow past 64 bits, while epoch totals still overflow at them
+        stats.record(epoch, TokenFlow::Escrowed, amount).unwrap();
+        prop_assert_eq!(stats.total_escrowed, u128::from(u64::MAX) + u128::from(amount));
+        prop_assert_eq!(stats.clone().record(epoch, TokenFlow::Escrowed, u64::MAX), None);
+
+        // Stats with the legacy length must be reallocated to store them
+        let mut data = vec![0; Stats::LEGACY_LEN];
+        prop_assert_eq!(
+            Stats::pack_account(stats.clone(), &mut data),
+            Err(ProgramError::AccountDataTooSmall)
+        );
+        let mut data = vec![0; Stats::LEN];
+        Stats::pack_account(stats.clone(), &mut data).unwrap();
+        prop_assert_eq!(Stats::unpack_account(&data).unwrap(), stats);
+    }
+
+    #[test]
+    fn test_stake_pool_round_trip(stake_pool in stake_pool()) {
+        let data = pack(&stake_pool);
+        prop_assert_eq!(
+            &data[StakePool::RECEIPT_MINT_OFFSET..][..32],
+            stake_pool.receipt_mint.as_ref()
+        );
+        prop_assert_eq!(
+            &data[StakePool::TOTAL_STAKED_OFFSET..][..8],
+            &stake_pool.total_staked.to_le_bytes()[..]
+        );
+        prop_assert_eq!(
+            &data[StakePool::RECEIPT_SUPPLY_OFFSET..][..8],
+            &stake_pool.receipt_supply.to_le_bytes()[..]
+        );
+        prop_assert_eq!(StakePool::unpack_from_slice(&data).unwrap(), stake_pool);
+    }
+
+    #[test]
+    fn test_stake_pool_exchange_rate(
+        total_staked in 1..=u64::MAX / 4,
+        receipt_supply in 1..=u64::MAX / 4,
+        amount in any::<u64>(),
+    ) {
+        let amount = amount % total_staked;
+        let mut stake_pool = StakePool {
+            total_staked,
+            receipt_supply,
+            ..StakePool::default()
+        };
+        let receipt_amount = stake_pool.receipt_amount(amount).unwrap();
+        stake_pool.total_staked += amount;
+        stake_pool.receipt_supply += receipt_amount;
+        // Staking and immediately unstaking never withdraws more than was staked
+        prop_assert!(stake_pool.stake_amount(receipt_amount).unwrap() <= amount);
+    }
+
+    #[test]
+    fn test_mint_migration_round_trip(mint_migration in mint_migration()) {
+        let data = pack(&mint_migration);
+        prop_assert_eq!(
+            &data[MintMigration::ESCROW_OFFSET..][..32],
+            mint_migration.escrow.as_ref()
+        );
+        prop_assert_eq!(MintMigration::unpack_from_slice(&data).unwrap(), mint_migration);
+
+        let mut data = data;
+        data[MintMigration::VAULT_CONVERTED_OFFSET] = 2;
+        prop_assert!(MintMigration::unpack_from_slice(&data).is_err());
+    }
+
+    #[test]
+    fn test_mint_migration_convert(
+        amount in any::<u64>(),
+        rate_numerator in 1..=u64::MAX,
+        rate_denominator in 1..=u64::MAX,
+    ) {
+        let mint_migration = MintMigration {
+            rate_numerator,
+            rate_denominator,
+            ..MintMigration::default()
+        };
+        let converted = u128::from(amount) * u128::from(rate_numerator) / u128::from(rate_denominator);
+        prop_assert_eq!(mint_migration.convert(amount), u64::try_from(converted).ok());
+        // Converting at a rate of one keeps the amount
+        let identity = MintMigration {
+            rate_numerator,
+            rate_denominator: rate_numerator,
+            ..MintMigration::default()
+        };
+        prop_assert_eq!(identity.convert(amount), Some(amount));
+    }
+
+    #[test]
+    fn test_verifier_selection_sample(
+        job in pubkey(),
+        slot_hash in any::<[u8; 32]>(),
+        registered in vec(pubkey(), 0..=MAX_VERIFIERS),
+        count in 0..=MAX_SELECTED_VERIFIERS,
+    ) {
+        let slot_hash = Hash::new_from_array(slot_hash);
+        let sample = VerifierSelection::sample(&job, &slot_hash, &registered, count);
+        prop_assert_eq!(sample.len(), count.min(registered.len()));
+        for (i, verifier) in sample.iter().enumerate() {
+            prop_assert!(registered.contains(verifier));
+            prop_assert!(!sample[..i].contains(verifier));
+        }
+        prop_assert_eq!(
+            VerifierSelection::sample(&job, &slot_hash, &registered, count),
+            sample
+        );
+    }
+
+    #[test]
+    fn test_state_unpack_pack(
+        mut escrow_data in vec(any::<u8>(), Escrow::LEN),
+        mut job_data in vec(any::<u8>(), Job::LEN),
+        mut escrow_shard_data in vec(any::<u8>(), EscrowShard::LEN),
+    ) {
+        escrow_data[0] = AccountType::EscrowV1.into();
+        let owner_history_len =
+            escrow_data[Escrow::OWNER_HISTORY_LEN_OFFSET] as usize % (MAX_OWNER_HISTORY + 1);
+        escrow_data[Escrow::OWNER_HISTORY_LEN_OFFSET] = owner_history_len as u8;
+        escrow_data[Escrow::OWNER_HISTORY_OFFSET + owner_history_len * OwnerRotation::LEN..].fill(0);
+        let escrow = Escrow::unpack_from_slice(&escrow_data).unwrap();
+        prop_assert_eq!(pack(&escrow), escrow_data);
+
+        job_data[0] = AccountType::JobV1.into();
+        job_data[Job::ISOLATED_OFFSET] %= 2;
+        job_data[Job::STATUS_OFFSET] %= 8;
+        job_data[Job::FROZEN_OFFSET] %= 2;
+        let metadata_uri_len =
+            job_data[Job::METADATA_URI_OFFSET] as usize % (MAX_JOB_METADATA_URI_LEN + 1);
+        job_data[Job::METADATA_URI_OFFSET] = metadata_uri_len as u8;
+        let metadata_uri = &mut job_data[Job::METADATA_URI_OFFSET + 1..Job::METADATA_HASH_OFFSET];
+        let (metadata_uri, padding) = metadata_uri.split_at_mut(metadata_uri_len);
+        metadata_uri.iter_mut().for_each(|byte| *byte = b'a' + *byte % 26);
+        padding.fill(0);
+        let job = Job::unpack_from_slice(&job_data).unwrap();
+        prop_assert_eq!(pack(&job), job_data);
+
+        escrow_shard_data[0] = AccountType::EscrowShardV1.into();
+        let escrow_shard = EscrowShard::unpack_from_slice(&escrow_shard_data).unwrap();
+        prop_assert_eq!(pack(&escrow_shard), escrow_shard_data);
+    }
+}
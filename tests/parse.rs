@@ -0,0 +1,124 @@
+#![cfg(feature = "client")]
+
+use {
+    rndr::{
+        client::parse::{parse, ParseInstructionError, ParsedInstruction},
+        instruction::{aggregate_escrow_shard, disburse_batch, fund_job, RNDRInstruction},
+        pda::find_escrow_address,
+    },
+    serde_json::json,
+    solana_program::{
+        instruction::{CompiledInstruction, Instruction},
+        message::{AccountKeys, Message},
+        pubkey::Pubkey,
+    },
+};
+
+fn parse_instruction(instruction: Instruction) -> Result<ParsedInstruction, ParseInstructionError> {
+    let message = Message::new(&[instruction], None);
+    parse(
+        &message.instructions[0],
+        &AccountKeys::new(&message.account_keys, None),
+    )
+}
+
+#[test]
+fn test_parse_fund_job() {
+    let token_mint = Pubkey::new_unique();
+    let funder = Pubkey::new_unique();
+    let source_token = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let instruction = fund_job(
+        rndr::id(),
+        42,
+        7,
+        token_mint,
+        funder,
+        source_token,
+        authority,
+    );
+    let keys = instruction
+        .accounts
+        .iter()
+        .map(|account| account.pubkey.to_string())
+        .collect::<Vec<_>>();
+
+    let parsed = parse_instruction(instruction).unwrap();
+    assert_eq!(parsed.instruction_type, "fundJob");
+    assert_eq!(
+        parsed.info,
+        json!({
+            "amount": 42,
+            "jobId": 7,
+            "metadata": null,
+            "funderSolAccount": keys[0],
+            "sourceRndrTokenAccount": keys[1],
+            "sourceTokenAccountAuthority": keys[2],
+            "escrowAccount": keys[3],
+            "escrowAtaAccount": keys[4],
+            "jobAccount": keys[5],
+            "escrowShardAccount": keys[6],
+            "systemProgramId": keys[7],
+            "tokenProgramId": keys[8],
+        })
+    );
+    assert_eq!(
+        serde_json::to_value(&parsed).unwrap()["type"],
+        json!("fundJob")
+    );
+}
+
+#[test]
+fn test_parse_remaining_accounts() {
+    let destinations = [Pubkey::new_unique(), Pubkey::new_unique()];
+    let instruction = disburse_batch(
+        rndr::id(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        &[(destinations[0], 1), (destinations[1], 2)],
+    );
+
+    let parsed = parse_instruction(instruction).unwrap();
+    assert_eq!(parsed.instruction_type, "disburseBatch");
+    assert_eq!(parsed.info["amounts"], json!([1, 2]));
+    assert_eq!(
+        parsed.info["destinationRndrTokenAccounts"],
+        json!([destinations[0].to_string(), destinations[1].to_string()])
+    );
+    assert_eq!(
+        parsed.info["escrowShardAccounts"].as_array().unwrap().len(),
+        1
+    );
+}
+
+#[test]
+fn test_parse_unit_instruction() {
+    let (escrow, _bump_seed) = find_escrow_address(&rndr::id(), &Pubkey::new_unique());
+    let parsed = parse_instruction(aggregate_escrow_shard(rndr::id(), escrow, 3)).unwrap();
+    assert_eq!(parsed.instruction_type, "aggregateEscrowShard");
+    assert_eq!(parsed.info["escrowAccount"], json!(escrow.to_string()));
+    assert!(parsed.info.get("crankVaultAccount").is_none());
+}
+
+#[test]
+fn test_parse_errors() {
+    let keys = [Pubkey::new_unique()];
+    let account_keys = AccountKeys::new(&keys, None);
+
+    let garbage = CompiledInstruction::new_from_raw_parts(0, vec![255], vec![]);
+    assert_eq!(
+        parse(&garbage, &account_keys),
+        Err(ParseInstructionError::InstructionNotParsable)
+    );
+
+    let mismatch = CompiledInstruction::new_from_raw_parts(
+        0,
+        RNDRInstruction::AggregateEscrowShard.pack(),
+        vec![0, 1],
+    );
+    assert_eq!(
+        parse(&mismatch, &account_keys),
+        Err(ParseInstructionError::InstructionKeyMismatch(1))
+    );
+}
@@ -0,0 +1,165 @@
+#![cfg(feature = "client")]
+
+use {
+    base64::{engine::general_purpose::STANDARD, Engine},
+    rndr::{
+        client::{preflight::ReturnData, RndrClient},
+        error::RNDRError,
+        instruction::{disburse_funds, get_escrowed_amount},
+    },
+    serde_json::{json, Value},
+    solana_client::{
+        nonblocking::rpc_client::RpcClient,
+        rpc_client::Mocks,
+        rpc_request::RpcRequest,
+        rpc_response::{Response, RpcResponseContext},
+    },
+    solana_program_test::tokio,
+    solana_sdk::{instruction::InstructionError, pubkey::Pubkey, transaction::TransactionError},
+};
+
+fn simulation_mocks(result: Value) -> Mocks {
+    let mut mocks = Mocks::default();
+    mocks.insert(
+        RpcRequest::SimulateTransaction,
+        json!(Response {
+            context: RpcResponseContext {
+                slot: 1,
+                api_version: None,
+            },
+            value: result,
+        }),
+    );
+    mocks
+}
+
+fn client(mocks: Mocks) -> RndrClient {
+    let rpc_client = RpcClient::new_mock_with_mocks("succeeds".to_string(), mocks);
+    RndrClient::new(rpc_client, Pubkey::new_unique())
+}
+
+#[tokio::test]
+async fn test_failure_cause() {
+    let program_id = rndr::id();
+    let error = TransactionError::InstructionError(
+        1,
+        InstructionError::Custom(RNDRError::JobDisputed as u32),
+    );
+    let client = client(simulation_mocks(json!({
+        "err": error,
+        "logs": [
+            "Program ComputeBudget111111111111111111111111111111 invoke [1]",
+            "Program ComputeBudget111111111111111111111111111111 success",
+            format!("Program {} invoke [1]", program_id),
+            "Program log: Instruction: DisburseFunds",
+            "Program log: Job is disputed, so its funds can't be disbursed",
+            "Program log: JobDisputed",
+            format!("Program {} consumed 4321 of 1399850 compute units", program_id),
+            format!("Program {} failed: custom program error: 0xc", program_id),
+        ],
+        "accounts": null,
+        "unitsConsumed": 4471,
+        "returnData": null,
+    })));
+    let payer = Pubkey::new_unique();
+
+    let instruction = disburse_funds(
+        program_id,
+        1,
+        client.escrow_seeds(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    );
+    let preflight = client
+        .transaction()
+        .instruction(instruction)
+        .preflight(&payer)
+        .await
+        .unwrap();
+    assert!(!preflight.is_ok());
+    assert_eq!(preflight.units_consumed, Some(4471));
+    assert_eq!(preflight.return_data, None);
+
+    let failure = preflight.failure.unwrap();
+    assert_eq!(failure.error, error);
+    assert_eq!(failure.instruction_index, Some(1));
+    assert_eq!(failure.program_error, Some(RNDRError::JobDisputed));
+    assert_eq!(
+        failure.cause,
+        "Job is disputed, so its funds can't be disbursed"
+    );
+}
+
+#[tokio::test]
+async fn test_failure_without_cause() {
+    let program_id = rndr::id();
+    let error = TransactionError::InstructionError(1, InstructionError::Custom(6));
+    let client = client(simulation_mocks(json!({
+        "err": error,
+        "logs": [
+            format!("Program {} invoke [1]", program_id),
+            "Program log: Instruction: DisburseFunds",
+            "Program log: DuplicateAccount",
+            format!("Program {} failed: custom program error: 0x6", program_id),
+        ],
+        "accounts": null,
+        "unitsConsumed": 150,
+        "returnData": null,
+    })));
+    let payer = Pubkey::new_unique();
+
+    let instruction = disburse_funds(
+        program_id,
+        1,
+        client.escrow_seeds(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    );
+    let failure = client
+        .transaction()
+        .instruction(instruction)
+        .preflight(&payer)
+        .await
+        .unwrap()
+        .failure
+        .unwrap();
+    assert_eq!(failure.program_error, Some(RNDRError::DuplicateAccount));
+    assert_eq!(failure.cause, "DuplicateAccount");
+}
+
+#[tokio::test]
+async fn test_return_data() {
+    let program_id = rndr::id();
+    let client = client(simulation_mocks(json!({
+        "err": null,
+        "logs": [
+            format!("Program {} invoke [1]", program_id),
+            "Program log: Instruction: GetEscrowedAmount",
+            format!("Program {} success", program_id),
+        ],
+        "accounts": null,
+        "unitsConsumed": 1200,
+        "returnData": {
+            "programId": program_id.to_string(),
+            "data": [STANDARD.encode(1_000u64.to_le_bytes()), "base64"],
+        },
+    })));
+    let payer = Pubkey::new_unique();
+
+    let instruction =
+        get_escrowed_amount(program_id, client.escrow_seeds(), Pubkey::new_unique(), 1);
+    let preflight = client
+        .transaction()
+        .instruction(instruction)
+        .preflight(&payer)
+        .await
+        .unwrap();
+    assert!(preflight.is_ok());
+    assert_eq!(preflight.units_consumed, Some(1200));
+    assert_eq!(
+        preflight.return_data,
+        Some(ReturnData::EscrowedAmount(1_000))
+    );
+}
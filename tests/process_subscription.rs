@@ -0,0 +1,247 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{
+        instruction::{process_subscription, with_crank_bounty},
+        processor::process_instruction,
+        test_fixtures::*,
+    },
+    solana_program::{native_token::LAMPORTS_PER_SOL, pubkey::Pubkey},
+    solana_program_test::*,
+    solana_sdk::{signature::Signer, transaction::Transaction},
+};
+
+const ZERO: u64 = 0;
+const DECIMALS: u64 = 1_000_000_000;
+const AMOUNT: u64 = 1 * DECIMALS;
+const PERIOD: u64 = 1_000;
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const BOUNTY: u64 = LAMPORTS_PER_SOL / 100;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let authority = Pubkey::new_unique();
+    let (subscription_pubkey, _bump_seed) =
+        find_subscription_address(test_escrow.pubkey, authority, 0);
+    let test_source_token = TestToken::add_delegated(
+        &mut test,
+        test_mint.pubkey,
+        10 * AMOUNT,
+        subscription_pubkey,
+        2 * AMOUNT,
+    );
+    let test_subscription = TestSubscription::add(
+        &mut test,
+        test_escrow.pubkey,
+        authority,
+        test_source_token.pubkey,
+        AMOUNT,
+        PERIOD,
+        ZERO,
+    );
+    let test_crank_vault = TestCrankVault::add(&mut test, test_escrow.pubkey, BOUNTY, BOUNTY);
+    let recipient = Pubkey::new_unique();
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[with_crank_bounty(
+            process_subscription(
+                rndr::id(),
+                test_mint.pubkey,
+                payer.pubkey(),
+                test_source_token.pubkey,
+                authority,
+                0,
+            ),
+            test_escrow.pubkey,
+            recipient,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    // The first crank creates the job
+    let (job_pubkey, _bump_seed) = find_job_address(test_escrow.pubkey, authority, 0);
+    let job = get_job(&mut banks_client, job_pubkey).await;
+    assert_eq!(job.authority, authority);
+    assert_eq!(job.amount, AMOUNT);
+
+    let source_token_balance_after =
+        get_token_balance(&mut banks_client, test_source_token.pubkey).await;
+    assert_eq!(source_token_balance_after, 9 * AMOUNT);
+
+    let escrow_balance_after =
+        get_token_balance(&mut banks_client, test_escrow.associated_token).await;
+    assert_eq!(escrow_balance_after, AMOUNT);
+
+    let subscription = test_subscription.get(&mut banks_client).await;
+    assert_eq!(subscription.next_slot, PERIOD);
+
+    let recipient_balance = banks_client.get_balance(recipient).await.unwrap();
+    assert_eq!(recipient_balance, BOUNTY);
+
+    let crank_vault = test_crank_vault.get(&mut banks_client).await;
+    assert_eq!(crank_vault.paid, BOUNTY);
+}
+
+#[tokio::test]
+async fn test_not_due() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let authority = Pubkey::new_unique();
+    let (subscription_pubkey, _bump_seed) =
+        find_subscription_address(test_escrow.pubkey, authority, 0);
+    let test_source_token = TestToken::add_delegated(
+        &mut test,
+        test_mint.pubkey,
+        10 * AMOUNT,
+        subscription_pubkey,
+        2 * AMOUNT,
+    );
+    let test_subscription = TestSubscription::add(
+        &mut test,
+        test_escrow.pubkey,
+        authority,
+        test_source_token.pubkey,
+        AMOUNT,
+        PERIOD,
+        PERIOD,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[process_subscription(
+            rndr::id(),
+            test_mint.pubkey,
+            payer.pubkey(),
+            test_source_token.pubkey,
+            authority,
+            0,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let source_token_balance_after =
+        get_token_balance(&mut banks_client, test_source_token.pubkey).await;
+    assert_eq!(source_token_balance_after, 10 * AMOUNT);
+
+    let subscription = test_subscription.get(&mut banks_client).await;
+    assert_eq!(subscription.next_slot, PERIOD);
+}
+
+#[tokio::test]
+async fn test_exceeds_delegated_amount() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let authority = Pubkey::new_unique();
+    let (subscription_pubkey, _bump_seed) =
+        find_subscription_address(test_escrow.pubkey, authority, 0);
+    // The delegated amount caps what the subscription can transfer
+    let test_source_token = TestToken::add_delegated(
+        &mut test,
+        test_mint.pubkey,
+        10 * AMOUNT,
+        subscription_pubkey,
+        AMOUNT - 1,
+    );
+    let test_subscription = TestSubscription::add(
+        &mut test,
+        test_escrow.pubkey,
+        authority,
+        test_source_token.pubkey,
+        AMOUNT,
+        PERIOD,
+        ZERO,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[process_subscription(
+            rndr::id(),
+            test_mint.pubkey,
+            payer.pubkey(),
+            test_source_token.pubkey,
+            authority,
+            0,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let subscription = test_subscription.get(&mut banks_client).await;
+    assert_eq!(subscription.next_slot, ZERO);
+}
+
+#[tokio::test]
+async fn test_wrong_source_token() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let authority = Pubkey::new_unique();
+    let (subscription_pubkey, _bump_seed) =
+        find_subscription_address(test_escrow.pubkey, authority, 0);
+    let test_source_token = TestToken::add_delegated(
+        &mut test,
+        test_mint.pubkey,
+        10 * AMOUNT,
+        subscription_pubkey,
+        2 * AMOUNT,
+    );
+    // Another account that delegated to the subscription can't be drawn from
+    let test_other_token = TestToken::add_delegated(
+        &mut test,
+        test_mint.pubkey,
+        10 * AMOUNT,
+        subscription_pubkey,
+        2 * AMOUNT,
+    );
+    TestSubscription::add(
+        &mut test,
+        test_escrow.pubkey,
+        authority,
+        test_source_token.pubkey,
+        AMOUNT,
+        PERIOD,
+        ZERO,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[process_subscription(
+            rndr::id(),
+            test_mint.pubkey,
+            payer.pubkey(),
+            test_other_token.pubkey,
+            authority,
+            0,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let other_token_balance_after =
+        get_token_balance(&mut banks_client, test_other_token.pubkey).await;
+    assert_eq!(other_token_balance_after, 10 * AMOUNT);
+}
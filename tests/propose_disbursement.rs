@@ -0,0 +1,62 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{instruction::propose_disbursement, processor::process_instruction, test_fixtures::*},
+    solana_program_test::*,
+    solana_sdk::{
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        system_instruction,
+        transaction::Transaction,
+    },
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const AMOUNT: u64 = 1_000_000_000;
+    const PROPOSAL_ID: u64 = 1;
+
+    let approver = Keypair::new();
+    let test_mint = TestMint::add(&mut test, 9, 100 * AMOUNT);
+    let test_escrow =
+        TestEscrow::add_with_approver(&mut test, test_mint.pubkey, AMOUNT, approver.pubkey());
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, Pubkey::new_unique(), AMOUNT);
+    let destination_token = Pubkey::new_unique();
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let owner = &test_escrow.owner;
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            system_instruction::transfer(&payer.pubkey(), &owner.pubkey(), 1_000_000_000),
+            propose_disbursement(
+                rndr::id(),
+                PROPOSAL_ID,
+                AMOUNT,
+                test_mint.pubkey,
+                owner.pubkey(),
+                test_job.pubkey,
+                destination_token,
+            ),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let (proposal_pubkey, _bump_seed) = find_proposal_address(test_escrow.pubkey, PROPOSAL_ID);
+    let proposal = get_proposal(&mut banks_client, proposal_pubkey).await;
+    assert_eq!(proposal.escrow, test_escrow.pubkey);
+    assert_eq!(proposal.id, PROPOSAL_ID);
+    assert_eq!(proposal.job, test_job.pubkey);
+    assert_eq!(proposal.destination, destination_token);
+    assert_eq!(proposal.amount, AMOUNT);
+    assert!(!proposal.is_approved());
+
+    // Funds are still held by the job until the proposal is executed
+    let job = test_job.get(&mut banks_client).await;
+    assert_eq!(job.amount, AMOUNT);
+}
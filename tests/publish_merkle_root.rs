@@ -0,0 +1,133 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{
+        instruction::publish_merkle_root, processor::process_instruction, state::AccountType,
+        test_fixtures::*,
+    },
+    solana_program_test::*,
+    solana_sdk::{
+        native_token::LAMPORTS_PER_SOL, pubkey::Pubkey, signature::Signer, system_instruction,
+        transaction::Transaction,
+    },
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+    const EPOCH: u64 = 0;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_source_token = TestToken::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let owner = &test_escrow.owner;
+    let tree = TestMerkleTree::new(&[
+        (Pubkey::new_unique(), AMOUNT / 4),
+        (Pubkey::new_unique(), AMOUNT / 4),
+        (Pubkey::new_unique(), AMOUNT / 2),
+    ]);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            system_instruction::transfer(&payer.pubkey(), &owner.pubkey(), LAMPORTS_PER_SOL),
+            publish_merkle_root(
+                rndr::id(),
+                test_mint.pubkey,
+                owner.pubkey(),
+                test_source_token.pubkey,
+                test_source_token.owner.pubkey(),
+                EPOCH,
+                tree.root,
+                3,
+                AMOUNT,
+            ),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, owner, &test_source_token.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let (merkle_distribution_pubkey, _bump_seed) =
+        find_merkle_distribution_address(test_escrow.pubkey, EPOCH);
+    let merkle_distribution_associated_token =
+        spl_associated_token_account::get_associated_token_address(
+            &merkle_distribution_pubkey,
+            &test_mint.pubkey,
+        );
+
+    let source_token_balance_after =
+        get_token_balance(&mut banks_client, test_source_token.pubkey).await;
+    let merkle_distribution_balance_after =
+        get_token_balance(&mut banks_client, merkle_distribution_associated_token).await;
+
+    assert_eq!(source_token_balance_after, ZERO);
+    assert_eq!(merkle_distribution_balance_after, AMOUNT);
+
+    let merkle_distribution =
+        get_merkle_distribution(&mut banks_client, merkle_distribution_pubkey).await;
+    assert_eq!(
+        merkle_distribution.account_type,
+        AccountType::MerkleDistributionV1
+    );
+    assert_eq!(merkle_distribution.escrow, test_escrow.pubkey);
+    assert_eq!(merkle_distribution.epoch, EPOCH);
+    assert_eq!(merkle_distribution.root, tree.root);
+    assert_eq!(merkle_distribution.node_count, 3);
+    assert_eq!(merkle_distribution.amount, AMOUNT);
+    assert_eq!(merkle_distribution.claimed, ZERO);
+}
+
+#[tokio::test]
+async fn test_already_published() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+    const EPOCH: u64 = 0;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_source_token = TestToken::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let owner = &test_escrow.owner;
+    let tree = TestMerkleTree::new(&[(Pubkey::new_unique(), AMOUNT)]);
+    TestMerkleDistribution::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_mint.pubkey,
+        EPOCH,
+        [0; 32],
+        1,
+        AMOUNT,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            system_instruction::transfer(&payer.pubkey(), &owner.pubkey(), LAMPORTS_PER_SOL),
+            publish_merkle_root(
+                rndr::id(),
+                test_mint.pubkey,
+                owner.pubkey(),
+                test_source_token.pubkey,
+                test_source_token.owner.pubkey(),
+                EPOCH,
+                tree.root,
+                1,
+                AMOUNT,
+            ),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, owner, &test_source_token.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
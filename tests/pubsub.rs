@@ -0,0 +1,183 @@
+#![cfg(feature = "client")]
+
+use {
+    futures_util::{SinkExt, StreamExt},
+    rndr::{
+        client::RndrClient,
+        state::{AccountType, Escrow, InitJobParams, Job, JobStatus},
+    },
+    serde_json::{json, Value},
+    solana_account_decoder::{UiAccount, UiAccountEncoding},
+    solana_client::{
+        nonblocking::{pubsub_client::PubsubClient, rpc_client::RpcClient},
+        rpc_response::RpcKeyedAccount,
+    },
+    solana_program_test::tokio::{self, net::TcpListener},
+    solana_sdk::{account::Account, program_pack::Pack, pubkey::Pubkey},
+    tokio_tungstenite::{accept_async, tungstenite::Message},
+};
+
+const SLOT: u64 = 5;
+const SUBSCRIPTION: u64 = 1;
+
+fn ui_account(pubkey: &Pubkey, data: Vec<u8>) -> UiAccount {
+    let account = Account {
+        lamports: 1,
+        data,
+        owner: rndr::id(),
+        executable: false,
+        rent_epoch: 0,
+    };
+    UiAccount::encode(pubkey, &account, UiAccountEncoding::Base64, None, None)
+}
+
+fn packed_job(authority: Pubkey, status: JobStatus) -> Vec<u8> {
+    let mut job = Job::new(InitJobParams {
+        authority,
+        id: 0,
+        isolated: false,
+    });
+    job.status = status;
+    let mut data = vec![0; Job::LEN];
+    Job::pack_account(job, &mut data).unwrap();
+    data
+}
+
+/// Serve a single subscription over a websocket, confirming it and then sending notifications
+async fn serve(method: &'static str, notifications: Vec<Value>) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("ws://{}", listener.local_addr().unwrap());
+    tokio::spawn(async move {
+        let (stream, _address) = listener.accept().await.unwrap();
+        let mut ws = accept_async(stream).await.unwrap();
+
+        // Program subscriptions with filters ask for the node version first
+        let request = loop {
+            let request = ws.next().await.unwrap().unwrap();
+            let request: Value = serde_json::from_str(request.to_text().unwrap()).unwrap();
+            if request["method"] != "getVersion" {
+                break request;
+            }
+            ws.send(Message::Text(
+                json!({
+                    "jsonrpc": "2.0",
+                    "result": {"solana-core": "1.18.26", "feature-set": 0},
+                    "id": request["id"],
+                })
+                .to_string(),
+            ))
+            .await
+            .unwrap();
+        };
+        assert_eq!(request["method"], format!("{}Subscribe", method));
+        ws.send(Message::Text(
+            json!({"jsonrpc": "2.0", "result": SUBSCRIPTION, "id": request["id"]}).to_string(),
+        ))
+        .await
+        .unwrap();
+
+        for notification in notifications {
+            ws.send(Message::Text(
+                json!({
+                    "jsonrpc": "2.0",
+                    "method": format!("{}Notification", method),
+                    "params": {
+                        "result": {"context": {"slot": SLOT}, "value": notification},
+                        "subscription": SUBSCRIPTION,
+                    },
+                })
+                .to_string(),
+            ))
+            .await
+            .unwrap();
+        }
+
+        // Confirm the unsubscribe request, then keep the connection open until the client hangs up
+        while let Some(Ok(request)) = ws.next().await {
+            if let Ok(request) = serde_json::from_str::<Value>(request.to_text().unwrap_or("")) {
+                ws.send(Message::Text(
+                    json!({"jsonrpc": "2.0", "result": true, "id": request["id"]}).to_string(),
+                ))
+                .await
+                .unwrap();
+            }
+        }
+    });
+    url
+}
+
+#[tokio::test]
+async fn test_subscribe_escrow() {
+    let client = RndrClient::new(
+        RpcClient::new_mock("succeeds".to_string()),
+        Pubkey::new_unique(),
+    );
+    let escrow = Escrow {
+        account_type: AccountType::EscrowV1,
+        amount: 100,
+        ..Escrow::default()
+    };
+    let mut data = vec![0; Escrow::LEN];
+    Escrow::pack_account(escrow.clone(), &mut data).unwrap();
+
+    let url = serve(
+        "account",
+        vec![
+            json!(ui_account(&client.escrow_address(), data)),
+            json!(ui_account(&client.escrow_address(), vec![0; 3])),
+        ],
+    )
+    .await;
+    let pubsub_client = PubsubClient::new(&url).await.unwrap();
+    let (mut stream, unsubscribe) = client.subscribe_escrow(&pubsub_client).await.unwrap();
+
+    let update = stream.next().await.unwrap().unwrap();
+    assert_eq!(update.pubkey, client.escrow_address());
+    assert_eq!(update.slot, SLOT);
+    assert_eq!(update.account, escrow);
+
+    // Updates that can't be unpacked are yielded as errors
+    assert!(stream.next().await.unwrap().is_err());
+
+    unsubscribe().await;
+}
+
+#[tokio::test]
+async fn test_subscribe_jobs_by_status() {
+    let client = RndrClient::new(
+        RpcClient::new_mock("succeeds".to_string()),
+        Pubkey::new_unique(),
+    );
+    let other_client = RndrClient::new(
+        RpcClient::new_mock("succeeds".to_string()),
+        Pubkey::new_unique(),
+    );
+    let authority = Pubkey::new_unique();
+    let job_address = client.job_address(&authority, 0);
+    let other_job_address = other_client.job_address(&authority, 0);
+
+    let keyed_account = |pubkey: Pubkey| RpcKeyedAccount {
+        pubkey: pubkey.to_string(),
+        account: ui_account(&pubkey, packed_job(authority, JobStatus::Open)),
+    };
+    let url = serve(
+        "program",
+        vec![
+            json!(keyed_account(other_job_address)),
+            json!(keyed_account(job_address)),
+        ],
+    )
+    .await;
+    let pubsub_client = PubsubClient::new(&url).await.unwrap();
+    let (mut stream, unsubscribe) = client
+        .subscribe_jobs_by_status(&pubsub_client, JobStatus::Open)
+        .await
+        .unwrap();
+
+    // Jobs of escrows for other token mints are skipped
+    let update = stream.next().await.unwrap().unwrap();
+    assert_eq!(update.pubkey, job_address);
+    assert_eq!(update.account.status, JobStatus::Open);
+
+    unsubscribe().await;
+}
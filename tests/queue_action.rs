@@ -0,0 +1,135 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{
+        instruction::queue_action,
+        math::Rate,
+        processor::process_instruction,
+        state::{AdminAction, ConfigChange, DEFAULT_TIMELOCK_DURATION, MAX_FEE_BPS},
+        test_fixtures::*,
+    },
+    solana_program_test::*,
+    solana_sdk::{
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        system_instruction,
+        transaction::Transaction,
+    },
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const AMOUNT: u64 = 1_000_000_000;
+    const ACTION_ID: u64 = 1;
+
+    let test_config = TestConfig::add(&mut test);
+    let test_mint = TestMint::add(&mut test, 9, AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let new_owner = Pubkey::new_unique();
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // Only the escrow owner can queue a change of its owner
+    let impostor = Keypair::new();
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            system_instruction::transfer(&payer.pubkey(), &impostor.pubkey(), AMOUNT),
+            queue_action(
+                rndr::id(),
+                impostor.pubkey(),
+                test_escrow.pubkey,
+                ACTION_ID,
+                AdminAction::SetEscrowOwner(new_owner),
+            ),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &impostor], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let owner = &test_escrow.owner;
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            system_instruction::transfer(&payer.pubkey(), &owner.pubkey(), AMOUNT),
+            queue_action(
+                rndr::id(),
+                owner.pubkey(),
+                test_escrow.pubkey,
+                ACTION_ID,
+                AdminAction::SetEscrowOwner(new_owner),
+            ),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let (queued_action_pubkey, _bump_seed) =
+        find_queued_action_address(test_escrow.pubkey, ACTION_ID);
+    let queued_action = get_queued_action(&mut banks_client, queued_action_pubkey).await;
+    assert_eq!(queued_action.target, test_escrow.pubkey);
+    assert_eq!(queued_action.id, ACTION_ID);
+    assert_eq!(queued_action.authority, owner.pubkey());
+    assert_eq!(queued_action.action, AdminAction::SetEscrowOwner(new_owner));
+    assert_eq!(
+        queued_action.executable_at,
+        queued_action.queued_at + DEFAULT_TIMELOCK_DURATION as i64
+    );
+    assert!(!queued_action.is_executed());
+
+    // The owner isn't changed until the queued action is executed
+    let escrow = test_escrow.get(&mut banks_client).await;
+    assert_eq!(escrow.owner, owner.pubkey());
+
+    // Config changes are queued by the governance authority and must be within bounds
+    let governance = &test_config.governance;
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            system_instruction::transfer(&payer.pubkey(), &governance.pubkey(), AMOUNT),
+            queue_action(
+                rndr::id(),
+                governance.pubkey(),
+                test_config.pubkey,
+                ACTION_ID,
+                AdminAction::SetConfig(ConfigChange::FeeBps(Rate::new(MAX_FEE_BPS.bps() + 1))),
+            ),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, governance], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            system_instruction::transfer(&payer.pubkey(), &governance.pubkey(), AMOUNT),
+            queue_action(
+                rndr::id(),
+                governance.pubkey(),
+                test_config.pubkey,
+                ACTION_ID,
+                AdminAction::SetConfig(ConfigChange::FeeBps(MAX_FEE_BPS)),
+            ),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, governance], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let (queued_action_pubkey, _bump_seed) =
+        find_queued_action_address(test_config.pubkey, ACTION_ID);
+    let queued_action = get_queued_action(&mut banks_client, queued_action_pubkey).await;
+    assert_eq!(queued_action.authority, governance.pubkey());
+    assert_eq!(
+        queued_action.action,
+        AdminAction::SetConfig(ConfigChange::FeeBps(MAX_FEE_BPS))
+    );
+
+    let config = test_config.get(&mut banks_client).await;
+    assert_eq!(config.fee_bps, Rate::ZERO);
+}
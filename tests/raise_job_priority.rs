@@ -0,0 +1,162 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{
+        instruction::{commit_result, fund_job, raise_job_priority},
+        math::Rate,
+        processor::process_instruction,
+        state::{JobStatus, MAX_JOB_PRIORITY},
+        test_fixtures::*,
+    },
+    solana_program_test::*,
+    solana_sdk::{signature::Signer, transaction::Transaction},
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 10 * DECIMALS;
+    const PRIORITY_SURCHARGE_BPS: Rate = Rate::new(100);
+    // 1% of the amount for each of the two levels raised
+    const SURCHARGE: u64 = 2 * AMOUNT / 100;
+
+    TestConfig::add_with_priority(&mut test, PRIORITY_SURCHARGE_BPS, 0);
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_treasury = TestTreasury::add(&mut test, test_escrow.pubkey, test_mint.pubkey, 0, 0);
+    let test_source_token = TestToken::add(&mut test, test_mint.pubkey, DECIMALS);
+    let authority = test_source_token.owner.pubkey();
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, authority, AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[raise_job_priority(
+            rndr::id(),
+            test_mint.pubkey,
+            authority,
+            0,
+            test_source_token.pubkey,
+            2,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_source_token.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let job = get_job(&mut banks_client, test_job.pubkey).await;
+    assert_eq!(job.priority, 2);
+    assert_eq!(job.amount, AMOUNT);
+
+    let treasury_balance =
+        get_token_balance(&mut banks_client, test_treasury.associated_token).await;
+    let source_balance = get_token_balance(&mut banks_client, test_source_token.pubkey).await;
+    assert_eq!(treasury_balance, SURCHARGE);
+    assert_eq!(source_balance, DECIMALS - SURCHARGE);
+
+    // The priority of a job can only be raised, and only up to the maximum priority
+    for priority in [2, MAX_JOB_PRIORITY + 1] {
+        let recent_blockhash = banks_client
+            .get_new_latest_blockhash(&recent_blockhash)
+            .await
+            .unwrap();
+        let mut transaction = Transaction::new_with_payer(
+            &[raise_job_priority(
+                rndr::id(),
+                test_mint.pubkey,
+                authority,
+                0,
+                test_source_token.pubkey,
+                priority,
+            )],
+            Some(&payer.pubkey()),
+        );
+        transaction.sign(&[&payer, &test_source_token.owner], recent_blockhash);
+
+        assert!(banks_client.process_transaction(transaction).await.is_err());
+    }
+
+    let job = get_job(&mut banks_client, test_job.pubkey).await;
+    assert_eq!(job.priority, 2);
+}
+
+#[tokio::test]
+async fn test_claim_delay() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 10 * DECIMALS;
+    const PRIORITY_CLAIM_DELAY: u64 = 60 * 60;
+    const COMMITMENT: [u8; 32] = [1; 32];
+
+    TestConfig::add_with_priority(&mut test, Rate::ZERO, PRIORITY_CLAIM_DELAY);
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, 0);
+    let test_source_token = TestToken::add(&mut test, test_mint.pubkey, AMOUNT);
+    let authority = test_source_token.owner.pubkey();
+    let (job_pubkey, _bump_seed) = find_job_address(test_escrow.pubkey, authority, 0);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[fund_job(
+            rndr::id(),
+            AMOUNT,
+            0,
+            test_mint.pubkey,
+            payer.pubkey(),
+            test_source_token.pubkey,
+            authority,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_source_token.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    // A job of the lowest priority can't be committed to until the claim delay of each level
+    // below the maximum priority has passed
+    let mut transaction = Transaction::new_with_payer(
+        &[commit_result(
+            rndr::id(),
+            COMMITMENT,
+            payer.pubkey(),
+            job_pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    // Without a surcharge, raising the priority doesn't need a treasury
+    let recent_blockhash = banks_client
+        .get_new_latest_blockhash(&recent_blockhash)
+        .await
+        .unwrap();
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            raise_job_priority(
+                rndr::id(),
+                test_mint.pubkey,
+                authority,
+                0,
+                test_source_token.pubkey,
+                MAX_JOB_PRIORITY,
+            ),
+            commit_result(rndr::id(), COMMITMENT, payer.pubkey(), job_pubkey),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_source_token.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let job = get_job(&mut banks_client, job_pubkey).await;
+    assert_eq!(job.priority, MAX_JOB_PRIORITY);
+    assert_eq!(job.status, JobStatus::Committed);
+    assert_eq!(job.node, payer.pubkey());
+}
@@ -0,0 +1,154 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{
+        instruction::reconcile_escrow, processor::process_instruction, state::Escrow,
+        test_fixtures::*,
+    },
+    solana_program::program_pack::Pack,
+    solana_program_test::*,
+    solana_sdk::{signature::Signer, transaction::Transaction},
+};
+
+const DECIMALS: u64 = 1_000_000_000;
+const AMOUNT: u64 = 1 * DECIMALS;
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_source_token = TestToken::add(&mut test, test_mint.pubkey, AMOUNT);
+    // The shard is credited with tokens the escrow token account doesn't hold
+    TestEscrowShard::add(&mut test, test_escrow.pubkey, 3, AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let reconcile = || {
+        reconcile_escrow(
+            rndr::id(),
+            test_mint.pubkey,
+            test_escrow.associated_token,
+            payer.pubkey(),
+        )
+    };
+
+    let mut transaction = Transaction::new_with_payer(&[reconcile()], Some(&payer.pubkey()));
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let escrow = test_escrow.get(&mut banks_client).await;
+    assert!(escrow.needs_attention);
+    assert_eq!(escrow.amount, AMOUNT);
+
+    // Once the escrow token account covers the tracked amount, the flag is cleared
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            spl_token::instruction::transfer(
+                &spl_token::id(),
+                &test_source_token.pubkey,
+                &test_escrow.associated_token,
+                &test_source_token.owner.pubkey(),
+                &[],
+                AMOUNT,
+            )
+            .unwrap(),
+            reconcile(),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_source_token.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let escrow = test_escrow.get(&mut banks_client).await;
+    assert!(!escrow.needs_attention);
+}
+
+#[tokio::test]
+async fn test_legacy_escrow() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add_legacy(&mut test, test_mint.pubkey, AMOUNT);
+    TestEscrowShard::add(&mut test, test_escrow.pubkey, 0, AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[reconcile_escrow(
+            rndr::id(),
+            test_mint.pubkey,
+            test_escrow.associated_token,
+            payer.pubkey(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let account = banks_client
+        .get_account(test_escrow.pubkey)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(account.data.len(), Escrow::LEN);
+    assert!(test_escrow.get(&mut banks_client).await.needs_attention);
+}
+
+#[tokio::test]
+async fn test_missing_escrow_shards() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    TestEscrowShard::add(&mut test, test_escrow.pubkey, 7, AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // Leaving out the shard that's short would understate the tracked amount
+    let mut instruction = reconcile_escrow(
+        rndr::id(),
+        test_mint.pubkey,
+        test_escrow.associated_token,
+        payer.pubkey(),
+    );
+    instruction.accounts.pop();
+
+    let mut transaction = Transaction::new_with_payer(&[instruction], Some(&payer.pubkey()));
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    assert!(!test_escrow.get(&mut banks_client).await.needs_attention);
+}
+
+#[tokio::test]
+async fn test_invalid_token_account() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    // An empty token account of the escrow that isn't the one its tokens are held in
+    let token_account = test_escrow.add_token_account(&mut test, test_mint.pubkey, 0);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[reconcile_escrow(
+            rndr::id(),
+            test_mint.pubkey,
+            token_account,
+            payer.pubkey(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    assert!(!test_escrow.get(&mut banks_client).await.needs_attention);
+}
@@ -0,0 +1,137 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{
+        instruction::register_session_key,
+        processor::process_instruction,
+        state::{AccountType, SESSION_KEY_CHALLENGE_RESULT, SESSION_KEY_INSTRUCTIONS},
+        test_fixtures::*,
+    },
+    solana_program_test::*,
+    solana_sdk::{
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        transaction::Transaction,
+    },
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const EXPIRY_SLOT: u64 = 1_000;
+
+    let test_mint = TestMint::add(&mut test, 9, 0);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, 0);
+    let session_key = Pubkey::new_unique();
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+    let authority = &payer;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[register_session_key(
+            rndr::id(),
+            test_mint.pubkey,
+            authority.pubkey(),
+            session_key,
+            EXPIRY_SLOT,
+            SESSION_KEY_INSTRUCTIONS,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let (session_key_pubkey, _bump_seed) =
+        find_session_key_address(test_escrow.pubkey, authority.pubkey(), session_key);
+    let session_key_account = get_session_key(&mut banks_client, session_key_pubkey).await;
+
+    assert_eq!(session_key_account.account_type, AccountType::SessionKeyV1);
+    assert_eq!(session_key_account.escrow, test_escrow.pubkey);
+    assert_eq!(session_key_account.authority, authority.pubkey());
+    assert_eq!(session_key_account.session_key, session_key);
+    assert_eq!(session_key_account.expiry_slot, EXPIRY_SLOT);
+    assert_eq!(
+        session_key_account.instruction_mask,
+        SESSION_KEY_INSTRUCTIONS
+    );
+}
+
+#[tokio::test]
+async fn test_success_replace() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const EXPIRY_SLOT: u64 = 1_000;
+
+    let test_mint = TestMint::add(&mut test, 9, 0);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, 0);
+    let authority = Keypair::new();
+    let test_session_key = TestSessionKey::add(
+        &mut test,
+        test_escrow.pubkey,
+        authority.pubkey(),
+        EXPIRY_SLOT,
+        SESSION_KEY_INSTRUCTIONS,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // Registering the session key again with an empty mask revokes it
+    let mut transaction = Transaction::new_with_payer(
+        &[register_session_key(
+            rndr::id(),
+            test_mint.pubkey,
+            authority.pubkey(),
+            test_session_key.session_key.pubkey(),
+            EXPIRY_SLOT / 2,
+            0,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let session_key_account = test_session_key.get(&mut banks_client).await;
+    assert_eq!(session_key_account.authority, authority.pubkey());
+    assert_eq!(session_key_account.expiry_slot, EXPIRY_SLOT / 2);
+    assert_eq!(session_key_account.instruction_mask, 0);
+    assert!(!session_key_account.allows(SESSION_KEY_CHALLENGE_RESULT, 0));
+}
+
+#[tokio::test]
+async fn test_invalid_mask() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let test_mint = TestMint::add(&mut test, 9, 0);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, 0);
+    let session_key = Pubkey::new_unique();
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+    let authority = &payer;
+
+    // Session keys can't sign instructions that move funds out of the escrow
+    let mut transaction = Transaction::new_with_payer(
+        &[register_session_key(
+            rndr::id(),
+            test_mint.pubkey,
+            authority.pubkey(),
+            session_key,
+            1_000,
+            u64::MAX,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let (session_key_pubkey, _bump_seed) =
+        find_session_key_address(test_escrow.pubkey, authority.pubkey(), session_key);
+    assert!(banks_client
+        .get_account(session_key_pubkey)
+        .await
+        .unwrap()
+        .is_none());
+}
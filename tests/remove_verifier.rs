@@ -0,0 +1,42 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{instruction::remove_verifier, processor::process_instruction, test_fixtures::*},
+    solana_program_test::*,
+    solana_sdk::{pubkey::Pubkey, signature::Signer, transaction::Transaction},
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const AMOUNT: u64 = 1_000_000_000;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let verifier = Pubkey::new_unique();
+    let other_verifier = Pubkey::new_unique();
+    let test_verifier_registry = TestVerifierRegistry::add(
+        &mut test,
+        test_escrow.pubkey,
+        vec![verifier, other_verifier],
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[remove_verifier(
+            rndr::id(),
+            test_mint.pubkey,
+            test_escrow.owner.pubkey(),
+            verifier,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let verifier_registry = test_verifier_registry.get(&mut banks_client).await;
+    assert_eq!(verifier_registry.verifiers, vec![other_verifier]);
+}
@@ -0,0 +1,267 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{
+        instruction::replace_compressed_job,
+        processor::process_instruction,
+        state::{CompressedJob, JobStatus},
+        test_fixtures::*,
+    },
+    solana_program_test::*,
+    solana_sdk::{
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        transaction::Transaction,
+    },
+};
+
+const DECIMALS: u64 = 1_000_000_000;
+const AMOUNT: u64 = 1 * DECIMALS;
+const MAX_DEPTH: u8 = 4;
+
+fn open_job() -> CompressedJob {
+    CompressedJob {
+        amount: AMOUNT,
+        status: JobStatus::Open,
+        ..CompressedJob::default()
+    }
+}
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+    add_account_compression(&mut test);
+
+    let authority = Keypair::new();
+    let node = Pubkey::new_unique();
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, 3 * AMOUNT);
+    let mut test_job_tree = TestJobTree::add(
+        &mut test,
+        test_escrow.pubkey,
+        authority.pubkey(),
+        MAX_DEPTH,
+        &[open_job(), open_job(), open_job()],
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let job = test_job_tree.jobs[1];
+    let (root, proof) = test_job_tree.proof(job.id);
+    let mut transaction = Transaction::new_with_payer(
+        &[replace_compressed_job(
+            rndr::id(),
+            test_mint.pubkey,
+            authority.pubkey(),
+            test_job_tree.merkle_tree,
+            job,
+            node,
+            root,
+            proof.clone(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    test_job_tree.jobs[1] = CompressedJob {
+        status: JobStatus::Committed,
+        node,
+        ..job
+    };
+    let (new_root, _proof) = test_job_tree.proof(job.id);
+    assert_eq!(test_job_tree.get_root(&mut banks_client).await, new_root);
+
+    // The replaced leaf no longer proves the job was open
+    let recent_blockhash = banks_client
+        .get_new_latest_blockhash(&recent_blockhash)
+        .await
+        .unwrap();
+    let mut transaction = Transaction::new_with_payer(
+        &[replace_compressed_job(
+            rndr::id(),
+            test_mint.pubkey,
+            authority.pubkey(),
+            test_job_tree.merkle_tree,
+            job,
+            node,
+            new_root,
+            proof,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+#[tokio::test]
+async fn test_invalid_authority() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+    add_account_compression(&mut test);
+
+    let authority = Keypair::new();
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_job_tree = TestJobTree::add(
+        &mut test,
+        test_escrow.pubkey,
+        authority.pubkey(),
+        MAX_DEPTH,
+        &[open_job()],
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // The payer's tree doesn't exist, so the job can't be replaced in it
+    let job = test_job_tree.jobs[0];
+    let (root, proof) = test_job_tree.proof(job.id);
+    let mut transaction = Transaction::new_with_payer(
+        &[replace_compressed_job(
+            rndr::id(),
+            test_mint.pubkey,
+            payer.pubkey(),
+            test_job_tree.merkle_tree,
+            job,
+            Pubkey::new_unique(),
+            root,
+            proof,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+#[tokio::test]
+async fn test_committed_job_needs_node() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+    add_account_compression(&mut test);
+
+    let authority = Keypair::new();
+    let node = Keypair::new();
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let mut test_job_tree = TestJobTree::add(
+        &mut test,
+        test_escrow.pubkey,
+        authority.pubkey(),
+        MAX_DEPTH,
+        &[CompressedJob {
+            status: JobStatus::Committed,
+            node: node.pubkey(),
+            ..open_job()
+        }],
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // The authority can't reopen or reassign the job without the node
+    let job = test_job_tree.jobs[0];
+    let (root, proof) = test_job_tree.proof(job.id);
+    for new_node in [Pubkey::default(), Pubkey::new_unique()] {
+        let mut instruction = replace_compressed_job(
+            rndr::id(),
+            test_mint.pubkey,
+            authority.pubkey(),
+            test_job_tree.merkle_tree,
+            job,
+            new_node,
+            root,
+            proof.clone(),
+        );
+        instruction.accounts.remove(6);
+        let mut transaction = Transaction::new_with_payer(&[instruction], Some(&payer.pubkey()));
+        transaction.sign(&[&payer, &authority], recent_blockhash);
+
+        assert!(banks_client.process_transaction(transaction).await.is_err());
+    }
+
+    // With the node's signature, the job is reopened
+    let mut transaction = Transaction::new_with_payer(
+        &[replace_compressed_job(
+            rndr::id(),
+            test_mint.pubkey,
+            authority.pubkey(),
+            test_job_tree.merkle_tree,
+            job,
+            Pubkey::default(),
+            root,
+            proof,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &authority, &node], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    test_job_tree.jobs[0] = open_job();
+    let job = test_job_tree.jobs[0];
+    let (root, proof) = test_job_tree.proof(job.id);
+    assert_eq!(test_job_tree.get_root(&mut banks_client).await, root);
+
+    // An open job can't be reopened
+    let recent_blockhash = banks_client
+        .get_new_latest_blockhash(&recent_blockhash)
+        .await
+        .unwrap();
+    let mut transaction = Transaction::new_with_payer(
+        &[replace_compressed_job(
+            rndr::id(),
+            test_mint.pubkey,
+            authority.pubkey(),
+            test_job_tree.merkle_tree,
+            job,
+            Pubkey::default(),
+            root,
+            proof,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+#[tokio::test]
+async fn test_invalid_merkle_tree() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+    add_account_compression(&mut test);
+
+    let authority = Keypair::new();
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_job_tree = TestJobTree::add(
+        &mut test,
+        test_escrow.pubkey,
+        authority.pubkey(),
+        MAX_DEPTH,
+        &[open_job()],
+    );
+    let other_merkle_tree =
+        add_merkle_tree(&mut test, MAX_DEPTH as u32, TestJobTree::MAX_BUFFER_SIZE);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // Only the merkle tree of the job tree holds its jobs
+    let job = test_job_tree.jobs[0];
+    let (root, proof) = test_job_tree.proof(job.id);
+    let mut transaction = Transaction::new_with_payer(
+        &[replace_compressed_job(
+            rndr::id(),
+            test_mint.pubkey,
+            authority.pubkey(),
+            other_merkle_tree,
+            job,
+            Pubkey::new_unique(),
+            root,
+            proof,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
@@ -0,0 +1,105 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{
+        instruction::resolve_dispute, processor::process_instruction, state::JobStatus,
+        test_fixtures::*,
+    },
+    solana_program_test::*,
+    solana_sdk::{
+        signature::{Keypair, Signer},
+        transaction::Transaction,
+    },
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const AMOUNT: u64 = 1_000_000_000;
+    const NODE_AMOUNT: u64 = 750_000_000;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * AMOUNT);
+    let arbitrator = Keypair::new();
+    let test_escrow =
+        TestEscrow::add_with_arbitrator(&mut test, test_mint.pubkey, AMOUNT, arbitrator.pubkey());
+    let test_node_token =
+        TestToken::add_associated(&mut test, test_mint.pubkey, Keypair::new(), ZERO);
+    let test_authority_token =
+        TestToken::add_associated(&mut test, test_mint.pubkey, Keypair::new(), ZERO);
+    let node = test_node_token.owner.pubkey();
+    let authority = test_authority_token.owner.pubkey();
+    let test_job = TestJob::add_disputed(&mut test, test_escrow.pubkey, authority, node, AMOUNT);
+    let test_dispute = TestDispute::add(&mut test, test_job.pubkey, authority);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // The node can't be awarded more than the job's amount
+    let mut transaction = Transaction::new_with_payer(
+        &[resolve_dispute(
+            rndr::id(),
+            AMOUNT + 1,
+            test_mint.pubkey,
+            arbitrator.pubkey(),
+            test_job.pubkey,
+            node,
+            authority,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &arbitrator], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    // Only the escrow's arbitrator can resolve the dispute
+    let mut transaction = Transaction::new_with_payer(
+        &[resolve_dispute(
+            rndr::id(),
+            NODE_AMOUNT,
+            test_mint.pubkey,
+            payer.pubkey(),
+            test_job.pubkey,
+            node,
+            authority,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let mut transaction = Transaction::new_with_payer(
+        &[resolve_dispute(
+            rndr::id(),
+            NODE_AMOUNT,
+            test_mint.pubkey,
+            arbitrator.pubkey(),
+            test_job.pubkey,
+            node,
+            authority,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &arbitrator], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let node_balance = get_token_balance(&mut banks_client, test_node_token.pubkey).await;
+    assert_eq!(node_balance, NODE_AMOUNT);
+
+    let authority_balance = get_token_balance(&mut banks_client, test_authority_token.pubkey).await;
+    assert_eq!(authority_balance, AMOUNT - NODE_AMOUNT);
+
+    let job = test_job.get(&mut banks_client).await;
+    assert_eq!(job.status, JobStatus::Resolved);
+    assert_eq!(job.amount, ZERO);
+
+    let escrow = test_escrow.get(&mut banks_client).await;
+    assert_eq!(escrow.amount, ZERO);
+
+    let dispute = test_dispute.get(&mut banks_client).await;
+    assert!(dispute.is_resolved());
+    assert_eq!(dispute.node_amount, NODE_AMOUNT);
+    assert_eq!(dispute.authority_amount, AMOUNT - NODE_AMOUNT);
+}
@@ -0,0 +1,184 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{
+        instruction::reveal_result,
+        processor::process_instruction,
+        state::{Job, JobStatus},
+        test_fixtures::*,
+    },
+    solana_program::{
+        account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    },
+    solana_program_test::*,
+    solana_sdk::{
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        transaction::Transaction,
+    },
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const AMOUNT: u64 = 1_000_000_000;
+    const RESULT_HASH: [u8; 32] = [2; 32];
+    const SALT: [u8; 32] = [3; 32];
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let authority = Pubkey::new_unique();
+    let node = Keypair::new();
+    let (job_pubkey, _bump_seed) = find_job_address(test_escrow.pubkey, authority, 0);
+    let commitment = Job::result_commitment(&job_pubkey, &node.pubkey(), &RESULT_HASH, &SALT);
+    let test_job = TestJob::add_committed(
+        &mut test,
+        test_escrow.pubkey,
+        authority,
+        node.pubkey(),
+        commitment,
+        AMOUNT,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // A result that doesn't match the commitment is rejected
+    let mut transaction = Transaction::new_with_payer(
+        &[reveal_result(
+            rndr::id(),
+            RESULT_HASH,
+            [4; 32],
+            test_mint.pubkey,
+            node.pubkey(),
+            test_job.pubkey,
+            None,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &node], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let mut transaction = Transaction::new_with_payer(
+        &[reveal_result(
+            rndr::id(),
+            RESULT_HASH,
+            SALT,
+            test_mint.pubkey,
+            node.pubkey(),
+            test_job.pubkey,
+            None,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &node], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let job = get_job(&mut banks_client, test_job.pubkey).await;
+    assert_eq!(job.status, JobStatus::Revealed);
+    assert_eq!(job.result_hash, RESULT_HASH);
+    assert!(job.revealed_at > 0);
+}
+
+const VALID_PROOF: &[u8] = b"valid";
+
+/// Proof verifier that accepts the result hash `[2; 32]` with the proof `VALID_PROOF`
+fn process_verify_proof(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    input: &[u8],
+) -> ProgramResult {
+    if accounts.is_empty() || input.get(..32) != Some(&[2; 32]) || &input[32..] != VALID_PROOF {
+        return Err(ProgramError::InvalidArgument);
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_success_with_proof_verifier() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+    let proof_verifier = Pubkey::new_unique();
+    test.add_program(
+        "proof_verifier",
+        proof_verifier,
+        processor!(process_verify_proof),
+    );
+
+    const AMOUNT: u64 = 1_000_000_000;
+    const RESULT_HASH: [u8; 32] = [2; 32];
+    const SALT: [u8; 32] = [3; 32];
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * AMOUNT);
+    let test_escrow =
+        TestEscrow::add_with_proof_verifier(&mut test, test_mint.pubkey, AMOUNT, proof_verifier);
+    let authority = Pubkey::new_unique();
+    let node = Keypair::new();
+    let (job_pubkey, _bump_seed) = find_job_address(test_escrow.pubkey, authority, 0);
+    let commitment = Job::result_commitment(&job_pubkey, &node.pubkey(), &RESULT_HASH, &SALT);
+    let test_job = TestJob::add_committed(
+        &mut test,
+        test_escrow.pubkey,
+        authority,
+        node.pubkey(),
+        commitment,
+        AMOUNT,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // A result can't be revealed without the escrow's proof verifier
+    let mut transaction = Transaction::new_with_payer(
+        &[reveal_result(
+            rndr::id(),
+            RESULT_HASH,
+            SALT,
+            test_mint.pubkey,
+            node.pubkey(),
+            test_job.pubkey,
+            None,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &node], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    // A result is rejected if the proof verifier rejects its proof
+    let mut transaction = Transaction::new_with_payer(
+        &[reveal_result(
+            rndr::id(),
+            RESULT_HASH,
+            SALT,
+            test_mint.pubkey,
+            node.pubkey(),
+            test_job.pubkey,
+            Some((proof_verifier, b"invalid".to_vec())),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &node], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let mut transaction = Transaction::new_with_payer(
+        &[reveal_result(
+            rndr::id(),
+            RESULT_HASH,
+            SALT,
+            test_mint.pubkey,
+            node.pubkey(),
+            test_job.pubkey,
+            Some((proof_verifier, VALID_PROOF.to_vec())),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &node], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let job = get_job(&mut banks_client, test_job.pubkey).await;
+    assert_eq!(job.status, JobStatus::Revealed);
+    assert_eq!(job.result_hash, RESULT_HASH);
+}
@@ -0,0 +1,109 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{
+        instruction::{disburse_funds_as_delegate, revoke_delegate},
+        processor::process_instruction,
+        test_fixtures::*,
+    },
+    solana_program_test::*,
+    solana_sdk::{signature::Signer, system_instruction, transaction::Transaction},
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const AMOUNT: u64 = 1_000_000_000;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let test_job = TestJob::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_destination_token.owner.pubkey(),
+        AMOUNT,
+    );
+    let test_delegate_allowance =
+        TestDelegateAllowance::add(&mut test, test_escrow.pubkey, AMOUNT, u64::MAX);
+    let delegate = &test_delegate_allowance.delegate;
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let owner = &test_escrow.owner;
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            system_instruction::transfer(&payer.pubkey(), &owner.pubkey(), 1_000_000_000),
+            revoke_delegate(
+                rndr::id(),
+                test_mint.pubkey,
+                owner.pubkey(),
+                delegate.pubkey(),
+            ),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    assert!(banks_client
+        .get_account(test_delegate_allowance.pubkey)
+        .await
+        .unwrap()
+        .is_none());
+
+    // The delegate can no longer disburse once revoked
+    let mut transaction = Transaction::new_with_payer(
+        &[disburse_funds_as_delegate(
+            rndr::id(),
+            AMOUNT,
+            test_mint.pubkey,
+            test_destination_token.pubkey,
+            test_job.pubkey,
+            delegate.pubkey(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, delegate], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let destination_token_balance_after =
+        get_token_balance(&mut banks_client, test_destination_token.pubkey).await;
+    assert_eq!(destination_token_balance_after, ZERO);
+}
+
+#[tokio::test]
+async fn test_not_owner() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const AMOUNT: u64 = 1_000_000_000;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_delegate_allowance =
+        TestDelegateAllowance::add(&mut test, test_escrow.pubkey, AMOUNT, u64::MAX);
+    let delegate = &test_delegate_allowance.delegate;
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // Delegates cannot revoke themselves, only the escrow owner can
+    let mut transaction = Transaction::new_with_payer(
+        &[revoke_delegate(
+            rndr::id(),
+            test_mint.pubkey,
+            delegate.pubkey(),
+            delegate.pubkey(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, delegate], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let delegate_allowance = test_delegate_allowance.get(&mut banks_client).await;
+    assert_eq!(delegate_allowance.remaining, AMOUNT);
+}
@@ -0,0 +1,78 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{
+        instruction::schedule_owner_rotation,
+        processor::process_instruction,
+        state::{AdminAction, DEFAULT_TIMELOCK_DURATION},
+        test_fixtures::*,
+    },
+    solana_program_test::*,
+    solana_sdk::{pubkey::Pubkey, signature::Signer, system_instruction, transaction::Transaction},
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const AMOUNT: u64 = 1_000_000_000;
+    const ROTATION_ID: u64 = 1;
+
+    TestConfig::add(&mut test);
+    let test_mint = TestMint::add(&mut test, 9, AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let new_owner = Pubkey::new_unique();
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // The owner can't be rotated to the default pubkey
+    let owner = &test_escrow.owner;
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            system_instruction::transfer(&payer.pubkey(), &owner.pubkey(), AMOUNT),
+            schedule_owner_rotation(
+                rndr::id(),
+                test_escrow.pubkey,
+                owner.pubkey(),
+                ROTATION_ID,
+                Pubkey::default(),
+            ),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            system_instruction::transfer(&payer.pubkey(), &owner.pubkey(), AMOUNT),
+            schedule_owner_rotation(
+                rndr::id(),
+                test_escrow.pubkey,
+                owner.pubkey(),
+                ROTATION_ID,
+                new_owner,
+            ),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let (queued_action_pubkey, _bump_seed) =
+        find_queued_action_address(test_escrow.pubkey, ROTATION_ID);
+    let queued_action = get_queued_action(&mut banks_client, queued_action_pubkey).await;
+    assert_eq!(queued_action.target, test_escrow.pubkey);
+    assert_eq!(queued_action.authority, owner.pubkey());
+    assert_eq!(queued_action.action, AdminAction::SetEscrowOwner(new_owner));
+    assert_eq!(
+        queued_action.executable_at,
+        queued_action.queued_at + DEFAULT_TIMELOCK_DURATION as i64
+    );
+
+    // The owner isn't rotated until the delay has passed and the rotation is executed
+    let escrow = test_escrow.get(&mut banks_client).await;
+    assert_eq!(escrow.owner, owner.pubkey());
+}
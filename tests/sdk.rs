@@ -0,0 +1,94 @@
+#![cfg(feature = "client")]
+
+use {
+    rndr::{
+        client::RndrClient,
+        pda::find_job_address,
+        state::{InitJobParams, Job, JobStatus},
+    },
+    serde_json::json,
+    solana_account_decoder::{UiAccount, UiAccountEncoding},
+    solana_client::{
+        nonblocking::rpc_client::RpcClient,
+        rpc_client::Mocks,
+        rpc_request::RpcRequest,
+        rpc_response::{Response, RpcResponseContext},
+    },
+    solana_program_test::tokio,
+    solana_sdk::{account::Account, program_pack::Pack, pubkey::Pubkey},
+};
+
+fn job_mocks(job_address: &Pubkey, job: Job) -> Mocks {
+    let mut data = vec![0; Job::LEN];
+    Job::pack_account(job, &mut data).unwrap();
+    let account = Account {
+        lamports: 1,
+        data,
+        owner: rndr::id(),
+        executable: false,
+        rent_epoch: 0,
+    };
+
+    let mut mocks = Mocks::default();
+    mocks.insert(
+        RpcRequest::GetAccountInfo,
+        json!(Response {
+            context: RpcResponseContext {
+                slot: 1,
+                api_version: None,
+            },
+            value: Some(UiAccount::encode(
+                job_address,
+                &account,
+                UiAccountEncoding::Base64,
+                None,
+                None,
+            )),
+        }),
+    );
+    mocks
+}
+
+#[test]
+fn test_job_address() {
+    let rpc_client = RpcClient::new_mock("succeeds".to_string());
+    let client = RndrClient::new(rpc_client, Pubkey::new_unique()).with_escrow_id(7);
+    let authority = Pubkey::new_unique();
+
+    let job = client.job(authority, 3);
+
+    let (job_address, _bump_seed) =
+        find_job_address(&rndr::id(), &client.escrow_address(), &authority, 3);
+    assert_eq!(job.address(), job_address);
+    assert_eq!(job.authority(), authority);
+    assert_eq!(job.job_id(), 3);
+}
+
+#[tokio::test]
+async fn test_job_status() {
+    let token_mint = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let address = RndrClient::new(RpcClient::new_mock("succeeds".to_string()), token_mint)
+        .job_address(&authority, 0);
+
+    let mut job = Job::new(InitJobParams {
+        authority,
+        id: 0,
+        isolated: false,
+    });
+    job.amount = 100;
+    job.status = JobStatus::Paid;
+
+    let rpc_client =
+        RpcClient::new_mock_with_mocks("succeeds".to_string(), job_mocks(&address, job.clone()));
+    let client = RndrClient::new(rpc_client, token_mint);
+    assert_eq!(
+        client.job(authority, 0).status().await.unwrap(),
+        JobStatus::Paid
+    );
+
+    let rpc_client =
+        RpcClient::new_mock_with_mocks("succeeds".to_string(), job_mocks(&address, job.clone()));
+    let client = RndrClient::new(rpc_client, token_mint);
+    assert_eq!(Job::fetch(&client, &authority, 0).await.unwrap(), job);
+}
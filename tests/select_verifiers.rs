@@ -0,0 +1,77 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{instruction::select_verifiers, processor::process_instruction, test_fixtures::*},
+    solana_program_test::*,
+    solana_sdk::{pubkey::Pubkey, signature::Signer, transaction::Transaction},
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const AMOUNT: u64 = 1_000_000_000;
+    const COUNT: u8 = 3;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let verifiers: Vec<Pubkey> = (0..5).map(|_| Pubkey::new_unique()).collect();
+    TestVerifierRegistry::add(&mut test, test_escrow.pubkey, verifiers.clone());
+    let test_job = TestJob::add_revealed(
+        &mut test,
+        test_escrow.pubkey,
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        AMOUNT,
+    );
+
+    let mut context = test.start_with_context().await;
+    context.warp_to_slot(4).unwrap();
+
+    let mut transaction = Transaction::new_with_payer(
+        &[select_verifiers(
+            rndr::id(),
+            COUNT,
+            test_mint.pubkey,
+            context.payer.pubkey(),
+            test_job.pubkey,
+        )],
+        Some(&context.payer.pubkey()),
+    );
+    transaction.sign(&[&context.payer], context.last_blockhash);
+
+    assert!(context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .is_ok());
+
+    let (verifier_selection_pubkey, _bump_seed) = find_verifier_selection_address(test_job.pubkey);
+    let verifier_selection =
+        get_verifier_selection(&mut context.banks_client, verifier_selection_pubkey).await;
+    assert_eq!(verifier_selection.job, test_job.pubkey);
+    assert_eq!(verifier_selection.verifiers.len(), COUNT as usize);
+    for (i, verifier) in verifier_selection.verifiers.iter().enumerate() {
+        assert!(verifiers.contains(verifier));
+        assert!(!verifier_selection.verifiers[..i].contains(verifier));
+    }
+
+    // Verifiers can only be selected once, so the selection can't be rerolled
+    let mut transaction = Transaction::new_with_payer(
+        &[select_verifiers(
+            rndr::id(),
+            COUNT - 1,
+            test_mint.pubkey,
+            context.payer.pubkey(),
+            test_job.pubkey,
+        )],
+        Some(&context.payer.pubkey()),
+    );
+    transaction.sign(&[&context.payer], context.last_blockhash);
+
+    assert!(context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .is_err());
+}
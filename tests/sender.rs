@@ -0,0 +1,131 @@
+#![cfg(feature = "client")]
+
+use {
+    rndr::{
+        client::{
+            sender::{RetryPolicy, SendOutcome},
+            transaction::ComputeUnitLimit,
+            RndrClient,
+        },
+        error::RNDRError,
+        instruction::aggregate_escrow_shard,
+    },
+    serde_json::json,
+    solana_client::{
+        nonblocking::rpc_client::RpcClient,
+        rpc_client::Mocks,
+        rpc_request::RpcRequest,
+        rpc_response::{Response, RpcResponseContext},
+    },
+    solana_program_test::tokio,
+    solana_sdk::{
+        instruction::InstructionError, pubkey::Pubkey, signature::Keypair,
+        transaction::TransactionError,
+    },
+    std::time::Duration,
+};
+
+fn policy(max_attempts: u32) -> RetryPolicy {
+    RetryPolicy {
+        max_attempts,
+        initial_backoff: Duration::ZERO,
+        max_backoff: Duration::ZERO,
+        poll_interval: Duration::ZERO,
+        nonce_fallback: None,
+    }
+}
+
+async fn send(url: &str, mocks: Mocks, max_attempts: u32) -> SendOutcome {
+    let rpc_client = RpcClient::new_mock_with_mocks(url.to_string(), mocks);
+    let client = RndrClient::new(rpc_client, Pubkey::new_unique());
+    let payer = Keypair::new();
+
+    let instruction = aggregate_escrow_shard(rndr::id(), client.escrow_address(), 0);
+    client
+        .transaction()
+        .instruction(instruction)
+        .compute_unit_limit(ComputeUnitLimit::Default)
+        .send_with_retry(&payer, &[], &policy(max_attempts))
+        .await
+        .unwrap()
+}
+
+fn status_mocks(err: TransactionError) -> Mocks {
+    let mut mocks = Mocks::default();
+    mocks.insert(
+        RpcRequest::GetSignatureStatuses,
+        json!(Response {
+            context: RpcResponseContext {
+                slot: 1,
+                api_version: None,
+            },
+            value: vec![json!({
+                "slot": 1,
+                "confirmations": null,
+                "status": {"Err": err},
+                "err": err,
+                "confirmationStatus": "finalized",
+            })],
+        }),
+    );
+    mocks
+}
+
+#[tokio::test]
+async fn test_confirmed() {
+    let outcome = send("succeeds", Mocks::default(), 1).await;
+    assert!(matches!(outcome, SendOutcome::Confirmed(_)));
+    assert!(outcome.signature().is_some());
+}
+
+#[tokio::test]
+async fn test_program_error() {
+    let mocks = status_mocks(TransactionError::InstructionError(
+        0,
+        InstructionError::Custom(RNDRError::DuplicateAccount as u32),
+    ));
+    let outcome = send("succeeds", mocks, 1).await;
+    assert!(matches!(
+        outcome,
+        SendOutcome::ProgramError {
+            instruction_index: 0,
+            error: RNDRError::DuplicateAccount,
+            ..
+        }
+    ));
+}
+
+#[tokio::test]
+async fn test_failed() {
+    // Custom errors of instructions of other programs aren't decoded as RNDR errors
+    let error = TransactionError::InstructionError(1, InstructionError::Custom(0));
+    let outcome = send("succeeds", status_mocks(error.clone()), 1).await;
+    assert!(matches!(outcome, SendOutcome::Failed { error: e, .. } if e == error));
+
+    let outcome = send("instruction_error", Mocks::default(), 1).await;
+    assert!(matches!(
+        outcome,
+        SendOutcome::Failed {
+            error: TransactionError::InstructionError(0, InstructionError::UninitializedAccount),
+            ..
+        }
+    ));
+}
+
+#[tokio::test]
+async fn test_dropped() {
+    let mut mocks = Mocks::default();
+    mocks.insert(
+        RpcRequest::IsBlockhashValid,
+        json!(Response {
+            context: RpcResponseContext {
+                slot: 1,
+                api_version: None,
+            },
+            value: false,
+        }),
+    );
+    let outcome = send("sig_not_found", mocks, 1).await;
+    assert!(matches!(&outcome, SendOutcome::Dropped { signatures } if signatures.len() == 1));
+    assert_eq!(outcome.signature(), None);
+}
@@ -0,0 +1,66 @@
+#![cfg(feature = "serde")]
+
+use {
+    rndr::{
+        instruction::RNDRInstruction,
+        state::{AccountType, Job},
+    },
+    serde_json::json,
+    solana_program::pubkey::Pubkey,
+};
+
+#[test]
+fn test_success() {
+    let authority = Pubkey::new_unique();
+    let job = Job {
+        account_type: AccountType::JobV1,
+        amount: 100,
+        authority,
+        isolated: false,
+        id: 1,
+        ..Job::default()
+    };
+
+    let value = serde_json::to_value(&job).unwrap();
+    assert_eq!(
+        value,
+        json!({
+            "account_type": "JobV1",
+            "amount": 100,
+            "authority": authority.to_string(),
+            "isolated": false,
+            "id": 1,
+            "status": "Open",
+            "node": Pubkey::default().to_string(),
+            "result_commitment": job.result_commitment,
+            "result_hash": job.result_hash,
+            "revealed_at": 0,
+            "verifier": Pubkey::default().to_string(),
+            "evidence_hash": job.evidence_hash,
+            "funded_at": 0,
+            "pending_authority": Pubkey::default().to_string(),
+            "original_authority": Pubkey::default().to_string(),
+            "metadata_uri": "",
+            "metadata_hash": job.metadata_hash,
+            "tags": 0,
+            "mint_migrations": 0,
+            "priority": 0,
+            "exchange_rate_numerator": 0,
+            "exchange_rate_denominator": 0,
+            "exchange_rate_slot": 0,
+            "frozen": false,
+        })
+    );
+    assert_eq!(serde_json::from_value::<Job>(value).unwrap(), job);
+
+    let instruction = RNDRInstruction::InitEscrow { owner: authority };
+    let value = serde_json::to_value(&instruction).unwrap();
+    assert_eq!(
+        value,
+        json!({ "InitEscrow": { "owner": authority.to_string() } })
+    );
+    assert_eq!(
+        serde_json::from_value::<RNDRInstruction>(value).unwrap(),
+        instruction
+    );
+}
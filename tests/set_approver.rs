@@ -0,0 +1,157 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{
+        error::RNDRError,
+        instruction::{set_approver, set_approver_with_current_approver},
+        processor::process_instruction,
+        state::Escrow,
+        test_fixtures::*,
+    },
+    solana_program::{program_pack::Pack, pubkey::Pubkey},
+    solana_program_test::*,
+    solana_sdk::{
+        signature::{Keypair, Signer},
+        system_instruction,
+        transaction::Transaction,
+    },
+};
+
+#[tokio::test]
+async fn test_success_with_legacy_escrow() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const AMOUNT: u64 = 1_000_000_000;
+    const APPROVER: Pubkey = Pubkey::new_from_array([7; 32]);
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * AMOUNT);
+    let test_escrow = TestEscrow::add_legacy(&mut test, test_mint.pubkey, AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let account = get_account(&mut banks_client, test_escrow.pubkey).await;
+    assert_eq!(account.data.len(), Escrow::LEGACY_LEN);
+
+    let owner = &test_escrow.owner;
+    let mut transaction = Transaction::new_with_payer(
+        &[system_instruction::transfer(
+            &payer.pubkey(),
+            &owner.pubkey(),
+            1_000_000_000,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    // The owner can't approve their own disbursements
+    let mut transaction = Transaction::new_with_payer(
+        &[set_approver(
+            rndr::id(),
+            test_escrow.pubkey,
+            owner.pubkey(),
+            owner.pubkey(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, owner], recent_blockhash);
+
+    assert_eq!(
+        program_error(banks_client.process_transaction(transaction).await),
+        Some(RNDRError::InvalidApprover)
+    );
+
+    let mut transaction = Transaction::new_with_payer(
+        &[set_approver(
+            rndr::id(),
+            test_escrow.pubkey,
+            owner.pubkey(),
+            APPROVER,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let account = get_account(&mut banks_client, test_escrow.pubkey).await;
+    assert_eq!(account.data.len(), Escrow::LEN);
+
+    let escrow = test_escrow.get(&mut banks_client).await;
+    assert_eq!(escrow.approver, APPROVER);
+    assert_eq!(escrow.amount, AMOUNT);
+}
+
+#[tokio::test]
+async fn test_change_requires_current_approver() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const AMOUNT: u64 = 1_000_000_000;
+
+    let approver = Keypair::new();
+    let test_mint = TestMint::add(&mut test, 9, 100 * AMOUNT);
+    let test_escrow =
+        TestEscrow::add_with_approver(&mut test, test_mint.pubkey, AMOUNT, approver.pubkey());
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // The owner can't replace or clear the approver alone
+    let owner = &test_escrow.owner;
+    for new_approver in [Pubkey::new_unique(), Pubkey::default()] {
+        let mut transaction = Transaction::new_with_payer(
+            &[set_approver(
+                rndr::id(),
+                test_escrow.pubkey,
+                owner.pubkey(),
+                new_approver,
+            )],
+            Some(&payer.pubkey()),
+        );
+        transaction.sign(&[&payer, owner], recent_blockhash);
+
+        assert_eq!(
+            program_error(banks_client.process_transaction(transaction).await),
+            Some(RNDRError::InvalidApprover)
+        );
+    }
+
+    // Nor with a signer that isn't the current approver
+    let other = Keypair::new();
+    let mut transaction = Transaction::new_with_payer(
+        &[set_approver_with_current_approver(
+            rndr::id(),
+            test_escrow.pubkey,
+            owner.pubkey(),
+            other.pubkey(),
+            Pubkey::default(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, owner, &other], recent_blockhash);
+
+    assert_eq!(
+        program_error(banks_client.process_transaction(transaction).await),
+        Some(RNDRError::InvalidApprover)
+    );
+
+    let escrow = test_escrow.get(&mut banks_client).await;
+    assert_eq!(escrow.approver, approver.pubkey());
+
+    let mut transaction = Transaction::new_with_payer(
+        &[set_approver_with_current_approver(
+            rndr::id(),
+            test_escrow.pubkey,
+            owner.pubkey(),
+            approver.pubkey(),
+            Pubkey::default(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, owner, &approver], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let escrow = test_escrow.get(&mut banks_client).await;
+    assert_eq!(escrow.approver, Pubkey::default());
+}
@@ -0,0 +1,48 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{instruction::set_auto_compound, processor::process_instruction, test_fixtures::*},
+    solana_program_test::*,
+    solana_sdk::{
+        signature::{Keypair, Signer},
+        transaction::Transaction,
+    },
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let node_authority = Keypair::new();
+    let test_node = TestNode::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_mint.pubkey,
+        node_authority.pubkey(),
+        ZERO,
+        false,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[set_auto_compound(
+            rndr::id(),
+            test_escrow.pubkey,
+            node_authority.pubkey(),
+            true,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &node_authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let node = test_node.get(&mut banks_client).await;
+    assert!(node.auto_compound);
+}
@@ -0,0 +1,60 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{
+        instruction::set_challenge_window, processor::process_instruction, state::Escrow,
+        test_fixtures::*,
+    },
+    solana_program::program_pack::Pack,
+    solana_program_test::*,
+    solana_sdk::{signature::Signer, system_instruction, transaction::Transaction},
+};
+
+#[tokio::test]
+async fn test_success_with_legacy_escrow() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const AMOUNT: u64 = 1_000_000_000;
+    const CHALLENGE_WINDOW: u64 = 24 * 60 * 60;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * AMOUNT);
+    let test_escrow = TestEscrow::add_legacy(&mut test, test_mint.pubkey, AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let account = get_account(&mut banks_client, test_escrow.pubkey).await;
+    assert_eq!(account.data.len(), Escrow::LEGACY_LEN);
+
+    let owner = &test_escrow.owner;
+    let mut transaction = Transaction::new_with_payer(
+        &[system_instruction::transfer(
+            &payer.pubkey(),
+            &owner.pubkey(),
+            1_000_000_000,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let mut transaction = Transaction::new_with_payer(
+        &[set_challenge_window(
+            rndr::id(),
+            test_escrow.pubkey,
+            owner.pubkey(),
+            CHALLENGE_WINDOW,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let account = get_account(&mut banks_client, test_escrow.pubkey).await;
+    assert_eq!(account.data.len(), Escrow::LEN);
+
+    let escrow = test_escrow.get(&mut banks_client).await;
+    assert_eq!(escrow.challenge_window, CHALLENGE_WINDOW);
+    assert_eq!(escrow.amount, AMOUNT);
+}
@@ -0,0 +1,367 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{
+        instruction::{set_config, set_config_with_payer},
+        math::{Rate, RoundingPolicy},
+        processor::process_instruction,
+        state::{
+            Config, ConfigChange, DEFAULT_FEE_BPS, DEFAULT_SWEEP_DELAY, MAX_DISPUTE_WINDOW,
+            MAX_FEE_BPS, MAX_MAX_PRICE_AGE, MAX_MAX_PRICE_CONFIDENCE_BPS, MAX_PRICE_TIERS,
+            MAX_SWEEP_DELAY, MIN_SWEEP_DELAY,
+        },
+        test_fixtures::*,
+    },
+    solana_program::program_pack::Pack,
+    solana_program_test::*,
+    solana_sdk::{
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        transaction::Transaction,
+    },
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let test_config = TestConfig::add(&mut test);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // Only the governance authority can change the config
+    let impostor = Keypair::new();
+    let mut transaction = Transaction::new_with_payer(
+        &[set_config(
+            rndr::id(),
+            impostor.pubkey(),
+            ConfigChange::DisputeWindow(MAX_DISPUTE_WINDOW),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &impostor], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    // Dispute windows above the maximum are rejected
+    let governance = &test_config.governance;
+    let mut transaction = Transaction::new_with_payer(
+        &[set_config(
+            rndr::id(),
+            governance.pubkey(),
+            ConfigChange::DisputeWindow(MAX_DISPUTE_WINDOW + 1),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, governance], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let mut transaction = Transaction::new_with_payer(
+        &[set_config(
+            rndr::id(),
+            governance.pubkey(),
+            ConfigChange::DisputeWindow(MAX_DISPUTE_WINDOW),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, governance], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    // Fees are timelocked, so they can only be changed by queueing the change
+    let mut transaction = Transaction::new_with_payer(
+        &[set_config(
+            rndr::id(),
+            governance.pubkey(),
+            ConfigChange::FeeBps(MAX_FEE_BPS),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, governance], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let config = test_config.get(&mut banks_client).await;
+    assert_eq!(config.dispute_window, MAX_DISPUTE_WINDOW);
+    assert_eq!(config.fee_bps, DEFAULT_FEE_BPS);
+    assert_eq!(config.governance, governance.pubkey());
+}
+
+#[tokio::test]
+async fn test_success_legacy() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let test_config = TestConfig::add_legacy(&mut test);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // Fields of the legacy layout are set without reallocating the config
+    let governance = &test_config.governance;
+    let mut transaction = Transaction::new_with_payer(
+        &[set_config(
+            rndr::id(),
+            governance.pubkey(),
+            ConfigChange::DisputeWindow(MAX_DISPUTE_WINDOW),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, governance], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let account = get_account(&mut banks_client, test_config.pubkey).await;
+    assert_eq!(account.data.len(), Config::LEGACY_LEN);
+
+    // Appended fields can only be set once a payer reallocates the config
+    let mut transaction = Transaction::new_with_payer(
+        &[set_config(
+            rndr::id(),
+            governance.pubkey(),
+            ConfigChange::RoundingPolicy(RoundingPolicy::Burn),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, governance], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let mut transaction = Transaction::new_with_payer(
+        &[set_config_with_payer(
+            rndr::id(),
+            governance.pubkey(),
+            ConfigChange::RoundingPolicy(RoundingPolicy::Burn),
+            payer.pubkey(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, governance], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let account = get_account(&mut banks_client, test_config.pubkey).await;
+    assert_eq!(account.data.len(), Config::LEN);
+
+    let config = test_config.get(&mut banks_client).await;
+    assert_eq!(config.dispute_window, MAX_DISPUTE_WINDOW);
+    assert_eq!(config.rounding_policy, RoundingPolicy::Burn);
+}
+
+#[tokio::test]
+async fn test_obh_price() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let test_config = TestConfig::add_legacy(&mut test);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // Prices can only be set for the tiers of the config
+    let governance = &test_config.governance;
+    let mut transaction = Transaction::new_with_payer(
+        &[set_config_with_payer(
+            rndr::id(),
+            governance.pubkey(),
+            ConfigChange::ObhPrice(MAX_PRICE_TIERS as u8, 1),
+            payer.pubkey(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, governance], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let mut transaction = Transaction::new_with_payer(
+        &[set_config_with_payer(
+            rndr::id(),
+            governance.pubkey(),
+            ConfigChange::ObhPrice(1, 150_000_000),
+            payer.pubkey(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, governance], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    // The config is reallocated to hold the prices
+    let account = get_account(&mut banks_client, test_config.pubkey).await;
+    assert_eq!(account.data.len(), Config::LEN);
+
+    let config = test_config.get(&mut banks_client).await;
+    assert_eq!(config.obh_prices, [0, 150_000_000, 0]);
+    assert_eq!(config.job_cost(1, 2_000), Some(300_000_000));
+}
+
+#[tokio::test]
+async fn test_price_guards() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let test_config = TestConfig::add_legacy(&mut test);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // A legacy config only accepts prices published at the current slot without a confidence
+    let config = test_config.get(&mut banks_client).await;
+    assert_eq!(config.max_price_age, 0);
+    assert_eq!(config.max_price_confidence_bps, Rate::ZERO);
+
+    // The guards can't be loosened beyond their maximums
+    let governance = &test_config.governance;
+    for change in [
+        ConfigChange::MaxPriceAge(MAX_MAX_PRICE_AGE + 1),
+        ConfigChange::MaxPriceConfidenceBps(Rate::new(MAX_MAX_PRICE_CONFIDENCE_BPS.bps() + 1)),
+    ] {
+        let mut transaction = Transaction::new_with_payer(
+            &[set_config_with_payer(
+                rndr::id(),
+                governance.pubkey(),
+                change,
+                payer.pubkey(),
+            )],
+            Some(&payer.pubkey()),
+        );
+        transaction.sign(&[&payer, governance], recent_blockhash);
+
+        assert!(banks_client.process_transaction(transaction).await.is_err());
+    }
+
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            set_config_with_payer(
+                rndr::id(),
+                governance.pubkey(),
+                ConfigChange::MaxPriceAge(MAX_MAX_PRICE_AGE),
+                payer.pubkey(),
+            ),
+            set_config_with_payer(
+                rndr::id(),
+                governance.pubkey(),
+                ConfigChange::MaxPriceConfidenceBps(Rate::new(50)),
+                payer.pubkey(),
+            ),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, governance], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    // The config is reallocated to hold the guards
+    let account = get_account(&mut banks_client, test_config.pubkey).await;
+    assert_eq!(account.data.len(), Config::LEN);
+
+    let config = test_config.get(&mut banks_client).await;
+    assert_eq!(config.max_price_age, MAX_MAX_PRICE_AGE);
+    assert_eq!(config.max_price_confidence_bps, Rate::new(50));
+}
+
+#[tokio::test]
+async fn test_compliance() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let test_config = TestConfig::add_legacy(&mut test);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // A legacy config has no compliance authority
+    let config = test_config.get(&mut banks_client).await;
+    assert_eq!(config.compliance(), None);
+
+    let governance = &test_config.governance;
+    let compliance = test_config.compliance.pubkey();
+    let mut transaction = Transaction::new_with_payer(
+        &[set_config_with_payer(
+            rndr::id(),
+            governance.pubkey(),
+            ConfigChange::Compliance(compliance),
+            payer.pubkey(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, governance], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    // The config is reallocated to hold the compliance authority
+    let account = get_account(&mut banks_client, test_config.pubkey).await;
+    assert_eq!(account.data.len(), Config::LEN);
+
+    let config = test_config.get(&mut banks_client).await;
+    assert_eq!(config.compliance(), Some(&compliance));
+
+    // Setting the default pubkey removes the compliance authority
+    let recent_blockhash = banks_client
+        .get_new_latest_blockhash(&recent_blockhash)
+        .await
+        .unwrap();
+    let mut transaction = Transaction::new_with_payer(
+        &[set_config_with_payer(
+            rndr::id(),
+            governance.pubkey(),
+            ConfigChange::Compliance(Pubkey::default()),
+            payer.pubkey(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, governance], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let config = test_config.get(&mut banks_client).await;
+    assert_eq!(config.compliance(), None);
+}
+
+#[tokio::test]
+async fn test_sweep_delay() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let test_config = TestConfig::add_legacy(&mut test);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // A legacy config has no sweep delay, so sweeps wait for the default one
+    let config = test_config.get(&mut banks_client).await;
+    assert_eq!(config.sweep_delay, 0);
+    assert_eq!(
+        config.sweep_executable_at(0),
+        Some(DEFAULT_SWEEP_DELAY as i64)
+    );
+
+    // The sweep delay can't be set outside its bounds
+    let governance = &test_config.governance;
+    for sweep_delay in [0, MIN_SWEEP_DELAY - 1, MAX_SWEEP_DELAY + 1] {
+        let mut transaction = Transaction::new_with_payer(
+            &[set_config_with_payer(
+                rndr::id(),
+                governance.pubkey(),
+                ConfigChange::SweepDelay(sweep_delay),
+                payer.pubkey(),
+            )],
+            Some(&payer.pubkey()),
+        );
+        transaction.sign(&[&payer, governance], recent_blockhash);
+
+        assert!(banks_client.process_transaction(transaction).await.is_err());
+    }
+
+    let mut transaction = Transaction::new_with_payer(
+        &[set_config_with_payer(
+            rndr::id(),
+            governance.pubkey(),
+            ConfigChange::SweepDelay(MIN_SWEEP_DELAY),
+            payer.pubkey(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, governance], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    // The config is reallocated to hold the sweep delay
+    let account = get_account(&mut banks_client, test_config.pubkey).await;
+    assert_eq!(account.data.len(), Config::LEN);
+
+    let config = test_config.get(&mut banks_client).await;
+    assert_eq!(config.sweep_delay, MIN_SWEEP_DELAY);
+    assert_eq!(config.sweep_executable_at(0), Some(MIN_SWEEP_DELAY as i64));
+}
@@ -0,0 +1,107 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{
+        instruction::set_crank_bounty, processor::process_instruction, state::AccountType,
+        test_fixtures::*,
+    },
+    solana_program_test::*,
+    solana_sdk::{
+        native_token::LAMPORTS_PER_SOL,
+        signature::{Keypair, Signer},
+        system_instruction,
+        transaction::Transaction,
+    },
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const BOUNTY: u64 = LAMPORTS_PER_SOL / 100;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let owner = &test_escrow.owner;
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            system_instruction::transfer(&payer.pubkey(), &owner.pubkey(), LAMPORTS_PER_SOL),
+            set_crank_bounty(rndr::id(), test_escrow.pubkey, owner.pubkey(), BOUNTY),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let (crank_vault_pubkey, _bump_seed) = find_crank_vault_address(test_escrow.pubkey);
+    let crank_vault = get_crank_vault(&mut banks_client, crank_vault_pubkey).await;
+    assert_eq!(crank_vault.account_type, AccountType::CrankVaultV1);
+    assert_eq!(crank_vault.escrow, test_escrow.pubkey);
+    assert_eq!(crank_vault.bounty, BOUNTY);
+    assert_eq!(crank_vault.paid, ZERO);
+}
+
+#[tokio::test]
+async fn test_success_update() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const BOUNTY: u64 = LAMPORTS_PER_SOL / 100;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let owner = &test_escrow.owner;
+    let test_crank_vault =
+        TestCrankVault::add(&mut test, test_escrow.pubkey, BOUNTY, LAMPORTS_PER_SOL);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[set_crank_bounty(
+            rndr::id(),
+            test_escrow.pubkey,
+            owner.pubkey(),
+            2 * BOUNTY,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let crank_vault = test_crank_vault.get(&mut banks_client).await;
+    assert_eq!(crank_vault.bounty, 2 * BOUNTY);
+}
+
+#[tokio::test]
+async fn test_invalid_owner() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const BOUNTY: u64 = LAMPORTS_PER_SOL / 100;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let impostor = Keypair::new();
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            system_instruction::transfer(&payer.pubkey(), &impostor.pubkey(), LAMPORTS_PER_SOL),
+            set_crank_bounty(rndr::id(), test_escrow.pubkey, impostor.pubkey(), BOUNTY),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &impostor], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
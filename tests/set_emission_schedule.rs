@@ -0,0 +1,100 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{
+        instruction::set_emission_schedule, math::Rate, processor::process_instruction,
+        state::AccountType, test_fixtures::*,
+    },
+    solana_program_test::*,
+    solana_sdk::{
+        native_token::LAMPORTS_PER_SOL, signature::Signer, system_instruction,
+        transaction::Transaction,
+    },
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+    const START_EPOCH: u64 = 2;
+    const DECAY_BPS: Rate = Rate::new(100);
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let owner = &test_escrow.owner;
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            system_instruction::transfer(&payer.pubkey(), &owner.pubkey(), LAMPORTS_PER_SOL),
+            set_emission_schedule(
+                rndr::id(),
+                test_escrow.pubkey,
+                owner.pubkey(),
+                START_EPOCH,
+                AMOUNT,
+                DECAY_BPS,
+            ),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let (emission_schedule_pubkey, _bump_seed) = find_emission_schedule_address(test_escrow.pubkey);
+    let emission_schedule =
+        get_emission_schedule(&mut banks_client, emission_schedule_pubkey).await;
+    assert_eq!(
+        emission_schedule.account_type,
+        AccountType::EmissionScheduleV1
+    );
+    assert_eq!(emission_schedule.escrow, test_escrow.pubkey);
+    assert_eq!(emission_schedule.start_epoch, START_EPOCH);
+    assert_eq!(emission_schedule.initial_amount, AMOUNT);
+    assert_eq!(emission_schedule.decay_bps, DECAY_BPS);
+    assert_eq!(emission_schedule.next_epoch, START_EPOCH);
+    assert_eq!(emission_schedule.emitted, ZERO);
+}
+
+#[tokio::test]
+async fn test_decay_too_high() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let owner = &test_escrow.owner;
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // A decay above 100% can't be constructed, so it's packed into the instruction data directly
+    let mut instruction = set_emission_schedule(
+        rndr::id(),
+        test_escrow.pubkey,
+        owner.pubkey(),
+        ZERO,
+        AMOUNT,
+        Rate::ONE,
+    );
+    let decay_bps = instruction.data.len() - 2;
+    instruction.data[decay_bps..].copy_from_slice(&10_001u16.to_le_bytes());
+
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            system_instruction::transfer(&payer.pubkey(), &owner.pubkey(), LAMPORTS_PER_SOL),
+            instruction,
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
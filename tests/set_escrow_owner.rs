@@ -1,12 +1,13 @@
 #![cfg(feature = "test-bpf")]
 
-mod helpers;
-
 use {
-    helpers::*,
-    rndr::{instruction::set_escrow_owner, processor::process_instruction},
+    rndr::{
+        instruction::set_escrow_owner, processor::process_instruction, state::Escrow,
+        test_fixtures::*,
+    },
+    solana_program::program_pack::Pack,
     solana_program_test::*,
-    solana_sdk::{pubkey::Pubkey, signature::Signer, transaction::Transaction},
+    solana_sdk::{pubkey::Pubkey, signature::Signer, system_instruction, transaction::Transaction},
 };
 
 #[tokio::test]
@@ -16,6 +17,7 @@ async fn test_success() {
     const ZERO: u64 = 0;
     const DECIMALS: u64 = 1_000_000_000;
 
+    TestConfig::add_with_instant_owner_change(&mut test, true);
     let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
     let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
     let new_owner = Pubkey::new_unique();
@@ -40,4 +42,78 @@ async fn test_success() {
 
     let escrow = get_escrow(&mut banks_client, test_escrow.pubkey).await;
     assert_eq!(escrow.owner, new_owner);
+    assert_eq!(escrow.owner_history.len(), 1);
+    assert_eq!(
+        escrow.owner_history[0].previous_owner,
+        test_escrow.owner.pubkey()
+    );
+    assert!(escrow.owner_history[0].slot > 0);
+}
+
+#[tokio::test]
+async fn test_success_with_legacy_escrow() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const AMOUNT: u64 = 1_000_000_000;
+
+    TestConfig::add_with_instant_owner_change(&mut test, true);
+    let test_mint = TestMint::add(&mut test, 9, 100 * AMOUNT);
+    let test_escrow = TestEscrow::add_legacy(&mut test, test_mint.pubkey, AMOUNT);
+    let new_owner = Pubkey::new_unique();
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let account = get_account(&mut banks_client, test_escrow.pubkey).await;
+    assert_eq!(account.data.len(), Escrow::LEGACY_LEN);
+
+    // Legacy escrows are reallocated to record the owner history, paid for by the owner
+    let owner = &test_escrow.owner;
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            system_instruction::transfer(&payer.pubkey(), &owner.pubkey(), AMOUNT),
+            set_escrow_owner(rndr::id(), test_escrow.pubkey, owner.pubkey(), new_owner),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let account = get_account(&mut banks_client, test_escrow.pubkey).await;
+    assert_eq!(account.data.len(), Escrow::LEN);
+
+    let escrow = get_escrow(&mut banks_client, test_escrow.pubkey).await;
+    assert_eq!(escrow.owner, new_owner);
+    assert_eq!(escrow.owner_history[0].previous_owner, owner.pubkey());
+}
+
+#[tokio::test]
+async fn test_instant_owner_change_disabled() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+
+    TestConfig::add(&mut test);
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // Owners must be rotated with a delay unless the config allows instant changes
+    let mut transaction = Transaction::new_with_payer(
+        &[set_escrow_owner(
+            rndr::id(),
+            test_escrow.pubkey,
+            test_escrow.owner.pubkey(),
+            Pubkey::new_unique(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let escrow = get_escrow(&mut banks_client, test_escrow.pubkey).await;
+    assert_eq!(escrow.owner, test_escrow.owner.pubkey());
 }
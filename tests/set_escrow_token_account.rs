@@ -0,0 +1,227 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{
+        instruction::{disburse_funds, set_escrow_token_account, with_escrow_token_account},
+        processor::process_instruction,
+        state::Escrow,
+        test_fixtures::*,
+    },
+    solana_program::{program_pack::Pack, pubkey::Pubkey},
+    solana_program_test::*,
+    solana_sdk::{signature::Signer, system_instruction, transaction::Transaction},
+};
+
+#[tokio::test]
+async fn test_success_with_legacy_escrow() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const AMOUNT: u64 = 1_000_000_000;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * AMOUNT);
+    let test_escrow = TestEscrow::add_legacy(&mut test, test_mint.pubkey, AMOUNT);
+    let token_account = test_escrow.add_token_account(&mut test, test_mint.pubkey, ZERO);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let test_job = TestJob::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_destination_token.owner.pubkey(),
+        AMOUNT,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let owner = &test_escrow.owner;
+    let mut transaction = Transaction::new_with_payer(
+        &[system_instruction::transfer(
+            &payer.pubkey(),
+            &owner.pubkey(),
+            1_000_000_000,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let mut transaction = Transaction::new_with_payer(
+        &[set_escrow_token_account(
+            rndr::id(),
+            test_mint.pubkey,
+            owner.pubkey(),
+            test_escrow.associated_token,
+            token_account,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let account = get_account(&mut banks_client, test_escrow.pubkey).await;
+    assert_eq!(account.data.len(), Escrow::LEN);
+
+    let escrow = test_escrow.get(&mut banks_client).await;
+    assert_eq!(escrow.token_account, token_account);
+    assert_eq!(escrow.token_account(&test_escrow.pubkey), token_account);
+    assert_eq!(escrow.amount, AMOUNT);
+
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_escrow.associated_token).await,
+        ZERO
+    );
+    assert_eq!(
+        get_token_balance(&mut banks_client, token_account).await,
+        AMOUNT
+    );
+
+    // Funds are no longer disbursed from the associated token account
+    let instruction = disburse_funds(
+        rndr::id(),
+        AMOUNT,
+        test_mint.pubkey,
+        test_destination_token.pubkey,
+        test_job.pubkey,
+        owner.pubkey(),
+    );
+    let mut transaction =
+        Transaction::new_with_payer(&[instruction.clone()], Some(&payer.pubkey()));
+    transaction.sign(&[&payer, owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let mut transaction = Transaction::new_with_payer(
+        &[with_escrow_token_account(
+            instruction,
+            test_mint.pubkey,
+            token_account,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    assert_eq!(
+        get_token_balance(&mut banks_client, token_account).await,
+        ZERO
+    );
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_destination_token.pubkey).await,
+        AMOUNT
+    );
+}
+
+#[tokio::test]
+async fn test_success_back_to_associated_token() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const AMOUNT: u64 = 1_000_000_000;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let token_account = test_escrow.add_token_account(&mut test, test_mint.pubkey, 0);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let owner = &test_escrow.owner;
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            set_escrow_token_account(
+                rndr::id(),
+                test_mint.pubkey,
+                owner.pubkey(),
+                test_escrow.associated_token,
+                token_account,
+            ),
+            set_escrow_token_account(
+                rndr::id(),
+                test_mint.pubkey,
+                owner.pubkey(),
+                token_account,
+                test_escrow.associated_token,
+            ),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    // The associated token account is stored as the default pubkey
+    let escrow = test_escrow.get(&mut banks_client).await;
+    assert_eq!(escrow.token_account, Pubkey::default());
+    assert_eq!(
+        escrow.token_account(&test_escrow.pubkey),
+        test_escrow.associated_token
+    );
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_escrow.associated_token).await,
+        AMOUNT
+    );
+}
+
+#[tokio::test]
+async fn test_invalid_token_account() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const AMOUNT: u64 = 1_000_000_000;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let token_account = test_escrow.add_token_account(&mut test, test_mint.pubkey, 0);
+    let other_token = TestToken::add(&mut test, test_mint.pubkey, 0);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let owner = &test_escrow.owner;
+
+    // The new token account must be owned by the escrow
+    let mut transaction = Transaction::new_with_payer(
+        &[set_escrow_token_account(
+            rndr::id(),
+            test_mint.pubkey,
+            owner.pubkey(),
+            test_escrow.associated_token,
+            other_token.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    // The current token account must be the one holding the escrow's tokens
+    let mut transaction = Transaction::new_with_payer(
+        &[set_escrow_token_account(
+            rndr::id(),
+            test_mint.pubkey,
+            owner.pubkey(),
+            token_account,
+            test_escrow.associated_token,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    // Only the owner can move the escrow's tokens
+    let mut transaction = Transaction::new_with_payer(
+        &[set_escrow_token_account(
+            rndr::id(),
+            test_mint.pubkey,
+            payer.pubkey(),
+            test_escrow.associated_token,
+            token_account,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let escrow = test_escrow.get(&mut banks_client).await;
+    assert_eq!(escrow.token_account, Pubkey::default());
+}
@@ -0,0 +1,242 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{
+        error::RNDRError, instruction::set_job_exchange_rate, math::ExchangeRate,
+        oracle::OraclePrice, processor::process_instruction, state::Job, test_fixtures::*,
+    },
+    solana_program::{program_pack::Pack, pubkey::Pubkey},
+    solana_program_test::*,
+    solana_sdk::{signature::Signer, system_instruction, transaction::Transaction},
+};
+
+// 2 RNDR for one whole token
+const ORACLE_PRICE: OraclePrice = OraclePrice {
+    price: 2,
+    confidence: 0,
+    exponent: 0,
+    publish_slot: 0,
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const AMOUNT: u64 = 1_000_000_000_000_000_000;
+    // 2 RNDR of 8 decimals for a token of 18 decimals
+    const RATE_NUMERATOR: u64 = 2;
+    const RATE_DENOMINATOR: u64 = 10_000_000_000;
+
+    TestConfig::add(&mut test);
+    let test_mint = TestMint::add(&mut test, 18, AMOUNT);
+    let test_price_feed = TestPriceFeed::add(&mut test, ORACLE_PRICE);
+    let other_price_feed = TestPriceFeed::add(&mut test, ORACLE_PRICE);
+    let test_escrow = TestEscrow::add_with_price_feed(
+        &mut test,
+        test_mint.pubkey,
+        AMOUNT,
+        test_price_feed.pubkey,
+    );
+    let authority = Pubkey::new_unique();
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, authority, AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // The rate can only be derived from the price feed of the escrow
+    let mut transaction = Transaction::new_with_payer(
+        &[set_job_exchange_rate(
+            rndr::id(),
+            test_mint.pubkey,
+            other_price_feed.pubkey,
+            test_escrow.owner.pubkey(),
+            authority,
+            0,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let mut transaction = Transaction::new_with_payer(
+        &[set_job_exchange_rate(
+            rndr::id(),
+            test_mint.pubkey,
+            test_price_feed.pubkey,
+            test_escrow.owner.pubkey(),
+            authority,
+            0,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let job = get_job(&mut banks_client, test_job.pubkey).await;
+    let exchange_rate = job.exchange_rate().unwrap();
+    assert_eq!(
+        exchange_rate,
+        ExchangeRate::new(RATE_NUMERATOR, RATE_DENOMINATOR).unwrap()
+    );
+    assert_eq!(job.exchange_rate_slot, ORACLE_PRICE.publish_slot);
+    assert_eq!(exchange_rate.try_to_rndr(job.amount), Ok(200_000_000));
+
+    // The rate can only be recorded once
+    let recent_blockhash = banks_client
+        .get_new_latest_blockhash(&recent_blockhash)
+        .await
+        .unwrap();
+    let mut transaction = Transaction::new_with_payer(
+        &[set_job_exchange_rate(
+            rndr::id(),
+            test_mint.pubkey,
+            test_price_feed.pubkey,
+            test_escrow.owner.pubkey(),
+            authority,
+            0,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert_eq!(
+        program_error(banks_client.process_transaction(transaction).await),
+        Some(RNDRError::ExchangeRateAlreadySet)
+    );
+
+    let job = get_job(&mut banks_client, test_job.pubkey).await;
+    assert_eq!(job.exchange_rate(), Some(exchange_rate));
+}
+
+#[tokio::test]
+async fn test_success_with_legacy_job() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const AMOUNT: u64 = 1_000_000;
+
+    TestConfig::add(&mut test);
+    let test_mint = TestMint::add(&mut test, 6, AMOUNT);
+    let test_price_feed = TestPriceFeed::add(&mut test, ORACLE_PRICE);
+    let test_escrow = TestEscrow::add_with_price_feed(
+        &mut test,
+        test_mint.pubkey,
+        AMOUNT,
+        test_price_feed.pubkey,
+    );
+    let authority = Pubkey::new_unique();
+    let test_job = TestJob::add_legacy(&mut test, test_escrow.pubkey, authority, AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let owner = &test_escrow.owner;
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            system_instruction::transfer(&payer.pubkey(), &owner.pubkey(), 1_000_000_000),
+            set_job_exchange_rate(
+                rndr::id(),
+                test_mint.pubkey,
+                test_price_feed.pubkey,
+                owner.pubkey(),
+                authority,
+                0,
+            ),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    // The legacy job is reallocated to hold the rate, 2 RNDR of 8 decimals for a token of 6
+    let account = get_account(&mut banks_client, test_job.pubkey).await;
+    assert_eq!(account.data.len(), Job::LEN);
+    let job = get_job(&mut banks_client, test_job.pubkey).await;
+    assert_eq!(job.exchange_rate(), ExchangeRate::new(200, 1));
+    assert_eq!(job.amount, AMOUNT);
+}
+
+#[tokio::test]
+async fn test_unreliable_price() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const AMOUNT: u64 = 1_000_000;
+
+    TestConfig::add(&mut test);
+    let test_mint = TestMint::add(&mut test, 6, AMOUNT);
+    let test_price_feed = TestPriceFeed::add(
+        &mut test,
+        OraclePrice {
+            confidence: ORACLE_PRICE.price,
+            ..ORACLE_PRICE
+        },
+    );
+    let test_escrow = TestEscrow::add_with_price_feed(
+        &mut test,
+        test_mint.pubkey,
+        AMOUNT,
+        test_price_feed.pubkey,
+    );
+    let authority = Pubkey::new_unique();
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, authority, AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // A price less certain than the oracle price guards of the config allow isn't recorded
+    let mut transaction = Transaction::new_with_payer(
+        &[set_job_exchange_rate(
+            rndr::id(),
+            test_mint.pubkey,
+            test_price_feed.pubkey,
+            test_escrow.owner.pubkey(),
+            authority,
+            0,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_escrow.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let job = get_job(&mut banks_client, test_job.pubkey).await;
+    assert_eq!(job.exchange_rate(), None);
+}
+
+#[tokio::test]
+async fn test_invalid_owner() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const AMOUNT: u64 = 1_000_000;
+
+    TestConfig::add(&mut test);
+    let test_mint = TestMint::add(&mut test, 6, AMOUNT);
+    let test_price_feed = TestPriceFeed::add(&mut test, ORACLE_PRICE);
+    let test_escrow = TestEscrow::add_with_price_feed(
+        &mut test,
+        test_mint.pubkey,
+        AMOUNT,
+        test_price_feed.pubkey,
+    );
+    let authority = Pubkey::new_unique();
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, authority, AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[set_job_exchange_rate(
+            rndr::id(),
+            test_mint.pubkey,
+            test_price_feed.pubkey,
+            payer.pubkey(),
+            authority,
+            0,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let job = get_job(&mut banks_client, test_job.pubkey).await;
+    assert_eq!(job.exchange_rate(), None);
+}
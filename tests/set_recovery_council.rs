@@ -0,0 +1,128 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{instruction::set_recovery_council, processor::process_instruction, test_fixtures::*},
+    solana_program_test::*,
+    solana_sdk::{
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        system_instruction,
+        transaction::Transaction,
+    },
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const AMOUNT: u64 = 1_000_000_000;
+
+    let test_mint = TestMint::add(&mut test, 9, AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let guardians = vec![
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    ];
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // Only the escrow owner can set the recovery council
+    let impostor = Keypair::new();
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            system_instruction::transfer(&payer.pubkey(), &impostor.pubkey(), AMOUNT),
+            set_recovery_council(
+                rndr::id(),
+                test_escrow.pubkey,
+                impostor.pubkey(),
+                2,
+                guardians.clone(),
+            ),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &impostor], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    // The threshold can't exceed the number of guardians
+    let owner = &test_escrow.owner;
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            system_instruction::transfer(&payer.pubkey(), &owner.pubkey(), AMOUNT),
+            set_recovery_council(
+                rndr::id(),
+                test_escrow.pubkey,
+                owner.pubkey(),
+                4,
+                guardians.clone(),
+            ),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            system_instruction::transfer(&payer.pubkey(), &owner.pubkey(), AMOUNT),
+            set_recovery_council(
+                rndr::id(),
+                test_escrow.pubkey,
+                owner.pubkey(),
+                2,
+                guardians.clone(),
+            ),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let (recovery_pubkey, _bump_seed) = find_recovery_address(test_escrow.pubkey);
+    let recovery = get_recovery(&mut banks_client, recovery_pubkey).await;
+    assert_eq!(recovery.escrow, test_escrow.pubkey);
+    assert_eq!(recovery.threshold, 2);
+    assert_eq!(recovery.guardians, guardians);
+    assert_eq!(recovery.approvals(&Pubkey::default()), guardians.len());
+    assert!(!recovery.is_pending());
+}
+
+#[tokio::test]
+async fn test_replace_pending() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const AMOUNT: u64 = 1_000_000_000;
+
+    let test_mint = TestMint::add(&mut test, 9, AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_recovery =
+        TestRecovery::add_pending(&mut test, test_escrow.pubkey, 2, 3, Pubkey::new_unique(), 1);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // An owner that still holds their key can veto a recovery by replacing the council
+    let guardians = vec![Pubkey::new_unique()];
+    let owner = &test_escrow.owner;
+    let mut transaction = Transaction::new_with_payer(
+        &[set_recovery_council(
+            rndr::id(),
+            test_escrow.pubkey,
+            owner.pubkey(),
+            1,
+            guardians.clone(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let recovery = test_recovery.get(&mut banks_client).await;
+    assert_eq!(recovery.threshold, 1);
+    assert_eq!(recovery.guardians, guardians);
+    assert!(!recovery.is_pending());
+}
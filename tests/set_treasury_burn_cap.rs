@@ -0,0 +1,87 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{
+        instruction::set_treasury_burn_cap, processor::process_instruction, state::AccountType,
+        test_fixtures::*,
+    },
+    solana_program_test::*,
+    solana_sdk::{
+        native_token::LAMPORTS_PER_SOL, signature::Signer, system_instruction,
+        transaction::Transaction,
+    },
+    spl_associated_token_account::get_associated_token_address,
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const CAP: u64 = 10 * DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let owner = &test_escrow.owner;
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            system_instruction::transfer(&payer.pubkey(), &owner.pubkey(), LAMPORTS_PER_SOL),
+            set_treasury_burn_cap(rndr::id(), test_mint.pubkey, owner.pubkey(), CAP),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let (treasury_pubkey, _bump_seed) = find_treasury_address(test_escrow.pubkey);
+    let treasury = get_treasury(&mut banks_client, treasury_pubkey).await;
+    assert_eq!(treasury.account_type, AccountType::TreasuryV1);
+    assert_eq!(treasury.escrow, test_escrow.pubkey);
+    assert_eq!(treasury.token_mint, test_mint.pubkey);
+    assert_eq!(treasury.epoch_burn_cap, CAP);
+    assert_eq!(treasury.total_burned, 0);
+
+    let treasury_associated_token =
+        get_associated_token_address(&treasury_pubkey, &test_mint.pubkey);
+    let treasury_token = get_token(&mut banks_client, treasury_associated_token).await;
+    assert_eq!(treasury_token.owner, treasury_pubkey);
+    assert_eq!(treasury_token.amount, ZERO);
+}
+
+#[tokio::test]
+async fn test_success_update() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const CAP: u64 = 10 * DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let owner = &test_escrow.owner;
+    let test_treasury =
+        TestTreasury::add(&mut test, test_escrow.pubkey, test_mint.pubkey, CAP, ZERO);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[set_treasury_burn_cap(
+            rndr::id(),
+            test_mint.pubkey,
+            owner.pubkey(),
+            ZERO,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let treasury = test_treasury.get(&mut banks_client).await;
+    assert_eq!(treasury.epoch_burn_cap, ZERO);
+}
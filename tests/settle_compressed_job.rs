@@ -0,0 +1,191 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{
+        instruction::settle_compressed_job,
+        processor::process_instruction,
+        state::{CompressedJob, JobStatus},
+        test_fixtures::*,
+    },
+    solana_program_test::*,
+    solana_sdk::{
+        signature::{Keypair, Signer},
+        transaction::Transaction,
+    },
+};
+
+const ZERO: u64 = 0;
+const DECIMALS: u64 = 1_000_000_000;
+const AMOUNT: u64 = 1 * DECIMALS;
+const MAX_DEPTH: u8 = 4;
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+    add_account_compression(&mut test);
+
+    let authority = Keypair::new();
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, 2 * AMOUNT);
+    let test_node_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let test_refund_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let mut test_job_tree = TestJobTree::add(
+        &mut test,
+        test_escrow.pubkey,
+        authority.pubkey(),
+        MAX_DEPTH,
+        &[
+            CompressedJob {
+                amount: AMOUNT,
+                status: JobStatus::Committed,
+                node: test_node_token.owner.pubkey(),
+                ..CompressedJob::default()
+            },
+            CompressedJob {
+                amount: AMOUNT,
+                status: JobStatus::Open,
+                ..CompressedJob::default()
+            },
+        ],
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // The committed job is paid to its node and the open job is refunded
+    let committed_job = test_job_tree.jobs[0];
+    let open_job = test_job_tree.jobs[1];
+    let (root, proof) = test_job_tree.proof(committed_job.id);
+    let mut transaction = Transaction::new_with_payer(
+        &[settle_compressed_job(
+            rndr::id(),
+            test_mint.pubkey,
+            authority.pubkey(),
+            test_job_tree.merkle_tree,
+            test_node_token.pubkey,
+            committed_job,
+            root,
+            proof,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    test_job_tree.jobs[0] = CompressedJob {
+        amount: ZERO,
+        status: JobStatus::Paid,
+        ..committed_job
+    };
+    let (root, proof) = test_job_tree.proof(open_job.id);
+    let mut transaction = Transaction::new_with_payer(
+        &[settle_compressed_job(
+            rndr::id(),
+            test_mint.pubkey,
+            authority.pubkey(),
+            test_job_tree.merkle_tree,
+            test_refund_token.pubkey,
+            open_job,
+            root,
+            proof,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    test_job_tree.jobs[1] = CompressedJob {
+        amount: ZERO,
+        status: JobStatus::Cancelled,
+        ..open_job
+    };
+
+    let escrow_balance_after =
+        get_token_balance(&mut banks_client, test_escrow.associated_token).await;
+    let node_token_balance_after =
+        get_token_balance(&mut banks_client, test_node_token.pubkey).await;
+    let refund_token_balance_after =
+        get_token_balance(&mut banks_client, test_refund_token.pubkey).await;
+
+    assert_eq!(escrow_balance_after, ZERO);
+    assert_eq!(node_token_balance_after, AMOUNT);
+    assert_eq!(refund_token_balance_after, AMOUNT);
+
+    let escrow = get_escrow(&mut banks_client, test_escrow.pubkey).await;
+    assert_eq!(escrow.amount, ZERO);
+
+    let job_tree = test_job_tree.get(&mut banks_client).await;
+    assert_eq!(job_tree.amount, ZERO);
+    let (root, _proof) = test_job_tree.proof(0);
+    assert_eq!(test_job_tree.get_root(&mut banks_client).await, root);
+
+    // A settled job can't be settled again
+    let settled_job = test_job_tree.jobs[0];
+    let (root, proof) = test_job_tree.proof(settled_job.id);
+    let recent_blockhash = banks_client
+        .get_new_latest_blockhash(&recent_blockhash)
+        .await
+        .unwrap();
+    let mut transaction = Transaction::new_with_payer(
+        &[settle_compressed_job(
+            rndr::id(),
+            test_mint.pubkey,
+            authority.pubkey(),
+            test_job_tree.merkle_tree,
+            test_node_token.pubkey,
+            settled_job,
+            root,
+            proof,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+#[tokio::test]
+async fn test_invalid_node_token() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+    add_account_compression(&mut test);
+
+    let authority = Keypair::new();
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_node_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let test_other_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let test_job_tree = TestJobTree::add(
+        &mut test,
+        test_escrow.pubkey,
+        authority.pubkey(),
+        MAX_DEPTH,
+        &[CompressedJob {
+            amount: AMOUNT,
+            status: JobStatus::Committed,
+            node: test_node_token.owner.pubkey(),
+            ..CompressedJob::default()
+        }],
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let job = test_job_tree.jobs[0];
+    let (root, proof) = test_job_tree.proof(job.id);
+    let mut transaction = Transaction::new_with_payer(
+        &[settle_compressed_job(
+            rndr::id(),
+            test_mint.pubkey,
+            authority.pubkey(),
+            test_job_tree.merkle_tree,
+            test_other_token.pubkey,
+            job,
+            root,
+            proof,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
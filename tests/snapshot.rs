@@ -0,0 +1,107 @@
+#![cfg(feature = "client")]
+
+use {
+    rndr::{
+        client::snapshot::{Snapshot, SnapshotAccount, CSV_HEADER},
+        state::{AccountType, Escrow, EscrowShard, IncidentMode, Job},
+    },
+    solana_program::{hash::Hash, pubkey::Pubkey},
+};
+
+#[test]
+fn test_success() {
+    let escrow = SnapshotAccount {
+        address: Pubkey::new_unique(),
+        account: Escrow {
+            account_type: AccountType::EscrowV1,
+            amount: 300,
+            owner: Pubkey::new_unique(),
+            token_mint: Pubkey::new_unique(),
+            challenge_window: 60,
+            proof_verifier: Pubkey::new_unique(),
+            arbitrator: Pubkey::new_unique(),
+            approver: Pubkey::new_unique(),
+            owner_history: vec![],
+            token_account: Pubkey::default(),
+            original_token_mint: Pubkey::default(),
+            mint_migrations: 0,
+            escrow_id: 0,
+            price_feed: Pubkey::default(),
+            incident_mode: IncidentMode::Normal,
+            needs_attention: false,
+        },
+    };
+    let escrow_shard = SnapshotAccount {
+        address: Pubkey::new_unique(),
+        account: EscrowShard {
+            account_type: AccountType::EscrowShardV1,
+            escrow: escrow.address,
+            index: 3,
+            amount: 50,
+        },
+    };
+    let job = SnapshotAccount {
+        address: Pubkey::new_unique(),
+        account: Job {
+            account_type: AccountType::JobV1,
+            amount: 350,
+            authority: Pubkey::new_unique(),
+            isolated: true,
+            id: 7,
+            ..Job::default()
+        },
+    };
+    let snapshot = Snapshot {
+        slot: 42,
+        escrows: vec![escrow.clone()],
+        escrow_shards: vec![escrow_shard.clone()],
+        jobs: vec![job.clone()],
+    };
+
+    let mut csv = vec![];
+    snapshot.write_csv(&mut csv).unwrap();
+    let csv = String::from_utf8(csv).unwrap();
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(
+        lines,
+        vec![
+            CSV_HEADER.to_string(),
+            format!(
+                "{},EscrowV1,300,{},{},60,{},{},{},,,,,,,,,,,,",
+                escrow.address,
+                escrow.account.owner,
+                escrow.account.token_mint,
+                escrow.account.proof_verifier,
+                escrow.account.arbitrator,
+                escrow.account.approver
+            ),
+            format!(
+                "{},EscrowShardV1,50,,,,,,,{},3,,,,,,,,,,",
+                escrow_shard.address, escrow.address
+            ),
+            format!(
+                "{},JobV1,350,,,,,,,,,{},7,true,Open,{},{},{},0,{},{}",
+                job.address,
+                job.account.authority,
+                Pubkey::default(),
+                Hash::default(),
+                Hash::default(),
+                Pubkey::default(),
+                Hash::default()
+            ),
+        ]
+    );
+    for line in lines {
+        assert_eq!(line.split(',').count(), CSV_HEADER.split(',').count());
+    }
+
+    let mut json = vec![];
+    snapshot.write_json(&mut json).unwrap();
+    let value: serde_json::Value = serde_json::from_slice(&json).unwrap();
+    assert_eq!(value["jobs"][0]["address"], job.address.to_string());
+    assert_eq!(
+        value["jobs"][0]["authority"],
+        job.account.authority.to_string()
+    );
+    assert_eq!(serde_json::from_value::<Snapshot>(value).unwrap(), snapshot);
+}
@@ -0,0 +1,78 @@
+#![cfg(feature = "client")]
+
+use {
+    base64::{engine::general_purpose::STANDARD, Engine},
+    rndr::client::{
+        solana_pay::{transaction_request_url, TransactionRequest},
+        RndrClient,
+    },
+    solana_client::nonblocking::rpc_client::RpcClient,
+    solana_program_test::tokio,
+    solana_sdk::{
+        pubkey::Pubkey,
+        signature::{Keypair, Signature, Signer},
+        transaction::Transaction,
+    },
+};
+
+#[tokio::test]
+async fn test_success() {
+    const AMOUNT: u64 = 1_000_000_000;
+    const JOB_ID: u64 = 1;
+
+    let rpc_client = RpcClient::new_mock("succeeds".to_string());
+    let client = RndrClient::new(rpc_client, Pubkey::new_unique());
+    let account = Pubkey::new_unique();
+    let authority = Keypair::new();
+    let reference = Pubkey::new_unique();
+
+    let request = TransactionRequest {
+        account: account.to_string(),
+    };
+    let response = client
+        .fund_job_transaction_request(
+            &request.account().unwrap(),
+            &authority,
+            JOB_ID,
+            AMOUNT,
+            &reference,
+            Some("Fund render job".to_string()),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.message.as_deref(), Some("Fund render job"));
+
+    let transaction: Transaction =
+        bincode::deserialize(&STANDARD.decode(&response.transaction).unwrap()).unwrap();
+    let message = &transaction.message;
+    assert_eq!(message.account_keys[0], account);
+
+    let reference_index = message
+        .account_keys
+        .iter()
+        .position(|key| *key == reference)
+        .unwrap();
+    assert!(!message.is_signer(reference_index));
+    assert!(!message.is_writable(reference_index));
+
+    let authority_index = message
+        .account_keys
+        .iter()
+        .position(|key| *key == authority.pubkey())
+        .unwrap();
+    assert_eq!(transaction.signatures[0], Signature::default());
+    assert!(transaction.signatures[authority_index]
+        .verify(authority.pubkey().as_ref(), &message.serialize()));
+}
+
+#[test]
+fn test_transaction_request_url() {
+    assert_eq!(
+        transaction_request_url("https://example.com/fund"),
+        "solana:https://example.com/fund"
+    );
+    assert_eq!(
+        transaction_request_url("https://example.com/fund?job=1"),
+        "solana:https%3A%2F%2Fexample.com%2Ffund%3Fjob%3D1"
+    );
+}
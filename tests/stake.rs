@@ -0,0 +1,167 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{
+        instruction::stake, processor::process_instruction, state::AccountType, test_fixtures::*,
+    },
+    solana_program_test::*,
+    solana_sdk::{
+        native_token::LAMPORTS_PER_SOL,
+        signature::{Keypair, Signer},
+        system_instruction,
+        transaction::Transaction,
+    },
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_source_token = TestToken::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let authority = test_source_token.owner.pubkey();
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[stake(
+            rndr::id(),
+            AMOUNT,
+            test_mint.pubkey,
+            payer.pubkey(),
+            test_source_token.pubkey,
+            authority,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &test_source_token.owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let (node_pubkey, _bump_seed) = find_node_address(test_escrow.pubkey, payer.pubkey());
+    let node_associated_token =
+        spl_associated_token_account::get_associated_token_address(&node_pubkey, &test_mint.pubkey);
+
+    let source_token_balance_after =
+        get_token_balance(&mut banks_client, test_source_token.pubkey).await;
+    let node_balance_after = get_token_balance(&mut banks_client, node_associated_token).await;
+
+    assert_eq!(source_token_balance_after, ZERO);
+    assert_eq!(node_balance_after, AMOUNT);
+
+    let node = get_node(&mut banks_client, node_pubkey).await;
+    assert_eq!(node.account_type, AccountType::NodeV1);
+    assert_eq!(node.escrow, test_escrow.pubkey);
+    assert_eq!(node.authority, payer.pubkey());
+    assert_eq!(node.stake, AMOUNT);
+    assert!(!node.auto_compound);
+}
+
+#[tokio::test]
+async fn test_success_existing() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_source_token = TestToken::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let authority = test_source_token.owner.pubkey();
+    let node_authority = Keypair::new();
+    let test_node = TestNode::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_mint.pubkey,
+        node_authority.pubkey(),
+        AMOUNT,
+        false,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[stake(
+            rndr::id(),
+            AMOUNT,
+            test_mint.pubkey,
+            node_authority.pubkey(),
+            test_source_token.pubkey,
+            authority,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(
+        &[&payer, &node_authority, &test_source_token.owner],
+        recent_blockhash,
+    );
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let node_balance_after = get_token_balance(&mut banks_client, test_node.associated_token).await;
+    assert_eq!(node_balance_after, 2 * AMOUNT);
+
+    let node = test_node.get(&mut banks_client).await;
+    assert_eq!(node.stake, 2 * AMOUNT);
+}
+
+#[tokio::test]
+async fn test_success_legacy() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_source_token = TestToken::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let authority = test_source_token.owner.pubkey();
+    let node_authority = Keypair::new();
+    let test_node = TestNode::add_legacy(
+        &mut test,
+        test_escrow.pubkey,
+        test_mint.pubkey,
+        node_authority.pubkey(),
+        AMOUNT,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            system_instruction::transfer(
+                &payer.pubkey(),
+                &node_authority.pubkey(),
+                LAMPORTS_PER_SOL,
+            ),
+            stake(
+                rndr::id(),
+                AMOUNT,
+                test_mint.pubkey,
+                node_authority.pubkey(),
+                test_source_token.pubkey,
+                authority,
+            ),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(
+        &[&payer, &node_authority, &test_source_token.owner],
+        recent_blockhash,
+    );
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    // The node is reallocated to record the tokens staked during the epoch
+    let node = test_node.get(&mut banks_client).await;
+    assert_eq!(node.stake, 2 * AMOUNT);
+    assert_eq!(node.epoch_staked, AMOUNT);
+    assert_eq!(node.tier_stake(node.stake_epoch), AMOUNT);
+}
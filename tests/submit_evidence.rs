@@ -0,0 +1,73 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{instruction::submit_evidence, processor::process_instruction, test_fixtures::*},
+    solana_program_test::*,
+    solana_sdk::{
+        signature::{Keypair, Signer},
+        transaction::Transaction,
+    },
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const AMOUNT: u64 = 1_000_000_000;
+    const AUTHORITY_EVIDENCE_HASH: [u8; 32] = [6; 32];
+    const NODE_EVIDENCE_HASH: [u8; 32] = [7; 32];
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * AMOUNT);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let authority = Keypair::new();
+    let node = Keypair::new();
+    let test_job = TestJob::add_disputed(
+        &mut test,
+        test_escrow.pubkey,
+        authority.pubkey(),
+        node.pubkey(),
+        AMOUNT,
+    );
+    let test_dispute = TestDispute::add(&mut test, test_job.pubkey, authority.pubkey());
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // Only the job's authority or node can submit evidence
+    let mut transaction = Transaction::new_with_payer(
+        &[submit_evidence(
+            rndr::id(),
+            AUTHORITY_EVIDENCE_HASH,
+            payer.pubkey(),
+            test_job.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            submit_evidence(
+                rndr::id(),
+                AUTHORITY_EVIDENCE_HASH,
+                authority.pubkey(),
+                test_job.pubkey,
+            ),
+            submit_evidence(
+                rndr::id(),
+                NODE_EVIDENCE_HASH,
+                node.pubkey(),
+                test_job.pubkey,
+            ),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &authority, &node], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let dispute = test_dispute.get(&mut banks_client).await;
+    assert_eq!(dispute.authority_evidence_hash, AUTHORITY_EVIDENCE_HASH);
+    assert_eq!(dispute.node_evidence_hash, NODE_EVIDENCE_HASH);
+}
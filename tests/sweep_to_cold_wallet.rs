@@ -0,0 +1,357 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{
+        instruction::{
+            cancel_treasury_sweep, register_cold_wallet, schedule_treasury_sweep,
+            sweep_to_cold_wallet,
+        },
+        processor::process_instruction,
+        state::Treasury,
+        test_fixtures::*,
+    },
+    solana_program::program_pack::Pack,
+    solana_program_test::*,
+    solana_sdk::{signature::Signer, system_instruction, transaction::Transaction},
+};
+
+const ZERO: u64 = 0;
+const DECIMALS: u64 = 1_000_000_000;
+const AMOUNT: u64 = 1 * DECIMALS;
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let test_config = TestConfig::add(&mut test);
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let test_cold_wallet = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let test_treasury = TestTreasury::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_mint.pubkey,
+        ZERO,
+        2 * AMOUNT,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let owner = &test_escrow.owner;
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            register_cold_wallet(
+                rndr::id(),
+                test_mint.pubkey,
+                owner.pubkey(),
+                test_cold_wallet.pubkey,
+            ),
+            schedule_treasury_sweep(rndr::id(), AMOUNT, test_mint.pubkey, owner.pubkey()),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let treasury = test_treasury.get(&mut banks_client).await;
+    assert_eq!(treasury.cold_wallet(), Some(&test_cold_wallet.pubkey));
+    assert_eq!(treasury.sweep_amount, AMOUNT);
+
+    // The sweep can't be executed or replaced until the sweep delay has passed
+    for (instruction, signer) in [
+        (
+            sweep_to_cold_wallet(rndr::id(), test_mint.pubkey, test_cold_wallet.pubkey),
+            &payer,
+        ),
+        (
+            schedule_treasury_sweep(rndr::id(), AMOUNT, test_mint.pubkey, owner.pubkey()),
+            owner,
+        ),
+    ] {
+        let recent_blockhash = banks_client
+            .get_new_latest_blockhash(&recent_blockhash)
+            .await
+            .unwrap();
+        let mut transaction = Transaction::new_with_payer(&[instruction], Some(&payer.pubkey()));
+        transaction.sign(&[&payer, signer], recent_blockhash);
+
+        assert!(banks_client.process_transaction(transaction).await.is_err());
+    }
+
+    // Until then the guardian can cancel it
+    let guardian = &test_config.guardian;
+    let mut transaction = Transaction::new_with_payer(
+        &[cancel_treasury_sweep(
+            rndr::id(),
+            test_mint.pubkey,
+            guardian.pubkey(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, guardian], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let treasury = test_treasury.get(&mut banks_client).await;
+    assert_eq!(treasury.sweep_amount, ZERO);
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_treasury.associated_token).await,
+        2 * AMOUNT
+    );
+}
+
+#[tokio::test]
+async fn test_success_after_sweep_delay() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let test_config = TestConfig::add(&mut test);
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let test_cold_wallet = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let test_other_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let test_treasury = TestTreasury::add_with_sweep(
+        &mut test,
+        test_escrow.pubkey,
+        test_mint.pubkey,
+        2 * AMOUNT,
+        test_cold_wallet.pubkey,
+        AMOUNT,
+        1,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // Once the sweep delay has passed, the guardian can no longer cancel the sweep
+    let guardian = &test_config.guardian;
+    let mut transaction = Transaction::new_with_payer(
+        &[cancel_treasury_sweep(
+            rndr::id(),
+            test_mint.pubkey,
+            guardian.pubkey(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, guardian], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    // Tokens can only be swept to the registered cold wallet
+    let mut transaction = Transaction::new_with_payer(
+        &[sweep_to_cold_wallet(
+            rndr::id(),
+            test_mint.pubkey,
+            test_other_token.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    // Anyone can execute the sweep
+    let mut transaction = Transaction::new_with_payer(
+        &[sweep_to_cold_wallet(
+            rndr::id(),
+            test_mint.pubkey,
+            test_cold_wallet.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_cold_wallet.pubkey).await,
+        AMOUNT
+    );
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_treasury.associated_token).await,
+        AMOUNT
+    );
+    let treasury = test_treasury.get(&mut banks_client).await;
+    assert_eq!(treasury.sweep_amount, ZERO);
+
+    // The sweep can only be executed once
+    let recent_blockhash = banks_client
+        .get_new_latest_blockhash(&recent_blockhash)
+        .await
+        .unwrap();
+    let mut transaction = Transaction::new_with_payer(
+        &[sweep_to_cold_wallet(
+            rndr::id(),
+            test_mint.pubkey,
+            test_cold_wallet.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+#[tokio::test]
+async fn test_register_cancels_sweep() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let test_cold_wallet = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let test_new_cold_wallet = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let test_treasury = TestTreasury::add_with_sweep(
+        &mut test,
+        test_escrow.pubkey,
+        test_mint.pubkey,
+        2 * AMOUNT,
+        test_cold_wallet.pubkey,
+        AMOUNT,
+        1,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // Registering a new cold wallet cancels the sweep to the previous one, so it can't be
+    // redirected without waiting for the sweep delay again
+    let owner = &test_escrow.owner;
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            register_cold_wallet(
+                rndr::id(),
+                test_mint.pubkey,
+                owner.pubkey(),
+                test_new_cold_wallet.pubkey,
+            ),
+            sweep_to_cold_wallet(rndr::id(), test_mint.pubkey, test_new_cold_wallet.pubkey),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let mut transaction = Transaction::new_with_payer(
+        &[register_cold_wallet(
+            rndr::id(),
+            test_mint.pubkey,
+            owner.pubkey(),
+            test_new_cold_wallet.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let treasury = test_treasury.get(&mut banks_client).await;
+    assert_eq!(treasury.cold_wallet(), Some(&test_new_cold_wallet.pubkey));
+    assert_eq!(treasury.sweep_amount, ZERO);
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_treasury.associated_token).await,
+        2 * AMOUNT
+    );
+}
+
+#[tokio::test]
+async fn test_success_with_legacy_treasury() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let test_cold_wallet = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let test_treasury =
+        TestTreasury::add_legacy(&mut test, test_escrow.pubkey, test_mint.pubkey, AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let owner = &test_escrow.owner;
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            system_instruction::transfer(&payer.pubkey(), &owner.pubkey(), 1_000_000_000),
+            register_cold_wallet(
+                rndr::id(),
+                test_mint.pubkey,
+                owner.pubkey(),
+                test_cold_wallet.pubkey,
+            ),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    // The legacy treasury is reallocated to hold the cold wallet
+    let account = get_account(&mut banks_client, test_treasury.pubkey).await;
+    assert_eq!(account.data.len(), Treasury::LEN);
+
+    let treasury = test_treasury.get(&mut banks_client).await;
+    assert_eq!(treasury.cold_wallet(), Some(&test_cold_wallet.pubkey));
+}
+
+#[tokio::test]
+async fn test_invalid_cold_wallet() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let test_config = TestConfig::add(&mut test);
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_other_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let test_other_token = TestToken::add(&mut test, test_other_mint.pubkey, ZERO);
+    let test_treasury = TestTreasury::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_mint.pubkey,
+        ZERO,
+        AMOUNT,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let owner = &test_escrow.owner;
+    for instruction in [
+        // A sweep can't be scheduled without a cold wallet
+        schedule_treasury_sweep(rndr::id(), AMOUNT, test_mint.pubkey, owner.pubkey()),
+        // The cold wallet must hold the treasury's token mint
+        register_cold_wallet(
+            rndr::id(),
+            test_mint.pubkey,
+            owner.pubkey(),
+            test_other_token.pubkey,
+        ),
+        // The cold wallet can't be the treasury's own token account
+        register_cold_wallet(
+            rndr::id(),
+            test_mint.pubkey,
+            owner.pubkey(),
+            test_treasury.associated_token,
+        ),
+    ] {
+        let recent_blockhash = banks_client
+            .get_new_latest_blockhash(&recent_blockhash)
+            .await
+            .unwrap();
+        let mut transaction = Transaction::new_with_payer(&[instruction], Some(&payer.pubkey()));
+        transaction.sign(&[&payer, owner], recent_blockhash);
+
+        assert!(banks_client.process_transaction(transaction).await.is_err());
+    }
+
+    // Only the escrow owner can register a cold wallet
+    let guardian = &test_config.guardian;
+    let mut transaction = Transaction::new_with_payer(
+        &[register_cold_wallet(
+            rndr::id(),
+            test_mint.pubkey,
+            guardian.pubkey(),
+            test_other_token.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, guardian], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let treasury = test_treasury.get(&mut banks_client).await;
+    assert_eq!(treasury.cold_wallet(), None);
+}
@@ -0,0 +1,232 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{
+        error::RNDRError,
+        instruction::{disburse_funds, fund_job, set_config, sync_incident_mode},
+        processor::process_instruction,
+        state::{ConfigChange, Escrow, IncidentMode},
+        test_fixtures::*,
+    },
+    solana_program::program_pack::Pack,
+    solana_program_test::*,
+    solana_sdk::{
+        signature::{Keypair, Signer},
+        transaction::Transaction,
+    },
+};
+
+const ZERO: u64 = 0;
+const DECIMALS: u64 = 1_000_000_000;
+const AMOUNT: u64 = 1 * DECIMALS;
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let test_config = TestConfig::add(&mut test);
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_source_token = TestToken::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let authority = &test_source_token.owner;
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, authority.pubkey(), AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let governance = &test_config.governance;
+    let owner = &test_escrow.owner;
+    let set_incident_mode = |mode| {
+        [
+            set_config(
+                rndr::id(),
+                governance.pubkey(),
+                ConfigChange::IncidentMode(mode),
+            ),
+            sync_incident_mode(rndr::id(), test_escrow.pubkey, payer.pubkey()),
+        ]
+    };
+    let fund = |job_id| {
+        fund_job(
+            rndr::id(),
+            AMOUNT,
+            job_id,
+            test_mint.pubkey,
+            payer.pubkey(),
+            test_source_token.pubkey,
+            authority.pubkey(),
+        )
+    };
+    let disburse = || {
+        disburse_funds(
+            rndr::id(),
+            AMOUNT / 2,
+            test_mint.pubkey,
+            test_destination_token.pubkey,
+            test_job.pubkey,
+            owner.pubkey(),
+        )
+    };
+
+    // Existing jobs settle while no new jobs can be funded
+    let mut transaction = Transaction::new_with_payer(
+        &set_incident_mode(IncidentMode::DisburseOnly),
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, governance], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let config = get_config(&mut banks_client).await;
+    assert_eq!(config.incident_mode, IncidentMode::DisburseOnly);
+    let escrow = test_escrow.get(&mut banks_client).await;
+    assert_eq!(escrow.incident_mode, IncidentMode::DisburseOnly);
+
+    let mut transaction = Transaction::new_with_payer(&[fund(1)], Some(&payer.pubkey()));
+    transaction.sign(&[&payer, authority], recent_blockhash);
+
+    assert_eq!(
+        program_error(banks_client.process_transaction(transaction).await),
+        Some(RNDRError::IncidentModeActive)
+    );
+
+    let mut transaction = Transaction::new_with_payer(&[disburse()], Some(&payer.pubkey()));
+    transaction.sign(&[&payer, owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    // Jobs can be funded while no funds can be disbursed
+    let recent_blockhash = banks_client
+        .get_new_latest_blockhash(&recent_blockhash)
+        .await
+        .unwrap();
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            set_incident_mode(IncidentMode::FundOnly).as_slice(),
+            &[fund(1)],
+        ]
+        .concat(),
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, governance, authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let mut transaction = Transaction::new_with_payer(&[disburse()], Some(&payer.pubkey()));
+    transaction.sign(&[&payer, owner], recent_blockhash);
+
+    assert_eq!(
+        program_error(banks_client.process_transaction(transaction).await),
+        Some(RNDRError::IncidentModeActive)
+    );
+
+    // Both directions work again once the incident is over
+    let recent_blockhash = banks_client
+        .get_new_latest_blockhash(&recent_blockhash)
+        .await
+        .unwrap();
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            set_incident_mode(IncidentMode::Normal).as_slice(),
+            &[disburse()],
+        ]
+        .concat(),
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, governance, owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_destination_token.pubkey).await,
+        AMOUNT
+    );
+    assert_eq!(
+        get_token_balance(&mut banks_client, test_escrow.associated_token).await,
+        AMOUNT
+    );
+    let job = get_job(&mut banks_client, test_job.pubkey).await;
+    assert_eq!(job.amount, ZERO);
+}
+
+#[tokio::test]
+async fn test_success_with_legacy_escrow() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let test_config = TestConfig::add(&mut test);
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add_legacy(&mut test, test_mint.pubkey, AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let governance = &test_config.governance;
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            set_config(
+                rndr::id(),
+                governance.pubkey(),
+                ConfigChange::IncidentMode(IncidentMode::FundOnly),
+            ),
+            sync_incident_mode(rndr::id(), test_escrow.pubkey, payer.pubkey()),
+        ],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, governance], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    // The legacy escrow is reallocated to hold the incident mode
+    let account = get_account(&mut banks_client, test_escrow.pubkey).await;
+    assert_eq!(account.data.len(), Escrow::LEN);
+
+    let escrow = test_escrow.get(&mut banks_client).await;
+    assert_eq!(escrow.incident_mode, IncidentMode::FundOnly);
+    assert_eq!(escrow.amount, AMOUNT);
+}
+
+#[tokio::test]
+async fn test_invalid_governance() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let test_config = TestConfig::add(&mut test);
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // Neither the escrow owner nor anyone else but the governance authority can set the mode
+    for impostor in [&test_escrow.owner, &Keypair::new()] {
+        let mut transaction = Transaction::new_with_payer(
+            &[
+                set_config(
+                    rndr::id(),
+                    impostor.pubkey(),
+                    ConfigChange::IncidentMode(IncidentMode::DisburseOnly),
+                ),
+                sync_incident_mode(rndr::id(), test_escrow.pubkey, payer.pubkey()),
+            ],
+            Some(&payer.pubkey()),
+        );
+        transaction.sign(&[&payer, impostor], recent_blockhash);
+
+        assert!(banks_client.process_transaction(transaction).await.is_err());
+    }
+
+    // Anyone can sync an escrow, but only to the mode of the config
+    let mut transaction = Transaction::new_with_payer(
+        &[sync_incident_mode(
+            rndr::id(),
+            test_escrow.pubkey,
+            payer.pubkey(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let config = get_config(&mut banks_client).await;
+    assert_eq!(config.incident_mode, IncidentMode::Normal);
+    let escrow = test_escrow.get(&mut banks_client).await;
+    assert_eq!(escrow.incident_mode, IncidentMode::Normal);
+}
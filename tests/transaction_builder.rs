@@ -0,0 +1,326 @@
+#![cfg(feature = "client")]
+
+use {
+    rndr::{
+        client::{
+            transaction::{priority_fee_percentile, ComputeUnitLimit, PriorityFee},
+            Payers, RndrClient,
+        },
+        instruction::{aggregate_escrow_shard, disburse_batch, fund_job, MAX_DISBURSE_BATCH_LEN},
+    },
+    serde_json::json,
+    solana_account_decoder::{UiAccount, UiAccountEncoding},
+    solana_client::{
+        nonblocking::rpc_client::RpcClient,
+        rpc_client::Mocks,
+        rpc_request::RpcRequest,
+        rpc_response::{
+            Response, RpcPrioritizationFee, RpcResponseContext, RpcSimulateTransactionResult,
+        },
+    },
+    solana_program_test::tokio,
+    solana_sdk::{
+        account::Account,
+        address_lookup_table::AddressLookupTableAccount,
+        compute_budget::ComputeBudgetInstruction,
+        hash::Hash,
+        message::VersionedMessage,
+        nonce::{
+            state::{Data, DurableNonce, Versions},
+            State,
+        },
+        packet::PACKET_DATA_SIZE,
+        pubkey::Pubkey,
+        signature::{Keypair, Signature, Signer},
+        system_instruction, system_program,
+    },
+};
+
+#[tokio::test]
+async fn test_success() {
+    const UNITS_CONSUMED: u64 = 20_000;
+    const MICRO_LAMPORTS: u64 = 5;
+
+    let mut mocks = Mocks::default();
+    mocks.insert(
+        RpcRequest::SimulateTransaction,
+        json!(Response {
+            context: RpcResponseContext {
+                slot: 1,
+                api_version: None,
+            },
+            value: RpcSimulateTransactionResult {
+                err: None,
+                logs: None,
+                accounts: None,
+                units_consumed: Some(UNITS_CONSUMED),
+                return_data: None,
+                inner_instructions: None,
+            },
+        }),
+    );
+    let rpc_client = RpcClient::new_mock_with_mocks("succeeds".to_string(), mocks);
+    let client = RndrClient::new(rpc_client, Pubkey::new_unique());
+    let payer = Keypair::new();
+
+    let instruction = aggregate_escrow_shard(rndr::id(), client.escrow_address(), 0);
+
+    let instructions = client
+        .transaction()
+        .instruction(instruction.clone())
+        .compute_unit_limit(ComputeUnitLimit::Simulated { margin_percent: 10 })
+        .priority_fee(PriorityFee::MicroLamportsPerUnit(MICRO_LAMPORTS))
+        .build_instructions(&payer.pubkey())
+        .await
+        .unwrap();
+
+    assert_eq!(
+        instructions,
+        vec![
+            ComputeBudgetInstruction::set_compute_unit_limit(22_000),
+            ComputeBudgetInstruction::set_compute_unit_price(MICRO_LAMPORTS),
+            instruction.clone(),
+        ]
+    );
+
+    let instructions = client
+        .transaction()
+        .instruction(instruction.clone())
+        .compute_unit_limit(ComputeUnitLimit::Default)
+        .build_instructions(&payer.pubkey())
+        .await
+        .unwrap();
+
+    assert_eq!(instructions, vec![instruction]);
+}
+
+#[tokio::test]
+async fn test_success_versioned() {
+    let rpc_client = RpcClient::new_mock("succeeds".to_string());
+    let client = RndrClient::new(rpc_client, Pubkey::new_unique());
+    let payer = Keypair::new();
+    let owner = Keypair::new();
+
+    let disbursements: Vec<(Pubkey, u64)> = (0..MAX_DISBURSE_BATCH_LEN)
+        .map(|amount| (Pubkey::new_unique(), amount as u64 + 1))
+        .collect();
+    let job = client.job_address(&Pubkey::new_unique(), 0);
+    let instruction = disburse_batch(
+        rndr::id(),
+        client.token_mint(),
+        job,
+        owner.pubkey(),
+        &disbursements,
+    );
+
+    let mut addresses = client.escrow_lookup_table_addresses();
+    addresses.push(job);
+    addresses.extend(
+        disbursements
+            .iter()
+            .map(|(destination, _amount)| *destination),
+    );
+    let lookup_table = AddressLookupTableAccount {
+        key: Pubkey::new_unique(),
+        addresses,
+    };
+
+    let transaction = client
+        .transaction()
+        .instruction(instruction)
+        .compute_unit_limit(ComputeUnitLimit::Fixed(1_000_000))
+        .lookup_table(lookup_table)
+        .build_versioned(&payer, &[&owner])
+        .await
+        .unwrap();
+
+    match &transaction.message {
+        VersionedMessage::V0(message) => assert_eq!(message.address_table_lookups.len(), 1),
+        VersionedMessage::Legacy(_) => panic!("expected a versioned message"),
+    }
+    assert_eq!(transaction.signatures.len(), 2);
+    assert!(bincode::serialize(&transaction).unwrap().len() <= PACKET_DATA_SIZE);
+}
+
+#[tokio::test]
+async fn test_success_durable_nonce() {
+    let nonce_account = Pubkey::new_unique();
+    let nonce_authority = Keypair::new();
+    let durable_nonce = DurableNonce::from_blockhash(&Hash::new_unique());
+    let state = Versions::new(State::Initialized(Data::new(
+        nonce_authority.pubkey(),
+        durable_nonce,
+        5_000,
+    )));
+    let account = Account {
+        lamports: 1_447_680,
+        data: bincode::serialize(&state).unwrap(),
+        owner: system_program::id(),
+        executable: false,
+        rent_epoch: 0,
+    };
+
+    let mut mocks = Mocks::default();
+    mocks.insert(
+        RpcRequest::GetAccountInfo,
+        json!(Response {
+            context: RpcResponseContext {
+                slot: 1,
+                api_version: None,
+            },
+            value: Some(UiAccount::encode(
+                &nonce_account,
+                &account,
+                UiAccountEncoding::Base64,
+                None,
+                None,
+            )),
+        }),
+    );
+    let rpc_client = RpcClient::new_mock_with_mocks("succeeds".to_string(), mocks);
+    let client = RndrClient::new(rpc_client, Pubkey::new_unique());
+    let payer = Keypair::new();
+
+    let instruction = aggregate_escrow_shard(rndr::id(), client.escrow_address(), 0);
+
+    let mut transaction = client
+        .transaction()
+        .instruction(instruction.clone())
+        .compute_unit_limit(ComputeUnitLimit::Fixed(50_000))
+        .durable_nonce(nonce_account, nonce_authority.pubkey())
+        .build_unsigned(&payer.pubkey())
+        .await
+        .unwrap();
+
+    assert_eq!(
+        transaction.message.recent_blockhash,
+        *durable_nonce.as_hash()
+    );
+    assert_eq!(transaction.message.account_keys[0], payer.pubkey());
+    assert_eq!(
+        transaction.message.instructions[0],
+        transaction
+            .message
+            .compile_instruction(&system_instruction::advance_nonce_account(
+                &nonce_account,
+                &nonce_authority.pubkey()
+            ))
+    );
+    assert!(transaction
+        .signatures
+        .iter()
+        .all(|signature| *signature == Signature::default()));
+
+    let recent_blockhash = transaction.message.recent_blockhash;
+    transaction.partial_sign(&[&nonce_authority], recent_blockhash);
+    assert!(!transaction.is_signed());
+    transaction.partial_sign(&[&payer], recent_blockhash);
+    transaction.verify().unwrap();
+}
+
+#[tokio::test]
+async fn test_success_distinct_payers() {
+    let rpc_client = RpcClient::new_mock("succeeds".to_string());
+    let client = RndrClient::new(rpc_client, Pubkey::new_unique());
+    let fee_payer = Keypair::new();
+    let rent_payer = Keypair::new();
+    let authority = Keypair::new();
+
+    let transaction = client
+        .transaction()
+        .instruction(fund_job(
+            rndr::id(),
+            1,
+            1,
+            client.token_mint(),
+            rent_payer.pubkey(),
+            Pubkey::new_unique(),
+            authority.pubkey(),
+        ))
+        .compute_unit_limit(ComputeUnitLimit::Default)
+        .build(&fee_payer, &[&rent_payer, &authority])
+        .await
+        .unwrap();
+
+    // The fee payer comes first, and the rent payer signs as the funder of the instruction
+    assert_eq!(transaction.message.account_keys[0], fee_payer.pubkey());
+    assert!(transaction.is_signed());
+    assert!(transaction.verify().is_ok());
+
+    let signature = client
+        .fund_job_with_payers(
+            Payers::new(&fee_payer, &rent_payer),
+            &Pubkey::new_unique(),
+            &authority,
+            1,
+            1,
+        )
+        .await
+        .unwrap();
+    assert_ne!(signature, Signature::default());
+}
+
+#[test]
+fn test_priority_fee_percentile() {
+    let fees: Vec<_> = [30, 0, 10, 20, 40]
+        .iter()
+        .enumerate()
+        .map(|(slot, prioritization_fee)| RpcPrioritizationFee {
+            slot: slot as u64,
+            prioritization_fee: *prioritization_fee,
+        })
+        .collect();
+
+    assert_eq!(priority_fee_percentile(&fees, 0), 0);
+    assert_eq!(priority_fee_percentile(&fees, 50), 20);
+    assert_eq!(priority_fee_percentile(&fees, 75), 30);
+    assert_eq!(priority_fee_percentile(&fees, 100), 40);
+    assert_eq!(priority_fee_percentile(&fees, u8::MAX), 40);
+    assert_eq!(priority_fee_percentile(&[], 75), 0);
+}
+
+#[tokio::test]
+async fn test_success_estimated_priority_fee() {
+    let mut mocks = Mocks::default();
+    mocks.insert(
+        RpcRequest::GetRecentPrioritizationFees,
+        json!([
+            RpcPrioritizationFee {
+                slot: 1,
+                prioritization_fee: 1_000,
+            },
+            RpcPrioritizationFee {
+                slot: 2,
+                prioritization_fee: 500_000,
+            },
+        ]),
+    );
+    let rpc_client = RpcClient::new_mock_with_mocks("succeeds".to_string(), mocks);
+    // The client's priority fee is used by the transactions it builds
+    let client = RndrClient::new(rpc_client, Pubkey::new_unique()).with_priority_fee(
+        PriorityFee::Estimated {
+            percentile: 100,
+            max_micro_lamports: 100_000,
+        },
+    );
+    let payer = Keypair::new();
+
+    let instruction = aggregate_escrow_shard(rndr::id(), client.escrow_address(), 0);
+
+    let instructions = client
+        .transaction()
+        .instruction(instruction.clone())
+        .compute_unit_limit(ComputeUnitLimit::Default)
+        .build_instructions(&payer.pubkey())
+        .await
+        .unwrap();
+
+    // The estimate is capped at the maximum
+    assert_eq!(
+        instructions,
+        vec![
+            ComputeBudgetInstruction::set_compute_unit_price(100_000),
+            instruction,
+        ]
+    );
+}
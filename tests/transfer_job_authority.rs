@@ -0,0 +1,220 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{
+        instruction::{accept_job_authority, cancel_job, transfer_job_authority},
+        processor::process_instruction,
+        state::{Job, JobStatus},
+        test_fixtures::*,
+    },
+    solana_program::program_pack::Pack,
+    solana_program_test::*,
+    solana_sdk::{
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        transaction::Transaction,
+    },
+};
+
+const ZERO: u64 = 0;
+const DECIMALS: u64 = 1_000_000_000;
+const AMOUNT: u64 = 1 * DECIMALS;
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    TestConfig::add(&mut test);
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let authority = Keypair::new();
+    let new_authority = &test_destination_token.owner;
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, authority.pubkey(), AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[transfer_job_authority(
+            rndr::id(),
+            test_mint.pubkey,
+            authority.pubkey(),
+            test_job.pubkey,
+            new_authority.pubkey(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let job = get_job(&mut banks_client, test_job.pubkey).await;
+    assert_eq!(job.authority, authority.pubkey());
+    assert_eq!(job.pending_authority, new_authority.pubkey());
+
+    let mut transaction = Transaction::new_with_payer(
+        &[accept_job_authority(
+            rndr::id(),
+            test_mint.pubkey,
+            new_authority.pubkey(),
+            test_job.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, new_authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let job = get_job(&mut banks_client, test_job.pubkey).await;
+    assert_eq!(job.authority, new_authority.pubkey());
+    assert_eq!(job.pending_authority, Pubkey::default());
+    assert_eq!(job.original_authority, authority.pubkey());
+
+    // The new authority manages the job at its original address
+    let mut instruction = cancel_job(
+        rndr::id(),
+        test_mint.pubkey,
+        new_authority.pubkey(),
+        0,
+        test_destination_token.pubkey,
+    );
+    instruction.accounts[3].pubkey = test_job.pubkey;
+    let mut transaction = Transaction::new_with_payer(&[instruction], Some(&payer.pubkey()));
+    transaction.sign(&[&payer, new_authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let destination_token_balance_after =
+        get_token_balance(&mut banks_client, test_destination_token.pubkey).await;
+    assert_eq!(destination_token_balance_after, AMOUNT);
+
+    let job = get_job(&mut banks_client, test_job.pubkey).await;
+    assert_eq!(job.status, JobStatus::Cancelled);
+}
+
+#[tokio::test]
+async fn test_success_legacy() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let authority = Keypair::new();
+    let new_authority = Pubkey::new_unique();
+    let test_job = TestJob::add_legacy(&mut test, test_escrow.pubkey, authority.pubkey(), AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[transfer_job_authority(
+            rndr::id(),
+            test_mint.pubkey,
+            authority.pubkey(),
+            test_job.pubkey,
+            new_authority,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    // The legacy job was reallocated to record the pending authority
+    let account = banks_client
+        .get_account(test_job.pubkey)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(account.data.len(), Job::LEN);
+
+    let job = get_job(&mut banks_client, test_job.pubkey).await;
+    assert_eq!(job.amount, AMOUNT);
+    assert_eq!(job.pending_authority, new_authority);
+}
+
+#[tokio::test]
+async fn test_not_authority() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, Pubkey::new_unique(), AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let not_authority = Keypair::new();
+    let mut transaction = Transaction::new_with_payer(
+        &[transfer_job_authority(
+            rndr::id(),
+            test_mint.pubkey,
+            not_authority.pubkey(),
+            test_job.pubkey,
+            not_authority.pubkey(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &not_authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+#[tokio::test]
+async fn test_not_pending_authority() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let authority = Keypair::new();
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, authority.pubkey(), AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    // The job can't be accepted before a transfer is started
+    let not_pending_authority = Keypair::new();
+    let mut transaction = Transaction::new_with_payer(
+        &[accept_job_authority(
+            rndr::id(),
+            test_mint.pubkey,
+            not_pending_authority.pubkey(),
+            test_job.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &not_pending_authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let mut transaction = Transaction::new_with_payer(
+        &[transfer_job_authority(
+            rndr::id(),
+            test_mint.pubkey,
+            authority.pubkey(),
+            test_job.pubkey,
+            Pubkey::new_unique(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    // Only the pending authority can accept the transfer
+    let recent_blockhash = banks_client
+        .get_new_latest_blockhash(&recent_blockhash)
+        .await
+        .unwrap();
+    let mut transaction = Transaction::new_with_payer(
+        &[accept_job_authority(
+            rndr::id(),
+            test_mint.pubkey,
+            not_pending_authority.pubkey(),
+            test_job.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &not_pending_authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let job = get_job(&mut banks_client, test_job.pubkey).await;
+    assert_eq!(job.authority, authority.pubkey());
+}
@@ -0,0 +1,93 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{instruction::unstake, processor::process_instruction, test_fixtures::*},
+    solana_program_test::*,
+    solana_sdk::{signature::Signer, transaction::Transaction},
+};
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let node_authority = &test_destination_token.owner;
+    let test_node = TestNode::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_mint.pubkey,
+        node_authority.pubkey(),
+        2 * AMOUNT,
+        false,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[unstake(
+            rndr::id(),
+            AMOUNT,
+            test_mint.pubkey,
+            node_authority.pubkey(),
+            test_destination_token.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, node_authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let node_balance_after = get_token_balance(&mut banks_client, test_node.associated_token).await;
+    let destination_token_balance_after =
+        get_token_balance(&mut banks_client, test_destination_token.pubkey).await;
+
+    assert_eq!(node_balance_after, AMOUNT);
+    assert_eq!(destination_token_balance_after, AMOUNT);
+
+    let node = test_node.get(&mut banks_client).await;
+    assert_eq!(node.stake, AMOUNT);
+}
+
+#[tokio::test]
+async fn test_insufficient_stake() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    const ZERO: u64 = 0;
+    const DECIMALS: u64 = 1_000_000_000;
+    const AMOUNT: u64 = 1 * DECIMALS;
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, ZERO);
+    let test_destination_token = TestToken::add(&mut test, test_mint.pubkey, ZERO);
+    let node_authority = &test_destination_token.owner;
+    TestNode::add(
+        &mut test,
+        test_escrow.pubkey,
+        test_mint.pubkey,
+        node_authority.pubkey(),
+        AMOUNT,
+        false,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[unstake(
+            rndr::id(),
+            AMOUNT + 1,
+            test_mint.pubkey,
+            node_authority.pubkey(),
+            test_destination_token.pubkey,
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, node_authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
@@ -0,0 +1,210 @@
+#![cfg(feature = "test-bpf")]
+
+use {
+    rndr::{
+        instruction::update_job_metadata,
+        processor::process_instruction,
+        state::{Job, JobMetadata, MAX_JOB_METADATA_URI_LEN},
+        test_fixtures::*,
+    },
+    solana_program::program_pack::Pack,
+    solana_program_test::*,
+    solana_sdk::{
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        transaction::Transaction,
+    },
+};
+
+const DECIMALS: u64 = 1_000_000_000;
+const AMOUNT: u64 = 1 * DECIMALS;
+const URI: &str = "ar://bNbA3TEQVL60xlgCcqdz4ZPHFZ711cZ3hmkpGttDt_U";
+const HASH: [u8; 32] = [7; 32];
+const TAGS: u64 = 0b101;
+
+fn metadata() -> JobMetadata {
+    JobMetadata {
+        uri: URI.to_string(),
+        hash: HASH,
+        tags: TAGS,
+    }
+}
+
+#[tokio::test]
+async fn test_success() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let authority = Keypair::new();
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, authority.pubkey(), AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[update_job_metadata(
+            rndr::id(),
+            test_mint.pubkey,
+            authority.pubkey(),
+            test_job.pubkey,
+            metadata(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let job = get_job(&mut banks_client, test_job.pubkey).await;
+    assert_eq!(job.amount, AMOUNT);
+    assert_eq!(job.metadata_uri, URI);
+    assert_eq!(job.metadata_hash, HASH);
+    assert_eq!(job.tags, TAGS);
+
+    // Metadata is cleared with an empty URI
+    let mut transaction = Transaction::new_with_payer(
+        &[update_job_metadata(
+            rndr::id(),
+            test_mint.pubkey,
+            authority.pubkey(),
+            test_job.pubkey,
+            JobMetadata::default(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    let job = get_job(&mut banks_client, test_job.pubkey).await;
+    assert_eq!(job.metadata_uri, "");
+    assert_eq!(job.metadata_hash, [0; 32]);
+    assert_eq!(job.tags, 0);
+}
+
+#[tokio::test]
+async fn test_success_legacy() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let authority = Keypair::new();
+    let test_job = TestJob::add_legacy(&mut test, test_escrow.pubkey, authority.pubkey(), AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[update_job_metadata(
+            rndr::id(),
+            test_mint.pubkey,
+            authority.pubkey(),
+            test_job.pubkey,
+            metadata(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_ok());
+
+    // The legacy job was reallocated to store the metadata
+    let account = banks_client
+        .get_account(test_job.pubkey)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(account.data.len(), Job::LEN);
+
+    let job = get_job(&mut banks_client, test_job.pubkey).await;
+    assert_eq!(job.amount, AMOUNT);
+    assert_eq!(job.metadata_uri, URI);
+}
+
+#[tokio::test]
+async fn test_not_authority() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, Pubkey::new_unique(), AMOUNT);
+    let impostor = Keypair::new();
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[update_job_metadata(
+            rndr::id(),
+            test_mint.pubkey,
+            impostor.pubkey(),
+            test_job.pubkey,
+            metadata(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &impostor], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+#[tokio::test]
+async fn test_not_open() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let authority = Keypair::new();
+    let test_job = TestJob::add_committed(
+        &mut test,
+        test_escrow.pubkey,
+        authority.pubkey(),
+        Pubkey::new_unique(),
+        [1; 32],
+        AMOUNT,
+    );
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[update_job_metadata(
+            rndr::id(),
+            test_mint.pubkey,
+            authority.pubkey(),
+            test_job.pubkey,
+            metadata(),
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+#[tokio::test]
+async fn test_uri_too_long() {
+    let mut test = ProgramTest::new("rndr", rndr::id(), processor!(process_instruction));
+
+    let test_mint = TestMint::add(&mut test, 9, 100 * DECIMALS);
+    let test_escrow = TestEscrow::add(&mut test, test_mint.pubkey, AMOUNT);
+    let authority = Keypair::new();
+    let test_job = TestJob::add(&mut test, test_escrow.pubkey, authority.pubkey(), AMOUNT);
+
+    let (mut banks_client, payer, recent_blockhash) = test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[update_job_metadata(
+            rndr::id(),
+            test_mint.pubkey,
+            authority.pubkey(),
+            test_job.pubkey,
+            JobMetadata {
+                uri: "a".repeat(MAX_JOB_METADATA_URI_LEN + 1),
+                hash: HASH,
+                tags: TAGS,
+            },
+        )],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &authority], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}